@@ -1,5 +1,6 @@
 use std::collections::BTreeMap;
 use serde::{Deserialize, Serialize};
+use rten_text::tokenizer::EncodeOptions;
 use rten_text::{Tokenizer, TokenizerError};
 use crate::process::MessageChunk;
 
@@ -55,7 +56,13 @@ pub(crate) fn encode_message(
         match chunk {
             MessageChunk::Token(tok_id) => token_ids.push(*tok_id),
             MessageChunk::Text(text) => {
-                let encoded = tokenizer.encode(*text, None)?;
+                // Special tokens are added explicitly above. Don't let the
+                // user's text inject them.
+                let options = EncodeOptions {
+                    split_special_tokens: true,
+                    ..Default::default()
+                };
+                let encoded = tokenizer.encode(*text, Some(options))?;
                 token_ids.extend(encoded.token_ids());
             }
         }
@@ -60,8 +60,29 @@ impl_pod!(u8);
 impl_pod!(f32);
 impl_pod!(i32);
 impl_pod!(u32);
+impl_pod!(i64);
 impl_pod!(u64);
 
+macro_rules! impl_pod_half {
+    ($type:ty) => {
+        unsafe impl Pod for $type {
+            type Bytes = [u8; 2];
+
+            fn to_bytes(self) -> Self::Bytes {
+                self.to_bits().to_ne_bytes()
+            }
+
+            fn from_bytes(val: Self::Bytes) -> Self {
+                Self::from_bits(u16::from_ne_bytes(val))
+            }
+        }
+    };
+}
+impl_pod!(i16);
+impl_pod!(u16);
+impl_pod_half!(crate::half::f16);
+impl_pod_half!(crate::half::bf16);
+
 /// Return the length of a slice transmuted from `Src` to `Dst`, or `None` if
 /// the transmute is not possible.
 fn transmuted_slice_len<Src, Dst>(src: &[Src]) -> Option<usize> {
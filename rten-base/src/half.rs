@@ -3,6 +3,8 @@
 //! This can be replaced with f16 from the Rust standard library when that is
 //! stabilized. See <https://github.com/rust-lang/rust/issues/116909>.
 
+use std::fmt;
+
 /// Convert an f16 value to f32.
 ///
 /// The implementation is copied from the `half` crate -
@@ -50,7 +52,7 @@ pub fn f16_to_f32(i: u16) -> f32 {
     f32::from_bits(sign | exp | man)
 }
 
-/// Convert an f16 value to f32.
+/// Convert an f32 value to f16.
 ///
 /// The implementation is copied from the `half` crate -
 /// <https://github.com/VoidStarKat/half-rs>.
@@ -112,11 +114,101 @@ pub fn f32_to_f16(value: f32) -> u16 {
     }
 }
 
+/// Convert a bfloat16 value to f32.
+pub fn bf16_to_f32(i: u16) -> f32 {
+    f32::from_bits((i as u32) << 16)
+}
+
+/// Convert an f32 value to bfloat16, rounding to nearest even.
+pub fn f32_to_bf16(value: f32) -> u16 {
+    let x = value.to_bits();
+    if value.is_nan() {
+        // Truncate, keeping the NaN quiet.
+        return ((x >> 16) | 0x0040) as u16;
+    }
+    let round_bias = 0x7FFF + ((x >> 16) & 1);
+    (x.wrapping_add(round_bias) >> 16) as u16
+}
+
+macro_rules! impl_half_type {
+    ($type:ident, $to_f32:ident, $from_f32:ident) => {
+        impl $type {
+            /// Create a value from its bit representation.
+            pub const fn from_bits(bits: u16) -> Self {
+                Self(bits)
+            }
+
+            /// Return the bit representation of this value.
+            pub const fn to_bits(self) -> u16 {
+                self.0
+            }
+
+            /// Convert an f32 value to this type, rounding to nearest even.
+            pub fn from_f32(val: f32) -> Self {
+                Self($from_f32(val))
+            }
+
+            /// Convert this value to f32. This conversion is lossless.
+            pub fn to_f32(self) -> f32 {
+                $to_f32(self.0)
+            }
+        }
+
+        impl PartialEq for $type {
+            fn eq(&self, other: &Self) -> bool {
+                self.to_f32() == other.to_f32()
+            }
+        }
+
+        impl PartialOrd for $type {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                self.to_f32().partial_cmp(&other.to_f32())
+            }
+        }
+
+        impl fmt::Debug for $type {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&self.to_f32(), f)
+            }
+        }
+
+        impl fmt::Display for $type {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.to_f32(), f)
+            }
+        }
+
+        impl From<$type> for f32 {
+            fn from(val: $type) -> f32 {
+                val.to_f32()
+            }
+        }
+    };
+}
+
+/// IEEE 754 half-precision (binary16) floating point value.
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, Default)]
+#[repr(transparent)]
+pub struct f16(u16);
+
+impl_half_type!(f16, f16_to_f32, f32_to_f16);
+
+/// bfloat16 ("brain float") value.
+///
+/// This has the same exponent range as f32 but only 8 bits of precision.
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, Default)]
+#[repr(transparent)]
+pub struct bf16(u16);
+
+impl_half_type!(bf16, bf16_to_f32, f32_to_bf16);
+
 #[cfg(test)]
 mod tests {
     use rten_testing::TestCases;
 
-    use crate::half::{f16_to_f32, f32_to_f16};
+    use crate::half::{bf16, bf16_to_f32, f16, f16_to_f32, f32_to_bf16, f32_to_f16};
 
     #[test]
     fn test_f16_to_f32() {
@@ -178,4 +270,61 @@ mod tests {
             assert_eq!(float, output);
         });
     }
+
+    #[test]
+    fn test_bf16_to_f32() {
+        #[derive(Clone, Debug)]
+        struct Case {
+            input: f32,
+            output: f32,
+        }
+        let cases = [
+            Case {
+                input: 0.,
+                output: 0.,
+            },
+            Case {
+                input: -1.,
+                output: -1.,
+            },
+            Case {
+                input: 0.333,
+                output: 0.33203125,
+            },
+            Case {
+                input: 3.0e38,
+                output: 3.0040553e38,
+            },
+            Case {
+                input: f32::INFINITY,
+                output: f32::INFINITY,
+            },
+            Case {
+                input: f32::MAX,
+                output: f32::INFINITY,
+            },
+        ];
+
+        cases.test_each_clone(|Case { input, output }| {
+            let bf = f32_to_bf16(input);
+            let float = bf16_to_f32(bf);
+            assert_eq!(float, output);
+        });
+
+        assert!(bf16_to_f32(f32_to_bf16(f32::NAN)).is_nan());
+    }
+
+    #[test]
+    fn test_half_types() {
+        let x = f16::from_f32(1.5);
+        assert_eq!(x.to_bits(), f32_to_f16(1.5));
+        assert_eq!(x.to_f32(), 1.5);
+        assert_eq!(f16::from_f32(0.), f16::from_f32(-0.));
+        assert_eq!(format!("{:?}", x), "1.5");
+
+        let y = bf16::from_f32(-2.);
+        assert_eq!(f32::from(y), -2.);
+        assert!(y < bf16::from_f32(1.));
+        assert_eq!(bf16::default().to_f32(), 0.);
+    }
 }
@@ -76,6 +76,34 @@ impl_int_identities!(isize);
 impl_int_identities!(i32);
 impl_int_identities!(i8);
 impl_int_identities!(u8);
+impl_int_identities!(i64);
+
+impl Identities for bool {
+    fn one() -> Self {
+        true
+    }
+
+    fn zero() -> Self {
+        false
+    }
+}
+
+macro_rules! impl_half_identities {
+    ($type:ty) => {
+        impl Identities for $type {
+            fn one() -> Self {
+                <$type>::from_f32(1.)
+            }
+
+            fn zero() -> Self {
+                <$type>::default()
+            }
+        }
+    };
+}
+
+impl_half_identities!(crate::half::f16);
+impl_half_identities!(crate::half::bf16);
 
 /// Test if a number is a float NaN ("Not a number") value.
 pub trait IsNaN {
@@ -107,6 +135,7 @@ macro_rules! impl_isnan_int {
 
 impl_isnan_float!(f32);
 impl_isnan_int!(i32);
+impl_isnan_int!(i64);
 impl_isnan_int!(i8);
 impl_isnan_int!(u8);
 
@@ -144,6 +173,37 @@ impl_le_bytes!(u32, 4);
 impl_le_bytes!(i64, 8);
 impl_le_bytes!(u64, 8);
 
+macro_rules! impl_le_bytes_half {
+    ($type:ty) => {
+        impl LeBytes for $type {
+            type Bytes = [u8; 2];
+
+            fn from_le_bytes(bytes: Self::Bytes) -> Self {
+                <$type>::from_bits(u16::from_le_bytes(bytes))
+            }
+
+            fn to_le_bytes(self) -> Self::Bytes {
+                self.to_bits().to_le_bytes()
+            }
+        }
+    };
+}
+
+impl_le_bytes_half!(crate::half::f16);
+impl_le_bytes_half!(crate::half::bf16);
+
+impl LeBytes for bool {
+    type Bytes = [u8; 1];
+
+    fn from_le_bytes(bytes: Self::Bytes) -> Self {
+        bytes[0] != 0
+    }
+
+    fn to_le_bytes(self) -> Self::Bytes {
+        [self as u8]
+    }
+}
+
 pub trait MinMax {
     /// Return the maximum value for this type.
     #[allow(unused)] // Not used yet, but included for completeness
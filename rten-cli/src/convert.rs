@@ -0,0 +1,112 @@
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+use argh::FromArgs;
+use rten::{ModelOptions, SaveOptions};
+
+/// Convert an ONNX model to the .rten format.
+#[derive(FromArgs)]
+#[argh(subcommand, name = "convert")]
+pub struct ConvertArgs {
+    /// path to '.onnx' model to convert
+    #[argh(positional)]
+    pub input: String,
+
+    /// path to write '.rten' model to. Defaults to the input path with the extension replaced by '.rten'.
+    #[argh(positional)]
+    pub output: Option<String>,
+
+    /// save the optimized graph. The optimized graph is only used by the same version of RTen which saved it. Other versions will re-optimize the graph when loading.
+    #[argh(switch)]
+    pub optimize: bool,
+
+    /// save prepacked weights for the current CPU. Implies `--optimize`.
+    #[argh(switch)]
+    pub prepack_weights: bool,
+}
+
+/// Return the path that a converted model is written to if no output path is
+/// specified.
+fn default_output_path(input: &Path) -> PathBuf {
+    input.with_extension("rten")
+}
+
+/// Options for [`convert`].
+#[derive(Default)]
+struct ConvertOptions {
+    optimize: bool,
+    prepack_weights: bool,
+}
+
+fn convert(input: &Path, output: &Path, opts: &ConvertOptions) -> Result<(), Box<dyn Error>> {
+    if input == output {
+        return Err("output path is the same as the input path".into());
+    }
+
+    // By default optimization is disabled so that the saved graph can be used
+    // by any version of RTen. Models are then optimized when the converted
+    // file is loaded.
+    let optimize = opts.optimize || opts.prepack_weights;
+    let model = ModelOptions::with_all_ops()
+        .enable_optimization(optimize)
+        .prepack_weights(opts.prepack_weights)
+        .load_file(input)?;
+
+    let mut save_opts = SaveOptions::default();
+    save_opts.prepacked_weights(opts.prepack_weights);
+    model.save_with_options(output, &save_opts)?;
+
+    Ok(())
+}
+
+/// Entry point for the `rten convert` subcommand.
+pub fn main(args: ConvertArgs) {
+    let input = PathBuf::from(&args.input);
+    let output = args
+        .output
+        .map(PathBuf::from)
+        .unwrap_or_else(|| default_output_path(&input));
+
+    let opts = ConvertOptions {
+        optimize: args.optimize,
+        prepack_weights: args.prepack_weights,
+    };
+
+    if let Err(err) = convert(&input, &output, &opts) {
+        eprintln!("Failed to convert \"{}\": {}", input.display(), err);
+        std::process::exit(1);
+    }
+    println!(
+        "Converted \"{}\" to \"{}\"",
+        input.display(),
+        output.display()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{ConvertOptions, convert, default_output_path};
+
+    #[test]
+    fn test_default_output_path() {
+        assert_eq!(
+            default_output_path(Path::new("models/mnist.onnx")),
+            Path::new("models/mnist.rten")
+        );
+        assert_eq!(
+            default_output_path(Path::new("model")),
+            Path::new("model.rten")
+        );
+    }
+
+    #[test]
+    fn test_convert_same_path() {
+        let path = Path::new("model.rten");
+        let err = convert(path, path, &ConvertOptions::default())
+            .err()
+            .unwrap();
+        assert_eq!(err.to_string(), "output path is the same as the input path");
+    }
+}
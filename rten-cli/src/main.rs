@@ -13,6 +13,7 @@ use rten_tensor::prelude::*;
 use rten_tensor::{Tensor, TensorView};
 use safetensors::SafeTensors;
 
+mod convert;
 mod dim_size;
 use dim_size::DimSize;
 mod input_info;
@@ -32,7 +33,11 @@ enum ProfileMode {
 
 /// Inspect and run ONNX or RTen models.
 #[derive(argh::FromArgs)]
+#[argh(note = "To run a model file whose name matches a subcommand, prefix the path with `./`.")]
 struct Args {
+    #[argh(subcommand)]
+    command: Option<Command>,
+
     /// path to '.onnx' or '.rten' model to inspect and run
     #[argh(positional)]
     model: Option<String>,
@@ -99,6 +104,12 @@ struct Args {
     version: bool,
 }
 
+#[derive(argh::FromArgs)]
+#[argh(subcommand)]
+enum Command {
+    Convert(convert::ConvertArgs),
+}
+
 #[derive(Clone, Copy, Default, PartialEq)]
 enum InferShapesMode {
     #[default]
@@ -469,6 +480,12 @@ impl RandomInputGenerator {
             name if name.ends_with("_mask") && matches!(dtype, Some(DataType::Int32) | None) => {
                 Value::from(Tensor::full(&resolved_shape, 1i32))
             }
+            name if name.ends_with("_mask") && dtype == Some(DataType::Int64) => {
+                Value::from(Tensor::full(&resolved_shape, 1i64))
+            }
+            name if name.ends_with("_mask") && dtype == Some(DataType::Bool) => {
+                Value::from(Tensor::full(&resolved_shape, true))
+            }
 
             // Inputs such as `token_type_ids`, `position_ids`, `input_ids`.
             // We use zero as a value that is likely to be valid for all
@@ -476,6 +493,9 @@ impl RandomInputGenerator {
             name if name.ends_with("_ids") && matches!(dtype, Some(DataType::Int32) | None) => {
                 Value::from(Tensor::<i32>::zeros(&resolved_shape))
             }
+            name if name.ends_with("_ids") && dtype == Some(DataType::Int64) => {
+                Value::from(Tensor::<i64>::zeros(&resolved_shape))
+            }
 
             // Optimum can export "merged" transformer models which have two
             // branches. One accepts KV-cache inputs and the other does not.
@@ -484,6 +504,7 @@ impl RandomInputGenerator {
             "use_cache_branch" if matches!(dtype, Some(DataType::Int32) | None) => {
                 Value::from(Tensor::from(0i32))
             }
+            "use_cache_branch" if dtype == Some(DataType::Bool) => Value::from(Tensor::from(false)),
 
             // For anything else, random values.
             _ => match dtype {
@@ -497,6 +518,8 @@ impl RandomInputGenerator {
                 Some(DataType::Int32) => random_ints(&resolved_shape, || self.rng.i32(0..256)),
                 Some(DataType::Int8) => random_ints(&resolved_shape, || self.rng.i8(0..=127)),
                 Some(DataType::UInt8) => random_ints(&resolved_shape, || self.rng.u8(0..=255)),
+                Some(DataType::Int64) => random_ints(&resolved_shape, || self.rng.i64(0..256)),
+                Some(DataType::Bool) => random_ints(&resolved_shape, || self.rng.bool()),
                 Some(dtype) => {
                     return Err(GenerateError::UnsupportedDataType(ValueType::Tensor(dtype)));
                 }
@@ -533,6 +556,8 @@ fn read_safetensors(path: &Path) -> Result<HashMap<String, Value>, Box<dyn Error
         let value: Value = match view.dtype() {
             Dtype::F32 => read_tensor::<f32, _>(view, f32::from_le_bytes).into(),
             Dtype::I32 => read_tensor::<i32, _>(view, i32::from_le_bytes).into(),
+            Dtype::I64 => read_tensor::<i64, _>(view, i64::from_le_bytes).into(),
+            Dtype::BOOL => read_tensor::<bool, _>(view, |[x]| x != 0).into(),
             _ => {
                 return Err(format!("Unsupported tensor dtype {:?}", view.dtype()).into());
             }
@@ -542,12 +567,18 @@ fn read_safetensors(path: &Path) -> Result<HashMap<String, Value>, Box<dyn Error
     Ok(result)
 }
 
-/// Tool for inspecting converted ONNX models and running them with randomly
+/// Tool for inspecting ONNX and RTen models and running them with randomly
 /// generated inputs.
 ///
 /// ```
-/// rten-convert model.onnx output.rten
-/// cargo run -p rten-cli --release output.rten
+/// cargo run -p rten-cli --release model.onnx
+/// ```
+///
+/// ONNX models can be converted to `.rten` format using the `convert`
+/// subcommand:
+///
+/// ```
+/// cargo run -p rten-cli --release convert model.onnx output.rten
 /// ```
 ///
 /// To get detailed timing information set the `RTEN_TIMING` env var before
@@ -555,6 +586,11 @@ fn read_safetensors(path: &Path) -> Result<HashMap<String, Value>, Box<dyn Error
 fn main() {
     let args: Args = argh::from_env();
 
+    if let Some(Command::Convert(convert_args)) = args.command {
+        convert::main(convert_args);
+        return;
+    }
+
     // Handle --version flag
     if args.version {
         println!("rten {}", env!("CARGO_PKG_VERSION"));
@@ -691,3 +727,40 @@ fn main() {
         std::process::exit(1);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use argh::FromArgs;
+
+    use super::{Args, Command};
+
+    fn parse(args: &[&str]) -> Args {
+        Args::from_args(&["rten"], args).unwrap()
+    }
+
+    #[test]
+    fn test_parse_convert_command() {
+        let args = parse(&["convert", "model.onnx", "model.rten", "--optimize"]);
+        let Some(Command::Convert(convert_args)) = args.command else {
+            panic!("expected convert command");
+        };
+        assert_eq!(convert_args.input, "model.onnx");
+        assert_eq!(convert_args.output.as_deref(), Some("model.rten"));
+        assert!(convert_args.optimize);
+        assert!(args.model.is_none());
+    }
+
+    #[test]
+    fn test_parse_model_path() {
+        let args = parse(&["model.onnx", "-n", "2"]);
+        assert!(args.command.is_none());
+        assert_eq!(args.model.as_deref(), Some("model.onnx"));
+        assert_eq!(args.n_iters, 2);
+
+        // A model whose path matches a subcommand name can be run by giving
+        // a path that doesn't exactly match it.
+        let args = parse(&["./convert"]);
+        assert!(args.command.is_none());
+        assert_eq!(args.model.as_deref(), Some("./convert"));
+    }
+}
@@ -136,6 +136,50 @@ class OperatorType(object):
     PRelu = 126
     STFT = 127
     GlobalMaxPool = 128
+    AddSoftmax = 129
+    ComputeShape = 130
+    FusedMatMul = 131
+    GroupedQueryAttentionMatMul = 132
+    MatMulIntegerToFloat = 133
+    RepeatInterleave = 134
+    RmsNormalization = 135
+    Silu = 136
+    Swish = 137
+    TransformInputs = 138
+    Attention = 139
+    RotaryEmbedding = 140
+    GroupQueryAttention = 141
+    SkipSimplifiedLayerNormalization = 142
+    SpaceToDepth = 143
+    DFT = 144
+    HannWindow = 145
+    HammingWindow = 146
+    BlackmanWindow = 147
+    MelWeightMatrix = 148
+    GroupNormalization = 149
+    Col2Im = 150
+    RoiAlign = 151
+    LpPool = 152
+    MaxUnpool = 153
+    MeanVarianceNormalization = 154
+    LpNormalization = 155
+    Mish = 156
+    Selu = 157
+    Celu = 158
+    Shrink = 159
+    ThresholdedRelu = 160
+    Hardmax = 161
+    BitShift = 162
+    BitwiseAnd = 163
+    BitwiseNot = 164
+    BitwiseOr = 165
+    BitwiseXor = 166
+    ReverseSequence = 167
+    Compress = 168
+    Unique = 169
+    ReduceLogSumExp = 170
+    Det = 171
+    MatMulNBits = 172
 
 
 class RNNDirection(object):
@@ -154,6 +198,10 @@ class DataType(object):
     Float = 1
     Int8 = 2
     UInt8 = 3
+    Float16 = 4
+    BFloat16 = 5
+    Int64 = 6
+    Bool = 7
 
 
 class CoordTransformMode(object):
@@ -231,6 +279,34 @@ class OperatorAttrs(object):
     SplitToSequenceAttrs = 52
     GridSampleAttrs = 53
     STFTAttrs = 54
+    ComputeShapeAttrs = 55
+    FusedMatMulAttrs = 56
+    GroupedQueryAttentionMatMulAttrs = 57
+    RepeatInterleaveAttrs = 58
+    SwishAttrs = 59
+    TransformInputsAttrs = 60
+    AttentionAttrs = 61
+    GroupQueryAttentionAttrs = 62
+    RotaryEmbeddingAttrs = 63
+    SpaceToDepthAttrs = 64
+    DFTAttrs = 65
+    WindowAttrs = 66
+    GroupNormalizationAttrs = 67
+    Col2ImAttrs = 68
+    RoiAlignAttrs = 69
+    LpPoolAttrs = 70
+    MeanVarianceNormalizationAttrs = 71
+    LpNormalizationAttrs = 72
+    BitShiftAttrs = 73
+    CompressAttrs = 74
+    ReverseSequenceAttrs = 75
+    SeluAttrs = 76
+    ShrinkAttrs = 77
+    UniqueAttrs = 78
+    CeluAttrs = 79
+    HardmaxAttrs = 80
+    ThresholdedReluAttrs = 81
+    MatMulNBitsAttrs = 82
 
 def OperatorAttrsCreator(unionType, table):
     from flatbuffers.table import Table
@@ -344,9 +420,84 @@ def OperatorAttrsCreator(unionType, table):
         return GridSampleAttrsT.InitFromBuf(table.Bytes, table.Pos)
     if unionType == OperatorAttrs.STFTAttrs:
         return STFTAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs.ComputeShapeAttrs:
+        return ComputeShapeAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs.FusedMatMulAttrs:
+        return FusedMatMulAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs.GroupedQueryAttentionMatMulAttrs:
+        return GroupedQueryAttentionMatMulAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs.RepeatInterleaveAttrs:
+        return RepeatInterleaveAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs.SwishAttrs:
+        return SwishAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs.TransformInputsAttrs:
+        return TransformInputsAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs.AttentionAttrs:
+        return AttentionAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs.GroupQueryAttentionAttrs:
+        return GroupQueryAttentionAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs.RotaryEmbeddingAttrs:
+        return RotaryEmbeddingAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs.SpaceToDepthAttrs:
+        return SpaceToDepthAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs.DFTAttrs:
+        return DFTAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs.WindowAttrs:
+        return WindowAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs.GroupNormalizationAttrs:
+        return GroupNormalizationAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs.Col2ImAttrs:
+        return Col2ImAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs.RoiAlignAttrs:
+        return RoiAlignAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs.LpPoolAttrs:
+        return LpPoolAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs.MeanVarianceNormalizationAttrs:
+        return MeanVarianceNormalizationAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs.LpNormalizationAttrs:
+        return LpNormalizationAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs.BitShiftAttrs:
+        return BitShiftAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs.CompressAttrs:
+        return CompressAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs.ReverseSequenceAttrs:
+        return ReverseSequenceAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs.SeluAttrs:
+        return SeluAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs.ShrinkAttrs:
+        return ShrinkAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs.UniqueAttrs:
+        return UniqueAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs.CeluAttrs:
+        return CeluAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs.HardmaxAttrs:
+        return HardmaxAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs.ThresholdedReluAttrs:
+        return ThresholdedReluAttrsT.InitFromBuf(table.Bytes, table.Pos)
+    if unionType == OperatorAttrs.MatMulNBitsAttrs:
+        return MatMulNBitsAttrsT.InitFromBuf(table.Bytes, table.Pos)
     return None
 
 
+class BitShiftDirection(object):
+    Left = 0
+    Right = 1
+
+
+class SymExprOp(object):
+    Value = 0
+    Var = 1
+    Add = 2
+    Sub = 3
+    Mul = 4
+    Div = 5
+    DivCeil = 6
+    Max = 7
+    Min = 8
+    Broadcast = 9
+    Neg = 10
+
+
 class DepthToSpaceMode(object):
     DCR = 0
     CRD = 1
@@ -385,6 +536,11 @@ class PadMode(object):
     Wrap = 3
 
 
+class RoiAlignMode(object):
+    Avg = 0
+    Max = 1
+
+
 class ScatterReduction(object):
     None_ = 0
     Add = 1
@@ -439,6 +595,10 @@ class ConstantDataType(object):
     Float32 = 1
     Int8 = 2
     UInt8 = 3
+    Float16 = 4
+    BFloat16 = 5
+    Int64 = 6
+    Bool = 7
 
 
 class ArgMaxAttrs(object):
@@ -535,6 +695,160 @@ class ArgMaxAttrsT(object):
         return argMaxAttrs
 
 
+class AttentionAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = AttentionAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsAttentionAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def AttentionAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # AttentionAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # AttentionAttrs
+    def IsCausal(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+    # AttentionAttrs
+    def QNumHeads(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return None
+
+    # AttentionAttrs
+    def KvNumHeads(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return None
+
+    # AttentionAttrs
+    def Scale(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return None
+
+    # AttentionAttrs
+    def Softcap(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return None
+
+    # AttentionAttrs
+    def FlushNansToZero(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+def AttentionAttrsStart(builder):
+    builder.StartObject(6)
+
+def AttentionAttrsAddIsCausal(builder, isCausal):
+    builder.PrependBoolSlot(0, isCausal, 0)
+
+def AttentionAttrsAddQNumHeads(builder, qNumHeads):
+    builder.PrependUint32Slot(1, qNumHeads, None)
+
+def AttentionAttrsAddKvNumHeads(builder, kvNumHeads):
+    builder.PrependUint32Slot(2, kvNumHeads, None)
+
+def AttentionAttrsAddScale(builder, scale):
+    builder.PrependFloat32Slot(3, scale, None)
+
+def AttentionAttrsAddSoftcap(builder, softcap):
+    builder.PrependFloat32Slot(4, softcap, None)
+
+def AttentionAttrsAddFlushNansToZero(builder, flushNansToZero):
+    builder.PrependBoolSlot(5, flushNansToZero, 0)
+
+def AttentionAttrsEnd(builder):
+    return builder.EndObject()
+
+
+try:
+    from typing import Optional
+except:
+    pass
+
+class AttentionAttrsT(object):
+
+    # AttentionAttrsT
+    def __init__(
+        self,
+        isCausal = False,
+        qNumHeads = None,
+        kvNumHeads = None,
+        scale = None,
+        softcap = None,
+        flushNansToZero = False,
+    ):
+        self.isCausal = isCausal  # type: bool
+        self.qNumHeads = qNumHeads  # type: Optional[int]
+        self.kvNumHeads = kvNumHeads  # type: Optional[int]
+        self.scale = scale  # type: Optional[float]
+        self.softcap = softcap  # type: Optional[float]
+        self.flushNansToZero = flushNansToZero  # type: bool
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        attentionAttrs = AttentionAttrs()
+        attentionAttrs.Init(buf, pos)
+        return cls.InitFromObj(attentionAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, attentionAttrs):
+        x = AttentionAttrsT()
+        x._UnPack(attentionAttrs)
+        return x
+
+    # AttentionAttrsT
+    def _UnPack(self, attentionAttrs):
+        if attentionAttrs is None:
+            return
+        self.isCausal = attentionAttrs.IsCausal()
+        self.qNumHeads = attentionAttrs.QNumHeads()
+        self.kvNumHeads = attentionAttrs.KvNumHeads()
+        self.scale = attentionAttrs.Scale()
+        self.softcap = attentionAttrs.Softcap()
+        self.flushNansToZero = attentionAttrs.FlushNansToZero()
+
+    # AttentionAttrsT
+    def Pack(self, builder):
+        AttentionAttrsStart(builder)
+        AttentionAttrsAddIsCausal(builder, self.isCausal)
+        AttentionAttrsAddQNumHeads(builder, self.qNumHeads)
+        AttentionAttrsAddKvNumHeads(builder, self.kvNumHeads)
+        AttentionAttrsAddScale(builder, self.scale)
+        AttentionAttrsAddSoftcap(builder, self.softcap)
+        AttentionAttrsAddFlushNansToZero(builder, self.flushNansToZero)
+        attentionAttrs = AttentionAttrsEnd(builder)
+        return attentionAttrs
+
+
 class AveragePoolAttrs(object):
     __slots__ = ['_tab']
 
@@ -883,6 +1197,86 @@ class BatchNormalizationAttrsT(object):
         return batchNormalizationAttrs
 
 
+class BitShiftAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = BitShiftAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsBitShiftAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def BitShiftAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # BitShiftAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # BitShiftAttrs
+    def Direction(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+def BitShiftAttrsStart(builder):
+    builder.StartObject(1)
+
+def BitShiftAttrsAddDirection(builder, direction):
+    builder.PrependUint8Slot(0, direction, 0)
+
+def BitShiftAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class BitShiftAttrsT(object):
+
+    # BitShiftAttrsT
+    def __init__(
+        self,
+        direction = 0,
+    ):
+        self.direction = direction  # type: int
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        bitShiftAttrs = BitShiftAttrs()
+        bitShiftAttrs.Init(buf, pos)
+        return cls.InitFromObj(bitShiftAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, bitShiftAttrs):
+        x = BitShiftAttrsT()
+        x._UnPack(bitShiftAttrs)
+        return x
+
+    # BitShiftAttrsT
+    def _UnPack(self, bitShiftAttrs):
+        if bitShiftAttrs is None:
+            return
+        self.direction = bitShiftAttrs.Direction()
+
+    # BitShiftAttrsT
+    def Pack(self, builder):
+        BitShiftAttrsStart(builder)
+        BitShiftAttrsAddDirection(builder, self.direction)
+        bitShiftAttrs = BitShiftAttrsEnd(builder)
+        return bitShiftAttrs
+
+
 class CastAttrs(object):
     __slots__ = ['_tab']
 
@@ -1030,60 +1424,96 @@ class CastLikeAttrsT(object):
         return castLikeAttrs
 
 
-class ConcatAttrs(object):
+class SymExprNode(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = ConcatAttrs()
+        x = SymExprNode()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsConcatAttrs(cls, buf, offset=0):
+    def GetRootAsSymExprNode(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def ConcatAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def SymExprNodeBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # ConcatAttrs
+    # SymExprNode
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # ConcatAttrs
-    def Axis(self):
+    # SymExprNode
+    def Op(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # SymExprNode
+    def Value(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
             return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
         return 0
 
-def ConcatAttrsStart(builder):
-    builder.StartObject(1)
+    # SymExprNode
+    def Name(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
 
-def ConcatAttrsAddAxis(builder, axis):
-    builder.PrependInt32Slot(0, axis, 0)
+    # SymExprNode
+    def Positive(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
 
-def ConcatAttrsEnd(builder):
+def SymExprNodeStart(builder):
+    builder.StartObject(4)
+
+def SymExprNodeAddOp(builder, op):
+    builder.PrependUint8Slot(0, op, 0)
+
+def SymExprNodeAddValue(builder, value):
+    builder.PrependInt32Slot(1, value, 0)
+
+def SymExprNodeAddName(builder, name):
+    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(name), 0)
+
+def SymExprNodeAddPositive(builder, positive):
+    builder.PrependBoolSlot(3, positive, 0)
+
+def SymExprNodeEnd(builder):
     return builder.EndObject()
 
 
 
-class ConcatAttrsT(object):
+class SymExprNodeT(object):
 
-    # ConcatAttrsT
+    # SymExprNodeT
     def __init__(
         self,
-        axis = 0,
+        op = 0,
+        value = 0,
+        name = None,
+        positive = False,
     ):
-        self.axis = axis  # type: int
+        self.op = op  # type: int
+        self.value = value  # type: int
+        self.name = name  # type: Optional[str]
+        self.positive = positive  # type: bool
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        concatAttrs = ConcatAttrs()
-        concatAttrs.Init(buf, pos)
-        return cls.InitFromObj(concatAttrs)
+        symExprNode = SymExprNode()
+        symExprNode.Init(buf, pos)
+        return cls.InitFromObj(symExprNode)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -1091,91 +1521,112 @@ class ConcatAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, concatAttrs):
-        x = ConcatAttrsT()
-        x._UnPack(concatAttrs)
+    def InitFromObj(cls, symExprNode):
+        x = SymExprNodeT()
+        x._UnPack(symExprNode)
         return x
 
-    # ConcatAttrsT
-    def _UnPack(self, concatAttrs):
-        if concatAttrs is None:
+    # SymExprNodeT
+    def _UnPack(self, symExprNode):
+        if symExprNode is None:
             return
-        self.axis = concatAttrs.Axis()
+        self.op = symExprNode.Op()
+        self.value = symExprNode.Value()
+        self.name = symExprNode.Name()
+        self.positive = symExprNode.Positive()
 
-    # ConcatAttrsT
+    # SymExprNodeT
     def Pack(self, builder):
-        ConcatAttrsStart(builder)
-        ConcatAttrsAddAxis(builder, self.axis)
-        concatAttrs = ConcatAttrsEnd(builder)
-        return concatAttrs
+        if self.name is not None:
+            name = builder.CreateString(self.name)
+        SymExprNodeStart(builder)
+        SymExprNodeAddOp(builder, self.op)
+        SymExprNodeAddValue(builder, self.value)
+        if self.name is not None:
+            SymExprNodeAddName(builder, name)
+        SymExprNodeAddPositive(builder, self.positive)
+        symExprNode = SymExprNodeEnd(builder)
+        return symExprNode
 
 
-class ConcatFromSequenceAttrs(object):
+class SymbolInfo(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = ConcatFromSequenceAttrs()
+        x = SymbolInfo()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsConcatFromSequenceAttrs(cls, buf, offset=0):
+    def GetRootAsSymbolInfo(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def ConcatFromSequenceAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def SymbolInfoBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # ConcatFromSequenceAttrs
+    # SymbolInfo
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # ConcatFromSequenceAttrs
-    def Axis(self):
+    # SymbolInfo
+    def Name(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
-        return 0
+            return self._tab.String(o + self._tab.Pos)
+        return None
 
-    # ConcatFromSequenceAttrs
-    def NewAxis(self):
+    # SymbolInfo
+    def Input(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
-        return False
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
 
-def ConcatFromSequenceAttrsStart(builder):
-    builder.StartObject(2)
+    # SymbolInfo
+    def Axis(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
 
-def ConcatFromSequenceAttrsAddAxis(builder, axis):
-    builder.PrependInt32Slot(0, axis, 0)
+def SymbolInfoStart(builder):
+    builder.StartObject(3)
 
-def ConcatFromSequenceAttrsAddNewAxis(builder, newAxis):
-    builder.PrependBoolSlot(1, newAxis, 0)
+def SymbolInfoAddName(builder, name):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(name), 0)
 
-def ConcatFromSequenceAttrsEnd(builder):
+def SymbolInfoAddInput(builder, input):
+    builder.PrependUint32Slot(1, input, 0)
+
+def SymbolInfoAddAxis(builder, axis):
+    builder.PrependUint32Slot(2, axis, 0)
+
+def SymbolInfoEnd(builder):
     return builder.EndObject()
 
 
 
-class ConcatFromSequenceAttrsT(object):
+class SymbolInfoT(object):
 
-    # ConcatFromSequenceAttrsT
+    # SymbolInfoT
     def __init__(
         self,
+        name = None,
+        input = 0,
         axis = 0,
-        newAxis = False,
     ):
+        self.name = name  # type: Optional[str]
+        self.input = input  # type: int
         self.axis = axis  # type: int
-        self.newAxis = newAxis  # type: bool
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        concatFromSequenceAttrs = ConcatFromSequenceAttrs()
-        concatFromSequenceAttrs.Init(buf, pos)
-        return cls.InitFromObj(concatFromSequenceAttrs)
+        symbolInfo = SymbolInfo()
+        symbolInfo.Init(buf, pos)
+        return cls.InitFromObj(symbolInfo)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -1183,93 +1634,142 @@ class ConcatFromSequenceAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, concatFromSequenceAttrs):
-        x = ConcatFromSequenceAttrsT()
-        x._UnPack(concatFromSequenceAttrs)
+    def InitFromObj(cls, symbolInfo):
+        x = SymbolInfoT()
+        x._UnPack(symbolInfo)
         return x
 
-    # ConcatFromSequenceAttrsT
-    def _UnPack(self, concatFromSequenceAttrs):
-        if concatFromSequenceAttrs is None:
+    # SymbolInfoT
+    def _UnPack(self, symbolInfo):
+        if symbolInfo is None:
             return
-        self.axis = concatFromSequenceAttrs.Axis()
-        self.newAxis = concatFromSequenceAttrs.NewAxis()
+        self.name = symbolInfo.Name()
+        self.input = symbolInfo.Input()
+        self.axis = symbolInfo.Axis()
 
-    # ConcatFromSequenceAttrsT
+    # SymbolInfoT
     def Pack(self, builder):
-        ConcatFromSequenceAttrsStart(builder)
-        ConcatFromSequenceAttrsAddAxis(builder, self.axis)
-        ConcatFromSequenceAttrsAddNewAxis(builder, self.newAxis)
-        concatFromSequenceAttrs = ConcatFromSequenceAttrsEnd(builder)
-        return concatFromSequenceAttrs
+        if self.name is not None:
+            name = builder.CreateString(self.name)
+        SymbolInfoStart(builder)
+        if self.name is not None:
+            SymbolInfoAddName(builder, name)
+        SymbolInfoAddInput(builder, self.input)
+        SymbolInfoAddAxis(builder, self.axis)
+        symbolInfo = SymbolInfoEnd(builder)
+        return symbolInfo
 
 
-class DepthToSpaceAttrs(object):
+class ComputeShapeAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = DepthToSpaceAttrs()
+        x = ComputeShapeAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsDepthToSpaceAttrs(cls, buf, offset=0):
+    def GetRootAsComputeShapeAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def DepthToSpaceAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def ComputeShapeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # DepthToSpaceAttrs
+    # ComputeShapeAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # DepthToSpaceAttrs
-    def Mode(self):
+    # ComputeShapeAttrs
+    def Symbols(self, j):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+            x = self._tab.Vector(o)
+            x += flatbuffers.number_types.UOffsetTFlags.py_type(j) * 4
+            x = self._tab.Indirect(x)
+            obj = SymbolInfo()
+            obj.Init(self._tab.Bytes, x)
+            return obj
+        return None
+
+    # ComputeShapeAttrs
+    def SymbolsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.VectorLen(o)
         return 0
 
-    # DepthToSpaceAttrs
-    def BlockSize(self):
+    # ComputeShapeAttrs
+    def SymbolsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        return o == 0
+
+    # ComputeShapeAttrs
+    def Shape(self, j):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+            x = self._tab.Vector(o)
+            x += flatbuffers.number_types.UOffsetTFlags.py_type(j) * 4
+            x = self._tab.Indirect(x)
+            obj = SymExprNode()
+            obj.Init(self._tab.Bytes, x)
+            return obj
+        return None
+
+    # ComputeShapeAttrs
+    def ShapeLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.VectorLen(o)
         return 0
 
-def DepthToSpaceAttrsStart(builder):
+    # ComputeShapeAttrs
+    def ShapeIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        return o == 0
+
+def ComputeShapeAttrsStart(builder):
     builder.StartObject(2)
 
-def DepthToSpaceAttrsAddMode(builder, mode):
-    builder.PrependUint8Slot(0, mode, 0)
+def ComputeShapeAttrsAddSymbols(builder, symbols):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(symbols), 0)
 
-def DepthToSpaceAttrsAddBlockSize(builder, blockSize):
-    builder.PrependUint32Slot(1, blockSize, 0)
+def ComputeShapeAttrsStartSymbolsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
 
-def DepthToSpaceAttrsEnd(builder):
+def ComputeShapeAttrsAddShape(builder, shape):
+    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(shape), 0)
+
+def ComputeShapeAttrsStartShapeVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def ComputeShapeAttrsEnd(builder):
     return builder.EndObject()
 
 
+try:
+    from typing import List
+except:
+    pass
 
-class DepthToSpaceAttrsT(object):
+class ComputeShapeAttrsT(object):
 
-    # DepthToSpaceAttrsT
+    # ComputeShapeAttrsT
     def __init__(
         self,
-        mode = 0,
-        blockSize = 0,
+        symbols = None,
+        shape = None,
     ):
-        self.mode = mode  # type: int
-        self.blockSize = blockSize  # type: int
+        self.symbols = symbols  # type: Optional[List[SymbolInfoT]]
+        self.shape = shape  # type: Optional[List[SymExprNodeT]]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        depthToSpaceAttrs = DepthToSpaceAttrs()
-        depthToSpaceAttrs.Init(buf, pos)
-        return cls.InitFromObj(depthToSpaceAttrs)
+        computeShapeAttrs = ComputeShapeAttrs()
+        computeShapeAttrs.Init(buf, pos)
+        return cls.InitFromObj(computeShapeAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -1277,85 +1777,113 @@ class DepthToSpaceAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, depthToSpaceAttrs):
-        x = DepthToSpaceAttrsT()
-        x._UnPack(depthToSpaceAttrs)
+    def InitFromObj(cls, computeShapeAttrs):
+        x = ComputeShapeAttrsT()
+        x._UnPack(computeShapeAttrs)
         return x
 
-    # DepthToSpaceAttrsT
-    def _UnPack(self, depthToSpaceAttrs):
-        if depthToSpaceAttrs is None:
+    # ComputeShapeAttrsT
+    def _UnPack(self, computeShapeAttrs):
+        if computeShapeAttrs is None:
             return
-        self.mode = depthToSpaceAttrs.Mode()
-        self.blockSize = depthToSpaceAttrs.BlockSize()
+        if not computeShapeAttrs.SymbolsIsNone():
+            self.symbols = []
+            for i in range(computeShapeAttrs.SymbolsLength()):
+                if computeShapeAttrs.Symbols(i) is None:
+                    self.symbols.append(None)
+                else:
+                    symbolInfo_ = SymbolInfoT.InitFromObj(computeShapeAttrs.Symbols(i))
+                    self.symbols.append(symbolInfo_)
+        if not computeShapeAttrs.ShapeIsNone():
+            self.shape = []
+            for i in range(computeShapeAttrs.ShapeLength()):
+                if computeShapeAttrs.Shape(i) is None:
+                    self.shape.append(None)
+                else:
+                    symExprNode_ = SymExprNodeT.InitFromObj(computeShapeAttrs.Shape(i))
+                    self.shape.append(symExprNode_)
 
-    # DepthToSpaceAttrsT
+    # ComputeShapeAttrsT
     def Pack(self, builder):
-        DepthToSpaceAttrsStart(builder)
-        DepthToSpaceAttrsAddMode(builder, self.mode)
-        DepthToSpaceAttrsAddBlockSize(builder, self.blockSize)
-        depthToSpaceAttrs = DepthToSpaceAttrsEnd(builder)
-        return depthToSpaceAttrs
+        if self.symbols is not None:
+            symbolslist = []
+            for i in range(len(self.symbols)):
+                symbolslist.append(self.symbols[i].Pack(builder))
+            ComputeShapeAttrsStartSymbolsVector(builder, len(self.symbols))
+            for i in reversed(range(len(self.symbols))):
+                builder.PrependUOffsetTRelative(symbolslist[i])
+            symbols = builder.EndVector()
+        if self.shape is not None:
+            shapelist = []
+            for i in range(len(self.shape)):
+                shapelist.append(self.shape[i].Pack(builder))
+            ComputeShapeAttrsStartShapeVector(builder, len(self.shape))
+            for i in reversed(range(len(self.shape))):
+                builder.PrependUOffsetTRelative(shapelist[i])
+            shape = builder.EndVector()
+        ComputeShapeAttrsStart(builder)
+        if self.symbols is not None:
+            ComputeShapeAttrsAddSymbols(builder, symbols)
+        if self.shape is not None:
+            ComputeShapeAttrsAddShape(builder, shape)
+        computeShapeAttrs = ComputeShapeAttrsEnd(builder)
+        return computeShapeAttrs
 
 
-class DropoutAttrs(object):
+class CeluAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = DropoutAttrs()
+        x = CeluAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsDropoutAttrs(cls, buf, offset=0):
+    def GetRootAsCeluAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def DropoutAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def CeluAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # DropoutAttrs
+    # CeluAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # DropoutAttrs
-    def Seed(self):
+    # CeluAttrs
+    def Alpha(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
-        return None
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
 
-def DropoutAttrsStart(builder):
+def CeluAttrsStart(builder):
     builder.StartObject(1)
 
-def DropoutAttrsAddSeed(builder, seed):
-    builder.PrependInt32Slot(0, seed, None)
+def CeluAttrsAddAlpha(builder, alpha):
+    builder.PrependFloat32Slot(0, alpha, 0.0)
 
-def DropoutAttrsEnd(builder):
+def CeluAttrsEnd(builder):
     return builder.EndObject()
 
 
-try:
-    from typing import Optional
-except:
-    pass
 
-class DropoutAttrsT(object):
+class CeluAttrsT(object):
 
-    # DropoutAttrsT
+    # CeluAttrsT
     def __init__(
         self,
-        seed = None,
+        alpha = 0.0,
     ):
-        self.seed = seed  # type: Optional[int]
+        self.alpha = alpha  # type: float
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        dropoutAttrs = DropoutAttrs()
-        dropoutAttrs.Init(buf, pos)
-        return cls.InitFromObj(dropoutAttrs)
+        celuAttrs = CeluAttrs()
+        celuAttrs.Init(buf, pos)
+        return cls.InitFromObj(celuAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -1363,95 +1891,176 @@ class DropoutAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, dropoutAttrs):
-        x = DropoutAttrsT()
-        x._UnPack(dropoutAttrs)
+    def InitFromObj(cls, celuAttrs):
+        x = CeluAttrsT()
+        x._UnPack(celuAttrs)
         return x
 
-    # DropoutAttrsT
-    def _UnPack(self, dropoutAttrs):
-        if dropoutAttrs is None:
+    # CeluAttrsT
+    def _UnPack(self, celuAttrs):
+        if celuAttrs is None:
             return
-        self.seed = dropoutAttrs.Seed()
+        self.alpha = celuAttrs.Alpha()
 
-    # DropoutAttrsT
+    # CeluAttrsT
     def Pack(self, builder):
-        DropoutAttrsStart(builder)
-        DropoutAttrsAddSeed(builder, self.seed)
-        dropoutAttrs = DropoutAttrsEnd(builder)
-        return dropoutAttrs
+        CeluAttrsStart(builder)
+        CeluAttrsAddAlpha(builder, self.alpha)
+        celuAttrs = CeluAttrsEnd(builder)
+        return celuAttrs
 
 
-class EyeLikeAttrs(object):
+class Col2ImAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = EyeLikeAttrs()
+        x = Col2ImAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsEyeLikeAttrs(cls, buf, offset=0):
+    def GetRootAsCol2ImAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def EyeLikeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def Col2ImAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # EyeLikeAttrs
+    # Col2ImAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # EyeLikeAttrs
-    def Dtype(self):
+    # Col2ImAttrs
+    def Dilations(self, j):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
-        return None
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
 
-    # EyeLikeAttrs
-    def K(self):
+    # Col2ImAttrs
+    def DilationsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # Col2ImAttrs
+    def DilationsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # Col2ImAttrs
+    def DilationsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        return o == 0
+
+    # Col2ImAttrs
+    def Pads(self, j):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
         return 0
 
-def EyeLikeAttrsStart(builder):
-    builder.StartObject(2)
+    # Col2ImAttrs
+    def PadsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
 
-def EyeLikeAttrsAddDtype(builder, dtype):
-    builder.PrependUint8Slot(0, dtype, None)
+    # Col2ImAttrs
+    def PadsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
 
-def EyeLikeAttrsAddK(builder, k):
-    builder.PrependInt32Slot(1, k, 0)
+    # Col2ImAttrs
+    def PadsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        return o == 0
 
-def EyeLikeAttrsEnd(builder):
+    # Col2ImAttrs
+    def Strides(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # Col2ImAttrs
+    def StridesAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # Col2ImAttrs
+    def StridesLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # Col2ImAttrs
+    def StridesIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        return o == 0
+
+def Col2ImAttrsStart(builder):
+    builder.StartObject(3)
+
+def Col2ImAttrsAddDilations(builder, dilations):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(dilations), 0)
+
+def Col2ImAttrsStartDilationsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def Col2ImAttrsAddPads(builder, pads):
+    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(pads), 0)
+
+def Col2ImAttrsStartPadsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def Col2ImAttrsAddStrides(builder, strides):
+    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(strides), 0)
+
+def Col2ImAttrsStartStridesVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def Col2ImAttrsEnd(builder):
     return builder.EndObject()
 
 
 try:
-    from typing import Optional
+    from typing import List
 except:
     pass
 
-class EyeLikeAttrsT(object):
+class Col2ImAttrsT(object):
 
-    # EyeLikeAttrsT
+    # Col2ImAttrsT
     def __init__(
         self,
-        dtype = None,
-        k = 0,
+        dilations = None,
+        pads = None,
+        strides = None,
     ):
-        self.dtype = dtype  # type: Optional[int]
-        self.k = k  # type: int
+        self.dilations = dilations  # type: Optional[List[int]]
+        self.pads = pads  # type: Optional[List[int]]
+        self.strides = strides  # type: Optional[List[int]]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        eyeLikeAttrs = EyeLikeAttrs()
-        eyeLikeAttrs.Init(buf, pos)
-        return cls.InitFromObj(eyeLikeAttrs)
+        col2ImAttrs = Col2ImAttrs()
+        col2ImAttrs.Init(buf, pos)
+        return cls.InitFromObj(col2ImAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -1459,70 +2068,132 @@ class EyeLikeAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, eyeLikeAttrs):
-        x = EyeLikeAttrsT()
-        x._UnPack(eyeLikeAttrs)
+    def InitFromObj(cls, col2ImAttrs):
+        x = Col2ImAttrsT()
+        x._UnPack(col2ImAttrs)
         return x
 
-    # EyeLikeAttrsT
-    def _UnPack(self, eyeLikeAttrs):
-        if eyeLikeAttrs is None:
+    # Col2ImAttrsT
+    def _UnPack(self, col2ImAttrs):
+        if col2ImAttrs is None:
             return
-        self.dtype = eyeLikeAttrs.Dtype()
-        self.k = eyeLikeAttrs.K()
-
-    # EyeLikeAttrsT
+        if not col2ImAttrs.DilationsIsNone():
+            if np is None:
+                self.dilations = []
+                for i in range(col2ImAttrs.DilationsLength()):
+                    self.dilations.append(col2ImAttrs.Dilations(i))
+            else:
+                self.dilations = col2ImAttrs.DilationsAsNumpy()
+        if not col2ImAttrs.PadsIsNone():
+            if np is None:
+                self.pads = []
+                for i in range(col2ImAttrs.PadsLength()):
+                    self.pads.append(col2ImAttrs.Pads(i))
+            else:
+                self.pads = col2ImAttrs.PadsAsNumpy()
+        if not col2ImAttrs.StridesIsNone():
+            if np is None:
+                self.strides = []
+                for i in range(col2ImAttrs.StridesLength()):
+                    self.strides.append(col2ImAttrs.Strides(i))
+            else:
+                self.strides = col2ImAttrs.StridesAsNumpy()
+
+    # Col2ImAttrsT
     def Pack(self, builder):
-        EyeLikeAttrsStart(builder)
-        EyeLikeAttrsAddDtype(builder, self.dtype)
-        EyeLikeAttrsAddK(builder, self.k)
-        eyeLikeAttrs = EyeLikeAttrsEnd(builder)
-        return eyeLikeAttrs
+        if self.dilations is not None:
+            if np is not None and type(self.dilations) is np.ndarray:
+                dilations = builder.CreateNumpyVector(self.dilations)
+            else:
+                Col2ImAttrsStartDilationsVector(builder, len(self.dilations))
+                for i in reversed(range(len(self.dilations))):
+                    builder.PrependUint32(self.dilations[i])
+                dilations = builder.EndVector()
+        if self.pads is not None:
+            if np is not None and type(self.pads) is np.ndarray:
+                pads = builder.CreateNumpyVector(self.pads)
+            else:
+                Col2ImAttrsStartPadsVector(builder, len(self.pads))
+                for i in reversed(range(len(self.pads))):
+                    builder.PrependUint32(self.pads[i])
+                pads = builder.EndVector()
+        if self.strides is not None:
+            if np is not None and type(self.strides) is np.ndarray:
+                strides = builder.CreateNumpyVector(self.strides)
+            else:
+                Col2ImAttrsStartStridesVector(builder, len(self.strides))
+                for i in reversed(range(len(self.strides))):
+                    builder.PrependUint32(self.strides[i])
+                strides = builder.EndVector()
+        Col2ImAttrsStart(builder)
+        if self.dilations is not None:
+            Col2ImAttrsAddDilations(builder, dilations)
+        if self.pads is not None:
+            Col2ImAttrsAddPads(builder, pads)
+        if self.strides is not None:
+            Col2ImAttrsAddStrides(builder, strides)
+        col2ImAttrs = Col2ImAttrsEnd(builder)
+        return col2ImAttrs
 
 
-class IsInfAttrs(object):
+class CompressAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = IsInfAttrs()
+        x = CompressAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsIsInfAttrs(cls, buf, offset=0):
+    def GetRootAsCompressAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def IsInfAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def CompressAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # IsInfAttrs
+    # CompressAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-def IsInfAttrsStart(builder):
-    builder.StartObject(0)
+    # CompressAttrs
+    def Axis(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return None
 
-def IsInfAttrsEnd(builder):
+def CompressAttrsStart(builder):
+    builder.StartObject(1)
+
+def CompressAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, None)
+
+def CompressAttrsEnd(builder):
     return builder.EndObject()
 
 
+try:
+    from typing import Optional
+except:
+    pass
 
-class IsInfAttrsT(object):
+class CompressAttrsT(object):
 
-    # IsInfAttrsT
+    # CompressAttrsT
     def __init__(
         self,
+        axis = None,
     ):
-        pass
+        self.axis = axis  # type: Optional[int]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        isInfAttrs = IsInfAttrs()
-        isInfAttrs.Init(buf, pos)
-        return cls.InitFromObj(isInfAttrs)
+        compressAttrs = CompressAttrs()
+        compressAttrs.Init(buf, pos)
+        return cls.InitFromObj(compressAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -1530,77 +2201,79 @@ class IsInfAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, isInfAttrs):
-        x = IsInfAttrsT()
-        x._UnPack(isInfAttrs)
+    def InitFromObj(cls, compressAttrs):
+        x = CompressAttrsT()
+        x._UnPack(compressAttrs)
         return x
 
-    # IsInfAttrsT
-    def _UnPack(self, isInfAttrs):
-        if isInfAttrs is None:
+    # CompressAttrsT
+    def _UnPack(self, compressAttrs):
+        if compressAttrs is None:
             return
+        self.axis = compressAttrs.Axis()
 
-    # IsInfAttrsT
+    # CompressAttrsT
     def Pack(self, builder):
-        IsInfAttrsStart(builder)
-        isInfAttrs = IsInfAttrsEnd(builder)
-        return isInfAttrs
+        CompressAttrsStart(builder)
+        CompressAttrsAddAxis(builder, self.axis)
+        compressAttrs = CompressAttrsEnd(builder)
+        return compressAttrs
 
 
-class IntScalar(object):
+class ConcatAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = IntScalar()
+        x = ConcatAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsIntScalar(cls, buf, offset=0):
+    def GetRootAsConcatAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def IntScalarBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def ConcatAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # IntScalar
+    # ConcatAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # IntScalar
-    def Value(self):
+    # ConcatAttrs
+    def Axis(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
             return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
         return 0
 
-def IntScalarStart(builder):
+def ConcatAttrsStart(builder):
     builder.StartObject(1)
 
-def IntScalarAddValue(builder, value):
-    builder.PrependInt32Slot(0, value, 0)
+def ConcatAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, 0)
 
-def IntScalarEnd(builder):
+def ConcatAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class IntScalarT(object):
+class ConcatAttrsT(object):
 
-    # IntScalarT
+    # ConcatAttrsT
     def __init__(
         self,
-        value = 0,
+        axis = 0,
     ):
-        self.value = value  # type: int
+        self.axis = axis  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        intScalar = IntScalar()
-        intScalar.Init(buf, pos)
-        return cls.InitFromObj(intScalar)
+        concatAttrs = ConcatAttrs()
+        concatAttrs.Init(buf, pos)
+        return cls.InitFromObj(concatAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -1608,79 +2281,91 @@ class IntScalarT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, intScalar):
-        x = IntScalarT()
-        x._UnPack(intScalar)
+    def InitFromObj(cls, concatAttrs):
+        x = ConcatAttrsT()
+        x._UnPack(concatAttrs)
         return x
 
-    # IntScalarT
-    def _UnPack(self, intScalar):
-        if intScalar is None:
+    # ConcatAttrsT
+    def _UnPack(self, concatAttrs):
+        if concatAttrs is None:
             return
-        self.value = intScalar.Value()
+        self.axis = concatAttrs.Axis()
 
-    # IntScalarT
+    # ConcatAttrsT
     def Pack(self, builder):
-        IntScalarStart(builder)
-        IntScalarAddValue(builder, self.value)
-        intScalar = IntScalarEnd(builder)
-        return intScalar
+        ConcatAttrsStart(builder)
+        ConcatAttrsAddAxis(builder, self.axis)
+        concatAttrs = ConcatAttrsEnd(builder)
+        return concatAttrs
 
 
-class FloatScalar(object):
+class ConcatFromSequenceAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = FloatScalar()
+        x = ConcatFromSequenceAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsFloatScalar(cls, buf, offset=0):
+    def GetRootAsConcatFromSequenceAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def FloatScalarBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def ConcatFromSequenceAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # FloatScalar
+    # ConcatFromSequenceAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # FloatScalar
-    def Value(self):
+    # ConcatFromSequenceAttrs
+    def Axis(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
 
-def FloatScalarStart(builder):
-    builder.StartObject(1)
+    # ConcatFromSequenceAttrs
+    def NewAxis(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
 
-def FloatScalarAddValue(builder, value):
-    builder.PrependFloat32Slot(0, value, 0.0)
+def ConcatFromSequenceAttrsStart(builder):
+    builder.StartObject(2)
 
-def FloatScalarEnd(builder):
+def ConcatFromSequenceAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, 0)
+
+def ConcatFromSequenceAttrsAddNewAxis(builder, newAxis):
+    builder.PrependBoolSlot(1, newAxis, 0)
+
+def ConcatFromSequenceAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class FloatScalarT(object):
+class ConcatFromSequenceAttrsT(object):
 
-    # FloatScalarT
+    # ConcatFromSequenceAttrsT
     def __init__(
         self,
-        value = 0.0,
+        axis = 0,
+        newAxis = False,
     ):
-        self.value = value  # type: float
+        self.axis = axis  # type: int
+        self.newAxis = newAxis  # type: bool
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        floatScalar = FloatScalar()
-        floatScalar.Init(buf, pos)
-        return cls.InitFromObj(floatScalar)
+        concatFromSequenceAttrs = ConcatFromSequenceAttrs()
+        concatFromSequenceAttrs.Init(buf, pos)
+        return cls.InitFromObj(concatFromSequenceAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -1688,98 +2373,93 @@ class FloatScalarT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, floatScalar):
-        x = FloatScalarT()
-        x._UnPack(floatScalar)
+    def InitFromObj(cls, concatFromSequenceAttrs):
+        x = ConcatFromSequenceAttrsT()
+        x._UnPack(concatFromSequenceAttrs)
         return x
 
-    # FloatScalarT
-    def _UnPack(self, floatScalar):
-        if floatScalar is None:
+    # ConcatFromSequenceAttrsT
+    def _UnPack(self, concatFromSequenceAttrs):
+        if concatFromSequenceAttrs is None:
             return
-        self.value = floatScalar.Value()
+        self.axis = concatFromSequenceAttrs.Axis()
+        self.newAxis = concatFromSequenceAttrs.NewAxis()
 
-    # FloatScalarT
+    # ConcatFromSequenceAttrsT
     def Pack(self, builder):
-        FloatScalarStart(builder)
-        FloatScalarAddValue(builder, self.value)
-        floatScalar = FloatScalarEnd(builder)
-        return floatScalar
+        ConcatFromSequenceAttrsStart(builder)
+        ConcatFromSequenceAttrsAddAxis(builder, self.axis)
+        ConcatFromSequenceAttrsAddNewAxis(builder, self.newAxis)
+        concatFromSequenceAttrs = ConcatFromSequenceAttrsEnd(builder)
+        return concatFromSequenceAttrs
 
 
-class ConstantOfShapeAttrs(object):
+class DepthToSpaceAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = ConstantOfShapeAttrs()
+        x = DepthToSpaceAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsConstantOfShapeAttrs(cls, buf, offset=0):
+    def GetRootAsDepthToSpaceAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def ConstantOfShapeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def DepthToSpaceAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # ConstantOfShapeAttrs
+    # DepthToSpaceAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # ConstantOfShapeAttrs
-    def ValueType(self):
+    # DepthToSpaceAttrs
+    def Mode(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
             return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
         return 0
 
-    # ConstantOfShapeAttrs
-    def Value(self):
+    # DepthToSpaceAttrs
+    def BlockSize(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            from flatbuffers.table import Table
-            obj = Table(bytearray(), 0)
-            self._tab.Union(obj, o)
-            return obj
-        return None
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
 
-def ConstantOfShapeAttrsStart(builder):
+def DepthToSpaceAttrsStart(builder):
     builder.StartObject(2)
 
-def ConstantOfShapeAttrsAddValueType(builder, valueType):
-    builder.PrependUint8Slot(0, valueType, 0)
+def DepthToSpaceAttrsAddMode(builder, mode):
+    builder.PrependUint8Slot(0, mode, 0)
 
-def ConstantOfShapeAttrsAddValue(builder, value):
-    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(value), 0)
+def DepthToSpaceAttrsAddBlockSize(builder, blockSize):
+    builder.PrependUint32Slot(1, blockSize, 0)
 
-def ConstantOfShapeAttrsEnd(builder):
+def DepthToSpaceAttrsEnd(builder):
     return builder.EndObject()
 
 
-try:
-    from typing import Union
-except:
-    pass
 
-class ConstantOfShapeAttrsT(object):
+class DepthToSpaceAttrsT(object):
 
-    # ConstantOfShapeAttrsT
+    # DepthToSpaceAttrsT
     def __init__(
         self,
-        valueType = 0,
-        value = None,
+        mode = 0,
+        blockSize = 0,
     ):
-        self.valueType = valueType  # type: int
-        self.value = value  # type: Union[None, 'IntScalarT', 'FloatScalarT']
+        self.mode = mode  # type: int
+        self.blockSize = blockSize  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        constantOfShapeAttrs = ConstantOfShapeAttrs()
-        constantOfShapeAttrs.Init(buf, pos)
-        return cls.InitFromObj(constantOfShapeAttrs)
+        depthToSpaceAttrs = DepthToSpaceAttrs()
+        depthToSpaceAttrs.Init(buf, pos)
+        return cls.InitFromObj(depthToSpaceAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -1787,205 +2467,197 @@ class ConstantOfShapeAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, constantOfShapeAttrs):
-        x = ConstantOfShapeAttrsT()
-        x._UnPack(constantOfShapeAttrs)
+    def InitFromObj(cls, depthToSpaceAttrs):
+        x = DepthToSpaceAttrsT()
+        x._UnPack(depthToSpaceAttrs)
         return x
 
-    # ConstantOfShapeAttrsT
-    def _UnPack(self, constantOfShapeAttrs):
-        if constantOfShapeAttrs is None:
+    # DepthToSpaceAttrsT
+    def _UnPack(self, depthToSpaceAttrs):
+        if depthToSpaceAttrs is None:
             return
-        self.valueType = constantOfShapeAttrs.ValueType()
-        self.value = ScalarCreator(self.valueType, constantOfShapeAttrs.Value())
+        self.mode = depthToSpaceAttrs.Mode()
+        self.blockSize = depthToSpaceAttrs.BlockSize()
 
-    # ConstantOfShapeAttrsT
+    # DepthToSpaceAttrsT
     def Pack(self, builder):
-        if self.value is not None:
-            value = self.value.Pack(builder)
-        ConstantOfShapeAttrsStart(builder)
-        ConstantOfShapeAttrsAddValueType(builder, self.valueType)
-        if self.value is not None:
-            ConstantOfShapeAttrsAddValue(builder, value)
-        constantOfShapeAttrs = ConstantOfShapeAttrsEnd(builder)
-        return constantOfShapeAttrs
+        DepthToSpaceAttrsStart(builder)
+        DepthToSpaceAttrsAddMode(builder, self.mode)
+        DepthToSpaceAttrsAddBlockSize(builder, self.blockSize)
+        depthToSpaceAttrs = DepthToSpaceAttrsEnd(builder)
+        return depthToSpaceAttrs
 
 
-class ConvAttrs(object):
+class DFTAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = ConvAttrs()
+        x = DFTAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsConvAttrs(cls, buf, offset=0):
+    def GetRootAsDFTAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def ConvAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def DFTAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # ConvAttrs
+    # DFTAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # ConvAttrs
-    def AutoPad(self):
+    # DFTAttrs
+    def Axis(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
-        return 0
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return None
 
-    # ConvAttrs
-    def Pads(self, j):
+    # DFTAttrs
+    def Inverse(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
-        return 0
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
 
-    # ConvAttrs
-    def PadsAsNumpy(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+    # DFTAttrs
+    def Onesided(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
         if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
-        return 0
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
 
-    # ConvAttrs
-    def PadsLength(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
-        if o != 0:
-            return self._tab.VectorLen(o)
-        return 0
+def DFTAttrsStart(builder):
+    builder.StartObject(3)
 
-    # ConvAttrs
-    def PadsIsNone(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
-        return o == 0
+def DFTAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, None)
 
-    # ConvAttrs
-    def Groups(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
-        if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
-        return 0
+def DFTAttrsAddInverse(builder, inverse):
+    builder.PrependBoolSlot(1, inverse, 0)
 
-    # ConvAttrs
-    def Strides(self, j):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        if o != 0:
-            a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
-        return 0
+def DFTAttrsAddOnesided(builder, onesided):
+    builder.PrependBoolSlot(2, onesided, 0)
 
-    # ConvAttrs
-    def StridesAsNumpy(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
-        return 0
+def DFTAttrsEnd(builder):
+    return builder.EndObject()
 
-    # ConvAttrs
-    def StridesLength(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        if o != 0:
-            return self._tab.VectorLen(o)
-        return 0
 
-    # ConvAttrs
-    def StridesIsNone(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        return o == 0
+try:
+    from typing import Optional
+except:
+    pass
 
-    # ConvAttrs
-    def Dilations(self, j):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
-        if o != 0:
-            a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
-        return 0
+class DFTAttrsT(object):
 
-    # ConvAttrs
-    def DilationsAsNumpy(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
-        if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
-        return 0
+    # DFTAttrsT
+    def __init__(
+        self,
+        axis = None,
+        inverse = False,
+        onesided = False,
+    ):
+        self.axis = axis  # type: Optional[int]
+        self.inverse = inverse  # type: bool
+        self.onesided = onesided  # type: bool
 
-    # ConvAttrs
-    def DilationsLength(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
-        if o != 0:
-            return self._tab.VectorLen(o)
-        return 0
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        dftattrs = DFTAttrs()
+        dftattrs.Init(buf, pos)
+        return cls.InitFromObj(dftattrs)
 
-    # ConvAttrs
-    def DilationsIsNone(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
-        return o == 0
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
 
-def ConvAttrsStart(builder):
-    builder.StartObject(5)
+    @classmethod
+    def InitFromObj(cls, dftattrs):
+        x = DFTAttrsT()
+        x._UnPack(dftattrs)
+        return x
 
-def ConvAttrsAddAutoPad(builder, autoPad):
-    builder.PrependUint8Slot(0, autoPad, 0)
+    # DFTAttrsT
+    def _UnPack(self, dftattrs):
+        if dftattrs is None:
+            return
+        self.axis = dftattrs.Axis()
+        self.inverse = dftattrs.Inverse()
+        self.onesided = dftattrs.Onesided()
 
-def ConvAttrsAddPads(builder, pads):
-    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(pads), 0)
+    # DFTAttrsT
+    def Pack(self, builder):
+        DFTAttrsStart(builder)
+        DFTAttrsAddAxis(builder, self.axis)
+        DFTAttrsAddInverse(builder, self.inverse)
+        DFTAttrsAddOnesided(builder, self.onesided)
+        dftattrs = DFTAttrsEnd(builder)
+        return dftattrs
 
-def ConvAttrsStartPadsVector(builder, numElems):
-    return builder.StartVector(4, numElems, 4)
 
-def ConvAttrsAddGroups(builder, groups):
-    builder.PrependUint32Slot(2, groups, 0)
+class DropoutAttrs(object):
+    __slots__ = ['_tab']
 
-def ConvAttrsAddStrides(builder, strides):
-    builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(strides), 0)
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = DropoutAttrs()
+        x.Init(buf, n + offset)
+        return x
 
-def ConvAttrsStartStridesVector(builder, numElems):
-    return builder.StartVector(4, numElems, 4)
+    @classmethod
+    def GetRootAsDropoutAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def DropoutAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-def ConvAttrsAddDilations(builder, dilations):
-    builder.PrependUOffsetTRelativeSlot(4, flatbuffers.number_types.UOffsetTFlags.py_type(dilations), 0)
+    # DropoutAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
 
-def ConvAttrsStartDilationsVector(builder, numElems):
-    return builder.StartVector(4, numElems, 4)
+    # DropoutAttrs
+    def Seed(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return None
 
-def ConvAttrsEnd(builder):
+def DropoutAttrsStart(builder):
+    builder.StartObject(1)
+
+def DropoutAttrsAddSeed(builder, seed):
+    builder.PrependInt32Slot(0, seed, None)
+
+def DropoutAttrsEnd(builder):
     return builder.EndObject()
 
 
 try:
-    from typing import List
+    from typing import Optional
 except:
     pass
 
-class ConvAttrsT(object):
+class DropoutAttrsT(object):
 
-    # ConvAttrsT
+    # DropoutAttrsT
     def __init__(
         self,
-        autoPad = 0,
-        pads = None,
-        groups = 0,
-        strides = None,
-        dilations = None,
+        seed = None,
     ):
-        self.autoPad = autoPad  # type: int
-        self.pads = pads  # type: Optional[List[int]]
-        self.groups = groups  # type: int
-        self.strides = strides  # type: Optional[List[int]]
-        self.dilations = dilations  # type: Optional[List[int]]
+        self.seed = seed  # type: Optional[int]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        convAttrs = ConvAttrs()
-        convAttrs.Init(buf, pos)
-        return cls.InitFromObj(convAttrs)
+        dropoutAttrs = DropoutAttrs()
+        dropoutAttrs.Init(buf, pos)
+        return cls.InitFromObj(dropoutAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -1993,253 +2665,2732 @@ class ConvAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, convAttrs):
-        x = ConvAttrsT()
-        x._UnPack(convAttrs)
+    def InitFromObj(cls, dropoutAttrs):
+        x = DropoutAttrsT()
+        x._UnPack(dropoutAttrs)
         return x
 
-    # ConvAttrsT
-    def _UnPack(self, convAttrs):
-        if convAttrs is None:
+    # DropoutAttrsT
+    def _UnPack(self, dropoutAttrs):
+        if dropoutAttrs is None:
             return
-        self.autoPad = convAttrs.AutoPad()
-        if not convAttrs.PadsIsNone():
-            if np is None:
-                self.pads = []
-                for i in range(convAttrs.PadsLength()):
-                    self.pads.append(convAttrs.Pads(i))
-            else:
-                self.pads = convAttrs.PadsAsNumpy()
-        self.groups = convAttrs.Groups()
-        if not convAttrs.StridesIsNone():
-            if np is None:
+        self.seed = dropoutAttrs.Seed()
+
+    # DropoutAttrsT
+    def Pack(self, builder):
+        DropoutAttrsStart(builder)
+        DropoutAttrsAddSeed(builder, self.seed)
+        dropoutAttrs = DropoutAttrsEnd(builder)
+        return dropoutAttrs
+
+
+class EyeLikeAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = EyeLikeAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsEyeLikeAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def EyeLikeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # EyeLikeAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # EyeLikeAttrs
+    def Dtype(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return None
+
+    # EyeLikeAttrs
+    def K(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
+
+def EyeLikeAttrsStart(builder):
+    builder.StartObject(2)
+
+def EyeLikeAttrsAddDtype(builder, dtype):
+    builder.PrependUint8Slot(0, dtype, None)
+
+def EyeLikeAttrsAddK(builder, k):
+    builder.PrependInt32Slot(1, k, 0)
+
+def EyeLikeAttrsEnd(builder):
+    return builder.EndObject()
+
+
+try:
+    from typing import Optional
+except:
+    pass
+
+class EyeLikeAttrsT(object):
+
+    # EyeLikeAttrsT
+    def __init__(
+        self,
+        dtype = None,
+        k = 0,
+    ):
+        self.dtype = dtype  # type: Optional[int]
+        self.k = k  # type: int
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        eyeLikeAttrs = EyeLikeAttrs()
+        eyeLikeAttrs.Init(buf, pos)
+        return cls.InitFromObj(eyeLikeAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, eyeLikeAttrs):
+        x = EyeLikeAttrsT()
+        x._UnPack(eyeLikeAttrs)
+        return x
+
+    # EyeLikeAttrsT
+    def _UnPack(self, eyeLikeAttrs):
+        if eyeLikeAttrs is None:
+            return
+        self.dtype = eyeLikeAttrs.Dtype()
+        self.k = eyeLikeAttrs.K()
+
+    # EyeLikeAttrsT
+    def Pack(self, builder):
+        EyeLikeAttrsStart(builder)
+        EyeLikeAttrsAddDtype(builder, self.dtype)
+        EyeLikeAttrsAddK(builder, self.k)
+        eyeLikeAttrs = EyeLikeAttrsEnd(builder)
+        return eyeLikeAttrs
+
+
+class IsInfAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = IsInfAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsIsInfAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def IsInfAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # IsInfAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+def IsInfAttrsStart(builder):
+    builder.StartObject(0)
+
+def IsInfAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class IsInfAttrsT(object):
+
+    # IsInfAttrsT
+    def __init__(
+        self,
+    ):
+        pass
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        isInfAttrs = IsInfAttrs()
+        isInfAttrs.Init(buf, pos)
+        return cls.InitFromObj(isInfAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, isInfAttrs):
+        x = IsInfAttrsT()
+        x._UnPack(isInfAttrs)
+        return x
+
+    # IsInfAttrsT
+    def _UnPack(self, isInfAttrs):
+        if isInfAttrs is None:
+            return
+
+    # IsInfAttrsT
+    def Pack(self, builder):
+        IsInfAttrsStart(builder)
+        isInfAttrs = IsInfAttrsEnd(builder)
+        return isInfAttrs
+
+
+class IntScalar(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = IntScalar()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsIntScalar(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def IntScalarBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # IntScalar
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # IntScalar
+    def Value(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
+
+def IntScalarStart(builder):
+    builder.StartObject(1)
+
+def IntScalarAddValue(builder, value):
+    builder.PrependInt32Slot(0, value, 0)
+
+def IntScalarEnd(builder):
+    return builder.EndObject()
+
+
+
+class IntScalarT(object):
+
+    # IntScalarT
+    def __init__(
+        self,
+        value = 0,
+    ):
+        self.value = value  # type: int
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        intScalar = IntScalar()
+        intScalar.Init(buf, pos)
+        return cls.InitFromObj(intScalar)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, intScalar):
+        x = IntScalarT()
+        x._UnPack(intScalar)
+        return x
+
+    # IntScalarT
+    def _UnPack(self, intScalar):
+        if intScalar is None:
+            return
+        self.value = intScalar.Value()
+
+    # IntScalarT
+    def Pack(self, builder):
+        IntScalarStart(builder)
+        IntScalarAddValue(builder, self.value)
+        intScalar = IntScalarEnd(builder)
+        return intScalar
+
+
+class FloatScalar(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = FloatScalar()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsFloatScalar(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def FloatScalarBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # FloatScalar
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # FloatScalar
+    def Value(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+def FloatScalarStart(builder):
+    builder.StartObject(1)
+
+def FloatScalarAddValue(builder, value):
+    builder.PrependFloat32Slot(0, value, 0.0)
+
+def FloatScalarEnd(builder):
+    return builder.EndObject()
+
+
+
+class FloatScalarT(object):
+
+    # FloatScalarT
+    def __init__(
+        self,
+        value = 0.0,
+    ):
+        self.value = value  # type: float
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        floatScalar = FloatScalar()
+        floatScalar.Init(buf, pos)
+        return cls.InitFromObj(floatScalar)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, floatScalar):
+        x = FloatScalarT()
+        x._UnPack(floatScalar)
+        return x
+
+    # FloatScalarT
+    def _UnPack(self, floatScalar):
+        if floatScalar is None:
+            return
+        self.value = floatScalar.Value()
+
+    # FloatScalarT
+    def Pack(self, builder):
+        FloatScalarStart(builder)
+        FloatScalarAddValue(builder, self.value)
+        floatScalar = FloatScalarEnd(builder)
+        return floatScalar
+
+
+class ConstantOfShapeAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = ConstantOfShapeAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsConstantOfShapeAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def ConstantOfShapeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # ConstantOfShapeAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # ConstantOfShapeAttrs
+    def ValueType(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # ConstantOfShapeAttrs
+    def Value(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            from flatbuffers.table import Table
+            obj = Table(bytearray(), 0)
+            self._tab.Union(obj, o)
+            return obj
+        return None
+
+def ConstantOfShapeAttrsStart(builder):
+    builder.StartObject(2)
+
+def ConstantOfShapeAttrsAddValueType(builder, valueType):
+    builder.PrependUint8Slot(0, valueType, 0)
+
+def ConstantOfShapeAttrsAddValue(builder, value):
+    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(value), 0)
+
+def ConstantOfShapeAttrsEnd(builder):
+    return builder.EndObject()
+
+
+try:
+    from typing import Union
+except:
+    pass
+
+class ConstantOfShapeAttrsT(object):
+
+    # ConstantOfShapeAttrsT
+    def __init__(
+        self,
+        valueType = 0,
+        value = None,
+    ):
+        self.valueType = valueType  # type: int
+        self.value = value  # type: Union[None, 'IntScalarT', 'FloatScalarT']
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        constantOfShapeAttrs = ConstantOfShapeAttrs()
+        constantOfShapeAttrs.Init(buf, pos)
+        return cls.InitFromObj(constantOfShapeAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, constantOfShapeAttrs):
+        x = ConstantOfShapeAttrsT()
+        x._UnPack(constantOfShapeAttrs)
+        return x
+
+    # ConstantOfShapeAttrsT
+    def _UnPack(self, constantOfShapeAttrs):
+        if constantOfShapeAttrs is None:
+            return
+        self.valueType = constantOfShapeAttrs.ValueType()
+        self.value = ScalarCreator(self.valueType, constantOfShapeAttrs.Value())
+
+    # ConstantOfShapeAttrsT
+    def Pack(self, builder):
+        if self.value is not None:
+            value = self.value.Pack(builder)
+        ConstantOfShapeAttrsStart(builder)
+        ConstantOfShapeAttrsAddValueType(builder, self.valueType)
+        if self.value is not None:
+            ConstantOfShapeAttrsAddValue(builder, value)
+        constantOfShapeAttrs = ConstantOfShapeAttrsEnd(builder)
+        return constantOfShapeAttrs
+
+
+class ConvAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = ConvAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsConvAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def ConvAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # ConvAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # ConvAttrs
+    def AutoPad(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # ConvAttrs
+    def Pads(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # ConvAttrs
+    def PadsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # ConvAttrs
+    def PadsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # ConvAttrs
+    def PadsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        return o == 0
+
+    # ConvAttrs
+    def Groups(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
+
+    # ConvAttrs
+    def Strides(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # ConvAttrs
+    def StridesAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # ConvAttrs
+    def StridesLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # ConvAttrs
+    def StridesIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        return o == 0
+
+    # ConvAttrs
+    def Dilations(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # ConvAttrs
+    def DilationsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # ConvAttrs
+    def DilationsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # ConvAttrs
+    def DilationsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        return o == 0
+
+def ConvAttrsStart(builder):
+    builder.StartObject(5)
+
+def ConvAttrsAddAutoPad(builder, autoPad):
+    builder.PrependUint8Slot(0, autoPad, 0)
+
+def ConvAttrsAddPads(builder, pads):
+    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(pads), 0)
+
+def ConvAttrsStartPadsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def ConvAttrsAddGroups(builder, groups):
+    builder.PrependUint32Slot(2, groups, 0)
+
+def ConvAttrsAddStrides(builder, strides):
+    builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(strides), 0)
+
+def ConvAttrsStartStridesVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def ConvAttrsAddDilations(builder, dilations):
+    builder.PrependUOffsetTRelativeSlot(4, flatbuffers.number_types.UOffsetTFlags.py_type(dilations), 0)
+
+def ConvAttrsStartDilationsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def ConvAttrsEnd(builder):
+    return builder.EndObject()
+
+
+try:
+    from typing import List
+except:
+    pass
+
+class ConvAttrsT(object):
+
+    # ConvAttrsT
+    def __init__(
+        self,
+        autoPad = 0,
+        pads = None,
+        groups = 0,
+        strides = None,
+        dilations = None,
+    ):
+        self.autoPad = autoPad  # type: int
+        self.pads = pads  # type: Optional[List[int]]
+        self.groups = groups  # type: int
+        self.strides = strides  # type: Optional[List[int]]
+        self.dilations = dilations  # type: Optional[List[int]]
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        convAttrs = ConvAttrs()
+        convAttrs.Init(buf, pos)
+        return cls.InitFromObj(convAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, convAttrs):
+        x = ConvAttrsT()
+        x._UnPack(convAttrs)
+        return x
+
+    # ConvAttrsT
+    def _UnPack(self, convAttrs):
+        if convAttrs is None:
+            return
+        self.autoPad = convAttrs.AutoPad()
+        if not convAttrs.PadsIsNone():
+            if np is None:
+                self.pads = []
+                for i in range(convAttrs.PadsLength()):
+                    self.pads.append(convAttrs.Pads(i))
+            else:
+                self.pads = convAttrs.PadsAsNumpy()
+        self.groups = convAttrs.Groups()
+        if not convAttrs.StridesIsNone():
+            if np is None:
+                self.strides = []
+                for i in range(convAttrs.StridesLength()):
+                    self.strides.append(convAttrs.Strides(i))
+            else:
+                self.strides = convAttrs.StridesAsNumpy()
+        if not convAttrs.DilationsIsNone():
+            if np is None:
+                self.dilations = []
+                for i in range(convAttrs.DilationsLength()):
+                    self.dilations.append(convAttrs.Dilations(i))
+            else:
+                self.dilations = convAttrs.DilationsAsNumpy()
+
+    # ConvAttrsT
+    def Pack(self, builder):
+        if self.pads is not None:
+            if np is not None and type(self.pads) is np.ndarray:
+                pads = builder.CreateNumpyVector(self.pads)
+            else:
+                ConvAttrsStartPadsVector(builder, len(self.pads))
+                for i in reversed(range(len(self.pads))):
+                    builder.PrependUint32(self.pads[i])
+                pads = builder.EndVector()
+        if self.strides is not None:
+            if np is not None and type(self.strides) is np.ndarray:
+                strides = builder.CreateNumpyVector(self.strides)
+            else:
+                ConvAttrsStartStridesVector(builder, len(self.strides))
+                for i in reversed(range(len(self.strides))):
+                    builder.PrependUint32(self.strides[i])
+                strides = builder.EndVector()
+        if self.dilations is not None:
+            if np is not None and type(self.dilations) is np.ndarray:
+                dilations = builder.CreateNumpyVector(self.dilations)
+            else:
+                ConvAttrsStartDilationsVector(builder, len(self.dilations))
+                for i in reversed(range(len(self.dilations))):
+                    builder.PrependUint32(self.dilations[i])
+                dilations = builder.EndVector()
+        ConvAttrsStart(builder)
+        ConvAttrsAddAutoPad(builder, self.autoPad)
+        if self.pads is not None:
+            ConvAttrsAddPads(builder, pads)
+        ConvAttrsAddGroups(builder, self.groups)
+        if self.strides is not None:
+            ConvAttrsAddStrides(builder, strides)
+        if self.dilations is not None:
+            ConvAttrsAddDilations(builder, dilations)
+        convAttrs = ConvAttrsEnd(builder)
+        return convAttrs
+
+
+class ConvTransposeAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = ConvTransposeAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsConvTransposeAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def ConvTransposeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # ConvTransposeAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # ConvTransposeAttrs
+    def Strides(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # ConvTransposeAttrs
+    def StridesAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # ConvTransposeAttrs
+    def StridesLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # ConvTransposeAttrs
+    def StridesIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        return o == 0
+
+    # ConvTransposeAttrs
+    def AutoPad(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 1
+
+    # ConvTransposeAttrs
+    def Pads(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # ConvTransposeAttrs
+    def PadsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # ConvTransposeAttrs
+    def PadsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # ConvTransposeAttrs
+    def PadsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        return o == 0
+
+    # ConvTransposeAttrs
+    def Groups(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 1
+
+    # ConvTransposeAttrs
+    def OutputPadding(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # ConvTransposeAttrs
+    def OutputPaddingAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # ConvTransposeAttrs
+    def OutputPaddingLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # ConvTransposeAttrs
+    def OutputPaddingIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        return o == 0
+
+def ConvTransposeAttrsStart(builder):
+    builder.StartObject(5)
+
+def ConvTransposeAttrsAddStrides(builder, strides):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(strides), 0)
+
+def ConvTransposeAttrsStartStridesVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def ConvTransposeAttrsAddAutoPad(builder, autoPad):
+    builder.PrependUint8Slot(1, autoPad, 1)
+
+def ConvTransposeAttrsAddPads(builder, pads):
+    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(pads), 0)
+
+def ConvTransposeAttrsStartPadsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def ConvTransposeAttrsAddGroups(builder, groups):
+    builder.PrependUint32Slot(3, groups, 1)
+
+def ConvTransposeAttrsAddOutputPadding(builder, outputPadding):
+    builder.PrependUOffsetTRelativeSlot(4, flatbuffers.number_types.UOffsetTFlags.py_type(outputPadding), 0)
+
+def ConvTransposeAttrsStartOutputPaddingVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def ConvTransposeAttrsEnd(builder):
+    return builder.EndObject()
+
+
+try:
+    from typing import List
+except:
+    pass
+
+class ConvTransposeAttrsT(object):
+
+    # ConvTransposeAttrsT
+    def __init__(
+        self,
+        strides = None,
+        autoPad = 1,
+        pads = None,
+        groups = 1,
+        outputPadding = None,
+    ):
+        self.strides = strides  # type: Optional[List[int]]
+        self.autoPad = autoPad  # type: int
+        self.pads = pads  # type: Optional[List[int]]
+        self.groups = groups  # type: int
+        self.outputPadding = outputPadding  # type: Optional[List[int]]
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        convTransposeAttrs = ConvTransposeAttrs()
+        convTransposeAttrs.Init(buf, pos)
+        return cls.InitFromObj(convTransposeAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, convTransposeAttrs):
+        x = ConvTransposeAttrsT()
+        x._UnPack(convTransposeAttrs)
+        return x
+
+    # ConvTransposeAttrsT
+    def _UnPack(self, convTransposeAttrs):
+        if convTransposeAttrs is None:
+            return
+        if not convTransposeAttrs.StridesIsNone():
+            if np is None:
                 self.strides = []
-                for i in range(convAttrs.StridesLength()):
-                    self.strides.append(convAttrs.Strides(i))
+                for i in range(convTransposeAttrs.StridesLength()):
+                    self.strides.append(convTransposeAttrs.Strides(i))
+            else:
+                self.strides = convTransposeAttrs.StridesAsNumpy()
+        self.autoPad = convTransposeAttrs.AutoPad()
+        if not convTransposeAttrs.PadsIsNone():
+            if np is None:
+                self.pads = []
+                for i in range(convTransposeAttrs.PadsLength()):
+                    self.pads.append(convTransposeAttrs.Pads(i))
+            else:
+                self.pads = convTransposeAttrs.PadsAsNumpy()
+        self.groups = convTransposeAttrs.Groups()
+        if not convTransposeAttrs.OutputPaddingIsNone():
+            if np is None:
+                self.outputPadding = []
+                for i in range(convTransposeAttrs.OutputPaddingLength()):
+                    self.outputPadding.append(convTransposeAttrs.OutputPadding(i))
+            else:
+                self.outputPadding = convTransposeAttrs.OutputPaddingAsNumpy()
+
+    # ConvTransposeAttrsT
+    def Pack(self, builder):
+        if self.strides is not None:
+            if np is not None and type(self.strides) is np.ndarray:
+                strides = builder.CreateNumpyVector(self.strides)
+            else:
+                ConvTransposeAttrsStartStridesVector(builder, len(self.strides))
+                for i in reversed(range(len(self.strides))):
+                    builder.PrependUint32(self.strides[i])
+                strides = builder.EndVector()
+        if self.pads is not None:
+            if np is not None and type(self.pads) is np.ndarray:
+                pads = builder.CreateNumpyVector(self.pads)
             else:
-                self.strides = convAttrs.StridesAsNumpy()
-        if not convAttrs.DilationsIsNone():
-            if np is None:
-                self.dilations = []
-                for i in range(convAttrs.DilationsLength()):
-                    self.dilations.append(convAttrs.Dilations(i))
+                ConvTransposeAttrsStartPadsVector(builder, len(self.pads))
+                for i in reversed(range(len(self.pads))):
+                    builder.PrependUint32(self.pads[i])
+                pads = builder.EndVector()
+        if self.outputPadding is not None:
+            if np is not None and type(self.outputPadding) is np.ndarray:
+                outputPadding = builder.CreateNumpyVector(self.outputPadding)
             else:
-                self.dilations = convAttrs.DilationsAsNumpy()
+                ConvTransposeAttrsStartOutputPaddingVector(builder, len(self.outputPadding))
+                for i in reversed(range(len(self.outputPadding))):
+                    builder.PrependUint32(self.outputPadding[i])
+                outputPadding = builder.EndVector()
+        ConvTransposeAttrsStart(builder)
+        if self.strides is not None:
+            ConvTransposeAttrsAddStrides(builder, strides)
+        ConvTransposeAttrsAddAutoPad(builder, self.autoPad)
+        if self.pads is not None:
+            ConvTransposeAttrsAddPads(builder, pads)
+        ConvTransposeAttrsAddGroups(builder, self.groups)
+        if self.outputPadding is not None:
+            ConvTransposeAttrsAddOutputPadding(builder, outputPadding)
+        convTransposeAttrs = ConvTransposeAttrsEnd(builder)
+        return convTransposeAttrs
+
+
+class DequantizeLinearAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = DequantizeLinearAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsDequantizeLinearAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def DequantizeLinearAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # DequantizeLinearAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # DequantizeLinearAttrs
+    def Axis(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
+
+def DequantizeLinearAttrsStart(builder):
+    builder.StartObject(1)
+
+def DequantizeLinearAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, 0)
+
+def DequantizeLinearAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class DequantizeLinearAttrsT(object):
+
+    # DequantizeLinearAttrsT
+    def __init__(
+        self,
+        axis = 0,
+    ):
+        self.axis = axis  # type: int
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        dequantizeLinearAttrs = DequantizeLinearAttrs()
+        dequantizeLinearAttrs.Init(buf, pos)
+        return cls.InitFromObj(dequantizeLinearAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, dequantizeLinearAttrs):
+        x = DequantizeLinearAttrsT()
+        x._UnPack(dequantizeLinearAttrs)
+        return x
+
+    # DequantizeLinearAttrsT
+    def _UnPack(self, dequantizeLinearAttrs):
+        if dequantizeLinearAttrs is None:
+            return
+        self.axis = dequantizeLinearAttrs.Axis()
+
+    # DequantizeLinearAttrsT
+    def Pack(self, builder):
+        DequantizeLinearAttrsStart(builder)
+        DequantizeLinearAttrsAddAxis(builder, self.axis)
+        dequantizeLinearAttrs = DequantizeLinearAttrsEnd(builder)
+        return dequantizeLinearAttrs
+
+
+class EinsumAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = EinsumAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsEinsumAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def EinsumAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # EinsumAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # EinsumAttrs
+    def Equation(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+def EinsumAttrsStart(builder):
+    builder.StartObject(1)
+
+def EinsumAttrsAddEquation(builder, equation):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(equation), 0)
+
+def EinsumAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class EinsumAttrsT(object):
+
+    # EinsumAttrsT
+    def __init__(
+        self,
+        equation = None,
+    ):
+        self.equation = equation  # type: Optional[str]
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        einsumAttrs = EinsumAttrs()
+        einsumAttrs.Init(buf, pos)
+        return cls.InitFromObj(einsumAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, einsumAttrs):
+        x = EinsumAttrsT()
+        x._UnPack(einsumAttrs)
+        return x
+
+    # EinsumAttrsT
+    def _UnPack(self, einsumAttrs):
+        if einsumAttrs is None:
+            return
+        self.equation = einsumAttrs.Equation()
+
+    # EinsumAttrsT
+    def Pack(self, builder):
+        if self.equation is not None:
+            equation = builder.CreateString(self.equation)
+        EinsumAttrsStart(builder)
+        if self.equation is not None:
+            EinsumAttrsAddEquation(builder, equation)
+        einsumAttrs = EinsumAttrsEnd(builder)
+        return einsumAttrs
+
+
+class EluAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = EluAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsEluAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def EluAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # EluAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # EluAttrs
+    def Alpha(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+def EluAttrsStart(builder):
+    builder.StartObject(1)
+
+def EluAttrsAddAlpha(builder, alpha):
+    builder.PrependFloat32Slot(0, alpha, 0.0)
+
+def EluAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class EluAttrsT(object):
+
+    # EluAttrsT
+    def __init__(
+        self,
+        alpha = 0.0,
+    ):
+        self.alpha = alpha  # type: float
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        eluAttrs = EluAttrs()
+        eluAttrs.Init(buf, pos)
+        return cls.InitFromObj(eluAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, eluAttrs):
+        x = EluAttrsT()
+        x._UnPack(eluAttrs)
+        return x
+
+    # EluAttrsT
+    def _UnPack(self, eluAttrs):
+        if eluAttrs is None:
+            return
+        self.alpha = eluAttrs.Alpha()
+
+    # EluAttrsT
+    def Pack(self, builder):
+        EluAttrsStart(builder)
+        EluAttrsAddAlpha(builder, self.alpha)
+        eluAttrs = EluAttrsEnd(builder)
+        return eluAttrs
+
+
+class FlattenAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = FlattenAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsFlattenAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def FlattenAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # FlattenAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # FlattenAttrs
+    def Axis(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
+
+def FlattenAttrsStart(builder):
+    builder.StartObject(1)
+
+def FlattenAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, 0)
+
+def FlattenAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class FlattenAttrsT(object):
+
+    # FlattenAttrsT
+    def __init__(
+        self,
+        axis = 0,
+    ):
+        self.axis = axis  # type: int
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        flattenAttrs = FlattenAttrs()
+        flattenAttrs.Init(buf, pos)
+        return cls.InitFromObj(flattenAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, flattenAttrs):
+        x = FlattenAttrsT()
+        x._UnPack(flattenAttrs)
+        return x
+
+    # FlattenAttrsT
+    def _UnPack(self, flattenAttrs):
+        if flattenAttrs is None:
+            return
+        self.axis = flattenAttrs.Axis()
+
+    # FlattenAttrsT
+    def Pack(self, builder):
+        FlattenAttrsStart(builder)
+        FlattenAttrsAddAxis(builder, self.axis)
+        flattenAttrs = FlattenAttrsEnd(builder)
+        return flattenAttrs
+
+
+class LayerNormalizationAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = LayerNormalizationAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsLayerNormalizationAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def LayerNormalizationAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # LayerNormalizationAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # LayerNormalizationAttrs
+    def Axis(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
+
+    # LayerNormalizationAttrs
+    def Epsilon(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+def LayerNormalizationAttrsStart(builder):
+    builder.StartObject(2)
+
+def LayerNormalizationAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, 0)
+
+def LayerNormalizationAttrsAddEpsilon(builder, epsilon):
+    builder.PrependFloat32Slot(1, epsilon, 0.0)
+
+def LayerNormalizationAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class LayerNormalizationAttrsT(object):
+
+    # LayerNormalizationAttrsT
+    def __init__(
+        self,
+        axis = 0,
+        epsilon = 0.0,
+    ):
+        self.axis = axis  # type: int
+        self.epsilon = epsilon  # type: float
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        layerNormalizationAttrs = LayerNormalizationAttrs()
+        layerNormalizationAttrs.Init(buf, pos)
+        return cls.InitFromObj(layerNormalizationAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, layerNormalizationAttrs):
+        x = LayerNormalizationAttrsT()
+        x._UnPack(layerNormalizationAttrs)
+        return x
+
+    # LayerNormalizationAttrsT
+    def _UnPack(self, layerNormalizationAttrs):
+        if layerNormalizationAttrs is None:
+            return
+        self.axis = layerNormalizationAttrs.Axis()
+        self.epsilon = layerNormalizationAttrs.Epsilon()
+
+    # LayerNormalizationAttrsT
+    def Pack(self, builder):
+        LayerNormalizationAttrsStart(builder)
+        LayerNormalizationAttrsAddAxis(builder, self.axis)
+        LayerNormalizationAttrsAddEpsilon(builder, self.epsilon)
+        layerNormalizationAttrs = LayerNormalizationAttrsEnd(builder)
+        return layerNormalizationAttrs
+
+
+class LoopAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = LoopAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsLoopAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def LoopAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # LoopAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # LoopAttrs
+    def Body(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            x = self._tab.Indirect(o + self._tab.Pos)
+            obj = Graph()
+            obj.Init(self._tab.Bytes, x)
+            return obj
+        return None
+
+def LoopAttrsStart(builder):
+    builder.StartObject(1)
+
+def LoopAttrsAddBody(builder, body):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(body), 0)
+
+def LoopAttrsEnd(builder):
+    return builder.EndObject()
+
+
+try:
+    from typing import Optional
+except:
+    pass
+
+class LoopAttrsT(object):
+
+    # LoopAttrsT
+    def __init__(
+        self,
+        body = None,
+    ):
+        self.body = body  # type: Optional[GraphT]
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        loopAttrs = LoopAttrs()
+        loopAttrs.Init(buf, pos)
+        return cls.InitFromObj(loopAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, loopAttrs):
+        x = LoopAttrsT()
+        x._UnPack(loopAttrs)
+        return x
+
+    # LoopAttrsT
+    def _UnPack(self, loopAttrs):
+        if loopAttrs is None:
+            return
+        if loopAttrs.Body() is not None:
+            self.body = GraphT.InitFromObj(loopAttrs.Body())
+
+    # LoopAttrsT
+    def Pack(self, builder):
+        if self.body is not None:
+            body = self.body.Pack(builder)
+        LoopAttrsStart(builder)
+        if self.body is not None:
+            LoopAttrsAddBody(builder, body)
+        loopAttrs = LoopAttrsEnd(builder)
+        return loopAttrs
+
+
+class FusedMatMulAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = FusedMatMulAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsFusedMatMulAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def FusedMatMulAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # FusedMatMulAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # FusedMatMulAttrs
+    def Alpha(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return None
+
+def FusedMatMulAttrsStart(builder):
+    builder.StartObject(1)
+
+def FusedMatMulAttrsAddAlpha(builder, alpha):
+    builder.PrependFloat32Slot(0, alpha, None)
+
+def FusedMatMulAttrsEnd(builder):
+    return builder.EndObject()
+
+
+try:
+    from typing import Optional
+except:
+    pass
+
+class FusedMatMulAttrsT(object):
+
+    # FusedMatMulAttrsT
+    def __init__(
+        self,
+        alpha = None,
+    ):
+        self.alpha = alpha  # type: Optional[float]
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        fusedMatMulAttrs = FusedMatMulAttrs()
+        fusedMatMulAttrs.Init(buf, pos)
+        return cls.InitFromObj(fusedMatMulAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, fusedMatMulAttrs):
+        x = FusedMatMulAttrsT()
+        x._UnPack(fusedMatMulAttrs)
+        return x
+
+    # FusedMatMulAttrsT
+    def _UnPack(self, fusedMatMulAttrs):
+        if fusedMatMulAttrs is None:
+            return
+        self.alpha = fusedMatMulAttrs.Alpha()
+
+    # FusedMatMulAttrsT
+    def Pack(self, builder):
+        FusedMatMulAttrsStart(builder)
+        FusedMatMulAttrsAddAlpha(builder, self.alpha)
+        fusedMatMulAttrs = FusedMatMulAttrsEnd(builder)
+        return fusedMatMulAttrs
+
+
+class GatherAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = GatherAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsGatherAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def GatherAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # GatherAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # GatherAttrs
+    def Axis(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
+
+def GatherAttrsStart(builder):
+    builder.StartObject(1)
+
+def GatherAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, 0)
+
+def GatherAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class GatherAttrsT(object):
+
+    # GatherAttrsT
+    def __init__(
+        self,
+        axis = 0,
+    ):
+        self.axis = axis  # type: int
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        gatherAttrs = GatherAttrs()
+        gatherAttrs.Init(buf, pos)
+        return cls.InitFromObj(gatherAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, gatherAttrs):
+        x = GatherAttrsT()
+        x._UnPack(gatherAttrs)
+        return x
+
+    # GatherAttrsT
+    def _UnPack(self, gatherAttrs):
+        if gatherAttrs is None:
+            return
+        self.axis = gatherAttrs.Axis()
+
+    # GatherAttrsT
+    def Pack(self, builder):
+        GatherAttrsStart(builder)
+        GatherAttrsAddAxis(builder, self.axis)
+        gatherAttrs = GatherAttrsEnd(builder)
+        return gatherAttrs
+
+
+class GatherNDAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = GatherNDAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsGatherNDAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def GatherNDAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # GatherNDAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # GatherNDAttrs
+    def BatchDims(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
+
+def GatherNDAttrsStart(builder):
+    builder.StartObject(1)
+
+def GatherNDAttrsAddBatchDims(builder, batchDims):
+    builder.PrependInt32Slot(0, batchDims, 0)
+
+def GatherNDAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class GatherNDAttrsT(object):
+
+    # GatherNDAttrsT
+    def __init__(
+        self,
+        batchDims = 0,
+    ):
+        self.batchDims = batchDims  # type: int
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        gatherNdattrs = GatherNDAttrs()
+        gatherNdattrs.Init(buf, pos)
+        return cls.InitFromObj(gatherNdattrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, gatherNdattrs):
+        x = GatherNDAttrsT()
+        x._UnPack(gatherNdattrs)
+        return x
+
+    # GatherNDAttrsT
+    def _UnPack(self, gatherNdattrs):
+        if gatherNdattrs is None:
+            return
+        self.batchDims = gatherNdattrs.BatchDims()
+
+    # GatherNDAttrsT
+    def Pack(self, builder):
+        GatherNDAttrsStart(builder)
+        GatherNDAttrsAddBatchDims(builder, self.batchDims)
+        gatherNdattrs = GatherNDAttrsEnd(builder)
+        return gatherNdattrs
+
+
+class GeluAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = GeluAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsGeluAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def GeluAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # GeluAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # GeluAttrs
+    def Approximate(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+def GeluAttrsStart(builder):
+    builder.StartObject(1)
+
+def GeluAttrsAddApproximate(builder, approximate):
+    builder.PrependUint8Slot(0, approximate, 0)
+
+def GeluAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class GeluAttrsT(object):
+
+    # GeluAttrsT
+    def __init__(
+        self,
+        approximate = 0,
+    ):
+        self.approximate = approximate  # type: int
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        geluAttrs = GeluAttrs()
+        geluAttrs.Init(buf, pos)
+        return cls.InitFromObj(geluAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, geluAttrs):
+        x = GeluAttrsT()
+        x._UnPack(geluAttrs)
+        return x
+
+    # GeluAttrsT
+    def _UnPack(self, geluAttrs):
+        if geluAttrs is None:
+            return
+        self.approximate = geluAttrs.Approximate()
+
+    # GeluAttrsT
+    def Pack(self, builder):
+        GeluAttrsStart(builder)
+        GeluAttrsAddApproximate(builder, self.approximate)
+        geluAttrs = GeluAttrsEnd(builder)
+        return geluAttrs
+
+
+class GemmAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = GemmAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsGemmAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def GemmAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # GemmAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # GemmAttrs
+    def Alpha(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+    # GemmAttrs
+    def Beta(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+    # GemmAttrs
+    def TransposeA(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+    # GemmAttrs
+    def TransposeB(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+def GemmAttrsStart(builder):
+    builder.StartObject(4)
+
+def GemmAttrsAddAlpha(builder, alpha):
+    builder.PrependFloat32Slot(0, alpha, 0.0)
+
+def GemmAttrsAddBeta(builder, beta):
+    builder.PrependFloat32Slot(1, beta, 0.0)
+
+def GemmAttrsAddTransposeA(builder, transposeA):
+    builder.PrependBoolSlot(2, transposeA, 0)
+
+def GemmAttrsAddTransposeB(builder, transposeB):
+    builder.PrependBoolSlot(3, transposeB, 0)
+
+def GemmAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class GemmAttrsT(object):
+
+    # GemmAttrsT
+    def __init__(
+        self,
+        alpha = 0.0,
+        beta = 0.0,
+        transposeA = False,
+        transposeB = False,
+    ):
+        self.alpha = alpha  # type: float
+        self.beta = beta  # type: float
+        self.transposeA = transposeA  # type: bool
+        self.transposeB = transposeB  # type: bool
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        gemmAttrs = GemmAttrs()
+        gemmAttrs.Init(buf, pos)
+        return cls.InitFromObj(gemmAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, gemmAttrs):
+        x = GemmAttrsT()
+        x._UnPack(gemmAttrs)
+        return x
+
+    # GemmAttrsT
+    def _UnPack(self, gemmAttrs):
+        if gemmAttrs is None:
+            return
+        self.alpha = gemmAttrs.Alpha()
+        self.beta = gemmAttrs.Beta()
+        self.transposeA = gemmAttrs.TransposeA()
+        self.transposeB = gemmAttrs.TransposeB()
+
+    # GemmAttrsT
+    def Pack(self, builder):
+        GemmAttrsStart(builder)
+        GemmAttrsAddAlpha(builder, self.alpha)
+        GemmAttrsAddBeta(builder, self.beta)
+        GemmAttrsAddTransposeA(builder, self.transposeA)
+        GemmAttrsAddTransposeB(builder, self.transposeB)
+        gemmAttrs = GemmAttrsEnd(builder)
+        return gemmAttrs
+
+
+class GridSampleAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = GridSampleAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsGridSampleAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def GridSampleAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # GridSampleAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # GridSampleAttrs
+    def AlignCorners(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+def GridSampleAttrsStart(builder):
+    builder.StartObject(1)
+
+def GridSampleAttrsAddAlignCorners(builder, alignCorners):
+    builder.PrependBoolSlot(0, alignCorners, 0)
+
+def GridSampleAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class GridSampleAttrsT(object):
+
+    # GridSampleAttrsT
+    def __init__(
+        self,
+        alignCorners = False,
+    ):
+        self.alignCorners = alignCorners  # type: bool
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        gridSampleAttrs = GridSampleAttrs()
+        gridSampleAttrs.Init(buf, pos)
+        return cls.InitFromObj(gridSampleAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, gridSampleAttrs):
+        x = GridSampleAttrsT()
+        x._UnPack(gridSampleAttrs)
+        return x
+
+    # GridSampleAttrsT
+    def _UnPack(self, gridSampleAttrs):
+        if gridSampleAttrs is None:
+            return
+        self.alignCorners = gridSampleAttrs.AlignCorners()
 
-    # ConvAttrsT
+    # GridSampleAttrsT
     def Pack(self, builder):
-        if self.pads is not None:
-            if np is not None and type(self.pads) is np.ndarray:
-                pads = builder.CreateNumpyVector(self.pads)
-            else:
-                ConvAttrsStartPadsVector(builder, len(self.pads))
-                for i in reversed(range(len(self.pads))):
-                    builder.PrependUint32(self.pads[i])
-                pads = builder.EndVector()
-        if self.strides is not None:
-            if np is not None and type(self.strides) is np.ndarray:
-                strides = builder.CreateNumpyVector(self.strides)
-            else:
-                ConvAttrsStartStridesVector(builder, len(self.strides))
-                for i in reversed(range(len(self.strides))):
-                    builder.PrependUint32(self.strides[i])
-                strides = builder.EndVector()
-        if self.dilations is not None:
-            if np is not None and type(self.dilations) is np.ndarray:
-                dilations = builder.CreateNumpyVector(self.dilations)
-            else:
-                ConvAttrsStartDilationsVector(builder, len(self.dilations))
-                for i in reversed(range(len(self.dilations))):
-                    builder.PrependUint32(self.dilations[i])
-                dilations = builder.EndVector()
-        ConvAttrsStart(builder)
-        ConvAttrsAddAutoPad(builder, self.autoPad)
-        if self.pads is not None:
-            ConvAttrsAddPads(builder, pads)
-        ConvAttrsAddGroups(builder, self.groups)
-        if self.strides is not None:
-            ConvAttrsAddStrides(builder, strides)
-        if self.dilations is not None:
-            ConvAttrsAddDilations(builder, dilations)
-        convAttrs = ConvAttrsEnd(builder)
-        return convAttrs
+        GridSampleAttrsStart(builder)
+        GridSampleAttrsAddAlignCorners(builder, self.alignCorners)
+        gridSampleAttrs = GridSampleAttrsEnd(builder)
+        return gridSampleAttrs
+
+
+class GroupNormalizationAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = GroupNormalizationAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsGroupNormalizationAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def GroupNormalizationAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # GroupNormalizationAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # GroupNormalizationAttrs
+    def NumGroups(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
+
+    # GroupNormalizationAttrs
+    def Epsilon(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return None
+
+def GroupNormalizationAttrsStart(builder):
+    builder.StartObject(2)
+
+def GroupNormalizationAttrsAddNumGroups(builder, numGroups):
+    builder.PrependUint32Slot(0, numGroups, 0)
+
+def GroupNormalizationAttrsAddEpsilon(builder, epsilon):
+    builder.PrependFloat32Slot(1, epsilon, None)
+
+def GroupNormalizationAttrsEnd(builder):
+    return builder.EndObject()
+
+
+try:
+    from typing import Optional
+except:
+    pass
+
+class GroupNormalizationAttrsT(object):
+
+    # GroupNormalizationAttrsT
+    def __init__(
+        self,
+        numGroups = 0,
+        epsilon = None,
+    ):
+        self.numGroups = numGroups  # type: int
+        self.epsilon = epsilon  # type: Optional[float]
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        groupNormalizationAttrs = GroupNormalizationAttrs()
+        groupNormalizationAttrs.Init(buf, pos)
+        return cls.InitFromObj(groupNormalizationAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, groupNormalizationAttrs):
+        x = GroupNormalizationAttrsT()
+        x._UnPack(groupNormalizationAttrs)
+        return x
+
+    # GroupNormalizationAttrsT
+    def _UnPack(self, groupNormalizationAttrs):
+        if groupNormalizationAttrs is None:
+            return
+        self.numGroups = groupNormalizationAttrs.NumGroups()
+        self.epsilon = groupNormalizationAttrs.Epsilon()
+
+    # GroupNormalizationAttrsT
+    def Pack(self, builder):
+        GroupNormalizationAttrsStart(builder)
+        GroupNormalizationAttrsAddNumGroups(builder, self.numGroups)
+        GroupNormalizationAttrsAddEpsilon(builder, self.epsilon)
+        groupNormalizationAttrs = GroupNormalizationAttrsEnd(builder)
+        return groupNormalizationAttrs
+
+
+class GroupQueryAttentionAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = GroupQueryAttentionAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsGroupQueryAttentionAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def GroupQueryAttentionAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # GroupQueryAttentionAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # GroupQueryAttentionAttrs
+    def NumHeads(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
+
+    # GroupQueryAttentionAttrs
+    def KvNumHeads(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
+
+    # GroupQueryAttentionAttrs
+    def Scale(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return None
+
+    # GroupQueryAttentionAttrs
+    def Softcap(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return None
+
+    # GroupQueryAttentionAttrs
+    def LocalWindowSize(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return None
+
+    # GroupQueryAttentionAttrs
+    def DoRotary(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+    # GroupQueryAttentionAttrs
+    def RotaryInterleaved(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(16))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+def GroupQueryAttentionAttrsStart(builder):
+    builder.StartObject(7)
+
+def GroupQueryAttentionAttrsAddNumHeads(builder, numHeads):
+    builder.PrependUint32Slot(0, numHeads, 0)
+
+def GroupQueryAttentionAttrsAddKvNumHeads(builder, kvNumHeads):
+    builder.PrependUint32Slot(1, kvNumHeads, 0)
+
+def GroupQueryAttentionAttrsAddScale(builder, scale):
+    builder.PrependFloat32Slot(2, scale, None)
+
+def GroupQueryAttentionAttrsAddSoftcap(builder, softcap):
+    builder.PrependFloat32Slot(3, softcap, None)
+
+def GroupQueryAttentionAttrsAddLocalWindowSize(builder, localWindowSize):
+    builder.PrependUint32Slot(4, localWindowSize, None)
+
+def GroupQueryAttentionAttrsAddDoRotary(builder, doRotary):
+    builder.PrependBoolSlot(5, doRotary, 0)
+
+def GroupQueryAttentionAttrsAddRotaryInterleaved(builder, rotaryInterleaved):
+    builder.PrependBoolSlot(6, rotaryInterleaved, 0)
+
+def GroupQueryAttentionAttrsEnd(builder):
+    return builder.EndObject()
+
+
+try:
+    from typing import Optional
+except:
+    pass
+
+class GroupQueryAttentionAttrsT(object):
+
+    # GroupQueryAttentionAttrsT
+    def __init__(
+        self,
+        numHeads = 0,
+        kvNumHeads = 0,
+        scale = None,
+        softcap = None,
+        localWindowSize = None,
+        doRotary = False,
+        rotaryInterleaved = False,
+    ):
+        self.numHeads = numHeads  # type: int
+        self.kvNumHeads = kvNumHeads  # type: int
+        self.scale = scale  # type: Optional[float]
+        self.softcap = softcap  # type: Optional[float]
+        self.localWindowSize = localWindowSize  # type: Optional[int]
+        self.doRotary = doRotary  # type: bool
+        self.rotaryInterleaved = rotaryInterleaved  # type: bool
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        groupQueryAttentionAttrs = GroupQueryAttentionAttrs()
+        groupQueryAttentionAttrs.Init(buf, pos)
+        return cls.InitFromObj(groupQueryAttentionAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, groupQueryAttentionAttrs):
+        x = GroupQueryAttentionAttrsT()
+        x._UnPack(groupQueryAttentionAttrs)
+        return x
+
+    # GroupQueryAttentionAttrsT
+    def _UnPack(self, groupQueryAttentionAttrs):
+        if groupQueryAttentionAttrs is None:
+            return
+        self.numHeads = groupQueryAttentionAttrs.NumHeads()
+        self.kvNumHeads = groupQueryAttentionAttrs.KvNumHeads()
+        self.scale = groupQueryAttentionAttrs.Scale()
+        self.softcap = groupQueryAttentionAttrs.Softcap()
+        self.localWindowSize = groupQueryAttentionAttrs.LocalWindowSize()
+        self.doRotary = groupQueryAttentionAttrs.DoRotary()
+        self.rotaryInterleaved = groupQueryAttentionAttrs.RotaryInterleaved()
+
+    # GroupQueryAttentionAttrsT
+    def Pack(self, builder):
+        GroupQueryAttentionAttrsStart(builder)
+        GroupQueryAttentionAttrsAddNumHeads(builder, self.numHeads)
+        GroupQueryAttentionAttrsAddKvNumHeads(builder, self.kvNumHeads)
+        GroupQueryAttentionAttrsAddScale(builder, self.scale)
+        GroupQueryAttentionAttrsAddSoftcap(builder, self.softcap)
+        GroupQueryAttentionAttrsAddLocalWindowSize(builder, self.localWindowSize)
+        GroupQueryAttentionAttrsAddDoRotary(builder, self.doRotary)
+        GroupQueryAttentionAttrsAddRotaryInterleaved(builder, self.rotaryInterleaved)
+        groupQueryAttentionAttrs = GroupQueryAttentionAttrsEnd(builder)
+        return groupQueryAttentionAttrs
+
+
+class GroupedQueryAttentionMatMulAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = GroupedQueryAttentionMatMulAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsGroupedQueryAttentionMatMulAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def GroupedQueryAttentionMatMulAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # GroupedQueryAttentionMatMulAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # GroupedQueryAttentionMatMulAttrs
+    def Repeats(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
+
+    # GroupedQueryAttentionMatMulAttrs
+    def Alpha(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return None
+
+    # GroupedQueryAttentionMatMulAttrs
+    def TransposeRhs(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+def GroupedQueryAttentionMatMulAttrsStart(builder):
+    builder.StartObject(3)
+
+def GroupedQueryAttentionMatMulAttrsAddRepeats(builder, repeats):
+    builder.PrependUint32Slot(0, repeats, 0)
+
+def GroupedQueryAttentionMatMulAttrsAddAlpha(builder, alpha):
+    builder.PrependFloat32Slot(1, alpha, None)
+
+def GroupedQueryAttentionMatMulAttrsAddTransposeRhs(builder, transposeRhs):
+    builder.PrependBoolSlot(2, transposeRhs, 0)
+
+def GroupedQueryAttentionMatMulAttrsEnd(builder):
+    return builder.EndObject()
+
+
+try:
+    from typing import Optional
+except:
+    pass
+
+class GroupedQueryAttentionMatMulAttrsT(object):
+
+    # GroupedQueryAttentionMatMulAttrsT
+    def __init__(
+        self,
+        repeats = 0,
+        alpha = None,
+        transposeRhs = False,
+    ):
+        self.repeats = repeats  # type: int
+        self.alpha = alpha  # type: Optional[float]
+        self.transposeRhs = transposeRhs  # type: bool
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        groupedQueryAttentionMatMulAttrs = GroupedQueryAttentionMatMulAttrs()
+        groupedQueryAttentionMatMulAttrs.Init(buf, pos)
+        return cls.InitFromObj(groupedQueryAttentionMatMulAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, groupedQueryAttentionMatMulAttrs):
+        x = GroupedQueryAttentionMatMulAttrsT()
+        x._UnPack(groupedQueryAttentionMatMulAttrs)
+        return x
 
+    # GroupedQueryAttentionMatMulAttrsT
+    def _UnPack(self, groupedQueryAttentionMatMulAttrs):
+        if groupedQueryAttentionMatMulAttrs is None:
+            return
+        self.repeats = groupedQueryAttentionMatMulAttrs.Repeats()
+        self.alpha = groupedQueryAttentionMatMulAttrs.Alpha()
+        self.transposeRhs = groupedQueryAttentionMatMulAttrs.TransposeRhs()
 
-class ConvTransposeAttrs(object):
+    # GroupedQueryAttentionMatMulAttrsT
+    def Pack(self, builder):
+        GroupedQueryAttentionMatMulAttrsStart(builder)
+        GroupedQueryAttentionMatMulAttrsAddRepeats(builder, self.repeats)
+        GroupedQueryAttentionMatMulAttrsAddAlpha(builder, self.alpha)
+        GroupedQueryAttentionMatMulAttrsAddTransposeRhs(builder, self.transposeRhs)
+        groupedQueryAttentionMatMulAttrs = GroupedQueryAttentionMatMulAttrsEnd(builder)
+        return groupedQueryAttentionMatMulAttrs
+
+
+class GRUAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = ConvTransposeAttrs()
+        x = GRUAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsConvTransposeAttrs(cls, buf, offset=0):
+    def GetRootAsGRUAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def ConvTransposeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def GRUAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # ConvTransposeAttrs
+    # GRUAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # ConvTransposeAttrs
-    def Strides(self, j):
+    # GRUAttrs
+    def Direction(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
         return 0
 
-    # ConvTransposeAttrs
-    def StridesAsNumpy(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+    # GRUAttrs
+    def HiddenSize(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
         return 0
 
-    # ConvTransposeAttrs
-    def StridesLength(self):
+    # GRUAttrs
+    def LinearBeforeReset(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+def GRUAttrsStart(builder):
+    builder.StartObject(3)
+
+def GRUAttrsAddDirection(builder, direction):
+    builder.PrependUint8Slot(0, direction, 0)
+
+def GRUAttrsAddHiddenSize(builder, hiddenSize):
+    builder.PrependUint32Slot(1, hiddenSize, 0)
+
+def GRUAttrsAddLinearBeforeReset(builder, linearBeforeReset):
+    builder.PrependBoolSlot(2, linearBeforeReset, 0)
+
+def GRUAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class GRUAttrsT(object):
+
+    # GRUAttrsT
+    def __init__(
+        self,
+        direction = 0,
+        hiddenSize = 0,
+        linearBeforeReset = False,
+    ):
+        self.direction = direction  # type: int
+        self.hiddenSize = hiddenSize  # type: int
+        self.linearBeforeReset = linearBeforeReset  # type: bool
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        gruattrs = GRUAttrs()
+        gruattrs.Init(buf, pos)
+        return cls.InitFromObj(gruattrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, gruattrs):
+        x = GRUAttrsT()
+        x._UnPack(gruattrs)
+        return x
+
+    # GRUAttrsT
+    def _UnPack(self, gruattrs):
+        if gruattrs is None:
+            return
+        self.direction = gruattrs.Direction()
+        self.hiddenSize = gruattrs.HiddenSize()
+        self.linearBeforeReset = gruattrs.LinearBeforeReset()
+
+    # GRUAttrsT
+    def Pack(self, builder):
+        GRUAttrsStart(builder)
+        GRUAttrsAddDirection(builder, self.direction)
+        GRUAttrsAddHiddenSize(builder, self.hiddenSize)
+        GRUAttrsAddLinearBeforeReset(builder, self.linearBeforeReset)
+        gruattrs = GRUAttrsEnd(builder)
+        return gruattrs
+
+
+class HardmaxAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = HardmaxAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsHardmaxAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def HardmaxAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # HardmaxAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # HardmaxAttrs
+    def Axis(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.VectorLen(o)
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
         return 0
 
-    # ConvTransposeAttrs
-    def StridesIsNone(self):
+def HardmaxAttrsStart(builder):
+    builder.StartObject(1)
+
+def HardmaxAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, 0)
+
+def HardmaxAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class HardmaxAttrsT(object):
+
+    # HardmaxAttrsT
+    def __init__(
+        self,
+        axis = 0,
+    ):
+        self.axis = axis  # type: int
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        hardmaxAttrs = HardmaxAttrs()
+        hardmaxAttrs.Init(buf, pos)
+        return cls.InitFromObj(hardmaxAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, hardmaxAttrs):
+        x = HardmaxAttrsT()
+        x._UnPack(hardmaxAttrs)
+        return x
+
+    # HardmaxAttrsT
+    def _UnPack(self, hardmaxAttrs):
+        if hardmaxAttrs is None:
+            return
+        self.axis = hardmaxAttrs.Axis()
+
+    # HardmaxAttrsT
+    def Pack(self, builder):
+        HardmaxAttrsStart(builder)
+        HardmaxAttrsAddAxis(builder, self.axis)
+        hardmaxAttrs = HardmaxAttrsEnd(builder)
+        return hardmaxAttrs
+
+
+class HardSigmoidAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = HardSigmoidAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsHardSigmoidAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def HardSigmoidAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # HardSigmoidAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # HardSigmoidAttrs
+    def Alpha(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
-        return o == 0
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
 
-    # ConvTransposeAttrs
-    def AutoPad(self):
+    # HardSigmoidAttrs
+    def Beta(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
-        return 1
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
 
-    # ConvTransposeAttrs
-    def Pads(self, j):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
-        if o != 0:
-            a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
-        return 0
+def HardSigmoidAttrsStart(builder):
+    builder.StartObject(2)
 
-    # ConvTransposeAttrs
-    def PadsAsNumpy(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
-        if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
-        return 0
+def HardSigmoidAttrsAddAlpha(builder, alpha):
+    builder.PrependFloat32Slot(0, alpha, 0.0)
 
-    # ConvTransposeAttrs
-    def PadsLength(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
-        if o != 0:
-            return self._tab.VectorLen(o)
-        return 0
+def HardSigmoidAttrsAddBeta(builder, beta):
+    builder.PrependFloat32Slot(1, beta, 0.0)
 
-    # ConvTransposeAttrs
-    def PadsIsNone(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
-        return o == 0
+def HardSigmoidAttrsEnd(builder):
+    return builder.EndObject()
 
-    # ConvTransposeAttrs
-    def Groups(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
-        return 1
 
-    # ConvTransposeAttrs
-    def OutputPadding(self, j):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
-        if o != 0:
-            a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
-        return 0
 
-    # ConvTransposeAttrs
-    def OutputPaddingAsNumpy(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
-        if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
-        return 0
+class HardSigmoidAttrsT(object):
+
+    # HardSigmoidAttrsT
+    def __init__(
+        self,
+        alpha = 0.0,
+        beta = 0.0,
+    ):
+        self.alpha = alpha  # type: float
+        self.beta = beta  # type: float
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        hardSigmoidAttrs = HardSigmoidAttrs()
+        hardSigmoidAttrs.Init(buf, pos)
+        return cls.InitFromObj(hardSigmoidAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, hardSigmoidAttrs):
+        x = HardSigmoidAttrsT()
+        x._UnPack(hardSigmoidAttrs)
+        return x
 
-    # ConvTransposeAttrs
-    def OutputPaddingLength(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
-        if o != 0:
-            return self._tab.VectorLen(o)
-        return 0
+    # HardSigmoidAttrsT
+    def _UnPack(self, hardSigmoidAttrs):
+        if hardSigmoidAttrs is None:
+            return
+        self.alpha = hardSigmoidAttrs.Alpha()
+        self.beta = hardSigmoidAttrs.Beta()
 
-    # ConvTransposeAttrs
-    def OutputPaddingIsNone(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
-        return o == 0
+    # HardSigmoidAttrsT
+    def Pack(self, builder):
+        HardSigmoidAttrsStart(builder)
+        HardSigmoidAttrsAddAlpha(builder, self.alpha)
+        HardSigmoidAttrsAddBeta(builder, self.beta)
+        hardSigmoidAttrs = HardSigmoidAttrsEnd(builder)
+        return hardSigmoidAttrs
 
-def ConvTransposeAttrsStart(builder):
-    builder.StartObject(5)
 
-def ConvTransposeAttrsAddStrides(builder, strides):
-    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(strides), 0)
+class IfAttrs(object):
+    __slots__ = ['_tab']
 
-def ConvTransposeAttrsStartStridesVector(builder, numElems):
-    return builder.StartVector(4, numElems, 4)
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = IfAttrs()
+        x.Init(buf, n + offset)
+        return x
 
-def ConvTransposeAttrsAddAutoPad(builder, autoPad):
-    builder.PrependUint8Slot(1, autoPad, 1)
+    @classmethod
+    def GetRootAsIfAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def IfAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-def ConvTransposeAttrsAddPads(builder, pads):
-    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(pads), 0)
+    # IfAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
 
-def ConvTransposeAttrsStartPadsVector(builder, numElems):
-    return builder.StartVector(4, numElems, 4)
+    # IfAttrs
+    def ThenBranch(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            x = self._tab.Indirect(o + self._tab.Pos)
+            obj = Graph()
+            obj.Init(self._tab.Bytes, x)
+            return obj
+        return None
 
-def ConvTransposeAttrsAddGroups(builder, groups):
-    builder.PrependUint32Slot(3, groups, 1)
+    # IfAttrs
+    def ElseBranch(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            x = self._tab.Indirect(o + self._tab.Pos)
+            obj = Graph()
+            obj.Init(self._tab.Bytes, x)
+            return obj
+        return None
 
-def ConvTransposeAttrsAddOutputPadding(builder, outputPadding):
-    builder.PrependUOffsetTRelativeSlot(4, flatbuffers.number_types.UOffsetTFlags.py_type(outputPadding), 0)
+def IfAttrsStart(builder):
+    builder.StartObject(2)
 
-def ConvTransposeAttrsStartOutputPaddingVector(builder, numElems):
-    return builder.StartVector(4, numElems, 4)
+def IfAttrsAddThenBranch(builder, thenBranch):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(thenBranch), 0)
 
-def ConvTransposeAttrsEnd(builder):
+def IfAttrsAddElseBranch(builder, elseBranch):
+    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(elseBranch), 0)
+
+def IfAttrsEnd(builder):
     return builder.EndObject()
 
 
 try:
-    from typing import List
+    from typing import Optional
 except:
     pass
 
-class ConvTransposeAttrsT(object):
+class IfAttrsT(object):
 
-    # ConvTransposeAttrsT
+    # IfAttrsT
     def __init__(
         self,
-        strides = None,
-        autoPad = 1,
-        pads = None,
-        groups = 1,
-        outputPadding = None,
+        thenBranch = None,
+        elseBranch = None,
     ):
-        self.strides = strides  # type: Optional[List[int]]
-        self.autoPad = autoPad  # type: int
-        self.pads = pads  # type: Optional[List[int]]
-        self.groups = groups  # type: int
-        self.outputPadding = outputPadding  # type: Optional[List[int]]
+        self.thenBranch = thenBranch  # type: Optional[GraphT]
+        self.elseBranch = elseBranch  # type: Optional[GraphT]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        convTransposeAttrs = ConvTransposeAttrs()
-        convTransposeAttrs.Init(buf, pos)
-        return cls.InitFromObj(convTransposeAttrs)
+        ifAttrs = IfAttrs()
+        ifAttrs.Init(buf, pos)
+        return cls.InitFromObj(ifAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -2247,132 +5398,89 @@ class ConvTransposeAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, convTransposeAttrs):
-        x = ConvTransposeAttrsT()
-        x._UnPack(convTransposeAttrs)
+    def InitFromObj(cls, ifAttrs):
+        x = IfAttrsT()
+        x._UnPack(ifAttrs)
         return x
 
-    # ConvTransposeAttrsT
-    def _UnPack(self, convTransposeAttrs):
-        if convTransposeAttrs is None:
+    # IfAttrsT
+    def _UnPack(self, ifAttrs):
+        if ifAttrs is None:
             return
-        if not convTransposeAttrs.StridesIsNone():
-            if np is None:
-                self.strides = []
-                for i in range(convTransposeAttrs.StridesLength()):
-                    self.strides.append(convTransposeAttrs.Strides(i))
-            else:
-                self.strides = convTransposeAttrs.StridesAsNumpy()
-        self.autoPad = convTransposeAttrs.AutoPad()
-        if not convTransposeAttrs.PadsIsNone():
-            if np is None:
-                self.pads = []
-                for i in range(convTransposeAttrs.PadsLength()):
-                    self.pads.append(convTransposeAttrs.Pads(i))
-            else:
-                self.pads = convTransposeAttrs.PadsAsNumpy()
-        self.groups = convTransposeAttrs.Groups()
-        if not convTransposeAttrs.OutputPaddingIsNone():
-            if np is None:
-                self.outputPadding = []
-                for i in range(convTransposeAttrs.OutputPaddingLength()):
-                    self.outputPadding.append(convTransposeAttrs.OutputPadding(i))
-            else:
-                self.outputPadding = convTransposeAttrs.OutputPaddingAsNumpy()
+        if ifAttrs.ThenBranch() is not None:
+            self.thenBranch = GraphT.InitFromObj(ifAttrs.ThenBranch())
+        if ifAttrs.ElseBranch() is not None:
+            self.elseBranch = GraphT.InitFromObj(ifAttrs.ElseBranch())
 
-    # ConvTransposeAttrsT
+    # IfAttrsT
     def Pack(self, builder):
-        if self.strides is not None:
-            if np is not None and type(self.strides) is np.ndarray:
-                strides = builder.CreateNumpyVector(self.strides)
-            else:
-                ConvTransposeAttrsStartStridesVector(builder, len(self.strides))
-                for i in reversed(range(len(self.strides))):
-                    builder.PrependUint32(self.strides[i])
-                strides = builder.EndVector()
-        if self.pads is not None:
-            if np is not None and type(self.pads) is np.ndarray:
-                pads = builder.CreateNumpyVector(self.pads)
-            else:
-                ConvTransposeAttrsStartPadsVector(builder, len(self.pads))
-                for i in reversed(range(len(self.pads))):
-                    builder.PrependUint32(self.pads[i])
-                pads = builder.EndVector()
-        if self.outputPadding is not None:
-            if np is not None and type(self.outputPadding) is np.ndarray:
-                outputPadding = builder.CreateNumpyVector(self.outputPadding)
-            else:
-                ConvTransposeAttrsStartOutputPaddingVector(builder, len(self.outputPadding))
-                for i in reversed(range(len(self.outputPadding))):
-                    builder.PrependUint32(self.outputPadding[i])
-                outputPadding = builder.EndVector()
-        ConvTransposeAttrsStart(builder)
-        if self.strides is not None:
-            ConvTransposeAttrsAddStrides(builder, strides)
-        ConvTransposeAttrsAddAutoPad(builder, self.autoPad)
-        if self.pads is not None:
-            ConvTransposeAttrsAddPads(builder, pads)
-        ConvTransposeAttrsAddGroups(builder, self.groups)
-        if self.outputPadding is not None:
-            ConvTransposeAttrsAddOutputPadding(builder, outputPadding)
-        convTransposeAttrs = ConvTransposeAttrsEnd(builder)
-        return convTransposeAttrs
+        if self.thenBranch is not None:
+            thenBranch = self.thenBranch.Pack(builder)
+        if self.elseBranch is not None:
+            elseBranch = self.elseBranch.Pack(builder)
+        IfAttrsStart(builder)
+        if self.thenBranch is not None:
+            IfAttrsAddThenBranch(builder, thenBranch)
+        if self.elseBranch is not None:
+            IfAttrsAddElseBranch(builder, elseBranch)
+        ifAttrs = IfAttrsEnd(builder)
+        return ifAttrs
 
 
-class DequantizeLinearAttrs(object):
+class LeakyReluAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = DequantizeLinearAttrs()
+        x = LeakyReluAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsDequantizeLinearAttrs(cls, buf, offset=0):
+    def GetRootAsLeakyReluAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def DequantizeLinearAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def LeakyReluAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # DequantizeLinearAttrs
+    # LeakyReluAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # DequantizeLinearAttrs
-    def Axis(self):
+    # LeakyReluAttrs
+    def Alpha(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
-        return 0
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
 
-def DequantizeLinearAttrsStart(builder):
+def LeakyReluAttrsStart(builder):
     builder.StartObject(1)
 
-def DequantizeLinearAttrsAddAxis(builder, axis):
-    builder.PrependInt32Slot(0, axis, 0)
+def LeakyReluAttrsAddAlpha(builder, alpha):
+    builder.PrependFloat32Slot(0, alpha, 0.0)
 
-def DequantizeLinearAttrsEnd(builder):
+def LeakyReluAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class DequantizeLinearAttrsT(object):
+class LeakyReluAttrsT(object):
 
-    # DequantizeLinearAttrsT
+    # LeakyReluAttrsT
     def __init__(
         self,
-        axis = 0,
+        alpha = 0.0,
     ):
-        self.axis = axis  # type: int
+        self.alpha = alpha  # type: float
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        dequantizeLinearAttrs = DequantizeLinearAttrs()
-        dequantizeLinearAttrs.Init(buf, pos)
-        return cls.InitFromObj(dequantizeLinearAttrs)
+        leakyReluAttrs = LeakyReluAttrs()
+        leakyReluAttrs.Init(buf, pos)
+        return cls.InitFromObj(leakyReluAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -2380,79 +5488,91 @@ class DequantizeLinearAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, dequantizeLinearAttrs):
-        x = DequantizeLinearAttrsT()
-        x._UnPack(dequantizeLinearAttrs)
+    def InitFromObj(cls, leakyReluAttrs):
+        x = LeakyReluAttrsT()
+        x._UnPack(leakyReluAttrs)
         return x
 
-    # DequantizeLinearAttrsT
-    def _UnPack(self, dequantizeLinearAttrs):
-        if dequantizeLinearAttrs is None:
+    # LeakyReluAttrsT
+    def _UnPack(self, leakyReluAttrs):
+        if leakyReluAttrs is None:
             return
-        self.axis = dequantizeLinearAttrs.Axis()
+        self.alpha = leakyReluAttrs.Alpha()
 
-    # DequantizeLinearAttrsT
+    # LeakyReluAttrsT
     def Pack(self, builder):
-        DequantizeLinearAttrsStart(builder)
-        DequantizeLinearAttrsAddAxis(builder, self.axis)
-        dequantizeLinearAttrs = DequantizeLinearAttrsEnd(builder)
-        return dequantizeLinearAttrs
+        LeakyReluAttrsStart(builder)
+        LeakyReluAttrsAddAlpha(builder, self.alpha)
+        leakyReluAttrs = LeakyReluAttrsEnd(builder)
+        return leakyReluAttrs
 
 
-class EinsumAttrs(object):
+class LSTMAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = EinsumAttrs()
+        x = LSTMAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsEinsumAttrs(cls, buf, offset=0):
+    def GetRootAsLSTMAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def EinsumAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def LSTMAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # EinsumAttrs
+    # LSTMAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # EinsumAttrs
-    def Equation(self):
+    # LSTMAttrs
+    def Direction(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.String(o + self._tab.Pos)
-        return None
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
 
-def EinsumAttrsStart(builder):
-    builder.StartObject(1)
+    # LSTMAttrs
+    def HiddenSize(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
 
-def EinsumAttrsAddEquation(builder, equation):
-    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(equation), 0)
+def LSTMAttrsStart(builder):
+    builder.StartObject(2)
 
-def EinsumAttrsEnd(builder):
+def LSTMAttrsAddDirection(builder, direction):
+    builder.PrependUint8Slot(0, direction, 0)
+
+def LSTMAttrsAddHiddenSize(builder, hiddenSize):
+    builder.PrependUint32Slot(1, hiddenSize, 0)
+
+def LSTMAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class EinsumAttrsT(object):
+class LSTMAttrsT(object):
 
-    # EinsumAttrsT
+    # LSTMAttrsT
     def __init__(
         self,
-        equation = None,
+        direction = 0,
+        hiddenSize = 0,
     ):
-        self.equation = equation  # type: Optional[str]
+        self.direction = direction  # type: int
+        self.hiddenSize = hiddenSize  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        einsumAttrs = EinsumAttrs()
-        einsumAttrs.Init(buf, pos)
-        return cls.InitFromObj(einsumAttrs)
+        lstmattrs = LSTMAttrs()
+        lstmattrs.Init(buf, pos)
+        return cls.InitFromObj(lstmattrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -2460,82 +5580,93 @@ class EinsumAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, einsumAttrs):
-        x = EinsumAttrsT()
-        x._UnPack(einsumAttrs)
+    def InitFromObj(cls, lstmattrs):
+        x = LSTMAttrsT()
+        x._UnPack(lstmattrs)
         return x
 
-    # EinsumAttrsT
-    def _UnPack(self, einsumAttrs):
-        if einsumAttrs is None:
+    # LSTMAttrsT
+    def _UnPack(self, lstmattrs):
+        if lstmattrs is None:
             return
-        self.equation = einsumAttrs.Equation()
+        self.direction = lstmattrs.Direction()
+        self.hiddenSize = lstmattrs.HiddenSize()
 
-    # EinsumAttrsT
+    # LSTMAttrsT
     def Pack(self, builder):
-        if self.equation is not None:
-            equation = builder.CreateString(self.equation)
-        EinsumAttrsStart(builder)
-        if self.equation is not None:
-            EinsumAttrsAddEquation(builder, equation)
-        einsumAttrs = EinsumAttrsEnd(builder)
-        return einsumAttrs
+        LSTMAttrsStart(builder)
+        LSTMAttrsAddDirection(builder, self.direction)
+        LSTMAttrsAddHiddenSize(builder, self.hiddenSize)
+        lstmattrs = LSTMAttrsEnd(builder)
+        return lstmattrs
 
 
-class EluAttrs(object):
+class LpNormalizationAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = EluAttrs()
+        x = LpNormalizationAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsEluAttrs(cls, buf, offset=0):
+    def GetRootAsLpNormalizationAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def EluAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def LpNormalizationAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # EluAttrs
+    # LpNormalizationAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # EluAttrs
-    def Alpha(self):
+    # LpNormalizationAttrs
+    def Axis(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
 
-def EluAttrsStart(builder):
-    builder.StartObject(1)
+    # LpNormalizationAttrs
+    def P(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
 
-def EluAttrsAddAlpha(builder, alpha):
-    builder.PrependFloat32Slot(0, alpha, 0.0)
+def LpNormalizationAttrsStart(builder):
+    builder.StartObject(2)
 
-def EluAttrsEnd(builder):
+def LpNormalizationAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, 0)
+
+def LpNormalizationAttrsAddP(builder, p):
+    builder.PrependInt32Slot(1, p, 0)
+
+def LpNormalizationAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class EluAttrsT(object):
+class LpNormalizationAttrsT(object):
 
-    # EluAttrsT
+    # LpNormalizationAttrsT
     def __init__(
         self,
-        alpha = 0.0,
+        axis = 0,
+        p = 0,
     ):
-        self.alpha = alpha  # type: float
+        self.axis = axis  # type: int
+        self.p = p  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        eluAttrs = EluAttrs()
-        eluAttrs.Init(buf, pos)
-        return cls.InitFromObj(eluAttrs)
+        lpNormalizationAttrs = LpNormalizationAttrs()
+        lpNormalizationAttrs.Init(buf, pos)
+        return cls.InitFromObj(lpNormalizationAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -2543,79 +5674,214 @@ class EluAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, eluAttrs):
-        x = EluAttrsT()
-        x._UnPack(eluAttrs)
+    def InitFromObj(cls, lpNormalizationAttrs):
+        x = LpNormalizationAttrsT()
+        x._UnPack(lpNormalizationAttrs)
         return x
 
-    # EluAttrsT
-    def _UnPack(self, eluAttrs):
-        if eluAttrs is None:
+    # LpNormalizationAttrsT
+    def _UnPack(self, lpNormalizationAttrs):
+        if lpNormalizationAttrs is None:
             return
-        self.alpha = eluAttrs.Alpha()
+        self.axis = lpNormalizationAttrs.Axis()
+        self.p = lpNormalizationAttrs.P()
 
-    # EluAttrsT
+    # LpNormalizationAttrsT
     def Pack(self, builder):
-        EluAttrsStart(builder)
-        EluAttrsAddAlpha(builder, self.alpha)
-        eluAttrs = EluAttrsEnd(builder)
-        return eluAttrs
+        LpNormalizationAttrsStart(builder)
+        LpNormalizationAttrsAddAxis(builder, self.axis)
+        LpNormalizationAttrsAddP(builder, self.p)
+        lpNormalizationAttrs = LpNormalizationAttrsEnd(builder)
+        return lpNormalizationAttrs
 
 
-class FlattenAttrs(object):
+class LpPoolAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = FlattenAttrs()
+        x = LpPoolAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsFlattenAttrs(cls, buf, offset=0):
+    def GetRootAsLpPoolAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def FlattenAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def LpPoolAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # FlattenAttrs
+    # LpPoolAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # FlattenAttrs
-    def Axis(self):
+    # LpPoolAttrs
+    def KernelSize(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # LpPoolAttrs
+    def KernelSizeAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # LpPoolAttrs
+    def KernelSizeLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # LpPoolAttrs
+    def KernelSizeIsNone(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        return o == 0
+
+    # LpPoolAttrs
+    def AutoPad(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # LpPoolAttrs
+    def Pads(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # LpPoolAttrs
+    def PadsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # LpPoolAttrs
+    def PadsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # LpPoolAttrs
+    def PadsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        return o == 0
+
+    # LpPoolAttrs
+    def Strides(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # LpPoolAttrs
+    def StridesAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # LpPoolAttrs
+    def StridesLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # LpPoolAttrs
+    def StridesIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        return o == 0
+
+    # LpPoolAttrs
+    def CeilMode(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+    # LpPoolAttrs
+    def P(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
         if o != 0:
             return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
         return 0
 
-def FlattenAttrsStart(builder):
-    builder.StartObject(1)
+def LpPoolAttrsStart(builder):
+    builder.StartObject(6)
+
+def LpPoolAttrsAddKernelSize(builder, kernelSize):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(kernelSize), 0)
+
+def LpPoolAttrsStartKernelSizeVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def LpPoolAttrsAddAutoPad(builder, autoPad):
+    builder.PrependUint8Slot(1, autoPad, 0)
+
+def LpPoolAttrsAddPads(builder, pads):
+    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(pads), 0)
+
+def LpPoolAttrsStartPadsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def LpPoolAttrsAddStrides(builder, strides):
+    builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(strides), 0)
+
+def LpPoolAttrsStartStridesVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def LpPoolAttrsAddCeilMode(builder, ceilMode):
+    builder.PrependBoolSlot(4, ceilMode, 0)
 
-def FlattenAttrsAddAxis(builder, axis):
-    builder.PrependInt32Slot(0, axis, 0)
+def LpPoolAttrsAddP(builder, p):
+    builder.PrependInt32Slot(5, p, 0)
 
-def FlattenAttrsEnd(builder):
+def LpPoolAttrsEnd(builder):
     return builder.EndObject()
 
 
+try:
+    from typing import List
+except:
+    pass
 
-class FlattenAttrsT(object):
+class LpPoolAttrsT(object):
 
-    # FlattenAttrsT
+    # LpPoolAttrsT
     def __init__(
         self,
-        axis = 0,
+        kernelSize = None,
+        autoPad = 0,
+        pads = None,
+        strides = None,
+        ceilMode = False,
+        p = 0,
     ):
-        self.axis = axis  # type: int
+        self.kernelSize = kernelSize  # type: Optional[List[int]]
+        self.autoPad = autoPad  # type: int
+        self.pads = pads  # type: Optional[List[int]]
+        self.strides = strides  # type: Optional[List[int]]
+        self.ceilMode = ceilMode  # type: bool
+        self.p = p  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        flattenAttrs = FlattenAttrs()
-        flattenAttrs.Init(buf, pos)
-        return cls.InitFromObj(flattenAttrs)
+        lpPoolAttrs = LpPoolAttrs()
+        lpPoolAttrs.Init(buf, pos)
+        return cls.InitFromObj(lpPoolAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -2623,91 +5889,158 @@ class FlattenAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, flattenAttrs):
-        x = FlattenAttrsT()
-        x._UnPack(flattenAttrs)
+    def InitFromObj(cls, lpPoolAttrs):
+        x = LpPoolAttrsT()
+        x._UnPack(lpPoolAttrs)
         return x
 
-    # FlattenAttrsT
-    def _UnPack(self, flattenAttrs):
-        if flattenAttrs is None:
+    # LpPoolAttrsT
+    def _UnPack(self, lpPoolAttrs):
+        if lpPoolAttrs is None:
             return
-        self.axis = flattenAttrs.Axis()
+        if not lpPoolAttrs.KernelSizeIsNone():
+            if np is None:
+                self.kernelSize = []
+                for i in range(lpPoolAttrs.KernelSizeLength()):
+                    self.kernelSize.append(lpPoolAttrs.KernelSize(i))
+            else:
+                self.kernelSize = lpPoolAttrs.KernelSizeAsNumpy()
+        self.autoPad = lpPoolAttrs.AutoPad()
+        if not lpPoolAttrs.PadsIsNone():
+            if np is None:
+                self.pads = []
+                for i in range(lpPoolAttrs.PadsLength()):
+                    self.pads.append(lpPoolAttrs.Pads(i))
+            else:
+                self.pads = lpPoolAttrs.PadsAsNumpy()
+        if not lpPoolAttrs.StridesIsNone():
+            if np is None:
+                self.strides = []
+                for i in range(lpPoolAttrs.StridesLength()):
+                    self.strides.append(lpPoolAttrs.Strides(i))
+            else:
+                self.strides = lpPoolAttrs.StridesAsNumpy()
+        self.ceilMode = lpPoolAttrs.CeilMode()
+        self.p = lpPoolAttrs.P()
 
-    # FlattenAttrsT
+    # LpPoolAttrsT
     def Pack(self, builder):
-        FlattenAttrsStart(builder)
-        FlattenAttrsAddAxis(builder, self.axis)
-        flattenAttrs = FlattenAttrsEnd(builder)
-        return flattenAttrs
+        if self.kernelSize is not None:
+            if np is not None and type(self.kernelSize) is np.ndarray:
+                kernelSize = builder.CreateNumpyVector(self.kernelSize)
+            else:
+                LpPoolAttrsStartKernelSizeVector(builder, len(self.kernelSize))
+                for i in reversed(range(len(self.kernelSize))):
+                    builder.PrependUint32(self.kernelSize[i])
+                kernelSize = builder.EndVector()
+        if self.pads is not None:
+            if np is not None and type(self.pads) is np.ndarray:
+                pads = builder.CreateNumpyVector(self.pads)
+            else:
+                LpPoolAttrsStartPadsVector(builder, len(self.pads))
+                for i in reversed(range(len(self.pads))):
+                    builder.PrependUint32(self.pads[i])
+                pads = builder.EndVector()
+        if self.strides is not None:
+            if np is not None and type(self.strides) is np.ndarray:
+                strides = builder.CreateNumpyVector(self.strides)
+            else:
+                LpPoolAttrsStartStridesVector(builder, len(self.strides))
+                for i in reversed(range(len(self.strides))):
+                    builder.PrependUint32(self.strides[i])
+                strides = builder.EndVector()
+        LpPoolAttrsStart(builder)
+        if self.kernelSize is not None:
+            LpPoolAttrsAddKernelSize(builder, kernelSize)
+        LpPoolAttrsAddAutoPad(builder, self.autoPad)
+        if self.pads is not None:
+            LpPoolAttrsAddPads(builder, pads)
+        if self.strides is not None:
+            LpPoolAttrsAddStrides(builder, strides)
+        LpPoolAttrsAddCeilMode(builder, self.ceilMode)
+        LpPoolAttrsAddP(builder, self.p)
+        lpPoolAttrs = LpPoolAttrsEnd(builder)
+        return lpPoolAttrs
 
 
-class LayerNormalizationAttrs(object):
+class MatMulNBitsAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = LayerNormalizationAttrs()
+        x = MatMulNBitsAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsLayerNormalizationAttrs(cls, buf, offset=0):
+    def GetRootAsMatMulNBitsAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def LayerNormalizationAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def MatMulNBitsAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # LayerNormalizationAttrs
+    # MatMulNBitsAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # LayerNormalizationAttrs
-    def Axis(self):
+    # MatMulNBitsAttrs
+    def Bits(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
         return 0
 
-    # LayerNormalizationAttrs
-    def Epsilon(self):
+    # MatMulNBitsAttrs
+    def BlockSize(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
 
-def LayerNormalizationAttrsStart(builder):
-    builder.StartObject(2)
+    # MatMulNBitsAttrs
+    def AccuracyLevel(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
 
-def LayerNormalizationAttrsAddAxis(builder, axis):
-    builder.PrependInt32Slot(0, axis, 0)
+def MatMulNBitsAttrsStart(builder):
+    builder.StartObject(3)
 
-def LayerNormalizationAttrsAddEpsilon(builder, epsilon):
-    builder.PrependFloat32Slot(1, epsilon, 0.0)
+def MatMulNBitsAttrsAddBits(builder, bits):
+    builder.PrependUint8Slot(0, bits, 0)
 
-def LayerNormalizationAttrsEnd(builder):
+def MatMulNBitsAttrsAddBlockSize(builder, blockSize):
+    builder.PrependUint32Slot(1, blockSize, 0)
+
+def MatMulNBitsAttrsAddAccuracyLevel(builder, accuracyLevel):
+    builder.PrependUint8Slot(2, accuracyLevel, 0)
+
+def MatMulNBitsAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class LayerNormalizationAttrsT(object):
+class MatMulNBitsAttrsT(object):
 
-    # LayerNormalizationAttrsT
+    # MatMulNBitsAttrsT
     def __init__(
         self,
-        axis = 0,
-        epsilon = 0.0,
+        bits = 0,
+        blockSize = 0,
+        accuracyLevel = 0,
     ):
-        self.axis = axis  # type: int
-        self.epsilon = epsilon  # type: float
+        self.bits = bits  # type: int
+        self.blockSize = blockSize  # type: int
+        self.accuracyLevel = accuracyLevel  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        layerNormalizationAttrs = LayerNormalizationAttrs()
-        layerNormalizationAttrs.Init(buf, pos)
-        return cls.InitFromObj(layerNormalizationAttrs)
+        matMulNbitsAttrs = MatMulNBitsAttrs()
+        matMulNbitsAttrs.Init(buf, pos)
+        return cls.InitFromObj(matMulNbitsAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -2715,88 +6048,204 @@ class LayerNormalizationAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, layerNormalizationAttrs):
-        x = LayerNormalizationAttrsT()
-        x._UnPack(layerNormalizationAttrs)
+    def InitFromObj(cls, matMulNbitsAttrs):
+        x = MatMulNBitsAttrsT()
+        x._UnPack(matMulNbitsAttrs)
         return x
 
-    # LayerNormalizationAttrsT
-    def _UnPack(self, layerNormalizationAttrs):
-        if layerNormalizationAttrs is None:
+    # MatMulNBitsAttrsT
+    def _UnPack(self, matMulNbitsAttrs):
+        if matMulNbitsAttrs is None:
             return
-        self.axis = layerNormalizationAttrs.Axis()
-        self.epsilon = layerNormalizationAttrs.Epsilon()
+        self.bits = matMulNbitsAttrs.Bits()
+        self.blockSize = matMulNbitsAttrs.BlockSize()
+        self.accuracyLevel = matMulNbitsAttrs.AccuracyLevel()
 
-    # LayerNormalizationAttrsT
+    # MatMulNBitsAttrsT
     def Pack(self, builder):
-        LayerNormalizationAttrsStart(builder)
-        LayerNormalizationAttrsAddAxis(builder, self.axis)
-        LayerNormalizationAttrsAddEpsilon(builder, self.epsilon)
-        layerNormalizationAttrs = LayerNormalizationAttrsEnd(builder)
-        return layerNormalizationAttrs
+        MatMulNBitsAttrsStart(builder)
+        MatMulNBitsAttrsAddBits(builder, self.bits)
+        MatMulNBitsAttrsAddBlockSize(builder, self.blockSize)
+        MatMulNBitsAttrsAddAccuracyLevel(builder, self.accuracyLevel)
+        matMulNbitsAttrs = MatMulNBitsAttrsEnd(builder)
+        return matMulNbitsAttrs
 
 
-class LoopAttrs(object):
+class MaxPoolAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = LoopAttrs()
+        x = MaxPoolAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsLoopAttrs(cls, buf, offset=0):
+    def GetRootAsMaxPoolAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def LoopAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def MaxPoolAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # LoopAttrs
+    # MaxPoolAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # LoopAttrs
-    def Body(self):
+    # MaxPoolAttrs
+    def KernelSize(self, j):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            x = self._tab.Indirect(o + self._tab.Pos)
-            obj = Graph()
-            obj.Init(self._tab.Bytes, x)
-            return obj
-        return None
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
 
-def LoopAttrsStart(builder):
-    builder.StartObject(1)
+    # MaxPoolAttrs
+    def KernelSizeAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # MaxPoolAttrs
+    def KernelSizeLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # MaxPoolAttrs
+    def KernelSizeIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        return o == 0
+
+    # MaxPoolAttrs
+    def AutoPad(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
+
+    # MaxPoolAttrs
+    def Pads(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # MaxPoolAttrs
+    def PadsAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # MaxPoolAttrs
+    def PadsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # MaxPoolAttrs
+    def PadsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        return o == 0
+
+    # MaxPoolAttrs
+    def Strides(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # MaxPoolAttrs
+    def StridesAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # MaxPoolAttrs
+    def StridesLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # MaxPoolAttrs
+    def StridesIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        return o == 0
+
+    # MaxPoolAttrs
+    def CeilMode(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+def MaxPoolAttrsStart(builder):
+    builder.StartObject(5)
+
+def MaxPoolAttrsAddKernelSize(builder, kernelSize):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(kernelSize), 0)
+
+def MaxPoolAttrsStartKernelSizeVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def MaxPoolAttrsAddAutoPad(builder, autoPad):
+    builder.PrependUint8Slot(1, autoPad, 0)
+
+def MaxPoolAttrsAddPads(builder, pads):
+    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(pads), 0)
+
+def MaxPoolAttrsStartPadsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def MaxPoolAttrsAddStrides(builder, strides):
+    builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(strides), 0)
 
-def LoopAttrsAddBody(builder, body):
-    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(body), 0)
+def MaxPoolAttrsStartStridesVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
 
-def LoopAttrsEnd(builder):
+def MaxPoolAttrsAddCeilMode(builder, ceilMode):
+    builder.PrependBoolSlot(4, ceilMode, 0)
+
+def MaxPoolAttrsEnd(builder):
     return builder.EndObject()
 
 
 try:
-    from typing import Optional
+    from typing import List
 except:
     pass
 
-class LoopAttrsT(object):
+class MaxPoolAttrsT(object):
 
-    # LoopAttrsT
+    # MaxPoolAttrsT
     def __init__(
         self,
-        body = None,
+        kernelSize = None,
+        autoPad = 0,
+        pads = None,
+        strides = None,
+        ceilMode = False,
     ):
-        self.body = body  # type: Optional[GraphT]
+        self.kernelSize = kernelSize  # type: Optional[List[int]]
+        self.autoPad = autoPad  # type: int
+        self.pads = pads  # type: Optional[List[int]]
+        self.strides = strides  # type: Optional[List[int]]
+        self.ceilMode = ceilMode  # type: bool
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        loopAttrs = LoopAttrs()
-        loopAttrs.Init(buf, pos)
-        return cls.InitFromObj(loopAttrs)
+        maxPoolAttrs = MaxPoolAttrs()
+        maxPoolAttrs.Init(buf, pos)
+        return cls.InitFromObj(maxPoolAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -2804,83 +6253,159 @@ class LoopAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, loopAttrs):
-        x = LoopAttrsT()
-        x._UnPack(loopAttrs)
+    def InitFromObj(cls, maxPoolAttrs):
+        x = MaxPoolAttrsT()
+        x._UnPack(maxPoolAttrs)
         return x
 
-    # LoopAttrsT
-    def _UnPack(self, loopAttrs):
-        if loopAttrs is None:
+    # MaxPoolAttrsT
+    def _UnPack(self, maxPoolAttrs):
+        if maxPoolAttrs is None:
             return
-        if loopAttrs.Body() is not None:
-            self.body = GraphT.InitFromObj(loopAttrs.Body())
+        if not maxPoolAttrs.KernelSizeIsNone():
+            if np is None:
+                self.kernelSize = []
+                for i in range(maxPoolAttrs.KernelSizeLength()):
+                    self.kernelSize.append(maxPoolAttrs.KernelSize(i))
+            else:
+                self.kernelSize = maxPoolAttrs.KernelSizeAsNumpy()
+        self.autoPad = maxPoolAttrs.AutoPad()
+        if not maxPoolAttrs.PadsIsNone():
+            if np is None:
+                self.pads = []
+                for i in range(maxPoolAttrs.PadsLength()):
+                    self.pads.append(maxPoolAttrs.Pads(i))
+            else:
+                self.pads = maxPoolAttrs.PadsAsNumpy()
+        if not maxPoolAttrs.StridesIsNone():
+            if np is None:
+                self.strides = []
+                for i in range(maxPoolAttrs.StridesLength()):
+                    self.strides.append(maxPoolAttrs.Strides(i))
+            else:
+                self.strides = maxPoolAttrs.StridesAsNumpy()
+        self.ceilMode = maxPoolAttrs.CeilMode()
 
-    # LoopAttrsT
+    # MaxPoolAttrsT
     def Pack(self, builder):
-        if self.body is not None:
-            body = self.body.Pack(builder)
-        LoopAttrsStart(builder)
-        if self.body is not None:
-            LoopAttrsAddBody(builder, body)
-        loopAttrs = LoopAttrsEnd(builder)
-        return loopAttrs
+        if self.kernelSize is not None:
+            if np is not None and type(self.kernelSize) is np.ndarray:
+                kernelSize = builder.CreateNumpyVector(self.kernelSize)
+            else:
+                MaxPoolAttrsStartKernelSizeVector(builder, len(self.kernelSize))
+                for i in reversed(range(len(self.kernelSize))):
+                    builder.PrependUint32(self.kernelSize[i])
+                kernelSize = builder.EndVector()
+        if self.pads is not None:
+            if np is not None and type(self.pads) is np.ndarray:
+                pads = builder.CreateNumpyVector(self.pads)
+            else:
+                MaxPoolAttrsStartPadsVector(builder, len(self.pads))
+                for i in reversed(range(len(self.pads))):
+                    builder.PrependUint32(self.pads[i])
+                pads = builder.EndVector()
+        if self.strides is not None:
+            if np is not None and type(self.strides) is np.ndarray:
+                strides = builder.CreateNumpyVector(self.strides)
+            else:
+                MaxPoolAttrsStartStridesVector(builder, len(self.strides))
+                for i in reversed(range(len(self.strides))):
+                    builder.PrependUint32(self.strides[i])
+                strides = builder.EndVector()
+        MaxPoolAttrsStart(builder)
+        if self.kernelSize is not None:
+            MaxPoolAttrsAddKernelSize(builder, kernelSize)
+        MaxPoolAttrsAddAutoPad(builder, self.autoPad)
+        if self.pads is not None:
+            MaxPoolAttrsAddPads(builder, pads)
+        if self.strides is not None:
+            MaxPoolAttrsAddStrides(builder, strides)
+        MaxPoolAttrsAddCeilMode(builder, self.ceilMode)
+        maxPoolAttrs = MaxPoolAttrsEnd(builder)
+        return maxPoolAttrs
 
 
-class GatherAttrs(object):
+class MeanVarianceNormalizationAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = GatherAttrs()
+        x = MeanVarianceNormalizationAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsGatherAttrs(cls, buf, offset=0):
+    def GetRootAsMeanVarianceNormalizationAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def GatherAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def MeanVarianceNormalizationAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # GatherAttrs
+    # MeanVarianceNormalizationAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # GatherAttrs
-    def Axis(self):
+    # MeanVarianceNormalizationAttrs
+    def Axes(self, j):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
         return 0
 
-def GatherAttrsStart(builder):
+    # MeanVarianceNormalizationAttrs
+    def AxesAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Int32Flags, o)
+        return 0
+
+    # MeanVarianceNormalizationAttrs
+    def AxesLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # MeanVarianceNormalizationAttrs
+    def AxesIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        return o == 0
+
+def MeanVarianceNormalizationAttrsStart(builder):
     builder.StartObject(1)
 
-def GatherAttrsAddAxis(builder, axis):
-    builder.PrependInt32Slot(0, axis, 0)
+def MeanVarianceNormalizationAttrsAddAxes(builder, axes):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(axes), 0)
 
-def GatherAttrsEnd(builder):
+def MeanVarianceNormalizationAttrsStartAxesVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def MeanVarianceNormalizationAttrsEnd(builder):
     return builder.EndObject()
 
 
+try:
+    from typing import List
+except:
+    pass
 
-class GatherAttrsT(object):
+class MeanVarianceNormalizationAttrsT(object):
 
-    # GatherAttrsT
+    # MeanVarianceNormalizationAttrsT
     def __init__(
         self,
-        axis = 0,
+        axes = None,
     ):
-        self.axis = axis  # type: int
+        self.axes = axes  # type: Optional[List[int]]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        gatherAttrs = GatherAttrs()
-        gatherAttrs.Init(buf, pos)
-        return cls.InitFromObj(gatherAttrs)
+        meanVarianceNormalizationAttrs = MeanVarianceNormalizationAttrs()
+        meanVarianceNormalizationAttrs.Init(buf, pos)
+        return cls.InitFromObj(meanVarianceNormalizationAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -2888,79 +6413,94 @@ class GatherAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, gatherAttrs):
-        x = GatherAttrsT()
-        x._UnPack(gatherAttrs)
+    def InitFromObj(cls, meanVarianceNormalizationAttrs):
+        x = MeanVarianceNormalizationAttrsT()
+        x._UnPack(meanVarianceNormalizationAttrs)
         return x
 
-    # GatherAttrsT
-    def _UnPack(self, gatherAttrs):
-        if gatherAttrs is None:
+    # MeanVarianceNormalizationAttrsT
+    def _UnPack(self, meanVarianceNormalizationAttrs):
+        if meanVarianceNormalizationAttrs is None:
             return
-        self.axis = gatherAttrs.Axis()
+        if not meanVarianceNormalizationAttrs.AxesIsNone():
+            if np is None:
+                self.axes = []
+                for i in range(meanVarianceNormalizationAttrs.AxesLength()):
+                    self.axes.append(meanVarianceNormalizationAttrs.Axes(i))
+            else:
+                self.axes = meanVarianceNormalizationAttrs.AxesAsNumpy()
 
-    # GatherAttrsT
+    # MeanVarianceNormalizationAttrsT
     def Pack(self, builder):
-        GatherAttrsStart(builder)
-        GatherAttrsAddAxis(builder, self.axis)
-        gatherAttrs = GatherAttrsEnd(builder)
-        return gatherAttrs
+        if self.axes is not None:
+            if np is not None and type(self.axes) is np.ndarray:
+                axes = builder.CreateNumpyVector(self.axes)
+            else:
+                MeanVarianceNormalizationAttrsStartAxesVector(builder, len(self.axes))
+                for i in reversed(range(len(self.axes))):
+                    builder.PrependInt32(self.axes[i])
+                axes = builder.EndVector()
+        MeanVarianceNormalizationAttrsStart(builder)
+        if self.axes is not None:
+            MeanVarianceNormalizationAttrsAddAxes(builder, axes)
+        meanVarianceNormalizationAttrs = MeanVarianceNormalizationAttrsEnd(builder)
+        return meanVarianceNormalizationAttrs
 
 
-class GatherNDAttrs(object):
+class ModAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = GatherNDAttrs()
+        x = ModAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsGatherNDAttrs(cls, buf, offset=0):
+    def GetRootAsModAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def GatherNDAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def ModAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # GatherNDAttrs
+    # ModAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # GatherNDAttrs
-    def BatchDims(self):
+    # ModAttrs
+    def Fmod(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
-        return 0
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
 
-def GatherNDAttrsStart(builder):
+def ModAttrsStart(builder):
     builder.StartObject(1)
 
-def GatherNDAttrsAddBatchDims(builder, batchDims):
-    builder.PrependInt32Slot(0, batchDims, 0)
+def ModAttrsAddFmod(builder, fmod):
+    builder.PrependBoolSlot(0, fmod, 0)
 
-def GatherNDAttrsEnd(builder):
+def ModAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class GatherNDAttrsT(object):
+class ModAttrsT(object):
 
-    # GatherNDAttrsT
+    # ModAttrsT
     def __init__(
         self,
-        batchDims = 0,
+        fmod = False,
     ):
-        self.batchDims = batchDims  # type: int
+        self.fmod = fmod  # type: bool
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        gatherNdattrs = GatherNDAttrs()
-        gatherNdattrs.Init(buf, pos)
-        return cls.InitFromObj(gatherNdattrs)
+        modAttrs = ModAttrs()
+        modAttrs.Init(buf, pos)
+        return cls.InitFromObj(modAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -2968,79 +6508,79 @@ class GatherNDAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, gatherNdattrs):
-        x = GatherNDAttrsT()
-        x._UnPack(gatherNdattrs)
+    def InitFromObj(cls, modAttrs):
+        x = ModAttrsT()
+        x._UnPack(modAttrs)
         return x
 
-    # GatherNDAttrsT
-    def _UnPack(self, gatherNdattrs):
-        if gatherNdattrs is None:
+    # ModAttrsT
+    def _UnPack(self, modAttrs):
+        if modAttrs is None:
             return
-        self.batchDims = gatherNdattrs.BatchDims()
+        self.fmod = modAttrs.Fmod()
 
-    # GatherNDAttrsT
+    # ModAttrsT
     def Pack(self, builder):
-        GatherNDAttrsStart(builder)
-        GatherNDAttrsAddBatchDims(builder, self.batchDims)
-        gatherNdattrs = GatherNDAttrsEnd(builder)
-        return gatherNdattrs
+        ModAttrsStart(builder)
+        ModAttrsAddFmod(builder, self.fmod)
+        modAttrs = ModAttrsEnd(builder)
+        return modAttrs
 
 
-class GeluAttrs(object):
+class NonMaxSuppressionAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = GeluAttrs()
+        x = NonMaxSuppressionAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsGeluAttrs(cls, buf, offset=0):
+    def GetRootAsNonMaxSuppressionAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def GeluAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def NonMaxSuppressionAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # GeluAttrs
+    # NonMaxSuppressionAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # GeluAttrs
-    def Approximate(self):
+    # NonMaxSuppressionAttrs
+    def BoxOrder(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
             return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
         return 0
 
-def GeluAttrsStart(builder):
+def NonMaxSuppressionAttrsStart(builder):
     builder.StartObject(1)
 
-def GeluAttrsAddApproximate(builder, approximate):
-    builder.PrependUint8Slot(0, approximate, 0)
+def NonMaxSuppressionAttrsAddBoxOrder(builder, boxOrder):
+    builder.PrependUint8Slot(0, boxOrder, 0)
 
-def GeluAttrsEnd(builder):
+def NonMaxSuppressionAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class GeluAttrsT(object):
+class NonMaxSuppressionAttrsT(object):
 
-    # GeluAttrsT
+    # NonMaxSuppressionAttrsT
     def __init__(
         self,
-        approximate = 0,
+        boxOrder = 0,
     ):
-        self.approximate = approximate  # type: int
+        self.boxOrder = boxOrder  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        geluAttrs = GeluAttrs()
-        geluAttrs.Init(buf, pos)
-        return cls.InitFromObj(geluAttrs)
+        nonMaxSuppressionAttrs = NonMaxSuppressionAttrs()
+        nonMaxSuppressionAttrs.Init(buf, pos)
+        return cls.InitFromObj(nonMaxSuppressionAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -3048,115 +6588,79 @@ class GeluAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, geluAttrs):
-        x = GeluAttrsT()
-        x._UnPack(geluAttrs)
+    def InitFromObj(cls, nonMaxSuppressionAttrs):
+        x = NonMaxSuppressionAttrsT()
+        x._UnPack(nonMaxSuppressionAttrs)
         return x
 
-    # GeluAttrsT
-    def _UnPack(self, geluAttrs):
-        if geluAttrs is None:
+    # NonMaxSuppressionAttrsT
+    def _UnPack(self, nonMaxSuppressionAttrs):
+        if nonMaxSuppressionAttrs is None:
             return
-        self.approximate = geluAttrs.Approximate()
+        self.boxOrder = nonMaxSuppressionAttrs.BoxOrder()
 
-    # GeluAttrsT
+    # NonMaxSuppressionAttrsT
     def Pack(self, builder):
-        GeluAttrsStart(builder)
-        GeluAttrsAddApproximate(builder, self.approximate)
-        geluAttrs = GeluAttrsEnd(builder)
-        return geluAttrs
+        NonMaxSuppressionAttrsStart(builder)
+        NonMaxSuppressionAttrsAddBoxOrder(builder, self.boxOrder)
+        nonMaxSuppressionAttrs = NonMaxSuppressionAttrsEnd(builder)
+        return nonMaxSuppressionAttrs
 
 
-class GemmAttrs(object):
+class OneHotAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = GemmAttrs()
+        x = OneHotAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsGemmAttrs(cls, buf, offset=0):
+    def GetRootAsOneHotAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def GemmAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def OneHotAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # GemmAttrs
+    # OneHotAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # GemmAttrs
-    def Alpha(self):
+    # OneHotAttrs
+    def Axis(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
-
-    # GemmAttrs
-    def Beta(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
-        if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
-
-    # GemmAttrs
-    def TransposeA(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
-        if o != 0:
-            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
-        return False
-
-    # GemmAttrs
-    def TransposeB(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        if o != 0:
-            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
-        return False
-
-def GemmAttrsStart(builder):
-    builder.StartObject(4)
-
-def GemmAttrsAddAlpha(builder, alpha):
-    builder.PrependFloat32Slot(0, alpha, 0.0)
-
-def GemmAttrsAddBeta(builder, beta):
-    builder.PrependFloat32Slot(1, beta, 0.0)
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
 
-def GemmAttrsAddTransposeA(builder, transposeA):
-    builder.PrependBoolSlot(2, transposeA, 0)
+def OneHotAttrsStart(builder):
+    builder.StartObject(1)
 
-def GemmAttrsAddTransposeB(builder, transposeB):
-    builder.PrependBoolSlot(3, transposeB, 0)
+def OneHotAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, 0)
 
-def GemmAttrsEnd(builder):
+def OneHotAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class GemmAttrsT(object):
+class OneHotAttrsT(object):
 
-    # GemmAttrsT
+    # OneHotAttrsT
     def __init__(
         self,
-        alpha = 0.0,
-        beta = 0.0,
-        transposeA = False,
-        transposeB = False,
+        axis = 0,
     ):
-        self.alpha = alpha  # type: float
-        self.beta = beta  # type: float
-        self.transposeA = transposeA  # type: bool
-        self.transposeB = transposeB  # type: bool
+        self.axis = axis  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        gemmAttrs = GemmAttrs()
-        gemmAttrs.Init(buf, pos)
-        return cls.InitFromObj(gemmAttrs)
+        oneHotAttrs = OneHotAttrs()
+        oneHotAttrs.Init(buf, pos)
+        return cls.InitFromObj(oneHotAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -3164,85 +6668,79 @@ class GemmAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, gemmAttrs):
-        x = GemmAttrsT()
-        x._UnPack(gemmAttrs)
+    def InitFromObj(cls, oneHotAttrs):
+        x = OneHotAttrsT()
+        x._UnPack(oneHotAttrs)
         return x
 
-    # GemmAttrsT
-    def _UnPack(self, gemmAttrs):
-        if gemmAttrs is None:
+    # OneHotAttrsT
+    def _UnPack(self, oneHotAttrs):
+        if oneHotAttrs is None:
             return
-        self.alpha = gemmAttrs.Alpha()
-        self.beta = gemmAttrs.Beta()
-        self.transposeA = gemmAttrs.TransposeA()
-        self.transposeB = gemmAttrs.TransposeB()
+        self.axis = oneHotAttrs.Axis()
 
-    # GemmAttrsT
+    # OneHotAttrsT
     def Pack(self, builder):
-        GemmAttrsStart(builder)
-        GemmAttrsAddAlpha(builder, self.alpha)
-        GemmAttrsAddBeta(builder, self.beta)
-        GemmAttrsAddTransposeA(builder, self.transposeA)
-        GemmAttrsAddTransposeB(builder, self.transposeB)
-        gemmAttrs = GemmAttrsEnd(builder)
-        return gemmAttrs
+        OneHotAttrsStart(builder)
+        OneHotAttrsAddAxis(builder, self.axis)
+        oneHotAttrs = OneHotAttrsEnd(builder)
+        return oneHotAttrs
 
 
-class GridSampleAttrs(object):
+class PadAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = GridSampleAttrs()
+        x = PadAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsGridSampleAttrs(cls, buf, offset=0):
+    def GetRootAsPadAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def GridSampleAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def PadAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # GridSampleAttrs
+    # PadAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # GridSampleAttrs
-    def AlignCorners(self):
+    # PadAttrs
+    def Mode(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
-        return False
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
 
-def GridSampleAttrsStart(builder):
+def PadAttrsStart(builder):
     builder.StartObject(1)
 
-def GridSampleAttrsAddAlignCorners(builder, alignCorners):
-    builder.PrependBoolSlot(0, alignCorners, 0)
+def PadAttrsAddMode(builder, mode):
+    builder.PrependUint8Slot(0, mode, 0)
 
-def GridSampleAttrsEnd(builder):
+def PadAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class GridSampleAttrsT(object):
+class PadAttrsT(object):
 
-    # GridSampleAttrsT
+    # PadAttrsT
     def __init__(
         self,
-        alignCorners = False,
+        mode = 0,
     ):
-        self.alignCorners = alignCorners  # type: bool
+        self.mode = mode  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        gridSampleAttrs = GridSampleAttrs()
-        gridSampleAttrs.Init(buf, pos)
-        return cls.InitFromObj(gridSampleAttrs)
+        padAttrs = PadAttrs()
+        padAttrs.Init(buf, pos)
+        return cls.InitFromObj(padAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -3250,103 +6748,95 @@ class GridSampleAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, gridSampleAttrs):
-        x = GridSampleAttrsT()
-        x._UnPack(gridSampleAttrs)
+    def InitFromObj(cls, padAttrs):
+        x = PadAttrsT()
+        x._UnPack(padAttrs)
         return x
 
-    # GridSampleAttrsT
-    def _UnPack(self, gridSampleAttrs):
-        if gridSampleAttrs is None:
+    # PadAttrsT
+    def _UnPack(self, padAttrs):
+        if padAttrs is None:
             return
-        self.alignCorners = gridSampleAttrs.AlignCorners()
+        self.mode = padAttrs.Mode()
 
-    # GridSampleAttrsT
+    # PadAttrsT
     def Pack(self, builder):
-        GridSampleAttrsStart(builder)
-        GridSampleAttrsAddAlignCorners(builder, self.alignCorners)
-        gridSampleAttrs = GridSampleAttrsEnd(builder)
-        return gridSampleAttrs
+        PadAttrsStart(builder)
+        PadAttrsAddMode(builder, self.mode)
+        padAttrs = PadAttrsEnd(builder)
+        return padAttrs
 
 
-class GRUAttrs(object):
+class QuantizeLinearAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = GRUAttrs()
+        x = QuantizeLinearAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsGRUAttrs(cls, buf, offset=0):
+    def GetRootAsQuantizeLinearAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def GRUAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def QuantizeLinearAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # GRUAttrs
+    # QuantizeLinearAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # GRUAttrs
-    def Direction(self):
+    # QuantizeLinearAttrs
+    def Axis(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
         return 0
 
-    # GRUAttrs
-    def HiddenSize(self):
+    # QuantizeLinearAttrs
+    def OutputDtype(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
-        return 0
-
-    # GRUAttrs
-    def LinearBeforeReset(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
-        if o != 0:
-            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
-        return False
-
-def GRUAttrsStart(builder):
-    builder.StartObject(3)
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return None
 
-def GRUAttrsAddDirection(builder, direction):
-    builder.PrependUint8Slot(0, direction, 0)
+def QuantizeLinearAttrsStart(builder):
+    builder.StartObject(2)
 
-def GRUAttrsAddHiddenSize(builder, hiddenSize):
-    builder.PrependUint32Slot(1, hiddenSize, 0)
+def QuantizeLinearAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, 0)
 
-def GRUAttrsAddLinearBeforeReset(builder, linearBeforeReset):
-    builder.PrependBoolSlot(2, linearBeforeReset, 0)
+def QuantizeLinearAttrsAddOutputDtype(builder, outputDtype):
+    builder.PrependUint8Slot(1, outputDtype, None)
 
-def GRUAttrsEnd(builder):
+def QuantizeLinearAttrsEnd(builder):
     return builder.EndObject()
 
 
+try:
+    from typing import Optional
+except:
+    pass
 
-class GRUAttrsT(object):
+class QuantizeLinearAttrsT(object):
 
-    # GRUAttrsT
+    # QuantizeLinearAttrsT
     def __init__(
         self,
-        direction = 0,
-        hiddenSize = 0,
-        linearBeforeReset = False,
+        axis = 0,
+        outputDtype = None,
     ):
-        self.direction = direction  # type: int
-        self.hiddenSize = hiddenSize  # type: int
-        self.linearBeforeReset = linearBeforeReset  # type: bool
+        self.axis = axis  # type: int
+        self.outputDtype = outputDtype  # type: Optional[int]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        gruattrs = GRUAttrs()
-        gruattrs.Init(buf, pos)
-        return cls.InitFromObj(gruattrs)
+        quantizeLinearAttrs = QuantizeLinearAttrs()
+        quantizeLinearAttrs.Init(buf, pos)
+        return cls.InitFromObj(quantizeLinearAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -3354,95 +6844,144 @@ class GRUAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, gruattrs):
-        x = GRUAttrsT()
-        x._UnPack(gruattrs)
+    def InitFromObj(cls, quantizeLinearAttrs):
+        x = QuantizeLinearAttrsT()
+        x._UnPack(quantizeLinearAttrs)
         return x
 
-    # GRUAttrsT
-    def _UnPack(self, gruattrs):
-        if gruattrs is None:
+    # QuantizeLinearAttrsT
+    def _UnPack(self, quantizeLinearAttrs):
+        if quantizeLinearAttrs is None:
             return
-        self.direction = gruattrs.Direction()
-        self.hiddenSize = gruattrs.HiddenSize()
-        self.linearBeforeReset = gruattrs.LinearBeforeReset()
+        self.axis = quantizeLinearAttrs.Axis()
+        self.outputDtype = quantizeLinearAttrs.OutputDtype()
 
-    # GRUAttrsT
+    # QuantizeLinearAttrsT
     def Pack(self, builder):
-        GRUAttrsStart(builder)
-        GRUAttrsAddDirection(builder, self.direction)
-        GRUAttrsAddHiddenSize(builder, self.hiddenSize)
-        GRUAttrsAddLinearBeforeReset(builder, self.linearBeforeReset)
-        gruattrs = GRUAttrsEnd(builder)
-        return gruattrs
+        QuantizeLinearAttrsStart(builder)
+        QuantizeLinearAttrsAddAxis(builder, self.axis)
+        QuantizeLinearAttrsAddOutputDtype(builder, self.outputDtype)
+        quantizeLinearAttrs = QuantizeLinearAttrsEnd(builder)
+        return quantizeLinearAttrs
 
 
-class HardSigmoidAttrs(object):
+class RandomNormalAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = HardSigmoidAttrs()
+        x = RandomNormalAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsHardSigmoidAttrs(cls, buf, offset=0):
+    def GetRootAsRandomNormalAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def HardSigmoidAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def RandomNormalAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # HardSigmoidAttrs
+    # RandomNormalAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # HardSigmoidAttrs
-    def Alpha(self):
+    # RandomNormalAttrs
+    def Mean(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
             return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
         return 0.0
 
-    # HardSigmoidAttrs
-    def Beta(self):
+    # RandomNormalAttrs
+    def Scale(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
             return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
         return 0.0
 
-def HardSigmoidAttrsStart(builder):
-    builder.StartObject(2)
+    # RandomNormalAttrs
+    def Seed(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return None
 
-def HardSigmoidAttrsAddAlpha(builder, alpha):
-    builder.PrependFloat32Slot(0, alpha, 0.0)
+    # RandomNormalAttrs
+    def Shape(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
 
-def HardSigmoidAttrsAddBeta(builder, beta):
-    builder.PrependFloat32Slot(1, beta, 0.0)
+    # RandomNormalAttrs
+    def ShapeAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
 
-def HardSigmoidAttrsEnd(builder):
+    # RandomNormalAttrs
+    def ShapeLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # RandomNormalAttrs
+    def ShapeIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        return o == 0
+
+def RandomNormalAttrsStart(builder):
+    builder.StartObject(4)
+
+def RandomNormalAttrsAddMean(builder, mean):
+    builder.PrependFloat32Slot(0, mean, 0.0)
+
+def RandomNormalAttrsAddScale(builder, scale):
+    builder.PrependFloat32Slot(1, scale, 0.0)
+
+def RandomNormalAttrsAddSeed(builder, seed):
+    builder.PrependFloat32Slot(2, seed, None)
+
+def RandomNormalAttrsAddShape(builder, shape):
+    builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(shape), 0)
+
+def RandomNormalAttrsStartShapeVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def RandomNormalAttrsEnd(builder):
     return builder.EndObject()
 
 
+try:
+    from typing import List, Optional
+except:
+    pass
 
-class HardSigmoidAttrsT(object):
+class RandomNormalAttrsT(object):
 
-    # HardSigmoidAttrsT
+    # RandomNormalAttrsT
     def __init__(
         self,
-        alpha = 0.0,
-        beta = 0.0,
+        mean = 0.0,
+        scale = 0.0,
+        seed = None,
+        shape = None,
     ):
-        self.alpha = alpha  # type: float
-        self.beta = beta  # type: float
+        self.mean = mean  # type: float
+        self.scale = scale  # type: float
+        self.seed = seed  # type: Optional[float]
+        self.shape = shape  # type: Optional[List[int]]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        hardSigmoidAttrs = HardSigmoidAttrs()
-        hardSigmoidAttrs.Init(buf, pos)
-        return cls.InitFromObj(hardSigmoidAttrs)
+        randomNormalAttrs = RandomNormalAttrs()
+        randomNormalAttrs.Init(buf, pos)
+        return cls.InitFromObj(randomNormalAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -3450,79 +6989,102 @@ class HardSigmoidAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, hardSigmoidAttrs):
-        x = HardSigmoidAttrsT()
-        x._UnPack(hardSigmoidAttrs)
+    def InitFromObj(cls, randomNormalAttrs):
+        x = RandomNormalAttrsT()
+        x._UnPack(randomNormalAttrs)
         return x
 
-    # HardSigmoidAttrsT
-    def _UnPack(self, hardSigmoidAttrs):
-        if hardSigmoidAttrs is None:
+    # RandomNormalAttrsT
+    def _UnPack(self, randomNormalAttrs):
+        if randomNormalAttrs is None:
             return
-        self.alpha = hardSigmoidAttrs.Alpha()
-        self.beta = hardSigmoidAttrs.Beta()
+        self.mean = randomNormalAttrs.Mean()
+        self.scale = randomNormalAttrs.Scale()
+        self.seed = randomNormalAttrs.Seed()
+        if not randomNormalAttrs.ShapeIsNone():
+            if np is None:
+                self.shape = []
+                for i in range(randomNormalAttrs.ShapeLength()):
+                    self.shape.append(randomNormalAttrs.Shape(i))
+            else:
+                self.shape = randomNormalAttrs.ShapeAsNumpy()
 
-    # HardSigmoidAttrsT
+    # RandomNormalAttrsT
     def Pack(self, builder):
-        HardSigmoidAttrsStart(builder)
-        HardSigmoidAttrsAddAlpha(builder, self.alpha)
-        HardSigmoidAttrsAddBeta(builder, self.beta)
-        hardSigmoidAttrs = HardSigmoidAttrsEnd(builder)
-        return hardSigmoidAttrs
+        if self.shape is not None:
+            if np is not None and type(self.shape) is np.ndarray:
+                shape = builder.CreateNumpyVector(self.shape)
+            else:
+                RandomNormalAttrsStartShapeVector(builder, len(self.shape))
+                for i in reversed(range(len(self.shape))):
+                    builder.PrependUint32(self.shape[i])
+                shape = builder.EndVector()
+        RandomNormalAttrsStart(builder)
+        RandomNormalAttrsAddMean(builder, self.mean)
+        RandomNormalAttrsAddScale(builder, self.scale)
+        RandomNormalAttrsAddSeed(builder, self.seed)
+        if self.shape is not None:
+            RandomNormalAttrsAddShape(builder, shape)
+        randomNormalAttrs = RandomNormalAttrsEnd(builder)
+        return randomNormalAttrs
 
 
-class IfAttrs(object):
+class RandomNormalLikeAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = IfAttrs()
+        x = RandomNormalLikeAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsIfAttrs(cls, buf, offset=0):
+    def GetRootAsRandomNormalLikeAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def IfAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def RandomNormalLikeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # IfAttrs
+    # RandomNormalLikeAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # IfAttrs
-    def ThenBranch(self):
+    # RandomNormalLikeAttrs
+    def Mean(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            x = self._tab.Indirect(o + self._tab.Pos)
-            obj = Graph()
-            obj.Init(self._tab.Bytes, x)
-            return obj
-        return None
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
 
-    # IfAttrs
-    def ElseBranch(self):
+    # RandomNormalLikeAttrs
+    def Scale(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            x = self._tab.Indirect(o + self._tab.Pos)
-            obj = Graph()
-            obj.Init(self._tab.Bytes, x)
-            return obj
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+    # RandomNormalLikeAttrs
+    def Seed(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
         return None
 
-def IfAttrsStart(builder):
-    builder.StartObject(2)
+def RandomNormalLikeAttrsStart(builder):
+    builder.StartObject(3)
+
+def RandomNormalLikeAttrsAddMean(builder, mean):
+    builder.PrependFloat32Slot(0, mean, 0.0)
 
-def IfAttrsAddThenBranch(builder, thenBranch):
-    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(thenBranch), 0)
+def RandomNormalLikeAttrsAddScale(builder, scale):
+    builder.PrependFloat32Slot(1, scale, 0.0)
 
-def IfAttrsAddElseBranch(builder, elseBranch):
-    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(elseBranch), 0)
+def RandomNormalLikeAttrsAddSeed(builder, seed):
+    builder.PrependFloat32Slot(2, seed, None)
 
-def IfAttrsEnd(builder):
+def RandomNormalLikeAttrsEnd(builder):
     return builder.EndObject()
 
 
@@ -3531,22 +7093,24 @@ try:
 except:
     pass
 
-class IfAttrsT(object):
+class RandomNormalLikeAttrsT(object):
 
-    # IfAttrsT
+    # RandomNormalLikeAttrsT
     def __init__(
         self,
-        thenBranch = None,
-        elseBranch = None,
+        mean = 0.0,
+        scale = 0.0,
+        seed = None,
     ):
-        self.thenBranch = thenBranch  # type: Optional[GraphT]
-        self.elseBranch = elseBranch  # type: Optional[GraphT]
+        self.mean = mean  # type: float
+        self.scale = scale  # type: float
+        self.seed = seed  # type: Optional[float]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        ifAttrs = IfAttrs()
-        ifAttrs.Init(buf, pos)
-        return cls.InitFromObj(ifAttrs)
+        randomNormalLikeAttrs = RandomNormalLikeAttrs()
+        randomNormalLikeAttrs.Init(buf, pos)
+        return cls.InitFromObj(randomNormalLikeAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -3554,89 +7118,146 @@ class IfAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, ifAttrs):
-        x = IfAttrsT()
-        x._UnPack(ifAttrs)
+    def InitFromObj(cls, randomNormalLikeAttrs):
+        x = RandomNormalLikeAttrsT()
+        x._UnPack(randomNormalLikeAttrs)
         return x
 
-    # IfAttrsT
-    def _UnPack(self, ifAttrs):
-        if ifAttrs is None:
+    # RandomNormalLikeAttrsT
+    def _UnPack(self, randomNormalLikeAttrs):
+        if randomNormalLikeAttrs is None:
             return
-        if ifAttrs.ThenBranch() is not None:
-            self.thenBranch = GraphT.InitFromObj(ifAttrs.ThenBranch())
-        if ifAttrs.ElseBranch() is not None:
-            self.elseBranch = GraphT.InitFromObj(ifAttrs.ElseBranch())
+        self.mean = randomNormalLikeAttrs.Mean()
+        self.scale = randomNormalLikeAttrs.Scale()
+        self.seed = randomNormalLikeAttrs.Seed()
 
-    # IfAttrsT
+    # RandomNormalLikeAttrsT
     def Pack(self, builder):
-        if self.thenBranch is not None:
-            thenBranch = self.thenBranch.Pack(builder)
-        if self.elseBranch is not None:
-            elseBranch = self.elseBranch.Pack(builder)
-        IfAttrsStart(builder)
-        if self.thenBranch is not None:
-            IfAttrsAddThenBranch(builder, thenBranch)
-        if self.elseBranch is not None:
-            IfAttrsAddElseBranch(builder, elseBranch)
-        ifAttrs = IfAttrsEnd(builder)
-        return ifAttrs
+        RandomNormalLikeAttrsStart(builder)
+        RandomNormalLikeAttrsAddMean(builder, self.mean)
+        RandomNormalLikeAttrsAddScale(builder, self.scale)
+        RandomNormalLikeAttrsAddSeed(builder, self.seed)
+        randomNormalLikeAttrs = RandomNormalLikeAttrsEnd(builder)
+        return randomNormalLikeAttrs
 
 
-class LeakyReluAttrs(object):
+class RandomUniformAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = LeakyReluAttrs()
+        x = RandomUniformAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsLeakyReluAttrs(cls, buf, offset=0):
+    def GetRootAsRandomUniformAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def LeakyReluAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def RandomUniformAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # LeakyReluAttrs
+    # RandomUniformAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # LeakyReluAttrs
-    def Alpha(self):
+    # RandomUniformAttrs
+    def Shape(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
+
+    # RandomUniformAttrs
+    def ShapeAsNumpy(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
+
+    # RandomUniformAttrs
+    def ShapeLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # RandomUniformAttrs
+    def ShapeIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        return o == 0
+
+    # RandomUniformAttrs
+    def High(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
             return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
         return 0.0
 
-def LeakyReluAttrsStart(builder):
-    builder.StartObject(1)
+    # RandomUniformAttrs
+    def Low(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
 
-def LeakyReluAttrsAddAlpha(builder, alpha):
-    builder.PrependFloat32Slot(0, alpha, 0.0)
+    # RandomUniformAttrs
+    def Seed(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return None
 
-def LeakyReluAttrsEnd(builder):
+def RandomUniformAttrsStart(builder):
+    builder.StartObject(4)
+
+def RandomUniformAttrsAddShape(builder, shape):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(shape), 0)
+
+def RandomUniformAttrsStartShapeVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def RandomUniformAttrsAddHigh(builder, high):
+    builder.PrependFloat32Slot(1, high, 0.0)
+
+def RandomUniformAttrsAddLow(builder, low):
+    builder.PrependFloat32Slot(2, low, 0.0)
+
+def RandomUniformAttrsAddSeed(builder, seed):
+    builder.PrependFloat32Slot(3, seed, None)
+
+def RandomUniformAttrsEnd(builder):
     return builder.EndObject()
 
 
+try:
+    from typing import List, Optional
+except:
+    pass
 
-class LeakyReluAttrsT(object):
+class RandomUniformAttrsT(object):
 
-    # LeakyReluAttrsT
+    # RandomUniformAttrsT
     def __init__(
         self,
-        alpha = 0.0,
+        shape = None,
+        high = 0.0,
+        low = 0.0,
+        seed = None,
     ):
-        self.alpha = alpha  # type: float
+        self.shape = shape  # type: Optional[List[int]]
+        self.high = high  # type: float
+        self.low = low  # type: float
+        self.seed = seed  # type: Optional[float]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        leakyReluAttrs = LeakyReluAttrs()
-        leakyReluAttrs.Init(buf, pos)
-        return cls.InitFromObj(leakyReluAttrs)
+        randomUniformAttrs = RandomUniformAttrs()
+        randomUniformAttrs.Init(buf, pos)
+        return cls.InitFromObj(randomUniformAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -3644,91 +7265,128 @@ class LeakyReluAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, leakyReluAttrs):
-        x = LeakyReluAttrsT()
-        x._UnPack(leakyReluAttrs)
+    def InitFromObj(cls, randomUniformAttrs):
+        x = RandomUniformAttrsT()
+        x._UnPack(randomUniformAttrs)
         return x
 
-    # LeakyReluAttrsT
-    def _UnPack(self, leakyReluAttrs):
-        if leakyReluAttrs is None:
+    # RandomUniformAttrsT
+    def _UnPack(self, randomUniformAttrs):
+        if randomUniformAttrs is None:
             return
-        self.alpha = leakyReluAttrs.Alpha()
+        if not randomUniformAttrs.ShapeIsNone():
+            if np is None:
+                self.shape = []
+                for i in range(randomUniformAttrs.ShapeLength()):
+                    self.shape.append(randomUniformAttrs.Shape(i))
+            else:
+                self.shape = randomUniformAttrs.ShapeAsNumpy()
+        self.high = randomUniformAttrs.High()
+        self.low = randomUniformAttrs.Low()
+        self.seed = randomUniformAttrs.Seed()
 
-    # LeakyReluAttrsT
+    # RandomUniformAttrsT
     def Pack(self, builder):
-        LeakyReluAttrsStart(builder)
-        LeakyReluAttrsAddAlpha(builder, self.alpha)
-        leakyReluAttrs = LeakyReluAttrsEnd(builder)
-        return leakyReluAttrs
+        if self.shape is not None:
+            if np is not None and type(self.shape) is np.ndarray:
+                shape = builder.CreateNumpyVector(self.shape)
+            else:
+                RandomUniformAttrsStartShapeVector(builder, len(self.shape))
+                for i in reversed(range(len(self.shape))):
+                    builder.PrependUint32(self.shape[i])
+                shape = builder.EndVector()
+        RandomUniformAttrsStart(builder)
+        if self.shape is not None:
+            RandomUniformAttrsAddShape(builder, shape)
+        RandomUniformAttrsAddHigh(builder, self.high)
+        RandomUniformAttrsAddLow(builder, self.low)
+        RandomUniformAttrsAddSeed(builder, self.seed)
+        randomUniformAttrs = RandomUniformAttrsEnd(builder)
+        return randomUniformAttrs
 
 
-class LSTMAttrs(object):
+class RandomUniformLikeAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = LSTMAttrs()
+        x = RandomUniformLikeAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsLSTMAttrs(cls, buf, offset=0):
+    def GetRootAsRandomUniformLikeAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def LSTMAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def RandomUniformLikeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # LSTMAttrs
+    # RandomUniformLikeAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # LSTMAttrs
-    def Direction(self):
+    # RandomUniformLikeAttrs
+    def High(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
-        return 0
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
 
-    # LSTMAttrs
-    def HiddenSize(self):
+    # RandomUniformLikeAttrs
+    def Low(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
-        return 0
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+    # RandomUniformLikeAttrs
+    def Seed(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return None
+
+def RandomUniformLikeAttrsStart(builder):
+    builder.StartObject(3)
 
-def LSTMAttrsStart(builder):
-    builder.StartObject(2)
+def RandomUniformLikeAttrsAddHigh(builder, high):
+    builder.PrependFloat32Slot(0, high, 0.0)
 
-def LSTMAttrsAddDirection(builder, direction):
-    builder.PrependUint8Slot(0, direction, 0)
+def RandomUniformLikeAttrsAddLow(builder, low):
+    builder.PrependFloat32Slot(1, low, 0.0)
 
-def LSTMAttrsAddHiddenSize(builder, hiddenSize):
-    builder.PrependUint32Slot(1, hiddenSize, 0)
+def RandomUniformLikeAttrsAddSeed(builder, seed):
+    builder.PrependFloat32Slot(2, seed, None)
 
-def LSTMAttrsEnd(builder):
+def RandomUniformLikeAttrsEnd(builder):
     return builder.EndObject()
 
 
+try:
+    from typing import Optional
+except:
+    pass
 
-class LSTMAttrsT(object):
+class RandomUniformLikeAttrsT(object):
 
-    # LSTMAttrsT
+    # RandomUniformLikeAttrsT
     def __init__(
         self,
-        direction = 0,
-        hiddenSize = 0,
+        high = 0.0,
+        low = 0.0,
+        seed = None,
     ):
-        self.direction = direction  # type: int
-        self.hiddenSize = hiddenSize  # type: int
+        self.high = high  # type: float
+        self.low = low  # type: float
+        self.seed = seed  # type: Optional[float]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        lstmattrs = LSTMAttrs()
-        lstmattrs.Init(buf, pos)
-        return cls.InitFromObj(lstmattrs)
+        randomUniformLikeAttrs = RandomUniformLikeAttrs()
+        randomUniformLikeAttrs.Init(buf, pos)
+        return cls.InitFromObj(randomUniformLikeAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -3736,202 +7394,245 @@ class LSTMAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, lstmattrs):
-        x = LSTMAttrsT()
-        x._UnPack(lstmattrs)
+    def InitFromObj(cls, randomUniformLikeAttrs):
+        x = RandomUniformLikeAttrsT()
+        x._UnPack(randomUniformLikeAttrs)
         return x
 
-    # LSTMAttrsT
-    def _UnPack(self, lstmattrs):
-        if lstmattrs is None:
+    # RandomUniformLikeAttrsT
+    def _UnPack(self, randomUniformLikeAttrs):
+        if randomUniformLikeAttrs is None:
             return
-        self.direction = lstmattrs.Direction()
-        self.hiddenSize = lstmattrs.HiddenSize()
+        self.high = randomUniformLikeAttrs.High()
+        self.low = randomUniformLikeAttrs.Low()
+        self.seed = randomUniformLikeAttrs.Seed()
 
-    # LSTMAttrsT
+    # RandomUniformLikeAttrsT
     def Pack(self, builder):
-        LSTMAttrsStart(builder)
-        LSTMAttrsAddDirection(builder, self.direction)
-        LSTMAttrsAddHiddenSize(builder, self.hiddenSize)
-        lstmattrs = LSTMAttrsEnd(builder)
-        return lstmattrs
+        RandomUniformLikeAttrsStart(builder)
+        RandomUniformLikeAttrsAddHigh(builder, self.high)
+        RandomUniformLikeAttrsAddLow(builder, self.low)
+        RandomUniformLikeAttrsAddSeed(builder, self.seed)
+        randomUniformLikeAttrs = RandomUniformLikeAttrsEnd(builder)
+        return randomUniformLikeAttrs
 
 
-class MaxPoolAttrs(object):
+class ReduceMeanAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = MaxPoolAttrs()
+        x = ReduceMeanAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsMaxPoolAttrs(cls, buf, offset=0):
+    def GetRootAsReduceMeanAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def MaxPoolAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def ReduceMeanAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # MaxPoolAttrs
+    # ReduceMeanAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # MaxPoolAttrs
-    def KernelSize(self, j):
+    # ReduceMeanAttrs
+    def Axes(self, j):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
             a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
         return 0
 
-    # MaxPoolAttrs
-    def KernelSizeAsNumpy(self):
+    # ReduceMeanAttrs
+    def AxesAsNumpy(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Int32Flags, o)
         return 0
 
-    # MaxPoolAttrs
-    def KernelSizeLength(self):
+    # ReduceMeanAttrs
+    def AxesLength(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
             return self._tab.VectorLen(o)
         return 0
 
-    # MaxPoolAttrs
-    def KernelSizeIsNone(self):
+    # ReduceMeanAttrs
+    def AxesIsNone(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         return o == 0
 
-    # MaxPoolAttrs
-    def AutoPad(self):
+    # ReduceMeanAttrs
+    def KeepDims(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
-        return 0
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
 
-    # MaxPoolAttrs
-    def Pads(self, j):
+    # ReduceMeanAttrs
+    def NoopWithEmptyAxes(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
         if o != 0:
-            a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
-        return 0
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
 
-    # MaxPoolAttrs
-    def PadsAsNumpy(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
-        if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
-        return 0
+def ReduceMeanAttrsStart(builder):
+    builder.StartObject(3)
 
-    # MaxPoolAttrs
-    def PadsLength(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
-        if o != 0:
-            return self._tab.VectorLen(o)
-        return 0
+def ReduceMeanAttrsAddAxes(builder, axes):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(axes), 0)
 
-    # MaxPoolAttrs
-    def PadsIsNone(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
-        return o == 0
+def ReduceMeanAttrsStartAxesVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
 
-    # MaxPoolAttrs
-    def Strides(self, j):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        if o != 0:
-            a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
-        return 0
+def ReduceMeanAttrsAddKeepDims(builder, keepDims):
+    builder.PrependBoolSlot(1, keepDims, 0)
 
-    # MaxPoolAttrs
-    def StridesAsNumpy(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
-        return 0
+def ReduceMeanAttrsAddNoopWithEmptyAxes(builder, noopWithEmptyAxes):
+    builder.PrependBoolSlot(2, noopWithEmptyAxes, 0)
 
-    # MaxPoolAttrs
-    def StridesLength(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        if o != 0:
-            return self._tab.VectorLen(o)
-        return 0
+def ReduceMeanAttrsEnd(builder):
+    return builder.EndObject()
 
-    # MaxPoolAttrs
-    def StridesIsNone(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        return o == 0
 
-    # MaxPoolAttrs
-    def CeilMode(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
-        if o != 0:
-            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
-        return False
+try:
+    from typing import List
+except:
+    pass
 
-def MaxPoolAttrsStart(builder):
-    builder.StartObject(5)
+class ReduceMeanAttrsT(object):
 
-def MaxPoolAttrsAddKernelSize(builder, kernelSize):
-    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(kernelSize), 0)
+    # ReduceMeanAttrsT
+    def __init__(
+        self,
+        axes = None,
+        keepDims = False,
+        noopWithEmptyAxes = False,
+    ):
+        self.axes = axes  # type: Optional[List[int]]
+        self.keepDims = keepDims  # type: bool
+        self.noopWithEmptyAxes = noopWithEmptyAxes  # type: bool
 
-def MaxPoolAttrsStartKernelSizeVector(builder, numElems):
-    return builder.StartVector(4, numElems, 4)
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        reduceMeanAttrs = ReduceMeanAttrs()
+        reduceMeanAttrs.Init(buf, pos)
+        return cls.InitFromObj(reduceMeanAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, reduceMeanAttrs):
+        x = ReduceMeanAttrsT()
+        x._UnPack(reduceMeanAttrs)
+        return x
+
+    # ReduceMeanAttrsT
+    def _UnPack(self, reduceMeanAttrs):
+        if reduceMeanAttrs is None:
+            return
+        if not reduceMeanAttrs.AxesIsNone():
+            if np is None:
+                self.axes = []
+                for i in range(reduceMeanAttrs.AxesLength()):
+                    self.axes.append(reduceMeanAttrs.Axes(i))
+            else:
+                self.axes = reduceMeanAttrs.AxesAsNumpy()
+        self.keepDims = reduceMeanAttrs.KeepDims()
+        self.noopWithEmptyAxes = reduceMeanAttrs.NoopWithEmptyAxes()
+
+    # ReduceMeanAttrsT
+    def Pack(self, builder):
+        if self.axes is not None:
+            if np is not None and type(self.axes) is np.ndarray:
+                axes = builder.CreateNumpyVector(self.axes)
+            else:
+                ReduceMeanAttrsStartAxesVector(builder, len(self.axes))
+                for i in reversed(range(len(self.axes))):
+                    builder.PrependInt32(self.axes[i])
+                axes = builder.EndVector()
+        ReduceMeanAttrsStart(builder)
+        if self.axes is not None:
+            ReduceMeanAttrsAddAxes(builder, axes)
+        ReduceMeanAttrsAddKeepDims(builder, self.keepDims)
+        ReduceMeanAttrsAddNoopWithEmptyAxes(builder, self.noopWithEmptyAxes)
+        reduceMeanAttrs = ReduceMeanAttrsEnd(builder)
+        return reduceMeanAttrs
+
+
+class RepeatInterleaveAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = RepeatInterleaveAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsRepeatInterleaveAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def RepeatInterleaveAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-def MaxPoolAttrsAddAutoPad(builder, autoPad):
-    builder.PrependUint8Slot(1, autoPad, 0)
+    # RepeatInterleaveAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
 
-def MaxPoolAttrsAddPads(builder, pads):
-    builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(pads), 0)
+    # RepeatInterleaveAttrs
+    def Axis(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
 
-def MaxPoolAttrsStartPadsVector(builder, numElems):
-    return builder.StartVector(4, numElems, 4)
+    # RepeatInterleaveAttrs
+    def Repeats(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
 
-def MaxPoolAttrsAddStrides(builder, strides):
-    builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(strides), 0)
+def RepeatInterleaveAttrsStart(builder):
+    builder.StartObject(2)
 
-def MaxPoolAttrsStartStridesVector(builder, numElems):
-    return builder.StartVector(4, numElems, 4)
+def RepeatInterleaveAttrsAddAxis(builder, axis):
+    builder.PrependUint32Slot(0, axis, 0)
 
-def MaxPoolAttrsAddCeilMode(builder, ceilMode):
-    builder.PrependBoolSlot(4, ceilMode, 0)
+def RepeatInterleaveAttrsAddRepeats(builder, repeats):
+    builder.PrependUint32Slot(1, repeats, 0)
 
-def MaxPoolAttrsEnd(builder):
+def RepeatInterleaveAttrsEnd(builder):
     return builder.EndObject()
 
 
-try:
-    from typing import List
-except:
-    pass
 
-class MaxPoolAttrsT(object):
+class RepeatInterleaveAttrsT(object):
 
-    # MaxPoolAttrsT
+    # RepeatInterleaveAttrsT
     def __init__(
         self,
-        kernelSize = None,
-        autoPad = 0,
-        pads = None,
-        strides = None,
-        ceilMode = False,
+        axis = 0,
+        repeats = 0,
     ):
-        self.kernelSize = kernelSize  # type: Optional[List[int]]
-        self.autoPad = autoPad  # type: int
-        self.pads = pads  # type: Optional[List[int]]
-        self.strides = strides  # type: Optional[List[int]]
-        self.ceilMode = ceilMode  # type: bool
+        self.axis = axis  # type: int
+        self.repeats = repeats  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        maxPoolAttrs = MaxPoolAttrs()
-        maxPoolAttrs.Init(buf, pos)
-        return cls.InitFromObj(maxPoolAttrs)
+        repeatInterleaveAttrs = RepeatInterleaveAttrs()
+        repeatInterleaveAttrs.Init(buf, pos)
+        return cls.InitFromObj(repeatInterleaveAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -3939,132 +7640,81 @@ class MaxPoolAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, maxPoolAttrs):
-        x = MaxPoolAttrsT()
-        x._UnPack(maxPoolAttrs)
+    def InitFromObj(cls, repeatInterleaveAttrs):
+        x = RepeatInterleaveAttrsT()
+        x._UnPack(repeatInterleaveAttrs)
         return x
 
-    # MaxPoolAttrsT
-    def _UnPack(self, maxPoolAttrs):
-        if maxPoolAttrs is None:
+    # RepeatInterleaveAttrsT
+    def _UnPack(self, repeatInterleaveAttrs):
+        if repeatInterleaveAttrs is None:
             return
-        if not maxPoolAttrs.KernelSizeIsNone():
-            if np is None:
-                self.kernelSize = []
-                for i in range(maxPoolAttrs.KernelSizeLength()):
-                    self.kernelSize.append(maxPoolAttrs.KernelSize(i))
-            else:
-                self.kernelSize = maxPoolAttrs.KernelSizeAsNumpy()
-        self.autoPad = maxPoolAttrs.AutoPad()
-        if not maxPoolAttrs.PadsIsNone():
-            if np is None:
-                self.pads = []
-                for i in range(maxPoolAttrs.PadsLength()):
-                    self.pads.append(maxPoolAttrs.Pads(i))
-            else:
-                self.pads = maxPoolAttrs.PadsAsNumpy()
-        if not maxPoolAttrs.StridesIsNone():
-            if np is None:
-                self.strides = []
-                for i in range(maxPoolAttrs.StridesLength()):
-                    self.strides.append(maxPoolAttrs.Strides(i))
-            else:
-                self.strides = maxPoolAttrs.StridesAsNumpy()
-        self.ceilMode = maxPoolAttrs.CeilMode()
+        self.axis = repeatInterleaveAttrs.Axis()
+        self.repeats = repeatInterleaveAttrs.Repeats()
 
-    # MaxPoolAttrsT
+    # RepeatInterleaveAttrsT
     def Pack(self, builder):
-        if self.kernelSize is not None:
-            if np is not None and type(self.kernelSize) is np.ndarray:
-                kernelSize = builder.CreateNumpyVector(self.kernelSize)
-            else:
-                MaxPoolAttrsStartKernelSizeVector(builder, len(self.kernelSize))
-                for i in reversed(range(len(self.kernelSize))):
-                    builder.PrependUint32(self.kernelSize[i])
-                kernelSize = builder.EndVector()
-        if self.pads is not None:
-            if np is not None and type(self.pads) is np.ndarray:
-                pads = builder.CreateNumpyVector(self.pads)
-            else:
-                MaxPoolAttrsStartPadsVector(builder, len(self.pads))
-                for i in reversed(range(len(self.pads))):
-                    builder.PrependUint32(self.pads[i])
-                pads = builder.EndVector()
-        if self.strides is not None:
-            if np is not None and type(self.strides) is np.ndarray:
-                strides = builder.CreateNumpyVector(self.strides)
-            else:
-                MaxPoolAttrsStartStridesVector(builder, len(self.strides))
-                for i in reversed(range(len(self.strides))):
-                    builder.PrependUint32(self.strides[i])
-                strides = builder.EndVector()
-        MaxPoolAttrsStart(builder)
-        if self.kernelSize is not None:
-            MaxPoolAttrsAddKernelSize(builder, kernelSize)
-        MaxPoolAttrsAddAutoPad(builder, self.autoPad)
-        if self.pads is not None:
-            MaxPoolAttrsAddPads(builder, pads)
-        if self.strides is not None:
-            MaxPoolAttrsAddStrides(builder, strides)
-        MaxPoolAttrsAddCeilMode(builder, self.ceilMode)
-        maxPoolAttrs = MaxPoolAttrsEnd(builder)
-        return maxPoolAttrs
+        RepeatInterleaveAttrsStart(builder)
+        RepeatInterleaveAttrsAddAxis(builder, self.axis)
+        RepeatInterleaveAttrsAddRepeats(builder, self.repeats)
+        repeatInterleaveAttrs = RepeatInterleaveAttrsEnd(builder)
+        return repeatInterleaveAttrs
 
 
-class ModAttrs(object):
+class ReshapeAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = ModAttrs()
+        x = ReshapeAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsModAttrs(cls, buf, offset=0):
+    def GetRootAsReshapeAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def ModAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def ReshapeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # ModAttrs
+    # ReshapeAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # ModAttrs
-    def Fmod(self):
+    # ReshapeAttrs
+    def AllowZero(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
             return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
         return False
 
-def ModAttrsStart(builder):
+def ReshapeAttrsStart(builder):
     builder.StartObject(1)
 
-def ModAttrsAddFmod(builder, fmod):
-    builder.PrependBoolSlot(0, fmod, 0)
+def ReshapeAttrsAddAllowZero(builder, allowZero):
+    builder.PrependBoolSlot(0, allowZero, 0)
 
-def ModAttrsEnd(builder):
+def ReshapeAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class ModAttrsT(object):
+class ReshapeAttrsT(object):
 
-    # ModAttrsT
+    # ReshapeAttrsT
     def __init__(
         self,
-        fmod = False,
+        allowZero = False,
     ):
-        self.fmod = fmod  # type: bool
+        self.allowZero = allowZero  # type: bool
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        modAttrs = ModAttrs()
-        modAttrs.Init(buf, pos)
-        return cls.InitFromObj(modAttrs)
+        reshapeAttrs = ReshapeAttrs()
+        reshapeAttrs.Init(buf, pos)
+        return cls.InitFromObj(reshapeAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -4072,79 +7722,103 @@ class ModAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, modAttrs):
-        x = ModAttrsT()
-        x._UnPack(modAttrs)
+    def InitFromObj(cls, reshapeAttrs):
+        x = ReshapeAttrsT()
+        x._UnPack(reshapeAttrs)
         return x
 
-    # ModAttrsT
-    def _UnPack(self, modAttrs):
-        if modAttrs is None:
+    # ReshapeAttrsT
+    def _UnPack(self, reshapeAttrs):
+        if reshapeAttrs is None:
             return
-        self.fmod = modAttrs.Fmod()
+        self.allowZero = reshapeAttrs.AllowZero()
 
-    # ModAttrsT
+    # ReshapeAttrsT
     def Pack(self, builder):
-        ModAttrsStart(builder)
-        ModAttrsAddFmod(builder, self.fmod)
-        modAttrs = ModAttrsEnd(builder)
-        return modAttrs
+        ReshapeAttrsStart(builder)
+        ReshapeAttrsAddAllowZero(builder, self.allowZero)
+        reshapeAttrs = ReshapeAttrsEnd(builder)
+        return reshapeAttrs
 
 
-class NonMaxSuppressionAttrs(object):
+class ResizeAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = NonMaxSuppressionAttrs()
+        x = ResizeAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsNonMaxSuppressionAttrs(cls, buf, offset=0):
+    def GetRootAsResizeAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def NonMaxSuppressionAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def ResizeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # NonMaxSuppressionAttrs
+    # ResizeAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # NonMaxSuppressionAttrs
-    def BoxOrder(self):
+    # ResizeAttrs
+    def Mode(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
             return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
         return 0
 
-def NonMaxSuppressionAttrsStart(builder):
-    builder.StartObject(1)
+    # ResizeAttrs
+    def CoordMode(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
 
-def NonMaxSuppressionAttrsAddBoxOrder(builder, boxOrder):
-    builder.PrependUint8Slot(0, boxOrder, 0)
+    # ResizeAttrs
+    def NearestMode(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
 
-def NonMaxSuppressionAttrsEnd(builder):
+def ResizeAttrsStart(builder):
+    builder.StartObject(3)
+
+def ResizeAttrsAddMode(builder, mode):
+    builder.PrependUint8Slot(0, mode, 0)
+
+def ResizeAttrsAddCoordMode(builder, coordMode):
+    builder.PrependUint8Slot(1, coordMode, 0)
+
+def ResizeAttrsAddNearestMode(builder, nearestMode):
+    builder.PrependUint8Slot(2, nearestMode, 0)
+
+def ResizeAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class NonMaxSuppressionAttrsT(object):
+class ResizeAttrsT(object):
 
-    # NonMaxSuppressionAttrsT
+    # ResizeAttrsT
     def __init__(
         self,
-        boxOrder = 0,
+        mode = 0,
+        coordMode = 0,
+        nearestMode = 0,
     ):
-        self.boxOrder = boxOrder  # type: int
+        self.mode = mode  # type: int
+        self.coordMode = coordMode  # type: int
+        self.nearestMode = nearestMode  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        nonMaxSuppressionAttrs = NonMaxSuppressionAttrs()
-        nonMaxSuppressionAttrs.Init(buf, pos)
-        return cls.InitFromObj(nonMaxSuppressionAttrs)
+        resizeAttrs = ResizeAttrs()
+        resizeAttrs.Init(buf, pos)
+        return cls.InitFromObj(resizeAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -4152,79 +7826,143 @@ class NonMaxSuppressionAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, nonMaxSuppressionAttrs):
-        x = NonMaxSuppressionAttrsT()
-        x._UnPack(nonMaxSuppressionAttrs)
+    def InitFromObj(cls, resizeAttrs):
+        x = ResizeAttrsT()
+        x._UnPack(resizeAttrs)
         return x
 
-    # NonMaxSuppressionAttrsT
-    def _UnPack(self, nonMaxSuppressionAttrs):
-        if nonMaxSuppressionAttrs is None:
+    # ResizeAttrsT
+    def _UnPack(self, resizeAttrs):
+        if resizeAttrs is None:
             return
-        self.boxOrder = nonMaxSuppressionAttrs.BoxOrder()
+        self.mode = resizeAttrs.Mode()
+        self.coordMode = resizeAttrs.CoordMode()
+        self.nearestMode = resizeAttrs.NearestMode()
 
-    # NonMaxSuppressionAttrsT
+    # ResizeAttrsT
     def Pack(self, builder):
-        NonMaxSuppressionAttrsStart(builder)
-        NonMaxSuppressionAttrsAddBoxOrder(builder, self.boxOrder)
-        nonMaxSuppressionAttrs = NonMaxSuppressionAttrsEnd(builder)
-        return nonMaxSuppressionAttrs
+        ResizeAttrsStart(builder)
+        ResizeAttrsAddMode(builder, self.mode)
+        ResizeAttrsAddCoordMode(builder, self.coordMode)
+        ResizeAttrsAddNearestMode(builder, self.nearestMode)
+        resizeAttrs = ResizeAttrsEnd(builder)
+        return resizeAttrs
 
 
-class OneHotAttrs(object):
+class RoiAlignAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = OneHotAttrs()
+        x = RoiAlignAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsOneHotAttrs(cls, buf, offset=0):
+    def GetRootAsRoiAlignAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def OneHotAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def RoiAlignAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # OneHotAttrs
+    # RoiAlignAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # OneHotAttrs
-    def Axis(self):
+    # RoiAlignAttrs
+    def Mode(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
         return 0
 
-def OneHotAttrsStart(builder):
-    builder.StartObject(1)
+    # RoiAlignAttrs
+    def OutputHeight(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
 
-def OneHotAttrsAddAxis(builder, axis):
-    builder.PrependInt32Slot(0, axis, 0)
+    # RoiAlignAttrs
+    def OutputWidth(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
 
-def OneHotAttrsEnd(builder):
+    # RoiAlignAttrs
+    def SamplingRatio(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
+
+    # RoiAlignAttrs
+    def SpatialScale(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
+
+    # RoiAlignAttrs
+    def HalfPixel(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(14))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+def RoiAlignAttrsStart(builder):
+    builder.StartObject(6)
+
+def RoiAlignAttrsAddMode(builder, mode):
+    builder.PrependUint8Slot(0, mode, 0)
+
+def RoiAlignAttrsAddOutputHeight(builder, outputHeight):
+    builder.PrependUint32Slot(1, outputHeight, 0)
+
+def RoiAlignAttrsAddOutputWidth(builder, outputWidth):
+    builder.PrependUint32Slot(2, outputWidth, 0)
+
+def RoiAlignAttrsAddSamplingRatio(builder, samplingRatio):
+    builder.PrependUint32Slot(3, samplingRatio, 0)
+
+def RoiAlignAttrsAddSpatialScale(builder, spatialScale):
+    builder.PrependFloat32Slot(4, spatialScale, 0.0)
+
+def RoiAlignAttrsAddHalfPixel(builder, halfPixel):
+    builder.PrependBoolSlot(5, halfPixel, 0)
+
+def RoiAlignAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class OneHotAttrsT(object):
+class RoiAlignAttrsT(object):
 
-    # OneHotAttrsT
+    # RoiAlignAttrsT
     def __init__(
         self,
-        axis = 0,
+        mode = 0,
+        outputHeight = 0,
+        outputWidth = 0,
+        samplingRatio = 0,
+        spatialScale = 0.0,
+        halfPixel = False,
     ):
-        self.axis = axis  # type: int
+        self.mode = mode  # type: int
+        self.outputHeight = outputHeight  # type: int
+        self.outputWidth = outputWidth  # type: int
+        self.samplingRatio = samplingRatio  # type: int
+        self.spatialScale = spatialScale  # type: float
+        self.halfPixel = halfPixel  # type: bool
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        oneHotAttrs = OneHotAttrs()
-        oneHotAttrs.Init(buf, pos)
-        return cls.InitFromObj(oneHotAttrs)
+        roiAlignAttrs = RoiAlignAttrs()
+        roiAlignAttrs.Init(buf, pos)
+        return cls.InitFromObj(roiAlignAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -4232,79 +7970,101 @@ class OneHotAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, oneHotAttrs):
-        x = OneHotAttrsT()
-        x._UnPack(oneHotAttrs)
+    def InitFromObj(cls, roiAlignAttrs):
+        x = RoiAlignAttrsT()
+        x._UnPack(roiAlignAttrs)
         return x
 
-    # OneHotAttrsT
-    def _UnPack(self, oneHotAttrs):
-        if oneHotAttrs is None:
+    # RoiAlignAttrsT
+    def _UnPack(self, roiAlignAttrs):
+        if roiAlignAttrs is None:
             return
-        self.axis = oneHotAttrs.Axis()
-
-    # OneHotAttrsT
+        self.mode = roiAlignAttrs.Mode()
+        self.outputHeight = roiAlignAttrs.OutputHeight()
+        self.outputWidth = roiAlignAttrs.OutputWidth()
+        self.samplingRatio = roiAlignAttrs.SamplingRatio()
+        self.spatialScale = roiAlignAttrs.SpatialScale()
+        self.halfPixel = roiAlignAttrs.HalfPixel()
+
+    # RoiAlignAttrsT
     def Pack(self, builder):
-        OneHotAttrsStart(builder)
-        OneHotAttrsAddAxis(builder, self.axis)
-        oneHotAttrs = OneHotAttrsEnd(builder)
-        return oneHotAttrs
-
-
-class PadAttrs(object):
+        RoiAlignAttrsStart(builder)
+        RoiAlignAttrsAddMode(builder, self.mode)
+        RoiAlignAttrsAddOutputHeight(builder, self.outputHeight)
+        RoiAlignAttrsAddOutputWidth(builder, self.outputWidth)
+        RoiAlignAttrsAddSamplingRatio(builder, self.samplingRatio)
+        RoiAlignAttrsAddSpatialScale(builder, self.spatialScale)
+        RoiAlignAttrsAddHalfPixel(builder, self.halfPixel)
+        roiAlignAttrs = RoiAlignAttrsEnd(builder)
+        return roiAlignAttrs
+
+
+class ReverseSequenceAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = PadAttrs()
+        x = ReverseSequenceAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsPadAttrs(cls, buf, offset=0):
+    def GetRootAsReverseSequenceAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def PadAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def ReverseSequenceAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # PadAttrs
+    # ReverseSequenceAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # PadAttrs
-    def Mode(self):
+    # ReverseSequenceAttrs
+    def BatchAxis(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
         return 0
 
-def PadAttrsStart(builder):
-    builder.StartObject(1)
+    # ReverseSequenceAttrs
+    def TimeAxis(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
 
-def PadAttrsAddMode(builder, mode):
-    builder.PrependUint8Slot(0, mode, 0)
+def ReverseSequenceAttrsStart(builder):
+    builder.StartObject(2)
 
-def PadAttrsEnd(builder):
+def ReverseSequenceAttrsAddBatchAxis(builder, batchAxis):
+    builder.PrependUint32Slot(0, batchAxis, 0)
+
+def ReverseSequenceAttrsAddTimeAxis(builder, timeAxis):
+    builder.PrependUint32Slot(1, timeAxis, 0)
+
+def ReverseSequenceAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class PadAttrsT(object):
+class ReverseSequenceAttrsT(object):
 
-    # PadAttrsT
+    # ReverseSequenceAttrsT
     def __init__(
         self,
-        mode = 0,
+        batchAxis = 0,
+        timeAxis = 0,
     ):
-        self.mode = mode  # type: int
+        self.batchAxis = batchAxis  # type: int
+        self.timeAxis = timeAxis  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        padAttrs = PadAttrs()
-        padAttrs.Init(buf, pos)
-        return cls.InitFromObj(padAttrs)
+        reverseSequenceAttrs = ReverseSequenceAttrs()
+        reverseSequenceAttrs.Init(buf, pos)
+        return cls.InitFromObj(reverseSequenceAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -4312,71 +8072,83 @@ class PadAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, padAttrs):
-        x = PadAttrsT()
-        x._UnPack(padAttrs)
+    def InitFromObj(cls, reverseSequenceAttrs):
+        x = ReverseSequenceAttrsT()
+        x._UnPack(reverseSequenceAttrs)
         return x
 
-    # PadAttrsT
-    def _UnPack(self, padAttrs):
-        if padAttrs is None:
+    # ReverseSequenceAttrsT
+    def _UnPack(self, reverseSequenceAttrs):
+        if reverseSequenceAttrs is None:
             return
-        self.mode = padAttrs.Mode()
+        self.batchAxis = reverseSequenceAttrs.BatchAxis()
+        self.timeAxis = reverseSequenceAttrs.TimeAxis()
 
-    # PadAttrsT
+    # ReverseSequenceAttrsT
     def Pack(self, builder):
-        PadAttrsStart(builder)
-        PadAttrsAddMode(builder, self.mode)
-        padAttrs = PadAttrsEnd(builder)
-        return padAttrs
+        ReverseSequenceAttrsStart(builder)
+        ReverseSequenceAttrsAddBatchAxis(builder, self.batchAxis)
+        ReverseSequenceAttrsAddTimeAxis(builder, self.timeAxis)
+        reverseSequenceAttrs = ReverseSequenceAttrsEnd(builder)
+        return reverseSequenceAttrs
 
 
-class QuantizeLinearAttrs(object):
+class RotaryEmbeddingAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = QuantizeLinearAttrs()
+        x = RotaryEmbeddingAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsQuantizeLinearAttrs(cls, buf, offset=0):
+    def GetRootAsRotaryEmbeddingAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def QuantizeLinearAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def RotaryEmbeddingAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # QuantizeLinearAttrs
+    # RotaryEmbeddingAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # QuantizeLinearAttrs
-    def Axis(self):
+    # RotaryEmbeddingAttrs
+    def Interleaved(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
-        return 0
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
 
-    # QuantizeLinearAttrs
-    def OutputDtype(self):
+    # RotaryEmbeddingAttrs
+    def NumHeads(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
         return None
 
-def QuantizeLinearAttrsStart(builder):
-    builder.StartObject(2)
+    # RotaryEmbeddingAttrs
+    def RotaryEmbeddingDim(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return None
 
-def QuantizeLinearAttrsAddAxis(builder, axis):
-    builder.PrependInt32Slot(0, axis, 0)
+def RotaryEmbeddingAttrsStart(builder):
+    builder.StartObject(3)
 
-def QuantizeLinearAttrsAddOutputDtype(builder, outputDtype):
-    builder.PrependUint8Slot(1, outputDtype, None)
+def RotaryEmbeddingAttrsAddInterleaved(builder, interleaved):
+    builder.PrependBoolSlot(0, interleaved, 0)
 
-def QuantizeLinearAttrsEnd(builder):
+def RotaryEmbeddingAttrsAddNumHeads(builder, numHeads):
+    builder.PrependUint32Slot(1, numHeads, None)
+
+def RotaryEmbeddingAttrsAddRotaryEmbeddingDim(builder, rotaryEmbeddingDim):
+    builder.PrependUint32Slot(2, rotaryEmbeddingDim, None)
+
+def RotaryEmbeddingAttrsEnd(builder):
     return builder.EndObject()
 
 
@@ -4385,22 +8157,24 @@ try:
 except:
     pass
 
-class QuantizeLinearAttrsT(object):
+class RotaryEmbeddingAttrsT(object):
 
-    # QuantizeLinearAttrsT
+    # RotaryEmbeddingAttrsT
     def __init__(
         self,
-        axis = 0,
-        outputDtype = None,
+        interleaved = False,
+        numHeads = None,
+        rotaryEmbeddingDim = None,
     ):
-        self.axis = axis  # type: int
-        self.outputDtype = outputDtype  # type: Optional[int]
+        self.interleaved = interleaved  # type: bool
+        self.numHeads = numHeads  # type: Optional[int]
+        self.rotaryEmbeddingDim = rotaryEmbeddingDim  # type: Optional[int]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        quantizeLinearAttrs = QuantizeLinearAttrs()
-        quantizeLinearAttrs.Init(buf, pos)
-        return cls.InitFromObj(quantizeLinearAttrs)
+        rotaryEmbeddingAttrs = RotaryEmbeddingAttrs()
+        rotaryEmbeddingAttrs.Init(buf, pos)
+        return cls.InitFromObj(rotaryEmbeddingAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -4408,144 +8182,95 @@ class QuantizeLinearAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, quantizeLinearAttrs):
-        x = QuantizeLinearAttrsT()
-        x._UnPack(quantizeLinearAttrs)
+    def InitFromObj(cls, rotaryEmbeddingAttrs):
+        x = RotaryEmbeddingAttrsT()
+        x._UnPack(rotaryEmbeddingAttrs)
         return x
 
-    # QuantizeLinearAttrsT
-    def _UnPack(self, quantizeLinearAttrs):
-        if quantizeLinearAttrs is None:
+    # RotaryEmbeddingAttrsT
+    def _UnPack(self, rotaryEmbeddingAttrs):
+        if rotaryEmbeddingAttrs is None:
             return
-        self.axis = quantizeLinearAttrs.Axis()
-        self.outputDtype = quantizeLinearAttrs.OutputDtype()
+        self.interleaved = rotaryEmbeddingAttrs.Interleaved()
+        self.numHeads = rotaryEmbeddingAttrs.NumHeads()
+        self.rotaryEmbeddingDim = rotaryEmbeddingAttrs.RotaryEmbeddingDim()
 
-    # QuantizeLinearAttrsT
+    # RotaryEmbeddingAttrsT
     def Pack(self, builder):
-        QuantizeLinearAttrsStart(builder)
-        QuantizeLinearAttrsAddAxis(builder, self.axis)
-        QuantizeLinearAttrsAddOutputDtype(builder, self.outputDtype)
-        quantizeLinearAttrs = QuantizeLinearAttrsEnd(builder)
-        return quantizeLinearAttrs
+        RotaryEmbeddingAttrsStart(builder)
+        RotaryEmbeddingAttrsAddInterleaved(builder, self.interleaved)
+        RotaryEmbeddingAttrsAddNumHeads(builder, self.numHeads)
+        RotaryEmbeddingAttrsAddRotaryEmbeddingDim(builder, self.rotaryEmbeddingDim)
+        rotaryEmbeddingAttrs = RotaryEmbeddingAttrsEnd(builder)
+        return rotaryEmbeddingAttrs
 
 
-class RandomNormalAttrs(object):
+class ScatterElementsAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = RandomNormalAttrs()
+        x = ScatterElementsAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsRandomNormalAttrs(cls, buf, offset=0):
+    def GetRootAsScatterElementsAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def RandomNormalAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def ScatterElementsAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # RandomNormalAttrs
+    # ScatterElementsAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # RandomNormalAttrs
-    def Mean(self):
+    # ScatterElementsAttrs
+    def Axis(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
-
-    # RandomNormalAttrs
-    def Scale(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
-        if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
-
-    # RandomNormalAttrs
-    def Seed(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
-        if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return None
-
-    # RandomNormalAttrs
-    def Shape(self, j):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        if o != 0:
-            a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
-        return 0
-
-    # RandomNormalAttrs
-    def ShapeAsNumpy(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
         return 0
 
-    # RandomNormalAttrs
-    def ShapeLength(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+    # ScatterElementsAttrs
+    def Reduction(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            return self._tab.VectorLen(o)
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
         return 0
 
-    # RandomNormalAttrs
-    def ShapeIsNone(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        return o == 0
-
-def RandomNormalAttrsStart(builder):
-    builder.StartObject(4)
-
-def RandomNormalAttrsAddMean(builder, mean):
-    builder.PrependFloat32Slot(0, mean, 0.0)
-
-def RandomNormalAttrsAddScale(builder, scale):
-    builder.PrependFloat32Slot(1, scale, 0.0)
-
-def RandomNormalAttrsAddSeed(builder, seed):
-    builder.PrependFloat32Slot(2, seed, None)
-
-def RandomNormalAttrsAddShape(builder, shape):
-    builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(shape), 0)
-
-def RandomNormalAttrsStartShapeVector(builder, numElems):
-    return builder.StartVector(4, numElems, 4)
+def ScatterElementsAttrsStart(builder):
+    builder.StartObject(2)
 
-def RandomNormalAttrsEnd(builder):
-    return builder.EndObject()
+def ScatterElementsAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, 0)
 
+def ScatterElementsAttrsAddReduction(builder, reduction):
+    builder.PrependUint8Slot(1, reduction, 0)
 
-try:
-    from typing import List, Optional
-except:
-    pass
+def ScatterElementsAttrsEnd(builder):
+    return builder.EndObject()
 
-class RandomNormalAttrsT(object):
 
-    # RandomNormalAttrsT
+
+class ScatterElementsAttrsT(object):
+
+    # ScatterElementsAttrsT
     def __init__(
         self,
-        mean = 0.0,
-        scale = 0.0,
-        seed = None,
-        shape = None,
+        axis = 0,
+        reduction = 0,
     ):
-        self.mean = mean  # type: float
-        self.scale = scale  # type: float
-        self.seed = seed  # type: Optional[float]
-        self.shape = shape  # type: Optional[List[int]]
+        self.axis = axis  # type: int
+        self.reduction = reduction  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        randomNormalAttrs = RandomNormalAttrs()
-        randomNormalAttrs.Init(buf, pos)
-        return cls.InitFromObj(randomNormalAttrs)
+        scatterElementsAttrs = ScatterElementsAttrs()
+        scatterElementsAttrs.Init(buf, pos)
+        return cls.InitFromObj(scatterElementsAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -4553,128 +8278,81 @@ class RandomNormalAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, randomNormalAttrs):
-        x = RandomNormalAttrsT()
-        x._UnPack(randomNormalAttrs)
+    def InitFromObj(cls, scatterElementsAttrs):
+        x = ScatterElementsAttrsT()
+        x._UnPack(scatterElementsAttrs)
         return x
 
-    # RandomNormalAttrsT
-    def _UnPack(self, randomNormalAttrs):
-        if randomNormalAttrs is None:
+    # ScatterElementsAttrsT
+    def _UnPack(self, scatterElementsAttrs):
+        if scatterElementsAttrs is None:
             return
-        self.mean = randomNormalAttrs.Mean()
-        self.scale = randomNormalAttrs.Scale()
-        self.seed = randomNormalAttrs.Seed()
-        if not randomNormalAttrs.ShapeIsNone():
-            if np is None:
-                self.shape = []
-                for i in range(randomNormalAttrs.ShapeLength()):
-                    self.shape.append(randomNormalAttrs.Shape(i))
-            else:
-                self.shape = randomNormalAttrs.ShapeAsNumpy()
+        self.axis = scatterElementsAttrs.Axis()
+        self.reduction = scatterElementsAttrs.Reduction()
 
-    # RandomNormalAttrsT
+    # ScatterElementsAttrsT
     def Pack(self, builder):
-        if self.shape is not None:
-            if np is not None and type(self.shape) is np.ndarray:
-                shape = builder.CreateNumpyVector(self.shape)
-            else:
-                RandomNormalAttrsStartShapeVector(builder, len(self.shape))
-                for i in reversed(range(len(self.shape))):
-                    builder.PrependUint32(self.shape[i])
-                shape = builder.EndVector()
-        RandomNormalAttrsStart(builder)
-        RandomNormalAttrsAddMean(builder, self.mean)
-        RandomNormalAttrsAddScale(builder, self.scale)
-        RandomNormalAttrsAddSeed(builder, self.seed)
-        if self.shape is not None:
-            RandomNormalAttrsAddShape(builder, shape)
-        randomNormalAttrs = RandomNormalAttrsEnd(builder)
-        return randomNormalAttrs
+        ScatterElementsAttrsStart(builder)
+        ScatterElementsAttrsAddAxis(builder, self.axis)
+        ScatterElementsAttrsAddReduction(builder, self.reduction)
+        scatterElementsAttrs = ScatterElementsAttrsEnd(builder)
+        return scatterElementsAttrs
 
 
-class RandomNormalLikeAttrs(object):
+class ScatterNDAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = RandomNormalLikeAttrs()
+        x = ScatterNDAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsRandomNormalLikeAttrs(cls, buf, offset=0):
+    def GetRootAsScatterNDAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def RandomNormalLikeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def ScatterNDAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # RandomNormalLikeAttrs
+    # ScatterNDAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # RandomNormalLikeAttrs
-    def Mean(self):
+    # ScatterNDAttrs
+    def Reduction(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
-
-    # RandomNormalLikeAttrs
-    def Scale(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
-        if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
-
-    # RandomNormalLikeAttrs
-    def Seed(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
-        if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return None
-
-def RandomNormalLikeAttrsStart(builder):
-    builder.StartObject(3)
-
-def RandomNormalLikeAttrsAddMean(builder, mean):
-    builder.PrependFloat32Slot(0, mean, 0.0)
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return 0
 
-def RandomNormalLikeAttrsAddScale(builder, scale):
-    builder.PrependFloat32Slot(1, scale, 0.0)
+def ScatterNDAttrsStart(builder):
+    builder.StartObject(1)
 
-def RandomNormalLikeAttrsAddSeed(builder, seed):
-    builder.PrependFloat32Slot(2, seed, None)
+def ScatterNDAttrsAddReduction(builder, reduction):
+    builder.PrependUint8Slot(0, reduction, 0)
 
-def RandomNormalLikeAttrsEnd(builder):
+def ScatterNDAttrsEnd(builder):
     return builder.EndObject()
 
 
-try:
-    from typing import Optional
-except:
-    pass
 
-class RandomNormalLikeAttrsT(object):
+class ScatterNDAttrsT(object):
 
-    # RandomNormalLikeAttrsT
+    # ScatterNDAttrsT
     def __init__(
         self,
-        mean = 0.0,
-        scale = 0.0,
-        seed = None,
+        reduction = 0,
     ):
-        self.mean = mean  # type: float
-        self.scale = scale  # type: float
-        self.seed = seed  # type: Optional[float]
+        self.reduction = reduction  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        randomNormalLikeAttrs = RandomNormalLikeAttrs()
-        randomNormalLikeAttrs.Init(buf, pos)
-        return cls.InitFromObj(randomNormalLikeAttrs)
+        scatterNdattrs = ScatterNDAttrs()
+        scatterNdattrs.Init(buf, pos)
+        return cls.InitFromObj(scatterNdattrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -4682,146 +8360,177 @@ class RandomNormalLikeAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, randomNormalLikeAttrs):
-        x = RandomNormalLikeAttrsT()
-        x._UnPack(randomNormalLikeAttrs)
+    def InitFromObj(cls, scatterNdattrs):
+        x = ScatterNDAttrsT()
+        x._UnPack(scatterNdattrs)
         return x
 
-    # RandomNormalLikeAttrsT
-    def _UnPack(self, randomNormalLikeAttrs):
-        if randomNormalLikeAttrs is None:
+    # ScatterNDAttrsT
+    def _UnPack(self, scatterNdattrs):
+        if scatterNdattrs is None:
             return
-        self.mean = randomNormalLikeAttrs.Mean()
-        self.scale = randomNormalLikeAttrs.Scale()
-        self.seed = randomNormalLikeAttrs.Seed()
+        self.reduction = scatterNdattrs.Reduction()
 
-    # RandomNormalLikeAttrsT
+    # ScatterNDAttrsT
     def Pack(self, builder):
-        RandomNormalLikeAttrsStart(builder)
-        RandomNormalLikeAttrsAddMean(builder, self.mean)
-        RandomNormalLikeAttrsAddScale(builder, self.scale)
-        RandomNormalLikeAttrsAddSeed(builder, self.seed)
-        randomNormalLikeAttrs = RandomNormalLikeAttrsEnd(builder)
-        return randomNormalLikeAttrs
+        ScatterNDAttrsStart(builder)
+        ScatterNDAttrsAddReduction(builder, self.reduction)
+        scatterNdattrs = ScatterNDAttrsEnd(builder)
+        return scatterNdattrs
 
 
-class RandomUniformAttrs(object):
+class SeluAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = RandomUniformAttrs()
+        x = SeluAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsRandomUniformAttrs(cls, buf, offset=0):
+    def GetRootAsSeluAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def RandomUniformAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def SeluAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # RandomUniformAttrs
+    # SeluAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # RandomUniformAttrs
-    def Shape(self, j):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
-        if o != 0:
-            a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
-        return 0
-
-    # RandomUniformAttrs
-    def ShapeAsNumpy(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
-        if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
-        return 0
-
-    # RandomUniformAttrs
-    def ShapeLength(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
-        if o != 0:
-            return self._tab.VectorLen(o)
-        return 0
-
-    # RandomUniformAttrs
-    def ShapeIsNone(self):
+    # SeluAttrs
+    def Alpha(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
-        return o == 0
-
-    # RandomUniformAttrs
-    def High(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
             return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
         return 0.0
 
-    # RandomUniformAttrs
-    def Low(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+    # SeluAttrs
+    def Gamma(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
             return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
         return 0.0
 
-    # RandomUniformAttrs
-    def Seed(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
-        if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return None
+def SeluAttrsStart(builder):
+    builder.StartObject(2)
 
-def RandomUniformAttrsStart(builder):
-    builder.StartObject(4)
+def SeluAttrsAddAlpha(builder, alpha):
+    builder.PrependFloat32Slot(0, alpha, 0.0)
+
+def SeluAttrsAddGamma(builder, gamma):
+    builder.PrependFloat32Slot(1, gamma, 0.0)
+
+def SeluAttrsEnd(builder):
+    return builder.EndObject()
+
+
+
+class SeluAttrsT(object):
+
+    # SeluAttrsT
+    def __init__(
+        self,
+        alpha = 0.0,
+        gamma = 0.0,
+    ):
+        self.alpha = alpha  # type: float
+        self.gamma = gamma  # type: float
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        seluAttrs = SeluAttrs()
+        seluAttrs.Init(buf, pos)
+        return cls.InitFromObj(seluAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, seluAttrs):
+        x = SeluAttrsT()
+        x._UnPack(seluAttrs)
+        return x
+
+    # SeluAttrsT
+    def _UnPack(self, seluAttrs):
+        if seluAttrs is None:
+            return
+        self.alpha = seluAttrs.Alpha()
+        self.gamma = seluAttrs.Gamma()
+
+    # SeluAttrsT
+    def Pack(self, builder):
+        SeluAttrsStart(builder)
+        SeluAttrsAddAlpha(builder, self.alpha)
+        SeluAttrsAddGamma(builder, self.gamma)
+        seluAttrs = SeluAttrsEnd(builder)
+        return seluAttrs
+
+
+class SequenceEmptyAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = SequenceEmptyAttrs()
+        x.Init(buf, n + offset)
+        return x
 
-def RandomUniformAttrsAddShape(builder, shape):
-    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(shape), 0)
+    @classmethod
+    def GetRootAsSequenceEmptyAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def SequenceEmptyAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-def RandomUniformAttrsStartShapeVector(builder, numElems):
-    return builder.StartVector(4, numElems, 4)
+    # SequenceEmptyAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
 
-def RandomUniformAttrsAddHigh(builder, high):
-    builder.PrependFloat32Slot(1, high, 0.0)
+    # SequenceEmptyAttrs
+    def Dtype(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+        return None
 
-def RandomUniformAttrsAddLow(builder, low):
-    builder.PrependFloat32Slot(2, low, 0.0)
+def SequenceEmptyAttrsStart(builder):
+    builder.StartObject(1)
 
-def RandomUniformAttrsAddSeed(builder, seed):
-    builder.PrependFloat32Slot(3, seed, None)
+def SequenceEmptyAttrsAddDtype(builder, dtype):
+    builder.PrependUint8Slot(0, dtype, None)
 
-def RandomUniformAttrsEnd(builder):
+def SequenceEmptyAttrsEnd(builder):
     return builder.EndObject()
 
 
 try:
-    from typing import List, Optional
+    from typing import Optional
 except:
     pass
 
-class RandomUniformAttrsT(object):
+class SequenceEmptyAttrsT(object):
 
-    # RandomUniformAttrsT
+    # SequenceEmptyAttrsT
     def __init__(
         self,
-        shape = None,
-        high = 0.0,
-        low = 0.0,
-        seed = None,
+        dtype = None,
     ):
-        self.shape = shape  # type: Optional[List[int]]
-        self.high = high  # type: float
-        self.low = low  # type: float
-        self.seed = seed  # type: Optional[float]
+        self.dtype = dtype  # type: Optional[int]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        randomUniformAttrs = RandomUniformAttrs()
-        randomUniformAttrs.Init(buf, pos)
-        return cls.InitFromObj(randomUniformAttrs)
+        sequenceEmptyAttrs = SequenceEmptyAttrs()
+        sequenceEmptyAttrs.Init(buf, pos)
+        return cls.InitFromObj(sequenceEmptyAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -4829,102 +8538,71 @@ class RandomUniformAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, randomUniformAttrs):
-        x = RandomUniformAttrsT()
-        x._UnPack(randomUniformAttrs)
+    def InitFromObj(cls, sequenceEmptyAttrs):
+        x = SequenceEmptyAttrsT()
+        x._UnPack(sequenceEmptyAttrs)
         return x
 
-    # RandomUniformAttrsT
-    def _UnPack(self, randomUniformAttrs):
-        if randomUniformAttrs is None:
+    # SequenceEmptyAttrsT
+    def _UnPack(self, sequenceEmptyAttrs):
+        if sequenceEmptyAttrs is None:
             return
-        if not randomUniformAttrs.ShapeIsNone():
-            if np is None:
-                self.shape = []
-                for i in range(randomUniformAttrs.ShapeLength()):
-                    self.shape.append(randomUniformAttrs.Shape(i))
-            else:
-                self.shape = randomUniformAttrs.ShapeAsNumpy()
-        self.high = randomUniformAttrs.High()
-        self.low = randomUniformAttrs.Low()
-        self.seed = randomUniformAttrs.Seed()
+        self.dtype = sequenceEmptyAttrs.Dtype()
 
-    # RandomUniformAttrsT
+    # SequenceEmptyAttrsT
     def Pack(self, builder):
-        if self.shape is not None:
-            if np is not None and type(self.shape) is np.ndarray:
-                shape = builder.CreateNumpyVector(self.shape)
-            else:
-                RandomUniformAttrsStartShapeVector(builder, len(self.shape))
-                for i in reversed(range(len(self.shape))):
-                    builder.PrependUint32(self.shape[i])
-                shape = builder.EndVector()
-        RandomUniformAttrsStart(builder)
-        if self.shape is not None:
-            RandomUniformAttrsAddShape(builder, shape)
-        RandomUniformAttrsAddHigh(builder, self.high)
-        RandomUniformAttrsAddLow(builder, self.low)
-        RandomUniformAttrsAddSeed(builder, self.seed)
-        randomUniformAttrs = RandomUniformAttrsEnd(builder)
-        return randomUniformAttrs
+        SequenceEmptyAttrsStart(builder)
+        SequenceEmptyAttrsAddDtype(builder, self.dtype)
+        sequenceEmptyAttrs = SequenceEmptyAttrsEnd(builder)
+        return sequenceEmptyAttrs
 
 
-class RandomUniformLikeAttrs(object):
+class ShapeAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = RandomUniformLikeAttrs()
+        x = ShapeAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsRandomUniformLikeAttrs(cls, buf, offset=0):
+    def GetRootAsShapeAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def RandomUniformLikeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def ShapeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # RandomUniformLikeAttrs
+    # ShapeAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # RandomUniformLikeAttrs
-    def High(self):
+    # ShapeAttrs
+    def Start(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return None
 
-    # RandomUniformLikeAttrs
-    def Low(self):
+    # ShapeAttrs
+    def End(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
-        return 0.0
-
-    # RandomUniformLikeAttrs
-    def Seed(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
-        if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
         return None
 
-def RandomUniformLikeAttrsStart(builder):
-    builder.StartObject(3)
-
-def RandomUniformLikeAttrsAddHigh(builder, high):
-    builder.PrependFloat32Slot(0, high, 0.0)
+def ShapeAttrsStart(builder):
+    builder.StartObject(2)
 
-def RandomUniformLikeAttrsAddLow(builder, low):
-    builder.PrependFloat32Slot(1, low, 0.0)
+def ShapeAttrsAddStart(builder, start):
+    builder.PrependInt32Slot(0, start, None)
 
-def RandomUniformLikeAttrsAddSeed(builder, seed):
-    builder.PrependFloat32Slot(2, seed, None)
+def ShapeAttrsAddEnd(builder, end):
+    builder.PrependInt32Slot(1, end, None)
 
-def RandomUniformLikeAttrsEnd(builder):
+def ShapeAttrsEnd(builder):
     return builder.EndObject()
 
 
@@ -4933,24 +8611,22 @@ try:
 except:
     pass
 
-class RandomUniformLikeAttrsT(object):
+class ShapeAttrsT(object):
 
-    # RandomUniformLikeAttrsT
+    # ShapeAttrsT
     def __init__(
         self,
-        high = 0.0,
-        low = 0.0,
-        seed = None,
+        start = None,
+        end = None,
     ):
-        self.high = high  # type: float
-        self.low = low  # type: float
-        self.seed = seed  # type: Optional[float]
+        self.start = start  # type: Optional[int]
+        self.end = end  # type: Optional[int]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        randomUniformLikeAttrs = RandomUniformLikeAttrs()
-        randomUniformLikeAttrs.Init(buf, pos)
-        return cls.InitFromObj(randomUniformLikeAttrs)
+        shapeAttrs = ShapeAttrs()
+        shapeAttrs.Init(buf, pos)
+        return cls.InitFromObj(shapeAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -4958,134 +8634,93 @@ class RandomUniformLikeAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, randomUniformLikeAttrs):
-        x = RandomUniformLikeAttrsT()
-        x._UnPack(randomUniformLikeAttrs)
+    def InitFromObj(cls, shapeAttrs):
+        x = ShapeAttrsT()
+        x._UnPack(shapeAttrs)
         return x
 
-    # RandomUniformLikeAttrsT
-    def _UnPack(self, randomUniformLikeAttrs):
-        if randomUniformLikeAttrs is None:
+    # ShapeAttrsT
+    def _UnPack(self, shapeAttrs):
+        if shapeAttrs is None:
             return
-        self.high = randomUniformLikeAttrs.High()
-        self.low = randomUniformLikeAttrs.Low()
-        self.seed = randomUniformLikeAttrs.Seed()
+        self.start = shapeAttrs.Start()
+        self.end = shapeAttrs.End()
 
-    # RandomUniformLikeAttrsT
+    # ShapeAttrsT
     def Pack(self, builder):
-        RandomUniformLikeAttrsStart(builder)
-        RandomUniformLikeAttrsAddHigh(builder, self.high)
-        RandomUniformLikeAttrsAddLow(builder, self.low)
-        RandomUniformLikeAttrsAddSeed(builder, self.seed)
-        randomUniformLikeAttrs = RandomUniformLikeAttrsEnd(builder)
-        return randomUniformLikeAttrs
+        ShapeAttrsStart(builder)
+        ShapeAttrsAddStart(builder, self.start)
+        ShapeAttrsAddEnd(builder, self.end)
+        shapeAttrs = ShapeAttrsEnd(builder)
+        return shapeAttrs
 
 
-class ReduceMeanAttrs(object):
+class ShrinkAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = ReduceMeanAttrs()
+        x = ShrinkAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsReduceMeanAttrs(cls, buf, offset=0):
+    def GetRootAsShrinkAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def ReduceMeanAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def ShrinkAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # ReduceMeanAttrs
+    # ShrinkAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # ReduceMeanAttrs
-    def Axes(self, j):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
-        if o != 0:
-            a = self._tab.Vector(o)
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
-        return 0
-
-    # ReduceMeanAttrs
-    def AxesAsNumpy(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
-        if o != 0:
-            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Int32Flags, o)
-        return 0
-
-    # ReduceMeanAttrs
-    def AxesLength(self):
+    # ShrinkAttrs
+    def Bias(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.VectorLen(o)
-        return 0
-
-    # ReduceMeanAttrs
-    def AxesIsNone(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
-        return o == 0
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
 
-    # ReduceMeanAttrs
-    def KeepDims(self):
+    # ShrinkAttrs
+    def Lambd(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
-        return False
-
-    # ReduceMeanAttrs
-    def NoopWithEmptyAxes(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
-        if o != 0:
-            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
-        return False
-
-def ReduceMeanAttrsStart(builder):
-    builder.StartObject(3)
-
-def ReduceMeanAttrsAddAxes(builder, axes):
-    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(axes), 0)
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
 
-def ReduceMeanAttrsStartAxesVector(builder, numElems):
-    return builder.StartVector(4, numElems, 4)
+def ShrinkAttrsStart(builder):
+    builder.StartObject(2)
 
-def ReduceMeanAttrsAddKeepDims(builder, keepDims):
-    builder.PrependBoolSlot(1, keepDims, 0)
+def ShrinkAttrsAddBias(builder, bias):
+    builder.PrependFloat32Slot(0, bias, 0.0)
 
-def ReduceMeanAttrsAddNoopWithEmptyAxes(builder, noopWithEmptyAxes):
-    builder.PrependBoolSlot(2, noopWithEmptyAxes, 0)
+def ShrinkAttrsAddLambd(builder, lambd):
+    builder.PrependFloat32Slot(1, lambd, 0.0)
 
-def ReduceMeanAttrsEnd(builder):
+def ShrinkAttrsEnd(builder):
     return builder.EndObject()
 
 
-try:
-    from typing import List
-except:
-    pass
 
-class ReduceMeanAttrsT(object):
+class ShrinkAttrsT(object):
 
-    # ReduceMeanAttrsT
+    # ShrinkAttrsT
     def __init__(
         self,
-        axes = None,
-        keepDims = False,
-        noopWithEmptyAxes = False,
+        bias = 0.0,
+        lambd = 0.0,
     ):
-        self.axes = axes  # type: Optional[List[int]]
-        self.keepDims = keepDims  # type: bool
-        self.noopWithEmptyAxes = noopWithEmptyAxes  # type: bool
+        self.bias = bias  # type: float
+        self.lambd = lambd  # type: float
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        reduceMeanAttrs = ReduceMeanAttrs()
-        reduceMeanAttrs.Init(buf, pos)
-        return cls.InitFromObj(reduceMeanAttrs)
+        shrinkAttrs = ShrinkAttrs()
+        shrinkAttrs.Init(buf, pos)
+        return cls.InitFromObj(shrinkAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -5093,98 +8728,93 @@ class ReduceMeanAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, reduceMeanAttrs):
-        x = ReduceMeanAttrsT()
-        x._UnPack(reduceMeanAttrs)
+    def InitFromObj(cls, shrinkAttrs):
+        x = ShrinkAttrsT()
+        x._UnPack(shrinkAttrs)
         return x
 
-    # ReduceMeanAttrsT
-    def _UnPack(self, reduceMeanAttrs):
-        if reduceMeanAttrs is None:
+    # ShrinkAttrsT
+    def _UnPack(self, shrinkAttrs):
+        if shrinkAttrs is None:
             return
-        if not reduceMeanAttrs.AxesIsNone():
-            if np is None:
-                self.axes = []
-                for i in range(reduceMeanAttrs.AxesLength()):
-                    self.axes.append(reduceMeanAttrs.Axes(i))
-            else:
-                self.axes = reduceMeanAttrs.AxesAsNumpy()
-        self.keepDims = reduceMeanAttrs.KeepDims()
-        self.noopWithEmptyAxes = reduceMeanAttrs.NoopWithEmptyAxes()
+        self.bias = shrinkAttrs.Bias()
+        self.lambd = shrinkAttrs.Lambd()
 
-    # ReduceMeanAttrsT
+    # ShrinkAttrsT
     def Pack(self, builder):
-        if self.axes is not None:
-            if np is not None and type(self.axes) is np.ndarray:
-                axes = builder.CreateNumpyVector(self.axes)
-            else:
-                ReduceMeanAttrsStartAxesVector(builder, len(self.axes))
-                for i in reversed(range(len(self.axes))):
-                    builder.PrependInt32(self.axes[i])
-                axes = builder.EndVector()
-        ReduceMeanAttrsStart(builder)
-        if self.axes is not None:
-            ReduceMeanAttrsAddAxes(builder, axes)
-        ReduceMeanAttrsAddKeepDims(builder, self.keepDims)
-        ReduceMeanAttrsAddNoopWithEmptyAxes(builder, self.noopWithEmptyAxes)
-        reduceMeanAttrs = ReduceMeanAttrsEnd(builder)
-        return reduceMeanAttrs
+        ShrinkAttrsStart(builder)
+        ShrinkAttrsAddBias(builder, self.bias)
+        ShrinkAttrsAddLambd(builder, self.lambd)
+        shrinkAttrs = ShrinkAttrsEnd(builder)
+        return shrinkAttrs
 
 
-class ReshapeAttrs(object):
+class SoftmaxAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = ReshapeAttrs()
+        x = SoftmaxAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsReshapeAttrs(cls, buf, offset=0):
+    def GetRootAsSoftmaxAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def ReshapeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def SoftmaxAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # ReshapeAttrs
+    # SoftmaxAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # ReshapeAttrs
-    def AllowZero(self):
+    # SoftmaxAttrs
+    def Axis(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return 0
+
+    # SoftmaxAttrs
+    def FlushNansToZero(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
             return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
         return False
 
-def ReshapeAttrsStart(builder):
-    builder.StartObject(1)
+def SoftmaxAttrsStart(builder):
+    builder.StartObject(2)
 
-def ReshapeAttrsAddAllowZero(builder, allowZero):
-    builder.PrependBoolSlot(0, allowZero, 0)
+def SoftmaxAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, 0)
 
-def ReshapeAttrsEnd(builder):
+def SoftmaxAttrsAddFlushNansToZero(builder, flushNansToZero):
+    builder.PrependBoolSlot(1, flushNansToZero, 0)
+
+def SoftmaxAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class ReshapeAttrsT(object):
+class SoftmaxAttrsT(object):
 
-    # ReshapeAttrsT
+    # SoftmaxAttrsT
     def __init__(
         self,
-        allowZero = False,
+        axis = 0,
+        flushNansToZero = False,
     ):
-        self.allowZero = allowZero  # type: bool
+        self.axis = axis  # type: int
+        self.flushNansToZero = flushNansToZero  # type: bool
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        reshapeAttrs = ReshapeAttrs()
-        reshapeAttrs.Init(buf, pos)
-        return cls.InitFromObj(reshapeAttrs)
+        softmaxAttrs = SoftmaxAttrs()
+        softmaxAttrs.Init(buf, pos)
+        return cls.InitFromObj(softmaxAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -5192,103 +8822,81 @@ class ReshapeAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, reshapeAttrs):
-        x = ReshapeAttrsT()
-        x._UnPack(reshapeAttrs)
+    def InitFromObj(cls, softmaxAttrs):
+        x = SoftmaxAttrsT()
+        x._UnPack(softmaxAttrs)
         return x
 
-    # ReshapeAttrsT
-    def _UnPack(self, reshapeAttrs):
-        if reshapeAttrs is None:
+    # SoftmaxAttrsT
+    def _UnPack(self, softmaxAttrs):
+        if softmaxAttrs is None:
             return
-        self.allowZero = reshapeAttrs.AllowZero()
+        self.axis = softmaxAttrs.Axis()
+        self.flushNansToZero = softmaxAttrs.FlushNansToZero()
 
-    # ReshapeAttrsT
+    # SoftmaxAttrsT
     def Pack(self, builder):
-        ReshapeAttrsStart(builder)
-        ReshapeAttrsAddAllowZero(builder, self.allowZero)
-        reshapeAttrs = ReshapeAttrsEnd(builder)
-        return reshapeAttrs
+        SoftmaxAttrsStart(builder)
+        SoftmaxAttrsAddAxis(builder, self.axis)
+        SoftmaxAttrsAddFlushNansToZero(builder, self.flushNansToZero)
+        softmaxAttrs = SoftmaxAttrsEnd(builder)
+        return softmaxAttrs
 
 
-class ResizeAttrs(object):
+class SpaceToDepthAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = ResizeAttrs()
+        x = SpaceToDepthAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsResizeAttrs(cls, buf, offset=0):
+    def GetRootAsSpaceToDepthAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def ResizeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def SpaceToDepthAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # ResizeAttrs
+    # SpaceToDepthAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # ResizeAttrs
-    def Mode(self):
+    # SpaceToDepthAttrs
+    def BlockSize(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
-        return 0
-
-    # ResizeAttrs
-    def CoordMode(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
-        if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
-        return 0
-
-    # ResizeAttrs
-    def NearestMode(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
-        if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
         return 0
 
-def ResizeAttrsStart(builder):
-    builder.StartObject(3)
-
-def ResizeAttrsAddMode(builder, mode):
-    builder.PrependUint8Slot(0, mode, 0)
-
-def ResizeAttrsAddCoordMode(builder, coordMode):
-    builder.PrependUint8Slot(1, coordMode, 0)
+def SpaceToDepthAttrsStart(builder):
+    builder.StartObject(1)
 
-def ResizeAttrsAddNearestMode(builder, nearestMode):
-    builder.PrependUint8Slot(2, nearestMode, 0)
+def SpaceToDepthAttrsAddBlockSize(builder, blockSize):
+    builder.PrependUint32Slot(0, blockSize, 0)
 
-def ResizeAttrsEnd(builder):
+def SpaceToDepthAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class ResizeAttrsT(object):
+class SpaceToDepthAttrsT(object):
 
-    # ResizeAttrsT
+    # SpaceToDepthAttrsT
     def __init__(
         self,
-        mode = 0,
-        coordMode = 0,
-        nearestMode = 0,
+        blockSize = 0,
     ):
-        self.mode = mode  # type: int
-        self.coordMode = coordMode  # type: int
-        self.nearestMode = nearestMode  # type: int
+        self.blockSize = blockSize  # type: int
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        resizeAttrs = ResizeAttrs()
-        resizeAttrs.Init(buf, pos)
-        return cls.InitFromObj(resizeAttrs)
+        spaceToDepthAttrs = SpaceToDepthAttrs()
+        spaceToDepthAttrs.Init(buf, pos)
+        return cls.InitFromObj(spaceToDepthAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -5296,95 +8904,95 @@ class ResizeAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, resizeAttrs):
-        x = ResizeAttrsT()
-        x._UnPack(resizeAttrs)
+    def InitFromObj(cls, spaceToDepthAttrs):
+        x = SpaceToDepthAttrsT()
+        x._UnPack(spaceToDepthAttrs)
         return x
 
-    # ResizeAttrsT
-    def _UnPack(self, resizeAttrs):
-        if resizeAttrs is None:
+    # SpaceToDepthAttrsT
+    def _UnPack(self, spaceToDepthAttrs):
+        if spaceToDepthAttrs is None:
             return
-        self.mode = resizeAttrs.Mode()
-        self.coordMode = resizeAttrs.CoordMode()
-        self.nearestMode = resizeAttrs.NearestMode()
+        self.blockSize = spaceToDepthAttrs.BlockSize()
 
-    # ResizeAttrsT
+    # SpaceToDepthAttrsT
     def Pack(self, builder):
-        ResizeAttrsStart(builder)
-        ResizeAttrsAddMode(builder, self.mode)
-        ResizeAttrsAddCoordMode(builder, self.coordMode)
-        ResizeAttrsAddNearestMode(builder, self.nearestMode)
-        resizeAttrs = ResizeAttrsEnd(builder)
-        return resizeAttrs
+        SpaceToDepthAttrsStart(builder)
+        SpaceToDepthAttrsAddBlockSize(builder, self.blockSize)
+        spaceToDepthAttrs = SpaceToDepthAttrsEnd(builder)
+        return spaceToDepthAttrs
 
 
-class ScatterElementsAttrs(object):
+class SplitAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = ScatterElementsAttrs()
+        x = SplitAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsScatterElementsAttrs(cls, buf, offset=0):
+    def GetRootAsSplitAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def ScatterElementsAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def SplitAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # ScatterElementsAttrs
+    # SplitAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # ScatterElementsAttrs
+    # SplitAttrs
     def Axis(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
             return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
         return 0
 
-    # ScatterElementsAttrs
-    def Reduction(self):
+    # SplitAttrs
+    def NumOutputs(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
-        return 0
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return None
 
-def ScatterElementsAttrsStart(builder):
+def SplitAttrsStart(builder):
     builder.StartObject(2)
 
-def ScatterElementsAttrsAddAxis(builder, axis):
+def SplitAttrsAddAxis(builder, axis):
     builder.PrependInt32Slot(0, axis, 0)
 
-def ScatterElementsAttrsAddReduction(builder, reduction):
-    builder.PrependUint8Slot(1, reduction, 0)
+def SplitAttrsAddNumOutputs(builder, numOutputs):
+    builder.PrependInt32Slot(1, numOutputs, None)
 
-def ScatterElementsAttrsEnd(builder):
+def SplitAttrsEnd(builder):
     return builder.EndObject()
 
 
+try:
+    from typing import Optional
+except:
+    pass
 
-class ScatterElementsAttrsT(object):
+class SplitAttrsT(object):
 
-    # ScatterElementsAttrsT
+    # SplitAttrsT
     def __init__(
         self,
         axis = 0,
-        reduction = 0,
+        numOutputs = None,
     ):
         self.axis = axis  # type: int
-        self.reduction = reduction  # type: int
+        self.numOutputs = numOutputs  # type: Optional[int]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        scatterElementsAttrs = ScatterElementsAttrs()
-        scatterElementsAttrs.Init(buf, pos)
-        return cls.InitFromObj(scatterElementsAttrs)
+        splitAttrs = SplitAttrs()
+        splitAttrs.Init(buf, pos)
+        return cls.InitFromObj(splitAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -5392,81 +9000,93 @@ class ScatterElementsAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, scatterElementsAttrs):
-        x = ScatterElementsAttrsT()
-        x._UnPack(scatterElementsAttrs)
+    def InitFromObj(cls, splitAttrs):
+        x = SplitAttrsT()
+        x._UnPack(splitAttrs)
         return x
 
-    # ScatterElementsAttrsT
-    def _UnPack(self, scatterElementsAttrs):
-        if scatterElementsAttrs is None:
+    # SplitAttrsT
+    def _UnPack(self, splitAttrs):
+        if splitAttrs is None:
             return
-        self.axis = scatterElementsAttrs.Axis()
-        self.reduction = scatterElementsAttrs.Reduction()
+        self.axis = splitAttrs.Axis()
+        self.numOutputs = splitAttrs.NumOutputs()
 
-    # ScatterElementsAttrsT
+    # SplitAttrsT
     def Pack(self, builder):
-        ScatterElementsAttrsStart(builder)
-        ScatterElementsAttrsAddAxis(builder, self.axis)
-        ScatterElementsAttrsAddReduction(builder, self.reduction)
-        scatterElementsAttrs = ScatterElementsAttrsEnd(builder)
-        return scatterElementsAttrs
+        SplitAttrsStart(builder)
+        SplitAttrsAddAxis(builder, self.axis)
+        SplitAttrsAddNumOutputs(builder, self.numOutputs)
+        splitAttrs = SplitAttrsEnd(builder)
+        return splitAttrs
 
 
-class ScatterNDAttrs(object):
+class SplitToSequenceAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = ScatterNDAttrs()
+        x = SplitToSequenceAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsScatterNDAttrs(cls, buf, offset=0):
+    def GetRootAsSplitToSequenceAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def ScatterNDAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def SplitToSequenceAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # ScatterNDAttrs
+    # SplitToSequenceAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # ScatterNDAttrs
-    def Reduction(self):
+    # SplitToSequenceAttrs
+    def Axis(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
         return 0
 
-def ScatterNDAttrsStart(builder):
-    builder.StartObject(1)
+    # SplitToSequenceAttrs
+    def KeepDims(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return True
 
-def ScatterNDAttrsAddReduction(builder, reduction):
-    builder.PrependUint8Slot(0, reduction, 0)
+def SplitToSequenceAttrsStart(builder):
+    builder.StartObject(2)
 
-def ScatterNDAttrsEnd(builder):
+def SplitToSequenceAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, 0)
+
+def SplitToSequenceAttrsAddKeepDims(builder, keepDims):
+    builder.PrependBoolSlot(1, keepDims, 1)
+
+def SplitToSequenceAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class ScatterNDAttrsT(object):
+class SplitToSequenceAttrsT(object):
 
-    # ScatterNDAttrsT
+    # SplitToSequenceAttrsT
     def __init__(
         self,
-        reduction = 0,
+        axis = 0,
+        keepDims = True,
     ):
-        self.reduction = reduction  # type: int
+        self.axis = axis  # type: int
+        self.keepDims = keepDims  # type: bool
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        scatterNdattrs = ScatterNDAttrs()
-        scatterNdattrs.Init(buf, pos)
-        return cls.InitFromObj(scatterNdattrs)
+        splitToSequenceAttrs = SplitToSequenceAttrs()
+        splitToSequenceAttrs.Init(buf, pos)
+        return cls.InitFromObj(splitToSequenceAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -5474,83 +9094,81 @@ class ScatterNDAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, scatterNdattrs):
-        x = ScatterNDAttrsT()
-        x._UnPack(scatterNdattrs)
+    def InitFromObj(cls, splitToSequenceAttrs):
+        x = SplitToSequenceAttrsT()
+        x._UnPack(splitToSequenceAttrs)
         return x
 
-    # ScatterNDAttrsT
-    def _UnPack(self, scatterNdattrs):
-        if scatterNdattrs is None:
+    # SplitToSequenceAttrsT
+    def _UnPack(self, splitToSequenceAttrs):
+        if splitToSequenceAttrs is None:
             return
-        self.reduction = scatterNdattrs.Reduction()
+        self.axis = splitToSequenceAttrs.Axis()
+        self.keepDims = splitToSequenceAttrs.KeepDims()
 
-    # ScatterNDAttrsT
+    # SplitToSequenceAttrsT
     def Pack(self, builder):
-        ScatterNDAttrsStart(builder)
-        ScatterNDAttrsAddReduction(builder, self.reduction)
-        scatterNdattrs = ScatterNDAttrsEnd(builder)
-        return scatterNdattrs
+        SplitToSequenceAttrsStart(builder)
+        SplitToSequenceAttrsAddAxis(builder, self.axis)
+        SplitToSequenceAttrsAddKeepDims(builder, self.keepDims)
+        splitToSequenceAttrs = SplitToSequenceAttrsEnd(builder)
+        return splitToSequenceAttrs
 
 
-class SequenceEmptyAttrs(object):
+class STFTAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = SequenceEmptyAttrs()
+        x = STFTAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsSequenceEmptyAttrs(cls, buf, offset=0):
+    def GetRootAsSTFTAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def SequenceEmptyAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def STFTAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # SequenceEmptyAttrs
+    # STFTAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # SequenceEmptyAttrs
-    def Dtype(self):
+    # STFTAttrs
+    def Onesided(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Uint8Flags, o + self._tab.Pos)
-        return None
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return True
 
-def SequenceEmptyAttrsStart(builder):
+def STFTAttrsStart(builder):
     builder.StartObject(1)
 
-def SequenceEmptyAttrsAddDtype(builder, dtype):
-    builder.PrependUint8Slot(0, dtype, None)
+def STFTAttrsAddOnesided(builder, onesided):
+    builder.PrependBoolSlot(0, onesided, 1)
 
-def SequenceEmptyAttrsEnd(builder):
+def STFTAttrsEnd(builder):
     return builder.EndObject()
 
 
-try:
-    from typing import Optional
-except:
-    pass
 
-class SequenceEmptyAttrsT(object):
+class STFTAttrsT(object):
 
-    # SequenceEmptyAttrsT
+    # STFTAttrsT
     def __init__(
         self,
-        dtype = None,
+        onesided = True,
     ):
-        self.dtype = dtype  # type: Optional[int]
+        self.onesided = onesided  # type: bool
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        sequenceEmptyAttrs = SequenceEmptyAttrs()
-        sequenceEmptyAttrs.Init(buf, pos)
-        return cls.InitFromObj(sequenceEmptyAttrs)
+        stftattrs = STFTAttrs()
+        stftattrs.Init(buf, pos)
+        return cls.InitFromObj(stftattrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -5558,95 +9176,79 @@ class SequenceEmptyAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, sequenceEmptyAttrs):
-        x = SequenceEmptyAttrsT()
-        x._UnPack(sequenceEmptyAttrs)
+    def InitFromObj(cls, stftattrs):
+        x = STFTAttrsT()
+        x._UnPack(stftattrs)
         return x
 
-    # SequenceEmptyAttrsT
-    def _UnPack(self, sequenceEmptyAttrs):
-        if sequenceEmptyAttrs is None:
+    # STFTAttrsT
+    def _UnPack(self, stftattrs):
+        if stftattrs is None:
             return
-        self.dtype = sequenceEmptyAttrs.Dtype()
+        self.onesided = stftattrs.Onesided()
 
-    # SequenceEmptyAttrsT
+    # STFTAttrsT
     def Pack(self, builder):
-        SequenceEmptyAttrsStart(builder)
-        SequenceEmptyAttrsAddDtype(builder, self.dtype)
-        sequenceEmptyAttrs = SequenceEmptyAttrsEnd(builder)
-        return sequenceEmptyAttrs
+        STFTAttrsStart(builder)
+        STFTAttrsAddOnesided(builder, self.onesided)
+        stftattrs = STFTAttrsEnd(builder)
+        return stftattrs
 
 
-class ShapeAttrs(object):
+class WindowAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = ShapeAttrs()
+        x = WindowAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsShapeAttrs(cls, buf, offset=0):
+    def GetRootAsWindowAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def ShapeAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def WindowAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # ShapeAttrs
+    # WindowAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # ShapeAttrs
-    def Start(self):
+    # WindowAttrs
+    def Periodic(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
-        return None
-
-    # ShapeAttrs
-    def End(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
-        if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
-        return None
-
-def ShapeAttrsStart(builder):
-    builder.StartObject(2)
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
 
-def ShapeAttrsAddStart(builder, start):
-    builder.PrependInt32Slot(0, start, None)
+def WindowAttrsStart(builder):
+    builder.StartObject(1)
 
-def ShapeAttrsAddEnd(builder, end):
-    builder.PrependInt32Slot(1, end, None)
+def WindowAttrsAddPeriodic(builder, periodic):
+    builder.PrependBoolSlot(0, periodic, 0)
 
-def ShapeAttrsEnd(builder):
+def WindowAttrsEnd(builder):
     return builder.EndObject()
 
 
-try:
-    from typing import Optional
-except:
-    pass
 
-class ShapeAttrsT(object):
+class WindowAttrsT(object):
 
-    # ShapeAttrsT
+    # WindowAttrsT
     def __init__(
         self,
-        start = None,
-        end = None,
+        periodic = False,
     ):
-        self.start = start  # type: Optional[int]
-        self.end = end  # type: Optional[int]
+        self.periodic = periodic  # type: bool
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        shapeAttrs = ShapeAttrs()
-        shapeAttrs.Init(buf, pos)
-        return cls.InitFromObj(shapeAttrs)
+        windowAttrs = WindowAttrs()
+        windowAttrs.Init(buf, pos)
+        return cls.InitFromObj(windowAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -5654,81 +9256,79 @@ class ShapeAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, shapeAttrs):
-        x = ShapeAttrsT()
-        x._UnPack(shapeAttrs)
+    def InitFromObj(cls, windowAttrs):
+        x = WindowAttrsT()
+        x._UnPack(windowAttrs)
         return x
 
-    # ShapeAttrsT
-    def _UnPack(self, shapeAttrs):
-        if shapeAttrs is None:
+    # WindowAttrsT
+    def _UnPack(self, windowAttrs):
+        if windowAttrs is None:
             return
-        self.start = shapeAttrs.Start()
-        self.end = shapeAttrs.End()
+        self.periodic = windowAttrs.Periodic()
 
-    # ShapeAttrsT
+    # WindowAttrsT
     def Pack(self, builder):
-        ShapeAttrsStart(builder)
-        ShapeAttrsAddStart(builder, self.start)
-        ShapeAttrsAddEnd(builder, self.end)
-        shapeAttrs = ShapeAttrsEnd(builder)
-        return shapeAttrs
+        WindowAttrsStart(builder)
+        WindowAttrsAddPeriodic(builder, self.periodic)
+        windowAttrs = WindowAttrsEnd(builder)
+        return windowAttrs
 
 
-class SoftmaxAttrs(object):
+class SwishAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = SoftmaxAttrs()
+        x = SwishAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsSoftmaxAttrs(cls, buf, offset=0):
+    def GetRootAsSwishAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def SoftmaxAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def SwishAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # SoftmaxAttrs
+    # SwishAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # SoftmaxAttrs
-    def Axis(self):
+    # SwishAttrs
+    def Beta(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
-        return 0
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
 
-def SoftmaxAttrsStart(builder):
+def SwishAttrsStart(builder):
     builder.StartObject(1)
 
-def SoftmaxAttrsAddAxis(builder, axis):
-    builder.PrependInt32Slot(0, axis, 0)
+def SwishAttrsAddBeta(builder, beta):
+    builder.PrependFloat32Slot(0, beta, 0.0)
 
-def SoftmaxAttrsEnd(builder):
+def SwishAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class SoftmaxAttrsT(object):
+class SwishAttrsT(object):
 
-    # SoftmaxAttrsT
+    # SwishAttrsT
     def __init__(
         self,
-        axis = 0,
+        beta = 0.0,
     ):
-        self.axis = axis  # type: int
+        self.beta = beta  # type: float
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        softmaxAttrs = SoftmaxAttrs()
-        softmaxAttrs.Init(buf, pos)
-        return cls.InitFromObj(softmaxAttrs)
+        swishAttrs = SwishAttrs()
+        swishAttrs.Init(buf, pos)
+        return cls.InitFromObj(swishAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -5736,95 +9336,79 @@ class SoftmaxAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, softmaxAttrs):
-        x = SoftmaxAttrsT()
-        x._UnPack(softmaxAttrs)
+    def InitFromObj(cls, swishAttrs):
+        x = SwishAttrsT()
+        x._UnPack(swishAttrs)
         return x
 
-    # SoftmaxAttrsT
-    def _UnPack(self, softmaxAttrs):
-        if softmaxAttrs is None:
+    # SwishAttrsT
+    def _UnPack(self, swishAttrs):
+        if swishAttrs is None:
             return
-        self.axis = softmaxAttrs.Axis()
+        self.beta = swishAttrs.Beta()
 
-    # SoftmaxAttrsT
+    # SwishAttrsT
     def Pack(self, builder):
-        SoftmaxAttrsStart(builder)
-        SoftmaxAttrsAddAxis(builder, self.axis)
-        softmaxAttrs = SoftmaxAttrsEnd(builder)
-        return softmaxAttrs
+        SwishAttrsStart(builder)
+        SwishAttrsAddBeta(builder, self.beta)
+        swishAttrs = SwishAttrsEnd(builder)
+        return swishAttrs
 
 
-class SplitAttrs(object):
+class ThresholdedReluAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = SplitAttrs()
+        x = ThresholdedReluAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsSplitAttrs(cls, buf, offset=0):
+    def GetRootAsThresholdedReluAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def SplitAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def ThresholdedReluAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # SplitAttrs
+    # ThresholdedReluAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # SplitAttrs
-    def Axis(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
-        if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
-        return 0
-
-    # SplitAttrs
-    def NumOutputs(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
-        if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
-        return None
-
-def SplitAttrsStart(builder):
-    builder.StartObject(2)
+    # ThresholdedReluAttrs
+    def Alpha(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Float32Flags, o + self._tab.Pos)
+        return 0.0
 
-def SplitAttrsAddAxis(builder, axis):
-    builder.PrependInt32Slot(0, axis, 0)
+def ThresholdedReluAttrsStart(builder):
+    builder.StartObject(1)
 
-def SplitAttrsAddNumOutputs(builder, numOutputs):
-    builder.PrependInt32Slot(1, numOutputs, None)
+def ThresholdedReluAttrsAddAlpha(builder, alpha):
+    builder.PrependFloat32Slot(0, alpha, 0.0)
 
-def SplitAttrsEnd(builder):
+def ThresholdedReluAttrsEnd(builder):
     return builder.EndObject()
 
 
-try:
-    from typing import Optional
-except:
-    pass
 
-class SplitAttrsT(object):
+class ThresholdedReluAttrsT(object):
 
-    # SplitAttrsT
+    # ThresholdedReluAttrsT
     def __init__(
         self,
-        axis = 0,
-        numOutputs = None,
+        alpha = 0.0,
     ):
-        self.axis = axis  # type: int
-        self.numOutputs = numOutputs  # type: Optional[int]
+        self.alpha = alpha  # type: float
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        splitAttrs = SplitAttrs()
-        splitAttrs.Init(buf, pos)
-        return cls.InitFromObj(splitAttrs)
+        thresholdedReluAttrs = ThresholdedReluAttrs()
+        thresholdedReluAttrs.Init(buf, pos)
+        return cls.InitFromObj(thresholdedReluAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -5832,93 +9416,103 @@ class SplitAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, splitAttrs):
-        x = SplitAttrsT()
-        x._UnPack(splitAttrs)
+    def InitFromObj(cls, thresholdedReluAttrs):
+        x = ThresholdedReluAttrsT()
+        x._UnPack(thresholdedReluAttrs)
         return x
 
-    # SplitAttrsT
-    def _UnPack(self, splitAttrs):
-        if splitAttrs is None:
+    # ThresholdedReluAttrsT
+    def _UnPack(self, thresholdedReluAttrs):
+        if thresholdedReluAttrs is None:
             return
-        self.axis = splitAttrs.Axis()
-        self.numOutputs = splitAttrs.NumOutputs()
+        self.alpha = thresholdedReluAttrs.Alpha()
 
-    # SplitAttrsT
+    # ThresholdedReluAttrsT
     def Pack(self, builder):
-        SplitAttrsStart(builder)
-        SplitAttrsAddAxis(builder, self.axis)
-        SplitAttrsAddNumOutputs(builder, self.numOutputs)
-        splitAttrs = SplitAttrsEnd(builder)
-        return splitAttrs
+        ThresholdedReluAttrsStart(builder)
+        ThresholdedReluAttrsAddAlpha(builder, self.alpha)
+        thresholdedReluAttrs = ThresholdedReluAttrsEnd(builder)
+        return thresholdedReluAttrs
 
 
-class SplitToSequenceAttrs(object):
+class TopKAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = SplitToSequenceAttrs()
+        x = TopKAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsSplitToSequenceAttrs(cls, buf, offset=0):
+    def GetRootAsTopKAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def SplitToSequenceAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def TopKAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # SplitToSequenceAttrs
+    # TopKAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # SplitToSequenceAttrs
+    # TopKAttrs
     def Axis(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
             return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
         return 0
 
-    # SplitToSequenceAttrs
-    def KeepDims(self):
+    # TopKAttrs
+    def Largest(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
             return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
-        return True
+        return False
 
-def SplitToSequenceAttrsStart(builder):
-    builder.StartObject(2)
+    # TopKAttrs
+    def Sorted(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
 
-def SplitToSequenceAttrsAddAxis(builder, axis):
+def TopKAttrsStart(builder):
+    builder.StartObject(3)
+
+def TopKAttrsAddAxis(builder, axis):
     builder.PrependInt32Slot(0, axis, 0)
 
-def SplitToSequenceAttrsAddKeepDims(builder, keepDims):
-    builder.PrependBoolSlot(1, keepDims, 1)
+def TopKAttrsAddLargest(builder, largest):
+    builder.PrependBoolSlot(1, largest, 0)
 
-def SplitToSequenceAttrsEnd(builder):
+def TopKAttrsAddSorted(builder, sorted):
+    builder.PrependBoolSlot(2, sorted, 0)
+
+def TopKAttrsEnd(builder):
     return builder.EndObject()
 
 
 
-class SplitToSequenceAttrsT(object):
+class TopKAttrsT(object):
 
-    # SplitToSequenceAttrsT
+    # TopKAttrsT
     def __init__(
         self,
         axis = 0,
-        keepDims = True,
+        largest = False,
+        sorted = False,
     ):
         self.axis = axis  # type: int
-        self.keepDims = keepDims  # type: bool
+        self.largest = largest  # type: bool
+        self.sorted = sorted  # type: bool
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        splitToSequenceAttrs = SplitToSequenceAttrs()
-        splitToSequenceAttrs.Init(buf, pos)
-        return cls.InitFromObj(splitToSequenceAttrs)
+        topKattrs = TopKAttrs()
+        topKattrs.Init(buf, pos)
+        return cls.InitFromObj(topKattrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -5926,81 +9520,122 @@ class SplitToSequenceAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, splitToSequenceAttrs):
-        x = SplitToSequenceAttrsT()
-        x._UnPack(splitToSequenceAttrs)
+    def InitFromObj(cls, topKattrs):
+        x = TopKAttrsT()
+        x._UnPack(topKattrs)
         return x
 
-    # SplitToSequenceAttrsT
-    def _UnPack(self, splitToSequenceAttrs):
-        if splitToSequenceAttrs is None:
+    # TopKAttrsT
+    def _UnPack(self, topKattrs):
+        if topKattrs is None:
             return
-        self.axis = splitToSequenceAttrs.Axis()
-        self.keepDims = splitToSequenceAttrs.KeepDims()
+        self.axis = topKattrs.Axis()
+        self.largest = topKattrs.Largest()
+        self.sorted = topKattrs.Sorted()
 
-    # SplitToSequenceAttrsT
+    # TopKAttrsT
     def Pack(self, builder):
-        SplitToSequenceAttrsStart(builder)
-        SplitToSequenceAttrsAddAxis(builder, self.axis)
-        SplitToSequenceAttrsAddKeepDims(builder, self.keepDims)
-        splitToSequenceAttrs = SplitToSequenceAttrsEnd(builder)
-        return splitToSequenceAttrs
+        TopKAttrsStart(builder)
+        TopKAttrsAddAxis(builder, self.axis)
+        TopKAttrsAddLargest(builder, self.largest)
+        TopKAttrsAddSorted(builder, self.sorted)
+        topKattrs = TopKAttrsEnd(builder)
+        return topKattrs
 
 
-class STFTAttrs(object):
+class InputTransform(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = STFTAttrs()
+        x = InputTransform()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsSTFTAttrs(cls, buf, offset=0):
+    def GetRootAsInputTransform(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def STFTAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def InputTransformBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # STFTAttrs
+    # InputTransform
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # STFTAttrs
-    def Onesided(self):
+    # InputTransform
+    def InputIndex(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
-        return True
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
 
-def STFTAttrsStart(builder):
-    builder.StartObject(1)
+    # InputTransform
+    def Perm(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            a = self._tab.Vector(o)
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, a + flatbuffers.number_types.UOffsetTFlags.py_type(j * 4))
+        return 0
 
-def STFTAttrsAddOnesided(builder, onesided):
-    builder.PrependBoolSlot(0, onesided, 1)
+    # InputTransform
+    def PermAsNumpy(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.GetVectorAsNumpy(flatbuffers.number_types.Uint32Flags, o)
+        return 0
 
-def STFTAttrsEnd(builder):
+    # InputTransform
+    def PermLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # InputTransform
+    def PermIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        return o == 0
+
+def InputTransformStart(builder):
+    builder.StartObject(2)
+
+def InputTransformAddInputIndex(builder, inputIndex):
+    builder.PrependUint32Slot(0, inputIndex, 0)
+
+def InputTransformAddPerm(builder, perm):
+    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(perm), 0)
+
+def InputTransformStartPermVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def InputTransformEnd(builder):
     return builder.EndObject()
 
 
+try:
+    from typing import List
+except:
+    pass
 
-class STFTAttrsT(object):
+class InputTransformT(object):
 
-    # STFTAttrsT
+    # InputTransformT
     def __init__(
         self,
-        onesided = True,
+        inputIndex = 0,
+        perm = None,
     ):
-        self.onesided = onesided  # type: bool
+        self.inputIndex = inputIndex  # type: int
+        self.perm = perm  # type: Optional[List[int]]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        stftattrs = STFTAttrs()
-        stftattrs.Init(buf, pos)
-        return cls.InitFromObj(stftattrs)
+        inputTransform = InputTransform()
+        inputTransform.Init(buf, pos)
+        return cls.InitFromObj(inputTransform)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -6008,103 +9643,135 @@ class STFTAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, stftattrs):
-        x = STFTAttrsT()
-        x._UnPack(stftattrs)
+    def InitFromObj(cls, inputTransform):
+        x = InputTransformT()
+        x._UnPack(inputTransform)
         return x
 
-    # STFTAttrsT
-    def _UnPack(self, stftattrs):
-        if stftattrs is None:
+    # InputTransformT
+    def _UnPack(self, inputTransform):
+        if inputTransform is None:
             return
-        self.onesided = stftattrs.Onesided()
+        self.inputIndex = inputTransform.InputIndex()
+        if not inputTransform.PermIsNone():
+            if np is None:
+                self.perm = []
+                for i in range(inputTransform.PermLength()):
+                    self.perm.append(inputTransform.Perm(i))
+            else:
+                self.perm = inputTransform.PermAsNumpy()
 
-    # STFTAttrsT
+    # InputTransformT
     def Pack(self, builder):
-        STFTAttrsStart(builder)
-        STFTAttrsAddOnesided(builder, self.onesided)
-        stftattrs = STFTAttrsEnd(builder)
-        return stftattrs
+        if self.perm is not None:
+            if np is not None and type(self.perm) is np.ndarray:
+                perm = builder.CreateNumpyVector(self.perm)
+            else:
+                InputTransformStartPermVector(builder, len(self.perm))
+                for i in reversed(range(len(self.perm))):
+                    builder.PrependUint32(self.perm[i])
+                perm = builder.EndVector()
+        InputTransformStart(builder)
+        InputTransformAddInputIndex(builder, self.inputIndex)
+        if self.perm is not None:
+            InputTransformAddPerm(builder, perm)
+        inputTransform = InputTransformEnd(builder)
+        return inputTransform
 
 
-class TopKAttrs(object):
+class TransformInputsAttrs(object):
     __slots__ = ['_tab']
 
     @classmethod
     def GetRootAs(cls, buf, offset=0):
         n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
-        x = TopKAttrs()
+        x = TransformInputsAttrs()
         x.Init(buf, n + offset)
         return x
 
     @classmethod
-    def GetRootAsTopKAttrs(cls, buf, offset=0):
+    def GetRootAsTransformInputsAttrs(cls, buf, offset=0):
         """This method is deprecated. Please switch to GetRootAs."""
         return cls.GetRootAs(buf, offset)
     @classmethod
-    def TopKAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+    def TransformInputsAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
         return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
 
-    # TopKAttrs
+    # TransformInputsAttrs
     def Init(self, buf, pos):
         self._tab = flatbuffers.table.Table(buf, pos)
 
-    # TopKAttrs
-    def Axis(self):
+    # TransformInputsAttrs
+    def Op(self):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
         if o != 0:
-            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
-        return 0
+            x = self._tab.Indirect(o + self._tab.Pos)
+            obj = OperatorNode()
+            obj.Init(self._tab.Bytes, x)
+            return obj
+        return None
 
-    # TopKAttrs
-    def Largest(self):
+    # TransformInputsAttrs
+    def Transforms(self, j):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
-        return False
+            x = self._tab.Vector(o)
+            x += flatbuffers.number_types.UOffsetTFlags.py_type(j) * 4
+            x = self._tab.Indirect(x)
+            obj = InputTransform()
+            obj.Init(self._tab.Bytes, x)
+            return obj
+        return None
 
-    # TopKAttrs
-    def Sorted(self):
-        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+    # TransformInputsAttrs
+    def TransformsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
         if o != 0:
-            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
-        return False
+            return self._tab.VectorLen(o)
+        return 0
 
-def TopKAttrsStart(builder):
-    builder.StartObject(3)
+    # TransformInputsAttrs
+    def TransformsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        return o == 0
 
-def TopKAttrsAddAxis(builder, axis):
-    builder.PrependInt32Slot(0, axis, 0)
+def TransformInputsAttrsStart(builder):
+    builder.StartObject(2)
 
-def TopKAttrsAddLargest(builder, largest):
-    builder.PrependBoolSlot(1, largest, 0)
+def TransformInputsAttrsAddOp(builder, op):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(op), 0)
 
-def TopKAttrsAddSorted(builder, sorted):
-    builder.PrependBoolSlot(2, sorted, 0)
+def TransformInputsAttrsAddTransforms(builder, transforms):
+    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(transforms), 0)
 
-def TopKAttrsEnd(builder):
+def TransformInputsAttrsStartTransformsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
+def TransformInputsAttrsEnd(builder):
     return builder.EndObject()
 
 
+try:
+    from typing import List, Optional
+except:
+    pass
 
-class TopKAttrsT(object):
+class TransformInputsAttrsT(object):
 
-    # TopKAttrsT
+    # TransformInputsAttrsT
     def __init__(
         self,
-        axis = 0,
-        largest = False,
-        sorted = False,
+        op = None,
+        transforms = None,
     ):
-        self.axis = axis  # type: int
-        self.largest = largest  # type: bool
-        self.sorted = sorted  # type: bool
+        self.op = op  # type: Optional[OperatorNodeT]
+        self.transforms = transforms  # type: Optional[List[InputTransformT]]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
-        topKattrs = TopKAttrs()
-        topKattrs.Init(buf, pos)
-        return cls.InitFromObj(topKattrs)
+        transformInputsAttrs = TransformInputsAttrs()
+        transformInputsAttrs.Init(buf, pos)
+        return cls.InitFromObj(transformInputsAttrs)
 
     @classmethod
     def InitFromPackedBuf(cls, buf, pos=0):
@@ -6112,27 +9779,45 @@ class TopKAttrsT(object):
         return cls.InitFromBuf(buf, pos+n)
 
     @classmethod
-    def InitFromObj(cls, topKattrs):
-        x = TopKAttrsT()
-        x._UnPack(topKattrs)
+    def InitFromObj(cls, transformInputsAttrs):
+        x = TransformInputsAttrsT()
+        x._UnPack(transformInputsAttrs)
         return x
 
-    # TopKAttrsT
-    def _UnPack(self, topKattrs):
-        if topKattrs is None:
+    # TransformInputsAttrsT
+    def _UnPack(self, transformInputsAttrs):
+        if transformInputsAttrs is None:
             return
-        self.axis = topKattrs.Axis()
-        self.largest = topKattrs.Largest()
-        self.sorted = topKattrs.Sorted()
+        if transformInputsAttrs.Op() is not None:
+            self.op = OperatorNodeT.InitFromObj(transformInputsAttrs.Op())
+        if not transformInputsAttrs.TransformsIsNone():
+            self.transforms = []
+            for i in range(transformInputsAttrs.TransformsLength()):
+                if transformInputsAttrs.Transforms(i) is None:
+                    self.transforms.append(None)
+                else:
+                    inputTransform_ = InputTransformT.InitFromObj(transformInputsAttrs.Transforms(i))
+                    self.transforms.append(inputTransform_)
 
-    # TopKAttrsT
+    # TransformInputsAttrsT
     def Pack(self, builder):
-        TopKAttrsStart(builder)
-        TopKAttrsAddAxis(builder, self.axis)
-        TopKAttrsAddLargest(builder, self.largest)
-        TopKAttrsAddSorted(builder, self.sorted)
-        topKattrs = TopKAttrsEnd(builder)
-        return topKattrs
+        if self.op is not None:
+            op = self.op.Pack(builder)
+        if self.transforms is not None:
+            transformslist = []
+            for i in range(len(self.transforms)):
+                transformslist.append(self.transforms[i].Pack(builder))
+            TransformInputsAttrsStartTransformsVector(builder, len(self.transforms))
+            for i in reversed(range(len(self.transforms))):
+                builder.PrependUOffsetTRelative(transformslist[i])
+            transforms = builder.EndVector()
+        TransformInputsAttrsStart(builder)
+        if self.op is not None:
+            TransformInputsAttrsAddOp(builder, op)
+        if self.transforms is not None:
+            TransformInputsAttrsAddTransforms(builder, transforms)
+        transformInputsAttrs = TransformInputsAttrsEnd(builder)
+        return transformInputsAttrs
 
 
 class TransposeAttrs(object):
@@ -6257,6 +9942,104 @@ class TransposeAttrsT(object):
         return transposeAttrs
 
 
+class UniqueAttrs(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = UniqueAttrs()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsUniqueAttrs(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def UniqueAttrsBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # UniqueAttrs
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # UniqueAttrs
+    def Axis(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Int32Flags, o + self._tab.Pos)
+        return None
+
+    # UniqueAttrs
+    def Sorted(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return bool(self._tab.Get(flatbuffers.number_types.BoolFlags, o + self._tab.Pos))
+        return False
+
+def UniqueAttrsStart(builder):
+    builder.StartObject(2)
+
+def UniqueAttrsAddAxis(builder, axis):
+    builder.PrependInt32Slot(0, axis, None)
+
+def UniqueAttrsAddSorted(builder, sorted):
+    builder.PrependBoolSlot(1, sorted, 0)
+
+def UniqueAttrsEnd(builder):
+    return builder.EndObject()
+
+
+try:
+    from typing import Optional
+except:
+    pass
+
+class UniqueAttrsT(object):
+
+    # UniqueAttrsT
+    def __init__(
+        self,
+        axis = None,
+        sorted = False,
+    ):
+        self.axis = axis  # type: Optional[int]
+        self.sorted = sorted  # type: bool
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        uniqueAttrs = UniqueAttrs()
+        uniqueAttrs.Init(buf, pos)
+        return cls.InitFromObj(uniqueAttrs)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, uniqueAttrs):
+        x = UniqueAttrsT()
+        x._UnPack(uniqueAttrs)
+        return x
+
+    # UniqueAttrsT
+    def _UnPack(self, uniqueAttrs):
+        if uniqueAttrs is None:
+            return
+        self.axis = uniqueAttrs.Axis()
+        self.sorted = uniqueAttrs.Sorted()
+
+    # UniqueAttrsT
+    def Pack(self, builder):
+        UniqueAttrsStart(builder)
+        UniqueAttrsAddAxis(builder, self.axis)
+        UniqueAttrsAddSorted(builder, self.sorted)
+        uniqueAttrs = UniqueAttrsEnd(builder)
+        return uniqueAttrs
+
+
 class TriluAttrs(object):
     __slots__ = ['_tab']
 
@@ -6483,7 +10266,7 @@ class OperatorNodeT(object):
     ):
         self.type = type  # type: int
         self.attrsType = attrsType  # type: int
-        self.attrs = attrs  # type: Union[None, 'ArgMaxAttrsT', 'AveragePoolAttrsT', 'BatchNormalizationAttrsT', 'CastAttrsT', 'ConcatAttrsT', 'ConstantOfShapeAttrsT', 'ConvAttrsT', 'ConvTransposeAttrsT', 'FlattenAttrsT', 'GatherAttrsT', 'GemmAttrsT', 'GRUAttrsT', 'LeakyReluAttrsT', 'LSTMAttrsT', 'MaxPoolAttrsT', 'ReduceMeanAttrsT', 'ReshapeAttrsT', 'ResizeAttrsT', 'SplitAttrsT', 'SoftmaxAttrsT', 'TransposeAttrsT', 'ModAttrsT', 'ScatterElementsAttrsT', 'OneHotAttrsT', 'TopKAttrsT', 'HardSigmoidAttrsT', 'TriluAttrsT', 'ScatterNDAttrsT', 'NonMaxSuppressionAttrsT', 'LayerNormalizationAttrsT', 'RandomUniformAttrsT', 'EluAttrsT', 'RandomUniformLikeAttrsT', 'RandomNormalAttrsT', 'RandomNormalLikeAttrsT', 'GatherNDAttrsT', 'GeluAttrsT', 'EinsumAttrsT', 'IfAttrsT', 'PadAttrsT', 'DequantizeLinearAttrsT', 'QuantizeLinearAttrsT', 'DepthToSpaceAttrsT', 'CastLikeAttrsT', 'ShapeAttrsT', 'DropoutAttrsT', 'EyeLikeAttrsT', 'IsInfAttrsT', 'LoopAttrsT', 'SequenceEmptyAttrsT', 'ConcatFromSequenceAttrsT', 'SplitToSequenceAttrsT', 'GridSampleAttrsT', 'STFTAttrsT']
+        self.attrs = attrs  # type: Union[None, 'ArgMaxAttrsT', 'AveragePoolAttrsT', 'BatchNormalizationAttrsT', 'CastAttrsT', 'ConcatAttrsT', 'ConstantOfShapeAttrsT', 'ConvAttrsT', 'ConvTransposeAttrsT', 'FlattenAttrsT', 'GatherAttrsT', 'GemmAttrsT', 'GRUAttrsT', 'LeakyReluAttrsT', 'LSTMAttrsT', 'MaxPoolAttrsT', 'ReduceMeanAttrsT', 'ReshapeAttrsT', 'ResizeAttrsT', 'SplitAttrsT', 'SoftmaxAttrsT', 'TransposeAttrsT', 'ModAttrsT', 'ScatterElementsAttrsT', 'OneHotAttrsT', 'TopKAttrsT', 'HardSigmoidAttrsT', 'TriluAttrsT', 'ScatterNDAttrsT', 'NonMaxSuppressionAttrsT', 'LayerNormalizationAttrsT', 'RandomUniformAttrsT', 'EluAttrsT', 'RandomUniformLikeAttrsT', 'RandomNormalAttrsT', 'RandomNormalLikeAttrsT', 'GatherNDAttrsT', 'GeluAttrsT', 'EinsumAttrsT', 'IfAttrsT', 'PadAttrsT', 'DequantizeLinearAttrsT', 'QuantizeLinearAttrsT', 'DepthToSpaceAttrsT', 'CastLikeAttrsT', 'ShapeAttrsT', 'DropoutAttrsT', 'EyeLikeAttrsT', 'IsInfAttrsT', 'LoopAttrsT', 'SequenceEmptyAttrsT', 'ConcatFromSequenceAttrsT', 'SplitToSequenceAttrsT', 'GridSampleAttrsT', 'STFTAttrsT', 'ComputeShapeAttrsT', 'FusedMatMulAttrsT', 'GroupedQueryAttentionMatMulAttrsT', 'RepeatInterleaveAttrsT', 'SwishAttrsT', 'TransformInputsAttrsT', 'AttentionAttrsT', 'GroupQueryAttentionAttrsT', 'RotaryEmbeddingAttrsT', 'SpaceToDepthAttrsT', 'DFTAttrsT', 'WindowAttrsT', 'GroupNormalizationAttrsT', 'Col2ImAttrsT', 'RoiAlignAttrsT', 'LpPoolAttrsT', 'MeanVarianceNormalizationAttrsT', 'LpNormalizationAttrsT', 'BitShiftAttrsT', 'CompressAttrsT', 'ReverseSequenceAttrsT', 'SeluAttrsT', 'ShrinkAttrsT', 'UniqueAttrsT', 'CeluAttrsT', 'HardmaxAttrsT', 'ThresholdedReluAttrsT', 'MatMulNBitsAttrsT']
         self.inputs = inputs  # type: Optional[List[int]]
         self.outputs = outputs  # type: Optional[List[int]]
 
@@ -7583,6 +11366,145 @@ class NodeT(object):
         return node
 
 
+class PrepackedWeight(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = PrepackedWeight()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsPrepackedWeight(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def PrepackedWeightBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # PrepackedWeight
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # PrepackedWeight
+    def Node(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint32Flags, o + self._tab.Pos)
+        return 0
+
+    # PrepackedWeight
+    def Kernel(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(6))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+    # PrepackedWeight
+    def Dtype(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(8))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint16Flags, o + self._tab.Pos)
+        return 0
+
+    # PrepackedWeight
+    def DataOffset(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint64Flags, o + self._tab.Pos)
+        return 0
+
+    # PrepackedWeight
+    def DataLen(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.Get(flatbuffers.number_types.Uint64Flags, o + self._tab.Pos)
+        return 0
+
+def PrepackedWeightStart(builder):
+    builder.StartObject(5)
+
+def PrepackedWeightAddNode(builder, node):
+    builder.PrependUint32Slot(0, node, 0)
+
+def PrepackedWeightAddKernel(builder, kernel):
+    builder.PrependUOffsetTRelativeSlot(1, flatbuffers.number_types.UOffsetTFlags.py_type(kernel), 0)
+
+def PrepackedWeightAddDtype(builder, dtype):
+    builder.PrependUint16Slot(2, dtype, 0)
+
+def PrepackedWeightAddDataOffset(builder, dataOffset):
+    builder.PrependUint64Slot(3, dataOffset, 0)
+
+def PrepackedWeightAddDataLen(builder, dataLen):
+    builder.PrependUint64Slot(4, dataLen, 0)
+
+def PrepackedWeightEnd(builder):
+    return builder.EndObject()
+
+
+
+class PrepackedWeightT(object):
+
+    # PrepackedWeightT
+    def __init__(
+        self,
+        node = 0,
+        kernel = None,
+        dtype = 0,
+        dataOffset = 0,
+        dataLen = 0,
+    ):
+        self.node = node  # type: int
+        self.kernel = kernel  # type: Optional[str]
+        self.dtype = dtype  # type: int
+        self.dataOffset = dataOffset  # type: int
+        self.dataLen = dataLen  # type: int
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        prepackedWeight = PrepackedWeight()
+        prepackedWeight.Init(buf, pos)
+        return cls.InitFromObj(prepackedWeight)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, prepackedWeight):
+        x = PrepackedWeightT()
+        x._UnPack(prepackedWeight)
+        return x
+
+    # PrepackedWeightT
+    def _UnPack(self, prepackedWeight):
+        if prepackedWeight is None:
+            return
+        self.node = prepackedWeight.Node()
+        self.kernel = prepackedWeight.Kernel()
+        self.dtype = prepackedWeight.Dtype()
+        self.dataOffset = prepackedWeight.DataOffset()
+        self.dataLen = prepackedWeight.DataLen()
+
+    # PrepackedWeightT
+    def Pack(self, builder):
+        if self.kernel is not None:
+            kernel = builder.CreateString(self.kernel)
+        PrepackedWeightStart(builder)
+        PrepackedWeightAddNode(builder, self.node)
+        if self.kernel is not None:
+            PrepackedWeightAddKernel(builder, kernel)
+        PrepackedWeightAddDtype(builder, self.dtype)
+        PrepackedWeightAddDataOffset(builder, self.dataOffset)
+        PrepackedWeightAddDataLen(builder, self.dataLen)
+        prepackedWeight = PrepackedWeightEnd(builder)
+        return prepackedWeight
+
+
 class Graph(object):
     __slots__ = ['_tab']
 
@@ -7710,8 +11632,32 @@ class Graph(object):
         o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
         return o == 0
 
+    # Graph
+    def PrepackedWeights(self, j):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            x = self._tab.Vector(o)
+            x += flatbuffers.number_types.UOffsetTFlags.py_type(j) * 4
+            x = self._tab.Indirect(x)
+            obj = PrepackedWeight()
+            obj.Init(self._tab.Bytes, x)
+            return obj
+        return None
+
+    # Graph
+    def PrepackedWeightsLength(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        if o != 0:
+            return self._tab.VectorLen(o)
+        return 0
+
+    # Graph
+    def PrepackedWeightsIsNone(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(12))
+        return o == 0
+
 def GraphStart(builder):
-    builder.StartObject(4)
+    builder.StartObject(5)
 
 def GraphAddNodes(builder, nodes):
     builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(nodes), 0)
@@ -7737,6 +11683,12 @@ def GraphAddCaptures(builder, captures):
 def GraphStartCapturesVector(builder, numElems):
     return builder.StartVector(4, numElems, 4)
 
+def GraphAddPrepackedWeights(builder, prepackedWeights):
+    builder.PrependUOffsetTRelativeSlot(4, flatbuffers.number_types.UOffsetTFlags.py_type(prepackedWeights), 0)
+
+def GraphStartPrepackedWeightsVector(builder, numElems):
+    return builder.StartVector(4, numElems, 4)
+
 def GraphEnd(builder):
     return builder.EndObject()
 
@@ -7755,11 +11707,13 @@ class GraphT(object):
         inputs = None,
         outputs = None,
         captures = None,
+        prepackedWeights = None,
     ):
         self.nodes = nodes  # type: Optional[List[NodeT]]
         self.inputs = inputs  # type: Optional[List[int]]
         self.outputs = outputs  # type: Optional[List[int]]
         self.captures = captures  # type: Optional[List[int]]
+        self.prepackedWeights = prepackedWeights  # type: Optional[List[PrepackedWeightT]]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
@@ -7811,6 +11765,14 @@ class GraphT(object):
                     self.captures.append(graph.Captures(i))
             else:
                 self.captures = graph.CapturesAsNumpy()
+        if not graph.PrepackedWeightsIsNone():
+            self.prepackedWeights = []
+            for i in range(graph.PrepackedWeightsLength()):
+                if graph.PrepackedWeights(i) is None:
+                    self.prepackedWeights.append(None)
+                else:
+                    prepackedWeight_ = PrepackedWeightT.InitFromObj(graph.PrepackedWeights(i))
+                    self.prepackedWeights.append(prepackedWeight_)
 
     # GraphT
     def Pack(self, builder):
@@ -7846,6 +11808,14 @@ class GraphT(object):
                 for i in reversed(range(len(self.captures))):
                     builder.PrependUint32(self.captures[i])
                 captures = builder.EndVector()
+        if self.prepackedWeights is not None:
+            prepackedWeightslist = []
+            for i in range(len(self.prepackedWeights)):
+                prepackedWeightslist.append(self.prepackedWeights[i].Pack(builder))
+            GraphStartPrepackedWeightsVector(builder, len(self.prepackedWeights))
+            for i in reversed(range(len(self.prepackedWeights))):
+                builder.PrependUOffsetTRelative(prepackedWeightslist[i])
+            prepackedWeights = builder.EndVector()
         GraphStart(builder)
         if self.nodes is not None:
             GraphAddNodes(builder, nodes)
@@ -7855,6 +11825,8 @@ class GraphT(object):
             GraphAddOutputs(builder, outputs)
         if self.captures is not None:
             GraphAddCaptures(builder, captures)
+        if self.prepackedWeights is not None:
+            GraphAddPrepackedWeights(builder, prepackedWeights)
         graph = GraphEnd(builder)
         return graph
 
@@ -8061,6 +12033,89 @@ class MetadataT(object):
         return metadata
 
 
+class OptimizationInfo(object):
+    __slots__ = ['_tab']
+
+    @classmethod
+    def GetRootAs(cls, buf, offset=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, offset)
+        x = OptimizationInfo()
+        x.Init(buf, n + offset)
+        return x
+
+    @classmethod
+    def GetRootAsOptimizationInfo(cls, buf, offset=0):
+        """This method is deprecated. Please switch to GetRootAs."""
+        return cls.GetRootAs(buf, offset)
+    @classmethod
+    def OptimizationInfoBufferHasIdentifier(cls, buf, offset, size_prefixed=False):
+        return flatbuffers.util.BufferHasIdentifier(buf, offset, b"\x52\x54\x45\x4E", size_prefixed=size_prefixed)
+
+    # OptimizationInfo
+    def Init(self, buf, pos):
+        self._tab = flatbuffers.table.Table(buf, pos)
+
+    # OptimizationInfo
+    def RtenVersion(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(4))
+        if o != 0:
+            return self._tab.String(o + self._tab.Pos)
+        return None
+
+def OptimizationInfoStart(builder):
+    builder.StartObject(1)
+
+def OptimizationInfoAddRtenVersion(builder, rtenVersion):
+    builder.PrependUOffsetTRelativeSlot(0, flatbuffers.number_types.UOffsetTFlags.py_type(rtenVersion), 0)
+
+def OptimizationInfoEnd(builder):
+    return builder.EndObject()
+
+
+
+class OptimizationInfoT(object):
+
+    # OptimizationInfoT
+    def __init__(
+        self,
+        rtenVersion = None,
+    ):
+        self.rtenVersion = rtenVersion  # type: Optional[str]
+
+    @classmethod
+    def InitFromBuf(cls, buf, pos):
+        optimizationInfo = OptimizationInfo()
+        optimizationInfo.Init(buf, pos)
+        return cls.InitFromObj(optimizationInfo)
+
+    @classmethod
+    def InitFromPackedBuf(cls, buf, pos=0):
+        n = flatbuffers.encode.Get(flatbuffers.packer.uoffset, buf, pos)
+        return cls.InitFromBuf(buf, pos+n)
+
+    @classmethod
+    def InitFromObj(cls, optimizationInfo):
+        x = OptimizationInfoT()
+        x._UnPack(optimizationInfo)
+        return x
+
+    # OptimizationInfoT
+    def _UnPack(self, optimizationInfo):
+        if optimizationInfo is None:
+            return
+        self.rtenVersion = optimizationInfo.RtenVersion()
+
+    # OptimizationInfoT
+    def Pack(self, builder):
+        if self.rtenVersion is not None:
+            rtenVersion = builder.CreateString(self.rtenVersion)
+        OptimizationInfoStart(builder)
+        if self.rtenVersion is not None:
+            OptimizationInfoAddRtenVersion(builder, rtenVersion)
+        optimizationInfo = OptimizationInfoEnd(builder)
+        return optimizationInfo
+
+
 class Model(object):
     __slots__ = ['_tab']
 
@@ -8110,8 +12165,18 @@ class Model(object):
             return obj
         return None
 
+    # Model
+    def Optimization(self):
+        o = flatbuffers.number_types.UOffsetTFlags.py_type(self._tab.Offset(10))
+        if o != 0:
+            x = self._tab.Indirect(o + self._tab.Pos)
+            obj = OptimizationInfo()
+            obj.Init(self._tab.Bytes, x)
+            return obj
+        return None
+
 def ModelStart(builder):
-    builder.StartObject(3)
+    builder.StartObject(4)
 
 def ModelAddSchemaVersion(builder, schemaVersion):
     builder.PrependInt32Slot(0, schemaVersion, 0)
@@ -8122,6 +12187,9 @@ def ModelAddGraph(builder, graph):
 def ModelAddMetadata(builder, metadata):
     builder.PrependUOffsetTRelativeSlot(2, flatbuffers.number_types.UOffsetTFlags.py_type(metadata), 0)
 
+def ModelAddOptimization(builder, optimization):
+    builder.PrependUOffsetTRelativeSlot(3, flatbuffers.number_types.UOffsetTFlags.py_type(optimization), 0)
+
 def ModelEnd(builder):
     return builder.EndObject()
 
@@ -8139,10 +12207,12 @@ class ModelT(object):
         schemaVersion = 0,
         graph = None,
         metadata = None,
+        optimization = None,
     ):
         self.schemaVersion = schemaVersion  # type: int
         self.graph = graph  # type: Optional[GraphT]
         self.metadata = metadata  # type: Optional[MetadataT]
+        self.optimization = optimization  # type: Optional[OptimizationInfoT]
 
     @classmethod
     def InitFromBuf(cls, buf, pos):
@@ -8170,6 +12240,8 @@ class ModelT(object):
             self.graph = GraphT.InitFromObj(model.Graph())
         if model.Metadata() is not None:
             self.metadata = MetadataT.InitFromObj(model.Metadata())
+        if model.Optimization() is not None:
+            self.optimization = OptimizationInfoT.InitFromObj(model.Optimization())
 
     # ModelT
     def Pack(self, builder):
@@ -8177,12 +12249,16 @@ class ModelT(object):
             graph = self.graph.Pack(builder)
         if self.metadata is not None:
             metadata = self.metadata.Pack(builder)
+        if self.optimization is not None:
+            optimization = self.optimization.Pack(builder)
         ModelStart(builder)
         ModelAddSchemaVersion(builder, self.schemaVersion)
         if self.graph is not None:
             ModelAddGraph(builder, graph)
         if self.metadata is not None:
             ModelAddMetadata(builder, metadata)
+        if self.optimization is not None:
+            ModelAddOptimization(builder, optimization)
         model = ModelEnd(builder)
         return model
 
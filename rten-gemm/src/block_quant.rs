@@ -72,16 +72,16 @@ impl BlockQuantizedGemm {
             return Err(GemmError::KSizeMismatch);
         }
 
-        if rhs.bits != 4 {
-            return Err(GemmError::QuantBitsNotSupported);
-        }
-
         // Handle K=0 case here so we can rely on K > 0 in the kernels.
         if lhs_k == 0 {
             out.fill(MaybeUninit::new(0.));
             return Ok(unsafe { out.assume_init() });
         }
 
+        // The SIMD kernels support 4-bit elements with the default zero point.
+        // Other formats use a slower generic implementation.
+        let use_simd = rhs.bits == 4 && rhs.has_default_zero_points();
+
         enum LhsRow<'a> {
             Float(&'a [f32]),
             Quant {
@@ -93,7 +93,7 @@ impl BlockQuantizedGemm {
         let lhs = lhs.to_contiguous();
 
         let lhs_quant: Option<(NdTensor<i8, 4>, NdTensor<f32, 3>)> =
-            if matches!(self.mode, ComputeMode::Int8) && m == 1 {
+            if matches!(self.mode, ComputeMode::Int8) && m == 1 && use_simd {
                 Some(quantize(lhs.view(), rhs.elements_per_block()))
             } else {
                 None
@@ -125,6 +125,9 @@ impl BlockQuantizedGemm {
                             };
                             op.dispatch();
                         }
+                        LhsRow::Float(lhs) if !use_simd => {
+                            vec_dot_matrix_generic(lhs, rhs.slice(col_range), out_row_chunk);
+                        }
                         LhsRow::Float(lhs) => {
                             let op = VecDotMatrix {
                                 lhs,
@@ -161,6 +164,43 @@ fn is_int8_compute_optimized() -> bool {
     HasInt8Simd.dispatch()
 }
 
+/// Compute the product between an f32 vector and a block-quantized matrix.
+///
+/// This handles any element size and zero points, and is used for matrices
+/// which the SIMD kernels do not support.
+fn vec_dot_matrix_generic<'a>(
+    lhs: &[f32],
+    rhs: BlockQuantizedMatrix<f32>,
+    out: &'a mut [MaybeUninit<f32>],
+) -> &'a mut [f32] {
+    let block_size = rhs.elements_per_block();
+    let block_bytes = rhs.bytes_per_block();
+    let n_blocks = rhs.blocks_per_column();
+
+    for (col, out) in out.iter_mut().enumerate() {
+        let col_data = rhs.column_data(col, 0, n_blocks).unwrap();
+        let col_scales = rhs.column_scales(col, 0, n_blocks).unwrap();
+
+        let mut acc = 0.;
+        for (block, (lhs_block, rhs_block)) in lhs
+            .chunks_exact(block_size)
+            .zip(col_data.chunks_exact(block_bytes))
+            .enumerate()
+        {
+            let zero_point = rhs.zero_point(col, block);
+            let mut block_acc = 0.;
+            for (i, x) in lhs_block.iter().enumerate() {
+                let y = unpack_element(rhs_block, rhs.bits, i) as f32 - zero_point;
+                block_acc += x * y;
+            }
+            acc += block_acc * col_scales[block];
+        }
+        out.write(acc);
+    }
+
+    unsafe { out.assume_init() }
+}
+
 /// SIMD operation which computes the product between an f32 vector and a 4-bit
 /// quantized matrix.
 struct VecDotMatrix<'a> {
@@ -645,6 +685,26 @@ impl<'a> SimdInt8DotOp for VecDotMatrixQuant<'a> {
     }
 }
 
+/// Zero points for a [`BlockQuantizedMatrix`].
+#[derive(Copy, Clone)]
+pub enum BlockQuantizedZeroPoints<'a, T> {
+    /// Zero points packed using the same number of bits as the quantized
+    /// elements, with shape (N, ceil(k_blocks * bits / 8)).
+    Packed(Contiguous<NdTensorView<'a, u8, 2>>),
+
+    /// Zero points with the same type as the scales, with shape (N, k_blocks).
+    Float(Contiguous<NdTensorView<'a, T, 2>>),
+}
+
+impl<T> BlockQuantizedZeroPoints<'_, T> {
+    fn slice(&self, col_range: Range<usize>) -> Self {
+        match self {
+            Self::Packed(zp) => Self::Packed(Contiguous::new(zp.slice(col_range)).unwrap()),
+            Self::Float(zp) => Self::Float(Contiguous::new(zp.slice(col_range)).unwrap()),
+        }
+    }
+}
+
 /// Matrix which is quantized into blocks along the K dimension.
 ///
 /// The data layout and supported bit/block sizes follow ONNX Runtime's MatMulNBits
@@ -657,6 +717,10 @@ pub struct BlockQuantizedMatrix<'a, T> {
     /// Scales of shape (N, k_blocks)
     scales: Contiguous<NdTensorView<'a, T, 2>>,
 
+    /// Zero points for each block. If not set, the default zero point for
+    /// the element size is used. See [`nbit_zero_point`].
+    zero_points: Option<BlockQuantizedZeroPoints<'a, T>>,
+
     /// Bits per quantized element.
     ///
     /// Must be a divisor of `block_size * 8`.
@@ -672,7 +736,10 @@ impl<'a, T: Copy> BlockQuantizedMatrix<'a, T> {
     /// `quant` is the block-quantized input with shape (cols, k_blocks,
     /// block_size). `scales` are the per-block scales with shape (cols,
     /// k_blocks). `bits` specifies the number of bits per element in each
-    /// block.
+    /// block and must be between 2 and 8.
+    ///
+    /// Elements within each block are packed into a little-endian bitstream,
+    /// so the first element occupies the lowest bits of the first byte.
     ///
     /// The number of elements per quantized block must be a power of 2 of at
     /// least `MIN_BLOCK_SIZE`.
@@ -681,17 +748,17 @@ impl<'a, T: Copy> BlockQuantizedMatrix<'a, T> {
         scales: Contiguous<NdTensorView<'a, T, 2>>,
         bits: u8,
     ) -> Result<Self, BlockQuantizedError> {
-        // ONNX Runtime currently supports 2, 4 or 8 bits per element. These
-        // values have the convenient property that a byte is a whole number
-        // of elements. We only support 4 bits for the moment.
-        if !matches!(bits, 4 | 8) {
+        if !(2..=8).contains(&bits) {
             return Err(BlockQuantizedError::UnsupportedElementSize);
         }
-        let n_elem = 8 / bits;
 
         let [_batch, _k_blocks, block_bytes] = quant.shape();
 
-        let block_size = block_bytes * n_elem as usize;
+        let block_bits = block_bytes * 8;
+        if !block_bits.is_multiple_of(bits as usize) {
+            return Err(BlockQuantizedError::UnsupportedBlockSize);
+        }
+        let block_size = block_bits / bits as usize;
         if !block_size.is_power_of_two() || block_size < Self::MIN_BLOCK_SIZE {
             return Err(BlockQuantizedError::UnsupportedBlockSize);
         }
@@ -699,10 +766,41 @@ impl<'a, T: Copy> BlockQuantizedMatrix<'a, T> {
         Ok(Self {
             quant,
             scales,
+            zero_points: None,
             bits,
         })
     }
 
+    /// Set the zero points for each block.
+    ///
+    /// Dequantized values are computed as `(x - zero_point) * scale`.
+    pub fn with_zero_points(
+        mut self,
+        zero_points: BlockQuantizedZeroPoints<'a, T>,
+    ) -> Result<Self, BlockQuantizedError> {
+        let [cols, k_blocks, _] = self.quant.shape();
+        let expected_shape = match zero_points {
+            BlockQuantizedZeroPoints::Packed(_) => {
+                [cols, (k_blocks * self.bits as usize).div_ceil(8)]
+            }
+            BlockQuantizedZeroPoints::Float(_) => [cols, k_blocks],
+        };
+        let shape = match &zero_points {
+            BlockQuantizedZeroPoints::Packed(zp) => zp.shape(),
+            BlockQuantizedZeroPoints::Float(zp) => zp.shape(),
+        };
+        if shape != expected_shape {
+            return Err(BlockQuantizedError::WrongZeroPointSize);
+        }
+        self.zero_points = Some(zero_points);
+        Ok(self)
+    }
+
+    /// Return true if this matrix uses the default zero point for all blocks.
+    pub(crate) fn has_default_zero_points(&self) -> bool {
+        self.zero_points.is_none()
+    }
+
     /// Return the number of rows in the dequantized matrix.
     pub fn rows(&self) -> usize {
         self.blocks_per_column() * self.elements_per_block()
@@ -717,7 +815,8 @@ impl<'a, T: Copy> BlockQuantizedMatrix<'a, T> {
     pub(crate) fn slice(&self, col_range: Range<usize>) -> BlockQuantizedMatrix<'a, T> {
         BlockQuantizedMatrix {
             quant: Contiguous::new(self.quant.slice(col_range.clone())).unwrap(),
-            scales: Contiguous::new(self.scales.slice(col_range)).unwrap(),
+            scales: Contiguous::new(self.scales.slice(col_range.clone())).unwrap(),
+            zero_points: self.zero_points.map(|zp| zp.slice(col_range)),
             bits: self.bits,
         }
     }
@@ -732,7 +831,8 @@ impl<'a, T: Copy> BlockQuantizedMatrix<'a, T> {
         self.quant.size(1)
     }
 
-    pub(crate) fn elements_per_block(&self) -> usize {
+    /// Return the number of elements in each quantized block.
+    pub fn elements_per_block(&self) -> usize {
         (self.bytes_per_block() * 8) / self.bits as usize
     }
 
@@ -770,6 +870,79 @@ impl<'a, T: Copy> BlockQuantizedMatrix<'a, T> {
     }
 }
 
+impl BlockQuantizedMatrix<'_, f32> {
+    /// Return the zero point for a block in a column.
+    #[inline]
+    pub(crate) fn zero_point(&self, col: usize, block: usize) -> f32 {
+        match &self.zero_points {
+            None => nbit_zero_point(self.bits) as f32,
+            Some(BlockQuantizedZeroPoints::Packed(zp)) => {
+                let row_len = zp.size(1);
+                let row = &zp.data()[col * row_len..][..row_len];
+                unpack_element(row, self.bits, block) as f32
+            }
+            Some(BlockQuantizedZeroPoints::Float(zp)) => zp[[col, block]],
+        }
+    }
+
+    /// Dequantize the matrix into a row-major matrix with shape (K, N).
+    ///
+    /// `block_index` optionally specifies which block's scale and zero point
+    /// to use for each row. This corresponds to the `g_idx` input of ONNX
+    /// Runtime's MatMulNBits operator. If not specified, row `k` uses block
+    /// `k / elements_per_block`.
+    ///
+    /// Panics if `block_index` does not have one entry per row or contains
+    /// an out-of-range block index.
+    pub fn dequantize(&self, block_index: Option<&[usize]>) -> NdTensor<f32, 2> {
+        let rows = self.rows();
+        let cols = self.cols();
+        let n_blocks = self.blocks_per_column();
+        let block_size = self.elements_per_block();
+        let block_bytes = self.bytes_per_block();
+
+        if let Some(block_index) = block_index {
+            assert_eq!(block_index.len(), rows);
+            assert!(block_index.iter().all(|&block| block < n_blocks));
+        }
+
+        let mut out = NdTensor::zeros([rows, cols]);
+        for col in 0..cols {
+            let col_data = self.column_data(col, 0, n_blocks).unwrap();
+            let col_scales = self.column_scales(col, 0, n_blocks).unwrap();
+
+            for k in 0..rows {
+                let data_block = k / block_size;
+                let block = block_index.map(|bi| bi[k]).unwrap_or(data_block);
+                let elem = unpack_element(
+                    &col_data[data_block * block_bytes..][..block_bytes],
+                    self.bits,
+                    k % block_size,
+                );
+                out[[k, col]] = (elem as f32 - self.zero_point(col, block)) * col_scales[block];
+            }
+        }
+        out
+    }
+}
+
+/// Extract the `index`th `bits`-bit element from a packed bitstream.
+///
+/// Elements are packed starting from the least significant bit of the first
+/// byte and may span byte boundaries if `bits` is not a divisor of 8.
+#[inline(always)]
+pub(crate) fn unpack_element(data: &[u8], bits: u8, index: usize) -> u8 {
+    let bit_offset = index * bits as usize;
+    let byte = bit_offset / 8;
+    let shift = bit_offset % 8;
+
+    let mut val = (data[byte] >> shift) as u16;
+    if shift + bits as usize > 8 {
+        val |= (data[byte + 1] as u16) << (8 - shift);
+    }
+    (val & ((1 << bits) - 1)) as u8
+}
+
 /// Return the default zero point for n-bit quantization.
 ///
 /// This is an i16 because the maximum value is 128 (when n_bits=8) and the
@@ -807,9 +980,10 @@ mod tests {
     use rten_testing::TestCases;
 
     use super::{
-        BlockQuantizedGemm, BlockQuantizedMatrix, ComputeMode, nbit_zero_point, pack_4bit_elements,
-        quantize,
+        BlockQuantizedGemm, BlockQuantizedMatrix, BlockQuantizedZeroPoints, ComputeMode,
+        nbit_zero_point, pack_4bit_elements, quantize,
     };
+    use crate::errors::BlockQuantizedError;
 
     fn reference_gemm_f32_with_block_quantized_rhs(
         lhs: NdTensorView<f32, 2>,
@@ -844,6 +1018,63 @@ mod tests {
         out
     }
 
+    /// Read the `index`th `bits`-bit element from a little-endian bitstream,
+    /// one bit at a time.
+    fn read_bits(data: &[u8], bits: u8, index: usize) -> u8 {
+        let start = index * bits as usize;
+        (0..bits as usize).fold(0, |acc, i| {
+            let bit = (data[(start + i) / 8] >> ((start + i) % 8)) & 1;
+            acc | (bit << i)
+        })
+    }
+
+    /// Zero points used by [`reference_dequantize`].
+    enum RefZeroPoints<'a> {
+        Default,
+        Packed(NdTensorView<'a, u8, 2>),
+        Float(NdTensorView<'a, f32, 2>),
+    }
+
+    /// Dequantize an n-bit block-quantized matrix into a (K, N) matrix.
+    fn reference_dequantize(
+        rhs: NdTensorView<u8, 3>,
+        rhs_scales: NdTensorView<f32, 2>,
+        bits: u8,
+        zero_points: RefZeroPoints,
+        block_index: Option<&[usize]>,
+    ) -> NdTensor<f32, 2> {
+        let [n, k_blocks, block_bytes] = rhs.shape();
+        let block_size = block_bytes * 8 / bits as usize;
+        let k = k_blocks * block_size;
+
+        let mut out = NdTensor::zeros([k, n]);
+        for col in 0..n {
+            for ki in 0..k {
+                let data_block = ki / block_size;
+                let block = block_index.map(|bi| bi[ki]).unwrap_or(data_block);
+                let block_data = rhs.slice((col, data_block)).to_vec();
+                let elem = read_bits(&block_data, bits, ki % block_size) as f32;
+                let zero_point = match &zero_points {
+                    RefZeroPoints::Default => nbit_zero_point(bits) as f32,
+                    RefZeroPoints::Packed(zp) => {
+                        read_bits(&zp.slice(col).to_vec(), bits, block) as f32
+                    }
+                    RefZeroPoints::Float(zp) => zp[[col, block]],
+                };
+                out[[ki, col]] = (elem - zero_point) * rhs_scales[[col, block]];
+            }
+        }
+        out
+    }
+
+    fn reference_matmul(lhs: NdTensorView<f32, 2>, rhs: NdTensorView<f32, 2>) -> NdTensor<f32, 2> {
+        let [m, k] = lhs.shape();
+        let [_k, n] = rhs.shape();
+        NdTensor::from_fn([m, n], |[row, col]| {
+            (0..k).map(|ki| lhs[[row, ki]] * rhs[[ki, col]]).sum()
+        })
+    }
+
     #[test]
     fn test_block_quantized_matrix() {
         let zero_point = nbit_zero_point(4) as i8;
@@ -1063,4 +1294,235 @@ mod tests {
             }
         });
     }
+
+    #[test]
+    fn test_block_quantized_matrix_new() {
+        let scales = NdTensor::<f32, 2>::zeros([2, 1]);
+
+        #[derive(Clone, Debug)]
+        struct Case {
+            block_bytes: usize,
+            bits: u8,
+            expected: Result<usize, BlockQuantizedError>,
+        }
+
+        let cases = [
+            Case {
+                block_bytes: 4,
+                bits: 2,
+                expected: Ok(16),
+            },
+            Case {
+                block_bytes: 12,
+                bits: 3,
+                expected: Ok(32),
+            },
+            Case {
+                block_bytes: 16,
+                bits: 8,
+                expected: Ok(16),
+            },
+            Case {
+                block_bytes: 16,
+                bits: 1,
+                expected: Err(BlockQuantizedError::UnsupportedElementSize),
+            },
+            Case {
+                block_bytes: 16,
+                bits: 9,
+                expected: Err(BlockQuantizedError::UnsupportedElementSize),
+            },
+            // 16 bytes is not a whole number of 3-bit elements.
+            Case {
+                block_bytes: 16,
+                bits: 3,
+                expected: Err(BlockQuantizedError::UnsupportedBlockSize),
+            },
+            // 20 x 8-bit elements is not a power of 2.
+            Case {
+                block_bytes: 20,
+                bits: 8,
+                expected: Err(BlockQuantizedError::UnsupportedBlockSize),
+            },
+        ];
+
+        cases.test_each_clone(|case| {
+            let data = NdTensor::<u8, 3>::zeros([2, 1, case.block_bytes]);
+            let mat = BlockQuantizedMatrix::new(
+                Contiguous::new(data.view()).unwrap(),
+                Contiguous::new(scales.view()).unwrap(),
+                case.bits,
+            );
+            assert_eq!(
+                mat.map(|mat| mat.elements_per_block()).err(),
+                case.expected.err()
+            );
+            if let Ok(block_size) = case.expected {
+                assert_eq!(mat.unwrap().rows(), block_size);
+            }
+        })
+    }
+
+    #[test]
+    fn test_block_quantized_matrix_zero_points_size() {
+        let data = NdTensor::<u8, 3>::zeros([2, 3, 16]);
+        let scales = NdTensor::<f32, 2>::zeros([2, 3]);
+        let mat = BlockQuantizedMatrix::new(
+            Contiguous::new(data.view()).unwrap(),
+            Contiguous::new(scales.view()).unwrap(),
+            4,
+        )
+        .unwrap();
+
+        // 3 blocks x 4 bits packs into 2 bytes per column.
+        let packed_zp = NdTensor::<u8, 2>::zeros([2, 2]);
+        assert!(
+            mat.with_zero_points(BlockQuantizedZeroPoints::Packed(
+                Contiguous::new(packed_zp.view()).unwrap()
+            ))
+            .is_ok()
+        );
+
+        let wrong_packed_zp = NdTensor::<u8, 2>::zeros([2, 3]);
+        assert_eq!(
+            mat.with_zero_points(BlockQuantizedZeroPoints::Packed(
+                Contiguous::new(wrong_packed_zp.view()).unwrap()
+            ))
+            .err(),
+            Some(BlockQuantizedError::WrongZeroPointSize)
+        );
+
+        let float_zp = NdTensor::<f32, 2>::zeros([2, 3]);
+        assert!(
+            mat.with_zero_points(BlockQuantizedZeroPoints::Float(
+                Contiguous::new(float_zp.view()).unwrap()
+            ))
+            .is_ok()
+        );
+    }
+
+    #[derive(Copy, Clone, Debug)]
+    enum ZeroPointKind {
+        Default,
+        Packed,
+        Float,
+    }
+
+    #[test]
+    fn test_block_quantized_gemm_nbits() {
+        #[derive(Clone, Debug)]
+        struct Case {
+            bits: u8,
+            zero_points: ZeroPointKind,
+        }
+
+        let mut cases = Vec::new();
+        for bits in 2..=8 {
+            for zero_points in [
+                ZeroPointKind::Default,
+                ZeroPointKind::Packed,
+                ZeroPointKind::Float,
+            ] {
+                cases.push(Case { bits, zero_points });
+            }
+        }
+
+        cases.test_each_clone(|case| {
+            let Case { bits, zero_points } = case;
+
+            let mut rng = XorShiftRng::new(1234);
+            let n_cols = 5;
+            let n_blocks = 3;
+            let block_size = 32;
+            let block_bytes = block_size * bits as usize / 8;
+
+            let lhs = NdTensor::<f32, 2>::rand([1, n_blocks * block_size], &mut rng);
+            let rhs_data = NdTensor::<u8, 3>::rand([n_cols, n_blocks, block_bytes], &mut rng);
+            let rhs_scales = NdTensor::<f32, 2>::rand([n_cols, n_blocks], &mut rng);
+            let packed_zp =
+                NdTensor::<u8, 2>::rand([n_cols, (n_blocks * bits as usize).div_ceil(8)], &mut rng);
+            let float_zp = NdTensor::<f32, 2>::rand([n_cols, n_blocks], &mut rng);
+
+            let mut bqm = BlockQuantizedMatrix::new(
+                Contiguous::new(rhs_data.view()).unwrap(),
+                Contiguous::new(rhs_scales.view()).unwrap(),
+                bits,
+            )
+            .unwrap();
+            let ref_zero_points = match zero_points {
+                ZeroPointKind::Default => RefZeroPoints::Default,
+                ZeroPointKind::Packed => {
+                    bqm = bqm
+                        .with_zero_points(BlockQuantizedZeroPoints::Packed(
+                            Contiguous::new(packed_zp.view()).unwrap(),
+                        ))
+                        .unwrap();
+                    RefZeroPoints::Packed(packed_zp.view())
+                }
+                ZeroPointKind::Float => {
+                    bqm = bqm
+                        .with_zero_points(BlockQuantizedZeroPoints::Float(
+                            Contiguous::new(float_zp.view()).unwrap(),
+                        ))
+                        .unwrap();
+                    RefZeroPoints::Float(float_zp.view())
+                }
+            };
+
+            let dequantized = reference_dequantize(
+                rhs_data.view(),
+                rhs_scales.view(),
+                bits,
+                ref_zero_points,
+                None,
+            );
+            expect_equal(&bqm.dequantize(None).view(), &dequantized.view()).unwrap();
+
+            let expected = reference_matmul(lhs.view(), dequantized.view());
+
+            let mut out = Vec::with_capacity(n_cols);
+            let result = BlockQuantizedGemm::new()
+                .batched_gemm_uninit(
+                    out.spare_capacity_mut(),
+                    lhs.reshaped([1, lhs.size(0), lhs.size(1)]).view(),
+                    bqm,
+                )
+                .unwrap();
+            let result = NdTensorView::from_data([1, n_cols], &*result);
+
+            expect_equal_with_tolerance(&result, &expected.view(), 1e-3, 1e-4).unwrap();
+        })
+    }
+
+    #[test]
+    fn test_dequantize_block_index() {
+        let mut rng = XorShiftRng::new(1234);
+        let bits = 4;
+        let n_cols = 3;
+        let n_blocks = 2;
+        let block_size = 16;
+
+        let rhs_data = NdTensor::<u8, 3>::rand([n_cols, n_blocks, block_size / 2], &mut rng);
+        let rhs_scales = NdTensor::<f32, 2>::rand([n_cols, n_blocks], &mut rng);
+        let bqm = BlockQuantizedMatrix::new(
+            Contiguous::new(rhs_data.view()).unwrap(),
+            Contiguous::new(rhs_scales.view()).unwrap(),
+            bits,
+        )
+        .unwrap();
+
+        // Alternate rows between blocks, as in models quantized with
+        // activation reordering.
+        let block_index: Vec<usize> = (0..n_blocks * block_size).map(|k| k % 2).collect();
+
+        let expected = reference_dequantize(
+            rhs_data.view(),
+            rhs_scales.view(),
+            bits,
+            RefZeroPoints::Default,
+            Some(&block_index),
+        );
+        let dequantized = bqm.dequantize(Some(&block_index));
+        expect_equal(&dequantized.view(), &expected.view()).unwrap();
+    }
 }
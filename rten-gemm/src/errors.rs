@@ -21,6 +21,9 @@ pub enum GemmError {
     /// The data was packed with different cache blocking parameters than are
     /// currently being used.
     PackedDataBlockingMismatch,
+    /// The packed data does not have the size expected for the matrix
+    /// dimensions.
+    PackedDataSizeMismatch,
     /// Block-quantized inputs are not supported for this data type.
     BlockQuantizedInputNotSupported,
     /// Quantization element size is not supported.
@@ -47,6 +50,9 @@ impl Display for GemmError {
             Self::PackedDataBlockingMismatch => {
                 write!(fmt, "matrix was packed with a different blocking size")
             }
+            Self::PackedDataSizeMismatch => {
+                write!(fmt, "packed data has wrong length for matrix size")
+            }
             Self::BlockQuantizedInputNotSupported => {
                 write!(fmt, "block-quantized inputs not supported for data type")
             }
@@ -69,6 +75,8 @@ pub enum BlockQuantizedError {
     UnsupportedBlockSize,
     /// The number of bits per element is unsupported.
     UnsupportedElementSize,
+    /// The zero points do not have the expected shape for the quantized data.
+    WrongZeroPointSize,
 }
 
 impl Display for BlockQuantizedError {
@@ -76,6 +84,7 @@ impl Display for BlockQuantizedError {
         match self {
             Self::UnsupportedBlockSize => write!(f, "block size is unsupported"),
             Self::UnsupportedElementSize => write!(f, "unsupported bits-per-element"),
+            Self::WrongZeroPointSize => write!(f, "zero point size does not match input"),
         }
     }
 }
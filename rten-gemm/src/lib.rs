@@ -27,7 +27,9 @@ mod packing;
 mod prepack;
 mod tiles;
 
-pub use block_quant::{BlockQuantizedGemm, BlockQuantizedMatrix, ComputeMode};
+pub use block_quant::{
+    BlockQuantizedGemm, BlockQuantizedMatrix, BlockQuantizedZeroPoints, ComputeMode,
+};
 pub use errors::{BlockQuantizedError, GemmError};
 pub use im2col::{ColOffsets, Im2Col, RowOffsets};
 pub use kernels::QuantParams;
@@ -252,6 +254,23 @@ impl<LhsT: GemmInT, RhsT: GemmInT, OutT: GemmOutT> GemmExecutor<LhsT, RhsT, OutT
         prepack::prepack_b(&*self.kernel, alloc, b)
     }
 
+    /// Restore a pre-packed "B" matrix from the data returned by
+    /// [`PackedBMatrix::as_bytes`].
+    ///
+    /// `kernel_name` is the value of [`PackedBMatrix::kernel_name`] for the
+    /// original matrix and `rows` and `cols` are its dimensions. Returns an
+    /// error if the data was packed by a different kernel than the one this
+    /// executor uses, or if the data length does not match.
+    pub fn prepacked_b_from_bytes(
+        &self,
+        kernel_name: &str,
+        rows: usize,
+        cols: usize,
+        data: &[u8],
+    ) -> Result<PackedBMatrix<RhsT>, GemmError> {
+        prepack::packed_b_from_bytes(&*self.kernel, kernel_name, rows, cols, data)
+    }
+
     /// Perform a General Matrix Multiplication ("gemm").
     ///
     /// This computes `output = alpha * (a @ b) + beta * output` where `@` is
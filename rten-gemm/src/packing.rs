@@ -7,7 +7,7 @@ use rten_tensor::storage::Alloc;
 use rten_tensor::{AssumeInit, Matrix, MatrixLayout, Storage};
 
 use super::kernels::PackedLayout;
-use crate::block_quant::{BlockQuantizedMatrix, nbit_zero_point};
+use crate::block_quant::{BlockQuantizedMatrix, nbit_zero_point, unpack_element};
 
 pub mod int8;
 
@@ -256,11 +256,8 @@ impl<'a, const NR: usize> BlockQuantizedMatrixPacker<'a, f32, NR> {
         let n_blocks = rows.len() / block_size;
         let start_block = rows.start / block_size;
 
-        // Only 4-bit elements are supported. With small changes this could
-        // support 2 or 8-bits. ONNX Runtime's implementation only supports 2, 4
-        // or 8 bits.
-        const N_BITS: u8 = 4;
-        const ZERO_POINT: i16 = nbit_zero_point(N_BITS);
+        let n_bits = self.mat.n_bits();
+        let zero_point = nbit_zero_point(n_bits);
 
         // Data for padding and scales if column count is not a multiple of NR.
         //
@@ -269,12 +266,16 @@ impl<'a, const NR: usize> BlockQuantizedMatrixPacker<'a, f32, NR> {
         let mut pad_data: Vec<u8> = Vec::new();
         let mut pad_scales: Vec<f32> = Vec::new();
 
+        let block_bytes = self.mat.bytes_per_block();
+
         if !cols.len().is_multiple_of(NR) {
-            pad_data.resize(block_size * n_blocks, ZERO_POINT as u8);
+            pad_data.resize(block_bytes * n_blocks, 0);
             pad_scales.resize(n_blocks, 0.);
         }
 
-        let block_bytes = self.mat.bytes_per_block();
+        // 4-bit elements with the default zero point are the most common
+        // case, so they get a specialized loop.
+        let is_default_4bit = n_bits == 4 && self.mat.has_default_zero_points();
 
         let mut out = SliceWriter::new(out);
         for panel in 0..n_panels {
@@ -297,23 +298,44 @@ impl<'a, const NR: usize> BlockQuantizedMatrixPacker<'a, f32, NR> {
             for block_idx in 0..n_blocks {
                 let block_scales = scales.map(|bs| *unsafe { bs.get_unchecked(block_idx) });
 
-                for k in 0..block_bytes {
-                    let bytes: [u8; NR] = std::array::from_fn(|c| unsafe {
-                        *data[c].get_unchecked(block_idx * block_bytes + k)
-                    });
+                if is_default_4bit {
+                    for k in 0..block_bytes {
+                        let bytes: [u8; NR] = std::array::from_fn(|c| unsafe {
+                            *data[c].get_unchecked(block_idx * block_bytes + k)
+                        });
 
-                    // First row from low 4 bits.
-                    for c in 0..NR {
-                        let elem = (bytes[c] & 0x0F) as i16 - ZERO_POINT;
-                        let dequant = elem as f32 * block_scales[c];
-                        unsafe { out.write_unchecked(dequant) };
-                    }
+                        // First row from low 4 bits.
+                        for c in 0..NR {
+                            let elem = (bytes[c] & 0x0F) as i16 - zero_point;
+                            let dequant = elem as f32 * block_scales[c];
+                            unsafe { out.write_unchecked(dequant) };
+                        }
 
-                    // Second row from high 4 bits.
-                    for c in 0..NR {
-                        let elem = (bytes[c] >> 4) as i16 - ZERO_POINT;
-                        let dequant = elem as f32 * block_scales[c];
-                        unsafe { out.write_unchecked(dequant) };
+                        // Second row from high 4 bits.
+                        for c in 0..NR {
+                            let elem = (bytes[c] >> 4) as i16 - zero_point;
+                            let dequant = elem as f32 * block_scales[c];
+                            unsafe { out.write_unchecked(dequant) };
+                        }
+                    }
+                } else {
+                    let block_zero_points: [f32; NR] = std::array::from_fn(|c| {
+                        let col = start_col + c;
+                        if col < self.mat.cols() {
+                            self.mat.zero_point(col, start_block + block_idx)
+                        } else {
+                            0.
+                        }
+                    });
+                    let block_data: [&[u8]; NR] =
+                        data.map(|d| &d[block_idx * block_bytes..][..block_bytes]);
+
+                    for k in 0..block_size {
+                        for c in 0..NR {
+                            let elem = unpack_element(block_data[c], n_bits, k) as f32;
+                            let dequant = (elem - block_zero_points[c]) * block_scales[c];
+                            unsafe { out.write_unchecked(dequant) };
+                        }
                     }
                 }
             }
@@ -5,6 +5,7 @@ use rten_base::iter::range_chunks;
 use rten_tensor::storage::Alloc;
 use rten_tensor::{Matrix, MatrixLayout};
 
+use super::kernels::PackedLayout;
 use super::packing::PackingBuffer;
 use super::{GemmError, Kernel, LhsBlock, RhsBlock, depth_block_size};
 
@@ -166,6 +167,21 @@ impl<T> PackedBMatrix<T> {
     pub fn into_vec(self) -> Vec<u32> {
         self.base.data.into_vec()
     }
+
+    /// Return the packed data as bytes.
+    ///
+    /// The data can be restored using
+    /// [`GemmExecutor::prepacked_b_from_bytes`](crate::GemmExecutor::prepacked_b_from_bytes)
+    /// with an executor that uses the same kernel. The layout is specific to
+    /// the kernel and the version of this crate.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.base.data.as_bytes()
+    }
+
+    /// Return the name of the kernel that was used to pack the data.
+    pub fn kernel_name(&self) -> &str {
+        self.base.kernel_name
+    }
 }
 
 /// Prepack a GEMM LHS input for use with a given kernel.
@@ -224,34 +240,88 @@ pub fn prepack_a<A: Alloc, LhsT, RhsT, OutT>(
     }
 }
 
+/// Data layout of a pre-packed GEMM RHS input.
+struct PackedBLayout {
+    depth_block: usize,
+    layout: PackedLayout,
+    tail_layout: Option<PackedLayout>,
+    total_size: usize,
+}
+
+impl PackedBLayout {
+    fn new<LhsT, RhsT, OutT>(
+        kernel: &dyn Kernel<LhsT, RhsT, OutT>,
+        rows: usize,
+        cols: usize,
+    ) -> PackedBLayout {
+        let depth_block = depth_block_size::<RhsT>(rows, None);
+
+        let layout = kernel.packed_b_layout(depth_block, cols, None);
+        let tail_layout = if !rows.is_multiple_of(depth_block) {
+            Some(kernel.packed_b_layout(rows % depth_block, cols, None))
+        } else {
+            None
+        };
+
+        // Require the size to be a multiple of the alignment. This avoids the
+        // need for any gaps between blocks, which would have to be initialized
+        // after packing.
+        assert_eq!(layout.size() % layout.align(), 0);
+
+        let n_blocks = rows / depth_block;
+        let total_size =
+            (n_blocks * layout.size()) + tail_layout.as_ref().map(|l| l.size()).unwrap_or(0);
+
+        PackedBLayout {
+            depth_block,
+            layout,
+            tail_layout,
+            total_size,
+        }
+    }
+
+    /// Create a packed matrix from a buffer filled according to this layout.
+    fn into_packed<LhsT, RhsT, OutT>(
+        self,
+        kernel: &dyn Kernel<LhsT, RhsT, OutT>,
+        data: PackingBuffer,
+        rows: usize,
+        cols: usize,
+    ) -> PackedBMatrix<RhsT> {
+        PackedBMatrix {
+            base: PackedMatrixBase {
+                data,
+                depth_size: rows,
+                nm_size: cols,
+                panel_size: kernel.nr(),
+                depth_block: self.depth_block,
+                panel_stride: self.layout.panel_stride(),
+                tail_panel_stride: self
+                    .tail_layout
+                    .map(|tl| tl.panel_stride())
+                    .unwrap_or(self.layout.panel_stride()),
+                depth_block_stride: self.layout.size(),
+                kernel_name: kernel.name(),
+            },
+            _marker: PhantomData,
+        }
+    }
+}
+
 /// Prepack a GEMM RHS input for use with a given kernel.
 pub fn prepack_b<A: Alloc, LhsT, RhsT, OutT>(
     kernel: &dyn Kernel<LhsT, RhsT, OutT>,
     alloc: A,
     b: Matrix<RhsT>,
 ) -> PackedBMatrix<RhsT> {
-    let depth_block = depth_block_size::<RhsT>(b.rows(), None);
+    let layout = PackedBLayout::new(kernel, b.rows(), b.cols());
+    let total_size = layout.total_size;
 
-    let layout = kernel.packed_b_layout(depth_block, b.cols(), None);
-    let tail_layout = if !b.rows().is_multiple_of(depth_block) {
-        Some(kernel.packed_b_layout(b.rows() % depth_block, b.cols(), None))
-    } else {
-        None
-    };
-
-    // Require the size to be a multiple of the alignment. This avoids the
-    // need for any gaps between blocks, which would have to be initialized
-    // after packing.
-    assert_eq!(layout.size() % layout.align(), 0);
-
-    let n_blocks = b.rows() / depth_block;
-    let total_size =
-        (n_blocks * layout.size()) + tail_layout.as_ref().map(|l| l.size()).unwrap_or(0);
     let mut data = PackingBuffer::new();
-    let uninit_data = data.alloc_in(alloc, total_size, layout.align());
+    let uninit_data = data.alloc_in(alloc, total_size, layout.layout.align());
 
-    for (row_block, block_data) in
-        range_chunks(0..b.rows(), depth_block).zip(uninit_data.chunks_mut(layout.size()))
+    for (row_block, block_data) in range_chunks(0..b.rows(), layout.depth_block)
+        .zip(uninit_data.chunks_mut(layout.layout.size()))
     {
         kernel.pack_b_block(block_data, b, row_block, 0..b.cols(), None);
     }
@@ -261,20 +331,37 @@ pub fn prepack_b<A: Alloc, LhsT, RhsT, OutT>(
         data.set_len(total_size);
     }
 
-    PackedBMatrix {
-        base: PackedMatrixBase {
-            data,
-            depth_size: b.rows(),
-            nm_size: b.cols(),
-            panel_size: kernel.nr(),
-            depth_block,
-            panel_stride: layout.panel_stride(),
-            tail_panel_stride: tail_layout
-                .map(|tl| tl.panel_stride())
-                .unwrap_or(layout.panel_stride()),
-            depth_block_stride: layout.size(),
-            kernel_name: kernel.name(),
-        },
-        _marker: PhantomData,
+    layout.into_packed(kernel, data, b.rows(), b.cols())
+}
+
+/// Restore a GEMM RHS input that was packed for a given kernel from the bytes
+/// returned by [`PackedBMatrix::as_bytes`].
+pub fn packed_b_from_bytes<LhsT, RhsT, OutT>(
+    kernel: &dyn Kernel<LhsT, RhsT, OutT>,
+    kernel_name: &str,
+    rows: usize,
+    cols: usize,
+    bytes: &[u8],
+) -> Result<PackedBMatrix<RhsT>, GemmError> {
+    if kernel_name != kernel.name() {
+        return Err(GemmError::PackedDataKernelMismatch);
+    }
+
+    let layout = PackedBLayout::new(kernel, rows, cols);
+    if bytes.len() != layout.total_size {
+        return Err(GemmError::PackedDataSizeMismatch);
     }
+
+    let mut data = PackingBuffer::new();
+    let uninit_data = data.alloc(layout.total_size, layout.layout.align());
+    for (dst, src) in uninit_data.iter_mut().zip(bytes) {
+        dst.write(*src);
+    }
+
+    // Safety: We initialized `total_size` bytes from `bytes`.
+    unsafe {
+        data.set_len(layout.total_size);
+    }
+
+    Ok(layout.into_packed(kernel, data, rows, cols))
 }
@@ -4,14 +4,14 @@ use std::time::Instant;
 use rten_bench::run_bench;
 use rten_tensor::prelude::*;
 use rten_tensor::rng::XorShiftRng;
-use rten_tensor::test_util::{ApproxEq, expect_equal};
+use rten_tensor::test_util::{ApproxEq, expect_equal, expect_equal_with_tolerance};
 use rten_tensor::{Matrix, MatrixLayout, MatrixMut, NdTensor, NdTensorView, RandomSource};
 use rten_testing::TestCases;
 
 use super::{
-    BiasVector, BlockQuantizedMatrix, ColOffsets, F32KernelType, GemmError, GemmExecutor, GemmInT,
-    GemmInputA, GemmInputB, GemmOptions, GemmOutT, Im2Col, QuantParams, ReducedRangeRng,
-    RowOffsets, WithKernel,
+    BiasVector, BlockQuantizedMatrix, BlockQuantizedZeroPoints, ColOffsets, F32KernelType,
+    GemmError, GemmExecutor, GemmInT, GemmInputA, GemmInputB, GemmOptions, GemmOutT, Im2Col,
+    QuantParams, ReducedRangeRng, RowOffsets, WithKernel,
 };
 
 /// Scale a possibly non-float value by a float.
@@ -790,6 +790,54 @@ fn test_gemm_prepack() {
     })
 }
 
+#[test]
+fn test_prepacked_b_from_bytes() {
+    let mut rng = XorShiftRng::new(1234);
+    let (m, n, k) = (10, 15, DEPTH_BLOCK_SIZE + DEPTH_BLOCK_SIZE / 2);
+    let a = NdTensor::rand([m, k], &mut rng);
+    let b = NdTensor::rand([k, n], &mut rng);
+
+    let gemm = GemmExecutor::<f32>::new();
+    let packed_b = gemm.prepack_b(b.view());
+    let restored_b = gemm
+        .prepacked_b_from_bytes(packed_b.kernel_name(), k, n, packed_b.as_bytes())
+        .unwrap();
+    assert_eq!(restored_b.rows(), k);
+    assert_eq!(restored_b.cols(), n);
+    assert_eq!(restored_b.as_bytes(), packed_b.as_bytes());
+
+    let mut result = NdTensor::zeros([m, n]);
+    gemm.gemm(
+        result.data_mut().unwrap(),
+        GemmInputA::Unpacked(a.view()),
+        GemmInputB::Packed(&restored_b),
+        GemmOptions::default(),
+    )
+    .unwrap();
+
+    let mut expected = NdTensor::zeros([m, n]);
+    gemm.gemm(
+        expected.data_mut().unwrap(),
+        GemmInputA::Unpacked(a.view()),
+        GemmInputB::Unpacked(b.view()),
+        GemmOptions::default(),
+    )
+    .unwrap();
+    expect_equal(&result, &expected).unwrap();
+
+    // Data packed by a different kernel.
+    let err = gemm
+        .prepacked_b_from_bytes("other-kernel", k, n, packed_b.as_bytes())
+        .err();
+    assert_eq!(err, Some(GemmError::PackedDataKernelMismatch));
+
+    // Data that doesn't match the matrix size.
+    let err = gemm
+        .prepacked_b_from_bytes(packed_b.kernel_name(), k + 1, n, packed_b.as_bytes())
+        .err();
+    assert_eq!(err, Some(GemmError::PackedDataSizeMismatch));
+}
+
 // Simplified version of the im2col builder used by convolution code.
 //
 // This builds a mapping between elements of an image and a
@@ -1070,6 +1118,86 @@ fn test_gemm_f32_with_block_quantized_rhs() {
     });
 }
 
+#[test]
+fn test_gemm_f32_with_nbit_block_quantized_rhs() {
+    #[derive(Clone, Debug)]
+    struct Case {
+        n_bits: u8,
+        zero_points: bool,
+    }
+
+    let mut cases = Vec::new();
+    for n_bits in [2, 3, 5, 8] {
+        for zero_points in [false, true] {
+            cases.push(Case {
+                n_bits,
+                zero_points,
+            });
+        }
+    }
+
+    cases.test_each_clone(|case| {
+        let Case {
+            n_bits,
+            zero_points,
+        } = case;
+
+        let [m, n, k] = [4, 17, 64];
+        let block_size = 32;
+        let k_blocks = k / block_size;
+        let block_bytes = block_size * n_bits as usize / 8;
+
+        let mut rng = XorShiftRng::new(1234);
+        let lhs = NdTensor::<f32, 2>::rand([m, k], &mut rng);
+        let rhs = NdTensor::<u8, 3>::rand([n, k_blocks, block_bytes], &mut rng);
+        let rhs_scales = NdTensor::<f32, 2>::rand([n, k_blocks], &mut rng);
+        let rhs_zero_points =
+            NdTensor::<u8, 2>::rand([n, (k_blocks * n_bits as usize).div_ceil(8)], &mut rng);
+        let bias = NdTensor::<f32, 1>::rand([n], &mut rng);
+        let (rhs, rhs_scales, rhs_zero_points) = (
+            rhs.to_contiguous(),
+            rhs_scales.to_contiguous(),
+            rhs_zero_points.to_contiguous(),
+        );
+
+        let mut rhs_bqm = BlockQuantizedMatrix::new(rhs.view(), rhs_scales.view(), n_bits).unwrap();
+        if zero_points {
+            rhs_bqm = rhs_bqm
+                .with_zero_points(BlockQuantizedZeroPoints::Packed(rhs_zero_points.view()))
+                .unwrap();
+        }
+
+        let rhs_dequant = rhs_bqm.dequantize(None);
+        let mut expected = NdTensor::<f32, 2>::zeros([m, n]);
+        let bias_vec = BiasVector::Row(bias.data().unwrap());
+        reference_gemm(
+            expected.view_mut(),
+            lhs.view(),
+            rhs_dequant.view(),
+            Some(GemmOpts {
+                bias: Some(bias_vec),
+                ..Default::default()
+            }),
+        );
+
+        for gemm in all_gemms() {
+            let mut output = NdTensor::<f32, 2>::zeros([m, n]);
+            gemm.gemm(
+                output.view_mut().data_mut().unwrap(),
+                GemmInputA::Unpacked(lhs.view()),
+                GemmInputB::BlockQuantized(rhs_bqm),
+                GemmOptions {
+                    bias: Some(bias_vec),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+            expect_equal_with_tolerance(&output, &expected, 1e-4, 1e-4).unwrap();
+        }
+    });
+}
+
 #[test]
 fn test_gemv() {
     #[derive(Clone, Copy, Debug)]
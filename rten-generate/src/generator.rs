@@ -17,6 +17,8 @@ use crate::metrics::Metrics;
 use crate::model::Model;
 use crate::sampler::{ArgMax, Sampler};
 
+#[cfg(feature = "lora")]
+use crate::lora::MergedLora;
 #[cfg(feature = "text-decoder")]
 use crate::text_decoder::TextDecoder;
 
@@ -77,7 +79,7 @@ impl std::fmt::Display for ErrorContext {
     }
 }
 
-enum KvCacheData {
+pub(crate) enum KvCacheData {
     /// Key-value cache with shape `[batch, seq_len, channels]`.
     ///
     /// In this configuration the channels for all heads are combined into the
@@ -113,7 +115,7 @@ impl KvCacheData {
     }
 
     /// Return the current sequence length of the cache.
-    fn sequence_len(&self) -> usize {
+    pub(crate) fn sequence_len(&self) -> usize {
         match self {
             KvCacheData::BatchSeqChans(data) => data.size(1),
             KvCacheData::BatchHeadSeqChans(data) => data.size(2),
@@ -121,7 +123,7 @@ impl KvCacheData {
     }
 
     /// Return true if the KV cache has capacity for a given sequence length.
-    fn has_capacity(&self, sequence_len: usize) -> bool {
+    pub(crate) fn has_capacity(&self, sequence_len: usize) -> bool {
         match self {
             KvCacheData::BatchSeqChans(data) => {
                 data.has_capacity(1 /* seq dim */, sequence_len)
@@ -134,7 +136,7 @@ impl KvCacheData {
 
     /// Clone this cache into a new buffer with space to store sequences of
     /// a given size.
-    fn clone_with_capacity(&self, max_sequence_len: usize) -> KvCacheData {
+    pub(crate) fn clone_with_capacity(&self, max_sequence_len: usize) -> KvCacheData {
         let max_sequence_len = max_sequence_len.max(self.sequence_len());
         match self {
             KvCacheData::BatchSeqChans(data) => {
@@ -155,6 +157,23 @@ impl KvCacheData {
             }
         }
     }
+
+    /// Convert a KV cache output from a model run into a cache buffer.
+    pub(crate) fn from_output(output: Value) -> Result<KvCacheData, GeneratorError> {
+        let err_context = "failed to save self-attention KV-cache";
+        match output.ndim() {
+            3 => Ok(KvCacheData::BatchSeqChans(
+                output.try_into().map_err(|e| wrap_error(e, err_context))?,
+            )),
+            4 => Ok(KvCacheData::BatchHeadSeqChans(
+                output.try_into().map_err(|e| wrap_error(e, err_context))?,
+            )),
+            ndim => Err(wrap_error(
+                format!("KV cache has {} dims, expected 3 or 4", ndim),
+                err_context,
+            )),
+        }
+    }
 }
 
 /// Key-value cache for a single layer of a transformer model.
@@ -180,6 +199,7 @@ impl KvCache {
 ///
 /// These inputs are expected to have the form `{prefix}{layer_number}{suffix}`,
 /// with one input and output per layer for the key cache and the value cache.
+#[derive(Clone)]
 pub struct KVCachePattern<'a> {
     pub prefix: &'a str,
     pub suffix: &'a str,
@@ -195,6 +215,7 @@ impl<'a> From<(&'a str, &'a str)> for KVCachePattern<'a> {
 
 /// Specifies a pair of patterns for corresponding input and output key-value
 /// cache entries.
+#[derive(Clone)]
 pub struct KVCachePair<'a> {
     /// The pattern for the model input name.
     pub input: KVCachePattern<'a>,
@@ -216,6 +237,7 @@ pub struct KVCachePair<'a> {
 /// follow the configuration of Hugging Face's Optimum tool.
 ///
 /// Any inputs that are not present in the model are ignored.
+#[derive(Clone)]
 pub struct ModelInputsConfig<'a> {
     /// Model input that contains the token IDs of the prompt and output
     /// generated so far.
@@ -247,7 +269,7 @@ pub struct ModelInputsConfig<'a> {
 
 /// Contains essential configuration needed for a `Generator` to execute a
 /// model, such as the roles of different inputs and outputs.
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct GeneratorConfig<'a> {
     /// Specifies names and roles of model inputs and outputs.
     pub model_inputs: ModelInputsConfig<'a>,
@@ -444,6 +466,14 @@ pub struct Generator<'a> {
     /// This is used by encoder-decoder models. The cross-attention values
     /// are computed on the first run and reused in subsequent runs.
     encoder_kv_cache: Vec<KvCache>,
+
+    /// Maximum number of prompt tokens to feed into the model in one run.
+    /// See [`with_prefill_chunk_size`](Self::with_prefill_chunk_size).
+    prefill_chunk_size: Option<usize>,
+
+    /// Callback invoked after each chunk of a chunked prefill.
+    #[allow(clippy::type_complexity)]
+    prefill_progress: Option<Box<dyn FnMut(usize, usize) + 'a>>,
 }
 
 impl<'a> Generator<'a> {
@@ -612,6 +642,8 @@ impl<'a> Generator<'a> {
             encoder_kv_cache,
             prev_tokens: Vec::new(),
             sampler: Box::new(ArgMax::new()),
+            prefill_chunk_size: None,
+            prefill_progress: None,
         };
 
         let attention_mask_input = model.find_node(model_inputs.attention_mask);
@@ -709,6 +741,19 @@ impl<'a> Generator<'a> {
         self
     }
 
+    /// Run the model with the weights of a merged LoRA adapter.
+    ///
+    /// The adapted weights replace the corresponding weights of the base
+    /// model for this generator only. Other generators using the same model
+    /// are not affected.
+    #[cfg(feature = "lora")]
+    pub fn with_lora(mut self, lora: &'a MergedLora) -> Self {
+        for (weight_id, value) in lora.weights() {
+            self = self.with_constant_input(weight_id, value);
+        }
+        self
+    }
+
     /// Add an input which varies with the sequence position.
     ///
     /// `value_fn` receives `(batch_size, sequence_positions)` as input and
@@ -717,9 +762,19 @@ impl<'a> Generator<'a> {
     /// A common use case is to pass position embeddings, if they are not
     /// computed internally by the model.
     pub fn with_varying_input<F: Fn(usize, Range<usize>) -> ValueOrView<'a>>(
-        mut self,
+        self,
         input_id: NodeId,
         value_fn: &'a F,
+    ) -> Self {
+        self.with_varying_input_dyn(input_id, value_fn)
+    }
+
+    /// Variant of [`with_varying_input`](Self::with_varying_input) which
+    /// takes a trait object.
+    pub(crate) fn with_varying_input_dyn(
+        mut self,
+        input_id: NodeId,
+        value_fn: &'a dyn Fn(usize, Range<usize>) -> ValueOrView<'a>,
     ) -> Self {
         self.varying_inputs.push((input_id, value_fn));
         self
@@ -751,23 +806,98 @@ impl<'a> Generator<'a> {
         self
     }
 
+    /// Process long prompts in chunks of at most `chunk_size` tokens.
+    ///
+    /// By default the whole pending prompt is fed into the model in a single
+    /// run. For long prompts the memory needed for attention grows
+    /// quadratically with the prompt length. When a chunk size is set, the
+    /// prompt is instead processed in several runs which extend the KV cache
+    /// incrementally, bounding peak memory usage.
+    ///
+    /// Chunking only applies to models with a KV cache. Models without a KV
+    /// cache need the whole sequence as input on each run.
+    ///
+    /// Panics if `chunk_size` is zero.
+    pub fn with_prefill_chunk_size(mut self, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "chunk size must be positive");
+        self.prefill_chunk_size = Some(chunk_size);
+        self
+    }
+
+    /// Set a callback which is invoked after each chunk of a prompt has been
+    /// processed.
+    ///
+    /// The callback receives `(processed, total)` where `processed` is the
+    /// number of prompt tokens fed into the model so far and `total` is the
+    /// length of the pending prompt when processing started. This is only
+    /// used if a chunk size has been set using
+    /// [`with_prefill_chunk_size`](Self::with_prefill_chunk_size).
+    pub fn with_prefill_progress<F: FnMut(usize, usize) + 'a>(mut self, progress: F) -> Self {
+        self.prefill_progress = Some(Box::new(progress));
+        self
+    }
+
     /// Feed the current prompt into the model and update the KV cache.
     ///
+    /// If a prefill chunk size is set, all but the last chunk of the prompt
+    /// are processed without computing logits.
+    ///
     /// If `generate_logits` is true, the model's logits output is computed and
     /// returned as a `(batch, sequence, vocab)` tensor.
     fn generate_impl(
         &mut self,
         generate_logits: bool,
+    ) -> Result<Option<NdTensor<f32, 3>>, GeneratorError> {
+        let chunk_size = match self.prefill_chunk_size {
+            Some(size) if !self.kv_cache.is_empty() && self.input_ids.len() > size => size,
+            _ => return self.run_model(self.input_ids.len(), generate_logits),
+        };
+
+        self.save_prompt();
+        let total = self.input_ids.len();
+        while self.input_ids.len() > chunk_size {
+            self.run_model(chunk_size, false)?;
+            if let Some(progress) = self.prefill_progress.as_mut() {
+                progress(total - self.input_ids.len(), total);
+            }
+        }
+
+        let output = self.run_model(self.input_ids.len(), generate_logits)?;
+        if let Some(progress) = self.prefill_progress.as_mut() {
+            progress(total, total);
+        }
+        Ok(output)
+    }
+
+    /// Record the pending prompt in `prev_tokens` if this is the first run.
+    fn save_prompt(&mut self) {
+        if self.prev_tokens.is_empty() {
+            self.prev_tokens.extend(self.input_ids.iter());
+        }
+    }
+
+    /// Feed the first `n_tokens` tokens of the current prompt into the model
+    /// and update the KV cache.
+    ///
+    /// If `generate_logits` is true, the model's logits output is computed and
+    /// returned as a `(batch, sequence, vocab)` tensor.
+    fn run_model(
+        &mut self,
+        n_tokens: usize,
+        generate_logits: bool,
     ) -> Result<Option<NdTensor<f32, 3>>, GeneratorError> {
         let batch_size = 1;
-        let input_ids: NdTensor<i32, 2> = self
-            .input_ids
+        let input_ids: NdTensor<i32, 2> = self.input_ids[..n_tokens]
             .iter()
             .map(|id| *id as i32)
             .collect::<Tensor<_>>()
-            .into_shape([batch_size, self.input_ids.len()]);
+            .into_shape([batch_size, n_tokens]);
 
-        let input_positions = self.input_offset..self.input_offset + self.input_ids.len();
+        let input_positions = self.input_offset..self.input_offset + n_tokens;
+
+        // Number of tokens that will be fed into the model in later runs,
+        // without any tokens sampled from this run's output.
+        let pending_len = self.input_ids.len() - n_tokens;
 
         let mut model_inputs: Vec<(NodeId, ValueOrView)> =
             vec![(self.input_ids_input, input_ids.view().into())];
@@ -857,30 +987,18 @@ impl<'a> Generator<'a> {
         // axis.
         for cache_entry in self.kv_cache.iter_mut() {
             let output = outputs.remove(0);
+            let mut kv_cache = KvCacheData::from_output(output)?;
 
-            let err_context = "failed to save self-attention KV-cache";
-            let mut kv_cache = match output.ndim() {
-                3 => KvCacheData::BatchSeqChans(
-                    output.try_into().map_err(|e| wrap_error(e, err_context))?,
-                ),
-                4 => KvCacheData::BatchHeadSeqChans(
-                    output.try_into().map_err(|e| wrap_error(e, err_context))?,
-                ),
-                ndim => {
-                    return Err(wrap_error(
-                        format!("KV cache has {} dims, expected 3 or 4", ndim),
-                        err_context,
-                    ));
-                }
-            };
-
-            // Grow the KV cache buffer if it has reached the limit of its
-            // pre-allocated sequence length.
+            // Grow the KV cache buffer if it does not have space for the
+            // next run, which will append either the remaining prompt tokens
+            // or a single sampled token.
             //
             // Double the capacity each time to amortize the costs of copying
             // the previous buffer.
-            if !kv_cache.has_capacity(kv_cache.sequence_len() + 1) {
-                kv_cache = kv_cache.clone_with_capacity(kv_cache.sequence_len() * 2);
+            let required_len = kv_cache.sequence_len() + pending_len.max(1);
+            if !kv_cache.has_capacity(required_len) {
+                kv_cache =
+                    kv_cache.clone_with_capacity((kv_cache.sequence_len() * 2).max(required_len));
             }
 
             cache_entry.cache = Some(kv_cache);
@@ -915,14 +1033,12 @@ impl<'a> Generator<'a> {
         }
 
         // Save prompt for use in logit filters.
-        if self.prev_tokens.is_empty() {
-            self.prev_tokens.extend(self.input_ids.iter());
-        }
+        self.save_prompt();
 
-        // Clear the prompt for the next generation.
+        // Remove the processed tokens from the prompt for the next generation.
         if !self.kv_cache.is_empty() {
-            self.input_offset += self.input_ids.len();
-            self.input_ids.clear();
+            self.input_offset += n_tokens;
+            self.input_ids.drain(..n_tokens);
         }
 
         if generate_logits {
@@ -945,6 +1061,33 @@ impl<'a> Generator<'a> {
         self.generate_impl(false).map(|_| ())
     }
 
+    /// Run the model on at most `max_tokens` tokens of the pending prompt and
+    /// update the KV cache.
+    ///
+    /// This allows long prompts to be processed incrementally, interleaved
+    /// with other work. Returns the number of prompt tokens which remain to be
+    /// processed.
+    ///
+    /// Models without a KV cache process the whole prompt each time a token
+    /// is generated, so for these models this returns zero without running
+    /// the model. If there is no pending prompt, this also returns immediately
+    /// without running the model. Returns an error if `max_tokens` is zero.
+    pub fn process_prompt_chunk(&mut self, max_tokens: usize) -> Result<usize, GeneratorError> {
+        if self.input_ids.is_empty() || self.kv_cache.is_empty() {
+            return Ok(0);
+        }
+        if max_tokens == 0 {
+            return Err(GeneratorError::GenerateError(
+                "max_tokens must be greater than zero".into(),
+            ));
+        }
+
+        let n_tokens = max_tokens.min(self.input_ids.len());
+        self.save_prompt();
+        self.run_model(n_tokens, false)?;
+        Ok(self.input_ids.len())
+    }
+
     /// Run the model and generate the next token.
     ///
     /// The generated token is automatically added to the prompt for the next
@@ -952,6 +1095,13 @@ impl<'a> Generator<'a> {
     fn generate_next_token(&mut self) -> Result<TokenId, GeneratorError> {
         let logits = self.generate_impl(true)?.expect("should have logits");
         let last_logits = Logits::dense(logits.slice((0, -1)).to_contiguous().to_vec());
+        self.sample_token(last_logits)
+    }
+
+    /// Filter logits for the next position and sample a token from them.
+    ///
+    /// The sampled token is added to the prompt for the next generation.
+    fn sample_token(&mut self, last_logits: Logits) -> Result<TokenId, GeneratorError> {
         let filtered_logits = if let Some(filter) = self.logits_filter.as_ref() {
             filter.filter(last_logits, &self.prev_tokens)
         } else {
@@ -974,9 +1124,64 @@ impl<'a> Generator<'a> {
 
         Ok(next_id)
     }
+
+    /// Return the token and position that will be fed to the model in the
+    /// next step, if that step decodes a single sampled token.
+    pub(crate) fn decode_input(&self) -> Option<(TokenId, usize)> {
+        match self.input_ids[..] {
+            [token_id] if self.input_offset > 0 => Some((token_id, self.input_offset)),
+            _ => None,
+        }
+    }
+
+    /// Return the `(input, output)` node IDs of the self-attention KV cache
+    /// for each layer.
+    pub(crate) fn kv_cache_nodes(&self) -> Vec<(NodeId, NodeId)> {
+        self.kv_cache
+            .iter()
+            .map(|entry| (entry.input_id, entry.output_id))
+            .collect()
+    }
+
+    /// Return true if the model has a cross-attention KV cache.
+    pub(crate) fn has_encoder_kv_cache(&self) -> bool {
+        !self.encoder_kv_cache.is_empty()
+    }
+
+    /// Take the self-attention KV cache for each layer, so that the next
+    /// decoding step can be run outside of the generator.
+    ///
+    /// The cache must be restored using
+    /// [`restore_kv_cache`](Self::restore_kv_cache) before the generator is
+    /// used again.
+    pub(crate) fn take_kv_cache(&mut self) -> Vec<KvCacheData> {
+        self.kv_cache
+            .iter_mut()
+            .map(|entry| entry.cache.take().expect("KV cache should be present"))
+            .collect()
+    }
+
+    /// Restore the self-attention KV cache taken by
+    /// [`take_kv_cache`](Self::take_kv_cache).
+    pub(crate) fn restore_kv_cache(&mut self, caches: Vec<KvCacheData>) {
+        for (entry, cache) in self.kv_cache.iter_mut().zip(caches) {
+            entry.cache = Some(cache);
+        }
+    }
+
+    /// Complete a decoding step that was run outside of the generator, given
+    /// the output logits for the token returned by
+    /// [`decode_input`](Self::decode_input).
+    ///
+    /// Returns the next sampled token.
+    pub(crate) fn finish_decode_step(&mut self, logits: Logits) -> Result<TokenId, GeneratorError> {
+        self.input_offset += self.input_ids.len();
+        self.input_ids.clear();
+        self.sample_token(logits)
+    }
 }
 
-fn wrap_error<E>(error: E, context: &str) -> GeneratorError
+pub(crate) fn wrap_error<E>(error: E, context: &str) -> GeneratorError
 where
     E: Into<Box<dyn Error>>,
 {
@@ -1614,6 +1819,121 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_chunked_prefill() -> Result<(), Box<dyn Error>> {
+        let params = TransformerParams::default();
+        let prompt = [1, 2, 3, 4, 1, 2, 3, 4, 1, 2];
+        let chunk_size = 4;
+
+        // The first two runs process full chunks and don't generate logits.
+        // The third run processes the last partial chunk and generates the
+        // first token.
+        let output_token_ids = [0, 0, 3, 4];
+        let model = fake_transformer_model(
+            params,
+            Some(KvCacheType::Decoder),
+            prompt.len(),
+            &output_token_ids,
+        );
+
+        let progress = RefCell::new(Vec::new());
+        let mut generator = Generator::from_model(&model)?
+            .with_prompt(&prompt)
+            .with_prefill_chunk_size(chunk_size)
+            .with_prefill_progress(|processed, total| {
+                progress.borrow_mut().push((processed, total))
+            });
+
+        let tokens: Vec<_> = generator
+            .by_ref()
+            .take(2)
+            .map(|id| id.expect("generation failed"))
+            .collect();
+        assert_eq!(tokens, [3, 4]);
+        assert_eq!(generator.prev_tokens(), [&prompt[..], &[3, 4]].concat());
+        drop(generator);
+        assert_eq!(progress.take(), [(4, 10), (8, 10), (10, 10)]);
+
+        let input_id = model.find_node("input_ids").unwrap();
+        let position_ids = model.find_node("position_ids").unwrap();
+        let attention_mask = model.find_node("attention_mask").unwrap();
+
+        let expected_ranges = [0..4, 4..8, 8..10];
+        for (step, range) in expected_ranges.into_iter().enumerate() {
+            let inputs: NdTensor<i32, 2> = model.get_inputs(step, input_id).unwrap().try_into()?;
+            let expected: Vec<i32> = prompt[range.clone()].iter().map(|x| *x as i32).collect();
+            assert_eq!(inputs.to_vec(), expected);
+
+            let pos_ids: NdTensor<i32, 2> =
+                model.get_inputs(step, position_ids).unwrap().try_into()?;
+            let expected_pos: Vec<i32> = range.clone().map(|x| x as i32).collect();
+            assert_eq!(pos_ids.to_vec(), expected_pos);
+
+            let attn_mask: NdTensor<i32, 2> =
+                model.get_inputs(step, attention_mask).unwrap().try_into()?;
+            assert_eq!(attn_mask.size(1), range.end);
+        }
+
+        // After the prompt, the sampled token is fed in alone.
+        let inputs: NdTensor<i32, 2> = model.get_inputs(3, input_id).unwrap().try_into()?;
+        assert_eq!(inputs, NdTensor::from([[3]]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_prompt_chunk() -> Result<(), Box<dyn Error>> {
+        let params = TransformerParams::default();
+        let prompt = [1, 2, 3, 1, 2, 3];
+        let model =
+            fake_transformer_model(params, Some(KvCacheType::Decoder), prompt.len(), &[0, 0, 0]);
+
+        let mut generator = Generator::from_model(&model)?.with_prompt(&prompt);
+
+        assert_eq!(generator.process_prompt_chunk(4)?, 2);
+        assert_eq!(generator.prompt(), [2, 3]);
+        assert_eq!(generator.prev_tokens(), prompt);
+
+        assert_eq!(generator.process_prompt_chunk(4)?, 0);
+        assert!(generator.prompt().is_empty());
+        assert_eq!(generator.prev_tokens(), prompt);
+
+        // With no pending prompt, the model is not run.
+        assert_eq!(generator.process_prompt_chunk(4)?, 0);
+        assert_eq!(generator.prev_tokens(), prompt);
+
+        // A chunk size of zero is invalid.
+        let mut generator = Generator::from_model(&model)?.with_prompt(&prompt);
+        let err = generator.process_prompt_chunk(0).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "generation error: max_tokens must be greater than zero"
+        );
+        assert_eq!(generator.prompt(), prompt);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_prompt_chunk_without_kv_cache() -> Result<(), Box<dyn Error>> {
+        let params = TransformerParams::default();
+        let prompt = [1, 2, 3, 1, 2, 3];
+        let model = fake_transformer_model(params, None, prompt.len(), &[4]);
+
+        // Without a KV cache the prompt can't be processed incrementally, so
+        // the model should only be run when generating the first token.
+        let mut generator = Generator::from_model(&model)?.with_prompt(&prompt);
+        assert_eq!(generator.process_prompt_chunk(4)?, 0);
+        assert_eq!(generator.prompt(), prompt);
+        assert_eq!(model.step.get(), 0);
+
+        let token = generator.next().unwrap()?;
+        assert_eq!(token, 4);
+        assert_eq!(model.step.get(), 1);
+
+        Ok(())
+    }
+
     #[test]
     fn test_profile() -> Result<(), Box<dyn Error>> {
         let params = TransformerParams::default();
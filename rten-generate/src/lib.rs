@@ -10,9 +10,12 @@
 pub mod filter;
 pub mod generator;
 mod logits;
+#[cfg(feature = "lora")]
+pub mod lora;
 pub mod metrics;
 pub mod model;
 pub mod sampler;
+pub mod scheduler;
 
 #[cfg(feature = "text-decoder")]
 pub mod text_decoder;
@@ -0,0 +1,784 @@
+//! Apply LoRA (Low-Rank Adaptation) fine-tuning adapters to a model.
+//!
+//! A LoRA adapter contains a pair of low-rank matrices `A` (aka. "down") and
+//! `B` (aka. "up") for each adapted weight. The adapted weight is
+//! `W + (alpha / rank) * B @ A`.
+//!
+//! Adapters are applied by computing merged copies of the adapted weights.
+//! These are passed to the model as overrides for the original weights on
+//! each run, so the base model is shared and several adapters can be used
+//! with different [`Generator`](crate::Generator)s at the same time.
+//!
+//! ```no_run
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use rten::Model;
+//! use rten_generate::Generator;
+//! use rten_generate::lora::LoraAdapter;
+//!
+//! let model = Model::load_file("model.rten")?;
+//! let adapter = LoraAdapter::load_file("adapter_model.safetensors")?.with_alpha(16.);
+//!
+//! // Map adapter module names (eg. "model.layers.0.self_attn.q_proj") to
+//! // the names of MatMul weights in the model.
+//! let merged = adapter.merge(&model, |module| Some(format!("{module}.weight")))?;
+//!
+//! let generator = Generator::from_model(&model)?.with_lora(&merged);
+//! # Ok(()) }
+//! ```
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::path::Path;
+
+use rten::{NodeId, Value, ValueView};
+use rten_base::half::{bf16_to_f32, f16_to_f32};
+use rten_tensor::NdTensor;
+use rten_tensor::prelude::*;
+use safetensors::SafeTensors;
+use safetensors::tensor::{Dtype, TensorView};
+
+use crate::model::Model;
+
+/// Errors that occur when loading or applying a LoRA adapter.
+#[derive(Debug)]
+pub enum LoraError {
+    /// The adapter file could not be read.
+    IoError(std::io::Error),
+
+    /// The adapter file is not a valid Safetensors file.
+    ParseError(String),
+
+    /// An adapter tensor has an unsupported data type.
+    UnsupportedDataType(String),
+
+    /// An adapter tensor is missing, or has an unexpected shape.
+    InvalidAdapter(String),
+
+    /// The weight to be adapted was not found in the model or is not a
+    /// constant.
+    WeightNotFound(String),
+
+    /// The weight to be adapted is not a float tensor.
+    UnsupportedWeightType(String),
+
+    /// The weight to be adapted is used by an operator other than `MatMul`,
+    /// `FusedMatMul` or `Gemm`. This happens if graph optimization fused an
+    /// operation that transforms the weight into the operator which uses it.
+    ///
+    /// The fields are the names of the weight and the operator.
+    FusedWeight(String, String),
+
+    /// The weight to be adapted has been pre-packed, so overriding it would
+    /// have no effect on the pre-packed copy. Load the model with
+    /// pre-packing disabled to apply adapters.
+    PrepackedWeight(String),
+
+    /// The layout of the weight could not be determined from the operators
+    /// that use it. Specify it using
+    /// [`LoraAdapter::with_weight_layout`].
+    UnknownLayout(String),
+
+    /// The shape of the adapter does not match the weight it is applied to.
+    ShapeMismatch(String),
+}
+
+impl fmt::Display for LoraError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LoraError::IoError(err) => write!(f, "io error: {}", err),
+            LoraError::ParseError(err) => write!(f, "parse error: {}", err),
+            LoraError::UnsupportedDataType(name) => {
+                write!(f, "unsupported data type for tensor \"{}\"", name)
+            }
+            LoraError::InvalidAdapter(err) => write!(f, "invalid adapter: {}", err),
+            LoraError::WeightNotFound(name) => write!(f, "weight not found: {}", name),
+            LoraError::UnsupportedWeightType(name) => {
+                write!(f, "weight \"{}\" is not a float tensor", name)
+            }
+            LoraError::FusedWeight(name, op) => {
+                write!(
+                    f,
+                    "weight \"{}\" is used by fused operator \"{}\"",
+                    name, op
+                )
+            }
+            LoraError::PrepackedWeight(name) => {
+                write!(f, "weight \"{}\" has been pre-packed", name)
+            }
+            LoraError::UnknownLayout(name) => {
+                write!(f, "layout of weight \"{}\" is unknown", name)
+            }
+            LoraError::ShapeMismatch(err) => write!(f, "shape mismatch: {}", err),
+        }
+    }
+}
+
+impl Error for LoraError {}
+
+/// Layout of a weight matrix that an adapter is applied to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WeightLayout {
+    /// `[in_features, out_features]` layout, used for the right-hand side of
+    /// a `MatMul`, or a `Gemm` with `transB = 0`.
+    InOut,
+
+    /// `[out_features, in_features]` layout, used by PyTorch's `Linear`
+    /// module and for a `Gemm` with `transB = 1`.
+    OutIn,
+}
+
+/// Low-rank update for a single weight.
+struct LoraLayer {
+    /// `A` matrix with shape `[rank, in_features]`.
+    down: NdTensor<f32, 2>,
+
+    /// `B` matrix with shape `[out_features, rank]`.
+    up: NdTensor<f32, 2>,
+
+    /// Scaling numerator specific to this layer.
+    alpha: Option<f32>,
+}
+
+impl LoraLayer {
+    fn rank(&self) -> usize {
+        self.down.size(0)
+    }
+
+    /// Compute the update `scale * (B @ A)^T` with shape
+    /// `[in_features, out_features]`.
+    fn delta(&self, scale: f32) -> NdTensor<f32, 2> {
+        let [rank, in_features] = self.down.shape();
+        let out_features = self.up.size(0);
+        let up_t = self.up.transposed().to_tensor();
+
+        let mut delta = NdTensor::zeros([in_features, out_features]);
+        for r in 0..rank {
+            let up_row = up_t.slice(r);
+            let up_row = up_row.data().expect("should be contiguous");
+            for i in 0..in_features {
+                let a = scale * self.down[[r, i]];
+                let mut delta_row = delta.slice_mut(i);
+                let delta_row = delta_row.data_mut().expect("should be contiguous");
+                for (d, u) in delta_row.iter_mut().zip(up_row) {
+                    *d += a * u;
+                }
+            }
+        }
+        delta
+    }
+}
+
+/// LoRA adapter loaded from a Safetensors file.
+///
+/// Adapters in the format saved by Hugging Face's PEFT library
+/// (`{module}.lora_A.weight` and `{module}.lora_B.weight`) and in the
+/// `{module}.lora_down.weight` / `{module}.lora_up.weight` format with
+/// optional `{module}.alpha` scalars are supported. The `base_model.model.`
+/// prefix that PEFT adds to module names is removed.
+///
+/// Tensors can be stored as f32, f16 or bf16.
+pub struct LoraAdapter {
+    layers: Vec<(String, LoraLayer)>,
+    alpha: Option<f32>,
+    weight_layout: Option<WeightLayout>,
+}
+
+impl LoraAdapter {
+    /// Load an adapter from a Safetensors file.
+    pub fn load_file<P: AsRef<Path>>(path: P) -> Result<LoraAdapter, LoraError> {
+        let data = std::fs::read(path).map_err(LoraError::IoError)?;
+        Self::from_bytes(&data)
+    }
+
+    /// Load an adapter from a buffer containing a Safetensors file.
+    ///
+    /// If the file's metadata contains a `lora_alpha` entry, it is used as
+    /// the default alpha value. This can be overridden using
+    /// [`with_alpha`](Self::with_alpha).
+    pub fn from_bytes(data: &[u8]) -> Result<LoraAdapter, LoraError> {
+        let (_, metadata) = SafeTensors::read_metadata(data)
+            .map_err(|err| LoraError::ParseError(err.to_string()))?;
+        let alpha = metadata
+            .metadata()
+            .as_ref()
+            .and_then(|meta| meta.get("lora_alpha"))
+            .and_then(|alpha| alpha.parse().ok());
+
+        let tensors =
+            SafeTensors::deserialize(data).map_err(|err| LoraError::ParseError(err.to_string()))?;
+
+        #[derive(Default)]
+        struct PartialLayer {
+            down: Option<NdTensor<f32, 2>>,
+            up: Option<NdTensor<f32, 2>>,
+            alpha: Option<f32>,
+        }
+        let mut partial_layers: HashMap<String, PartialLayer> = HashMap::new();
+
+        for (name, view) in tensors.iter() {
+            let name = name.strip_prefix("base_model.model.").unwrap_or(name);
+            let Some((module, kind)) = split_tensor_name(name) else {
+                continue;
+            };
+            let layer = partial_layers.entry(module.to_string()).or_default();
+            match kind {
+                TensorKind::Down => layer.down = Some(read_matrix(name, &view)?),
+                TensorKind::Up => layer.up = Some(read_matrix(name, &view)?),
+                TensorKind::Alpha => {
+                    let values = read_f32(name, &view)?;
+                    let [alpha] = values[..] else {
+                        return Err(LoraError::InvalidAdapter(format!(
+                            "alpha \"{}\" is not a scalar",
+                            name
+                        )));
+                    };
+                    layer.alpha = Some(alpha);
+                }
+            }
+        }
+
+        let mut layers = Vec::with_capacity(partial_layers.len());
+        for (module, layer) in partial_layers {
+            let (Some(down), Some(up)) = (layer.down, layer.up) else {
+                return Err(LoraError::InvalidAdapter(format!(
+                    "module \"{}\" is missing A or B matrix",
+                    module
+                )));
+            };
+            if down.size(0) != up.size(1) {
+                return Err(LoraError::InvalidAdapter(format!(
+                    "module \"{}\" has mismatched ranks {} and {}",
+                    module,
+                    down.size(0),
+                    up.size(1)
+                )));
+            }
+            layers.push((
+                module,
+                LoraLayer {
+                    down,
+                    up,
+                    alpha: layer.alpha,
+                },
+            ));
+        }
+        layers.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        Ok(LoraAdapter {
+            layers,
+            alpha,
+            weight_layout: None,
+        })
+    }
+
+    /// Set the alpha value used to scale the updates.
+    ///
+    /// Updates are scaled by `alpha / rank`. For adapters saved by PEFT, the
+    /// value is the `lora_alpha` field in `adapter_config.json`. If no alpha
+    /// is set, either here or in the adapter file, the scale is 1.
+    pub fn with_alpha(mut self, alpha: f32) -> Self {
+        self.alpha = Some(alpha);
+        self
+    }
+
+    /// Set the layout of the weights that the adapter is applied to.
+    ///
+    /// By default the layout is determined from the operators which use each
+    /// weight. This is only possible for weights used by `MatMul` (or
+    /// `FusedMatMul`) operators. Weights used by `Gemm` operators require the layout to be
+    /// set explicitly, as it depends on the operator's `transB` attribute.
+    pub fn with_weight_layout(mut self, layout: WeightLayout) -> Self {
+        self.weight_layout = Some(layout);
+        self
+    }
+
+    /// Return the names of the modules which this adapter updates.
+    pub fn module_names(&self) -> impl Iterator<Item = &str> {
+        self.layers.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Compute adapted copies of the weights in `model`.
+    ///
+    /// `weight_name` maps the name of a module in the adapter to the name of
+    /// the corresponding weight in the model. Modules for which it returns
+    /// `None` are skipped.
+    ///
+    /// Weights must be float constants which are used directly by `MatMul`,
+    /// `FusedMatMul` or `Gemm` operators. Weights that have been fused into other operators
+    /// or pre-packed when the model was loaded are rejected. See
+    /// [`with_weight_layout`](Self::with_weight_layout) for how the layout
+    /// of each weight is determined.
+    pub fn merge<F: Fn(&str) -> Option<String>>(
+        &self,
+        model: &dyn Model,
+        weight_name: F,
+    ) -> Result<MergedLora, LoraError> {
+        let mut weights = Vec::new();
+        for (module, layer) in &self.layers {
+            let Some(name) = weight_name(module) else {
+                continue;
+            };
+            let id = model
+                .find_node(&name)
+                .ok_or_else(|| LoraError::WeightNotFound(name.clone()))?;
+            let weight = match model.constant(id) {
+                Some(ValueView::FloatTensor(weight)) => weight,
+                Some(_) => return Err(LoraError::UnsupportedWeightType(name)),
+                None => return Err(LoraError::WeightNotFound(name)),
+            };
+            let layout = self.layout_of(model, id, &name)?;
+
+            let alpha = layer.alpha.or(self.alpha);
+            let scale = alpha.map(|a| a / layer.rank() as f32).unwrap_or(1.);
+            let delta = layer.delta(scale);
+            let delta = match layout {
+                WeightLayout::InOut => delta.as_dyn(),
+                WeightLayout::OutIn => delta.transposed().as_dyn(),
+            };
+            if weight.shape() != delta.shape() {
+                return Err(LoraError::ShapeMismatch(format!(
+                    "weight \"{}\" has shape {:?} but adapter has shape {:?}",
+                    name,
+                    weight.shape(),
+                    delta.shape()
+                )));
+            }
+            let mut merged = weight.to_tensor();
+            for (w, d) in merged.iter_mut().zip(delta.iter()) {
+                *w += d;
+            }
+            weights.push((id, Value::from(merged)));
+        }
+        Ok(MergedLora { weights })
+    }
+
+    /// Check that the weight `id` can be overridden and determine its layout.
+    fn layout_of(
+        &self,
+        model: &dyn Model,
+        id: NodeId,
+        name: &str,
+    ) -> Result<WeightLayout, LoraError> {
+        let consumers = model.consumer_ops(id);
+        if let Some(op) = consumers
+            .iter()
+            .flatten()
+            .find(|op| !["MatMul", "FusedMatMul", "Gemm"].contains(&op.as_str()))
+        {
+            return Err(LoraError::FusedWeight(name.to_string(), op.clone()));
+        }
+        if model.is_prepacked(id) {
+            return Err(LoraError::PrepackedWeight(name.to_string()));
+        }
+
+        if let Some(layout) = self.weight_layout {
+            return Ok(layout);
+        }
+        match consumers {
+            Some(ops)
+                if !ops.is_empty()
+                    && ops.iter().all(|op| op == "MatMul" || op == "FusedMatMul") =>
+            {
+                Ok(WeightLayout::InOut)
+            }
+            _ => Err(LoraError::UnknownLayout(name.to_string())),
+        }
+    }
+}
+
+/// Weights of a model with a LoRA adapter merged in.
+///
+/// Use [`Generator::with_lora`](crate::Generator::with_lora) to run a model
+/// with these weights.
+pub struct MergedLora {
+    weights: Vec<(NodeId, Value)>,
+}
+
+impl MergedLora {
+    /// Return the IDs and values of the adapted weights.
+    pub fn weights(&self) -> impl Iterator<Item = (NodeId, ValueView<'_>)> {
+        self.weights
+            .iter()
+            .map(|(id, value)| (*id, value.as_view()))
+    }
+
+    /// Return the number of adapted weights.
+    pub fn len(&self) -> usize {
+        self.weights.len()
+    }
+
+    /// Return true if no weights were adapted.
+    pub fn is_empty(&self) -> bool {
+        self.weights.is_empty()
+    }
+}
+
+enum TensorKind {
+    Down,
+    Up,
+    Alpha,
+}
+
+/// Split an adapter tensor name into the module name and the kind of tensor.
+fn split_tensor_name(name: &str) -> Option<(&str, TensorKind)> {
+    const SUFFIXES: [(&str, TensorKind); 5] = [
+        (".lora_A.weight", TensorKind::Down),
+        (".lora_B.weight", TensorKind::Up),
+        (".lora_down.weight", TensorKind::Down),
+        (".lora_up.weight", TensorKind::Up),
+        (".alpha", TensorKind::Alpha),
+    ];
+    SUFFIXES
+        .into_iter()
+        .find_map(|(suffix, kind)| name.strip_suffix(suffix).map(|module| (module, kind)))
+}
+
+/// Read the elements of a tensor, converting them to f32.
+fn read_f32(name: &str, view: &TensorView) -> Result<Vec<f32>, LoraError> {
+    let data = view.data();
+    let values = match view.dtype() {
+        Dtype::F32 => data
+            .chunks_exact(4)
+            .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .collect(),
+        Dtype::F16 => data
+            .chunks_exact(2)
+            .map(|b| f16_to_f32(u16::from_le_bytes([b[0], b[1]])))
+            .collect(),
+        Dtype::BF16 => data
+            .chunks_exact(2)
+            .map(|b| bf16_to_f32(u16::from_le_bytes([b[0], b[1]])))
+            .collect(),
+        _ => return Err(LoraError::UnsupportedDataType(name.to_string())),
+    };
+    Ok(values)
+}
+
+fn read_matrix(name: &str, view: &TensorView) -> Result<NdTensor<f32, 2>, LoraError> {
+    let &[rows, cols] = view.shape() else {
+        return Err(LoraError::InvalidAdapter(format!(
+            "tensor \"{}\" is not a matrix",
+            name
+        )));
+    };
+    Ok(NdTensor::from_data([rows, cols], read_f32(name, view)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::error::Error;
+
+    use rten::{NodeId, RunOptions, Value, ValueOrView, ValueView};
+    use rten_tensor::Tensor;
+    use rten_tensor::prelude::*;
+    use safetensors::tensor::{Dtype, TensorView};
+
+    use super::{LoraAdapter, LoraError, WeightLayout, read_f32};
+    use crate::model::{Model, NodeInfo};
+
+    /// Fake model which only contains constants.
+    ///
+    /// Each weight is used by a single operator.
+    struct WeightsModel {
+        names: Vec<String>,
+        weights: Vec<Value>,
+        op_types: Vec<String>,
+        prepacked: bool,
+    }
+
+    impl WeightsModel {
+        /// Create a model where each weight is used by a MatMul.
+        fn new(weights: &[(&str, Tensor<f32>)]) -> Self {
+            Self::with_op(weights, "MatMul")
+        }
+
+        fn with_op(weights: &[(&str, Tensor<f32>)], op_type: &str) -> Self {
+            WeightsModel {
+                names: weights.iter().map(|(name, _)| name.to_string()).collect(),
+                weights: weights.iter().map(|(_, w)| w.clone().into()).collect(),
+                op_types: vec![op_type.to_string(); weights.len()],
+                prepacked: false,
+            }
+        }
+    }
+
+    impl Model for WeightsModel {
+        fn find_node(&self, name: &str) -> Option<NodeId> {
+            self.names
+                .iter()
+                .position(|n| n == name)
+                .map(|pos| NodeId::from_u32(pos as u32))
+        }
+
+        fn node_info(&self, _id: NodeId) -> Option<NodeInfo> {
+            None
+        }
+
+        fn input_ids(&self) -> &[NodeId] {
+            &[]
+        }
+
+        fn constant(&self, id: NodeId) -> Option<ValueView<'_>> {
+            self.weights.get(id.as_usize()).map(|w| w.as_view())
+        }
+
+        fn consumer_ops(&self, id: NodeId) -> Option<Vec<String>> {
+            self.op_types.get(id.as_usize()).map(|op| vec![op.clone()])
+        }
+
+        fn is_prepacked(&self, _id: NodeId) -> bool {
+            self.prepacked
+        }
+
+        fn run(
+            &self,
+            _inputs: Vec<(NodeId, ValueOrView)>,
+            _outputs: &[NodeId],
+            _opts: Option<RunOptions>,
+        ) -> Result<Vec<Value>, Box<dyn Error>> {
+            Err("not supported".into())
+        }
+
+        fn partial_run(
+            &self,
+            _inputs: Vec<(NodeId, ValueOrView)>,
+            _outputs: &[NodeId],
+            _opts: Option<RunOptions>,
+        ) -> Result<Vec<(NodeId, Value)>, Box<dyn Error>> {
+            Err("not supported".into())
+        }
+    }
+
+    /// Serialize f32 tensors into a Safetensors file.
+    fn safetensors_file(
+        tensors: &[(&str, &[usize], &[f32])],
+        metadata: Option<HashMap<String, String>>,
+    ) -> Vec<u8> {
+        let bytes: Vec<Vec<u8>> = tensors
+            .iter()
+            .map(|(_, _, data)| data.iter().flat_map(|x| x.to_le_bytes()).collect())
+            .collect();
+        let views: Vec<_> = tensors
+            .iter()
+            .zip(&bytes)
+            .map(|((name, shape, _), bytes)| {
+                (
+                    name.to_string(),
+                    TensorView::new(Dtype::F32, shape.to_vec(), bytes).unwrap(),
+                )
+            })
+            .collect();
+        safetensors::serialize(views, metadata).unwrap()
+    }
+
+    fn merged_weight(model: &WeightsModel, adapter: &LoraAdapter, name: &str) -> Vec<f32> {
+        let merged = adapter
+            .merge(model, |module| Some(format!("{module}.weight")))
+            .unwrap();
+        let id = model.find_node(name).unwrap();
+        let (_, value) = merged.weights().find(|(w_id, _)| *w_id == id).unwrap();
+        let ValueView::FloatTensor(tensor) = value else {
+            panic!("expected float tensor");
+        };
+        tensor.to_vec()
+    }
+
+    #[test]
+    fn test_merge_peft_adapter() {
+        // A = [1, 2, 3] (rank 1, 3 inputs), B = [1, 10] (2 outputs)
+        let data = safetensors_file(
+            &[
+                (
+                    "base_model.model.layer.q_proj.lora_A.weight",
+                    &[1, 3],
+                    &[1., 2., 3.],
+                ),
+                (
+                    "base_model.model.layer.q_proj.lora_B.weight",
+                    &[2, 1],
+                    &[1., 10.],
+                ),
+            ],
+            None,
+        );
+        let adapter = LoraAdapter::from_bytes(&data).unwrap().with_alpha(2.);
+        assert_eq!(adapter.module_names().collect::<Vec<_>>(), ["layer.q_proj"]);
+
+        // MatMul layout: `[in, out]`.
+        let model = WeightsModel::new(&[("layer.q_proj.weight", Tensor::full(&[3, 2], 1.))]);
+        assert_eq!(
+            merged_weight(&model, &adapter, "layer.q_proj.weight"),
+            [3., 21., 5., 41., 7., 61.]
+        );
+
+        // PyTorch `Linear` layout: `[out, in]`, used by a `Gemm` with
+        // `transB = 1`.
+        let model = WeightsModel::with_op(
+            &[("layer.q_proj.weight", Tensor::full(&[2, 3], 1.))],
+            "Gemm",
+        );
+        let adapter = adapter.with_weight_layout(WeightLayout::OutIn);
+        assert_eq!(
+            merged_weight(&model, &adapter, "layer.q_proj.weight"),
+            [3., 5., 7., 21., 41., 61.]
+        );
+
+        // Square weights use the layout that was specified, rather than
+        // being inferred from the shape.
+        let data = safetensors_file(
+            &[
+                ("proj.lora_A.weight", &[1, 2], &[1., 2.]),
+                ("proj.lora_B.weight", &[2, 1], &[1., 10.]),
+            ],
+            None,
+        );
+        let model = WeightsModel::with_op(&[("proj.weight", Tensor::zeros(&[2, 2]))], "Gemm");
+        let adapter = LoraAdapter::from_bytes(&data)
+            .unwrap()
+            .with_weight_layout(WeightLayout::OutIn);
+        assert_eq!(
+            merged_weight(&model, &adapter, "proj.weight"),
+            [1., 2., 10., 20.]
+        );
+        let adapter = adapter.with_weight_layout(WeightLayout::InOut);
+        assert_eq!(
+            merged_weight(&model, &adapter, "proj.weight"),
+            [1., 10., 2., 20.]
+        );
+    }
+
+    #[test]
+    fn test_merge_adapter_with_layer_alpha() {
+        let mut metadata = HashMap::new();
+        metadata.insert("lora_alpha".to_string(), "8".to_string());
+
+        let data = safetensors_file(
+            &[
+                ("proj.lora_down.weight", &[2, 1], &[1., 1.]),
+                ("proj.lora_up.weight", &[1, 2], &[1., 2.]),
+                ("proj.alpha", &[], &[1.]),
+                ("other.lora_down.weight", &[2, 1], &[1., 1.]),
+                ("other.lora_up.weight", &[1, 2], &[1., 2.]),
+            ],
+            Some(metadata),
+        );
+        let adapter = LoraAdapter::from_bytes(&data).unwrap();
+        let model = WeightsModel::new(&[
+            ("proj.weight", Tensor::zeros(&[1, 1])),
+            ("other.weight", Tensor::zeros(&[1, 1])),
+        ]);
+
+        // `proj` uses its own alpha. `other` uses the alpha from the metadata.
+        assert_eq!(merged_weight(&model, &adapter, "proj.weight"), [1.5]);
+        assert_eq!(merged_weight(&model, &adapter, "other.weight"), [12.]);
+    }
+
+    #[test]
+    fn test_merge_errors() {
+        let data = safetensors_file(
+            &[
+                ("proj.lora_A.weight", &[1, 3], &[1., 2., 3.]),
+                ("proj.lora_B.weight", &[2, 1], &[1., 10.]),
+            ],
+            None,
+        );
+        let adapter = LoraAdapter::from_bytes(&data).unwrap();
+
+        let model = WeightsModel::new(&[("proj.weight", Tensor::zeros(&[4, 4]))]);
+        let err = adapter
+            .merge(&model, |module| Some(format!("{module}.weight")))
+            .err()
+            .unwrap();
+        assert!(matches!(err, LoraError::ShapeMismatch(_)));
+
+        let err = adapter
+            .merge(&model, |module| Some(format!("{module}.bias")))
+            .err()
+            .unwrap();
+        assert!(matches!(err, LoraError::WeightNotFound(name) if name == "proj.bias"));
+
+        let merge_err = |model: &dyn Model| {
+            adapter
+                .merge(model, |module| Some(format!("{module}.weight")))
+                .err()
+                .unwrap()
+                .to_string()
+        };
+
+        let mut model = WeightsModel::new(&[("proj.weight", Tensor::zeros(&[3, 2]))]);
+        model.weights[0] = Tensor::<i32>::zeros(&[3, 2]).into();
+        assert_eq!(
+            merge_err(&model),
+            "weight \"proj.weight\" is not a float tensor"
+        );
+
+        let model = WeightsModel::with_op(
+            &[("proj.weight", Tensor::zeros(&[3, 2]))],
+            "TransformInputs(MatMul)",
+        );
+        assert_eq!(
+            merge_err(&model),
+            "weight \"proj.weight\" is used by fused operator \"TransformInputs(MatMul)\""
+        );
+
+        let mut model = WeightsModel::new(&[("proj.weight", Tensor::zeros(&[3, 2]))]);
+        model.prepacked = true;
+        assert_eq!(
+            merge_err(&model),
+            "weight \"proj.weight\" has been pre-packed"
+        );
+
+        // The layout of `Gemm` weights depends on the `transB` attribute.
+        let model = WeightsModel::with_op(&[("proj.weight", Tensor::zeros(&[2, 3]))], "Gemm");
+        assert_eq!(
+            merge_err(&model),
+            "layout of weight \"proj.weight\" is unknown"
+        );
+
+        // Modules can be skipped.
+        let merged = adapter.merge(&model, |_| None).unwrap();
+        assert!(merged.is_empty());
+    }
+
+    #[test]
+    fn test_missing_matrix() {
+        let data = safetensors_file(&[("proj.lora_A.weight", &[1, 3], &[1., 2., 3.])], None);
+        let err = LoraAdapter::from_bytes(&data).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "invalid adapter: module \"proj\" is missing A or B matrix"
+        );
+    }
+
+    #[test]
+    fn test_read_f32() {
+        let values = [1.0f32, -2.0, 0.5];
+        let f32_data: Vec<u8> = values.iter().flat_map(|x| x.to_le_bytes()).collect();
+        let f16_data: Vec<u8> = [0x3c00u16, 0xc000, 0x3800]
+            .iter()
+            .flat_map(|x| x.to_le_bytes())
+            .collect();
+        let bf16_data: Vec<u8> = [0x3f80u16, 0xc000, 0x3f00]
+            .iter()
+            .flat_map(|x| x.to_le_bytes())
+            .collect();
+
+        for (dtype, data) in [
+            (Dtype::F32, &f32_data),
+            (Dtype::F16, &f16_data),
+            (Dtype::BF16, &bf16_data),
+        ] {
+            let view = TensorView::new(dtype, vec![3], data).unwrap();
+            assert_eq!(read_f32("weight", &view).unwrap(), values);
+        }
+
+        let i32_data = [0u8; 12];
+        let view = TensorView::new(Dtype::I32, vec![3], &i32_data).unwrap();
+        assert!(matches!(
+            read_f32("weight", &view),
+            Err(LoraError::UnsupportedDataType(_))
+        ));
+    }
+}
@@ -2,7 +2,7 @@
 
 use std::error::Error;
 
-use rten::{Dimension, NodeId, RunOptions, Value, ValueOrView};
+use rten::{Dimension, NodeId, RunOptions, Value, ValueOrView, ValueView};
 
 /// Describes the name and shape of a model input or output.
 ///
@@ -48,6 +48,31 @@ pub trait Model {
     /// Return the node IDs of the model's inputs.
     fn input_ids(&self) -> &[NodeId];
 
+    /// Return the value of a constant node, such as a weight.
+    ///
+    /// The default implementation returns `None`, indicating that constant
+    /// values are not available.
+    fn constant(&self, _id: NodeId) -> Option<ValueView<'_>> {
+        None
+    }
+
+    /// Return the operator types (eg. "MatMul") of the nodes which use a
+    /// node as an input.
+    ///
+    /// The default implementation returns `None`, indicating that this
+    /// information is not available.
+    fn consumer_ops(&self, _id: NodeId) -> Option<Vec<String>> {
+        None
+    }
+
+    /// Return true if a constant has been pre-packed into an optimized
+    /// layout for the operators that use it.
+    ///
+    /// The default implementation returns `false`.
+    fn is_prepacked(&self, _id: NodeId) -> bool {
+        false
+    }
+
     /// Run the model with the provided inputs and return the results.
     fn run(
         &self,
@@ -87,6 +112,24 @@ impl Model for rten::Model {
         self.input_ids()
     }
 
+    fn constant(&self, id: NodeId) -> Option<ValueView<'_>> {
+        self.constant(id)
+    }
+
+    fn consumer_ops(&self, id: NodeId) -> Option<Vec<String>> {
+        let op_types = self
+            .consumers(id)
+            .iter()
+            .filter_map(|op_id| self.node_info(*op_id)?.op_type())
+            .map(|op_type| op_type.to_string())
+            .collect();
+        Some(op_types)
+    }
+
+    fn is_prepacked(&self, id: NodeId) -> bool {
+        self.is_prepacked(id)
+    }
+
     fn run(
         &self,
         inputs: Vec<(NodeId, ValueOrView)>,
@@ -0,0 +1,1325 @@
+//! Serve many generation sessions from one model using continuous batching.
+//!
+//! A [`Scheduler`] owns a set of generation sessions which share a single
+//! model. At each step it runs one forward pass over the pending decode tokens
+//! of all active sessions, admits waiting sessions as others finish and
+//! streams generated tokens back to each session over a channel.
+//!
+//! ```no_run
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use rten::Model;
+//! use rten_generate::scheduler::{Scheduler, SessionEvent, SessionRequest};
+//!
+//! let model = Model::load_file("model.rten")?;
+//! let mut scheduler = Scheduler::from_model(&model)?.with_max_batch_size(4);
+//!
+//! let (_id, events) = scheduler.submit(SessionRequest::new(&[1, 2, 3]).with_max_tokens(20));
+//! scheduler.run_until_idle();
+//!
+//! for event in events.try_iter() {
+//!     if let SessionEvent::Token(token_id) = event {
+//!         println!("{}", token_id);
+//!     }
+//! }
+//! # Ok(()) }
+//! ```
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::ops::Range;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+
+use rten::{NodeId, RunOptions, Value, ValueOrView, ValueView};
+use rten_tensor::prelude::*;
+use rten_tensor::{NdTensor, Tensor};
+
+use crate::Logits;
+use crate::filter::Chain;
+use crate::generator::{
+    Generator, GeneratorConfig, GeneratorError, KvCacheData, ModelInputsConfig, TokenId, wrap_error,
+};
+use crate::model::Model;
+use crate::sampler::Multinomial;
+
+#[cfg(feature = "lora")]
+use crate::lora::MergedLora;
+
+/// Identifies a session submitted to a [`Scheduler`].
+pub type SessionId = u64;
+
+/// Settings which control how tokens are sampled in a session.
+///
+/// If no filters or seed are set, the token with the highest probability is
+/// chosen at each step. Otherwise the filters are applied in the order
+/// top-K, temperature, top-P and a token is sampled according to the
+/// filtered probabilities.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SamplingConfig {
+    pub temperature: Option<f32>,
+    pub top_k: Option<usize>,
+    pub top_p: Option<f32>,
+
+    /// Seed for the random number generator. Set this for repeatable
+    /// sampling.
+    pub seed: Option<u64>,
+}
+
+impl SamplingConfig {
+    fn is_greedy(&self) -> bool {
+        *self == SamplingConfig::default()
+    }
+}
+
+/// Request to start a new generation session.
+#[derive(Clone, Debug)]
+pub struct SessionRequest {
+    prompt: Vec<TokenId>,
+    max_tokens: Option<usize>,
+    stop_tokens: Vec<TokenId>,
+    sampling: SamplingConfig,
+}
+
+impl SessionRequest {
+    /// Create a request to generate a continuation of `prompt`.
+    pub fn new(prompt: &[TokenId]) -> Self {
+        SessionRequest {
+            prompt: prompt.to_vec(),
+            max_tokens: None,
+            stop_tokens: Vec::new(),
+            sampling: SamplingConfig::default(),
+        }
+    }
+
+    /// Finish the session after `max_tokens` tokens have been generated.
+    pub fn with_max_tokens(mut self, max_tokens: usize) -> Self {
+        self.max_tokens = Some(max_tokens);
+        self
+    }
+
+    /// Finish the session when any of `stop_tokens` is generated.
+    ///
+    /// Stop tokens are not sent to the session as [`SessionEvent::Token`]s.
+    pub fn with_stop_tokens(mut self, stop_tokens: &[TokenId]) -> Self {
+        self.stop_tokens = stop_tokens.to_vec();
+        self
+    }
+
+    /// Set how tokens are sampled from the model's outputs.
+    pub fn with_sampling(mut self, sampling: SamplingConfig) -> Self {
+        self.sampling = sampling;
+        self
+    }
+}
+
+/// Reason why a session finished successfully.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FinishReason {
+    /// A stop token was generated.
+    StopToken,
+    /// The maximum number of tokens was generated.
+    MaxTokens,
+}
+
+/// Events sent from a [`Scheduler`] to the receiver for a session.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SessionEvent {
+    /// A token was generated.
+    Token(TokenId),
+
+    /// The session finished. No further events are sent.
+    Finished(FinishReason),
+
+    /// The session failed. No further events are sent.
+    Error(String),
+}
+
+struct Submission {
+    id: SessionId,
+    request: SessionRequest,
+    events: Sender<SessionEvent>,
+}
+
+/// Handle used to submit sessions to a [`Scheduler`].
+///
+/// Handles can be cloned and sent to other threads, while the scheduler runs
+/// on the thread which owns the model.
+#[derive(Clone)]
+pub struct SchedulerHandle {
+    submissions: Sender<Submission>,
+    next_id: Arc<AtomicU64>,
+}
+
+impl SchedulerHandle {
+    /// Submit a new session.
+    ///
+    /// Returns the ID of the session and a receiver for its events. If the
+    /// scheduler has stopped, the receiver will be disconnected without
+    /// yielding any events.
+    pub fn submit(&self, request: SessionRequest) -> (SessionId, Receiver<SessionEvent>) {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let (events, receiver) = mpsc::channel();
+        let _ = self.submissions.send(Submission {
+            id,
+            request,
+            events,
+        });
+        (id, receiver)
+    }
+}
+
+/// Active session in a [`Scheduler`].
+struct Session<'a> {
+    id: SessionId,
+    generator: Generator<'a>,
+    events: Sender<SessionEvent>,
+    stop_tokens: Vec<TokenId>,
+    max_tokens: Option<usize>,
+    n_generated: usize,
+    done: bool,
+}
+
+impl Session<'_> {
+    /// Return true if the session has processed its prompt and the next step
+    /// will feed a single sampled token into the model.
+    fn is_decoding(&self) -> bool {
+        self.generator.decode_input().is_some()
+    }
+
+    /// Report the result of a generation step to the session's receiver.
+    fn handle_result(&mut self, result: Result<TokenId, GeneratorError>) {
+        let token_id = match result {
+            Ok(token_id) => token_id,
+            Err(err) => {
+                self.finish(SessionEvent::Error(err.to_string()));
+                return;
+            }
+        };
+
+        if self.stop_tokens.contains(&token_id) {
+            self.finish(SessionEvent::Finished(FinishReason::StopToken));
+            return;
+        }
+
+        self.n_generated += 1;
+        if self.events.send(SessionEvent::Token(token_id)).is_err() {
+            // The receiver was dropped, so the session was cancelled.
+            self.done = true;
+            return;
+        }
+
+        if self.max_tokens.is_some_and(|max| self.n_generated >= max) {
+            self.finish(SessionEvent::Finished(FinishReason::MaxTokens));
+        }
+    }
+
+    fn finish(&mut self, event: SessionEvent) {
+        let _ = self.events.send(event);
+        self.done = true;
+    }
+}
+
+/// IDs of model inputs and outputs that are computed by the scheduler for
+/// batched decoding steps.
+struct BatchNodes {
+    input_ids: NodeId,
+    logits: NodeId,
+    attention_mask: Option<NodeId>,
+    position_ids: Option<NodeId>,
+    cache_position: Option<NodeId>,
+    use_cache_flag: Option<NodeId>,
+
+    /// `(input, output)` IDs of the KV cache for each layer.
+    kv_cache: Vec<(NodeId, NodeId)>,
+}
+
+impl BatchNodes {
+    fn new(
+        model: &dyn Model,
+        names: &ModelInputsConfig,
+        kv_cache: Vec<(NodeId, NodeId)>,
+    ) -> Result<BatchNodes, GeneratorError> {
+        Ok(BatchNodes {
+            input_ids: model
+                .find_node(names.input_ids)
+                .ok_or(GeneratorError::InputNotFound(names.input_ids.to_string()))?,
+            logits: model
+                .find_node(names.logits)
+                .ok_or(GeneratorError::OutputNotFound(names.logits.to_string()))?,
+            attention_mask: model.find_node(names.attention_mask),
+            position_ids: model.find_node(names.position_ids),
+            cache_position: model.find_node(names.cache_position),
+            use_cache_flag: model.find_node(names.use_cache_flag),
+            kv_cache,
+        })
+    }
+}
+
+/// Runs many generation sessions using one model, with continuous batching.
+///
+/// Each session is a [`Generator`] with its own KV cache. New sessions first
+/// process their prompt individually. Once a session is decoding, its next
+/// token is batched together with those of other decoding sessions into a
+/// single model run. Sessions with different sequence lengths are combined by
+/// left-padding their KV caches and masking the padding using the attention
+/// mask. The batched KV cache is kept between steps and is only rebuilt when
+/// the set of decoding sessions changes.
+///
+/// At most [`max_batch_size`](Self::with_max_batch_size) sessions are active
+/// at once. Further sessions wait in a queue and are admitted as others
+/// finish.
+///
+/// The model must have a self-attention KV cache, an `attention_mask` input
+/// and, if it uses positions, a `position_ids` input. Encoder-decoder models
+/// are not supported.
+pub struct Scheduler<'a> {
+    model: &'a dyn Model,
+    config: GeneratorConfig<'a>,
+    nodes: BatchNodes,
+    run_options: Option<RunOptions>,
+
+    max_batch_size: usize,
+    prefill_chunk_size: Option<usize>,
+
+    /// Handle owned by the scheduler. This is dropped by [`run`](Self::run)
+    /// so that it can detect when all other handles have been dropped.
+    handle: Option<SchedulerHandle>,
+    submissions: Receiver<Submission>,
+
+    /// Sessions which have been submitted but not yet admitted.
+    waiting: VecDeque<Submission>,
+
+    /// Sessions which are processing their prompt or decoding.
+    active: Vec<Session<'a>>,
+
+    /// Constant inputs passed to the model for every session.
+    constant_inputs: Vec<(NodeId, ValueView<'a>)>,
+
+    /// Inputs computed from `constant_inputs` using constant propagation,
+    /// for use in batched decoding steps. This is `None` if out of date.
+    constant_prop_inputs: Option<Vec<(NodeId, Value)>>,
+
+    /// Inputs which vary with the sequence position, passed to the model for
+    /// every session.
+    #[allow(clippy::type_complexity)]
+    varying_inputs: Vec<(NodeId, &'a dyn Fn(usize, Range<usize>) -> ValueOrView<'a>)>,
+
+    /// KV cache of the sessions which were decoded together in the previous
+    /// step.
+    batch: Option<DecodeBatch>,
+}
+
+impl<'a> Scheduler<'a> {
+    /// Create a scheduler using the default names for model inputs and
+    /// outputs. See [`Generator::from_model`].
+    pub fn from_model(model: &'a dyn Model) -> Result<Scheduler<'a>, GeneratorError> {
+        Self::from_model_config(model, GeneratorConfig::default())
+    }
+
+    /// Create a scheduler using custom names for model inputs and outputs.
+    pub fn from_model_config(
+        model: &'a dyn Model,
+        config: GeneratorConfig<'a>,
+    ) -> Result<Scheduler<'a>, GeneratorError> {
+        // Check the model is compatible by creating a generator for it.
+        let generator = Generator::from_model_config(model, config.clone())?;
+        let kv_cache = generator.kv_cache_nodes();
+        if kv_cache.is_empty() {
+            return Err(GeneratorError::InputNotFound(
+                "self-attention KV cache".to_string(),
+            ));
+        }
+        if generator.has_encoder_kv_cache() {
+            return Err(GeneratorError::GenerateError(
+                "encoder-decoder models are not supported".into(),
+            ));
+        }
+        let nodes = BatchNodes::new(model, &config.model_inputs, kv_cache)?;
+        if nodes.attention_mask.is_none() {
+            return Err(GeneratorError::InputNotFound(
+                config.model_inputs.attention_mask.to_string(),
+            ));
+        }
+
+        let (sender, submissions) = mpsc::channel();
+        let handle = SchedulerHandle {
+            submissions: sender,
+            next_id: Arc::new(AtomicU64::new(0)),
+        };
+
+        Ok(Scheduler {
+            model,
+            config,
+            nodes,
+            run_options: None,
+            max_batch_size: 8,
+            prefill_chunk_size: None,
+            handle: Some(handle),
+            submissions,
+            waiting: VecDeque::new(),
+            active: Vec::new(),
+            constant_inputs: Vec::new(),
+            constant_prop_inputs: None,
+            varying_inputs: Vec::new(),
+            batch: None,
+        })
+    }
+
+    /// Set the maximum number of sessions which are active at once.
+    ///
+    /// This is also the maximum batch size for decoding steps. The default is
+    /// 8.
+    ///
+    /// Panics if `max_batch_size` is zero.
+    pub fn with_max_batch_size(mut self, max_batch_size: usize) -> Self {
+        assert!(max_batch_size > 0, "max batch size must be positive");
+        self.max_batch_size = max_batch_size;
+        self
+    }
+
+    /// Process prompts in chunks of at most `chunk_size` tokens.
+    ///
+    /// One chunk of each new session's prompt is processed per step, so that
+    /// long prompts do not stall decoding of other sessions for long.
+    ///
+    /// Panics if `chunk_size` is zero.
+    pub fn with_prefill_chunk_size(mut self, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "chunk size must be positive");
+        self.prefill_chunk_size = Some(chunk_size);
+        self
+    }
+
+    /// Set execution options used when running model inference.
+    pub fn with_run_options(mut self, opts: Option<RunOptions>) -> Self {
+        self.run_options = opts;
+        self
+    }
+
+    /// Add a constant input which is provided to the model for every session.
+    ///
+    /// The value is shared by all sessions in a batch, so it must not have a
+    /// batch dimension. See [`Generator::with_constant_input`].
+    pub fn with_constant_input(mut self, input_id: NodeId, value: ValueView<'a>) -> Self {
+        self.constant_prop_inputs = None;
+        self.constant_inputs.push((input_id, value));
+        self
+    }
+
+    /// Run every session with the weights of a merged LoRA adapter.
+    ///
+    /// See [`Generator::with_lora`].
+    #[cfg(feature = "lora")]
+    pub fn with_lora(mut self, lora: &'a MergedLora) -> Self {
+        for (weight_id, value) in lora.weights() {
+            self = self.with_constant_input(weight_id, value);
+        }
+        self
+    }
+
+    /// Add an input which varies with the sequence position.
+    ///
+    /// Each session is at a different position, so sessions are decoded one
+    /// at a time instead of in batches when varying inputs are used. See
+    /// [`Generator::with_varying_input`].
+    pub fn with_varying_input<F: Fn(usize, Range<usize>) -> ValueOrView<'a>>(
+        mut self,
+        input_id: NodeId,
+        value_fn: &'a F,
+    ) -> Self {
+        self.varying_inputs.push((input_id, value_fn));
+        self
+    }
+
+    /// Return a handle which can be used to submit sessions from other
+    /// threads.
+    pub fn handle(&self) -> SchedulerHandle {
+        self.handle
+            .clone()
+            .expect("scheduler handle should be present")
+    }
+
+    /// Submit a new session.
+    ///
+    /// See [`SchedulerHandle::submit`].
+    pub fn submit(&self, request: SessionRequest) -> (SessionId, Receiver<SessionEvent>) {
+        self.handle().submit(request)
+    }
+
+    /// Return the number of sessions which are active.
+    pub fn active_sessions(&self) -> usize {
+        self.active.len()
+    }
+
+    /// Return the number of sessions waiting to be admitted.
+    ///
+    /// This does not include sessions which were submitted since the last
+    /// call to [`step`](Self::step).
+    pub fn waiting_sessions(&self) -> usize {
+        self.waiting.len()
+    }
+
+    /// Run one scheduling step.
+    ///
+    /// This admits waiting sessions, processes (a chunk of) the prompt for
+    /// new sessions and generates one token for every decoding session.
+    ///
+    /// Returns true if there are sessions that still need processing.
+    pub fn step(&mut self) -> bool {
+        self.waiting.extend(self.submissions.try_iter());
+        self.admit_sessions();
+
+        // Return the batched KV cache to the sessions if the set of decoding
+        // sessions has changed.
+        if let Some(batch) = self.batch.take() {
+            let same_sessions = self
+                .active
+                .iter()
+                .filter(|s| s.is_decoding())
+                .map(|s| s.id)
+                .eq(batch.session_ids.iter().copied());
+            if same_sessions {
+                self.batch = Some(batch);
+            } else {
+                batch.release(&mut self.active);
+            }
+        }
+
+        let (mut decoding, prefilling): (Vec<_>, Vec<_>) =
+            self.active.iter_mut().partition(|s| s.is_decoding());
+
+        for session in prefilling {
+            match self.prefill_chunk_size {
+                Some(chunk_size) if session.generator.prompt().len() > chunk_size => {
+                    if let Err(err) = session.generator.process_prompt_chunk(chunk_size) {
+                        session.finish(SessionEvent::Error(err.to_string()));
+                    }
+                }
+                _ => {
+                    let result = session.generator.next().expect("generator should not end");
+                    session.handle_result(result);
+                }
+            }
+        }
+
+        if decoding.len() == 1 || !self.varying_inputs.is_empty() {
+            // Use the generator directly to avoid the overhead of batching.
+            for session in decoding {
+                let result = session.generator.next().expect("generator should not end");
+                session.handle_result(result);
+            }
+        } else if !decoding.is_empty() {
+            let result = propagate_constants(
+                self.model,
+                &self.nodes,
+                &self.constant_inputs,
+                &mut self.constant_prop_inputs,
+                self.run_options.clone(),
+            )
+            .and_then(|constants| {
+                decode_batch(
+                    self.model,
+                    &self.nodes,
+                    self.run_options.clone(),
+                    constants,
+                    &mut self.batch,
+                    &mut decoding,
+                )
+            });
+            match result {
+                Ok(logits) => {
+                    for (session, logits) in decoding.iter_mut().zip(logits) {
+                        let result = session.generator.finish_decode_step(logits);
+                        session.handle_result(result);
+                    }
+                }
+                Err(err) => {
+                    let msg = err.to_string();
+                    for session in decoding {
+                        session.finish(SessionEvent::Error(msg.clone()));
+                    }
+                }
+            }
+        }
+
+        self.active.retain(|s| !s.done);
+
+        !self.active.is_empty() || !self.waiting.is_empty()
+    }
+
+    /// Run scheduling steps until all submitted sessions have finished.
+    pub fn run_until_idle(&mut self) {
+        while self.step() {}
+    }
+
+    /// Run scheduling steps until all handles have been dropped and all
+    /// sessions have finished.
+    ///
+    /// When there are no sessions to process, this blocks until a new session
+    /// is submitted.
+    pub fn run(mut self) {
+        self.handle = None;
+        loop {
+            if self.step() {
+                continue;
+            }
+            match self.submissions.recv() {
+                Ok(submission) => self.waiting.push_back(submission),
+                Err(_) => return,
+            }
+        }
+    }
+
+    /// Move sessions from the waiting queue into the active set.
+    fn admit_sessions(&mut self) {
+        while self.active.len() < self.max_batch_size {
+            let Some(Submission {
+                id,
+                request,
+                events,
+            }) = self.waiting.pop_front()
+            else {
+                break;
+            };
+
+            if request.prompt.is_empty() {
+                let _ = events.send(SessionEvent::Error("prompt is empty".to_string()));
+                continue;
+            }
+
+            let generator = match Generator::from_model_config(self.model, self.config.clone()) {
+                Ok(generator) => generator,
+                Err(err) => {
+                    let _ = events.send(SessionEvent::Error(err.to_string()));
+                    continue;
+                }
+            };
+            let mut generator = configure_sampling(generator, &request.sampling)
+                .with_run_options(self.run_options.clone())
+                .with_prompt(&request.prompt);
+            for (input_id, value) in &self.constant_inputs {
+                generator = generator.with_constant_input(*input_id, value.clone());
+            }
+            for (input_id, value_fn) in &self.varying_inputs {
+                generator = generator.with_varying_input_dyn(*input_id, *value_fn);
+            }
+
+            self.active.push(Session {
+                id,
+                generator,
+                events,
+                stop_tokens: request.stop_tokens,
+                max_tokens: request.max_tokens,
+                n_generated: 0,
+                done: false,
+            });
+        }
+    }
+}
+
+impl fmt::Debug for Scheduler<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Scheduler")
+            .field("max_batch_size", &self.max_batch_size)
+            .field("prefill_chunk_size", &self.prefill_chunk_size)
+            .field(
+                "active",
+                &self.active.iter().map(|s| s.id).collect::<Vec<_>>(),
+            )
+            .field(
+                "waiting",
+                &self.waiting.iter().map(|s| s.id).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+/// Apply the filters and sampler for a session to a generator.
+fn configure_sampling<'a>(generator: Generator<'a>, sampling: &SamplingConfig) -> Generator<'a> {
+    if sampling.is_greedy() {
+        return generator;
+    }
+
+    let mut filter = Chain::new();
+    if let Some(k) = sampling.top_k {
+        filter = filter.top_k(k);
+    }
+    if let Some(temp) = sampling.temperature {
+        filter = filter.temperature(temp);
+    }
+    if let Some(p) = sampling.top_p {
+        filter = filter.top_p(p);
+    }
+    let sampler = match sampling.seed {
+        Some(seed) => Multinomial::with_seed(seed),
+        None => Multinomial::new(),
+    };
+
+    generator.with_logits_filter(filter).with_sampler(sampler)
+}
+
+/// Compute the constant-propagated inputs for batched decoding steps, if they
+/// are out of date.
+fn propagate_constants<'c>(
+    model: &dyn Model,
+    nodes: &BatchNodes,
+    constant_inputs: &[(NodeId, ValueView)],
+    constant_prop_inputs: &'c mut Option<Vec<(NodeId, Value)>>,
+    run_options: Option<RunOptions>,
+) -> Result<&'c [(NodeId, Value)], GeneratorError> {
+    if constant_prop_inputs.is_none() {
+        let inputs = constant_inputs
+            .iter()
+            .map(|(node_id, value)| (*node_id, value.clone().into()))
+            .collect();
+        let outputs = model
+            .partial_run(inputs, &[nodes.logits], run_options)
+            .map_err(|e| {
+                wrap_error(e, "failed to partially evaluate model with constant inputs")
+            })?;
+        *constant_prop_inputs = Some(outputs);
+    }
+    Ok(constant_prop_inputs.as_deref().unwrap_or_default())
+}
+
+/// KV cache for a batch of sessions which are decoded together.
+///
+/// Each session's cache is left-padded to the same length. The batch is kept
+/// between decoding steps while the set of decoding sessions does not change,
+/// so that the model can append to the batched cache in place.
+struct DecodeBatch {
+    /// IDs of the sessions in the batch, in batch order.
+    session_ids: Vec<SessionId>,
+
+    /// Number of padding positions at the start of each session's cache.
+    padding: Vec<usize>,
+
+    /// Batched cache for each layer.
+    caches: Vec<KvCacheData>,
+}
+
+impl DecodeBatch {
+    /// Move the KV caches of `sessions` into a new batch.
+    fn new(sessions: &mut [&mut Session]) -> Result<DecodeBatch, GeneratorError> {
+        let session_caches: Vec<Vec<KvCacheData>> = sessions
+            .iter_mut()
+            .map(|s| s.generator.take_kv_cache())
+            .collect();
+        let seq_lens: Vec<usize> = session_caches
+            .iter()
+            .map(|caches| caches.first().map(|c| c.sequence_len()).unwrap_or(0))
+            .collect();
+        let max_len = seq_lens.iter().copied().max().unwrap_or(0);
+
+        // Reserve space for the next steps, doubling the capacity when it
+        // runs out, as `Generator` does.
+        let capacity = (max_len * 2).max(max_len + 1);
+
+        let n_layers = session_caches.first().map(|c| c.len()).unwrap_or(0);
+        let caches = (0..n_layers)
+            .map(|layer| {
+                let layer_caches: Vec<&KvCacheData> =
+                    session_caches.iter().map(|c| &c[layer]).collect();
+                pad_kv_caches(&layer_caches, max_len, capacity)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(DecodeBatch {
+            session_ids: sessions.iter().map(|s| s.id).collect(),
+            padding: seq_lens.iter().map(|len| max_len - len).collect(),
+            caches,
+        })
+    }
+
+    /// Move the KV caches in this batch back into the sessions, removing the
+    /// padding.
+    ///
+    /// Sessions in the batch which are no longer active are skipped.
+    fn release(self, sessions: &mut [Session]) {
+        for session in sessions {
+            let Some(b) = self.session_ids.iter().position(|id| *id == session.id) else {
+                continue;
+            };
+            let caches = self
+                .caches
+                .iter()
+                .map(|cache| unpad_kv_cache(cache, b, self.padding[b]))
+                .collect();
+            session.generator.restore_kv_cache(caches);
+        }
+    }
+}
+
+/// Run one decoding step for a batch of sessions.
+///
+/// If `batch` contains the KV cache for the same sessions from the previous
+/// step, it is reused. Otherwise the sessions' caches are moved into a new
+/// batch. On success `batch` is updated with the extended caches.
+///
+/// Returns the logits for the next token of each session.
+fn decode_batch(
+    model: &dyn Model,
+    nodes: &BatchNodes,
+    run_options: Option<RunOptions>,
+    constants: &[(NodeId, Value)],
+    batch: &mut Option<DecodeBatch>,
+    sessions: &mut [&mut Session],
+) -> Result<Vec<Logits>, GeneratorError> {
+    let DecodeBatch {
+        session_ids,
+        padding,
+        caches,
+    } = match batch.take() {
+        Some(batch) => batch,
+        None => DecodeBatch::new(sessions)?,
+    };
+
+    let batch_size = sessions.len();
+    let past_len = caches.first().map(|c| c.sequence_len()).unwrap_or(0);
+    let decode_inputs: Vec<(TokenId, usize)> = sessions
+        .iter()
+        .map(|s| {
+            s.generator
+                .decode_input()
+                .expect("session should be decoding")
+        })
+        .collect();
+
+    let input_ids = NdTensor::from_fn([batch_size, 1], |[b, _]| decode_inputs[b].0 as i32);
+    let mut model_inputs: Vec<(NodeId, ValueOrView)> = vec![(nodes.input_ids, input_ids.into())];
+
+    if let Some(attention_mask) = nodes.attention_mask {
+        let mask = NdTensor::from_fn([batch_size, past_len + 1], |[b, pos]| {
+            (pos >= padding[b]) as i32
+        });
+        model_inputs.push((attention_mask, mask.into()));
+    }
+    if let Some(position_ids) = nodes.position_ids {
+        let positions = NdTensor::from_fn([batch_size, 1], |[b, _]| decode_inputs[b].1 as i32);
+        model_inputs.push((position_ids, positions.into()));
+    }
+    if let Some(cache_position) = nodes.cache_position {
+        // Cache positions are shared across the batch, so use the position
+        // in the padded cache.
+        let positions = NdTensor::from_data([1], vec![past_len as i32]);
+        model_inputs.push((cache_position, positions.into()));
+    }
+    if let Some(use_cache_flag) = nodes.use_cache_flag {
+        model_inputs.push((use_cache_flag, Tensor::from(1i32).into()));
+    }
+
+    model_inputs.extend(
+        constants
+            .iter()
+            .map(|(node_id, value)| (*node_id, value.as_view().into())),
+    );
+
+    // Pass the batched caches to the model by value, so that it can append
+    // the entries for this step without copying the existing buffer.
+    for (cache, (input_id, _)) in caches.into_iter().zip(&nodes.kv_cache) {
+        let value: ValueOrView = match cache {
+            KvCacheData::BatchSeqChans(cache) => cache.into(),
+            KvCacheData::BatchHeadSeqChans(cache) => cache.into(),
+        };
+        model_inputs.push((*input_id, value));
+    }
+
+    let mut model_outputs: Vec<NodeId> = nodes.kv_cache.iter().map(|(_, id)| *id).collect();
+    model_outputs.push(nodes.logits);
+
+    let mut outputs = model
+        .run(model_inputs, &model_outputs, run_options)
+        .map_err(|e| wrap_error(e, "failed to run model"))?;
+
+    let logits: NdTensor<f32, 3> = outputs
+        .pop()
+        .expect("should have logits")
+        .try_into()
+        .map_err(|e| wrap_error(e, "failed to extract logits from model outputs"))?;
+
+    let caches = outputs
+        .into_iter()
+        .map(|output| {
+            let mut cache = KvCacheData::from_output(output)?;
+            let required_len = cache.sequence_len() + 1;
+            if !cache.has_capacity(required_len) {
+                cache = cache.clone_with_capacity((cache.sequence_len() * 2).max(required_len));
+            }
+            Ok(cache)
+        })
+        .collect::<Result<_, GeneratorError>>()?;
+
+    *batch = Some(DecodeBatch {
+        session_ids,
+        padding,
+        caches,
+    });
+
+    Ok((0..batch_size)
+        .map(|b| Logits::dense(logits.slice((b, -1)).to_contiguous().to_vec()))
+        .collect())
+}
+
+/// Combine the KV caches for a layer from several sessions into one batch,
+/// left-padding each to a sequence length of `max_len`.
+///
+/// The result has capacity to be extended to a sequence length of
+/// `capacity` without re-allocating.
+fn pad_kv_caches(
+    caches: &[&KvCacheData],
+    max_len: usize,
+    capacity: usize,
+) -> Result<KvCacheData, GeneratorError> {
+    let err_context = "failed to batch KV caches";
+    let layout_mismatch = || wrap_error("sessions have different KV cache layouts", err_context);
+
+    match caches[0] {
+        KvCacheData::BatchSeqChans(first) => {
+            let [_, _, chans] = first.shape();
+            let mut padded = NdTensor::with_capacity([caches.len(), capacity, chans], 1);
+            padded
+                .append(1, &NdTensor::zeros([caches.len(), max_len, chans]))
+                .expect("should have capacity");
+            for (b, cache) in caches.iter().enumerate() {
+                let KvCacheData::BatchSeqChans(cache) = cache else {
+                    return Err(layout_mismatch());
+                };
+                let start = max_len - cache.size(1);
+                padded.slice_mut((b..b + 1, start.., ..)).copy_from(cache);
+            }
+            Ok(KvCacheData::BatchSeqChans(padded))
+        }
+        KvCacheData::BatchHeadSeqChans(first) => {
+            let [_, n_heads, _, chans] = first.shape();
+            let mut padded = NdTensor::with_capacity([caches.len(), n_heads, capacity, chans], 2);
+            padded
+                .append(2, &NdTensor::zeros([caches.len(), n_heads, max_len, chans]))
+                .expect("should have capacity");
+            for (b, cache) in caches.iter().enumerate() {
+                let KvCacheData::BatchHeadSeqChans(cache) = cache else {
+                    return Err(layout_mismatch());
+                };
+                let start = max_len - cache.size(2);
+                padded
+                    .slice_mut((b..b + 1, .., start.., ..))
+                    .copy_from(cache);
+            }
+            Ok(KvCacheData::BatchHeadSeqChans(padded))
+        }
+    }
+}
+
+/// Extract the KV cache for the `index`th session from a batched cache,
+/// removing the first `padding` positions.
+fn unpad_kv_cache(cache: &KvCacheData, index: usize, padding: usize) -> KvCacheData {
+    // Leave space to append the next token without re-allocating.
+    let capacity = cache.sequence_len() - padding + 1;
+    match cache {
+        KvCacheData::BatchSeqChans(cache) => {
+            let [_, _, chans] = cache.shape();
+            let mut unpadded = NdTensor::with_capacity([1, capacity, chans], 1);
+            unpadded
+                .append(1, &cache.slice((index..index + 1, padding.., ..)))
+                .expect("should have capacity");
+            KvCacheData::BatchSeqChans(unpadded)
+        }
+        KvCacheData::BatchHeadSeqChans(cache) => {
+            let [_, n_heads, _, chans] = cache.shape();
+            let mut unpadded = NdTensor::with_capacity([1, n_heads, capacity, chans], 2);
+            unpadded
+                .append(2, &cache.slice((index..index + 1, .., padding.., ..)))
+                .expect("should have capacity");
+            KvCacheData::BatchHeadSeqChans(unpadded)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::error::Error;
+    use std::sync::mpsc::Receiver;
+
+    use rten::{Dimension, NodeId, RunOptions, Value, ValueOrView};
+    use rten_tensor::NdTensor;
+    use rten_tensor::prelude::*;
+
+    use super::{FinishReason, Scheduler, SessionEvent, SessionRequest};
+    use crate::model::{Model, NodeInfo};
+
+    const N_VOCAB: usize = 32;
+
+    /// Fake single-layer decoder whose next token is the last input token
+    /// plus one, plus the value of the optional `token_offset` input.
+    ///
+    /// Each KV cache entry stores `token_id + 1` so that the model can check
+    /// that the cache contents match the attention mask and position IDs.
+    struct CountingModel {
+        nodes: Vec<NodeInfo>,
+        input_ids: Vec<NodeId>,
+
+        /// Shape of `input_ids` for each run.
+        runs: RefCell<Vec<[usize; 2]>>,
+    }
+
+    impl CountingModel {
+        fn new() -> CountingModel {
+            let kv_shape = [
+                Dimension::Symbolic("batch".to_string()),
+                Dimension::Fixed(1),
+                Dimension::Symbolic("seq".to_string()),
+                Dimension::Fixed(1),
+            ];
+            let nodes = vec![
+                NodeInfo::from_name_shape("input_ids", &[]),
+                NodeInfo::from_name_shape("attention_mask", &[]),
+                NodeInfo::from_name_shape("position_ids", &[]),
+                NodeInfo::from_name_shape("past_key_values.0.key", &kv_shape),
+                NodeInfo::from_name_shape("logits", &[]),
+                NodeInfo::from_name_shape("present.0.key", &kv_shape),
+                NodeInfo::from_name_shape("token_offset", &[]),
+            ];
+            CountingModel {
+                nodes,
+                input_ids: [0, 1, 2, 3, 6].map(NodeId::from_u32).to_vec(),
+                runs: RefCell::new(Vec::new()),
+            }
+        }
+
+        fn batch_sizes(&self) -> Vec<usize> {
+            self.runs.borrow().iter().map(|[batch, _]| *batch).collect()
+        }
+    }
+
+    impl Model for CountingModel {
+        fn find_node(&self, name: &str) -> Option<NodeId> {
+            self.nodes
+                .iter()
+                .position(|info| info.name() == name)
+                .map(|pos| NodeId::from_u32(pos as u32))
+        }
+
+        fn node_info(&self, id: NodeId) -> Option<NodeInfo> {
+            self.nodes.get(id.as_usize()).cloned()
+        }
+
+        fn input_ids(&self) -> &[NodeId] {
+            &self.input_ids
+        }
+
+        fn run(
+            &self,
+            inputs: Vec<(NodeId, ValueOrView)>,
+            outputs: &[NodeId],
+            _opts: Option<RunOptions>,
+        ) -> Result<Vec<Value>, Box<dyn Error>> {
+            let get = |name: &str| {
+                let id = self.find_node(name).unwrap();
+                inputs
+                    .iter()
+                    .find(|(input_id, _)| *input_id == id)
+                    .map(|(_, value)| value.to_owned())
+                    .ok_or(format!("missing input {}", name))
+            };
+            let input_ids: NdTensor<i32, 2> = get("input_ids")?.try_into()?;
+            let mask: NdTensor<i32, 2> = get("attention_mask")?.try_into()?;
+            let position_ids: NdTensor<i32, 2> = get("position_ids")?.try_into()?;
+            let past: NdTensor<f32, 4> = get("past_key_values.0.key")?.try_into()?;
+            let token_offset = match get("token_offset") {
+                Ok(offset) => {
+                    let offset: NdTensor<i32, 0> = offset.try_into()?;
+                    offset.item().copied().unwrap() as usize
+                }
+                Err(_) => 0,
+            };
+
+            let [batch, seq] = input_ids.shape();
+            let past_len = past.size(2);
+            self.runs.borrow_mut().push([batch, seq]);
+
+            if mask.shape() != [batch, past_len + seq] {
+                return Err("attention mask has wrong shape".into());
+            }
+            for b in 0..batch {
+                for pos in 0..past_len {
+                    let valid = mask[[b, pos]] == 1;
+                    let is_padding = past[[b, 0, pos, 0]] == 0.;
+                    if valid == is_padding {
+                        return Err("attention mask does not match KV cache".into());
+                    }
+                }
+                let n_valid = mask.slice(b).iter().filter(|x| **x == 1).count();
+                if position_ids[[b, seq - 1]] as usize != n_valid - 1 {
+                    return Err("position IDs do not match attention mask".into());
+                }
+            }
+
+            let new_entries = NdTensor::from_fn([batch, 1, seq, 1], |[b, _, pos, _]| {
+                input_ids[[b, pos]] as f32 + 1.
+            });
+            let mut present = NdTensor::zeros([batch, 1, past_len + seq, 1]);
+            present.slice_mut((.., .., ..past_len, ..)).copy_from(&past);
+            present
+                .slice_mut((.., .., past_len.., ..))
+                .copy_from(&new_entries);
+
+            let logits = NdTensor::from_fn([batch, seq, N_VOCAB], |[b, pos, tok]| {
+                let next = (input_ids[[b, pos]] as usize + 1 + token_offset) % N_VOCAB;
+                if tok == next { 1. } else { 0. }
+            });
+
+            Ok(outputs
+                .iter()
+                .map(|&id| match self.node_info(id).unwrap().name() {
+                    "logits" => Value::from(logits.clone()),
+                    _ => Value::from(present.clone()),
+                })
+                .collect())
+        }
+
+        fn partial_run(
+            &self,
+            inputs: Vec<(NodeId, ValueOrView)>,
+            _outputs: &[NodeId],
+            _opts: Option<RunOptions>,
+        ) -> Result<Vec<(NodeId, Value)>, Box<dyn Error>> {
+            // Pass constant inputs through unchanged.
+            Ok(inputs
+                .into_iter()
+                .map(|(id, value)| (id, value.to_owned()))
+                .collect())
+        }
+    }
+
+    fn collect_tokens(events: &Receiver<SessionEvent>) -> (Vec<u32>, Option<SessionEvent>) {
+        let mut tokens = Vec::new();
+        let mut last = None;
+        for event in events.try_iter() {
+            match event {
+                SessionEvent::Token(id) => tokens.push(id),
+                other => last = Some(other),
+            }
+        }
+        (tokens, last)
+    }
+
+    #[test]
+    fn test_continuous_batching() -> Result<(), Box<dyn Error>> {
+        let model = CountingModel::new();
+        let mut scheduler = Scheduler::from_model(&model)?.with_max_batch_size(4);
+
+        let (_, events_a) = scheduler.submit(SessionRequest::new(&[1, 2, 3]).with_max_tokens(4));
+        let (_, events_b) = scheduler.submit(SessionRequest::new(&[5]).with_max_tokens(4));
+        let (_, events_c) = scheduler.submit(SessionRequest::new(&[7, 8]).with_max_tokens(2));
+        scheduler.run_until_idle();
+
+        let max_tokens = Some(SessionEvent::Finished(FinishReason::MaxTokens));
+        assert_eq!(
+            collect_tokens(&events_a),
+            (vec![4, 5, 6, 7], max_tokens.clone())
+        );
+        assert_eq!(
+            collect_tokens(&events_b),
+            (vec![6, 7, 8, 9], max_tokens.clone())
+        );
+        assert_eq!(collect_tokens(&events_c), (vec![9, 10], max_tokens));
+
+        // Three prompts are processed individually, followed by one step
+        // which decodes all sessions together and then two steps after the
+        // third session has finished.
+        assert_eq!(model.batch_sizes(), [1, 1, 1, 3, 2, 2]);
+        assert_eq!(scheduler.active_sessions(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_session_joins_decoding_batch() -> Result<(), Box<dyn Error>> {
+        let model = CountingModel::new();
+        let mut scheduler = Scheduler::from_model(&model)?.with_max_batch_size(4);
+
+        let (_, events_a) = scheduler.submit(SessionRequest::new(&[1, 2]).with_max_tokens(5));
+        let (_, events_b) = scheduler.submit(SessionRequest::new(&[10]).with_max_tokens(5));
+
+        // Decode several steps using the same batch before another session
+        // joins, so that the batched KV cache is reused and then split.
+        for _ in 0..3 {
+            scheduler.step();
+        }
+        let (_, events_c) = scheduler.submit(SessionRequest::new(&[20, 21, 22]).with_max_tokens(2));
+        scheduler.run_until_idle();
+
+        let max_tokens = Some(SessionEvent::Finished(FinishReason::MaxTokens));
+        assert_eq!(
+            collect_tokens(&events_a),
+            (vec![3, 4, 5, 6, 7], max_tokens.clone())
+        );
+        assert_eq!(
+            collect_tokens(&events_b),
+            (vec![11, 12, 13, 14, 15], max_tokens.clone())
+        );
+        assert_eq!(collect_tokens(&events_c), (vec![23, 24], max_tokens));
+        assert_eq!(model.batch_sizes(), [1, 1, 2, 2, 1, 2, 3]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_constant_input() -> Result<(), Box<dyn Error>> {
+        let model = CountingModel::new();
+        let offset = NdTensor::from(2i32);
+        let offset_id = model.find_node("token_offset").unwrap();
+        let mut scheduler =
+            Scheduler::from_model(&model)?.with_constant_input(offset_id, offset.view().into());
+
+        let (_, events_a) = scheduler.submit(SessionRequest::new(&[1]).with_max_tokens(3));
+        let (_, events_b) = scheduler.submit(SessionRequest::new(&[2, 3]).with_max_tokens(3));
+        scheduler.run_until_idle();
+
+        // The offset applies to prompt processing and batched decoding.
+        assert_eq!(collect_tokens(&events_a).0, [4, 7, 10]);
+        assert_eq!(collect_tokens(&events_b).0, [6, 9, 12]);
+        assert_eq!(model.batch_sizes(), [1, 1, 2, 2]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_varying_input() -> Result<(), Box<dyn Error>> {
+        let model = CountingModel::new();
+        let offset_id = model.find_node("token_offset").unwrap();
+        let offset_fn = |_batch_size, _positions| NdTensor::from(1i32).into();
+        let mut scheduler =
+            Scheduler::from_model(&model)?.with_varying_input(offset_id, &offset_fn);
+
+        let (_, events_a) = scheduler.submit(SessionRequest::new(&[1]).with_max_tokens(2));
+        let (_, events_b) = scheduler.submit(SessionRequest::new(&[2]).with_max_tokens(2));
+        scheduler.run_until_idle();
+
+        // Sessions using varying inputs are decoded individually.
+        assert_eq!(collect_tokens(&events_a).0, [3, 5]);
+        assert_eq!(collect_tokens(&events_b).0, [4, 6]);
+        assert_eq!(model.batch_sizes(), [1, 1, 1, 1]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_admits_waiting_sessions() -> Result<(), Box<dyn Error>> {
+        let model = CountingModel::new();
+        let mut scheduler = Scheduler::from_model(&model)?.with_max_batch_size(1);
+
+        let (_, events_a) = scheduler.submit(SessionRequest::new(&[1]).with_stop_tokens(&[4]));
+        let (_, events_b) = scheduler.submit(SessionRequest::new(&[10]).with_max_tokens(1));
+
+        assert!(scheduler.step());
+        assert_eq!(scheduler.active_sessions(), 1);
+        assert_eq!(scheduler.waiting_sessions(), 1);
+        scheduler.run_until_idle();
+
+        assert_eq!(
+            collect_tokens(&events_a),
+            (
+                vec![2, 3],
+                Some(SessionEvent::Finished(FinishReason::StopToken))
+            )
+        );
+        assert_eq!(
+            collect_tokens(&events_b),
+            (
+                vec![11],
+                Some(SessionEvent::Finished(FinishReason::MaxTokens))
+            )
+        );
+        assert!(model.batch_sizes().iter().all(|size| *size == 1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_chunked_prefill() -> Result<(), Box<dyn Error>> {
+        let model = CountingModel::new();
+        let mut scheduler = Scheduler::from_model(&model)?.with_prefill_chunk_size(2);
+
+        let (_, events) =
+            scheduler.submit(SessionRequest::new(&[1, 2, 3, 4, 5]).with_max_tokens(2));
+        scheduler.run_until_idle();
+
+        assert_eq!(collect_tokens(&events).0, [6, 7]);
+        assert_eq!(*model.runs.borrow(), [[1, 2], [1, 2], [1, 1], [1, 1]]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cancelled_session() -> Result<(), Box<dyn Error>> {
+        let model = CountingModel::new();
+        let mut scheduler = Scheduler::from_model(&model)?;
+
+        let (_, events) = scheduler.submit(SessionRequest::new(&[1]));
+        scheduler.step();
+        assert_eq!(scheduler.active_sessions(), 1);
+
+        drop(events);
+        scheduler.step();
+        assert_eq!(scheduler.active_sessions(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty_prompt() -> Result<(), Box<dyn Error>> {
+        let model = CountingModel::new();
+        let mut scheduler = Scheduler::from_model(&model)?;
+
+        let (_, events) = scheduler.submit(SessionRequest::new(&[]));
+        scheduler.run_until_idle();
+
+        assert_eq!(
+            collect_tokens(&events),
+            (vec![], Some(SessionEvent::Error("prompt is empty".into())))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_from_other_thread() -> Result<(), Box<dyn Error>> {
+        let model = CountingModel::new();
+        let scheduler = Scheduler::from_model(&model)?;
+        let handle = scheduler.handle();
+
+        let client = std::thread::spawn(move || {
+            let (_, events) = handle.submit(SessionRequest::new(&[20]).with_max_tokens(3));
+            events.iter().collect::<Vec<_>>()
+        });
+        scheduler.run();
+
+        let events = client.join().unwrap();
+        assert_eq!(
+            events,
+            [
+                SessionEvent::Token(21),
+                SessionEvent::Token(22),
+                SessionEvent::Token(23),
+                SessionEvent::Finished(FinishReason::MaxTokens),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_requires_kv_cache() {
+        let mut model = CountingModel::new();
+        model.nodes.truncate(3);
+        model.nodes.push(NodeInfo::from_name_shape("logits", &[]));
+        model.input_ids.truncate(3);
+
+        let err = Scheduler::from_model(&model).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "model input not found: self-attention KV cache"
+        );
+    }
+}
@@ -1,18 +1,26 @@
 //! Iterator adapters to decode token IDs into text using `rten-text`.
 
 use rten_text::models::DecodeError;
-use rten_text::{TokenId, Tokenizer, TokenizerError};
+use rten_text::{StreamingDecoder, TokenId, Tokenizer, TokenizerError};
 
 use crate::generator::{GeneratorError, GeneratorItem};
 
 /// Wraps a [`Generator`](crate::Generator) to decode the output token IDs from
 /// the model into text using a [`Tokenizer`].
 ///
+/// Tokens are decoded incrementally using a [`StreamingDecoder`], so that
+/// concatenating the outputs produces the same text as decoding all the
+/// tokens at once with [`Tokenizer::decode`].
+///
 /// This is normally created by calling [`decode`](crate::GeneratorUtils::decode)
 /// on a `Generator`.
 pub struct TextDecoder<'a, G: Iterator<Item = GeneratorItem>> {
     generator: G,
     tokenizer: &'a Tokenizer,
+    decoder: StreamingDecoder<'a>,
+
+    /// Token IDs received since text was last returned.
+    token_buf: Vec<TokenId>,
 }
 
 impl<'a, G> TextDecoder<'a, G>
@@ -24,6 +32,8 @@ where
         TextDecoder {
             generator,
             tokenizer,
+            decoder: StreamingDecoder::new(tokenizer),
+            token_buf: Vec::new(),
         }
     }
 
@@ -33,25 +43,22 @@ where
     }
 
     fn next_with_ids(&mut self) -> Option<Result<(Vec<TokenId>, String), GeneratorError>> {
-        // Buffer that holds model output tokens until it forms a valid UTF-8
-        // sequence.
-        let mut token_buf = Vec::new();
-
         for token in self.generator.by_ref() {
             let token = match token {
                 Ok(tok) => tok,
                 Err(err) => return Some(Err(err)),
             };
 
-            token_buf.push(token);
-
-            let text = self.tokenizer.decode(&token_buf);
-            match text {
-                Ok(text) => return Some(Ok((token_buf, text))),
-                Err(TokenizerError::DecodeError(DecodeError::InvalidUtf8)) => {
-                    // If the current token sequence doesn't correspond to a
-                    // complete UTF-8 sequence, add more tokens until it does.
-                    continue;
+            match self.decoder.step(token) {
+                Ok(Some(text)) => {
+                    self.token_buf.push(token);
+                    return Some(Ok((std::mem::take(&mut self.token_buf), text)));
+                }
+                Ok(None) => {
+                    // If the token doesn't add any text yet, for example
+                    // because it is part of a UTF-8 sequence, add more tokens
+                    // until it does.
+                    self.token_buf.push(token);
                 }
                 Err(err) => {
                     return Some(Err(GeneratorError::DecodeError(err)));
@@ -59,11 +66,20 @@ where
             }
         }
 
-        if !token_buf.is_empty() {
-            return Some(Ok((token_buf, String::new())));
+        if self.token_buf.is_empty() {
+            return None;
         }
 
-        None
+        // Decode any tokens left over at the end of the output. These will
+        // not produce any text if they form an incomplete UTF-8 sequence.
+        let text = match self.tokenizer.decode(self.decoder.pending()) {
+            Ok(text) => text,
+            Err(TokenizerError::DecodeError(DecodeError::InvalidUtf8)) => String::new(),
+            Err(err) => return Some(Err(GeneratorError::DecodeError(err))),
+        };
+        self.decoder = StreamingDecoder::new(self.tokenizer);
+
+        Some(Ok((std::mem::take(&mut self.token_buf), text)))
     }
 }
 
@@ -107,7 +123,8 @@ impl<G: Iterator<Item = GeneratorItem>> Iterator for TextDecoderWithIds<'_, G> {
 mod tests {
     use std::collections::HashMap;
 
-    use rten_text::models::{Bpe, BpeOptions, WordPiece};
+    use rten_text::decoders::{ByteFallback, Fuse, Replace, Sequence, Strip};
+    use rten_text::models::{Bpe, BpeOptions, Unigram, WordPiece};
     use rten_text::pre_tokenizers::Split;
     use rten_text::{TokenId, Tokenizer};
 
@@ -139,7 +156,7 @@ mod tests {
             .decode(&tokenizer)
             .map(|tok| tok.map_err(|e| e.to_string()))
             .collect();
-        assert_eq!(tokens, ["one", "two", "three"].map(|s| Ok(s.to_string())));
+        assert_eq!(tokens, ["one", " two", " three"].map(|s| Ok(s.to_string())));
     }
 
     #[test]
@@ -155,12 +172,57 @@ mod tests {
             tokens,
             [
                 Ok(([1].into(), "one".into())),
-                Ok(([2].into(), "two".into())),
-                Ok(([3].into(), "three".into())),
+                Ok(([2].into(), " two".into())),
+                Ok(([3].into(), " three".into())),
             ]
         );
     }
 
+    #[test]
+    fn test_decode_matches_tokenizer_decode() {
+        let json = r#"{
+          "model": {
+            "type": "Unigram",
+            "unk_id": 0,
+            "byte_fallback": true,
+            "vocab": [
+              ["<unk>", 0.0], ["▁Hello", -1.0], ["▁world", -1.0], ["▁", -2.0],
+              ["<0xC3>", -3.0], ["<0xA9>", -3.0]
+            ]
+          },
+          "pre_tokenizer": {
+            "type": "Metaspace",
+            "replacement": "▁",
+            "prepend_scheme": "always",
+            "split": false
+          },
+          "decoder": {
+            "type": "Sequence",
+            "decoders": [
+              {"type": "Replace", "pattern": {"String": "▁"}, "content": " "},
+              {"type": "ByteFallback"},
+              {"type": "Fuse"},
+              {"type": "Strip", "content": " ", "start": 1, "stop": 0}
+            ]
+          }
+        }"#;
+        let tokenizer = Tokenizer::from_json(json).unwrap();
+        let token_ids = tokenizer
+            .encode("Hello world é", None)
+            .unwrap()
+            .into_token_ids();
+        let expected = tokenizer.decode(&token_ids).unwrap();
+
+        let generator = token_ids.into_iter().map(Ok);
+        let tokens: Vec<_> = generator
+            .decode(&tokenizer)
+            .map(|tok| tok.unwrap())
+            .collect();
+
+        assert_eq!(tokens, ["Hello", " world", " ", "é"]);
+        assert_eq!(tokens.concat(), expected);
+    }
+
     #[test]
     fn test_decode_partial_utf8() {
         let tokenizer = create_bpe_tokenizer();
@@ -180,6 +242,35 @@ mod tests {
         assert_eq!(tokens, ["😊"].map(|s| Ok(s.to_string())));
     }
 
+    #[test]
+    fn test_decode_byte_fallback() {
+        let vocab = ["<unk>", "▁caf", "<0xC3>", "<0xA9>"]
+            .into_iter()
+            .map(|token| (token.to_string(), -1.0))
+            .collect();
+        let model = Unigram::from_vocab(vocab, Default::default()).unwrap();
+
+        // Decoder pipeline used by Llama-family tokenizers.
+        let decoder = Sequence::from_vec(vec![
+            Box::new(Replace::new("▁", " ".to_string()).unwrap()),
+            Box::new(ByteFallback::new()),
+            Box::new(Fuse::new()),
+            Box::new(Strip::new(' ', 1, 0)),
+        ]);
+        let tokenizer = Tokenizer::new(model, Default::default()).with_decoder(Box::new(decoder));
+
+        // Byte tokens are accumulated by the text decoder until they form a
+        // complete character. The leading space is stripped from the first
+        // token.
+        let generator = [1, 2, 3].into_iter().map(Ok);
+        let tokens: Vec<_> = generator
+            .decode(&tokenizer)
+            .map(|tok| tok.map_err(|e| e.to_string()))
+            .collect();
+
+        assert_eq!(tokens, ["caf", "é"].map(|s| Ok(s.to_string())));
+    }
+
     #[test]
     fn test_decode_ids_partial_utf8() {
         let tokenizer = create_bpe_tokenizer();
@@ -223,7 +314,7 @@ mod tests {
             [
                 Ok("one".to_string()),
                 Err("generation error: oh no".to_string()),
-                Ok("three".to_string())
+                Ok(" three".to_string())
             ]
         );
     }
@@ -243,7 +334,7 @@ mod tests {
             [
                 Ok("one".to_string()),
                 Err("decode error: decoding failed: cannot decode unknown token ID 5".to_string()),
-                Ok("three".to_string())
+                Ok(" three".to_string())
             ]
         );
     }
@@ -0,0 +1,570 @@
+//! This crate provides a parser for [GGUF][gguf] ML model files.
+//!
+//! # About GGUF models
+//!
+//! GGUF is the file format used by [llama.cpp][llama_cpp] and related
+//! projects. A GGUF file consists of a header, a list of typed key-value
+//! metadata entries, a list of tensor descriptions and a tensor data section.
+//!
+//! Unlike ONNX models, GGUF files do not describe the model's computation
+//! graph. Instead the metadata specifies the model architecture (eg. "llama")
+//! and its hyperparameters, and the consumer is expected to construct the
+//! graph. The metadata also usually contains the model's tokenizer.
+//!
+//! Tensor data may be stored in floating point formats or one of several
+//! block-quantized formats. The [`dequantize`] function converts tensor data
+//! to f32 values.
+//!
+//! # Usage
+//!
+//! To read a model from a file:
+//!
+//! ```no_run
+//! use std::error::Error;
+//!
+//! use rten_gguf::GgufFile;
+//!
+//! fn main() -> Result<(), Box<dyn Error>> {
+//!     let data = std::fs::read("model.gguf")?;
+//!     let file = GgufFile::parse(&data)?;
+//!
+//!     let arch = file.get_str("general.architecture").unwrap_or("unknown");
+//!     println!("Model architecture {} with {} tensors", arch, file.tensors().len());
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [gguf]: https://github.com/ggml-org/ggml/blob/master/docs/gguf.md
+//! [llama_cpp]: https://github.com/ggml-org/llama.cpp
+
+use std::error::Error;
+use std::fmt;
+
+mod quant;
+mod types;
+mod writer;
+
+pub use quant::{dequantize, q4_k_scale_min};
+pub use types::GgmlType;
+pub use writer::GgufWriter;
+
+/// The 4-byte identifier at the start of GGUF files.
+pub const GGUF_MAGIC: [u8; 4] = *b"GGUF";
+
+/// Default alignment of tensor data, if not specified by the
+/// `general.alignment` metadata key.
+pub const DEFAULT_ALIGNMENT: usize = 32;
+
+/// Return true if `data` starts with the GGUF file identifier.
+pub fn is_gguf(data: &[u8]) -> bool {
+    data.starts_with(&GGUF_MAGIC)
+}
+
+/// Errors reported when parsing a GGUF file.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GgufError {
+    /// The file does not start with the GGUF identifier.
+    InvalidMagic,
+    /// The file uses an unsupported version of the GGUF format.
+    UnsupportedVersion(u32),
+    /// The file ended before all expected data was read.
+    UnexpectedEof,
+    /// A metadata value has an unknown type.
+    InvalidValueType(u32),
+    /// A string is not valid UTF-8.
+    InvalidString,
+    /// The `general.alignment` value is not a power of two.
+    InvalidAlignment,
+    /// A tensor's data is outside the bounds of the file, or its size
+    /// cannot be computed.
+    InvalidTensor(String),
+    /// A tensor uses an element type which is not supported by the requested
+    /// operation.
+    UnsupportedType(GgmlType),
+}
+
+impl fmt::Display for GgufError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidMagic => write!(f, "not a GGUF file"),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported GGUF version {}", version)
+            }
+            Self::UnexpectedEof => write!(f, "unexpected end of file"),
+            Self::InvalidValueType(ty) => write!(f, "invalid metadata value type {}", ty),
+            Self::InvalidString => write!(f, "string is not valid UTF-8"),
+            Self::InvalidAlignment => write!(f, "alignment is not a power of two"),
+            Self::InvalidTensor(name) => write!(f, "invalid data for tensor \"{}\"", name),
+            Self::UnsupportedType(ty) => write!(f, "unsupported tensor type {}", ty),
+        }
+    }
+}
+
+impl Error for GgufError {}
+
+/// A metadata value.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    U8(u8),
+    I8(i8),
+    U16(u16),
+    I16(i16),
+    U32(u32),
+    I32(i32),
+    U64(u64),
+    I64(i64),
+    F32(f32),
+    F64(f64),
+    Bool(bool),
+    String(String),
+    Array(Vec<Value>),
+}
+
+impl Value {
+    const U8_TYPE: u32 = 0;
+    const I8_TYPE: u32 = 1;
+    const U16_TYPE: u32 = 2;
+    const I16_TYPE: u32 = 3;
+    const U32_TYPE: u32 = 4;
+    const I32_TYPE: u32 = 5;
+    const F32_TYPE: u32 = 6;
+    const BOOL_TYPE: u32 = 7;
+    const STRING_TYPE: u32 = 8;
+    const ARRAY_TYPE: u32 = 9;
+    const U64_TYPE: u32 = 10;
+    const I64_TYPE: u32 = 11;
+    const F64_TYPE: u32 = 12;
+
+    /// Return the value as an unsigned integer.
+    ///
+    /// This succeeds for integer values of any width, if the value is
+    /// non-negative.
+    pub fn as_u64(&self) -> Option<u64> {
+        match *self {
+            Self::U8(x) => Some(x as u64),
+            Self::U16(x) => Some(x as u64),
+            Self::U32(x) => Some(x as u64),
+            Self::U64(x) => Some(x),
+            Self::I8(x) => x.try_into().ok(),
+            Self::I16(x) => x.try_into().ok(),
+            Self::I32(x) => x.try_into().ok(),
+            Self::I64(x) => x.try_into().ok(),
+            _ => None,
+        }
+    }
+
+    /// Return the value as a signed integer.
+    pub fn as_i64(&self) -> Option<i64> {
+        match *self {
+            Self::I8(x) => Some(x as i64),
+            Self::I16(x) => Some(x as i64),
+            Self::I32(x) => Some(x as i64),
+            Self::I64(x) => Some(x),
+            Self::U8(x) => Some(x as i64),
+            Self::U16(x) => Some(x as i64),
+            Self::U32(x) => Some(x as i64),
+            Self::U64(x) => x.try_into().ok(),
+            _ => None,
+        }
+    }
+
+    /// Return the value as a float.
+    ///
+    /// This succeeds for float values of any width.
+    pub fn as_f32(&self) -> Option<f32> {
+        match *self {
+            Self::F32(x) => Some(x),
+            Self::F64(x) => Some(x as f32),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match *self {
+            Self::Bool(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Self::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+}
+
+/// Description of a tensor in a GGUF file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TensorInfo {
+    /// Name of the tensor, eg. "blk.0.attn_q.weight".
+    pub name: String,
+
+    /// Dimensions of the tensor, using GGML's convention of listing the
+    /// innermost (fastest-changing) dimension first.
+    ///
+    /// See [`shape`](Self::shape) for the dimensions in row-major order.
+    pub dims: Vec<u64>,
+
+    /// Element type.
+    pub ggml_type: GgmlType,
+
+    /// Offset of the tensor's data relative to the start of the data
+    /// section.
+    pub offset: u64,
+}
+
+impl TensorInfo {
+    /// Return the shape of the tensor in row-major order, where the last
+    /// dimension is the innermost.
+    pub fn shape(&self) -> Vec<usize> {
+        self.dims.iter().rev().map(|&d| d as usize).collect()
+    }
+
+    /// Return the total number of elements in the tensor.
+    ///
+    /// Returns [`GgufError::InvalidTensor`] if the element count does not
+    /// fit in a `usize`.
+    pub fn len(&self) -> Result<usize, GgufError> {
+        self.dims
+            .iter()
+            .try_fold(1usize, |len, &d| {
+                usize::try_from(d).ok().and_then(|d| len.checked_mul(d))
+            })
+            .ok_or_else(|| GgufError::InvalidTensor(self.name.clone()))
+    }
+
+    /// Return true if the tensor has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.dims.contains(&0)
+    }
+
+    /// Return the size of the tensor's data in bytes, or `None` if the
+    /// element type is unknown, the element count overflows or the size is
+    /// not a whole number of blocks.
+    pub fn size_in_bytes(&self) -> Option<usize> {
+        self.ggml_type.size_in_bytes(self.len().ok()?)
+    }
+}
+
+/// Parsed header, metadata and tensor descriptions of a GGUF file.
+///
+/// Tensor data is not copied. Use [`tensor_data`](Self::tensor_data) to get
+/// a tensor's data as a slice of the buffer that was parsed.
+pub struct GgufFile<'a> {
+    data: &'a [u8],
+    version: u32,
+    metadata: Vec<(String, Value)>,
+    tensors: Vec<TensorInfo>,
+    data_offset: usize,
+}
+
+impl<'a> GgufFile<'a> {
+    /// Parse the header, metadata and tensor descriptions from a buffer
+    /// containing a GGUF file.
+    ///
+    /// Versions 2 and 3 of the format are supported.
+    pub fn parse(data: &'a [u8]) -> Result<GgufFile<'a>, GgufError> {
+        let mut reader = Reader { data, pos: 0 };
+
+        if reader.read_bytes(4)? != GGUF_MAGIC {
+            return Err(GgufError::InvalidMagic);
+        }
+        let version = reader.read_u32()?;
+        if !matches!(version, 2 | 3) {
+            return Err(GgufError::UnsupportedVersion(version));
+        }
+        let tensor_count = reader.read_u64()?;
+        let metadata_count = reader.read_u64()?;
+
+        // Counts are not used to pre-allocate, as they come from untrusted
+        // input.
+        let mut metadata = Vec::new();
+        for _ in 0..metadata_count {
+            let key = reader.read_string()?;
+            let value_type = reader.read_u32()?;
+            let value = reader.read_value(value_type)?;
+            metadata.push((key, value));
+        }
+
+        let mut tensors = Vec::new();
+        for _ in 0..tensor_count {
+            let name = reader.read_string()?;
+            let n_dims = reader.read_u32()?;
+            let dims = (0..n_dims)
+                .map(|_| reader.read_u64())
+                .collect::<Result<Vec<_>, _>>()?;
+            let ggml_type = GgmlType(reader.read_u32()?);
+            let offset = reader.read_u64()?;
+            tensors.push(TensorInfo {
+                name,
+                dims,
+                ggml_type,
+                offset,
+            });
+        }
+
+        let mut file = GgufFile {
+            data,
+            version,
+            metadata,
+            tensors,
+            data_offset: 0,
+        };
+
+        let alignment = file
+            .get_u64("general.alignment")
+            .map(|align| align as usize)
+            .unwrap_or(DEFAULT_ALIGNMENT);
+        if !alignment.is_power_of_two() {
+            return Err(GgufError::InvalidAlignment);
+        }
+        file.data_offset = reader.pos.next_multiple_of(alignment);
+
+        Ok(file)
+    }
+
+    /// Return the version of the GGUF format used by the file.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Return the metadata entries in the order they appear in the file.
+    pub fn metadata(&self) -> &[(String, Value)] {
+        &self.metadata
+    }
+
+    /// Return the metadata value with a given key.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.metadata
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value)
+    }
+
+    /// Return the value of an integer metadata entry.
+    pub fn get_u64(&self, key: &str) -> Option<u64> {
+        self.get(key).and_then(|v| v.as_u64())
+    }
+
+    /// Return the value of a float metadata entry.
+    pub fn get_f32(&self, key: &str) -> Option<f32> {
+        self.get(key).and_then(|v| v.as_f32())
+    }
+
+    /// Return the value of a boolean metadata entry.
+    pub fn get_bool(&self, key: &str) -> Option<bool> {
+        self.get(key).and_then(|v| v.as_bool())
+    }
+
+    /// Return the value of a string metadata entry.
+    pub fn get_str(&self, key: &str) -> Option<&str> {
+        self.get(key).and_then(|v| v.as_str())
+    }
+
+    /// Return the value of an array metadata entry.
+    pub fn get_array(&self, key: &str) -> Option<&[Value]> {
+        self.get(key).and_then(|v| v.as_array())
+    }
+
+    /// Return descriptions of the tensors in the file.
+    pub fn tensors(&self) -> &[TensorInfo] {
+        &self.tensors
+    }
+
+    /// Return the description of the tensor with a given name.
+    pub fn tensor(&self, name: &str) -> Option<&TensorInfo> {
+        self.tensors.iter().find(|t| t.name == name)
+    }
+
+    /// Return the offset of the start of the tensor data section.
+    pub fn data_offset(&self) -> usize {
+        self.data_offset
+    }
+
+    /// Return the raw data for a tensor.
+    pub fn tensor_data(&self, info: &TensorInfo) -> Result<&'a [u8], GgufError> {
+        let invalid = || GgufError::InvalidTensor(info.name.clone());
+        let size = info.size_in_bytes().ok_or_else(invalid)?;
+        let start = usize::try_from(info.offset)
+            .ok()
+            .and_then(|offset| offset.checked_add(self.data_offset))
+            .ok_or_else(invalid)?;
+        let end = start.checked_add(size).ok_or_else(invalid)?;
+        self.data.get(start..end).ok_or_else(invalid)
+    }
+}
+
+/// Cursor which reads little-endian values from a buffer.
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], GgufError> {
+        let end = self.pos.checked_add(len).ok_or(GgufError::UnexpectedEof)?;
+        let bytes = self
+            .data
+            .get(self.pos..end)
+            .ok_or(GgufError::UnexpectedEof)?;
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], GgufError> {
+        Ok(self.read_bytes(N)?.try_into().unwrap())
+    }
+
+    fn read_u32(&mut self) -> Result<u32, GgufError> {
+        self.read_array().map(u32::from_le_bytes)
+    }
+
+    fn read_u64(&mut self) -> Result<u64, GgufError> {
+        self.read_array().map(u64::from_le_bytes)
+    }
+
+    fn read_len(&mut self) -> Result<usize, GgufError> {
+        self.read_u64()?
+            .try_into()
+            .map_err(|_| GgufError::UnexpectedEof)
+    }
+
+    fn read_string(&mut self) -> Result<String, GgufError> {
+        let len = self.read_len()?;
+        let bytes = self.read_bytes(len)?;
+        String::from_utf8(bytes.to_vec()).map_err(|_| GgufError::InvalidString)
+    }
+
+    fn read_value(&mut self, value_type: u32) -> Result<Value, GgufError> {
+        let value = match value_type {
+            Value::U8_TYPE => Value::U8(u8::from_le_bytes(self.read_array()?)),
+            Value::I8_TYPE => Value::I8(i8::from_le_bytes(self.read_array()?)),
+            Value::U16_TYPE => Value::U16(u16::from_le_bytes(self.read_array()?)),
+            Value::I16_TYPE => Value::I16(i16::from_le_bytes(self.read_array()?)),
+            Value::U32_TYPE => Value::U32(u32::from_le_bytes(self.read_array()?)),
+            Value::I32_TYPE => Value::I32(i32::from_le_bytes(self.read_array()?)),
+            Value::U64_TYPE => Value::U64(u64::from_le_bytes(self.read_array()?)),
+            Value::I64_TYPE => Value::I64(i64::from_le_bytes(self.read_array()?)),
+            Value::F32_TYPE => Value::F32(f32::from_le_bytes(self.read_array()?)),
+            Value::F64_TYPE => Value::F64(f64::from_le_bytes(self.read_array()?)),
+            Value::BOOL_TYPE => Value::Bool(self.read_array::<1>()?[0] != 0),
+            Value::STRING_TYPE => Value::String(self.read_string()?),
+            Value::ARRAY_TYPE => {
+                let item_type = self.read_u32()?;
+                let len = self.read_len()?;
+                let mut items = Vec::new();
+                for _ in 0..len {
+                    items.push(self.read_value(item_type)?);
+                }
+                Value::Array(items)
+            }
+            _ => return Err(GgufError::InvalidValueType(value_type)),
+        };
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GgmlType, GgufError, GgufFile, GgufWriter, TensorInfo, Value, is_gguf};
+
+    #[test]
+    fn test_parse() {
+        let mut writer = GgufWriter::new();
+        writer.add_metadata("general.architecture", Value::String("llama".into()));
+        writer.add_metadata("llama.block_count", Value::U32(2));
+        writer.add_metadata("llama.rope.freq_base", Value::F32(10000.));
+        writer.add_metadata(
+            "tokenizer.ggml.tokens",
+            Value::Array(vec![Value::String("a".into()), Value::String("b".into())]),
+        );
+        writer.add_f32_tensor("weight", &[2, 3], &[1., 2., 3., 4., 5., 6.]);
+        writer.add_f32_tensor("bias", &[3], &[7., 8., 9.]);
+        let data = writer.finish();
+
+        assert!(is_gguf(&data));
+        let file = GgufFile::parse(&data).unwrap();
+
+        assert_eq!(file.version(), 3);
+        assert_eq!(file.metadata().len(), 4);
+        assert_eq!(file.get_str("general.architecture"), Some("llama"));
+        assert_eq!(file.get_u64("llama.block_count"), Some(2));
+        assert_eq!(file.get_f32("llama.rope.freq_base"), Some(10000.));
+        assert_eq!(file.get_array("tokenizer.ggml.tokens").unwrap().len(), 2);
+        assert_eq!(file.get("missing"), None);
+
+        let weight = file.tensor("weight").unwrap();
+        assert_eq!(weight.shape(), [2, 3]);
+        assert_eq!(weight.dims, [3, 2]);
+        assert_eq!(weight.ggml_type, GgmlType::F32);
+        assert_eq!(file.data_offset() % 32, 0);
+
+        let bias = file.tensor("bias").unwrap();
+        let bias_data = file.tensor_data(bias).unwrap();
+        let bias_vals: Vec<f32> = bias_data
+            .chunks(4)
+            .map(|b| f32::from_le_bytes(b.try_into().unwrap()))
+            .collect();
+        assert_eq!(bias_vals, [7., 8., 9.]);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(
+            GgufFile::parse(b"ONNX").err(),
+            Some(GgufError::InvalidMagic)
+        );
+
+        let mut data = GgufWriter::new().finish();
+        data[4..8].copy_from_slice(&1u32.to_le_bytes());
+        assert_eq!(
+            GgufFile::parse(&data).err(),
+            Some(GgufError::UnsupportedVersion(1))
+        );
+
+        let mut writer = GgufWriter::new();
+        writer.add_metadata("general.name", Value::String("test".into()));
+        let data = writer.finish();
+        assert_eq!(
+            GgufFile::parse(&data[..30]).err(),
+            Some(GgufError::UnexpectedEof)
+        );
+    }
+
+    #[test]
+    fn test_tensor_data_out_of_bounds() {
+        let mut writer = GgufWriter::new();
+        writer.add_f32_tensor("weight", &[4], &[1., 2., 3., 4.]);
+        let data = writer.finish();
+        let truncated = &data[..data.len() - 4];
+
+        let file = GgufFile::parse(truncated).unwrap();
+        let weight = file.tensor("weight").unwrap();
+        assert_eq!(
+            file.tensor_data(weight).err(),
+            Some(GgufError::InvalidTensor("weight".into()))
+        );
+    }
+
+    #[test]
+    fn test_tensor_len_overflow() {
+        let info = TensorInfo {
+            name: "weight".into(),
+            dims: vec![u64::MAX / 2, 4],
+            ggml_type: GgmlType::F32,
+            offset: 0,
+        };
+        assert!(!info.is_empty());
+        assert_eq!(info.len(), Err(GgufError::InvalidTensor("weight".into())));
+        assert_eq!(info.size_in_bytes(), None);
+    }
+}
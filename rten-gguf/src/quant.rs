@@ -0,0 +1,282 @@
+//! Conversion of GGML tensor data to f32.
+//!
+//! The block layouts follow the definitions in GGML's `ggml-common.h`.
+
+use rten_base::half::f16_to_f32;
+
+use crate::{GgmlType, GgufError};
+
+/// Read a little-endian f16 value from the start of `bytes`.
+fn read_f16(bytes: &[u8]) -> f32 {
+    f16_to_f32(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+/// Dequantize `data`, containing elements of type `ty`, into `out`.
+///
+/// The supported types are F32, F16, BF16, Q4_0, Q8_0, Q4_K and Q6_K.
+///
+/// The length of `out` must match the number of elements in `data`.
+///
+/// # Panics
+///
+/// Panics if the size of `data` does not match `out.len()` elements of
+/// type `ty`.
+pub fn dequantize(ty: GgmlType, data: &[u8], out: &mut [f32]) -> Result<(), GgufError> {
+    let (block_elems, block_bytes) = ty.block_size().ok_or(GgufError::UnsupportedType(ty))?;
+    assert_eq!(ty.size_in_bytes(out.len()), Some(data.len()));
+
+    let blocks = data
+        .chunks_exact(block_bytes)
+        .zip(out.chunks_exact_mut(block_elems));
+
+    match ty {
+        GgmlType::F32 => {
+            for (src, dst) in blocks {
+                dst[0] = f32::from_le_bytes(src.try_into().unwrap());
+            }
+        }
+        GgmlType::F16 => {
+            for (src, dst) in blocks {
+                dst[0] = read_f16(src);
+            }
+        }
+        GgmlType::BF16 => {
+            for (src, dst) in blocks {
+                dst[0] = f32::from_bits((u16::from_le_bytes([src[0], src[1]]) as u32) << 16);
+            }
+        }
+        GgmlType::Q4_0 => blocks.for_each(|(src, dst)| dequantize_q4_0(src, dst)),
+        GgmlType::Q8_0 => blocks.for_each(|(src, dst)| dequantize_q8_0(src, dst)),
+        GgmlType::Q4_K => blocks.for_each(|(src, dst)| dequantize_q4_k(src, dst)),
+        GgmlType::Q6_K => blocks.for_each(|(src, dst)| dequantize_q6_k(src, dst)),
+        _ => return Err(GgufError::UnsupportedType(ty)),
+    }
+
+    Ok(())
+}
+
+/// Dequantize a Q4_0 block.
+///
+/// A block contains an f16 scale followed by 32 4-bit values. The first 16
+/// elements are stored in the low nibbles and the last 16 in the high
+/// nibbles. Elements are dequantized as `(q - 8) * scale`.
+fn dequantize_q4_0(block: &[u8], out: &mut [f32]) {
+    let scale = read_f16(&block[0..2]);
+    let qs = &block[2..18];
+    for (j, &q) in qs.iter().enumerate() {
+        out[j] = ((q & 0x0F) as i32 - 8) as f32 * scale;
+        out[j + 16] = ((q >> 4) as i32 - 8) as f32 * scale;
+    }
+}
+
+/// Dequantize a Q8_0 block.
+///
+/// A block contains an f16 scale followed by 32 i8 values.
+fn dequantize_q8_0(block: &[u8], out: &mut [f32]) {
+    let scale = read_f16(&block[0..2]);
+    for (y, &q) in out.iter_mut().zip(&block[2..34]) {
+        *y = q as i8 as f32 * scale;
+    }
+}
+
+/// Extract the 6-bit scale and min for sub-block `j` of a Q4_K block.
+///
+/// `scales` is the 12-byte packed scales field of the block, which follows
+/// the f16 scale and min. Element `i` of sub-block `j` is dequantized as
+/// `d * scale * q - dmin * min`.
+pub fn q4_k_scale_min(j: usize, scales: &[u8]) -> (u8, u8) {
+    if j < 4 {
+        (scales[j] & 63, scales[j + 4] & 63)
+    } else {
+        (
+            (scales[j + 4] & 0x0F) | ((scales[j - 4] >> 6) << 4),
+            (scales[j + 4] >> 4) | ((scales[j] >> 6) << 4),
+        )
+    }
+}
+
+/// Dequantize a Q4_K block.
+///
+/// A block of 256 elements contains an f16 scale and min, 8 pairs of 6-bit
+/// sub-block scales and mins packed into 12 bytes, and 4-bit values. Each
+/// group of 64 elements uses 32 bytes, with the first 32 elements in the low
+/// nibbles and the next 32 in the high nibbles.
+fn dequantize_q4_k(block: &[u8], out: &mut [f32]) {
+    let d = read_f16(&block[0..2]);
+    let dmin = read_f16(&block[2..4]);
+    let scales = &block[4..16];
+    let qs = &block[16..144];
+
+    for (group, (q, y)) in qs
+        .chunks_exact(32)
+        .zip(out.chunks_exact_mut(64))
+        .enumerate()
+    {
+        let (sc1, m1) = q4_k_scale_min(group * 2, scales);
+        let (sc2, m2) = q4_k_scale_min(group * 2 + 1, scales);
+        let (d1, m1) = (d * sc1 as f32, dmin * m1 as f32);
+        let (d2, m2) = (d * sc2 as f32, dmin * m2 as f32);
+        for l in 0..32 {
+            y[l] = d1 * (q[l] & 0x0F) as f32 - m1;
+            y[l + 32] = d2 * (q[l] >> 4) as f32 - m2;
+        }
+    }
+}
+
+/// Dequantize a Q6_K block.
+///
+/// A block of 256 elements contains the low 4 bits of each value, the high
+/// 2 bits of each value, 16 i8 sub-block scales and an f16 scale.
+fn dequantize_q6_k(block: &[u8], out: &mut [f32]) {
+    let ql = &block[0..128];
+    let qh = &block[128..192];
+    let scales = &block[192..208];
+    let d = read_f16(&block[208..210]);
+
+    for half in 0..2 {
+        let ql = &ql[half * 64..];
+        let qh = &qh[half * 32..];
+        let sc = &scales[half * 8..];
+        let y = &mut out[half * 128..];
+        for l in 0..32 {
+            let is = l / 16;
+            let q1 = ((ql[l] & 0x0F) | ((qh[l] & 3) << 4)) as i32 - 32;
+            let q2 = ((ql[l + 32] & 0x0F) | (((qh[l] >> 2) & 3) << 4)) as i32 - 32;
+            let q3 = ((ql[l] >> 4) | (((qh[l] >> 4) & 3) << 4)) as i32 - 32;
+            let q4 = ((ql[l + 32] >> 4) | (((qh[l] >> 6) & 3) << 4)) as i32 - 32;
+            y[l] = d * (sc[is] as i8) as f32 * q1 as f32;
+            y[l + 32] = d * (sc[is + 2] as i8) as f32 * q2 as f32;
+            y[l + 64] = d * (sc[is + 4] as i8) as f32 * q3 as f32;
+            y[l + 96] = d * (sc[is + 6] as i8) as f32 * q4 as f32;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rten_base::half::f32_to_f16;
+
+    use super::dequantize;
+    use crate::{GgmlType, GgufError};
+
+    #[test]
+    fn test_dequantize_float() {
+        let mut out = [0.; 2];
+
+        let f32_data: Vec<u8> = [1.5f32, -2.].iter().flat_map(|x| x.to_le_bytes()).collect();
+        dequantize(GgmlType::F32, &f32_data, &mut out).unwrap();
+        assert_eq!(out, [1.5, -2.]);
+
+        let f16_data: Vec<u8> = [1.5f32, -2.]
+            .iter()
+            .flat_map(|&x| f32_to_f16(x).to_le_bytes())
+            .collect();
+        dequantize(GgmlType::F16, &f16_data, &mut out).unwrap();
+        assert_eq!(out, [1.5, -2.]);
+
+        let bf16_data: Vec<u8> = [1.5f32, -2.]
+            .iter()
+            .flat_map(|x| ((x.to_bits() >> 16) as u16).to_le_bytes())
+            .collect();
+        dequantize(GgmlType::BF16, &bf16_data, &mut out).unwrap();
+        assert_eq!(out, [1.5, -2.]);
+    }
+
+    #[test]
+    fn test_dequantize_q4_0() {
+        let mut block = f32_to_f16(0.5).to_le_bytes().to_vec();
+        // Elements `i` and `i + 16` are stored in the low and high nibbles
+        // of byte `i`.
+        block.extend((0..16u8).map(|i| i | ((15 - i) << 4)));
+
+        let mut out = [0.; 32];
+        dequantize(GgmlType::Q4_0, &block, &mut out).unwrap();
+
+        let expected: Vec<f32> = (0..16)
+            .map(|i| (i - 8) as f32 * 0.5)
+            .chain((0..16).map(|i| (7 - i) as f32 * 0.5))
+            .collect();
+        assert_eq!(out.as_slice(), expected);
+    }
+
+    #[test]
+    fn test_dequantize_q8_0() {
+        let mut block = f32_to_f16(0.25).to_le_bytes().to_vec();
+        block.extend((0..32).map(|i| (i - 16) as i8 as u8));
+
+        let mut out = [0.; 32];
+        dequantize(GgmlType::Q8_0, &block, &mut out).unwrap();
+
+        let expected: Vec<f32> = (0..32).map(|i| (i - 16) as f32 * 0.25).collect();
+        assert_eq!(out.as_slice(), expected);
+    }
+
+    #[test]
+    fn test_dequantize_q4_k() {
+        let mut block = Vec::new();
+        block.extend(f32_to_f16(0.5).to_le_bytes());
+        block.extend(f32_to_f16(0.25).to_le_bytes());
+
+        // Sub-block scales are 1..=8 and mins are 0..8. Sub-blocks 0..4
+        // store the scale and min in the low 6 bits of bytes 0..4 and 4..8.
+        // Sub-blocks 4..8 store the low 4 bits in bytes 8..12 and the high
+        // 2 bits in the top bits of bytes 0..8.
+        let sub_scales: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+        let sub_mins: [u8; 8] = [0, 1, 2, 3, 4, 5, 6, 7];
+        let mut scales = [0u8; 12];
+        for j in 0..4 {
+            scales[j] = sub_scales[j];
+            scales[j + 4] = sub_mins[j];
+            scales[j + 8] = (sub_scales[j + 4] & 0x0F) | ((sub_mins[j + 4] & 0x0F) << 4);
+        }
+        block.extend(scales);
+
+        // Quantized values are all 3, except for the high nibble of the
+        // last byte of each group, which is 15.
+        let mut qs = [0x33u8; 128];
+        for group in 0..4 {
+            qs[group * 32 + 31] = 0xF3;
+        }
+        block.extend(qs);
+
+        let mut out = [0.; 256];
+        dequantize(GgmlType::Q4_K, &block, &mut out).unwrap();
+
+        for (i, &y) in out.iter().enumerate() {
+            let sub_block = i / 32;
+            let q = if i % 64 == 63 { 15. } else { 3. };
+            let expected =
+                0.5 * sub_scales[sub_block] as f32 * q - 0.25 * sub_mins[sub_block] as f32;
+            assert_eq!(y, expected, "mismatch at {}", i);
+        }
+    }
+
+    #[test]
+    fn test_dequantize_q6_k() {
+        // Set the low 4 bits of all values to 1 and the high 2 bits to 2,
+        // so all values are `(1 | 2 << 4) - 32 = 1`.
+        let mut block = vec![0x11u8; 128];
+        block.extend([0xAAu8; 64]);
+        block.extend((0..16).map(|i| (i as i8 - 8) as u8));
+        block.extend(f32_to_f16(2.).to_le_bytes());
+
+        let mut out = [0.; 256];
+        dequantize(GgmlType::Q6_K, &block, &mut out).unwrap();
+
+        for (i, &y) in out.iter().enumerate() {
+            let sub_block = i / 16;
+            let expected = 2. * (sub_block as i32 - 8) as f32;
+            assert_eq!(y, expected, "mismatch at {}", i);
+        }
+    }
+
+    #[test]
+    fn test_dequantize_unsupported() {
+        let mut out = [0.; 32];
+        let data = [0; 20];
+        assert_eq!(
+            dequantize(GgmlType::Q4_1, &data, &mut out),
+            Err(GgufError::UnsupportedType(GgmlType::Q4_1))
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use std::fmt;
+
+/// Element type of a tensor in a GGUF file.
+///
+/// This corresponds to the `ggml_type` enum in GGML. Block-quantized types
+/// store elements in fixed-size blocks, where each block contains the
+/// quantized values and the parameters needed to dequantize them.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GgmlType(pub u32);
+
+impl GgmlType {
+    pub const F32: Self = Self(0);
+    pub const F16: Self = Self(1);
+    pub const Q4_0: Self = Self(2);
+    pub const Q4_1: Self = Self(3);
+    pub const Q5_0: Self = Self(6);
+    pub const Q5_1: Self = Self(7);
+    pub const Q8_0: Self = Self(8);
+    pub const Q8_1: Self = Self(9);
+    pub const Q2_K: Self = Self(10);
+    pub const Q3_K: Self = Self(11);
+    pub const Q4_K: Self = Self(12);
+    pub const Q5_K: Self = Self(13);
+    pub const Q6_K: Self = Self(14);
+    pub const Q8_K: Self = Self(15);
+    pub const I8: Self = Self(24);
+    pub const I16: Self = Self(25);
+    pub const I32: Self = Self(26);
+    pub const I64: Self = Self(27);
+    pub const F64: Self = Self(28);
+    pub const BF16: Self = Self(30);
+
+    /// Return the name of the type as used by GGML, or `None` if the type
+    /// is unknown.
+    pub fn name(self) -> Option<&'static str> {
+        let name = match self {
+            Self::F32 => "F32",
+            Self::F16 => "F16",
+            Self::Q4_0 => "Q4_0",
+            Self::Q4_1 => "Q4_1",
+            Self::Q5_0 => "Q5_0",
+            Self::Q5_1 => "Q5_1",
+            Self::Q8_0 => "Q8_0",
+            Self::Q8_1 => "Q8_1",
+            Self::Q2_K => "Q2_K",
+            Self::Q3_K => "Q3_K",
+            Self::Q4_K => "Q4_K",
+            Self::Q5_K => "Q5_K",
+            Self::Q6_K => "Q6_K",
+            Self::Q8_K => "Q8_K",
+            Self::I8 => "I8",
+            Self::I16 => "I16",
+            Self::I32 => "I32",
+            Self::I64 => "I64",
+            Self::F64 => "F64",
+            Self::BF16 => "BF16",
+            _ => return None,
+        };
+        Some(name)
+    }
+
+    /// Return the `(elements, bytes)` size of a block of this type, or `None`
+    /// if the type is unknown.
+    ///
+    /// Non-quantized types have a block size of one element.
+    pub fn block_size(self) -> Option<(usize, usize)> {
+        let size = match self {
+            Self::F32 | Self::I32 => (1, 4),
+            Self::F16 | Self::BF16 | Self::I16 => (1, 2),
+            Self::I8 => (1, 1),
+            Self::I64 | Self::F64 => (1, 8),
+            Self::Q4_0 => (32, 18),
+            Self::Q4_1 => (32, 20),
+            Self::Q5_0 => (32, 22),
+            Self::Q5_1 => (32, 24),
+            Self::Q8_0 => (32, 34),
+            Self::Q8_1 => (32, 36),
+            Self::Q2_K => (256, 84),
+            Self::Q3_K => (256, 110),
+            Self::Q4_K => (256, 144),
+            Self::Q5_K => (256, 176),
+            Self::Q6_K => (256, 210),
+            Self::Q8_K => (256, 292),
+            _ => return None,
+        };
+        Some(size)
+    }
+
+    /// Return the number of bytes used to store `len` elements of this type.
+    ///
+    /// Returns `None` if the type is unknown or `len` is not a multiple of the
+    /// block size.
+    pub fn size_in_bytes(self, len: usize) -> Option<usize> {
+        let (block_elems, block_bytes) = self.block_size()?;
+        if !len.is_multiple_of(block_elems) {
+            return None;
+        }
+        (len / block_elems).checked_mul(block_bytes)
+    }
+}
+
+impl fmt::Display for GgmlType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => write!(f, "{}", name),
+            None => write!(f, "unknown ({})", self.0),
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{DEFAULT_ALIGNMENT, GGUF_MAGIC, GgmlType, Value};
+
+struct TensorEntry {
+    name: String,
+    shape: Vec<usize>,
+    ggml_type: GgmlType,
+    data: Vec<u8>,
+}
+
+/// Serializes metadata and tensors into a GGUF file.
+///
+/// This produces version 3 files with the default alignment. It is mainly
+/// useful for creating small models for testing.
+#[derive(Default)]
+pub struct GgufWriter {
+    metadata: Vec<(String, Value)>,
+    tensors: Vec<TensorEntry>,
+}
+
+impl GgufWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a metadata entry.
+    pub fn add_metadata(&mut self, key: &str, value: Value) {
+        self.metadata.push((key.to_string(), value));
+    }
+
+    /// Add a tensor with raw data of a given type.
+    ///
+    /// `shape` is specified in row-major order, as returned by
+    /// [`TensorInfo::shape`](crate::TensorInfo::shape).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `data` does not match the shape and type.
+    pub fn add_tensor(&mut self, name: &str, shape: &[usize], ggml_type: GgmlType, data: &[u8]) {
+        let len = shape.iter().product();
+        assert_eq!(ggml_type.size_in_bytes(len), Some(data.len()));
+        self.tensors.push(TensorEntry {
+            name: name.to_string(),
+            shape: shape.to_vec(),
+            ggml_type,
+            data: data.to_vec(),
+        });
+    }
+
+    /// Add an f32 tensor.
+    pub fn add_f32_tensor(&mut self, name: &str, shape: &[usize], data: &[f32]) {
+        let bytes: Vec<u8> = data.iter().flat_map(|x| x.to_le_bytes()).collect();
+        self.add_tensor(name, shape, GgmlType::F32, &bytes);
+    }
+
+    /// Serialize the file.
+    pub fn finish(self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        buf.extend(GGUF_MAGIC);
+        buf.extend(3u32.to_le_bytes());
+        buf.extend((self.tensors.len() as u64).to_le_bytes());
+        buf.extend((self.metadata.len() as u64).to_le_bytes());
+
+        for (key, value) in &self.metadata {
+            write_string(&mut buf, key);
+            buf.extend(value_type(value).to_le_bytes());
+            write_value(&mut buf, value);
+        }
+
+        let mut offset = 0;
+        for tensor in &self.tensors {
+            write_string(&mut buf, &tensor.name);
+            buf.extend((tensor.shape.len() as u32).to_le_bytes());
+            for &dim in tensor.shape.iter().rev() {
+                buf.extend((dim as u64).to_le_bytes());
+            }
+            buf.extend(tensor.ggml_type.0.to_le_bytes());
+            buf.extend((offset as u64).to_le_bytes());
+            offset = (offset + tensor.data.len()).next_multiple_of(DEFAULT_ALIGNMENT);
+        }
+
+        for tensor in &self.tensors {
+            buf.resize(buf.len().next_multiple_of(DEFAULT_ALIGNMENT), 0);
+            buf.extend(&tensor.data);
+        }
+
+        buf
+    }
+}
+
+fn write_string(buf: &mut Vec<u8>, s: &str) {
+    buf.extend((s.len() as u64).to_le_bytes());
+    buf.extend(s.as_bytes());
+}
+
+fn value_type(value: &Value) -> u32 {
+    match value {
+        Value::U8(_) => Value::U8_TYPE,
+        Value::I8(_) => Value::I8_TYPE,
+        Value::U16(_) => Value::U16_TYPE,
+        Value::I16(_) => Value::I16_TYPE,
+        Value::U32(_) => Value::U32_TYPE,
+        Value::I32(_) => Value::I32_TYPE,
+        Value::U64(_) => Value::U64_TYPE,
+        Value::I64(_) => Value::I64_TYPE,
+        Value::F32(_) => Value::F32_TYPE,
+        Value::F64(_) => Value::F64_TYPE,
+        Value::Bool(_) => Value::BOOL_TYPE,
+        Value::String(_) => Value::STRING_TYPE,
+        Value::Array(_) => Value::ARRAY_TYPE,
+    }
+}
+
+fn write_value(buf: &mut Vec<u8>, value: &Value) {
+    match value {
+        Value::U8(x) => buf.extend(x.to_le_bytes()),
+        Value::I8(x) => buf.extend(x.to_le_bytes()),
+        Value::U16(x) => buf.extend(x.to_le_bytes()),
+        Value::I16(x) => buf.extend(x.to_le_bytes()),
+        Value::U32(x) => buf.extend(x.to_le_bytes()),
+        Value::I32(x) => buf.extend(x.to_le_bytes()),
+        Value::U64(x) => buf.extend(x.to_le_bytes()),
+        Value::I64(x) => buf.extend(x.to_le_bytes()),
+        Value::F32(x) => buf.extend(x.to_le_bytes()),
+        Value::F64(x) => buf.extend(x.to_le_bytes()),
+        Value::Bool(x) => buf.push(*x as u8),
+        Value::String(s) => write_string(buf, s),
+        Value::Array(items) => {
+            // Arrays are homogenous. Empty arrays are written with an
+            // arbitrary item type.
+            let item_type = items.first().map(value_type).unwrap_or(Value::U8_TYPE);
+            buf.extend(item_type.to_le_bytes());
+            buf.extend((items.len() as u64).to_le_bytes());
+            for item in items {
+                write_value(buf, item);
+            }
+        }
+    }
+}
@@ -17,13 +17,13 @@ pub const ENUM_MIN_OPERATOR_TYPE: u8 = 0;
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_OPERATOR_TYPE: u8 = 128;
+pub const ENUM_MAX_OPERATOR_TYPE: u8 = 172;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_OPERATOR_TYPE: [OperatorType; 129] = [
+pub const ENUM_VALUES_OPERATOR_TYPE: [OperatorType; 173] = [
     OperatorType::Add,
     OperatorType::ArgMin,
     OperatorType::ArgMax,
@@ -153,6 +153,50 @@ pub const ENUM_VALUES_OPERATOR_TYPE: [OperatorType; 129] = [
     OperatorType::PRelu,
     OperatorType::STFT,
     OperatorType::GlobalMaxPool,
+    OperatorType::AddSoftmax,
+    OperatorType::ComputeShape,
+    OperatorType::FusedMatMul,
+    OperatorType::GroupedQueryAttentionMatMul,
+    OperatorType::MatMulIntegerToFloat,
+    OperatorType::RepeatInterleave,
+    OperatorType::RmsNormalization,
+    OperatorType::Silu,
+    OperatorType::Swish,
+    OperatorType::TransformInputs,
+    OperatorType::Attention,
+    OperatorType::RotaryEmbedding,
+    OperatorType::GroupQueryAttention,
+    OperatorType::SkipSimplifiedLayerNormalization,
+    OperatorType::SpaceToDepth,
+    OperatorType::DFT,
+    OperatorType::HannWindow,
+    OperatorType::HammingWindow,
+    OperatorType::BlackmanWindow,
+    OperatorType::MelWeightMatrix,
+    OperatorType::GroupNormalization,
+    OperatorType::Col2Im,
+    OperatorType::RoiAlign,
+    OperatorType::LpPool,
+    OperatorType::MaxUnpool,
+    OperatorType::MeanVarianceNormalization,
+    OperatorType::LpNormalization,
+    OperatorType::Mish,
+    OperatorType::Selu,
+    OperatorType::Celu,
+    OperatorType::Shrink,
+    OperatorType::ThresholdedRelu,
+    OperatorType::Hardmax,
+    OperatorType::BitShift,
+    OperatorType::BitwiseAnd,
+    OperatorType::BitwiseNot,
+    OperatorType::BitwiseOr,
+    OperatorType::BitwiseXor,
+    OperatorType::ReverseSequence,
+    OperatorType::Compress,
+    OperatorType::Unique,
+    OperatorType::ReduceLogSumExp,
+    OperatorType::Det,
+    OperatorType::MatMulNBits,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -289,9 +333,53 @@ impl OperatorType {
     pub const PRelu: Self = Self(126);
     pub const STFT: Self = Self(127);
     pub const GlobalMaxPool: Self = Self(128);
+    pub const AddSoftmax: Self = Self(129);
+    pub const ComputeShape: Self = Self(130);
+    pub const FusedMatMul: Self = Self(131);
+    pub const GroupedQueryAttentionMatMul: Self = Self(132);
+    pub const MatMulIntegerToFloat: Self = Self(133);
+    pub const RepeatInterleave: Self = Self(134);
+    pub const RmsNormalization: Self = Self(135);
+    pub const Silu: Self = Self(136);
+    pub const Swish: Self = Self(137);
+    pub const TransformInputs: Self = Self(138);
+    pub const Attention: Self = Self(139);
+    pub const RotaryEmbedding: Self = Self(140);
+    pub const GroupQueryAttention: Self = Self(141);
+    pub const SkipSimplifiedLayerNormalization: Self = Self(142);
+    pub const SpaceToDepth: Self = Self(143);
+    pub const DFT: Self = Self(144);
+    pub const HannWindow: Self = Self(145);
+    pub const HammingWindow: Self = Self(146);
+    pub const BlackmanWindow: Self = Self(147);
+    pub const MelWeightMatrix: Self = Self(148);
+    pub const GroupNormalization: Self = Self(149);
+    pub const Col2Im: Self = Self(150);
+    pub const RoiAlign: Self = Self(151);
+    pub const LpPool: Self = Self(152);
+    pub const MaxUnpool: Self = Self(153);
+    pub const MeanVarianceNormalization: Self = Self(154);
+    pub const LpNormalization: Self = Self(155);
+    pub const Mish: Self = Self(156);
+    pub const Selu: Self = Self(157);
+    pub const Celu: Self = Self(158);
+    pub const Shrink: Self = Self(159);
+    pub const ThresholdedRelu: Self = Self(160);
+    pub const Hardmax: Self = Self(161);
+    pub const BitShift: Self = Self(162);
+    pub const BitwiseAnd: Self = Self(163);
+    pub const BitwiseNot: Self = Self(164);
+    pub const BitwiseOr: Self = Self(165);
+    pub const BitwiseXor: Self = Self(166);
+    pub const ReverseSequence: Self = Self(167);
+    pub const Compress: Self = Self(168);
+    pub const Unique: Self = Self(169);
+    pub const ReduceLogSumExp: Self = Self(170);
+    pub const Det: Self = Self(171);
+    pub const MatMulNBits: Self = Self(172);
 
     pub const ENUM_MIN: u8 = 0;
-    pub const ENUM_MAX: u8 = 128;
+    pub const ENUM_MAX: u8 = 172;
     pub const ENUM_VALUES: &'static [Self] = &[
         Self::Add,
         Self::ArgMin,
@@ -422,6 +510,50 @@ impl OperatorType {
         Self::PRelu,
         Self::STFT,
         Self::GlobalMaxPool,
+        Self::AddSoftmax,
+        Self::ComputeShape,
+        Self::FusedMatMul,
+        Self::GroupedQueryAttentionMatMul,
+        Self::MatMulIntegerToFloat,
+        Self::RepeatInterleave,
+        Self::RmsNormalization,
+        Self::Silu,
+        Self::Swish,
+        Self::TransformInputs,
+        Self::Attention,
+        Self::RotaryEmbedding,
+        Self::GroupQueryAttention,
+        Self::SkipSimplifiedLayerNormalization,
+        Self::SpaceToDepth,
+        Self::DFT,
+        Self::HannWindow,
+        Self::HammingWindow,
+        Self::BlackmanWindow,
+        Self::MelWeightMatrix,
+        Self::GroupNormalization,
+        Self::Col2Im,
+        Self::RoiAlign,
+        Self::LpPool,
+        Self::MaxUnpool,
+        Self::MeanVarianceNormalization,
+        Self::LpNormalization,
+        Self::Mish,
+        Self::Selu,
+        Self::Celu,
+        Self::Shrink,
+        Self::ThresholdedRelu,
+        Self::Hardmax,
+        Self::BitShift,
+        Self::BitwiseAnd,
+        Self::BitwiseNot,
+        Self::BitwiseOr,
+        Self::BitwiseXor,
+        Self::ReverseSequence,
+        Self::Compress,
+        Self::Unique,
+        Self::ReduceLogSumExp,
+        Self::Det,
+        Self::MatMulNBits,
     ];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
@@ -555,6 +687,50 @@ impl OperatorType {
             Self::PRelu => Some("PRelu"),
             Self::STFT => Some("STFT"),
             Self::GlobalMaxPool => Some("GlobalMaxPool"),
+            Self::AddSoftmax => Some("AddSoftmax"),
+            Self::ComputeShape => Some("ComputeShape"),
+            Self::FusedMatMul => Some("FusedMatMul"),
+            Self::GroupedQueryAttentionMatMul => Some("GroupedQueryAttentionMatMul"),
+            Self::MatMulIntegerToFloat => Some("MatMulIntegerToFloat"),
+            Self::RepeatInterleave => Some("RepeatInterleave"),
+            Self::RmsNormalization => Some("RmsNormalization"),
+            Self::Silu => Some("Silu"),
+            Self::Swish => Some("Swish"),
+            Self::TransformInputs => Some("TransformInputs"),
+            Self::Attention => Some("Attention"),
+            Self::RotaryEmbedding => Some("RotaryEmbedding"),
+            Self::GroupQueryAttention => Some("GroupQueryAttention"),
+            Self::SkipSimplifiedLayerNormalization => Some("SkipSimplifiedLayerNormalization"),
+            Self::SpaceToDepth => Some("SpaceToDepth"),
+            Self::DFT => Some("DFT"),
+            Self::HannWindow => Some("HannWindow"),
+            Self::HammingWindow => Some("HammingWindow"),
+            Self::BlackmanWindow => Some("BlackmanWindow"),
+            Self::MelWeightMatrix => Some("MelWeightMatrix"),
+            Self::GroupNormalization => Some("GroupNormalization"),
+            Self::Col2Im => Some("Col2Im"),
+            Self::RoiAlign => Some("RoiAlign"),
+            Self::LpPool => Some("LpPool"),
+            Self::MaxUnpool => Some("MaxUnpool"),
+            Self::MeanVarianceNormalization => Some("MeanVarianceNormalization"),
+            Self::LpNormalization => Some("LpNormalization"),
+            Self::Mish => Some("Mish"),
+            Self::Selu => Some("Selu"),
+            Self::Celu => Some("Celu"),
+            Self::Shrink => Some("Shrink"),
+            Self::ThresholdedRelu => Some("ThresholdedRelu"),
+            Self::Hardmax => Some("Hardmax"),
+            Self::BitShift => Some("BitShift"),
+            Self::BitwiseAnd => Some("BitwiseAnd"),
+            Self::BitwiseNot => Some("BitwiseNot"),
+            Self::BitwiseOr => Some("BitwiseOr"),
+            Self::BitwiseXor => Some("BitwiseXor"),
+            Self::ReverseSequence => Some("ReverseSequence"),
+            Self::Compress => Some("Compress"),
+            Self::Unique => Some("Unique"),
+            Self::ReduceLogSumExp => Some("ReduceLogSumExp"),
+            Self::Det => Some("Det"),
+            Self::MatMulNBits => Some("MatMulNBits"),
             _ => None,
         }
     }
@@ -810,17 +986,21 @@ pub const ENUM_MIN_DATA_TYPE: u8 = 0;
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_DATA_TYPE: u8 = 3;
+pub const ENUM_MAX_DATA_TYPE: u8 = 7;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_DATA_TYPE: [DataType; 4] = [
+pub const ENUM_VALUES_DATA_TYPE: [DataType; 8] = [
     DataType::Int32,
     DataType::Float,
     DataType::Int8,
     DataType::UInt8,
+    DataType::Float16,
+    DataType::BFloat16,
+    DataType::Int64,
+    DataType::Bool,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -832,10 +1012,23 @@ impl DataType {
     pub const Float: Self = Self(1);
     pub const Int8: Self = Self(2);
     pub const UInt8: Self = Self(3);
+    pub const Float16: Self = Self(4);
+    pub const BFloat16: Self = Self(5);
+    pub const Int64: Self = Self(6);
+    pub const Bool: Self = Self(7);
 
     pub const ENUM_MIN: u8 = 0;
-    pub const ENUM_MAX: u8 = 3;
-    pub const ENUM_VALUES: &'static [Self] = &[Self::Int32, Self::Float, Self::Int8, Self::UInt8];
+    pub const ENUM_MAX: u8 = 7;
+    pub const ENUM_VALUES: &'static [Self] = &[
+        Self::Int32,
+        Self::Float,
+        Self::Int8,
+        Self::UInt8,
+        Self::Float16,
+        Self::BFloat16,
+        Self::Int64,
+        Self::Bool,
+    ];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
         match self {
@@ -843,6 +1036,10 @@ impl DataType {
             Self::Float => Some("Float"),
             Self::Int8 => Some("Int8"),
             Self::UInt8 => Some("UInt8"),
+            Self::Float16 => Some("Float16"),
+            Self::BFloat16 => Some("BFloat16"),
+            Self::Int64 => Some("Int64"),
+            Self::Bool => Some("Bool"),
             _ => None,
         }
     }
@@ -1211,13 +1408,13 @@ pub const ENUM_MIN_OPERATOR_ATTRS: u8 = 0;
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_OPERATOR_ATTRS: u8 = 54;
+pub const ENUM_MAX_OPERATOR_ATTRS: u8 = 82;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_OPERATOR_ATTRS: [OperatorAttrs; 55] = [
+pub const ENUM_VALUES_OPERATOR_ATTRS: [OperatorAttrs; 83] = [
     OperatorAttrs::NONE,
     OperatorAttrs::ArgMaxAttrs,
     OperatorAttrs::AveragePoolAttrs,
@@ -1273,6 +1470,34 @@ pub const ENUM_VALUES_OPERATOR_ATTRS: [OperatorAttrs; 55] = [
     OperatorAttrs::SplitToSequenceAttrs,
     OperatorAttrs::GridSampleAttrs,
     OperatorAttrs::STFTAttrs,
+    OperatorAttrs::ComputeShapeAttrs,
+    OperatorAttrs::FusedMatMulAttrs,
+    OperatorAttrs::GroupedQueryAttentionMatMulAttrs,
+    OperatorAttrs::RepeatInterleaveAttrs,
+    OperatorAttrs::SwishAttrs,
+    OperatorAttrs::TransformInputsAttrs,
+    OperatorAttrs::AttentionAttrs,
+    OperatorAttrs::GroupQueryAttentionAttrs,
+    OperatorAttrs::RotaryEmbeddingAttrs,
+    OperatorAttrs::SpaceToDepthAttrs,
+    OperatorAttrs::DFTAttrs,
+    OperatorAttrs::WindowAttrs,
+    OperatorAttrs::GroupNormalizationAttrs,
+    OperatorAttrs::Col2ImAttrs,
+    OperatorAttrs::RoiAlignAttrs,
+    OperatorAttrs::LpPoolAttrs,
+    OperatorAttrs::MeanVarianceNormalizationAttrs,
+    OperatorAttrs::LpNormalizationAttrs,
+    OperatorAttrs::BitShiftAttrs,
+    OperatorAttrs::CompressAttrs,
+    OperatorAttrs::ReverseSequenceAttrs,
+    OperatorAttrs::SeluAttrs,
+    OperatorAttrs::ShrinkAttrs,
+    OperatorAttrs::UniqueAttrs,
+    OperatorAttrs::CeluAttrs,
+    OperatorAttrs::HardmaxAttrs,
+    OperatorAttrs::ThresholdedReluAttrs,
+    OperatorAttrs::MatMulNBitsAttrs,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -1335,9 +1560,37 @@ impl OperatorAttrs {
     pub const SplitToSequenceAttrs: Self = Self(52);
     pub const GridSampleAttrs: Self = Self(53);
     pub const STFTAttrs: Self = Self(54);
+    pub const ComputeShapeAttrs: Self = Self(55);
+    pub const FusedMatMulAttrs: Self = Self(56);
+    pub const GroupedQueryAttentionMatMulAttrs: Self = Self(57);
+    pub const RepeatInterleaveAttrs: Self = Self(58);
+    pub const SwishAttrs: Self = Self(59);
+    pub const TransformInputsAttrs: Self = Self(60);
+    pub const AttentionAttrs: Self = Self(61);
+    pub const GroupQueryAttentionAttrs: Self = Self(62);
+    pub const RotaryEmbeddingAttrs: Self = Self(63);
+    pub const SpaceToDepthAttrs: Self = Self(64);
+    pub const DFTAttrs: Self = Self(65);
+    pub const WindowAttrs: Self = Self(66);
+    pub const GroupNormalizationAttrs: Self = Self(67);
+    pub const Col2ImAttrs: Self = Self(68);
+    pub const RoiAlignAttrs: Self = Self(69);
+    pub const LpPoolAttrs: Self = Self(70);
+    pub const MeanVarianceNormalizationAttrs: Self = Self(71);
+    pub const LpNormalizationAttrs: Self = Self(72);
+    pub const BitShiftAttrs: Self = Self(73);
+    pub const CompressAttrs: Self = Self(74);
+    pub const ReverseSequenceAttrs: Self = Self(75);
+    pub const SeluAttrs: Self = Self(76);
+    pub const ShrinkAttrs: Self = Self(77);
+    pub const UniqueAttrs: Self = Self(78);
+    pub const CeluAttrs: Self = Self(79);
+    pub const HardmaxAttrs: Self = Self(80);
+    pub const ThresholdedReluAttrs: Self = Self(81);
+    pub const MatMulNBitsAttrs: Self = Self(82);
 
     pub const ENUM_MIN: u8 = 0;
-    pub const ENUM_MAX: u8 = 54;
+    pub const ENUM_MAX: u8 = 82;
     pub const ENUM_VALUES: &'static [Self] = &[
         Self::NONE,
         Self::ArgMaxAttrs,
@@ -1394,6 +1647,34 @@ impl OperatorAttrs {
         Self::SplitToSequenceAttrs,
         Self::GridSampleAttrs,
         Self::STFTAttrs,
+        Self::ComputeShapeAttrs,
+        Self::FusedMatMulAttrs,
+        Self::GroupedQueryAttentionMatMulAttrs,
+        Self::RepeatInterleaveAttrs,
+        Self::SwishAttrs,
+        Self::TransformInputsAttrs,
+        Self::AttentionAttrs,
+        Self::GroupQueryAttentionAttrs,
+        Self::RotaryEmbeddingAttrs,
+        Self::SpaceToDepthAttrs,
+        Self::DFTAttrs,
+        Self::WindowAttrs,
+        Self::GroupNormalizationAttrs,
+        Self::Col2ImAttrs,
+        Self::RoiAlignAttrs,
+        Self::LpPoolAttrs,
+        Self::MeanVarianceNormalizationAttrs,
+        Self::LpNormalizationAttrs,
+        Self::BitShiftAttrs,
+        Self::CompressAttrs,
+        Self::ReverseSequenceAttrs,
+        Self::SeluAttrs,
+        Self::ShrinkAttrs,
+        Self::UniqueAttrs,
+        Self::CeluAttrs,
+        Self::HardmaxAttrs,
+        Self::ThresholdedReluAttrs,
+        Self::MatMulNBitsAttrs,
     ];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
@@ -1453,6 +1734,34 @@ impl OperatorAttrs {
             Self::SplitToSequenceAttrs => Some("SplitToSequenceAttrs"),
             Self::GridSampleAttrs => Some("GridSampleAttrs"),
             Self::STFTAttrs => Some("STFTAttrs"),
+            Self::ComputeShapeAttrs => Some("ComputeShapeAttrs"),
+            Self::FusedMatMulAttrs => Some("FusedMatMulAttrs"),
+            Self::GroupedQueryAttentionMatMulAttrs => Some("GroupedQueryAttentionMatMulAttrs"),
+            Self::RepeatInterleaveAttrs => Some("RepeatInterleaveAttrs"),
+            Self::SwishAttrs => Some("SwishAttrs"),
+            Self::TransformInputsAttrs => Some("TransformInputsAttrs"),
+            Self::AttentionAttrs => Some("AttentionAttrs"),
+            Self::GroupQueryAttentionAttrs => Some("GroupQueryAttentionAttrs"),
+            Self::RotaryEmbeddingAttrs => Some("RotaryEmbeddingAttrs"),
+            Self::SpaceToDepthAttrs => Some("SpaceToDepthAttrs"),
+            Self::DFTAttrs => Some("DFTAttrs"),
+            Self::WindowAttrs => Some("WindowAttrs"),
+            Self::GroupNormalizationAttrs => Some("GroupNormalizationAttrs"),
+            Self::Col2ImAttrs => Some("Col2ImAttrs"),
+            Self::RoiAlignAttrs => Some("RoiAlignAttrs"),
+            Self::LpPoolAttrs => Some("LpPoolAttrs"),
+            Self::MeanVarianceNormalizationAttrs => Some("MeanVarianceNormalizationAttrs"),
+            Self::LpNormalizationAttrs => Some("LpNormalizationAttrs"),
+            Self::BitShiftAttrs => Some("BitShiftAttrs"),
+            Self::CompressAttrs => Some("CompressAttrs"),
+            Self::ReverseSequenceAttrs => Some("ReverseSequenceAttrs"),
+            Self::SeluAttrs => Some("SeluAttrs"),
+            Self::ShrinkAttrs => Some("ShrinkAttrs"),
+            Self::UniqueAttrs => Some("UniqueAttrs"),
+            Self::CeluAttrs => Some("CeluAttrs"),
+            Self::HardmaxAttrs => Some("HardmaxAttrs"),
+            Self::ThresholdedReluAttrs => Some("ThresholdedReluAttrs"),
+            Self::MatMulNBitsAttrs => Some("MatMulNBitsAttrs"),
             _ => None,
         }
     }
@@ -1512,6 +1821,231 @@ impl<'a> flatbuffers::Verifiable for OperatorAttrs {
 
 impl flatbuffers::SimpleToVerifyInSlice for OperatorAttrs {}
 pub struct OperatorAttrsUnionTableOffset {}
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MIN_BIT_SHIFT_DIRECTION: u8 = 0;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MAX_BIT_SHIFT_DIRECTION: u8 = 1;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_BIT_SHIFT_DIRECTION: [BitShiftDirection; 2] =
+    [BitShiftDirection::Left, BitShiftDirection::Right];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct BitShiftDirection(pub u8);
+#[allow(non_upper_case_globals)]
+impl BitShiftDirection {
+    pub const Left: Self = Self(0);
+    pub const Right: Self = Self(1);
+
+    pub const ENUM_MIN: u8 = 0;
+    pub const ENUM_MAX: u8 = 1;
+    pub const ENUM_VALUES: &'static [Self] = &[Self::Left, Self::Right];
+    /// Returns the variant's name or "" if unknown.
+    pub fn variant_name(self) -> Option<&'static str> {
+        match self {
+            Self::Left => Some("Left"),
+            Self::Right => Some("Right"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Debug for BitShiftDirection {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(name) = self.variant_name() {
+            f.write_str(name)
+        } else {
+            f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+        }
+    }
+}
+impl<'a> flatbuffers::Follow<'a> for BitShiftDirection {
+    type Inner = Self;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        let b = unsafe { flatbuffers::read_scalar_at::<u8>(buf, loc) };
+        Self(b)
+    }
+}
+
+impl flatbuffers::Push for BitShiftDirection {
+    type Output = BitShiftDirection;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe {
+            flatbuffers::emplace_scalar::<u8>(dst, self.0);
+        }
+    }
+}
+
+impl flatbuffers::EndianScalar for BitShiftDirection {
+    type Scalar = u8;
+    #[inline]
+    fn to_little_endian(self) -> u8 {
+        self.0.to_le()
+    }
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_little_endian(v: u8) -> Self {
+        let b = u8::from_le(v);
+        Self(b)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for BitShiftDirection {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        u8::run_verifier(v, pos)
+    }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for BitShiftDirection {}
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MIN_SYM_EXPR_OP: u8 = 0;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MAX_SYM_EXPR_OP: u8 = 10;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_SYM_EXPR_OP: [SymExprOp; 11] = [
+    SymExprOp::Value,
+    SymExprOp::Var,
+    SymExprOp::Add,
+    SymExprOp::Sub,
+    SymExprOp::Mul,
+    SymExprOp::Div,
+    SymExprOp::DivCeil,
+    SymExprOp::Max,
+    SymExprOp::Min,
+    SymExprOp::Broadcast,
+    SymExprOp::Neg,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct SymExprOp(pub u8);
+#[allow(non_upper_case_globals)]
+impl SymExprOp {
+    pub const Value: Self = Self(0);
+    pub const Var: Self = Self(1);
+    pub const Add: Self = Self(2);
+    pub const Sub: Self = Self(3);
+    pub const Mul: Self = Self(4);
+    pub const Div: Self = Self(5);
+    pub const DivCeil: Self = Self(6);
+    pub const Max: Self = Self(7);
+    pub const Min: Self = Self(8);
+    pub const Broadcast: Self = Self(9);
+    pub const Neg: Self = Self(10);
+
+    pub const ENUM_MIN: u8 = 0;
+    pub const ENUM_MAX: u8 = 10;
+    pub const ENUM_VALUES: &'static [Self] = &[
+        Self::Value,
+        Self::Var,
+        Self::Add,
+        Self::Sub,
+        Self::Mul,
+        Self::Div,
+        Self::DivCeil,
+        Self::Max,
+        Self::Min,
+        Self::Broadcast,
+        Self::Neg,
+    ];
+    /// Returns the variant's name or "" if unknown.
+    pub fn variant_name(self) -> Option<&'static str> {
+        match self {
+            Self::Value => Some("Value"),
+            Self::Var => Some("Var"),
+            Self::Add => Some("Add"),
+            Self::Sub => Some("Sub"),
+            Self::Mul => Some("Mul"),
+            Self::Div => Some("Div"),
+            Self::DivCeil => Some("DivCeil"),
+            Self::Max => Some("Max"),
+            Self::Min => Some("Min"),
+            Self::Broadcast => Some("Broadcast"),
+            Self::Neg => Some("Neg"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Debug for SymExprOp {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(name) = self.variant_name() {
+            f.write_str(name)
+        } else {
+            f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+        }
+    }
+}
+impl<'a> flatbuffers::Follow<'a> for SymExprOp {
+    type Inner = Self;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        let b = unsafe { flatbuffers::read_scalar_at::<u8>(buf, loc) };
+        Self(b)
+    }
+}
+
+impl flatbuffers::Push for SymExprOp {
+    type Output = SymExprOp;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe {
+            flatbuffers::emplace_scalar::<u8>(dst, self.0);
+        }
+    }
+}
+
+impl flatbuffers::EndianScalar for SymExprOp {
+    type Scalar = u8;
+    #[inline]
+    fn to_little_endian(self) -> u8 {
+        self.0.to_le()
+    }
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_little_endian(v: u8) -> Self {
+        let b = u8::from_le(v);
+        Self(b)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for SymExprOp {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        u8::run_verifier(v, pos)
+    }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for SymExprOp {}
 
 #[deprecated(
     since = "2.0.0",
@@ -1991,6 +2525,97 @@ impl flatbuffers::SimpleToVerifyInSlice for PadMode {}
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
+pub const ENUM_MIN_ROI_ALIGN_MODE: u8 = 0;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+pub const ENUM_MAX_ROI_ALIGN_MODE: u8 = 1;
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_ROI_ALIGN_MODE: [RoiAlignMode; 2] = [RoiAlignMode::Avg, RoiAlignMode::Max];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct RoiAlignMode(pub u8);
+#[allow(non_upper_case_globals)]
+impl RoiAlignMode {
+    pub const Avg: Self = Self(0);
+    pub const Max: Self = Self(1);
+
+    pub const ENUM_MIN: u8 = 0;
+    pub const ENUM_MAX: u8 = 1;
+    pub const ENUM_VALUES: &'static [Self] = &[Self::Avg, Self::Max];
+    /// Returns the variant's name or "" if unknown.
+    pub fn variant_name(self) -> Option<&'static str> {
+        match self {
+            Self::Avg => Some("Avg"),
+            Self::Max => Some("Max"),
+            _ => None,
+        }
+    }
+}
+impl core::fmt::Debug for RoiAlignMode {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if let Some(name) = self.variant_name() {
+            f.write_str(name)
+        } else {
+            f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+        }
+    }
+}
+impl<'a> flatbuffers::Follow<'a> for RoiAlignMode {
+    type Inner = Self;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        let b = unsafe { flatbuffers::read_scalar_at::<u8>(buf, loc) };
+        Self(b)
+    }
+}
+
+impl flatbuffers::Push for RoiAlignMode {
+    type Output = RoiAlignMode;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe {
+            flatbuffers::emplace_scalar::<u8>(dst, self.0);
+        }
+    }
+}
+
+impl flatbuffers::EndianScalar for RoiAlignMode {
+    type Scalar = u8;
+    #[inline]
+    fn to_little_endian(self) -> u8 {
+        self.0.to_le()
+    }
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn from_little_endian(v: u8) -> Self {
+        let b = u8::from_le(v);
+        Self(b)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for RoiAlignMode {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        u8::run_verifier(v, pos)
+    }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for RoiAlignMode {}
+#[deprecated(
+    since = "2.0.0",
+    note = "Use associated constants instead. This will no longer be generated in 2021."
+)]
 pub const ENUM_MIN_SCATTER_REDUCTION: u8 = 0;
 #[deprecated(
     since = "2.0.0",
@@ -2318,17 +2943,21 @@ pub const ENUM_MIN_CONSTANT_DATA_TYPE: u16 = 0;
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_CONSTANT_DATA_TYPE: u16 = 3;
+pub const ENUM_MAX_CONSTANT_DATA_TYPE: u16 = 7;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_CONSTANT_DATA_TYPE: [ConstantDataType; 4] = [
+pub const ENUM_VALUES_CONSTANT_DATA_TYPE: [ConstantDataType; 8] = [
     ConstantDataType::Int32,
     ConstantDataType::Float32,
     ConstantDataType::Int8,
     ConstantDataType::UInt8,
+    ConstantDataType::Float16,
+    ConstantDataType::BFloat16,
+    ConstantDataType::Int64,
+    ConstantDataType::Bool,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -2340,10 +2969,23 @@ impl ConstantDataType {
     pub const Float32: Self = Self(1);
     pub const Int8: Self = Self(2);
     pub const UInt8: Self = Self(3);
+    pub const Float16: Self = Self(4);
+    pub const BFloat16: Self = Self(5);
+    pub const Int64: Self = Self(6);
+    pub const Bool: Self = Self(7);
 
     pub const ENUM_MIN: u16 = 0;
-    pub const ENUM_MAX: u16 = 3;
-    pub const ENUM_VALUES: &'static [Self] = &[Self::Int32, Self::Float32, Self::Int8, Self::UInt8];
+    pub const ENUM_MAX: u16 = 7;
+    pub const ENUM_VALUES: &'static [Self] = &[
+        Self::Int32,
+        Self::Float32,
+        Self::Int8,
+        Self::UInt8,
+        Self::Float16,
+        Self::BFloat16,
+        Self::Int64,
+        Self::Bool,
+    ];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
         match self {
@@ -2351,6 +2993,10 @@ impl ConstantDataType {
             Self::Float32 => Some("Float32"),
             Self::Int8 => Some("Int8"),
             Self::UInt8 => Some("UInt8"),
+            Self::Float16 => Some("Float16"),
+            Self::BFloat16 => Some("BFloat16"),
+            Self::Int64 => Some("Int64"),
+            Self::Bool => Some("Bool"),
             _ => None,
         }
     }
@@ -2532,15 +3178,15 @@ impl core::fmt::Debug for ArgMaxAttrs<'_> {
         ds.finish()
     }
 }
-pub enum AveragePoolAttrsOffset {}
+pub enum AttentionAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct AveragePoolAttrs<'a> {
+pub struct AttentionAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for AveragePoolAttrs<'a> {
-    type Inner = AveragePoolAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for AttentionAttrs<'a> {
+    type Inner = AttentionAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -2549,17 +3195,233 @@ impl<'a> flatbuffers::Follow<'a> for AveragePoolAttrs<'a> {
     }
 }
 
-impl<'a> AveragePoolAttrs<'a> {
-    pub const VT_KERNEL_SIZE: flatbuffers::VOffsetT = 4;
-    pub const VT_AUTO_PAD: flatbuffers::VOffsetT = 6;
-    pub const VT_PADS: flatbuffers::VOffsetT = 8;
-    pub const VT_STRIDES: flatbuffers::VOffsetT = 10;
-    pub const VT_COUNT_INCLUDE_PAD: flatbuffers::VOffsetT = 12;
-    pub const VT_CEIL_MODE: flatbuffers::VOffsetT = 14;
+impl<'a> AttentionAttrs<'a> {
+    pub const VT_IS_CAUSAL: flatbuffers::VOffsetT = 4;
+    pub const VT_Q_NUM_HEADS: flatbuffers::VOffsetT = 6;
+    pub const VT_KV_NUM_HEADS: flatbuffers::VOffsetT = 8;
+    pub const VT_SCALE: flatbuffers::VOffsetT = 10;
+    pub const VT_SOFTCAP: flatbuffers::VOffsetT = 12;
+    pub const VT_FLUSH_NANS_TO_ZERO: flatbuffers::VOffsetT = 14;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        AveragePoolAttrs { _tab: table }
+        AttentionAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args AttentionAttrsArgs,
+    ) -> flatbuffers::WIPOffset<AttentionAttrs<'bldr>> {
+        let mut builder = AttentionAttrsBuilder::new(_fbb);
+        if let Some(x) = args.softcap {
+            builder.add_softcap(x);
+        }
+        if let Some(x) = args.scale {
+            builder.add_scale(x);
+        }
+        if let Some(x) = args.kv_num_heads {
+            builder.add_kv_num_heads(x);
+        }
+        if let Some(x) = args.q_num_heads {
+            builder.add_q_num_heads(x);
+        }
+        builder.add_flush_nans_to_zero(args.flush_nans_to_zero);
+        builder.add_is_causal(args.is_causal);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn is_causal(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(AttentionAttrs::VT_IS_CAUSAL, Some(false))
+                .unwrap()
+        }
+    }
+
+    #[inline]
+    pub fn q_num_heads(&self) -> Option<u32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<u32>(AttentionAttrs::VT_Q_NUM_HEADS, None) }
+    }
+
+    #[inline]
+    pub fn kv_num_heads(&self) -> Option<u32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<u32>(AttentionAttrs::VT_KV_NUM_HEADS, None) }
+    }
+
+    #[inline]
+    pub fn scale(&self) -> Option<f32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<f32>(AttentionAttrs::VT_SCALE, None) }
+    }
+
+    #[inline]
+    pub fn softcap(&self) -> Option<f32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<f32>(AttentionAttrs::VT_SOFTCAP, None) }
+    }
+
+    #[inline]
+    pub fn flush_nans_to_zero(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(AttentionAttrs::VT_FLUSH_NANS_TO_ZERO, Some(false))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for AttentionAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<bool>("is_causal", Self::VT_IS_CAUSAL, false)?
+            .visit_field::<u32>("q_num_heads", Self::VT_Q_NUM_HEADS, false)?
+            .visit_field::<u32>("kv_num_heads", Self::VT_KV_NUM_HEADS, false)?
+            .visit_field::<f32>("scale", Self::VT_SCALE, false)?
+            .visit_field::<f32>("softcap", Self::VT_SOFTCAP, false)?
+            .visit_field::<bool>("flush_nans_to_zero", Self::VT_FLUSH_NANS_TO_ZERO, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct AttentionAttrsArgs {
+    pub is_causal: bool,
+    pub q_num_heads: Option<u32>,
+    pub kv_num_heads: Option<u32>,
+    pub scale: Option<f32>,
+    pub softcap: Option<f32>,
+    pub flush_nans_to_zero: bool,
+}
+impl<'a> Default for AttentionAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        AttentionAttrsArgs {
+            is_causal: false,
+            q_num_heads: None,
+            kv_num_heads: None,
+            scale: None,
+            softcap: None,
+            flush_nans_to_zero: false,
+        }
+    }
+}
+
+pub struct AttentionAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AttentionAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_is_causal(&mut self, is_causal: bool) {
+        self.fbb_
+            .push_slot::<bool>(AttentionAttrs::VT_IS_CAUSAL, is_causal, false);
+    }
+    #[inline]
+    pub fn add_q_num_heads(&mut self, q_num_heads: u32) {
+        self.fbb_
+            .push_slot_always::<u32>(AttentionAttrs::VT_Q_NUM_HEADS, q_num_heads);
+    }
+    #[inline]
+    pub fn add_kv_num_heads(&mut self, kv_num_heads: u32) {
+        self.fbb_
+            .push_slot_always::<u32>(AttentionAttrs::VT_KV_NUM_HEADS, kv_num_heads);
+    }
+    #[inline]
+    pub fn add_scale(&mut self, scale: f32) {
+        self.fbb_
+            .push_slot_always::<f32>(AttentionAttrs::VT_SCALE, scale);
+    }
+    #[inline]
+    pub fn add_softcap(&mut self, softcap: f32) {
+        self.fbb_
+            .push_slot_always::<f32>(AttentionAttrs::VT_SOFTCAP, softcap);
+    }
+    #[inline]
+    pub fn add_flush_nans_to_zero(&mut self, flush_nans_to_zero: bool) {
+        self.fbb_.push_slot::<bool>(
+            AttentionAttrs::VT_FLUSH_NANS_TO_ZERO,
+            flush_nans_to_zero,
+            false,
+        );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> AttentionAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        AttentionAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<AttentionAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for AttentionAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("AttentionAttrs");
+        ds.field("is_causal", &self.is_causal());
+        ds.field("q_num_heads", &self.q_num_heads());
+        ds.field("kv_num_heads", &self.kv_num_heads());
+        ds.field("scale", &self.scale());
+        ds.field("softcap", &self.softcap());
+        ds.field("flush_nans_to_zero", &self.flush_nans_to_zero());
+        ds.finish()
+    }
+}
+pub enum AveragePoolAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct AveragePoolAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for AveragePoolAttrs<'a> {
+    type Inner = AveragePoolAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> AveragePoolAttrs<'a> {
+    pub const VT_KERNEL_SIZE: flatbuffers::VOffsetT = 4;
+    pub const VT_AUTO_PAD: flatbuffers::VOffsetT = 6;
+    pub const VT_PADS: flatbuffers::VOffsetT = 8;
+    pub const VT_STRIDES: flatbuffers::VOffsetT = 10;
+    pub const VT_COUNT_INCLUDE_PAD: flatbuffers::VOffsetT = 12;
+    pub const VT_CEIL_MODE: flatbuffers::VOffsetT = 14;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        AveragePoolAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
@@ -2887,6 +3749,118 @@ impl core::fmt::Debug for BatchNormalizationAttrs<'_> {
         ds.finish()
     }
 }
+pub enum BitShiftAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct BitShiftAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for BitShiftAttrs<'a> {
+    type Inner = BitShiftAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> BitShiftAttrs<'a> {
+    pub const VT_DIRECTION: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        BitShiftAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args BitShiftAttrsArgs,
+    ) -> flatbuffers::WIPOffset<BitShiftAttrs<'bldr>> {
+        let mut builder = BitShiftAttrsBuilder::new(_fbb);
+        builder.add_direction(args.direction);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn direction(&self) -> BitShiftDirection {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<BitShiftDirection>(
+                    BitShiftAttrs::VT_DIRECTION,
+                    Some(BitShiftDirection::Left),
+                )
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for BitShiftAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<BitShiftDirection>("direction", Self::VT_DIRECTION, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct BitShiftAttrsArgs {
+    pub direction: BitShiftDirection,
+}
+impl<'a> Default for BitShiftAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        BitShiftAttrsArgs {
+            direction: BitShiftDirection::Left,
+        }
+    }
+}
+
+pub struct BitShiftAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> BitShiftAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_direction(&mut self, direction: BitShiftDirection) {
+        self.fbb_.push_slot::<BitShiftDirection>(
+            BitShiftAttrs::VT_DIRECTION,
+            direction,
+            BitShiftDirection::Left,
+        );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> BitShiftAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        BitShiftAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<BitShiftAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for BitShiftAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("BitShiftAttrs");
+        ds.field("direction", &self.direction());
+        ds.finish()
+    }
+}
 pub enum CastAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
@@ -3070,15 +4044,15 @@ impl core::fmt::Debug for CastLikeAttrs<'_> {
         ds.finish()
     }
 }
-pub enum ConcatAttrsOffset {}
+pub enum SymExprNodeOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ConcatAttrs<'a> {
+pub struct SymExprNode<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ConcatAttrs<'a> {
-    type Inner = ConcatAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for SymExprNode<'a> {
+    type Inner = SymExprNode<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -3087,97 +4061,170 @@ impl<'a> flatbuffers::Follow<'a> for ConcatAttrs<'a> {
     }
 }
 
-impl<'a> ConcatAttrs<'a> {
-    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+impl<'a> SymExprNode<'a> {
+    pub const VT_OP: flatbuffers::VOffsetT = 4;
+    pub const VT_VALUE: flatbuffers::VOffsetT = 6;
+    pub const VT_NAME: flatbuffers::VOffsetT = 8;
+    pub const VT_POSITIVE: flatbuffers::VOffsetT = 10;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ConcatAttrs { _tab: table }
+        SymExprNode { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args ConcatAttrsArgs,
-    ) -> flatbuffers::WIPOffset<ConcatAttrs<'bldr>> {
-        let mut builder = ConcatAttrsBuilder::new(_fbb);
-        builder.add_axis(args.axis);
+        args: &'args SymExprNodeArgs<'args>,
+    ) -> flatbuffers::WIPOffset<SymExprNode<'bldr>> {
+        let mut builder = SymExprNodeBuilder::new(_fbb);
+        if let Some(x) = args.name {
+            builder.add_name(x);
+        }
+        builder.add_value(args.value);
+        builder.add_positive(args.positive);
+        builder.add_op(args.op);
         builder.finish()
     }
 
     #[inline]
-    pub fn axis(&self) -> i32 {
+    pub fn op(&self) -> SymExprOp {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<i32>(ConcatAttrs::VT_AXIS, Some(0)).unwrap() }
+        unsafe {
+            self._tab
+                .get::<SymExprOp>(SymExprNode::VT_OP, Some(SymExprOp::Value))
+                .unwrap()
+        }
     }
-}
-
-impl flatbuffers::Verifiable for ConcatAttrs<'_> {
     #[inline]
-    fn run_verifier(
-        v: &mut flatbuffers::Verifier,
-        pos: usize,
-    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    pub fn value(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<i32>(SymExprNode::VT_VALUE, Some(0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn name(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(SymExprNode::VT_NAME, None)
+        }
+    }
+    #[inline]
+    pub fn positive(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(SymExprNode::VT_POSITIVE, Some(false))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for SymExprNode<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<SymExprOp>("op", Self::VT_OP, false)?
+            .visit_field::<i32>("value", Self::VT_VALUE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+            .visit_field::<bool>("positive", Self::VT_POSITIVE, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ConcatAttrsArgs {
-    pub axis: i32,
+pub struct SymExprNodeArgs<'a> {
+    pub op: SymExprOp,
+    pub value: i32,
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub positive: bool,
 }
-impl<'a> Default for ConcatAttrsArgs {
+impl<'a> Default for SymExprNodeArgs<'a> {
     #[inline]
     fn default() -> Self {
-        ConcatAttrsArgs { axis: 0 }
+        SymExprNodeArgs {
+            op: SymExprOp::Value,
+            value: 0,
+            name: None,
+            positive: false,
+        }
     }
 }
 
-pub struct ConcatAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct SymExprNodeBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ConcatAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SymExprNodeBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_.push_slot::<i32>(ConcatAttrs::VT_AXIS, axis, 0);
+    pub fn add_op(&mut self, op: SymExprOp) {
+        self.fbb_
+            .push_slot::<SymExprOp>(SymExprNode::VT_OP, op, SymExprOp::Value);
+    }
+    #[inline]
+    pub fn add_value(&mut self, value: i32) {
+        self.fbb_.push_slot::<i32>(SymExprNode::VT_VALUE, value, 0);
+    }
+    #[inline]
+    pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(SymExprNode::VT_NAME, name);
+    }
+    #[inline]
+    pub fn add_positive(&mut self, positive: bool) {
+        self.fbb_
+            .push_slot::<bool>(SymExprNode::VT_POSITIVE, positive, false);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> ConcatAttrsBuilder<'a, 'b, A> {
+    ) -> SymExprNodeBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        ConcatAttrsBuilder {
+        SymExprNodeBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ConcatAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<SymExprNode<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ConcatAttrs<'_> {
+impl core::fmt::Debug for SymExprNode<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ConcatAttrs");
-        ds.field("axis", &self.axis());
+        let mut ds = f.debug_struct("SymExprNode");
+        ds.field("op", &self.op());
+        ds.field("value", &self.value());
+        ds.field("name", &self.name());
+        ds.field("positive", &self.positive());
         ds.finish()
     }
 }
-pub enum ConcatFromSequenceAttrsOffset {}
+pub enum SymbolInfoOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ConcatFromSequenceAttrs<'a> {
+pub struct SymbolInfo<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ConcatFromSequenceAttrs<'a> {
-    type Inner = ConcatFromSequenceAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for SymbolInfo<'a> {
+    type Inner = SymbolInfo<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -3186,50 +4233,57 @@ impl<'a> flatbuffers::Follow<'a> for ConcatFromSequenceAttrs<'a> {
     }
 }
 
-impl<'a> ConcatFromSequenceAttrs<'a> {
-    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
-    pub const VT_NEW_AXIS: flatbuffers::VOffsetT = 6;
+impl<'a> SymbolInfo<'a> {
+    pub const VT_NAME: flatbuffers::VOffsetT = 4;
+    pub const VT_INPUT: flatbuffers::VOffsetT = 6;
+    pub const VT_AXIS: flatbuffers::VOffsetT = 8;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ConcatFromSequenceAttrs { _tab: table }
+        SymbolInfo { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args ConcatFromSequenceAttrsArgs,
-    ) -> flatbuffers::WIPOffset<ConcatFromSequenceAttrs<'bldr>> {
-        let mut builder = ConcatFromSequenceAttrsBuilder::new(_fbb);
+        args: &'args SymbolInfoArgs<'args>,
+    ) -> flatbuffers::WIPOffset<SymbolInfo<'bldr>> {
+        let mut builder = SymbolInfoBuilder::new(_fbb);
         builder.add_axis(args.axis);
-        builder.add_new_axis(args.new_axis);
+        builder.add_input(args.input);
+        if let Some(x) = args.name {
+            builder.add_name(x);
+        }
         builder.finish()
     }
 
     #[inline]
-    pub fn axis(&self) -> i32 {
+    pub fn name(&self) -> &'a str {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<i32>(ConcatFromSequenceAttrs::VT_AXIS, Some(0))
+                .get::<flatbuffers::ForwardsUOffset<&str>>(SymbolInfo::VT_NAME, None)
                 .unwrap()
         }
     }
     #[inline]
-    pub fn new_axis(&self) -> bool {
+    pub fn input(&self) -> u32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<bool>(ConcatFromSequenceAttrs::VT_NEW_AXIS, Some(false))
-                .unwrap()
-        }
+        unsafe { self._tab.get::<u32>(SymbolInfo::VT_INPUT, Some(0)).unwrap() }
+    }
+    #[inline]
+    pub fn axis(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<u32>(SymbolInfo::VT_AXIS, Some(0)).unwrap() }
     }
 }
 
-impl flatbuffers::Verifiable for ConcatFromSequenceAttrs<'_> {
+impl flatbuffers::Verifiable for SymbolInfo<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -3237,75 +4291,83 @@ impl flatbuffers::Verifiable for ConcatFromSequenceAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
-            .visit_field::<bool>("new_axis", Self::VT_NEW_AXIS, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, true)?
+            .visit_field::<u32>("input", Self::VT_INPUT, false)?
+            .visit_field::<u32>("axis", Self::VT_AXIS, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ConcatFromSequenceAttrsArgs {
-    pub axis: i32,
-    pub new_axis: bool,
+pub struct SymbolInfoArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub input: u32,
+    pub axis: u32,
 }
-impl<'a> Default for ConcatFromSequenceAttrsArgs {
+impl<'a> Default for SymbolInfoArgs<'a> {
     #[inline]
     fn default() -> Self {
-        ConcatFromSequenceAttrsArgs {
+        SymbolInfoArgs {
+            name: None, // required field
+            input: 0,
             axis: 0,
-            new_axis: false,
         }
     }
 }
 
-pub struct ConcatFromSequenceAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct SymbolInfoBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ConcatFromSequenceAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SymbolInfoBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_axis(&mut self, axis: i32) {
+    pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b str>) {
         self.fbb_
-            .push_slot::<i32>(ConcatFromSequenceAttrs::VT_AXIS, axis, 0);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(SymbolInfo::VT_NAME, name);
     }
     #[inline]
-    pub fn add_new_axis(&mut self, new_axis: bool) {
-        self.fbb_
-            .push_slot::<bool>(ConcatFromSequenceAttrs::VT_NEW_AXIS, new_axis, false);
+    pub fn add_input(&mut self, input: u32) {
+        self.fbb_.push_slot::<u32>(SymbolInfo::VT_INPUT, input, 0);
+    }
+    #[inline]
+    pub fn add_axis(&mut self, axis: u32) {
+        self.fbb_.push_slot::<u32>(SymbolInfo::VT_AXIS, axis, 0);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> ConcatFromSequenceAttrsBuilder<'a, 'b, A> {
+    ) -> SymbolInfoBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        ConcatFromSequenceAttrsBuilder {
+        SymbolInfoBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ConcatFromSequenceAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<SymbolInfo<'a>> {
         let o = self.fbb_.end_table(self.start_);
+        self.fbb_.required(o, SymbolInfo::VT_NAME, "name");
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ConcatFromSequenceAttrs<'_> {
+impl core::fmt::Debug for SymbolInfo<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ConcatFromSequenceAttrs");
+        let mut ds = f.debug_struct("SymbolInfo");
+        ds.field("name", &self.name());
+        ds.field("input", &self.input());
         ds.field("axis", &self.axis());
-        ds.field("new_axis", &self.new_axis());
         ds.finish()
     }
 }
-pub enum DepthToSpaceAttrsOffset {}
+pub enum ComputeShapeAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct DepthToSpaceAttrs<'a> {
+pub struct ComputeShapeAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for DepthToSpaceAttrs<'a> {
-    type Inner = DepthToSpaceAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for ComputeShapeAttrs<'a> {
+    type Inner = ComputeShapeAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -3314,50 +4376,58 @@ impl<'a> flatbuffers::Follow<'a> for DepthToSpaceAttrs<'a> {
     }
 }
 
-impl<'a> DepthToSpaceAttrs<'a> {
-    pub const VT_MODE: flatbuffers::VOffsetT = 4;
-    pub const VT_BLOCK_SIZE: flatbuffers::VOffsetT = 6;
+impl<'a> ComputeShapeAttrs<'a> {
+    pub const VT_SYMBOLS: flatbuffers::VOffsetT = 4;
+    pub const VT_SHAPE: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        DepthToSpaceAttrs { _tab: table }
+        ComputeShapeAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args DepthToSpaceAttrsArgs,
-    ) -> flatbuffers::WIPOffset<DepthToSpaceAttrs<'bldr>> {
-        let mut builder = DepthToSpaceAttrsBuilder::new(_fbb);
-        builder.add_block_size(args.block_size);
-        builder.add_mode(args.mode);
+        args: &'args ComputeShapeAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<ComputeShapeAttrs<'bldr>> {
+        let mut builder = ComputeShapeAttrsBuilder::new(_fbb);
+        if let Some(x) = args.shape {
+            builder.add_shape(x);
+        }
+        if let Some(x) = args.symbols {
+            builder.add_symbols(x);
+        }
         builder.finish()
     }
 
     #[inline]
-    pub fn mode(&self) -> DepthToSpaceMode {
+    pub fn symbols(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SymbolInfo<'a>>>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
-            self._tab
-                .get::<DepthToSpaceMode>(DepthToSpaceAttrs::VT_MODE, Some(DepthToSpaceMode::DCR))
-                .unwrap()
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SymbolInfo>>,
+            >>(ComputeShapeAttrs::VT_SYMBOLS, None)
         }
     }
     #[inline]
-    pub fn block_size(&self) -> u32 {
+    pub fn shape(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SymExprNode<'a>>>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
-            self._tab
-                .get::<u32>(DepthToSpaceAttrs::VT_BLOCK_SIZE, Some(0))
-                .unwrap()
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SymExprNode>>,
+            >>(ComputeShapeAttrs::VT_SHAPE, None)
         }
     }
 }
 
-impl flatbuffers::Verifiable for DepthToSpaceAttrs<'_> {
+impl flatbuffers::Verifiable for ComputeShapeAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -3365,78 +4435,97 @@ impl flatbuffers::Verifiable for DepthToSpaceAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<DepthToSpaceMode>("mode", Self::VT_MODE, false)?
-            .visit_field::<u32>("block_size", Self::VT_BLOCK_SIZE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<SymbolInfo>>,
+            >>("symbols", Self::VT_SYMBOLS, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<SymExprNode>>,
+            >>("shape", Self::VT_SHAPE, false)?
             .finish();
         Ok(())
     }
 }
-pub struct DepthToSpaceAttrsArgs {
-    pub mode: DepthToSpaceMode,
-    pub block_size: u32,
+pub struct ComputeShapeAttrsArgs<'a> {
+    pub symbols: Option<
+        flatbuffers::WIPOffset<
+            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SymbolInfo<'a>>>,
+        >,
+    >,
+    pub shape: Option<
+        flatbuffers::WIPOffset<
+            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<SymExprNode<'a>>>,
+        >,
+    >,
 }
-impl<'a> Default for DepthToSpaceAttrsArgs {
+impl<'a> Default for ComputeShapeAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        DepthToSpaceAttrsArgs {
-            mode: DepthToSpaceMode::DCR,
-            block_size: 0,
+        ComputeShapeAttrsArgs {
+            symbols: None,
+            shape: None,
         }
     }
 }
 
-pub struct DepthToSpaceAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct ComputeShapeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DepthToSpaceAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ComputeShapeAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_mode(&mut self, mode: DepthToSpaceMode) {
-        self.fbb_.push_slot::<DepthToSpaceMode>(
-            DepthToSpaceAttrs::VT_MODE,
-            mode,
-            DepthToSpaceMode::DCR,
-        );
+    pub fn add_symbols(
+        &mut self,
+        symbols: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<SymbolInfo<'b>>>,
+        >,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ComputeShapeAttrs::VT_SYMBOLS, symbols);
     }
     #[inline]
-    pub fn add_block_size(&mut self, block_size: u32) {
+    pub fn add_shape(
+        &mut self,
+        shape: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<SymExprNode<'b>>>,
+        >,
+    ) {
         self.fbb_
-            .push_slot::<u32>(DepthToSpaceAttrs::VT_BLOCK_SIZE, block_size, 0);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ComputeShapeAttrs::VT_SHAPE, shape);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> DepthToSpaceAttrsBuilder<'a, 'b, A> {
+    ) -> ComputeShapeAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        DepthToSpaceAttrsBuilder {
+        ComputeShapeAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<DepthToSpaceAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<ComputeShapeAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for DepthToSpaceAttrs<'_> {
+impl core::fmt::Debug for ComputeShapeAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("DepthToSpaceAttrs");
-        ds.field("mode", &self.mode());
-        ds.field("block_size", &self.block_size());
+        let mut ds = f.debug_struct("ComputeShapeAttrs");
+        ds.field("symbols", &self.symbols());
+        ds.field("shape", &self.shape());
         ds.finish()
     }
 }
-pub enum DropoutAttrsOffset {}
+pub enum CeluAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct DropoutAttrs<'a> {
+pub struct CeluAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for DropoutAttrs<'a> {
-    type Inner = DropoutAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for CeluAttrs<'a> {
+    type Inner = CeluAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -3445,35 +4534,37 @@ impl<'a> flatbuffers::Follow<'a> for DropoutAttrs<'a> {
     }
 }
 
-impl<'a> DropoutAttrs<'a> {
-    pub const VT_SEED: flatbuffers::VOffsetT = 4;
+impl<'a> CeluAttrs<'a> {
+    pub const VT_ALPHA: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        DropoutAttrs { _tab: table }
+        CeluAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args DropoutAttrsArgs,
-    ) -> flatbuffers::WIPOffset<DropoutAttrs<'bldr>> {
-        let mut builder = DropoutAttrsBuilder::new(_fbb);
-        if let Some(x) = args.seed {
-            builder.add_seed(x);
-        }
+        args: &'args CeluAttrsArgs,
+    ) -> flatbuffers::WIPOffset<CeluAttrs<'bldr>> {
+        let mut builder = CeluAttrsBuilder::new(_fbb);
+        builder.add_alpha(args.alpha);
         builder.finish()
     }
 
     #[inline]
-    pub fn seed(&self) -> Option<i32> {
+    pub fn alpha(&self) -> f32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<i32>(DropoutAttrs::VT_SEED, None) }
+        unsafe {
+            self._tab
+                .get::<f32>(CeluAttrs::VT_ALPHA, Some(0.0))
+                .unwrap()
+        }
     }
 }
 
-impl flatbuffers::Verifiable for DropoutAttrs<'_> {
+impl flatbuffers::Verifiable for CeluAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -3481,64 +4572,61 @@ impl flatbuffers::Verifiable for DropoutAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("seed", Self::VT_SEED, false)?
+            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
             .finish();
         Ok(())
     }
 }
-pub struct DropoutAttrsArgs {
-    pub seed: Option<i32>,
+pub struct CeluAttrsArgs {
+    pub alpha: f32,
 }
-impl<'a> Default for DropoutAttrsArgs {
+impl<'a> Default for CeluAttrsArgs {
     #[inline]
     fn default() -> Self {
-        DropoutAttrsArgs { seed: None }
+        CeluAttrsArgs { alpha: 0.0 }
     }
 }
 
-pub struct DropoutAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct CeluAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DropoutAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> CeluAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_seed(&mut self, seed: i32) {
-        self.fbb_
-            .push_slot_always::<i32>(DropoutAttrs::VT_SEED, seed);
+    pub fn add_alpha(&mut self, alpha: f32) {
+        self.fbb_.push_slot::<f32>(CeluAttrs::VT_ALPHA, alpha, 0.0);
     }
     #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> DropoutAttrsBuilder<'a, 'b, A> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> CeluAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        DropoutAttrsBuilder {
+        CeluAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<DropoutAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<CeluAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for DropoutAttrs<'_> {
+impl core::fmt::Debug for CeluAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("DropoutAttrs");
-        ds.field("seed", &self.seed());
+        let mut ds = f.debug_struct("CeluAttrs");
+        ds.field("alpha", &self.alpha());
         ds.finish()
     }
 }
-pub enum EyeLikeAttrsOffset {}
+pub enum Col2ImAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct EyeLikeAttrs<'a> {
+pub struct Col2ImAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for EyeLikeAttrs<'a> {
-    type Inner = EyeLikeAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for Col2ImAttrs<'a> {
+    type Inner = Col2ImAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -3547,44 +4635,77 @@ impl<'a> flatbuffers::Follow<'a> for EyeLikeAttrs<'a> {
     }
 }
 
-impl<'a> EyeLikeAttrs<'a> {
-    pub const VT_DTYPE: flatbuffers::VOffsetT = 4;
-    pub const VT_K: flatbuffers::VOffsetT = 6;
+impl<'a> Col2ImAttrs<'a> {
+    pub const VT_DILATIONS: flatbuffers::VOffsetT = 4;
+    pub const VT_PADS: flatbuffers::VOffsetT = 6;
+    pub const VT_STRIDES: flatbuffers::VOffsetT = 8;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        EyeLikeAttrs { _tab: table }
+        Col2ImAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args EyeLikeAttrsArgs,
-    ) -> flatbuffers::WIPOffset<EyeLikeAttrs<'bldr>> {
-        let mut builder = EyeLikeAttrsBuilder::new(_fbb);
-        builder.add_k(args.k);
-        if let Some(x) = args.dtype {
-            builder.add_dtype(x);
+        args: &'args Col2ImAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<Col2ImAttrs<'bldr>> {
+        let mut builder = Col2ImAttrsBuilder::new(_fbb);
+        if let Some(x) = args.strides {
+            builder.add_strides(x);
+        }
+        if let Some(x) = args.pads {
+            builder.add_pads(x);
+        }
+        if let Some(x) = args.dilations {
+            builder.add_dilations(x);
         }
         builder.finish()
     }
 
     #[inline]
-    pub fn dtype(&self) -> Option<DataType> {
+    pub fn dilations(&self) -> Option<flatbuffers::Vector<'a, u32>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<DataType>(EyeLikeAttrs::VT_DTYPE, None) }
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    Col2ImAttrs::VT_DILATIONS,
+                    None,
+                )
+        }
     }
+
     #[inline]
-    pub fn k(&self) -> i32 {
+    pub fn pads(&self) -> Option<flatbuffers::Vector<'a, u32>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<i32>(EyeLikeAttrs::VT_K, Some(0)).unwrap() }
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    Col2ImAttrs::VT_PADS,
+                    None,
+                )
+        }
+    }
+
+    #[inline]
+    pub fn strides(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    Col2ImAttrs::VT_STRIDES,
+                    None,
+                )
+        }
     }
 }
 
-impl flatbuffers::Verifiable for EyeLikeAttrs<'_> {
+impl flatbuffers::Verifiable for Col2ImAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -3592,71 +4713,99 @@ impl flatbuffers::Verifiable for EyeLikeAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<DataType>("dtype", Self::VT_DTYPE, false)?
-            .visit_field::<i32>("k", Self::VT_K, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "dilations",
+                Self::VT_DILATIONS,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "pads",
+                Self::VT_PADS,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "strides",
+                Self::VT_STRIDES,
+                false,
+            )?
             .finish();
         Ok(())
     }
 }
-pub struct EyeLikeAttrsArgs {
-    pub dtype: Option<DataType>,
-    pub k: i32,
+pub struct Col2ImAttrsArgs<'a> {
+    pub dilations: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
 }
-impl<'a> Default for EyeLikeAttrsArgs {
+impl<'a> Default for Col2ImAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        EyeLikeAttrsArgs { dtype: None, k: 0 }
+        Col2ImAttrsArgs {
+            dilations: None,
+            pads: None,
+            strides: None,
+        }
     }
 }
 
-pub struct EyeLikeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct Col2ImAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> EyeLikeAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> Col2ImAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_dtype(&mut self, dtype: DataType) {
+    pub fn add_dilations(
+        &mut self,
+        dilations: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
         self.fbb_
-            .push_slot_always::<DataType>(EyeLikeAttrs::VT_DTYPE, dtype);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Col2ImAttrs::VT_DILATIONS, dilations);
     }
     #[inline]
-    pub fn add_k(&mut self, k: i32) {
-        self.fbb_.push_slot::<i32>(EyeLikeAttrs::VT_K, k, 0);
+    pub fn add_pads(&mut self, pads: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Col2ImAttrs::VT_PADS, pads);
+    }
+    #[inline]
+    pub fn add_strides(&mut self, strides: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(Col2ImAttrs::VT_STRIDES, strides);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> EyeLikeAttrsBuilder<'a, 'b, A> {
+    ) -> Col2ImAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        EyeLikeAttrsBuilder {
+        Col2ImAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<EyeLikeAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<Col2ImAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for EyeLikeAttrs<'_> {
+impl core::fmt::Debug for Col2ImAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("EyeLikeAttrs");
-        ds.field("dtype", &self.dtype());
-        ds.field("k", &self.k());
+        let mut ds = f.debug_struct("Col2ImAttrs");
+        ds.field("dilations", &self.dilations());
+        ds.field("pads", &self.pads());
+        ds.field("strides", &self.strides());
         ds.finish()
     }
 }
-pub enum IsInfAttrsOffset {}
+pub enum CompressAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct IsInfAttrs<'a> {
+pub struct CompressAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for IsInfAttrs<'a> {
-    type Inner = IsInfAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for CompressAttrs<'a> {
+    type Inner = CompressAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -3665,77 +4814,100 @@ impl<'a> flatbuffers::Follow<'a> for IsInfAttrs<'a> {
     }
 }
 
-impl<'a> IsInfAttrs<'a> {
+impl<'a> CompressAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        IsInfAttrs { _tab: table }
+        CompressAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        _args: &'args IsInfAttrsArgs,
-    ) -> flatbuffers::WIPOffset<IsInfAttrs<'bldr>> {
-        let mut builder = IsInfAttrsBuilder::new(_fbb);
+        args: &'args CompressAttrsArgs,
+    ) -> flatbuffers::WIPOffset<CompressAttrs<'bldr>> {
+        let mut builder = CompressAttrsBuilder::new(_fbb);
+        if let Some(x) = args.axis {
+            builder.add_axis(x);
+        }
         builder.finish()
     }
+
+    #[inline]
+    pub fn axis(&self) -> Option<i32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<i32>(CompressAttrs::VT_AXIS, None) }
+    }
 }
 
-impl flatbuffers::Verifiable for IsInfAttrs<'_> {
+impl flatbuffers::Verifiable for CompressAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
         pos: usize,
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
-        v.visit_table(pos)?.finish();
+        v.visit_table(pos)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .finish();
         Ok(())
     }
 }
-pub struct IsInfAttrsArgs {}
-impl<'a> Default for IsInfAttrsArgs {
+pub struct CompressAttrsArgs {
+    pub axis: Option<i32>,
+}
+impl<'a> Default for CompressAttrsArgs {
     #[inline]
     fn default() -> Self {
-        IsInfAttrsArgs {}
+        CompressAttrsArgs { axis: None }
     }
 }
 
-pub struct IsInfAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct CompressAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> IsInfAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> CompressAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_
+            .push_slot_always::<i32>(CompressAttrs::VT_AXIS, axis);
+    }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> IsInfAttrsBuilder<'a, 'b, A> {
+    ) -> CompressAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        IsInfAttrsBuilder {
+        CompressAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<IsInfAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<CompressAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for IsInfAttrs<'_> {
+impl core::fmt::Debug for CompressAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("IsInfAttrs");
+        let mut ds = f.debug_struct("CompressAttrs");
+        ds.field("axis", &self.axis());
         ds.finish()
     }
 }
-pub enum IntScalarOffset {}
+pub enum ConcatAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct IntScalar<'a> {
+pub struct ConcatAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for IntScalar<'a> {
-    type Inner = IntScalar<'a>;
+impl<'a> flatbuffers::Follow<'a> for ConcatAttrs<'a> {
+    type Inner = ConcatAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -3744,33 +4916,33 @@ impl<'a> flatbuffers::Follow<'a> for IntScalar<'a> {
     }
 }
 
-impl<'a> IntScalar<'a> {
-    pub const VT_VALUE: flatbuffers::VOffsetT = 4;
+impl<'a> ConcatAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        IntScalar { _tab: table }
+        ConcatAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args IntScalarArgs,
-    ) -> flatbuffers::WIPOffset<IntScalar<'bldr>> {
-        let mut builder = IntScalarBuilder::new(_fbb);
-        builder.add_value(args.value);
+        args: &'args ConcatAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ConcatAttrs<'bldr>> {
+        let mut builder = ConcatAttrsBuilder::new(_fbb);
+        builder.add_axis(args.axis);
         builder.finish()
     }
 
     #[inline]
-    pub fn value(&self) -> i32 {
+    pub fn axis(&self) -> i32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<i32>(IntScalar::VT_VALUE, Some(0)).unwrap() }
+        unsafe { self._tab.get::<i32>(ConcatAttrs::VT_AXIS, Some(0)).unwrap() }
     }
 }
 
-impl flatbuffers::Verifiable for IntScalar<'_> {
+impl flatbuffers::Verifiable for ConcatAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -3778,61 +4950,63 @@ impl flatbuffers::Verifiable for IntScalar<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("value", Self::VT_VALUE, false)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
             .finish();
         Ok(())
     }
 }
-pub struct IntScalarArgs {
-    pub value: i32,
+pub struct ConcatAttrsArgs {
+    pub axis: i32,
 }
-impl<'a> Default for IntScalarArgs {
+impl<'a> Default for ConcatAttrsArgs {
     #[inline]
     fn default() -> Self {
-        IntScalarArgs { value: 0 }
+        ConcatAttrsArgs { axis: 0 }
     }
 }
 
-pub struct IntScalarBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct ConcatAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> IntScalarBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ConcatAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_value(&mut self, value: i32) {
-        self.fbb_.push_slot::<i32>(IntScalar::VT_VALUE, value, 0);
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_.push_slot::<i32>(ConcatAttrs::VT_AXIS, axis, 0);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> IntScalarBuilder<'a, 'b, A> {
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> ConcatAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        IntScalarBuilder {
+        ConcatAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<IntScalar<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<ConcatAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for IntScalar<'_> {
+impl core::fmt::Debug for ConcatAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("IntScalar");
-        ds.field("value", &self.value());
+        let mut ds = f.debug_struct("ConcatAttrs");
+        ds.field("axis", &self.axis());
         ds.finish()
     }
 }
-pub enum FloatScalarOffset {}
+pub enum ConcatFromSequenceAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct FloatScalar<'a> {
+pub struct ConcatFromSequenceAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for FloatScalar<'a> {
-    type Inner = FloatScalar<'a>;
+impl<'a> flatbuffers::Follow<'a> for ConcatFromSequenceAttrs<'a> {
+    type Inner = ConcatFromSequenceAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -3841,37 +5015,50 @@ impl<'a> flatbuffers::Follow<'a> for FloatScalar<'a> {
     }
 }
 
-impl<'a> FloatScalar<'a> {
-    pub const VT_VALUE: flatbuffers::VOffsetT = 4;
+impl<'a> ConcatFromSequenceAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_NEW_AXIS: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        FloatScalar { _tab: table }
+        ConcatFromSequenceAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args FloatScalarArgs,
-    ) -> flatbuffers::WIPOffset<FloatScalar<'bldr>> {
-        let mut builder = FloatScalarBuilder::new(_fbb);
-        builder.add_value(args.value);
+        args: &'args ConcatFromSequenceAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ConcatFromSequenceAttrs<'bldr>> {
+        let mut builder = ConcatFromSequenceAttrsBuilder::new(_fbb);
+        builder.add_axis(args.axis);
+        builder.add_new_axis(args.new_axis);
         builder.finish()
     }
 
     #[inline]
-    pub fn value(&self) -> f32 {
+    pub fn axis(&self) -> i32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(FloatScalar::VT_VALUE, Some(0.0))
+                .get::<i32>(ConcatFromSequenceAttrs::VT_AXIS, Some(0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn new_axis(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(ConcatFromSequenceAttrs::VT_NEW_AXIS, Some(false))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for FloatScalar<'_> {
+impl flatbuffers::Verifiable for ConcatFromSequenceAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -3879,64 +5066,75 @@ impl flatbuffers::Verifiable for FloatScalar<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<f32>("value", Self::VT_VALUE, false)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<bool>("new_axis", Self::VT_NEW_AXIS, false)?
             .finish();
         Ok(())
     }
 }
-pub struct FloatScalarArgs {
-    pub value: f32,
+pub struct ConcatFromSequenceAttrsArgs {
+    pub axis: i32,
+    pub new_axis: bool,
 }
-impl<'a> Default for FloatScalarArgs {
+impl<'a> Default for ConcatFromSequenceAttrsArgs {
     #[inline]
     fn default() -> Self {
-        FloatScalarArgs { value: 0.0 }
+        ConcatFromSequenceAttrsArgs {
+            axis: 0,
+            new_axis: false,
+        }
     }
 }
 
-pub struct FloatScalarBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct ConcatFromSequenceAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> FloatScalarBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ConcatFromSequenceAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_value(&mut self, value: f32) {
+    pub fn add_axis(&mut self, axis: i32) {
         self.fbb_
-            .push_slot::<f32>(FloatScalar::VT_VALUE, value, 0.0);
+            .push_slot::<i32>(ConcatFromSequenceAttrs::VT_AXIS, axis, 0);
+    }
+    #[inline]
+    pub fn add_new_axis(&mut self, new_axis: bool) {
+        self.fbb_
+            .push_slot::<bool>(ConcatFromSequenceAttrs::VT_NEW_AXIS, new_axis, false);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> FloatScalarBuilder<'a, 'b, A> {
+    ) -> ConcatFromSequenceAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        FloatScalarBuilder {
+        ConcatFromSequenceAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<FloatScalar<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<ConcatFromSequenceAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for FloatScalar<'_> {
+impl core::fmt::Debug for ConcatFromSequenceAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("FloatScalar");
-        ds.field("value", &self.value());
+        let mut ds = f.debug_struct("ConcatFromSequenceAttrs");
+        ds.field("axis", &self.axis());
+        ds.field("new_axis", &self.new_axis());
         ds.finish()
     }
 }
-pub enum ConstantOfShapeAttrsOffset {}
+pub enum DepthToSpaceAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ConstantOfShapeAttrs<'a> {
+pub struct DepthToSpaceAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ConstantOfShapeAttrs<'a> {
-    type Inner = ConstantOfShapeAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for DepthToSpaceAttrs<'a> {
+    type Inner = DepthToSpaceAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -3945,82 +5143,50 @@ impl<'a> flatbuffers::Follow<'a> for ConstantOfShapeAttrs<'a> {
     }
 }
 
-impl<'a> ConstantOfShapeAttrs<'a> {
-    pub const VT_VALUE_TYPE: flatbuffers::VOffsetT = 4;
-    pub const VT_VALUE: flatbuffers::VOffsetT = 6;
+impl<'a> DepthToSpaceAttrs<'a> {
+    pub const VT_MODE: flatbuffers::VOffsetT = 4;
+    pub const VT_BLOCK_SIZE: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ConstantOfShapeAttrs { _tab: table }
+        DepthToSpaceAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args ConstantOfShapeAttrsArgs,
-    ) -> flatbuffers::WIPOffset<ConstantOfShapeAttrs<'bldr>> {
-        let mut builder = ConstantOfShapeAttrsBuilder::new(_fbb);
-        if let Some(x) = args.value {
-            builder.add_value(x);
-        }
-        builder.add_value_type(args.value_type);
+        args: &'args DepthToSpaceAttrsArgs,
+    ) -> flatbuffers::WIPOffset<DepthToSpaceAttrs<'bldr>> {
+        let mut builder = DepthToSpaceAttrsBuilder::new(_fbb);
+        builder.add_block_size(args.block_size);
+        builder.add_mode(args.mode);
         builder.finish()
     }
 
     #[inline]
-    pub fn value_type(&self) -> Scalar {
+    pub fn mode(&self) -> DepthToSpaceMode {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<Scalar>(ConstantOfShapeAttrs::VT_VALUE_TYPE, Some(Scalar::NONE))
+                .get::<DepthToSpaceMode>(DepthToSpaceAttrs::VT_MODE, Some(DepthToSpaceMode::DCR))
                 .unwrap()
         }
     }
     #[inline]
-    pub fn value(&self) -> flatbuffers::Table<'a> {
+    pub fn block_size(&self) -> u32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(
-                    ConstantOfShapeAttrs::VT_VALUE,
-                    None,
-                )
+                .get::<u32>(DepthToSpaceAttrs::VT_BLOCK_SIZE, Some(0))
                 .unwrap()
         }
     }
-    #[inline]
-    #[allow(non_snake_case)]
-    pub fn value_as_int_scalar(&self) -> Option<IntScalar<'a>> {
-        if self.value_type() == Scalar::IntScalar {
-            let u = self.value();
-            // Safety:
-            // Created from a valid Table for this object
-            // Which contains a valid union in this slot
-            Some(unsafe { IntScalar::init_from_table(u) })
-        } else {
-            None
-        }
-    }
-
-    #[inline]
-    #[allow(non_snake_case)]
-    pub fn value_as_float_scalar(&self) -> Option<FloatScalar<'a>> {
-        if self.value_type() == Scalar::FloatScalar {
-            let u = self.value();
-            // Safety:
-            // Created from a valid Table for this object
-            // Which contains a valid union in this slot
-            Some(unsafe { FloatScalar::init_from_table(u) })
-        } else {
-            None
-        }
-    }
 }
 
-impl flatbuffers::Verifiable for ConstantOfShapeAttrs<'_> {
+impl flatbuffers::Verifiable for DepthToSpaceAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -4028,123 +5194,78 @@ impl flatbuffers::Verifiable for ConstantOfShapeAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_union::<Scalar, _>(
-                "value_type",
-                Self::VT_VALUE_TYPE,
-                "value",
-                Self::VT_VALUE,
-                true,
-                |key, v, pos| match key {
-                    Scalar::IntScalar => v
-                        .verify_union_variant::<flatbuffers::ForwardsUOffset<IntScalar>>(
-                            "Scalar::IntScalar",
-                            pos,
-                        ),
-                    Scalar::FloatScalar => v
-                        .verify_union_variant::<flatbuffers::ForwardsUOffset<FloatScalar>>(
-                            "Scalar::FloatScalar",
-                            pos,
-                        ),
-                    _ => Ok(()),
-                },
-            )?
+            .visit_field::<DepthToSpaceMode>("mode", Self::VT_MODE, false)?
+            .visit_field::<u32>("block_size", Self::VT_BLOCK_SIZE, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ConstantOfShapeAttrsArgs {
-    pub value_type: Scalar,
-    pub value: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+pub struct DepthToSpaceAttrsArgs {
+    pub mode: DepthToSpaceMode,
+    pub block_size: u32,
 }
-impl<'a> Default for ConstantOfShapeAttrsArgs {
+impl<'a> Default for DepthToSpaceAttrsArgs {
     #[inline]
     fn default() -> Self {
-        ConstantOfShapeAttrsArgs {
-            value_type: Scalar::NONE,
-            value: None, // required field
+        DepthToSpaceAttrsArgs {
+            mode: DepthToSpaceMode::DCR,
+            block_size: 0,
         }
     }
 }
 
-pub struct ConstantOfShapeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct DepthToSpaceAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ConstantOfShapeAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DepthToSpaceAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_value_type(&mut self, value_type: Scalar) {
-        self.fbb_.push_slot::<Scalar>(
-            ConstantOfShapeAttrs::VT_VALUE_TYPE,
-            value_type,
-            Scalar::NONE,
+    pub fn add_mode(&mut self, mode: DepthToSpaceMode) {
+        self.fbb_.push_slot::<DepthToSpaceMode>(
+            DepthToSpaceAttrs::VT_MODE,
+            mode,
+            DepthToSpaceMode::DCR,
         );
     }
     #[inline]
-    pub fn add_value(&mut self, value: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+    pub fn add_block_size(&mut self, block_size: u32) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConstantOfShapeAttrs::VT_VALUE, value);
+            .push_slot::<u32>(DepthToSpaceAttrs::VT_BLOCK_SIZE, block_size, 0);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> ConstantOfShapeAttrsBuilder<'a, 'b, A> {
+    ) -> DepthToSpaceAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        ConstantOfShapeAttrsBuilder {
+        DepthToSpaceAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ConstantOfShapeAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<DepthToSpaceAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
-        self.fbb_
-            .required(o, ConstantOfShapeAttrs::VT_VALUE, "value");
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ConstantOfShapeAttrs<'_> {
+impl core::fmt::Debug for DepthToSpaceAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ConstantOfShapeAttrs");
-        ds.field("value_type", &self.value_type());
-        match self.value_type() {
-            Scalar::IntScalar => {
-                if let Some(x) = self.value_as_int_scalar() {
-                    ds.field("value", &x)
-                } else {
-                    ds.field(
-                        "value",
-                        &"InvalidFlatbuffer: Union discriminant does not match value.",
-                    )
-                }
-            }
-            Scalar::FloatScalar => {
-                if let Some(x) = self.value_as_float_scalar() {
-                    ds.field("value", &x)
-                } else {
-                    ds.field(
-                        "value",
-                        &"InvalidFlatbuffer: Union discriminant does not match value.",
-                    )
-                }
-            }
-            _ => {
-                let x: Option<()> = None;
-                ds.field("value", &x)
-            }
-        };
+        let mut ds = f.debug_struct("DepthToSpaceAttrs");
+        ds.field("mode", &self.mode());
+        ds.field("block_size", &self.block_size());
         ds.finish()
     }
 }
-pub enum ConvAttrsOffset {}
+pub enum DFTAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ConvAttrs<'a> {
+pub struct DFTAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ConvAttrs<'a> {
-    type Inner = ConvAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for DFTAttrs<'a> {
+    type Inner = DFTAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4153,97 +5274,63 @@ impl<'a> flatbuffers::Follow<'a> for ConvAttrs<'a> {
     }
 }
 
-impl<'a> ConvAttrs<'a> {
-    pub const VT_AUTO_PAD: flatbuffers::VOffsetT = 4;
-    pub const VT_PADS: flatbuffers::VOffsetT = 6;
-    pub const VT_GROUPS: flatbuffers::VOffsetT = 8;
-    pub const VT_STRIDES: flatbuffers::VOffsetT = 10;
-    pub const VT_DILATIONS: flatbuffers::VOffsetT = 12;
+impl<'a> DFTAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_INVERSE: flatbuffers::VOffsetT = 6;
+    pub const VT_ONESIDED: flatbuffers::VOffsetT = 8;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ConvAttrs { _tab: table }
+        DFTAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args ConvAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<ConvAttrs<'bldr>> {
-        let mut builder = ConvAttrsBuilder::new(_fbb);
-        if let Some(x) = args.dilations {
-            builder.add_dilations(x);
-        }
-        if let Some(x) = args.strides {
-            builder.add_strides(x);
-        }
-        builder.add_groups(args.groups);
-        if let Some(x) = args.pads {
-            builder.add_pads(x);
+        args: &'args DFTAttrsArgs,
+    ) -> flatbuffers::WIPOffset<DFTAttrs<'bldr>> {
+        let mut builder = DFTAttrsBuilder::new(_fbb);
+        if let Some(x) = args.axis {
+            builder.add_axis(x);
         }
-        builder.add_auto_pad(args.auto_pad);
+        builder.add_onesided(args.onesided);
+        builder.add_inverse(args.inverse);
         builder.finish()
     }
 
     #[inline]
-    pub fn auto_pad(&self) -> AutoPad {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<AutoPad>(ConvAttrs::VT_AUTO_PAD, Some(AutoPad::Same))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn pads(&self) -> Option<flatbuffers::Vector<'a, u32>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    ConvAttrs::VT_PADS,
-                    None,
-                )
-        }
-    }
-    #[inline]
-    pub fn groups(&self) -> u32 {
+    pub fn axis(&self) -> Option<i32> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<u32>(ConvAttrs::VT_GROUPS, Some(0)).unwrap() }
+        unsafe { self._tab.get::<i32>(DFTAttrs::VT_AXIS, None) }
     }
+
     #[inline]
-    pub fn strides(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    pub fn inverse(&self) -> bool {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    ConvAttrs::VT_STRIDES,
-                    None,
-                )
+                .get::<bool>(DFTAttrs::VT_INVERSE, Some(false))
+                .unwrap()
         }
     }
+
     #[inline]
-    pub fn dilations(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    pub fn onesided(&self) -> bool {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    ConvAttrs::VT_DILATIONS,
-                    None,
-                )
+                .get::<bool>(DFTAttrs::VT_ONESIDED, Some(false))
+                .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for ConvAttrs<'_> {
+impl flatbuffers::Verifiable for DFTAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -4251,114 +5338,81 @@ impl flatbuffers::Verifiable for ConvAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<AutoPad>("auto_pad", Self::VT_AUTO_PAD, false)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "pads",
-                Self::VT_PADS,
-                false,
-            )?
-            .visit_field::<u32>("groups", Self::VT_GROUPS, false)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "strides",
-                Self::VT_STRIDES,
-                false,
-            )?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "dilations",
-                Self::VT_DILATIONS,
-                false,
-            )?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<bool>("inverse", Self::VT_INVERSE, false)?
+            .visit_field::<bool>("onesided", Self::VT_ONESIDED, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ConvAttrsArgs<'a> {
-    pub auto_pad: AutoPad,
-    pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
-    pub groups: u32,
-    pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
-    pub dilations: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+pub struct DFTAttrsArgs {
+    pub axis: Option<i32>,
+    pub inverse: bool,
+    pub onesided: bool,
 }
-impl<'a> Default for ConvAttrsArgs<'a> {
+impl<'a> Default for DFTAttrsArgs {
     #[inline]
     fn default() -> Self {
-        ConvAttrsArgs {
-            auto_pad: AutoPad::Same,
-            pads: None,
-            groups: 0,
-            strides: None,
-            dilations: None,
+        DFTAttrsArgs {
+            axis: None,
+            inverse: false,
+            onesided: false,
         }
     }
 }
 
-pub struct ConvAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct DFTAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ConvAttrsBuilder<'a, 'b, A> {
-    #[inline]
-    pub fn add_auto_pad(&mut self, auto_pad: AutoPad) {
-        self.fbb_
-            .push_slot::<AutoPad>(ConvAttrs::VT_AUTO_PAD, auto_pad, AutoPad::Same);
-    }
-    #[inline]
-    pub fn add_pads(&mut self, pads: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvAttrs::VT_PADS, pads);
-    }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DFTAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_groups(&mut self, groups: u32) {
-        self.fbb_.push_slot::<u32>(ConvAttrs::VT_GROUPS, groups, 0);
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_.push_slot_always::<i32>(DFTAttrs::VT_AXIS, axis);
     }
     #[inline]
-    pub fn add_strides(&mut self, strides: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+    pub fn add_inverse(&mut self, inverse: bool) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvAttrs::VT_STRIDES, strides);
+            .push_slot::<bool>(DFTAttrs::VT_INVERSE, inverse, false);
     }
     #[inline]
-    pub fn add_dilations(
-        &mut self,
-        dilations: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
-    ) {
+    pub fn add_onesided(&mut self, onesided: bool) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvAttrs::VT_DILATIONS, dilations);
+            .push_slot::<bool>(DFTAttrs::VT_ONESIDED, onesided, false);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ConvAttrsBuilder<'a, 'b, A> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> DFTAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        ConvAttrsBuilder {
+        DFTAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ConvAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<DFTAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ConvAttrs<'_> {
+impl core::fmt::Debug for DFTAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ConvAttrs");
-        ds.field("auto_pad", &self.auto_pad());
-        ds.field("pads", &self.pads());
-        ds.field("groups", &self.groups());
-        ds.field("strides", &self.strides());
-        ds.field("dilations", &self.dilations());
+        let mut ds = f.debug_struct("DFTAttrs");
+        ds.field("axis", &self.axis());
+        ds.field("inverse", &self.inverse());
+        ds.field("onesided", &self.onesided());
         ds.finish()
     }
 }
-pub enum ConvTransposeAttrsOffset {}
+pub enum DropoutAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ConvTransposeAttrs<'a> {
+pub struct DropoutAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ConvTransposeAttrs<'a> {
-    type Inner = ConvTransposeAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for DropoutAttrs<'a> {
+    type Inner = DropoutAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4367,101 +5421,35 @@ impl<'a> flatbuffers::Follow<'a> for ConvTransposeAttrs<'a> {
     }
 }
 
-impl<'a> ConvTransposeAttrs<'a> {
-    pub const VT_STRIDES: flatbuffers::VOffsetT = 4;
-    pub const VT_AUTO_PAD: flatbuffers::VOffsetT = 6;
-    pub const VT_PADS: flatbuffers::VOffsetT = 8;
-    pub const VT_GROUPS: flatbuffers::VOffsetT = 10;
-    pub const VT_OUTPUT_PADDING: flatbuffers::VOffsetT = 12;
+impl<'a> DropoutAttrs<'a> {
+    pub const VT_SEED: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ConvTransposeAttrs { _tab: table }
+        DropoutAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args ConvTransposeAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<ConvTransposeAttrs<'bldr>> {
-        let mut builder = ConvTransposeAttrsBuilder::new(_fbb);
-        if let Some(x) = args.output_padding {
-            builder.add_output_padding(x);
-        }
-        builder.add_groups(args.groups);
-        if let Some(x) = args.pads {
-            builder.add_pads(x);
-        }
-        if let Some(x) = args.strides {
-            builder.add_strides(x);
+        args: &'args DropoutAttrsArgs,
+    ) -> flatbuffers::WIPOffset<DropoutAttrs<'bldr>> {
+        let mut builder = DropoutAttrsBuilder::new(_fbb);
+        if let Some(x) = args.seed {
+            builder.add_seed(x);
         }
-        builder.add_auto_pad(args.auto_pad);
         builder.finish()
     }
 
     #[inline]
-    pub fn strides(&self) -> Option<flatbuffers::Vector<'a, u32>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    ConvTransposeAttrs::VT_STRIDES,
-                    None,
-                )
-        }
-    }
-    #[inline]
-    pub fn auto_pad(&self) -> AutoPad {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<AutoPad>(ConvTransposeAttrs::VT_AUTO_PAD, Some(AutoPad::NotSet))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn pads(&self) -> Option<flatbuffers::Vector<'a, u32>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    ConvTransposeAttrs::VT_PADS,
-                    None,
-                )
-        }
-    }
-    #[inline]
-    pub fn groups(&self) -> u32 {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<u32>(ConvTransposeAttrs::VT_GROUPS, Some(1))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn output_padding(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    pub fn seed(&self) -> Option<i32> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    ConvTransposeAttrs::VT_OUTPUT_PADDING,
-                    None,
-                )
-        }
+        unsafe { self._tab.get::<i32>(DropoutAttrs::VT_SEED, None) }
     }
 }
 
-impl flatbuffers::Verifiable for ConvTransposeAttrs<'_> {
+impl flatbuffers::Verifiable for DropoutAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -4469,119 +5457,64 @@ impl flatbuffers::Verifiable for ConvTransposeAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "strides",
-                Self::VT_STRIDES,
-                false,
-            )?
-            .visit_field::<AutoPad>("auto_pad", Self::VT_AUTO_PAD, false)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "pads",
-                Self::VT_PADS,
-                false,
-            )?
-            .visit_field::<u32>("groups", Self::VT_GROUPS, false)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "output_padding",
-                Self::VT_OUTPUT_PADDING,
-                false,
-            )?
+            .visit_field::<i32>("seed", Self::VT_SEED, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ConvTransposeAttrsArgs<'a> {
-    pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
-    pub auto_pad: AutoPad,
-    pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
-    pub groups: u32,
-    pub output_padding: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+pub struct DropoutAttrsArgs {
+    pub seed: Option<i32>,
 }
-impl<'a> Default for ConvTransposeAttrsArgs<'a> {
+impl<'a> Default for DropoutAttrsArgs {
     #[inline]
     fn default() -> Self {
-        ConvTransposeAttrsArgs {
-            strides: None,
-            auto_pad: AutoPad::NotSet,
-            pads: None,
-            groups: 1,
-            output_padding: None,
-        }
+        DropoutAttrsArgs { seed: None }
     }
 }
 
-pub struct ConvTransposeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct DropoutAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ConvTransposeAttrsBuilder<'a, 'b, A> {
-    #[inline]
-    pub fn add_strides(&mut self, strides: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvTransposeAttrs::VT_STRIDES, strides);
-    }
-    #[inline]
-    pub fn add_auto_pad(&mut self, auto_pad: AutoPad) {
-        self.fbb_
-            .push_slot::<AutoPad>(ConvTransposeAttrs::VT_AUTO_PAD, auto_pad, AutoPad::NotSet);
-    }
-    #[inline]
-    pub fn add_pads(&mut self, pads: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvTransposeAttrs::VT_PADS, pads);
-    }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DropoutAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_groups(&mut self, groups: u32) {
+    pub fn add_seed(&mut self, seed: i32) {
         self.fbb_
-            .push_slot::<u32>(ConvTransposeAttrs::VT_GROUPS, groups, 1);
-    }
-    #[inline]
-    pub fn add_output_padding(
-        &mut self,
-        output_padding: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
-    ) {
-        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
-            ConvTransposeAttrs::VT_OUTPUT_PADDING,
-            output_padding,
-        );
+            .push_slot_always::<i32>(DropoutAttrs::VT_SEED, seed);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> ConvTransposeAttrsBuilder<'a, 'b, A> {
+    ) -> DropoutAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        ConvTransposeAttrsBuilder {
+        DropoutAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ConvTransposeAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<DropoutAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ConvTransposeAttrs<'_> {
+impl core::fmt::Debug for DropoutAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ConvTransposeAttrs");
-        ds.field("strides", &self.strides());
-        ds.field("auto_pad", &self.auto_pad());
-        ds.field("pads", &self.pads());
-        ds.field("groups", &self.groups());
-        ds.field("output_padding", &self.output_padding());
+        let mut ds = f.debug_struct("DropoutAttrs");
+        ds.field("seed", &self.seed());
         ds.finish()
     }
 }
-pub enum DequantizeLinearAttrsOffset {}
+pub enum EyeLikeAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct DequantizeLinearAttrs<'a> {
+pub struct EyeLikeAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for DequantizeLinearAttrs<'a> {
-    type Inner = DequantizeLinearAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for EyeLikeAttrs<'a> {
+    type Inner = EyeLikeAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4590,37 +5523,44 @@ impl<'a> flatbuffers::Follow<'a> for DequantizeLinearAttrs<'a> {
     }
 }
 
-impl<'a> DequantizeLinearAttrs<'a> {
-    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+impl<'a> EyeLikeAttrs<'a> {
+    pub const VT_DTYPE: flatbuffers::VOffsetT = 4;
+    pub const VT_K: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        DequantizeLinearAttrs { _tab: table }
+        EyeLikeAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args DequantizeLinearAttrsArgs,
-    ) -> flatbuffers::WIPOffset<DequantizeLinearAttrs<'bldr>> {
-        let mut builder = DequantizeLinearAttrsBuilder::new(_fbb);
-        builder.add_axis(args.axis);
+        args: &'args EyeLikeAttrsArgs,
+    ) -> flatbuffers::WIPOffset<EyeLikeAttrs<'bldr>> {
+        let mut builder = EyeLikeAttrsBuilder::new(_fbb);
+        builder.add_k(args.k);
+        if let Some(x) = args.dtype {
+            builder.add_dtype(x);
+        }
         builder.finish()
     }
 
     #[inline]
-    pub fn axis(&self) -> i32 {
+    pub fn dtype(&self) -> Option<DataType> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<i32>(DequantizeLinearAttrs::VT_AXIS, Some(0))
-                .unwrap()
-        }
+        unsafe { self._tab.get::<DataType>(EyeLikeAttrs::VT_DTYPE, None) }
+    }
+    #[inline]
+    pub fn k(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<i32>(EyeLikeAttrs::VT_K, Some(0)).unwrap() }
     }
 }
 
-impl flatbuffers::Verifiable for DequantizeLinearAttrs<'_> {
+impl flatbuffers::Verifiable for EyeLikeAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -4628,64 +5568,71 @@ impl flatbuffers::Verifiable for DequantizeLinearAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<DataType>("dtype", Self::VT_DTYPE, false)?
+            .visit_field::<i32>("k", Self::VT_K, false)?
             .finish();
         Ok(())
     }
 }
-pub struct DequantizeLinearAttrsArgs {
-    pub axis: i32,
+pub struct EyeLikeAttrsArgs {
+    pub dtype: Option<DataType>,
+    pub k: i32,
 }
-impl<'a> Default for DequantizeLinearAttrsArgs {
+impl<'a> Default for EyeLikeAttrsArgs {
     #[inline]
     fn default() -> Self {
-        DequantizeLinearAttrsArgs { axis: 0 }
+        EyeLikeAttrsArgs { dtype: None, k: 0 }
     }
 }
 
-pub struct DequantizeLinearAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct EyeLikeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DequantizeLinearAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> EyeLikeAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_axis(&mut self, axis: i32) {
+    pub fn add_dtype(&mut self, dtype: DataType) {
         self.fbb_
-            .push_slot::<i32>(DequantizeLinearAttrs::VT_AXIS, axis, 0);
+            .push_slot_always::<DataType>(EyeLikeAttrs::VT_DTYPE, dtype);
+    }
+    #[inline]
+    pub fn add_k(&mut self, k: i32) {
+        self.fbb_.push_slot::<i32>(EyeLikeAttrs::VT_K, k, 0);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> DequantizeLinearAttrsBuilder<'a, 'b, A> {
+    ) -> EyeLikeAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        DequantizeLinearAttrsBuilder {
+        EyeLikeAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<DequantizeLinearAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<EyeLikeAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for DequantizeLinearAttrs<'_> {
+impl core::fmt::Debug for EyeLikeAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("DequantizeLinearAttrs");
-        ds.field("axis", &self.axis());
+        let mut ds = f.debug_struct("EyeLikeAttrs");
+        ds.field("dtype", &self.dtype());
+        ds.field("k", &self.k());
         ds.finish()
     }
 }
-pub enum EinsumAttrsOffset {}
+pub enum IsInfAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct EinsumAttrs<'a> {
+pub struct IsInfAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for EinsumAttrs<'a> {
-    type Inner = EinsumAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for IsInfAttrs<'a> {
+    type Inner = IsInfAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4694,107 +5641,77 @@ impl<'a> flatbuffers::Follow<'a> for EinsumAttrs<'a> {
     }
 }
 
-impl<'a> EinsumAttrs<'a> {
-    pub const VT_EQUATION: flatbuffers::VOffsetT = 4;
-
+impl<'a> IsInfAttrs<'a> {
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        EinsumAttrs { _tab: table }
+        IsInfAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args EinsumAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<EinsumAttrs<'bldr>> {
-        let mut builder = EinsumAttrsBuilder::new(_fbb);
-        if let Some(x) = args.equation {
-            builder.add_equation(x);
-        }
+        _args: &'args IsInfAttrsArgs,
+    ) -> flatbuffers::WIPOffset<IsInfAttrs<'bldr>> {
+        let mut builder = IsInfAttrsBuilder::new(_fbb);
         builder.finish()
     }
-
-    #[inline]
-    pub fn equation(&self) -> Option<&'a str> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<&str>>(EinsumAttrs::VT_EQUATION, None)
-        }
-    }
 }
 
-impl flatbuffers::Verifiable for EinsumAttrs<'_> {
+impl flatbuffers::Verifiable for IsInfAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
         pos: usize,
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
-        v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
-                "equation",
-                Self::VT_EQUATION,
-                false,
-            )?
-            .finish();
+        v.visit_table(pos)?.finish();
         Ok(())
     }
 }
-pub struct EinsumAttrsArgs<'a> {
-    pub equation: Option<flatbuffers::WIPOffset<&'a str>>,
-}
-impl<'a> Default for EinsumAttrsArgs<'a> {
+pub struct IsInfAttrsArgs {}
+impl<'a> Default for IsInfAttrsArgs {
     #[inline]
     fn default() -> Self {
-        EinsumAttrsArgs { equation: None }
+        IsInfAttrsArgs {}
     }
 }
 
-pub struct EinsumAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct IsInfAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> EinsumAttrsBuilder<'a, 'b, A> {
-    #[inline]
-    pub fn add_equation(&mut self, equation: flatbuffers::WIPOffset<&'b str>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(EinsumAttrs::VT_EQUATION, equation);
-    }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> IsInfAttrsBuilder<'a, 'b, A> {
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> EinsumAttrsBuilder<'a, 'b, A> {
+    ) -> IsInfAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        EinsumAttrsBuilder {
+        IsInfAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<EinsumAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<IsInfAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for EinsumAttrs<'_> {
+impl core::fmt::Debug for IsInfAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("EinsumAttrs");
-        ds.field("equation", &self.equation());
+        let mut ds = f.debug_struct("IsInfAttrs");
         ds.finish()
     }
 }
-pub enum EluAttrsOffset {}
+pub enum IntScalarOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct EluAttrs<'a> {
+pub struct IntScalar<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for EluAttrs<'a> {
-    type Inner = EluAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for IntScalar<'a> {
+    type Inner = IntScalar<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4803,33 +5720,33 @@ impl<'a> flatbuffers::Follow<'a> for EluAttrs<'a> {
     }
 }
 
-impl<'a> EluAttrs<'a> {
-    pub const VT_ALPHA: flatbuffers::VOffsetT = 4;
+impl<'a> IntScalar<'a> {
+    pub const VT_VALUE: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        EluAttrs { _tab: table }
+        IntScalar { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args EluAttrsArgs,
-    ) -> flatbuffers::WIPOffset<EluAttrs<'bldr>> {
-        let mut builder = EluAttrsBuilder::new(_fbb);
-        builder.add_alpha(args.alpha);
+        args: &'args IntScalarArgs,
+    ) -> flatbuffers::WIPOffset<IntScalar<'bldr>> {
+        let mut builder = IntScalarBuilder::new(_fbb);
+        builder.add_value(args.value);
         builder.finish()
     }
 
     #[inline]
-    pub fn alpha(&self) -> f32 {
+    pub fn value(&self) -> i32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<f32>(EluAttrs::VT_ALPHA, Some(0.0)).unwrap() }
+        unsafe { self._tab.get::<i32>(IntScalar::VT_VALUE, Some(0)).unwrap() }
     }
 }
 
-impl flatbuffers::Verifiable for EluAttrs<'_> {
+impl flatbuffers::Verifiable for IntScalar<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -4837,61 +5754,61 @@ impl flatbuffers::Verifiable for EluAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
+            .visit_field::<i32>("value", Self::VT_VALUE, false)?
             .finish();
         Ok(())
     }
 }
-pub struct EluAttrsArgs {
-    pub alpha: f32,
+pub struct IntScalarArgs {
+    pub value: i32,
 }
-impl<'a> Default for EluAttrsArgs {
+impl<'a> Default for IntScalarArgs {
     #[inline]
     fn default() -> Self {
-        EluAttrsArgs { alpha: 0.0 }
+        IntScalarArgs { value: 0 }
     }
 }
 
-pub struct EluAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct IntScalarBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> EluAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> IntScalarBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_alpha(&mut self, alpha: f32) {
-        self.fbb_.push_slot::<f32>(EluAttrs::VT_ALPHA, alpha, 0.0);
+    pub fn add_value(&mut self, value: i32) {
+        self.fbb_.push_slot::<i32>(IntScalar::VT_VALUE, value, 0);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> EluAttrsBuilder<'a, 'b, A> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> IntScalarBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        EluAttrsBuilder {
+        IntScalarBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<EluAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<IntScalar<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for EluAttrs<'_> {
+impl core::fmt::Debug for IntScalar<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("EluAttrs");
-        ds.field("alpha", &self.alpha());
+        let mut ds = f.debug_struct("IntScalar");
+        ds.field("value", &self.value());
         ds.finish()
     }
 }
-pub enum FlattenAttrsOffset {}
+pub enum FloatScalarOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct FlattenAttrs<'a> {
+pub struct FloatScalar<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for FlattenAttrs<'a> {
-    type Inner = FlattenAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for FloatScalar<'a> {
+    type Inner = FloatScalar<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -4900,37 +5817,37 @@ impl<'a> flatbuffers::Follow<'a> for FlattenAttrs<'a> {
     }
 }
 
-impl<'a> FlattenAttrs<'a> {
-    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+impl<'a> FloatScalar<'a> {
+    pub const VT_VALUE: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        FlattenAttrs { _tab: table }
+        FloatScalar { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args FlattenAttrsArgs,
-    ) -> flatbuffers::WIPOffset<FlattenAttrs<'bldr>> {
-        let mut builder = FlattenAttrsBuilder::new(_fbb);
-        builder.add_axis(args.axis);
+        args: &'args FloatScalarArgs,
+    ) -> flatbuffers::WIPOffset<FloatScalar<'bldr>> {
+        let mut builder = FloatScalarBuilder::new(_fbb);
+        builder.add_value(args.value);
         builder.finish()
     }
 
     #[inline]
-    pub fn axis(&self) -> i32 {
+    pub fn value(&self) -> f32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<i32>(FlattenAttrs::VT_AXIS, Some(0))
+                .get::<f32>(FloatScalar::VT_VALUE, Some(0.0))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for FlattenAttrs<'_> {
+impl flatbuffers::Verifiable for FloatScalar<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -4938,63 +5855,64 @@ impl flatbuffers::Verifiable for FlattenAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<f32>("value", Self::VT_VALUE, false)?
             .finish();
         Ok(())
     }
 }
-pub struct FlattenAttrsArgs {
-    pub axis: i32,
+pub struct FloatScalarArgs {
+    pub value: f32,
 }
-impl<'a> Default for FlattenAttrsArgs {
+impl<'a> Default for FloatScalarArgs {
     #[inline]
     fn default() -> Self {
-        FlattenAttrsArgs { axis: 0 }
+        FloatScalarArgs { value: 0.0 }
     }
 }
 
-pub struct FlattenAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct FloatScalarBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> FlattenAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> FloatScalarBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_.push_slot::<i32>(FlattenAttrs::VT_AXIS, axis, 0);
+    pub fn add_value(&mut self, value: f32) {
+        self.fbb_
+            .push_slot::<f32>(FloatScalar::VT_VALUE, value, 0.0);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> FlattenAttrsBuilder<'a, 'b, A> {
+    ) -> FloatScalarBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        FlattenAttrsBuilder {
+        FloatScalarBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<FlattenAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<FloatScalar<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for FlattenAttrs<'_> {
+impl core::fmt::Debug for FloatScalar<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("FlattenAttrs");
-        ds.field("axis", &self.axis());
+        let mut ds = f.debug_struct("FloatScalar");
+        ds.field("value", &self.value());
         ds.finish()
     }
 }
-pub enum LayerNormalizationAttrsOffset {}
+pub enum ConstantOfShapeAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct LayerNormalizationAttrs<'a> {
+pub struct ConstantOfShapeAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for LayerNormalizationAttrs<'a> {
-    type Inner = LayerNormalizationAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for ConstantOfShapeAttrs<'a> {
+    type Inner = ConstantOfShapeAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -5003,50 +5921,82 @@ impl<'a> flatbuffers::Follow<'a> for LayerNormalizationAttrs<'a> {
     }
 }
 
-impl<'a> LayerNormalizationAttrs<'a> {
-    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
-    pub const VT_EPSILON: flatbuffers::VOffsetT = 6;
+impl<'a> ConstantOfShapeAttrs<'a> {
+    pub const VT_VALUE_TYPE: flatbuffers::VOffsetT = 4;
+    pub const VT_VALUE: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        LayerNormalizationAttrs { _tab: table }
+        ConstantOfShapeAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args LayerNormalizationAttrsArgs,
-    ) -> flatbuffers::WIPOffset<LayerNormalizationAttrs<'bldr>> {
-        let mut builder = LayerNormalizationAttrsBuilder::new(_fbb);
-        builder.add_epsilon(args.epsilon);
-        builder.add_axis(args.axis);
+        args: &'args ConstantOfShapeAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ConstantOfShapeAttrs<'bldr>> {
+        let mut builder = ConstantOfShapeAttrsBuilder::new(_fbb);
+        if let Some(x) = args.value {
+            builder.add_value(x);
+        }
+        builder.add_value_type(args.value_type);
         builder.finish()
     }
 
     #[inline]
-    pub fn axis(&self) -> i32 {
+    pub fn value_type(&self) -> Scalar {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<i32>(LayerNormalizationAttrs::VT_AXIS, Some(0))
+                .get::<Scalar>(ConstantOfShapeAttrs::VT_VALUE_TYPE, Some(Scalar::NONE))
                 .unwrap()
         }
     }
     #[inline]
-    pub fn epsilon(&self) -> f32 {
+    pub fn value(&self) -> flatbuffers::Table<'a> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(LayerNormalizationAttrs::VT_EPSILON, Some(0.0))
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(
+                    ConstantOfShapeAttrs::VT_VALUE,
+                    None,
+                )
                 .unwrap()
         }
     }
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn value_as_int_scalar(&self) -> Option<IntScalar<'a>> {
+        if self.value_type() == Scalar::IntScalar {
+            let u = self.value();
+            // Safety:
+            // Created from a valid Table for this object
+            // Which contains a valid union in this slot
+            Some(unsafe { IntScalar::init_from_table(u) })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn value_as_float_scalar(&self) -> Option<FloatScalar<'a>> {
+        if self.value_type() == Scalar::FloatScalar {
+            let u = self.value();
+            // Safety:
+            // Created from a valid Table for this object
+            // Which contains a valid union in this slot
+            Some(unsafe { FloatScalar::init_from_table(u) })
+        } else {
+            None
+        }
+    }
 }
 
-impl flatbuffers::Verifiable for LayerNormalizationAttrs<'_> {
+impl flatbuffers::Verifiable for ConstantOfShapeAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5054,75 +6004,123 @@ impl flatbuffers::Verifiable for LayerNormalizationAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
-            .visit_field::<f32>("epsilon", Self::VT_EPSILON, false)?
+            .visit_union::<Scalar, _>(
+                "value_type",
+                Self::VT_VALUE_TYPE,
+                "value",
+                Self::VT_VALUE,
+                true,
+                |key, v, pos| match key {
+                    Scalar::IntScalar => v
+                        .verify_union_variant::<flatbuffers::ForwardsUOffset<IntScalar>>(
+                            "Scalar::IntScalar",
+                            pos,
+                        ),
+                    Scalar::FloatScalar => v
+                        .verify_union_variant::<flatbuffers::ForwardsUOffset<FloatScalar>>(
+                            "Scalar::FloatScalar",
+                            pos,
+                        ),
+                    _ => Ok(()),
+                },
+            )?
             .finish();
         Ok(())
     }
 }
-pub struct LayerNormalizationAttrsArgs {
-    pub axis: i32,
-    pub epsilon: f32,
+pub struct ConstantOfShapeAttrsArgs {
+    pub value_type: Scalar,
+    pub value: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
 }
-impl<'a> Default for LayerNormalizationAttrsArgs {
+impl<'a> Default for ConstantOfShapeAttrsArgs {
     #[inline]
     fn default() -> Self {
-        LayerNormalizationAttrsArgs {
-            axis: 0,
-            epsilon: 0.0,
+        ConstantOfShapeAttrsArgs {
+            value_type: Scalar::NONE,
+            value: None, // required field
         }
     }
 }
 
-pub struct LayerNormalizationAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct ConstantOfShapeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LayerNormalizationAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ConstantOfShapeAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_
-            .push_slot::<i32>(LayerNormalizationAttrs::VT_AXIS, axis, 0);
+    pub fn add_value_type(&mut self, value_type: Scalar) {
+        self.fbb_.push_slot::<Scalar>(
+            ConstantOfShapeAttrs::VT_VALUE_TYPE,
+            value_type,
+            Scalar::NONE,
+        );
     }
     #[inline]
-    pub fn add_epsilon(&mut self, epsilon: f32) {
+    pub fn add_value(&mut self, value: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
         self.fbb_
-            .push_slot::<f32>(LayerNormalizationAttrs::VT_EPSILON, epsilon, 0.0);
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConstantOfShapeAttrs::VT_VALUE, value);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> LayerNormalizationAttrsBuilder<'a, 'b, A> {
+    ) -> ConstantOfShapeAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        LayerNormalizationAttrsBuilder {
+        ConstantOfShapeAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<LayerNormalizationAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<ConstantOfShapeAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
+        self.fbb_
+            .required(o, ConstantOfShapeAttrs::VT_VALUE, "value");
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for LayerNormalizationAttrs<'_> {
+impl core::fmt::Debug for ConstantOfShapeAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("LayerNormalizationAttrs");
-        ds.field("axis", &self.axis());
-        ds.field("epsilon", &self.epsilon());
+        let mut ds = f.debug_struct("ConstantOfShapeAttrs");
+        ds.field("value_type", &self.value_type());
+        match self.value_type() {
+            Scalar::IntScalar => {
+                if let Some(x) = self.value_as_int_scalar() {
+                    ds.field("value", &x)
+                } else {
+                    ds.field(
+                        "value",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            Scalar::FloatScalar => {
+                if let Some(x) = self.value_as_float_scalar() {
+                    ds.field("value", &x)
+                } else {
+                    ds.field(
+                        "value",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            _ => {
+                let x: Option<()> = None;
+                ds.field("value", &x)
+            }
+        };
         ds.finish()
     }
 }
-pub enum LoopAttrsOffset {}
+pub enum ConvAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct LoopAttrs<'a> {
+pub struct ConvAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for LoopAttrs<'a> {
-    type Inner = LoopAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for ConvAttrs<'a> {
+    type Inner = ConvAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -5131,38 +6129,97 @@ impl<'a> flatbuffers::Follow<'a> for LoopAttrs<'a> {
     }
 }
 
-impl<'a> LoopAttrs<'a> {
-    pub const VT_BODY: flatbuffers::VOffsetT = 4;
+impl<'a> ConvAttrs<'a> {
+    pub const VT_AUTO_PAD: flatbuffers::VOffsetT = 4;
+    pub const VT_PADS: flatbuffers::VOffsetT = 6;
+    pub const VT_GROUPS: flatbuffers::VOffsetT = 8;
+    pub const VT_STRIDES: flatbuffers::VOffsetT = 10;
+    pub const VT_DILATIONS: flatbuffers::VOffsetT = 12;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        LoopAttrs { _tab: table }
+        ConvAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args LoopAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<LoopAttrs<'bldr>> {
-        let mut builder = LoopAttrsBuilder::new(_fbb);
-        if let Some(x) = args.body {
-            builder.add_body(x);
+        args: &'args ConvAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<ConvAttrs<'bldr>> {
+        let mut builder = ConvAttrsBuilder::new(_fbb);
+        if let Some(x) = args.dilations {
+            builder.add_dilations(x);
+        }
+        if let Some(x) = args.strides {
+            builder.add_strides(x);
+        }
+        builder.add_groups(args.groups);
+        if let Some(x) = args.pads {
+            builder.add_pads(x);
         }
+        builder.add_auto_pad(args.auto_pad);
         builder.finish()
     }
 
     #[inline]
-    pub fn body(&self) -> Option<Graph<'a>> {
+    pub fn auto_pad(&self) -> AutoPad {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<Graph>>(LoopAttrs::VT_BODY, None)
+                .get::<AutoPad>(ConvAttrs::VT_AUTO_PAD, Some(AutoPad::Same))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn pads(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    ConvAttrs::VT_PADS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn groups(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<u32>(ConvAttrs::VT_GROUPS, Some(0)).unwrap() }
+    }
+    #[inline]
+    pub fn strides(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    ConvAttrs::VT_STRIDES,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn dilations(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    ConvAttrs::VT_DILATIONS,
+                    None,
+                )
         }
     }
 }
 
-impl flatbuffers::Verifiable for LoopAttrs<'_> {
+impl flatbuffers::Verifiable for ConvAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5170,62 +6227,114 @@ impl flatbuffers::Verifiable for LoopAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<Graph>>("body", Self::VT_BODY, false)?
+            .visit_field::<AutoPad>("auto_pad", Self::VT_AUTO_PAD, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "pads",
+                Self::VT_PADS,
+                false,
+            )?
+            .visit_field::<u32>("groups", Self::VT_GROUPS, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "strides",
+                Self::VT_STRIDES,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "dilations",
+                Self::VT_DILATIONS,
+                false,
+            )?
             .finish();
         Ok(())
     }
 }
-pub struct LoopAttrsArgs<'a> {
-    pub body: Option<flatbuffers::WIPOffset<Graph<'a>>>,
+pub struct ConvAttrsArgs<'a> {
+    pub auto_pad: AutoPad,
+    pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub groups: u32,
+    pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub dilations: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
 }
-impl<'a> Default for LoopAttrsArgs<'a> {
+impl<'a> Default for ConvAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        LoopAttrsArgs { body: None }
+        ConvAttrsArgs {
+            auto_pad: AutoPad::Same,
+            pads: None,
+            groups: 0,
+            strides: None,
+            dilations: None,
+        }
     }
 }
 
-pub struct LoopAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct ConvAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LoopAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ConvAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_body(&mut self, body: flatbuffers::WIPOffset<Graph<'b>>) {
+    pub fn add_auto_pad(&mut self, auto_pad: AutoPad) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<Graph>>(LoopAttrs::VT_BODY, body);
+            .push_slot::<AutoPad>(ConvAttrs::VT_AUTO_PAD, auto_pad, AutoPad::Same);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> LoopAttrsBuilder<'a, 'b, A> {
+    pub fn add_pads(&mut self, pads: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvAttrs::VT_PADS, pads);
+    }
+    #[inline]
+    pub fn add_groups(&mut self, groups: u32) {
+        self.fbb_.push_slot::<u32>(ConvAttrs::VT_GROUPS, groups, 0);
+    }
+    #[inline]
+    pub fn add_strides(&mut self, strides: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvAttrs::VT_STRIDES, strides);
+    }
+    #[inline]
+    pub fn add_dilations(
+        &mut self,
+        dilations: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvAttrs::VT_DILATIONS, dilations);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ConvAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        LoopAttrsBuilder {
+        ConvAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<LoopAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<ConvAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for LoopAttrs<'_> {
+impl core::fmt::Debug for ConvAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("LoopAttrs");
-        ds.field("body", &self.body());
+        let mut ds = f.debug_struct("ConvAttrs");
+        ds.field("auto_pad", &self.auto_pad());
+        ds.field("pads", &self.pads());
+        ds.field("groups", &self.groups());
+        ds.field("strides", &self.strides());
+        ds.field("dilations", &self.dilations());
         ds.finish()
     }
 }
-pub enum GatherAttrsOffset {}
+pub enum ConvTransposeAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct GatherAttrs<'a> {
+pub struct ConvTransposeAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for GatherAttrs<'a> {
-    type Inner = GatherAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for ConvTransposeAttrs<'a> {
+    type Inner = ConvTransposeAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -5234,33 +6343,101 @@ impl<'a> flatbuffers::Follow<'a> for GatherAttrs<'a> {
     }
 }
 
-impl<'a> GatherAttrs<'a> {
-    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+impl<'a> ConvTransposeAttrs<'a> {
+    pub const VT_STRIDES: flatbuffers::VOffsetT = 4;
+    pub const VT_AUTO_PAD: flatbuffers::VOffsetT = 6;
+    pub const VT_PADS: flatbuffers::VOffsetT = 8;
+    pub const VT_GROUPS: flatbuffers::VOffsetT = 10;
+    pub const VT_OUTPUT_PADDING: flatbuffers::VOffsetT = 12;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        GatherAttrs { _tab: table }
+        ConvTransposeAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args GatherAttrsArgs,
-    ) -> flatbuffers::WIPOffset<GatherAttrs<'bldr>> {
-        let mut builder = GatherAttrsBuilder::new(_fbb);
-        builder.add_axis(args.axis);
+        args: &'args ConvTransposeAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<ConvTransposeAttrs<'bldr>> {
+        let mut builder = ConvTransposeAttrsBuilder::new(_fbb);
+        if let Some(x) = args.output_padding {
+            builder.add_output_padding(x);
+        }
+        builder.add_groups(args.groups);
+        if let Some(x) = args.pads {
+            builder.add_pads(x);
+        }
+        if let Some(x) = args.strides {
+            builder.add_strides(x);
+        }
+        builder.add_auto_pad(args.auto_pad);
         builder.finish()
     }
 
     #[inline]
-    pub fn axis(&self) -> i32 {
+    pub fn strides(&self) -> Option<flatbuffers::Vector<'a, u32>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<i32>(GatherAttrs::VT_AXIS, Some(0)).unwrap() }
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    ConvTransposeAttrs::VT_STRIDES,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn auto_pad(&self) -> AutoPad {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<AutoPad>(ConvTransposeAttrs::VT_AUTO_PAD, Some(AutoPad::NotSet))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn pads(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    ConvTransposeAttrs::VT_PADS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn groups(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(ConvTransposeAttrs::VT_GROUPS, Some(1))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn output_padding(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    ConvTransposeAttrs::VT_OUTPUT_PADDING,
+                    None,
+                )
+        }
     }
 }
 
-impl flatbuffers::Verifiable for GatherAttrs<'_> {
+impl flatbuffers::Verifiable for ConvTransposeAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5268,63 +6445,119 @@ impl flatbuffers::Verifiable for GatherAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "strides",
+                Self::VT_STRIDES,
+                false,
+            )?
+            .visit_field::<AutoPad>("auto_pad", Self::VT_AUTO_PAD, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "pads",
+                Self::VT_PADS,
+                false,
+            )?
+            .visit_field::<u32>("groups", Self::VT_GROUPS, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "output_padding",
+                Self::VT_OUTPUT_PADDING,
+                false,
+            )?
             .finish();
         Ok(())
     }
 }
-pub struct GatherAttrsArgs {
-    pub axis: i32,
+pub struct ConvTransposeAttrsArgs<'a> {
+    pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub auto_pad: AutoPad,
+    pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub groups: u32,
+    pub output_padding: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
 }
-impl<'a> Default for GatherAttrsArgs {
+impl<'a> Default for ConvTransposeAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        GatherAttrsArgs { axis: 0 }
+        ConvTransposeAttrsArgs {
+            strides: None,
+            auto_pad: AutoPad::NotSet,
+            pads: None,
+            groups: 1,
+            output_padding: None,
+        }
     }
 }
 
-pub struct GatherAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct ConvTransposeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GatherAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ConvTransposeAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_.push_slot::<i32>(GatherAttrs::VT_AXIS, axis, 0);
+    pub fn add_strides(&mut self, strides: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvTransposeAttrs::VT_STRIDES, strides);
+    }
+    #[inline]
+    pub fn add_auto_pad(&mut self, auto_pad: AutoPad) {
+        self.fbb_
+            .push_slot::<AutoPad>(ConvTransposeAttrs::VT_AUTO_PAD, auto_pad, AutoPad::NotSet);
+    }
+    #[inline]
+    pub fn add_pads(&mut self, pads: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ConvTransposeAttrs::VT_PADS, pads);
+    }
+    #[inline]
+    pub fn add_groups(&mut self, groups: u32) {
+        self.fbb_
+            .push_slot::<u32>(ConvTransposeAttrs::VT_GROUPS, groups, 1);
+    }
+    #[inline]
+    pub fn add_output_padding(
+        &mut self,
+        output_padding: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            ConvTransposeAttrs::VT_OUTPUT_PADDING,
+            output_padding,
+        );
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> GatherAttrsBuilder<'a, 'b, A> {
+    ) -> ConvTransposeAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        GatherAttrsBuilder {
+        ConvTransposeAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<GatherAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<ConvTransposeAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for GatherAttrs<'_> {
+impl core::fmt::Debug for ConvTransposeAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("GatherAttrs");
-        ds.field("axis", &self.axis());
+        let mut ds = f.debug_struct("ConvTransposeAttrs");
+        ds.field("strides", &self.strides());
+        ds.field("auto_pad", &self.auto_pad());
+        ds.field("pads", &self.pads());
+        ds.field("groups", &self.groups());
+        ds.field("output_padding", &self.output_padding());
         ds.finish()
     }
 }
-pub enum GatherNDAttrsOffset {}
+pub enum DequantizeLinearAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct GatherNDAttrs<'a> {
+pub struct DequantizeLinearAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for GatherNDAttrs<'a> {
-    type Inner = GatherNDAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for DequantizeLinearAttrs<'a> {
+    type Inner = DequantizeLinearAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -5333,37 +6566,37 @@ impl<'a> flatbuffers::Follow<'a> for GatherNDAttrs<'a> {
     }
 }
 
-impl<'a> GatherNDAttrs<'a> {
-    pub const VT_BATCH_DIMS: flatbuffers::VOffsetT = 4;
+impl<'a> DequantizeLinearAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        GatherNDAttrs { _tab: table }
+        DequantizeLinearAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args GatherNDAttrsArgs,
-    ) -> flatbuffers::WIPOffset<GatherNDAttrs<'bldr>> {
-        let mut builder = GatherNDAttrsBuilder::new(_fbb);
-        builder.add_batch_dims(args.batch_dims);
+        args: &'args DequantizeLinearAttrsArgs,
+    ) -> flatbuffers::WIPOffset<DequantizeLinearAttrs<'bldr>> {
+        let mut builder = DequantizeLinearAttrsBuilder::new(_fbb);
+        builder.add_axis(args.axis);
         builder.finish()
     }
 
     #[inline]
-    pub fn batch_dims(&self) -> i32 {
+    pub fn axis(&self) -> i32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<i32>(GatherNDAttrs::VT_BATCH_DIMS, Some(0))
+                .get::<i32>(DequantizeLinearAttrs::VT_AXIS, Some(0))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for GatherNDAttrs<'_> {
+impl flatbuffers::Verifiable for DequantizeLinearAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5371,64 +6604,64 @@ impl flatbuffers::Verifiable for GatherNDAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("batch_dims", Self::VT_BATCH_DIMS, false)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
             .finish();
         Ok(())
     }
 }
-pub struct GatherNDAttrsArgs {
-    pub batch_dims: i32,
+pub struct DequantizeLinearAttrsArgs {
+    pub axis: i32,
 }
-impl<'a> Default for GatherNDAttrsArgs {
+impl<'a> Default for DequantizeLinearAttrsArgs {
     #[inline]
     fn default() -> Self {
-        GatherNDAttrsArgs { batch_dims: 0 }
+        DequantizeLinearAttrsArgs { axis: 0 }
     }
 }
 
-pub struct GatherNDAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct DequantizeLinearAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GatherNDAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> DequantizeLinearAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_batch_dims(&mut self, batch_dims: i32) {
+    pub fn add_axis(&mut self, axis: i32) {
         self.fbb_
-            .push_slot::<i32>(GatherNDAttrs::VT_BATCH_DIMS, batch_dims, 0);
+            .push_slot::<i32>(DequantizeLinearAttrs::VT_AXIS, axis, 0);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> GatherNDAttrsBuilder<'a, 'b, A> {
+    ) -> DequantizeLinearAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        GatherNDAttrsBuilder {
+        DequantizeLinearAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<GatherNDAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<DequantizeLinearAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for GatherNDAttrs<'_> {
+impl core::fmt::Debug for DequantizeLinearAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("GatherNDAttrs");
-        ds.field("batch_dims", &self.batch_dims());
+        let mut ds = f.debug_struct("DequantizeLinearAttrs");
+        ds.field("axis", &self.axis());
         ds.finish()
     }
 }
-pub enum GeluAttrsOffset {}
+pub enum EinsumAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct GeluAttrs<'a> {
+pub struct EinsumAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for GeluAttrs<'a> {
-    type Inner = GeluAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for EinsumAttrs<'a> {
+    type Inner = EinsumAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -5437,37 +6670,38 @@ impl<'a> flatbuffers::Follow<'a> for GeluAttrs<'a> {
     }
 }
 
-impl<'a> GeluAttrs<'a> {
-    pub const VT_APPROXIMATE: flatbuffers::VOffsetT = 4;
+impl<'a> EinsumAttrs<'a> {
+    pub const VT_EQUATION: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        GeluAttrs { _tab: table }
+        EinsumAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args GeluAttrsArgs,
-    ) -> flatbuffers::WIPOffset<GeluAttrs<'bldr>> {
-        let mut builder = GeluAttrsBuilder::new(_fbb);
-        builder.add_approximate(args.approximate);
+        args: &'args EinsumAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<EinsumAttrs<'bldr>> {
+        let mut builder = EinsumAttrsBuilder::new(_fbb);
+        if let Some(x) = args.equation {
+            builder.add_equation(x);
+        }
         builder.finish()
     }
 
     #[inline]
-    pub fn approximate(&self) -> GeluApproximation {
+    pub fn equation(&self) -> Option<&'a str> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<GeluApproximation>(GeluAttrs::VT_APPROXIMATE, Some(GeluApproximation::None))
-                .unwrap()
+                .get::<flatbuffers::ForwardsUOffset<&str>>(EinsumAttrs::VT_EQUATION, None)
         }
     }
 }
 
-impl flatbuffers::Verifiable for GeluAttrs<'_> {
+impl flatbuffers::Verifiable for EinsumAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5475,67 +6709,68 @@ impl flatbuffers::Verifiable for GeluAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<GeluApproximation>("approximate", Self::VT_APPROXIMATE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                "equation",
+                Self::VT_EQUATION,
+                false,
+            )?
             .finish();
         Ok(())
     }
 }
-pub struct GeluAttrsArgs {
-    pub approximate: GeluApproximation,
+pub struct EinsumAttrsArgs<'a> {
+    pub equation: Option<flatbuffers::WIPOffset<&'a str>>,
 }
-impl<'a> Default for GeluAttrsArgs {
+impl<'a> Default for EinsumAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        GeluAttrsArgs {
-            approximate: GeluApproximation::None,
-        }
+        EinsumAttrsArgs { equation: None }
     }
 }
 
-pub struct GeluAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct EinsumAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GeluAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> EinsumAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_approximate(&mut self, approximate: GeluApproximation) {
-        self.fbb_.push_slot::<GeluApproximation>(
-            GeluAttrs::VT_APPROXIMATE,
-            approximate,
-            GeluApproximation::None,
-        );
+    pub fn add_equation(&mut self, equation: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(EinsumAttrs::VT_EQUATION, equation);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GeluAttrsBuilder<'a, 'b, A> {
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> EinsumAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        GeluAttrsBuilder {
+        EinsumAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<GeluAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<EinsumAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for GeluAttrs<'_> {
+impl core::fmt::Debug for EinsumAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("GeluAttrs");
-        ds.field("approximate", &self.approximate());
+        let mut ds = f.debug_struct("EinsumAttrs");
+        ds.field("equation", &self.equation());
         ds.finish()
     }
 }
-pub enum GemmAttrsOffset {}
+pub enum EluAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct GemmAttrs<'a> {
+pub struct EluAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for GemmAttrs<'a> {
-    type Inner = GemmAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for EluAttrs<'a> {
+    type Inner = EluAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -5544,26 +6779,20 @@ impl<'a> flatbuffers::Follow<'a> for GemmAttrs<'a> {
     }
 }
 
-impl<'a> GemmAttrs<'a> {
+impl<'a> EluAttrs<'a> {
     pub const VT_ALPHA: flatbuffers::VOffsetT = 4;
-    pub const VT_BETA: flatbuffers::VOffsetT = 6;
-    pub const VT_TRANSPOSE_A: flatbuffers::VOffsetT = 8;
-    pub const VT_TRANSPOSE_B: flatbuffers::VOffsetT = 10;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        GemmAttrs { _tab: table }
+        EluAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args GemmAttrsArgs,
-    ) -> flatbuffers::WIPOffset<GemmAttrs<'bldr>> {
-        let mut builder = GemmAttrsBuilder::new(_fbb);
-        builder.add_beta(args.beta);
+        args: &'args EluAttrsArgs,
+    ) -> flatbuffers::WIPOffset<EluAttrs<'bldr>> {
+        let mut builder = EluAttrsBuilder::new(_fbb);
         builder.add_alpha(args.alpha);
-        builder.add_transpose_b(args.transpose_b);
-        builder.add_transpose_a(args.transpose_a);
         builder.finish()
     }
 
@@ -5572,44 +6801,11 @@ impl<'a> GemmAttrs<'a> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<f32>(GemmAttrs::VT_ALPHA, Some(0.0))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn beta(&self) -> f32 {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe { self._tab.get::<f32>(GemmAttrs::VT_BETA, Some(0.0)).unwrap() }
-    }
-    #[inline]
-    pub fn transpose_a(&self) -> bool {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<bool>(GemmAttrs::VT_TRANSPOSE_A, Some(false))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn transpose_b(&self) -> bool {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<bool>(GemmAttrs::VT_TRANSPOSE_B, Some(false))
-                .unwrap()
-        }
+        unsafe { self._tab.get::<f32>(EluAttrs::VT_ALPHA, Some(0.0)).unwrap() }
     }
 }
 
-impl flatbuffers::Verifiable for GemmAttrs<'_> {
+impl flatbuffers::Verifiable for EluAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5618,88 +6814,60 @@ impl flatbuffers::Verifiable for GemmAttrs<'_> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
             .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
-            .visit_field::<f32>("beta", Self::VT_BETA, false)?
-            .visit_field::<bool>("transpose_a", Self::VT_TRANSPOSE_A, false)?
-            .visit_field::<bool>("transpose_b", Self::VT_TRANSPOSE_B, false)?
             .finish();
         Ok(())
     }
 }
-pub struct GemmAttrsArgs {
+pub struct EluAttrsArgs {
     pub alpha: f32,
-    pub beta: f32,
-    pub transpose_a: bool,
-    pub transpose_b: bool,
 }
-impl<'a> Default for GemmAttrsArgs {
+impl<'a> Default for EluAttrsArgs {
     #[inline]
     fn default() -> Self {
-        GemmAttrsArgs {
-            alpha: 0.0,
-            beta: 0.0,
-            transpose_a: false,
-            transpose_b: false,
-        }
+        EluAttrsArgs { alpha: 0.0 }
     }
 }
 
-pub struct GemmAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct EluAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GemmAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> EluAttrsBuilder<'a, 'b, A> {
     #[inline]
     pub fn add_alpha(&mut self, alpha: f32) {
-        self.fbb_.push_slot::<f32>(GemmAttrs::VT_ALPHA, alpha, 0.0);
-    }
-    #[inline]
-    pub fn add_beta(&mut self, beta: f32) {
-        self.fbb_.push_slot::<f32>(GemmAttrs::VT_BETA, beta, 0.0);
-    }
-    #[inline]
-    pub fn add_transpose_a(&mut self, transpose_a: bool) {
-        self.fbb_
-            .push_slot::<bool>(GemmAttrs::VT_TRANSPOSE_A, transpose_a, false);
-    }
-    #[inline]
-    pub fn add_transpose_b(&mut self, transpose_b: bool) {
-        self.fbb_
-            .push_slot::<bool>(GemmAttrs::VT_TRANSPOSE_B, transpose_b, false);
+        self.fbb_.push_slot::<f32>(EluAttrs::VT_ALPHA, alpha, 0.0);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GemmAttrsBuilder<'a, 'b, A> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> EluAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        GemmAttrsBuilder {
+        EluAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<GemmAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<EluAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for GemmAttrs<'_> {
+impl core::fmt::Debug for EluAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("GemmAttrs");
+        let mut ds = f.debug_struct("EluAttrs");
         ds.field("alpha", &self.alpha());
-        ds.field("beta", &self.beta());
-        ds.field("transpose_a", &self.transpose_a());
-        ds.field("transpose_b", &self.transpose_b());
         ds.finish()
     }
 }
-pub enum GridSampleAttrsOffset {}
+pub enum FlattenAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct GridSampleAttrs<'a> {
+pub struct FlattenAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for GridSampleAttrs<'a> {
-    type Inner = GridSampleAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for FlattenAttrs<'a> {
+    type Inner = FlattenAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -5708,37 +6876,37 @@ impl<'a> flatbuffers::Follow<'a> for GridSampleAttrs<'a> {
     }
 }
 
-impl<'a> GridSampleAttrs<'a> {
-    pub const VT_ALIGN_CORNERS: flatbuffers::VOffsetT = 4;
+impl<'a> FlattenAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        GridSampleAttrs { _tab: table }
+        FlattenAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args GridSampleAttrsArgs,
-    ) -> flatbuffers::WIPOffset<GridSampleAttrs<'bldr>> {
-        let mut builder = GridSampleAttrsBuilder::new(_fbb);
-        builder.add_align_corners(args.align_corners);
+        args: &'args FlattenAttrsArgs,
+    ) -> flatbuffers::WIPOffset<FlattenAttrs<'bldr>> {
+        let mut builder = FlattenAttrsBuilder::new(_fbb);
+        builder.add_axis(args.axis);
         builder.finish()
     }
 
     #[inline]
-    pub fn align_corners(&self) -> bool {
+    pub fn axis(&self) -> i32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<bool>(GridSampleAttrs::VT_ALIGN_CORNERS, Some(false))
+                .get::<i32>(FlattenAttrs::VT_AXIS, Some(0))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for GridSampleAttrs<'_> {
+impl flatbuffers::Verifiable for FlattenAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5746,66 +6914,63 @@ impl flatbuffers::Verifiable for GridSampleAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<bool>("align_corners", Self::VT_ALIGN_CORNERS, false)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
             .finish();
         Ok(())
     }
 }
-pub struct GridSampleAttrsArgs {
-    pub align_corners: bool,
+pub struct FlattenAttrsArgs {
+    pub axis: i32,
 }
-impl<'a> Default for GridSampleAttrsArgs {
+impl<'a> Default for FlattenAttrsArgs {
     #[inline]
     fn default() -> Self {
-        GridSampleAttrsArgs {
-            align_corners: false,
-        }
+        FlattenAttrsArgs { axis: 0 }
     }
 }
 
-pub struct GridSampleAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct FlattenAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GridSampleAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> FlattenAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_align_corners(&mut self, align_corners: bool) {
-        self.fbb_
-            .push_slot::<bool>(GridSampleAttrs::VT_ALIGN_CORNERS, align_corners, false);
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_.push_slot::<i32>(FlattenAttrs::VT_AXIS, axis, 0);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> GridSampleAttrsBuilder<'a, 'b, A> {
+    ) -> FlattenAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        GridSampleAttrsBuilder {
+        FlattenAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<GridSampleAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<FlattenAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for GridSampleAttrs<'_> {
+impl core::fmt::Debug for FlattenAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("GridSampleAttrs");
-        ds.field("align_corners", &self.align_corners());
+        let mut ds = f.debug_struct("FlattenAttrs");
+        ds.field("axis", &self.axis());
         ds.finish()
     }
 }
-pub enum GRUAttrsOffset {}
+pub enum LayerNormalizationAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct GRUAttrs<'a> {
+pub struct LayerNormalizationAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for GRUAttrs<'a> {
-    type Inner = GRUAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for LayerNormalizationAttrs<'a> {
+    type Inner = LayerNormalizationAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -5814,63 +6979,50 @@ impl<'a> flatbuffers::Follow<'a> for GRUAttrs<'a> {
     }
 }
 
-impl<'a> GRUAttrs<'a> {
-    pub const VT_DIRECTION: flatbuffers::VOffsetT = 4;
-    pub const VT_HIDDEN_SIZE: flatbuffers::VOffsetT = 6;
-    pub const VT_LINEAR_BEFORE_RESET: flatbuffers::VOffsetT = 8;
+impl<'a> LayerNormalizationAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_EPSILON: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        GRUAttrs { _tab: table }
+        LayerNormalizationAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args GRUAttrsArgs,
-    ) -> flatbuffers::WIPOffset<GRUAttrs<'bldr>> {
-        let mut builder = GRUAttrsBuilder::new(_fbb);
-        builder.add_hidden_size(args.hidden_size);
-        builder.add_linear_before_reset(args.linear_before_reset);
-        builder.add_direction(args.direction);
+        args: &'args LayerNormalizationAttrsArgs,
+    ) -> flatbuffers::WIPOffset<LayerNormalizationAttrs<'bldr>> {
+        let mut builder = LayerNormalizationAttrsBuilder::new(_fbb);
+        builder.add_epsilon(args.epsilon);
+        builder.add_axis(args.axis);
         builder.finish()
     }
 
     #[inline]
-    pub fn direction(&self) -> RNNDirection {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<RNNDirection>(GRUAttrs::VT_DIRECTION, Some(RNNDirection::Forward))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn hidden_size(&self) -> u32 {
+    pub fn axis(&self) -> i32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<u32>(GRUAttrs::VT_HIDDEN_SIZE, Some(0))
+                .get::<i32>(LayerNormalizationAttrs::VT_AXIS, Some(0))
                 .unwrap()
         }
     }
     #[inline]
-    pub fn linear_before_reset(&self) -> bool {
+    pub fn epsilon(&self) -> f32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<bool>(GRUAttrs::VT_LINEAR_BEFORE_RESET, Some(false))
+                .get::<f32>(LayerNormalizationAttrs::VT_EPSILON, Some(0.0))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for GRUAttrs<'_> {
+impl flatbuffers::Verifiable for LayerNormalizationAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -5878,85 +7030,75 @@ impl flatbuffers::Verifiable for GRUAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<RNNDirection>("direction", Self::VT_DIRECTION, false)?
-            .visit_field::<u32>("hidden_size", Self::VT_HIDDEN_SIZE, false)?
-            .visit_field::<bool>("linear_before_reset", Self::VT_LINEAR_BEFORE_RESET, false)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<f32>("epsilon", Self::VT_EPSILON, false)?
             .finish();
         Ok(())
     }
 }
-pub struct GRUAttrsArgs {
-    pub direction: RNNDirection,
-    pub hidden_size: u32,
-    pub linear_before_reset: bool,
+pub struct LayerNormalizationAttrsArgs {
+    pub axis: i32,
+    pub epsilon: f32,
 }
-impl<'a> Default for GRUAttrsArgs {
+impl<'a> Default for LayerNormalizationAttrsArgs {
     #[inline]
     fn default() -> Self {
-        GRUAttrsArgs {
-            direction: RNNDirection::Forward,
-            hidden_size: 0,
-            linear_before_reset: false,
+        LayerNormalizationAttrsArgs {
+            axis: 0,
+            epsilon: 0.0,
         }
     }
 }
 
-pub struct GRUAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct LayerNormalizationAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GRUAttrsBuilder<'a, 'b, A> {
-    #[inline]
-    pub fn add_direction(&mut self, direction: RNNDirection) {
-        self.fbb_.push_slot::<RNNDirection>(
-            GRUAttrs::VT_DIRECTION,
-            direction,
-            RNNDirection::Forward,
-        );
-    }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LayerNormalizationAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_hidden_size(&mut self, hidden_size: u32) {
+    pub fn add_axis(&mut self, axis: i32) {
         self.fbb_
-            .push_slot::<u32>(GRUAttrs::VT_HIDDEN_SIZE, hidden_size, 0);
+            .push_slot::<i32>(LayerNormalizationAttrs::VT_AXIS, axis, 0);
     }
     #[inline]
-    pub fn add_linear_before_reset(&mut self, linear_before_reset: bool) {
+    pub fn add_epsilon(&mut self, epsilon: f32) {
         self.fbb_
-            .push_slot::<bool>(GRUAttrs::VT_LINEAR_BEFORE_RESET, linear_before_reset, false);
+            .push_slot::<f32>(LayerNormalizationAttrs::VT_EPSILON, epsilon, 0.0);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GRUAttrsBuilder<'a, 'b, A> {
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> LayerNormalizationAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        GRUAttrsBuilder {
+        LayerNormalizationAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<GRUAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<LayerNormalizationAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for GRUAttrs<'_> {
+impl core::fmt::Debug for LayerNormalizationAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("GRUAttrs");
-        ds.field("direction", &self.direction());
-        ds.field("hidden_size", &self.hidden_size());
-        ds.field("linear_before_reset", &self.linear_before_reset());
+        let mut ds = f.debug_struct("LayerNormalizationAttrs");
+        ds.field("axis", &self.axis());
+        ds.field("epsilon", &self.epsilon());
         ds.finish()
     }
 }
-pub enum HardSigmoidAttrsOffset {}
+pub enum LoopAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct HardSigmoidAttrs<'a> {
+pub struct LoopAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for HardSigmoidAttrs<'a> {
-    type Inner = HardSigmoidAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for LoopAttrs<'a> {
+    type Inner = LoopAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -5965,50 +7107,38 @@ impl<'a> flatbuffers::Follow<'a> for HardSigmoidAttrs<'a> {
     }
 }
 
-impl<'a> HardSigmoidAttrs<'a> {
-    pub const VT_ALPHA: flatbuffers::VOffsetT = 4;
-    pub const VT_BETA: flatbuffers::VOffsetT = 6;
+impl<'a> LoopAttrs<'a> {
+    pub const VT_BODY: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        HardSigmoidAttrs { _tab: table }
+        LoopAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args HardSigmoidAttrsArgs,
-    ) -> flatbuffers::WIPOffset<HardSigmoidAttrs<'bldr>> {
-        let mut builder = HardSigmoidAttrsBuilder::new(_fbb);
-        builder.add_beta(args.beta);
-        builder.add_alpha(args.alpha);
+        args: &'args LoopAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<LoopAttrs<'bldr>> {
+        let mut builder = LoopAttrsBuilder::new(_fbb);
+        if let Some(x) = args.body {
+            builder.add_body(x);
+        }
         builder.finish()
     }
 
     #[inline]
-    pub fn alpha(&self) -> f32 {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<f32>(HardSigmoidAttrs::VT_ALPHA, Some(0.0))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn beta(&self) -> f32 {
+    pub fn body(&self) -> Option<Graph<'a>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(HardSigmoidAttrs::VT_BETA, Some(0.0))
-                .unwrap()
+                .get::<flatbuffers::ForwardsUOffset<Graph>>(LoopAttrs::VT_BODY, None)
         }
     }
 }
 
-impl flatbuffers::Verifiable for HardSigmoidAttrs<'_> {
+impl flatbuffers::Verifiable for LoopAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -6016,75 +7146,62 @@ impl flatbuffers::Verifiable for HardSigmoidAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
-            .visit_field::<f32>("beta", Self::VT_BETA, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<Graph>>("body", Self::VT_BODY, false)?
             .finish();
         Ok(())
     }
 }
-pub struct HardSigmoidAttrsArgs {
-    pub alpha: f32,
-    pub beta: f32,
+pub struct LoopAttrsArgs<'a> {
+    pub body: Option<flatbuffers::WIPOffset<Graph<'a>>>,
 }
-impl<'a> Default for HardSigmoidAttrsArgs {
+impl<'a> Default for LoopAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        HardSigmoidAttrsArgs {
-            alpha: 0.0,
-            beta: 0.0,
-        }
+        LoopAttrsArgs { body: None }
     }
 }
 
-pub struct HardSigmoidAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct LoopAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> HardSigmoidAttrsBuilder<'a, 'b, A> {
-    #[inline]
-    pub fn add_alpha(&mut self, alpha: f32) {
-        self.fbb_
-            .push_slot::<f32>(HardSigmoidAttrs::VT_ALPHA, alpha, 0.0);
-    }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LoopAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_beta(&mut self, beta: f32) {
+    pub fn add_body(&mut self, body: flatbuffers::WIPOffset<Graph<'b>>) {
         self.fbb_
-            .push_slot::<f32>(HardSigmoidAttrs::VT_BETA, beta, 0.0);
+            .push_slot_always::<flatbuffers::WIPOffset<Graph>>(LoopAttrs::VT_BODY, body);
     }
     #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> HardSigmoidAttrsBuilder<'a, 'b, A> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> LoopAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        HardSigmoidAttrsBuilder {
+        LoopAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<HardSigmoidAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<LoopAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for HardSigmoidAttrs<'_> {
+impl core::fmt::Debug for LoopAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("HardSigmoidAttrs");
-        ds.field("alpha", &self.alpha());
-        ds.field("beta", &self.beta());
+        let mut ds = f.debug_struct("LoopAttrs");
+        ds.field("body", &self.body());
         ds.finish()
     }
 }
-pub enum IfAttrsOffset {}
+pub enum FusedMatMulAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct IfAttrs<'a> {
+pub struct FusedMatMulAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for IfAttrs<'a> {
-    type Inner = IfAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for FusedMatMulAttrs<'a> {
+    type Inner = FusedMatMulAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -6093,52 +7210,35 @@ impl<'a> flatbuffers::Follow<'a> for IfAttrs<'a> {
     }
 }
 
-impl<'a> IfAttrs<'a> {
-    pub const VT_THEN_BRANCH: flatbuffers::VOffsetT = 4;
-    pub const VT_ELSE_BRANCH: flatbuffers::VOffsetT = 6;
+impl<'a> FusedMatMulAttrs<'a> {
+    pub const VT_ALPHA: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        IfAttrs { _tab: table }
+        FusedMatMulAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args IfAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<IfAttrs<'bldr>> {
-        let mut builder = IfAttrsBuilder::new(_fbb);
-        if let Some(x) = args.else_branch {
-            builder.add_else_branch(x);
-        }
-        if let Some(x) = args.then_branch {
-            builder.add_then_branch(x);
+        args: &'args FusedMatMulAttrsArgs,
+    ) -> flatbuffers::WIPOffset<FusedMatMulAttrs<'bldr>> {
+        let mut builder = FusedMatMulAttrsBuilder::new(_fbb);
+        if let Some(x) = args.alpha {
+            builder.add_alpha(x);
         }
         builder.finish()
     }
 
     #[inline]
-    pub fn then_branch(&self) -> Option<Graph<'a>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<Graph>>(IfAttrs::VT_THEN_BRANCH, None)
-        }
-    }
-    #[inline]
-    pub fn else_branch(&self) -> Option<Graph<'a>> {
+    pub fn alpha(&self) -> Option<f32> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<Graph>>(IfAttrs::VT_ELSE_BRANCH, None)
-        }
+        unsafe { self._tab.get::<f32>(FusedMatMulAttrs::VT_ALPHA, None) }
     }
 }
 
-impl flatbuffers::Verifiable for IfAttrs<'_> {
+impl flatbuffers::Verifiable for FusedMatMulAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -6146,85 +7246,64 @@ impl flatbuffers::Verifiable for IfAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<Graph>>(
-                "then_branch",
-                Self::VT_THEN_BRANCH,
-                false,
-            )?
-            .visit_field::<flatbuffers::ForwardsUOffset<Graph>>(
-                "else_branch",
-                Self::VT_ELSE_BRANCH,
-                false,
-            )?
+            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
             .finish();
         Ok(())
     }
 }
-pub struct IfAttrsArgs<'a> {
-    pub then_branch: Option<flatbuffers::WIPOffset<Graph<'a>>>,
-    pub else_branch: Option<flatbuffers::WIPOffset<Graph<'a>>>,
+pub struct FusedMatMulAttrsArgs {
+    pub alpha: Option<f32>,
 }
-impl<'a> Default for IfAttrsArgs<'a> {
+impl<'a> Default for FusedMatMulAttrsArgs {
     #[inline]
     fn default() -> Self {
-        IfAttrsArgs {
-            then_branch: None,
-            else_branch: None,
-        }
+        FusedMatMulAttrsArgs { alpha: None }
     }
 }
 
-pub struct IfAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct FusedMatMulAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> IfAttrsBuilder<'a, 'b, A> {
-    #[inline]
-    pub fn add_then_branch(&mut self, then_branch: flatbuffers::WIPOffset<Graph<'b>>) {
-        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Graph>>(
-            IfAttrs::VT_THEN_BRANCH,
-            then_branch,
-        );
-    }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> FusedMatMulAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_else_branch(&mut self, else_branch: flatbuffers::WIPOffset<Graph<'b>>) {
-        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Graph>>(
-            IfAttrs::VT_ELSE_BRANCH,
-            else_branch,
-        );
+    pub fn add_alpha(&mut self, alpha: f32) {
+        self.fbb_
+            .push_slot_always::<f32>(FusedMatMulAttrs::VT_ALPHA, alpha);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> IfAttrsBuilder<'a, 'b, A> {
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> FusedMatMulAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        IfAttrsBuilder {
+        FusedMatMulAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<IfAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<FusedMatMulAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for IfAttrs<'_> {
+impl core::fmt::Debug for FusedMatMulAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("IfAttrs");
-        ds.field("then_branch", &self.then_branch());
-        ds.field("else_branch", &self.else_branch());
+        let mut ds = f.debug_struct("FusedMatMulAttrs");
+        ds.field("alpha", &self.alpha());
         ds.finish()
     }
 }
-pub enum LeakyReluAttrsOffset {}
+pub enum GatherAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct LeakyReluAttrs<'a> {
+pub struct GatherAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for LeakyReluAttrs<'a> {
-    type Inner = LeakyReluAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for GatherAttrs<'a> {
+    type Inner = GatherAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -6233,37 +7312,33 @@ impl<'a> flatbuffers::Follow<'a> for LeakyReluAttrs<'a> {
     }
 }
 
-impl<'a> LeakyReluAttrs<'a> {
-    pub const VT_ALPHA: flatbuffers::VOffsetT = 4;
+impl<'a> GatherAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        LeakyReluAttrs { _tab: table }
+        GatherAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args LeakyReluAttrsArgs,
-    ) -> flatbuffers::WIPOffset<LeakyReluAttrs<'bldr>> {
-        let mut builder = LeakyReluAttrsBuilder::new(_fbb);
-        builder.add_alpha(args.alpha);
+        args: &'args GatherAttrsArgs,
+    ) -> flatbuffers::WIPOffset<GatherAttrs<'bldr>> {
+        let mut builder = GatherAttrsBuilder::new(_fbb);
+        builder.add_axis(args.axis);
         builder.finish()
     }
 
     #[inline]
-    pub fn alpha(&self) -> f32 {
+    pub fn axis(&self) -> i32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<f32>(LeakyReluAttrs::VT_ALPHA, Some(0.0))
-                .unwrap()
-        }
+        unsafe { self._tab.get::<i32>(GatherAttrs::VT_AXIS, Some(0)).unwrap() }
     }
 }
 
-impl flatbuffers::Verifiable for LeakyReluAttrs<'_> {
+impl flatbuffers::Verifiable for GatherAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -6271,64 +7346,63 @@ impl flatbuffers::Verifiable for LeakyReluAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
             .finish();
         Ok(())
     }
 }
-pub struct LeakyReluAttrsArgs {
-    pub alpha: f32,
+pub struct GatherAttrsArgs {
+    pub axis: i32,
 }
-impl<'a> Default for LeakyReluAttrsArgs {
+impl<'a> Default for GatherAttrsArgs {
     #[inline]
     fn default() -> Self {
-        LeakyReluAttrsArgs { alpha: 0.0 }
+        GatherAttrsArgs { axis: 0 }
     }
 }
 
-pub struct LeakyReluAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct GatherAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LeakyReluAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GatherAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_alpha(&mut self, alpha: f32) {
-        self.fbb_
-            .push_slot::<f32>(LeakyReluAttrs::VT_ALPHA, alpha, 0.0);
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_.push_slot::<i32>(GatherAttrs::VT_AXIS, axis, 0);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> LeakyReluAttrsBuilder<'a, 'b, A> {
+    ) -> GatherAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        LeakyReluAttrsBuilder {
+        GatherAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<LeakyReluAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<GatherAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for LeakyReluAttrs<'_> {
+impl core::fmt::Debug for GatherAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("LeakyReluAttrs");
-        ds.field("alpha", &self.alpha());
+        let mut ds = f.debug_struct("GatherAttrs");
+        ds.field("axis", &self.axis());
         ds.finish()
     }
 }
-pub enum LSTMAttrsOffset {}
+pub enum GatherNDAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct LSTMAttrs<'a> {
+pub struct GatherNDAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for LSTMAttrs<'a> {
-    type Inner = LSTMAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for GatherNDAttrs<'a> {
+    type Inner = GatherNDAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -6337,50 +7411,37 @@ impl<'a> flatbuffers::Follow<'a> for LSTMAttrs<'a> {
     }
 }
 
-impl<'a> LSTMAttrs<'a> {
-    pub const VT_DIRECTION: flatbuffers::VOffsetT = 4;
-    pub const VT_HIDDEN_SIZE: flatbuffers::VOffsetT = 6;
+impl<'a> GatherNDAttrs<'a> {
+    pub const VT_BATCH_DIMS: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        LSTMAttrs { _tab: table }
+        GatherNDAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args LSTMAttrsArgs,
-    ) -> flatbuffers::WIPOffset<LSTMAttrs<'bldr>> {
-        let mut builder = LSTMAttrsBuilder::new(_fbb);
-        builder.add_hidden_size(args.hidden_size);
-        builder.add_direction(args.direction);
+        args: &'args GatherNDAttrsArgs,
+    ) -> flatbuffers::WIPOffset<GatherNDAttrs<'bldr>> {
+        let mut builder = GatherNDAttrsBuilder::new(_fbb);
+        builder.add_batch_dims(args.batch_dims);
         builder.finish()
     }
 
     #[inline]
-    pub fn direction(&self) -> RNNDirection {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<RNNDirection>(LSTMAttrs::VT_DIRECTION, Some(RNNDirection::Forward))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn hidden_size(&self) -> u32 {
+    pub fn batch_dims(&self) -> i32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<u32>(LSTMAttrs::VT_HIDDEN_SIZE, Some(0))
+                .get::<i32>(GatherNDAttrs::VT_BATCH_DIMS, Some(0))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for LSTMAttrs<'_> {
+impl flatbuffers::Verifiable for GatherNDAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -6388,76 +7449,64 @@ impl flatbuffers::Verifiable for LSTMAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<RNNDirection>("direction", Self::VT_DIRECTION, false)?
-            .visit_field::<u32>("hidden_size", Self::VT_HIDDEN_SIZE, false)?
+            .visit_field::<i32>("batch_dims", Self::VT_BATCH_DIMS, false)?
             .finish();
         Ok(())
     }
 }
-pub struct LSTMAttrsArgs {
-    pub direction: RNNDirection,
-    pub hidden_size: u32,
+pub struct GatherNDAttrsArgs {
+    pub batch_dims: i32,
 }
-impl<'a> Default for LSTMAttrsArgs {
+impl<'a> Default for GatherNDAttrsArgs {
     #[inline]
     fn default() -> Self {
-        LSTMAttrsArgs {
-            direction: RNNDirection::Forward,
-            hidden_size: 0,
-        }
+        GatherNDAttrsArgs { batch_dims: 0 }
     }
 }
 
-pub struct LSTMAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct GatherNDAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LSTMAttrsBuilder<'a, 'b, A> {
-    #[inline]
-    pub fn add_direction(&mut self, direction: RNNDirection) {
-        self.fbb_.push_slot::<RNNDirection>(
-            LSTMAttrs::VT_DIRECTION,
-            direction,
-            RNNDirection::Forward,
-        );
-    }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GatherNDAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_hidden_size(&mut self, hidden_size: u32) {
+    pub fn add_batch_dims(&mut self, batch_dims: i32) {
         self.fbb_
-            .push_slot::<u32>(LSTMAttrs::VT_HIDDEN_SIZE, hidden_size, 0);
+            .push_slot::<i32>(GatherNDAttrs::VT_BATCH_DIMS, batch_dims, 0);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> LSTMAttrsBuilder<'a, 'b, A> {
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> GatherNDAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        LSTMAttrsBuilder {
+        GatherNDAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<LSTMAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<GatherNDAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for LSTMAttrs<'_> {
+impl core::fmt::Debug for GatherNDAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("LSTMAttrs");
-        ds.field("direction", &self.direction());
-        ds.field("hidden_size", &self.hidden_size());
+        let mut ds = f.debug_struct("GatherNDAttrs");
+        ds.field("batch_dims", &self.batch_dims());
         ds.finish()
     }
 }
-pub enum MaxPoolAttrsOffset {}
+pub enum GeluAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct MaxPoolAttrs<'a> {
+pub struct GeluAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for MaxPoolAttrs<'a> {
-    type Inner = MaxPoolAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for GeluAttrs<'a> {
+    type Inner = GeluAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -6466,102 +7515,37 @@ impl<'a> flatbuffers::Follow<'a> for MaxPoolAttrs<'a> {
     }
 }
 
-impl<'a> MaxPoolAttrs<'a> {
-    pub const VT_KERNEL_SIZE: flatbuffers::VOffsetT = 4;
-    pub const VT_AUTO_PAD: flatbuffers::VOffsetT = 6;
-    pub const VT_PADS: flatbuffers::VOffsetT = 8;
-    pub const VT_STRIDES: flatbuffers::VOffsetT = 10;
-    pub const VT_CEIL_MODE: flatbuffers::VOffsetT = 12;
+impl<'a> GeluAttrs<'a> {
+    pub const VT_APPROXIMATE: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        MaxPoolAttrs { _tab: table }
+        GeluAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args MaxPoolAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<MaxPoolAttrs<'bldr>> {
-        let mut builder = MaxPoolAttrsBuilder::new(_fbb);
-        if let Some(x) = args.strides {
-            builder.add_strides(x);
-        }
-        if let Some(x) = args.pads {
-            builder.add_pads(x);
-        }
-        if let Some(x) = args.kernel_size {
-            builder.add_kernel_size(x);
-        }
-        builder.add_ceil_mode(args.ceil_mode);
-        builder.add_auto_pad(args.auto_pad);
-        builder.finish()
-    }
-
-    #[inline]
-    pub fn kernel_size(&self) -> flatbuffers::Vector<'a, u32> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    MaxPoolAttrs::VT_KERNEL_SIZE,
-                    None,
-                )
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn auto_pad(&self) -> AutoPad {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<AutoPad>(MaxPoolAttrs::VT_AUTO_PAD, Some(AutoPad::Same))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn pads(&self) -> Option<flatbuffers::Vector<'a, u32>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    MaxPoolAttrs::VT_PADS,
-                    None,
-                )
-        }
-    }
-    #[inline]
-    pub fn strides(&self) -> Option<flatbuffers::Vector<'a, u32>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    MaxPoolAttrs::VT_STRIDES,
-                    None,
-                )
-        }
+        args: &'args GeluAttrsArgs,
+    ) -> flatbuffers::WIPOffset<GeluAttrs<'bldr>> {
+        let mut builder = GeluAttrsBuilder::new(_fbb);
+        builder.add_approximate(args.approximate);
+        builder.finish()
     }
+
     #[inline]
-    pub fn ceil_mode(&self) -> bool {
+    pub fn approximate(&self) -> GeluApproximation {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<bool>(MaxPoolAttrs::VT_CEIL_MODE, Some(false))
+                .get::<GeluApproximation>(GeluAttrs::VT_APPROXIMATE, Some(GeluApproximation::None))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for MaxPoolAttrs<'_> {
+impl flatbuffers::Verifiable for GeluAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -6569,121 +7553,67 @@ impl flatbuffers::Verifiable for MaxPoolAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "kernel_size",
-                Self::VT_KERNEL_SIZE,
-                true,
-            )?
-            .visit_field::<AutoPad>("auto_pad", Self::VT_AUTO_PAD, false)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "pads",
-                Self::VT_PADS,
-                false,
-            )?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "strides",
-                Self::VT_STRIDES,
-                false,
-            )?
-            .visit_field::<bool>("ceil_mode", Self::VT_CEIL_MODE, false)?
+            .visit_field::<GeluApproximation>("approximate", Self::VT_APPROXIMATE, false)?
             .finish();
         Ok(())
     }
 }
-pub struct MaxPoolAttrsArgs<'a> {
-    pub kernel_size: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
-    pub auto_pad: AutoPad,
-    pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
-    pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
-    pub ceil_mode: bool,
+pub struct GeluAttrsArgs {
+    pub approximate: GeluApproximation,
 }
-impl<'a> Default for MaxPoolAttrsArgs<'a> {
+impl<'a> Default for GeluAttrsArgs {
     #[inline]
     fn default() -> Self {
-        MaxPoolAttrsArgs {
-            kernel_size: None, // required field
-            auto_pad: AutoPad::Same,
-            pads: None,
-            strides: None,
-            ceil_mode: false,
+        GeluAttrsArgs {
+            approximate: GeluApproximation::None,
         }
     }
 }
 
-pub struct MaxPoolAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct GeluAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MaxPoolAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GeluAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_kernel_size(
-        &mut self,
-        kernel_size: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
-    ) {
-        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
-            MaxPoolAttrs::VT_KERNEL_SIZE,
-            kernel_size,
+    pub fn add_approximate(&mut self, approximate: GeluApproximation) {
+        self.fbb_.push_slot::<GeluApproximation>(
+            GeluAttrs::VT_APPROXIMATE,
+            approximate,
+            GeluApproximation::None,
         );
     }
     #[inline]
-    pub fn add_auto_pad(&mut self, auto_pad: AutoPad) {
-        self.fbb_
-            .push_slot::<AutoPad>(MaxPoolAttrs::VT_AUTO_PAD, auto_pad, AutoPad::Same);
-    }
-    #[inline]
-    pub fn add_pads(&mut self, pads: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(MaxPoolAttrs::VT_PADS, pads);
-    }
-    #[inline]
-    pub fn add_strides(&mut self, strides: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(MaxPoolAttrs::VT_STRIDES, strides);
-    }
-    #[inline]
-    pub fn add_ceil_mode(&mut self, ceil_mode: bool) {
-        self.fbb_
-            .push_slot::<bool>(MaxPoolAttrs::VT_CEIL_MODE, ceil_mode, false);
-    }
-    #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> MaxPoolAttrsBuilder<'a, 'b, A> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GeluAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        MaxPoolAttrsBuilder {
+        GeluAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<MaxPoolAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<GeluAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
-        self.fbb_
-            .required(o, MaxPoolAttrs::VT_KERNEL_SIZE, "kernel_size");
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for MaxPoolAttrs<'_> {
+impl core::fmt::Debug for GeluAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("MaxPoolAttrs");
-        ds.field("kernel_size", &self.kernel_size());
-        ds.field("auto_pad", &self.auto_pad());
-        ds.field("pads", &self.pads());
-        ds.field("strides", &self.strides());
-        ds.field("ceil_mode", &self.ceil_mode());
+        let mut ds = f.debug_struct("GeluAttrs");
+        ds.field("approximate", &self.approximate());
         ds.finish()
     }
 }
-pub enum ModAttrsOffset {}
+pub enum GemmAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ModAttrs<'a> {
+pub struct GemmAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ModAttrs<'a> {
-    type Inner = ModAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for GemmAttrs<'a> {
+    type Inner = GemmAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -6692,37 +7622,72 @@ impl<'a> flatbuffers::Follow<'a> for ModAttrs<'a> {
     }
 }
 
-impl<'a> ModAttrs<'a> {
-    pub const VT_FMOD: flatbuffers::VOffsetT = 4;
+impl<'a> GemmAttrs<'a> {
+    pub const VT_ALPHA: flatbuffers::VOffsetT = 4;
+    pub const VT_BETA: flatbuffers::VOffsetT = 6;
+    pub const VT_TRANSPOSE_A: flatbuffers::VOffsetT = 8;
+    pub const VT_TRANSPOSE_B: flatbuffers::VOffsetT = 10;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ModAttrs { _tab: table }
+        GemmAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args ModAttrsArgs,
-    ) -> flatbuffers::WIPOffset<ModAttrs<'bldr>> {
-        let mut builder = ModAttrsBuilder::new(_fbb);
-        builder.add_fmod(args.fmod);
+        args: &'args GemmAttrsArgs,
+    ) -> flatbuffers::WIPOffset<GemmAttrs<'bldr>> {
+        let mut builder = GemmAttrsBuilder::new(_fbb);
+        builder.add_beta(args.beta);
+        builder.add_alpha(args.alpha);
+        builder.add_transpose_b(args.transpose_b);
+        builder.add_transpose_a(args.transpose_a);
         builder.finish()
     }
 
     #[inline]
-    pub fn fmod(&self) -> bool {
+    pub fn alpha(&self) -> f32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<bool>(ModAttrs::VT_FMOD, Some(false))
+                .get::<f32>(GemmAttrs::VT_ALPHA, Some(0.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn beta(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<f32>(GemmAttrs::VT_BETA, Some(0.0)).unwrap() }
+    }
+    #[inline]
+    pub fn transpose_a(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(GemmAttrs::VT_TRANSPOSE_A, Some(false))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn transpose_b(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(GemmAttrs::VT_TRANSPOSE_B, Some(false))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for ModAttrs<'_> {
+impl flatbuffers::Verifiable for GemmAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -6730,61 +7695,89 @@ impl flatbuffers::Verifiable for ModAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<bool>("fmod", Self::VT_FMOD, false)?
+            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
+            .visit_field::<f32>("beta", Self::VT_BETA, false)?
+            .visit_field::<bool>("transpose_a", Self::VT_TRANSPOSE_A, false)?
+            .visit_field::<bool>("transpose_b", Self::VT_TRANSPOSE_B, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ModAttrsArgs {
-    pub fmod: bool,
+pub struct GemmAttrsArgs {
+    pub alpha: f32,
+    pub beta: f32,
+    pub transpose_a: bool,
+    pub transpose_b: bool,
 }
-impl<'a> Default for ModAttrsArgs {
+impl<'a> Default for GemmAttrsArgs {
     #[inline]
     fn default() -> Self {
-        ModAttrsArgs { fmod: false }
+        GemmAttrsArgs {
+            alpha: 0.0,
+            beta: 0.0,
+            transpose_a: false,
+            transpose_b: false,
+        }
     }
 }
 
-pub struct ModAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct GemmAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ModAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GemmAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_fmod(&mut self, fmod: bool) {
-        self.fbb_.push_slot::<bool>(ModAttrs::VT_FMOD, fmod, false);
+    pub fn add_alpha(&mut self, alpha: f32) {
+        self.fbb_.push_slot::<f32>(GemmAttrs::VT_ALPHA, alpha, 0.0);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ModAttrsBuilder<'a, 'b, A> {
-        let start = _fbb.start_table();
-        ModAttrsBuilder {
-            fbb_: _fbb,
-            start_: start,
-        }
+    pub fn add_beta(&mut self, beta: f32) {
+        self.fbb_.push_slot::<f32>(GemmAttrs::VT_BETA, beta, 0.0);
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ModAttrs<'a>> {
-        let o = self.fbb_.end_table(self.start_);
+    pub fn add_transpose_a(&mut self, transpose_a: bool) {
+        self.fbb_
+            .push_slot::<bool>(GemmAttrs::VT_TRANSPOSE_A, transpose_a, false);
+    }
+    #[inline]
+    pub fn add_transpose_b(&mut self, transpose_b: bool) {
+        self.fbb_
+            .push_slot::<bool>(GemmAttrs::VT_TRANSPOSE_B, transpose_b, false);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GemmAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        GemmAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<GemmAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ModAttrs<'_> {
+impl core::fmt::Debug for GemmAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ModAttrs");
-        ds.field("fmod", &self.fmod());
+        let mut ds = f.debug_struct("GemmAttrs");
+        ds.field("alpha", &self.alpha());
+        ds.field("beta", &self.beta());
+        ds.field("transpose_a", &self.transpose_a());
+        ds.field("transpose_b", &self.transpose_b());
         ds.finish()
     }
 }
-pub enum NonMaxSuppressionAttrsOffset {}
+pub enum GridSampleAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct NonMaxSuppressionAttrs<'a> {
+pub struct GridSampleAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for NonMaxSuppressionAttrs<'a> {
-    type Inner = NonMaxSuppressionAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for GridSampleAttrs<'a> {
+    type Inner = GridSampleAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -6793,40 +7786,37 @@ impl<'a> flatbuffers::Follow<'a> for NonMaxSuppressionAttrs<'a> {
     }
 }
 
-impl<'a> NonMaxSuppressionAttrs<'a> {
-    pub const VT_BOX_ORDER: flatbuffers::VOffsetT = 4;
+impl<'a> GridSampleAttrs<'a> {
+    pub const VT_ALIGN_CORNERS: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        NonMaxSuppressionAttrs { _tab: table }
+        GridSampleAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args NonMaxSuppressionAttrsArgs,
-    ) -> flatbuffers::WIPOffset<NonMaxSuppressionAttrs<'bldr>> {
-        let mut builder = NonMaxSuppressionAttrsBuilder::new(_fbb);
-        builder.add_box_order(args.box_order);
+        args: &'args GridSampleAttrsArgs,
+    ) -> flatbuffers::WIPOffset<GridSampleAttrs<'bldr>> {
+        let mut builder = GridSampleAttrsBuilder::new(_fbb);
+        builder.add_align_corners(args.align_corners);
         builder.finish()
     }
 
     #[inline]
-    pub fn box_order(&self) -> NMSBoxOrder {
+    pub fn align_corners(&self) -> bool {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<NMSBoxOrder>(
-                    NonMaxSuppressionAttrs::VT_BOX_ORDER,
-                    Some(NMSBoxOrder::TopLeftBottomRight),
-                )
+                .get::<bool>(GridSampleAttrs::VT_ALIGN_CORNERS, Some(false))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for NonMaxSuppressionAttrs<'_> {
+impl flatbuffers::Verifiable for GridSampleAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -6834,69 +7824,66 @@ impl flatbuffers::Verifiable for NonMaxSuppressionAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<NMSBoxOrder>("box_order", Self::VT_BOX_ORDER, false)?
+            .visit_field::<bool>("align_corners", Self::VT_ALIGN_CORNERS, false)?
             .finish();
         Ok(())
     }
 }
-pub struct NonMaxSuppressionAttrsArgs {
-    pub box_order: NMSBoxOrder,
+pub struct GridSampleAttrsArgs {
+    pub align_corners: bool,
 }
-impl<'a> Default for NonMaxSuppressionAttrsArgs {
+impl<'a> Default for GridSampleAttrsArgs {
     #[inline]
     fn default() -> Self {
-        NonMaxSuppressionAttrsArgs {
-            box_order: NMSBoxOrder::TopLeftBottomRight,
+        GridSampleAttrsArgs {
+            align_corners: false,
         }
     }
 }
 
-pub struct NonMaxSuppressionAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct GridSampleAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> NonMaxSuppressionAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GridSampleAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_box_order(&mut self, box_order: NMSBoxOrder) {
-        self.fbb_.push_slot::<NMSBoxOrder>(
-            NonMaxSuppressionAttrs::VT_BOX_ORDER,
-            box_order,
-            NMSBoxOrder::TopLeftBottomRight,
-        );
+    pub fn add_align_corners(&mut self, align_corners: bool) {
+        self.fbb_
+            .push_slot::<bool>(GridSampleAttrs::VT_ALIGN_CORNERS, align_corners, false);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> NonMaxSuppressionAttrsBuilder<'a, 'b, A> {
+    ) -> GridSampleAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        NonMaxSuppressionAttrsBuilder {
+        GridSampleAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<NonMaxSuppressionAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<GridSampleAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for NonMaxSuppressionAttrs<'_> {
+impl core::fmt::Debug for GridSampleAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("NonMaxSuppressionAttrs");
-        ds.field("box_order", &self.box_order());
+        let mut ds = f.debug_struct("GridSampleAttrs");
+        ds.field("align_corners", &self.align_corners());
         ds.finish()
     }
 }
-pub enum OneHotAttrsOffset {}
+pub enum GroupNormalizationAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct OneHotAttrs<'a> {
+pub struct GroupNormalizationAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for OneHotAttrs<'a> {
-    type Inner = OneHotAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for GroupNormalizationAttrs<'a> {
+    type Inner = GroupNormalizationAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -6905,33 +7892,52 @@ impl<'a> flatbuffers::Follow<'a> for OneHotAttrs<'a> {
     }
 }
 
-impl<'a> OneHotAttrs<'a> {
-    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+impl<'a> GroupNormalizationAttrs<'a> {
+    pub const VT_NUM_GROUPS: flatbuffers::VOffsetT = 4;
+    pub const VT_EPSILON: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        OneHotAttrs { _tab: table }
+        GroupNormalizationAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args OneHotAttrsArgs,
-    ) -> flatbuffers::WIPOffset<OneHotAttrs<'bldr>> {
-        let mut builder = OneHotAttrsBuilder::new(_fbb);
-        builder.add_axis(args.axis);
+        args: &'args GroupNormalizationAttrsArgs,
+    ) -> flatbuffers::WIPOffset<GroupNormalizationAttrs<'bldr>> {
+        let mut builder = GroupNormalizationAttrsBuilder::new(_fbb);
+        if let Some(x) = args.epsilon {
+            builder.add_epsilon(x);
+        }
+        builder.add_num_groups(args.num_groups);
         builder.finish()
     }
 
     #[inline]
-    pub fn axis(&self) -> i32 {
+    pub fn num_groups(&self) -> u32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<i32>(OneHotAttrs::VT_AXIS, Some(0)).unwrap() }
+        unsafe {
+            self._tab
+                .get::<u32>(GroupNormalizationAttrs::VT_NUM_GROUPS, Some(0))
+                .unwrap()
+        }
+    }
+
+    #[inline]
+    pub fn epsilon(&self) -> Option<f32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(GroupNormalizationAttrs::VT_EPSILON, None)
+        }
     }
 }
 
-impl flatbuffers::Verifiable for OneHotAttrs<'_> {
+impl flatbuffers::Verifiable for GroupNormalizationAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -6939,63 +7945,75 @@ impl flatbuffers::Verifiable for OneHotAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<u32>("num_groups", Self::VT_NUM_GROUPS, false)?
+            .visit_field::<f32>("epsilon", Self::VT_EPSILON, false)?
             .finish();
         Ok(())
     }
 }
-pub struct OneHotAttrsArgs {
-    pub axis: i32,
+pub struct GroupNormalizationAttrsArgs {
+    pub num_groups: u32,
+    pub epsilon: Option<f32>,
 }
-impl<'a> Default for OneHotAttrsArgs {
+impl<'a> Default for GroupNormalizationAttrsArgs {
     #[inline]
     fn default() -> Self {
-        OneHotAttrsArgs { axis: 0 }
+        GroupNormalizationAttrsArgs {
+            num_groups: 0,
+            epsilon: None,
+        }
     }
 }
 
-pub struct OneHotAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct GroupNormalizationAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> OneHotAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GroupNormalizationAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_.push_slot::<i32>(OneHotAttrs::VT_AXIS, axis, 0);
+    pub fn add_num_groups(&mut self, num_groups: u32) {
+        self.fbb_
+            .push_slot::<u32>(GroupNormalizationAttrs::VT_NUM_GROUPS, num_groups, 0);
+    }
+    #[inline]
+    pub fn add_epsilon(&mut self, epsilon: f32) {
+        self.fbb_
+            .push_slot_always::<f32>(GroupNormalizationAttrs::VT_EPSILON, epsilon);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> OneHotAttrsBuilder<'a, 'b, A> {
+    ) -> GroupNormalizationAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        OneHotAttrsBuilder {
+        GroupNormalizationAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<OneHotAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<GroupNormalizationAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for OneHotAttrs<'_> {
+impl core::fmt::Debug for GroupNormalizationAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("OneHotAttrs");
-        ds.field("axis", &self.axis());
+        let mut ds = f.debug_struct("GroupNormalizationAttrs");
+        ds.field("num_groups", &self.num_groups());
+        ds.field("epsilon", &self.epsilon());
         ds.finish()
     }
 }
-pub enum PadAttrsOffset {}
+pub enum GroupQueryAttentionAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct PadAttrs<'a> {
+pub struct GroupQueryAttentionAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for PadAttrs<'a> {
-    type Inner = PadAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for GroupQueryAttentionAttrs<'a> {
+    type Inner = GroupQueryAttentionAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -7004,37 +8022,124 @@ impl<'a> flatbuffers::Follow<'a> for PadAttrs<'a> {
     }
 }
 
-impl<'a> PadAttrs<'a> {
-    pub const VT_MODE: flatbuffers::VOffsetT = 4;
+impl<'a> GroupQueryAttentionAttrs<'a> {
+    pub const VT_NUM_HEADS: flatbuffers::VOffsetT = 4;
+    pub const VT_KV_NUM_HEADS: flatbuffers::VOffsetT = 6;
+    pub const VT_SCALE: flatbuffers::VOffsetT = 8;
+    pub const VT_SOFTCAP: flatbuffers::VOffsetT = 10;
+    pub const VT_LOCAL_WINDOW_SIZE: flatbuffers::VOffsetT = 12;
+    pub const VT_DO_ROTARY: flatbuffers::VOffsetT = 14;
+    pub const VT_ROTARY_INTERLEAVED: flatbuffers::VOffsetT = 16;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        PadAttrs { _tab: table }
+        GroupQueryAttentionAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args PadAttrsArgs,
-    ) -> flatbuffers::WIPOffset<PadAttrs<'bldr>> {
-        let mut builder = PadAttrsBuilder::new(_fbb);
-        builder.add_mode(args.mode);
+        args: &'args GroupQueryAttentionAttrsArgs,
+    ) -> flatbuffers::WIPOffset<GroupQueryAttentionAttrs<'bldr>> {
+        let mut builder = GroupQueryAttentionAttrsBuilder::new(_fbb);
+        if let Some(x) = args.local_window_size {
+            builder.add_local_window_size(x);
+        }
+        if let Some(x) = args.softcap {
+            builder.add_softcap(x);
+        }
+        if let Some(x) = args.scale {
+            builder.add_scale(x);
+        }
+        builder.add_kv_num_heads(args.kv_num_heads);
+        builder.add_num_heads(args.num_heads);
+        builder.add_rotary_interleaved(args.rotary_interleaved);
+        builder.add_do_rotary(args.do_rotary);
         builder.finish()
     }
 
     #[inline]
-    pub fn mode(&self) -> PadMode {
+    pub fn num_heads(&self) -> u32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<PadMode>(PadAttrs::VT_MODE, Some(PadMode::Constant))
+                .get::<u32>(GroupQueryAttentionAttrs::VT_NUM_HEADS, Some(0))
+                .unwrap()
+        }
+    }
+
+    #[inline]
+    pub fn kv_num_heads(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(GroupQueryAttentionAttrs::VT_KV_NUM_HEADS, Some(0))
+                .unwrap()
+        }
+    }
+
+    #[inline]
+    pub fn scale(&self) -> Option<f32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(GroupQueryAttentionAttrs::VT_SCALE, None)
+        }
+    }
+
+    #[inline]
+    pub fn softcap(&self) -> Option<f32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(GroupQueryAttentionAttrs::VT_SOFTCAP, None)
+        }
+    }
+
+    #[inline]
+    pub fn local_window_size(&self) -> Option<u32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(GroupQueryAttentionAttrs::VT_LOCAL_WINDOW_SIZE, None)
+        }
+    }
+
+    #[inline]
+    pub fn do_rotary(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(GroupQueryAttentionAttrs::VT_DO_ROTARY, Some(false))
+                .unwrap()
+        }
+    }
+
+    #[inline]
+    pub fn rotary_interleaved(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(GroupQueryAttentionAttrs::VT_ROTARY_INTERLEAVED, Some(false))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for PadAttrs<'_> {
+impl flatbuffers::Verifiable for GroupQueryAttentionAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -7042,64 +8147,125 @@ impl flatbuffers::Verifiable for PadAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<PadMode>("mode", Self::VT_MODE, false)?
+            .visit_field::<u32>("num_heads", Self::VT_NUM_HEADS, false)?
+            .visit_field::<u32>("kv_num_heads", Self::VT_KV_NUM_HEADS, false)?
+            .visit_field::<f32>("scale", Self::VT_SCALE, false)?
+            .visit_field::<f32>("softcap", Self::VT_SOFTCAP, false)?
+            .visit_field::<u32>("local_window_size", Self::VT_LOCAL_WINDOW_SIZE, false)?
+            .visit_field::<bool>("do_rotary", Self::VT_DO_ROTARY, false)?
+            .visit_field::<bool>("rotary_interleaved", Self::VT_ROTARY_INTERLEAVED, false)?
             .finish();
         Ok(())
     }
 }
-pub struct PadAttrsArgs {
-    pub mode: PadMode,
+pub struct GroupQueryAttentionAttrsArgs {
+    pub num_heads: u32,
+    pub kv_num_heads: u32,
+    pub scale: Option<f32>,
+    pub softcap: Option<f32>,
+    pub local_window_size: Option<u32>,
+    pub do_rotary: bool,
+    pub rotary_interleaved: bool,
 }
-impl<'a> Default for PadAttrsArgs {
+impl<'a> Default for GroupQueryAttentionAttrsArgs {
     #[inline]
     fn default() -> Self {
-        PadAttrsArgs {
-            mode: PadMode::Constant,
+        GroupQueryAttentionAttrsArgs {
+            num_heads: 0,
+            kv_num_heads: 0,
+            scale: None,
+            softcap: None,
+            local_window_size: None,
+            do_rotary: false,
+            rotary_interleaved: false,
         }
     }
 }
 
-pub struct PadAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct GroupQueryAttentionAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> PadAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GroupQueryAttentionAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_mode(&mut self, mode: PadMode) {
+    pub fn add_num_heads(&mut self, num_heads: u32) {
         self.fbb_
-            .push_slot::<PadMode>(PadAttrs::VT_MODE, mode, PadMode::Constant);
+            .push_slot::<u32>(GroupQueryAttentionAttrs::VT_NUM_HEADS, num_heads, 0);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> PadAttrsBuilder<'a, 'b, A> {
+    pub fn add_kv_num_heads(&mut self, kv_num_heads: u32) {
+        self.fbb_
+            .push_slot::<u32>(GroupQueryAttentionAttrs::VT_KV_NUM_HEADS, kv_num_heads, 0);
+    }
+    #[inline]
+    pub fn add_scale(&mut self, scale: f32) {
+        self.fbb_
+            .push_slot_always::<f32>(GroupQueryAttentionAttrs::VT_SCALE, scale);
+    }
+    #[inline]
+    pub fn add_softcap(&mut self, softcap: f32) {
+        self.fbb_
+            .push_slot_always::<f32>(GroupQueryAttentionAttrs::VT_SOFTCAP, softcap);
+    }
+    #[inline]
+    pub fn add_local_window_size(&mut self, local_window_size: u32) {
+        self.fbb_.push_slot_always::<u32>(
+            GroupQueryAttentionAttrs::VT_LOCAL_WINDOW_SIZE,
+            local_window_size,
+        );
+    }
+    #[inline]
+    pub fn add_do_rotary(&mut self, do_rotary: bool) {
+        self.fbb_
+            .push_slot::<bool>(GroupQueryAttentionAttrs::VT_DO_ROTARY, do_rotary, false);
+    }
+    #[inline]
+    pub fn add_rotary_interleaved(&mut self, rotary_interleaved: bool) {
+        self.fbb_.push_slot::<bool>(
+            GroupQueryAttentionAttrs::VT_ROTARY_INTERLEAVED,
+            rotary_interleaved,
+            false,
+        );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> GroupQueryAttentionAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        PadAttrsBuilder {
+        GroupQueryAttentionAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<PadAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<GroupQueryAttentionAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for PadAttrs<'_> {
+impl core::fmt::Debug for GroupQueryAttentionAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("PadAttrs");
-        ds.field("mode", &self.mode());
+        let mut ds = f.debug_struct("GroupQueryAttentionAttrs");
+        ds.field("num_heads", &self.num_heads());
+        ds.field("kv_num_heads", &self.kv_num_heads());
+        ds.field("scale", &self.scale());
+        ds.field("softcap", &self.softcap());
+        ds.field("local_window_size", &self.local_window_size());
+        ds.field("do_rotary", &self.do_rotary());
+        ds.field("rotary_interleaved", &self.rotary_interleaved());
         ds.finish()
     }
 }
-pub enum QuantizeLinearAttrsOffset {}
+pub enum GroupedQueryAttentionMatMulAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct QuantizeLinearAttrs<'a> {
+pub struct GroupedQueryAttentionMatMulAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for QuantizeLinearAttrs<'a> {
-    type Inner = QuantizeLinearAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for GroupedQueryAttentionMatMulAttrs<'a> {
+    type Inner = GroupedQueryAttentionMatMulAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -7108,51 +8274,67 @@ impl<'a> flatbuffers::Follow<'a> for QuantizeLinearAttrs<'a> {
     }
 }
 
-impl<'a> QuantizeLinearAttrs<'a> {
-    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
-    pub const VT_OUTPUT_DTYPE: flatbuffers::VOffsetT = 6;
+impl<'a> GroupedQueryAttentionMatMulAttrs<'a> {
+    pub const VT_REPEATS: flatbuffers::VOffsetT = 4;
+    pub const VT_ALPHA: flatbuffers::VOffsetT = 6;
+    pub const VT_TRANSPOSE_RHS: flatbuffers::VOffsetT = 8;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        QuantizeLinearAttrs { _tab: table }
+        GroupedQueryAttentionMatMulAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args QuantizeLinearAttrsArgs,
-    ) -> flatbuffers::WIPOffset<QuantizeLinearAttrs<'bldr>> {
-        let mut builder = QuantizeLinearAttrsBuilder::new(_fbb);
-        builder.add_axis(args.axis);
-        if let Some(x) = args.output_dtype {
-            builder.add_output_dtype(x);
-        }
+        args: &'args GroupedQueryAttentionMatMulAttrsArgs,
+    ) -> flatbuffers::WIPOffset<GroupedQueryAttentionMatMulAttrs<'bldr>> {
+        let mut builder = GroupedQueryAttentionMatMulAttrsBuilder::new(_fbb);
+        if let Some(x) = args.alpha {
+            builder.add_alpha(x);
+        }
+        builder.add_repeats(args.repeats);
+        builder.add_transpose_rhs(args.transpose_rhs);
         builder.finish()
     }
 
     #[inline]
-    pub fn axis(&self) -> i32 {
+    pub fn repeats(&self) -> u32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<i32>(QuantizeLinearAttrs::VT_AXIS, Some(0))
+                .get::<u32>(GroupedQueryAttentionMatMulAttrs::VT_REPEATS, Some(0))
                 .unwrap()
         }
     }
     #[inline]
-    pub fn output_dtype(&self) -> Option<DataType> {
+    pub fn alpha(&self) -> Option<f32> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<DataType>(QuantizeLinearAttrs::VT_OUTPUT_DTYPE, None)
+                .get::<f32>(GroupedQueryAttentionMatMulAttrs::VT_ALPHA, None)
+        }
+    }
+    #[inline]
+    pub fn transpose_rhs(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(
+                    GroupedQueryAttentionMatMulAttrs::VT_TRANSPOSE_RHS,
+                    Some(false),
+                )
+                .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for QuantizeLinearAttrs<'_> {
+impl flatbuffers::Verifiable for GroupedQueryAttentionMatMulAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -7160,75 +8342,89 @@ impl flatbuffers::Verifiable for QuantizeLinearAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
-            .visit_field::<DataType>("output_dtype", Self::VT_OUTPUT_DTYPE, false)?
+            .visit_field::<u32>("repeats", Self::VT_REPEATS, false)?
+            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
+            .visit_field::<bool>("transpose_rhs", Self::VT_TRANSPOSE_RHS, false)?
             .finish();
         Ok(())
     }
 }
-pub struct QuantizeLinearAttrsArgs {
-    pub axis: i32,
-    pub output_dtype: Option<DataType>,
+pub struct GroupedQueryAttentionMatMulAttrsArgs {
+    pub repeats: u32,
+    pub alpha: Option<f32>,
+    pub transpose_rhs: bool,
 }
-impl<'a> Default for QuantizeLinearAttrsArgs {
+impl<'a> Default for GroupedQueryAttentionMatMulAttrsArgs {
     #[inline]
     fn default() -> Self {
-        QuantizeLinearAttrsArgs {
-            axis: 0,
-            output_dtype: None,
+        GroupedQueryAttentionMatMulAttrsArgs {
+            repeats: 0,
+            alpha: None,
+            transpose_rhs: false,
         }
     }
 }
 
-pub struct QuantizeLinearAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct GroupedQueryAttentionMatMulAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> QuantizeLinearAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a>
+    GroupedQueryAttentionMatMulAttrsBuilder<'a, 'b, A>
+{
     #[inline]
-    pub fn add_axis(&mut self, axis: i32) {
+    pub fn add_repeats(&mut self, repeats: u32) {
         self.fbb_
-            .push_slot::<i32>(QuantizeLinearAttrs::VT_AXIS, axis, 0);
+            .push_slot::<u32>(GroupedQueryAttentionMatMulAttrs::VT_REPEATS, repeats, 0);
     }
     #[inline]
-    pub fn add_output_dtype(&mut self, output_dtype: DataType) {
+    pub fn add_alpha(&mut self, alpha: f32) {
         self.fbb_
-            .push_slot_always::<DataType>(QuantizeLinearAttrs::VT_OUTPUT_DTYPE, output_dtype);
+            .push_slot_always::<f32>(GroupedQueryAttentionMatMulAttrs::VT_ALPHA, alpha);
+    }
+    #[inline]
+    pub fn add_transpose_rhs(&mut self, transpose_rhs: bool) {
+        self.fbb_.push_slot::<bool>(
+            GroupedQueryAttentionMatMulAttrs::VT_TRANSPOSE_RHS,
+            transpose_rhs,
+            false,
+        );
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> QuantizeLinearAttrsBuilder<'a, 'b, A> {
+    ) -> GroupedQueryAttentionMatMulAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        QuantizeLinearAttrsBuilder {
+        GroupedQueryAttentionMatMulAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<QuantizeLinearAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<GroupedQueryAttentionMatMulAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for QuantizeLinearAttrs<'_> {
+impl core::fmt::Debug for GroupedQueryAttentionMatMulAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("QuantizeLinearAttrs");
-        ds.field("axis", &self.axis());
-        ds.field("output_dtype", &self.output_dtype());
+        let mut ds = f.debug_struct("GroupedQueryAttentionMatMulAttrs");
+        ds.field("repeats", &self.repeats());
+        ds.field("alpha", &self.alpha());
+        ds.field("transpose_rhs", &self.transpose_rhs());
         ds.finish()
     }
 }
-pub enum RandomNormalAttrsOffset {}
+pub enum GRUAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct RandomNormalAttrs<'a> {
+pub struct GRUAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for RandomNormalAttrs<'a> {
-    type Inner = RandomNormalAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for GRUAttrs<'a> {
+    type Inner = GRUAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -7237,78 +8433,63 @@ impl<'a> flatbuffers::Follow<'a> for RandomNormalAttrs<'a> {
     }
 }
 
-impl<'a> RandomNormalAttrs<'a> {
-    pub const VT_MEAN: flatbuffers::VOffsetT = 4;
-    pub const VT_SCALE: flatbuffers::VOffsetT = 6;
-    pub const VT_SEED: flatbuffers::VOffsetT = 8;
-    pub const VT_SHAPE: flatbuffers::VOffsetT = 10;
+impl<'a> GRUAttrs<'a> {
+    pub const VT_DIRECTION: flatbuffers::VOffsetT = 4;
+    pub const VT_HIDDEN_SIZE: flatbuffers::VOffsetT = 6;
+    pub const VT_LINEAR_BEFORE_RESET: flatbuffers::VOffsetT = 8;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        RandomNormalAttrs { _tab: table }
+        GRUAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args RandomNormalAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<RandomNormalAttrs<'bldr>> {
-        let mut builder = RandomNormalAttrsBuilder::new(_fbb);
-        if let Some(x) = args.shape {
-            builder.add_shape(x);
-        }
-        if let Some(x) = args.seed {
-            builder.add_seed(x);
-        }
-        builder.add_scale(args.scale);
-        builder.add_mean(args.mean);
+        args: &'args GRUAttrsArgs,
+    ) -> flatbuffers::WIPOffset<GRUAttrs<'bldr>> {
+        let mut builder = GRUAttrsBuilder::new(_fbb);
+        builder.add_hidden_size(args.hidden_size);
+        builder.add_linear_before_reset(args.linear_before_reset);
+        builder.add_direction(args.direction);
         builder.finish()
     }
 
     #[inline]
-    pub fn mean(&self) -> f32 {
+    pub fn direction(&self) -> RNNDirection {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(RandomNormalAttrs::VT_MEAN, Some(0.0))
+                .get::<RNNDirection>(GRUAttrs::VT_DIRECTION, Some(RNNDirection::Forward))
                 .unwrap()
         }
     }
     #[inline]
-    pub fn scale(&self) -> f32 {
+    pub fn hidden_size(&self) -> u32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(RandomNormalAttrs::VT_SCALE, Some(0.0))
+                .get::<u32>(GRUAttrs::VT_HIDDEN_SIZE, Some(0))
                 .unwrap()
         }
     }
     #[inline]
-    pub fn seed(&self) -> Option<f32> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe { self._tab.get::<f32>(RandomNormalAttrs::VT_SEED, None) }
-    }
-    #[inline]
-    pub fn shape(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    pub fn linear_before_reset(&self) -> bool {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    RandomNormalAttrs::VT_SHAPE,
-                    None,
-                )
+                .get::<bool>(GRUAttrs::VT_LINEAR_BEFORE_RESET, Some(false))
+                .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for RandomNormalAttrs<'_> {
+impl flatbuffers::Verifiable for GRUAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -7316,97 +8497,85 @@ impl flatbuffers::Verifiable for RandomNormalAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<f32>("mean", Self::VT_MEAN, false)?
-            .visit_field::<f32>("scale", Self::VT_SCALE, false)?
-            .visit_field::<f32>("seed", Self::VT_SEED, false)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "shape",
-                Self::VT_SHAPE,
-                false,
-            )?
+            .visit_field::<RNNDirection>("direction", Self::VT_DIRECTION, false)?
+            .visit_field::<u32>("hidden_size", Self::VT_HIDDEN_SIZE, false)?
+            .visit_field::<bool>("linear_before_reset", Self::VT_LINEAR_BEFORE_RESET, false)?
             .finish();
         Ok(())
     }
 }
-pub struct RandomNormalAttrsArgs<'a> {
-    pub mean: f32,
-    pub scale: f32,
-    pub seed: Option<f32>,
-    pub shape: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+pub struct GRUAttrsArgs {
+    pub direction: RNNDirection,
+    pub hidden_size: u32,
+    pub linear_before_reset: bool,
 }
-impl<'a> Default for RandomNormalAttrsArgs<'a> {
+impl<'a> Default for GRUAttrsArgs {
     #[inline]
     fn default() -> Self {
-        RandomNormalAttrsArgs {
-            mean: 0.0,
-            scale: 0.0,
-            seed: None,
-            shape: None,
+        GRUAttrsArgs {
+            direction: RNNDirection::Forward,
+            hidden_size: 0,
+            linear_before_reset: false,
         }
     }
 }
 
-pub struct RandomNormalAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct GRUAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RandomNormalAttrsBuilder<'a, 'b, A> {
-    #[inline]
-    pub fn add_mean(&mut self, mean: f32) {
-        self.fbb_
-            .push_slot::<f32>(RandomNormalAttrs::VT_MEAN, mean, 0.0);
-    }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GRUAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_scale(&mut self, scale: f32) {
-        self.fbb_
-            .push_slot::<f32>(RandomNormalAttrs::VT_SCALE, scale, 0.0);
+    pub fn add_direction(&mut self, direction: RNNDirection) {
+        self.fbb_.push_slot::<RNNDirection>(
+            GRUAttrs::VT_DIRECTION,
+            direction,
+            RNNDirection::Forward,
+        );
     }
     #[inline]
-    pub fn add_seed(&mut self, seed: f32) {
+    pub fn add_hidden_size(&mut self, hidden_size: u32) {
         self.fbb_
-            .push_slot_always::<f32>(RandomNormalAttrs::VT_SEED, seed);
+            .push_slot::<u32>(GRUAttrs::VT_HIDDEN_SIZE, hidden_size, 0);
     }
     #[inline]
-    pub fn add_shape(&mut self, shape: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+    pub fn add_linear_before_reset(&mut self, linear_before_reset: bool) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(RandomNormalAttrs::VT_SHAPE, shape);
+            .push_slot::<bool>(GRUAttrs::VT_LINEAR_BEFORE_RESET, linear_before_reset, false);
     }
     #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> RandomNormalAttrsBuilder<'a, 'b, A> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GRUAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        RandomNormalAttrsBuilder {
+        GRUAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<RandomNormalAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<GRUAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for RandomNormalAttrs<'_> {
+impl core::fmt::Debug for GRUAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("RandomNormalAttrs");
-        ds.field("mean", &self.mean());
-        ds.field("scale", &self.scale());
-        ds.field("seed", &self.seed());
-        ds.field("shape", &self.shape());
+        let mut ds = f.debug_struct("GRUAttrs");
+        ds.field("direction", &self.direction());
+        ds.field("hidden_size", &self.hidden_size());
+        ds.field("linear_before_reset", &self.linear_before_reset());
         ds.finish()
     }
 }
-pub enum RandomNormalLikeAttrsOffset {}
+pub enum HardmaxAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct RandomNormalLikeAttrs<'a> {
+pub struct HardmaxAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for RandomNormalLikeAttrs<'a> {
-    type Inner = RandomNormalLikeAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for HardmaxAttrs<'a> {
+    type Inner = HardmaxAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -7415,61 +8584,37 @@ impl<'a> flatbuffers::Follow<'a> for RandomNormalLikeAttrs<'a> {
     }
 }
 
-impl<'a> RandomNormalLikeAttrs<'a> {
-    pub const VT_MEAN: flatbuffers::VOffsetT = 4;
-    pub const VT_SCALE: flatbuffers::VOffsetT = 6;
-    pub const VT_SEED: flatbuffers::VOffsetT = 8;
+impl<'a> HardmaxAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        RandomNormalLikeAttrs { _tab: table }
+        HardmaxAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args RandomNormalLikeAttrsArgs,
-    ) -> flatbuffers::WIPOffset<RandomNormalLikeAttrs<'bldr>> {
-        let mut builder = RandomNormalLikeAttrsBuilder::new(_fbb);
-        if let Some(x) = args.seed {
-            builder.add_seed(x);
-        }
-        builder.add_scale(args.scale);
-        builder.add_mean(args.mean);
+        args: &'args HardmaxAttrsArgs,
+    ) -> flatbuffers::WIPOffset<HardmaxAttrs<'bldr>> {
+        let mut builder = HardmaxAttrsBuilder::new(_fbb);
+        builder.add_axis(args.axis);
         builder.finish()
     }
 
     #[inline]
-    pub fn mean(&self) -> f32 {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<f32>(RandomNormalLikeAttrs::VT_MEAN, Some(0.0))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn scale(&self) -> f32 {
+    pub fn axis(&self) -> i32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(RandomNormalLikeAttrs::VT_SCALE, Some(0.0))
+                .get::<i32>(HardmaxAttrs::VT_AXIS, Some(0))
                 .unwrap()
         }
     }
-    #[inline]
-    pub fn seed(&self) -> Option<f32> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe { self._tab.get::<f32>(RandomNormalLikeAttrs::VT_SEED, None) }
-    }
 }
 
-impl flatbuffers::Verifiable for RandomNormalLikeAttrs<'_> {
+impl flatbuffers::Verifiable for HardmaxAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -7477,84 +8622,63 @@ impl flatbuffers::Verifiable for RandomNormalLikeAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<f32>("mean", Self::VT_MEAN, false)?
-            .visit_field::<f32>("scale", Self::VT_SCALE, false)?
-            .visit_field::<f32>("seed", Self::VT_SEED, false)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
             .finish();
         Ok(())
     }
 }
-pub struct RandomNormalLikeAttrsArgs {
-    pub mean: f32,
-    pub scale: f32,
-    pub seed: Option<f32>,
+pub struct HardmaxAttrsArgs {
+    pub axis: i32,
 }
-impl<'a> Default for RandomNormalLikeAttrsArgs {
+impl<'a> Default for HardmaxAttrsArgs {
     #[inline]
     fn default() -> Self {
-        RandomNormalLikeAttrsArgs {
-            mean: 0.0,
-            scale: 0.0,
-            seed: None,
-        }
+        HardmaxAttrsArgs { axis: 0 }
     }
 }
 
-pub struct RandomNormalLikeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct HardmaxAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RandomNormalLikeAttrsBuilder<'a, 'b, A> {
-    #[inline]
-    pub fn add_mean(&mut self, mean: f32) {
-        self.fbb_
-            .push_slot::<f32>(RandomNormalLikeAttrs::VT_MEAN, mean, 0.0);
-    }
-    #[inline]
-    pub fn add_scale(&mut self, scale: f32) {
-        self.fbb_
-            .push_slot::<f32>(RandomNormalLikeAttrs::VT_SCALE, scale, 0.0);
-    }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> HardmaxAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_seed(&mut self, seed: f32) {
-        self.fbb_
-            .push_slot_always::<f32>(RandomNormalLikeAttrs::VT_SEED, seed);
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_.push_slot::<i32>(HardmaxAttrs::VT_AXIS, axis, 0);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> RandomNormalLikeAttrsBuilder<'a, 'b, A> {
+    ) -> HardmaxAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        RandomNormalLikeAttrsBuilder {
+        HardmaxAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<RandomNormalLikeAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<HardmaxAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for RandomNormalLikeAttrs<'_> {
+impl core::fmt::Debug for HardmaxAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("RandomNormalLikeAttrs");
-        ds.field("mean", &self.mean());
-        ds.field("scale", &self.scale());
-        ds.field("seed", &self.seed());
+        let mut ds = f.debug_struct("HardmaxAttrs");
+        ds.field("axis", &self.axis());
         ds.finish()
     }
 }
-pub enum RandomUniformAttrsOffset {}
+pub enum HardSigmoidAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct RandomUniformAttrs<'a> {
+pub struct HardSigmoidAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for RandomUniformAttrs<'a> {
-    type Inner = RandomUniformAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for HardSigmoidAttrs<'a> {
+    type Inner = HardSigmoidAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -7563,78 +8687,50 @@ impl<'a> flatbuffers::Follow<'a> for RandomUniformAttrs<'a> {
     }
 }
 
-impl<'a> RandomUniformAttrs<'a> {
-    pub const VT_SHAPE: flatbuffers::VOffsetT = 4;
-    pub const VT_HIGH: flatbuffers::VOffsetT = 6;
-    pub const VT_LOW: flatbuffers::VOffsetT = 8;
-    pub const VT_SEED: flatbuffers::VOffsetT = 10;
+impl<'a> HardSigmoidAttrs<'a> {
+    pub const VT_ALPHA: flatbuffers::VOffsetT = 4;
+    pub const VT_BETA: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        RandomUniformAttrs { _tab: table }
+        HardSigmoidAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args RandomUniformAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<RandomUniformAttrs<'bldr>> {
-        let mut builder = RandomUniformAttrsBuilder::new(_fbb);
-        if let Some(x) = args.seed {
-            builder.add_seed(x);
-        }
-        builder.add_low(args.low);
-        builder.add_high(args.high);
-        if let Some(x) = args.shape {
-            builder.add_shape(x);
-        }
+        args: &'args HardSigmoidAttrsArgs,
+    ) -> flatbuffers::WIPOffset<HardSigmoidAttrs<'bldr>> {
+        let mut builder = HardSigmoidAttrsBuilder::new(_fbb);
+        builder.add_beta(args.beta);
+        builder.add_alpha(args.alpha);
         builder.finish()
     }
 
     #[inline]
-    pub fn shape(&self) -> Option<flatbuffers::Vector<'a, u32>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    RandomUniformAttrs::VT_SHAPE,
-                    None,
-                )
-        }
-    }
-    #[inline]
-    pub fn high(&self) -> f32 {
+    pub fn alpha(&self) -> f32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(RandomUniformAttrs::VT_HIGH, Some(0.0))
+                .get::<f32>(HardSigmoidAttrs::VT_ALPHA, Some(0.0))
                 .unwrap()
         }
     }
     #[inline]
-    pub fn low(&self) -> f32 {
+    pub fn beta(&self) -> f32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(RandomUniformAttrs::VT_LOW, Some(0.0))
+                .get::<f32>(HardSigmoidAttrs::VT_BETA, Some(0.0))
                 .unwrap()
         }
     }
-    #[inline]
-    pub fn seed(&self) -> Option<f32> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe { self._tab.get::<f32>(RandomUniformAttrs::VT_SEED, None) }
-    }
 }
 
-impl flatbuffers::Verifiable for RandomUniformAttrs<'_> {
+impl flatbuffers::Verifiable for HardSigmoidAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -7642,97 +8738,75 @@ impl flatbuffers::Verifiable for RandomUniformAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "shape",
-                Self::VT_SHAPE,
-                false,
-            )?
-            .visit_field::<f32>("high", Self::VT_HIGH, false)?
-            .visit_field::<f32>("low", Self::VT_LOW, false)?
-            .visit_field::<f32>("seed", Self::VT_SEED, false)?
+            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
+            .visit_field::<f32>("beta", Self::VT_BETA, false)?
             .finish();
         Ok(())
     }
 }
-pub struct RandomUniformAttrsArgs<'a> {
-    pub shape: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
-    pub high: f32,
-    pub low: f32,
-    pub seed: Option<f32>,
+pub struct HardSigmoidAttrsArgs {
+    pub alpha: f32,
+    pub beta: f32,
 }
-impl<'a> Default for RandomUniformAttrsArgs<'a> {
+impl<'a> Default for HardSigmoidAttrsArgs {
     #[inline]
     fn default() -> Self {
-        RandomUniformAttrsArgs {
-            shape: None,
-            high: 0.0,
-            low: 0.0,
-            seed: None,
+        HardSigmoidAttrsArgs {
+            alpha: 0.0,
+            beta: 0.0,
         }
     }
 }
 
-pub struct RandomUniformAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct HardSigmoidAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RandomUniformAttrsBuilder<'a, 'b, A> {
-    #[inline]
-    pub fn add_shape(&mut self, shape: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(RandomUniformAttrs::VT_SHAPE, shape);
-    }
-    #[inline]
-    pub fn add_high(&mut self, high: f32) {
-        self.fbb_
-            .push_slot::<f32>(RandomUniformAttrs::VT_HIGH, high, 0.0);
-    }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> HardSigmoidAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_low(&mut self, low: f32) {
+    pub fn add_alpha(&mut self, alpha: f32) {
         self.fbb_
-            .push_slot::<f32>(RandomUniformAttrs::VT_LOW, low, 0.0);
+            .push_slot::<f32>(HardSigmoidAttrs::VT_ALPHA, alpha, 0.0);
     }
     #[inline]
-    pub fn add_seed(&mut self, seed: f32) {
+    pub fn add_beta(&mut self, beta: f32) {
         self.fbb_
-            .push_slot_always::<f32>(RandomUniformAttrs::VT_SEED, seed);
+            .push_slot::<f32>(HardSigmoidAttrs::VT_BETA, beta, 0.0);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> RandomUniformAttrsBuilder<'a, 'b, A> {
+    ) -> HardSigmoidAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        RandomUniformAttrsBuilder {
+        HardSigmoidAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<RandomUniformAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<HardSigmoidAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for RandomUniformAttrs<'_> {
+impl core::fmt::Debug for HardSigmoidAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("RandomUniformAttrs");
-        ds.field("shape", &self.shape());
-        ds.field("high", &self.high());
-        ds.field("low", &self.low());
-        ds.field("seed", &self.seed());
+        let mut ds = f.debug_struct("HardSigmoidAttrs");
+        ds.field("alpha", &self.alpha());
+        ds.field("beta", &self.beta());
         ds.finish()
     }
 }
-pub enum RandomUniformLikeAttrsOffset {}
+pub enum IfAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct RandomUniformLikeAttrs<'a> {
+pub struct IfAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for RandomUniformLikeAttrs<'a> {
-    type Inner = RandomUniformLikeAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for IfAttrs<'a> {
+    type Inner = IfAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -7741,61 +8815,52 @@ impl<'a> flatbuffers::Follow<'a> for RandomUniformLikeAttrs<'a> {
     }
 }
 
-impl<'a> RandomUniformLikeAttrs<'a> {
-    pub const VT_HIGH: flatbuffers::VOffsetT = 4;
-    pub const VT_LOW: flatbuffers::VOffsetT = 6;
-    pub const VT_SEED: flatbuffers::VOffsetT = 8;
+impl<'a> IfAttrs<'a> {
+    pub const VT_THEN_BRANCH: flatbuffers::VOffsetT = 4;
+    pub const VT_ELSE_BRANCH: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        RandomUniformLikeAttrs { _tab: table }
+        IfAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args RandomUniformLikeAttrsArgs,
-    ) -> flatbuffers::WIPOffset<RandomUniformLikeAttrs<'bldr>> {
-        let mut builder = RandomUniformLikeAttrsBuilder::new(_fbb);
-        if let Some(x) = args.seed {
-            builder.add_seed(x);
+        args: &'args IfAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<IfAttrs<'bldr>> {
+        let mut builder = IfAttrsBuilder::new(_fbb);
+        if let Some(x) = args.else_branch {
+            builder.add_else_branch(x);
+        }
+        if let Some(x) = args.then_branch {
+            builder.add_then_branch(x);
         }
-        builder.add_low(args.low);
-        builder.add_high(args.high);
         builder.finish()
     }
 
     #[inline]
-    pub fn high(&self) -> f32 {
+    pub fn then_branch(&self) -> Option<Graph<'a>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(RandomUniformLikeAttrs::VT_HIGH, Some(0.0))
-                .unwrap()
+                .get::<flatbuffers::ForwardsUOffset<Graph>>(IfAttrs::VT_THEN_BRANCH, None)
         }
     }
     #[inline]
-    pub fn low(&self) -> f32 {
+    pub fn else_branch(&self) -> Option<Graph<'a>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<f32>(RandomUniformLikeAttrs::VT_LOW, Some(0.0))
-                .unwrap()
+                .get::<flatbuffers::ForwardsUOffset<Graph>>(IfAttrs::VT_ELSE_BRANCH, None)
         }
     }
-    #[inline]
-    pub fn seed(&self) -> Option<f32> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe { self._tab.get::<f32>(RandomUniformLikeAttrs::VT_SEED, None) }
-    }
 }
 
-impl flatbuffers::Verifiable for RandomUniformLikeAttrs<'_> {
+impl flatbuffers::Verifiable for IfAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -7803,84 +8868,85 @@ impl flatbuffers::Verifiable for RandomUniformLikeAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<f32>("high", Self::VT_HIGH, false)?
-            .visit_field::<f32>("low", Self::VT_LOW, false)?
-            .visit_field::<f32>("seed", Self::VT_SEED, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<Graph>>(
+                "then_branch",
+                Self::VT_THEN_BRANCH,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<Graph>>(
+                "else_branch",
+                Self::VT_ELSE_BRANCH,
+                false,
+            )?
             .finish();
         Ok(())
     }
 }
-pub struct RandomUniformLikeAttrsArgs {
-    pub high: f32,
-    pub low: f32,
-    pub seed: Option<f32>,
+pub struct IfAttrsArgs<'a> {
+    pub then_branch: Option<flatbuffers::WIPOffset<Graph<'a>>>,
+    pub else_branch: Option<flatbuffers::WIPOffset<Graph<'a>>>,
 }
-impl<'a> Default for RandomUniformLikeAttrsArgs {
+impl<'a> Default for IfAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        RandomUniformLikeAttrsArgs {
-            high: 0.0,
-            low: 0.0,
-            seed: None,
+        IfAttrsArgs {
+            then_branch: None,
+            else_branch: None,
         }
     }
 }
 
-pub struct RandomUniformLikeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct IfAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RandomUniformLikeAttrsBuilder<'a, 'b, A> {
-    #[inline]
-    pub fn add_high(&mut self, high: f32) {
-        self.fbb_
-            .push_slot::<f32>(RandomUniformLikeAttrs::VT_HIGH, high, 0.0);
-    }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> IfAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_low(&mut self, low: f32) {
-        self.fbb_
-            .push_slot::<f32>(RandomUniformLikeAttrs::VT_LOW, low, 0.0);
+    pub fn add_then_branch(&mut self, then_branch: flatbuffers::WIPOffset<Graph<'b>>) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Graph>>(
+            IfAttrs::VT_THEN_BRANCH,
+            then_branch,
+        );
     }
     #[inline]
-    pub fn add_seed(&mut self, seed: f32) {
-        self.fbb_
-            .push_slot_always::<f32>(RandomUniformLikeAttrs::VT_SEED, seed);
+    pub fn add_else_branch(&mut self, else_branch: flatbuffers::WIPOffset<Graph<'b>>) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Graph>>(
+            IfAttrs::VT_ELSE_BRANCH,
+            else_branch,
+        );
     }
     #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> RandomUniformLikeAttrsBuilder<'a, 'b, A> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> IfAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        RandomUniformLikeAttrsBuilder {
+        IfAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<RandomUniformLikeAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<IfAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for RandomUniformLikeAttrs<'_> {
+impl core::fmt::Debug for IfAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("RandomUniformLikeAttrs");
-        ds.field("high", &self.high());
-        ds.field("low", &self.low());
-        ds.field("seed", &self.seed());
+        let mut ds = f.debug_struct("IfAttrs");
+        ds.field("then_branch", &self.then_branch());
+        ds.field("else_branch", &self.else_branch());
         ds.finish()
     }
 }
-pub enum ReduceMeanAttrsOffset {}
+pub enum LeakyReluAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ReduceMeanAttrs<'a> {
+pub struct LeakyReluAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ReduceMeanAttrs<'a> {
-    type Inner = ReduceMeanAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for LeakyReluAttrs<'a> {
+    type Inner = LeakyReluAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -7889,67 +8955,37 @@ impl<'a> flatbuffers::Follow<'a> for ReduceMeanAttrs<'a> {
     }
 }
 
-impl<'a> ReduceMeanAttrs<'a> {
-    pub const VT_AXES: flatbuffers::VOffsetT = 4;
-    pub const VT_KEEP_DIMS: flatbuffers::VOffsetT = 6;
-    pub const VT_NOOP_WITH_EMPTY_AXES: flatbuffers::VOffsetT = 8;
+impl<'a> LeakyReluAttrs<'a> {
+    pub const VT_ALPHA: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ReduceMeanAttrs { _tab: table }
+        LeakyReluAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args ReduceMeanAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<ReduceMeanAttrs<'bldr>> {
-        let mut builder = ReduceMeanAttrsBuilder::new(_fbb);
-        if let Some(x) = args.axes {
-            builder.add_axes(x);
-        }
-        builder.add_noop_with_empty_axes(args.noop_with_empty_axes);
-        builder.add_keep_dims(args.keep_dims);
+        args: &'args LeakyReluAttrsArgs,
+    ) -> flatbuffers::WIPOffset<LeakyReluAttrs<'bldr>> {
+        let mut builder = LeakyReluAttrsBuilder::new(_fbb);
+        builder.add_alpha(args.alpha);
         builder.finish()
     }
 
     #[inline]
-    pub fn axes(&self) -> Option<flatbuffers::Vector<'a, i32>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
-                    ReduceMeanAttrs::VT_AXES,
-                    None,
-                )
-        }
-    }
-    #[inline]
-    pub fn keep_dims(&self) -> bool {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<bool>(ReduceMeanAttrs::VT_KEEP_DIMS, Some(false))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn noop_with_empty_axes(&self) -> bool {
+    pub fn alpha(&self) -> f32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<bool>(ReduceMeanAttrs::VT_NOOP_WITH_EMPTY_AXES, Some(false))
+                .get::<f32>(LeakyReluAttrs::VT_ALPHA, Some(0.0))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for ReduceMeanAttrs<'_> {
+impl flatbuffers::Verifiable for LeakyReluAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -7957,91 +8993,64 @@ impl flatbuffers::Verifiable for ReduceMeanAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i32>>>(
-                "axes",
-                Self::VT_AXES,
-                false,
-            )?
-            .visit_field::<bool>("keep_dims", Self::VT_KEEP_DIMS, false)?
-            .visit_field::<bool>("noop_with_empty_axes", Self::VT_NOOP_WITH_EMPTY_AXES, false)?
+            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ReduceMeanAttrsArgs<'a> {
-    pub axes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i32>>>,
-    pub keep_dims: bool,
-    pub noop_with_empty_axes: bool,
+pub struct LeakyReluAttrsArgs {
+    pub alpha: f32,
 }
-impl<'a> Default for ReduceMeanAttrsArgs<'a> {
+impl<'a> Default for LeakyReluAttrsArgs {
     #[inline]
     fn default() -> Self {
-        ReduceMeanAttrsArgs {
-            axes: None,
-            keep_dims: false,
-            noop_with_empty_axes: false,
-        }
+        LeakyReluAttrsArgs { alpha: 0.0 }
     }
 }
 
-pub struct ReduceMeanAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct LeakyReluAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ReduceMeanAttrsBuilder<'a, 'b, A> {
-    #[inline]
-    pub fn add_axes(&mut self, axes: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i32>>) {
-        self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(ReduceMeanAttrs::VT_AXES, axes);
-    }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LeakyReluAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_keep_dims(&mut self, keep_dims: bool) {
+    pub fn add_alpha(&mut self, alpha: f32) {
         self.fbb_
-            .push_slot::<bool>(ReduceMeanAttrs::VT_KEEP_DIMS, keep_dims, false);
-    }
-    #[inline]
-    pub fn add_noop_with_empty_axes(&mut self, noop_with_empty_axes: bool) {
-        self.fbb_.push_slot::<bool>(
-            ReduceMeanAttrs::VT_NOOP_WITH_EMPTY_AXES,
-            noop_with_empty_axes,
-            false,
-        );
+            .push_slot::<f32>(LeakyReluAttrs::VT_ALPHA, alpha, 0.0);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> ReduceMeanAttrsBuilder<'a, 'b, A> {
+    ) -> LeakyReluAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        ReduceMeanAttrsBuilder {
+        LeakyReluAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ReduceMeanAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<LeakyReluAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ReduceMeanAttrs<'_> {
+impl core::fmt::Debug for LeakyReluAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ReduceMeanAttrs");
-        ds.field("axes", &self.axes());
-        ds.field("keep_dims", &self.keep_dims());
-        ds.field("noop_with_empty_axes", &self.noop_with_empty_axes());
+        let mut ds = f.debug_struct("LeakyReluAttrs");
+        ds.field("alpha", &self.alpha());
         ds.finish()
     }
 }
-pub enum ReshapeAttrsOffset {}
+pub enum LSTMAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ReshapeAttrs<'a> {
+pub struct LSTMAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ReshapeAttrs<'a> {
-    type Inner = ReshapeAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for LSTMAttrs<'a> {
+    type Inner = LSTMAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -8050,37 +9059,50 @@ impl<'a> flatbuffers::Follow<'a> for ReshapeAttrs<'a> {
     }
 }
 
-impl<'a> ReshapeAttrs<'a> {
-    pub const VT_ALLOW_ZERO: flatbuffers::VOffsetT = 4;
+impl<'a> LSTMAttrs<'a> {
+    pub const VT_DIRECTION: flatbuffers::VOffsetT = 4;
+    pub const VT_HIDDEN_SIZE: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ReshapeAttrs { _tab: table }
+        LSTMAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args ReshapeAttrsArgs,
-    ) -> flatbuffers::WIPOffset<ReshapeAttrs<'bldr>> {
-        let mut builder = ReshapeAttrsBuilder::new(_fbb);
-        builder.add_allow_zero(args.allow_zero);
+        args: &'args LSTMAttrsArgs,
+    ) -> flatbuffers::WIPOffset<LSTMAttrs<'bldr>> {
+        let mut builder = LSTMAttrsBuilder::new(_fbb);
+        builder.add_hidden_size(args.hidden_size);
+        builder.add_direction(args.direction);
         builder.finish()
     }
 
     #[inline]
-    pub fn allow_zero(&self) -> bool {
+    pub fn direction(&self) -> RNNDirection {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<bool>(ReshapeAttrs::VT_ALLOW_ZERO, Some(false))
+                .get::<RNNDirection>(LSTMAttrs::VT_DIRECTION, Some(RNNDirection::Forward))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn hidden_size(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(LSTMAttrs::VT_HIDDEN_SIZE, Some(0))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for ReshapeAttrs<'_> {
+impl flatbuffers::Verifiable for LSTMAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -8088,64 +9110,76 @@ impl flatbuffers::Verifiable for ReshapeAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<bool>("allow_zero", Self::VT_ALLOW_ZERO, false)?
+            .visit_field::<RNNDirection>("direction", Self::VT_DIRECTION, false)?
+            .visit_field::<u32>("hidden_size", Self::VT_HIDDEN_SIZE, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ReshapeAttrsArgs {
-    pub allow_zero: bool,
+pub struct LSTMAttrsArgs {
+    pub direction: RNNDirection,
+    pub hidden_size: u32,
 }
-impl<'a> Default for ReshapeAttrsArgs {
+impl<'a> Default for LSTMAttrsArgs {
     #[inline]
     fn default() -> Self {
-        ReshapeAttrsArgs { allow_zero: false }
+        LSTMAttrsArgs {
+            direction: RNNDirection::Forward,
+            hidden_size: 0,
+        }
     }
 }
 
-pub struct ReshapeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct LSTMAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ReshapeAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LSTMAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_allow_zero(&mut self, allow_zero: bool) {
+    pub fn add_direction(&mut self, direction: RNNDirection) {
+        self.fbb_.push_slot::<RNNDirection>(
+            LSTMAttrs::VT_DIRECTION,
+            direction,
+            RNNDirection::Forward,
+        );
+    }
+    #[inline]
+    pub fn add_hidden_size(&mut self, hidden_size: u32) {
         self.fbb_
-            .push_slot::<bool>(ReshapeAttrs::VT_ALLOW_ZERO, allow_zero, false);
+            .push_slot::<u32>(LSTMAttrs::VT_HIDDEN_SIZE, hidden_size, 0);
     }
     #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> ReshapeAttrsBuilder<'a, 'b, A> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> LSTMAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        ReshapeAttrsBuilder {
+        LSTMAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ReshapeAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<LSTMAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ReshapeAttrs<'_> {
+impl core::fmt::Debug for LSTMAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ReshapeAttrs");
-        ds.field("allow_zero", &self.allow_zero());
+        let mut ds = f.debug_struct("LSTMAttrs");
+        ds.field("direction", &self.direction());
+        ds.field("hidden_size", &self.hidden_size());
         ds.finish()
     }
 }
-pub enum ResizeAttrsOffset {}
+pub enum LpNormalizationAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ResizeAttrs<'a> {
+pub struct LpNormalizationAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ResizeAttrs<'a> {
-    type Inner = ResizeAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for LpNormalizationAttrs<'a> {
+    type Inner = LpNormalizationAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -8154,66 +9188,51 @@ impl<'a> flatbuffers::Follow<'a> for ResizeAttrs<'a> {
     }
 }
 
-impl<'a> ResizeAttrs<'a> {
-    pub const VT_MODE: flatbuffers::VOffsetT = 4;
-    pub const VT_COORD_MODE: flatbuffers::VOffsetT = 6;
-    pub const VT_NEAREST_MODE: flatbuffers::VOffsetT = 8;
+impl<'a> LpNormalizationAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_P: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ResizeAttrs { _tab: table }
+        LpNormalizationAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args ResizeAttrsArgs,
-    ) -> flatbuffers::WIPOffset<ResizeAttrs<'bldr>> {
-        let mut builder = ResizeAttrsBuilder::new(_fbb);
-        builder.add_nearest_mode(args.nearest_mode);
-        builder.add_coord_mode(args.coord_mode);
-        builder.add_mode(args.mode);
+        args: &'args LpNormalizationAttrsArgs,
+    ) -> flatbuffers::WIPOffset<LpNormalizationAttrs<'bldr>> {
+        let mut builder = LpNormalizationAttrsBuilder::new(_fbb);
+        builder.add_p(args.p);
+        builder.add_axis(args.axis);
         builder.finish()
     }
 
     #[inline]
-    pub fn mode(&self) -> ResizeMode {
+    pub fn axis(&self) -> i32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<ResizeMode>(ResizeAttrs::VT_MODE, Some(ResizeMode::Nearest))
+                .get::<i32>(LpNormalizationAttrs::VT_AXIS, Some(0))
                 .unwrap()
         }
     }
+
     #[inline]
-    pub fn coord_mode(&self) -> CoordTransformMode {
+    pub fn p(&self) -> i32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<CoordTransformMode>(
-                    ResizeAttrs::VT_COORD_MODE,
-                    Some(CoordTransformMode::HalfPixel),
-                )
+                .get::<i32>(LpNormalizationAttrs::VT_P, Some(0))
                 .unwrap()
         }
     }
-    #[inline]
-    pub fn nearest_mode(&self) -> NearestMode {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<NearestMode>(ResizeAttrs::VT_NEAREST_MODE, Some(NearestMode::Floor))
-                .unwrap()
-        }
-    }
-}
-
-impl flatbuffers::Verifiable for ResizeAttrs<'_> {
+}
+
+impl flatbuffers::Verifiable for LpNormalizationAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -8221,90 +9240,71 @@ impl flatbuffers::Verifiable for ResizeAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<ResizeMode>("mode", Self::VT_MODE, false)?
-            .visit_field::<CoordTransformMode>("coord_mode", Self::VT_COORD_MODE, false)?
-            .visit_field::<NearestMode>("nearest_mode", Self::VT_NEAREST_MODE, false)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<i32>("p", Self::VT_P, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ResizeAttrsArgs {
-    pub mode: ResizeMode,
-    pub coord_mode: CoordTransformMode,
-    pub nearest_mode: NearestMode,
+pub struct LpNormalizationAttrsArgs {
+    pub axis: i32,
+    pub p: i32,
 }
-impl<'a> Default for ResizeAttrsArgs {
+impl<'a> Default for LpNormalizationAttrsArgs {
     #[inline]
     fn default() -> Self {
-        ResizeAttrsArgs {
-            mode: ResizeMode::Nearest,
-            coord_mode: CoordTransformMode::HalfPixel,
-            nearest_mode: NearestMode::Floor,
-        }
+        LpNormalizationAttrsArgs { axis: 0, p: 0 }
     }
 }
 
-pub struct ResizeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct LpNormalizationAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ResizeAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LpNormalizationAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_mode(&mut self, mode: ResizeMode) {
+    pub fn add_axis(&mut self, axis: i32) {
         self.fbb_
-            .push_slot::<ResizeMode>(ResizeAttrs::VT_MODE, mode, ResizeMode::Nearest);
-    }
-    #[inline]
-    pub fn add_coord_mode(&mut self, coord_mode: CoordTransformMode) {
-        self.fbb_.push_slot::<CoordTransformMode>(
-            ResizeAttrs::VT_COORD_MODE,
-            coord_mode,
-            CoordTransformMode::HalfPixel,
-        );
+            .push_slot::<i32>(LpNormalizationAttrs::VT_AXIS, axis, 0);
     }
     #[inline]
-    pub fn add_nearest_mode(&mut self, nearest_mode: NearestMode) {
-        self.fbb_.push_slot::<NearestMode>(
-            ResizeAttrs::VT_NEAREST_MODE,
-            nearest_mode,
-            NearestMode::Floor,
-        );
+    pub fn add_p(&mut self, p: i32) {
+        self.fbb_.push_slot::<i32>(LpNormalizationAttrs::VT_P, p, 0);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> ResizeAttrsBuilder<'a, 'b, A> {
+    ) -> LpNormalizationAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        ResizeAttrsBuilder {
+        LpNormalizationAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ResizeAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<LpNormalizationAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ResizeAttrs<'_> {
+impl core::fmt::Debug for LpNormalizationAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ResizeAttrs");
-        ds.field("mode", &self.mode());
-        ds.field("coord_mode", &self.coord_mode());
-        ds.field("nearest_mode", &self.nearest_mode());
+        let mut ds = f.debug_struct("LpNormalizationAttrs");
+        ds.field("axis", &self.axis());
+        ds.field("p", &self.p());
         ds.finish()
     }
 }
-pub enum ScatterElementsAttrsOffset {}
+pub enum LpPoolAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ScatterElementsAttrs<'a> {
+pub struct LpPoolAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ScatterElementsAttrs<'a> {
-    type Inner = ScatterElementsAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for LpPoolAttrs<'a> {
+    type Inner = LpPoolAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -8313,53 +9313,116 @@ impl<'a> flatbuffers::Follow<'a> for ScatterElementsAttrs<'a> {
     }
 }
 
-impl<'a> ScatterElementsAttrs<'a> {
-    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
-    pub const VT_REDUCTION: flatbuffers::VOffsetT = 6;
+impl<'a> LpPoolAttrs<'a> {
+    pub const VT_KERNEL_SIZE: flatbuffers::VOffsetT = 4;
+    pub const VT_AUTO_PAD: flatbuffers::VOffsetT = 6;
+    pub const VT_PADS: flatbuffers::VOffsetT = 8;
+    pub const VT_STRIDES: flatbuffers::VOffsetT = 10;
+    pub const VT_CEIL_MODE: flatbuffers::VOffsetT = 12;
+    pub const VT_P: flatbuffers::VOffsetT = 14;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ScatterElementsAttrs { _tab: table }
+        LpPoolAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args ScatterElementsAttrsArgs,
-    ) -> flatbuffers::WIPOffset<ScatterElementsAttrs<'bldr>> {
-        let mut builder = ScatterElementsAttrsBuilder::new(_fbb);
-        builder.add_axis(args.axis);
-        builder.add_reduction(args.reduction);
+        args: &'args LpPoolAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<LpPoolAttrs<'bldr>> {
+        let mut builder = LpPoolAttrsBuilder::new(_fbb);
+        builder.add_p(args.p);
+        if let Some(x) = args.strides {
+            builder.add_strides(x);
+        }
+        if let Some(x) = args.pads {
+            builder.add_pads(x);
+        }
+        if let Some(x) = args.kernel_size {
+            builder.add_kernel_size(x);
+        }
+        builder.add_ceil_mode(args.ceil_mode);
+        builder.add_auto_pad(args.auto_pad);
         builder.finish()
     }
 
     #[inline]
-    pub fn axis(&self) -> i32 {
+    pub fn kernel_size(&self) -> flatbuffers::Vector<'a, u32> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<i32>(ScatterElementsAttrs::VT_AXIS, Some(0))
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    LpPoolAttrs::VT_KERNEL_SIZE,
+                    None,
+                )
                 .unwrap()
         }
     }
+
     #[inline]
-    pub fn reduction(&self) -> ScatterReduction {
+    pub fn auto_pad(&self) -> AutoPad {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<ScatterReduction>(
-                    ScatterElementsAttrs::VT_REDUCTION,
-                    Some(ScatterReduction::None),
+                .get::<AutoPad>(LpPoolAttrs::VT_AUTO_PAD, Some(AutoPad::Same))
+                .unwrap()
+        }
+    }
+
+    #[inline]
+    pub fn pads(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    LpPoolAttrs::VT_PADS,
+                    None,
+                )
+        }
+    }
+
+    #[inline]
+    pub fn strides(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    LpPoolAttrs::VT_STRIDES,
+                    None,
                 )
+        }
+    }
+
+    #[inline]
+    pub fn ceil_mode(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(LpPoolAttrs::VT_CEIL_MODE, Some(false))
                 .unwrap()
         }
     }
+
+    #[inline]
+    pub fn p(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<i32>(LpPoolAttrs::VT_P, Some(0)).unwrap() }
+    }
 }
 
-impl flatbuffers::Verifiable for ScatterElementsAttrs<'_> {
+impl flatbuffers::Verifiable for LpPoolAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -8367,78 +9430,129 @@ impl flatbuffers::Verifiable for ScatterElementsAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
-            .visit_field::<ScatterReduction>("reduction", Self::VT_REDUCTION, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "kernel_size",
+                Self::VT_KERNEL_SIZE,
+                true,
+            )?
+            .visit_field::<AutoPad>("auto_pad", Self::VT_AUTO_PAD, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "pads",
+                Self::VT_PADS,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "strides",
+                Self::VT_STRIDES,
+                false,
+            )?
+            .visit_field::<bool>("ceil_mode", Self::VT_CEIL_MODE, false)?
+            .visit_field::<i32>("p", Self::VT_P, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ScatterElementsAttrsArgs {
-    pub axis: i32,
-    pub reduction: ScatterReduction,
+pub struct LpPoolAttrsArgs<'a> {
+    pub kernel_size: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub auto_pad: AutoPad,
+    pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub ceil_mode: bool,
+    pub p: i32,
 }
-impl<'a> Default for ScatterElementsAttrsArgs {
+impl<'a> Default for LpPoolAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        ScatterElementsAttrsArgs {
-            axis: 0,
-            reduction: ScatterReduction::None,
+        LpPoolAttrsArgs {
+            kernel_size: None, // required field
+            auto_pad: AutoPad::Same,
+            pads: None,
+            strides: None,
+            ceil_mode: false,
+            p: 0,
         }
     }
 }
 
-pub struct ScatterElementsAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct LpPoolAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ScatterElementsAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> LpPoolAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_axis(&mut self, axis: i32) {
+    pub fn add_kernel_size(
+        &mut self,
+        kernel_size: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            LpPoolAttrs::VT_KERNEL_SIZE,
+            kernel_size,
+        );
+    }
+    #[inline]
+    pub fn add_auto_pad(&mut self, auto_pad: AutoPad) {
         self.fbb_
-            .push_slot::<i32>(ScatterElementsAttrs::VT_AXIS, axis, 0);
+            .push_slot::<AutoPad>(LpPoolAttrs::VT_AUTO_PAD, auto_pad, AutoPad::Same);
     }
     #[inline]
-    pub fn add_reduction(&mut self, reduction: ScatterReduction) {
-        self.fbb_.push_slot::<ScatterReduction>(
-            ScatterElementsAttrs::VT_REDUCTION,
-            reduction,
-            ScatterReduction::None,
-        );
+    pub fn add_pads(&mut self, pads: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(LpPoolAttrs::VT_PADS, pads);
+    }
+    #[inline]
+    pub fn add_strides(&mut self, strides: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(LpPoolAttrs::VT_STRIDES, strides);
+    }
+    #[inline]
+    pub fn add_ceil_mode(&mut self, ceil_mode: bool) {
+        self.fbb_
+            .push_slot::<bool>(LpPoolAttrs::VT_CEIL_MODE, ceil_mode, false);
+    }
+    #[inline]
+    pub fn add_p(&mut self, p: i32) {
+        self.fbb_.push_slot::<i32>(LpPoolAttrs::VT_P, p, 0);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> ScatterElementsAttrsBuilder<'a, 'b, A> {
+    ) -> LpPoolAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        ScatterElementsAttrsBuilder {
+        LpPoolAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ScatterElementsAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<LpPoolAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
+        self.fbb_
+            .required(o, LpPoolAttrs::VT_KERNEL_SIZE, "kernel_size");
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ScatterElementsAttrs<'_> {
+impl core::fmt::Debug for LpPoolAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ScatterElementsAttrs");
-        ds.field("axis", &self.axis());
-        ds.field("reduction", &self.reduction());
+        let mut ds = f.debug_struct("LpPoolAttrs");
+        ds.field("kernel_size", &self.kernel_size());
+        ds.field("auto_pad", &self.auto_pad());
+        ds.field("pads", &self.pads());
+        ds.field("strides", &self.strides());
+        ds.field("ceil_mode", &self.ceil_mode());
+        ds.field("p", &self.p());
         ds.finish()
     }
 }
-pub enum ScatterNDAttrsOffset {}
+pub enum MatMulNBitsAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ScatterNDAttrs<'a> {
+pub struct MatMulNBitsAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ScatterNDAttrs<'a> {
-    type Inner = ScatterNDAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for MatMulNBitsAttrs<'a> {
+    type Inner = MatMulNBitsAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -8447,37 +9561,65 @@ impl<'a> flatbuffers::Follow<'a> for ScatterNDAttrs<'a> {
     }
 }
 
-impl<'a> ScatterNDAttrs<'a> {
-    pub const VT_REDUCTION: flatbuffers::VOffsetT = 4;
+impl<'a> MatMulNBitsAttrs<'a> {
+    pub const VT_BITS: flatbuffers::VOffsetT = 4;
+    pub const VT_BLOCK_SIZE: flatbuffers::VOffsetT = 6;
+    pub const VT_ACCURACY_LEVEL: flatbuffers::VOffsetT = 8;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ScatterNDAttrs { _tab: table }
+        MatMulNBitsAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args ScatterNDAttrsArgs,
-    ) -> flatbuffers::WIPOffset<ScatterNDAttrs<'bldr>> {
-        let mut builder = ScatterNDAttrsBuilder::new(_fbb);
-        builder.add_reduction(args.reduction);
+        args: &'args MatMulNBitsAttrsArgs,
+    ) -> flatbuffers::WIPOffset<MatMulNBitsAttrs<'bldr>> {
+        let mut builder = MatMulNBitsAttrsBuilder::new(_fbb);
+        builder.add_block_size(args.block_size);
+        builder.add_accuracy_level(args.accuracy_level);
+        builder.add_bits(args.bits);
         builder.finish()
     }
 
     #[inline]
-    pub fn reduction(&self) -> ScatterReduction {
+    pub fn bits(&self) -> u8 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<ScatterReduction>(ScatterNDAttrs::VT_REDUCTION, Some(ScatterReduction::None))
+                .get::<u8>(MatMulNBitsAttrs::VT_BITS, Some(0))
+                .unwrap()
+        }
+    }
+
+    #[inline]
+    pub fn block_size(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(MatMulNBitsAttrs::VT_BLOCK_SIZE, Some(0))
+                .unwrap()
+        }
+    }
+
+    #[inline]
+    pub fn accuracy_level(&self) -> u8 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u8>(MatMulNBitsAttrs::VT_ACCURACY_LEVEL, Some(0))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for ScatterNDAttrs<'_> {
+impl flatbuffers::Verifiable for MatMulNBitsAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -8485,69 +9627,84 @@ impl flatbuffers::Verifiable for ScatterNDAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<ScatterReduction>("reduction", Self::VT_REDUCTION, false)?
+            .visit_field::<u8>("bits", Self::VT_BITS, false)?
+            .visit_field::<u32>("block_size", Self::VT_BLOCK_SIZE, false)?
+            .visit_field::<u8>("accuracy_level", Self::VT_ACCURACY_LEVEL, false)?
             .finish();
         Ok(())
     }
 }
-pub struct ScatterNDAttrsArgs {
-    pub reduction: ScatterReduction,
+pub struct MatMulNBitsAttrsArgs {
+    pub bits: u8,
+    pub block_size: u32,
+    pub accuracy_level: u8,
 }
-impl<'a> Default for ScatterNDAttrsArgs {
+impl<'a> Default for MatMulNBitsAttrsArgs {
     #[inline]
     fn default() -> Self {
-        ScatterNDAttrsArgs {
-            reduction: ScatterReduction::None,
+        MatMulNBitsAttrsArgs {
+            bits: 0,
+            block_size: 0,
+            accuracy_level: 0,
         }
     }
 }
 
-pub struct ScatterNDAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct MatMulNBitsAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ScatterNDAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MatMulNBitsAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_reduction(&mut self, reduction: ScatterReduction) {
-        self.fbb_.push_slot::<ScatterReduction>(
-            ScatterNDAttrs::VT_REDUCTION,
-            reduction,
-            ScatterReduction::None,
-        );
+    pub fn add_bits(&mut self, bits: u8) {
+        self.fbb_
+            .push_slot::<u8>(MatMulNBitsAttrs::VT_BITS, bits, 0);
+    }
+    #[inline]
+    pub fn add_block_size(&mut self, block_size: u32) {
+        self.fbb_
+            .push_slot::<u32>(MatMulNBitsAttrs::VT_BLOCK_SIZE, block_size, 0);
+    }
+    #[inline]
+    pub fn add_accuracy_level(&mut self, accuracy_level: u8) {
+        self.fbb_
+            .push_slot::<u8>(MatMulNBitsAttrs::VT_ACCURACY_LEVEL, accuracy_level, 0);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> ScatterNDAttrsBuilder<'a, 'b, A> {
+    ) -> MatMulNBitsAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        ScatterNDAttrsBuilder {
+        MatMulNBitsAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ScatterNDAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<MatMulNBitsAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ScatterNDAttrs<'_> {
+impl core::fmt::Debug for MatMulNBitsAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ScatterNDAttrs");
-        ds.field("reduction", &self.reduction());
+        let mut ds = f.debug_struct("MatMulNBitsAttrs");
+        ds.field("bits", &self.bits());
+        ds.field("block_size", &self.block_size());
+        ds.field("accuracy_level", &self.accuracy_level());
         ds.finish()
     }
 }
-pub enum SequenceEmptyAttrsOffset {}
+pub enum MaxPoolAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct SequenceEmptyAttrs<'a> {
+pub struct MaxPoolAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for SequenceEmptyAttrs<'a> {
-    type Inner = SequenceEmptyAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for MaxPoolAttrs<'a> {
+    type Inner = MaxPoolAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -8556,38 +9713,102 @@ impl<'a> flatbuffers::Follow<'a> for SequenceEmptyAttrs<'a> {
     }
 }
 
-impl<'a> SequenceEmptyAttrs<'a> {
-    pub const VT_DTYPE: flatbuffers::VOffsetT = 4;
+impl<'a> MaxPoolAttrs<'a> {
+    pub const VT_KERNEL_SIZE: flatbuffers::VOffsetT = 4;
+    pub const VT_AUTO_PAD: flatbuffers::VOffsetT = 6;
+    pub const VT_PADS: flatbuffers::VOffsetT = 8;
+    pub const VT_STRIDES: flatbuffers::VOffsetT = 10;
+    pub const VT_CEIL_MODE: flatbuffers::VOffsetT = 12;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        SequenceEmptyAttrs { _tab: table }
+        MaxPoolAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args SequenceEmptyAttrsArgs,
-    ) -> flatbuffers::WIPOffset<SequenceEmptyAttrs<'bldr>> {
-        let mut builder = SequenceEmptyAttrsBuilder::new(_fbb);
-        if let Some(x) = args.dtype {
-            builder.add_dtype(x);
+        args: &'args MaxPoolAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<MaxPoolAttrs<'bldr>> {
+        let mut builder = MaxPoolAttrsBuilder::new(_fbb);
+        if let Some(x) = args.strides {
+            builder.add_strides(x);
+        }
+        if let Some(x) = args.pads {
+            builder.add_pads(x);
+        }
+        if let Some(x) = args.kernel_size {
+            builder.add_kernel_size(x);
         }
+        builder.add_ceil_mode(args.ceil_mode);
+        builder.add_auto_pad(args.auto_pad);
         builder.finish()
     }
 
     #[inline]
-    pub fn dtype(&self) -> Option<DataType> {
+    pub fn kernel_size(&self) -> flatbuffers::Vector<'a, u32> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<DataType>(SequenceEmptyAttrs::VT_DTYPE, None)
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    MaxPoolAttrs::VT_KERNEL_SIZE,
+                    None,
+                )
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn auto_pad(&self) -> AutoPad {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<AutoPad>(MaxPoolAttrs::VT_AUTO_PAD, Some(AutoPad::Same))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn pads(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    MaxPoolAttrs::VT_PADS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn strides(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    MaxPoolAttrs::VT_STRIDES,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn ceil_mode(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(MaxPoolAttrs::VT_CEIL_MODE, Some(false))
+                .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for SequenceEmptyAttrs<'_> {
+impl flatbuffers::Verifiable for MaxPoolAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -8595,64 +9816,121 @@ impl flatbuffers::Verifiable for SequenceEmptyAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<DataType>("dtype", Self::VT_DTYPE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "kernel_size",
+                Self::VT_KERNEL_SIZE,
+                true,
+            )?
+            .visit_field::<AutoPad>("auto_pad", Self::VT_AUTO_PAD, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "pads",
+                Self::VT_PADS,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "strides",
+                Self::VT_STRIDES,
+                false,
+            )?
+            .visit_field::<bool>("ceil_mode", Self::VT_CEIL_MODE, false)?
             .finish();
         Ok(())
     }
 }
-pub struct SequenceEmptyAttrsArgs {
-    pub dtype: Option<DataType>,
+pub struct MaxPoolAttrsArgs<'a> {
+    pub kernel_size: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub auto_pad: AutoPad,
+    pub pads: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub strides: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub ceil_mode: bool,
 }
-impl<'a> Default for SequenceEmptyAttrsArgs {
+impl<'a> Default for MaxPoolAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        SequenceEmptyAttrsArgs { dtype: None }
+        MaxPoolAttrsArgs {
+            kernel_size: None, // required field
+            auto_pad: AutoPad::Same,
+            pads: None,
+            strides: None,
+            ceil_mode: false,
+        }
     }
 }
 
-pub struct SequenceEmptyAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct MaxPoolAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SequenceEmptyAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MaxPoolAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_dtype(&mut self, dtype: DataType) {
+    pub fn add_kernel_size(
+        &mut self,
+        kernel_size: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            MaxPoolAttrs::VT_KERNEL_SIZE,
+            kernel_size,
+        );
+    }
+    #[inline]
+    pub fn add_auto_pad(&mut self, auto_pad: AutoPad) {
         self.fbb_
-            .push_slot_always::<DataType>(SequenceEmptyAttrs::VT_DTYPE, dtype);
+            .push_slot::<AutoPad>(MaxPoolAttrs::VT_AUTO_PAD, auto_pad, AutoPad::Same);
+    }
+    #[inline]
+    pub fn add_pads(&mut self, pads: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(MaxPoolAttrs::VT_PADS, pads);
+    }
+    #[inline]
+    pub fn add_strides(&mut self, strides: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(MaxPoolAttrs::VT_STRIDES, strides);
+    }
+    #[inline]
+    pub fn add_ceil_mode(&mut self, ceil_mode: bool) {
+        self.fbb_
+            .push_slot::<bool>(MaxPoolAttrs::VT_CEIL_MODE, ceil_mode, false);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> SequenceEmptyAttrsBuilder<'a, 'b, A> {
+    ) -> MaxPoolAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        SequenceEmptyAttrsBuilder {
+        MaxPoolAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<SequenceEmptyAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<MaxPoolAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
+        self.fbb_
+            .required(o, MaxPoolAttrs::VT_KERNEL_SIZE, "kernel_size");
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for SequenceEmptyAttrs<'_> {
+impl core::fmt::Debug for MaxPoolAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("SequenceEmptyAttrs");
-        ds.field("dtype", &self.dtype());
+        let mut ds = f.debug_struct("MaxPoolAttrs");
+        ds.field("kernel_size", &self.kernel_size());
+        ds.field("auto_pad", &self.auto_pad());
+        ds.field("pads", &self.pads());
+        ds.field("strides", &self.strides());
+        ds.field("ceil_mode", &self.ceil_mode());
         ds.finish()
     }
 }
-pub enum ShapeAttrsOffset {}
+pub enum MeanVarianceNormalizationAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct ShapeAttrs<'a> {
+pub struct MeanVarianceNormalizationAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for ShapeAttrs<'a> {
-    type Inner = ShapeAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for MeanVarianceNormalizationAttrs<'a> {
+    type Inner = MeanVarianceNormalizationAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -8661,46 +9939,41 @@ impl<'a> flatbuffers::Follow<'a> for ShapeAttrs<'a> {
     }
 }
 
-impl<'a> ShapeAttrs<'a> {
-    pub const VT_START: flatbuffers::VOffsetT = 4;
-    pub const VT_END: flatbuffers::VOffsetT = 6;
+impl<'a> MeanVarianceNormalizationAttrs<'a> {
+    pub const VT_AXES: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        ShapeAttrs { _tab: table }
+        MeanVarianceNormalizationAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args ShapeAttrsArgs,
-    ) -> flatbuffers::WIPOffset<ShapeAttrs<'bldr>> {
-        let mut builder = ShapeAttrsBuilder::new(_fbb);
-        if let Some(x) = args.end {
-            builder.add_end(x);
-        }
-        if let Some(x) = args.start {
-            builder.add_start(x);
+        args: &'args MeanVarianceNormalizationAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<MeanVarianceNormalizationAttrs<'bldr>> {
+        let mut builder = MeanVarianceNormalizationAttrsBuilder::new(_fbb);
+        if let Some(x) = args.axes {
+            builder.add_axes(x);
         }
         builder.finish()
     }
 
     #[inline]
-    pub fn start(&self) -> Option<i32> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe { self._tab.get::<i32>(ShapeAttrs::VT_START, None) }
-    }
-    #[inline]
-    pub fn end(&self) -> Option<i32> {
+    pub fn axes(&self) -> Option<flatbuffers::Vector<'a, i32>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<i32>(ShapeAttrs::VT_END, None) }
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
+                    MeanVarianceNormalizationAttrs::VT_AXES,
+                    None,
+                )
+        }
     }
 }
 
-impl flatbuffers::Verifiable for ShapeAttrs<'_> {
+impl flatbuffers::Verifiable for MeanVarianceNormalizationAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -8708,74 +9981,70 @@ impl flatbuffers::Verifiable for ShapeAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("start", Self::VT_START, false)?
-            .visit_field::<i32>("end", Self::VT_END, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i32>>>(
+                "axes",
+                Self::VT_AXES,
+                false,
+            )?
             .finish();
         Ok(())
     }
 }
-pub struct ShapeAttrsArgs {
-    pub start: Option<i32>,
-    pub end: Option<i32>,
+pub struct MeanVarianceNormalizationAttrsArgs<'a> {
+    pub axes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i32>>>,
 }
-impl<'a> Default for ShapeAttrsArgs {
+impl<'a> Default for MeanVarianceNormalizationAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        ShapeAttrsArgs {
-            start: None,
-            end: None,
-        }
+        MeanVarianceNormalizationAttrsArgs { axes: None }
     }
 }
 
-pub struct ShapeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct MeanVarianceNormalizationAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ShapeAttrsBuilder<'a, 'b, A> {
-    #[inline]
-    pub fn add_start(&mut self, start: i32) {
-        self.fbb_
-            .push_slot_always::<i32>(ShapeAttrs::VT_START, start);
-    }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MeanVarianceNormalizationAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_end(&mut self, end: i32) {
-        self.fbb_.push_slot_always::<i32>(ShapeAttrs::VT_END, end);
+    pub fn add_axes(&mut self, axes: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i32>>) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            MeanVarianceNormalizationAttrs::VT_AXES,
+            axes,
+        );
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> ShapeAttrsBuilder<'a, 'b, A> {
+    ) -> MeanVarianceNormalizationAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        ShapeAttrsBuilder {
+        MeanVarianceNormalizationAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<ShapeAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<MeanVarianceNormalizationAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for ShapeAttrs<'_> {
+impl core::fmt::Debug for MeanVarianceNormalizationAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("ShapeAttrs");
-        ds.field("start", &self.start());
-        ds.field("end", &self.end());
+        let mut ds = f.debug_struct("MeanVarianceNormalizationAttrs");
+        ds.field("axes", &self.axes());
         ds.finish()
     }
 }
-pub enum SoftmaxAttrsOffset {}
+pub enum ModAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct SoftmaxAttrs<'a> {
+pub struct ModAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for SoftmaxAttrs<'a> {
-    type Inner = SoftmaxAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for ModAttrs<'a> {
+    type Inner = ModAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -8784,37 +10053,37 @@ impl<'a> flatbuffers::Follow<'a> for SoftmaxAttrs<'a> {
     }
 }
 
-impl<'a> SoftmaxAttrs<'a> {
-    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+impl<'a> ModAttrs<'a> {
+    pub const VT_FMOD: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        SoftmaxAttrs { _tab: table }
+        ModAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args SoftmaxAttrsArgs,
-    ) -> flatbuffers::WIPOffset<SoftmaxAttrs<'bldr>> {
-        let mut builder = SoftmaxAttrsBuilder::new(_fbb);
-        builder.add_axis(args.axis);
+        args: &'args ModAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ModAttrs<'bldr>> {
+        let mut builder = ModAttrsBuilder::new(_fbb);
+        builder.add_fmod(args.fmod);
         builder.finish()
     }
 
     #[inline]
-    pub fn axis(&self) -> i32 {
+    pub fn fmod(&self) -> bool {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<i32>(SoftmaxAttrs::VT_AXIS, Some(0))
+                .get::<bool>(ModAttrs::VT_FMOD, Some(false))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for SoftmaxAttrs<'_> {
+impl flatbuffers::Verifiable for ModAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -8822,63 +10091,61 @@ impl flatbuffers::Verifiable for SoftmaxAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<bool>("fmod", Self::VT_FMOD, false)?
             .finish();
         Ok(())
     }
 }
-pub struct SoftmaxAttrsArgs {
-    pub axis: i32,
+pub struct ModAttrsArgs {
+    pub fmod: bool,
 }
-impl<'a> Default for SoftmaxAttrsArgs {
+impl<'a> Default for ModAttrsArgs {
     #[inline]
     fn default() -> Self {
-        SoftmaxAttrsArgs { axis: 0 }
+        ModAttrsArgs { fmod: false }
     }
 }
 
-pub struct SoftmaxAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct ModAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SoftmaxAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ModAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_.push_slot::<i32>(SoftmaxAttrs::VT_AXIS, axis, 0);
+    pub fn add_fmod(&mut self, fmod: bool) {
+        self.fbb_.push_slot::<bool>(ModAttrs::VT_FMOD, fmod, false);
     }
     #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> SoftmaxAttrsBuilder<'a, 'b, A> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ModAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        SoftmaxAttrsBuilder {
+        ModAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<SoftmaxAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<ModAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for SoftmaxAttrs<'_> {
+impl core::fmt::Debug for ModAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("SoftmaxAttrs");
-        ds.field("axis", &self.axis());
+        let mut ds = f.debug_struct("ModAttrs");
+        ds.field("fmod", &self.fmod());
         ds.finish()
     }
 }
-pub enum SplitAttrsOffset {}
+pub enum NonMaxSuppressionAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct SplitAttrs<'a> {
+pub struct NonMaxSuppressionAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for SplitAttrs<'a> {
-    type Inner = SplitAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for NonMaxSuppressionAttrs<'a> {
+    type Inner = NonMaxSuppressionAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -8887,44 +10154,40 @@ impl<'a> flatbuffers::Follow<'a> for SplitAttrs<'a> {
     }
 }
 
-impl<'a> SplitAttrs<'a> {
-    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
-    pub const VT_NUM_OUTPUTS: flatbuffers::VOffsetT = 6;
+impl<'a> NonMaxSuppressionAttrs<'a> {
+    pub const VT_BOX_ORDER: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        SplitAttrs { _tab: table }
+        NonMaxSuppressionAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args SplitAttrsArgs,
-    ) -> flatbuffers::WIPOffset<SplitAttrs<'bldr>> {
-        let mut builder = SplitAttrsBuilder::new(_fbb);
-        if let Some(x) = args.num_outputs {
-            builder.add_num_outputs(x);
-        }
-        builder.add_axis(args.axis);
+        args: &'args NonMaxSuppressionAttrsArgs,
+    ) -> flatbuffers::WIPOffset<NonMaxSuppressionAttrs<'bldr>> {
+        let mut builder = NonMaxSuppressionAttrsBuilder::new(_fbb);
+        builder.add_box_order(args.box_order);
         builder.finish()
     }
 
     #[inline]
-    pub fn axis(&self) -> i32 {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe { self._tab.get::<i32>(SplitAttrs::VT_AXIS, Some(0)).unwrap() }
-    }
-    #[inline]
-    pub fn num_outputs(&self) -> Option<i32> {
+    pub fn box_order(&self) -> NMSBoxOrder {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe { self._tab.get::<i32>(SplitAttrs::VT_NUM_OUTPUTS, None) }
+        unsafe {
+            self._tab
+                .get::<NMSBoxOrder>(
+                    NonMaxSuppressionAttrs::VT_BOX_ORDER,
+                    Some(NMSBoxOrder::TopLeftBottomRight),
+                )
+                .unwrap()
+        }
     }
 }
 
-impl flatbuffers::Verifiable for SplitAttrs<'_> {
+impl flatbuffers::Verifiable for NonMaxSuppressionAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -8932,74 +10195,69 @@ impl flatbuffers::Verifiable for SplitAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
-            .visit_field::<i32>("num_outputs", Self::VT_NUM_OUTPUTS, false)?
+            .visit_field::<NMSBoxOrder>("box_order", Self::VT_BOX_ORDER, false)?
             .finish();
         Ok(())
     }
 }
-pub struct SplitAttrsArgs {
-    pub axis: i32,
-    pub num_outputs: Option<i32>,
+pub struct NonMaxSuppressionAttrsArgs {
+    pub box_order: NMSBoxOrder,
 }
-impl<'a> Default for SplitAttrsArgs {
+impl<'a> Default for NonMaxSuppressionAttrsArgs {
     #[inline]
     fn default() -> Self {
-        SplitAttrsArgs {
-            axis: 0,
-            num_outputs: None,
+        NonMaxSuppressionAttrsArgs {
+            box_order: NMSBoxOrder::TopLeftBottomRight,
         }
     }
 }
 
-pub struct SplitAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct NonMaxSuppressionAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SplitAttrsBuilder<'a, 'b, A> {
-    #[inline]
-    pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_.push_slot::<i32>(SplitAttrs::VT_AXIS, axis, 0);
-    }
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> NonMaxSuppressionAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_num_outputs(&mut self, num_outputs: i32) {
-        self.fbb_
-            .push_slot_always::<i32>(SplitAttrs::VT_NUM_OUTPUTS, num_outputs);
+    pub fn add_box_order(&mut self, box_order: NMSBoxOrder) {
+        self.fbb_.push_slot::<NMSBoxOrder>(
+            NonMaxSuppressionAttrs::VT_BOX_ORDER,
+            box_order,
+            NMSBoxOrder::TopLeftBottomRight,
+        );
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> SplitAttrsBuilder<'a, 'b, A> {
+    ) -> NonMaxSuppressionAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        SplitAttrsBuilder {
+        NonMaxSuppressionAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<SplitAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<NonMaxSuppressionAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for SplitAttrs<'_> {
+impl core::fmt::Debug for NonMaxSuppressionAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("SplitAttrs");
-        ds.field("axis", &self.axis());
-        ds.field("num_outputs", &self.num_outputs());
+        let mut ds = f.debug_struct("NonMaxSuppressionAttrs");
+        ds.field("box_order", &self.box_order());
         ds.finish()
     }
 }
-pub enum SplitToSequenceAttrsOffset {}
+pub enum OneHotAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct SplitToSequenceAttrs<'a> {
+pub struct OneHotAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for SplitToSequenceAttrs<'a> {
-    type Inner = SplitToSequenceAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for OneHotAttrs<'a> {
+    type Inner = OneHotAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -9008,22 +10266,20 @@ impl<'a> flatbuffers::Follow<'a> for SplitToSequenceAttrs<'a> {
     }
 }
 
-impl<'a> SplitToSequenceAttrs<'a> {
+impl<'a> OneHotAttrs<'a> {
     pub const VT_AXIS: flatbuffers::VOffsetT = 4;
-    pub const VT_KEEP_DIMS: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        SplitToSequenceAttrs { _tab: table }
+        OneHotAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args SplitToSequenceAttrsArgs,
-    ) -> flatbuffers::WIPOffset<SplitToSequenceAttrs<'bldr>> {
-        let mut builder = SplitToSequenceAttrsBuilder::new(_fbb);
+        args: &'args OneHotAttrsArgs,
+    ) -> flatbuffers::WIPOffset<OneHotAttrs<'bldr>> {
+        let mut builder = OneHotAttrsBuilder::new(_fbb);
         builder.add_axis(args.axis);
-        builder.add_keep_dims(args.keep_dims);
         builder.finish()
     }
 
@@ -9032,26 +10288,11 @@ impl<'a> SplitToSequenceAttrs<'a> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<i32>(SplitToSequenceAttrs::VT_AXIS, Some(0))
-                .unwrap()
-        }
-    }
-    #[inline]
-    pub fn keep_dims(&self) -> bool {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<bool>(SplitToSequenceAttrs::VT_KEEP_DIMS, Some(true))
-                .unwrap()
-        }
+        unsafe { self._tab.get::<i32>(OneHotAttrs::VT_AXIS, Some(0)).unwrap() }
     }
 }
 
-impl flatbuffers::Verifiable for SplitToSequenceAttrs<'_> {
+impl flatbuffers::Verifiable for OneHotAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -9060,74 +10301,62 @@ impl flatbuffers::Verifiable for SplitToSequenceAttrs<'_> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
             .visit_field::<i32>("axis", Self::VT_AXIS, false)?
-            .visit_field::<bool>("keep_dims", Self::VT_KEEP_DIMS, false)?
             .finish();
         Ok(())
     }
 }
-pub struct SplitToSequenceAttrsArgs {
+pub struct OneHotAttrsArgs {
     pub axis: i32,
-    pub keep_dims: bool,
 }
-impl<'a> Default for SplitToSequenceAttrsArgs {
+impl<'a> Default for OneHotAttrsArgs {
     #[inline]
     fn default() -> Self {
-        SplitToSequenceAttrsArgs {
-            axis: 0,
-            keep_dims: true,
-        }
+        OneHotAttrsArgs { axis: 0 }
     }
 }
 
-pub struct SplitToSequenceAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct OneHotAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SplitToSequenceAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> OneHotAttrsBuilder<'a, 'b, A> {
     #[inline]
     pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_
-            .push_slot::<i32>(SplitToSequenceAttrs::VT_AXIS, axis, 0);
-    }
-    #[inline]
-    pub fn add_keep_dims(&mut self, keep_dims: bool) {
-        self.fbb_
-            .push_slot::<bool>(SplitToSequenceAttrs::VT_KEEP_DIMS, keep_dims, true);
+        self.fbb_.push_slot::<i32>(OneHotAttrs::VT_AXIS, axis, 0);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> SplitToSequenceAttrsBuilder<'a, 'b, A> {
+    ) -> OneHotAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        SplitToSequenceAttrsBuilder {
+        OneHotAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<SplitToSequenceAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<OneHotAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for SplitToSequenceAttrs<'_> {
+impl core::fmt::Debug for OneHotAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("SplitToSequenceAttrs");
+        let mut ds = f.debug_struct("OneHotAttrs");
         ds.field("axis", &self.axis());
-        ds.field("keep_dims", &self.keep_dims());
         ds.finish()
     }
 }
-pub enum STFTAttrsOffset {}
+pub enum PadAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct STFTAttrs<'a> {
+pub struct PadAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for STFTAttrs<'a> {
-    type Inner = STFTAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for PadAttrs<'a> {
+    type Inner = PadAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -9136,37 +10365,37 @@ impl<'a> flatbuffers::Follow<'a> for STFTAttrs<'a> {
     }
 }
 
-impl<'a> STFTAttrs<'a> {
-    pub const VT_ONESIDED: flatbuffers::VOffsetT = 4;
+impl<'a> PadAttrs<'a> {
+    pub const VT_MODE: flatbuffers::VOffsetT = 4;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        STFTAttrs { _tab: table }
+        PadAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args STFTAttrsArgs,
-    ) -> flatbuffers::WIPOffset<STFTAttrs<'bldr>> {
-        let mut builder = STFTAttrsBuilder::new(_fbb);
-        builder.add_onesided(args.onesided);
+        args: &'args PadAttrsArgs,
+    ) -> flatbuffers::WIPOffset<PadAttrs<'bldr>> {
+        let mut builder = PadAttrsBuilder::new(_fbb);
+        builder.add_mode(args.mode);
         builder.finish()
     }
 
     #[inline]
-    pub fn onesided(&self) -> bool {
+    pub fn mode(&self) -> PadMode {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<bool>(STFTAttrs::VT_ONESIDED, Some(true))
+                .get::<PadMode>(PadAttrs::VT_MODE, Some(PadMode::Constant))
                 .unwrap()
         }
     }
 }
 
-impl flatbuffers::Verifiable for STFTAttrs<'_> {
+impl flatbuffers::Verifiable for PadAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -9174,62 +10403,64 @@ impl flatbuffers::Verifiable for STFTAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<bool>("onesided", Self::VT_ONESIDED, false)?
+            .visit_field::<PadMode>("mode", Self::VT_MODE, false)?
             .finish();
         Ok(())
     }
 }
-pub struct STFTAttrsArgs {
-    pub onesided: bool,
+pub struct PadAttrsArgs {
+    pub mode: PadMode,
 }
-impl<'a> Default for STFTAttrsArgs {
+impl<'a> Default for PadAttrsArgs {
     #[inline]
     fn default() -> Self {
-        STFTAttrsArgs { onesided: true }
+        PadAttrsArgs {
+            mode: PadMode::Constant,
+        }
     }
 }
 
-pub struct STFTAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct PadAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> STFTAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> PadAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_onesided(&mut self, onesided: bool) {
+    pub fn add_mode(&mut self, mode: PadMode) {
         self.fbb_
-            .push_slot::<bool>(STFTAttrs::VT_ONESIDED, onesided, true);
+            .push_slot::<PadMode>(PadAttrs::VT_MODE, mode, PadMode::Constant);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> STFTAttrsBuilder<'a, 'b, A> {
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> PadAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        STFTAttrsBuilder {
+        PadAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<STFTAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<PadAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for STFTAttrs<'_> {
+impl core::fmt::Debug for PadAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("STFTAttrs");
-        ds.field("onesided", &self.onesided());
+        let mut ds = f.debug_struct("PadAttrs");
+        ds.field("mode", &self.mode());
         ds.finish()
     }
 }
-pub enum TopKAttrsOffset {}
+pub enum QuantizeLinearAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct TopKAttrs<'a> {
+pub struct QuantizeLinearAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for TopKAttrs<'a> {
-    type Inner = TopKAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for QuantizeLinearAttrs<'a> {
+    type Inner = QuantizeLinearAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -9238,59 +10469,51 @@ impl<'a> flatbuffers::Follow<'a> for TopKAttrs<'a> {
     }
 }
 
-impl<'a> TopKAttrs<'a> {
+impl<'a> QuantizeLinearAttrs<'a> {
     pub const VT_AXIS: flatbuffers::VOffsetT = 4;
-    pub const VT_LARGEST: flatbuffers::VOffsetT = 6;
-    pub const VT_SORTED: flatbuffers::VOffsetT = 8;
+    pub const VT_OUTPUT_DTYPE: flatbuffers::VOffsetT = 6;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        TopKAttrs { _tab: table }
+        QuantizeLinearAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args TopKAttrsArgs,
-    ) -> flatbuffers::WIPOffset<TopKAttrs<'bldr>> {
-        let mut builder = TopKAttrsBuilder::new(_fbb);
+        args: &'args QuantizeLinearAttrsArgs,
+    ) -> flatbuffers::WIPOffset<QuantizeLinearAttrs<'bldr>> {
+        let mut builder = QuantizeLinearAttrsBuilder::new(_fbb);
         builder.add_axis(args.axis);
-        builder.add_sorted(args.sorted);
-        builder.add_largest(args.largest);
+        if let Some(x) = args.output_dtype {
+            builder.add_output_dtype(x);
+        }
         builder.finish()
     }
 
     #[inline]
     pub fn axis(&self) -> i32 {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe { self._tab.get::<i32>(TopKAttrs::VT_AXIS, Some(0)).unwrap() }
-    }
-    #[inline]
-    pub fn largest(&self) -> bool {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<bool>(TopKAttrs::VT_LARGEST, Some(false))
+                .get::<i32>(QuantizeLinearAttrs::VT_AXIS, Some(0))
                 .unwrap()
         }
     }
     #[inline]
-    pub fn sorted(&self) -> bool {
+    pub fn output_dtype(&self) -> Option<DataType> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<bool>(TopKAttrs::VT_SORTED, Some(false))
-                .unwrap()
+                .get::<DataType>(QuantizeLinearAttrs::VT_OUTPUT_DTYPE, None)
         }
     }
 }
 
-impl flatbuffers::Verifiable for TopKAttrs<'_> {
+impl flatbuffers::Verifiable for QuantizeLinearAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -9299,80 +10522,74 @@ impl flatbuffers::Verifiable for TopKAttrs<'_> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
             .visit_field::<i32>("axis", Self::VT_AXIS, false)?
-            .visit_field::<bool>("largest", Self::VT_LARGEST, false)?
-            .visit_field::<bool>("sorted", Self::VT_SORTED, false)?
+            .visit_field::<DataType>("output_dtype", Self::VT_OUTPUT_DTYPE, false)?
             .finish();
         Ok(())
     }
 }
-pub struct TopKAttrsArgs {
+pub struct QuantizeLinearAttrsArgs {
     pub axis: i32,
-    pub largest: bool,
-    pub sorted: bool,
+    pub output_dtype: Option<DataType>,
 }
-impl<'a> Default for TopKAttrsArgs {
+impl<'a> Default for QuantizeLinearAttrsArgs {
     #[inline]
     fn default() -> Self {
-        TopKAttrsArgs {
+        QuantizeLinearAttrsArgs {
             axis: 0,
-            largest: false,
-            sorted: false,
+            output_dtype: None,
         }
     }
 }
 
-pub struct TopKAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct QuantizeLinearAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TopKAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> QuantizeLinearAttrsBuilder<'a, 'b, A> {
     #[inline]
     pub fn add_axis(&mut self, axis: i32) {
-        self.fbb_.push_slot::<i32>(TopKAttrs::VT_AXIS, axis, 0);
-    }
-    #[inline]
-    pub fn add_largest(&mut self, largest: bool) {
         self.fbb_
-            .push_slot::<bool>(TopKAttrs::VT_LARGEST, largest, false);
+            .push_slot::<i32>(QuantizeLinearAttrs::VT_AXIS, axis, 0);
     }
     #[inline]
-    pub fn add_sorted(&mut self, sorted: bool) {
+    pub fn add_output_dtype(&mut self, output_dtype: DataType) {
         self.fbb_
-            .push_slot::<bool>(TopKAttrs::VT_SORTED, sorted, false);
+            .push_slot_always::<DataType>(QuantizeLinearAttrs::VT_OUTPUT_DTYPE, output_dtype);
     }
     #[inline]
-    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> TopKAttrsBuilder<'a, 'b, A> {
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> QuantizeLinearAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        TopKAttrsBuilder {
+        QuantizeLinearAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<TopKAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<QuantizeLinearAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for TopKAttrs<'_> {
+impl core::fmt::Debug for QuantizeLinearAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("TopKAttrs");
+        let mut ds = f.debug_struct("QuantizeLinearAttrs");
         ds.field("axis", &self.axis());
-        ds.field("largest", &self.largest());
-        ds.field("sorted", &self.sorted());
+        ds.field("output_dtype", &self.output_dtype());
         ds.finish()
     }
 }
-pub enum TransposeAttrsOffset {}
+pub enum RandomNormalAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct TransposeAttrs<'a> {
+pub struct RandomNormalAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for TransposeAttrs<'a> {
-    type Inner = TransposeAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for RandomNormalAttrs<'a> {
+    type Inner = RandomNormalAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -9381,41 +10598,78 @@ impl<'a> flatbuffers::Follow<'a> for TransposeAttrs<'a> {
     }
 }
 
-impl<'a> TransposeAttrs<'a> {
-    pub const VT_PERM: flatbuffers::VOffsetT = 4;
+impl<'a> RandomNormalAttrs<'a> {
+    pub const VT_MEAN: flatbuffers::VOffsetT = 4;
+    pub const VT_SCALE: flatbuffers::VOffsetT = 6;
+    pub const VT_SEED: flatbuffers::VOffsetT = 8;
+    pub const VT_SHAPE: flatbuffers::VOffsetT = 10;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        TransposeAttrs { _tab: table }
+        RandomNormalAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args TransposeAttrsArgs<'args>,
-    ) -> flatbuffers::WIPOffset<TransposeAttrs<'bldr>> {
-        let mut builder = TransposeAttrsBuilder::new(_fbb);
-        if let Some(x) = args.perm {
-            builder.add_perm(x);
+        args: &'args RandomNormalAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<RandomNormalAttrs<'bldr>> {
+        let mut builder = RandomNormalAttrsBuilder::new(_fbb);
+        if let Some(x) = args.shape {
+            builder.add_shape(x);
+        }
+        if let Some(x) = args.seed {
+            builder.add_seed(x);
         }
+        builder.add_scale(args.scale);
+        builder.add_mean(args.mean);
         builder.finish()
     }
 
     #[inline]
-    pub fn perm(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    pub fn mean(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(RandomNormalAttrs::VT_MEAN, Some(0.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn scale(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(RandomNormalAttrs::VT_SCALE, Some(0.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn seed(&self) -> Option<f32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<f32>(RandomNormalAttrs::VT_SEED, None) }
+    }
+    #[inline]
+    pub fn shape(&self) -> Option<flatbuffers::Vector<'a, u32>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
                 .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
-                    TransposeAttrs::VT_PERM,
+                    RandomNormalAttrs::VT_SHAPE,
                     None,
                 )
         }
     }
 }
 
-impl flatbuffers::Verifiable for TransposeAttrs<'_> {
+impl flatbuffers::Verifiable for RandomNormalAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -9423,68 +10677,97 @@ impl flatbuffers::Verifiable for TransposeAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
+            .visit_field::<f32>("mean", Self::VT_MEAN, false)?
+            .visit_field::<f32>("scale", Self::VT_SCALE, false)?
+            .visit_field::<f32>("seed", Self::VT_SEED, false)?
             .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
-                "perm",
-                Self::VT_PERM,
+                "shape",
+                Self::VT_SHAPE,
                 false,
             )?
             .finish();
         Ok(())
     }
 }
-pub struct TransposeAttrsArgs<'a> {
-    pub perm: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+pub struct RandomNormalAttrsArgs<'a> {
+    pub mean: f32,
+    pub scale: f32,
+    pub seed: Option<f32>,
+    pub shape: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
 }
-impl<'a> Default for TransposeAttrsArgs<'a> {
+impl<'a> Default for RandomNormalAttrsArgs<'a> {
     #[inline]
     fn default() -> Self {
-        TransposeAttrsArgs { perm: None }
+        RandomNormalAttrsArgs {
+            mean: 0.0,
+            scale: 0.0,
+            seed: None,
+            shape: None,
+        }
     }
 }
 
-pub struct TransposeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct RandomNormalAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TransposeAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RandomNormalAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_perm(&mut self, perm: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+    pub fn add_mean(&mut self, mean: f32) {
         self.fbb_
-            .push_slot_always::<flatbuffers::WIPOffset<_>>(TransposeAttrs::VT_PERM, perm);
+            .push_slot::<f32>(RandomNormalAttrs::VT_MEAN, mean, 0.0);
     }
     #[inline]
-    pub fn new(
-        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> TransposeAttrsBuilder<'a, 'b, A> {
-        let start = _fbb.start_table();
-        TransposeAttrsBuilder {
-            fbb_: _fbb,
-            start_: start,
-        }
+    pub fn add_scale(&mut self, scale: f32) {
+        self.fbb_
+            .push_slot::<f32>(RandomNormalAttrs::VT_SCALE, scale, 0.0);
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<TransposeAttrs<'a>> {
-        let o = self.fbb_.end_table(self.start_);
-        flatbuffers::WIPOffset::new(o.value())
-    }
-}
+    pub fn add_seed(&mut self, seed: f32) {
+        self.fbb_
+            .push_slot_always::<f32>(RandomNormalAttrs::VT_SEED, seed);
+    }
+    #[inline]
+    pub fn add_shape(&mut self, shape: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(RandomNormalAttrs::VT_SHAPE, shape);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> RandomNormalAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        RandomNormalAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<RandomNormalAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
 
-impl core::fmt::Debug for TransposeAttrs<'_> {
+impl core::fmt::Debug for RandomNormalAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("TransposeAttrs");
-        ds.field("perm", &self.perm());
+        let mut ds = f.debug_struct("RandomNormalAttrs");
+        ds.field("mean", &self.mean());
+        ds.field("scale", &self.scale());
+        ds.field("seed", &self.seed());
+        ds.field("shape", &self.shape());
         ds.finish()
     }
 }
-pub enum TriluAttrsOffset {}
+pub enum RandomNormalLikeAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct TriluAttrs<'a> {
+pub struct RandomNormalLikeAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for TriluAttrs<'a> {
-    type Inner = TriluAttrs<'a>;
+impl<'a> flatbuffers::Follow<'a> for RandomNormalLikeAttrs<'a> {
+    type Inner = RandomNormalLikeAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -9493,37 +10776,61 @@ impl<'a> flatbuffers::Follow<'a> for TriluAttrs<'a> {
     }
 }
 
-impl<'a> TriluAttrs<'a> {
-    pub const VT_UPPER: flatbuffers::VOffsetT = 4;
+impl<'a> RandomNormalLikeAttrs<'a> {
+    pub const VT_MEAN: flatbuffers::VOffsetT = 4;
+    pub const VT_SCALE: flatbuffers::VOffsetT = 6;
+    pub const VT_SEED: flatbuffers::VOffsetT = 8;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        TriluAttrs { _tab: table }
+        RandomNormalLikeAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args TriluAttrsArgs,
-    ) -> flatbuffers::WIPOffset<TriluAttrs<'bldr>> {
-        let mut builder = TriluAttrsBuilder::new(_fbb);
-        builder.add_upper(args.upper);
+        args: &'args RandomNormalLikeAttrsArgs,
+    ) -> flatbuffers::WIPOffset<RandomNormalLikeAttrs<'bldr>> {
+        let mut builder = RandomNormalLikeAttrsBuilder::new(_fbb);
+        if let Some(x) = args.seed {
+            builder.add_seed(x);
+        }
+        builder.add_scale(args.scale);
+        builder.add_mean(args.mean);
         builder.finish()
     }
 
     #[inline]
-    pub fn upper(&self) -> bool {
+    pub fn mean(&self) -> f32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<bool>(TriluAttrs::VT_UPPER, Some(false))
+                .get::<f32>(RandomNormalLikeAttrs::VT_MEAN, Some(0.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn scale(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(RandomNormalLikeAttrs::VT_SCALE, Some(0.0))
                 .unwrap()
         }
     }
+    #[inline]
+    pub fn seed(&self) -> Option<f32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<f32>(RandomNormalLikeAttrs::VT_SEED, None) }
+    }
 }
 
-impl flatbuffers::Verifiable for TriluAttrs<'_> {
+impl flatbuffers::Verifiable for RandomNormalLikeAttrs<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -9531,64 +10838,84 @@ impl flatbuffers::Verifiable for TriluAttrs<'_> {
     ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
         use self::flatbuffers::Verifiable;
         v.visit_table(pos)?
-            .visit_field::<bool>("upper", Self::VT_UPPER, false)?
+            .visit_field::<f32>("mean", Self::VT_MEAN, false)?
+            .visit_field::<f32>("scale", Self::VT_SCALE, false)?
+            .visit_field::<f32>("seed", Self::VT_SEED, false)?
             .finish();
         Ok(())
     }
 }
-pub struct TriluAttrsArgs {
-    pub upper: bool,
+pub struct RandomNormalLikeAttrsArgs {
+    pub mean: f32,
+    pub scale: f32,
+    pub seed: Option<f32>,
 }
-impl<'a> Default for TriluAttrsArgs {
+impl<'a> Default for RandomNormalLikeAttrsArgs {
     #[inline]
     fn default() -> Self {
-        TriluAttrsArgs { upper: false }
+        RandomNormalLikeAttrsArgs {
+            mean: 0.0,
+            scale: 0.0,
+            seed: None,
+        }
     }
 }
 
-pub struct TriluAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+pub struct RandomNormalLikeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
     fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
     start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
 }
-impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TriluAttrsBuilder<'a, 'b, A> {
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RandomNormalLikeAttrsBuilder<'a, 'b, A> {
     #[inline]
-    pub fn add_upper(&mut self, upper: bool) {
+    pub fn add_mean(&mut self, mean: f32) {
         self.fbb_
-            .push_slot::<bool>(TriluAttrs::VT_UPPER, upper, false);
+            .push_slot::<f32>(RandomNormalLikeAttrs::VT_MEAN, mean, 0.0);
+    }
+    #[inline]
+    pub fn add_scale(&mut self, scale: f32) {
+        self.fbb_
+            .push_slot::<f32>(RandomNormalLikeAttrs::VT_SCALE, scale, 0.0);
+    }
+    #[inline]
+    pub fn add_seed(&mut self, seed: f32) {
+        self.fbb_
+            .push_slot_always::<f32>(RandomNormalLikeAttrs::VT_SEED, seed);
     }
     #[inline]
     pub fn new(
         _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
-    ) -> TriluAttrsBuilder<'a, 'b, A> {
+    ) -> RandomNormalLikeAttrsBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
-        TriluAttrsBuilder {
+        RandomNormalLikeAttrsBuilder {
             fbb_: _fbb,
             start_: start,
         }
     }
     #[inline]
-    pub fn finish(self) -> flatbuffers::WIPOffset<TriluAttrs<'a>> {
+    pub fn finish(self) -> flatbuffers::WIPOffset<RandomNormalLikeAttrs<'a>> {
         let o = self.fbb_.end_table(self.start_);
         flatbuffers::WIPOffset::new(o.value())
     }
 }
 
-impl core::fmt::Debug for TriluAttrs<'_> {
+impl core::fmt::Debug for RandomNormalLikeAttrs<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let mut ds = f.debug_struct("TriluAttrs");
-        ds.field("upper", &self.upper());
+        let mut ds = f.debug_struct("RandomNormalLikeAttrs");
+        ds.field("mean", &self.mean());
+        ds.field("scale", &self.scale());
+        ds.field("seed", &self.seed());
         ds.finish()
     }
 }
-pub enum OperatorNodeOffset {}
+pub enum RandomUniformAttrsOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct OperatorNode<'a> {
+pub struct RandomUniformAttrs<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for OperatorNode<'a> {
-    type Inner = OperatorNode<'a>;
+impl<'a> flatbuffers::Follow<'a> for RandomUniformAttrs<'a> {
+    type Inner = RandomUniformAttrs<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -9597,107 +10924,4292 @@ impl<'a> flatbuffers::Follow<'a> for OperatorNode<'a> {
     }
 }
 
-impl<'a> OperatorNode<'a> {
-    pub const VT_TYPE_: flatbuffers::VOffsetT = 4;
-    pub const VT_ATTRS_TYPE: flatbuffers::VOffsetT = 6;
-    pub const VT_ATTRS: flatbuffers::VOffsetT = 8;
-    pub const VT_INPUTS: flatbuffers::VOffsetT = 10;
-    pub const VT_OUTPUTS: flatbuffers::VOffsetT = 12;
+impl<'a> RandomUniformAttrs<'a> {
+    pub const VT_SHAPE: flatbuffers::VOffsetT = 4;
+    pub const VT_HIGH: flatbuffers::VOffsetT = 6;
+    pub const VT_LOW: flatbuffers::VOffsetT = 8;
+    pub const VT_SEED: flatbuffers::VOffsetT = 10;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        OperatorNode { _tab: table }
+        RandomUniformAttrs { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
-        args: &'args OperatorNodeArgs<'args>,
-    ) -> flatbuffers::WIPOffset<OperatorNode<'bldr>> {
-        let mut builder = OperatorNodeBuilder::new(_fbb);
-        if let Some(x) = args.outputs {
-            builder.add_outputs(x);
-        }
-        if let Some(x) = args.inputs {
-            builder.add_inputs(x);
+        args: &'args RandomUniformAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<RandomUniformAttrs<'bldr>> {
+        let mut builder = RandomUniformAttrsBuilder::new(_fbb);
+        if let Some(x) = args.seed {
+            builder.add_seed(x);
         }
-        if let Some(x) = args.attrs {
-            builder.add_attrs(x);
+        builder.add_low(args.low);
+        builder.add_high(args.high);
+        if let Some(x) = args.shape {
+            builder.add_shape(x);
         }
-        builder.add_attrs_type(args.attrs_type);
-        builder.add_type_(args.type_);
         builder.finish()
     }
 
     #[inline]
-    pub fn type_(&self) -> OperatorType {
+    pub fn shape(&self) -> Option<flatbuffers::Vector<'a, u32>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<OperatorType>(OperatorNode::VT_TYPE_, Some(OperatorType::Add))
-                .unwrap()
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    RandomUniformAttrs::VT_SHAPE,
+                    None,
+                )
         }
     }
     #[inline]
-    pub fn attrs_type(&self) -> OperatorAttrs {
+    pub fn high(&self) -> f32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<OperatorAttrs>(OperatorNode::VT_ATTRS_TYPE, Some(OperatorAttrs::NONE))
+                .get::<f32>(RandomUniformAttrs::VT_HIGH, Some(0.0))
                 .unwrap()
         }
     }
     #[inline]
-    pub fn attrs(&self) -> Option<flatbuffers::Table<'a>> {
+    pub fn low(&self) -> f32 {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(
-                    OperatorNode::VT_ATTRS,
-                    None,
-                )
+                .get::<f32>(RandomUniformAttrs::VT_LOW, Some(0.0))
+                .unwrap()
         }
     }
     #[inline]
-    pub fn inputs(&self) -> Option<flatbuffers::Vector<'a, i32>> {
+    pub fn seed(&self) -> Option<f32> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
-                    OperatorNode::VT_INPUTS,
-                    None,
-                )
+        unsafe { self._tab.get::<f32>(RandomUniformAttrs::VT_SEED, None) }
+    }
+}
+
+impl flatbuffers::Verifiable for RandomUniformAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "shape",
+                Self::VT_SHAPE,
+                false,
+            )?
+            .visit_field::<f32>("high", Self::VT_HIGH, false)?
+            .visit_field::<f32>("low", Self::VT_LOW, false)?
+            .visit_field::<f32>("seed", Self::VT_SEED, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct RandomUniformAttrsArgs<'a> {
+    pub shape: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub high: f32,
+    pub low: f32,
+    pub seed: Option<f32>,
+}
+impl<'a> Default for RandomUniformAttrsArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        RandomUniformAttrsArgs {
+            shape: None,
+            high: 0.0,
+            low: 0.0,
+            seed: None,
+        }
+    }
+}
+
+pub struct RandomUniformAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RandomUniformAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_shape(&mut self, shape: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(RandomUniformAttrs::VT_SHAPE, shape);
+    }
+    #[inline]
+    pub fn add_high(&mut self, high: f32) {
+        self.fbb_
+            .push_slot::<f32>(RandomUniformAttrs::VT_HIGH, high, 0.0);
+    }
+    #[inline]
+    pub fn add_low(&mut self, low: f32) {
+        self.fbb_
+            .push_slot::<f32>(RandomUniformAttrs::VT_LOW, low, 0.0);
+    }
+    #[inline]
+    pub fn add_seed(&mut self, seed: f32) {
+        self.fbb_
+            .push_slot_always::<f32>(RandomUniformAttrs::VT_SEED, seed);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> RandomUniformAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        RandomUniformAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<RandomUniformAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for RandomUniformAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("RandomUniformAttrs");
+        ds.field("shape", &self.shape());
+        ds.field("high", &self.high());
+        ds.field("low", &self.low());
+        ds.field("seed", &self.seed());
+        ds.finish()
+    }
+}
+pub enum RandomUniformLikeAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct RandomUniformLikeAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for RandomUniformLikeAttrs<'a> {
+    type Inner = RandomUniformLikeAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> RandomUniformLikeAttrs<'a> {
+    pub const VT_HIGH: flatbuffers::VOffsetT = 4;
+    pub const VT_LOW: flatbuffers::VOffsetT = 6;
+    pub const VT_SEED: flatbuffers::VOffsetT = 8;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        RandomUniformLikeAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args RandomUniformLikeAttrsArgs,
+    ) -> flatbuffers::WIPOffset<RandomUniformLikeAttrs<'bldr>> {
+        let mut builder = RandomUniformLikeAttrsBuilder::new(_fbb);
+        if let Some(x) = args.seed {
+            builder.add_seed(x);
+        }
+        builder.add_low(args.low);
+        builder.add_high(args.high);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn high(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(RandomUniformLikeAttrs::VT_HIGH, Some(0.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn low(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(RandomUniformLikeAttrs::VT_LOW, Some(0.0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn seed(&self) -> Option<f32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<f32>(RandomUniformLikeAttrs::VT_SEED, None) }
+    }
+}
+
+impl flatbuffers::Verifiable for RandomUniformLikeAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<f32>("high", Self::VT_HIGH, false)?
+            .visit_field::<f32>("low", Self::VT_LOW, false)?
+            .visit_field::<f32>("seed", Self::VT_SEED, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct RandomUniformLikeAttrsArgs {
+    pub high: f32,
+    pub low: f32,
+    pub seed: Option<f32>,
+}
+impl<'a> Default for RandomUniformLikeAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        RandomUniformLikeAttrsArgs {
+            high: 0.0,
+            low: 0.0,
+            seed: None,
+        }
+    }
+}
+
+pub struct RandomUniformLikeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RandomUniformLikeAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_high(&mut self, high: f32) {
+        self.fbb_
+            .push_slot::<f32>(RandomUniformLikeAttrs::VT_HIGH, high, 0.0);
+    }
+    #[inline]
+    pub fn add_low(&mut self, low: f32) {
+        self.fbb_
+            .push_slot::<f32>(RandomUniformLikeAttrs::VT_LOW, low, 0.0);
+    }
+    #[inline]
+    pub fn add_seed(&mut self, seed: f32) {
+        self.fbb_
+            .push_slot_always::<f32>(RandomUniformLikeAttrs::VT_SEED, seed);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> RandomUniformLikeAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        RandomUniformLikeAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<RandomUniformLikeAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for RandomUniformLikeAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("RandomUniformLikeAttrs");
+        ds.field("high", &self.high());
+        ds.field("low", &self.low());
+        ds.field("seed", &self.seed());
+        ds.finish()
+    }
+}
+pub enum ReduceMeanAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ReduceMeanAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ReduceMeanAttrs<'a> {
+    type Inner = ReduceMeanAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> ReduceMeanAttrs<'a> {
+    pub const VT_AXES: flatbuffers::VOffsetT = 4;
+    pub const VT_KEEP_DIMS: flatbuffers::VOffsetT = 6;
+    pub const VT_NOOP_WITH_EMPTY_AXES: flatbuffers::VOffsetT = 8;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        ReduceMeanAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args ReduceMeanAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<ReduceMeanAttrs<'bldr>> {
+        let mut builder = ReduceMeanAttrsBuilder::new(_fbb);
+        if let Some(x) = args.axes {
+            builder.add_axes(x);
+        }
+        builder.add_noop_with_empty_axes(args.noop_with_empty_axes);
+        builder.add_keep_dims(args.keep_dims);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn axes(&self) -> Option<flatbuffers::Vector<'a, i32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
+                    ReduceMeanAttrs::VT_AXES,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn keep_dims(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(ReduceMeanAttrs::VT_KEEP_DIMS, Some(false))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn noop_with_empty_axes(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(ReduceMeanAttrs::VT_NOOP_WITH_EMPTY_AXES, Some(false))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for ReduceMeanAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i32>>>(
+                "axes",
+                Self::VT_AXES,
+                false,
+            )?
+            .visit_field::<bool>("keep_dims", Self::VT_KEEP_DIMS, false)?
+            .visit_field::<bool>("noop_with_empty_axes", Self::VT_NOOP_WITH_EMPTY_AXES, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ReduceMeanAttrsArgs<'a> {
+    pub axes: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i32>>>,
+    pub keep_dims: bool,
+    pub noop_with_empty_axes: bool,
+}
+impl<'a> Default for ReduceMeanAttrsArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        ReduceMeanAttrsArgs {
+            axes: None,
+            keep_dims: false,
+            noop_with_empty_axes: false,
+        }
+    }
+}
+
+pub struct ReduceMeanAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ReduceMeanAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_axes(&mut self, axes: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ReduceMeanAttrs::VT_AXES, axes);
+    }
+    #[inline]
+    pub fn add_keep_dims(&mut self, keep_dims: bool) {
+        self.fbb_
+            .push_slot::<bool>(ReduceMeanAttrs::VT_KEEP_DIMS, keep_dims, false);
+    }
+    #[inline]
+    pub fn add_noop_with_empty_axes(&mut self, noop_with_empty_axes: bool) {
+        self.fbb_.push_slot::<bool>(
+            ReduceMeanAttrs::VT_NOOP_WITH_EMPTY_AXES,
+            noop_with_empty_axes,
+            false,
+        );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> ReduceMeanAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        ReduceMeanAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<ReduceMeanAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for ReduceMeanAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("ReduceMeanAttrs");
+        ds.field("axes", &self.axes());
+        ds.field("keep_dims", &self.keep_dims());
+        ds.field("noop_with_empty_axes", &self.noop_with_empty_axes());
+        ds.finish()
+    }
+}
+pub enum RepeatInterleaveAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct RepeatInterleaveAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for RepeatInterleaveAttrs<'a> {
+    type Inner = RepeatInterleaveAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> RepeatInterleaveAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_REPEATS: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        RepeatInterleaveAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args RepeatInterleaveAttrsArgs,
+    ) -> flatbuffers::WIPOffset<RepeatInterleaveAttrs<'bldr>> {
+        let mut builder = RepeatInterleaveAttrsBuilder::new(_fbb);
+        builder.add_repeats(args.repeats);
+        builder.add_axis(args.axis);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn axis(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(RepeatInterleaveAttrs::VT_AXIS, Some(0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn repeats(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(RepeatInterleaveAttrs::VT_REPEATS, Some(0))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for RepeatInterleaveAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<u32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<u32>("repeats", Self::VT_REPEATS, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct RepeatInterleaveAttrsArgs {
+    pub axis: u32,
+    pub repeats: u32,
+}
+impl<'a> Default for RepeatInterleaveAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        RepeatInterleaveAttrsArgs {
+            axis: 0,
+            repeats: 0,
+        }
+    }
+}
+
+pub struct RepeatInterleaveAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RepeatInterleaveAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_axis(&mut self, axis: u32) {
+        self.fbb_
+            .push_slot::<u32>(RepeatInterleaveAttrs::VT_AXIS, axis, 0);
+    }
+    #[inline]
+    pub fn add_repeats(&mut self, repeats: u32) {
+        self.fbb_
+            .push_slot::<u32>(RepeatInterleaveAttrs::VT_REPEATS, repeats, 0);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> RepeatInterleaveAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        RepeatInterleaveAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<RepeatInterleaveAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for RepeatInterleaveAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("RepeatInterleaveAttrs");
+        ds.field("axis", &self.axis());
+        ds.field("repeats", &self.repeats());
+        ds.finish()
+    }
+}
+pub enum ReshapeAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ReshapeAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ReshapeAttrs<'a> {
+    type Inner = ReshapeAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> ReshapeAttrs<'a> {
+    pub const VT_ALLOW_ZERO: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        ReshapeAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args ReshapeAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ReshapeAttrs<'bldr>> {
+        let mut builder = ReshapeAttrsBuilder::new(_fbb);
+        builder.add_allow_zero(args.allow_zero);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn allow_zero(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(ReshapeAttrs::VT_ALLOW_ZERO, Some(false))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for ReshapeAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<bool>("allow_zero", Self::VT_ALLOW_ZERO, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ReshapeAttrsArgs {
+    pub allow_zero: bool,
+}
+impl<'a> Default for ReshapeAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        ReshapeAttrsArgs { allow_zero: false }
+    }
+}
+
+pub struct ReshapeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ReshapeAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_allow_zero(&mut self, allow_zero: bool) {
+        self.fbb_
+            .push_slot::<bool>(ReshapeAttrs::VT_ALLOW_ZERO, allow_zero, false);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> ReshapeAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        ReshapeAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<ReshapeAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for ReshapeAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("ReshapeAttrs");
+        ds.field("allow_zero", &self.allow_zero());
+        ds.finish()
+    }
+}
+pub enum ResizeAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ResizeAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ResizeAttrs<'a> {
+    type Inner = ResizeAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> ResizeAttrs<'a> {
+    pub const VT_MODE: flatbuffers::VOffsetT = 4;
+    pub const VT_COORD_MODE: flatbuffers::VOffsetT = 6;
+    pub const VT_NEAREST_MODE: flatbuffers::VOffsetT = 8;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        ResizeAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args ResizeAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ResizeAttrs<'bldr>> {
+        let mut builder = ResizeAttrsBuilder::new(_fbb);
+        builder.add_nearest_mode(args.nearest_mode);
+        builder.add_coord_mode(args.coord_mode);
+        builder.add_mode(args.mode);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn mode(&self) -> ResizeMode {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<ResizeMode>(ResizeAttrs::VT_MODE, Some(ResizeMode::Nearest))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn coord_mode(&self) -> CoordTransformMode {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<CoordTransformMode>(
+                    ResizeAttrs::VT_COORD_MODE,
+                    Some(CoordTransformMode::HalfPixel),
+                )
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn nearest_mode(&self) -> NearestMode {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<NearestMode>(ResizeAttrs::VT_NEAREST_MODE, Some(NearestMode::Floor))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for ResizeAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<ResizeMode>("mode", Self::VT_MODE, false)?
+            .visit_field::<CoordTransformMode>("coord_mode", Self::VT_COORD_MODE, false)?
+            .visit_field::<NearestMode>("nearest_mode", Self::VT_NEAREST_MODE, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ResizeAttrsArgs {
+    pub mode: ResizeMode,
+    pub coord_mode: CoordTransformMode,
+    pub nearest_mode: NearestMode,
+}
+impl<'a> Default for ResizeAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        ResizeAttrsArgs {
+            mode: ResizeMode::Nearest,
+            coord_mode: CoordTransformMode::HalfPixel,
+            nearest_mode: NearestMode::Floor,
+        }
+    }
+}
+
+pub struct ResizeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ResizeAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_mode(&mut self, mode: ResizeMode) {
+        self.fbb_
+            .push_slot::<ResizeMode>(ResizeAttrs::VT_MODE, mode, ResizeMode::Nearest);
+    }
+    #[inline]
+    pub fn add_coord_mode(&mut self, coord_mode: CoordTransformMode) {
+        self.fbb_.push_slot::<CoordTransformMode>(
+            ResizeAttrs::VT_COORD_MODE,
+            coord_mode,
+            CoordTransformMode::HalfPixel,
+        );
+    }
+    #[inline]
+    pub fn add_nearest_mode(&mut self, nearest_mode: NearestMode) {
+        self.fbb_.push_slot::<NearestMode>(
+            ResizeAttrs::VT_NEAREST_MODE,
+            nearest_mode,
+            NearestMode::Floor,
+        );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> ResizeAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        ResizeAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<ResizeAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for ResizeAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("ResizeAttrs");
+        ds.field("mode", &self.mode());
+        ds.field("coord_mode", &self.coord_mode());
+        ds.field("nearest_mode", &self.nearest_mode());
+        ds.finish()
+    }
+}
+pub enum RoiAlignAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct RoiAlignAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for RoiAlignAttrs<'a> {
+    type Inner = RoiAlignAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> RoiAlignAttrs<'a> {
+    pub const VT_MODE: flatbuffers::VOffsetT = 4;
+    pub const VT_OUTPUT_HEIGHT: flatbuffers::VOffsetT = 6;
+    pub const VT_OUTPUT_WIDTH: flatbuffers::VOffsetT = 8;
+    pub const VT_SAMPLING_RATIO: flatbuffers::VOffsetT = 10;
+    pub const VT_SPATIAL_SCALE: flatbuffers::VOffsetT = 12;
+    pub const VT_HALF_PIXEL: flatbuffers::VOffsetT = 14;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        RoiAlignAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args RoiAlignAttrsArgs,
+    ) -> flatbuffers::WIPOffset<RoiAlignAttrs<'bldr>> {
+        let mut builder = RoiAlignAttrsBuilder::new(_fbb);
+        builder.add_spatial_scale(args.spatial_scale);
+        builder.add_sampling_ratio(args.sampling_ratio);
+        builder.add_output_width(args.output_width);
+        builder.add_output_height(args.output_height);
+        builder.add_half_pixel(args.half_pixel);
+        builder.add_mode(args.mode);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn mode(&self) -> RoiAlignMode {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<RoiAlignMode>(RoiAlignAttrs::VT_MODE, Some(RoiAlignMode::Avg))
+                .unwrap()
+        }
+    }
+
+    #[inline]
+    pub fn output_height(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(RoiAlignAttrs::VT_OUTPUT_HEIGHT, Some(0))
+                .unwrap()
+        }
+    }
+
+    #[inline]
+    pub fn output_width(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(RoiAlignAttrs::VT_OUTPUT_WIDTH, Some(0))
+                .unwrap()
+        }
+    }
+
+    #[inline]
+    pub fn sampling_ratio(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(RoiAlignAttrs::VT_SAMPLING_RATIO, Some(0))
+                .unwrap()
+        }
+    }
+
+    #[inline]
+    pub fn spatial_scale(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(RoiAlignAttrs::VT_SPATIAL_SCALE, Some(0.0))
+                .unwrap()
+        }
+    }
+
+    #[inline]
+    pub fn half_pixel(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(RoiAlignAttrs::VT_HALF_PIXEL, Some(false))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for RoiAlignAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<RoiAlignMode>("mode", Self::VT_MODE, false)?
+            .visit_field::<u32>("output_height", Self::VT_OUTPUT_HEIGHT, false)?
+            .visit_field::<u32>("output_width", Self::VT_OUTPUT_WIDTH, false)?
+            .visit_field::<u32>("sampling_ratio", Self::VT_SAMPLING_RATIO, false)?
+            .visit_field::<f32>("spatial_scale", Self::VT_SPATIAL_SCALE, false)?
+            .visit_field::<bool>("half_pixel", Self::VT_HALF_PIXEL, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct RoiAlignAttrsArgs {
+    pub mode: RoiAlignMode,
+    pub output_height: u32,
+    pub output_width: u32,
+    pub sampling_ratio: u32,
+    pub spatial_scale: f32,
+    pub half_pixel: bool,
+}
+impl<'a> Default for RoiAlignAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        RoiAlignAttrsArgs {
+            mode: RoiAlignMode::Avg,
+            output_height: 0,
+            output_width: 0,
+            sampling_ratio: 0,
+            spatial_scale: 0.0,
+            half_pixel: false,
+        }
+    }
+}
+
+pub struct RoiAlignAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RoiAlignAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_mode(&mut self, mode: RoiAlignMode) {
+        self.fbb_
+            .push_slot::<RoiAlignMode>(RoiAlignAttrs::VT_MODE, mode, RoiAlignMode::Avg);
+    }
+    #[inline]
+    pub fn add_output_height(&mut self, output_height: u32) {
+        self.fbb_
+            .push_slot::<u32>(RoiAlignAttrs::VT_OUTPUT_HEIGHT, output_height, 0);
+    }
+    #[inline]
+    pub fn add_output_width(&mut self, output_width: u32) {
+        self.fbb_
+            .push_slot::<u32>(RoiAlignAttrs::VT_OUTPUT_WIDTH, output_width, 0);
+    }
+    #[inline]
+    pub fn add_sampling_ratio(&mut self, sampling_ratio: u32) {
+        self.fbb_
+            .push_slot::<u32>(RoiAlignAttrs::VT_SAMPLING_RATIO, sampling_ratio, 0);
+    }
+    #[inline]
+    pub fn add_spatial_scale(&mut self, spatial_scale: f32) {
+        self.fbb_
+            .push_slot::<f32>(RoiAlignAttrs::VT_SPATIAL_SCALE, spatial_scale, 0.0);
+    }
+    #[inline]
+    pub fn add_half_pixel(&mut self, half_pixel: bool) {
+        self.fbb_
+            .push_slot::<bool>(RoiAlignAttrs::VT_HALF_PIXEL, half_pixel, false);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> RoiAlignAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        RoiAlignAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<RoiAlignAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for RoiAlignAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("RoiAlignAttrs");
+        ds.field("mode", &self.mode());
+        ds.field("output_height", &self.output_height());
+        ds.field("output_width", &self.output_width());
+        ds.field("sampling_ratio", &self.sampling_ratio());
+        ds.field("spatial_scale", &self.spatial_scale());
+        ds.field("half_pixel", &self.half_pixel());
+        ds.finish()
+    }
+}
+pub enum ReverseSequenceAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ReverseSequenceAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ReverseSequenceAttrs<'a> {
+    type Inner = ReverseSequenceAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> ReverseSequenceAttrs<'a> {
+    pub const VT_BATCH_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_TIME_AXIS: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        ReverseSequenceAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args ReverseSequenceAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ReverseSequenceAttrs<'bldr>> {
+        let mut builder = ReverseSequenceAttrsBuilder::new(_fbb);
+        builder.add_time_axis(args.time_axis);
+        builder.add_batch_axis(args.batch_axis);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn batch_axis(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(ReverseSequenceAttrs::VT_BATCH_AXIS, Some(0))
+                .unwrap()
+        }
+    }
+
+    #[inline]
+    pub fn time_axis(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(ReverseSequenceAttrs::VT_TIME_AXIS, Some(0))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for ReverseSequenceAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<u32>("batch_axis", Self::VT_BATCH_AXIS, false)?
+            .visit_field::<u32>("time_axis", Self::VT_TIME_AXIS, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ReverseSequenceAttrsArgs {
+    pub batch_axis: u32,
+    pub time_axis: u32,
+}
+impl<'a> Default for ReverseSequenceAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        ReverseSequenceAttrsArgs {
+            batch_axis: 0,
+            time_axis: 0,
+        }
+    }
+}
+
+pub struct ReverseSequenceAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ReverseSequenceAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_batch_axis(&mut self, batch_axis: u32) {
+        self.fbb_
+            .push_slot::<u32>(ReverseSequenceAttrs::VT_BATCH_AXIS, batch_axis, 0);
+    }
+    #[inline]
+    pub fn add_time_axis(&mut self, time_axis: u32) {
+        self.fbb_
+            .push_slot::<u32>(ReverseSequenceAttrs::VT_TIME_AXIS, time_axis, 0);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> ReverseSequenceAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        ReverseSequenceAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<ReverseSequenceAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for ReverseSequenceAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("ReverseSequenceAttrs");
+        ds.field("batch_axis", &self.batch_axis());
+        ds.field("time_axis", &self.time_axis());
+        ds.finish()
+    }
+}
+pub enum RotaryEmbeddingAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct RotaryEmbeddingAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for RotaryEmbeddingAttrs<'a> {
+    type Inner = RotaryEmbeddingAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> RotaryEmbeddingAttrs<'a> {
+    pub const VT_INTERLEAVED: flatbuffers::VOffsetT = 4;
+    pub const VT_NUM_HEADS: flatbuffers::VOffsetT = 6;
+    pub const VT_ROTARY_EMBEDDING_DIM: flatbuffers::VOffsetT = 8;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        RotaryEmbeddingAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args RotaryEmbeddingAttrsArgs,
+    ) -> flatbuffers::WIPOffset<RotaryEmbeddingAttrs<'bldr>> {
+        let mut builder = RotaryEmbeddingAttrsBuilder::new(_fbb);
+        if let Some(x) = args.rotary_embedding_dim {
+            builder.add_rotary_embedding_dim(x);
+        }
+        if let Some(x) = args.num_heads {
+            builder.add_num_heads(x);
+        }
+        builder.add_interleaved(args.interleaved);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn interleaved(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(RotaryEmbeddingAttrs::VT_INTERLEAVED, Some(false))
+                .unwrap()
+        }
+    }
+
+    #[inline]
+    pub fn num_heads(&self) -> Option<u32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(RotaryEmbeddingAttrs::VT_NUM_HEADS, None)
+        }
+    }
+
+    #[inline]
+    pub fn rotary_embedding_dim(&self) -> Option<u32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(RotaryEmbeddingAttrs::VT_ROTARY_EMBEDDING_DIM, None)
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for RotaryEmbeddingAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<bool>("interleaved", Self::VT_INTERLEAVED, false)?
+            .visit_field::<u32>("num_heads", Self::VT_NUM_HEADS, false)?
+            .visit_field::<u32>("rotary_embedding_dim", Self::VT_ROTARY_EMBEDDING_DIM, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct RotaryEmbeddingAttrsArgs {
+    pub interleaved: bool,
+    pub num_heads: Option<u32>,
+    pub rotary_embedding_dim: Option<u32>,
+}
+impl<'a> Default for RotaryEmbeddingAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        RotaryEmbeddingAttrsArgs {
+            interleaved: false,
+            num_heads: None,
+            rotary_embedding_dim: None,
+        }
+    }
+}
+
+pub struct RotaryEmbeddingAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> RotaryEmbeddingAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_interleaved(&mut self, interleaved: bool) {
+        self.fbb_
+            .push_slot::<bool>(RotaryEmbeddingAttrs::VT_INTERLEAVED, interleaved, false);
+    }
+    #[inline]
+    pub fn add_num_heads(&mut self, num_heads: u32) {
+        self.fbb_
+            .push_slot_always::<u32>(RotaryEmbeddingAttrs::VT_NUM_HEADS, num_heads);
+    }
+    #[inline]
+    pub fn add_rotary_embedding_dim(&mut self, rotary_embedding_dim: u32) {
+        self.fbb_.push_slot_always::<u32>(
+            RotaryEmbeddingAttrs::VT_ROTARY_EMBEDDING_DIM,
+            rotary_embedding_dim,
+        );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> RotaryEmbeddingAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        RotaryEmbeddingAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<RotaryEmbeddingAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for RotaryEmbeddingAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("RotaryEmbeddingAttrs");
+        ds.field("interleaved", &self.interleaved());
+        ds.field("num_heads", &self.num_heads());
+        ds.field("rotary_embedding_dim", &self.rotary_embedding_dim());
+        ds.finish()
+    }
+}
+pub enum ScatterElementsAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ScatterElementsAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ScatterElementsAttrs<'a> {
+    type Inner = ScatterElementsAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> ScatterElementsAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_REDUCTION: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        ScatterElementsAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args ScatterElementsAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ScatterElementsAttrs<'bldr>> {
+        let mut builder = ScatterElementsAttrsBuilder::new(_fbb);
+        builder.add_axis(args.axis);
+        builder.add_reduction(args.reduction);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn axis(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<i32>(ScatterElementsAttrs::VT_AXIS, Some(0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn reduction(&self) -> ScatterReduction {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<ScatterReduction>(
+                    ScatterElementsAttrs::VT_REDUCTION,
+                    Some(ScatterReduction::None),
+                )
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for ScatterElementsAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<ScatterReduction>("reduction", Self::VT_REDUCTION, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ScatterElementsAttrsArgs {
+    pub axis: i32,
+    pub reduction: ScatterReduction,
+}
+impl<'a> Default for ScatterElementsAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        ScatterElementsAttrsArgs {
+            axis: 0,
+            reduction: ScatterReduction::None,
+        }
+    }
+}
+
+pub struct ScatterElementsAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ScatterElementsAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_
+            .push_slot::<i32>(ScatterElementsAttrs::VT_AXIS, axis, 0);
+    }
+    #[inline]
+    pub fn add_reduction(&mut self, reduction: ScatterReduction) {
+        self.fbb_.push_slot::<ScatterReduction>(
+            ScatterElementsAttrs::VT_REDUCTION,
+            reduction,
+            ScatterReduction::None,
+        );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> ScatterElementsAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        ScatterElementsAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<ScatterElementsAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for ScatterElementsAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("ScatterElementsAttrs");
+        ds.field("axis", &self.axis());
+        ds.field("reduction", &self.reduction());
+        ds.finish()
+    }
+}
+pub enum ScatterNDAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ScatterNDAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ScatterNDAttrs<'a> {
+    type Inner = ScatterNDAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> ScatterNDAttrs<'a> {
+    pub const VT_REDUCTION: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        ScatterNDAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args ScatterNDAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ScatterNDAttrs<'bldr>> {
+        let mut builder = ScatterNDAttrsBuilder::new(_fbb);
+        builder.add_reduction(args.reduction);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn reduction(&self) -> ScatterReduction {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<ScatterReduction>(ScatterNDAttrs::VT_REDUCTION, Some(ScatterReduction::None))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for ScatterNDAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<ScatterReduction>("reduction", Self::VT_REDUCTION, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ScatterNDAttrsArgs {
+    pub reduction: ScatterReduction,
+}
+impl<'a> Default for ScatterNDAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        ScatterNDAttrsArgs {
+            reduction: ScatterReduction::None,
+        }
+    }
+}
+
+pub struct ScatterNDAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ScatterNDAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_reduction(&mut self, reduction: ScatterReduction) {
+        self.fbb_.push_slot::<ScatterReduction>(
+            ScatterNDAttrs::VT_REDUCTION,
+            reduction,
+            ScatterReduction::None,
+        );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> ScatterNDAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        ScatterNDAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<ScatterNDAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for ScatterNDAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("ScatterNDAttrs");
+        ds.field("reduction", &self.reduction());
+        ds.finish()
+    }
+}
+pub enum SeluAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SeluAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SeluAttrs<'a> {
+    type Inner = SeluAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> SeluAttrs<'a> {
+    pub const VT_ALPHA: flatbuffers::VOffsetT = 4;
+    pub const VT_GAMMA: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        SeluAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args SeluAttrsArgs,
+    ) -> flatbuffers::WIPOffset<SeluAttrs<'bldr>> {
+        let mut builder = SeluAttrsBuilder::new(_fbb);
+        builder.add_gamma(args.gamma);
+        builder.add_alpha(args.alpha);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn alpha(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(SeluAttrs::VT_ALPHA, Some(0.0))
+                .unwrap()
+        }
+    }
+
+    #[inline]
+    pub fn gamma(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(SeluAttrs::VT_GAMMA, Some(0.0))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for SeluAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
+            .visit_field::<f32>("gamma", Self::VT_GAMMA, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct SeluAttrsArgs {
+    pub alpha: f32,
+    pub gamma: f32,
+}
+impl<'a> Default for SeluAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        SeluAttrsArgs {
+            alpha: 0.0,
+            gamma: 0.0,
+        }
+    }
+}
+
+pub struct SeluAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SeluAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_alpha(&mut self, alpha: f32) {
+        self.fbb_.push_slot::<f32>(SeluAttrs::VT_ALPHA, alpha, 0.0);
+    }
+    #[inline]
+    pub fn add_gamma(&mut self, gamma: f32) {
+        self.fbb_.push_slot::<f32>(SeluAttrs::VT_GAMMA, gamma, 0.0);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> SeluAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        SeluAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<SeluAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for SeluAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("SeluAttrs");
+        ds.field("alpha", &self.alpha());
+        ds.field("gamma", &self.gamma());
+        ds.finish()
+    }
+}
+pub enum SequenceEmptyAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SequenceEmptyAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SequenceEmptyAttrs<'a> {
+    type Inner = SequenceEmptyAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> SequenceEmptyAttrs<'a> {
+    pub const VT_DTYPE: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        SequenceEmptyAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args SequenceEmptyAttrsArgs,
+    ) -> flatbuffers::WIPOffset<SequenceEmptyAttrs<'bldr>> {
+        let mut builder = SequenceEmptyAttrsBuilder::new(_fbb);
+        if let Some(x) = args.dtype {
+            builder.add_dtype(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn dtype(&self) -> Option<DataType> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<DataType>(SequenceEmptyAttrs::VT_DTYPE, None)
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for SequenceEmptyAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<DataType>("dtype", Self::VT_DTYPE, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct SequenceEmptyAttrsArgs {
+    pub dtype: Option<DataType>,
+}
+impl<'a> Default for SequenceEmptyAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        SequenceEmptyAttrsArgs { dtype: None }
+    }
+}
+
+pub struct SequenceEmptyAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SequenceEmptyAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_dtype(&mut self, dtype: DataType) {
+        self.fbb_
+            .push_slot_always::<DataType>(SequenceEmptyAttrs::VT_DTYPE, dtype);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> SequenceEmptyAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        SequenceEmptyAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<SequenceEmptyAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for SequenceEmptyAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("SequenceEmptyAttrs");
+        ds.field("dtype", &self.dtype());
+        ds.finish()
+    }
+}
+pub enum ShapeAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ShapeAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ShapeAttrs<'a> {
+    type Inner = ShapeAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> ShapeAttrs<'a> {
+    pub const VT_START: flatbuffers::VOffsetT = 4;
+    pub const VT_END: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        ShapeAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args ShapeAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ShapeAttrs<'bldr>> {
+        let mut builder = ShapeAttrsBuilder::new(_fbb);
+        if let Some(x) = args.end {
+            builder.add_end(x);
+        }
+        if let Some(x) = args.start {
+            builder.add_start(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn start(&self) -> Option<i32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<i32>(ShapeAttrs::VT_START, None) }
+    }
+    #[inline]
+    pub fn end(&self) -> Option<i32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<i32>(ShapeAttrs::VT_END, None) }
+    }
+}
+
+impl flatbuffers::Verifiable for ShapeAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<i32>("start", Self::VT_START, false)?
+            .visit_field::<i32>("end", Self::VT_END, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ShapeAttrsArgs {
+    pub start: Option<i32>,
+    pub end: Option<i32>,
+}
+impl<'a> Default for ShapeAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        ShapeAttrsArgs {
+            start: None,
+            end: None,
+        }
+    }
+}
+
+pub struct ShapeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ShapeAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_start(&mut self, start: i32) {
+        self.fbb_
+            .push_slot_always::<i32>(ShapeAttrs::VT_START, start);
+    }
+    #[inline]
+    pub fn add_end(&mut self, end: i32) {
+        self.fbb_.push_slot_always::<i32>(ShapeAttrs::VT_END, end);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> ShapeAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        ShapeAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<ShapeAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for ShapeAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("ShapeAttrs");
+        ds.field("start", &self.start());
+        ds.field("end", &self.end());
+        ds.finish()
+    }
+}
+pub enum ShrinkAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ShrinkAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ShrinkAttrs<'a> {
+    type Inner = ShrinkAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> ShrinkAttrs<'a> {
+    pub const VT_BIAS: flatbuffers::VOffsetT = 4;
+    pub const VT_LAMBD: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        ShrinkAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args ShrinkAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ShrinkAttrs<'bldr>> {
+        let mut builder = ShrinkAttrsBuilder::new(_fbb);
+        builder.add_lambd(args.lambd);
+        builder.add_bias(args.bias);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn bias(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(ShrinkAttrs::VT_BIAS, Some(0.0))
+                .unwrap()
+        }
+    }
+
+    #[inline]
+    pub fn lambd(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(ShrinkAttrs::VT_LAMBD, Some(0.0))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for ShrinkAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<f32>("bias", Self::VT_BIAS, false)?
+            .visit_field::<f32>("lambd", Self::VT_LAMBD, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ShrinkAttrsArgs {
+    pub bias: f32,
+    pub lambd: f32,
+}
+impl<'a> Default for ShrinkAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        ShrinkAttrsArgs {
+            bias: 0.0,
+            lambd: 0.0,
+        }
+    }
+}
+
+pub struct ShrinkAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ShrinkAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_bias(&mut self, bias: f32) {
+        self.fbb_.push_slot::<f32>(ShrinkAttrs::VT_BIAS, bias, 0.0);
+    }
+    #[inline]
+    pub fn add_lambd(&mut self, lambd: f32) {
+        self.fbb_
+            .push_slot::<f32>(ShrinkAttrs::VT_LAMBD, lambd, 0.0);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> ShrinkAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        ShrinkAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<ShrinkAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for ShrinkAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("ShrinkAttrs");
+        ds.field("bias", &self.bias());
+        ds.field("lambd", &self.lambd());
+        ds.finish()
+    }
+}
+pub enum SoftmaxAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SoftmaxAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SoftmaxAttrs<'a> {
+    type Inner = SoftmaxAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> SoftmaxAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_FLUSH_NANS_TO_ZERO: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        SoftmaxAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args SoftmaxAttrsArgs,
+    ) -> flatbuffers::WIPOffset<SoftmaxAttrs<'bldr>> {
+        let mut builder = SoftmaxAttrsBuilder::new(_fbb);
+        builder.add_axis(args.axis);
+        builder.add_flush_nans_to_zero(args.flush_nans_to_zero);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn axis(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<i32>(SoftmaxAttrs::VT_AXIS, Some(0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn flush_nans_to_zero(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(SoftmaxAttrs::VT_FLUSH_NANS_TO_ZERO, Some(false))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for SoftmaxAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<bool>("flush_nans_to_zero", Self::VT_FLUSH_NANS_TO_ZERO, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct SoftmaxAttrsArgs {
+    pub axis: i32,
+    pub flush_nans_to_zero: bool,
+}
+impl<'a> Default for SoftmaxAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        SoftmaxAttrsArgs {
+            axis: 0,
+            flush_nans_to_zero: false,
+        }
+    }
+}
+
+pub struct SoftmaxAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SoftmaxAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_.push_slot::<i32>(SoftmaxAttrs::VT_AXIS, axis, 0);
+    }
+    #[inline]
+    pub fn add_flush_nans_to_zero(&mut self, flush_nans_to_zero: bool) {
+        self.fbb_.push_slot::<bool>(
+            SoftmaxAttrs::VT_FLUSH_NANS_TO_ZERO,
+            flush_nans_to_zero,
+            false,
+        );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> SoftmaxAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        SoftmaxAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<SoftmaxAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for SoftmaxAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("SoftmaxAttrs");
+        ds.field("axis", &self.axis());
+        ds.field("flush_nans_to_zero", &self.flush_nans_to_zero());
+        ds.finish()
+    }
+}
+pub enum SpaceToDepthAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SpaceToDepthAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SpaceToDepthAttrs<'a> {
+    type Inner = SpaceToDepthAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> SpaceToDepthAttrs<'a> {
+    pub const VT_BLOCK_SIZE: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        SpaceToDepthAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args SpaceToDepthAttrsArgs,
+    ) -> flatbuffers::WIPOffset<SpaceToDepthAttrs<'bldr>> {
+        let mut builder = SpaceToDepthAttrsBuilder::new(_fbb);
+        builder.add_block_size(args.block_size);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn block_size(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(SpaceToDepthAttrs::VT_BLOCK_SIZE, Some(0))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for SpaceToDepthAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<u32>("block_size", Self::VT_BLOCK_SIZE, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct SpaceToDepthAttrsArgs {
+    pub block_size: u32,
+}
+impl<'a> Default for SpaceToDepthAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        SpaceToDepthAttrsArgs { block_size: 0 }
+    }
+}
+
+pub struct SpaceToDepthAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SpaceToDepthAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_block_size(&mut self, block_size: u32) {
+        self.fbb_
+            .push_slot::<u32>(SpaceToDepthAttrs::VT_BLOCK_SIZE, block_size, 0);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> SpaceToDepthAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        SpaceToDepthAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<SpaceToDepthAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for SpaceToDepthAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("SpaceToDepthAttrs");
+        ds.field("block_size", &self.block_size());
+        ds.finish()
+    }
+}
+pub enum SplitAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SplitAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SplitAttrs<'a> {
+    type Inner = SplitAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> SplitAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_NUM_OUTPUTS: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        SplitAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args SplitAttrsArgs,
+    ) -> flatbuffers::WIPOffset<SplitAttrs<'bldr>> {
+        let mut builder = SplitAttrsBuilder::new(_fbb);
+        if let Some(x) = args.num_outputs {
+            builder.add_num_outputs(x);
+        }
+        builder.add_axis(args.axis);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn axis(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<i32>(SplitAttrs::VT_AXIS, Some(0)).unwrap() }
+    }
+    #[inline]
+    pub fn num_outputs(&self) -> Option<i32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<i32>(SplitAttrs::VT_NUM_OUTPUTS, None) }
+    }
+}
+
+impl flatbuffers::Verifiable for SplitAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<i32>("num_outputs", Self::VT_NUM_OUTPUTS, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct SplitAttrsArgs {
+    pub axis: i32,
+    pub num_outputs: Option<i32>,
+}
+impl<'a> Default for SplitAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        SplitAttrsArgs {
+            axis: 0,
+            num_outputs: None,
+        }
+    }
+}
+
+pub struct SplitAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SplitAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_.push_slot::<i32>(SplitAttrs::VT_AXIS, axis, 0);
+    }
+    #[inline]
+    pub fn add_num_outputs(&mut self, num_outputs: i32) {
+        self.fbb_
+            .push_slot_always::<i32>(SplitAttrs::VT_NUM_OUTPUTS, num_outputs);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> SplitAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        SplitAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<SplitAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for SplitAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("SplitAttrs");
+        ds.field("axis", &self.axis());
+        ds.field("num_outputs", &self.num_outputs());
+        ds.finish()
+    }
+}
+pub enum SplitToSequenceAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SplitToSequenceAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SplitToSequenceAttrs<'a> {
+    type Inner = SplitToSequenceAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> SplitToSequenceAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_KEEP_DIMS: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        SplitToSequenceAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args SplitToSequenceAttrsArgs,
+    ) -> flatbuffers::WIPOffset<SplitToSequenceAttrs<'bldr>> {
+        let mut builder = SplitToSequenceAttrsBuilder::new(_fbb);
+        builder.add_axis(args.axis);
+        builder.add_keep_dims(args.keep_dims);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn axis(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<i32>(SplitToSequenceAttrs::VT_AXIS, Some(0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn keep_dims(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(SplitToSequenceAttrs::VT_KEEP_DIMS, Some(true))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for SplitToSequenceAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<bool>("keep_dims", Self::VT_KEEP_DIMS, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct SplitToSequenceAttrsArgs {
+    pub axis: i32,
+    pub keep_dims: bool,
+}
+impl<'a> Default for SplitToSequenceAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        SplitToSequenceAttrsArgs {
+            axis: 0,
+            keep_dims: true,
+        }
+    }
+}
+
+pub struct SplitToSequenceAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SplitToSequenceAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_
+            .push_slot::<i32>(SplitToSequenceAttrs::VT_AXIS, axis, 0);
+    }
+    #[inline]
+    pub fn add_keep_dims(&mut self, keep_dims: bool) {
+        self.fbb_
+            .push_slot::<bool>(SplitToSequenceAttrs::VT_KEEP_DIMS, keep_dims, true);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> SplitToSequenceAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        SplitToSequenceAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<SplitToSequenceAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for SplitToSequenceAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("SplitToSequenceAttrs");
+        ds.field("axis", &self.axis());
+        ds.field("keep_dims", &self.keep_dims());
+        ds.finish()
+    }
+}
+pub enum STFTAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct STFTAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for STFTAttrs<'a> {
+    type Inner = STFTAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> STFTAttrs<'a> {
+    pub const VT_ONESIDED: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        STFTAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args STFTAttrsArgs,
+    ) -> flatbuffers::WIPOffset<STFTAttrs<'bldr>> {
+        let mut builder = STFTAttrsBuilder::new(_fbb);
+        builder.add_onesided(args.onesided);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn onesided(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(STFTAttrs::VT_ONESIDED, Some(true))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for STFTAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<bool>("onesided", Self::VT_ONESIDED, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct STFTAttrsArgs {
+    pub onesided: bool,
+}
+impl<'a> Default for STFTAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        STFTAttrsArgs { onesided: true }
+    }
+}
+
+pub struct STFTAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> STFTAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_onesided(&mut self, onesided: bool) {
+        self.fbb_
+            .push_slot::<bool>(STFTAttrs::VT_ONESIDED, onesided, true);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> STFTAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        STFTAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<STFTAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for STFTAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("STFTAttrs");
+        ds.field("onesided", &self.onesided());
+        ds.finish()
+    }
+}
+pub enum WindowAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct WindowAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for WindowAttrs<'a> {
+    type Inner = WindowAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> WindowAttrs<'a> {
+    pub const VT_PERIODIC: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        WindowAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args WindowAttrsArgs,
+    ) -> flatbuffers::WIPOffset<WindowAttrs<'bldr>> {
+        let mut builder = WindowAttrsBuilder::new(_fbb);
+        builder.add_periodic(args.periodic);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn periodic(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(WindowAttrs::VT_PERIODIC, Some(false))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for WindowAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<bool>("periodic", Self::VT_PERIODIC, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct WindowAttrsArgs {
+    pub periodic: bool,
+}
+impl<'a> Default for WindowAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        WindowAttrsArgs { periodic: false }
+    }
+}
+
+pub struct WindowAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> WindowAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_periodic(&mut self, periodic: bool) {
+        self.fbb_
+            .push_slot::<bool>(WindowAttrs::VT_PERIODIC, periodic, false);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> WindowAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        WindowAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<WindowAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for WindowAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("WindowAttrs");
+        ds.field("periodic", &self.periodic());
+        ds.finish()
+    }
+}
+pub enum SwishAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct SwishAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for SwishAttrs<'a> {
+    type Inner = SwishAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> SwishAttrs<'a> {
+    pub const VT_BETA: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        SwishAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args SwishAttrsArgs,
+    ) -> flatbuffers::WIPOffset<SwishAttrs<'bldr>> {
+        let mut builder = SwishAttrsBuilder::new(_fbb);
+        builder.add_beta(args.beta);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn beta(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(SwishAttrs::VT_BETA, Some(0.0))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for SwishAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<f32>("beta", Self::VT_BETA, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct SwishAttrsArgs {
+    pub beta: f32,
+}
+impl<'a> Default for SwishAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        SwishAttrsArgs { beta: 0.0 }
+    }
+}
+
+pub struct SwishAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> SwishAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_beta(&mut self, beta: f32) {
+        self.fbb_.push_slot::<f32>(SwishAttrs::VT_BETA, beta, 0.0);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> SwishAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        SwishAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<SwishAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for SwishAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("SwishAttrs");
+        ds.field("beta", &self.beta());
+        ds.finish()
+    }
+}
+pub enum ThresholdedReluAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ThresholdedReluAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ThresholdedReluAttrs<'a> {
+    type Inner = ThresholdedReluAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> ThresholdedReluAttrs<'a> {
+    pub const VT_ALPHA: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        ThresholdedReluAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args ThresholdedReluAttrsArgs,
+    ) -> flatbuffers::WIPOffset<ThresholdedReluAttrs<'bldr>> {
+        let mut builder = ThresholdedReluAttrsBuilder::new(_fbb);
+        builder.add_alpha(args.alpha);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn alpha(&self) -> f32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<f32>(ThresholdedReluAttrs::VT_ALPHA, Some(0.0))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for ThresholdedReluAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<f32>("alpha", Self::VT_ALPHA, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ThresholdedReluAttrsArgs {
+    pub alpha: f32,
+}
+impl<'a> Default for ThresholdedReluAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        ThresholdedReluAttrsArgs { alpha: 0.0 }
+    }
+}
+
+pub struct ThresholdedReluAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ThresholdedReluAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_alpha(&mut self, alpha: f32) {
+        self.fbb_
+            .push_slot::<f32>(ThresholdedReluAttrs::VT_ALPHA, alpha, 0.0);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> ThresholdedReluAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        ThresholdedReluAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<ThresholdedReluAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for ThresholdedReluAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("ThresholdedReluAttrs");
+        ds.field("alpha", &self.alpha());
+        ds.finish()
+    }
+}
+pub enum TopKAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TopKAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TopKAttrs<'a> {
+    type Inner = TopKAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> TopKAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_LARGEST: flatbuffers::VOffsetT = 6;
+    pub const VT_SORTED: flatbuffers::VOffsetT = 8;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        TopKAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args TopKAttrsArgs,
+    ) -> flatbuffers::WIPOffset<TopKAttrs<'bldr>> {
+        let mut builder = TopKAttrsBuilder::new(_fbb);
+        builder.add_axis(args.axis);
+        builder.add_sorted(args.sorted);
+        builder.add_largest(args.largest);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn axis(&self) -> i32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<i32>(TopKAttrs::VT_AXIS, Some(0)).unwrap() }
+    }
+    #[inline]
+    pub fn largest(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(TopKAttrs::VT_LARGEST, Some(false))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn sorted(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(TopKAttrs::VT_SORTED, Some(false))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for TopKAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<bool>("largest", Self::VT_LARGEST, false)?
+            .visit_field::<bool>("sorted", Self::VT_SORTED, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct TopKAttrsArgs {
+    pub axis: i32,
+    pub largest: bool,
+    pub sorted: bool,
+}
+impl<'a> Default for TopKAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        TopKAttrsArgs {
+            axis: 0,
+            largest: false,
+            sorted: false,
+        }
+    }
+}
+
+pub struct TopKAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TopKAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_.push_slot::<i32>(TopKAttrs::VT_AXIS, axis, 0);
+    }
+    #[inline]
+    pub fn add_largest(&mut self, largest: bool) {
+        self.fbb_
+            .push_slot::<bool>(TopKAttrs::VT_LARGEST, largest, false);
+    }
+    #[inline]
+    pub fn add_sorted(&mut self, sorted: bool) {
+        self.fbb_
+            .push_slot::<bool>(TopKAttrs::VT_SORTED, sorted, false);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> TopKAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        TopKAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<TopKAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for TopKAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("TopKAttrs");
+        ds.field("axis", &self.axis());
+        ds.field("largest", &self.largest());
+        ds.field("sorted", &self.sorted());
+        ds.finish()
+    }
+}
+pub enum InputTransformOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct InputTransform<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for InputTransform<'a> {
+    type Inner = InputTransform<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> InputTransform<'a> {
+    pub const VT_INPUT_INDEX: flatbuffers::VOffsetT = 4;
+    pub const VT_PERM: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        InputTransform { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args InputTransformArgs<'args>,
+    ) -> flatbuffers::WIPOffset<InputTransform<'bldr>> {
+        let mut builder = InputTransformBuilder::new(_fbb);
+        if let Some(x) = args.perm {
+            builder.add_perm(x);
+        }
+        builder.add_input_index(args.input_index);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn input_index(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(InputTransform::VT_INPUT_INDEX, Some(0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn perm(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    InputTransform::VT_PERM,
+                    None,
+                )
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for InputTransform<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<u32>("input_index", Self::VT_INPUT_INDEX, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "perm",
+                Self::VT_PERM,
+                false,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct InputTransformArgs<'a> {
+    pub input_index: u32,
+    pub perm: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+}
+impl<'a> Default for InputTransformArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        InputTransformArgs {
+            input_index: 0,
+            perm: None,
+        }
+    }
+}
+
+pub struct InputTransformBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> InputTransformBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_input_index(&mut self, input_index: u32) {
+        self.fbb_
+            .push_slot::<u32>(InputTransform::VT_INPUT_INDEX, input_index, 0);
+    }
+    #[inline]
+    pub fn add_perm(&mut self, perm: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(InputTransform::VT_PERM, perm);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> InputTransformBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        InputTransformBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<InputTransform<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for InputTransform<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("InputTransform");
+        ds.field("input_index", &self.input_index());
+        ds.field("perm", &self.perm());
+        ds.finish()
+    }
+}
+pub enum TransformInputsAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TransformInputsAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TransformInputsAttrs<'a> {
+    type Inner = TransformInputsAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> TransformInputsAttrs<'a> {
+    pub const VT_OP: flatbuffers::VOffsetT = 4;
+    pub const VT_TRANSFORMS: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        TransformInputsAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args TransformInputsAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<TransformInputsAttrs<'bldr>> {
+        let mut builder = TransformInputsAttrsBuilder::new(_fbb);
+        if let Some(x) = args.transforms {
+            builder.add_transforms(x);
+        }
+        if let Some(x) = args.op {
+            builder.add_op(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn op(&self) -> OperatorNode<'a> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<OperatorNode>>(
+                    TransformInputsAttrs::VT_OP,
+                    None,
+                )
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn transforms(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<InputTransform<'a>>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<InputTransform>>,
+            >>(TransformInputsAttrs::VT_TRANSFORMS, None)
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for TransformInputsAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<OperatorNode>>("op", Self::VT_OP, true)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<InputTransform>>,
+            >>("transforms", Self::VT_TRANSFORMS, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct TransformInputsAttrsArgs<'a> {
+    pub op: Option<flatbuffers::WIPOffset<OperatorNode<'a>>>,
+    pub transforms: Option<
+        flatbuffers::WIPOffset<
+            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<InputTransform<'a>>>,
+        >,
+    >,
+}
+impl<'a> Default for TransformInputsAttrsArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        TransformInputsAttrsArgs {
+            op: None, // required field
+            transforms: None,
+        }
+    }
+}
+
+pub struct TransformInputsAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TransformInputsAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_op(&mut self, op: flatbuffers::WIPOffset<OperatorNode<'b>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<OperatorNode>>(
+                TransformInputsAttrs::VT_OP,
+                op,
+            );
+    }
+    #[inline]
+    pub fn add_transforms(
+        &mut self,
+        transforms: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<InputTransform<'b>>>,
+        >,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            TransformInputsAttrs::VT_TRANSFORMS,
+            transforms,
+        );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> TransformInputsAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        TransformInputsAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<TransformInputsAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        self.fbb_.required(o, TransformInputsAttrs::VT_OP, "op");
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for TransformInputsAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("TransformInputsAttrs");
+        ds.field("op", &self.op());
+        ds.field("transforms", &self.transforms());
+        ds.finish()
+    }
+}
+pub enum TransposeAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TransposeAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TransposeAttrs<'a> {
+    type Inner = TransposeAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> TransposeAttrs<'a> {
+    pub const VT_PERM: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        TransposeAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args TransposeAttrsArgs<'args>,
+    ) -> flatbuffers::WIPOffset<TransposeAttrs<'bldr>> {
+        let mut builder = TransposeAttrsBuilder::new(_fbb);
+        if let Some(x) = args.perm {
+            builder.add_perm(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn perm(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(
+                    TransposeAttrs::VT_PERM,
+                    None,
+                )
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for TransposeAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>(
+                "perm",
+                Self::VT_PERM,
+                false,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct TransposeAttrsArgs<'a> {
+    pub perm: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+}
+impl<'a> Default for TransposeAttrsArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        TransposeAttrsArgs { perm: None }
+    }
+}
+
+pub struct TransposeAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TransposeAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_perm(&mut self, perm: flatbuffers::WIPOffset<flatbuffers::Vector<'b, u32>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(TransposeAttrs::VT_PERM, perm);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> TransposeAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        TransposeAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<TransposeAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for TransposeAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("TransposeAttrs");
+        ds.field("perm", &self.perm());
+        ds.finish()
+    }
+}
+pub enum UniqueAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct UniqueAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for UniqueAttrs<'a> {
+    type Inner = UniqueAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> UniqueAttrs<'a> {
+    pub const VT_AXIS: flatbuffers::VOffsetT = 4;
+    pub const VT_SORTED: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        UniqueAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args UniqueAttrsArgs,
+    ) -> flatbuffers::WIPOffset<UniqueAttrs<'bldr>> {
+        let mut builder = UniqueAttrsBuilder::new(_fbb);
+        if let Some(x) = args.axis {
+            builder.add_axis(x);
+        }
+        builder.add_sorted(args.sorted);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn axis(&self) -> Option<i32> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe { self._tab.get::<i32>(UniqueAttrs::VT_AXIS, None) }
+    }
+
+    #[inline]
+    pub fn sorted(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(UniqueAttrs::VT_SORTED, Some(false))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for UniqueAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<i32>("axis", Self::VT_AXIS, false)?
+            .visit_field::<bool>("sorted", Self::VT_SORTED, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct UniqueAttrsArgs {
+    pub axis: Option<i32>,
+    pub sorted: bool,
+}
+impl<'a> Default for UniqueAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        UniqueAttrsArgs {
+            axis: None,
+            sorted: false,
+        }
+    }
+}
+
+pub struct UniqueAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> UniqueAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_axis(&mut self, axis: i32) {
+        self.fbb_
+            .push_slot_always::<i32>(UniqueAttrs::VT_AXIS, axis);
+    }
+    #[inline]
+    pub fn add_sorted(&mut self, sorted: bool) {
+        self.fbb_
+            .push_slot::<bool>(UniqueAttrs::VT_SORTED, sorted, false);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> UniqueAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        UniqueAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<UniqueAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for UniqueAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("UniqueAttrs");
+        ds.field("axis", &self.axis());
+        ds.field("sorted", &self.sorted());
+        ds.finish()
+    }
+}
+pub enum TriluAttrsOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TriluAttrs<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TriluAttrs<'a> {
+    type Inner = TriluAttrs<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> TriluAttrs<'a> {
+    pub const VT_UPPER: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        TriluAttrs { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args TriluAttrsArgs,
+    ) -> flatbuffers::WIPOffset<TriluAttrs<'bldr>> {
+        let mut builder = TriluAttrsBuilder::new(_fbb);
+        builder.add_upper(args.upper);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn upper(&self) -> bool {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<bool>(TriluAttrs::VT_UPPER, Some(false))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for TriluAttrs<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<bool>("upper", Self::VT_UPPER, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct TriluAttrsArgs {
+    pub upper: bool,
+}
+impl<'a> Default for TriluAttrsArgs {
+    #[inline]
+    fn default() -> Self {
+        TriluAttrsArgs { upper: false }
+    }
+}
+
+pub struct TriluAttrsBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TriluAttrsBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_upper(&mut self, upper: bool) {
+        self.fbb_
+            .push_slot::<bool>(TriluAttrs::VT_UPPER, upper, false);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> TriluAttrsBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        TriluAttrsBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<TriluAttrs<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for TriluAttrs<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("TriluAttrs");
+        ds.field("upper", &self.upper());
+        ds.finish()
+    }
+}
+pub enum OperatorNodeOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct OperatorNode<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for OperatorNode<'a> {
+    type Inner = OperatorNode<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> OperatorNode<'a> {
+    pub const VT_TYPE_: flatbuffers::VOffsetT = 4;
+    pub const VT_ATTRS_TYPE: flatbuffers::VOffsetT = 6;
+    pub const VT_ATTRS: flatbuffers::VOffsetT = 8;
+    pub const VT_INPUTS: flatbuffers::VOffsetT = 10;
+    pub const VT_OUTPUTS: flatbuffers::VOffsetT = 12;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        OperatorNode { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args OperatorNodeArgs<'args>,
+    ) -> flatbuffers::WIPOffset<OperatorNode<'bldr>> {
+        let mut builder = OperatorNodeBuilder::new(_fbb);
+        if let Some(x) = args.outputs {
+            builder.add_outputs(x);
+        }
+        if let Some(x) = args.inputs {
+            builder.add_inputs(x);
+        }
+        if let Some(x) = args.attrs {
+            builder.add_attrs(x);
+        }
+        builder.add_attrs_type(args.attrs_type);
+        builder.add_type_(args.type_);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn type_(&self) -> OperatorType {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<OperatorType>(OperatorNode::VT_TYPE_, Some(OperatorType::Add))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn attrs_type(&self) -> OperatorAttrs {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<OperatorAttrs>(OperatorNode::VT_ATTRS_TYPE, Some(OperatorAttrs::NONE))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn attrs(&self) -> Option<flatbuffers::Table<'a>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(
+                    OperatorNode::VT_ATTRS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn inputs(&self) -> Option<flatbuffers::Vector<'a, i32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
+                    OperatorNode::VT_INPUTS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    pub fn outputs(&self) -> Option<flatbuffers::Vector<'a, i32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
+                    OperatorNode::VT_OUTPUTS,
+                    None,
+                )
+        }
+    }
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_arg_max_attrs(&self) -> Option<ArgMaxAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ArgMaxAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ArgMaxAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_average_pool_attrs(&self) -> Option<AveragePoolAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::AveragePoolAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { AveragePoolAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_batch_normalization_attrs(&self) -> Option<BatchNormalizationAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::BatchNormalizationAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { BatchNormalizationAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_cast_attrs(&self) -> Option<CastAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::CastAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { CastAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_concat_attrs(&self) -> Option<ConcatAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ConcatAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ConcatAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_constant_of_shape_attrs(&self) -> Option<ConstantOfShapeAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ConstantOfShapeAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ConstantOfShapeAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_conv_attrs(&self) -> Option<ConvAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ConvAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ConvAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_conv_transpose_attrs(&self) -> Option<ConvTransposeAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ConvTransposeAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ConvTransposeAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_flatten_attrs(&self) -> Option<FlattenAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::FlattenAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { FlattenAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_gather_attrs(&self) -> Option<GatherAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::GatherAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { GatherAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_gemm_attrs(&self) -> Option<GemmAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::GemmAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { GemmAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_gruattrs(&self) -> Option<GRUAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::GRUAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { GRUAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_leaky_relu_attrs(&self) -> Option<LeakyReluAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::LeakyReluAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { LeakyReluAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_lstmattrs(&self) -> Option<LSTMAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::LSTMAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { LSTMAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_max_pool_attrs(&self) -> Option<MaxPoolAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::MaxPoolAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { MaxPoolAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_reduce_mean_attrs(&self) -> Option<ReduceMeanAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ReduceMeanAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ReduceMeanAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_reshape_attrs(&self) -> Option<ReshapeAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ReshapeAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ReshapeAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_resize_attrs(&self) -> Option<ResizeAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ResizeAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ResizeAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_split_attrs(&self) -> Option<SplitAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::SplitAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { SplitAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_softmax_attrs(&self) -> Option<SoftmaxAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::SoftmaxAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { SoftmaxAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_transpose_attrs(&self) -> Option<TransposeAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::TransposeAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { TransposeAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_mod_attrs(&self) -> Option<ModAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ModAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ModAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_scatter_elements_attrs(&self) -> Option<ScatterElementsAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ScatterElementsAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ScatterElementsAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_one_hot_attrs(&self) -> Option<OneHotAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::OneHotAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { OneHotAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_top_kattrs(&self) -> Option<TopKAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::TopKAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { TopKAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_hard_sigmoid_attrs(&self) -> Option<HardSigmoidAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::HardSigmoidAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { HardSigmoidAttrs::init_from_table(t) }
+            })
+        } else {
+            None
         }
     }
+
     #[inline]
-    pub fn outputs(&self) -> Option<flatbuffers::Vector<'a, i32>> {
-        // Safety:
-        // Created from valid Table for this object
-        // which contains a valid value in this slot
-        unsafe {
-            self._tab
-                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
-                    OperatorNode::VT_OUTPUTS,
-                    None,
-                )
+    #[allow(non_snake_case)]
+    pub fn attrs_as_trilu_attrs(&self) -> Option<TriluAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::TriluAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { TriluAttrs::init_from_table(t) }
+            })
+        } else {
+            None
         }
     }
+
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_arg_max_attrs(&self) -> Option<ArgMaxAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ArgMaxAttrs {
+    pub fn attrs_as_scatter_ndattrs(&self) -> Option<ScatterNDAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ScatterNDAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { ArgMaxAttrs::init_from_table(t) }
+                unsafe { ScatterNDAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -9706,13 +15218,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_average_pool_attrs(&self) -> Option<AveragePoolAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::AveragePoolAttrs {
+    pub fn attrs_as_non_max_suppression_attrs(&self) -> Option<NonMaxSuppressionAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::NonMaxSuppressionAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { AveragePoolAttrs::init_from_table(t) }
+                unsafe { NonMaxSuppressionAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -9721,13 +15233,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_batch_normalization_attrs(&self) -> Option<BatchNormalizationAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::BatchNormalizationAttrs {
+    pub fn attrs_as_layer_normalization_attrs(&self) -> Option<LayerNormalizationAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::LayerNormalizationAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { BatchNormalizationAttrs::init_from_table(t) }
+                unsafe { LayerNormalizationAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -9736,13 +15248,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_cast_attrs(&self) -> Option<CastAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::CastAttrs {
+    pub fn attrs_as_random_uniform_attrs(&self) -> Option<RandomUniformAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::RandomUniformAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { CastAttrs::init_from_table(t) }
+                unsafe { RandomUniformAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -9751,13 +15263,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_concat_attrs(&self) -> Option<ConcatAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ConcatAttrs {
+    pub fn attrs_as_elu_attrs(&self) -> Option<EluAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::EluAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { ConcatAttrs::init_from_table(t) }
+                unsafe { EluAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -9766,13 +15278,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_constant_of_shape_attrs(&self) -> Option<ConstantOfShapeAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ConstantOfShapeAttrs {
+    pub fn attrs_as_random_uniform_like_attrs(&self) -> Option<RandomUniformLikeAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::RandomUniformLikeAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { ConstantOfShapeAttrs::init_from_table(t) }
+                unsafe { RandomUniformLikeAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -9781,13 +15293,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_conv_attrs(&self) -> Option<ConvAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ConvAttrs {
+    pub fn attrs_as_random_normal_attrs(&self) -> Option<RandomNormalAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::RandomNormalAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { ConvAttrs::init_from_table(t) }
+                unsafe { RandomNormalAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -9796,13 +15308,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_conv_transpose_attrs(&self) -> Option<ConvTransposeAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ConvTransposeAttrs {
+    pub fn attrs_as_random_normal_like_attrs(&self) -> Option<RandomNormalLikeAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::RandomNormalLikeAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { ConvTransposeAttrs::init_from_table(t) }
+                unsafe { RandomNormalLikeAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -9811,13 +15323,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_flatten_attrs(&self) -> Option<FlattenAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::FlattenAttrs {
+    pub fn attrs_as_gather_ndattrs(&self) -> Option<GatherNDAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::GatherNDAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { FlattenAttrs::init_from_table(t) }
+                unsafe { GatherNDAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -9826,13 +15338,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_gather_attrs(&self) -> Option<GatherAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::GatherAttrs {
+    pub fn attrs_as_gelu_attrs(&self) -> Option<GeluAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::GeluAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { GatherAttrs::init_from_table(t) }
+                unsafe { GeluAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -9841,13 +15353,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_gemm_attrs(&self) -> Option<GemmAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::GemmAttrs {
+    pub fn attrs_as_einsum_attrs(&self) -> Option<EinsumAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::EinsumAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { GemmAttrs::init_from_table(t) }
+                unsafe { EinsumAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -9856,13 +15368,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_gruattrs(&self) -> Option<GRUAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::GRUAttrs {
+    pub fn attrs_as_if_attrs(&self) -> Option<IfAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::IfAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { GRUAttrs::init_from_table(t) }
+                unsafe { IfAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -9871,13 +15383,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_leaky_relu_attrs(&self) -> Option<LeakyReluAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::LeakyReluAttrs {
+    pub fn attrs_as_pad_attrs(&self) -> Option<PadAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::PadAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { LeakyReluAttrs::init_from_table(t) }
+                unsafe { PadAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -9886,13 +15398,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_lstmattrs(&self) -> Option<LSTMAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::LSTMAttrs {
+    pub fn attrs_as_dequantize_linear_attrs(&self) -> Option<DequantizeLinearAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::DequantizeLinearAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { LSTMAttrs::init_from_table(t) }
+                unsafe { DequantizeLinearAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -9901,13 +15413,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_max_pool_attrs(&self) -> Option<MaxPoolAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::MaxPoolAttrs {
+    pub fn attrs_as_quantize_linear_attrs(&self) -> Option<QuantizeLinearAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::QuantizeLinearAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { MaxPoolAttrs::init_from_table(t) }
+                unsafe { QuantizeLinearAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -9916,13 +15428,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_reduce_mean_attrs(&self) -> Option<ReduceMeanAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ReduceMeanAttrs {
+    pub fn attrs_as_depth_to_space_attrs(&self) -> Option<DepthToSpaceAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::DepthToSpaceAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { ReduceMeanAttrs::init_from_table(t) }
+                unsafe { DepthToSpaceAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -9931,13 +15443,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_reshape_attrs(&self) -> Option<ReshapeAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ReshapeAttrs {
+    pub fn attrs_as_cast_like_attrs(&self) -> Option<CastLikeAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::CastLikeAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { ReshapeAttrs::init_from_table(t) }
+                unsafe { CastLikeAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -9946,13 +15458,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_resize_attrs(&self) -> Option<ResizeAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ResizeAttrs {
+    pub fn attrs_as_shape_attrs(&self) -> Option<ShapeAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ShapeAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { ResizeAttrs::init_from_table(t) }
+                unsafe { ShapeAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -9961,13 +15473,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_split_attrs(&self) -> Option<SplitAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::SplitAttrs {
+    pub fn attrs_as_dropout_attrs(&self) -> Option<DropoutAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::DropoutAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { SplitAttrs::init_from_table(t) }
+                unsafe { DropoutAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -9976,13 +15488,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_softmax_attrs(&self) -> Option<SoftmaxAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::SoftmaxAttrs {
+    pub fn attrs_as_eye_like_attrs(&self) -> Option<EyeLikeAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::EyeLikeAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { SoftmaxAttrs::init_from_table(t) }
+                unsafe { EyeLikeAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -9991,13 +15503,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_transpose_attrs(&self) -> Option<TransposeAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::TransposeAttrs {
+    pub fn attrs_as_is_inf_attrs(&self) -> Option<IsInfAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::IsInfAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { TransposeAttrs::init_from_table(t) }
+                unsafe { IsInfAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10006,13 +15518,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_mod_attrs(&self) -> Option<ModAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ModAttrs {
+    pub fn attrs_as_loop_attrs(&self) -> Option<LoopAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::LoopAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { ModAttrs::init_from_table(t) }
+                unsafe { LoopAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10021,13 +15533,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_scatter_elements_attrs(&self) -> Option<ScatterElementsAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ScatterElementsAttrs {
+    pub fn attrs_as_sequence_empty_attrs(&self) -> Option<SequenceEmptyAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::SequenceEmptyAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { ScatterElementsAttrs::init_from_table(t) }
+                unsafe { SequenceEmptyAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10036,13 +15548,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_one_hot_attrs(&self) -> Option<OneHotAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::OneHotAttrs {
+    pub fn attrs_as_concat_from_sequence_attrs(&self) -> Option<ConcatFromSequenceAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ConcatFromSequenceAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { OneHotAttrs::init_from_table(t) }
+                unsafe { ConcatFromSequenceAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10051,13 +15563,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_top_kattrs(&self) -> Option<TopKAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::TopKAttrs {
+    pub fn attrs_as_split_to_sequence_attrs(&self) -> Option<SplitToSequenceAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::SplitToSequenceAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { TopKAttrs::init_from_table(t) }
+                unsafe { SplitToSequenceAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10066,13 +15578,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_hard_sigmoid_attrs(&self) -> Option<HardSigmoidAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::HardSigmoidAttrs {
+    pub fn attrs_as_grid_sample_attrs(&self) -> Option<GridSampleAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::GridSampleAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { HardSigmoidAttrs::init_from_table(t) }
+                unsafe { GridSampleAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10081,13 +15593,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_trilu_attrs(&self) -> Option<TriluAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::TriluAttrs {
+    pub fn attrs_as_stftattrs(&self) -> Option<STFTAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::STFTAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { TriluAttrs::init_from_table(t) }
+                unsafe { STFTAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10096,13 +15608,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_scatter_ndattrs(&self) -> Option<ScatterNDAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ScatterNDAttrs {
+    pub fn attrs_as_compute_shape_attrs(&self) -> Option<ComputeShapeAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ComputeShapeAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { ScatterNDAttrs::init_from_table(t) }
+                unsafe { ComputeShapeAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10111,13 +15623,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_non_max_suppression_attrs(&self) -> Option<NonMaxSuppressionAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::NonMaxSuppressionAttrs {
+    pub fn attrs_as_fused_mat_mul_attrs(&self) -> Option<FusedMatMulAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::FusedMatMulAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { NonMaxSuppressionAttrs::init_from_table(t) }
+                unsafe { FusedMatMulAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10126,13 +15638,15 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_layer_normalization_attrs(&self) -> Option<LayerNormalizationAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::LayerNormalizationAttrs {
+    pub fn attrs_as_grouped_query_attention_mat_mul_attrs(
+        &self,
+    ) -> Option<GroupedQueryAttentionMatMulAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::GroupedQueryAttentionMatMulAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { LayerNormalizationAttrs::init_from_table(t) }
+                unsafe { GroupedQueryAttentionMatMulAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10141,13 +15655,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_random_uniform_attrs(&self) -> Option<RandomUniformAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::RandomUniformAttrs {
+    pub fn attrs_as_repeat_interleave_attrs(&self) -> Option<RepeatInterleaveAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::RepeatInterleaveAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { RandomUniformAttrs::init_from_table(t) }
+                unsafe { RepeatInterleaveAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10156,13 +15670,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_elu_attrs(&self) -> Option<EluAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::EluAttrs {
+    pub fn attrs_as_swish_attrs(&self) -> Option<SwishAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::SwishAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { EluAttrs::init_from_table(t) }
+                unsafe { SwishAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10171,13 +15685,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_random_uniform_like_attrs(&self) -> Option<RandomUniformLikeAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::RandomUniformLikeAttrs {
+    pub fn attrs_as_transform_inputs_attrs(&self) -> Option<TransformInputsAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::TransformInputsAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { RandomUniformLikeAttrs::init_from_table(t) }
+                unsafe { TransformInputsAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10186,13 +15700,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_random_normal_attrs(&self) -> Option<RandomNormalAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::RandomNormalAttrs {
+    pub fn attrs_as_attention_attrs(&self) -> Option<AttentionAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::AttentionAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { RandomNormalAttrs::init_from_table(t) }
+                unsafe { AttentionAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10201,13 +15715,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_random_normal_like_attrs(&self) -> Option<RandomNormalLikeAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::RandomNormalLikeAttrs {
+    pub fn attrs_as_group_query_attention_attrs(&self) -> Option<GroupQueryAttentionAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::GroupQueryAttentionAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { RandomNormalLikeAttrs::init_from_table(t) }
+                unsafe { GroupQueryAttentionAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10216,13 +15730,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_gather_ndattrs(&self) -> Option<GatherNDAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::GatherNDAttrs {
+    pub fn attrs_as_rotary_embedding_attrs(&self) -> Option<RotaryEmbeddingAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::RotaryEmbeddingAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { GatherNDAttrs::init_from_table(t) }
+                unsafe { RotaryEmbeddingAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10231,13 +15745,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_gelu_attrs(&self) -> Option<GeluAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::GeluAttrs {
+    pub fn attrs_as_space_to_depth_attrs(&self) -> Option<SpaceToDepthAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::SpaceToDepthAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { GeluAttrs::init_from_table(t) }
+                unsafe { SpaceToDepthAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10246,13 +15760,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_einsum_attrs(&self) -> Option<EinsumAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::EinsumAttrs {
+    pub fn attrs_as_dftattrs(&self) -> Option<DFTAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::DFTAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { EinsumAttrs::init_from_table(t) }
+                unsafe { DFTAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10261,13 +15775,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_if_attrs(&self) -> Option<IfAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::IfAttrs {
+    pub fn attrs_as_window_attrs(&self) -> Option<WindowAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::WindowAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { IfAttrs::init_from_table(t) }
+                unsafe { WindowAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10276,13 +15790,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_pad_attrs(&self) -> Option<PadAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::PadAttrs {
+    pub fn attrs_as_group_normalization_attrs(&self) -> Option<GroupNormalizationAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::GroupNormalizationAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { PadAttrs::init_from_table(t) }
+                unsafe { GroupNormalizationAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10291,13 +15805,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_dequantize_linear_attrs(&self) -> Option<DequantizeLinearAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::DequantizeLinearAttrs {
+    pub fn attrs_as_col2_im_attrs(&self) -> Option<Col2ImAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::Col2ImAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { DequantizeLinearAttrs::init_from_table(t) }
+                unsafe { Col2ImAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10306,13 +15820,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_quantize_linear_attrs(&self) -> Option<QuantizeLinearAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::QuantizeLinearAttrs {
+    pub fn attrs_as_roi_align_attrs(&self) -> Option<RoiAlignAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::RoiAlignAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { QuantizeLinearAttrs::init_from_table(t) }
+                unsafe { RoiAlignAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10321,13 +15835,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_depth_to_space_attrs(&self) -> Option<DepthToSpaceAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::DepthToSpaceAttrs {
+    pub fn attrs_as_lp_pool_attrs(&self) -> Option<LpPoolAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::LpPoolAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { DepthToSpaceAttrs::init_from_table(t) }
+                unsafe { LpPoolAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10336,13 +15850,15 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_cast_like_attrs(&self) -> Option<CastLikeAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::CastLikeAttrs {
+    pub fn attrs_as_mean_variance_normalization_attrs(
+        &self,
+    ) -> Option<MeanVarianceNormalizationAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::MeanVarianceNormalizationAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { CastLikeAttrs::init_from_table(t) }
+                unsafe { MeanVarianceNormalizationAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10351,13 +15867,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_shape_attrs(&self) -> Option<ShapeAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ShapeAttrs {
+    pub fn attrs_as_lp_normalization_attrs(&self) -> Option<LpNormalizationAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::LpNormalizationAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { ShapeAttrs::init_from_table(t) }
+                unsafe { LpNormalizationAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10366,13 +15882,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_dropout_attrs(&self) -> Option<DropoutAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::DropoutAttrs {
+    pub fn attrs_as_bit_shift_attrs(&self) -> Option<BitShiftAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::BitShiftAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { DropoutAttrs::init_from_table(t) }
+                unsafe { BitShiftAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10381,13 +15897,28 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_eye_like_attrs(&self) -> Option<EyeLikeAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::EyeLikeAttrs {
+    pub fn attrs_as_compress_attrs(&self) -> Option<CompressAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::CompressAttrs {
+            self.attrs().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { CompressAttrs::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn attrs_as_reverse_sequence_attrs(&self) -> Option<ReverseSequenceAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ReverseSequenceAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { EyeLikeAttrs::init_from_table(t) }
+                unsafe { ReverseSequenceAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10396,13 +15927,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_is_inf_attrs(&self) -> Option<IsInfAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::IsInfAttrs {
+    pub fn attrs_as_selu_attrs(&self) -> Option<SeluAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::SeluAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { IsInfAttrs::init_from_table(t) }
+                unsafe { SeluAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10411,13 +15942,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_loop_attrs(&self) -> Option<LoopAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::LoopAttrs {
+    pub fn attrs_as_shrink_attrs(&self) -> Option<ShrinkAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ShrinkAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { LoopAttrs::init_from_table(t) }
+                unsafe { ShrinkAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10426,13 +15957,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_sequence_empty_attrs(&self) -> Option<SequenceEmptyAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::SequenceEmptyAttrs {
+    pub fn attrs_as_unique_attrs(&self) -> Option<UniqueAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::UniqueAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { SequenceEmptyAttrs::init_from_table(t) }
+                unsafe { UniqueAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10441,13 +15972,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_concat_from_sequence_attrs(&self) -> Option<ConcatFromSequenceAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::ConcatFromSequenceAttrs {
+    pub fn attrs_as_celu_attrs(&self) -> Option<CeluAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::CeluAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { ConcatFromSequenceAttrs::init_from_table(t) }
+                unsafe { CeluAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10456,13 +15987,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_split_to_sequence_attrs(&self) -> Option<SplitToSequenceAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::SplitToSequenceAttrs {
+    pub fn attrs_as_hardmax_attrs(&self) -> Option<HardmaxAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::HardmaxAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { SplitToSequenceAttrs::init_from_table(t) }
+                unsafe { HardmaxAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10471,13 +16002,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_grid_sample_attrs(&self) -> Option<GridSampleAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::GridSampleAttrs {
+    pub fn attrs_as_thresholded_relu_attrs(&self) -> Option<ThresholdedReluAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::ThresholdedReluAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { GridSampleAttrs::init_from_table(t) }
+                unsafe { ThresholdedReluAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10486,13 +16017,13 @@ impl<'a> OperatorNode<'a> {
 
     #[inline]
     #[allow(non_snake_case)]
-    pub fn attrs_as_stftattrs(&self) -> Option<STFTAttrs<'a>> {
-        if self.attrs_type() == OperatorAttrs::STFTAttrs {
+    pub fn attrs_as_mat_mul_nbits_attrs(&self) -> Option<MatMulNBitsAttrs<'a>> {
+        if self.attrs_type() == OperatorAttrs::MatMulNBitsAttrs {
             self.attrs().map(|t| {
                 // Safety:
                 // Created from a valid Table for this object
                 // Which contains a valid union in this slot
-                unsafe { STFTAttrs::init_from_table(t) }
+                unsafe { MatMulNBitsAttrs::init_from_table(t) }
             })
         } else {
             None
@@ -10565,6 +16096,34 @@ impl flatbuffers::Verifiable for OperatorNode<'_> {
           OperatorAttrs::SplitToSequenceAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SplitToSequenceAttrs>>("OperatorAttrs::SplitToSequenceAttrs", pos),
           OperatorAttrs::GridSampleAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GridSampleAttrs>>("OperatorAttrs::GridSampleAttrs", pos),
           OperatorAttrs::STFTAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<STFTAttrs>>("OperatorAttrs::STFTAttrs", pos),
+          OperatorAttrs::ComputeShapeAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ComputeShapeAttrs>>("OperatorAttrs::ComputeShapeAttrs", pos),
+          OperatorAttrs::FusedMatMulAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<FusedMatMulAttrs>>("OperatorAttrs::FusedMatMulAttrs", pos),
+          OperatorAttrs::GroupedQueryAttentionMatMulAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GroupedQueryAttentionMatMulAttrs>>("OperatorAttrs::GroupedQueryAttentionMatMulAttrs", pos),
+          OperatorAttrs::RepeatInterleaveAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<RepeatInterleaveAttrs>>("OperatorAttrs::RepeatInterleaveAttrs", pos),
+          OperatorAttrs::SwishAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SwishAttrs>>("OperatorAttrs::SwishAttrs", pos),
+          OperatorAttrs::TransformInputsAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<TransformInputsAttrs>>("OperatorAttrs::TransformInputsAttrs", pos),
+          OperatorAttrs::AttentionAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<AttentionAttrs>>("OperatorAttrs::AttentionAttrs", pos),
+          OperatorAttrs::GroupQueryAttentionAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GroupQueryAttentionAttrs>>("OperatorAttrs::GroupQueryAttentionAttrs", pos),
+          OperatorAttrs::RotaryEmbeddingAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<RotaryEmbeddingAttrs>>("OperatorAttrs::RotaryEmbeddingAttrs", pos),
+          OperatorAttrs::SpaceToDepthAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SpaceToDepthAttrs>>("OperatorAttrs::SpaceToDepthAttrs", pos),
+          OperatorAttrs::DFTAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<DFTAttrs>>("OperatorAttrs::DFTAttrs", pos),
+          OperatorAttrs::WindowAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<WindowAttrs>>("OperatorAttrs::WindowAttrs", pos),
+          OperatorAttrs::GroupNormalizationAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<GroupNormalizationAttrs>>("OperatorAttrs::GroupNormalizationAttrs", pos),
+          OperatorAttrs::Col2ImAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Col2ImAttrs>>("OperatorAttrs::Col2ImAttrs", pos),
+          OperatorAttrs::RoiAlignAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<RoiAlignAttrs>>("OperatorAttrs::RoiAlignAttrs", pos),
+          OperatorAttrs::LpPoolAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<LpPoolAttrs>>("OperatorAttrs::LpPoolAttrs", pos),
+          OperatorAttrs::MeanVarianceNormalizationAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<MeanVarianceNormalizationAttrs>>("OperatorAttrs::MeanVarianceNormalizationAttrs", pos),
+          OperatorAttrs::LpNormalizationAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<LpNormalizationAttrs>>("OperatorAttrs::LpNormalizationAttrs", pos),
+          OperatorAttrs::BitShiftAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<BitShiftAttrs>>("OperatorAttrs::BitShiftAttrs", pos),
+          OperatorAttrs::CompressAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<CompressAttrs>>("OperatorAttrs::CompressAttrs", pos),
+          OperatorAttrs::ReverseSequenceAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReverseSequenceAttrs>>("OperatorAttrs::ReverseSequenceAttrs", pos),
+          OperatorAttrs::SeluAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<SeluAttrs>>("OperatorAttrs::SeluAttrs", pos),
+          OperatorAttrs::ShrinkAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ShrinkAttrs>>("OperatorAttrs::ShrinkAttrs", pos),
+          OperatorAttrs::UniqueAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<UniqueAttrs>>("OperatorAttrs::UniqueAttrs", pos),
+          OperatorAttrs::CeluAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<CeluAttrs>>("OperatorAttrs::CeluAttrs", pos),
+          OperatorAttrs::HardmaxAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<HardmaxAttrs>>("OperatorAttrs::HardmaxAttrs", pos),
+          OperatorAttrs::ThresholdedReluAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ThresholdedReluAttrs>>("OperatorAttrs::ThresholdedReluAttrs", pos),
+          OperatorAttrs::MatMulNBitsAttrs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<MatMulNBitsAttrs>>("OperatorAttrs::MatMulNBitsAttrs", pos),
           _ => Ok(()),
         }
      })?
@@ -11140,8 +16699,288 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::SequenceEmptyAttrs => {
-                if let Some(x) = self.attrs_as_sequence_empty_attrs() {
+            OperatorAttrs::SequenceEmptyAttrs => {
+                if let Some(x) = self.attrs_as_sequence_empty_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::ConcatFromSequenceAttrs => {
+                if let Some(x) = self.attrs_as_concat_from_sequence_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::SplitToSequenceAttrs => {
+                if let Some(x) = self.attrs_as_split_to_sequence_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::GridSampleAttrs => {
+                if let Some(x) = self.attrs_as_grid_sample_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::STFTAttrs => {
+                if let Some(x) = self.attrs_as_stftattrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::ComputeShapeAttrs => {
+                if let Some(x) = self.attrs_as_compute_shape_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::FusedMatMulAttrs => {
+                if let Some(x) = self.attrs_as_fused_mat_mul_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::GroupedQueryAttentionMatMulAttrs => {
+                if let Some(x) = self.attrs_as_grouped_query_attention_mat_mul_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::RepeatInterleaveAttrs => {
+                if let Some(x) = self.attrs_as_repeat_interleave_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::SwishAttrs => {
+                if let Some(x) = self.attrs_as_swish_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::TransformInputsAttrs => {
+                if let Some(x) = self.attrs_as_transform_inputs_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::AttentionAttrs => {
+                if let Some(x) = self.attrs_as_attention_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::GroupQueryAttentionAttrs => {
+                if let Some(x) = self.attrs_as_group_query_attention_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::RotaryEmbeddingAttrs => {
+                if let Some(x) = self.attrs_as_rotary_embedding_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::SpaceToDepthAttrs => {
+                if let Some(x) = self.attrs_as_space_to_depth_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::DFTAttrs => {
+                if let Some(x) = self.attrs_as_dftattrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::WindowAttrs => {
+                if let Some(x) = self.attrs_as_window_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::GroupNormalizationAttrs => {
+                if let Some(x) = self.attrs_as_group_normalization_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::Col2ImAttrs => {
+                if let Some(x) = self.attrs_as_col2_im_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::RoiAlignAttrs => {
+                if let Some(x) = self.attrs_as_roi_align_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::LpPoolAttrs => {
+                if let Some(x) = self.attrs_as_lp_pool_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::MeanVarianceNormalizationAttrs => {
+                if let Some(x) = self.attrs_as_mean_variance_normalization_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::LpNormalizationAttrs => {
+                if let Some(x) = self.attrs_as_lp_normalization_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::BitShiftAttrs => {
+                if let Some(x) = self.attrs_as_bit_shift_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::CompressAttrs => {
+                if let Some(x) = self.attrs_as_compress_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::ReverseSequenceAttrs => {
+                if let Some(x) = self.attrs_as_reverse_sequence_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::SeluAttrs => {
+                if let Some(x) = self.attrs_as_selu_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::ShrinkAttrs => {
+                if let Some(x) = self.attrs_as_shrink_attrs() {
+                    ds.field("attrs", &x)
+                } else {
+                    ds.field(
+                        "attrs",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            OperatorAttrs::UniqueAttrs => {
+                if let Some(x) = self.attrs_as_unique_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -11150,8 +16989,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::ConcatFromSequenceAttrs => {
-                if let Some(x) = self.attrs_as_concat_from_sequence_attrs() {
+            OperatorAttrs::CeluAttrs => {
+                if let Some(x) = self.attrs_as_celu_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -11160,8 +16999,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::SplitToSequenceAttrs => {
-                if let Some(x) = self.attrs_as_split_to_sequence_attrs() {
+            OperatorAttrs::HardmaxAttrs => {
+                if let Some(x) = self.attrs_as_hardmax_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -11170,8 +17009,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::GridSampleAttrs => {
-                if let Some(x) = self.attrs_as_grid_sample_attrs() {
+            OperatorAttrs::ThresholdedReluAttrs => {
+                if let Some(x) = self.attrs_as_thresholded_relu_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -11180,8 +17019,8 @@ impl core::fmt::Debug for OperatorNode<'_> {
                     )
                 }
             }
-            OperatorAttrs::STFTAttrs => {
-                if let Some(x) = self.attrs_as_stftattrs() {
+            OperatorAttrs::MatMulNBitsAttrs => {
+                if let Some(x) = self.attrs_as_mat_mul_nbits_attrs() {
                     ds.field("attrs", &x)
                 } else {
                     ds.field(
@@ -12507,6 +18346,206 @@ impl core::fmt::Debug for Node<'_> {
         ds.finish()
     }
 }
+pub enum PrepackedWeightOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PrepackedWeight<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PrepackedWeight<'a> {
+    type Inner = PrepackedWeight<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> PrepackedWeight<'a> {
+    pub const VT_NODE: flatbuffers::VOffsetT = 4;
+    pub const VT_KERNEL: flatbuffers::VOffsetT = 6;
+    pub const VT_DTYPE: flatbuffers::VOffsetT = 8;
+    pub const VT_DATA_OFFSET: flatbuffers::VOffsetT = 10;
+    pub const VT_DATA_LEN: flatbuffers::VOffsetT = 12;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        PrepackedWeight { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args PrepackedWeightArgs<'args>,
+    ) -> flatbuffers::WIPOffset<PrepackedWeight<'bldr>> {
+        let mut builder = PrepackedWeightBuilder::new(_fbb);
+        builder.add_data_len(args.data_len);
+        builder.add_data_offset(args.data_offset);
+        if let Some(x) = args.kernel {
+            builder.add_kernel(x);
+        }
+        builder.add_node(args.node);
+        builder.add_dtype(args.dtype);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn node(&self) -> u32 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u32>(PrepackedWeight::VT_NODE, Some(0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn kernel(&self) -> &'a str {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(PrepackedWeight::VT_KERNEL, None)
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn dtype(&self) -> ConstantDataType {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<ConstantDataType>(PrepackedWeight::VT_DTYPE, Some(ConstantDataType::Int32))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn data_offset(&self) -> u64 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u64>(PrepackedWeight::VT_DATA_OFFSET, Some(0))
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn data_len(&self) -> u64 {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<u64>(PrepackedWeight::VT_DATA_LEN, Some(0))
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for PrepackedWeight<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<u32>("node", Self::VT_NODE, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("kernel", Self::VT_KERNEL, true)?
+            .visit_field::<ConstantDataType>("dtype", Self::VT_DTYPE, false)?
+            .visit_field::<u64>("data_offset", Self::VT_DATA_OFFSET, false)?
+            .visit_field::<u64>("data_len", Self::VT_DATA_LEN, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct PrepackedWeightArgs<'a> {
+    pub node: u32,
+    pub kernel: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub dtype: ConstantDataType,
+    pub data_offset: u64,
+    pub data_len: u64,
+}
+impl<'a> Default for PrepackedWeightArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        PrepackedWeightArgs {
+            node: 0,
+            kernel: None, // required field
+            dtype: ConstantDataType::Int32,
+            data_offset: 0,
+            data_len: 0,
+        }
+    }
+}
+
+pub struct PrepackedWeightBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> PrepackedWeightBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_node(&mut self, node: u32) {
+        self.fbb_
+            .push_slot::<u32>(PrepackedWeight::VT_NODE, node, 0);
+    }
+    #[inline]
+    pub fn add_kernel(&mut self, kernel: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(PrepackedWeight::VT_KERNEL, kernel);
+    }
+    #[inline]
+    pub fn add_dtype(&mut self, dtype: ConstantDataType) {
+        self.fbb_.push_slot::<ConstantDataType>(
+            PrepackedWeight::VT_DTYPE,
+            dtype,
+            ConstantDataType::Int32,
+        );
+    }
+    #[inline]
+    pub fn add_data_offset(&mut self, data_offset: u64) {
+        self.fbb_
+            .push_slot::<u64>(PrepackedWeight::VT_DATA_OFFSET, data_offset, 0);
+    }
+    #[inline]
+    pub fn add_data_len(&mut self, data_len: u64) {
+        self.fbb_
+            .push_slot::<u64>(PrepackedWeight::VT_DATA_LEN, data_len, 0);
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> PrepackedWeightBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        PrepackedWeightBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<PrepackedWeight<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        self.fbb_.required(o, PrepackedWeight::VT_KERNEL, "kernel");
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for PrepackedWeight<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("PrepackedWeight");
+        ds.field("node", &self.node());
+        ds.field("kernel", &self.kernel());
+        ds.field("dtype", &self.dtype());
+        ds.field("data_offset", &self.data_offset());
+        ds.field("data_len", &self.data_len());
+        ds.finish()
+    }
+}
 pub enum GraphOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
@@ -12529,6 +18568,7 @@ impl<'a> Graph<'a> {
     pub const VT_INPUTS: flatbuffers::VOffsetT = 6;
     pub const VT_OUTPUTS: flatbuffers::VOffsetT = 8;
     pub const VT_CAPTURES: flatbuffers::VOffsetT = 10;
+    pub const VT_PREPACKED_WEIGHTS: flatbuffers::VOffsetT = 12;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -12540,6 +18580,9 @@ impl<'a> Graph<'a> {
         args: &'args GraphArgs<'args>,
     ) -> flatbuffers::WIPOffset<Graph<'bldr>> {
         let mut builder = GraphBuilder::new(_fbb);
+        if let Some(x) = args.prepacked_weights {
+            builder.add_prepacked_weights(x);
+        }
         if let Some(x) = args.captures {
             builder.add_captures(x);
         }
@@ -12605,6 +18648,19 @@ impl<'a> Graph<'a> {
                 )
         }
     }
+    #[inline]
+    pub fn prepacked_weights(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PrepackedWeight<'a>>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PrepackedWeight>>,
+            >>(Graph::VT_PREPACKED_WEIGHTS, None)
+        }
+    }
 }
 
 impl flatbuffers::Verifiable for Graph<'_> {
@@ -12633,6 +18689,9 @@ impl flatbuffers::Verifiable for Graph<'_> {
                 Self::VT_CAPTURES,
                 false,
             )?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<PrepackedWeight>>,
+            >>("prepacked_weights", Self::VT_PREPACKED_WEIGHTS, false)?
             .finish();
         Ok(())
     }
@@ -12644,6 +18703,11 @@ pub struct GraphArgs<'a> {
     pub inputs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
     pub outputs: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
     pub captures: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub prepacked_weights: Option<
+        flatbuffers::WIPOffset<
+            flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<PrepackedWeight<'a>>>,
+        >,
+    >,
 }
 impl<'a> Default for GraphArgs<'a> {
     #[inline]
@@ -12653,6 +18717,7 @@ impl<'a> Default for GraphArgs<'a> {
             inputs: None,
             outputs: None,
             captures: None,
+            prepacked_weights: None,
         }
     }
 }
@@ -12688,6 +18753,18 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> GraphBuilder<'a, 'b, A> {
             .push_slot_always::<flatbuffers::WIPOffset<_>>(Graph::VT_CAPTURES, captures);
     }
     #[inline]
+    pub fn add_prepacked_weights(
+        &mut self,
+        prepacked_weights: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<PrepackedWeight<'b>>>,
+        >,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            Graph::VT_PREPACKED_WEIGHTS,
+            prepacked_weights,
+        );
+    }
+    #[inline]
     pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> GraphBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
         GraphBuilder {
@@ -12709,6 +18786,7 @@ impl core::fmt::Debug for Graph<'_> {
         ds.field("inputs", &self.inputs());
         ds.field("outputs", &self.outputs());
         ds.field("captures", &self.captures());
+        ds.field("prepacked_weights", &self.prepacked_weights());
         ds.finish()
     }
 }
@@ -12998,6 +19076,122 @@ impl core::fmt::Debug for Metadata<'_> {
         ds.finish()
     }
 }
+pub enum OptimizationInfoOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct OptimizationInfo<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for OptimizationInfo<'a> {
+    type Inner = OptimizationInfo<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: unsafe { flatbuffers::Table::new(buf, loc) },
+        }
+    }
+}
+
+impl<'a> OptimizationInfo<'a> {
+    pub const VT_RTEN_VERSION: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        OptimizationInfo { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+        args: &'args OptimizationInfoArgs<'args>,
+    ) -> flatbuffers::WIPOffset<OptimizationInfo<'bldr>> {
+        let mut builder = OptimizationInfoBuilder::new(_fbb);
+        if let Some(x) = args.rten_version {
+            builder.add_rten_version(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn rten_version(&self) -> &'a str {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(OptimizationInfo::VT_RTEN_VERSION, None)
+                .unwrap()
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for OptimizationInfo<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                "rten_version",
+                Self::VT_RTEN_VERSION,
+                true,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct OptimizationInfoArgs<'a> {
+    pub rten_version: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for OptimizationInfoArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        OptimizationInfoArgs {
+            rten_version: None, // required field
+        }
+    }
+}
+
+pub struct OptimizationInfoBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> OptimizationInfoBuilder<'a, 'b, A> {
+    #[inline]
+    pub fn add_rten_version(&mut self, rten_version: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            OptimizationInfo::VT_RTEN_VERSION,
+            rten_version,
+        );
+    }
+    #[inline]
+    pub fn new(
+        _fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+    ) -> OptimizationInfoBuilder<'a, 'b, A> {
+        let start = _fbb.start_table();
+        OptimizationInfoBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<OptimizationInfo<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        self.fbb_
+            .required(o, OptimizationInfo::VT_RTEN_VERSION, "rten_version");
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for OptimizationInfo<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("OptimizationInfo");
+        ds.field("rten_version", &self.rten_version());
+        ds.finish()
+    }
+}
 pub enum ModelOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
@@ -13019,6 +19213,7 @@ impl<'a> Model<'a> {
     pub const VT_SCHEMA_VERSION: flatbuffers::VOffsetT = 4;
     pub const VT_GRAPH: flatbuffers::VOffsetT = 6;
     pub const VT_METADATA: flatbuffers::VOffsetT = 8;
+    pub const VT_OPTIMIZATION: flatbuffers::VOffsetT = 10;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -13030,6 +19225,9 @@ impl<'a> Model<'a> {
         args: &'args ModelArgs<'args>,
     ) -> flatbuffers::WIPOffset<Model<'bldr>> {
         let mut builder = ModelBuilder::new(_fbb);
+        if let Some(x) = args.optimization {
+            builder.add_optimization(x);
+        }
         if let Some(x) = args.metadata {
             builder.add_metadata(x);
         }
@@ -13072,6 +19270,16 @@ impl<'a> Model<'a> {
                 .get::<flatbuffers::ForwardsUOffset<Metadata>>(Model::VT_METADATA, None)
         }
     }
+    #[inline]
+    pub fn optimization(&self) -> Option<OptimizationInfo<'a>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<OptimizationInfo>>(Model::VT_OPTIMIZATION, None)
+        }
+    }
 }
 
 impl flatbuffers::Verifiable for Model<'_> {
@@ -13089,6 +19297,11 @@ impl flatbuffers::Verifiable for Model<'_> {
                 Self::VT_METADATA,
                 false,
             )?
+            .visit_field::<flatbuffers::ForwardsUOffset<OptimizationInfo>>(
+                "optimization",
+                Self::VT_OPTIMIZATION,
+                false,
+            )?
             .finish();
         Ok(())
     }
@@ -13097,6 +19310,7 @@ pub struct ModelArgs<'a> {
     pub schema_version: i32,
     pub graph: Option<flatbuffers::WIPOffset<Graph<'a>>>,
     pub metadata: Option<flatbuffers::WIPOffset<Metadata<'a>>>,
+    pub optimization: Option<flatbuffers::WIPOffset<OptimizationInfo<'a>>>,
 }
 impl<'a> Default for ModelArgs<'a> {
     #[inline]
@@ -13105,6 +19319,7 @@ impl<'a> Default for ModelArgs<'a> {
             schema_version: 0,
             graph: None, // required field
             metadata: None,
+            optimization: None,
         }
     }
 }
@@ -13130,6 +19345,14 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ModelBuilder<'a, 'b, A> {
             .push_slot_always::<flatbuffers::WIPOffset<Metadata>>(Model::VT_METADATA, metadata);
     }
     #[inline]
+    pub fn add_optimization(&mut self, optimization: flatbuffers::WIPOffset<OptimizationInfo<'b>>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<OptimizationInfo>>(
+                Model::VT_OPTIMIZATION,
+                optimization,
+            );
+    }
+    #[inline]
     pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ModelBuilder<'a, 'b, A> {
         let start = _fbb.start_table();
         ModelBuilder {
@@ -13151,6 +19374,7 @@ impl core::fmt::Debug for Model<'_> {
         ds.field("schema_version", &self.schema_version());
         ds.field("graph", &self.graph());
         ds.field("metadata", &self.metadata());
+        ds.field("optimization", &self.optimization());
         ds.finish()
     }
 }
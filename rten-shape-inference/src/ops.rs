@@ -8,18 +8,22 @@ use crate::sym_expr::SymExpr;
 use crate::sym_gen::SymbolGen;
 use crate::sym_tensor::{Constant, SymTensor};
 
+mod attention;
 mod binary;
 mod conv_pool;
 mod layout;
 mod matmul;
+mod norm;
 mod slice;
 mod split;
 mod unary;
 
+pub use attention::{Attention, GroupQueryAttention};
 pub use binary::{Add, Div, Equal, Mul, Sub};
 pub use conv_pool::{Conv, GlobalPool, Padding, Pool};
 pub use layout::{Expand, Flatten, Reshape, Shape, Squeeze, Transpose, Unsqueeze};
 pub use matmul::{Gemm, MatMul, MatMulNBits};
+pub use norm::SkipSimplifiedLayerNormalization;
 pub use slice::Slice;
 pub use split::Split;
 pub use unary::Neg;
@@ -0,0 +1,313 @@
+use crate::infer_shapes::{InferShapes, InferShapesError};
+use crate::sym_expr::SymExpr;
+use crate::sym_gen::SymbolGen;
+use crate::sym_tensor::SymTensor;
+
+/// Split the last dimension of a 3D `(batch, seq, heads * head_size)` shape
+/// into heads, returning a 4D `(batch, heads, seq, head_size)` shape.
+fn split_heads(
+    dims: &[SymExpr],
+    num_heads: Option<usize>,
+) -> Result<[SymExpr; 4], InferShapesError> {
+    let [batch, seq, hidden] = dims else {
+        return Err(InferShapesError::IncorrectRank);
+    };
+    let num_heads = num_heads.ok_or(InferShapesError::InvalidValue)?;
+    let num_heads = SymExpr::Value(num_heads as i32);
+    Ok([
+        batch.clone(),
+        num_heads.clone(),
+        seq.clone(),
+        hidden.clone() / num_heads,
+    ])
+}
+
+/// Attention operator.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__Attention.html>.
+pub struct Attention {
+    /// Number of query heads. Required if inputs are 3D.
+    pub q_num_heads: Option<usize>,
+    /// Number of key and value heads. Required if inputs are 3D.
+    pub kv_num_heads: Option<usize>,
+}
+
+impl InferShapes for Attention {
+    fn infer_shapes(
+        &self,
+        inputs: &[SymTensor],
+        _sym_gen: &mut SymbolGen,
+    ) -> Result<Vec<SymTensor>, InferShapesError> {
+        let [q, k, v, rest @ ..] = inputs else {
+            return Err(InferShapesError::IncorrectInputCount);
+        };
+        let (Some(q_dims), Some(k_dims), Some(v_dims)) = (q.shape(), k.shape(), v.shape()) else {
+            return Ok([SymTensor::unknown("unknown input shape")].into());
+        };
+        let q_dims: Vec<_> = q_dims.collect();
+        let k_dims: Vec<_> = k_dims.collect();
+        let v_dims: Vec<_> = v_dims.collect();
+
+        let (output, mut present_key, mut present_value) = match q_dims.len() {
+            4 => {
+                let (Ok(k_dims), Ok(v_dims)) = (
+                    <[SymExpr; 4]>::try_from(k_dims),
+                    <[SymExpr; 4]>::try_from(v_dims),
+                ) else {
+                    return Err(InferShapesError::IncorrectRank);
+                };
+                let output = vec![
+                    q_dims[0].clone(),
+                    q_dims[1].clone(),
+                    q_dims[2].clone(),
+                    v_dims[3].clone(),
+                ];
+                (output, k_dims, v_dims)
+            }
+            3 => {
+                let k_dims = split_heads(&k_dims, self.kv_num_heads)?;
+                let v_dims = split_heads(&v_dims, self.kv_num_heads)?;
+                let q_heads = self.q_num_heads.ok_or(InferShapesError::InvalidValue)?;
+                let output = vec![
+                    q_dims[0].clone(),
+                    q_dims[1].clone(),
+                    SymExpr::Value(q_heads as i32) * v_dims[3].clone(),
+                ];
+                (output, k_dims, v_dims)
+            }
+            _ => {
+                return Err(InferShapesError::IncorrectRank);
+            }
+        };
+
+        // Past keys and values are prepended to the current keys and values
+        // along the sequence dimension.
+        let past_seq_len = |past: Option<&SymTensor>| past.and_then(|p| p.size(2));
+        if let Some(past_len) = past_seq_len(rest.get(1)) {
+            present_key[2] = past_len + present_key[2].clone();
+        }
+        if let Some(past_len) = past_seq_len(rest.get(2)) {
+            present_value[2] = past_len + present_value[2].clone();
+        }
+
+        Ok([
+            SymTensor::from_shape(output),
+            SymTensor::from_shape(present_key.into()),
+            SymTensor::from_shape(present_value.into()),
+        ]
+        .into())
+    }
+}
+
+/// GroupQueryAttention operator from ONNX Runtime.
+///
+/// See <https://github.com/microsoft/onnxruntime/blob/main/docs/ContribOperators.md#com.microsoft.GroupQueryAttention>.
+pub struct GroupQueryAttention {
+    pub num_heads: usize,
+    pub kv_num_heads: usize,
+}
+
+impl InferShapes for GroupQueryAttention {
+    fn infer_shapes(
+        &self,
+        inputs: &[SymTensor],
+        sym_gen: &mut SymbolGen,
+    ) -> Result<Vec<SymTensor>, InferShapesError> {
+        let [query, rest @ ..] = inputs else {
+            return Err(InferShapesError::IncorrectInputCount);
+        };
+        let Some(q_dims) = query.shape() else {
+            return Ok([SymTensor::unknown("unknown input shape")].into());
+        };
+        let q_dims: Vec<_> = q_dims.collect();
+        let [batch, seq, q_hidden] = q_dims.as_slice() else {
+            return Err(InferShapesError::IncorrectRank);
+        };
+
+        // The key input is absent if Q, K and V are packed into the query
+        // input. In that case the head size can only be determined from the
+        // past key input. Missing inputs are indistinguishable from inputs
+        // with unknown shapes, so packing is not inferred from the query size.
+        let num_heads = SymExpr::Value(self.num_heads as i32);
+        let head_size = if let Some(k_hidden) = rest.first().and_then(|k| k.size(2)) {
+            Some(k_hidden / SymExpr::Value(self.kv_num_heads as i32))
+        } else {
+            rest.get(2).and_then(|past_key| past_key.size(3))
+        };
+        let q_hidden = if rest.first().and_then(|k| k.ndim()).is_some() {
+            q_hidden.clone()
+        } else if let Some(head_size) = &head_size {
+            num_heads.clone() * head_size.clone()
+        } else {
+            sym_gen.gen_positive()
+        };
+        let head_size = head_size.unwrap_or_else(|| q_hidden.clone() / num_heads);
+        let output = vec![batch.clone(), seq.clone(), q_hidden];
+
+        // The present key/value length is the larger of the past buffer
+        // length and the total sequence length.
+        let past_len = rest.get(2).and_then(|past_key| past_key.size(2));
+        let total_len = rest.get(5).and_then(|len| len.as_scalar()).cloned();
+        let present_len = match (past_len, total_len) {
+            (Some(past_len), Some(total_len)) => past_len.max(&total_len),
+            (None, Some(len)) | (Some(len), None) => len,
+            (None, None) => sym_gen.gen_positive(),
+        };
+        let present = vec![
+            batch.clone(),
+            SymExpr::Value(self.kv_num_heads as i32),
+            present_len,
+            head_size,
+        ];
+
+        Ok([
+            SymTensor::from_shape(output),
+            SymTensor::from_shape(present.clone()),
+            SymTensor::from_shape(present),
+        ]
+        .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infer_shapes::InferShapes;
+    use crate::sym_expr::SymExpr;
+    use crate::sym_gen::SymbolGen;
+    use crate::sym_tensor::{SymTensor, sym_shape};
+
+    use super::{Attention, GroupQueryAttention};
+
+    #[test]
+    fn test_attention() {
+        let mut sym_gen = SymbolGen::new();
+        let op = Attention {
+            q_num_heads: None,
+            kv_num_heads: None,
+        };
+
+        // 4D inputs with grouped KV heads.
+        let q = sym_shape!("batch", 8, "seq", 64);
+        let k = sym_shape!("batch", 2, "seq", 64);
+        let v = sym_shape!("batch", 2, "seq", 32);
+        let result = op.infer_shapes(&[q, k, v], &mut sym_gen).unwrap();
+        assert_eq!(result[0], sym_shape!("batch", 8, "seq", 32));
+        assert_eq!(result[1], sym_shape!("batch", 2, "seq", 64));
+        assert_eq!(result[2], sym_shape!("batch", 2, "seq", 32));
+
+        // 4D inputs with past keys and values.
+        let q = sym_shape!("batch", 8, "seq", 64);
+        let k = sym_shape!("batch", 2, "seq", 64);
+        let v = sym_shape!("batch", 2, "seq", 32);
+        let mask = SymTensor::unknown("mask");
+        let past_k = sym_shape!("batch", 2, "past", 64);
+        let past_v = sym_shape!("batch", 2, "past", 32);
+        let result = op
+            .infer_shapes(&[q, k, v, mask, past_k, past_v], &mut sym_gen)
+            .unwrap();
+        assert_eq!(result[0], sym_shape!("batch", 8, "seq", 32));
+        assert_eq!(
+            result[1].clone().simplify(),
+            SymTensor::from_shape(vec![
+                "batch".into(),
+                2.into(),
+                SymExpr::from("past") + SymExpr::from("seq"),
+                64.into()
+            ])
+            .simplify()
+        );
+
+        // 3D inputs.
+        let op = Attention {
+            q_num_heads: Some(8),
+            kv_num_heads: Some(2),
+        };
+        let q = sym_shape!("batch", "seq", 512);
+        let k = sym_shape!("batch", "seq", 128);
+        let v = sym_shape!("batch", "seq", 128);
+        let result = op.infer_shapes(&[q, k, v], &mut sym_gen).unwrap();
+        assert_eq!(
+            result[0].clone().simplify(),
+            sym_shape!("batch", "seq", 512)
+        );
+        assert_eq!(
+            result[1].clone().simplify(),
+            sym_shape!("batch", 2, "seq", 64)
+        );
+
+        // 3D inputs without head counts.
+        let op = Attention {
+            q_num_heads: None,
+            kv_num_heads: None,
+        };
+        let q = sym_shape!("batch", "seq", 512);
+        let k = sym_shape!("batch", "seq", 128);
+        let v = sym_shape!("batch", "seq", 128);
+        let err = op.infer_shapes(&[q, k, v], &mut sym_gen).err();
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn test_group_query_attention() {
+        let mut sym_gen = SymbolGen::new();
+        let op = GroupQueryAttention {
+            num_heads: 8,
+            kv_num_heads: 2,
+        };
+
+        // Separate Q, K and V inputs.
+        let q = sym_shape!("batch", "seq", 512);
+        let k = sym_shape!("batch", "seq", 128);
+        let v = sym_shape!("batch", "seq", 128);
+        let past_k = sym_shape!("batch", 2, "past", 64);
+        let past_v = sym_shape!("batch", 2, "past", 64);
+        let seqlens_k = sym_shape!("batch");
+        let total_len = SymTensor::from_scalar("total".into());
+        let result = op
+            .infer_shapes(
+                &[q, k, v, past_k, past_v, seqlens_k, total_len],
+                &mut sym_gen,
+            )
+            .unwrap();
+        assert_eq!(result[0], sym_shape!("batch", "seq", 512));
+        assert_eq!(
+            result[1].clone().simplify(),
+            SymTensor::from_shape(vec![
+                "batch".into(),
+                2.into(),
+                SymExpr::from("past").max(&SymExpr::from("total")),
+                64.into(),
+            ])
+        );
+
+        // Packed QKV input.
+        let qkv = sym_shape!("batch", "seq", 768);
+        let missing = SymTensor::unknown("missing");
+        let past_k = sym_shape!("batch", 2, 100, 64);
+        let past_v = sym_shape!("batch", 2, 100, 64);
+        let seqlens_k = sym_shape!("batch");
+        let total_len = SymTensor::from_scalar(10.into());
+        let result = op
+            .infer_shapes(
+                &[
+                    qkv,
+                    missing.clone(),
+                    missing,
+                    past_k,
+                    past_v,
+                    seqlens_k,
+                    total_len,
+                ],
+                &mut sym_gen,
+            )
+            .unwrap();
+        assert_eq!(
+            result[0].clone().simplify(),
+            sym_shape!("batch", "seq", 512)
+        );
+        assert_eq!(
+            result[1].clone().simplify(),
+            sym_shape!("batch", 2, 100, 64)
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use crate::infer_shapes::{InferShapes, InferShapesError};
+use crate::sym_expr::SymExpr;
+use crate::sym_gen::SymbolGen;
+use crate::sym_tensor::SymTensor;
+
+/// SkipSimplifiedLayerNormalization operator from ONNX Runtime.
+///
+/// Outputs are the normalized output, mean, inverse standard deviation and
+/// the sum of the input and skip connection.
+///
+/// See <https://github.com/microsoft/onnxruntime/blob/main/docs/ContribOperators.md#com.microsoft.SkipSimplifiedLayerNormalization>.
+pub struct SkipSimplifiedLayerNormalization;
+
+impl InferShapes for SkipSimplifiedLayerNormalization {
+    fn infer_shapes(
+        &self,
+        inputs: &[SymTensor],
+        _sym_gen: &mut SymbolGen,
+    ) -> Result<Vec<SymTensor>, InferShapesError> {
+        let [input, ..] = inputs else {
+            return Err(InferShapesError::IncorrectInputCount);
+        };
+        let Some(dims) = input.shape() else {
+            return Ok([SymTensor::unknown("unknown input shape")].into());
+        };
+        let dims: Vec<_> = dims.collect();
+        if dims.is_empty() {
+            return Err(InferShapesError::IncorrectRank);
+        }
+
+        // Statistics are computed over the last axis.
+        let mut stat_dims = dims.clone();
+        *stat_dims.last_mut().unwrap() = SymExpr::Value(1);
+
+        Ok([
+            SymTensor::from_shape(dims.clone()),
+            SymTensor::from_shape(stat_dims.clone()),
+            SymTensor::from_shape(stat_dims),
+            SymTensor::from_shape(dims),
+        ]
+        .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::infer_shapes::InferShapes;
+    use crate::sym_expr::SymExpr;
+    use crate::sym_gen::SymbolGen;
+    use crate::sym_tensor::{SymTensor, sym_shape};
+
+    use super::SkipSimplifiedLayerNormalization;
+
+    #[test]
+    fn test_skip_simplified_layer_normalization() {
+        let mut sym_gen = SymbolGen::new();
+        let input = sym_shape!("batch", "seq", 64);
+        let skip = sym_shape!("batch", "seq", 64);
+        let gamma = sym_shape!(64);
+        let result = SkipSimplifiedLayerNormalization
+            .infer_shapes(&[input, skip, gamma], &mut sym_gen)
+            .unwrap();
+        assert_eq!(result.len(), 4);
+        assert_eq!(result[0], sym_shape!("batch", "seq", 64));
+        assert_eq!(result[1], sym_shape!("batch", "seq", 1));
+        assert_eq!(result[2], sym_shape!("batch", "seq", 1));
+        assert_eq!(result[3], sym_shape!("batch", "seq", 64));
+
+        let err = SkipSimplifiedLayerNormalization
+            .infer_shapes(&[SymTensor::from_shape(Vec::new())], &mut sym_gen)
+            .err();
+        assert!(err.is_some());
+    }
+}
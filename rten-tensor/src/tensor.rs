@@ -2481,6 +2481,8 @@ impl_scalar!(usize);
 impl_scalar!(isize);
 impl_scalar!(f32);
 impl_scalar!(f64);
+impl_scalar!(rten_base::half::f16);
+impl_scalar!(rten_base::half::bf16);
 impl_scalar!(String);
 
 // The `T: Scalar` bound avoids ambiguity when choosing a `Tensor::from`
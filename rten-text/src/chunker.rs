@@ -0,0 +1,701 @@
+//! Splitting of documents into chunks that fit a token budget.
+//!
+//! This is used when ingesting documents for retrieval-augmented generation
+//! (RAG), where each chunk is embedded and indexed separately. See
+//! [`TextChunker`].
+
+use std::ops::Range;
+
+use crate::tokenizer::{EncodeOptions, Tokenizer, TokenizerError};
+
+/// Configuration for a [`TextChunker`].
+#[derive(Clone, Debug)]
+pub struct ChunkerOptions {
+    /// Maximum number of tokens in each chunk, including any special tokens
+    /// (eg. `[CLS]`, `[SEP]`) that the tokenizer adds.
+    pub max_tokens: usize,
+
+    /// Maximum number of tokens that a chunk will share with the end of the
+    /// previous chunk.
+    ///
+    /// Overlap is applied in whole pieces (sentences, words etc.), so the
+    /// actual overlap may be less than this.
+    pub overlap: usize,
+}
+
+impl Default for ChunkerOptions {
+    fn default() -> Self {
+        ChunkerOptions {
+            max_tokens: 256,
+            overlap: 0,
+        }
+    }
+}
+
+/// A chunk of text produced by [`TextChunker::chunk`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextChunk<'a> {
+    /// Text of the chunk. This is a slice of the source document with
+    /// leading and trailing whitespace removed.
+    pub text: &'a str,
+
+    /// Byte offsets of the chunk in the source document.
+    pub byte_offsets: Range<usize>,
+
+    /// Character offsets of the chunk in the source document.
+    pub char_offsets: Range<usize>,
+
+    /// Number of tokens in the chunk, as measured by the tokenizer.
+    pub token_count: usize,
+}
+
+/// Boundaries at which text is split, from coarsest to finest.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Level {
+    Paragraph,
+    Sentence,
+    Word,
+    Char,
+}
+
+impl Level {
+    fn next(self) -> Option<Level> {
+        match self {
+            Level::Paragraph => Some(Level::Sentence),
+            Level::Sentence => Some(Level::Word),
+            Level::Word => Some(Level::Char),
+            Level::Char => None,
+        }
+    }
+}
+
+/// Return true if `ch` ends a sentence regardless of what follows it.
+fn is_cjk_sentence_end(ch: char) -> bool {
+    matches!(ch, '。' | '！' | '？')
+}
+
+/// Return true if `ch` ends a sentence when followed by whitespace.
+fn is_sentence_end(ch: char) -> bool {
+    matches!(ch, '.' | '!' | '?')
+}
+
+/// Return true if `ch` may follow a sentence terminator as part of the same
+/// sentence (eg. `He said "Hi."`).
+fn is_closing_punct(ch: char) -> bool {
+    matches!(ch, '"' | '\'' | ')' | ']' | '”' | '’')
+}
+
+/// Return the byte offsets, relative to the start of `text`, at which each
+/// piece after the first begins when `text` is split at `level`.
+///
+/// Whitespace which follows a boundary is included in the preceding piece.
+fn split_points(text: &str, level: Level) -> Vec<usize> {
+    let mut points = Vec::new();
+    let mut chars = text.char_indices().peekable();
+
+    // Skip over a run of whitespace and record a split point after it.
+    let mut end_piece = |chars: &mut std::iter::Peekable<std::str::CharIndices>| {
+        while chars.next_if(|(_, ch)| ch.is_whitespace()).is_some() {}
+        if let Some(&(pos, _)) = chars.peek() {
+            points.push(pos);
+        }
+    };
+
+    match level {
+        Level::Paragraph => {
+            while let Some((_, ch)) = chars.next() {
+                if ch != '\n' {
+                    continue;
+                }
+                // A paragraph ends at a line break followed by a line that is
+                // empty, or contains only whitespace.
+                let mut blank_line = false;
+                while let Some((_, ch)) = chars.next_if(|(_, ch)| ch.is_whitespace()) {
+                    if ch == '\n' {
+                        blank_line = true;
+                    }
+                }
+                if blank_line && let Some(&(pos, _)) = chars.peek() {
+                    points.push(pos);
+                }
+            }
+        }
+        Level::Sentence => {
+            while let Some((_, ch)) = chars.next() {
+                if is_cjk_sentence_end(ch) {
+                    while chars.next_if(|(_, ch)| is_closing_punct(*ch)).is_some() {}
+                    end_piece(&mut chars);
+                } else if is_sentence_end(ch) {
+                    while chars
+                        .next_if(|(_, ch)| is_sentence_end(*ch) || is_closing_punct(*ch))
+                        .is_some()
+                    {}
+                    if chars.peek().is_some_and(|(_, ch)| ch.is_whitespace()) {
+                        end_piece(&mut chars);
+                    }
+                }
+            }
+        }
+        Level::Word => {
+            while let Some((_, ch)) = chars.next() {
+                if ch.is_whitespace() {
+                    end_piece(&mut chars);
+                }
+            }
+        }
+        Level::Char => {
+            points.extend(chars.skip(1).map(|(pos, _)| pos));
+        }
+    }
+
+    points
+}
+
+/// Return `range` with leading and trailing whitespace in `text` removed.
+fn trim_range(text: &str, range: Range<usize>) -> Range<usize> {
+    let slice = &text[range.clone()];
+    let start = range.start + (slice.len() - slice.trim_start().len());
+    let end = range.end - (slice.len() - slice.trim_end().len());
+    start..end.max(start)
+}
+
+/// Splits documents into chunks which fit within a token budget.
+///
+/// Text is split at the coarsest boundaries possible: first at paragraphs
+/// (separated by blank lines), then at sentences, then at words and finally
+/// at individual characters. Pieces are then merged into chunks, such that
+/// each chunk contains as many consecutive pieces as fit within
+/// [`max_tokens`](ChunkerOptions::max_tokens). Token counts are measured by
+/// encoding the text of each chunk with a [`Tokenizer`], usually the one used
+/// by the embedding model.
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use rten_text::chunker::{ChunkerOptions, TextChunker};
+/// use rten_text::models::WordPiece;
+/// use rten_text::pre_tokenizers::Bert;
+/// use rten_text::Tokenizer;
+///
+/// let vocab: HashMap<String, u32> = ["[UNK]", "one", "two", "three", "."]
+///     .into_iter()
+///     .enumerate()
+///     .map(|(id, token)| (token.to_string(), id as u32))
+///     .collect();
+/// let tokenizer = Tokenizer::new(
+///     WordPiece::from_vocab(vocab, Default::default()),
+///     Default::default(),
+/// )
+/// .with_pre_tokenizer(Box::new(Bert::new()));
+///
+/// let chunker = TextChunker::new(
+///     &tokenizer,
+///     ChunkerOptions {
+///         max_tokens: 6,
+///         overlap: 0,
+///     },
+/// );
+/// let chunks = chunker.chunk("one two. three one. two.")?;
+/// let texts: Vec<_> = chunks.iter().map(|c| c.text).collect();
+/// assert_eq!(texts, ["one two. three one.", "two."]);
+/// # Ok::<_, rten_text::TokenizerError>(())
+/// ```
+pub struct TextChunker<'a> {
+    tokenizer: &'a Tokenizer,
+    options: ChunkerOptions,
+}
+
+impl<'a> TextChunker<'a> {
+    /// Create a chunker which measures chunk lengths using `tokenizer`.
+    ///
+    /// Panics if `options.overlap` is not less than `options.max_tokens`.
+    pub fn new(tokenizer: &'a Tokenizer, options: ChunkerOptions) -> Self {
+        // Chunking cannot make progress unless each chunk can contain new
+        // content.
+        assert!(options.overlap < options.max_tokens);
+        TextChunker { tokenizer, options }
+    }
+
+    /// Split `text` into chunks.
+    ///
+    /// Chunks are returned in order. Chunks consisting only of whitespace are
+    /// omitted. A chunk may exceed the token budget only if it consists of a
+    /// single character.
+    pub fn chunk<'t>(&self, text: &'t str) -> Result<Vec<TextChunk<'t>>, TokenizerError> {
+        let mut pieces = Vec::new();
+        self.split(text, 0..text.len(), Some(Level::Paragraph), &mut pieces)?;
+
+        // Number of special tokens (eg. `[CLS]`, `[SEP]`) added to every
+        // encoded piece of text, and hence counted once per piece.
+        let n_special = self.count_tokens("")?;
+
+        let mut chunks = Vec::new();
+        let mut char_offset = 0;
+        let mut char_offset_pos = 0;
+        let mut start = 0;
+
+        while start < pieces.len() {
+            let (end, token_count) = self.merge_pieces(text, &pieces, start, n_special)?;
+            let range = trim_range(text, pieces[start].range.start..pieces[end - 1].range.end);
+
+            if !range.is_empty() {
+                // Chunks are produced in order of their start positions, so
+                // character offsets can be computed incrementally.
+                char_offset += text[char_offset_pos..range.start].chars().count();
+                char_offset_pos = range.start;
+                let char_len = text[range.clone()].chars().count();
+
+                chunks.push(TextChunk {
+                    text: &text[range.clone()],
+                    char_offsets: char_offset..char_offset + char_len,
+                    byte_offsets: range,
+                    token_count,
+                });
+            }
+
+            if end == pieces.len() {
+                break;
+            }
+            start = self.overlap_start(text, &pieces, start, end, n_special)?;
+        }
+
+        Ok(chunks)
+    }
+
+    /// Return the number of tokens in a piece of text.
+    fn count_tokens(&self, text: &str) -> Result<usize, TokenizerError> {
+        let encoded = self.tokenizer.encode(
+            text,
+            Some(EncodeOptions {
+                split_special_tokens: true,
+                ..Default::default()
+            }),
+        )?;
+        Ok(encoded.token_ids().len())
+    }
+
+    /// Return the number of tokens in the text spanning `pieces`, with
+    /// leading and trailing whitespace removed.
+    fn count_run_tokens(&self, text: &str, pieces: &[Piece]) -> Result<usize, TokenizerError> {
+        let (first, last) = (&pieces[0], &pieces[pieces.len() - 1]);
+        if pieces.len() == 1 {
+            return Ok(first.token_count);
+        }
+        self.count_tokens(&text[trim_range(text, first.range.start..last.range.end)])
+    }
+
+    /// Split `range` of `text` into pieces that each fit within the token
+    /// budget, using boundaries at `level` or finer.
+    ///
+    /// If `level` is `None`, `range` is added as a piece without splitting.
+    fn split(
+        &self,
+        text: &str,
+        range: Range<usize>,
+        level: Option<Level>,
+        pieces: &mut Vec<Piece>,
+    ) -> Result<(), TokenizerError> {
+        let token_count = self.count_tokens(&text[trim_range(text, range.clone())])?;
+        let level = match level {
+            Some(level) if token_count > self.options.max_tokens => level,
+            _ => {
+                pieces.push(Piece { range, token_count });
+                return Ok(());
+            }
+        };
+
+        let points = split_points(&text[range.clone()], level);
+        let starts = std::iter::once(range.start).chain(points.iter().map(|p| range.start + p));
+        let ends = points
+            .iter()
+            .map(|p| range.start + p)
+            .chain(std::iter::once(range.end));
+        for (start, end) in starts.zip(ends) {
+            self.split(text, start..end, level.next(), pieces)?;
+        }
+        Ok(())
+    }
+
+    /// Find the longest run of pieces starting at `start` that fits within
+    /// the token budget.
+    ///
+    /// Returns the end index of the run and its token count. The run always
+    /// contains at least one piece.
+    ///
+    /// The length of the run is first estimated from the token counts of
+    /// individual pieces. Tokens can span piece boundaries, so the estimate
+    /// is then corrected by encoding the run, which usually requires only
+    /// one or two calls to the tokenizer.
+    fn merge_pieces(
+        &self,
+        text: &str,
+        pieces: &[Piece],
+        start: usize,
+        n_special: usize,
+    ) -> Result<(usize, usize), TokenizerError> {
+        let max_tokens = self.options.max_tokens;
+
+        let mut end = start + 1;
+        let mut estimate = pieces[start].token_count;
+        while end < pieces.len() {
+            let n_tokens = estimate + pieces[end].content_tokens(n_special);
+            if n_tokens > max_tokens {
+                break;
+            }
+            estimate = n_tokens;
+            end += 1;
+        }
+
+        let mut token_count = self.count_run_tokens(text, &pieces[start..end])?;
+        if token_count > max_tokens {
+            while token_count > max_tokens && end > start + 1 {
+                end -= 1;
+                token_count = self.count_run_tokens(text, &pieces[start..end])?;
+            }
+        } else {
+            while end < pieces.len() {
+                let n_tokens = self.count_run_tokens(text, &pieces[start..end + 1])?;
+                if n_tokens > max_tokens {
+                    break;
+                }
+                token_count = n_tokens;
+                end += 1;
+            }
+        }
+
+        Ok((end, token_count))
+    }
+
+    /// Return the index of the first piece of the chunk after the one
+    /// spanning `pieces[start..end]`.
+    ///
+    /// This steps back from `end` over as many pieces as fit within the
+    /// overlap, while leaving room in the next chunk for the piece at `end`.
+    /// As in [`merge_pieces`](Self::merge_pieces), the result is estimated
+    /// from the token counts of individual pieces and then corrected.
+    fn overlap_start(
+        &self,
+        text: &str,
+        pieces: &[Piece],
+        start: usize,
+        end: usize,
+        n_special: usize,
+    ) -> Result<usize, TokenizerError> {
+        let mut next_start = end;
+        if self.options.overlap == 0 {
+            return Ok(next_start);
+        }
+
+        let mut overlap_tokens = n_special;
+        let next_piece_tokens = pieces[end].content_tokens(n_special);
+        while next_start > start + 1 {
+            let n_tokens = overlap_tokens + pieces[next_start - 1].content_tokens(n_special);
+            if n_tokens > self.options.overlap
+                || n_tokens + next_piece_tokens > self.options.max_tokens
+            {
+                break;
+            }
+            overlap_tokens = n_tokens;
+            next_start -= 1;
+        }
+
+        while next_start < end
+            && (self.count_run_tokens(text, &pieces[next_start..end])? > self.options.overlap
+                || self.count_run_tokens(text, &pieces[next_start..end + 1])?
+                    > self.options.max_tokens)
+        {
+            next_start += 1;
+        }
+
+        Ok(next_start)
+    }
+}
+
+/// A piece of a document produced by [`TextChunker::split`].
+struct Piece {
+    /// Byte offsets of the piece in the document.
+    range: Range<usize>,
+
+    /// Number of tokens in the piece, including special tokens.
+    token_count: usize,
+}
+
+impl Piece {
+    /// Return the number of tokens in this piece excluding the `n_special`
+    /// special tokens that are added to every encoded piece of text.
+    fn content_tokens(&self, n_special: usize) -> usize {
+        self.token_count.saturating_sub(n_special)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use rten_testing::TestCases;
+
+    use super::{ChunkerOptions, Level, TextChunker, split_points};
+    use crate::models::WordPiece;
+    use crate::normalizers::{NormalizeError, Normalizer};
+    use crate::pre_tokenizers::Bert;
+    use crate::tokenizer::TokenizerOptions;
+    use crate::{TokenId, Tokenizer};
+
+    /// Create a tokenizer which encodes each word and punctuation character
+    /// as one token.
+    fn word_tokenizer(cls_sep: bool) -> Tokenizer {
+        let words = [
+            "[UNK]", "[CLS]", "[SEP]", "the", "cat", "sat", "on", "mat", "a", "dog", "ran", ".",
+            "!", "?",
+        ];
+        let vocab: HashMap<String, TokenId> = words
+            .into_iter()
+            .enumerate()
+            .map(|(id, word)| (word.to_string(), id as TokenId))
+            .collect();
+        let options = if cls_sep {
+            TokenizerOptions {
+                cls_token: Some("[CLS]"),
+                sep_token: Some("[SEP]"),
+            }
+        } else {
+            TokenizerOptions::default()
+        };
+        Tokenizer::new(WordPiece::from_vocab(vocab, Default::default()), options)
+            .with_pre_tokenizer(Box::new(Bert::new()))
+    }
+
+    #[test]
+    fn test_split_points() {
+        #[derive(Debug)]
+        struct Case {
+            text: &'static str,
+            level: Level,
+            expected: Vec<&'static str>,
+        }
+
+        let cases = [
+            Case {
+                text: "one\ntwo\n\nthree\n  \n four",
+                level: Level::Paragraph,
+                expected: vec!["one\ntwo\n\n", "three\n  \n ", "four"],
+            },
+            Case {
+                text: "One. Two! \"Three?\" Four.Five 3.5 six",
+                level: Level::Sentence,
+                expected: vec!["One. ", "Two! ", "\"Three?\" ", "Four.Five 3.5 six"],
+            },
+            Case {
+                text: "一。二！三",
+                level: Level::Sentence,
+                expected: vec!["一。", "二！", "三"],
+            },
+            Case {
+                text: "one  two\tthree ",
+                level: Level::Word,
+                expected: vec!["one  ", "two\t", "three "],
+            },
+            Case {
+                text: "aé😊",
+                level: Level::Char,
+                expected: vec!["a", "é", "😊"],
+            },
+        ];
+
+        cases.test_each(|case| {
+            let points = split_points(case.text, case.level);
+            let starts = std::iter::once(0).chain(points.iter().copied());
+            let ends = points
+                .iter()
+                .copied()
+                .chain(std::iter::once(case.text.len()));
+            let pieces: Vec<_> = starts.zip(ends).map(|(s, e)| &case.text[s..e]).collect();
+            assert_eq!(pieces, case.expected);
+        })
+    }
+
+    #[test]
+    fn test_chunk() {
+        #[derive(Debug)]
+        struct Case {
+            text: &'static str,
+            max_tokens: usize,
+            overlap: usize,
+            cls_sep: bool,
+            expected: Vec<&'static str>,
+        }
+
+        let cases = [
+            // Whole text fits in one chunk.
+            Case {
+                text: "  the cat sat.  ",
+                max_tokens: 10,
+                overlap: 0,
+                cls_sep: false,
+                expected: vec!["the cat sat."],
+            },
+            // Paragraphs are kept together when they fit.
+            Case {
+                text: "the cat sat.\n\nthe dog ran. a cat ran.",
+                max_tokens: 10,
+                overlap: 0,
+                cls_sep: false,
+                expected: vec!["the cat sat.", "the dog ran. a cat ran."],
+            },
+            // Paragraphs that don't fit are split into sentences, which are
+            // merged until the budget is reached.
+            Case {
+                text: "the cat sat. the dog ran. a cat sat!",
+                max_tokens: 8,
+                overlap: 0,
+                cls_sep: false,
+                expected: vec!["the cat sat. the dog ran.", "a cat sat!"],
+            },
+            // Sentences that don't fit are split into words.
+            Case {
+                text: "the cat sat on the mat.",
+                max_tokens: 3,
+                overlap: 0,
+                cls_sep: false,
+                expected: vec!["the cat sat", "on the", "mat."],
+            },
+            // Special tokens count towards the budget.
+            Case {
+                text: "the cat sat on the mat.",
+                max_tokens: 5,
+                overlap: 0,
+                cls_sep: true,
+                expected: vec!["the cat sat", "on the", "mat."],
+            },
+            // Overlap with the previous chunk.
+            Case {
+                text: "the cat sat. the dog ran. a cat sat.",
+                max_tokens: 8,
+                overlap: 4,
+                cls_sep: false,
+                expected: vec!["the cat sat. the dog ran.", "the dog ran. a cat sat."],
+            },
+            // Overlap in words.
+            Case {
+                text: "the cat sat on the mat",
+                max_tokens: 3,
+                overlap: 1,
+                cls_sep: false,
+                expected: vec!["the cat sat", "sat on the", "the mat"],
+            },
+            // Whitespace-only input.
+            Case {
+                text: " \n\n ",
+                max_tokens: 3,
+                overlap: 0,
+                cls_sep: false,
+                expected: vec![],
+            },
+        ];
+
+        cases.test_each(|case| {
+            let tokenizer = word_tokenizer(case.cls_sep);
+            let chunker = TextChunker::new(
+                &tokenizer,
+                ChunkerOptions {
+                    max_tokens: case.max_tokens,
+                    overlap: case.overlap,
+                },
+            );
+            let chunks = chunker.chunk(case.text).unwrap();
+            let texts: Vec<_> = chunks.iter().map(|c| c.text).collect();
+            assert_eq!(texts, case.expected);
+
+            for chunk in chunks {
+                assert!(chunk.token_count <= case.max_tokens);
+                assert_eq!(&case.text[chunk.byte_offsets.clone()], chunk.text);
+            }
+        })
+    }
+
+    #[test]
+    fn test_chunk_offsets() {
+        let tokenizer = word_tokenizer(false);
+        let chunker = TextChunker::new(
+            &tokenizer,
+            ChunkerOptions {
+                max_tokens: 5,
+                overlap: 0,
+            },
+        );
+        let text = "é the cat sat. ½ the dog ran.";
+        let chunks = chunker.chunk(text).unwrap();
+
+        let chars: Vec<char> = text.chars().collect();
+        let texts: Vec<_> = chunks.iter().map(|c| c.text).collect();
+        assert_eq!(texts, ["é the cat sat.", "½ the dog ran."]);
+
+        for chunk in chunks {
+            let chunk_chars: String = chars[chunk.char_offsets.clone()].iter().collect();
+            assert_eq!(chunk_chars, chunk.text);
+            assert_eq!(chunk.token_count, 5);
+        }
+    }
+
+    #[test]
+    fn test_chunk_oversized_char() {
+        let tokenizer = word_tokenizer(true);
+        let chunker = TextChunker::new(
+            &tokenizer,
+            ChunkerOptions {
+                max_tokens: 2,
+                overlap: 0,
+            },
+        );
+
+        // Each character is encoded as `[CLS] [UNK] [SEP]`, which exceeds the
+        // budget, but cannot be split further.
+        let chunks = chunker.chunk("ab").unwrap();
+        let texts: Vec<_> = chunks.iter().map(|c| c.text).collect();
+        assert_eq!(texts, ["a", "b"]);
+        assert!(chunks.iter().all(|c| c.token_count == 3));
+    }
+
+    /// Normalizer which counts how many times text is encoded.
+    #[derive(Debug)]
+    struct CountingNormalizer {
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl Normalizer for CountingNormalizer {
+        fn normalize(&self, text: &str) -> Result<(String, Vec<usize>), NormalizeError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok((text.to_string(), (0..text.len()).collect()))
+        }
+    }
+
+    #[test]
+    fn test_chunk_tokenizer_calls() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let tokenizer = word_tokenizer(true).with_normalizer(Box::new(CountingNormalizer {
+            calls: calls.clone(),
+        }));
+        let chunker = TextChunker::new(
+            &tokenizer,
+            ChunkerOptions {
+                max_tokens: 100,
+                overlap: 20,
+            },
+        );
+
+        let n_sentences = 500;
+        let text = "the cat sat. ".repeat(n_sentences);
+        let chunks = chunker.chunk(&text).unwrap();
+        assert!(chunks.len() > 1);
+        assert!(chunks.iter().all(|c| c.token_count <= 100));
+
+        // Each sentence should be encoded once when the text is split, plus
+        // a small number of times per chunk when merging sentences. Counting
+        // tokens by encoding each candidate chunk would instead require a
+        // number of calls proportional to the number of sentences per chunk.
+        let max_calls = n_sentences + 1 + 6 * chunks.len();
+        assert!(calls.load(Ordering::SeqCst) <= max_calls);
+    }
+}
@@ -0,0 +1,824 @@
+//! Decoders which convert the token strings produced by a model into the
+//! final decoded text.
+//!
+//! Decoders perform the inverse of the normalization and pre-tokenization
+//! steps. For example a [`Metaspace`] decoder replaces the "▁" characters that
+//! SentencePiece tokenizers use to represent spaces.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use fancy_regex::Regex;
+use serde_json::json;
+
+use crate::models::{char_to_byte, parse_byte_token};
+
+/// Errors occuring while constructing a [`Decoder`] or decoding tokens
+/// using one.
+#[derive(Clone, Debug)]
+pub enum DecoderError {
+    /// The decoded bytes do not form a valid UTF-8 sequence.
+    ///
+    /// See [`DecodeError::InvalidUtf8`](crate::models::DecodeError::InvalidUtf8).
+    InvalidUtf8,
+
+    /// An error occurred while constructing a regex from a pattern or
+    /// replacing matches of a regex.
+    RegexError(Box<fancy_regex::Error>),
+}
+
+impl fmt::Display for DecoderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidUtf8 => write!(f, "decoded tokens do not form valid UTF-8 text"),
+            Self::RegexError(err) => write!(f, "regex failed {}", err),
+        }
+    }
+}
+
+impl Error for DecoderError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidUtf8 => None,
+            Self::RegexError(err) => Some(err),
+        }
+    }
+}
+
+impl From<fancy_regex::Error> for DecoderError {
+    fn from(val: fancy_regex::Error) -> Self {
+        DecoderError::RegexError(Box::new(val))
+    }
+}
+
+/// A decoder transforms the canonical token strings returned by
+/// [`Model::get_tokens`](crate::models::Model::get_tokens) into text.
+pub trait Decoder: std::fmt::Debug + Send + Sync {
+    /// Transform a sequence of token strings.
+    ///
+    /// The decoded text is the concatenation of the returned strings. Decoders
+    /// may return fewer strings than they were passed if they merge tokens.
+    fn decode_chain(&self, tokens: Vec<String>) -> Result<Vec<String>, DecoderError>;
+
+    /// Return the configuration of this decoder in the format used by
+    /// `tokenizer.json` files, or `None` if it cannot be serialized.
+    ///
+    /// This is used by [`Tokenizer::to_json`](crate::Tokenizer::to_json).
+    fn to_json(&self) -> Option<serde_json::Value> {
+        None
+    }
+}
+
+/// Decoder for byte-level tokenizers such as GPT-2, where each token string is
+/// an encoding of a sequence of bytes.
+///
+/// This reverses the byte-to-character mapping described in
+/// [`char_to_byte`]. Characters which are not part of the mapping, as may
+/// occur in special tokens, are decoded as-is.
+#[derive(Clone, Debug)]
+pub struct ByteLevel {
+    char_to_byte: HashMap<char, u8>,
+}
+
+impl ByteLevel {
+    pub fn new() -> Self {
+        ByteLevel {
+            char_to_byte: char_to_byte(),
+        }
+    }
+}
+
+impl Default for ByteLevel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Decoder for ByteLevel {
+    fn decode_chain(&self, tokens: Vec<String>) -> Result<Vec<String>, DecoderError> {
+        let mut bytes = Vec::new();
+        for token in tokens {
+            let token_bytes: Option<Vec<u8>> = token
+                .chars()
+                .map(|ch| self.char_to_byte.get(&ch).copied())
+                .collect();
+            match token_bytes {
+                Some(token_bytes) => bytes.extend(token_bytes),
+                None => bytes.extend(token.as_bytes()),
+            }
+        }
+        let text = String::from_utf8(bytes).map_err(|_| DecoderError::InvalidUtf8)?;
+        Ok(vec![text])
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(json!({ "type": "ByteLevel" }))
+    }
+}
+
+/// Decoder which converts byte fallback tokens of the form `<0xXX>` into the
+/// corresponding bytes.
+///
+/// Runs of consecutive byte tokens are converted to text. Byte sequences that
+/// are not valid UTF-8 are replaced with one U+FFFD replacement character per
+/// byte, except for an incomplete sequence at the end of the input, which
+/// produces a [`DecoderError::InvalidUtf8`] error. This allows callers to
+/// accumulate more tokens and then retry decoding.
+#[derive(Clone, Debug, Default)]
+pub struct ByteFallback {}
+
+impl ByteFallback {
+    pub fn new() -> Self {
+        ByteFallback {}
+    }
+
+    /// Convert a run of byte tokens to text and append it to `output`.
+    fn flush_bytes(
+        bytes: &mut Vec<u8>,
+        output: &mut Vec<String>,
+        end_of_input: bool,
+    ) -> Result<(), DecoderError> {
+        if bytes.is_empty() {
+            return Ok(());
+        }
+        match String::from_utf8(std::mem::take(bytes)) {
+            Ok(text) => output.push(text),
+            Err(err) if end_of_input && err.utf8_error().error_len().is_none() => {
+                return Err(DecoderError::InvalidUtf8);
+            }
+            Err(err) => {
+                let n_bytes = err.as_bytes().len();
+                output.extend(std::iter::repeat_n("\u{FFFD}".to_string(), n_bytes));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Decoder for ByteFallback {
+    fn decode_chain(&self, tokens: Vec<String>) -> Result<Vec<String>, DecoderError> {
+        let mut output = Vec::with_capacity(tokens.len());
+        let mut bytes = Vec::new();
+
+        for token in tokens {
+            if let Some(byte) = parse_byte_token(&token) {
+                bytes.push(byte);
+            } else {
+                Self::flush_bytes(&mut bytes, &mut output, false)?;
+                output.push(token);
+            }
+        }
+        Self::flush_bytes(&mut bytes, &mut output, true)?;
+
+        Ok(output)
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(json!({ "type": "ByteFallback" }))
+    }
+}
+
+/// Decoder which concatenates all tokens into a single string.
+#[derive(Clone, Debug, Default)]
+pub struct Fuse {}
+
+impl Fuse {
+    pub fn new() -> Self {
+        Fuse {}
+    }
+}
+
+impl Decoder for Fuse {
+    fn decode_chain(&self, tokens: Vec<String>) -> Result<Vec<String>, DecoderError> {
+        Ok(vec![tokens.concat()])
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(json!({ "type": "Fuse" }))
+    }
+}
+
+/// Decoder which reverses the [Metaspace
+/// pre-tokenizer](https://huggingface.co/docs/tokenizers/en/api/pre-tokenizers)
+/// used by SentencePiece-derived tokenizers.
+///
+/// This replaces occurrences of the replacement character (usually "▁") with
+/// spaces.
+#[derive(Clone, Debug)]
+pub struct Metaspace {
+    replacement: char,
+    add_prefix_space: bool,
+}
+
+impl Metaspace {
+    /// Create a Metaspace decoder.
+    ///
+    /// If `add_prefix_space` is true, the replacement characters in the first
+    /// token are removed rather than converted to spaces. This reverses the
+    /// space that the pre-tokenizer adds at the start of the input.
+    pub fn new(replacement: char, add_prefix_space: bool) -> Self {
+        Metaspace {
+            replacement,
+            add_prefix_space,
+        }
+    }
+}
+
+impl Decoder for Metaspace {
+    fn decode_chain(&self, tokens: Vec<String>) -> Result<Vec<String>, DecoderError> {
+        let decoded = tokens
+            .iter()
+            .enumerate()
+            .map(|(i, token)| {
+                token
+                    .chars()
+                    .filter_map(|ch| {
+                        if ch != self.replacement {
+                            Some(ch)
+                        } else if i == 0 && self.add_prefix_space {
+                            None
+                        } else {
+                            Some(' ')
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        Ok(decoded)
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(json!({
+            "type": "Metaspace",
+            "replacement": self.replacement,
+            "add_prefix_space": self.add_prefix_space,
+        }))
+    }
+}
+
+/// Decoder which replaces occurrences of a pattern in each token.
+#[derive(Clone, Debug)]
+pub struct Replace {
+    regex: Regex,
+    content: String,
+}
+
+impl Replace {
+    /// Replaces occurrences of `pattern` with `content`.
+    ///
+    /// `pattern` is a regex pattern. See the
+    /// [fancy-regex](https://docs.rs/fancy-regex/) docs for supported syntax.
+    pub fn new(pattern: &str, content: String) -> Result<Replace, DecoderError> {
+        Ok(Replace {
+            regex: Regex::new(pattern)?,
+            content,
+        })
+    }
+}
+
+impl Decoder for Replace {
+    fn decode_chain(&self, tokens: Vec<String>) -> Result<Vec<String>, DecoderError> {
+        tokens
+            .iter()
+            .map(|token| {
+                let replaced = self.regex.try_replacen(token, 0, self.content.as_str())?;
+                Ok(replaced.into_owned())
+            })
+            .collect()
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(json!({
+            "type": "Replace",
+            "pattern": { "Regex": self.regex.as_str() },
+            "content": self.content,
+        }))
+    }
+}
+
+/// Decoder which removes up to a given number of occurrences of a character
+/// from the start and end of each token.
+#[derive(Clone, Debug)]
+pub struct Strip {
+    content: char,
+    start: usize,
+    stop: usize,
+}
+
+impl Strip {
+    /// Strip up to `start` occurrences of `content` from the start of each
+    /// token and up to `stop` occurrences from the end.
+    pub fn new(content: char, start: usize, stop: usize) -> Self {
+        Strip {
+            content,
+            start,
+            stop,
+        }
+    }
+}
+
+impl Decoder for Strip {
+    fn decode_chain(&self, tokens: Vec<String>) -> Result<Vec<String>, DecoderError> {
+        let decoded = tokens
+            .iter()
+            .map(|token| {
+                let mut stripped = token.as_str();
+                for _ in 0..self.start {
+                    let Some(rest) = stripped.strip_prefix(self.content) else {
+                        break;
+                    };
+                    stripped = rest;
+                }
+                for _ in 0..self.stop {
+                    let Some(rest) = stripped.strip_suffix(self.content) else {
+                        break;
+                    };
+                    stripped = rest;
+                }
+                stripped.to_string()
+            })
+            .collect();
+        Ok(decoded)
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(json!({
+            "type": "Strip",
+            "content": self.content,
+            "start": self.start,
+            "stop": self.stop,
+        }))
+    }
+}
+
+/// Decoder for WordPiece tokenizers such as BERT.
+///
+/// This removes the continuation prefix (usually "##") from tokens which
+/// continue a word, and inserts spaces before other tokens.
+#[derive(Clone, Debug)]
+pub struct WordPiece {
+    prefix: String,
+    cleanup: bool,
+}
+
+impl WordPiece {
+    /// Create a WordPiece decoder.
+    ///
+    /// If `cleanup` is true, spaces before punctuation and English
+    /// contractions are removed (eg. "don ' t ." becomes "don't.").
+    pub fn new(prefix: String, cleanup: bool) -> Self {
+        WordPiece { prefix, cleanup }
+    }
+}
+
+impl Default for WordPiece {
+    fn default() -> Self {
+        WordPiece::new("##".to_string(), true)
+    }
+}
+
+/// Remove spaces which a WordPiece tokenizer's pre-tokenization introduced
+/// before punctuation and in English contractions.
+fn cleanup_wordpiece(text: &str) -> String {
+    text.replace(" .", ".")
+        .replace(" ?", "?")
+        .replace(" !", "!")
+        .replace(" ,", ",")
+        .replace(" ' ", "'")
+        .replace(" n't", "n't")
+        .replace(" 'm", "'m")
+        .replace(" do not", " don't")
+        .replace(" 's", "'s")
+        .replace(" 've", "'ve")
+        .replace(" 're", "'re")
+}
+
+impl Decoder for WordPiece {
+    fn decode_chain(&self, tokens: Vec<String>) -> Result<Vec<String>, DecoderError> {
+        let decoded = tokens
+            .into_iter()
+            .enumerate()
+            .map(|(i, token)| {
+                let token = if i == 0 {
+                    token
+                } else if let Some(rest) = token.strip_prefix(&self.prefix) {
+                    rest.to_string()
+                } else {
+                    format!(" {}", token)
+                };
+                if self.cleanup {
+                    cleanup_wordpiece(&token)
+                } else {
+                    token
+                }
+            })
+            .collect();
+        Ok(decoded)
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(json!({
+            "type": "WordPiece",
+            "prefix": self.prefix,
+            "cleanup": self.cleanup,
+        }))
+    }
+}
+
+/// Decoder for BPE tokenizers which mark the end of a word with a suffix,
+/// such as the original OpenAI GPT.
+///
+/// This replaces the suffix with a space, except in the last token.
+#[derive(Clone, Debug)]
+pub struct Bpe {
+    suffix: String,
+}
+
+impl Bpe {
+    /// Create a decoder which replaces the end-of-word marker `suffix`.
+    pub fn new(suffix: String) -> Self {
+        Bpe { suffix }
+    }
+}
+
+impl Default for Bpe {
+    fn default() -> Self {
+        Bpe::new("</w>".to_string())
+    }
+}
+
+impl Decoder for Bpe {
+    fn decode_chain(&self, tokens: Vec<String>) -> Result<Vec<String>, DecoderError> {
+        let last = tokens.len().saturating_sub(1);
+        let decoded = tokens
+            .into_iter()
+            .enumerate()
+            .map(|(i, token)| {
+                let replacement = if i == last { "" } else { " " };
+                token.replace(&self.suffix, replacement)
+            })
+            .collect();
+        Ok(decoded)
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(json!({
+            "type": "BPEDecoder",
+            "suffix": self.suffix,
+        }))
+    }
+}
+
+/// Decoder for the output of models trained with Connectionist Temporal
+/// Classification (CTC), such as Wav2Vec2.
+///
+/// This merges repeated tokens, removes padding tokens and, if `cleanup` is
+/// enabled, replaces the word delimiter with a space.
+#[derive(Clone, Debug)]
+pub struct Ctc {
+    pad_token: String,
+    word_delimiter_token: String,
+    cleanup: bool,
+}
+
+impl Ctc {
+    /// Create a CTC decoder.
+    ///
+    /// If `cleanup` is true, spaces before punctuation and English
+    /// contractions are removed, as in [`WordPiece`], and word delimiters are
+    /// replaced with spaces.
+    pub fn new(pad_token: String, word_delimiter_token: String, cleanup: bool) -> Self {
+        Ctc {
+            pad_token,
+            word_delimiter_token,
+            cleanup,
+        }
+    }
+}
+
+impl Default for Ctc {
+    fn default() -> Self {
+        Ctc::new("<pad>".to_string(), "|".to_string(), true)
+    }
+}
+
+impl Decoder for Ctc {
+    fn decode_chain(&self, mut tokens: Vec<String>) -> Result<Vec<String>, DecoderError> {
+        tokens.dedup();
+        let decoded = tokens
+            .into_iter()
+            .filter_map(|token| {
+                let mut token = token.replace(&self.pad_token, "");
+                if self.cleanup {
+                    token = cleanup_wordpiece(&token).replace(&self.word_delimiter_token, " ");
+                }
+                (!token.is_empty()).then_some(token)
+            })
+            .collect();
+        Ok(decoded)
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(json!({
+            "type": "CTC",
+            "pad_token": self.pad_token,
+            "word_delimiter_token": self.word_delimiter_token,
+            "cleanup": self.cleanup,
+        }))
+    }
+}
+
+/// Run a series of decoders in sequence.
+#[derive(Debug)]
+pub struct Sequence {
+    decoders: Vec<Box<dyn Decoder>>,
+}
+
+impl Sequence {
+    pub fn from_vec(decoders: Vec<Box<dyn Decoder>>) -> Self {
+        Sequence { decoders }
+    }
+}
+
+impl Decoder for Sequence {
+    fn decode_chain(&self, mut tokens: Vec<String>) -> Result<Vec<String>, DecoderError> {
+        for decoder in &self.decoders {
+            tokens = decoder.decode_chain(tokens)?;
+        }
+        Ok(tokens)
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        let decoders = self
+            .decoders
+            .iter()
+            .map(|decoder| decoder.to_json())
+            .collect::<Option<Vec<_>>>()?;
+        Some(json!({ "type": "Sequence", "decoders": decoders }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rten_testing::TestCases;
+
+    use super::{
+        Bpe, ByteFallback, ByteLevel, Ctc, Decoder, DecoderError, Fuse, Metaspace, Replace,
+        Sequence, Strip, WordPiece,
+    };
+
+    fn to_strings(tokens: &[&str]) -> Vec<String> {
+        tokens.iter().map(|t| t.to_string()).collect()
+    }
+
+    #[test]
+    fn test_bpe() {
+        let decoder = Bpe::default();
+        let decoded = decoder
+            .decode_chain(to_strings(&["hel", "lo</w>", "world</w>"]))
+            .unwrap();
+        assert_eq!(decoded, ["hel", "lo ", "world"]);
+
+        let decoded = decoder.decode_chain(Vec::new()).unwrap();
+        assert!(decoded.is_empty());
+
+        assert_eq!(
+            decoder.to_json().unwrap().to_string(),
+            r#"{"suffix":"</w>","type":"BPEDecoder"}"#
+        );
+    }
+
+    #[test]
+    fn test_byte_level() {
+        let decoder = ByteLevel::new();
+
+        // "Ġ" encodes a space. "Ã©" encodes "é".
+        let decoded = decoder
+            .decode_chain(to_strings(&[
+                "Hello",
+                "Ġworld",
+                "Ġcaf",
+                "Ã©",
+                "<|endoftext|>",
+            ]))
+            .unwrap();
+        assert_eq!(decoded, ["Hello world café<|endoftext|>"]);
+
+        // Incomplete UTF-8 sequence.
+        let result = decoder.decode_chain(to_strings(&["caf", "Ã"]));
+        assert!(matches!(result, Err(DecoderError::InvalidUtf8)));
+    }
+
+    #[test]
+    fn test_byte_fallback() {
+        #[derive(Debug)]
+        struct Case<'a> {
+            tokens: &'a [&'a str],
+            expected: Option<&'a [&'a str]>,
+        }
+
+        let cases = [
+            Case {
+                tokens: &["▁caf", "<0xC3>", "<0xA9>", "▁au"],
+                expected: Some(&["▁caf", "é", "▁au"]),
+            },
+            // Invalid sequence in the middle of the input.
+            Case {
+                tokens: &["<0xC3>", "<0xC3>", "a"],
+                expected: Some(&["\u{FFFD}", "\u{FFFD}", "a"]),
+            },
+            // Incomplete sequence at the end of the input.
+            Case {
+                tokens: &["a", "<0xC3>"],
+                expected: None,
+            },
+            // Tokens which resemble byte tokens.
+            Case {
+                tokens: &["<0xZZ>", "<0x123>"],
+                expected: Some(&["<0xZZ>", "<0x123>"]),
+            },
+        ];
+
+        cases.test_each(|case| {
+            let decoder = ByteFallback::new();
+            let result = decoder.decode_chain(to_strings(case.tokens));
+            match case.expected {
+                Some(expected) => assert_eq!(result.unwrap(), expected),
+                None => assert!(matches!(result, Err(DecoderError::InvalidUtf8))),
+            }
+        })
+    }
+
+    #[test]
+    fn test_ctc() {
+        #[derive(Debug)]
+        struct Case<'a> {
+            tokens: &'a [&'a str],
+            cleanup: bool,
+            expected: &'a [&'a str],
+        }
+
+        let cases = [
+            Case {
+                tokens: &[
+                    "<pad>", "H", "H", "<pad>", "E", "L", "<pad>", "L", "O", "|", "|", "W", "O",
+                    "R", "L", "D", "<pad>",
+                ],
+                cleanup: true,
+                expected: &["H", "E", "L", "L", "O", " ", "W", "O", "R", "L", "D"],
+            },
+            // Without cleanup, word delimiters are kept.
+            Case {
+                tokens: &["A", "A", "|", "B", "<pad>"],
+                cleanup: false,
+                expected: &["A", "|", "B"],
+            },
+        ];
+
+        cases.test_each(|case| {
+            let decoder = Ctc::new("<pad>".to_string(), "|".to_string(), case.cleanup);
+            let decoded = decoder.decode_chain(to_strings(case.tokens)).unwrap();
+            assert_eq!(decoded, case.expected);
+        });
+
+        assert_eq!(
+            Ctc::default().to_json().unwrap().to_string(),
+            r#"{"cleanup":true,"pad_token":"<pad>","type":"CTC","word_delimiter_token":"|"}"#
+        );
+    }
+
+    #[test]
+    fn test_fuse() {
+        let decoder = Fuse::new();
+        let decoded = decoder.decode_chain(to_strings(&["a", "b", "c"])).unwrap();
+        assert_eq!(decoded, ["abc"]);
+    }
+
+    #[test]
+    fn test_metaspace() {
+        #[derive(Debug)]
+        struct Case<'a> {
+            add_prefix_space: bool,
+            tokens: &'a [&'a str],
+            expected: &'a [&'a str],
+        }
+
+        let cases = [
+            Case {
+                add_prefix_space: true,
+                tokens: &["▁Hello", "▁world", "!"],
+                expected: &["Hello", " world", "!"],
+            },
+            Case {
+                add_prefix_space: false,
+                tokens: &["▁Hello", "▁world", "!"],
+                expected: &[" Hello", " world", "!"],
+            },
+        ];
+
+        cases.test_each(|case| {
+            let decoder = Metaspace::new('▁', case.add_prefix_space);
+            let decoded = decoder.decode_chain(to_strings(case.tokens)).unwrap();
+            assert_eq!(decoded, case.expected);
+        })
+    }
+
+    #[test]
+    fn test_replace() {
+        let decoder = Replace::new("▁", " ".to_string()).unwrap();
+        let decoded = decoder
+            .decode_chain(to_strings(&["▁Hello", "▁▁world"]))
+            .unwrap();
+        assert_eq!(decoded, [" Hello", "  world"]);
+    }
+
+    #[test]
+    fn test_strip() {
+        #[derive(Debug)]
+        struct Case<'a> {
+            start: usize,
+            stop: usize,
+            tokens: &'a [&'a str],
+            expected: &'a [&'a str],
+        }
+
+        let cases = [
+            Case {
+                start: 1,
+                stop: 0,
+                tokens: &["  Hello ", " world"],
+                expected: &[" Hello ", "world"],
+            },
+            Case {
+                start: 0,
+                stop: 2,
+                tokens: &["Hello   ", " "],
+                expected: &["Hello ", ""],
+            },
+            Case {
+                start: 5,
+                stop: 5,
+                tokens: &["  "],
+                expected: &[""],
+            },
+        ];
+
+        cases.test_each(|case| {
+            let decoder = Strip::new(' ', case.start, case.stop);
+            let decoded = decoder.decode_chain(to_strings(case.tokens)).unwrap();
+            assert_eq!(decoded, case.expected);
+        })
+    }
+
+    #[test]
+    fn test_sequence() {
+        // Decoder configuration used by Llama 2.
+        let decoder = Sequence::from_vec(vec![
+            Box::new(Replace::new("▁", " ".to_string()).unwrap()),
+            Box::new(ByteFallback::new()),
+            Box::new(Fuse::new()),
+            Box::new(Strip::new(' ', 1, 0)),
+        ]);
+        let decoded = decoder
+            .decode_chain(to_strings(&[
+                "▁Hello", "▁caf", "<0xC3>", "<0xA9>", "▁world",
+            ]))
+            .unwrap();
+        assert_eq!(decoded, ["Hello café world"]);
+    }
+
+    #[test]
+    fn test_wordpiece() {
+        #[derive(Debug)]
+        struct Case<'a> {
+            tokens: &'a [&'a str],
+            cleanup: bool,
+            expected: &'a [&'a str],
+        }
+
+        let cases = [
+            Case {
+                tokens: &["[CLS]", "foo", "##bar", "[SEP]"],
+                cleanup: true,
+                expected: &["[CLS]", " foo", "bar", " [SEP]"],
+            },
+            Case {
+                tokens: &["don", "'", "t", "stop", "."],
+                cleanup: true,
+                expected: &["don", " '", " t", " stop", "."],
+            },
+            Case {
+                tokens: &["hello", "##s", ",", "world"],
+                cleanup: false,
+                expected: &["hello", "s", " ,", " world"],
+            },
+        ];
+
+        cases.test_each(|case| {
+            let decoder = WordPiece::new("##".to_string(), case.cleanup);
+            let decoded = decoder.decode_chain(to_strings(case.tokens)).unwrap();
+            assert_eq!(decoded, case.expected);
+        })
+    }
+}
@@ -13,8 +13,9 @@
 //! [`tokenizers`](https://github.com/huggingface/tokenizers) crate. The main
 //! differences compared to that crate are:
 //!
-//! - rten-text focuses on inference only and does not support training
-//!   tokenizers.
+//! - rten-text focuses on inference. It supports training BPE and WordPiece
+//!   vocabularies (see [`trainers`]), but with fewer options than the
+//!   _tokenizers_ crate.
 //! - rten-text is a pure Rust library with no dependencies written in C/C++.
 //!   This means it is easy to build for WebAssembly and other targets where
 //!   non-Rust dependencies may cause difficulties.
@@ -31,7 +32,9 @@
 //!
 //! The main entry point is the [`Tokenizer`] type. Use [`Tokenizer::from_file`]
 //! or [`Tokenizer::from_json`] to construct a tokenizer from a `tokenizer.json`
-//! file.
+//! file. Models which are distributed as SentencePiece `tokenizer.model` files
+//! can be loaded using `Tokenizer::from_sentencepiece_file`, if the
+//! `sentencepiece` feature is enabled.
 //!
 //! ## Encoding text
 //!
@@ -62,6 +65,10 @@
 //! # Ok::<_, Box<dyn std::error::Error>>(())
 //! ```
 //!
+//! To display text as it is generated, one token at a time, use a
+//! [`StreamingDecoder`]. This handles tokens which don't correspond to complete
+//! characters and decoders whose output depends on surrounding tokens.
+//!
 //! ## More examples
 //!
 //! See the
@@ -69,12 +76,16 @@
 //! crate for various examples showing how to use this crate as part of an
 //! end-to-end pipeline.
 
+pub mod chunker;
+pub mod decoders;
 pub mod models;
 pub mod normalizers;
+pub mod post_processors;
 pub mod pre_tokenizers;
 pub mod tokenizer;
+pub mod trainers;
 
 mod serde;
 mod split;
 
-pub use tokenizer::{TokenId, Tokenizer, TokenizerError};
+pub use tokenizer::{StreamingDecoder, TokenId, Tokenizer, TokenizerError};
@@ -1,15 +1,19 @@
-//! Implementations of popular tokenization models including WordPiece and
-//! Byte Pair Encoding (BPE).
+//! Implementations of popular tokenization models including WordPiece,
+//! Byte Pair Encoding (BPE) and Unigram.
 
 use std::error::Error;
 use std::fmt;
 
 mod bpe;
+mod unigram;
 mod wordpiece;
 
+pub(crate) use bpe::byte_to_char;
 pub use bpe::{
     Bpe, BpeError, BpeOptions, EncodedByteSlice, EncodedBytes, char_to_byte, merge_pairs_from_lines,
 };
+pub(crate) use unigram::parse_byte_token;
+pub use unigram::{Unigram, UnigramError, UnigramOptions};
 pub use wordpiece::{WordPiece, WordPieceOptions};
 
 use crate::tokenizer::TokenId;
@@ -64,7 +68,7 @@ impl Error for DecodeError {}
 ///
 /// Models are not generally used directly but instead via a wrapping
 /// [`Tokenizer`](crate::tokenizer::Tokenizer).
-pub trait Model {
+pub trait Model: Send + Sync {
     /// Look up the numeric ID for a token given its canonical string
     /// representation. This is used eg. for looking up the IDs of special
     /// tokens.
@@ -128,4 +132,12 @@ pub trait Model {
     /// Special tokens are decoded into their canonical string representations
     /// as returned by [`get_token_str`](Self::get_token_str).
     fn decode(&self, ids: &[TokenId]) -> Result<String, DecodeError>;
+
+    /// Return the configuration of this model in the format used by
+    /// `tokenizer.json` files, or `None` if it cannot be serialized.
+    ///
+    /// This is used by [`Tokenizer::to_json`](crate::Tokenizer::to_json).
+    fn to_json(&self) -> Option<serde_json::Value> {
+        None
+    }
 }
@@ -4,9 +4,10 @@ use std::error::Error;
 use std::fmt;
 use std::fmt::{Debug, Display};
 
-use super::{DecodeError, EncodeError, Model};
+use super::{DecodeError, EncodeError, Model, parse_byte_token};
 use crate::tokenizer::TokenId;
 use rustc_hash::{FxBuildHasher, FxHashMap};
+use serde_json::json;
 
 /// Errors that can occur when building a [`Bpe`] tokenizer or encoding or
 /// decoding text using it.
@@ -24,6 +25,9 @@ pub enum BpeError {
 
     /// An entry was not found in the vocabulary.
     MissingVocabEntry(String),
+
+    /// A character-level model was created without a vocabulary.
+    MissingVocab,
 }
 
 impl Display for BpeError {
@@ -32,6 +36,7 @@ impl Display for BpeError {
             BpeError::InvalidMergeEntry(entry) => write!(fmt, "invalid merge entry: {}", entry),
             BpeError::InvalidVocabEntry(entry) => write!(fmt, "invalid vocab entry: {}", entry),
             BpeError::MissingVocabEntry(entry) => write!(fmt, "missing vocab entry: {}", entry),
+            BpeError::MissingVocab => write!(fmt, "character-level model requires a vocab"),
         }
     }
 }
@@ -71,7 +76,7 @@ fn is_printable(c: char) -> bool {
 ///
 /// Based on the `bytes_to_unicode` function in the original GPT-2 encoder -
 /// <https://github.com/openai/gpt-2/blob/master/src/encoder.py>.
-fn byte_to_char() -> [char; 256] {
+pub(crate) fn byte_to_char() -> [char; 256] {
     let mut chars = ['\x00'; 256];
 
     for b in 0..=255u8 {
@@ -287,6 +292,24 @@ pub struct BpeOptions<'a> {
     /// When encoding a string piece, match the entire piece against the
     /// vocabulary before applying merge rules.
     pub ignore_merges: bool,
+
+    /// Use characters rather than bytes as the initial tokens.
+    ///
+    /// In this mode the strings in `merges` and `vocab` are plain text
+    /// rather than encoded bytes, and `vocab` must be provided. Characters
+    /// which are not in the vocabulary are encoded using byte fallback tokens
+    /// if `byte_fallback` is set, or the unknown token otherwise.
+    pub char_level: bool,
+
+    /// ID of the token used for characters that are not in the vocabulary
+    /// in character-level models.
+    ///
+    /// If not set, encoding such characters fails.
+    pub unk_id: Option<TokenId>,
+
+    /// Encode characters that are not in the vocabulary as a sequence of byte
+    /// tokens of the form `<0xXX>` in character-level models.
+    pub byte_fallback: bool,
 }
 
 /// Byte Pair Encoding tokenizer used by GPT-2 [^1] and subsequently used by
@@ -295,7 +318,8 @@ pub struct BpeOptions<'a> {
 /// Byte Pair Encoding was introduced by [^2]. Despite the name, the original
 /// version operated on characters. The variant used by GPT-2 and other OpenAI
 /// models operates on bytes instead. This avoids needing a huge base vocabulary
-/// to support Unicode.
+/// to support Unicode. Both variants are supported, see
+/// [`BpeOptions::char_level`].
 ///
 /// [^1]: Radford, Alec, et al. (2019) "Language models are unsupervised multitask learners."
 ///       <https://openai.com/research/better-language-models>
@@ -330,6 +354,15 @@ pub struct Bpe {
     /// When encoding a string piece, match the entire piece against the
     /// vocabulary before applying merge rules.
     ignore_merges: bool,
+
+    /// Use characters rather than bytes as the initial tokens.
+    char_level: bool,
+
+    /// ID of token used for unknown characters in character-level models.
+    unk_id: Option<TokenId>,
+
+    /// Encode unknown characters as byte tokens in character-level models.
+    byte_fallback: bool,
 }
 
 impl Bpe {
@@ -341,33 +374,44 @@ impl Bpe {
             added_tokens,
             mut end_of_word_suffix,
             ignore_merges,
+            char_level,
+            unk_id,
+            byte_fallback,
         } = config;
 
         // Normalize empty end-of-word suffix to `None`.
         end_of_word_suffix.take_if(|suffix| suffix.is_empty());
 
-        let vocab = vocab.unwrap_or_else(|| build_vocab(merges, end_of_word_suffix.as_deref()));
+        let vocab = match vocab {
+            Some(vocab) => vocab,
+            None if char_level => return Err(BpeError::MissingVocab),
+            None => build_vocab(merges, end_of_word_suffix.as_deref()),
+        };
 
         let merges = build_merge_map(&vocab, merges)?;
 
-        // Build byte -> token ID mapping for encoding.
+        // Build byte -> token ID mapping for encoding. This is not used by
+        // character-level models.
         let mut byte_to_token_id = [0; 256];
-        for (i, ch) in byte_to_char().into_iter().enumerate() {
-            let mut ch_buf = [0u8; 4];
-            let ch_str = ch.encode_utf8(&mut ch_buf);
-            if let Some(id) = vocab.get(ch_str).copied() {
-                byte_to_token_id[i] = id;
-            } else {
-                return Err(BpeError::MissingVocabEntry(ch_str.to_string()));
+        if !char_level {
+            for (i, ch) in byte_to_char().into_iter().enumerate() {
+                let mut ch_buf = [0u8; 4];
+                let ch_str = ch.encode_utf8(&mut ch_buf);
+                if let Some(id) = vocab.get(ch_str).copied() {
+                    byte_to_token_id[i] = id;
+                } else {
+                    return Err(BpeError::MissingVocabEntry(ch_str.to_string()));
+                }
             }
         }
 
-        // If the `ignore_merges` flag is set for this tokenizer, we'll need
-        // to use the vocabulary during encoding.
+        // If the `ignore_merges` flag is set for this tokenizer, or it is a
+        // character-level model, we'll need to use the vocabulary during
+        // encoding.
         //
         // Otherwise we can re-use the hash/string allocations for
         // `token_id_to_encoded_bytes`.
-        let (vocab, token_id_to_encoded_bytes) = if ignore_merges {
+        let (vocab, token_id_to_encoded_bytes) = if ignore_merges || char_level {
             let token_id_to_encoded_bytes = vocab
                 .iter()
                 .map(|(token, id)| (*id, token.clone()))
@@ -388,49 +432,115 @@ impl Bpe {
             merges,
             token_id_to_encoded_bytes,
             vocab,
+            char_level,
+            unk_id,
+            byte_fallback,
         })
     }
 
+    /// Return true if this is a character-level rather than byte-level model.
+    pub fn is_char_level(&self) -> bool {
+        self.char_level
+    }
+
     /// Encode a string as a sequence of tokens.
     ///
     /// `end_of_word` specifies whether to apply end-of-word processing rules
     /// to the initial tokenization of piece.
-    fn encode_piece(&self, piece: &str, end_of_word: bool) -> Vec<TokenId> {
+    fn encode_piece(&self, piece: &str, end_of_word: bool) -> Result<Vec<TokenId>, EncodeError> {
         // If `ignore_merges` is set, check for the entire string in the vocab
         // before using merges.
         if self.ignore_merges
             && let Some(vocab) = self.vocab.as_ref()
         {
-            let encoded: EncodedBytes = piece
-                .as_bytes()
-                .iter()
-                .map(|&b| self.byte_to_char[b as usize])
-                .collect();
-            if let Some(&id) = vocab.get(&encoded) {
-                return [id].into();
+            let id = if self.char_level {
+                vocab.get(piece)
+            } else {
+                let encoded: EncodedBytes = piece
+                    .as_bytes()
+                    .iter()
+                    .map(|&b| self.byte_to_char[b as usize])
+                    .collect();
+                vocab.get(&encoded)
+            };
+            if let Some(&id) = id {
+                return Ok([id].into());
             }
         }
 
-        // Start with one token per byte.
-        let mut tokens: Vec<TokenId> = piece
-            .as_bytes()
-            .iter()
-            .map(|&b| self.byte_to_token_id[b as usize])
-            .collect();
+        let mut tokens = if self.char_level {
+            self.char_tokens(piece, end_of_word)?
+        } else {
+            // Start with one token per byte.
+            let mut tokens: Vec<TokenId> = piece
+                .as_bytes()
+                .iter()
+                .map(|&b| self.byte_to_token_id[b as usize])
+                .collect();
 
-        // If the end-of-word suffix is enabled, replace the last byte's token
-        // with the one that corresponds to "{byte}{end_of_word_suffix}".
-        if self.end_of_word_suffix.is_some()
-            && end_of_word
-            && let Some(last) = tokens.pop()
-        {
-            tokens.push(last + 256);
-        }
+            // If the end-of-word suffix is enabled, replace the last byte's
+            // token with the one that corresponds to
+            // "{byte}{end_of_word_suffix}".
+            if self.end_of_word_suffix.is_some()
+                && end_of_word
+                && let Some(last) = tokens.pop()
+            {
+                tokens.push(last + 256);
+            }
+            tokens
+        };
 
         // Iteratively merge tokens together until no more are possible.
         bpe_merge(&mut tokens, &self.merges);
 
-        tokens
+        Ok(tokens)
+    }
+
+    /// Return the initial tokens for a piece in a character-level model,
+    /// with one token per character, or several if byte fallback is used.
+    fn char_tokens(&self, piece: &str, end_of_word: bool) -> Result<Vec<TokenId>, EncodeError> {
+        let vocab = self
+            .vocab
+            .as_ref()
+            .expect("character-level model should have vocab");
+        let mut tokens = Vec::with_capacity(piece.len());
+        let mut key = String::new();
+
+        for (i, ch) in piece.char_indices() {
+            key.clear();
+            key.push(ch);
+            if end_of_word
+                && i + ch.len_utf8() == piece.len()
+                && let Some(suffix) = self.end_of_word_suffix.as_deref()
+            {
+                key.push_str(suffix);
+            }
+
+            if let Some(&id) = vocab.get(&key) {
+                tokens.push(id);
+                continue;
+            }
+
+            if self.byte_fallback {
+                let mut buf = [0u8; 4];
+                let byte_ids: Option<Vec<TokenId>> = ch
+                    .encode_utf8(&mut buf)
+                    .bytes()
+                    .map(|b| vocab.get(&format!("<0x{:02X}>", b)).copied())
+                    .collect();
+                if let Some(byte_ids) = byte_ids {
+                    tokens.extend(byte_ids);
+                    continue;
+                }
+            }
+
+            let unk_id = self
+                .unk_id
+                .ok_or_else(|| EncodeError::TokenIdNotFound(key.clone()))?;
+            tokens.push(unk_id);
+        }
+
+        Ok(tokens)
     }
 }
 
@@ -447,6 +557,14 @@ impl Model for Bpe {
             return Some(id);
         }
 
+        // In character-level models, token strings are plain text, apart from
+        // byte fallback tokens, so they can be looked up directly.
+        if self.char_level
+            && let Some(&id) = self.vocab.as_ref().and_then(|vocab| vocab.get(text))
+        {
+            return Some(id);
+        }
+
         // Determine the end-of-word context. eg. In CLIP's tokenizer, the
         // trailing "</w>" in "from</w>" indicates that it should be treated as
         // occurring at the end of a piece from the initial split.
@@ -458,7 +576,7 @@ impl Model for Bpe {
             end_of_word = true;
         }
 
-        let tokens = self.encode_piece(text, end_of_word);
+        let tokens = self.encode_piece(text, end_of_word).ok()?;
         if tokens.len() == 1 {
             Some(tokens[0])
         } else {
@@ -474,12 +592,44 @@ impl Model for Bpe {
         if piece.is_empty() {
             return Ok(());
         }
-        for token in self.encode_piece(piece, true /* end_of_word */) {
+        for token in self.encode_piece(piece, true /* end_of_word */)? {
             on_token(0, token)
         }
         Ok(())
     }
 
+    fn to_json(&self) -> Option<serde_json::Value> {
+        let vocab: serde_json::Map<String, serde_json::Value> = self
+            .token_id_to_encoded_bytes
+            .iter()
+            .map(|(id, token)| (token.clone(), (*id).into()))
+            .collect();
+
+        let mut merges: Vec<_> = self.merges.iter().collect();
+        merges.sort_by_key(|(_pair, (rank, _merged_id))| *rank);
+        let merges: Vec<[&str; 2]> = merges
+            .into_iter()
+            .map(|((a, b), _)| {
+                let token_str = |id| self.token_id_to_encoded_bytes.get(id).map(|s| s.as_str());
+                Some([token_str(a)?, token_str(b)?])
+            })
+            .collect::<Option<_>>()?;
+
+        let unk_token = self
+            .unk_id
+            .and_then(|id| self.token_id_to_encoded_bytes.get(&id));
+
+        Some(json!({
+            "type": "BPE",
+            "vocab": vocab,
+            "merges": merges,
+            "end_of_word_suffix": self.end_of_word_suffix,
+            "ignore_merges": self.ignore_merges,
+            "unk_token": unk_token,
+            "byte_fallback": self.byte_fallback,
+        }))
+    }
+
     fn decode(&self, ids: &[TokenId]) -> Result<String, DecodeError> {
         let char_to_byte = char_to_byte();
 
@@ -488,6 +638,13 @@ impl Model for Bpe {
             if let Some(tok_str) = self.added_tokens.get(&id) {
                 bytes.extend(tok_str.as_bytes());
             } else if let Some(encoded_bytes) = self.token_id_to_encoded_bytes.get(&id) {
+                if self.char_level {
+                    match parse_byte_token(encoded_bytes).filter(|_| self.byte_fallback) {
+                        Some(byte) => bytes.push(byte),
+                        None => bytes.extend(encoded_bytes.as_bytes()),
+                    }
+                    continue;
+                }
                 bytes.extend(
                     encoded_bytes
                         .chars()
@@ -507,7 +664,7 @@ mod tests {
     use rten_testing::TestCases;
     use rustc_hash::FxHashMap;
 
-    use super::{Bpe, BpeOptions, EncodedBytes, merge_pairs_from_lines};
+    use super::{Bpe, BpeError, BpeOptions, EncodedBytes, merge_pairs_from_lines};
     use crate::pre_tokenizers::Split;
     use crate::tokenizer::{TokenId, Tokenizer};
 
@@ -680,7 +837,7 @@ ba r",
                 vocab: vocab.clone(),
                 end_of_word_suffix: end_of_word_suffix.clone(),
                 ignore_merges: *ignore_merges,
-                added_tokens: Default::default(),
+                ..Default::default()
             };
             let model = Bpe::new(bpe_opts).unwrap();
             let tokenizer = Tokenizer::new(model, Default::default())
@@ -808,4 +965,90 @@ ba r",
             assert_eq!(decoded, *expected);
         })
     }
+
+    #[test]
+    fn test_char_level() {
+        #[derive(Debug)]
+        struct Case<'a> {
+            text: &'a str,
+            unk_id: Option<TokenId>,
+            byte_fallback: bool,
+            expected_tokens: Option<&'a [&'a str]>,
+        }
+
+        let cases = [
+            Case {
+                text: "abcab",
+                unk_id: None,
+                byte_fallback: false,
+                expected_tokens: Some(&["ab", "c", "ab"]),
+            },
+            // Unknown character with byte fallback.
+            Case {
+                text: "abé",
+                unk_id: Some(0),
+                byte_fallback: true,
+                expected_tokens: Some(&["ab", "<0xC3>", "<0xA9>"]),
+            },
+            // Unknown character with no byte token.
+            Case {
+                text: "abd",
+                unk_id: Some(0),
+                byte_fallback: true,
+                expected_tokens: Some(&["ab", "<unk>"]),
+            },
+            // Unknown character with no byte or unknown token.
+            Case {
+                text: "abd",
+                unk_id: None,
+                byte_fallback: false,
+                expected_tokens: None,
+            },
+        ];
+
+        let vocab: FxHashMap<EncodedBytes, TokenId> =
+            ["<unk>", "<0xC3>", "<0xA9>", "a", "b", "c", "ab"]
+                .into_iter()
+                .enumerate()
+                .map(|(id, token)| (token.to_string(), id as TokenId))
+                .collect();
+        let merge_pairs = merge_pairs_from_lines(&["a b"]);
+
+        cases.test_each(|case| {
+            let bpe_opts = BpeOptions {
+                merges: &merge_pairs,
+                vocab: Some(vocab.clone()),
+                char_level: true,
+                unk_id: case.unk_id,
+                byte_fallback: case.byte_fallback,
+                ..Default::default()
+            };
+            let model = Bpe::new(bpe_opts).unwrap();
+            let tokenizer = Tokenizer::new(model, Default::default());
+
+            let encoded = tokenizer.encode(case.text, None);
+            let Some(expected_tokens) = case.expected_tokens else {
+                assert!(encoded.is_err());
+                return;
+            };
+            let token_ids = encoded.unwrap().token_ids().to_vec();
+            assert_eq!(
+                tokenizer.model().get_tokens(&token_ids).unwrap(),
+                expected_tokens
+            );
+            if !expected_tokens.contains(&"<unk>") {
+                assert_eq!(tokenizer.decode(&token_ids).unwrap(), case.text);
+            }
+        })
+    }
+
+    #[test]
+    fn test_char_level_missing_vocab() {
+        let bpe_opts = BpeOptions {
+            char_level: true,
+            ..Default::default()
+        };
+        let result = Bpe::new(bpe_opts);
+        assert!(matches!(result, Err(BpeError::MissingVocab)));
+    }
 }
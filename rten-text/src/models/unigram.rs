@@ -0,0 +1,467 @@
+use std::error::Error;
+use std::fmt;
+
+use rustc_hash::FxHashMap;
+use serde_json::json;
+
+use super::{DecodeError, EncodeError, Model};
+use crate::tokenizer::TokenId;
+
+/// Penalty subtracted from the lowest score in the vocabulary to get the score
+/// for unknown characters.
+///
+/// This matches the value used by SentencePiece and Hugging Face Tokenizers.
+const UNK_PENALTY: f64 = 10.0;
+
+/// Errors that can occur when constructing a [`Unigram`] model.
+#[derive(Debug)]
+pub enum UnigramError {
+    /// The vocabulary was empty.
+    EmptyVocab,
+
+    /// The ID of the unknown token is outside the vocabulary.
+    InvalidUnkId(TokenId),
+}
+
+impl fmt::Display for UnigramError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnigramError::EmptyVocab => write!(fmt, "vocabulary is empty"),
+            UnigramError::InvalidUnkId(id) => {
+                write!(fmt, "unknown token ID {} is not in vocabulary", id)
+            }
+        }
+    }
+}
+
+impl Error for UnigramError {}
+
+/// Configuration for a [`Unigram`] tokenization model.
+#[derive(Debug, Default, Clone)]
+pub struct UnigramOptions {
+    /// ID of the token used for characters that cannot be encoded using
+    /// any other token in the vocabulary.
+    ///
+    /// If not set, encoding such characters fails.
+    pub unk_id: Option<TokenId>,
+
+    /// Encode unknown characters as a sequence of byte tokens of the form
+    /// `<0xXX>`, if the vocabulary contains them, instead of using the
+    /// unknown token.
+    pub byte_fallback: bool,
+}
+
+/// Unigram language model tokenizer [^1], as implemented by SentencePiece [^2].
+///
+/// This is used by T5, mBART, XLM-RoBERTa and many other multilingual models.
+/// Each token in the vocabulary has an associated log probability. A piece of
+/// text is encoded as the segmentation which maximizes the sum of token
+/// scores, found using the Viterbi algorithm.
+///
+/// Decoding concatenates token strings and converts any byte fallback tokens
+/// back into bytes. Tokens produced by SentencePiece typically use "▁"
+/// (U+2581) in place of spaces. Replacing these is left to a decoder.
+///
+/// [^1]: Kudo, Taku. "Subword regularization: Improving neural network
+///       translation models with multiple subword candidates." arXiv preprint
+///       arXiv:1804.10959 (2018). <https://arxiv.org/abs/1804.10959>
+///
+/// [^2]: <https://github.com/google/sentencepiece>
+#[derive(Clone)]
+pub struct Unigram {
+    /// Token strings and scores, indexed by token ID.
+    vocab: Vec<(String, f64)>,
+
+    token_to_id: FxHashMap<String, TokenId>,
+
+    /// Length in bytes of the longest token in the vocabulary.
+    max_token_len: usize,
+
+    unk_id: Option<TokenId>,
+
+    /// Score assigned to unknown characters.
+    unk_score: f64,
+
+    /// Map from byte value to ID of the corresponding `<0xXX>` token, if byte
+    /// fallback is enabled.
+    byte_to_token_id: Option<[Option<TokenId>; 256]>,
+}
+
+/// Best path found so far to a position in the input during Viterbi decoding.
+#[derive(Clone, Copy)]
+struct Node {
+    score: f64,
+
+    /// Byte offset where the last token on the path starts.
+    start: usize,
+
+    /// ID of the last token on the path, or `None` for an unknown character.
+    token_id: Option<TokenId>,
+}
+
+/// Parse a byte fallback token of the form `<0xXX>`.
+pub(crate) fn parse_byte_token(token: &str) -> Option<u8> {
+    let hex = token.strip_prefix("<0x")?.strip_suffix('>')?;
+    if hex.len() != 2 {
+        return None;
+    }
+    u8::from_str_radix(hex, 16).ok()
+}
+
+impl Unigram {
+    /// Construct a Unigram model from a vocabulary.
+    ///
+    /// `vocab` is a list of `(token, score)` pairs, where the token ID is the
+    /// index in the list and the score is a log probability.
+    pub fn from_vocab(
+        vocab: Vec<(String, f64)>,
+        options: UnigramOptions,
+    ) -> Result<Unigram, UnigramError> {
+        if vocab.is_empty() {
+            return Err(UnigramError::EmptyVocab);
+        }
+        if let Some(unk_id) = options.unk_id
+            && unk_id as usize >= vocab.len()
+        {
+            return Err(UnigramError::InvalidUnkId(unk_id));
+        }
+
+        let token_to_id: FxHashMap<String, TokenId> = vocab
+            .iter()
+            .enumerate()
+            .map(|(id, (token, _score))| (token.clone(), id as TokenId))
+            .collect();
+        let max_token_len = vocab
+            .iter()
+            .map(|(token, _score)| token.len())
+            .max()
+            .unwrap_or(0);
+        let min_score = vocab
+            .iter()
+            .map(|(_token, score)| *score)
+            .fold(f64::INFINITY, f64::min);
+
+        let byte_to_token_id = options.byte_fallback.then(|| {
+            let mut byte_to_token_id = [None; 256];
+            for (id, (token, _score)) in vocab.iter().enumerate() {
+                if let Some(byte) = parse_byte_token(token) {
+                    byte_to_token_id[byte as usize] = Some(id as TokenId);
+                }
+            }
+            byte_to_token_id
+        });
+
+        Ok(Unigram {
+            vocab,
+            token_to_id,
+            max_token_len,
+            unk_id: options.unk_id,
+            unk_score: min_score - UNK_PENALTY,
+            byte_to_token_id,
+        })
+    }
+
+    /// Find the highest-scoring segmentation of `text`.
+    ///
+    /// Returns a list of `(start, end, token_id)` tuples where `token_id` is
+    /// `None` for spans of unknown characters. Adjacent unknown characters are
+    /// fused into a single span.
+    fn viterbi(&self, text: &str) -> Vec<(usize, usize, Option<TokenId>)> {
+        let mut best: Vec<Option<Node>> = vec![None; text.len() + 1];
+        best[0] = Some(Node {
+            score: 0.,
+            start: 0,
+            token_id: None,
+        });
+
+        for (start, ch) in text.char_indices() {
+            let Some(prefix_score) = best[start].map(|node| node.score) else {
+                continue;
+            };
+
+            let mut update = |end: usize, score: f64, token_id: Option<TokenId>| {
+                if best[end].is_none_or(|node| score > node.score) {
+                    best[end] = Some(Node {
+                        score,
+                        start,
+                        token_id,
+                    });
+                }
+            };
+
+            let char_end = start + ch.len_utf8();
+            let mut has_single_char_token = false;
+            let mut end = start;
+            for ch in text[start..].chars() {
+                end += ch.len_utf8();
+                if end - start > self.max_token_len {
+                    break;
+                }
+                if let Some(&id) = self.token_to_id.get(&text[start..end]) {
+                    let token_score = self.vocab[id as usize].1;
+                    update(end, prefix_score + token_score, Some(id));
+                    has_single_char_token |= end == char_end;
+                }
+            }
+
+            if !has_single_char_token {
+                update(char_end, prefix_score + self.unk_score, None);
+            }
+        }
+
+        let mut spans = Vec::new();
+        let mut end = text.len();
+        while end > 0 {
+            let node = best[end].expect("should have path to end of input");
+            match (node.token_id, spans.last_mut()) {
+                (None, Some((next_start, _next_end, None))) => {
+                    *next_start = node.start;
+                }
+                _ => spans.push((node.start, end, node.token_id)),
+            }
+            end = node.start;
+        }
+        spans.reverse();
+        spans
+    }
+}
+
+impl Model for Unigram {
+    fn get_token_id(&self, token: &str) -> Option<TokenId> {
+        self.token_to_id.get(token).copied()
+    }
+
+    fn get_token_str(&self, id: TokenId) -> Option<String> {
+        self.vocab
+            .get(id as usize)
+            .map(|(token, _score)| token.clone())
+    }
+
+    fn encode_with_offsets(
+        &self,
+        text: &str,
+        on_token: &mut dyn FnMut(usize, TokenId),
+    ) -> Result<(), EncodeError> {
+        for (start, end, token_id) in self.viterbi(text) {
+            if let Some(id) = token_id {
+                on_token(start, id);
+                continue;
+            }
+
+            let unknown = &text[start..end];
+            if let Some(byte_to_token_id) = &self.byte_to_token_id
+                && let Some(byte_ids) = unknown
+                    .bytes()
+                    .map(|byte| byte_to_token_id[byte as usize])
+                    .collect::<Option<Vec<_>>>()
+            {
+                for id in byte_ids {
+                    on_token(start, id);
+                }
+                continue;
+            }
+
+            let unk_id = self
+                .unk_id
+                .ok_or_else(|| EncodeError::TokenIdNotFound(unknown.to_string()))?;
+            on_token(start, unk_id);
+        }
+        Ok(())
+    }
+
+    fn decode(&self, ids: &[TokenId]) -> Result<String, DecodeError> {
+        let mut bytes = Vec::new();
+        for &id in ids {
+            let (token, _score) = self
+                .vocab
+                .get(id as usize)
+                .ok_or(DecodeError::InvalidTokenId(id))?;
+            match parse_byte_token(token).filter(|_| self.byte_to_token_id.is_some()) {
+                Some(byte) => bytes.push(byte),
+                None => bytes.extend(token.as_bytes()),
+            }
+        }
+        String::from_utf8(bytes).map_err(|_| DecodeError::InvalidUtf8)
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(json!({
+            "type": "Unigram",
+            "vocab": self.vocab,
+            "unk_id": self.unk_id,
+            "byte_fallback": self.byte_to_token_id.is_some(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::read_to_string;
+    use std::path::PathBuf;
+
+    use rten_testing::TestCases;
+
+    use super::{Unigram, UnigramOptions};
+    use crate::models::{DecodeError, EncodeError, Model};
+    use crate::tokenizer::TokenId;
+
+    /// Create a Unigram model using the vocabulary from the `unigram.json`
+    /// tokenizer test file.
+    fn make_unigram(options: UnigramOptions) -> Unigram {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("test-data/tokenizer-json/unigram.json");
+        let json: serde_json::Value = serde_json::from_str(&read_to_string(path).unwrap()).unwrap();
+        let vocab = serde_json::from_value(json["tokenizer"]["model"]["vocab"].clone()).unwrap();
+        Unigram::from_vocab(vocab, options).unwrap()
+    }
+
+    #[test]
+    fn test_unigram_encode() {
+        #[derive(Debug)]
+        struct Case<'a> {
+            text: &'a str,
+            byte_fallback: bool,
+            expected: &'a [TokenId],
+            offsets: &'a [usize],
+        }
+
+        let cases = [
+            // Whole word in vocab.
+            Case {
+                text: "▁hello▁world",
+                byte_fallback: false,
+                expected: &[5, 10],
+                offsets: &[0, 8],
+            },
+            // Best segmentation uses several tokens.
+            Case {
+                text: "▁helllo",
+                byte_fallback: false,
+                expected: &[3, 8, 4],
+                offsets: &[0, 5, 6],
+            },
+            // Adjacent unknown characters are fused.
+            Case {
+                text: "▁heqq",
+                byte_fallback: false,
+                expected: &[3, 0],
+                offsets: &[0, 5],
+            },
+            // Unknown character without byte fallback.
+            Case {
+                text: "▁hé",
+                byte_fallback: false,
+                expected: &[2, 0],
+                offsets: &[0, 4],
+            },
+            // Unknown character with byte fallback.
+            Case {
+                text: "▁hé",
+                byte_fallback: true,
+                expected: &[2, 11, 12],
+                offsets: &[0, 4, 4],
+            },
+            // Byte fallback where byte tokens are missing from the vocab.
+            Case {
+                text: "▁h€",
+                byte_fallback: true,
+                expected: &[2, 0],
+                offsets: &[0, 4],
+            },
+            // Empty input.
+            Case {
+                text: "",
+                byte_fallback: false,
+                expected: &[],
+                offsets: &[],
+            },
+        ];
+
+        cases.test_each(|case| {
+            let model = make_unigram(UnigramOptions {
+                unk_id: Some(0),
+                byte_fallback: case.byte_fallback,
+            });
+
+            let mut tokens = Vec::new();
+            let mut offsets = Vec::new();
+            model
+                .encode_with_offsets(case.text, &mut |offset, token| {
+                    offsets.push(offset);
+                    tokens.push(token);
+                })
+                .unwrap();
+
+            assert_eq!(tokens, case.expected);
+            assert_eq!(offsets, case.offsets);
+        })
+    }
+
+    #[test]
+    fn test_unigram_missing_unk_id() {
+        let model = make_unigram(UnigramOptions::default());
+        let result = model.encode("▁heq");
+        assert_eq!(result, Err(EncodeError::TokenIdNotFound("q".to_string())));
+    }
+
+    #[test]
+    fn test_unigram_decode() {
+        #[derive(Debug)]
+        struct Case<'a> {
+            ids: &'a [TokenId],
+            byte_fallback: bool,
+            expected: Result<&'a str, DecodeError>,
+        }
+
+        let cases = [
+            Case {
+                ids: &[5, 10],
+                byte_fallback: false,
+                expected: Ok("▁hello▁world"),
+            },
+            Case {
+                ids: &[2, 11, 12],
+                byte_fallback: true,
+                expected: Ok("▁hé"),
+            },
+            Case {
+                ids: &[2, 11, 12],
+                byte_fallback: false,
+                expected: Ok("▁h<0xC3><0xA9>"),
+            },
+            // Incomplete UTF-8 sequence.
+            Case {
+                ids: &[2, 11],
+                byte_fallback: true,
+                expected: Err(DecodeError::InvalidUtf8),
+            },
+            Case {
+                ids: &[2, 100],
+                byte_fallback: false,
+                expected: Err(DecodeError::InvalidTokenId(100)),
+            },
+        ];
+
+        cases.test_each(|case| {
+            let model = make_unigram(UnigramOptions {
+                unk_id: Some(0),
+                byte_fallback: case.byte_fallback,
+            });
+            let decoded = model.decode(case.ids);
+            assert_eq!(decoded, case.expected.clone().map(|s| s.to_string()));
+        })
+    }
+
+    #[test]
+    fn test_unigram_invalid_unk_id() {
+        let vocab = vec![("a".to_string(), -1.0)];
+        let result = Unigram::from_vocab(
+            vocab,
+            UnigramOptions {
+                unk_id: Some(5),
+                ..Default::default()
+            },
+        );
+        assert!(result.is_err());
+    }
+}
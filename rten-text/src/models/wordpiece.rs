@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use serde_json::json;
+
 use super::{DecodeError, EncodeError, Model};
 use crate::tokenizer::TokenId;
 
@@ -19,16 +21,22 @@ pub struct WordPiece {
     id_to_token: HashMap<TokenId, String>,
     subword_prefix: String,
     max_word_len: usize,
+    unk_token: String,
 }
 
 /// Configuration for a [`WordPiece`] tokenizer.
 #[derive(Debug, Default, Clone)]
 pub struct WordPieceOptions {
     /// The maximum length of words that can be tokenized. Any words longer than
-    /// this are tokenized as `[UNK]`.
+    /// this are tokenized as the unknown token.
     ///
     /// Defaults to 100.
     pub max_word_len: Option<usize>,
+
+    /// Token used for words which cannot be tokenized.
+    ///
+    /// Defaults to `[UNK]`.
+    pub unk_token: Option<String>,
 }
 
 impl WordPiece {
@@ -45,6 +53,7 @@ impl WordPiece {
             token_to_id: vocab,
             subword_prefix,
             max_word_len: options.max_word_len.unwrap_or(100),
+            unk_token: options.unk_token.unwrap_or_else(|| "[UNK]".to_string()),
             id_to_token,
         }
     }
@@ -61,10 +70,9 @@ impl Model for WordPiece {
 
         macro_rules! add_unknown_token {
             () => {
-                let unknown_token = "[UNK]";
                 let unknown_token_id = self
-                    .get_token_id(unknown_token)
-                    .ok_or_else(|| EncodeError::TokenIdNotFound(unknown_token.to_string()))?;
+                    .get_token_id(&self.unk_token)
+                    .ok_or_else(|| EncodeError::TokenIdNotFound(self.unk_token.clone()))?;
                 on_token(offset, unknown_token_id);
             };
         }
@@ -126,6 +134,16 @@ impl Model for WordPiece {
         let token_strings = self.get_tokens(ids)?;
         Ok(token_strings.join(" "))
     }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(json!({
+            "type": "WordPiece",
+            "vocab": self.token_to_id,
+            "unk_token": self.unk_token,
+            "continuing_subword_prefix": self.subword_prefix,
+            "max_input_chars_per_word": self.max_word_len,
+        }))
+    }
 }
 
 #[cfg(test)]
@@ -256,6 +274,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_wordpiece_unk_token() {
+        let vocab = &["[CLS]", "[SEP]", "<unk>", "foo"];
+        let opts = WordPieceOptions {
+            unk_token: Some("<unk>".into()),
+            ..Default::default()
+        };
+        let tokenizer = create_tokenizer(vocab, None, opts);
+
+        let encoded = tokenizer.encode("foo bar", None).unwrap();
+        assert_eq!(
+            tokenizer.model().get_tokens(encoded.token_ids()).unwrap(),
+            &["[CLS]", "foo", "<unk>", "[SEP]"]
+        );
+
+        let json = tokenizer.model().to_json().unwrap();
+        assert_eq!(json["unk_token"], "<unk>");
+    }
+
     #[test]
     fn test_wordpiece_model_lowercase() {
         #[derive(Debug)]
@@ -3,7 +3,10 @@
 use std::error::Error;
 use std::fmt;
 
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
 use fancy_regex::Regex;
+use serde_json::json;
 use unicode_categories::UnicodeCategories;
 use unicode_normalization::char::{compose, decompose_canonical, decompose_compatible};
 
@@ -69,12 +72,16 @@ impl CharNormalizer {
 #[derive(Clone, Debug)]
 pub enum NormalizeError {
     RegexError(Box<fancy_regex::Error>),
+
+    /// The character map for a [`Precompiled`] normalizer is invalid.
+    InvalidCharsMap,
 }
 
 impl fmt::Display for NormalizeError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::RegexError(err) => write!(f, "regex failed {}", err),
+            Self::InvalidCharsMap => write!(f, "invalid precompiled character map"),
         }
     }
 }
@@ -83,6 +90,7 @@ impl Error for NormalizeError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
             Self::RegexError(err) => Some(err),
+            Self::InvalidCharsMap => None,
         }
     }
 }
@@ -100,13 +108,21 @@ impl From<fancy_regex::Error> for NormalizeError {
 /// from positions in the normalized string back to the original string. This
 /// is useful for post-processing in NLP tasks to map machine learning model
 /// outputs back to the location in the original text.
-pub trait Normalizer: std::fmt::Debug {
+pub trait Normalizer: std::fmt::Debug + Send + Sync {
     /// Apply normalization to a string.
     ///
     /// Returns a tuple of `(normalized_string, offset_map)` where `offset_map`
     /// is a mapping from byte offsets in the normalized string to corresponding
     /// offsets in the original string.
     fn normalize(&self, text: &str) -> Result<(String, Vec<usize>), NormalizeError>;
+
+    /// Return the configuration of this normalizer in the format used by
+    /// `tokenizer.json` files, or `None` if it cannot be serialized.
+    ///
+    /// This is used by [`Tokenizer::to_json`](crate::Tokenizer::to_json).
+    fn to_json(&self) -> Option<serde_json::Value> {
+        None
+    }
 }
 
 /// A [`Normalizer`] that implements normalization used by BERT and BERT-derived
@@ -174,6 +190,23 @@ impl Normalizer for Bert {
 
         Ok((normalized, offsets))
     }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        let config = if self.lowercase && !self.strip_accents {
+            json!({ "type": "Lowercase" })
+        } else {
+            // This normalizer doesn't implement the text cleaning and CJK
+            // character handling options of the Hugging Face version.
+            json!({
+                "type": "BertNormalizer",
+                "lowercase": self.lowercase,
+                "strip_accents": self.strip_accents,
+                "clean_text": false,
+                "handle_chinese_chars": false,
+            })
+        };
+        Some(config)
+    }
 }
 
 /// Replaces occurrences of a pattern with a given string.
@@ -220,6 +253,241 @@ impl Normalizer for Replace {
 
         Ok((normalized, offsets))
     }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(json!({
+            "type": "Replace",
+            "pattern": { "Regex": self.regex.as_str() },
+            "content": self.content,
+        }))
+    }
+}
+
+/// Removes whitespace from the start and/or end of the input.
+#[derive(Clone, Debug)]
+pub struct Strip {
+    left: bool,
+    right: bool,
+}
+
+impl Strip {
+    /// Create a normalizer which strips whitespace from the start of the
+    /// input if `left` is true and the end if `right` is true.
+    pub fn new(left: bool, right: bool) -> Self {
+        Strip { left, right }
+    }
+}
+
+impl Normalizer for Strip {
+    fn normalize(&self, text: &str) -> Result<(String, Vec<usize>), NormalizeError> {
+        let mut start = 0;
+        let mut end = text.len();
+        if self.left {
+            start = end - text.trim_start().len();
+        }
+        if self.right {
+            end = start + text[start..].trim_end().len();
+        }
+        Ok((text[start..end].to_string(), (start..end).collect()))
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(json!({
+            "type": "Strip",
+            "strip_left": self.left,
+            "strip_right": self.right,
+        }))
+    }
+}
+
+/// Removes characters in the Unicode Mark ("M") categories.
+///
+/// This is typically preceded by a [`Unicode::Nfd`] or [`Unicode::Nfkd`]
+/// normalizer, which decomposes accented characters into a base character
+/// followed by combining marks.
+#[derive(Clone, Debug, Default)]
+pub struct StripAccents {}
+
+impl StripAccents {
+    pub fn new() -> Self {
+        StripAccents {}
+    }
+}
+
+impl Normalizer for StripAccents {
+    fn normalize(&self, text: &str) -> Result<(String, Vec<usize>), NormalizeError> {
+        let mut normalized = String::with_capacity(text.len());
+        let mut offsets = Vec::with_capacity(text.len());
+
+        for (offset, ch) in text.char_indices() {
+            if ch.is_mark() {
+                continue;
+            }
+            normalized.push(ch);
+            offsets.extend(offset..offset + ch.len_utf8());
+        }
+
+        Ok((normalized, offsets))
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(json!({ "type": "StripAccents" }))
+    }
+}
+
+/// Adds a prefix to the start of non-empty inputs.
+#[derive(Clone, Debug)]
+pub struct Prepend {
+    prefix: String,
+}
+
+impl Prepend {
+    pub fn new(prefix: String) -> Self {
+        Prepend { prefix }
+    }
+}
+
+impl Normalizer for Prepend {
+    fn normalize(&self, text: &str) -> Result<(String, Vec<usize>), NormalizeError> {
+        if text.is_empty() {
+            return Ok((String::new(), Vec::new()));
+        }
+
+        let mut normalized = String::with_capacity(self.prefix.len() + text.len());
+        normalized.push_str(&self.prefix);
+        normalized.push_str(text);
+
+        let mut offsets = Vec::with_capacity(normalized.len());
+        offsets.extend(std::iter::repeat_n(0, self.prefix.len()));
+        offsets.extend(0..text.len());
+
+        Ok((normalized, offsets))
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(json!({ "type": "Prepend", "prepend": self.prefix }))
+    }
+}
+
+/// Normalizer which applies a precompiled character mapping from a
+/// SentencePiece model.
+///
+/// The character map consists of a double-array trie, which maps byte
+/// sequences in the input to offsets of replacement strings, followed by the
+/// null-terminated replacement strings. At each position in the input the
+/// longest matching sequence is replaced. Characters which don't match are
+/// left as-is.
+///
+/// See <https://github.com/google/sentencepiece/blob/master/src/normalizer.h>.
+#[derive(Clone, Debug)]
+pub struct Precompiled {
+    /// Units of the double-array trie.
+    trie: Vec<u32>,
+
+    /// Null-terminated replacement strings referenced by values in the trie.
+    replacements: String,
+}
+
+impl Precompiled {
+    /// Create a normalizer from the serialized `precompiled_charsmap` of a
+    /// SentencePiece model.
+    pub fn from_charsmap(data: &[u8]) -> Result<Self, NormalizeError> {
+        let (size, rest) = data
+            .split_first_chunk::<4>()
+            .ok_or(NormalizeError::InvalidCharsMap)?;
+        let trie_size = u32::from_le_bytes(*size) as usize;
+        if !trie_size.is_multiple_of(4) || rest.len() < trie_size {
+            return Err(NormalizeError::InvalidCharsMap);
+        }
+        let (trie, replacements) = rest.split_at(trie_size);
+
+        let trie = trie
+            .chunks_exact(4)
+            .map(|unit| u32::from_le_bytes(unit.try_into().unwrap()))
+            .collect();
+        let replacements = String::from_utf8(replacements.to_vec())
+            .map_err(|_| NormalizeError::InvalidCharsMap)?;
+
+        Ok(Precompiled { trie, replacements })
+    }
+
+    /// Return the serialized character map that this normalizer was created
+    /// from.
+    pub fn charsmap(&self) -> Vec<u8> {
+        let trie_size = (self.trie.len() * 4) as u32;
+        let mut data = Vec::with_capacity(4 + trie_size as usize + self.replacements.len());
+        data.extend(trie_size.to_le_bytes());
+        for unit in &self.trie {
+            data.extend(unit.to_le_bytes());
+        }
+        data.extend(self.replacements.as_bytes());
+        data
+    }
+
+    /// Find the longest prefix of `key` in the trie and return the length of
+    /// the prefix and the associated replacement.
+    fn longest_match(&self, key: &[u8]) -> Option<(usize, &str)> {
+        // Accessors for fields of a unit in the double array.
+        // See https://github.com/s-yata/darts-clone/blob/master/include/darts.h.
+        let has_leaf = |unit: u32| (unit >> 8) & 1 == 1;
+        let value = |unit: u32| unit & ((1 << 31) - 1);
+        let label = |unit: u32| unit & ((1 << 31) | 0xFF);
+        let offset = |unit: u32| ((unit >> 10) << ((unit & (1 << 9)) >> 6)) as usize;
+
+        let mut best_match = None;
+        let mut node_pos = offset(*self.trie.first()?);
+
+        for (i, &byte) in key.iter().enumerate() {
+            node_pos ^= byte as usize;
+            let unit = *self.trie.get(node_pos)?;
+            if label(unit) != byte as u32 {
+                break;
+            }
+            node_pos ^= offset(unit);
+            if has_leaf(unit) {
+                let leaf = *self.trie.get(node_pos)?;
+                best_match = Some((i + 1, value(leaf) as usize));
+            }
+        }
+
+        let (len, start) = best_match?;
+        let replacement = self.replacements.get(start..)?;
+        let end = replacement.find('\0').unwrap_or(replacement.len());
+        Some((len, &replacement[..end]))
+    }
+}
+
+impl Normalizer for Precompiled {
+    fn normalize(&self, text: &str) -> Result<(String, Vec<usize>), NormalizeError> {
+        let mut normalized = String::with_capacity(text.len());
+        let mut offsets = Vec::with_capacity(text.len());
+
+        let mut pos = 0;
+        while pos < text.len() {
+            match self.longest_match(&text.as_bytes()[pos..]) {
+                Some((len, replacement)) if text.is_char_boundary(pos + len) => {
+                    normalized.push_str(replacement);
+                    offsets.extend(std::iter::repeat_n(pos, replacement.len()));
+                    pos += len;
+                }
+                _ => {
+                    let ch = text[pos..].chars().next().unwrap();
+                    normalized.push(ch);
+                    offsets.extend(pos..pos + ch.len_utf8());
+                    pos += ch.len_utf8();
+                }
+            }
+        }
+
+        Ok((normalized, offsets))
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(json!({
+            "type": "Precompiled",
+            "precompiled_charsmap": BASE64.encode(self.charsmap()),
+        }))
+    }
 }
 
 /// Run a series of normalizers in sequence.
@@ -250,6 +518,15 @@ impl Normalizer for Sequence {
 
         Ok((normalized, offsets))
     }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        let normalizers = self
+            .normalizers
+            .iter()
+            .map(|normalizer| normalizer.to_json())
+            .collect::<Option<Vec<_>>>()?;
+        Some(json!({ "type": "Sequence", "normalizers": normalizers }))
+    }
 }
 
 /// Temporary buffer used while normalizing text.
@@ -352,13 +629,26 @@ impl Normalizer for Unicode {
 
         Ok(tmp.into_string_with_byte_offsets())
     }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        let kind = match self {
+            Self::Nfc => "NFC",
+            Self::Nfd => "NFD",
+            Self::Nfkc => "NFKC",
+            Self::Nfkd => "NFKD",
+        };
+        Some(json!({ "type": kind }))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use rten_testing::TestCases;
 
-    use super::{Bert, BertOptions, Normalizer, Replace, Sequence, Unicode};
+    use super::{
+        Bert, BertOptions, Normalizer, Precompiled, Prepend, Replace, Sequence, Strip,
+        StripAccents, Unicode,
+    };
 
     #[test]
     fn test_bert_noop() {
@@ -528,6 +818,156 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_strip() {
+        #[derive(Debug)]
+        struct Case<'a> {
+            input: &'a str,
+            left: bool,
+            right: bool,
+            expected: &'a str,
+            expected_offsets: Vec<usize>,
+        }
+
+        let cases = [
+            Case {
+                input: "  foo ",
+                left: true,
+                right: true,
+                expected: "foo",
+                expected_offsets: [2, 3, 4].into(),
+            },
+            Case {
+                input: "  foo ",
+                left: true,
+                right: false,
+                expected: "foo ",
+                expected_offsets: [2, 3, 4, 5].into(),
+            },
+            Case {
+                input: "  foo ",
+                left: false,
+                right: true,
+                expected: "  foo",
+                expected_offsets: [0, 1, 2, 3, 4].into(),
+            },
+            Case {
+                input: "   ",
+                left: true,
+                right: true,
+                expected: "",
+                expected_offsets: [].into(),
+            },
+        ];
+
+        cases.test_each(|case| {
+            let normalizer = Strip::new(case.left, case.right);
+            let (normalized, offsets) = normalizer.normalize(case.input).unwrap();
+            assert_eq!(normalized, case.expected);
+            assert_eq!(offsets, case.expected_offsets);
+        })
+    }
+
+    #[test]
+    fn test_strip_accents() {
+        let normalizer = StripAccents::new();
+        let (normalized, offsets) = normalizer.normalize("Mote\u{301}ö").unwrap();
+
+        // Combining marks are removed, but precomposed characters are not
+        // decomposed.
+        assert_eq!(normalized, "Moteö");
+        assert_eq!(offsets, [0, 1, 2, 3, 6, 7]);
+    }
+
+    #[test]
+    fn test_prepend() {
+        let normalizer = Prepend::new("▁".into());
+
+        let (normalized, offsets) = normalizer.normalize("foo").unwrap();
+        assert_eq!(normalized, "▁foo");
+        assert_eq!(offsets, [0, 0, 0, 0, 1, 2]);
+
+        let (normalized, offsets) = normalizer.normalize("").unwrap();
+        assert_eq!(normalized, "");
+        assert!(offsets.is_empty());
+    }
+
+    /// Create a character map which maps "Ａ" to "A", "ﬁ" to "fi", "AB" to
+    /// "X" and "A" to "a".
+    fn test_charsmap() -> Vec<u8> {
+        // Non-zero units of the double-array trie.
+        let units = [
+            (0, 0x400),
+            (4, 0x80000000),
+            (6, 0x80000002),
+            (7, 0x80000007),
+            (8, 0x80000005),
+            (64, 0x11d41),
+            (69, 0x13542),
+            (132, 0x20981),
+            (162, 0x299a1),
+            (174, 0x2acac),
+            (190, 0x2f4bc),
+            (238, 0x3b0ef),
+        ];
+        let mut trie = vec![0u32; 239];
+        for (pos, unit) in units {
+            trie[pos] = unit;
+        }
+
+        let mut charsmap = Vec::new();
+        charsmap.extend(((trie.len() * 4) as u32).to_le_bytes());
+        for unit in trie {
+            charsmap.extend(unit.to_le_bytes());
+        }
+        charsmap.extend(b"A\0fi\0X\0a\0");
+        charsmap
+    }
+
+    #[test]
+    fn test_precompiled() {
+        #[derive(Debug)]
+        struct Case<'a> {
+            input: &'a str,
+            expected: &'a str,
+            expected_offsets: Vec<usize>,
+        }
+
+        let cases = [
+            // Input with no matches.
+            Case {
+                input: "foo",
+                expected: "foo",
+                expected_offsets: [0, 1, 2].into(),
+            },
+            // Single and multi-character replacements.
+            Case {
+                input: "Ａﬁ",
+                expected: "Afi",
+                expected_offsets: [0, 3, 3].into(),
+            },
+            // Longest match is preferred.
+            Case {
+                input: "ABA",
+                expected: "Xa",
+                expected_offsets: [0, 2].into(),
+            },
+        ];
+
+        let normalizer = Precompiled::from_charsmap(&test_charsmap()).unwrap();
+        cases.test_each(|case| {
+            let (normalized, offsets) = normalizer.normalize(case.input).unwrap();
+            assert_eq!(normalized, case.expected);
+            assert_eq!(offsets, case.expected_offsets);
+        })
+    }
+
+    #[test]
+    fn test_precompiled_invalid() {
+        assert!(Precompiled::from_charsmap(&[]).is_err());
+        assert!(Precompiled::from_charsmap(&[8, 0, 0, 0, 1, 2, 3, 4]).is_err());
+    }
+
     fn lowercase_normalizer() -> Box<dyn Normalizer> {
         Box::new(Bert::new(BertOptions {
             lowercase: true,
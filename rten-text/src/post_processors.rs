@@ -0,0 +1,342 @@
+//! Post-processors which add special tokens to encoded sequences.
+//!
+//! Post-processing is the final step of encoding. It combines the tokens from
+//! one or two input sequences with special tokens, such as the `[CLS]` and
+//! `[SEP]` tokens used by BERT, and assigns a type ID to each token.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::tokenizer::TokenId;
+
+/// Errors occuring while constructing a post-processor.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PostProcessorError {
+    /// A template does not contain the expected sequence placeholders.
+    InvalidTemplate(String),
+
+    /// A template refers to a special token which is not defined.
+    UnknownSpecialToken(String),
+}
+
+impl fmt::Display for PostProcessorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidTemplate(msg) => write!(f, "invalid template: {}", msg),
+            Self::UnknownSpecialToken(token) => write!(f, "unknown special token {}", token),
+        }
+    }
+}
+
+impl Error for PostProcessorError {}
+
+/// Identifies an input sequence in a [`TemplatePiece::Sequence`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SequenceId {
+    /// The first sequence, referred to as `$A` in templates.
+    A,
+    /// The second sequence, referred to as `$B` in templates.
+    B,
+}
+
+/// An element of a [`TemplateProcessing`] template.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TemplatePiece {
+    /// Tokens from one of the input sequences.
+    Sequence { id: SequenceId, type_id: u32 },
+
+    /// One or more special tokens.
+    SpecialToken { ids: Vec<TokenId>, type_id: u32 },
+}
+
+impl TemplatePiece {
+    /// Return the type ID assigned to tokens produced by this piece.
+    pub fn type_id(&self) -> u32 {
+        match self {
+            Self::Sequence { type_id, .. } | Self::SpecialToken { type_id, .. } => *type_id,
+        }
+    }
+}
+
+/// Type of post-processor in `tokenizer.json` files which a
+/// [`TemplateProcessing`] was created from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum TemplateKind {
+    /// Generic template (`TemplateProcessing`).
+    Template,
+    /// Template for BERT models (`BertProcessing`).
+    Bert { cls: TokenId, sep: TokenId },
+    /// Template for RoBERTa models (`RobertaProcessing`).
+    Roberta { cls: TokenId, sep: TokenId },
+}
+
+/// Post-processor which combines input sequences with special tokens
+/// according to a template.
+///
+/// There are separate templates for single sequence and sequence pair inputs.
+/// Convenience constructors are provided for the templates used by common
+/// models. See also
+/// <https://huggingface.co/docs/tokenizers/en/api/post-processors>.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TemplateProcessing {
+    single: Vec<TemplatePiece>,
+    pair: Vec<TemplatePiece>,
+    kind: TemplateKind,
+}
+
+impl TemplateProcessing {
+    /// Create a post-processor from templates for single sequence and sequence
+    /// pair inputs.
+    ///
+    /// `single` must contain the sequence [`SequenceId::A`] exactly once.
+    /// `pair` must contain both [`SequenceId::A`] and [`SequenceId::B`]
+    /// exactly once, in that order.
+    pub fn new(
+        single: Vec<TemplatePiece>,
+        pair: Vec<TemplatePiece>,
+    ) -> Result<Self, PostProcessorError> {
+        fn sequence_ids(template: &[TemplatePiece]) -> Vec<SequenceId> {
+            template
+                .iter()
+                .filter_map(|piece| match piece {
+                    TemplatePiece::Sequence { id, .. } => Some(*id),
+                    TemplatePiece::SpecialToken { .. } => None,
+                })
+                .collect()
+        }
+
+        if sequence_ids(&single) != [SequenceId::A] {
+            return Err(PostProcessorError::InvalidTemplate(
+                "single sequence template must contain $A once".into(),
+            ));
+        }
+        if sequence_ids(&pair) != [SequenceId::A, SequenceId::B] {
+            return Err(PostProcessorError::InvalidTemplate(
+                "pair template must contain $A and $B once".into(),
+            ));
+        }
+
+        Ok(TemplateProcessing {
+            single,
+            pair,
+            kind: TemplateKind::Template,
+        })
+    }
+
+    /// Create the post-processor used by BERT models.
+    ///
+    /// This produces `[CLS] $A [SEP]` for single sequences and
+    /// `[CLS] $A [SEP] $B [SEP]` for pairs, where the tokens from `$B` and the
+    /// final `[SEP]` have a type ID of 1.
+    pub fn bert(cls: TokenId, sep: TokenId) -> Self {
+        let special = |id, type_id| TemplatePiece::SpecialToken {
+            ids: vec![id],
+            type_id,
+        };
+        let seq = |id, type_id| TemplatePiece::Sequence { id, type_id };
+
+        TemplateProcessing {
+            single: vec![special(cls, 0), seq(SequenceId::A, 0), special(sep, 0)],
+            pair: vec![
+                special(cls, 0),
+                seq(SequenceId::A, 0),
+                special(sep, 0),
+                seq(SequenceId::B, 1),
+                special(sep, 1),
+            ],
+            kind: TemplateKind::Bert { cls, sep },
+        }
+    }
+
+    /// Create the post-processor used by RoBERTa models.
+    ///
+    /// This produces `<s> $A </s>` for single sequences and
+    /// `<s> $A </s> </s> $B </s>` for pairs. All tokens have a type ID of 0.
+    pub fn roberta(cls: TokenId, sep: TokenId) -> Self {
+        let special = |id| TemplatePiece::SpecialToken {
+            ids: vec![id],
+            type_id: 0,
+        };
+        let seq = |id| TemplatePiece::Sequence { id, type_id: 0 };
+
+        TemplateProcessing {
+            single: vec![special(cls), seq(SequenceId::A), special(sep)],
+            pair: vec![
+                special(cls),
+                seq(SequenceId::A),
+                special(sep),
+                special(sep),
+                seq(SequenceId::B),
+                special(sep),
+            ],
+            kind: TemplateKind::Roberta { cls, sep },
+        }
+    }
+
+    /// Return a post-processor which applies this post-processor followed by
+    /// `next`.
+    ///
+    /// After the first post-processor has combined the inputs into one
+    /// sequence, `next` processes the result as a single sequence. As in
+    /// Hugging Face Tokenizers, the type ID of `next`'s `$A` placeholder
+    /// replaces the type IDs assigned by the first post-processor.
+    pub fn then(&self, next: &TemplateProcessing) -> Self {
+        let compose = |inner: &[TemplatePiece]| {
+            next.single
+                .iter()
+                .flat_map(|piece| match piece {
+                    TemplatePiece::Sequence { type_id, .. } => inner
+                        .iter()
+                        .map(|inner_piece| match inner_piece {
+                            TemplatePiece::Sequence { id, .. } => TemplatePiece::Sequence {
+                                id: *id,
+                                type_id: *type_id,
+                            },
+                            TemplatePiece::SpecialToken { ids, .. } => {
+                                TemplatePiece::SpecialToken {
+                                    ids: ids.clone(),
+                                    type_id: *type_id,
+                                }
+                            }
+                        })
+                        .collect(),
+                    TemplatePiece::SpecialToken { .. } => vec![piece.clone()],
+                })
+                .collect()
+        };
+
+        TemplateProcessing {
+            single: compose(&self.single),
+            pair: compose(&self.pair),
+            kind: TemplateKind::Template,
+        }
+    }
+
+    /// Return the type of post-processor this was created from.
+    pub(crate) fn kind(&self) -> TemplateKind {
+        self.kind
+    }
+
+    /// Return the template for single sequence or sequence pair inputs.
+    pub fn template(&self, pair: bool) -> &[TemplatePiece] {
+        if pair { &self.pair } else { &self.single }
+    }
+
+    /// Return the number of special tokens added to single sequence or
+    /// sequence pair inputs.
+    pub fn added_tokens(&self, pair: bool) -> usize {
+        self.template(pair)
+            .iter()
+            .map(|piece| match piece {
+                TemplatePiece::SpecialToken { ids, .. } => ids.len(),
+                TemplatePiece::Sequence { .. } => 0,
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PostProcessorError, SequenceId, TemplatePiece, TemplateProcessing};
+
+    #[test]
+    fn test_template_processing_new() {
+        let seq = |id| TemplatePiece::Sequence { id, type_id: 0 };
+        let bos = TemplatePiece::SpecialToken {
+            ids: vec![1],
+            type_id: 0,
+        };
+
+        let processor = TemplateProcessing::new(
+            vec![bos.clone(), seq(SequenceId::A)],
+            vec![
+                bos.clone(),
+                seq(SequenceId::A),
+                bos.clone(),
+                seq(SequenceId::B),
+            ],
+        )
+        .unwrap();
+        assert_eq!(processor.added_tokens(false), 1);
+        assert_eq!(processor.added_tokens(true), 2);
+
+        let result = TemplateProcessing::new(
+            vec![bos.clone()],
+            vec![seq(SequenceId::A), seq(SequenceId::B)],
+        );
+        assert!(matches!(
+            result,
+            Err(PostProcessorError::InvalidTemplate(_))
+        ));
+
+        let result = TemplateProcessing::new(
+            vec![seq(SequenceId::A)],
+            vec![seq(SequenceId::B), seq(SequenceId::A)],
+        );
+        assert!(matches!(
+            result,
+            Err(PostProcessorError::InvalidTemplate(_))
+        ));
+    }
+
+    #[test]
+    fn test_bert_and_roberta() {
+        let bert = TemplateProcessing::bert(101, 102);
+        assert_eq!(bert.added_tokens(false), 2);
+        assert_eq!(bert.added_tokens(true), 3);
+        let type_ids: Vec<_> = bert.template(true).iter().map(|p| p.type_id()).collect();
+        assert_eq!(type_ids, [0, 0, 0, 1, 1]);
+
+        let roberta = TemplateProcessing::roberta(0, 2);
+        assert_eq!(roberta.added_tokens(false), 2);
+        assert_eq!(roberta.added_tokens(true), 4);
+        assert!(roberta.template(true).iter().all(|p| p.type_id() == 0));
+    }
+
+    #[test]
+    fn test_then() {
+        let seq = |id, type_id| TemplatePiece::Sequence { id, type_id };
+        let special = |id, type_id| TemplatePiece::SpecialToken {
+            ids: vec![id],
+            type_id,
+        };
+
+        let bert = TemplateProcessing::bert(101, 102);
+        let bos = TemplateProcessing::new(
+            vec![special(1, 0), seq(SequenceId::A, 0)],
+            vec![
+                special(1, 0),
+                seq(SequenceId::A, 0),
+                special(1, 1),
+                seq(SequenceId::B, 1),
+            ],
+        )
+        .unwrap();
+
+        let combined = bert.then(&bos);
+        assert_eq!(
+            combined.template(false),
+            [
+                special(1, 0),
+                special(101, 0),
+                seq(SequenceId::A, 0),
+                special(102, 0),
+            ]
+        );
+
+        // The second post-processor receives the combined pair as a single
+        // sequence, so all tokens get the type ID of its `$A` placeholder.
+        assert_eq!(
+            combined.template(true),
+            [
+                special(1, 0),
+                special(101, 0),
+                seq(SequenceId::A, 0),
+                special(102, 0),
+                seq(SequenceId::B, 0),
+                special(102, 0),
+            ]
+        );
+        assert_eq!(combined.added_tokens(true), 4);
+    }
+}
@@ -3,11 +3,14 @@
 
 use std::error::Error;
 use std::fmt;
+use std::ops::Range;
 
 use fancy_regex::Regex;
+use serde_json::json;
 use unicode_categories::UnicodeCategories;
+use unicode_script::{Script, UnicodeScript};
 
-use crate::split::{SliceExt, SplitExt};
+use crate::split::SplitExt;
 
 /// Errors occuring while constructing a [`PreTokenizer`] or splitting input
 /// using one.
@@ -42,14 +45,38 @@ impl From<fancy_regex::Error> for PreTokenizeError {
 
 /// A pre-tokenizer splits input text into chunks ("words") which are then
 /// tokenized by a [`Model`](crate::models::Model) individually.
-pub trait PreTokenizer {
+pub trait PreTokenizer: Send + Sync {
     /// Split `text` into chunks and return a vector of sub-slices.
     fn pre_tokenize<'a>(&self, text: &'a str) -> Result<Vec<&'a str>, PreTokenizeError>;
+
+    /// Modify the text of a chunk returned by
+    /// [`pre_tokenize`](PreTokenizer::pre_tokenize) before it is passed to the
+    /// model.
+    ///
+    /// `is_first` indicates whether the chunk is at the start of the input
+    /// sequence. Returns `None` if the chunk is unchanged, or a tuple of
+    /// `(text, offsets)` where `offsets` maps byte offsets in `text` to
+    /// byte offsets in `chunk`.
+    ///
+    /// Most pre-tokenizers only split text and don't need to implement this.
+    fn transform_chunk(&self, chunk: &str, is_first: bool) -> Option<(String, Vec<usize>)> {
+        let _ = (chunk, is_first);
+        None
+    }
+
+    /// Return the configuration of this pre-tokenizer in the format used by
+    /// `tokenizer.json` files, or `None` if it cannot be serialized.
+    ///
+    /// This is used by [`Tokenizer::to_json`](crate::Tokenizer::to_json).
+    fn to_json(&self) -> Option<serde_json::Value> {
+        None
+    }
 }
 
 /// Split into tokens containing either digits or non-digits.
 pub struct Digits {
     split: Split,
+    individual_digits: bool,
 }
 
 impl Digits {
@@ -71,6 +98,7 @@ impl Digits {
                 delimiter: SplitDelimiterBehavior::Remove,
             })
             .expect("pattern should be valid"),
+            individual_digits,
         }
     }
 }
@@ -79,6 +107,13 @@ impl PreTokenizer for Digits {
     fn pre_tokenize<'a>(&self, text: &'a str) -> Result<Vec<&'a str>, PreTokenizeError> {
         self.split.pre_tokenize(text)
     }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(json!({
+            "type": "Digits",
+            "individual_digits": self.individual_digits,
+        }))
+    }
 }
 
 /// Tokenization regex used by GPT-2.
@@ -87,7 +122,22 @@ impl PreTokenizer for Digits {
 pub const GPT2_REGEX: &str =
     r"'s|'t|'re|'ve|'m|'ll|'d| ?\p{L}+| ?\p{N}+| ?[^\s\p{L}\p{N}]+|\s+(?!\S)|\s+";
 
-/// Specifies how [`Split`] should handle delimiters between chunks.
+/// Tokenization regex used by the `cl100k_base` encoding (GPT-3.5, GPT-4).
+///
+/// See <https://github.com/openai/tiktoken/blob/main/tiktoken_ext/openai_public.py>.
+pub const CL100K_REGEX: &str = r"(?i:'s|'t|'re|'ve|'m|'ll|'d)|[^\r\n\p{L}\p{N}]?\p{L}+|\p{N}{1,3}| ?[^\s\p{L}\p{N}]+[\r\n]*|\s*[\r\n]+|\s+(?!\S)|\s+";
+
+/// Tokenization regex used by the `o200k_base` encoding (GPT-4o).
+///
+/// See <https://github.com/openai/tiktoken/blob/main/tiktoken_ext/openai_public.py>.
+pub const O200K_REGEX: &str = concat!(
+    r"[^\r\n\p{L}\p{N}]?[\p{Lu}\p{Lt}\p{Lm}\p{Lo}\p{M}]*[\p{Ll}\p{Lm}\p{Lo}\p{M}]+(?i:'s|'t|'re|'ve|'m|'ll|'d)?",
+    r"|[^\r\n\p{L}\p{N}]?[\p{Lu}\p{Lt}\p{Lm}\p{Lo}\p{M}]+[\p{Ll}\p{Lm}\p{Lo}\p{M}]*(?i:'s|'t|'re|'ve|'m|'ll|'d)?",
+    r"|\p{N}{1,3}| ?[^\s\p{L}\p{N}]+[\r\n/]*|\s*[\r\n]+|\s+(?!\S)|\s+",
+);
+
+/// Specifies how [`Split`] and other pre-tokenizers should handle delimiters
+/// between chunks.
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
 pub enum SplitDelimiterBehavior {
     /// Exclude the delimiter from the output.
@@ -96,6 +146,83 @@ pub enum SplitDelimiterBehavior {
 
     /// Add the delimiter to the output as its own chunk.
     Isolate,
+
+    /// Add the delimiter to the end of the preceding chunk.
+    MergeWithPrevious,
+
+    /// Add the delimiter to the start of the following chunk.
+    MergeWithNext,
+
+    /// Merge adjacent delimiters and add them to the output as one chunk.
+    Contiguous,
+}
+
+impl SplitDelimiterBehavior {
+    /// Return the name of this behavior in `tokenizer.json` files.
+    fn json_name(self) -> &'static str {
+        match self {
+            Self::Remove => "Removed",
+            Self::Isolate => "Isolated",
+            Self::MergeWithPrevious => "MergedWithPrevious",
+            Self::MergeWithNext => "MergedWithNext",
+            Self::Contiguous => "Contiguous",
+        }
+    }
+}
+
+/// Split `text` into chunks according to a list of `(range, is_delimiter)`
+/// segments, which must cover the text in order. Empty chunks are omitted.
+fn split_segments(
+    text: &str,
+    segments: impl IntoIterator<Item = (Range<usize>, bool)>,
+    behavior: SplitDelimiterBehavior,
+) -> Vec<&str> {
+    let mut chunks: Vec<Range<usize>> = Vec::new();
+    let mut prev_is_delim = None;
+
+    for (range, is_delim) in segments {
+        if range.is_empty() {
+            continue;
+        }
+
+        let merge = match behavior {
+            SplitDelimiterBehavior::Remove if is_delim => continue,
+            SplitDelimiterBehavior::Remove | SplitDelimiterBehavior::Isolate => false,
+            SplitDelimiterBehavior::MergeWithPrevious => is_delim && prev_is_delim == Some(false),
+            SplitDelimiterBehavior::MergeWithNext => !is_delim && prev_is_delim == Some(true),
+            SplitDelimiterBehavior::Contiguous => is_delim && prev_is_delim == Some(true),
+        };
+        prev_is_delim = Some(is_delim);
+
+        match chunks.last_mut() {
+            Some(last) if merge => last.end = range.end,
+            _ => chunks.push(range),
+        }
+    }
+
+    chunks.into_iter().map(|range| &text[range]).collect()
+}
+
+/// Convert a list of non-overlapping byte ranges of pattern matches in a text
+/// of length `len` into a list of `(range, is_delimiter)` segments for use
+/// with [`split_segments`].
+///
+/// If `invert` is false, the matches are delimiters. Otherwise the text
+/// between matches is.
+fn match_segments(
+    len: usize,
+    matches: impl IntoIterator<Item = Range<usize>>,
+    invert: bool,
+) -> Vec<(Range<usize>, bool)> {
+    let mut segments = Vec::new();
+    let mut last_match_end = 0;
+    for range in matches {
+        segments.push((last_match_end..range.start, invert));
+        last_match_end = range.end;
+        segments.push((range, !invert));
+    }
+    segments.push((last_match_end..len, invert));
+    segments
 }
 
 #[derive(Clone, Debug, Default)]
@@ -146,66 +273,21 @@ impl Split {
 
 impl PreTokenizer for Split {
     fn pre_tokenize<'a>(&self, text: &'a str) -> Result<Vec<&'a str>, PreTokenizeError> {
-        let mut chunks = Vec::new();
-        let mut last_match_end = 0;
-
-        if self.invert {
-            for match_ in self.regex.find_iter(text) {
-                let match_ = match_?;
-
-                match self.delimiter {
-                    SplitDelimiterBehavior::Isolate => {
-                        let delim_text = &text[last_match_end..match_.range().start];
-                        if !delim_text.is_empty() {
-                            chunks.push(delim_text);
-                        }
-                    }
-                    SplitDelimiterBehavior::Remove => {}
-                }
-
-                if !match_.range().is_empty() {
-                    chunks.push(match_.as_str());
-                }
-
-                last_match_end = match_.range().end;
-            }
-        } else {
-            for match_ in self.regex.split(text) {
-                let match_ = match_?;
-                let match_range = text
-                    .as_bytes()
-                    .subslice_offsets(match_.as_bytes())
-                    .expect("should be sub-slice");
-
-                match self.delimiter {
-                    SplitDelimiterBehavior::Isolate => {
-                        let delim_text = &text[last_match_end..match_range.start];
-                        if !delim_text.is_empty() {
-                            chunks.push(delim_text);
-                        }
-                    }
-                    SplitDelimiterBehavior::Remove => {}
-                }
-
-                if !match_.is_empty() {
-                    chunks.push(match_);
-                }
-
-                last_match_end = match_range.end;
-            }
-        }
-
-        match self.delimiter {
-            SplitDelimiterBehavior::Isolate => {
-                let delim_text = &text[last_match_end..];
-                if !delim_text.is_empty() {
-                    chunks.push(delim_text);
-                }
-            }
-            SplitDelimiterBehavior::Remove => {}
+        let mut matches = Vec::new();
+        for match_ in self.regex.find_iter(text) {
+            matches.push(match_?.range());
         }
+        let segments = match_segments(text.len(), matches, self.invert);
+        Ok(split_segments(text, segments, self.delimiter))
+    }
 
-        Ok(chunks)
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(json!({
+            "type": "Split",
+            "pattern": { "Regex": self.regex.as_str() },
+            "behavior": self.delimiter.json_name(),
+            "invert": self.invert,
+        }))
     }
 }
 
@@ -234,6 +316,334 @@ impl PreTokenizer for Bert {
         let words = text.split_keep_delimeters(is_punc_or_space).collect();
         Ok(words)
     }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(json!({ "type": "BertPreTokenizer" }))
+    }
+}
+
+/// Split text into chunks of word characters and chunks of punctuation,
+/// removing whitespace.
+///
+/// This is equivalent to splitting with the pattern `\w+|[^\w\s]+`.
+pub struct Whitespace {
+    split: Split,
+}
+
+impl Whitespace {
+    pub fn new() -> Self {
+        Whitespace {
+            split: Split::new(SplitOptions {
+                pattern: r"\w+|[^\w\s]+",
+                invert: true,
+                delimiter: SplitDelimiterBehavior::Remove,
+            })
+            .expect("pattern should be valid"),
+        }
+    }
+}
+
+impl Default for Whitespace {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PreTokenizer for Whitespace {
+    fn pre_tokenize<'a>(&self, text: &'a str) -> Result<Vec<&'a str>, PreTokenizeError> {
+        self.split.pre_tokenize(text)
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(json!({ "type": "Whitespace" }))
+    }
+}
+
+/// Split text on whitespace, removing the whitespace.
+#[derive(Clone, Debug, Default)]
+pub struct WhitespaceSplit {}
+
+impl WhitespaceSplit {
+    pub fn new() -> Self {
+        WhitespaceSplit {}
+    }
+}
+
+impl PreTokenizer for WhitespaceSplit {
+    fn pre_tokenize<'a>(&self, text: &'a str) -> Result<Vec<&'a str>, PreTokenizeError> {
+        Ok(text.split_whitespace().collect())
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(json!({ "type": "WhitespaceSplit" }))
+    }
+}
+
+/// Return `(range, is_delimiter)` segments for use with [`split_segments`],
+/// where each delimiter is a single character matching `is_delimiter`.
+fn char_segments(text: &str, is_delimiter: impl Fn(char) -> bool) -> Vec<(Range<usize>, bool)> {
+    let mut segments = Vec::new();
+    let mut start = 0;
+    for (pos, ch) in text.char_indices() {
+        if is_delimiter(ch) {
+            let end = pos + ch.len_utf8();
+            segments.push((start..pos, false));
+            segments.push((pos..end, true));
+            start = end;
+        }
+    }
+    segments.push((start..text.len(), false));
+    segments
+}
+
+/// Split text on punctuation characters.
+#[derive(Clone, Debug, Default)]
+pub struct Punctuation {
+    behavior: SplitDelimiterBehavior,
+}
+
+impl Punctuation {
+    /// Create a pre-tokenizer which splits on punctuation characters and
+    /// handles them according to `behavior`.
+    ///
+    /// Hugging Face Tokenizers uses [`SplitDelimiterBehavior::Isolate`] by
+    /// default.
+    pub fn new(behavior: SplitDelimiterBehavior) -> Self {
+        Punctuation { behavior }
+    }
+}
+
+impl PreTokenizer for Punctuation {
+    fn pre_tokenize<'a>(&self, text: &'a str) -> Result<Vec<&'a str>, PreTokenizeError> {
+        let is_punc = |ch: char| ch.is_ascii_punctuation() || ch.is_punctuation();
+        Ok(split_segments(
+            text,
+            char_segments(text, is_punc),
+            self.behavior,
+        ))
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(json!({
+            "type": "Punctuation",
+            "behavior": self.behavior.json_name(),
+        }))
+    }
+}
+
+/// Split text on a delimiter character, removing the delimiter.
+#[derive(Clone, Debug)]
+pub struct CharDelimiterSplit {
+    delimiter: char,
+}
+
+impl CharDelimiterSplit {
+    pub fn new(delimiter: char) -> Self {
+        CharDelimiterSplit { delimiter }
+    }
+}
+
+impl PreTokenizer for CharDelimiterSplit {
+    fn pre_tokenize<'a>(&self, text: &'a str) -> Result<Vec<&'a str>, PreTokenizeError> {
+        Ok(text
+            .split(self.delimiter)
+            .filter(|chunk| !chunk.is_empty())
+            .collect())
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(json!({
+            "type": "CharDelimiterSplit",
+            "delimiter": self.delimiter,
+        }))
+    }
+}
+
+/// Split text into chunks where the characters in each chunk belong to the
+/// same Unicode script.
+///
+/// As in Hugging Face Tokenizers, spaces are treated as belonging to any
+/// script, and Hiragana and Katakana are treated as part of the Han script so
+/// that Japanese text is not split.
+#[derive(Clone, Debug, Default)]
+pub struct UnicodeScripts {}
+
+impl UnicodeScripts {
+    pub fn new() -> Self {
+        UnicodeScripts {}
+    }
+}
+
+impl PreTokenizer for UnicodeScripts {
+    fn pre_tokenize<'a>(&self, text: &'a str) -> Result<Vec<&'a str>, PreTokenizeError> {
+        // Return the script of a character, or `None` if it belongs to any
+        // script.
+        let char_script = |ch: char| match ch {
+            ' ' => None,
+            // Katakana-Hiragana prolonged sound mark, which is in the Common
+            // script.
+            '\u{30FC}' => Some(Script::Han),
+            ch => match ch.script() {
+                Script::Hiragana | Script::Katakana => Some(Script::Han),
+                script => Some(script),
+            },
+        };
+
+        let mut chunks = Vec::new();
+        let mut chunk_start = 0;
+        let mut prev_script = None;
+
+        for (pos, ch) in text.char_indices() {
+            let Some(script) = char_script(ch) else {
+                continue;
+            };
+            if prev_script.is_some_and(|prev| prev != script) && pos > chunk_start {
+                chunks.push(&text[chunk_start..pos]);
+                chunk_start = pos;
+            }
+            prev_script = Some(script);
+        }
+        if chunk_start < text.len() {
+            chunks.push(&text[chunk_start..]);
+        }
+
+        Ok(chunks)
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(json!({ "type": "UnicodeScripts" }))
+    }
+}
+
+/// Specifies when a [`Metaspace`] pre-tokenizer adds a replacement character
+/// to the start of a chunk.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum PrependScheme {
+    /// Add a replacement character to every chunk which does not already
+    /// start with one.
+    #[default]
+    Always,
+
+    /// Add a replacement character only to the chunk at the start of the
+    /// input sequence.
+    First,
+
+    /// Never add a replacement character.
+    Never,
+}
+
+/// Configuration for a [`Metaspace`] pre-tokenizer.
+#[derive(Clone, Debug)]
+pub struct MetaspaceOptions {
+    /// Character which replaces spaces.
+    pub replacement: char,
+
+    /// Specifies when to add a replacement character at the start of a chunk.
+    pub prepend_scheme: PrependScheme,
+
+    /// Whether to split the input into words. If true, each word starts with
+    /// the space or replacement character preceding it.
+    pub split: bool,
+}
+
+impl Default for MetaspaceOptions {
+    fn default() -> Self {
+        MetaspaceOptions {
+            replacement: '▁',
+            prepend_scheme: PrependScheme::Always,
+            split: true,
+        }
+    }
+}
+
+/// Pre-tokenizer used by SentencePiece models which replaces spaces with a
+/// visible character (by default "▁", U+2581) and optionally splits the input
+/// into words.
+///
+/// When used in a [`Sequence`], the replacement happens after all
+/// pre-tokenizers in the sequence have split the input.
+#[derive(Clone, Debug)]
+pub struct Metaspace {
+    replacement: char,
+    prepend_scheme: PrependScheme,
+    split: bool,
+}
+
+impl Metaspace {
+    pub fn new(opts: MetaspaceOptions) -> Self {
+        Metaspace {
+            replacement: opts.replacement,
+            prepend_scheme: opts.prepend_scheme,
+            split: opts.split,
+        }
+    }
+}
+
+impl Default for Metaspace {
+    fn default() -> Self {
+        Self::new(MetaspaceOptions::default())
+    }
+}
+
+impl PreTokenizer for Metaspace {
+    fn pre_tokenize<'a>(&self, text: &'a str) -> Result<Vec<&'a str>, PreTokenizeError> {
+        if !self.split {
+            return Ok(if text.is_empty() {
+                Vec::new()
+            } else {
+                vec![text]
+            });
+        }
+        let is_space = |ch: char| ch == ' ' || ch == self.replacement;
+        Ok(split_segments(
+            text,
+            char_segments(text, is_space),
+            SplitDelimiterBehavior::MergeWithNext,
+        ))
+    }
+
+    fn transform_chunk(&self, chunk: &str, is_first: bool) -> Option<(String, Vec<usize>)> {
+        let prepend = match self.prepend_scheme {
+            PrependScheme::Always => true,
+            PrependScheme::First => is_first,
+            PrependScheme::Never => false,
+        } && !chunk.starts_with([' ', self.replacement]);
+
+        let replacement_len = self.replacement.len_utf8();
+        let mut text = String::with_capacity(chunk.len() + replacement_len);
+        let mut offsets = Vec::with_capacity(chunk.len() + replacement_len);
+
+        if prepend {
+            text.push(self.replacement);
+            offsets.extend(std::iter::repeat_n(0, replacement_len));
+        }
+
+        for (pos, ch) in chunk.char_indices() {
+            if ch == ' ' {
+                text.push(self.replacement);
+                offsets.extend(std::iter::repeat_n(pos, replacement_len));
+            } else {
+                text.push(ch);
+                offsets.extend(pos..pos + ch.len_utf8());
+            }
+        }
+
+        Some((text, offsets))
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        let prepend_scheme = match self.prepend_scheme {
+            PrependScheme::Always => "always",
+            PrependScheme::First => "first",
+            PrependScheme::Never => "never",
+        };
+        Some(json!({
+            "type": "Metaspace",
+            "replacement": self.replacement,
+            "prepend_scheme": prepend_scheme,
+            "split": self.split,
+        }))
+    }
 }
 
 /// Compose a sequence of pre-tokenizers.
@@ -260,6 +670,34 @@ impl PreTokenizer for Sequence {
         }
         Ok(chunks)
     }
+
+    fn transform_chunk(&self, chunk: &str, is_first: bool) -> Option<(String, Vec<usize>)> {
+        let mut transformed: Option<(String, Vec<usize>)> = None;
+        for pre_tokenizer in &self.pre_tokenizers {
+            let text = transformed.as_ref().map(|(text, _)| text.as_str());
+            let Some((next_text, mut next_offsets)) =
+                pre_tokenizer.transform_chunk(text.unwrap_or(chunk), is_first)
+            else {
+                continue;
+            };
+            if let Some((_, offsets)) = &transformed {
+                for offset in next_offsets.iter_mut() {
+                    *offset = offsets[*offset];
+                }
+            }
+            transformed = Some((next_text, next_offsets));
+        }
+        transformed
+    }
+
+    fn to_json(&self) -> Option<serde_json::Value> {
+        let pre_tokenizers = self
+            .pre_tokenizers
+            .iter()
+            .map(|pre_tokenizer| pre_tokenizer.to_json())
+            .collect::<Option<Vec<_>>>()?;
+        Some(json!({ "type": "Sequence", "pretokenizers": pre_tokenizers }))
+    }
 }
 
 #[cfg(test)]
@@ -267,7 +705,9 @@ mod tests {
     use rten_testing::TestCases;
 
     use super::{
-        Bert, Digits, PreTokenizer, Sequence, Split, SplitDelimiterBehavior, SplitOptions,
+        Bert, CharDelimiterSplit, Digits, Metaspace, MetaspaceOptions, PreTokenizer, PrependScheme,
+        Punctuation, Sequence, Split, SplitDelimiterBehavior, SplitOptions, UnicodeScripts,
+        Whitespace, WhitespaceSplit,
     };
 
     #[test]
@@ -369,6 +809,34 @@ mod tests {
                 input: "foo bar   baz meep",
                 expected: ["foo", " ", "bar", "   ", "baz", " ", "meep"].into(),
             },
+            // Merged delimiters
+            Case {
+                opts: SplitOptions {
+                    pattern: r"-",
+                    delimiter: SplitDelimiterBehavior::MergeWithPrevious,
+                    ..Default::default()
+                },
+                input: "foo-bar--baz",
+                expected: ["foo-", "bar-", "-", "baz"].into(),
+            },
+            Case {
+                opts: SplitOptions {
+                    pattern: r"-",
+                    delimiter: SplitDelimiterBehavior::MergeWithNext,
+                    ..Default::default()
+                },
+                input: "foo-bar--baz",
+                expected: ["foo", "-bar", "-", "-baz"].into(),
+            },
+            Case {
+                opts: SplitOptions {
+                    pattern: r"-",
+                    delimiter: SplitDelimiterBehavior::Contiguous,
+                    ..Default::default()
+                },
+                input: "foo-bar--baz",
+                expected: ["foo", "-", "bar", "--", "baz"].into(),
+            },
         ];
 
         cases.test_each(|case| {
@@ -378,6 +846,186 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_whitespace() {
+        let whitespace = Whitespace::new();
+        let chunks = whitespace
+            .pre_tokenize("Hey friend!  How are you?!?")
+            .unwrap();
+        assert_eq!(chunks, ["Hey", "friend", "!", "How", "are", "you", "?!?"]);
+
+        let whitespace_split = WhitespaceSplit::new();
+        let chunks = whitespace_split
+            .pre_tokenize("Hey friend!  How are you?!?")
+            .unwrap();
+        assert_eq!(chunks, ["Hey", "friend!", "How", "are", "you?!?"]);
+    }
+
+    #[test]
+    fn test_punctuation() {
+        #[derive(Debug)]
+        struct Case<'a> {
+            behavior: SplitDelimiterBehavior,
+            expected: Vec<&'a str>,
+        }
+
+        let cases = [
+            Case {
+                behavior: SplitDelimiterBehavior::Isolate,
+                expected: ["Hey", ",", " friend", "!", "!", " ", "“", "ok", "”"].into(),
+            },
+            Case {
+                behavior: SplitDelimiterBehavior::Remove,
+                expected: ["Hey", " friend", " ", "ok"].into(),
+            },
+            Case {
+                behavior: SplitDelimiterBehavior::Contiguous,
+                expected: ["Hey", ",", " friend", "!!", " ", "“", "ok", "”"].into(),
+            },
+        ];
+
+        cases.test_each(|case| {
+            let punctuation = Punctuation::new(case.behavior);
+            let chunks = punctuation.pre_tokenize("Hey, friend!! “ok”").unwrap();
+            assert_eq!(chunks, case.expected);
+        })
+    }
+
+    #[test]
+    fn test_char_delimiter_split() {
+        let split = CharDelimiterSplit::new('|');
+        let chunks = split.pre_tokenize("|foo|bar||baz").unwrap();
+        assert_eq!(chunks, ["foo", "bar", "baz"]);
+    }
+
+    #[test]
+    fn test_unicode_scripts() {
+        #[derive(Debug)]
+        struct Case<'a> {
+            input: &'a str,
+            expected: Vec<&'a str>,
+        }
+
+        let cases = [
+            // Spaces belong to any script.
+            Case {
+                input: "Hello world",
+                expected: ["Hello world"].into(),
+            },
+            Case {
+                input: "Hello 世界 world",
+                expected: ["Hello ", "世界 ", "world"].into(),
+            },
+            // Hiragana and Katakana are treated as Han.
+            Case {
+                input: "どこで生れ。Yes",
+                expected: ["どこで生れ", "。", "Yes"].into(),
+            },
+            Case {
+                input: "ネーム",
+                expected: ["ネーム"].into(),
+            },
+        ];
+
+        cases.test_each(|case| {
+            let chunks = UnicodeScripts::new().pre_tokenize(case.input).unwrap();
+            assert_eq!(chunks, case.expected);
+        })
+    }
+
+    #[test]
+    fn test_metaspace() {
+        #[derive(Debug)]
+        struct Case<'a> {
+            input: &'a str,
+            is_first: bool,
+            prepend_scheme: PrependScheme,
+            split: bool,
+            expected: Vec<&'a str>,
+        }
+
+        let cases = [
+            Case {
+                input: "Hey  friend",
+                is_first: true,
+                prepend_scheme: PrependScheme::Always,
+                split: true,
+                expected: ["▁Hey", "▁", "▁friend"].into(),
+            },
+            Case {
+                input: "Hey friend",
+                is_first: true,
+                prepend_scheme: PrependScheme::Always,
+                split: false,
+                expected: ["▁Hey▁friend"].into(),
+            },
+            // Replacement is not added to chunks which are not at the start
+            // of the input.
+            Case {
+                input: "Hey friend",
+                is_first: false,
+                prepend_scheme: PrependScheme::First,
+                split: true,
+                expected: ["Hey", "▁friend"].into(),
+            },
+            Case {
+                input: "Hey friend",
+                is_first: true,
+                prepend_scheme: PrependScheme::First,
+                split: true,
+                expected: ["▁Hey", "▁friend"].into(),
+            },
+            Case {
+                input: "Hey friend",
+                is_first: true,
+                prepend_scheme: PrependScheme::Never,
+                split: true,
+                expected: ["Hey", "▁friend"].into(),
+            },
+            // Input which already contains replacement characters.
+            Case {
+                input: "▁Hey▁friend",
+                is_first: true,
+                prepend_scheme: PrependScheme::Always,
+                split: true,
+                expected: ["▁Hey", "▁friend"].into(),
+            },
+        ];
+
+        cases.test_each(|case| {
+            let metaspace = Metaspace::new(MetaspaceOptions {
+                prepend_scheme: case.prepend_scheme,
+                split: case.split,
+                ..Default::default()
+            });
+            let chunks: Vec<String> = metaspace
+                .pre_tokenize(case.input)
+                .unwrap()
+                .into_iter()
+                .enumerate()
+                .map(|(i, chunk)| {
+                    let (text, offsets) = metaspace
+                        .transform_chunk(chunk, case.is_first && i == 0)
+                        .unwrap();
+                    assert_eq!(offsets.len(), text.len());
+                    text
+                })
+                .collect();
+            assert_eq!(chunks, case.expected);
+        })
+    }
+
+    #[test]
+    fn test_metaspace_offsets() {
+        let metaspace = Metaspace::new(MetaspaceOptions {
+            split: false,
+            ..Default::default()
+        });
+        let (text, offsets) = metaspace.transform_chunk("a b", true).unwrap();
+        assert_eq!(text, "▁a▁b");
+        assert_eq!(offsets, [0, 0, 0, 0, 1, 1, 1, 2]);
+    }
+
     #[test]
     fn test_sequence() {
         let split_space: Box<dyn PreTokenizer> = Box::new(
@@ -400,4 +1048,26 @@ mod tests {
 
         assert_eq!(chunks, ["foo", "bar", "baz", "meep"]);
     }
+
+    #[test]
+    fn test_sequence_transform_chunk() {
+        // Sequence used by T5 models.
+        let seq = Sequence::from_vec(
+            [
+                Box::new(WhitespaceSplit::new()) as Box<dyn PreTokenizer>,
+                Box::new(Metaspace::default()),
+            ]
+            .into(),
+        );
+
+        let chunks = seq.pre_tokenize("foo  bar").unwrap();
+        assert_eq!(chunks, ["foo", "bar"]);
+
+        let (text, offsets) = seq.transform_chunk("bar", false).unwrap();
+        assert_eq!(text, "▁bar");
+        assert_eq!(offsets, [0, 0, 0, 0, 1, 2]);
+
+        let no_transform = Sequence::from_vec([Box::new(WhitespaceSplit::new()) as _].into());
+        assert!(no_transform.transform_chunk("bar", false).is_none());
+    }
 }
@@ -1,8 +1,13 @@
-//! Internal utilities related to serde deserialization.
+//! Internal utilities related to serde serialization and deserialization.
 
 use std::borrow::Cow;
+use std::collections::HashMap;
+use std::hash::BuildHasher;
 
-use serde::Deserializer;
+use serde::ser::SerializeMap;
+use serde::{Deserializer, Serializer};
+
+use crate::TokenId;
 
 /// A wrapper around [`Cow<str>`] which implements [`serde::Deserialize`] as
 /// expected.
@@ -63,3 +68,21 @@ impl<'de> serde::de::Visitor<'de> for CowStrVisitor {
         Ok(CowStr(Cow::Owned(val)))
     }
 }
+
+/// Serialize a map of token string to ID, ordered by ID.
+///
+/// This makes the output deterministic and easier to read than the arbitrary
+/// order of the hash map.
+pub fn serialize_vocab<S: Serializer, H: BuildHasher>(
+    vocab: &HashMap<String, TokenId, H>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut entries: Vec<_> = vocab.iter().collect();
+    entries.sort_by_key(|(_token, id)| **id);
+
+    let mut map = serializer.serialize_map(Some(entries.len()))?;
+    for (token, id) in entries {
+        map.serialize_entry(token, id)?;
+    }
+    map.end()
+}
@@ -11,23 +11,52 @@
 //!    [`Tokenizer::new`].
 
 use std::borrow::Cow;
+use std::collections::HashMap;
 use std::error::Error;
 use std::fmt;
-use std::iter::repeat;
 use std::ops::Range;
 use std::path::Path;
 
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use rayon::prelude::*;
 use rustc_hash::FxHashMap;
 
+use crate::decoders::{Decoder, DecoderError};
 use crate::models::{
-    Bpe, BpeError, BpeOptions, DecodeError, EncodeError, Model, WordPiece, merge_pairs_from_lines,
+    Bpe, BpeError, BpeOptions, DecodeError, EncodeError, Model, Unigram, UnigramError,
+    UnigramOptions, WordPiece, WordPieceOptions, char_to_byte, merge_pairs_from_lines,
 };
 use crate::normalizers::{NormalizeError, Normalizer};
+use crate::post_processors::{
+    PostProcessorError, SequenceId, TemplateKind, TemplatePiece, TemplateProcessing,
+};
 use crate::pre_tokenizers::{PreTokenizeError, PreTokenizer};
 use crate::split::SliceExt;
-use crate::{normalizers, pre_tokenizers};
-
-mod json;
+use crate::{decoders, normalizers, pre_tokenizers};
+
+mod added_tokens;
+mod batch;
+#[cfg(feature = "gguf")]
+mod gguf;
+pub(crate) mod json;
+#[cfg(feature = "sentencepiece")]
+mod sentencepiece;
+mod stream;
+mod tiktoken;
+
+pub use added_tokens::AddedToken;
+use added_tokens::{AddedTokenMatcher, Segment};
+pub use batch::{
+    BatchEncoding, Padding, PaddingDirection, PaddingStrategy, Truncation, TruncationDirection,
+    TruncationStrategy,
+};
+#[cfg(feature = "gguf")]
+pub use gguf::FromGgufError;
+#[cfg(feature = "sentencepiece")]
+pub use sentencepiece::FromSentencePieceError;
+pub use stream::StreamingDecoder;
+pub use tiktoken::{FromTiktokenError, TiktokenOptions};
 
 /// Input sequences for [`Tokenizer::encode`].
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -73,10 +102,10 @@ pub struct Encoded<'a> {
     input: EncoderInput<'a>,
     token_ids: Vec<TokenId>,
 
-    /// Number of tokens in `token_ids` that were generated from the first
-    /// sequence in the input. This includes the `[CLS]` and `[SEP]` tokens
-    /// which come before and after the sequence respectively.
-    first_seq_tokens: usize,
+    /// Type ID of each token, assigned by the post-processor. For BERT models
+    /// this is 0 for tokens from the first sequence and 1 for tokens from the
+    /// second sequence.
+    type_ids: Vec<u32>,
 
     /// Offsets of text corresponding to tokens in the input string. When the
     /// input contains two sentences, the offsets are relative to the string
@@ -89,13 +118,13 @@ impl<'a> Encoded<'a> {
         input: EncoderInput<'a>,
         ids: Vec<TokenId>,
         offsets: Vec<usize>,
-        first_seq_tokens: usize,
+        type_ids: Vec<u32>,
     ) -> Encoded<'a> {
         Encoded {
             input,
             token_ids: ids,
             token_offsets: offsets,
-            first_seq_tokens,
+            type_ids,
         }
     }
 
@@ -122,10 +151,7 @@ impl<'a> Encoded<'a> {
     /// Return an iterator of the inputs for the `token_type_ids` input field
     /// in the model, if it has one.
     pub fn token_type_ids(&self) -> impl Iterator<Item = usize> {
-        let second_seq_tokens = self.token_ids.len() - self.first_seq_tokens;
-        repeat(0)
-            .take(self.first_seq_tokens)
-            .chain(repeat(1).take(second_seq_tokens))
+        self.type_ids.iter().map(|&type_id| type_id as usize)
     }
 
     /// Return the text from the input sequence(s) that corresponds to a range
@@ -168,6 +194,14 @@ pub struct EncodeOptions {
 
     /// The number of tokens that a chunk will overlap with the previous chunk.
     pub overlap: usize,
+
+    /// Treat [special tokens](AddedToken::special) in the input as ordinary
+    /// text, rather than encoding them as the corresponding token IDs.
+    ///
+    /// This should be enabled when encoding untrusted input, to prevent it
+    /// from injecting special tokens (eg. `<|im_start|>`) which change how the
+    /// model interprets the input.
+    pub split_special_tokens: bool,
 }
 
 /// Errors returned by [`Tokenizer::from_json`].
@@ -181,8 +215,14 @@ pub enum FromJsonError {
     NormalizerError(NormalizeError),
     /// Could not instantiate a pre-tokenizer.
     PreTokenizerError(PreTokenizeError),
+    /// Could not instantiate a post-processor.
+    PostProcessorError(PostProcessorError),
+    /// Could not instantiate a decoder.
+    DecoderError(DecoderError),
     /// There was an error loading a BPE tokenizer.
     BpeError(BpeError),
+    /// There was an error loading a Unigram tokenizer.
+    UnigramError(UnigramError),
     /// The model type isn't supported by this crate.
     UnsupportedModel,
 }
@@ -194,7 +234,12 @@ impl fmt::Display for FromJsonError {
             Self::JsonError(err) => write!(f, "JSON error {}", err),
             Self::NormalizerError(err) => write!(f, "failed to construct normalizer: {}", err),
             Self::PreTokenizerError(err) => write!(f, "failed to construct pre-tokenizer: {}", err),
+            Self::PostProcessorError(err) => {
+                write!(f, "failed to construct post-processor: {}", err)
+            }
+            Self::DecoderError(err) => write!(f, "failed to construct decoder: {}", err),
             Self::BpeError(err) => write!(f, "BPE tokenizer error: {}", err),
+            Self::UnigramError(err) => write!(f, "Unigram tokenizer error: {}", err),
             Self::UnsupportedModel => write!(f, "unsupported model type"),
         }
     }
@@ -212,6 +257,18 @@ impl From<PreTokenizeError> for FromJsonError {
     }
 }
 
+impl From<PostProcessorError> for FromJsonError {
+    fn from(val: PostProcessorError) -> Self {
+        FromJsonError::PostProcessorError(val)
+    }
+}
+
+impl From<DecoderError> for FromJsonError {
+    fn from(val: DecoderError) -> Self {
+        FromJsonError::DecoderError(val)
+    }
+}
+
 impl Error for FromJsonError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
@@ -219,12 +276,47 @@ impl Error for FromJsonError {
             Self::JsonError(err) => Some(err),
             Self::NormalizerError(err) => Some(err),
             Self::PreTokenizerError(err) => Some(err),
+            Self::PostProcessorError(err) => Some(err),
+            Self::DecoderError(err) => Some(err),
             Self::BpeError(err) => Some(err),
+            Self::UnigramError(err) => Some(err),
             Self::UnsupportedModel => None,
         }
     }
 }
 
+/// Errors returned by [`Tokenizer::to_json`].
+#[derive(Debug)]
+pub enum ToJsonError {
+    /// There was an error writing the JSON data to a file.
+    IoError(std::io::Error),
+    /// A component of the tokenizer cannot be represented in `tokenizer.json`
+    /// format. The associated value names the component, eg. "normalizer".
+    Unsupported(&'static str),
+    /// A token ID used by the tokenizer configuration, such as the padding
+    /// token, is not in the vocabulary.
+    UnknownTokenId(TokenId),
+}
+
+impl fmt::Display for ToJsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IoError(err) => fmt::Display::fmt(err, f),
+            Self::Unsupported(component) => write!(f, "{} cannot be serialized", component),
+            Self::UnknownTokenId(id) => write!(f, "token ID {} is not in the vocabulary", id),
+        }
+    }
+}
+
+impl Error for ToJsonError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::IoError(err) => Some(err),
+            Self::Unsupported(_) | Self::UnknownTokenId(_) => None,
+        }
+    }
+}
+
 /// Configuration for a [`Tokenizer`].
 #[derive(Clone, Default)]
 pub struct TokenizerOptions<'a> {
@@ -248,12 +340,29 @@ pub struct Tokenizer {
     normalizer: Option<Box<dyn Normalizer>>,
     pre_tokenizer: Option<Box<dyn PreTokenizer>>,
     model: Box<dyn Model>,
+    decoder: Option<Box<dyn Decoder>>,
+
+    /// Added tokens which are matched against the input before normalization.
+    added_tokens: AddedTokenMatcher,
+
+    /// Added tokens which are matched against the input after normalization.
+    normalized_added_tokens: AddedTokenMatcher,
 
     /// Token added at start of output.
     cls_token: Option<String>,
 
     /// Token added after end of each sequence.
     sep_token: Option<String>,
+
+    /// Post-processor which adds special tokens to encoded sequences. If set,
+    /// this replaces `cls_token` and `sep_token`.
+    post_processor: Option<TemplateProcessing>,
+
+    /// Truncation settings for batch encoding.
+    truncation: Option<Truncation>,
+
+    /// Padding settings for batch encoding.
+    padding: Option<Padding>,
 }
 
 impl Tokenizer {
@@ -263,8 +372,14 @@ impl Tokenizer {
             model: Box::new(model),
             pre_tokenizer: None,
             normalizer: None,
+            decoder: None,
+            added_tokens: AddedTokenMatcher::default(),
+            normalized_added_tokens: AddedTokenMatcher::default(),
             cls_token: options.cls_token.map(|t| t.to_string()),
             sep_token: options.sep_token.map(|t| t.to_string()),
+            post_processor: None,
+            truncation: None,
+            padding: None,
         }
     }
 
@@ -280,6 +395,65 @@ impl Tokenizer {
         self
     }
 
+    /// Configure the decoder used by this tokenizer.
+    ///
+    /// If no decoder is set, [`decode`](Self::decode) uses the model's
+    /// [`Model::decode`] implementation.
+    pub fn with_decoder(mut self, decoder: Box<dyn Decoder>) -> Self {
+        self.decoder = Some(decoder);
+        self
+    }
+
+    /// Configure the post-processor which adds special tokens to encoded
+    /// sequences.
+    ///
+    /// This replaces the `[CLS]` and `[SEP]` tokens specified in
+    /// [`TokenizerOptions`].
+    pub fn with_post_processor(mut self, post_processor: TemplateProcessing) -> Self {
+        self.post_processor = Some(post_processor);
+        self
+    }
+
+    /// Configure how inputs are truncated by
+    /// [`encode_batch`](Self::encode_batch).
+    pub fn with_truncation(mut self, truncation: Truncation) -> Self {
+        self.truncation = Some(truncation);
+        self
+    }
+
+    /// Configure how inputs are padded by
+    /// [`encode_batch`](Self::encode_batch).
+    pub fn with_padding(mut self, padding: Padding) -> Self {
+        self.padding = Some(padding);
+        self
+    }
+
+    /// Return the truncation settings used by
+    /// [`encode_batch`](Self::encode_batch).
+    pub fn truncation(&self) -> Option<&Truncation> {
+        self.truncation.as_ref()
+    }
+
+    /// Return the padding settings used by
+    /// [`encode_batch`](Self::encode_batch).
+    pub fn padding(&self) -> Option<&Padding> {
+        self.padding.as_ref()
+    }
+
+    /// Configure the added tokens which are recognized in input text.
+    ///
+    /// Occurrences of added tokens in the input are encoded as the
+    /// corresponding token IDs and the rest of the input is encoded using the
+    /// normal tokenization pipeline. This replaces any previously configured
+    /// added tokens.
+    pub fn with_added_tokens(mut self, tokens: Vec<AddedToken>) -> Self {
+        let (normalized, unnormalized): (Vec<_>, Vec<_>) =
+            tokens.into_iter().partition(|token| token.normalized);
+        self.added_tokens = AddedTokenMatcher::new(unnormalized);
+        self.normalized_added_tokens = AddedTokenMatcher::new(normalized);
+        self
+    }
+
     /// Load a tokenizer from the contents of a Hugging Face `tokenizer.json`
     /// file.
     pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Tokenizer, FromJsonError> {
@@ -294,7 +468,77 @@ impl Tokenizer {
         Self::from_parsed_json(tokenizer_json)
     }
 
-    fn from_parsed_json(json: json::Tokenizer) -> Result<Tokenizer, FromJsonError> {
+    /// Load a tokenizer from a SentencePiece `.model` file.
+    ///
+    /// See [`from_sentencepiece`](Self::from_sentencepiece).
+    #[cfg(feature = "sentencepiece")]
+    pub fn from_sentencepiece_file<P: AsRef<Path>>(
+        path: P,
+    ) -> Result<Tokenizer, FromSentencePieceError> {
+        let data = std::fs::read(path).map_err(FromSentencePieceError::IoError)?;
+        Self::from_sentencepiece(&data)
+    }
+
+    /// Load a tokenizer from the contents of a SentencePiece `.model` file.
+    ///
+    /// Unigram and BPE models are supported. The tokenizer applies the
+    /// model's normalization rules and replaces spaces with "▁", but does not
+    /// add any special tokens such as `<s>` to encoded sequences. Use
+    /// [`with_post_processor`](Self::with_post_processor) to add them.
+    #[cfg(feature = "sentencepiece")]
+    pub fn from_sentencepiece(data: &[u8]) -> Result<Tokenizer, FromSentencePieceError> {
+        sentencepiece::from_sentencepiece(data)
+    }
+
+    /// Load a tokenizer from a GGUF file.
+    ///
+    /// Only the file's metadata is read. See [`from_gguf`](Self::from_gguf).
+    #[cfg(feature = "gguf")]
+    pub fn from_gguf_file<P: AsRef<Path>>(path: P) -> Result<Tokenizer, FromGgufError> {
+        gguf::from_gguf_file(path.as_ref())
+    }
+
+    /// Load a tokenizer from the contents of a GGUF file, as used by
+    /// llama.cpp.
+    ///
+    /// The tokenizer is constructed from the `tokenizer.ggml.*` metadata.
+    /// SentencePiece-style ("llama") and byte-level BPE ("gpt2") tokenizers
+    /// are supported. A BOS token is added to encoded sequences if the
+    /// model's configuration specifies it.
+    ///
+    /// `data` only needs to contain the file's header and metadata, not the
+    /// tensor data.
+    #[cfg(feature = "gguf")]
+    pub fn from_gguf(data: &[u8]) -> Result<Tokenizer, FromGgufError> {
+        gguf::from_gguf(data)
+    }
+
+    /// Load a tokenizer from a tiktoken BPE rank file.
+    ///
+    /// See [`from_tiktoken`](Self::from_tiktoken).
+    pub fn from_tiktoken_file<P: AsRef<Path>>(
+        path: P,
+        options: TiktokenOptions,
+    ) -> Result<Tokenizer, FromTiktokenError> {
+        let ranks = std::fs::read_to_string(path).map_err(FromTiktokenError::IoError)?;
+        Self::from_tiktoken(&ranks, options)
+    }
+
+    /// Load a tokenizer from the contents of a tiktoken BPE rank file, such
+    /// as `cl100k_base.tiktoken`.
+    ///
+    /// Each line of the file contains a base64-encoded token and its rank.
+    /// The merge list for the BPE model is derived from the ranks. Rank files
+    /// don't include the regex used to split text into pieces or special
+    /// tokens, so these are specified via `options`.
+    pub fn from_tiktoken(
+        ranks: &str,
+        options: TiktokenOptions,
+    ) -> Result<Tokenizer, FromTiktokenError> {
+        tiktoken::from_tiktoken(ranks, options)
+    }
+
+    pub(crate) fn from_parsed_json(json: json::Tokenizer) -> Result<Tokenizer, FromJsonError> {
         fn regex_pattern(pattern: &json::Pattern) -> Cow<'_, str> {
             match pattern {
                 json::Pattern::Regex(pat) => Cow::Borrowed(pat.as_str()),
@@ -322,6 +566,21 @@ impl Tokenizer {
                 json::Normalizer::Nfd => Box::new(normalizers::Unicode::Nfd),
                 json::Normalizer::Nfkc => Box::new(normalizers::Unicode::Nfkc),
                 json::Normalizer::Nfkd => Box::new(normalizers::Unicode::Nfkd),
+                json::Normalizer::Precompiled(precompiled) => {
+                    let charsmap = precompiled.precompiled_charsmap.unwrap_or_default();
+                    if charsmap.is_empty() {
+                        // An empty character map doesn't modify the input.
+                        Box::new(normalizers::Sequence::from_vec(Vec::new()))
+                    } else {
+                        let charsmap = BASE64.decode(&charsmap).map_err(|_| {
+                            FromJsonError::NormalizerError(NormalizeError::InvalidCharsMap)
+                        })?;
+                        Box::new(normalizers::Precompiled::from_charsmap(&charsmap)?)
+                    }
+                }
+                json::Normalizer::Prepend(prepend) => {
+                    Box::new(normalizers::Prepend::new(prepend.prepend))
+                }
                 json::Normalizer::Replace(replace) => {
                     let pattern = regex_pattern(&replace.pattern);
                     Box::new(normalizers::Replace::new(&pattern, replace.content)?)
@@ -334,6 +593,10 @@ impl Tokenizer {
                         .collect::<Result<Vec<_>, _>>()?;
                     Box::new(normalizers::Sequence::from_vec(normalizers))
                 }
+                json::Normalizer::Strip(strip) => {
+                    Box::new(normalizers::Strip::new(strip.strip_left, strip.strip_right))
+                }
+                json::Normalizer::StripAccents => Box::new(normalizers::StripAccents::new()),
             };
             Ok(normalizer)
         }
@@ -341,6 +604,21 @@ impl Tokenizer {
         let normalizer: Option<Box<dyn Normalizer>> =
             json.normalizer.map(create_normalizer).transpose()?;
 
+        fn split_delimiter(
+            behavior: json::pre_tokenizers::SplitDelimiter,
+        ) -> pre_tokenizers::SplitDelimiterBehavior {
+            use json::pre_tokenizers::SplitDelimiter;
+            use pre_tokenizers::SplitDelimiterBehavior;
+
+            match behavior {
+                SplitDelimiter::Removed => SplitDelimiterBehavior::Remove,
+                SplitDelimiter::Isolated => SplitDelimiterBehavior::Isolate,
+                SplitDelimiter::MergedWithPrevious => SplitDelimiterBehavior::MergeWithPrevious,
+                SplitDelimiter::MergedWithNext => SplitDelimiterBehavior::MergeWithNext,
+                SplitDelimiter::Contiguous => SplitDelimiterBehavior::Contiguous,
+            }
+        }
+
         fn create_pre_tokenizer(
             config: json::PreTokenizer,
         ) -> Result<Box<dyn PreTokenizer>, FromJsonError> {
@@ -358,9 +636,33 @@ impl Tokenizer {
                         Box::new(pre_tokenizers::Split::new(noop_split)?)
                     }
                 }
+                json::PreTokenizer::CharDelimiterSplit(split) => {
+                    Box::new(pre_tokenizers::CharDelimiterSplit::new(split.delimiter))
+                }
                 json::PreTokenizer::Digits(digits) => {
                     Box::new(pre_tokenizers::Digits::new(digits.individual_digits))
                 }
+                json::PreTokenizer::Metaspace(metaspace) => {
+                    let prepend_scheme = match metaspace.prepend_scheme {
+                        Some(json::PrependScheme::Always) => pre_tokenizers::PrependScheme::Always,
+                        Some(json::PrependScheme::First) => pre_tokenizers::PrependScheme::First,
+                        Some(json::PrependScheme::Never) => pre_tokenizers::PrependScheme::Never,
+                        None if metaspace.add_prefix_space.unwrap_or(true) => {
+                            pre_tokenizers::PrependScheme::Always
+                        }
+                        None => pre_tokenizers::PrependScheme::Never,
+                    };
+                    Box::new(pre_tokenizers::Metaspace::new(
+                        pre_tokenizers::MetaspaceOptions {
+                            replacement: metaspace.replacement,
+                            prepend_scheme,
+                            split: metaspace.split,
+                        },
+                    ))
+                }
+                json::PreTokenizer::Punctuation(punctuation) => Box::new(
+                    pre_tokenizers::Punctuation::new(split_delimiter(punctuation.behavior)),
+                ),
                 json::PreTokenizer::Sequence(seq) => {
                     let pre_tokenizers = seq
                         .pretokenizers
@@ -374,17 +676,17 @@ impl Tokenizer {
                     let opts = pre_tokenizers::SplitOptions {
                         pattern: &pattern,
                         invert: split.invert,
-                        delimiter: match split.behavior {
-                            json::pre_tokenizers::SplitDelimiter::Isolated => {
-                                pre_tokenizers::SplitDelimiterBehavior::Isolate
-                            }
-                            json::pre_tokenizers::SplitDelimiter::Removed => {
-                                pre_tokenizers::SplitDelimiterBehavior::Remove
-                            }
-                        },
+                        delimiter: split_delimiter(split.behavior),
                     };
                     Box::new(pre_tokenizers::Split::new(opts)?)
                 }
+                json::PreTokenizer::UnicodeScripts => {
+                    Box::new(pre_tokenizers::UnicodeScripts::new())
+                }
+                json::PreTokenizer::Whitespace => Box::new(pre_tokenizers::Whitespace::new()),
+                json::PreTokenizer::WhitespaceSplit => {
+                    Box::new(pre_tokenizers::WhitespaceSplit::new())
+                }
             };
             Ok(pre_tokenizer)
         }
@@ -392,6 +694,119 @@ impl Tokenizer {
         let pre_tokenizer: Option<Box<dyn PreTokenizer>> =
             json.pre_tokenizer.map(create_pre_tokenizer).transpose()?;
 
+        fn create_decoder(config: json::Decoder) -> Result<Box<dyn Decoder>, FromJsonError> {
+            let decoder: Box<dyn Decoder> = match config {
+                json::Decoder::Bpe(bpe) => Box::new(decoders::Bpe::new(bpe.suffix)),
+                json::Decoder::ByteFallback => Box::new(decoders::ByteFallback::new()),
+                json::Decoder::ByteLevel => Box::new(decoders::ByteLevel::new()),
+                json::Decoder::Ctc(ctc) => Box::new(decoders::Ctc::new(
+                    ctc.pad_token,
+                    ctc.word_delimiter_token,
+                    ctc.cleanup,
+                )),
+                json::Decoder::Fuse => Box::new(decoders::Fuse::new()),
+                json::Decoder::Metaspace(metaspace) => {
+                    let add_prefix_space = match metaspace.prepend_scheme {
+                        Some(scheme) => scheme != json::PrependScheme::Never,
+                        None => metaspace.add_prefix_space.unwrap_or(true),
+                    };
+                    Box::new(decoders::Metaspace::new(
+                        metaspace.replacement,
+                        add_prefix_space,
+                    ))
+                }
+                json::Decoder::Replace(replace) => {
+                    let pattern = regex_pattern(&replace.pattern);
+                    Box::new(decoders::Replace::new(&pattern, replace.content)?)
+                }
+                json::Decoder::Sequence(seq) => {
+                    let decoders = seq
+                        .decoders
+                        .into_iter()
+                        .map(create_decoder)
+                        .collect::<Result<Vec<_>, _>>()?;
+                    Box::new(decoders::Sequence::from_vec(decoders))
+                }
+                json::Decoder::Strip(strip) => {
+                    Box::new(decoders::Strip::new(strip.content, strip.start, strip.stop))
+                }
+                json::Decoder::WordPiece(wordpiece) => Box::new(decoders::WordPiece::new(
+                    wordpiece.prefix,
+                    wordpiece.cleanup,
+                )),
+            };
+            Ok(decoder)
+        }
+
+        let decoder: Option<Box<dyn Decoder>> = json.decoder.map(create_decoder).transpose()?;
+
+        /// Create a post-processor from its configuration, or return `None` if
+        /// the configuration does not add any special tokens.
+        fn create_post_processor(
+            config: json::PostProcessor,
+        ) -> Result<Option<TemplateProcessing>, FromJsonError> {
+            let post_processor = match config {
+                json::PostProcessor::BertProcessing(bert) => {
+                    TemplateProcessing::bert(bert.cls.1, bert.sep.1)
+                }
+                json::PostProcessor::RobertaProcessing(roberta) => {
+                    TemplateProcessing::roberta(roberta.cls.1, roberta.sep.1)
+                }
+                json::PostProcessor::TemplateProcessing(template) => {
+                    let convert_template = |pieces: Vec<json::post_processors::TemplatePiece>| {
+                        pieces
+                            .into_iter()
+                            .map(|piece| match piece {
+                                json::post_processors::TemplatePiece::Sequence { id, type_id } => {
+                                    let id = match id {
+                                        json::post_processors::SequenceId::A => SequenceId::A,
+                                        json::post_processors::SequenceId::B => SequenceId::B,
+                                    };
+                                    Ok(TemplatePiece::Sequence { id, type_id })
+                                }
+                                json::post_processors::TemplatePiece::SpecialToken {
+                                    id,
+                                    type_id,
+                                } => {
+                                    let Some(token) = template.special_tokens.get(&id) else {
+                                        return Err(PostProcessorError::UnknownSpecialToken(id));
+                                    };
+                                    Ok(TemplatePiece::SpecialToken {
+                                        ids: token.ids.clone(),
+                                        type_id,
+                                    })
+                                }
+                            })
+                            .collect::<Result<Vec<_>, _>>()
+                    };
+                    let single = convert_template(template.single)?;
+                    let pair = convert_template(template.pair)?;
+                    TemplateProcessing::new(single, pair)?
+                }
+                json::PostProcessor::Sequence(seq) => {
+                    // Apply the post-processors which add special tokens in
+                    // order, by combining their templates.
+                    let mut post_processor: Option<TemplateProcessing> = None;
+                    for config in seq.processors {
+                        if let Some(next) = create_post_processor(config)? {
+                            post_processor = Some(match post_processor {
+                                Some(prev) => prev.then(&next),
+                                None => next,
+                            });
+                        }
+                    }
+                    return Ok(post_processor);
+                }
+                json::PostProcessor::Other => return Ok(None),
+            };
+            Ok(Some(post_processor))
+        }
+
+        let post_processor: Option<TemplateProcessing> = match json.post_processor {
+            Some(config) => create_post_processor(config)?,
+            None => None,
+        };
+
         let mut tokenizer = match json.model {
             json::Model::Bpe(model) => {
                 let added_tokens: FxHashMap<TokenId, String> = json
@@ -410,12 +825,27 @@ impl Tokenizer {
                         pairs.into_iter().map(|(a, b)| (a.0, b.0)).collect()
                     }
                 };
+                // Byte-level models have tokens for every byte. Other models,
+                // such as those converted from SentencePiece, operate on
+                // characters.
+                let char_level = char_to_byte().keys().any(|ch| {
+                    !model
+                        .vocab
+                        .contains_key(ch.encode_utf8(&mut [0; 4]) as &str)
+                });
+                let unk_id = model
+                    .unk_token
+                    .as_ref()
+                    .and_then(|unk| model.vocab.get(unk).copied());
                 let bpe_opts = BpeOptions {
                     merges: &merges,
                     vocab: Some(model.vocab),
                     added_tokens,
                     end_of_word_suffix: model.end_of_word_suffix,
                     ignore_merges: model.ignore_merges,
+                    char_level,
+                    unk_id,
+                    byte_fallback: model.byte_fallback,
                 };
                 let model = Bpe::new(bpe_opts).map_err(FromJsonError::BpeError)?;
 
@@ -429,8 +859,31 @@ impl Tokenizer {
 
                 Ok::<_, FromJsonError>(tokenizer)
             }
+            json::Model::Unigram(model) => {
+                let unigram_opts = UnigramOptions {
+                    unk_id: model.unk_id,
+                    byte_fallback: model.byte_fallback,
+                };
+                let model = Unigram::from_vocab(model.vocab, unigram_opts)
+                    .map_err(FromJsonError::UnigramError)?;
+                let tokenizer = Tokenizer::new(
+                    model,
+                    TokenizerOptions {
+                        cls_token: None,
+                        sep_token: None,
+                    },
+                );
+
+                Ok::<_, FromJsonError>(tokenizer)
+            }
             json::Model::WordPiece(model) => {
-                let model = WordPiece::from_vocab(model.vocab, Default::default());
+                let model = WordPiece::from_vocab(
+                    model.vocab,
+                    WordPieceOptions {
+                        max_word_len: model.max_input_chars_per_word,
+                        unk_token: model.unk_token,
+                    },
+                );
                 let tokenizer = Tokenizer::new(
                     model,
                     TokenizerOptions {
@@ -451,9 +904,245 @@ impl Tokenizer {
             tokenizer = tokenizer.with_pre_tokenizer(pre_tokenizer);
         }
 
+        if let Some(post_processor) = post_processor {
+            tokenizer = tokenizer.with_post_processor(post_processor);
+        }
+
+        if let Some(decoder) = decoder {
+            tokenizer = tokenizer.with_decoder(decoder);
+        }
+
+        if let Some(truncation) = json.truncation {
+            tokenizer = tokenizer.with_truncation(Truncation {
+                max_length: truncation.max_length,
+                strategy: match truncation.strategy {
+                    json::TruncationStrategy::LongestFirst => TruncationStrategy::LongestFirst,
+                    json::TruncationStrategy::OnlyFirst => TruncationStrategy::OnlyFirst,
+                    json::TruncationStrategy::OnlySecond => TruncationStrategy::OnlySecond,
+                },
+                direction: match truncation.direction {
+                    json::Direction::Left => TruncationDirection::Left,
+                    json::Direction::Right => TruncationDirection::Right,
+                },
+            });
+        }
+
+        if let Some(padding) = json.padding {
+            tokenizer = tokenizer.with_padding(Padding {
+                strategy: match padding.strategy {
+                    json::PaddingStrategy::BatchLongest => PaddingStrategy::BatchLongest,
+                    json::PaddingStrategy::Fixed(len) => PaddingStrategy::Fixed(len),
+                },
+                direction: match padding.direction {
+                    json::Direction::Left => PaddingDirection::Left,
+                    json::Direction::Right => PaddingDirection::Right,
+                },
+                pad_to_multiple_of: padding.pad_to_multiple_of,
+                pad_id: padding.pad_id,
+                pad_type_id: padding.pad_type_id,
+            });
+        }
+
+        if let Some(added_tokens) = json.added_tokens {
+            let added_tokens = added_tokens
+                .into_iter()
+                .map(|token| AddedToken {
+                    normalized: token.normalized.unwrap_or(!token.special),
+                    content: token.content,
+                    id: token.id,
+                    special: token.special,
+                    lstrip: token.lstrip,
+                    rstrip: token.rstrip,
+                    single_word: token.single_word,
+                })
+                .collect();
+            tokenizer = tokenizer.with_added_tokens(added_tokens);
+        }
+
         Ok(tokenizer)
     }
 
+    /// Serialize this tokenizer in the format of a Hugging Face
+    /// `tokenizer.json` file.
+    ///
+    /// The result can be loaded using [`from_json`](Self::from_json) or by
+    /// other tools which support this format. This fails if the model or any
+    /// of the normalizer, pre-tokenizer or decoder cannot be serialized, which
+    /// is the case for custom implementations of these traits that don't
+    /// implement `to_json`.
+    pub fn to_json(&self) -> Result<String, ToJsonError> {
+        let mut added_tokens: Vec<_> = self
+            .added_tokens
+            .tokens()
+            .iter()
+            .chain(self.normalized_added_tokens.tokens())
+            .map(|token| json::AddedToken {
+                content: token.content.clone(),
+                id: token.id,
+                special: token.special,
+                lstrip: token.lstrip,
+                rstrip: token.rstrip,
+                single_word: token.single_word,
+                normalized: Some(token.normalized),
+            })
+            .collect();
+        added_tokens.sort_by_key(|token| token.id);
+
+        let truncation = self.truncation.as_ref().map(|truncation| json::Truncation {
+            max_length: truncation.max_length,
+            strategy: match truncation.strategy {
+                TruncationStrategy::LongestFirst => json::TruncationStrategy::LongestFirst,
+                TruncationStrategy::OnlyFirst => json::TruncationStrategy::OnlyFirst,
+                TruncationStrategy::OnlySecond => json::TruncationStrategy::OnlySecond,
+            },
+            direction: match truncation.direction {
+                TruncationDirection::Left => json::Direction::Left,
+                TruncationDirection::Right => json::Direction::Right,
+            },
+        });
+
+        let token_str = |id| {
+            self.model
+                .get_token_str(id)
+                .ok_or(ToJsonError::UnknownTokenId(id))
+        };
+
+        let padding = self
+            .padding
+            .as_ref()
+            .map(|padding| {
+                Ok::<_, ToJsonError>(json::Padding {
+                    strategy: match padding.strategy {
+                        PaddingStrategy::BatchLongest => json::PaddingStrategy::BatchLongest,
+                        PaddingStrategy::Fixed(len) => json::PaddingStrategy::Fixed(len),
+                    },
+                    direction: match padding.direction {
+                        PaddingDirection::Left => json::Direction::Left,
+                        PaddingDirection::Right => json::Direction::Right,
+                    },
+                    pad_to_multiple_of: padding.pad_to_multiple_of,
+                    pad_id: padding.pad_id,
+                    pad_type_id: padding.pad_type_id,
+                    pad_token: token_str(padding.pad_id)?,
+                })
+            })
+            .transpose()?;
+
+        // The post-processor is always serialized, even if it was created from
+        // the `[CLS]` and `[SEP]` token options, so that the special tokens
+        // added when the tokenizer is loaded again are the same.
+        let post_processor = self
+            .post_processor()
+            .map_err(|_| ToJsonError::Unsupported("post-processor"))?;
+        let post_processor = match post_processor.kind() {
+            TemplateKind::Bert { cls, sep } => {
+                json::PostProcessor::BertProcessing(json::post_processors::ClsSep {
+                    cls: (token_str(cls)?, cls),
+                    sep: (token_str(sep)?, sep),
+                })
+            }
+            TemplateKind::Roberta { cls, sep } => {
+                json::PostProcessor::RobertaProcessing(json::post_processors::ClsSep {
+                    cls: (token_str(cls)?, cls),
+                    sep: (token_str(sep)?, sep),
+                })
+            }
+            TemplateKind::Template => {
+                let mut special_tokens = HashMap::new();
+                let mut convert_template = |pieces: &[TemplatePiece]| {
+                    pieces
+                        .iter()
+                        .map(|piece| match piece {
+                            TemplatePiece::Sequence { id, type_id } => {
+                                Ok(json::post_processors::TemplatePiece::Sequence {
+                                    id: match id {
+                                        SequenceId::A => json::post_processors::SequenceId::A,
+                                        SequenceId::B => json::post_processors::SequenceId::B,
+                                    },
+                                    type_id: *type_id,
+                                })
+                            }
+                            TemplatePiece::SpecialToken { ids, type_id } => {
+                                let tokens: Vec<String> = ids
+                                    .iter()
+                                    .map(|&id| token_str(id))
+                                    .collect::<Result<_, _>>()?;
+                                let key = tokens.concat();
+                                special_tokens.entry(key.clone()).or_insert_with(|| {
+                                    json::post_processors::SpecialToken {
+                                        id: key.clone(),
+                                        ids: ids.clone(),
+                                        tokens,
+                                    }
+                                });
+                                Ok(json::post_processors::TemplatePiece::SpecialToken {
+                                    id: key,
+                                    type_id: *type_id,
+                                })
+                            }
+                        })
+                        .collect::<Result<Vec<_>, ToJsonError>>()
+                };
+                let single = convert_template(post_processor.template(false))?;
+                let pair = convert_template(post_processor.template(true))?;
+                json::PostProcessor::TemplateProcessing(json::post_processors::TemplateProcessing {
+                    single,
+                    pair,
+                    special_tokens,
+                })
+            }
+        };
+
+        let normalizer = self
+            .normalizer
+            .as_ref()
+            .map(|normalizer| {
+                normalizer
+                    .to_json()
+                    .ok_or(ToJsonError::Unsupported("normalizer"))
+            })
+            .transpose()?;
+        let pre_tokenizer = self
+            .pre_tokenizer
+            .as_ref()
+            .map(|pre_tokenizer| {
+                pre_tokenizer
+                    .to_json()
+                    .ok_or(ToJsonError::Unsupported("pre-tokenizer"))
+            })
+            .transpose()?;
+        let decoder = self
+            .decoder
+            .as_ref()
+            .map(|decoder| decoder.to_json().ok_or(ToJsonError::Unsupported("decoder")))
+            .transpose()?;
+        let model = self
+            .model
+            .to_json()
+            .ok_or(ToJsonError::Unsupported("model"))?;
+
+        let tokenizer = serde_json::json!({
+            "version": "1.0",
+            "truncation": truncation,
+            "padding": padding,
+            "added_tokens": added_tokens,
+            "normalizer": normalizer,
+            "pre_tokenizer": pre_tokenizer,
+            "post_processor": post_processor,
+            "decoder": decoder,
+            "model": model,
+        });
+        Ok(serde_json::to_string_pretty(&tokenizer).expect("tokenizer should be serializable"))
+    }
+
+    /// Save this tokenizer as a Hugging Face `tokenizer.json` file.
+    ///
+    /// See [`to_json`](Self::to_json).
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), ToJsonError> {
+        let json = self.to_json()?;
+        std::fs::write(path, json).map_err(ToJsonError::IoError)
+    }
+
     #[deprecated = "`encoder` was renamed to `model`"]
     pub fn encoder(&self) -> &dyn Model {
         self.model()
@@ -471,6 +1160,16 @@ impl Tokenizer {
     /// This wraps [`Model::get_token_id`] but returns a `Result` rather than
     /// an `Option`, assuming the token is expected to be valid.
     pub fn get_token_id(&self, text: &str) -> Result<TokenId, TokenizerError> {
+        let added_token = self
+            .added_tokens
+            .tokens()
+            .iter()
+            .chain(self.normalized_added_tokens.tokens())
+            .find(|token| token.content == text);
+        if let Some(token) = added_token {
+            return Ok(token.id);
+        }
+
         self.model
             .get_token_id(text)
             .ok_or(TokenizerError::EncodeError(EncodeError::TokenIdNotFound(
@@ -492,6 +1191,51 @@ impl Tokenizer {
             .transpose()
     }
 
+    /// Return the post-processor which adds special tokens to encoded
+    /// sequences.
+    ///
+    /// If no post-processor was configured, this creates one using the
+    /// `[CLS]` and `[SEP]` tokens from [`TokenizerOptions`].
+    fn post_processor(&self) -> Result<Cow<'_, TemplateProcessing>, TokenizerError> {
+        if let Some(post_processor) = &self.post_processor {
+            return Ok(Cow::Borrowed(post_processor));
+        }
+
+        let cls_token = self.cls_token()?;
+        let sep_token = self.sep_token()?;
+        if let (Some(cls), Some(sep)) = (cls_token, sep_token) {
+            return Ok(Cow::Owned(TemplateProcessing::bert(cls, sep)));
+        }
+
+        let special = |id: Option<TokenId>, type_id| {
+            id.map(|id| TemplatePiece::SpecialToken {
+                ids: vec![id],
+                type_id,
+            })
+        };
+        let seq = |id, type_id| Some(TemplatePiece::Sequence { id, type_id });
+
+        let single = [
+            special(cls_token, 0),
+            seq(SequenceId::A, 0),
+            special(sep_token, 0),
+        ];
+        let pair = [
+            special(cls_token, 0),
+            seq(SequenceId::A, 0),
+            special(sep_token, 0),
+            seq(SequenceId::B, 1),
+            special(sep_token, 1),
+        ];
+        let post_processor = TemplateProcessing::new(
+            single.into_iter().flatten().collect(),
+            pair.into_iter().flatten().collect(),
+        )
+        .expect("template should be valid");
+
+        Ok(Cow::Owned(post_processor))
+    }
+
     /// Encode one or two sequences into a sequence of tokens.
     ///
     /// The input can be an `&str` or tuple of `(&str, &str)`.
@@ -505,9 +1249,8 @@ impl Tokenizer {
     ) -> Result<Encoded<'a>, TokenizerError> {
         let options = options.unwrap_or_default();
         let input: EncoderInput = input.into();
-
-        let cls_token = self.cls_token()?;
-        let sep_token = self.sep_token()?;
+        let is_pair = matches!(input, EncoderInput::Pair(_));
+        let post_processor = self.post_processor()?;
 
         // To simplify the implementation, we tokenize the whole input and
         // just discard all chunks except the first. This could be optimized
@@ -516,40 +1259,192 @@ impl Tokenizer {
 
         let chunk = chunks.into_iter().next().unwrap_or_else(|| {
             // If the input is empty after tokenization, generate a single
-            // empty chunk.
-            let mut tokens = Vec::new();
-            let mut offsets = Vec::new();
-            let mut first_seq_tokens = 0;
-
-            if let Some(cls_token) = cls_token {
-                tokens.push(cls_token);
-                offsets.push(0);
-                first_seq_tokens += 1;
-            }
-            if let Some(sep_token) = sep_token {
-                tokens.push(sep_token);
-                offsets.push(0);
-                first_seq_tokens += 1;
-
-                if matches!(input, EncoderInput::Pair(_)) {
-                    tokens.push(sep_token);
-                    offsets.push(0);
-                }
-            }
-
-            Encoded::new(input, tokens, offsets, first_seq_tokens)
+            // chunk containing only special tokens.
+            let empty = SequencePart {
+                tokens: &[],
+                offsets: &[],
+                start_offset: 0,
+                end_offset: 0,
+            };
+            let (tokens, offsets, type_ids) = apply_template(
+                post_processor.template(is_pair),
+                empty,
+                is_pair.then_some(empty),
+            );
+            Encoded::new(input, tokens, offsets, type_ids)
         });
 
         Ok(chunk)
     }
 
+    /// Encode a batch of inputs in parallel and pad the results to the same
+    /// length.
+    ///
+    /// Unlike [`encode`](Self::encode), this applies the truncation and
+    /// padding settings configured using
+    /// [`with_truncation`](Self::with_truncation) and
+    /// [`with_padding`](Self::with_padding), or loaded from a
+    /// `tokenizer.json` file. Inputs which exceed the maximum length are
+    /// truncated and the excess tokens are discarded. If no padding is
+    /// configured, inputs are padded to the length of the longest sequence in
+    /// the batch using token ID 0.
+    ///
+    /// Chunking is not supported. Returns
+    /// [`TokenizerError::UnsupportedOption`] if
+    /// [`EncodeOptions::max_chunk_len`] or [`EncodeOptions::overlap`] are set.
+    pub fn encode_batch<'a>(
+        &self,
+        inputs: &[EncoderInput<'a>],
+        options: Option<EncodeOptions>,
+    ) -> Result<BatchEncoding<'a>, TokenizerError> {
+        let options = options.unwrap_or_default();
+        if options.max_chunk_len.is_some() {
+            return Err(TokenizerError::UnsupportedOption("max_chunk_len"));
+        }
+        if options.overlap != 0 {
+            return Err(TokenizerError::UnsupportedOption("overlap"));
+        }
+        let encodings = inputs
+            .par_iter()
+            .map(|input| {
+                self.encode_truncated(
+                    *input,
+                    self.truncation.as_ref(),
+                    options.split_special_tokens,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(BatchEncoding::new(
+            encodings,
+            &self.padding.clone().unwrap_or_default(),
+        ))
+    }
+
+    /// Encode one or two sequences, truncating them if necessary so that the
+    /// output, including special tokens, fits within the maximum length
+    /// specified by `truncation`.
+    fn encode_truncated<'a>(
+        &self,
+        input: EncoderInput<'a>,
+        truncation: Option<&Truncation>,
+        split_special_tokens: bool,
+    ) -> Result<Encoded<'a>, TokenizerError> {
+        let is_pair = matches!(input, EncoderInput::Pair(_));
+        let post_processor = self.post_processor()?;
+
+        let (first, second) = match input {
+            EncoderInput::Item(first) => (first, None),
+            EncoderInput::Pair((first, second)) => (first, Some(second)),
+        };
+        let (first_tokens, first_offsets) = self.encode_str(first, 0, split_special_tokens)?;
+        let second_encoded = second
+            .map(|second| self.encode_str(second, first.len(), split_special_tokens))
+            .transpose()?;
+
+        let (first_len, second_len) = match truncation {
+            Some(truncation) => {
+                let max_len = truncation
+                    .max_length
+                    .checked_sub(post_processor.added_tokens(is_pair))
+                    .ok_or(TokenizerError::TruncationError)?;
+                truncation.truncated_lengths(
+                    first_tokens.len(),
+                    second_encoded.as_ref().map(|(tokens, _)| tokens.len()),
+                    max_len,
+                )?
+            }
+            None => (
+                first_tokens.len(),
+                second_encoded
+                    .as_ref()
+                    .map_or(0, |(tokens, _)| tokens.len()),
+            ),
+        };
+        let direction = truncation.map(|t| t.direction).unwrap_or_default();
+
+        let mut first_part = SequencePart::truncated(
+            &first_tokens,
+            &first_offsets,
+            first_len,
+            direction,
+            0..first.len(),
+        );
+        if !is_pair {
+            // Match `encode`, where the start offset of a single sequence is
+            // the offset of its first token.
+            first_part.start_offset = first_part
+                .offsets
+                .first()
+                .copied()
+                .unwrap_or(first_part.start_offset);
+        }
+        let second_part = second_encoded
+            .as_ref()
+            .zip(second)
+            .map(|((tokens, offsets), text)| {
+                let mut part = SequencePart::truncated(
+                    tokens,
+                    offsets,
+                    second_len,
+                    direction,
+                    first.len()..first.len() + text.len(),
+                );
+                part.start_offset = part.offsets.first().copied().unwrap_or(part.start_offset);
+                part
+            });
+
+        let (tokens, offsets, type_ids) =
+            apply_template(post_processor.template(is_pair), first_part, second_part);
+        Ok(Encoded::new(input, tokens, offsets, type_ids))
+    }
+
     /// Encode a single string into tokens and return a `(tokens, offsets)`
     /// tuple.
     fn encode_str(
         &self,
         text: &str,
         start_offset: usize,
+        split_special_tokens: bool,
     ) -> Result<(Vec<TokenId>, Vec<usize>), TokenizerError> {
+        let mut tokens = Vec::new();
+        let mut offsets = Vec::new();
+
+        for segment in self.added_tokens.split(text, split_special_tokens) {
+            match segment {
+                Segment::Token { start, id } => {
+                    tokens.push(id);
+                    offsets.push(start_offset + start);
+                }
+                Segment::Text(range) => self.encode_text(
+                    &text[range.clone()],
+                    start_offset + range.start,
+                    range.start == 0,
+                    split_special_tokens,
+                    &mut tokens,
+                    &mut offsets,
+                )?,
+            }
+        }
+
+        Ok((tokens, offsets))
+    }
+
+    /// Normalize, pre-tokenize and encode a piece of text which does not
+    /// contain any un-normalized added tokens. Tokens and offsets are appended
+    /// to `tokens` and `offsets`.
+    ///
+    /// `at_start` indicates whether `text` is at the start of the input
+    /// sequence.
+    fn encode_text(
+        &self,
+        text: &str,
+        start_offset: usize,
+        at_start: bool,
+        split_special_tokens: bool,
+        tokens: &mut Vec<TokenId>,
+        offsets: &mut Vec<usize>,
+    ) -> Result<(), TokenizerError> {
         let (normalized, offset_map) = match &self.normalizer {
             None => (text.to_string(), None),
             Some(normalizer) => {
@@ -558,14 +1453,6 @@ impl Tokenizer {
             }
         };
 
-        let chunks = self
-            .pre_tokenizer
-            .as_ref()
-            .map(|pt| pt.pre_tokenize(&normalized))
-            .transpose()
-            .map_err(TokenizerError::PreTokenizeError)?
-            .unwrap_or(Vec::from([normalized.as_str()]));
-
         // Map an offset into the normalized string into an offset in the source
         // string.
         let map_offset = |offset: usize| {
@@ -579,23 +1466,61 @@ impl Tokenizer {
             }
         };
 
-        let mut tokens = Vec::new();
-        let mut offsets = Vec::new();
+        for segment in self
+            .normalized_added_tokens
+            .split(&normalized, split_special_tokens)
+        {
+            let range = match segment {
+                Segment::Token { start, id } => {
+                    tokens.push(id);
+                    offsets.push(start_offset + map_offset(start));
+                    continue;
+                }
+                Segment::Text(range) => range,
+            };
 
-        for chunk in chunks {
-            let base_offset = normalized
-                .as_bytes()
-                .subslice_offsets(chunk.as_bytes())
-                .expect("should be a subslice")
-                .start;
-            self.model
-                .encode_with_offsets(chunk, &mut |offset, token| {
-                    offsets.push(start_offset + base_offset + map_offset(offset));
-                    tokens.push(token);
-                })?;
+            let piece = &normalized[range];
+            let chunks = self
+                .pre_tokenizer
+                .as_ref()
+                .map(|pt| pt.pre_tokenize(piece))
+                .transpose()
+                .map_err(TokenizerError::PreTokenizeError)?
+                .unwrap_or(Vec::from([piece]));
+
+            for chunk in chunks {
+                let base_offset = normalized
+                    .as_bytes()
+                    .subslice_offsets(chunk.as_bytes())
+                    .expect("should be a subslice")
+                    .start;
+
+                let transformed = self
+                    .pre_tokenizer
+                    .as_ref()
+                    .and_then(|pt| pt.transform_chunk(chunk, at_start && base_offset == 0));
+
+                match transformed {
+                    Some((chunk, chunk_offsets)) => {
+                        self.model
+                            .encode_with_offsets(&chunk, &mut |offset, token| {
+                                offsets.push(
+                                    start_offset + map_offset(base_offset + chunk_offsets[offset]),
+                                );
+                                tokens.push(token);
+                            })?
+                    }
+                    None => self
+                        .model
+                        .encode_with_offsets(chunk, &mut |offset, token| {
+                            offsets.push(start_offset + map_offset(base_offset + offset));
+                            tokens.push(token);
+                        })?,
+                }
+            }
         }
 
-        Ok((tokens, offsets))
+        Ok(())
     }
 
     /// Encode one or two sequences into a sequence of tokens.
@@ -607,18 +1532,12 @@ impl Tokenizer {
         input: EncoderInput<'a>,
         options: EncodeOptions,
     ) -> Result<Vec<Encoded<'a>>, TokenizerError> {
-        let cls_token = self.cls_token()?;
-        let sep_token = self.sep_token()?;
-
-        let has_cls = cls_token.is_some() as usize;
-        let has_sep = sep_token.is_some() as usize;
+        let is_pair = matches!(input, EncoderInput::Pair(_));
+        let post_processor = self.post_processor()?;
+        let template = post_processor.template(is_pair);
 
         // Number of non-content tokens added to each chunk.
-        let non_content_tokens_per_chunk = has_cls
-            + match input {
-                EncoderInput::Item(_) => has_sep,     // [CLS] .. [SEP]
-                EncoderInput::Pair(_) => has_sep * 2, // [CLS] .. [SEP] .. [SEP]
-            };
+        let non_content_tokens_per_chunk = post_processor.added_tokens(is_pair);
 
         // Encode the full input sequences.
         let mut tokens = Vec::new();
@@ -628,14 +1547,15 @@ impl Tokenizer {
             EncoderInput::Pair((first, second)) => (first, Some(second)),
         };
 
-        let (first_seq_tokens, first_seq_offsets) = self.encode_str(first_seq, 0)?;
+        let (first_seq_tokens, first_seq_offsets) =
+            self.encode_str(first_seq, 0, options.split_special_tokens)?;
         tokens.extend(first_seq_tokens);
         offsets.extend(first_seq_offsets);
         let first_seq_tokens = tokens.len();
 
         if let Some(second_seq) = second_seq {
             let (second_seq_tokens, second_seq_offsets) =
-                self.encode_str(second_seq, first_seq.len())?;
+                self.encode_str(second_seq, first_seq.len(), options.split_special_tokens)?;
             tokens.extend(second_seq_tokens);
             offsets.extend(second_seq_offsets);
         }
@@ -664,34 +1584,22 @@ impl Tokenizer {
                     .zip(offsets.chunks_with_overlap(max_tokens_per_chunk, options.overlap))
                     .enumerate()
                 {
-                    let mut tokens = Vec::new();
-                    let mut offsets = Vec::new();
-
-                    if let Some(cls_token) = cls_token {
-                        tokens.push(cls_token);
-                        offsets.push(offsets_chunk.first().copied().unwrap());
-                    }
-
-                    tokens.extend_from_slice(tokens_chunk);
-                    offsets.extend_from_slice(offsets_chunk);
-
-                    if let Some(sep_token) = sep_token {
-                        tokens.push(sep_token);
-                    }
-
-                    // The offset for the final token is the offset of the first
+                    // The end offset of the chunk is the offset of the first
                     // token in the next chunk, or the input length if this
                     // is the final chunk.
                     let chunk_start = chunk_idx * max_tokens_per_chunk;
-                    offsets.push(
-                        all_offsets
+                    let part = SequencePart {
+                        tokens: tokens_chunk,
+                        offsets: offsets_chunk,
+                        start_offset: offsets_chunk.first().copied().unwrap(),
+                        end_offset: all_offsets
                             .get(chunk_start + offsets_chunk.len())
                             .copied()
                             .unwrap_or(item.len()),
-                    );
+                    };
 
-                    let n_tokens = tokens.len();
-                    chunks.push(Encoded::new(input, tokens, offsets, n_tokens));
+                    let (tokens, offsets, type_ids) = apply_template(template, part, None);
+                    chunks.push(Encoded::new(input, tokens, offsets, type_ids));
                 }
             }
 
@@ -712,49 +1620,37 @@ impl Tokenizer {
                     return Ok(vec![]);
                 }
 
+                // The first sequence is the same for every chunk.
+                let first_part = SequencePart {
+                    tokens: &first_tokens[..first_len],
+                    offsets: &first_offsets[..first_len],
+                    start_offset: 0,
+                    end_offset: first.len(),
+                };
+
                 for (chunk_idx, (tokens_chunk, offsets_chunk)) in second_tokens
                     .chunks_with_overlap(second_len, options.overlap)
                     .zip(second_offsets.chunks_with_overlap(second_len, options.overlap))
                     .enumerate()
                 {
-                    let mut tokens = Vec::new();
-                    let mut offsets = Vec::new();
-
-                    // Add the first sequence. This is the same for every chunk.
-                    if let Some(cls_token) = cls_token {
-                        tokens.push(cls_token);
-                        offsets.push(0);
-                    }
-
-                    tokens.extend_from_slice(&first_tokens[..first_len]);
-                    offsets.extend_from_slice(&first_offsets[..first_len]);
-
-                    if let Some(sep_token) = sep_token {
-                        tokens.push(sep_token);
-                        offsets.push(first.len());
-                    }
-
-                    let first_seq_len = tokens.len();
-
-                    // Add the second sequence, which changes in each chunk.
-                    tokens.extend_from_slice(tokens_chunk);
-                    offsets.extend_from_slice(offsets_chunk);
-
-                    // The offset for the final token is the offset of the first
-                    // token from the second sequence in the next chunk, or
-                    // the concatenated input length if this is the final chunk.
-                    if let Some(sep_token) = sep_token {
-                        tokens.push(sep_token);
-                    }
+                    // The end offset of the second sequence is the offset of
+                    // the first token from the second sequence in the next
+                    // chunk, or the concatenated input length if this is the
+                    // final chunk.
                     let chunk_start = chunk_idx * second_len;
-                    offsets.push(
-                        second_offsets
+                    let second_part = SequencePart {
+                        tokens: tokens_chunk,
+                        offsets: offsets_chunk,
+                        start_offset: offsets_chunk.first().copied().unwrap(),
+                        end_offset: second_offsets
                             .get(chunk_start + offsets_chunk.len())
                             .copied()
                             .unwrap_or(first.len() + second.len()),
-                    );
+                    };
 
-                    chunks.push(Encoded::new(input, tokens, offsets, first_seq_len));
+                    let (tokens, offsets, type_ids) =
+                        apply_template(template, first_part, Some(second_part));
+                    chunks.push(Encoded::new(input, tokens, offsets, type_ids));
                 }
             }
         }
@@ -771,11 +1667,127 @@ impl Tokenizer {
     ///
     /// Special tokens are decoded into their canonical string representations
     /// as returned by [`Model::get_token_str`].
+    ///
+    /// If the tokenizer has a [`Decoder`], it is applied to the token strings
+    /// returned by [`Model::get_tokens`]. Otherwise decoding is delegated to
+    /// the model.
     pub fn decode(&self, ids: &[TokenId]) -> Result<String, TokenizerError> {
-        self.model.decode(ids).map_err(TokenizerError::DecodeError)
+        let Some(decoder) = &self.decoder else {
+            return self.model.decode(ids).map_err(TokenizerError::DecodeError);
+        };
+        let tokens = self
+            .model
+            .get_tokens(ids)
+            .map_err(TokenizerError::DecodeError)?;
+        let decoded = decoder.decode_chain(tokens).map_err(|err| match err {
+            DecoderError::InvalidUtf8 => TokenizerError::DecodeError(DecodeError::InvalidUtf8),
+            err => TokenizerError::DecoderError(err),
+        })?;
+        Ok(decoded.concat())
+    }
+}
+
+/// Tokens and offsets from part of an input sequence, which are combined with
+/// special tokens by [`apply_template`].
+#[derive(Copy, Clone)]
+struct SequencePart<'a> {
+    tokens: &'a [TokenId],
+    offsets: &'a [usize],
+
+    /// Offset of the start of the text that this part corresponds to.
+    start_offset: usize,
+
+    /// Offset of the end of the text that this part corresponds to.
+    end_offset: usize,
+}
+
+impl<'a> SequencePart<'a> {
+    /// Create the part of an encoded sequence that remains after truncating
+    /// it to `len` tokens. `text_range` is the range of the sequence in the
+    /// input text.
+    fn truncated(
+        tokens: &'a [TokenId],
+        offsets: &'a [usize],
+        len: usize,
+        direction: TruncationDirection,
+        text_range: Range<usize>,
+    ) -> Self {
+        let keep = match direction {
+            TruncationDirection::Left => tokens.len() - len..tokens.len(),
+            TruncationDirection::Right => 0..len,
+        };
+        SequencePart {
+            tokens: &tokens[keep.clone()],
+            offsets: &offsets[keep.clone()],
+            start_offset: if keep.start == 0 {
+                text_range.start
+            } else {
+                offsets[keep.start]
+            },
+            end_offset: offsets.get(keep.end).copied().unwrap_or(text_range.end),
+        }
     }
 }
 
+/// Combine encoded sequences with the special tokens in a post-processing
+/// template and return a `(tokens, offsets, type_ids)` tuple.
+///
+/// Special tokens are assigned the end offset of the preceding sequence, or
+/// the start offset of the following sequence if there is no preceding one.
+/// If the template ends with a sequence, an extra offset is added which marks
+/// the end of that sequence.
+fn apply_template(
+    template: &[TemplatePiece],
+    first: SequencePart,
+    second: Option<SequencePart>,
+) -> (Vec<TokenId>, Vec<usize>, Vec<u32>) {
+    let mut tokens = Vec::new();
+    let mut offsets = Vec::new();
+    let mut type_ids = Vec::new();
+
+    let get_part = |id: SequenceId| match id {
+        SequenceId::A => first,
+        SequenceId::B => second.expect("template should only use $B for pairs"),
+    };
+
+    let mut prev_end = None;
+    for (i, piece) in template.iter().enumerate() {
+        match piece {
+            TemplatePiece::Sequence { id, type_id } => {
+                let part = get_part(*id);
+                tokens.extend_from_slice(part.tokens);
+                offsets.extend_from_slice(part.offsets);
+                type_ids.extend(std::iter::repeat_n(*type_id, part.tokens.len()));
+                prev_end = Some(part.end_offset);
+            }
+            TemplatePiece::SpecialToken { ids, type_id } => {
+                let offset = prev_end.unwrap_or_else(|| {
+                    template[i..]
+                        .iter()
+                        .find_map(|piece| match piece {
+                            TemplatePiece::Sequence { id, .. } => Some(get_part(*id).start_offset),
+                            TemplatePiece::SpecialToken { .. } => None,
+                        })
+                        .unwrap_or(0)
+                });
+                for &id in ids {
+                    tokens.push(id);
+                    offsets.push(offset);
+                    type_ids.push(*type_id);
+                }
+            }
+        }
+    }
+
+    if let Some(TemplatePiece::Sequence { .. }) = template.last()
+        && let Some(end) = prev_end
+    {
+        offsets.push(end);
+    }
+
+    (tokens, offsets, type_ids)
+}
+
 /// Error type returned when tokenizing a string.
 #[derive(Clone, Debug)]
 pub enum TokenizerError {
@@ -789,6 +1801,25 @@ pub enum TokenizerError {
 
     /// Decoding token IDs into text failed.
     DecodeError(DecodeError),
+
+    /// Applying a decoder to the token strings failed.
+    DecoderError(DecoderError),
+
+    /// The input could not be truncated to the maximum length, because the
+    /// maximum length is too short for the special tokens or the sequence
+    /// selected for truncation is too short.
+    TruncationError,
+
+    /// Decoding a sequence of tokens with a new token appended produced text
+    /// which does not start with the text decoded from the original sequence.
+    ///
+    /// This is returned by [`StreamingDecoder`] if the tokenizer's decoder
+    /// changes earlier text in response to later tokens.
+    InvalidPrefix,
+
+    /// An [`EncodeOptions`] field was set which is not supported by the
+    /// encoding method that was called.
+    UnsupportedOption(&'static str),
 }
 
 impl fmt::Display for TokenizerError {
@@ -798,6 +1829,10 @@ impl fmt::Display for TokenizerError {
             Self::PreTokenizeError(err) => write!(f, "pretokenization error: {}", err),
             Self::EncodeError(err) => write!(f, "encoding with model failed: {}", err),
             Self::DecodeError(err) => write!(f, "decoding failed: {}", err),
+            Self::DecoderError(err) => write!(f, "decoder failed: {}", err),
+            Self::TruncationError => write!(f, "input could not be truncated to max length"),
+            Self::InvalidPrefix => write!(f, "decoded text does not extend previous output"),
+            Self::UnsupportedOption(name) => write!(f, "option \"{}\" is not supported", name),
         }
     }
 }
@@ -821,6 +1856,8 @@ impl Error for TokenizerError {
             Self::PreTokenizeError(e) => Some(e),
             Self::EncodeError(e) => Some(e),
             Self::DecodeError(e) => Some(e),
+            Self::DecoderError(e) => Some(e),
+            Self::TruncationError | Self::InvalidPrefix | Self::UnsupportedOption(_) => None,
         }
     }
 }
@@ -835,8 +1872,13 @@ mod tests {
 
     use rten_testing::TestCases;
 
-    use super::{EncodeOptions, EncoderInput, TokenId, Tokenizer, TokenizerOptions, WordPiece};
-    use crate::normalizers::Normalizer;
+    use super::{
+        AddedToken, EncodeOptions, EncoderInput, FromJsonError, Padding, PaddingDirection,
+        PaddingStrategy, ToJsonError, TokenId, Tokenizer, TokenizerError, TokenizerOptions,
+        Truncation, TruncationDirection, WordPiece,
+    };
+    use crate::normalizers::{NormalizeError, Normalizer};
+    use crate::post_processors::{SequenceId, TemplatePiece, TemplateProcessing};
     use crate::{normalizers, pre_tokenizers};
     use serde_derive::Deserialize;
 
@@ -889,6 +1931,218 @@ mod tests {
         assert_eq!(token_type_ids, &[0, 0, 0, 0, 1, 1, 1, 1]);
     }
 
+    #[test]
+    fn test_encode_with_post_processor() {
+        let vocab = &["<s>", "[UNK]", "This", "is", "a", "test"];
+        let model = make_wordpiece(vocab);
+        let bos = TemplatePiece::SpecialToken {
+            ids: vec![0],
+            type_id: 0,
+        };
+        let post_processor = TemplateProcessing::new(
+            vec![
+                bos.clone(),
+                TemplatePiece::Sequence {
+                    id: SequenceId::A,
+                    type_id: 0,
+                },
+            ],
+            vec![
+                bos.clone(),
+                TemplatePiece::Sequence {
+                    id: SequenceId::A,
+                    type_id: 0,
+                },
+                TemplatePiece::SpecialToken {
+                    ids: vec![0],
+                    type_id: 1,
+                },
+                TemplatePiece::Sequence {
+                    id: SequenceId::B,
+                    type_id: 1,
+                },
+            ],
+        )
+        .unwrap();
+        let tokenizer = Tokenizer::new(
+            model,
+            TokenizerOptions {
+                cls_token: Some("[CLS]"),
+                sep_token: Some("[SEP]"),
+            },
+        )
+        .with_pre_tokenizer(Box::new(pre_tokenizers::Bert::new()))
+        .with_post_processor(post_processor);
+
+        // Single sequence. The post-processor replaces the `[CLS]` and `[SEP]`
+        // tokens.
+        let encoded = tokenizer.encode("This is", None).unwrap();
+        assert_eq!(
+            tokenizer.model().get_tokens(encoded.token_ids()).unwrap(),
+            &["<s>", "This", "is"]
+        );
+        assert_eq!(encoded.token_offsets(), &[0, 0, 5, 7]);
+
+        // Sequence pair.
+        let encoded = tokenizer.encode(("This is", "a test"), None).unwrap();
+        assert_eq!(
+            tokenizer.model().get_tokens(encoded.token_ids()).unwrap(),
+            &["<s>", "This", "is", "<s>", "a", "test"]
+        );
+        assert_eq!(encoded.token_offsets(), &[0, 0, 5, 7, 7, 9, 13]);
+        let token_type_ids: Vec<_> = encoded.token_type_ids().collect();
+        assert_eq!(token_type_ids, &[0, 0, 0, 1, 1, 1]);
+    }
+
+    #[test]
+    fn test_encode_batch() {
+        #[derive(Debug)]
+        struct Case<'a> {
+            inputs: Vec<EncoderInput<'a>>,
+            truncation: Option<Truncation>,
+            padding: Option<Padding>,
+            expected_ids: Vec<i32>,
+            expected_mask: Vec<i32>,
+            expected_type_ids: Option<Vec<i32>>,
+        }
+
+        let vocab = &[
+            "[PAD]", "[CLS]", "[SEP]", "[UNK]", "This", "is", "a", "test", "sequence",
+        ];
+        let inputs: Vec<EncoderInput> = vec!["This is".into(), "This is a test sequence".into()];
+
+        let cases = [
+            // No truncation, default padding.
+            Case {
+                inputs: inputs.clone(),
+                truncation: None,
+                padding: None,
+                expected_ids: [[1, 4, 5, 2, 0, 0, 0], [1, 4, 5, 6, 7, 8, 2]].concat(),
+                expected_mask: [[1, 1, 1, 1, 0, 0, 0], [1; 7]].concat(),
+                expected_type_ids: None,
+            },
+            // Truncation from the right, left padding to a multiple of 4.
+            Case {
+                inputs: inputs.clone(),
+                truncation: Some(Truncation {
+                    max_length: 5,
+                    ..Default::default()
+                }),
+                padding: Some(Padding {
+                    direction: PaddingDirection::Left,
+                    pad_to_multiple_of: Some(4),
+                    ..Default::default()
+                }),
+                expected_ids: [[0, 0, 0, 0, 1, 4, 5, 2], [0, 0, 0, 1, 4, 5, 6, 2]].concat(),
+                expected_mask: [[0, 0, 0, 0, 1, 1, 1, 1], [0, 0, 0, 1, 1, 1, 1, 1]].concat(),
+                expected_type_ids: None,
+            },
+            // Truncation from the left, padding to a fixed length.
+            Case {
+                inputs: inputs.clone(),
+                truncation: Some(Truncation {
+                    max_length: 5,
+                    direction: TruncationDirection::Left,
+                    ..Default::default()
+                }),
+                padding: Some(Padding {
+                    strategy: PaddingStrategy::Fixed(6),
+                    pad_type_id: 1,
+                    ..Default::default()
+                }),
+                expected_ids: [[1, 4, 5, 2, 0, 0], [1, 6, 7, 8, 2, 0]].concat(),
+                expected_mask: [[1, 1, 1, 1, 0, 0], [1, 1, 1, 1, 1, 0]].concat(),
+                expected_type_ids: Some([[0, 0, 0, 0, 1, 1], [0, 0, 0, 0, 0, 1]].concat()),
+            },
+            // Sequence pairs truncated using the longest-first strategy.
+            Case {
+                inputs: vec![("This is", "a test sequence").into(), ("is", "a").into()],
+                truncation: Some(Truncation {
+                    max_length: 6,
+                    ..Default::default()
+                }),
+                padding: None,
+                expected_ids: [[1, 4, 2, 6, 7, 2], [1, 5, 2, 6, 2, 0]].concat(),
+                expected_mask: [[1, 1, 1, 1, 1, 1], [1, 1, 1, 1, 1, 0]].concat(),
+                expected_type_ids: Some([[0, 0, 0, 1, 1, 1], [0, 0, 0, 1, 1, 0]].concat()),
+            },
+        ];
+
+        cases.test_each(|case| {
+            let mut tokenizer = Tokenizer::new(
+                make_wordpiece(vocab),
+                TokenizerOptions {
+                    cls_token: Some("[CLS]"),
+                    sep_token: Some("[SEP]"),
+                },
+            )
+            .with_pre_tokenizer(Box::new(pre_tokenizers::Bert::new()));
+            if let Some(truncation) = case.truncation.clone() {
+                tokenizer = tokenizer.with_truncation(truncation);
+            }
+            if let Some(padding) = case.padding.clone() {
+                tokenizer = tokenizer.with_padding(padding);
+            }
+            let batch = tokenizer.encode_batch(&case.inputs, None).unwrap();
+
+            let seq_len = case.expected_ids.len() / case.inputs.len();
+            assert_eq!(batch.shape(), [case.inputs.len(), seq_len]);
+            assert_eq!(batch.input_ids(), case.expected_ids);
+            assert_eq!(batch.attention_mask(), case.expected_mask);
+            if let Some(type_ids) = &case.expected_type_ids {
+                assert_eq!(batch.token_type_ids(), type_ids);
+            }
+            assert_eq!(batch.encodings().len(), case.inputs.len());
+        })
+    }
+
+    #[test]
+    fn test_encode_batch_truncation_error() {
+        let vocab = &["[CLS]", "[SEP]", "[UNK]", "This", "is"];
+        let tokenizer = Tokenizer::new(
+            make_wordpiece(vocab),
+            TokenizerOptions {
+                cls_token: Some("[CLS]"),
+                sep_token: Some("[SEP]"),
+            },
+        )
+        .with_pre_tokenizer(Box::new(pre_tokenizers::Bert::new()))
+        .with_truncation(Truncation {
+            max_length: 2,
+            ..Default::default()
+        });
+
+        let result = tokenizer.encode_batch(&[("This", "is").into()], None);
+        assert!(matches!(result, Err(TokenizerError::TruncationError)));
+    }
+
+    #[test]
+    fn test_encode_batch_chunk_options() {
+        let vocab = &["[CLS]", "[SEP]", "[UNK]", "This", "is"];
+        let tokenizer = Tokenizer::new(make_wordpiece(vocab), TokenizerOptions::default())
+            .with_pre_tokenizer(Box::new(pre_tokenizers::Bert::new()));
+
+        let options = EncodeOptions {
+            max_chunk_len: Some(4),
+            ..Default::default()
+        };
+        let result = tokenizer.encode_batch(&["This is".into()], Some(options));
+        assert!(matches!(
+            result,
+            Err(TokenizerError::UnsupportedOption("max_chunk_len"))
+        ));
+
+        let options = EncodeOptions {
+            overlap: 1,
+            ..Default::default()
+        };
+        let result = tokenizer.encode_batch(&["This is".into()], Some(options));
+        assert!(matches!(
+            result,
+            Err(TokenizerError::UnsupportedOption("overlap"))
+        ));
+    }
+
     #[test]
     fn test_text_for_token_range() {
         #[derive(Debug)]
@@ -1109,6 +2363,7 @@ mod tests {
             let options = EncodeOptions {
                 max_chunk_len: *max_chunk_len,
                 overlap: *overlap,
+                ..Default::default()
             };
             let chunks = tokenizer.encode_chunks((*text).into(), options).unwrap();
             let chunk_tokens: Vec<_> = chunks
@@ -1339,10 +2594,94 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_added_tokens() {
+        #[derive(Debug)]
+        struct Case<'a> {
+            text: &'a str,
+            split_special_tokens: bool,
+            expected_ids: &'a [TokenId],
+            expected_offsets: &'a [usize],
+        }
+
+        let vocab = &[
+            "[CLS]", "[SEP]", "[UNK]", "hello", "world", "<", "|", ">", "im", "_", "start",
+        ];
+        let added_tokens = vec![
+            AddedToken {
+                content: "<|im_start|>".into(),
+                id: 100,
+                special: true,
+                ..Default::default()
+            },
+            AddedToken {
+                content: "greetings".into(),
+                id: 101,
+                normalized: true,
+                ..Default::default()
+            },
+        ];
+        let make_tokenizer = || {
+            Tokenizer::new(make_wordpiece(vocab), Default::default())
+                .with_normalizer(lowercase_normalizer())
+                .with_pre_tokenizer(Box::new(pre_tokenizers::Bert::new()))
+                .with_added_tokens(added_tokens.clone())
+        };
+
+        let cases = [
+            Case {
+                text: "<|im_start|>hello world",
+                split_special_tokens: false,
+                expected_ids: &[100, 3, 4],
+                expected_offsets: &[0, 12, 18, 23],
+            },
+            Case {
+                text: "<|im_start|>hello",
+                split_special_tokens: true,
+                expected_ids: &[5, 6, 8, 9, 10, 6, 7, 3],
+                expected_offsets: &[0, 1, 2, 4, 5, 10, 11, 12, 17],
+            },
+            // Normalized added token.
+            Case {
+                text: "Hello GREETINGS",
+                split_special_tokens: false,
+                expected_ids: &[3, 101],
+                expected_offsets: &[0, 6, 15],
+            },
+        ];
+
+        cases.test_each(|case| {
+            let tokenizer = make_tokenizer();
+            let options = EncodeOptions {
+                split_special_tokens: case.split_special_tokens,
+                ..Default::default()
+            };
+            let encoded = tokenizer.encode(case.text, Some(options)).unwrap();
+            assert_eq!(encoded.token_ids(), case.expected_ids);
+            assert_eq!(encoded.token_offsets(), case.expected_offsets);
+        });
+
+        let tokenizer = make_tokenizer();
+        assert_eq!(tokenizer.get_token_id("<|im_start|>").unwrap(), 100);
+    }
+
     #[derive(Deserialize)]
     struct TokenizerJsonCase {
         text: String,
+
+        /// Second sequence for tests with sequence pair inputs.
+        text_pair: Option<String>,
+
         token_ids: Vec<TokenId>,
+
+        /// Expected token type IDs.
+        token_type_ids: Option<Vec<usize>>,
+
+        /// Expected start offsets of tokens.
+        token_offsets: Option<Vec<usize>>,
+
+        /// Expected result of decoding `token_ids`.
+        decoded: Option<String>,
     }
 
     #[derive(Deserialize)]
@@ -1352,6 +2691,17 @@ mod tests {
         cases: Vec<TokenizerJsonCase>,
     }
 
+    /// Test case for batch encoding with the truncation and padding settings
+    /// from a `tokenizer.json` file.
+    #[derive(Deserialize)]
+    struct BatchJsonTest<'a> {
+        #[serde(borrow)]
+        tokenizer: super::json::Tokenizer<'a>,
+        inputs: Vec<String>,
+        input_ids: Vec<Vec<i32>>,
+        attention_mask: Vec<Vec<i32>>,
+    }
+
     fn read_test_json(path: &str) -> Result<String, Box<dyn Error>> {
         let mut abs_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
         abs_path.push("test-data/tokenizer-json/");
@@ -1362,17 +2712,176 @@ mod tests {
 
     #[test]
     fn test_from_json() {
-        let paths = ["wordpiece.json", "wordpiece-lower.json"];
+        // The `synthetic-*` files are cut-down configs in the style of the
+        // named models, with small vocabularies. They are not the models' real
+        // `tokenizer.json` files. Those are compared against the `tokenizers`
+        // package by the ignored tests in `tests/reftest.rs`.
+        let paths = [
+            "bert-processing.json",
+            "bpe-byte-fallback.json",
+            "metaspace.json",
+            "pre-tokenizer-sequence.json",
+            "roberta-processing.json",
+            "sentencepiece-normalizers.json",
+            "synthetic-bge-small-en.json",
+            "synthetic-llama3.json",
+            "synthetic-roberta-base.json",
+            "template-processing.json",
+            "unigram.json",
+            "whitespace.json",
+            "wordpiece.json",
+            "wordpiece-lower.json",
+        ];
 
         for path in paths.iter() {
             let json = read_test_json(path).unwrap();
             let config: TokenizerJsonTest = serde_json::from_str(&json).unwrap();
 
             let tokenizer = Tokenizer::from_parsed_json(config.tokenizer).unwrap();
-            for case in config.cases {
-                let encoded = tokenizer.encode(case.text.as_str(), None).unwrap();
-                assert_eq!(encoded.token_ids(), case.token_ids);
+
+            // Check that the tokenizer produces the same results after a
+            // round trip through `to_json`.
+            let json = tokenizer.to_json().unwrap();
+            let reloaded = Tokenizer::from_json(&json).unwrap();
+            assert_eq!(reloaded.to_json().unwrap(), json, "{}", path);
+
+            for tokenizer in [&tokenizer, &reloaded] {
+                for case in &config.cases {
+                    let input: EncoderInput = match &case.text_pair {
+                        Some(text_pair) => (case.text.as_str(), text_pair.as_str()).into(),
+                        None => case.text.as_str().into(),
+                    };
+                    let encoded = tokenizer.encode(input, None).expect(path);
+                    assert_eq!(encoded.token_ids(), case.token_ids);
+
+                    if let Some(expected) = &case.token_type_ids {
+                        let type_ids: Vec<_> = encoded.token_type_ids().collect();
+                        assert_eq!(&type_ids, expected);
+                    }
+
+                    if let Some(expected) = &case.token_offsets {
+                        assert_eq!(encoded.token_offsets(), expected, "{}", path);
+                    }
+
+                    if let Some(expected) = &case.decoded {
+                        let decoded = tokenizer.decode(&case.token_ids).unwrap();
+                        assert_eq!(&decoded, expected);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_json_decoders() {
+        let tokenizer_json = |vocab: &str, decoder: &str| {
+            format!(
+                r#"{{ "model": {{ "type": "WordPiece", "vocab": {} }}, "decoder": {} }}"#,
+                vocab, decoder
+            )
+        };
+
+        // BPE decoder which replaces end-of-word suffixes.
+        let json = tokenizer_json(
+            r#"{ "hel": 0, "lo</w>": 1, "world</w>": 2 }"#,
+            r#"{ "type": "BPEDecoder", "suffix": "</w>" }"#,
+        );
+        let tokenizer = Tokenizer::from_json(&json).unwrap();
+        assert_eq!(tokenizer.decode(&[0, 1, 2]).unwrap(), "hello world");
+
+        // CTC decoder nested in a sequence.
+        let json = tokenizer_json(
+            r#"{ "<pad>": 0, "|": 1, "A": 2, "B": 3 }"#,
+            r#"{ "type": "Sequence", "decoders": [{ "type": "CTC" }, { "type": "Fuse" }] }"#,
+        );
+        let tokenizer = Tokenizer::from_json(&json).unwrap();
+        assert_eq!(tokenizer.decode(&[2, 2, 0, 2, 1, 3, 3, 0]).unwrap(), "AA B");
+
+        // Unknown decoders are rejected, either at the top level or in a
+        // sequence.
+        for decoder in [
+            r#"{ "type": "Unknown" }"#,
+            r#"{ "type": "Sequence", "decoders": [{ "type": "Fuse" }, { "type": "Unknown" }] }"#,
+        ] {
+            let json = tokenizer_json(r#"{ "foo": 0 }"#, decoder);
+            let err = Tokenizer::from_json(&json).err().unwrap();
+            assert!(matches!(err, FromJsonError::JsonError(_)));
+            assert!(
+                err.to_string().contains("unknown variant `Unknown`"),
+                "{}",
+                err
+            );
+        }
+    }
+
+    #[test]
+    fn test_to_json_post_processor_type() {
+        let paths = [
+            ("bert-processing.json", "BertProcessing"),
+            ("roberta-processing.json", "RobertaProcessing"),
+            ("template-processing.json", "TemplateProcessing"),
+        ];
+
+        for (path, expected_type) in paths {
+            let json = read_test_json(path).unwrap();
+            let config: TokenizerJsonTest = serde_json::from_str(&json).unwrap();
+            let tokenizer = Tokenizer::from_parsed_json(config.tokenizer).unwrap();
+
+            let json: serde_json::Value =
+                serde_json::from_str(&tokenizer.to_json().unwrap()).unwrap();
+            assert_eq!(json["post_processor"]["type"], expected_type, "{}", path);
+        }
+    }
+
+    #[test]
+    fn test_to_json_unknown_pad_token() {
+        let vocab = &["[CLS]", "[SEP]", "[UNK]", "foo"];
+        let tokenizer =
+            Tokenizer::new(make_wordpiece(vocab), Default::default()).with_padding(Padding {
+                pad_id: 10,
+                ..Default::default()
+            });
+
+        let err = tokenizer.to_json().err().unwrap();
+        assert!(matches!(err, ToJsonError::UnknownTokenId(10)));
+        assert_eq!(err.to_string(), "token ID 10 is not in the vocabulary");
+    }
+
+    #[test]
+    fn test_to_json_unsupported() {
+        #[derive(Debug)]
+        struct CustomNormalizer;
+
+        impl Normalizer for CustomNormalizer {
+            fn normalize(&self, text: &str) -> Result<(String, Vec<usize>), NormalizeError> {
+                Ok((text.to_string(), (0..text.len()).collect()))
             }
         }
+
+        let vocab = &["[CLS]", "[SEP]", "[UNK]", "foo"];
+        let tokenizer = Tokenizer::new(make_wordpiece(vocab), Default::default())
+            .with_normalizer(Box::new(CustomNormalizer));
+
+        let err = tokenizer.to_json().err().unwrap();
+        assert!(matches!(err, ToJsonError::Unsupported("normalizer")));
+        assert_eq!(err.to_string(), "normalizer cannot be serialized");
+    }
+
+    #[test]
+    fn test_encode_batch_from_json() {
+        let json = read_test_json("padding-truncation.json").unwrap();
+        let config: BatchJsonTest = serde_json::from_str(&json).unwrap();
+
+        let tokenizer = Tokenizer::from_parsed_json(config.tokenizer).unwrap();
+        let reloaded = Tokenizer::from_json(&tokenizer.to_json().unwrap()).unwrap();
+        assert_eq!(reloaded.truncation(), tokenizer.truncation());
+        assert_eq!(reloaded.padding(), tokenizer.padding());
+
+        let inputs: Vec<EncoderInput> = config.inputs.iter().map(|s| s.as_str().into()).collect();
+        for tokenizer in [&tokenizer, &reloaded] {
+            let batch = tokenizer.encode_batch(&inputs, None).unwrap();
+            assert_eq!(batch.input_ids(), config.input_ids.concat());
+            assert_eq!(batch.attention_mask(), config.attention_mask.concat());
+        }
     }
 }
@@ -0,0 +1,269 @@
+//! Matching of added tokens in input text.
+
+use std::ops::Range;
+
+use crate::tokenizer::TokenId;
+
+/// A token which is matched in the input text before the normal tokenization
+/// pipeline is applied.
+///
+/// Added tokens include special tokens such as `<|im_start|>` or `[MASK]`
+/// which are used to structure model inputs, as well as ordinary tokens that
+/// were added to the vocabulary after the model's tokenizer was trained.
+/// Added tokens are never split into smaller tokens.
+///
+/// See <https://huggingface.co/docs/tokenizers/en/api/added-tokens>.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AddedToken {
+    /// Text of the token.
+    pub content: String,
+
+    /// ID of the token.
+    pub id: TokenId,
+
+    /// Whether this is a special token. Special tokens can be excluded from
+    /// matching using [`EncodeOptions::split_special_tokens`](super::EncodeOptions::split_special_tokens).
+    pub special: bool,
+
+    /// Whether to consume whitespace to the left of the token.
+    pub lstrip: bool,
+
+    /// Whether to consume whitespace to the right of the token.
+    pub rstrip: bool,
+
+    /// Only match the token if it is not part of a larger word.
+    pub single_word: bool,
+
+    /// Whether to match the token against the normalized text rather than
+    /// the original text.
+    pub normalized: bool,
+}
+
+/// A span of text produced by [`AddedTokenMatcher::split`].
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Segment {
+    /// Text which should be encoded using the normal tokenization pipeline.
+    Text(Range<usize>),
+
+    /// A matched added token, with the byte offset where the token starts.
+    Token { start: usize, id: TokenId },
+}
+
+/// Finds occurrences of added tokens in text.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct AddedTokenMatcher {
+    /// Tokens to match, ordered by decreasing length so that the longest
+    /// matching token is preferred.
+    tokens: Vec<AddedToken>,
+
+    /// Flags indicating whether a token starts with a given byte. This is
+    /// used to quickly skip positions where no token can match.
+    first_bytes: Vec<bool>,
+}
+
+/// Return true if `ch` is considered part of a word for the purposes of
+/// [`AddedToken::single_word`].
+fn is_word_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_'
+}
+
+impl AddedTokenMatcher {
+    pub fn new(tokens: impl IntoIterator<Item = AddedToken>) -> Self {
+        let mut tokens: Vec<_> = tokens
+            .into_iter()
+            .filter(|token| !token.content.is_empty())
+            .collect();
+        tokens.sort_by_key(|token| std::cmp::Reverse(token.content.len()));
+
+        let mut first_bytes = vec![false; 256];
+        for token in &tokens {
+            first_bytes[token.content.as_bytes()[0] as usize] = true;
+        }
+
+        AddedTokenMatcher {
+            tokens,
+            first_bytes,
+        }
+    }
+
+    /// Return the tokens that this matcher looks for.
+    pub fn tokens(&self) -> &[AddedToken] {
+        &self.tokens
+    }
+
+    /// Find the longest token which matches `text` at byte offset `pos`.
+    fn match_at(&self, text: &str, pos: usize, split_special_tokens: bool) -> Option<&AddedToken> {
+        if !self.first_bytes[text.as_bytes()[pos] as usize] {
+            return None;
+        }
+
+        let rest = &text[pos..];
+        self.tokens.iter().find(|token| {
+            if split_special_tokens && token.special {
+                return false;
+            }
+            if !rest.starts_with(&token.content) {
+                return false;
+            }
+            if token.single_word {
+                let end = pos + token.content.len();
+                let prev_is_word = text[..pos].chars().next_back().is_some_and(is_word_char);
+                let next_is_word = text[end..].chars().next().is_some_and(is_word_char);
+                if prev_is_word || next_is_word {
+                    return false;
+                }
+            }
+            true
+        })
+    }
+
+    /// Split `text` into segments of ordinary text and added tokens.
+    ///
+    /// If `split_special_tokens` is true, tokens marked as special are not
+    /// matched.
+    pub fn split(&self, text: &str, split_special_tokens: bool) -> Vec<Segment> {
+        let mut segments = Vec::new();
+        if self.tokens.is_empty() {
+            if !text.is_empty() {
+                segments.push(Segment::Text(0..text.len()));
+            }
+            return segments;
+        }
+
+        let mut text_start = 0;
+        let mut pos = 0;
+        while pos < text.len() {
+            let Some(token) = self.match_at(text, pos, split_special_tokens) else {
+                pos += text[pos..].chars().next().unwrap().len_utf8();
+                continue;
+            };
+            let token_end = pos + token.content.len();
+
+            let text_end = if token.lstrip {
+                text_start + text[text_start..pos].trim_end().len()
+            } else {
+                pos
+            };
+            if text_end > text_start {
+                segments.push(Segment::Text(text_start..text_end));
+            }
+            segments.push(Segment::Token {
+                start: pos,
+                id: token.id,
+            });
+
+            pos = if token.rstrip {
+                text.len() - text[token_end..].trim_start().len()
+            } else {
+                token_end
+            };
+            text_start = pos;
+        }
+
+        if text_start < text.len() {
+            segments.push(Segment::Text(text_start..text.len()));
+        }
+
+        segments
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rten_testing::TestCases;
+
+    use super::{AddedToken, AddedTokenMatcher, Segment};
+
+    #[test]
+    fn test_split() {
+        #[derive(Debug)]
+        struct Case<'a> {
+            text: &'a str,
+            split_special_tokens: bool,
+            expected: Vec<Segment>,
+        }
+
+        let tokens = [
+            AddedToken {
+                content: "<|im_start|>".into(),
+                id: 1,
+                special: true,
+                ..Default::default()
+            },
+            AddedToken {
+                content: "<|im".into(),
+                id: 2,
+                ..Default::default()
+            },
+            AddedToken {
+                content: "[MASK]".into(),
+                id: 3,
+                lstrip: true,
+                rstrip: true,
+                ..Default::default()
+            },
+            AddedToken {
+                content: "foo".into(),
+                id: 4,
+                single_word: true,
+                ..Default::default()
+            },
+        ];
+        let matcher = AddedTokenMatcher::new(tokens);
+
+        let cases = [
+            // No added tokens.
+            Case {
+                text: "hello world",
+                split_special_tokens: false,
+                expected: [Segment::Text(0..11)].into(),
+            },
+            // Special token, with the longest match preferred.
+            Case {
+                text: "<|im_start|>user",
+                split_special_tokens: false,
+                expected: [Segment::Token { start: 0, id: 1 }, Segment::Text(12..16)].into(),
+            },
+            // Special tokens disabled.
+            Case {
+                text: "<|im_start|>user",
+                split_special_tokens: true,
+                expected: [Segment::Token { start: 0, id: 2 }, Segment::Text(4..16)].into(),
+            },
+            // Whitespace stripping.
+            Case {
+                text: "a  [MASK] b",
+                split_special_tokens: false,
+                expected: [
+                    Segment::Text(0..1),
+                    Segment::Token { start: 3, id: 3 },
+                    Segment::Text(10..11),
+                ]
+                .into(),
+            },
+            // Single word matching.
+            Case {
+                text: "foo foobar foo_ (foo)",
+                split_special_tokens: false,
+                expected: [
+                    Segment::Token { start: 0, id: 4 },
+                    Segment::Text(3..17),
+                    Segment::Token { start: 17, id: 4 },
+                    Segment::Text(20..21),
+                ]
+                .into(),
+            },
+            // Empty text.
+            Case {
+                text: "",
+                split_special_tokens: false,
+                expected: [].into(),
+            },
+        ];
+
+        cases.test_each(|case| {
+            let segments = matcher.split(case.text, case.split_special_tokens);
+            assert_eq!(segments, case.expected);
+        })
+    }
+}
@@ -0,0 +1,348 @@
+//! Truncation and padding of encoded sequences for batch encoding.
+
+use super::{Encoded, TokenId, TokenizerError};
+
+/// Specifies which sequence(s) to remove tokens from when an input exceeds
+/// the maximum length.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum TruncationStrategy {
+    /// Remove tokens from the longest sequence until the input fits, or from
+    /// both sequences if they are equally long.
+    #[default]
+    LongestFirst,
+
+    /// Remove tokens only from the first sequence.
+    OnlyFirst,
+
+    /// Remove tokens only from the second sequence.
+    OnlySecond,
+}
+
+/// Specifies which end of a sequence tokens are removed from when truncating.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum TruncationDirection {
+    /// Remove tokens from the start of the sequence.
+    Left,
+
+    /// Remove tokens from the end of the sequence.
+    #[default]
+    Right,
+}
+
+/// Configuration for truncating inputs in [`Tokenizer::encode_batch`](super::Tokenizer::encode_batch).
+///
+/// See <https://huggingface.co/docs/tokenizers/en/api/tokenizer#tokenizers.Tokenizer.enable_truncation>.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Truncation {
+    /// Maximum number of tokens in the output, including special tokens added
+    /// by the post-processor.
+    pub max_length: usize,
+
+    /// Which sequence(s) to remove tokens from.
+    pub strategy: TruncationStrategy,
+
+    /// Which end of each sequence to remove tokens from.
+    pub direction: TruncationDirection,
+}
+
+impl Default for Truncation {
+    fn default() -> Self {
+        Truncation {
+            max_length: 512,
+            strategy: TruncationStrategy::default(),
+            direction: TruncationDirection::default(),
+        }
+    }
+}
+
+impl Truncation {
+    /// Return the number of tokens to keep from sequences of length `first`
+    /// and `second` so that the total is at most `max_len`.
+    ///
+    /// `max_len` excludes special tokens.
+    pub(crate) fn truncated_lengths(
+        &self,
+        first: usize,
+        second: Option<usize>,
+        max_len: usize,
+    ) -> Result<(usize, usize), TokenizerError> {
+        let second_len = second.unwrap_or(0);
+        if first + second_len <= max_len {
+            return Ok((first, second_len));
+        }
+        let to_remove = first + second_len - max_len;
+
+        match (self.strategy, second) {
+            (TruncationStrategy::LongestFirst, None) | (TruncationStrategy::OnlyFirst, None) => {
+                Ok((max_len, 0))
+            }
+            (TruncationStrategy::OnlySecond, None) => Err(TokenizerError::TruncationError),
+            (TruncationStrategy::LongestFirst, Some(second)) => {
+                // Truncate only the longer sequence if possible, otherwise
+                // split the space equally.
+                let shorter = first.min(second);
+                let (shorter, longer) = if shorter > max_len / 2 {
+                    (max_len / 2, max_len / 2 + max_len % 2)
+                } else {
+                    (shorter, max_len - shorter)
+                };
+                if first > second {
+                    Ok((longer, shorter))
+                } else {
+                    Ok((shorter, longer))
+                }
+            }
+            (TruncationStrategy::OnlyFirst, Some(second)) => {
+                if first > to_remove {
+                    Ok((first - to_remove, second))
+                } else {
+                    Err(TokenizerError::TruncationError)
+                }
+            }
+            (TruncationStrategy::OnlySecond, Some(second)) => {
+                if second > to_remove {
+                    Ok((first, second - to_remove))
+                } else {
+                    Err(TokenizerError::TruncationError)
+                }
+            }
+        }
+    }
+}
+
+/// Specifies the length that sequences in a batch are padded to.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum PaddingStrategy {
+    /// Pad to the length of the longest sequence in the batch.
+    #[default]
+    BatchLongest,
+
+    /// Pad to a fixed length. Sequences which are longer than this are not
+    /// truncated. Use [`Truncation`] to limit the length of sequences.
+    Fixed(usize),
+}
+
+/// Specifies which end of a sequence padding is added to.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum PaddingDirection {
+    /// Add padding before the tokens.
+    Left,
+
+    /// Add padding after the tokens.
+    #[default]
+    Right,
+}
+
+/// Configuration for padding sequences in [`Tokenizer::encode_batch`](super::Tokenizer::encode_batch).
+///
+/// See <https://huggingface.co/docs/tokenizers/en/api/tokenizer#tokenizers.Tokenizer.enable_padding>.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Padding {
+    /// Length that sequences are padded to.
+    pub strategy: PaddingStrategy,
+
+    /// Which end of each sequence padding is added to.
+    pub direction: PaddingDirection,
+
+    /// If set, round the padded length up to a multiple of this value.
+    pub pad_to_multiple_of: Option<usize>,
+
+    /// Token ID used for padding.
+    pub pad_id: TokenId,
+
+    /// Token type ID used for padding.
+    pub pad_type_id: u32,
+}
+
+/// Output of [`Tokenizer::encode_batch`](super::Tokenizer::encode_batch).
+///
+/// This contains the padded `input_ids`, `attention_mask` and
+/// `token_type_ids` inputs for a model, each laid out as a row-major matrix
+/// with shape [`shape`](Self::shape). For example to create an input for
+/// an RTen model:
+///
+/// ```text
+/// let input_ids = NdTensor::from_data(batch.shape(), batch.input_ids().to_vec());
+/// ```
+#[derive(Debug)]
+pub struct BatchEncoding<'a> {
+    encodings: Vec<Encoded<'a>>,
+    seq_len: usize,
+    input_ids: Vec<i32>,
+    attention_mask: Vec<i32>,
+    token_type_ids: Vec<i32>,
+}
+
+impl<'a> BatchEncoding<'a> {
+    /// Pad the token IDs and type IDs from `encodings` to the same length.
+    pub(crate) fn new(encodings: Vec<Encoded<'a>>, padding: &Padding) -> Self {
+        let longest = encodings
+            .iter()
+            .map(|enc| enc.token_ids().len())
+            .max()
+            .unwrap_or(0);
+        let mut seq_len = match padding.strategy {
+            PaddingStrategy::BatchLongest => longest,
+            PaddingStrategy::Fixed(len) => len.max(longest),
+        };
+        if let Some(multiple) = padding.pad_to_multiple_of.filter(|&m| m > 0) {
+            seq_len = seq_len.next_multiple_of(multiple);
+        }
+
+        let size = encodings.len() * seq_len;
+        let mut input_ids = Vec::with_capacity(size);
+        let mut attention_mask = Vec::with_capacity(size);
+        let mut token_type_ids = Vec::with_capacity(size);
+
+        for enc in &encodings {
+            let n_tokens = enc.token_ids().len();
+            let n_pad = seq_len - n_tokens;
+            let pad_left = padding.direction == PaddingDirection::Left;
+
+            let add_padding = |input_ids: &mut Vec<i32>,
+                               attention_mask: &mut Vec<i32>,
+                               token_type_ids: &mut Vec<i32>| {
+                input_ids.extend(std::iter::repeat_n(padding.pad_id as i32, n_pad));
+                attention_mask.extend(std::iter::repeat_n(0, n_pad));
+                token_type_ids.extend(std::iter::repeat_n(padding.pad_type_id as i32, n_pad));
+            };
+
+            if pad_left {
+                add_padding(&mut input_ids, &mut attention_mask, &mut token_type_ids);
+            }
+            input_ids.extend(enc.token_ids().iter().map(|&id| id as i32));
+            attention_mask.extend(std::iter::repeat_n(1, n_tokens));
+            token_type_ids.extend(enc.token_type_ids().map(|id| id as i32));
+            if !pad_left {
+                add_padding(&mut input_ids, &mut attention_mask, &mut token_type_ids);
+            }
+        }
+
+        BatchEncoding {
+            encodings,
+            seq_len,
+            input_ids,
+            attention_mask,
+            token_type_ids,
+        }
+    }
+
+    /// Return the `[batch, sequence]` shape of the model inputs.
+    pub fn shape(&self) -> [usize; 2] {
+        [self.encodings.len(), self.seq_len]
+    }
+
+    /// Return the padded token IDs.
+    pub fn input_ids(&self) -> &[i32] {
+        &self.input_ids
+    }
+
+    /// Return the attention mask, which is 1 for tokens and 0 for padding.
+    pub fn attention_mask(&self) -> &[i32] {
+        &self.attention_mask
+    }
+
+    /// Return the padded token type IDs.
+    pub fn token_type_ids(&self) -> &[i32] {
+        &self.token_type_ids
+    }
+
+    /// Return the truncated, but not padded, encoding of each input.
+    ///
+    /// These can be used to map tokens back to the input text. If padding
+    /// was added to the left of sequences, token indices in the encodings are
+    /// offset from positions in the model inputs by the amount of padding.
+    pub fn encodings(&self) -> &[Encoded<'a>] {
+        &self.encodings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rten_testing::TestCases;
+
+    use super::{Truncation, TruncationStrategy};
+
+    #[test]
+    fn test_truncated_lengths() {
+        #[derive(Debug)]
+        struct Case {
+            strategy: TruncationStrategy,
+            lengths: (usize, Option<usize>),
+            max_len: usize,
+            expected: Option<(usize, usize)>,
+        }
+
+        let cases = [
+            // No truncation needed.
+            Case {
+                strategy: TruncationStrategy::LongestFirst,
+                lengths: (3, Some(4)),
+                max_len: 7,
+                expected: Some((3, 4)),
+            },
+            // Single sequence.
+            Case {
+                strategy: TruncationStrategy::LongestFirst,
+                lengths: (10, None),
+                max_len: 7,
+                expected: Some((7, 0)),
+            },
+            Case {
+                strategy: TruncationStrategy::OnlySecond,
+                lengths: (10, None),
+                max_len: 7,
+                expected: None,
+            },
+            // Longest first, where only the longer sequence is truncated.
+            Case {
+                strategy: TruncationStrategy::LongestFirst,
+                lengths: (2, Some(10)),
+                max_len: 7,
+                expected: Some((2, 5)),
+            },
+            Case {
+                strategy: TruncationStrategy::LongestFirst,
+                lengths: (10, Some(2)),
+                max_len: 7,
+                expected: Some((5, 2)),
+            },
+            // Longest first, where both sequences are truncated.
+            Case {
+                strategy: TruncationStrategy::LongestFirst,
+                lengths: (6, Some(10)),
+                max_len: 7,
+                expected: Some((3, 4)),
+            },
+            Case {
+                strategy: TruncationStrategy::OnlyFirst,
+                lengths: (6, Some(3)),
+                max_len: 7,
+                expected: Some((4, 3)),
+            },
+            Case {
+                strategy: TruncationStrategy::OnlyFirst,
+                lengths: (2, Some(10)),
+                max_len: 7,
+                expected: None,
+            },
+            Case {
+                strategy: TruncationStrategy::OnlySecond,
+                lengths: (2, Some(10)),
+                max_len: 7,
+                expected: Some((2, 5)),
+            },
+        ];
+
+        cases.test_each(|case| {
+            let truncation = Truncation {
+                strategy: case.strategy,
+                ..Default::default()
+            };
+            let lengths = truncation
+                .truncated_lengths(case.lengths.0, case.lengths.1, case.max_len)
+                .ok();
+            assert_eq!(lengths, case.expected);
+        })
+    }
+}
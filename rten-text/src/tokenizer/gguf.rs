@@ -0,0 +1,454 @@
+//! Loading of tokenizers from GGUF files.
+//!
+//! GGUF files used by llama.cpp store the tokenizer vocabulary and
+//! configuration as `tokenizer.ggml.*` metadata entries. Two kinds of
+//! tokenizer are supported, identified by `tokenizer.ggml.model`:
+//!
+//! - "llama" - SentencePiece-style BPE tokenizers where merges are derived
+//!   from token scores.
+//! - "gpt2" - Byte-level BPE tokenizers with an explicit merge list.
+
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use rten_gguf::{GgufError, GgufFile, Value};
+
+use super::sentencepiece::{
+    self, FromSentencePieceError, ModelProto, ModelType, NormalizerSpec, PieceType, SentencePiece,
+    TrainerSpec,
+};
+use super::{AddedToken, Tokenizer, TokenizerOptions};
+use crate::decoders;
+use crate::models::{Bpe, BpeError, BpeOptions, merge_pairs_from_lines};
+use crate::post_processors::{SequenceId, TemplatePiece, TemplateProcessing};
+use crate::pre_tokenizers::{
+    CL100K_REGEX, GPT2_REGEX, PreTokenizeError, Split, SplitDelimiterBehavior, SplitOptions,
+};
+use crate::tokenizer::TokenId;
+
+/// Tokenization regex used by Qwen2 models.
+///
+/// This is the same as [`CL100K_REGEX`] except that digits are split
+/// individually.
+const QWEN2_REGEX: &str = r"(?i:'s|'t|'re|'ve|'m|'ll|'d)|[^\r\n\p{L}\p{N}]?\p{L}+|\p{N}| ?[^\s\p{L}\p{N}]+[\r\n]*|\s*[\r\n]+|\s+(?!\S)|\s+";
+
+/// Errors returned by [`Tokenizer::from_gguf`].
+#[derive(Debug)]
+pub enum FromGgufError {
+    /// There was an error reading the file.
+    IoError(std::io::Error),
+    /// The file is not a valid GGUF file.
+    GgufError(GgufError),
+    /// A required metadata entry is missing or has the wrong type.
+    MissingMetadata(&'static str),
+    /// The tokenizer type specified by `tokenizer.ggml.model` is not
+    /// supported.
+    UnsupportedModel(String),
+    /// There was an error constructing a tokenizer for a "llama" model.
+    SentencePieceError(FromSentencePieceError),
+    /// The split pattern is invalid.
+    PreTokenizeError(PreTokenizeError),
+    /// There was an error constructing the BPE model.
+    BpeError(BpeError),
+}
+
+impl fmt::Display for FromGgufError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IoError(err) => fmt::Display::fmt(err, f),
+            Self::GgufError(err) => write!(f, "failed to parse GGUF file: {}", err),
+            Self::MissingMetadata(key) => write!(f, "missing or invalid metadata \"{}\"", key),
+            Self::UnsupportedModel(model) => {
+                write!(f, "unsupported tokenizer model \"{}\"", model)
+            }
+            Self::SentencePieceError(err) => fmt::Display::fmt(err, f),
+            Self::PreTokenizeError(err) => write!(f, "failed to construct pre-tokenizer: {}", err),
+            Self::BpeError(err) => write!(f, "BPE tokenizer error: {}", err),
+        }
+    }
+}
+
+impl Error for FromGgufError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::IoError(err) => Some(err),
+            Self::GgufError(err) => Some(err),
+            Self::MissingMetadata(_) | Self::UnsupportedModel(_) => None,
+            Self::SentencePieceError(err) => Some(err),
+            Self::PreTokenizeError(err) => Some(err),
+            Self::BpeError(err) => Some(err),
+        }
+    }
+}
+
+/// Read the tokenizer configuration from a GGUF file.
+struct TokenizerConfig<'a> {
+    tokens: Vec<&'a str>,
+    scores: Option<Vec<f32>>,
+    token_types: Option<Vec<PieceType>>,
+}
+
+impl<'a> TokenizerConfig<'a> {
+    fn from_file(file: &'a GgufFile) -> Result<Self, FromGgufError> {
+        const TOKENS: &str = "tokenizer.ggml.tokens";
+        const SCORES: &str = "tokenizer.ggml.scores";
+        const TOKEN_TYPES: &str = "tokenizer.ggml.token_type";
+
+        let tokens = file
+            .get_array(TOKENS)
+            .and_then(|tokens| tokens.iter().map(Value::as_str).collect::<Option<Vec<_>>>())
+            .ok_or(FromGgufError::MissingMetadata(TOKENS))?;
+
+        let scores = file
+            .get_array(SCORES)
+            .map(|scores| {
+                scores
+                    .iter()
+                    .map(Value::as_f32)
+                    .collect::<Option<Vec<_>>>()
+                    .filter(|scores| scores.len() == tokens.len())
+                    .ok_or(FromGgufError::MissingMetadata(SCORES))
+            })
+            .transpose()?;
+
+        let token_types = file
+            .get_array(TOKEN_TYPES)
+            .map(|types| {
+                types
+                    .iter()
+                    .map(|ty| ty.as_i64().map(|ty| PieceType(ty as i32)))
+                    .collect::<Option<Vec<_>>>()
+                    .filter(|types| types.len() == tokens.len())
+                    .ok_or(FromGgufError::MissingMetadata(TOKEN_TYPES))
+            })
+            .transpose()?;
+
+        Ok(TokenizerConfig {
+            tokens,
+            scores,
+            token_types,
+        })
+    }
+
+    fn token_type(&self, id: usize) -> PieceType {
+        self.token_types
+            .as_ref()
+            .map(|types| types[id])
+            .unwrap_or(PieceType::NORMAL)
+    }
+
+    /// Return added tokens for control, unknown and user-defined tokens.
+    fn added_tokens(&self) -> Vec<AddedToken> {
+        self.tokens
+            .iter()
+            .enumerate()
+            .filter_map(|(id, content)| {
+                let special = match self.token_type(id) {
+                    PieceType::CONTROL | PieceType::UNKNOWN => true,
+                    PieceType::USER_DEFINED => false,
+                    _ => return None,
+                };
+                Some(AddedToken {
+                    content: content.to_string(),
+                    id: id as TokenId,
+                    special,
+                    lstrip: false,
+                    rstrip: false,
+                    single_word: false,
+                    normalized: false,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Create a tokenizer for a "llama" tokenizer model.
+///
+/// These use the same vocabulary representation as SentencePiece models, so
+/// the tokenizer is constructed in the same way.
+fn llama_tokenizer(file: &GgufFile, config: &TokenizerConfig) -> Result<Tokenizer, FromGgufError> {
+    let pieces = config
+        .tokens
+        .iter()
+        .enumerate()
+        .map(|(id, piece)| SentencePiece {
+            piece: piece.to_string(),
+            score: config.scores.as_ref().map(|s| s[id]).unwrap_or(0.),
+            r#type: config.token_type(id),
+        })
+        .collect();
+    let model = ModelProto {
+        pieces,
+        trainer_spec: TrainerSpec {
+            model_type: ModelType::BPE,
+            byte_fallback: false,
+        },
+        normalizer_spec: NormalizerSpec {
+            precompiled_charsmap: Vec::new(),
+            add_dummy_prefix: file
+                .get_bool("tokenizer.ggml.add_space_prefix")
+                .unwrap_or(true),
+            remove_extra_whitespaces: false,
+        },
+    };
+    sentencepiece::from_model(model).map_err(FromGgufError::SentencePieceError)
+}
+
+/// Create a tokenizer for a "gpt2" tokenizer model.
+fn gpt2_tokenizer(file: &GgufFile, config: &TokenizerConfig) -> Result<Tokenizer, FromGgufError> {
+    const MERGES: &str = "tokenizer.ggml.merges";
+
+    let merges = file
+        .get_array(MERGES)
+        .and_then(|merges| merges.iter().map(Value::as_str).collect::<Option<Vec<_>>>())
+        .ok_or(FromGgufError::MissingMetadata(MERGES))?;
+    let merges = merge_pairs_from_lines(&merges);
+
+    let vocab = config
+        .tokens
+        .iter()
+        .enumerate()
+        .map(|(id, token)| (token.to_string(), id as TokenId))
+        .collect();
+    let model = Bpe::new(BpeOptions {
+        merges: &merges,
+        vocab: Some(vocab),
+        ..Default::default()
+    })
+    .map_err(FromGgufError::BpeError)?;
+
+    // llama.cpp identifies the regex used to split text by the
+    // `tokenizer.ggml.pre` field.
+    let pattern = match file.get_str("tokenizer.ggml.pre") {
+        Some("qwen2") => QWEN2_REGEX,
+        Some("llama-bpe" | "llama3" | "smaug-bpe") => CL100K_REGEX,
+        _ => GPT2_REGEX,
+    };
+    let pre_tokenizer = Split::new(SplitOptions {
+        pattern,
+        delimiter: SplitDelimiterBehavior::Remove,
+        invert: true,
+    })
+    .map_err(FromGgufError::PreTokenizeError)?;
+
+    Ok(Tokenizer::new(
+        model,
+        TokenizerOptions {
+            cls_token: None,
+            sep_token: None,
+        },
+    )
+    .with_pre_tokenizer(Box::new(pre_tokenizer))
+    .with_decoder(Box::new(decoders::ByteLevel::new())))
+}
+
+/// Create a tokenizer from the contents of a GGUF file.
+pub(super) fn from_gguf(data: &[u8]) -> Result<Tokenizer, FromGgufError> {
+    const MODEL: &str = "tokenizer.ggml.model";
+
+    let file = GgufFile::parse(data).map_err(FromGgufError::GgufError)?;
+    let config = TokenizerConfig::from_file(&file)?;
+
+    let model = file
+        .get_str(MODEL)
+        .ok_or(FromGgufError::MissingMetadata(MODEL))?;
+    let (tokenizer, add_bos_default) = match model {
+        "llama" => (llama_tokenizer(&file, &config)?, true),
+        "gpt2" => (gpt2_tokenizer(&file, &config)?, false),
+        other => return Err(FromGgufError::UnsupportedModel(other.to_string())),
+    };
+    let mut tokenizer = tokenizer.with_added_tokens(config.added_tokens());
+
+    let bos_id = file
+        .get_u64("tokenizer.ggml.bos_token_id")
+        .map(|id| id as TokenId);
+    let add_bos = file
+        .get_bool("tokenizer.ggml.add_bos_token")
+        .unwrap_or(add_bos_default);
+    if let Some(bos_id) = bos_id
+        && add_bos
+    {
+        let bos = TemplatePiece::SpecialToken {
+            ids: vec![bos_id],
+            type_id: 0,
+        };
+        let seq = |id| TemplatePiece::Sequence { id, type_id: 0 };
+        let post_processor = TemplateProcessing::new(
+            vec![bos.clone(), seq(SequenceId::A)],
+            vec![bos, seq(SequenceId::A), seq(SequenceId::B)],
+        )
+        .expect("template should be valid");
+        tokenizer = tokenizer.with_post_processor(post_processor);
+    }
+
+    Ok(tokenizer)
+}
+
+/// Read the header and metadata of a GGUF file and create a tokenizer.
+///
+/// GGUF files are often large because they contain the model weights, so the
+/// file is read incrementally until the metadata has been parsed, rather than
+/// reading the whole file.
+pub(super) fn from_gguf_file(path: &Path) -> Result<Tokenizer, FromGgufError> {
+    let mut file = File::open(path).map_err(FromGgufError::IoError)?;
+    let mut buf = Vec::new();
+    let mut chunk_size = 1 << 20;
+
+    loop {
+        let prev_len = buf.len();
+        (&mut file)
+            .take(chunk_size)
+            .read_to_end(&mut buf)
+            .map_err(FromGgufError::IoError)?;
+        let at_end = buf.len() == prev_len;
+
+        match GgufFile::parse(&buf) {
+            Err(GgufError::UnexpectedEof) if !at_end => {
+                chunk_size *= 2;
+            }
+            _ => return from_gguf(&buf),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rten_gguf::{GgufWriter, Value};
+
+    use super::FromGgufError;
+    use crate::Tokenizer;
+    use crate::models::byte_to_char;
+
+    fn strings(items: &[&str]) -> Value {
+        Value::Array(items.iter().map(|s| Value::String(s.to_string())).collect())
+    }
+
+    #[test]
+    fn test_llama_tokenizer() {
+        let mut tokens = vec!["<unk>".to_string(), "<s>".into(), "</s>".into()];
+        tokens.extend((0..=255u8).map(|b| format!("<0x{:02X}>", b)));
+        let byte_tokens_end = tokens.len();
+        tokens.extend(["▁t", "he", "▁the", "▁", "t", "h", "e"].map(String::from));
+
+        let mut types = vec![2, 3, 3];
+        types.extend(std::iter::repeat_n(6, 256));
+        types.extend(std::iter::repeat_n(1, tokens.len() - byte_tokens_end));
+
+        let scores = (0..tokens.len()).map(|i| {
+            if i < byte_tokens_end {
+                0.
+            } else {
+                -((i - byte_tokens_end) as f32)
+            }
+        });
+
+        let mut writer = GgufWriter::new();
+        writer.add_metadata("tokenizer.ggml.model", Value::String("llama".into()));
+        writer.add_metadata(
+            "tokenizer.ggml.tokens",
+            Value::Array(tokens.iter().cloned().map(Value::String).collect()),
+        );
+        writer.add_metadata(
+            "tokenizer.ggml.scores",
+            Value::Array(scores.map(Value::F32).collect()),
+        );
+        writer.add_metadata(
+            "tokenizer.ggml.token_type",
+            Value::Array(types.into_iter().map(Value::I32).collect()),
+        );
+        writer.add_metadata("tokenizer.ggml.bos_token_id", Value::U32(1));
+        let data = writer.finish();
+
+        let tokenizer = Tokenizer::from_gguf(&data).unwrap();
+        let encoded = tokenizer.encode("the thé", None).unwrap();
+        let token_strs = tokenizer.model().get_tokens(encoded.token_ids()).unwrap();
+        assert_eq!(
+            token_strs,
+            &["<s>", "▁the", "▁t", "h", "<0xC3>", "<0xA9>"].map(String::from)
+        );
+        assert_eq!(
+            tokenizer.decode(&encoded.token_ids()[1..]).unwrap(),
+            "the thé"
+        );
+
+        // Control tokens are matched as special tokens.
+        let encoded = tokenizer.encode("</s>", None).unwrap();
+        assert_eq!(encoded.token_ids(), &[1, 2]);
+    }
+
+    #[test]
+    fn test_gpt2_tokenizer() {
+        // Tokens are stored using the byte-to-char mapping used by GPT-2.
+        let byte_to_char = byte_to_char();
+        let mut tokens: Vec<String> = byte_to_char.iter().map(|ch| ch.to_string()).collect();
+        let space = byte_to_char[b' ' as usize];
+        let merges = [
+            format!("{} h", space),
+            "e l".to_string(),
+            "el l".to_string(),
+            format!("{}h ell", space),
+        ];
+        for merge in &merges {
+            tokens.push(merge.replace(' ', ""));
+        }
+        tokens.push("<|endoftext|>".to_string());
+        let eot_id = tokens.len() - 1;
+
+        let mut types = vec![1; tokens.len()];
+        types[eot_id] = 3;
+
+        let mut writer = GgufWriter::new();
+        writer.add_metadata("tokenizer.ggml.model", Value::String("gpt2".into()));
+        writer.add_metadata("tokenizer.ggml.pre", Value::String("qwen2".into()));
+        writer.add_metadata(
+            "tokenizer.ggml.tokens",
+            Value::Array(tokens.iter().cloned().map(Value::String).collect()),
+        );
+        writer.add_metadata(
+            "tokenizer.ggml.merges",
+            strings(&merges.iter().map(|m| m.as_str()).collect::<Vec<_>>()),
+        );
+        writer.add_metadata(
+            "tokenizer.ggml.token_type",
+            Value::Array(types.into_iter().map(Value::I32).collect()),
+        );
+        writer.add_metadata("tokenizer.ggml.bos_token_id", Value::U32(eot_id as u32));
+        let data = writer.finish();
+
+        let tokenizer = Tokenizer::from_gguf(&data).unwrap();
+
+        // BOS tokens are not added by default for "gpt2" models.
+        let encoded = tokenizer.encode("a hell<|endoftext|>", None).unwrap();
+        let token_strs = tokenizer.model().get_tokens(encoded.token_ids()).unwrap();
+        assert_eq!(
+            token_strs,
+            &["a", &format!("{}hell", space), "<|endoftext|>"].map(String::from)
+        );
+        assert_eq!(
+            tokenizer.decode(encoded.token_ids()).unwrap(),
+            "a hell<|endoftext|>"
+        );
+    }
+
+    #[test]
+    fn test_invalid_tokenizer() {
+        let err = Tokenizer::from_gguf(b"not a gguf file").err().unwrap();
+        assert!(matches!(err, FromGgufError::GgufError(_)));
+
+        let mut writer = GgufWriter::new();
+        writer.add_metadata("tokenizer.ggml.model", Value::String("bert".into()));
+        writer.add_metadata("tokenizer.ggml.tokens", strings(&["a"]));
+        let err = Tokenizer::from_gguf(&writer.finish()).err().unwrap();
+        assert!(matches!(err, FromGgufError::UnsupportedModel(ref model) if model == "bert"));
+
+        let writer = GgufWriter::new();
+        let err = Tokenizer::from_gguf(&writer.finish()).err().unwrap();
+        assert!(matches!(
+            err,
+            FromGgufError::MissingMetadata("tokenizer.ggml.tokens")
+        ));
+    }
+}
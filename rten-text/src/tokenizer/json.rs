@@ -1,45 +1,84 @@
 //! Types for the supported subset of the `tokenizer.json` pre-trained tokenizer
 //! format.
+//!
+//! These types are used both for loading and saving tokenizers.
 
 use super::TokenId;
-use serde_derive::Deserialize;
+use serde_derive::{Deserialize, Serialize};
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 pub(crate) struct AddedToken {
     pub content: String,
     pub id: TokenId,
+    #[serde(default)]
+    pub special: bool,
+    #[serde(default)]
+    pub lstrip: bool,
+    #[serde(default)]
+    pub rstrip: bool,
+    #[serde(default)]
+    pub single_word: bool,
+    /// Defaults to `!special` if not specified.
+    pub normalized: Option<bool>,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 pub(crate) enum Pattern {
     Regex(String),
     String(String),
 }
 
+/// Specifies when a Metaspace pre-tokenizer adds a replacement character at
+/// the start of the input.
+#[derive(Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum PrependScheme {
+    Always,
+    First,
+    Never,
+}
+
 pub mod normalizers {
-    use serde_derive::Deserialize;
+    use serde_derive::{Deserialize, Serialize};
 
     use super::{Normalizer, Pattern};
 
-    #[derive(Deserialize)]
+    #[derive(Deserialize, Serialize)]
     pub(crate) struct Bert {
         pub lowercase: bool,
         pub strip_accents: Option<bool>,
     }
 
-    #[derive(Deserialize)]
+    #[derive(Deserialize, Serialize)]
+    pub(crate) struct Precompiled {
+        /// Base64-encoded character map from a SentencePiece model.
+        pub precompiled_charsmap: Option<String>,
+    }
+
+    #[derive(Deserialize, Serialize)]
+    pub(crate) struct Prepend {
+        pub prepend: String,
+    }
+
+    #[derive(Deserialize, Serialize)]
     pub(crate) struct Replace {
         pub pattern: Pattern,
         pub content: String,
     }
 
-    #[derive(Deserialize)]
+    #[derive(Deserialize, Serialize)]
     pub(crate) struct Sequence {
         pub normalizers: Vec<Normalizer>,
     }
+
+    #[derive(Deserialize, Serialize)]
+    pub(crate) struct Strip {
+        pub strip_left: bool,
+        pub strip_right: bool,
+    }
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 #[serde(tag = "type")]
 pub(crate) enum Normalizer {
     #[serde(rename = "BertNormalizer")]
@@ -53,37 +92,80 @@ pub(crate) enum Normalizer {
     Nfkc,
     #[serde(rename = "NFKD")]
     Nfkd,
+    Precompiled(normalizers::Precompiled),
+    Prepend(normalizers::Prepend),
     Replace(normalizers::Replace),
     Sequence(normalizers::Sequence),
+    Strip(normalizers::Strip),
+    StripAccents,
 }
 
 pub mod pre_tokenizers {
-    use serde_derive::Deserialize;
+    use serde_derive::{Deserialize, Serialize};
 
-    use super::{Pattern, PreTokenizer};
+    use super::{Pattern, PreTokenizer, PrependScheme};
 
-    #[derive(Deserialize)]
+    #[derive(Deserialize, Serialize)]
     pub(crate) struct ByteLevel {
         pub use_regex: bool,
     }
 
-    #[derive(Deserialize)]
+    #[derive(Deserialize, Serialize)]
+    pub(crate) struct CharDelimiterSplit {
+        pub delimiter: char,
+    }
+
+    #[derive(Deserialize, Serialize)]
     pub(crate) struct Digits {
         pub individual_digits: bool,
     }
 
-    #[derive(Deserialize)]
+    fn default_split() -> bool {
+        true
+    }
+
+    #[derive(Deserialize, Serialize)]
+    pub(crate) struct Metaspace {
+        pub replacement: char,
+
+        /// Replaces `add_prefix_space` in newer versions of Hugging Face
+        /// Tokenizers.
+        pub prepend_scheme: Option<PrependScheme>,
+        pub add_prefix_space: Option<bool>,
+
+        /// This field is missing in files generated by older versions of
+        /// Hugging Face Tokenizers.
+        #[serde(default = "default_split")]
+        pub split: bool,
+    }
+
+    #[derive(Deserialize, Serialize)]
+    pub(crate) struct Punctuation {
+        #[serde(default = "SplitDelimiter::isolated")]
+        pub behavior: SplitDelimiter,
+    }
+
+    #[derive(Deserialize, Serialize)]
     pub(crate) struct Sequence {
         pub pretokenizers: Vec<PreTokenizer>,
     }
 
-    #[derive(Deserialize)]
+    #[derive(Deserialize, Serialize)]
     pub(crate) enum SplitDelimiter {
         Removed,
         Isolated,
+        MergedWithPrevious,
+        MergedWithNext,
+        Contiguous,
     }
 
-    #[derive(Deserialize)]
+    impl SplitDelimiter {
+        fn isolated() -> Self {
+            SplitDelimiter::Isolated
+        }
+    }
+
+    #[derive(Deserialize, Serialize)]
     pub(crate) struct Split {
         pub pattern: Pattern,
         pub behavior: SplitDelimiter,
@@ -94,34 +176,217 @@ pub mod pre_tokenizers {
 /// Configuration for pre-tokenization.
 ///
 /// See https://huggingface.co/docs/tokenizers/en/api/pre-tokenizers.
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 #[serde(tag = "type")]
 pub(crate) enum PreTokenizer {
     #[serde(rename = "BertPreTokenizer")]
     Bert,
     #[serde(rename = "ByteLevel")]
     ByteLevel(pre_tokenizers::ByteLevel),
+    CharDelimiterSplit(pre_tokenizers::CharDelimiterSplit),
     Digits(pre_tokenizers::Digits),
+    Metaspace(pre_tokenizers::Metaspace),
+    Punctuation(pre_tokenizers::Punctuation),
     Sequence(pre_tokenizers::Sequence),
     Split(pre_tokenizers::Split),
+    UnicodeScripts,
+    Whitespace,
+    WhitespaceSplit,
+}
+
+pub mod decoders {
+    use serde_derive::{Deserialize, Serialize};
+
+    use super::{Decoder, Pattern, PrependScheme};
+
+    #[derive(Deserialize, Serialize)]
+    pub(crate) struct Bpe {
+        #[serde(default = "default_bpe_suffix")]
+        pub suffix: String,
+    }
+
+    fn default_bpe_suffix() -> String {
+        "</w>".to_string()
+    }
+
+    #[derive(Deserialize, Serialize)]
+    pub(crate) struct Ctc {
+        #[serde(default = "default_ctc_pad_token")]
+        pub pad_token: String,
+        #[serde(default = "default_ctc_word_delimiter_token")]
+        pub word_delimiter_token: String,
+        #[serde(default = "default_ctc_cleanup")]
+        pub cleanup: bool,
+    }
+
+    fn default_ctc_pad_token() -> String {
+        "<pad>".to_string()
+    }
+
+    fn default_ctc_word_delimiter_token() -> String {
+        "|".to_string()
+    }
+
+    fn default_ctc_cleanup() -> bool {
+        true
+    }
+
+    #[derive(Deserialize, Serialize)]
+    pub(crate) struct Metaspace {
+        pub replacement: char,
+
+        /// Replaces `add_prefix_space` in newer versions of Hugging Face
+        /// Tokenizers.
+        pub prepend_scheme: Option<PrependScheme>,
+        pub add_prefix_space: Option<bool>,
+    }
+
+    #[derive(Deserialize, Serialize)]
+    pub(crate) struct Replace {
+        pub pattern: Pattern,
+        pub content: String,
+    }
+
+    #[derive(Deserialize, Serialize)]
+    pub(crate) struct Sequence {
+        pub decoders: Vec<Decoder>,
+    }
+
+    #[derive(Deserialize, Serialize)]
+    pub(crate) struct Strip {
+        pub content: char,
+        pub start: usize,
+        pub stop: usize,
+    }
+
+    #[derive(Deserialize, Serialize)]
+    pub(crate) struct WordPiece {
+        pub prefix: String,
+        pub cleanup: bool,
+    }
+}
+
+/// Configuration for decoding.
+///
+/// See https://huggingface.co/docs/tokenizers/en/api/decoders.
+#[derive(Deserialize, Serialize)]
+#[serde(tag = "type")]
+pub(crate) enum Decoder {
+    #[serde(rename = "BPEDecoder")]
+    Bpe(decoders::Bpe),
+    ByteFallback,
+    ByteLevel,
+    #[serde(rename = "CTC")]
+    Ctc(decoders::Ctc),
+    Fuse,
+    Metaspace(decoders::Metaspace),
+    Replace(decoders::Replace),
+    Sequence(decoders::Sequence),
+    Strip(decoders::Strip),
+    WordPiece(decoders::WordPiece),
+}
+
+pub mod post_processors {
+    use std::collections::HashMap;
+
+    use serde_derive::{Deserialize, Serialize};
+
+    use super::PostProcessor;
+    use crate::TokenId;
+
+    #[derive(Deserialize, Serialize)]
+    pub(crate) enum SequenceId {
+        A,
+        B,
+    }
+
+    #[derive(Deserialize, Serialize)]
+    pub(crate) enum TemplatePiece {
+        Sequence {
+            id: SequenceId,
+            type_id: u32,
+        },
+        SpecialToken {
+            /// Key in [`TemplateProcessing::special_tokens`].
+            id: String,
+            type_id: u32,
+        },
+    }
+
+    #[derive(Deserialize, Serialize)]
+    pub(crate) struct SpecialToken {
+        /// Key of this token in [`TemplateProcessing::special_tokens`].
+        #[serde(default)]
+        pub id: String,
+        pub ids: Vec<TokenId>,
+        #[serde(default)]
+        pub tokens: Vec<String>,
+    }
+
+    #[derive(Deserialize, Serialize)]
+    pub(crate) struct TemplateProcessing {
+        pub single: Vec<TemplatePiece>,
+        pub pair: Vec<TemplatePiece>,
+        pub special_tokens: HashMap<String, SpecialToken>,
+    }
+
+    /// Configuration for `BertProcessing` and `RobertaProcessing`.
+    #[derive(Deserialize, Serialize)]
+    pub(crate) struct ClsSep {
+        /// `(token, id)` tuple for the classification token.
+        pub cls: (String, TokenId),
+
+        /// `(token, id)` tuple for the separator token.
+        pub sep: (String, TokenId),
+    }
+
+    #[derive(Deserialize, Serialize)]
+    pub(crate) struct Sequence {
+        pub processors: Vec<PostProcessor>,
+    }
+}
+
+/// Configuration for post-processing, which adds special tokens to encoded
+/// sequences.
+///
+/// See https://huggingface.co/docs/tokenizers/en/api/post-processors.
+#[derive(Deserialize, Serialize)]
+#[serde(tag = "type")]
+pub(crate) enum PostProcessor {
+    BertProcessing(post_processors::ClsSep),
+    RobertaProcessing(post_processors::ClsSep),
+    TemplateProcessing(post_processors::TemplateProcessing),
+    Sequence(post_processors::Sequence),
+    /// A post-processor which does not add special tokens, such as
+    /// `ByteLevel`, or a type which is not supported by this crate.
+    #[serde(other)]
+    Other,
 }
 
 pub mod models {
     use std::collections::HashMap;
 
     use rustc_hash::FxHashMap;
-    use serde_derive::Deserialize;
+    use serde_derive::{Deserialize, Serialize};
 
     use crate::TokenId;
-    use crate::serde::CowStr;
+    use crate::serde::{CowStr, serialize_vocab};
 
-    #[derive(Deserialize)]
+    #[derive(Deserialize, Serialize)]
     pub(crate) struct WordPiece {
         /// Mapping from token text to token ID.
+        #[serde(serialize_with = "serialize_vocab")]
         pub vocab: HashMap<String, TokenId>,
+
+        /// Words longer than this many characters are encoded as the unknown
+        /// token.
+        pub max_input_chars_per_word: Option<usize>,
+
+        /// Token used for words which cannot be tokenized.
+        pub unk_token: Option<String>,
     }
 
-    #[derive(Debug, Deserialize)]
+    #[derive(Debug, Deserialize, Serialize)]
     #[serde(untagged)]
     pub(crate) enum MergeList<'a> {
         /// Pairs represented as a JSON array.
@@ -131,9 +396,10 @@ pub mod models {
         Legacy(Vec<CowStr<'a>>),
     }
 
-    #[derive(Deserialize)]
+    #[derive(Deserialize, Serialize)]
     pub(crate) struct Bpe<'a> {
         /// Mapping from token text to token ID.
+        #[serde(serialize_with = "serialize_vocab")]
         pub vocab: FxHashMap<String, TokenId>,
 
         /// List of pairs of tokens to merge.
@@ -150,29 +416,101 @@ pub mod models {
         /// When encoding a string piece, look up the piece in the vocabulary
         /// before applying merge rules.
         pub ignore_merges: bool,
+
+        /// Token used for characters that are not in the vocabulary.
+        pub unk_token: Option<String>,
+
+        /// Encode unknown characters as `<0xXX>` byte tokens.
+        #[serde(default)]
+        pub byte_fallback: bool,
+    }
+
+    #[derive(Deserialize, Serialize)]
+    pub(crate) struct Unigram {
+        /// List of `(token, score)` pairs. The token ID is the index in the
+        /// list.
+        pub vocab: Vec<(String, f64)>,
+
+        /// ID of the token used for unknown characters.
+        pub unk_id: Option<TokenId>,
+
+        /// Encode unknown characters as `<0xXX>` byte tokens.
+        ///
+        /// This field is missing in files generated by older versions of
+        /// Hugging Face Tokenizers.
+        #[serde(default)]
+        pub byte_fallback: bool,
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 #[serde(tag = "type")]
 pub(crate) enum Model<'a> {
     #[serde(borrow)]
     #[serde(rename = "BPE")]
     Bpe(models::Bpe<'a>),
+    Unigram(models::Unigram),
     WordPiece(models::WordPiece),
 }
 
+#[derive(Deserialize, Serialize)]
+pub(crate) enum TruncationStrategy {
+    LongestFirst,
+    OnlyFirst,
+    OnlySecond,
+}
+
+#[derive(Deserialize, Serialize)]
+pub(crate) enum Direction {
+    Left,
+    Right,
+}
+
+/// Configuration for truncation of inputs.
+///
+/// See https://huggingface.co/docs/tokenizers/en/api/tokenizer#tokenizers.Tokenizer.truncation.
+#[derive(Deserialize, Serialize)]
+pub(crate) struct Truncation {
+    pub max_length: usize,
+    pub strategy: TruncationStrategy,
+    pub direction: Direction,
+}
+
+#[derive(Deserialize, Serialize)]
+pub(crate) enum PaddingStrategy {
+    BatchLongest,
+    Fixed(usize),
+}
+
+/// Configuration for padding of inputs.
+///
+/// See https://huggingface.co/docs/tokenizers/en/api/tokenizer#tokenizers.Tokenizer.padding.
+#[derive(Deserialize, Serialize)]
+pub(crate) struct Padding {
+    pub strategy: PaddingStrategy,
+    pub direction: Direction,
+    pub pad_to_multiple_of: Option<usize>,
+    pub pad_id: TokenId,
+    pub pad_type_id: u32,
+    #[serde(default)]
+    pub pad_token: String,
+}
+
 /// Structure of the `tokenizers.json` files generated by Hugging Face
 /// tokenizers [^1].
 ///
 /// [^1]: https://github.com/huggingface/tokenizers
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 pub(crate) struct Tokenizer<'a> {
     pub added_tokens: Option<Vec<AddedToken>>,
+    pub truncation: Option<Truncation>,
+    pub padding: Option<Padding>,
     pub normalizer: Option<Normalizer>,
     pub pre_tokenizer: Option<PreTokenizer>,
     #[serde(borrow)]
     pub model: Model<'a>,
+    pub post_processor: Option<PostProcessor>,
+    pub decoder: Option<Decoder>,
 }
 
 /// Deserialize a `tokenizer.json` file.
@@ -0,0 +1,592 @@
+//! Loading of tokenizers from SentencePiece `.model` files.
+//!
+//! These files contain a `ModelProto` Protocol Buffers message, as defined in
+//! <https://github.com/google/sentencepiece/blob/master/src/sentencepiece_model.proto>.
+//! Only the messages and fields needed to construct a [`Tokenizer`] are
+//! decoded.
+
+use std::error::Error;
+use std::fmt;
+
+use rten_onnx::protobuf::{
+    DecodeMessage, Fields, OwnedValues, ProtobufError, ReadValue, ValueReader,
+};
+use rustc_hash::FxHashMap;
+
+use super::{AddedToken, Tokenizer, TokenizerOptions};
+use crate::decoders::{self, Decoder, DecoderError};
+use crate::models::{Bpe, BpeError, BpeOptions, Unigram, UnigramError, UnigramOptions};
+use crate::normalizers::{self, NormalizeError, Normalizer};
+use crate::pre_tokenizers::{Metaspace, MetaspaceOptions, PrependScheme};
+use crate::tokenizer::TokenId;
+
+/// Errors returned by [`Tokenizer::from_sentencepiece`].
+#[derive(Debug)]
+pub enum FromSentencePieceError {
+    /// There was an error reading the model file.
+    IoError(std::io::Error),
+    /// The model data is not a valid `ModelProto` message.
+    ProtobufError(ProtobufError),
+    /// The model type (eg. "word" or "char") is not supported.
+    UnsupportedModelType(i32),
+    /// Could not instantiate the normalizer.
+    NormalizerError(NormalizeError),
+    /// Could not instantiate the decoder.
+    DecoderError(DecoderError),
+    /// There was an error loading a BPE model.
+    BpeError(BpeError),
+    /// There was an error loading a Unigram model.
+    UnigramError(UnigramError),
+}
+
+impl fmt::Display for FromSentencePieceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IoError(err) => fmt::Display::fmt(err, f),
+            Self::ProtobufError(err) => write!(f, "failed to parse model: {}", err),
+            Self::UnsupportedModelType(ty) => write!(f, "unsupported model type {}", ty),
+            Self::NormalizerError(err) => write!(f, "failed to construct normalizer: {}", err),
+            Self::DecoderError(err) => write!(f, "failed to construct decoder: {}", err),
+            Self::BpeError(err) => write!(f, "BPE tokenizer error: {}", err),
+            Self::UnigramError(err) => write!(f, "Unigram tokenizer error: {}", err),
+        }
+    }
+}
+
+impl Error for FromSentencePieceError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::IoError(err) => Some(err),
+            Self::ProtobufError(err) => Some(err),
+            Self::UnsupportedModelType(_) => None,
+            Self::NormalizerError(err) => Some(err),
+            Self::DecoderError(err) => Some(err),
+            Self::BpeError(err) => Some(err),
+            Self::UnigramError(err) => Some(err),
+        }
+    }
+}
+
+impl From<NormalizeError> for FromSentencePieceError {
+    fn from(val: NormalizeError) -> Self {
+        FromSentencePieceError::NormalizerError(val)
+    }
+}
+
+impl From<DecoderError> for FromSentencePieceError {
+    fn from(val: DecoderError) -> Self {
+        FromSentencePieceError::DecoderError(val)
+    }
+}
+
+/// Type of a piece in the vocabulary (`ModelProto.SentencePiece.Type`).
+///
+/// GGUF files use the same values for `tokenizer.ggml.token_type`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(super) struct PieceType(pub(super) i32);
+
+impl PieceType {
+    pub(super) const NORMAL: Self = Self(1);
+    pub(super) const UNKNOWN: Self = Self(2);
+    pub(super) const CONTROL: Self = Self(3);
+    pub(super) const USER_DEFINED: Self = Self(4);
+    pub(super) const BYTE: Self = Self(6);
+}
+
+/// Model algorithm (`TrainerSpec.ModelType`).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(super) struct ModelType(i32);
+
+impl ModelType {
+    const UNIGRAM: Self = Self(1);
+    pub(super) const BPE: Self = Self(2);
+}
+
+pub(super) struct SentencePiece {
+    pub(super) piece: String,
+    pub(super) score: f32,
+    pub(super) r#type: PieceType,
+}
+
+impl SentencePiece {
+    const PIECE: u64 = 1;
+    const SCORE: u64 = 2;
+    const TYPE: u64 = 3;
+}
+
+impl DecodeMessage for SentencePiece {
+    type Types = OwnedValues;
+
+    fn decode_fields<R: ReadValue<Types = Self::Types>>(
+        mut fields: Fields<R>,
+    ) -> Result<Self, ProtobufError> {
+        let mut msg = SentencePiece {
+            piece: String::new(),
+            score: 0.,
+            r#type: PieceType::NORMAL,
+        };
+        while let Some(mut field) = fields.next()? {
+            match field.number() {
+                Self::PIECE => {
+                    msg.piece = field.read_string()?;
+                }
+                Self::SCORE => {
+                    msg.score = field.get_float()?;
+                }
+                Self::TYPE => {
+                    msg.r#type = PieceType(field.get_enum()?);
+                }
+                _ => {
+                    field.skip()?;
+                }
+            }
+        }
+        Ok(msg)
+    }
+}
+
+pub(super) struct TrainerSpec {
+    pub(super) model_type: ModelType,
+    pub(super) byte_fallback: bool,
+}
+
+impl TrainerSpec {
+    const MODEL_TYPE: u64 = 3;
+    const BYTE_FALLBACK: u64 = 35;
+}
+
+impl Default for TrainerSpec {
+    fn default() -> Self {
+        TrainerSpec {
+            model_type: ModelType::UNIGRAM,
+            byte_fallback: false,
+        }
+    }
+}
+
+impl DecodeMessage for TrainerSpec {
+    type Types = OwnedValues;
+
+    fn decode_fields<R: ReadValue<Types = Self::Types>>(
+        mut fields: Fields<R>,
+    ) -> Result<Self, ProtobufError> {
+        let mut msg = Self::default();
+        while let Some(mut field) = fields.next()? {
+            match field.number() {
+                Self::MODEL_TYPE => {
+                    msg.model_type = ModelType(field.get_enum()?);
+                }
+                Self::BYTE_FALLBACK => {
+                    msg.byte_fallback = field.get_int32()? != 0;
+                }
+                _ => {
+                    field.skip()?;
+                }
+            }
+        }
+        Ok(msg)
+    }
+}
+
+pub(super) struct NormalizerSpec {
+    pub(super) precompiled_charsmap: Vec<u8>,
+    pub(super) add_dummy_prefix: bool,
+    pub(super) remove_extra_whitespaces: bool,
+}
+
+impl NormalizerSpec {
+    const PRECOMPILED_CHARSMAP: u64 = 2;
+    const ADD_DUMMY_PREFIX: u64 = 3;
+    const REMOVE_EXTRA_WHITESPACES: u64 = 4;
+}
+
+impl Default for NormalizerSpec {
+    fn default() -> Self {
+        NormalizerSpec {
+            precompiled_charsmap: Vec::new(),
+            add_dummy_prefix: true,
+            remove_extra_whitespaces: true,
+        }
+    }
+}
+
+impl DecodeMessage for NormalizerSpec {
+    type Types = OwnedValues;
+
+    fn decode_fields<R: ReadValue<Types = Self::Types>>(
+        mut fields: Fields<R>,
+    ) -> Result<Self, ProtobufError> {
+        let mut msg = Self::default();
+        while let Some(mut field) = fields.next()? {
+            match field.number() {
+                Self::PRECOMPILED_CHARSMAP => {
+                    msg.precompiled_charsmap = field.read_bytes()?;
+                }
+                Self::ADD_DUMMY_PREFIX => {
+                    msg.add_dummy_prefix = field.get_int32()? != 0;
+                }
+                Self::REMOVE_EXTRA_WHITESPACES => {
+                    msg.remove_extra_whitespaces = field.get_int32()? != 0;
+                }
+                _ => {
+                    field.skip()?;
+                }
+            }
+        }
+        Ok(msg)
+    }
+}
+
+#[derive(Default)]
+pub(super) struct ModelProto {
+    pub(super) pieces: Vec<SentencePiece>,
+    pub(super) trainer_spec: TrainerSpec,
+    pub(super) normalizer_spec: NormalizerSpec,
+}
+
+impl ModelProto {
+    const PIECES: u64 = 1;
+    const TRAINER_SPEC: u64 = 2;
+    const NORMALIZER_SPEC: u64 = 3;
+}
+
+impl DecodeMessage for ModelProto {
+    type Types = OwnedValues;
+
+    fn decode_fields<R: ReadValue<Types = Self::Types>>(
+        mut fields: Fields<R>,
+    ) -> Result<Self, ProtobufError> {
+        let mut msg = Self::default();
+        while let Some(mut field) = fields.next()? {
+            match field.number() {
+                Self::PIECES => {
+                    msg.pieces.push(SentencePiece::decode_field(&mut field)?);
+                }
+                Self::TRAINER_SPEC => {
+                    msg.trainer_spec = TrainerSpec::decode_field(&mut field)?;
+                }
+                Self::NORMALIZER_SPEC => {
+                    msg.normalizer_spec = NormalizerSpec::decode_field(&mut field)?;
+                }
+                _ => {
+                    field.skip()?;
+                }
+            }
+        }
+        Ok(msg)
+    }
+}
+
+/// Derive BPE merges from the vocabulary of a SentencePiece BPE model.
+///
+/// SentencePiece models don't store merges. Instead the encoder repeatedly
+/// merges the adjacent pair whose concatenation is the highest-scoring piece
+/// in the vocabulary. This is equivalent to a merge list where every split of
+/// a piece into two pieces is an entry, ordered by descending score of the
+/// merged piece.
+fn merges_from_pieces(pieces: &[SentencePiece]) -> Vec<(String, String)> {
+    let mergeable =
+        |piece: &SentencePiece| matches!(piece.r#type, PieceType::NORMAL | PieceType::USER_DEFINED);
+    let ids: FxHashMap<&str, usize> = pieces
+        .iter()
+        .enumerate()
+        .filter(|(_id, piece)| mergeable(piece))
+        .map(|(id, piece)| (piece.piece.as_str(), id))
+        .collect();
+
+    let mut merges = Vec::new();
+    for (id, piece) in pieces.iter().enumerate() {
+        if !mergeable(piece) {
+            continue;
+        }
+        let text = piece.piece.as_str();
+        for (split, _) in text.char_indices().skip(1) {
+            let (left, right) = text.split_at(split);
+            if let (Some(&left_id), Some(&right_id)) = (ids.get(left), ids.get(right)) {
+                merges.push((piece.score, id, left_id, right_id));
+            }
+        }
+    }
+    merges.sort_by(|a, b| {
+        b.0.total_cmp(&a.0)
+            .then(a.1.cmp(&b.1))
+            .then(a.2.cmp(&b.2))
+            .then(a.3.cmp(&b.3))
+    });
+
+    merges
+        .into_iter()
+        .map(|(_score, _id, left, right)| (pieces[left].piece.clone(), pieces[right].piece.clone()))
+        .collect()
+}
+
+/// Create a tokenizer from the contents of a SentencePiece `.model` file.
+pub(super) fn from_sentencepiece(data: &[u8]) -> Result<Tokenizer, FromSentencePieceError> {
+    let model = ModelProto::decode(ValueReader::from_buf(data))
+        .map_err(FromSentencePieceError::ProtobufError)?;
+    from_model(model)
+}
+
+/// Create a tokenizer from a decoded SentencePiece model.
+pub(super) fn from_model(model: ModelProto) -> Result<Tokenizer, FromSentencePieceError> {
+    let ModelProto {
+        pieces,
+        trainer_spec,
+        normalizer_spec,
+    } = model;
+
+    let unk_id = pieces
+        .iter()
+        .position(|piece| piece.r#type == PieceType::UNKNOWN)
+        .map(|id| id as TokenId);
+    let byte_fallback =
+        trainer_spec.byte_fallback || pieces.iter().any(|piece| piece.r#type == PieceType::BYTE);
+
+    // Control and unknown pieces are matched in the input as special tokens,
+    // and user-defined pieces as regular added tokens. This is consistent with
+    // Hugging Face's conversion of SentencePiece models.
+    let added_tokens: Vec<AddedToken> = pieces
+        .iter()
+        .enumerate()
+        .filter_map(|(id, piece)| {
+            let special = match piece.r#type {
+                PieceType::CONTROL | PieceType::UNKNOWN => true,
+                PieceType::USER_DEFINED => false,
+                _ => return None,
+            };
+            Some(AddedToken {
+                content: piece.piece.clone(),
+                id: id as TokenId,
+                special,
+                lstrip: false,
+                rstrip: false,
+                single_word: false,
+                normalized: false,
+            })
+        })
+        .collect();
+
+    let options = TokenizerOptions {
+        cls_token: None,
+        sep_token: None,
+    };
+    let tokenizer = match trainer_spec.model_type {
+        ModelType::UNIGRAM => {
+            let vocab = pieces
+                .into_iter()
+                .map(|piece| (piece.piece, piece.score as f64))
+                .collect();
+            let model = Unigram::from_vocab(
+                vocab,
+                UnigramOptions {
+                    unk_id,
+                    byte_fallback,
+                },
+            )
+            .map_err(FromSentencePieceError::UnigramError)?;
+            Tokenizer::new(model, options)
+        }
+        ModelType::BPE => {
+            let merges: Vec<_> = merges_from_pieces(&pieces)
+                .into_iter()
+                .map(|(a, b)| (a.into(), b.into()))
+                .collect();
+            let vocab = pieces
+                .into_iter()
+                .enumerate()
+                .map(|(id, piece)| (piece.piece, id as TokenId))
+                .collect();
+            let model = Bpe::new(BpeOptions {
+                merges: &merges,
+                vocab: Some(vocab),
+                char_level: true,
+                unk_id,
+                byte_fallback,
+                ..Default::default()
+            })
+            .map_err(FromSentencePieceError::BpeError)?;
+            Tokenizer::new(model, options)
+        }
+        other => return Err(FromSentencePieceError::UnsupportedModelType(other.0)),
+    };
+
+    let mut normalizers: Vec<Box<dyn Normalizer>> = Vec::new();
+    if !normalizer_spec.precompiled_charsmap.is_empty() {
+        normalizers.push(Box::new(normalizers::Precompiled::from_charsmap(
+            &normalizer_spec.precompiled_charsmap,
+        )?));
+    }
+    if normalizer_spec.remove_extra_whitespaces {
+        normalizers.push(Box::new(normalizers::Strip::new(true, true)));
+        normalizers.push(Box::new(normalizers::Replace::new(" {2,}", " ".into())?));
+    }
+
+    // SentencePiece encodes the whole input as a single piece, with spaces
+    // replaced by "▁".
+    let pre_tokenizer = Metaspace::new(MetaspaceOptions {
+        replacement: '▁',
+        prepend_scheme: if normalizer_spec.add_dummy_prefix {
+            PrependScheme::Always
+        } else {
+            PrependScheme::Never
+        },
+        split: false,
+    });
+
+    let strip_start = if normalizer_spec.add_dummy_prefix {
+        1
+    } else {
+        0
+    };
+    let decoders: Vec<Box<dyn Decoder>> = vec![
+        Box::new(decoders::Replace::new("▁", " ".into())?),
+        Box::new(decoders::ByteFallback::new()),
+        Box::new(decoders::Fuse::new()),
+        Box::new(decoders::Strip::new(' ', strip_start, 0)),
+    ];
+
+    Ok(tokenizer
+        .with_normalizer(Box::new(normalizers::Sequence::from_vec(normalizers)))
+        .with_pre_tokenizer(Box::new(pre_tokenizer))
+        .with_decoder(Box::new(decoders::Sequence::from_vec(decoders)))
+        .with_added_tokens(added_tokens))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FromSentencePieceError, PieceType};
+    use crate::Tokenizer;
+
+    /// Minimal Protocol Buffers encoder for constructing test models.
+    #[derive(Default)]
+    struct MessageWriter {
+        buf: Vec<u8>,
+    }
+
+    impl MessageWriter {
+        fn varint(&mut self, mut val: u64) {
+            loop {
+                let byte = (val & 0x7f) as u8;
+                val >>= 7;
+                if val == 0 {
+                    self.buf.push(byte);
+                    break;
+                }
+                self.buf.push(byte | 0x80);
+            }
+        }
+
+        fn int_field(&mut self, number: u64, val: i32) {
+            self.varint(number << 3);
+            self.varint(val as u64);
+        }
+
+        fn float_field(&mut self, number: u64, val: f32) {
+            self.varint((number << 3) | 5);
+            self.buf.extend(val.to_le_bytes());
+        }
+
+        fn bytes_field(&mut self, number: u64, val: &[u8]) {
+            self.varint((number << 3) | 2);
+            self.varint(val.len() as u64);
+            self.buf.extend(val);
+        }
+    }
+
+    /// Encode a `ModelProto` with the given pieces and model type.
+    fn encode_model(pieces: &[(&str, f32, PieceType)], model_type: i32) -> Vec<u8> {
+        let mut model = MessageWriter::default();
+        for (piece, score, piece_type) in pieces {
+            let mut msg = MessageWriter::default();
+            msg.bytes_field(1, piece.as_bytes());
+            msg.float_field(2, *score);
+            msg.int_field(3, piece_type.0);
+            model.bytes_field(1, &msg.buf);
+        }
+
+        let mut trainer_spec = MessageWriter::default();
+        trainer_spec.int_field(3, model_type);
+        model.bytes_field(2, &trainer_spec.buf);
+
+        model.buf
+    }
+
+    fn byte_pieces() -> Vec<(String, f32, PieceType)> {
+        (0..=255u8)
+            .map(|b| (format!("<0x{:02X}>", b), 0., PieceType::BYTE))
+            .collect()
+    }
+
+    #[test]
+    fn test_unigram() {
+        let pieces = [
+            ("<unk>", 0., PieceType::UNKNOWN),
+            ("<s>", 0., PieceType::CONTROL),
+            ("</s>", 0., PieceType::CONTROL),
+            ("▁", -2., PieceType::NORMAL),
+            ("▁hello", -3., PieceType::NORMAL),
+            ("▁world", -3., PieceType::NORMAL),
+            ("▁wor", -4., PieceType::NORMAL),
+            ("ld", -4., PieceType::NORMAL),
+        ];
+        let data = encode_model(&pieces, 1 /* UNIGRAM */);
+        let tokenizer = Tokenizer::from_sentencepiece(&data).unwrap();
+
+        let encoded = tokenizer.encode("  hello   world ", None).unwrap();
+        assert_eq!(encoded.token_ids(), &[4, 5]);
+        assert_eq!(
+            tokenizer.decode(encoded.token_ids()).unwrap(),
+            "hello world"
+        );
+
+        // Control tokens are matched as special tokens and unknown characters
+        // use the unknown token.
+        let encoded = tokenizer.encode("<s>hello x", None).unwrap();
+        assert_eq!(encoded.token_ids(), &[1, 4, 3, 0]);
+    }
+
+    #[test]
+    fn test_bpe_byte_fallback() {
+        let mut pieces: Vec<(String, f32, PieceType)> = [
+            ("<unk>", 0., PieceType::UNKNOWN),
+            ("<s>", 0., PieceType::CONTROL),
+            ("</s>", 0., PieceType::CONTROL),
+        ]
+        .into_iter()
+        .map(|(piece, score, ty)| (piece.to_string(), score, ty))
+        .collect();
+        pieces.extend(byte_pieces());
+        for (i, piece) in ["▁t", "he", "▁the", "▁", "t", "h", "e"].iter().enumerate() {
+            pieces.push((piece.to_string(), -(i as f32), PieceType::NORMAL));
+        }
+        let pieces: Vec<_> = pieces
+            .iter()
+            .map(|(piece, score, ty)| (piece.as_str(), *score, *ty))
+            .collect();
+
+        let data = encode_model(&pieces, 2 /* BPE */);
+        let tokenizer = Tokenizer::from_sentencepiece(&data).unwrap();
+        let model = tokenizer.model();
+
+        let encoded = tokenizer.encode("the thé", None).unwrap();
+        let tokens = model.get_tokens(encoded.token_ids()).unwrap();
+        assert_eq!(
+            tokens,
+            &["▁the", "▁t", "h", "<0xC3>", "<0xA9>"].map(String::from)
+        );
+        assert_eq!(tokenizer.decode(encoded.token_ids()).unwrap(), "the thé");
+    }
+
+    #[test]
+    fn test_invalid_model() {
+        let err = Tokenizer::from_sentencepiece(b"not a model").err().unwrap();
+        assert!(matches!(err, FromSentencePieceError::ProtobufError(_)));
+
+        let data = encode_model(&[("a", 0., PieceType::NORMAL)], 3 /* WORD */);
+        let err = Tokenizer::from_sentencepiece(&data).err().unwrap();
+        assert!(matches!(
+            err,
+            FromSentencePieceError::UnsupportedModelType(3)
+        ));
+    }
+}
@@ -0,0 +1,313 @@
+use crate::models::DecodeError;
+
+use super::{TokenId, Tokenizer, TokenizerError};
+
+/// Incrementally decodes a stream of token IDs into text.
+///
+/// This is used to display the output of a model as it is generated. Each
+/// call to [`step`](Self::step) returns the text that the new token adds to
+/// the output, such that concatenating the returned strings produces the same
+/// result as decoding all tokens at once with [`Tokenizer::decode`].
+///
+/// Decoding tokens individually is not sufficient for this, for two reasons:
+///
+/// - A token may contain only part of a UTF-8 character. Such tokens produce
+///   no output until the rest of the character has been received.
+/// - Decoders may produce different text for a token depending on its
+///   context. For example the decoder used with SentencePiece models removes
+///   the leading space from the first token.
+///
+/// To handle this, the decoder keeps a window of recent tokens. The window is
+/// split into a prefix, ending at the _prefix offset_, which has already been
+/// returned, and new tokens starting at the _read offset_. Each step decodes
+/// the window with and without the new tokens and returns the difference.
+/// This is the same approach used by Hugging Face's `DecodeStream`.
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use rten_text::models::WordPiece;
+/// use rten_text::{StreamingDecoder, Tokenizer};
+///
+/// let vocab: HashMap<String, u32> = [("hello", 0), ("world", 1)]
+///     .into_iter()
+///     .map(|(token, id)| (token.to_string(), id))
+///     .collect();
+/// let tokenizer = Tokenizer::new(
+///     WordPiece::from_vocab(vocab, Default::default()),
+///     Default::default(),
+/// );
+///
+/// let mut decoder = StreamingDecoder::new(&tokenizer);
+/// assert_eq!(decoder.step(0)?.as_deref(), Some("hello"));
+/// assert_eq!(decoder.step(1)?.as_deref(), Some(" world"));
+/// # Ok::<_, rten_text::TokenizerError>(())
+/// ```
+pub struct StreamingDecoder<'a> {
+    tokenizer: &'a Tokenizer,
+
+    /// Window of recent token IDs.
+    ids: Vec<TokenId>,
+
+    /// Offset in `ids` of the first token used as context for decoding new
+    /// tokens.
+    prefix_offset: usize,
+
+    /// Offset in `ids` of the first token whose text has not been returned.
+    read_offset: usize,
+}
+
+impl<'a> StreamingDecoder<'a> {
+    /// Create a decoder which uses `tokenizer` to decode tokens.
+    pub fn new(tokenizer: &'a Tokenizer) -> Self {
+        StreamingDecoder {
+            tokenizer,
+            ids: Vec::new(),
+            prefix_offset: 0,
+            read_offset: 0,
+        }
+    }
+
+    /// Add a token to the stream and return the text it adds to the output.
+    ///
+    /// Returns `None` if the token does not yet produce any text, for example
+    /// because it is part of an incomplete UTF-8 sequence.
+    ///
+    /// If the token cannot be decoded, an error is returned and the token is
+    /// discarded, so that decoding can continue with the next token.
+    pub fn step(&mut self, id: TokenId) -> Result<Option<String>, TokenizerError> {
+        self.ids.push(id);
+        let result = self.decode_new_tokens();
+        if result.is_err() {
+            self.ids.pop();
+        }
+        result
+    }
+
+    /// Decode the tokens in the window and return the text added by tokens
+    /// after the read offset.
+    fn decode_new_tokens(&mut self) -> Result<Option<String>, TokenizerError> {
+        let prefix_text = self
+            .tokenizer
+            .decode(&self.ids[self.prefix_offset..self.read_offset])?;
+        let new_text = match self.tokenizer.decode(&self.ids[self.prefix_offset..]) {
+            Ok(text) => text,
+            Err(TokenizerError::DecodeError(DecodeError::InvalidUtf8)) => return Ok(None),
+            Err(err) => return Err(err),
+        };
+
+        // If the new token adds no text, for example because it decodes to an
+        // empty string, discard it so that the window does not grow without
+        // bound. At the start of the output the first such token is kept,
+        // because it can affect how later tokens are decoded (eg. a leading
+        // space which is stripped from the first token only).
+        if new_text == prefix_text {
+            if !prefix_text.is_empty() || self.ids.len() > 1 {
+                self.ids.pop();
+            }
+            return Ok(None);
+        }
+
+        // Decoders which replace invalid UTF-8 sequences produce a
+        // replacement character, which may change once more tokens arrive.
+        if new_text.len() <= prefix_text.len() || new_text.ends_with('\u{FFFD}') {
+            return Ok(None);
+        }
+        let Some(delta) = new_text.strip_prefix(prefix_text.as_str()) else {
+            return Err(TokenizerError::InvalidPrefix);
+        };
+        let delta = delta.to_string();
+
+        // Discard tokens that are no longer needed as context.
+        self.ids.drain(..self.read_offset);
+        self.prefix_offset = 0;
+        self.read_offset = self.ids.len();
+
+        Ok(Some(delta))
+    }
+
+    /// Return the token IDs which have been added since text was last
+    /// returned by [`step`](Self::step).
+    ///
+    /// Tokens which do not add any text are not included.
+    pub fn pending(&self) -> &[TokenId] {
+        &self.ids[self.read_offset..]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use rten_testing::TestCases;
+
+    use super::StreamingDecoder;
+    use crate::models::{Bpe, BpeOptions, WordPiece};
+    use crate::pre_tokenizers::Split;
+    use crate::{TokenId, Tokenizer};
+
+    fn wordpiece_tokenizer() -> Tokenizer {
+        let vocab: HashMap<String, TokenId> = ["[UNK]", "one", "two", "##s"]
+            .into_iter()
+            .enumerate()
+            .map(|(id, token)| (token.to_string(), id as TokenId))
+            .collect();
+        Tokenizer::new(
+            WordPiece::from_vocab(vocab, Default::default()),
+            Default::default(),
+        )
+    }
+
+    fn bpe_tokenizer() -> Tokenizer {
+        let model = Bpe::new(BpeOptions::default()).unwrap();
+        Tokenizer::new(model, Default::default()).with_pre_tokenizer(Box::new(Split::gpt2()))
+    }
+
+    /// Create a SentencePiece-style tokenizer with byte fallback, using the
+    /// Metaspace, ByteFallback and Strip decoders.
+    fn metaspace_tokenizer() -> Tokenizer {
+        let json = r#"{
+          "model": {
+            "type": "Unigram",
+            "unk_id": 0,
+            "byte_fallback": true,
+            "vocab": [
+              ["<unk>", 0.0], ["▁hello", -1.0], ["▁world", -1.0], ["▁", -2.0],
+              ["<0xC3>", -3.0], ["<0xA9>", -3.0], ["<0xF0>", -3.0],
+              ["<0x9F>", -3.0], ["<0x98>", -3.0], ["<0x8A>", -3.0]
+            ]
+          },
+          "pre_tokenizer": {
+            "type": "Metaspace",
+            "replacement": "▁",
+            "prepend_scheme": "always",
+            "split": false
+          },
+          "decoder": {
+            "type": "Sequence",
+            "decoders": [
+              {"type": "Replace", "pattern": {"String": "▁"}, "content": " "},
+              {"type": "ByteFallback"},
+              {"type": "Fuse"},
+              {"type": "Strip", "content": " ", "start": 1, "stop": 0}
+            ]
+          }
+        }"#;
+        Tokenizer::from_json(json).unwrap()
+    }
+
+    #[test]
+    fn test_streaming_decoder() {
+        #[derive(Debug)]
+        struct Case {
+            tokenizer: fn() -> Tokenizer,
+            text: &'static str,
+        }
+
+        let cases = [
+            Case {
+                tokenizer: wordpiece_tokenizer,
+                text: "ones two one",
+            },
+            Case {
+                tokenizer: bpe_tokenizer,
+                text: "café 😊 done",
+            },
+            Case {
+                tokenizer: metaspace_tokenizer,
+                text: "hello world é😊 world",
+            },
+        ];
+
+        cases.test_each(|case| {
+            let tokenizer = (case.tokenizer)();
+            let ids = tokenizer.encode(case.text, None).unwrap().into_token_ids();
+            let expected = tokenizer.decode(&ids).unwrap();
+
+            let mut decoder = StreamingDecoder::new(&tokenizer);
+            let mut deltas = Vec::new();
+            for &id in &ids {
+                if let Some(delta) = decoder.step(id).unwrap() {
+                    deltas.push(delta);
+                }
+            }
+
+            assert!(decoder.pending().is_empty());
+            assert_eq!(deltas.concat(), expected);
+        })
+    }
+
+    #[test]
+    fn test_streaming_decoder_metaspace() {
+        let tokenizer = metaspace_tokenizer();
+        let mut decoder = StreamingDecoder::new(&tokenizer);
+
+        // The leading space is stripped from the first token only.
+        assert_eq!(decoder.step(1).unwrap().as_deref(), Some("hello"));
+        assert_eq!(decoder.step(2).unwrap().as_deref(), Some(" world"));
+
+        // Byte tokens produce no output until they form a complete character.
+        assert_eq!(decoder.step(3).unwrap().as_deref(), Some(" "));
+        assert_eq!(decoder.step(4).unwrap(), None);
+        assert_eq!(decoder.pending(), &[4]);
+        assert_eq!(decoder.step(5).unwrap().as_deref(), Some("é"));
+        assert!(decoder.pending().is_empty());
+    }
+
+    #[test]
+    fn test_streaming_decoder_partial_utf8() {
+        let tokenizer = bpe_tokenizer();
+        let ids = tokenizer.encode("😊", None).unwrap().into_token_ids();
+        assert_eq!(ids.len(), 4);
+
+        let mut decoder = StreamingDecoder::new(&tokenizer);
+        let outputs: Vec<_> = ids.iter().map(|&id| decoder.step(id).unwrap()).collect();
+        assert_eq!(outputs, [None, None, None, Some("😊".to_string())]);
+    }
+
+    #[test]
+    fn test_streaming_decoder_invalid_token() {
+        let tokenizer = wordpiece_tokenizer();
+        let mut decoder = StreamingDecoder::new(&tokenizer);
+
+        assert_eq!(decoder.step(1).unwrap().as_deref(), Some("one"));
+        assert!(decoder.step(10).is_err());
+        assert!(decoder.pending().is_empty());
+        assert_eq!(decoder.step(2).unwrap().as_deref(), Some(" two"));
+    }
+
+    #[test]
+    fn test_streaming_decoder_empty_tokens() {
+        // Tokenizer where token 2 decodes to an empty string.
+        let json = r#"{
+          "model": {
+            "type": "WordPiece",
+            "vocab": { "hello": 0, "world": 1, "<empty>": 2 }
+          },
+          "decoder": {
+            "type": "Sequence",
+            "decoders": [
+              {"type": "Replace", "pattern": {"String": "<empty>"}, "content": ""},
+              {"type": "Fuse"}
+            ]
+          }
+        }"#;
+        let tokenizer = Tokenizer::from_json(json).unwrap();
+        let mut decoder = StreamingDecoder::new(&tokenizer);
+
+        assert_eq!(decoder.step(0).unwrap().as_deref(), Some("hello"));
+        for _ in 0..100 {
+            assert_eq!(decoder.step(2).unwrap(), None);
+        }
+        assert!(decoder.pending().is_empty());
+        assert_eq!(decoder.ids.len(), 1);
+        assert_eq!(decoder.step(1).unwrap().as_deref(), Some("world"));
+
+        // Only the first empty token at the start of the output is kept.
+        let mut decoder = StreamingDecoder::new(&tokenizer);
+        for _ in 0..100 {
+            assert_eq!(decoder.step(2).unwrap(), None);
+        }
+        assert_eq!(decoder.pending(), &[2]);
+    }
+}
@@ -0,0 +1,268 @@
+//! Loading of tokenizers from tiktoken BPE rank files.
+//!
+//! These files are used by OpenAI's [tiktoken](https://github.com/openai/tiktoken)
+//! library and some open models. Each line contains a base64-encoded token
+//! and its rank, separated by a space. The rank is also the token ID.
+
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use rustc_hash::FxHashMap;
+
+use super::{AddedToken, Tokenizer, TokenizerOptions};
+use crate::decoders;
+use crate::models::{Bpe, BpeError, BpeOptions, EncodedBytes, byte_to_char};
+use crate::pre_tokenizers::{
+    CL100K_REGEX, PreTokenizeError, Split, SplitDelimiterBehavior, SplitOptions,
+};
+use crate::tokenizer::TokenId;
+
+/// Errors returned by [`Tokenizer::from_tiktoken`].
+#[derive(Debug)]
+pub enum FromTiktokenError {
+    /// There was an error reading the rank file.
+    IoError(std::io::Error),
+    /// A line in the rank file is not a base64-encoded token followed by a
+    /// rank. The associated value is the 1-based line number.
+    InvalidLine(usize),
+    /// The token with the given rank cannot be formed by merging a pair of
+    /// lower-ranked tokens.
+    InvalidToken(TokenId),
+    /// The split pattern is invalid.
+    PreTokenizeError(PreTokenizeError),
+    /// There was an error constructing the BPE model.
+    BpeError(BpeError),
+}
+
+impl fmt::Display for FromTiktokenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IoError(err) => fmt::Display::fmt(err, f),
+            Self::InvalidLine(line) => write!(f, "invalid entry on line {}", line),
+            Self::InvalidToken(rank) => write!(
+                f,
+                "token with rank {} is not a merge of lower-ranked tokens",
+                rank
+            ),
+            Self::PreTokenizeError(err) => write!(f, "failed to construct pre-tokenizer: {}", err),
+            Self::BpeError(err) => write!(f, "BPE tokenizer error: {}", err),
+        }
+    }
+}
+
+impl Error for FromTiktokenError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::IoError(err) => Some(err),
+            Self::InvalidLine(_) | Self::InvalidToken(_) => None,
+            Self::PreTokenizeError(err) => Some(err),
+            Self::BpeError(err) => Some(err),
+        }
+    }
+}
+
+/// Configuration for [`Tokenizer::from_tiktoken`].
+#[derive(Clone, Debug)]
+pub struct TiktokenOptions<'a> {
+    /// Regex used to split text into pieces before applying BPE.
+    ///
+    /// Defaults to [`CL100K_REGEX`]. See also
+    /// [`GPT2_REGEX`](crate::pre_tokenizers::GPT2_REGEX) and
+    /// [`O200K_REGEX`](crate::pre_tokenizers::O200K_REGEX).
+    pub pattern: &'a str,
+
+    /// Special tokens and their IDs. These are not included in rank files.
+    pub special_tokens: &'a [(&'a str, TokenId)],
+}
+
+impl Default for TiktokenOptions<'_> {
+    fn default() -> Self {
+        TiktokenOptions {
+            pattern: CL100K_REGEX,
+            special_tokens: &[],
+        }
+    }
+}
+
+/// Split `token` into the pair of tokens that were merged to produce it.
+///
+/// This runs the BPE algorithm on the bytes of `token` using only tokens with
+/// a rank lower than `max_rank`. For a valid rank file this produces exactly
+/// two parts.
+fn split_token(
+    ranks: &FxHashMap<&[u8], TokenId>,
+    token: &[u8],
+    max_rank: TokenId,
+) -> Option<usize> {
+    let mut parts: Vec<Range<usize>> = (0..token.len()).map(|i| i..i + 1).collect();
+    while parts.len() > 2 {
+        let best = parts
+            .windows(2)
+            .enumerate()
+            .filter_map(|(i, pair)| {
+                let rank = *ranks.get(&token[pair[0].start..pair[1].end])?;
+                (rank < max_rank).then_some((rank, i))
+            })
+            .min();
+        let (_rank, i) = best?;
+        parts[i].end = parts[i + 1].end;
+        parts.remove(i + 1);
+    }
+    (parts.len() == 2).then(|| parts[0].end)
+}
+
+/// Create a tokenizer from the contents of a tiktoken rank file.
+pub(super) fn from_tiktoken(
+    ranks: &str,
+    options: TiktokenOptions,
+) -> Result<Tokenizer, FromTiktokenError> {
+    let mut tokens: Vec<(Vec<u8>, TokenId)> = Vec::new();
+    for (i, line) in ranks.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let invalid_line = || FromTiktokenError::InvalidLine(i + 1);
+        let (token, rank) = line.split_once(' ').ok_or_else(invalid_line)?;
+        let token = BASE64.decode(token).map_err(|_| invalid_line())?;
+        let rank: TokenId = rank.trim().parse().map_err(|_| invalid_line())?;
+        tokens.push((token, rank));
+    }
+    tokens.sort_by_key(|(_token, rank)| *rank);
+
+    let rank_map: FxHashMap<&[u8], TokenId> = tokens
+        .iter()
+        .map(|(token, rank)| (token.as_slice(), *rank))
+        .collect();
+
+    let byte_to_char = byte_to_char();
+    let encode = |bytes: &[u8]| -> EncodedBytes {
+        bytes.iter().map(|&b| byte_to_char[b as usize]).collect()
+    };
+
+    let mut merges = Vec::new();
+    for (token, rank) in tokens.iter().filter(|(token, _)| token.len() > 1) {
+        let split =
+            split_token(&rank_map, token, *rank).ok_or(FromTiktokenError::InvalidToken(*rank))?;
+        let (a, b) = token.split_at(split);
+        merges.push((encode(a).into(), encode(b).into()));
+    }
+
+    let vocab = tokens
+        .iter()
+        .map(|(token, rank)| (encode(token), *rank))
+        .collect();
+    let added_tokens = options
+        .special_tokens
+        .iter()
+        .map(|(content, id)| (*id, content.to_string()))
+        .collect();
+    let model = Bpe::new(BpeOptions {
+        merges: &merges,
+        vocab: Some(vocab),
+        added_tokens,
+        ..Default::default()
+    })
+    .map_err(FromTiktokenError::BpeError)?;
+
+    let pre_tokenizer = Split::new(SplitOptions {
+        pattern: options.pattern,
+        delimiter: SplitDelimiterBehavior::Remove,
+        invert: true,
+    })
+    .map_err(FromTiktokenError::PreTokenizeError)?;
+
+    let special_tokens = options
+        .special_tokens
+        .iter()
+        .map(|(content, id)| AddedToken {
+            content: content.to_string(),
+            id: *id,
+            special: true,
+            lstrip: false,
+            rstrip: false,
+            single_word: false,
+            normalized: false,
+        })
+        .collect();
+
+    let tokenizer = Tokenizer::new(
+        model,
+        TokenizerOptions {
+            cls_token: None,
+            sep_token: None,
+        },
+    )
+    .with_pre_tokenizer(Box::new(pre_tokenizer))
+    .with_decoder(Box::new(decoders::ByteLevel::new()))
+    .with_added_tokens(special_tokens);
+
+    Ok(tokenizer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FromTiktokenError, TiktokenOptions};
+    use crate::Tokenizer;
+    use crate::pre_tokenizers::GPT2_REGEX;
+    use base64::Engine;
+    use base64::engine::general_purpose::STANDARD as BASE64;
+
+    /// Create a rank file containing all single bytes followed by `tokens`.
+    fn rank_file(tokens: &[&str]) -> String {
+        let bytes = (0..=255u8).map(|b| vec![b]);
+        let tokens = tokens.iter().map(|t| t.as_bytes().to_vec());
+        bytes
+            .chain(tokens)
+            .enumerate()
+            .map(|(rank, token)| format!("{} {}\n", BASE64.encode(&token), rank))
+            .collect()
+    }
+
+    #[test]
+    fn test_from_tiktoken() {
+        let ranks = rank_file(&["he", " t", "ll", " the", "hell", "hello"]);
+        let tokenizer = Tokenizer::from_tiktoken(
+            &ranks,
+            TiktokenOptions {
+                pattern: GPT2_REGEX,
+                special_tokens: &[("<|endoftext|>", 262)],
+            },
+        )
+        .unwrap();
+
+        let encoded = tokenizer.encode("hello the<|endoftext|>", None).unwrap();
+        assert_eq!(
+            encoded.token_ids(),
+            // "hello", " the", "<|endoftext|>"
+            &[261, 259, 262]
+        );
+
+        let encoded = tokenizer.encode("hell tell", None).unwrap();
+        assert_eq!(encoded.token_ids(), &[260, 257, 101, 258]);
+        assert_eq!(tokenizer.decode(encoded.token_ids()).unwrap(), "hell tell");
+    }
+
+    #[test]
+    fn test_from_tiktoken_invalid() {
+        let err = Tokenizer::from_tiktoken("aGk=", Default::default())
+            .err()
+            .unwrap();
+        assert!(matches!(err, FromTiktokenError::InvalidLine(1)));
+
+        let err = Tokenizer::from_tiktoken("aGk= one", Default::default())
+            .err()
+            .unwrap();
+        assert!(matches!(err, FromTiktokenError::InvalidLine(1)));
+
+        // "hel" cannot be formed from "he" and "l" because "he" has a higher
+        // rank.
+        let ranks = rank_file(&["hel", "he"]);
+        let err = Tokenizer::from_tiktoken(&ranks, Default::default())
+            .err()
+            .unwrap();
+        assert!(matches!(err, FromTiktokenError::InvalidToken(256)));
+    }
+}
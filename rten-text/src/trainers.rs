@@ -0,0 +1,903 @@
+//! Trainers which learn the vocabulary of a tokenization model from a corpus
+//! of text.
+//!
+//! Training has two steps. First the corpus is split into words using a
+//! [`WordCounts`], which applies an optional normalizer and pre-tokenizer
+//! to each line of text and counts how often each word occurs. Then a trainer
+//! such as [`BpeTrainer`] or [`WordPieceTrainer`] learns a vocabulary from
+//! the word counts. The result is a [`TrainedTokenizer`] which can be saved
+//! as a `tokenizer.json` file and loaded using
+//! [`Tokenizer::from_file`](crate::Tokenizer::from_file).
+//!
+//! ```no_run
+//! use rten_text::pre_tokenizers::Split;
+//! use rten_text::trainers::{BpeTrainer, BpeTrainerOptions, WordCounts};
+//!
+//! let mut counts = WordCounts::new().with_pre_tokenizer(Box::new(Split::gpt2()));
+//! counts.add_file("corpus.txt")?;
+//!
+//! let trainer = BpeTrainer::new(BpeTrainerOptions {
+//!     vocab_size: 8000,
+//!     special_tokens: vec!["<|endoftext|>".into()],
+//!     ..Default::default()
+//! });
+//! trainer.train(&counts).save("tokenizer.json")?;
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! ```
+
+use std::borrow::Cow;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::error::Error;
+use std::fmt;
+use std::path::Path;
+
+use rayon::prelude::*;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::models::char_to_byte;
+use crate::normalizers::{NormalizeError, Normalizer};
+use crate::pre_tokenizers::{PreTokenizeError, PreTokenizer};
+use crate::serde::CowStr;
+use crate::tokenizer::json;
+use crate::tokenizer::{FromJsonError, TokenId, Tokenizer};
+
+/// Errors that can occur when counting words in a training corpus.
+#[derive(Debug)]
+pub enum TrainError {
+    /// Reading a training file failed.
+    IoError(std::io::Error),
+
+    /// Normalizing training text failed.
+    NormalizeError(NormalizeError),
+
+    /// Pre-tokenizing training text failed.
+    PreTokenizeError(PreTokenizeError),
+}
+
+impl fmt::Display for TrainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IoError(err) => write!(f, "failed to read training data: {}", err),
+            Self::NormalizeError(err) => write!(f, "normalization error: {}", err),
+            Self::PreTokenizeError(err) => write!(f, "pretokenization error: {}", err),
+        }
+    }
+}
+
+impl Error for TrainError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::IoError(err) => Some(err),
+            Self::NormalizeError(err) => Some(err),
+            Self::PreTokenizeError(err) => Some(err),
+        }
+    }
+}
+
+impl From<std::io::Error> for TrainError {
+    fn from(val: std::io::Error) -> Self {
+        TrainError::IoError(val)
+    }
+}
+
+impl From<NormalizeError> for TrainError {
+    fn from(val: NormalizeError) -> Self {
+        TrainError::NormalizeError(val)
+    }
+}
+
+impl From<PreTokenizeError> for TrainError {
+    fn from(val: PreTokenizeError) -> Self {
+        TrainError::PreTokenizeError(val)
+    }
+}
+
+/// Counts of words in a training corpus.
+///
+/// Each line of input text is treated as a separate sequence. It is
+/// normalized and split into words using the configured normalizer and
+/// pre-tokenizer, in the same way as [`Tokenizer::encode`].
+#[derive(Default)]
+pub struct WordCounts {
+    normalizer: Option<Box<dyn Normalizer>>,
+    pre_tokenizer: Option<Box<dyn PreTokenizer>>,
+    counts: FxHashMap<String, usize>,
+}
+
+impl WordCounts {
+    /// Create an empty set of word counts which uses each line of input as a
+    /// word, unless a pre-tokenizer is configured.
+    pub fn new() -> WordCounts {
+        Self::default()
+    }
+
+    /// Configure the normalizer applied to input text.
+    pub fn with_normalizer(mut self, normalizer: Box<dyn Normalizer>) -> Self {
+        self.normalizer = Some(normalizer);
+        self
+    }
+
+    /// Configure the pre-tokenizer used to split input text into words.
+    pub fn with_pre_tokenizer(mut self, pre_tokenizer: Box<dyn PreTokenizer>) -> Self {
+        self.pre_tokenizer = Some(pre_tokenizer);
+        self
+    }
+
+    /// Count the words in each line of `text`.
+    pub fn add_text(&mut self, text: &str) -> Result<(), TrainError> {
+        let counts = text
+            .par_lines()
+            .try_fold(FxHashMap::default, |mut counts, line| {
+                self.count_words(line, &mut counts)?;
+                Ok::<_, TrainError>(counts)
+            })
+            .try_reduce(FxHashMap::default, |mut a, b| {
+                for (word, count) in b {
+                    *a.entry(word).or_default() += count;
+                }
+                Ok(a)
+            })?;
+        for (word, count) in counts {
+            *self.counts.entry(word).or_default() += count;
+        }
+        Ok(())
+    }
+
+    /// Count the words in each line of a UTF-8 text file.
+    pub fn add_file<P: AsRef<Path>>(&mut self, path: P) -> Result<(), TrainError> {
+        let text = std::fs::read_to_string(path)?;
+        self.add_text(&text)
+    }
+
+    /// Return the number of distinct words.
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Return true if no words have been counted.
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Return the number of occurrences of `word`.
+    pub fn get(&self, word: &str) -> usize {
+        self.counts.get(word).copied().unwrap_or(0)
+    }
+
+    /// Return the configuration of the normalizer and pre-tokenizer in
+    /// `tokenizer.json` format.
+    ///
+    /// Components which cannot be serialized are omitted.
+    fn to_json(&self) -> (Option<serde_json::Value>, Option<serde_json::Value>) {
+        let normalizer = self.normalizer.as_ref().and_then(|n| n.to_json());
+        let pre_tokenizer = self.pre_tokenizer.as_ref().and_then(|p| p.to_json());
+        (normalizer, pre_tokenizer)
+    }
+
+    fn count_words(
+        &self,
+        line: &str,
+        counts: &mut FxHashMap<String, usize>,
+    ) -> Result<(), TrainError> {
+        let normalized = match &self.normalizer {
+            Some(normalizer) => Cow::Owned(normalizer.normalize(line)?.0),
+            None => Cow::Borrowed(line),
+        };
+        let Some(pre_tokenizer) = &self.pre_tokenizer else {
+            if !normalized.is_empty() {
+                *counts.entry(normalized.into_owned()).or_default() += 1;
+            }
+            return Ok(());
+        };
+
+        for chunk in pre_tokenizer.pre_tokenize(&normalized)? {
+            let is_first = chunk.as_ptr() == normalized.as_ptr();
+            let word = match pre_tokenizer.transform_chunk(chunk, is_first) {
+                Some((text, _offsets)) => Cow::Owned(text),
+                None => Cow::Borrowed(chunk),
+            };
+            if word.is_empty() {
+                continue;
+            }
+            if let Some(count) = counts.get_mut(word.as_ref()) {
+                *count += 1;
+            } else {
+                counts.insert(word.into_owned(), 1);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Vocabulary learned by a trainer, which can be saved in the
+/// `tokenizer.json` format.
+///
+/// The output contains the model, special tokens, the normalizer and
+/// pre-tokenizer used for training and a decoder which matches the model.
+/// Custom normalizers and pre-tokenizers which cannot be serialized are
+/// omitted and need to be added to the [`Tokenizer`] after loading it.
+pub struct TrainedTokenizer {
+    config: json::Tokenizer<'static>,
+}
+
+impl TrainedTokenizer {
+    /// Serialize the tokenizer to `tokenizer.json` format.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self.config).expect("tokenizer should be serializable")
+    }
+
+    /// Save the tokenizer as a `tokenizer.json` file.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), std::io::Error> {
+        std::fs::write(path, self.to_json())
+    }
+
+    /// Create a [`Tokenizer`] which uses the trained model.
+    pub fn into_tokenizer(self) -> Result<Tokenizer, FromJsonError> {
+        Tokenizer::from_parsed_json(self.config)
+    }
+
+    fn new(
+        model: json::Model<'static>,
+        special_tokens: &[String],
+        counts: &WordCounts,
+        decoder: Option<json::Decoder>,
+    ) -> Self {
+        let added_tokens = special_tokens
+            .iter()
+            .enumerate()
+            .map(|(id, content)| json::AddedToken {
+                content: content.clone(),
+                id: id as TokenId,
+                special: true,
+                lstrip: false,
+                rstrip: false,
+                single_word: false,
+                normalized: Some(false),
+            })
+            .collect();
+
+        let (normalizer, pre_tokenizer) = counts.to_json();
+
+        TrainedTokenizer {
+            config: json::Tokenizer {
+                added_tokens: Some(added_tokens),
+                truncation: None,
+                padding: None,
+                normalizer: normalizer.and_then(|n| serde_json::from_value(n).ok()),
+                pre_tokenizer: pre_tokenizer.and_then(|p| serde_json::from_value(p).ok()),
+                model,
+                post_processor: None,
+                decoder,
+            },
+        }
+    }
+}
+
+/// Configuration for a [`BpeTrainer`].
+#[derive(Clone, Debug)]
+pub struct BpeTrainerOptions {
+    /// Maximum number of tokens in the vocabulary, including special tokens
+    /// and the initial alphabet.
+    ///
+    /// Defaults to 30,000.
+    pub vocab_size: usize,
+
+    /// Minimum number of times a pair of tokens must occur in the corpus for
+    /// it to be merged.
+    ///
+    /// Defaults to 2.
+    pub min_frequency: usize,
+
+    /// Special tokens which are assigned the first IDs in the vocabulary.
+    pub special_tokens: Vec<String>,
+
+    /// Use characters rather than bytes as the initial tokens.
+    ///
+    /// See [`BpeOptions::char_level`](crate::models::BpeOptions::char_level).
+    pub char_level: bool,
+
+    /// Token used for unknown characters in character-level models. This is
+    /// added to the special tokens if not already present.
+    pub unk_token: Option<String>,
+
+    /// Add byte tokens of the form `<0xXX>` to the vocabulary of
+    /// character-level models and use them for unknown characters.
+    pub byte_fallback: bool,
+
+    /// Maximum number of characters in the initial alphabet of
+    /// character-level models. The least frequent characters are excluded.
+    pub limit_alphabet: Option<usize>,
+}
+
+impl Default for BpeTrainerOptions {
+    fn default() -> Self {
+        BpeTrainerOptions {
+            vocab_size: 30_000,
+            min_frequency: 2,
+            special_tokens: Vec::new(),
+            char_level: false,
+            unk_token: None,
+            byte_fallback: false,
+            limit_alphabet: None,
+        }
+    }
+}
+
+/// Learns the vocabulary and merge list for a [`Bpe`](crate::models::Bpe)
+/// model.
+///
+/// Starting from an initial vocabulary of bytes or characters, the trainer
+/// repeatedly merges the most frequent pair of adjacent tokens in the corpus
+/// until the vocabulary reaches the requested size, or no pair occurs at least
+/// [`min_frequency`](BpeTrainerOptions::min_frequency) times.
+pub struct BpeTrainer {
+    options: BpeTrainerOptions,
+}
+
+impl BpeTrainer {
+    /// Create a trainer with the given configuration.
+    pub fn new(options: BpeTrainerOptions) -> BpeTrainer {
+        BpeTrainer { options }
+    }
+
+    /// Learn a vocabulary from word counts.
+    pub fn train(&self, counts: &WordCounts) -> TrainedTokenizer {
+        let opts = &self.options;
+        let mut special_tokens = opts.special_tokens.clone();
+        if let Some(unk) = opts.unk_token.as_ref().filter(|_| opts.char_level)
+            && !special_tokens.contains(unk)
+        {
+            special_tokens.push(unk.clone());
+        }
+
+        let mut vocab = Vocab::new(&special_tokens);
+        let words = if opts.char_level {
+            if opts.byte_fallback {
+                for byte in 0..=255u8 {
+                    vocab.add(format!("<0x{:02X}>", byte));
+                }
+            }
+            for ch in alphabet(counts, opts.limit_alphabet) {
+                vocab.add(ch.to_string());
+            }
+            counts
+                .counts
+                .iter()
+                .map(|(word, &count)| {
+                    let symbols = word
+                        .chars()
+                        .filter_map(|ch| vocab.get(ch.encode_utf8(&mut [0; 4])))
+                        .collect();
+                    (symbols, count)
+                })
+                .collect()
+        } else {
+            let mut byte_chars: Vec<_> = char_to_byte().into_iter().collect();
+            byte_chars.sort_by_key(|(_ch, byte)| *byte);
+            let byte_ids: Vec<TokenId> = byte_chars
+                .into_iter()
+                .map(|(ch, _byte)| vocab.add(ch.to_string()))
+                .collect();
+            counts
+                .counts
+                .iter()
+                .map(|(word, &count)| {
+                    let symbols = word.bytes().map(|b| byte_ids[b as usize]).collect();
+                    (symbols, count)
+                })
+                .collect()
+        };
+
+        let merges = learn_merges(
+            words,
+            &mut vocab,
+            opts.vocab_size,
+            opts.min_frequency,
+            |a, b| [a, b].concat(),
+        );
+
+        let unk_token = opts.unk_token.clone().filter(|_| opts.char_level);
+        let model = json::Model::Bpe(json::models::Bpe {
+            merges: json::models::MergeList::Tuple(
+                merges
+                    .into_iter()
+                    .map(|(a, b)| {
+                        (
+                            CowStr(vocab.tokens[a as usize].clone().into()),
+                            CowStr(vocab.tokens[b as usize].clone().into()),
+                        )
+                    })
+                    .collect(),
+            ),
+            vocab: vocab.into_map(),
+            end_of_word_suffix: None,
+            ignore_merges: false,
+            unk_token,
+            byte_fallback: opts.char_level && opts.byte_fallback,
+        });
+
+        let decoder = if opts.char_level {
+            let mut decoders = Vec::new();
+            if opts.byte_fallback {
+                decoders.push(json::Decoder::ByteFallback);
+            }
+
+            // The Metaspace pre-tokenizer and decoder have the same
+            // configuration fields.
+            if let (_, Some(pre_tokenizer)) = counts.to_json()
+                && pre_tokenizer["type"] == "Metaspace"
+                && let Ok(metaspace) = serde_json::from_value(pre_tokenizer)
+            {
+                decoders.push(metaspace);
+            }
+
+            match decoders.len() {
+                0 => None,
+                1 => decoders.pop(),
+                _ => Some(json::Decoder::Sequence(json::decoders::Sequence {
+                    decoders,
+                })),
+            }
+        } else {
+            Some(json::Decoder::ByteLevel)
+        };
+
+        TrainedTokenizer::new(model, &special_tokens, counts, decoder)
+    }
+}
+
+/// Configuration for a [`WordPieceTrainer`].
+#[derive(Clone, Debug)]
+pub struct WordPieceTrainerOptions {
+    /// Maximum number of tokens in the vocabulary, including special tokens
+    /// and the initial alphabet.
+    ///
+    /// Defaults to 30,000.
+    pub vocab_size: usize,
+
+    /// Minimum number of times a pair of tokens must occur in the corpus for
+    /// it to be merged.
+    ///
+    /// Defaults to 2.
+    pub min_frequency: usize,
+
+    /// Special tokens which are assigned the first IDs in the vocabulary.
+    ///
+    /// [`WordPiece`](crate::models::WordPiece) uses `[UNK]` for unknown
+    /// words, so this should be included. Defaults to `[PAD]`, `[UNK]`,
+    /// `[CLS]`, `[SEP]` and `[MASK]`.
+    pub special_tokens: Vec<String>,
+
+    /// Maximum number of characters in the initial alphabet. The least
+    /// frequent characters are excluded.
+    pub limit_alphabet: Option<usize>,
+}
+
+impl Default for WordPieceTrainerOptions {
+    fn default() -> Self {
+        WordPieceTrainerOptions {
+            vocab_size: 30_000,
+            min_frequency: 2,
+            special_tokens: ["[PAD]", "[UNK]", "[CLS]", "[SEP]", "[MASK]"]
+                .map(String::from)
+                .to_vec(),
+            limit_alphabet: None,
+        }
+    }
+}
+
+/// Learns the vocabulary for a [`WordPiece`](crate::models::WordPiece)
+/// model.
+///
+/// Like the Hugging Face Tokenizers implementation, this learns a vocabulary
+/// using BPE merges, where tokens that continue a word have a `##` prefix.
+pub struct WordPieceTrainer {
+    options: WordPieceTrainerOptions,
+}
+
+impl WordPieceTrainer {
+    /// Create a trainer with the given configuration.
+    pub fn new(options: WordPieceTrainerOptions) -> WordPieceTrainer {
+        WordPieceTrainer { options }
+    }
+
+    /// Learn a vocabulary from word counts.
+    pub fn train(&self, counts: &WordCounts) -> TrainedTokenizer {
+        let opts = &self.options;
+        let mut vocab = Vocab::new(&opts.special_tokens);
+
+        let alphabet = alphabet(counts, opts.limit_alphabet);
+        let mut symbol = String::new();
+        let words = counts
+            .counts
+            .iter()
+            .map(|(word, &count)| {
+                let symbols = word
+                    .chars()
+                    .enumerate()
+                    .filter(|(_i, ch)| alphabet.contains(ch))
+                    .map(|(i, ch)| {
+                        symbol.clear();
+                        if i > 0 {
+                            symbol.push_str(SUBWORD_PREFIX);
+                        }
+                        symbol.push(ch);
+                        vocab.get_or_add(&symbol)
+                    })
+                    .collect();
+                (symbols, count)
+            })
+            .collect();
+
+        learn_merges(
+            words,
+            &mut vocab,
+            opts.vocab_size,
+            opts.min_frequency,
+            |a, b| [a, b.strip_prefix(SUBWORD_PREFIX).unwrap_or(b)].concat(),
+        );
+
+        let model = json::Model::WordPiece(json::models::WordPiece {
+            vocab: vocab.into_map().into_iter().collect(),
+            max_input_chars_per_word: None,
+            unk_token: Some("[UNK]".to_string()),
+        });
+        let decoder = json::Decoder::WordPiece(json::decoders::WordPiece {
+            prefix: SUBWORD_PREFIX.to_string(),
+            cleanup: true,
+        });
+        TrainedTokenizer::new(model, &opts.special_tokens, counts, Some(decoder))
+    }
+}
+
+/// Prefix for WordPiece tokens which continue a word.
+const SUBWORD_PREFIX: &str = "##";
+
+/// Vocabulary under construction, where the ID of a token is its index.
+struct Vocab {
+    tokens: Vec<String>,
+    ids: FxHashMap<String, TokenId>,
+}
+
+impl Vocab {
+    fn new(special_tokens: &[String]) -> Vocab {
+        let mut vocab = Vocab {
+            tokens: Vec::new(),
+            ids: FxHashMap::default(),
+        };
+        for token in special_tokens {
+            vocab.get_or_add(token);
+        }
+        vocab
+    }
+
+    fn get(&self, token: &str) -> Option<TokenId> {
+        self.ids.get(token).copied()
+    }
+
+    fn add(&mut self, token: String) -> TokenId {
+        let id = self.tokens.len() as TokenId;
+        self.ids.insert(token.clone(), id);
+        self.tokens.push(token);
+        id
+    }
+
+    fn get_or_add(&mut self, token: &str) -> TokenId {
+        self.get(token)
+            .unwrap_or_else(|| self.add(token.to_string()))
+    }
+
+    fn into_map(self) -> FxHashMap<String, TokenId> {
+        self.ids
+    }
+}
+
+/// Return the characters which occur in `counts`, sorted by code point.
+///
+/// If `limit` is set, only the most frequent `limit` characters are returned.
+fn alphabet(counts: &WordCounts, limit: Option<usize>) -> Vec<char> {
+    let mut char_counts: HashMap<char, usize> = HashMap::new();
+    for (word, count) in &counts.counts {
+        for ch in word.chars() {
+            *char_counts.entry(ch).or_default() += count;
+        }
+    }
+
+    let mut chars: Vec<(char, usize)> = char_counts.into_iter().collect();
+    if let Some(limit) = limit {
+        chars.sort_by_key(|&(ch, count)| (Reverse(count), ch));
+        chars.truncate(limit);
+    }
+    let mut chars: Vec<char> = chars.into_iter().map(|(ch, _count)| ch).collect();
+    chars.sort();
+    chars
+}
+
+/// Learn BPE merges for `words`, which is a list of `(token_ids, count)`
+/// tuples, adding the merged tokens to `vocab`.
+///
+/// `merge` returns the string for the token created by merging two tokens.
+/// Returns the merged pairs in the order they were learned. Ties between
+/// equally frequent pairs are broken by choosing the pair with the lowest IDs,
+/// so the result is deterministic.
+fn learn_merges(
+    words: Vec<(Vec<TokenId>, usize)>,
+    vocab: &mut Vocab,
+    vocab_size: usize,
+    min_frequency: usize,
+    merge: impl Fn(&str, &str) -> String,
+) -> Vec<(TokenId, TokenId)> {
+    type Pair = (TokenId, TokenId);
+
+    let (mut words, counts): (Vec<Vec<TokenId>>, Vec<usize>) = words.into_iter().unzip();
+
+    // Count of each pair and the indices of words that contain it.
+    let mut pair_counts: FxHashMap<Pair, usize> = FxHashMap::default();
+    let mut pair_words: FxHashMap<Pair, FxHashSet<usize>> = FxHashMap::default();
+    for (i, (word, &count)) in words.iter().zip(&counts).enumerate() {
+        for pair in word.windows(2) {
+            let pair = (pair[0], pair[1]);
+            *pair_counts.entry(pair).or_default() += count;
+            pair_words.entry(pair).or_default().insert(i);
+        }
+    }
+
+    // Queue of pairs ordered by count. Entries are not removed when a pair's
+    // count changes, so counts are re-checked when an entry is popped.
+    let mut queue: BinaryHeap<(usize, Reverse<Pair>)> = pair_counts
+        .iter()
+        .map(|(&pair, &count)| (count, Reverse(pair)))
+        .collect();
+
+    let mut merges = Vec::new();
+    let mut changed_pairs = FxHashSet::default();
+
+    while vocab.tokens.len() < vocab_size {
+        let Some((count, Reverse(pair))) = queue.pop() else {
+            break;
+        };
+        let current_count = pair_counts.get(&pair).copied().unwrap_or(0);
+        if count != current_count {
+            if current_count > 0 {
+                queue.push((current_count, Reverse(pair)));
+            }
+            continue;
+        }
+        if count < min_frequency.max(1) {
+            break;
+        }
+
+        let merged = merge(
+            &vocab.tokens[pair.0 as usize],
+            &vocab.tokens[pair.1 as usize],
+        );
+        let merged_id = vocab.get_or_add(&merged);
+        merges.push(pair);
+
+        // Update words containing the pair, and the counts of pairs in those
+        // words.
+        changed_pairs.clear();
+        for i in pair_words.remove(&pair).unwrap_or_default() {
+            let word = &mut words[i];
+            let count = counts[i];
+            for old_pair in word.windows(2) {
+                let old_pair = (old_pair[0], old_pair[1]);
+                if let Some(pair_count) = pair_counts.get_mut(&old_pair) {
+                    *pair_count -= count;
+                }
+            }
+
+            let mut j = 0;
+            while j + 1 < word.len() {
+                if (word[j], word[j + 1]) == pair {
+                    word[j] = merged_id;
+                    word.remove(j + 1);
+                }
+                j += 1;
+            }
+
+            for new_pair in word.windows(2) {
+                let new_pair = (new_pair[0], new_pair[1]);
+                *pair_counts.entry(new_pair).or_default() += count;
+                pair_words.entry(new_pair).or_default().insert(i);
+                changed_pairs.insert(new_pair);
+            }
+        }
+        pair_counts.remove(&pair);
+
+        for changed in changed_pairs.iter() {
+            if let Some(&count) = pair_counts.get(changed)
+                && count > 0
+            {
+                queue.push((count, Reverse(*changed)));
+            }
+        }
+    }
+
+    merges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        BpeTrainer, BpeTrainerOptions, WordCounts, WordPieceTrainer, WordPieceTrainerOptions,
+    };
+    use crate::Tokenizer;
+    use crate::normalizers;
+    use crate::pre_tokenizers::{self, Metaspace, MetaspaceOptions, Split};
+
+    const CORPUS: &str = "
+the cat sat on the mat
+the cat ate the rat
+that rat sat on the hat
+";
+
+    #[test]
+    fn test_word_counts() {
+        let mut counts = WordCounts::new()
+            .with_normalizer(Box::new(normalizers::Unicode::Nfc))
+            .with_pre_tokenizer(Box::new(pre_tokenizers::WhitespaceSplit::new()));
+        counts.add_text(CORPUS).unwrap();
+
+        assert_eq!(counts.len(), 9);
+        assert_eq!(counts.get("the"), 5);
+        assert_eq!(counts.get("rat"), 2);
+        assert_eq!(counts.get("dog"), 0);
+    }
+
+    #[test]
+    fn test_train_bpe_byte_level() {
+        let mut counts = WordCounts::new().with_pre_tokenizer(Box::new(Split::gpt2()));
+        counts.add_text(CORPUS).unwrap();
+
+        let trainer = BpeTrainer::new(BpeTrainerOptions {
+            vocab_size: 270,
+            special_tokens: vec!["<|endoftext|>".into()],
+            ..Default::default()
+        });
+        let json = trainer.train(&counts).to_json();
+
+        let tokenizer = Tokenizer::from_json(&json).unwrap();
+        let model = tokenizer.model();
+        assert_eq!(model.get_token_id("<|endoftext|>"), Some(0));
+
+        // The first merge combines the most frequent pair. For byte-level
+        // models, `get_token_id` takes text rather than encoded bytes.
+        assert_eq!(model.get_token_id("at"), Some(257));
+        assert!(model.get_token_id(" the").is_some());
+
+        let text = "the rat sat on a mat";
+        let encoded = tokenizer.encode(text, None).unwrap();
+        assert!(encoded.token_ids().len() < text.len());
+        assert_eq!(tokenizer.decode(encoded.token_ids()).unwrap(), text);
+
+        // Training is deterministic.
+        assert_eq!(trainer.train(&counts).to_json(), json);
+    }
+
+    #[test]
+    fn test_train_bpe_char_level() {
+        let mut counts = WordCounts::new()
+            .with_pre_tokenizer(Box::new(Metaspace::new(MetaspaceOptions::default())));
+        counts.add_text(CORPUS).unwrap();
+
+        let trainer = BpeTrainer::new(BpeTrainerOptions {
+            vocab_size: 300,
+            char_level: true,
+            unk_token: Some("<unk>".into()),
+            byte_fallback: true,
+            ..Default::default()
+        });
+        let tokenizer = trainer.train(&counts).into_tokenizer().unwrap();
+        let model = tokenizer.model();
+        assert_eq!(model.get_token_id("<unk>"), Some(0));
+        assert_eq!(model.get_token_id("<0x00>"), Some(1));
+        assert!(model.get_token_id("▁the").is_some());
+
+        // Characters not in the corpus ("f" and "é") use byte fallback tokens.
+        let encoded = tokenizer.encode("the café", None).unwrap();
+        let tokens = model.get_tokens(encoded.token_ids()).unwrap();
+        assert_eq!(
+            tokens,
+            ["▁the", "▁", "c", "a", "<0x66>", "<0xC3>", "<0xA9>"]
+        );
+        assert_eq!(tokenizer.decode(encoded.token_ids()).unwrap(), "the café");
+    }
+
+    #[test]
+    fn test_train_wordpiece() {
+        let mut counts =
+            WordCounts::new().with_pre_tokenizer(Box::new(pre_tokenizers::Bert::new()));
+        counts.add_text(CORPUS).unwrap();
+
+        let trainer = WordPieceTrainer::new(WordPieceTrainerOptions {
+            vocab_size: 40,
+            ..Default::default()
+        });
+        let json = trainer.train(&counts).to_json();
+        let tokenizer = Tokenizer::from_json(&json).unwrap();
+        let model = tokenizer.model();
+        assert_eq!(model.get_token_id("[UNK]"), Some(1));
+        assert!(model.get_token_id("##at").is_some());
+
+        let encoded = tokenizer.encode("the cat sat", None).unwrap();
+        let tokens = model.get_tokens(encoded.token_ids()).unwrap();
+        assert_eq!(tokens, ["[CLS]", "the", "cat", "sat", "[SEP]"]);
+
+        let encoded = tokenizer.encode("dog", None).unwrap();
+        let tokens = model.get_tokens(encoded.token_ids()).unwrap();
+        assert_eq!(tokens, ["[CLS]", "[UNK]", "[SEP]"]);
+    }
+
+    #[test]
+    fn test_save_and_reload() {
+        let byte_level_counts = WordCounts::new()
+            .with_normalizer(Box::new(normalizers::Unicode::Nfc))
+            .with_pre_tokenizer(Box::new(Split::gpt2()));
+        let char_level_counts = WordCounts::new()
+            .with_pre_tokenizer(Box::new(Metaspace::new(MetaspaceOptions::default())));
+        let wordpiece_counts = WordCounts::new()
+            .with_normalizer(Box::new(normalizers::Bert::new(normalizers::BertOptions {
+                lowercase: true,
+                ..Default::default()
+            })))
+            .with_pre_tokenizer(Box::new(pre_tokenizers::Bert::new()));
+
+        let bpe_trainer = |char_level| {
+            BpeTrainer::new(BpeTrainerOptions {
+                vocab_size: 300,
+                char_level,
+                unk_token: Some("<unk>".into()),
+                ..Default::default()
+            })
+        };
+        let wordpiece_trainer = WordPieceTrainer::new(WordPieceTrainerOptions {
+            vocab_size: 40,
+            ..Default::default()
+        });
+
+        // Decoded text shows the effect of the normalizer and the unknown
+        // token ("T" does not occur in the corpus).
+        let cases = [
+            (
+                "bpe-byte-level",
+                byte_level_counts,
+                "The cat sat on the hat",
+            ),
+            (
+                "bpe-char-level",
+                char_level_counts,
+                "<unk>he cat sat on the hat",
+            ),
+            (
+                "wordpiece",
+                wordpiece_counts,
+                "[CLS] the cat sat on the hat [SEP]",
+            ),
+        ];
+
+        let text = "The cat sat on the hat";
+        for (name, mut counts, expected_decoded) in cases {
+            counts.add_text(CORPUS).unwrap();
+            let trained = match name {
+                "bpe-byte-level" => bpe_trainer(false).train(&counts),
+                "bpe-char-level" => bpe_trainer(true).train(&counts),
+                _ => wordpiece_trainer.train(&counts),
+            };
+
+            let path = std::env::temp_dir().join(format!("rten-text-trained-{}.json", name));
+            trained.save(&path).unwrap();
+            let reloaded = Tokenizer::from_file(&path).unwrap();
+            std::fs::remove_file(&path).unwrap();
+
+            let tokenizer = trained.into_tokenizer().unwrap();
+            let expected = tokenizer.encode(text, None).unwrap();
+            let encoded = reloaded.encode(text, None).unwrap();
+            assert_eq!(encoded.token_ids(), expected.token_ids(), "{}", name);
+
+            let decoded = reloaded.decode(encoded.token_ids()).unwrap();
+            assert_eq!(decoded, expected_decoded, "{}", name);
+        }
+    }
+}
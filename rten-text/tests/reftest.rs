@@ -4,8 +4,10 @@ use std::fs::read_to_string;
 use std::io;
 use std::path::PathBuf;
 
-use rten_text::models::{Bpe, BpeOptions, WordPiece, merge_pairs_from_lines};
-use rten_text::tokenizer::{TokenId, Tokenizer, TokenizerOptions};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use rten_text::models::{Bpe, BpeOptions, WordPiece, char_to_byte, merge_pairs_from_lines};
+use rten_text::tokenizer::{EncoderInput, TiktokenOptions, TokenId, Tokenizer, TokenizerOptions};
 use rten_text::{normalizers, pre_tokenizers};
 use serde_derive::Deserialize;
 
@@ -24,6 +26,10 @@ fn read_vocab_text_file(path: &str) -> Result<HashMap<String, TokenId>, io::Erro
 #[derive(Deserialize)]
 struct ReferenceTokenization {
     token_ids: Vec<TokenId>,
+
+    /// Sequence IDs of tokens. This is only present in tokenizations created
+    /// with the `tokenizers` package.
+    type_ids: Option<Vec<TokenId>>,
 }
 
 impl ReferenceTokenization {
@@ -185,3 +191,216 @@ fn test_bpe_gpt2() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_unigram() -> Result<(), Box<dyn Error>> {
+    // Unigram model with a 1000-token vocabulary trained on two of the
+    // reference texts. The third text contains characters which are not in
+    // the vocabulary.
+    let tokenizer_path = test_file_path("models/unigram-1k/tokenizer.json");
+    let tokenizer = Tokenizer::from_file(&tokenizer_path)?;
+
+    for text_file in [
+        "Rust_(programming_language).txt",
+        "monty-python-credits.txt",
+        "Metal_umlaut.txt",
+    ] {
+        let text = read_test_file(text_file)?;
+        let reference = text_file.replace(".txt", "-unigram-1k.json");
+        let expected = ReferenceTokenization::from_file(&reference)?;
+        let encoded = tokenizer.encode(text.as_str(), None)?;
+
+        compare_tokens(encoded.token_ids(), &expected.token_ids)?;
+    }
+
+    Ok(())
+}
+
+/// Compare tokenization of the reference texts using a model's
+/// `tokenizer.json` file against reference tokenizations created with the
+/// `tokenizers` package.
+///
+/// This checks the token IDs and type IDs for each text as a single sequence,
+/// and for a pair of texts encoded as two sequences.
+fn test_pretrained_tokenizer(model_name: &str) -> Result<(), Box<dyn Error>> {
+    let name = model_name.replace('/', "_");
+    let tokenizer_path = test_file_path(&format!("models/{name}/tokenizer.json"));
+    let tokenizer = Tokenizer::from_file(&tokenizer_path)?;
+
+    let check_encoding = |input: EncoderInput, reference: &str| -> Result<(), Box<dyn Error>> {
+        let expected = ReferenceTokenization::from_file(reference)?;
+        let encoded = tokenizer.encode(input, None)?;
+        compare_tokens(encoded.token_ids(), &expected.token_ids)?;
+
+        let type_ids: Vec<TokenId> = encoded
+            .token_type_ids()
+            .map(|type_id| type_id as TokenId)
+            .collect();
+        let expected_type_ids = expected
+            .type_ids
+            .ok_or_else(|| format!("{reference} does not contain type IDs"))?;
+        compare_tokens(&type_ids, &expected_type_ids)?;
+
+        Ok(())
+    };
+
+    for text_file in [
+        "Rust_(programming_language).txt",
+        "monty-python-credits.txt",
+        "Metal_umlaut.txt",
+    ] {
+        let text = read_test_file(text_file)?;
+        let reference = text_file.replace(".txt", &format!("-{name}.json"));
+        check_encoding(text.as_str().into(), &reference)?;
+    }
+
+    let text = read_test_file("monty-python-credits.txt")?;
+    let pair_text = read_test_file("Metal_umlaut.txt")?;
+    check_encoding(
+        (text.as_str(), pair_text.as_str()).into(),
+        &format!("monty-python-credits+Metal_umlaut-{name}.json"),
+    )?;
+
+    Ok(())
+}
+
+// The tokenizers for BGE, RoBERTa and Llama 3 and their reference
+// tokenizations are not included in the repository. See
+// `test-data/reftests/README.md` for how to create them.
+#[test]
+#[ignore = "requires BGE tokenizer and reference files"]
+fn test_wordpiece_bge_small_en() -> Result<(), Box<dyn Error>> {
+    test_pretrained_tokenizer("BAAI/bge-small-en")
+}
+
+#[test]
+#[ignore = "requires RoBERTa tokenizer and reference files"]
+fn test_bpe_roberta_base() -> Result<(), Box<dyn Error>> {
+    test_pretrained_tokenizer("FacebookAI/roberta-base")
+}
+
+#[test]
+#[ignore = "requires Llama 3 tokenizer and reference files"]
+fn test_bpe_llama3() -> Result<(), Box<dyn Error>> {
+    test_pretrained_tokenizer("meta-llama/Meta-Llama-3-8B")
+}
+
+#[test]
+fn test_tiktoken_gpt2() -> Result<(), Box<dyn Error>> {
+    // Convert the GPT-2 vocabulary into a tiktoken rank file. In tiktoken's
+    // GPT-2 encoding, the rank of each token is its ID.
+    let vocab: HashMap<String, TokenId> =
+        serde_json::from_str(&read_test_file("models/gpt2/vocab.json")?)?;
+    let char_to_byte = char_to_byte();
+    let mut ranks = String::new();
+    for (token, id) in &vocab {
+        if token == "<|endoftext|>" {
+            continue;
+        }
+        let bytes: Vec<u8> = token.chars().map(|ch| char_to_byte[&ch]).collect();
+        ranks.push_str(&format!("{} {}\n", BASE64.encode(&bytes), id));
+    }
+
+    let tokenizer = Tokenizer::from_tiktoken(
+        &ranks,
+        TiktokenOptions {
+            pattern: pre_tokenizers::GPT2_REGEX,
+            special_tokens: &[("<|endoftext|>", 50256)],
+        },
+    )?;
+
+    let text = read_test_file("monty-python-credits.txt")?;
+    let expected = ReferenceTokenization::from_file("monty-python-credits-gpt2.json")?;
+    let encoded = tokenizer.encode(text.as_str(), None)?;
+    compare_tokens(encoded.token_ids(), &expected.token_ids)?;
+    assert_eq!(tokenizer.decode(encoded.token_ids())?, text);
+
+    let encoded = tokenizer.encode("Hello<|endoftext|>", None)?;
+    assert_eq!(encoded.token_ids(), &[15496, 50256]);
+
+    Ok(())
+}
+
+/// Compare tokenization of the reference texts using a tiktoken rank file
+/// against reference tokenizations created with tiktoken.
+///
+/// `rank_file` is either the full rank file for the encoding, or a subset
+/// which contains all the tokens needed to encode the reference texts. See
+/// `test-data/reftests/README.md` for how these are created.
+fn test_tiktoken_encoding(
+    name: &str,
+    rank_file: &str,
+    pattern: &str,
+    end_of_text: TokenId,
+) -> Result<(), Box<dyn Error>> {
+    let tokenizer = Tokenizer::from_tiktoken_file(
+        test_file_path(rank_file),
+        TiktokenOptions {
+            pattern,
+            special_tokens: &[("<|endoftext|>", end_of_text)],
+        },
+    )?;
+
+    for text_file in [
+        "Rust_(programming_language).txt",
+        "monty-python-credits.txt",
+        "Metal_umlaut.txt",
+    ] {
+        let text = read_test_file(text_file)?;
+        let reference = text_file.replace(".txt", &format!("-{name}.json"));
+        let expected = ReferenceTokenization::from_file(&reference)?;
+        let encoded = tokenizer.encode(text.as_str(), None)?;
+
+        compare_tokens(encoded.token_ids(), &expected.token_ids)?;
+        assert_eq!(tokenizer.decode(encoded.token_ids())?, text);
+    }
+
+    let encoded = tokenizer.encode("<|endoftext|>", None)?;
+    assert_eq!(encoded.token_ids(), &[end_of_text]);
+
+    Ok(())
+}
+
+#[test]
+fn test_tiktoken_cl100k() -> Result<(), Box<dyn Error>> {
+    test_tiktoken_encoding(
+        "cl100k_base",
+        "models/tiktoken-subsets/cl100k_base.tiktoken",
+        pre_tokenizers::CL100K_REGEX,
+        100257,
+    )
+}
+
+// The full rank file is not included in the repository. See
+// `test-data/reftests/README.md` for how to download it.
+#[test]
+#[ignore = "requires cl100k_base rank file"]
+fn test_tiktoken_cl100k_full() -> Result<(), Box<dyn Error>> {
+    test_tiktoken_encoding(
+        "cl100k_base",
+        "models/cl100k_base/cl100k_base.tiktoken",
+        pre_tokenizers::CL100K_REGEX,
+        100257,
+    )
+}
+
+#[test]
+fn test_tiktoken_o200k() -> Result<(), Box<dyn Error>> {
+    test_tiktoken_encoding(
+        "o200k_base",
+        "models/tiktoken-subsets/o200k_base.tiktoken",
+        pre_tokenizers::O200K_REGEX,
+        199999,
+    )
+}
+
+#[test]
+#[ignore = "requires o200k_base rank file"]
+fn test_tiktoken_o200k_full() -> Result<(), Box<dyn Error>> {
+    test_tiktoken_encoding(
+        "o200k_base",
+        "models/o200k_base/o200k_base.tiktoken",
+        pre_tokenizers::O200K_REGEX,
+        199999,
+    )
+}
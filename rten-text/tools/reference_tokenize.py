@@ -1,5 +1,5 @@
 from argparse import ArgumentParser
-from os.path import splitext
+from os.path import basename, splitext
 import json
 
 from tokenizers import Tokenizer
@@ -8,29 +8,76 @@ from tokenizers import Tokenizer
 def main():
     parser = ArgumentParser(
         description="""
-Create a reference tokenization of text using the `tokenizers` package.
+Create a reference tokenization of text using the `tokenizers` package, or
+the `tiktoken` package if `--tiktoken` is specified.
 """
     )
-    parser.add_argument("model_name", help="Name of pretrained model from Hugging Face")
+    parser.add_argument(
+        "model_name",
+        help="Name of pretrained model from Hugging Face, or tiktoken encoding",
+    )
     parser.add_argument("text_file", help="Text to tokenize")
+    parser.add_argument(
+        "--pair",
+        metavar="PAIR_FILE",
+        help="Tokenize the text as a pair of sequences, with PAIR_FILE as the second",
+    )
+    parser.add_argument(
+        "--tiktoken",
+        action="store_true",
+        help="Use a tiktoken encoding (eg. cl100k_base) instead of a Hugging Face tokenizer",
+    )
     args = parser.parse_args()
 
-    tokenizer = Tokenizer.from_pretrained(args.model_name)
-
     with open(args.text_file) as text_fp:
         text = text_fp.read()
 
-    encoded = tokenizer.encode(text)
+    pair_text = None
+    if args.pair:
+        with open(args.pair) as pair_fp:
+            pair_text = pair_fp.read()
+
+    type_ids = None
+    if args.tiktoken:
+        if pair_text is not None:
+            raise ValueError("Sequence pairs are not supported with --tiktoken")
+
+        import tiktoken
+
+        encoding = tiktoken.get_encoding(args.model_name)
+        token_ids = encoding.encode(text)
+        tokens = [
+            encoding.decode_single_token_bytes(id).decode("utf-8", errors="replace")
+            for id in token_ids
+        ]
+    else:
+        tokenizer = Tokenizer.from_pretrained(args.model_name)
+
+        # Reference tokenizations contain the complete output for the input.
+        tokenizer.no_truncation()
+        tokenizer.no_padding()
+
+        encoded = tokenizer.encode(text, pair_text)
+        token_ids = encoded.ids
+        tokens = encoded.tokens
+        type_ids = encoded.type_ids
 
     output = {
         "input_file": args.text_file,
         "model_name": args.model_name,
-        "token_ids": encoded.ids,
-        "tokens": encoded.tokens,
+        "token_ids": token_ids,
+        "tokens": tokens,
     }
+    if args.pair:
+        output["pair_file"] = args.pair
+    if type_ids is not None:
+        output["type_ids"] = type_ids
     json_output = json.dumps(output, indent=2)
 
     text_file_base, _ = splitext(args.text_file)
+    if args.pair:
+        pair_file_base, _ = splitext(basename(args.pair))
+        text_file_base = f"{text_file_base}+{pair_file_base}"
     model_fname = args.model_name.replace("/", "_")
     output_fname = f"{text_file_base}-{model_fname}.json"
     with open(output_fname, "w") as output:
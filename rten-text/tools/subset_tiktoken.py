@@ -0,0 +1,49 @@
+from argparse import ArgumentParser
+import base64
+
+import regex
+import tiktoken
+
+
+def main():
+    parser = ArgumentParser(
+        description="""
+Create a subset of a tiktoken encoding's rank file which produces the same
+tokenization as the full file for the given texts.
+
+The subset contains all single-byte tokens, plus every token which occurs as a
+substring of a piece of the texts after splitting them with the encoding's
+pre-tokenization pattern. BPE merges only produce tokens which are substrings
+of a piece, so other tokens are not needed.
+"""
+    )
+    parser.add_argument("encoding", help="Name of tiktoken encoding (eg. cl100k_base)")
+    parser.add_argument("output_file", help="Path to write rank file to")
+    parser.add_argument("text_files", nargs="+", help="Texts to tokenize")
+    args = parser.parse_args()
+
+    encoding = tiktoken.get_encoding(args.encoding)
+    ranks = encoding._mergeable_ranks
+    pattern = regex.compile(encoding._pat_str)
+
+    subset = {rank for token, rank in ranks.items() if len(token) == 1}
+    for text_file in args.text_files:
+        with open(text_file) as text_fp:
+            text = text_fp.read()
+        for piece in pattern.findall(text):
+            piece = piece.encode("utf-8")
+            for start in range(len(piece)):
+                for end in range(start + 1, len(piece) + 1):
+                    rank = ranks.get(piece[start:end])
+                    if rank is not None:
+                        subset.add(rank)
+
+    tokens = {rank: token for token, rank in ranks.items()}
+    with open(args.output_file, "w") as output:
+        for rank in sorted(subset):
+            token = base64.b64encode(tokens[rank]).decode("ascii")
+            output.write(f"{token} {rank}\n")
+
+
+if __name__ == "__main__":
+    main()
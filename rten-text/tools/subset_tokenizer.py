@@ -0,0 +1,110 @@
+from argparse import ArgumentParser
+import json
+
+
+def merge_pair(merge):
+    """Return the pair of tokens in a BPE merge.
+
+    Merges are stored either as "a b" strings or as [a, b] lists, depending on
+    the version of the `tokenizers` library which saved the file.
+    """
+    if isinstance(merge, str):
+        return tuple(merge.split(" ", 1))
+    return tuple(merge)
+
+
+def subset_bpe(model, keep):
+    """Reduce a BPE model to the tokens in `keep` and the tokens they are
+    created from.
+
+    BPE applies the lowest-ranked merge of adjacent tokens at each step. Every
+    merge which is applied produces a token that is part of one of the output
+    tokens, so keeping all merges which produce kept tokens, and the tokens
+    they merge, leaves the result unchanged.
+    """
+    vocab = model["vocab"]
+    id_to_token = {id: token for token, id in vocab.items()}
+
+    # Initial alphabet, such as the 256 byte-level characters.
+    tokens = {token for token in vocab if len(token) == 1}
+    tokens |= {id_to_token[id] for id in keep if id in id_to_token}
+
+    producers = {}
+    for merge in model["merges"]:
+        a, b = merge_pair(merge)
+        producers.setdefault(a + b, []).append((a, b))
+
+    pending = list(tokens)
+    while pending:
+        token = pending.pop()
+        for a, b in producers.get(token, []):
+            for part in (a, b):
+                if part in vocab and part not in tokens:
+                    tokens.add(part)
+                    pending.append(part)
+
+    model["vocab"] = {token: id for token, id in vocab.items() if token in tokens}
+    model["merges"] = [
+        merge
+        for merge in model["merges"]
+        if all(part in tokens for part in merge_pair(merge))
+        and "".join(merge_pair(merge)) in tokens
+    ]
+
+
+def subset_wordpiece(model, keep):
+    """Reduce a WordPiece model to the tokens in `keep`.
+
+    WordPiece matches the longest token in the vocabulary at each position, so
+    tokens which were not matched can be removed.
+    """
+    model["vocab"] = {
+        token: id
+        for token, id in model["vocab"].items()
+        if id in keep or token == model.get("unk_token")
+    }
+
+
+def main():
+    parser = ArgumentParser(
+        description="""
+Create a subset of a Hugging Face `tokenizer.json` file which produces the
+same tokenization of the reference texts as the full file.
+
+The subset keeps the token IDs from the reference tokenizations created by
+reference_tokenize.py, the added tokens and, for BPE models, the tokens and
+merges needed to create them. Token IDs are unchanged. Only the model's
+vocabulary is modified, so the normalizer, pre-tokenizer, post-processor and
+decoder are the same as in the full file.
+"""
+    )
+    parser.add_argument("tokenizer_file", help="Path to full `tokenizer.json` file")
+    parser.add_argument("output_file", help="Path to write subset `tokenizer.json` to")
+    parser.add_argument(
+        "reference_files", nargs="+", help="Reference tokenizations of the texts"
+    )
+    args = parser.parse_args()
+
+    with open(args.tokenizer_file) as tokenizer_fp:
+        tokenizer = json.load(tokenizer_fp)
+
+    keep = {token["id"] for token in tokenizer.get("added_tokens", [])}
+    for reference_file in args.reference_files:
+        with open(reference_file) as reference_fp:
+            keep |= set(json.load(reference_fp)["token_ids"])
+
+    model = tokenizer["model"]
+    match model["type"]:
+        case "BPE":
+            subset_bpe(model, keep)
+        case "WordPiece":
+            subset_wordpiece(model, keep)
+        case model_type:
+            raise ValueError(f"Unsupported model type {model_type}")
+
+    with open(args.output_file, "w") as output:
+        json.dump(tokenizer, output, indent=2, ensure_ascii=False)
+
+
+if __name__ == "__main__":
+    main()
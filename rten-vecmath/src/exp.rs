@@ -272,13 +272,74 @@ impl SimdUnaryOp<f32> for Elu {
     }
 }
 
+/// Computes the Scaled Exponential Linear Unit function.
+///
+/// Computes `gamma * (if x > 0 { x } else { alpha * exp(x) - alpha })`.
+pub struct Selu {
+    pub alpha: f32,
+    pub gamma: f32,
+}
+
+impl SimdUnaryOp<f32> for Selu {
+    #[inline(always)]
+    fn eval<I: Isa>(&self, isa: I, x: I::F32) -> I::F32 {
+        let ops = isa.f32();
+        let alpha = ops.splat(self.alpha);
+        let x_pos = ops.gt(x, ops.zero());
+        let x_exp = ops.sub(ops.mul(alpha, Exp::apply(isa, x)), alpha);
+        ops.mul(ops.splat(self.gamma), ops.select(x, x_exp, x_pos))
+    }
+}
+
+/// Computes the Continuously Differentiable Exponential Linear Unit function.
+///
+/// Computes `max(0, x) + min(0, alpha * (exp(x / alpha) - 1))`.
+pub struct Celu {
+    pub alpha: f32,
+}
+
+impl SimdUnaryOp<f32> for Celu {
+    #[inline(always)]
+    fn eval<I: Isa>(&self, isa: I, x: I::F32) -> I::F32 {
+        let ops = isa.f32();
+        let alpha = ops.splat(self.alpha);
+        let x_exp = ops.mul(
+            alpha,
+            ops.sub(Exp::apply(isa, ops.div(x, alpha)), ops.one()),
+        );
+        ops.add(ops.max(x, ops.zero()), ops.min(x_exp, ops.zero()))
+    }
+}
+
+/// Computes the Mish activation function, `x * tanh(softplus(x))`.
+///
+/// This uses the identity `tanh(ln(1 + e^x)) = n / (n + 2)` where
+/// `n = e^x * (e^x + 2)`, which avoids evaluating a logarithm.
+pub struct Mish {}
+
+impl SimdUnaryOp<f32> for Mish {
+    #[inline(always)]
+    fn eval<I: Isa>(&self, isa: I, x: I::F32) -> I::F32 {
+        let ops = isa.f32();
+
+        // Above this cutoff `tanh(softplus(x))` rounds to 1 and `n` may
+        // overflow.
+        let x_large = ops.ge(x, ops.splat(20.));
+
+        let e = Exp::apply(isa, x);
+        let n = ops.mul(e, ops.add(e, ops.splat(2.)));
+        let y = ops.div(ops.mul(x, n), ops.add(n, ops.splat(2.)));
+        ops.select(x, y, x_large)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rten_simd::SimdUnaryOp;
 
     use super::{EXP_LOWER_CUTOFF, ReducedRangeExp};
     use crate::testing::{AllF32s, Tolerance, UnaryOpTester, arange, benchmark_op};
-    use crate::{Elu, Exp, Sigmoid, Silu, Swish};
+    use crate::{Celu, Elu, Exp, Mish, Selu, Sigmoid, Silu, Swish};
 
     // Maximum error of `Exp` compared to Rust standard library implementation.
     const MAX_EXP_ERROR_ULPS: f32 = 1.0;
@@ -290,6 +351,18 @@ mod tests {
         if x >= 0. { x } else { alpha * (x.exp() - 1.) }
     }
 
+    fn reference_selu(x: f32, alpha: f32, gamma: f32) -> f32 {
+        gamma * if x > 0. { x } else { alpha * x.exp() - alpha }
+    }
+
+    fn reference_celu(x: f32, alpha: f32) -> f32 {
+        x.max(0.) + (alpha * ((x / alpha).exp() - 1.)).min(0.)
+    }
+
+    fn reference_mish(x: f32) -> f32 {
+        x * x.exp().ln_1p().tanh()
+    }
+
     fn reference_sigmoid(x: f32) -> f32 {
         1. / (1. + (-x).exp())
     }
@@ -358,6 +431,46 @@ mod tests {
         test.run();
     }
 
+    #[test]
+    fn test_selu() {
+        let alpha = 1.67326;
+        let gamma = 1.0507;
+        let test = UnaryOpTester {
+            reference: |x| reference_selu(x, alpha, gamma),
+            simd: Selu { alpha, gamma },
+            range: arange(-6., 6., 0.001),
+            // Relative error is larger for inputs close to zero, as
+            // `exp(x) - 1` suffers from cancellation there.
+            tolerance: Tolerance::Absolute(1e-6),
+        };
+        test.run();
+    }
+
+    #[test]
+    fn test_celu() {
+        let alpha = 2.0;
+        let test = UnaryOpTester {
+            reference: |x| reference_celu(x, alpha),
+            simd: Celu { alpha },
+            range: arange(-6., 6., 0.001),
+            // Relative error is larger for inputs close to zero, as
+            // `exp(x) - 1` suffers from cancellation there.
+            tolerance: Tolerance::Absolute(1e-6),
+        };
+        test.run();
+    }
+
+    #[test]
+    fn test_mish() {
+        let test = UnaryOpTester {
+            reference: reference_mish,
+            simd: Mish {},
+            range: arange(-10., 30., 0.001),
+            tolerance: Tolerance::Ulp(8.0),
+        };
+        test.run();
+    }
+
     #[test]
     #[ignore] // Ignored by default due to long runtime
     fn test_exp_exhaustive() {
@@ -0,0 +1,177 @@
+use std::mem::MaybeUninit;
+
+use rten_base::byte_cast::cast_pod_slice;
+use rten_base::half::{bf16, f16};
+use rten_simd::ops::{Extend, IntOps, NumOps};
+use rten_simd::{Isa, Simd, SimdOp, SliceWriter};
+
+/// Convert a slice of [`f16`] elements to `f32`.
+pub struct F16ToF32<'s, 'd> {
+    src: &'s [f16],
+    dest: &'d mut [MaybeUninit<f32>],
+}
+
+impl<'s, 'd> F16ToF32<'s, 'd> {
+    pub fn new(src: &'s [f16], dest: &'d mut [MaybeUninit<f32>]) -> Self {
+        assert_eq!(src.len(), dest.len());
+        F16ToF32 { src, dest }
+    }
+}
+
+impl<'d> SimdOp for F16ToF32<'_, 'd> {
+    type Output = &'d mut [f32];
+
+    #[inline(always)]
+    fn eval<I: Isa>(self, isa: I) -> Self::Output {
+        let i16_ops = isa.i16();
+        let i32_ops = isa.i32();
+        let f32_ops = isa.f32();
+
+        let exp_man_mask = i32_ops.splat(0x7FFF);
+        let sign_mask = i32_ops.splat(0x8000);
+        let inf_nan_threshold = i32_ops.splat(0x7BFF);
+        let f32_inf_exp = i32_ops.splat(0x7F80_0000);
+
+        // Multiplying a value whose f16 exponent and mantissa bits have been
+        // shifted into the f32 position by 2^112 rebiases the exponent from
+        // 15 to 127. This handles zeros, subnormals and normal values.
+        let rebias = f32_ops.splat(f32::from_bits(0x7780_0000));
+
+        let convert = |x: I::I32| -> I::F32 {
+            let exp_man = i32_ops.and(x, exp_man_mask);
+            let sign = i32_ops.shift_left::<16>(i32_ops.and(x, sign_mask));
+            let shifted = i32_ops.shift_left::<13>(exp_man);
+
+            let finite = f32_ops.mul(shifted.reinterpret_cast(), rebias);
+            let inf_nan: I::F32 = i32_ops.or(shifted, f32_inf_exp).reinterpret_cast();
+            let is_inf_nan = i32_ops.gt(exp_man, inf_nan_threshold);
+            let abs: I::I32 = f32_ops
+                .select(inf_nan, finite, is_inf_nan)
+                .reinterpret_cast();
+            i32_ops.or(abs, sign).reinterpret_cast()
+        };
+
+        let src: &[i16] = cast_pod_slice(self.src).unwrap();
+        let mut src_chunks = src.chunks_exact(i16_ops.len());
+        let mut dest_writer = SliceWriter::new(self.dest);
+
+        for src_chunk in src_chunks.by_ref() {
+            let (lo, hi) = i16_ops.extend(i16_ops.load(src_chunk));
+            dest_writer.write_vec(f32_ops, convert(lo));
+            dest_writer.write_vec(f32_ops, convert(hi));
+        }
+
+        for x in src_chunks.remainder() {
+            dest_writer.write_scalar(f16::from_bits(*x as u16).to_f32());
+        }
+
+        dest_writer.into_mut_slice()
+    }
+}
+
+/// Convert a slice of [`bf16`] elements to `f32`.
+pub struct Bf16ToF32<'s, 'd> {
+    src: &'s [bf16],
+    dest: &'d mut [MaybeUninit<f32>],
+}
+
+impl<'s, 'd> Bf16ToF32<'s, 'd> {
+    pub fn new(src: &'s [bf16], dest: &'d mut [MaybeUninit<f32>]) -> Self {
+        assert_eq!(src.len(), dest.len());
+        Bf16ToF32 { src, dest }
+    }
+}
+
+impl<'d> SimdOp for Bf16ToF32<'_, 'd> {
+    type Output = &'d mut [f32];
+
+    #[inline(always)]
+    fn eval<I: Isa>(self, isa: I) -> Self::Output {
+        let i16_ops = isa.i16();
+        let i32_ops = isa.i32();
+        let f32_ops = isa.f32();
+
+        let src: &[i16] = cast_pod_slice(self.src).unwrap();
+        let mut src_chunks = src.chunks_exact(i16_ops.len());
+        let mut dest_writer = SliceWriter::new(self.dest);
+
+        // bf16 values are the upper 16 bits of an f32. Sign-extension when
+        // widening is harmless since the low bits are shifted out.
+        for src_chunk in src_chunks.by_ref() {
+            let (lo, hi) = i16_ops.extend(i16_ops.load(src_chunk));
+            dest_writer.write_vec(f32_ops, i32_ops.shift_left::<16>(lo).reinterpret_cast());
+            dest_writer.write_vec(f32_ops, i32_ops.shift_left::<16>(hi).reinterpret_cast());
+        }
+
+        for x in src_chunks.remainder() {
+            dest_writer.write_scalar(bf16::from_bits(*x as u16).to_f32());
+        }
+
+        dest_writer.into_mut_slice()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rten_base::half::{bf16, f16};
+    use rten_simd::SimdOp;
+
+    use super::{Bf16ToF32, F16ToF32};
+
+    /// Return a set of values covering zeros, subnormals, normals, infinities
+    /// and NaN, padded with random values so that there is a tail.
+    fn test_values() -> Vec<f32> {
+        let mut rng = fastrand::Rng::with_seed(1234);
+        let mut values = vec![
+            0.,
+            -0.,
+            1.,
+            -1.,
+            0.333,
+            65504.,
+            -65504.,
+            1e-7,  // f16 subnormal
+            -3e-5, // f16 subnormal
+            6.1e-5,
+            1e6, // f16 overflow
+            3.0e38,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            f32::NAN,
+        ];
+        values.extend(std::iter::from_fn(|| Some((rng.f32() - 0.5) * 1000.)).take(61));
+        values
+    }
+
+    fn assert_same(actual: &[f32], expected: &[f32]) {
+        assert_eq!(actual.len(), expected.len());
+        for (i, (x, y)) in actual.iter().zip(expected).enumerate() {
+            assert!(
+                x.to_bits() == y.to_bits() || (x.is_nan() && y.is_nan()),
+                "mismatch at {i}: {x} vs {y}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_f16_to_f32() {
+        let src: Vec<f16> = test_values().into_iter().map(f16::from_f32).collect();
+        let expected: Vec<f32> = src.iter().map(|x| x.to_f32()).collect();
+
+        let mut buf = Vec::with_capacity(src.len());
+        let actual = F16ToF32::new(&src, buf.spare_capacity_mut()).dispatch();
+
+        assert_same(actual, &expected);
+    }
+
+    #[test]
+    fn test_bf16_to_f32() {
+        let src: Vec<bf16> = test_values().into_iter().map(bf16::from_f32).collect();
+        let expected: Vec<f32> = src.iter().map(|x| x.to_f32()).collect();
+
+        let mut buf = Vec::with_capacity(src.len());
+        let actual = Bf16ToF32::new(&src, buf.spare_capacity_mut()).dispatch();
+
+        assert_same(actual, &expected);
+    }
+}
@@ -96,6 +96,7 @@
 
 mod erf;
 mod exp;
+mod half;
 mod min_max;
 mod normalize;
 mod quantize;
@@ -115,12 +116,15 @@ mod extend_init;
 
 // Unary functions.
 pub use erf::{ApproxGelu, Erf, Gelu};
-pub use exp::{Elu, Exp, Sigmoid, Silu, Swish};
+pub use exp::{Celu, Elu, Exp, Mish, Selu, Sigmoid, Silu, Swish};
 pub use quantize::Quantize;
-pub use relu::LeakyRelu;
+pub use relu::{LeakyRelu, Shrink, ThresholdedRelu};
 pub use sin_cos::{Cos, Sin};
 pub use tanh::Tanh;
 
+// Conversion functions.
+pub use half::{Bf16ToF32, F16ToF32};
+
 // Normalization and reduction functions.
 pub use min_max::{MaxNum, MinMax, MinNum};
 pub use normalize::{Normalize, NormalizeOptions};
@@ -24,16 +24,64 @@ impl SimdUnaryOp<f32> for LeakyRelu {
     }
 }
 
+/// Computes the thresholded ReLU activation function.
+///
+/// This evaluates `if x > alpha { x } else { 0. }`.
+pub struct ThresholdedRelu {
+    pub alpha: f32,
+}
+
+impl SimdUnaryOp<f32> for ThresholdedRelu {
+    #[inline(always)]
+    fn eval<I: Isa>(&self, isa: I, x: I::F32) -> I::F32 {
+        let ops = isa.f32();
+        let x_above = ops.gt(x, ops.splat(self.alpha));
+        ops.select(x, ops.zero(), x_above)
+    }
+}
+
+/// Computes the shrink function.
+///
+/// This evaluates `x + bias` if `x < -lambd`, `x - bias` if `x > lambd` and
+/// zero otherwise.
+pub struct Shrink {
+    pub bias: f32,
+    pub lambd: f32,
+}
+
+impl SimdUnaryOp<f32> for Shrink {
+    #[inline(always)]
+    fn eval<I: Isa>(&self, isa: I, x: I::F32) -> I::F32 {
+        let ops = isa.f32();
+        let bias = ops.splat(self.bias);
+        let lambd = ops.splat(self.lambd);
+        let x_below = ops.lt(x, ops.splat(-self.lambd));
+        let x_above = ops.gt(x, lambd);
+        let y = ops.select(ops.sub(x, bias), ops.zero(), x_above);
+        ops.select(ops.add(x, bias), y, x_below)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::testing::{Tolerance, UnaryOpTester};
 
-    use super::LeakyRelu;
+    use super::{LeakyRelu, Shrink, ThresholdedRelu};
 
     fn reference_leaky_relu(x: f32, alpha: f32) -> f32 {
         if x < 0. { alpha * x } else { x }
     }
 
+    fn reference_shrink(x: f32, bias: f32, lambd: f32) -> f32 {
+        if x < -lambd {
+            x + bias
+        } else if x > lambd {
+            x - bias
+        } else {
+            0.
+        }
+    }
+
     #[test]
     fn test_leaky_relu() {
         let alpha = 0.5;
@@ -45,4 +93,29 @@ mod tests {
         };
         test.run();
     }
+
+    #[test]
+    fn test_thresholded_relu() {
+        let alpha = 1.0;
+        let test = UnaryOpTester {
+            reference: |x: f32| if x > alpha { x } else { 0. },
+            simd: ThresholdedRelu { alpha },
+            range: [-2., -1., 0., 0.5, 1., 1.5, 2.].iter().copied(),
+            tolerance: Tolerance::Ulp(0.0),
+        };
+        test.run();
+    }
+
+    #[test]
+    fn test_shrink() {
+        let bias = 1.5;
+        let lambd = 1.0;
+        let test = UnaryOpTester {
+            reference: |x: f32| reference_shrink(x, bias, lambd),
+            simd: Shrink { bias, lambd },
+            range: [-2., -1., -0.5, 0., 0.5, 1., 2.].iter().copied(),
+            tolerance: Tolerance::Ulp(0.0),
+        };
+        test.run();
+    }
 }
@@ -4,6 +4,7 @@ use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use rayon::prelude::*;
+use rten_tensor::Tensor;
 use rten_tensor::prelude::*;
 
 // The std HashMap/HashSet provide DOS resistance. In this module hash keys are
@@ -13,9 +14,10 @@ use rustc_hash::{FxHashMap, FxHashSet};
 
 use smallvec::SmallVec;
 
-use crate::buffer_pool::BufferPool;
+use crate::buffer_pool::{AutoReturn, BufferPool};
 use crate::env::env_flag;
 use crate::operator::{InputList, OpRunContext, Operator, OutputList, PrepackedInput};
+use crate::ops::narrow_to_i32;
 use crate::threading;
 use crate::timing::{Instant, ProfileFormat, Profiler, TimingFilter, TimingRecord, TimingSort};
 use crate::value::{Value, ValueMeta, ValueOrView, ValueType, ValueView};
@@ -681,6 +683,9 @@ impl Graph {
 
     /// Compute a set of output values given a set of inputs, using the
     /// processing steps and constant values defined by the graph.
+    ///
+    /// `inputs` may include constant nodes, in which case the provided value
+    /// is used instead of the constant's value for this run.
     pub fn run(
         &self,
         inputs: Vec<(NodeId, ValueOrView)>,
@@ -807,22 +812,41 @@ impl Graph {
         //
         // This enables these inputs to be used for in-place operations or
         // returned directly as outputs.
+        //
+        // Inputs which replace the values of constants are kept separately,
+        // as constants are never passed to operators as owned values.
+        let mut constant_overrides = FxHashMap::<NodeId, Value>::default();
         let mut idx = 0;
         while idx < inputs.len() {
             if matches!(inputs[idx], (_, ValueOrView::Value(_))) {
                 let (node_id, ValueOrView::Value(outp)) = inputs.remove(idx) else {
                     unreachable!();
                 };
-                temp_values.insert(node_id, outp);
+                if let Some(Node::Constant(_)) = self.nodes.get(&node_id) {
+                    constant_overrides.insert(node_id, outp);
+                } else {
+                    temp_values.insert(node_id, outp);
+                }
             } else {
                 idx += 1;
             }
         }
 
         let inputs_by_id: FxHashMap<NodeId, ValueOrView> = inputs.iter().cloned().collect();
+        let is_overridden_constant = |node_id: NodeId| {
+            constant_overrides.contains_key(&node_id)
+                || (inputs_by_id.contains_key(&node_id)
+                    && matches!(self.nodes.get(&node_id), Some(Node::Constant(_))))
+        };
         let get_value_from_constant_or_input = |node_id: NodeId| -> Option<ValueView> {
             match self.nodes.get(&node_id) {
-                Some(Node::Constant(constant)) => Some(constant.as_view()),
+                Some(Node::Constant(constant)) => Some(
+                    constant_overrides
+                        .get(&node_id)
+                        .map(|value| value.as_view())
+                        .or_else(|| inputs_by_id.get(&node_id).map(|input| input.as_view()))
+                        .unwrap_or_else(|| constant.as_view()),
+                ),
                 Some(Node::Value(_)) => inputs_by_id.get(&node_id).map(|input| input.as_view()),
                 _ => {
                     panic!("node {} is not a value or constant", node_id);
@@ -935,6 +959,28 @@ impl Graph {
                 None
             };
 
+            // Convert an `i64` or `bool` in-place input to `i32` if the
+            // operator doesn't support these types.
+            let in_place_input = match in_place_input {
+                Some((id, value)) if !op_node.operator().supports_int64_bool_input(0) => {
+                    match narrow_to_i32(pool, &value.as_view()) {
+                        Ok(Some(narrowed)) => {
+                            value.add_to_pool(pool);
+                            Some((id, narrowed.into()))
+                        }
+                        Ok(None) => Some((id, value)),
+                        Err(err) => {
+                            return Err(RunError::op_input_error(
+                                op_node.name().unwrap_or_default(),
+                                err,
+                                [Some(value.to_meta())].into(),
+                            ));
+                        }
+                    }
+                }
+                other => other,
+            };
+
             // Extract values used by the operator's subgraphs which can be
             // passed by value.
             let subgraph_op = op_node.operator().as_subgraph_op();
@@ -985,6 +1031,49 @@ impl Graph {
                 }
             }
 
+            // Convert `i64` and `bool` inputs to `i32` for operator inputs which
+            // don't support these types.
+            let first_input_index = in_place_input.is_some() as usize;
+            let mut narrowed_inputs: SmallVec<[(usize, Tensor<i32>); 1]> = SmallVec::new();
+            for (i, input) in op_inputs.iter().enumerate() {
+                let Some(input) = input else {
+                    continue;
+                };
+                if op_node
+                    .operator()
+                    .supports_int64_bool_input(first_input_index + i)
+                {
+                    continue;
+                }
+                match narrow_to_i32(pool, input) {
+                    Ok(Some(narrowed)) => narrowed_inputs.push((i, narrowed)),
+                    Ok(None) => {}
+                    Err(err) => {
+                        return Err(RunError::op_input_error(
+                            op_node.name().unwrap_or_default(),
+                            err,
+                            op_inputs
+                                .iter()
+                                .map(|inp| inp.as_ref().map(|inp| inp.to_meta()))
+                                .collect(),
+                        ));
+                    }
+                }
+            }
+            let op_inputs: SmallVec<[Option<ValueView>; 4]> = op_inputs
+                .into_iter()
+                .enumerate()
+                .map(|(i, input)| {
+                    match narrowed_inputs
+                        .iter()
+                        .find(|(narrowed_index, _)| *narrowed_index == i)
+                    {
+                        Some((_, narrowed)) => Some(narrowed.view().into()),
+                        None => input,
+                    }
+                })
+                .collect();
+
             // Collect input metadata if we'll need it for timing or logging.
             let input_meta = if opts.timing_by_shape || opts.verbose {
                 // Record the input value IDs and metadata together here because
@@ -1014,6 +1103,7 @@ impl Graph {
                     .get(input_index)
                     .copied()
                     .flatten()
+                    .filter(|node_id| !is_overridden_constant(*node_id))
                     .and_then(|node_id| weight_cache.and_then(|wc| wc.get(node_id)))
             };
             let inputs = InputList::from_optional(&op_inputs)
@@ -1064,6 +1154,9 @@ impl Graph {
                     .map_err(|e| RunError::op_error(op_node.name().unwrap_or_default(), e, &ctx))
             };
             std::mem::drop(op_inputs);
+            for (_, narrowed) in narrowed_inputs {
+                narrowed.auto_return(pool);
+            }
 
             // Print verbose logs if enabled. This is done before checking the
             // op's result, so logs will contain details of the failed operation
@@ -221,6 +221,9 @@ impl Expr {
                     Value::Int8Tensor(value) => {
                         graph.add_constant(Some(name.as_str()), value.clone().into_arc())
                     }
+                    Value::Int64Tensor(value) => {
+                        graph.add_constant(Some(name.as_str()), value.clone().into_arc())
+                    }
                     _ => unimplemented!("constant type not supported"),
                 };
                 [const_id].into()
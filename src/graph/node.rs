@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::fmt;
 use std::sync::Arc;
 
+use rten_base::half::{bf16, f16};
 use rten_tensor::prelude::*;
 use rten_tensor::{ArcTensor, DynLayout, TensorView};
 
@@ -244,6 +245,10 @@ pub enum Constant {
     Int32(ConstantNode<i32>),
     Int8(ConstantNode<i8>),
     UInt8(ConstantNode<u8>),
+    Float16(ConstantNode<f16>),
+    BFloat16(ConstantNode<bf16>),
+    Int64(ConstantNode<i64>),
+    Bool(ConstantNode<bool>),
 }
 
 impl Constant {
@@ -260,6 +265,10 @@ impl Constant {
             Constant::Int32(i) => i.name.as_deref(),
             Constant::Int8(i) => i.name.as_deref(),
             Constant::UInt8(i) => i.name.as_deref(),
+            Constant::Float16(i) => i.name.as_deref(),
+            Constant::BFloat16(i) => i.name.as_deref(),
+            Constant::Int64(i) => i.name.as_deref(),
+            Constant::Bool(i) => i.name.as_deref(),
         }
     }
 
@@ -279,6 +288,10 @@ impl Constant {
             Constant::Int32(i) => i.clone_ref().map(Constant::Int32),
             Constant::Int8(i) => i.clone_ref().map(Constant::Int8),
             Constant::UInt8(i) => i.clone_ref().map(Constant::UInt8),
+            Constant::Float16(i) => i.clone_ref().map(Constant::Float16),
+            Constant::BFloat16(i) => i.clone_ref().map(Constant::BFloat16),
+            Constant::Int64(i) => i.clone_ref().map(Constant::Int64),
+            Constant::Bool(i) => i.clone_ref().map(Constant::Bool),
         }
     }
 
@@ -288,6 +301,10 @@ impl Constant {
             Constant::Int32(i) => i.layout(),
             Constant::Int8(i) => i.layout(),
             Constant::UInt8(i) => i.layout(),
+            Constant::Float16(i) => i.layout(),
+            Constant::BFloat16(i) => i.layout(),
+            Constant::Int64(i) => i.layout(),
+            Constant::Bool(i) => i.layout(),
         }
     }
 
@@ -298,6 +315,10 @@ impl Constant {
             Constant::Int32(i) => ValueView::Int32Tensor(i.view()),
             Constant::Int8(i) => ValueView::Int8Tensor(i.view()),
             Constant::UInt8(i) => ValueView::UInt8Tensor(i.view()),
+            Constant::Float16(i) => ValueView::Float16Tensor(i.view()),
+            Constant::BFloat16(i) => ValueView::BFloat16Tensor(i.view()),
+            Constant::Int64(i) => ValueView::Int64Tensor(i.view()),
+            Constant::Bool(i) => ValueView::BoolTensor(i.view()),
         }
     }
 
@@ -307,6 +328,10 @@ impl Constant {
             Constant::Int32(_) => DataType::Int32,
             Constant::Int8(_) => DataType::Int8,
             Constant::UInt8(_) => DataType::UInt8,
+            Constant::Float16(_) => DataType::Float16,
+            Constant::BFloat16(_) => DataType::BFloat16,
+            Constant::Int64(_) => DataType::Int64,
+            Constant::Bool(_) => DataType::Bool,
         }
     }
 }
@@ -362,6 +387,10 @@ impl_constant_node!(f32, Float);
 impl_constant_node!(i32, Int32);
 impl_constant_node!(i8, Int8);
 impl_constant_node!(u8, UInt8);
+impl_constant_node!(f16, Float16);
+impl_constant_node!(bf16, BFloat16);
+impl_constant_node!(i64, Int64);
+impl_constant_node!(bool, Bool);
 
 /// Data for a constant node (ie. model weights) in a [`Graph`].
 ///
@@ -441,3 +470,7 @@ impl_typed_constant!(f32, Float);
 impl_typed_constant!(i32, Int32);
 impl_typed_constant!(i8, Int8);
 impl_typed_constant!(u8, UInt8);
+impl_typed_constant!(f16, Float16);
+impl_typed_constant!(bf16, BFloat16);
+impl_typed_constant!(i64, Int64);
+impl_typed_constant!(bool, Bool);
@@ -61,6 +61,21 @@ impl RunError {
         .into()
     }
 
+    /// Create an error for a failure to prepare inputs before running an
+    /// operator.
+    pub(crate) fn op_input_error(
+        name: &str,
+        error: OpError,
+        inputs: Vec<Option<ValueMeta>>,
+    ) -> Self {
+        RunErrorImpl::OperatorError {
+            name: name.to_string(),
+            error,
+            inputs,
+        }
+        .into()
+    }
+
     pub(crate) fn subgraph_error(name: Option<&str>, error: Self) -> Self {
         RunErrorImpl::SubgraphError {
             name: name.unwrap_or_default().to_string(),
@@ -704,6 +704,44 @@ fn test_err_if_missing_operator_input() {
     );
 }
 
+#[test]
+fn test_converts_int64_and_bool_inputs() {
+    let mut g = Graph::new();
+    let a_id = g.add_value(Some("a"), None, None);
+    let b_id = g.add_value(Some("b"), None, None);
+    let (_, output) = g.add_simple_op("add", Add {}, &[a_id, b_id]);
+
+    // `Add` does not support i64 or bool inputs, so they are converted to i32.
+    let a = Tensor::from([1i64, 2, 3]);
+    let b = Tensor::from([true, false, true]);
+    let result = g
+        .run(
+            vec![(a_id, a.view().into()), (b_id, b.view().into())],
+            &[output],
+            None,
+            None,
+        )
+        .unwrap();
+    assert_eq!(
+        result[0].as_tensor_view::<i32>().unwrap(),
+        Tensor::from([2, 2, 4]).view()
+    );
+
+    // Conversion fails if i64 values are out of range.
+    let a = Tensor::from([1i64 << 40]);
+    let err = g
+        .run(
+            vec![(a_id, a.view().into()), (b_id, b.view().into())],
+            &[output],
+            None,
+            None,
+        )
+        .err()
+        .unwrap();
+    assert_eq!(err.kind(), RunErrorKind::OperatorError);
+    assert!(err.to_string().contains("outside the i32 range"));
+}
+
 #[derive(Debug)]
 struct AddOneInPlace {}
 impl Operator for AddOneInPlace {
@@ -1050,6 +1088,51 @@ fn test_partial_run() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn test_override_constant() -> Result<(), Box<dyn Error>> {
+    let mut g = Graph::new();
+    let weight = g.add_constant(Some("weight"), Tensor::from([1., 2.]).into_arc());
+    let input = g.add_value(Some("input"), None, None);
+    let (_, output) = g.add_simple_op("Mul", Mul {}, &[input, weight]);
+
+    let x = Tensor::from([3., 4.]);
+
+    // Run with the constant's own value.
+    let result = g.run(vec![(input, x.view().into())], &[output], None, None)?;
+    assert_eq!(result[0], Value::FloatTensor(Tensor::from([3., 8.])));
+
+    // Override the constant with a view.
+    let new_weight = Tensor::from([10., 20.]);
+    let result = g.run(
+        vec![(input, x.view().into()), (weight, new_weight.view().into())],
+        &[output],
+        None,
+        None,
+    )?;
+    assert_eq!(result[0], Value::FloatTensor(Tensor::from([30., 80.])));
+
+    // Override the constant with an owned value.
+    let result = g.run(
+        vec![
+            (input, x.view().into()),
+            (weight, Tensor::from([-1., -2.]).into()),
+        ],
+        &[output],
+        None,
+        None,
+    )?;
+    assert_eq!(result[0], Value::FloatTensor(Tensor::from([-3., -8.])));
+
+    // Overrides passed to a partial run are returned so they can be passed
+    // to a later run.
+    let partial_outs = g.partial_run(vec![(weight, new_weight.view().into())], &[output], None)?;
+    assert_eq!(partial_outs.len(), 1);
+    assert_eq!(partial_outs[0].0, weight);
+    assert_eq!(partial_outs[0].1, Value::FloatTensor(new_weight.clone()));
+
+    Ok(())
+}
+
 #[derive(Debug)]
 struct Counter {
     count: AtomicI32,
@@ -189,11 +189,23 @@ pub fn infer_shapes(graph: &Graph, opts: InferShapeOptions) -> Result<InferResul
                                 graph.get_node(id)?.dtype()
                             }
                         })
+                        .map(|dtype| {
+                            // Match the conversion of `i64` and `bool` inputs
+                            // by the graph executor.
+                            if op.operator().supports_int64_bool_input(index as usize) {
+                                dtype
+                            } else {
+                                dtype.narrow_int()
+                            }
+                        })
                 };
 
                 let dtype = match output_type {
                     OutputType::Fixed(dtype) => Some(dtype),
                     OutputType::CopyFromInput(index) => get_input_type(index),
+                    OutputType::WidenedFromInput(index) => {
+                        get_input_type(index).map(|t| t.widen_float())
+                    }
                     OutputType::ElementTypeOfInputSequence(index) => {
                         get_input_type(index).map(|t| t.to_tensor_type())
                     }
@@ -314,6 +326,15 @@ pub fn infer_shapes(graph: &Graph, opts: InferShapeOptions) -> Result<InferResul
     })
 }
 
+/// Convert an i64 constant value into a symbolic value, with saturation.
+///
+/// Symbolic values are i32. i64 values outside this range are typically values
+/// such as `i64::MAX` used to slice to the end of a dimension, so saturating
+/// them preserves their meaning.
+fn saturating_sym_value(x: i64) -> SymExpr {
+    SymExpr::Value(x.clamp(i32::MIN as i64, i32::MAX as i64) as i32)
+}
+
 /// Convert an operator input into a symbolic tensor.
 ///
 /// If the input is a constant, we can use its shape and values directly. If
@@ -336,6 +357,13 @@ fn sym_tensor_from_input(
             } else if let Some(vec) = constant.as_vector() {
                 let vec = vec.iter().copied().map(SymExpr::Value).collect();
                 SymTensor::from_vec(vec)
+            } else if let Some(scalar) = TypedConstant::<i64>::as_scalar(constant)
+                && constant.ndim() == 0
+            {
+                SymTensor::from_scalar(saturating_sym_value(scalar))
+            } else if let Some(vec) = TypedConstant::<i64>::as_vector(constant) {
+                let vec = vec.iter().copied().map(saturating_sym_value).collect();
+                SymTensor::from_vec(vec)
             } else {
                 SymTensor::from_fixed_shape(constant.shape())
             }
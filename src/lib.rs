@@ -27,7 +27,9 @@
 //!
 //! RTen can load and run ONNX models directly, but it also supports a custom
 //! [`.rten` file format][rten_format]. Models can be converted from ONNX to
-//! this format via [rten-convert](https://pypi.org/project/rten-convert/). The
+//! this format using the `rten convert` command from
+//! [rten-cli](https://crates.io/crates/rten-cli), or programmatically using
+//! [`Model::save`]. The
 //! `.rten` format can be faster to load and supports large (> 2GB) models in a
 //! single file, whereas ONNX models of this size must use external files for
 //! weights. It is recommended to start with the ONNX format and consider
@@ -80,20 +82,21 @@
 //!
 //! RTen supports tensors with the following data types:
 //!
-//! - `f32`, `i32`, `i8`, `u8`
-//! - `i64` and `bool` tensors are supported by converting them to `i32`
-//!   tensors, on the assumption that the values in `i64` tensors will be in the
-//!   `i32` range. When preparing model inputs that expect these data types in
-//!   ONNX, you will need to convert them to `i32`.
-//! - `f64` tensors are supported by converting them to `f32`.
+//! - `f32`, `i32`, `i8`, `u8`, `i64`, `bool`
+//! - `f16` and `bf16` tensors are supported for weights. Operators which use
+//!   these weights (eg. `MatMul`, `Gather`) compute in `f32`. Other `f16`,
+//!   `bf16` and `f64` tensors are converted to `f32`.
+//!
+//! `i64` and `bool` tensors in models, including weights and other constants,
+//! are kept in their original type when loaded. Indexing, shape manipulation,
+//! comparison and logical operators support `i64` and `bool` inputs directly.
+//! Other operators convert `i64` and `bool` inputs to `i32` when they are run,
+//! and return an error if an `i64` value is outside the `i32` range.
 //!
 //! Some operators support a more limited set of data types than described in
 //! the ONNX specification. Please file an issue if you need an operator to
 //! support additional data types.
 //!
-//! Support for additional types (eg. `f16`, `bf16`) is planned for the
-//! future.
-//!
 //! ## Supported operators
 //!
 //! RTen supports most ONNX operators. See the [tracking
@@ -144,13 +147,16 @@
 //!
 //!  - **all-ops** - Enables all operators which are not enabled by default
 //!  - **fft** - Enables FFT operators
+//!  - **gguf_format** - Enables support for loading llama-family models from
+//!    `.gguf` files.
 //!  - **mmap** - Enable loading models with memory mapping via [`Model::load_mmap`]
 //!  - **onnx_format** (enabled by default) - Enables support for loading `.onnx` models.
 //!  - **random** - Enables operators that generate random numbers
 //!  - **rten_format** (enabled by default) - Enables support for loading `.rten` models.
 //!  - **wasm_api** - Generate WebAssembly API using wasm-bindgen
 //!
-//! At least one of the **onnx_format** or **rten_format** features must be enabled.
+//! At least one of the **gguf_format**, **onnx_format** or **rten_format**
+//! features must be enabled.
 //!
 //! [model_formats]: https://github.com/robertknight/rten/blob/main/docs/model-formats.md
 //! [onnx_operators]: https://onnx.ai/onnx/operators/
@@ -191,6 +197,9 @@ pub use graph::{Dimension, NodeId, RunError, RunErrorKind, RunOptions};
 pub use model::{
     LoadError, LoadErrorKind, Model, ModelMetadata, ModelOptions, NodeInfo, ShapeInferenceMode,
 };
+
+#[cfg(feature = "rten_format")]
+pub use model::{SaveError, SaveOptions};
 pub use op_registry::{OpRegistry, RegisterOp, op_types};
 pub use ops::{FloatOperators, Operators};
 pub use threading::{ThreadPool, thread_pool};
@@ -16,7 +16,7 @@ use crate::infer_shapes::InferShapeOptions;
 use crate::op_registry::OpRegistry;
 use crate::optimize::OptimizeOptions;
 use crate::timing::{TimingFilter, TimingSort};
-use crate::value::{Value, ValueOrView, ValueType};
+use crate::value::{Value, ValueOrView, ValueType, ValueView};
 use crate::weight_cache::WeightCache;
 
 #[cfg(feature = "onnx_format")]
@@ -26,20 +26,29 @@ mod file_type;
 mod load_error;
 mod metadata;
 
+#[cfg(feature = "gguf_format")]
+mod gguf_loader;
+
 #[cfg(feature = "onnx_format")]
 mod onnx_loader;
 
 #[cfg(feature = "rten_format")]
 mod rten_loader;
 
+#[cfg(feature = "rten_format")]
+mod rten_writer;
+
 pub use load_error::{LoadError, LoadErrorKind};
 pub use metadata::ModelMetadata;
 
+#[cfg(feature = "rten_format")]
+pub use rten_writer::{SaveError, SaveOptions};
+
 use file_type::FileType;
 use load_error::LoadErrorImpl;
 
-#[cfg(test)]
-pub mod rten_builder;
+#[cfg(feature = "rten_format")]
+pub(crate) mod rten_builder;
 
 #[cfg(all(test, feature = "onnx_format"))]
 pub mod onnx_builder;
@@ -293,10 +302,13 @@ pub struct Model {
     graph: Graph,
     metadata: ModelMetadata,
     weight_cache: WeightCache,
+
+    /// True if graph optimizations have been applied to `graph`.
+    optimized: bool,
 }
 
 impl Model {
-    /// Load a serialized model from a `.onnx` or `.rten` file.
+    /// Load a serialized model from a `.onnx`, `.rten` or `.gguf` file.
     ///
     /// This method reads the entire file into memory. For large models (hundreds
     /// of MB or more), [`load_mmap`](Model::load_mmap) can be faster.
@@ -311,7 +323,7 @@ impl Model {
 
     /// Load a serialized model from a byte buffer.
     ///
-    /// The model can be in either ONNX or RTen format. The model type is
+    /// The model can be in ONNX, RTen or GGUF format. The model type is
     /// detected automatically.
     ///
     /// # External data
@@ -327,7 +339,7 @@ impl Model {
     /// This is useful for loading models embedded in the binary via
     /// [`include_bytes`] for example.
     ///
-    /// The model can be in either ONNX or RTen format. The model type is
+    /// The model can be in ONNX, RTen or GGUF format. The model type is
     /// detected automatically.
     ///
     /// # External data
@@ -364,13 +376,14 @@ impl Model {
     ///
     ///  - ONNX files with external data (eg. a `model.onnx` file with weights
     ///    stored in `model.onnx.data`)
-    ///  - .rten format model files created via [rten-convert](https://pypi.org/project/rten-convert/)
+    ///  - .rten format model files created via `rten convert` or [`Model::save`]
     ///
     /// For ONNX files with embedded weights, `load_mmap` will fall back to
     /// copying the weights into private memory, the same as if `load_file` was
     /// used. The reason for this is that tensor data needs to be appropriately
     /// aligned and this is not the case for `.onnx` files with embedded
-    /// weights.
+    /// weights. GGUF files are also copied, since their weights are converted
+    /// to a different format when loaded.
     ///
     /// # External data
     ///
@@ -434,11 +447,96 @@ impl Model {
         self.graph.get_node(id).map(|node| NodeInfo { node })
     }
 
+    /// Return the value of a constant node (eg. a weight) in the model's
+    /// graph.
+    ///
+    /// Returns `None` if the node does not exist or is not a constant.
+    pub fn constant(&self, id: NodeId) -> Option<ValueView<'_>> {
+        match self.graph.get_node(id) {
+            Some(Node::Constant(constant)) => Some(constant.as_view()),
+            _ => None,
+        }
+    }
+
+    /// Return the IDs of the operator nodes which use a node as an input.
+    ///
+    /// Use [`node_info`](Model::node_info) to get the operator types.
+    pub fn consumers(&self, id: NodeId) -> &[NodeId] {
+        self.graph.get_consumers(id).unwrap_or_default()
+    }
+
+    /// Return true if a constant node has been pre-packed into an optimized
+    /// layout for the operators that use it.
+    ///
+    /// See [`ModelOptions::prepack_weights`].
+    pub fn is_prepacked(&self, id: NodeId) -> bool {
+        self.weight_cache.contains(id)
+    }
+
     /// Return metadata about the model.
     pub fn metadata(&self) -> &ModelMetadata {
         &self.metadata
     }
 
+    /// Serialize the model to a buffer in the `.rten` format.
+    ///
+    /// This can be used to convert models loaded from `.onnx` files to
+    /// `.rten` format. The serialized graph is the one used by this model.
+    ///
+    /// If the model was loaded with graph optimization enabled (the default),
+    /// the optimized graph is saved and the file is marked with the version
+    /// of RTen that optimized it. Loading the file with the same version of
+    /// RTen will skip graph optimization. Other versions will load the graph
+    /// and optimize it again. To save the graph as it was before optimization,
+    /// load the model with optimization disabled (see
+    /// [`ModelOptions::enable_optimization`]).
+    ///
+    /// Only the standard `.rten` metadata fields are preserved.
+    ///
+    /// This method requires the `rten_format` crate feature to be enabled.
+    #[cfg(feature = "rten_format")]
+    pub fn serialize(&self) -> Result<Vec<u8>, SaveError> {
+        self.serialize_with_options(&SaveOptions::default())
+    }
+
+    /// Serialize the model to a buffer in the `.rten` format, with custom
+    /// options.
+    ///
+    /// See [`serialize`](Self::serialize) for details.
+    #[cfg(feature = "rten_format")]
+    pub fn serialize_with_options(&self, opts: &SaveOptions) -> Result<Vec<u8>, SaveError> {
+        rten_writer::serialize_model(
+            &self.graph,
+            &self.metadata,
+            self.optimized,
+            &self.weight_cache,
+            opts,
+        )
+    }
+
+    /// Serialize the model in `.rten` format and write it to a file.
+    ///
+    /// See [`serialize`](Self::serialize) for details.
+    #[cfg(feature = "rten_format")]
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), SaveError> {
+        self.save_with_options(path, &SaveOptions::default())
+    }
+
+    /// Serialize the model in `.rten` format with custom options and write it
+    /// to a file.
+    ///
+    /// See [`serialize`](Self::serialize) for details.
+    #[cfg(feature = "rten_format")]
+    pub fn save_with_options<P: AsRef<Path>>(
+        &self,
+        path: P,
+        opts: &SaveOptions,
+    ) -> Result<(), SaveError> {
+        let data = self.serialize_with_options(opts)?;
+        std::fs::write(path, data)?;
+        Ok(())
+    }
+
     /// Return the IDs of input nodes.
     pub fn input_ids(&self) -> &[NodeId] {
         self.graph.input_ids()
@@ -472,6 +570,12 @@ impl Model {
     ///
     /// The input and output nodes are specified via IDs looked up via
     /// [`node_id`](Model::node_id).
+    ///
+    /// Inputs may also specify the IDs of constant nodes, such as weights. In
+    /// that case the provided value replaces the constant's value for this
+    /// run. This can be used to substitute fine-tuned weights without
+    /// reloading the model. Overriding a weight disables any pre-packing of it
+    /// (see [`ModelOptions::prepack_weights`]).
     pub fn run(
         &self,
         inputs: Vec<(NodeId, ValueOrView)>,
@@ -597,6 +701,14 @@ impl<'a> NodeInfo<'a> {
     pub fn dtype(&self) -> Option<ValueType> {
         self.node.dtype()
     }
+
+    /// Return the operator type (eg. "MatMul") if this is an operator node.
+    pub fn op_type(&self) -> Option<&'a str> {
+        match self.node {
+            Node::Operator(op_node) => Some(op_node.operator().name()),
+            _ => None,
+        }
+    }
 }
 
 impl<'a> std::fmt::Debug for NodeInfo<'a> {
@@ -767,6 +879,13 @@ impl ModelOptions {
             }
             #[cfg(not(feature = "onnx_format"))]
             FileType::Onnx => Err(LoadErrorImpl::FormatNotEnabled.into()),
+            #[cfg(feature = "gguf_format")]
+            FileType::Gguf => gguf_loader::load(
+                &std::fs::read(&path).map_err(LoadErrorImpl::ReadFailed)?,
+                self,
+            ),
+            #[cfg(not(feature = "gguf_format"))]
+            FileType::Gguf => Err(LoadErrorImpl::FormatNotEnabled.into()),
         }
     }
 
@@ -796,6 +915,10 @@ impl ModelOptions {
             }
             #[cfg(not(feature = "onnx_format"))]
             FileType::Onnx => Err(LoadErrorImpl::FormatNotEnabled.into()),
+            #[cfg(feature = "gguf_format")]
+            FileType::Gguf => gguf_loader::load(&data, self),
+            #[cfg(not(feature = "gguf_format"))]
+            FileType::Gguf => Err(LoadErrorImpl::FormatNotEnabled.into()),
         }
     }
 
@@ -817,6 +940,10 @@ impl ModelOptions {
             }
             #[cfg(not(feature = "onnx_format"))]
             FileType::Onnx => Err(LoadErrorImpl::FormatNotEnabled.into()),
+            #[cfg(feature = "gguf_format")]
+            FileType::Gguf => gguf_loader::load(data, self),
+            #[cfg(not(feature = "gguf_format"))]
+            FileType::Gguf => Err(LoadErrorImpl::FormatNotEnabled.into()),
         }
     }
 
@@ -864,6 +991,10 @@ impl ModelOptions {
             }
             #[cfg(not(feature = "onnx_format"))]
             FileType::Onnx => Err(LoadErrorImpl::FormatNotEnabled.into()),
+            #[cfg(feature = "gguf_format")]
+            FileType::Gguf => gguf_loader::load(&mmap, self),
+            #[cfg(not(feature = "gguf_format"))]
+            FileType::Gguf => Err(LoadErrorImpl::FormatNotEnabled.into()),
         }
     }
 
@@ -911,18 +1042,21 @@ enum OptimizeMode {
 
 #[cfg(test)]
 mod tests {
+    use rten_base::half::f16;
+    use rten_shape_inference::SymExpr;
     use rten_tensor::prelude::*;
     use rten_tensor::{NdTensor, Tensor};
 
-    use crate::graph::{Dimension, NodeId, RunErrorKind};
+    use crate::graph::{Constant, Dimension, Node, NodeId, RunErrorKind};
     use crate::model::rten_builder::{
-        GraphBuilder, IfArgs, MetadataArgs, ModelBuilder, ModelFormat, OpType,
+        GraphBuilder, IfArgs, MetadataArgs, ModelBuilder, ModelFormat, OpType, TransformInputsArgs,
     };
-    use crate::model::{LoadErrorKind, Model, ModelOptions};
+    use crate::model::{LoadErrorKind, Model, ModelOptions, SaveOptions};
     use crate::op_registry;
     use crate::ops;
     use crate::ops::{
-        BoxOrder, CoordTransformMode, DepthToSpaceMode, NearestMode, ResizeMode, Shape,
+        BitShiftDirection, BoxOrder, CoordTransformMode, DepthToSpaceMode, NearestMode, PadMode,
+        ResizeMode, RoiAlignMode, RoiAlignOptions, Shape,
     };
     use crate::value::{DataType, Scalar, Value, ValueType};
 
@@ -959,6 +1093,7 @@ mod tests {
         builder.set_graph(graph);
         builder.add_metadata(MetadataArgs {
             onnx_hash: Some("abc".to_string()),
+            ..Default::default()
         });
 
         builder.finish()
@@ -1308,6 +1443,288 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_save() {
+        let buffer = generate_model_buffer(ModelFormat::V1);
+        let model = Model::load(buffer).unwrap();
+
+        let path = std::env::temp_dir().join("rten-model-save-test.rten");
+        model.save(&path).unwrap();
+        let saved_model = Model::load_file(&path).unwrap();
+
+        assert_eq!(saved_model.metadata().onnx_hash(), Some("abc"));
+        let input_info = saved_model.node_info(saved_model.input_ids()[0]).unwrap();
+        assert_eq!(input_info.name(), Some("input"));
+        assert_eq!(input_info.shape().unwrap(), [1, 2, 2].map(Dimension::Fixed));
+        assert_eq!(input_info.dtype(), Some(ValueType::Tensor(DataType::Float)));
+
+        let result = saved_model
+            .run(
+                vec![(saved_model.input_ids()[0], generate_input().into())],
+                saved_model.output_ids(),
+                None,
+            )
+            .unwrap();
+        check_output(result);
+    }
+
+    /// Generate a model containing a MatMul + Add sequence which is fused
+    /// by graph optimization.
+    fn generate_matmul_add_model(optimization_info: Option<&str>) -> Vec<u8> {
+        let mut builder = ModelBuilder::new(ModelFormat::V2);
+        let mut graph_builder = builder.graph_builder();
+
+        let input = graph_builder.add_value("input", None, None);
+        let weights =
+            graph_builder.add_constant(Tensor::from_data(&[2, 2], vec![1., 2., 3., 4.]).view());
+        let bias = graph_builder.add_constant(Tensor::from([1., 2.]).view());
+        let matmul_out = graph_builder.add_value("matmul_out", None, None);
+        let output = graph_builder.add_value("output", None, None);
+        graph_builder.add_input(input);
+        graph_builder.add_output(output);
+        graph_builder.add_operator(
+            "matmul",
+            OpType::MatMul,
+            &[input, weights].map(Some),
+            &[matmul_out],
+        );
+        graph_builder.add_operator("add", OpType::Add, &[matmul_out, bias].map(Some), &[output]);
+        let graph = graph_builder.finish();
+        builder.set_graph(graph);
+        if let Some(version) = optimization_info {
+            builder.set_optimization_info(version);
+        }
+        builder.finish()
+    }
+
+    fn op_names(model: &Model) -> Vec<&str> {
+        let mut names: Vec<&str> = model
+            .graph()
+            .iter()
+            .filter_map(|(_, node)| match node {
+                Node::Operator(op) => Some(op.operator().name()),
+                _ => None,
+            })
+            .collect();
+        names.sort();
+        names
+    }
+
+    fn run_matmul_add_model(model: &Model) -> Tensor<f32> {
+        let input = Tensor::from([[1., 2.], [3., 4.]]);
+        model
+            .run_one(input.into(), None)
+            .unwrap()
+            .try_into()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_save_fused_operator() {
+        let buffer = generate_matmul_add_model(None);
+
+        // When optimization is enabled, the MatMul and Add are fused. Saving
+        // the model should preserve the fused operator and loading it should
+        // skip re-optimizing the graph.
+        let model = Model::load(buffer.clone()).unwrap();
+        assert_eq!(op_names(&model), ["FusedMatMul"]);
+        let expected = run_matmul_add_model(&model);
+
+        let saved_model = ModelOptions::with_all_ops()
+            .enable_optimization(false)
+            .load(model.serialize().unwrap())
+            .unwrap();
+        assert!(saved_model.optimized);
+        assert_eq!(op_names(&saved_model), ["FusedMatMul"]);
+        assert_eq!(run_matmul_add_model(&saved_model), expected);
+
+        // Models saved without optimization are optimized when loaded.
+        let model = ModelOptions::with_all_ops()
+            .enable_optimization(false)
+            .load(buffer)
+            .unwrap();
+        assert!(!model.optimized);
+        assert_eq!(op_names(&model), ["Add", "MatMul"]);
+        let saved_model = Model::load(model.serialize().unwrap()).unwrap();
+        assert_eq!(op_names(&saved_model), ["FusedMatMul"]);
+        assert_eq!(run_matmul_add_model(&saved_model), expected);
+    }
+
+    #[test]
+    fn test_load_optimized_model_from_other_version() {
+        // If the model was optimized by a different version of RTen, the
+        // graph is optimized again when loaded.
+        let buffer = generate_matmul_add_model(Some("0.0.0"));
+        let model = Model::load(buffer).unwrap();
+        assert_eq!(op_names(&model), ["FusedMatMul"]);
+
+        let buffer = generate_matmul_add_model(Some(env!("CARGO_PKG_VERSION")));
+        let model = Model::load(buffer).unwrap();
+        assert_eq!(op_names(&model), ["Add", "MatMul"]);
+    }
+
+    #[test]
+    fn test_constant_consumers() {
+        let buffer = generate_matmul_add_model(None);
+        let model = ModelOptions::with_all_ops()
+            .prepack_weights(true)
+            .load(buffer)
+            .unwrap();
+        let (weights, _) = model
+            .graph()
+            .iter()
+            .find(|(_, node)| {
+                matches!(node, Node::Constant(_)) && node.shape().is_some_and(|s| s.len() == 2)
+            })
+            .unwrap();
+
+        let op_types: Vec<_> = model
+            .consumers(weights)
+            .iter()
+            .filter_map(|id| model.node_info(*id)?.op_type())
+            .collect();
+        assert_eq!(op_types, ["FusedMatMul"]);
+        assert_eq!(model.node_info(weights).unwrap().op_type(), None);
+        assert!(model.is_prepacked(weights));
+    }
+
+    #[test]
+    fn test_save_prepacked_weights() {
+        let buffer = generate_matmul_add_model(None);
+        let model = ModelOptions::with_all_ops()
+            .prepack_weights(true)
+            .load(buffer)
+            .unwrap();
+        assert_eq!(model.weight_cache.len(), 1);
+        let expected = run_matmul_add_model(&model);
+
+        // Prepacked weights are only saved if requested.
+        let saved_model = Model::load(model.serialize().unwrap()).unwrap();
+        assert_eq!(saved_model.weight_cache.len(), 0);
+
+        let mut save_opts = SaveOptions::default();
+        save_opts.prepacked_weights(true);
+        let saved_buf = model.serialize_with_options(&save_opts).unwrap();
+
+        // Prepacked weights are loaded from the file if prepacking is enabled.
+        let saved_model = ModelOptions::with_all_ops()
+            .prepack_weights(true)
+            .load(saved_buf.clone())
+            .unwrap();
+        assert_eq!(saved_model.weight_cache.len(), 1);
+        assert_eq!(run_matmul_add_model(&saved_model), expected);
+
+        // When prepacking is disabled, the saved weights are ignored.
+        let saved_model = Model::load(saved_buf).unwrap();
+        assert_eq!(saved_model.weight_cache.len(), 0);
+        assert_eq!(run_matmul_add_model(&saved_model), expected);
+    }
+
+    #[test]
+    fn test_half_float_weights() {
+        for format in [ModelFormat::V1, ModelFormat::V2] {
+            let mut builder = ModelBuilder::new(format);
+            let mut graph_builder = builder.graph_builder();
+
+            let input = graph_builder.add_value("input", None, None);
+            let weights = Tensor::from([[1., 2.], [3., 4.]]).map(|x| f16::from_f32(*x));
+            let weights = graph_builder.add_constant(weights.view());
+            let output = graph_builder.add_value("output", None, None);
+            graph_builder.add_input(input);
+            graph_builder.add_output(output);
+            graph_builder.add_operator(
+                "matmul",
+                OpType::MatMul,
+                &[input, weights].map(Some),
+                &[output],
+            );
+            let graph = graph_builder.finish();
+            builder.set_graph(graph);
+            let buffer = builder.finish();
+
+            // Weights should stay in 16-bit form when loaded and saved.
+            let model = Model::load(buffer).unwrap();
+            let saved_model = Model::load(model.serialize().unwrap()).unwrap();
+            assert!(
+                saved_model
+                    .graph()
+                    .iter()
+                    .any(|(_, node)| matches!(node, Node::Constant(Constant::Float16(_))))
+            );
+
+            for model in [&model, &saved_model] {
+                assert_eq!(
+                    run_matmul_add_model(model),
+                    Tensor::from([[7., 10.], [15., 22.]])
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_int64_and_bool_constants() {
+        for format in [ModelFormat::V1, ModelFormat::V2] {
+            let mut builder = ModelBuilder::new(format);
+            let mut graph_builder = builder.graph_builder();
+
+            let input = graph_builder.add_value("input", None, Some(DataType::Int64));
+            let token_id = graph_builder.add_constant(Tensor::from([1i64 << 40]).view());
+            let mask = graph_builder.add_constant(Tensor::from([true, false]).view());
+            let is_token = graph_builder.add_value("is_token", None, Some(DataType::Bool));
+            let output = graph_builder.add_value("output", None, Some(DataType::Bool));
+            graph_builder.add_input(input);
+            graph_builder.add_output(output);
+            graph_builder.add_operator(
+                "equal",
+                OpType::Equal,
+                &[input, token_id].map(Some),
+                &[is_token],
+            );
+            graph_builder.add_operator("xor", OpType::Xor, &[is_token, mask].map(Some), &[output]);
+            let graph = graph_builder.finish();
+            builder.set_graph(graph);
+            let buffer = builder.finish();
+
+            let model = Model::load(buffer).unwrap();
+            let saved_model = Model::load(model.serialize().unwrap()).unwrap();
+
+            for model in [&model, &saved_model] {
+                let input = Tensor::from([1i64, 1 << 40]);
+                let output: Tensor<bool> = model
+                    .run_one(input.into(), None)
+                    .unwrap()
+                    .try_into()
+                    .unwrap();
+                assert_eq!(output, Tensor::from([true, true]));
+            }
+        }
+    }
+
+    #[cfg(feature = "onnx_format")]
+    #[test]
+    fn test_save_onnx() {
+        let model_path = "rten-onnx/test-data/mnist.onnx";
+        let onnx_model = Model::load_file(model_path).unwrap();
+        let model = ModelOptions::with_all_ops()
+            .enable_optimization(false)
+            .load_file(model_path)
+            .unwrap();
+        let saved_model = Model::load(model.serialize().unwrap()).unwrap();
+
+        let input = NdTensor::from_fn([1, 1, 28, 28], |[_, _, y, x]| ((y * x) % 7) as f32);
+        let expected: NdTensor<f32, 2> = onnx_model
+            .run_one(input.view().into(), None)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let actual: NdTensor<f32, 2> = saved_model
+            .run_one(input.view().into(), None)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_run_one() {
         let buffer = generate_model_buffer(ModelFormat::V2);
@@ -1422,6 +1839,14 @@ mod tests {
         add_operator!(ArgMin, [input_node], { axis: 3, keep_dims: false });
         add_operator!(Asin, [input_node]);
         add_operator!(Atan, [input_node]);
+        add_operator!(Attention, [input_node, input_node, input_node], {
+            is_causal: true,
+            q_num_heads: None,
+            kv_num_heads: None,
+            scale: Some(0.5),
+            softcap: None,
+            flush_nans_to_zero: false,
+        });
         add_operator!(AveragePool, [input_node], {
             kernel_size: [2, 2].into(),
             strides: [2, 2].into(),
@@ -1446,13 +1871,32 @@ mod tests {
             { epsilon: 1e-5 }
         );
 
+        add_operator!(BitShift, [input_bool, input_bool], {
+            direction: BitShiftDirection::Left,
+        });
+        add_operator!(BitwiseAnd, [input_bool, input_bool]);
+        add_operator!(BitwiseNot, [input_bool]);
+        add_operator!(BitwiseOr, [input_bool, input_bool]);
+        add_operator!(BitwiseXor, [input_bool, input_bool]);
+
         add_operator!(Cast, [input_node], { to: DataType::Float });
         add_operator!(CastLike, [input_node, input_node], {});
         add_operator!(Ceil, [input_node]);
+        add_operator!(Celu, [input_node], { alpha: 1.0 });
 
         let clip_min = graph_builder.add_constant(Tensor::from(1.).view());
         let clip_max = graph_builder.add_constant(Tensor::from(6.).view());
         add_operator!(Clip, [input_node, clip_min, clip_max]);
+        let col2im_input = graph_builder.add_constant(Tensor::<f32>::zeros(&[1, 4, 4]).view());
+        let col2im_image_shape = graph_builder.add_constant(Tensor::from([3, 3]).view());
+        let col2im_block_shape = graph_builder.add_constant(Tensor::from([2, 2]).view());
+        add_operator!(Col2Im, [col2im_input, col2im_image_shape, col2im_block_shape], {
+            dilations: vec![],
+            pads: vec![],
+            strides: vec![],
+        });
+        let compress_cond = graph_builder.add_constant(Tensor::from([1, 0, 1]).view());
+        add_operator!(Compress, [input_node, compress_cond], { axis: Some(2) });
         add_operator!(Concat, [input_node, input_node], { axis: 0 });
 
         let shape = graph_builder.add_constant(Tensor::from([1, 5, 10]).view());
@@ -1478,6 +1922,9 @@ mod tests {
         });
         add_operator!(Cos, [input_node]);
 
+        let cumsum_axis = graph_builder.add_constant(Tensor::from(1).view());
+        add_operator!(CumSum, [input_node, cumsum_axis]);
+
         let const_u8_val = Tensor::from([0u8, 1, 2, 3, 4]);
         let const_u8 = graph_builder.add_constant(const_u8_val.view());
 
@@ -1495,6 +1942,7 @@ mod tests {
             mode: DepthToSpaceMode::DepthColumnRow,
             block_size: 1,
         });
+        add_operator!(Det, [input_node]);
         add_operator!(QuantizeLinear, [const_f32, scale, zero_point], {
             axis: 0,
             output_dtype: None,
@@ -1544,6 +1992,39 @@ mod tests {
         add_operator!(GlobalMaxPool, [input_node]);
         add_operator!(Greater, [input_node, input_node]);
         add_operator!(GreaterOrEqual, [input_node, input_node]);
+
+        let group_norm_scale = graph_builder.add_constant(Tensor::from([1.0]).view());
+        let group_norm_bias = graph_builder.add_constant(Tensor::from([0.0]).view());
+        add_operator!(GroupNormalization, [
+            input_node, group_norm_scale, group_norm_bias
+        ], { num_groups: 1, epsilon: Some(1e-5) });
+
+        let gqa_qkv = graph_builder.add_constant(Tensor::<f32>::zeros(&[1, 2, 4]).view());
+        let gqa_seqlens_k = graph_builder.add_constant(Tensor::from([1]).view());
+        let gqa_total_seq_len = graph_builder.add_constant(Tensor::from(2).view());
+        add_operator(
+            &mut graph_builder,
+            "GroupQueryAttention",
+            OpType::GroupQueryAttention(ops::GroupQueryAttention {
+                num_heads: 1,
+                kv_num_heads: 1,
+                scale: Some(0.5),
+                softcap: None,
+                local_window_size: Some(4),
+                do_rotary: false,
+                rotary_interleaved: false,
+            }),
+            &[
+                Some(gqa_qkv),
+                Some(gqa_qkv),
+                Some(gqa_qkv),
+                None, // past_key
+                None, // past_value
+                Some(gqa_seqlens_k),
+                Some(gqa_total_seq_len),
+            ],
+        );
+        add_operator!(Hardmax, [input_node], { axis: -1 });
         add_operator!(HardSigmoid, [input_node], {
             alpha: 0.2,
             beta: 0.5,
@@ -1604,11 +2085,32 @@ mod tests {
         add_operator!(Log, [input_node]);
         add_operator!(LogSoftmax, [input_node], { axis: 1 });
 
+        add_operator!(LpNormalization, [input_node], { axis: -1, p: 2 });
+        add_operator!(LpPool, [input_node], {
+            kernel_size: [2, 2].into(),
+            strides: [1, 1].into(),
+            padding: [0, 0, 0, 0].into(),
+            ceil_mode: false,
+            p: 2,
+        });
+
         // TODO - Add LSTM operator
 
         add_operator!(MatMul, [input_2d, input_2d]);
         add_operator!(MatMulInteger, [input_2d_u8, input_2d_i8]);
 
+        // 4-bit weights for a [K=3, N=2] matrix, with one block of 16 elements
+        // per column.
+        let nbits_weights_val = Tensor::from_data(&[2, 1, 8], vec![0x88u8; 16]);
+        let nbits_weights = graph_builder.add_constant(nbits_weights_val.view());
+        let nbits_scales_val = Tensor::from_data(&[2, 1], vec![0.5f32, 0.25]);
+        let nbits_scales = graph_builder.add_constant(nbits_scales_val.view());
+        add_operator!(MatMulNBits, [input_node, nbits_weights, nbits_scales], {
+            bits: 4,
+            block_size: 16,
+            accuracy_level: ops::AccuracyLevel::Float,
+        });
+
         add_operator!(Max, [input_node, input_node]);
         add_operator!(MaxPool, [input_node], {
             kernel_size: [2, 2].into(),
@@ -1616,8 +2118,20 @@ mod tests {
             padding: [0, 0, 0, 0].into(),
             ceil_mode: false,
         });
+
+        let max_unpool_indices_val = Tensor::from_data(&input_shape, (0..9).collect::<Vec<i32>>());
+        let max_unpool_indices = graph_builder.add_constant(max_unpool_indices_val.view());
+        add_operator!(MaxUnpool, [input_node, max_unpool_indices], {
+            kernel_size: [2, 2].into(),
+            strides: [2, 2].into(),
+            padding: [0, 0, 0, 0].into(),
+        });
         add_operator!(Mean, [input_node, input_node]);
+        add_operator!(MeanVarianceNormalization, [input_node], {
+            axes: vec![0, 2, 3],
+        });
         add_operator!(Min, [input_node, input_node]);
+        add_operator!(Mish, [input_node]);
         add_operator!(Mod, [input_node, input_node], {
             fmod: false,
         });
@@ -1651,8 +2165,11 @@ mod tests {
         add_operator!(Or, [input_bool, input_bool]);
 
         let pads = graph_builder.add_constant(Tensor::from([0, 0, 1, 1, 0, 0, 1, 1]).view());
-        add_operator!(Pad, [input_node, pads]);
+        add_operator!(Pad, [input_node, pads], {
+            mode: PadMode::Constant,
+        });
         add_operator!(Pow, [input_node, input_node]);
+        add_operator!(PRelu, [input_node, input_node]);
 
         #[cfg(feature = "random")]
         {
@@ -1689,6 +2206,16 @@ mod tests {
         );
 
         add_operator!(Reciprocal, [input_node]);
+        add_operator!(ReduceL2, [input_node], {
+            axes: None,
+            keep_dims: false,
+            noop_with_empty_axes: false,
+        });
+        add_operator!(ReduceLogSumExp, [input_node], {
+            axes: None,
+            keep_dims: false,
+            noop_with_empty_axes: false,
+        });
         add_operator!(ReduceMean, [input_node], {
             axes: None,
             keep_dims: false,
@@ -1736,16 +2263,50 @@ mod tests {
             coord_mode: CoordTransformMode::default()
         });
 
+        let reverse_seq_lens = graph_builder.add_constant(Tensor::from([2, 1, 2]).view());
+        add_operator!(ReverseSequence, [input_2d, reverse_seq_lens], {
+            batch_axis: 1,
+            time_axis: 0,
+        });
+
+        let rope_cos = graph_builder.add_constant(Tensor::from([[[1.], [1.], [1.]]]).view());
+        let rope_sin = graph_builder.add_constant(Tensor::from([[[0.], [0.], [0.]]]).view());
+
+        let roi_align_rois = graph_builder.add_constant(Tensor::from([[0., 0., 2., 2.]]).view());
+        let roi_align_batch_indices = graph_builder.add_constant(Tensor::from([0]).view());
+        add_operator!(RoiAlign, [input_node, roi_align_rois, roi_align_batch_indices], {
+            opts: RoiAlignOptions {
+                mode: RoiAlignMode::Avg,
+                output_height: 2,
+                output_width: 2,
+                sampling_ratio: 0,
+                spatial_scale: 1.0,
+                half_pixel: true,
+            },
+        });
+        add_operator!(RotaryEmbedding, [input_node, rope_cos, rope_sin], {
+            interleaved: false,
+            num_heads: None,
+            rotary_embedding_dim: Some(2),
+        });
         add_operator!(Round, [input_node]);
 
+        add_operator!(Selu, [input_node], {
+            alpha: 1.6732632,
+            gamma: 1.050701,
+        });
         add_operator!(Shape, [input_node], {
             start: Some(1),
             end: Some(-1),
         });
+        add_operator!(Shrink, [input_node], { bias: 0., lambd: 0.5 });
         add_operator!(Sigmoid, [input_node]);
         add_operator!(Sign, [input_node]);
         add_operator!(Sin, [input_node]);
         add_operator!(Size, [input_node]);
+        add_operator!(SkipSimplifiedLayerNormalization, [
+            input_node, input_node, layer_norm_scale
+        ], { epsilon: Some(1e-5) });
 
         let scatter_elem_indices_val = Tensor::<i32>::zeros(&input_shape);
         let scatter_elem_indices = graph_builder.add_constant(scatter_elem_indices_val.view());
@@ -1763,6 +2324,7 @@ mod tests {
 
         add_operator!(Softplus, [input_node]);
         add_operator!(Softmax, [input_node], { axis: 1, flush_nans_to_zero: false });
+        add_operator!(SpaceToDepth, [input_node], { block_size: 1 });
         add_operator!(Sqrt, [input_node]);
         add_operator!(Squeeze, [input_node]);
 
@@ -1783,6 +2345,7 @@ mod tests {
         add_operator!(Sum, [input_node, input_node]);
         add_operator!(Tan, [input_node]);
         add_operator!(Tanh, [input_node]);
+        add_operator!(ThresholdedRelu, [input_node], { alpha: 1.0 });
 
         let tile_repeats = graph_builder.add_constant(Tensor::from([1, 2, 3, 4]).view());
         add_operator!(Tile, [input_node, tile_repeats]);
@@ -1804,6 +2367,10 @@ mod tests {
         add_operator!(Transpose, [input_node], { perm: None });
 
         add_operator!(Trilu, [input_node], { upper: true });
+        add_operator!(Unique, [input_node], {
+            axis: None,
+            sorted: true,
+        });
 
         let unsqueeze_axes = graph_builder.add_constant(Tensor::from([0, 4]).view());
         add_operator!(Unsqueeze, [input_node, unsqueeze_axes]);
@@ -1815,146 +2382,199 @@ mod tests {
 
         add_operator!(Xor, [input_bool, input_bool]);
 
+        // Fused operators, which are normally created by graph optimization.
+        add_operator!(AddSoftmax, [input_node, input_node], {
+            flush_nans_to_zero: false
+        });
+        add_operator!(ComputeShape, [input_node], {
+            symbols: vec![ops::SymbolInfo {
+                name: "h".into(),
+                input: 0,
+                axis: 2,
+            }],
+            shape: vec![SymExpr::from("h") * SymExpr::from(2), SymExpr::from(1)],
+        });
+        add_operator!(FusedMatMul, [input_node, input_node], { alpha: Some(0.5) });
+        add_operator!(GroupedQueryAttentionMatMul, [input_node, input_node], {
+            repeats: 1,
+            alpha: None,
+            transpose_rhs: true,
+        });
+        add_operator!(RepeatInterleave, [input_node], { axis: 1, repeats: 2 });
+        add_operator!(RmsNormalization, [input_node, layer_norm_scale], {
+            axis: -1,
+            epsilon: Some(1e-5),
+        });
+        add_operator!(Silu, [input_node]);
+        add_operator!(Swish, [input_node], { beta: 1.5 });
+
+        let transform_inner = graph_builder.create_nested_operator(OpType::Sub);
+        add_operator(
+            &mut graph_builder,
+            "TransformInputs",
+            OpType::TransformInputs(TransformInputsArgs {
+                op: transform_inner,
+                transforms: vec![(1, Some(vec![0, 1, 3, 2]))],
+            }),
+            &[input_node, input_node].map(Some),
+        );
+
         let graph = graph_builder.finish();
         builder.set_graph(graph);
         let buffer = builder.finish();
 
-        let model = Model::load(buffer).unwrap();
-
-        // Most ops are tested with one of several standard inputs:
-        //
-        //  - 4D float tensor (like an NCHW image)
-        //  - Int8 NCHW tensor
-        //  - Bool-ish int tensor
+        // Run the model as loaded, and after saving and re-loading it, to check
+        // that all operators can be serialized. The model is saved both with
+        // and without optimization applied.
         //
-        // A few require different shapes are tested separately.
-        let input = Tensor::from_data(&input_shape, vec![1., 2., 3., 4., 5., 6., 7., 8., 9.]);
-        let input_2d_data = NdTensor::from([[1, 2, 3], [4, 5, 6]]);
-        let input_bool_data: Tensor<i32> = Tensor::from([0, 1, 1]);
-        let input_u8_data = input.map(|&x| x as u8);
-        let input_2d_u8_data = Tensor::from([[1u8, 2], [3, 4]]);
-        let input_2d_i8_data = Tensor::from([[1i8, 2], [3, 4]]);
-
-        for output in op_outputs {
-            if [
-                "Dropout_out",
-                "Dropout_out_mask",
+        // Saving preserves node IDs, so the IDs from the builder are valid for
+        // all models.
+        let model = Model::load(buffer.clone()).unwrap();
+        let saved_optimized_model = Model::load(model.serialize().unwrap()).unwrap();
+        let saved_buffer = ModelOptions::with_all_ops()
+            .enable_optimization(false)
+            .load(buffer)
+            .unwrap()
+            .serialize()
+            .unwrap();
+        let saved_model = Model::load(saved_buffer).unwrap();
+
+        for model in [model, saved_model, saved_optimized_model] {
+            // Most ops are tested with one of several standard inputs:
+            //
+            //  - 4D float tensor (like an NCHW image)
+            //  - Int8 NCHW tensor
+            //  - Bool-ish int tensor
+            //
+            // A few require different shapes are tested separately.
+            let input = Tensor::from_data(&input_shape, vec![1., 2., 3., 4., 5., 6., 7., 8., 9.]);
+            let input_2d_data = NdTensor::from([[1, 2, 3], [4, 5, 6]]);
+            let input_bool_data: Tensor<i32> = Tensor::from([0, 1, 1]);
+            let input_u8_data = input.map(|&x| x as u8);
+            let input_2d_u8_data = Tensor::from([[1u8, 2], [3, 4]]);
+            let input_2d_i8_data = Tensor::from([[1i8, 2], [3, 4]]);
+
+            for output in &op_outputs {
+                if [
+                    "Dropout_out",
+                    "Dropout_out_mask",
+                    "Gemm_out",
+                    "MatMul_out",
+                    "Range_out",
+                    "Split_out_1",
+                    "Split_out_2",
+                    "TopK_out_indices",
+                    "TopK_out_values",
+                    "Where_out",
+                ]
+                .contains(&output.as_str())
+                {
+                    // This op requires special handling. See below.
+                    continue;
+                }
+
+                // Run with inputs as views.
+                //
+                // This will run the non-in-place implementation of the operator
+                // (`Operator::run`).
+                let output_id = model.find_node(output).unwrap();
+                let result = model
+                    .run(
+                        vec![
+                            (input_node, input.view().into()),
+                            (input_bool, input_bool_data.view().into()),
+                            (input_u8, input_u8_data.view().into()),
+                            (input_2d, input_2d_data.view().into()),
+                            (input_2d_u8, input_2d_u8_data.view().into()),
+                            (input_2d_i8, input_2d_i8_data.view().into()),
+                        ],
+                        &[output_id],
+                        None,
+                    )
+                    .unwrap();
+                assert_eq!(result.len(), 1);
+
+                // Run with inputs as owned tensors.
+                //
+                // This will run the in-place implementation of the operator if
+                // supported (`Operator::run_in_place`).
+                let output_id = model.find_node(output).unwrap();
+                let result = model
+                    .run(
+                        vec![
+                            (input_node, input.clone().into()),
+                            (input_bool, input_bool_data.clone().into()),
+                            (input_u8, input_u8_data.clone().into()),
+                            (input_2d, input_2d_data.clone().into()),
+                            (input_2d_u8, input_2d_u8_data.view().into()),
+                            (input_2d_i8, input_2d_i8_data.view().into()),
+                        ],
+                        &[output_id],
+                        None,
+                    )
+                    .unwrap();
+                assert_eq!(result.len(), 1);
+            }
+
+            // Outputs of ops which require special handling.
+            #[allow(unused_mut)]
+            let mut outputs = vec![
                 "Gemm_out",
                 "MatMul_out",
-                "Range_out",
                 "Split_out_1",
                 "Split_out_2",
                 "TopK_out_indices",
                 "TopK_out_values",
-                "Where_out",
-            ]
-            .contains(&output.as_str())
+            ];
+
+            #[cfg(feature = "random")]
             {
-                // This op requires special handling. See below.
-                continue;
+                outputs.extend(["Dropout_out", "Dropout_out_mask"]);
             }
 
-            // Run with inputs as views.
-            //
-            // This will run the non-in-place implementation of the operator
-            // (`Operator::run`).
-            let output_id = model.find_node(&output).unwrap();
+            let input = Tensor::from_data(&[3, 3], vec![1., 2., 3., 4., 5., 6., 7., 8., 9.]);
+
+            for output in outputs {
+                let output_id = model.find_node(output).unwrap();
+                let result = model
+                    .run(vec![(input_2d, input.view().into())], &[output_id], None)
+                    .unwrap();
+                assert_eq!(result.len(), 1);
+            }
+
+            // Range op
+            let start = Tensor::from(0.);
+            let limit = Tensor::from(5.);
+            let delta = Tensor::from(1.);
             let result = model
                 .run(
                     vec![
-                        (input_node, input.view().into()),
-                        (input_bool, input_bool_data.view().into()),
-                        (input_u8, input_u8_data.view().into()),
-                        (input_2d, input_2d_data.view().into()),
-                        (input_2d_u8, input_2d_u8_data.view().into()),
-                        (input_2d_i8, input_2d_i8_data.view().into()),
+                        (range_start_node, start.into()),
+                        (range_limit_node, limit.into()),
+                        (range_delta_node, delta.into()),
                     ],
-                    &[output_id],
+                    &[range_out],
                     None,
                 )
                 .unwrap();
             assert_eq!(result.len(), 1);
 
-            // Run with inputs as owned tensors.
-            //
-            // This will run the in-place implementation of the operator if
-            // supported (`Operator::run_in_place`).
-            let output_id = model.find_node(&output).unwrap();
+            // Where op
+            let cond = Tensor::from(1);
+            let x = Tensor::from([1, 2, 3]);
+            let y = Tensor::from([4, 5, 6]);
             let result = model
                 .run(
                     vec![
-                        (input_node, input.clone().into()),
-                        (input_bool, input_bool_data.clone().into()),
-                        (input_u8, input_u8_data.clone().into()),
-                        (input_2d, input_2d_data.clone().into()),
-                        (input_2d_u8, input_2d_u8_data.view().into()),
-                        (input_2d_i8, input_2d_i8_data.view().into()),
+                        (where_cond, cond.into()),
+                        (where_x, x.into()),
+                        (where_y, y.into()),
                     ],
-                    &[output_id],
+                    &[where_out],
                     None,
                 )
                 .unwrap();
             assert_eq!(result.len(), 1);
         }
-
-        // Outputs of ops which require special handling.
-        #[allow(unused_mut)]
-        let mut outputs = vec![
-            "Gemm_out",
-            "MatMul_out",
-            "Split_out_1",
-            "Split_out_2",
-            "TopK_out_indices",
-            "TopK_out_values",
-        ];
-
-        #[cfg(feature = "random")]
-        {
-            outputs.extend(["Dropout_out", "Dropout_out_mask"]);
-        }
-
-        let input = Tensor::from_data(&[3, 3], vec![1., 2., 3., 4., 5., 6., 7., 8., 9.]);
-
-        for output in outputs {
-            let output_id = model.find_node(output).unwrap();
-            let result = model
-                .run(vec![(input_2d, input.view().into())], &[output_id], None)
-                .unwrap();
-            assert_eq!(result.len(), 1);
-        }
-
-        // Range op
-        let start = Tensor::from(0.);
-        let limit = Tensor::from(5.);
-        let delta = Tensor::from(1.);
-        let result = model
-            .run(
-                vec![
-                    (range_start_node, start.into()),
-                    (range_limit_node, limit.into()),
-                    (range_delta_node, delta.into()),
-                ],
-                &[range_out],
-                None,
-            )
-            .unwrap();
-        assert_eq!(result.len(), 1);
-
-        // Where op
-        let cond = Tensor::from(1);
-        let x = Tensor::from([1, 2, 3]);
-        let y = Tensor::from([4, 5, 6]);
-        let result = model
-            .run(
-                vec![
-                    (where_cond, cond.into()),
-                    (where_x, x.into()),
-                    (where_y, y.into()),
-                ],
-                &[where_out],
-                None,
-            )
-            .unwrap();
-        assert_eq!(result.len(), 1);
     }
 }
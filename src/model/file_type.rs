@@ -10,6 +10,8 @@ pub enum FileType {
     Rten,
     /// ONNX file format.
     Onnx,
+    /// GGUF file format used by llama.cpp.
+    Gguf,
 }
 
 impl FileType {
@@ -21,6 +23,8 @@ impl FileType {
             Some(FileType::Rten)
         } else if ext.eq_ignore_ascii_case(OsStr::new("onnx")) {
             Some(FileType::Onnx)
+        } else if ext.eq_ignore_ascii_case(OsStr::new("gguf")) {
+            Some(FileType::Gguf)
         } else {
             None
         }
@@ -37,6 +41,9 @@ impl FileType {
         if magic == Some(*b"RTEN") {
             return Some(FileType::Rten);
         }
+        if magic == Some(*b"GGUF") {
+            return Some(FileType::Gguf);
+        }
 
         #[cfg(feature = "onnx_format")]
         {
@@ -88,6 +95,10 @@ mod tests {
                 path: Path::new("foo.onnx"),
                 file_type: Some(FileType::Onnx),
             },
+            Case {
+                path: Path::new("foo.gguf"),
+                file_type: Some(FileType::Gguf),
+            },
             Case {
                 path: Path::new("foo.md"),
                 file_type: None,
@@ -120,6 +131,10 @@ mod tests {
                 buf: b"RTEN".into(),
                 expected: Some(FileType::Rten),
             },
+            Case {
+                buf: b"GGUF".into(),
+                expected: Some(FileType::Gguf),
+            },
             Case {
                 buf: b"".into(),
                 expected: None,
@@ -0,0 +1,1607 @@
+//! Construction of models from GGUF files.
+//!
+//! GGUF files contain weights and hyperparameters but not a computation
+//! graph, so the graph is constructed here based on the `general.architecture`
+//! metadata. Supported architectures are decoder-only transformers using the
+//! Llama design (RMS normalization, rotary position embeddings, grouped-query
+//! attention and SwiGLU feed-forward layers).
+//!
+//! The inputs and outputs of the graph follow the naming conventions of
+//! Hugging Face's Optimum exporter, so models can be used with
+//! `rten_generate::Generator`.
+
+use std::sync::Arc;
+
+use rten_base::half::{bf16, f16, f16_to_f32};
+use rten_gguf::{GgmlType, GgufFile, TensorInfo, dequantize, q4_k_scale_min};
+use rten_tensor::prelude::*;
+use rten_tensor::{ArcTensor, Tensor};
+
+use super::load_error::{LoadError, LoadErrorImpl, load_error};
+use super::metadata::{MetadataField, ModelMetadata};
+use super::{Model, ModelOptions, OptimizeMode};
+use crate::graph::{Constant, ConstantNode, ConstantNodeData, Dimension, Graph, NodeId};
+use crate::operator::Operator;
+use crate::ops::{
+    AccuracyLevel, Add, Cast, Concat, Cos, Gather, Greater, MatMul, MatMulNBits, Mul, Neg, Range,
+    RepeatInterleave, Reshape, RmsNormalization, Shape, Silu, Sin, Slice, Softmax, Transpose,
+    Unsqueeze, Where,
+};
+use crate::optimize::GraphOptimizer;
+use crate::value::{DataType, ValueType};
+use crate::weight_cache::WeightCache;
+
+/// Model architectures which can be loaded.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Architecture {
+    Llama,
+    Qwen2,
+}
+
+impl Architecture {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "llama" => Some(Self::Llama),
+            "qwen2" => Some(Self::Qwen2),
+            _ => None,
+        }
+    }
+
+    /// Return true if the query and key projection weights were permuted
+    /// when the model was converted to GGUF.
+    ///
+    /// llama.cpp's converter permutes the rows of these weights for Llama
+    /// models so that rotary embeddings can be applied to adjacent pairs of
+    /// elements. This is reversed when loading so that all architectures use
+    /// the "rotate half" style.
+    fn permuted_qk(self) -> bool {
+        self == Self::Llama
+    }
+}
+
+/// Hyperparameters of a Llama-family model.
+#[derive(Clone, Debug)]
+struct Config {
+    arch: Architecture,
+    n_layer: usize,
+    n_embd: usize,
+    n_head: usize,
+    n_head_kv: usize,
+    head_dim: usize,
+    rms_eps: f32,
+    rope_freq_base: f32,
+    rope_scale: f32,
+}
+
+impl Config {
+    fn from_metadata(file: &GgufFile) -> Result<Self, LoadError> {
+        let arch_name = file
+            .get_str("general.architecture")
+            .ok_or_else(|| graph_error("missing \"general.architecture\" metadata"))?;
+        let arch = Architecture::from_name(arch_name)
+            .ok_or_else(|| graph_error(&format!("unsupported architecture \"{}\"", arch_name)))?;
+
+        let key = |name: &str| format!("{}.{}", arch_name, name);
+        let get_usize = |name: &str| -> Result<usize, LoadError> {
+            file.get_u64(&key(name))
+                .map(|x| x as usize)
+                .ok_or_else(|| graph_error(&format!("missing \"{}\" metadata", key(name))))
+        };
+
+        let n_layer = get_usize("block_count")?;
+        let n_embd = get_usize("embedding_length")?;
+        let n_head = get_usize("attention.head_count")?;
+        let n_head_kv = get_usize("attention.head_count_kv").unwrap_or(n_head);
+        let head_dim = get_usize("attention.key_length").unwrap_or(n_embd / n_head.max(1));
+
+        if n_head == 0 || n_head_kv == 0 || !n_head.is_multiple_of(n_head_kv) {
+            return Err(graph_error("invalid attention head counts"));
+        }
+        if head_dim == 0 || !head_dim.is_multiple_of(2) {
+            return Err(graph_error("attention head size must be a multiple of 2"));
+        }
+        if get_usize("rope.dimension_count").unwrap_or(head_dim) != head_dim {
+            return Err(graph_error("partial rotary embeddings are not supported"));
+        }
+
+        let rope_scale = match file.get_str(&key("rope.scaling.type")) {
+            None | Some("none") => 1.,
+            Some("linear") => file.get_f32(&key("rope.scaling.factor")).unwrap_or(1.),
+            Some(other) => {
+                return Err(graph_error(&format!(
+                    "unsupported rope scaling type \"{}\"",
+                    other
+                )));
+            }
+        };
+
+        Ok(Config {
+            arch,
+            n_layer,
+            n_embd,
+            n_head,
+            n_head_kv,
+            head_dim,
+            rms_eps: file
+                .get_f32(&key("attention.layer_norm_rms_epsilon"))
+                .unwrap_or(1e-5),
+            rope_freq_base: file.get_f32(&key("rope.freq_base")).unwrap_or(10000.),
+            rope_scale,
+        })
+    }
+}
+
+fn graph_error(msg: &str) -> LoadError {
+    LoadErrorImpl::GraphError(msg.into()).into()
+}
+
+/// A float tensor loaded from a GGUF file.
+///
+/// 16-bit float types are preserved. The operators which use these tensors
+/// as weights (`MatMul`, `Gather`) convert them to f32 as needed.
+#[derive(Clone)]
+enum FloatTensor {
+    F32(ArcTensor<f32>),
+    F16(ArcTensor<f16>),
+    BF16(ArcTensor<bf16>),
+}
+
+impl FloatTensor {
+    fn shape(&self) -> &[usize] {
+        match self {
+            Self::F32(t) => t.shape(),
+            Self::F16(t) => t.shape(),
+            Self::BF16(t) => t.shape(),
+        }
+    }
+
+    fn transpose(&mut self) {
+        match self {
+            Self::F32(t) => t.transpose(),
+            Self::F16(t) => t.transpose(),
+            Self::BF16(t) => t.transpose(),
+        }
+    }
+}
+
+/// Weights for a linear layer.
+enum LinearWeight {
+    /// Float weights with shape `(in_features, out_features)`.
+    Float(NodeId),
+    /// Block-quantized weights in the layout used by [`MatMulNBits`].
+    Quantized {
+        op: MatMulNBits,
+        quant: NodeId,
+        scales: NodeId,
+        zero_points: Option<NodeId>,
+    },
+}
+
+/// Incrementally constructs a graph from the tensors in a GGUF file.
+struct GraphBuilder<'a> {
+    file: &'a GgufFile<'a>,
+    graph: Graph,
+}
+
+impl<'a> GraphBuilder<'a> {
+    fn tensor_info(&self, name: &str) -> Result<&'a TensorInfo, LoadError> {
+        self.file
+            .tensor(name)
+            .ok_or_else(|| load_error!(GraphError, Some(name), "tensor not found"))
+    }
+
+    fn tensor_data(&self, info: &TensorInfo) -> Result<&'a [u8], LoadError> {
+        self.file
+            .tensor_data(info)
+            .map_err(|err| load_error!(ParseFailed, Some(info.name.as_str()), err))
+    }
+
+    fn tensor_len(&self, info: &TensorInfo) -> Result<usize, LoadError> {
+        info.len()
+            .map_err(|err| load_error!(ParseFailed, Some(info.name.as_str()), err))
+    }
+
+    /// Load a tensor and convert it to f32.
+    fn load_f32(&self, name: &str) -> Result<Tensor<f32>, LoadError> {
+        let info = self.tensor_info(name)?;
+        let data = self.tensor_data(info)?;
+        let mut out = vec![0.; self.tensor_len(info)?];
+        dequantize(info.ggml_type, data, &mut out)
+            .map_err(|err| load_error!(GraphError, Some(name), err))?;
+        Ok(Tensor::from_data(&info.shape(), out))
+    }
+
+    /// Load a tensor with shape `shape` from `data`.
+    ///
+    /// F32, F16 and BF16 tensors keep their type. Other types are converted
+    /// to f32.
+    fn float_tensor(
+        &self,
+        info: &TensorInfo,
+        data: &[u8],
+        shape: &[usize],
+    ) -> Result<FloatTensor, LoadError> {
+        let read_u16 = || {
+            data.chunks_exact(2)
+                .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+        };
+        let tensor = match info.ggml_type {
+            GgmlType::F16 => {
+                let data: Vec<f16> = read_u16().map(f16::from_bits).collect();
+                FloatTensor::F16(Tensor::from_data(shape, data).into_arc())
+            }
+            GgmlType::BF16 => {
+                let data: Vec<bf16> = read_u16().map(bf16::from_bits).collect();
+                FloatTensor::BF16(Tensor::from_data(shape, data).into_arc())
+            }
+            _ => {
+                let mut out = vec![0.; self.tensor_len(info)?];
+                dequantize(info.ggml_type, data, &mut out)
+                    .map_err(|err| load_error!(GraphError, Some(info.name.as_str()), err))?;
+                FloatTensor::F32(Tensor::from_data(shape, out).into_arc())
+            }
+        };
+        Ok(tensor)
+    }
+
+    fn add_float_constant(&mut self, name: &str, tensor: FloatTensor) -> NodeId {
+        match tensor {
+            FloatTensor::F32(t) => self.add_constant(name, t),
+            FloatTensor::F16(t) => self.add_constant(name, t),
+            FloatTensor::BF16(t) => self.add_constant(name, t),
+        }
+    }
+
+    fn add_constant<T>(&mut self, name: &str, tensor: ArcTensor<T>) -> NodeId
+    where
+        ArcTensor<T>: Into<ConstantNodeData<T>>,
+        ConstantNode<T>: Into<Constant>,
+    {
+        self.graph.add_constant(Some(name), tensor)
+    }
+
+    fn add_i32(&mut self, name: &str, values: &[i32]) -> NodeId {
+        self.add_constant(name, Tensor::from(values.to_vec()).into_arc())
+    }
+
+    fn add_f32_scalar(&mut self, name: &str, value: f32) -> NodeId {
+        self.add_constant(name, Tensor::from(value).into_arc())
+    }
+
+    /// Add an operator with a single output to the graph.
+    ///
+    /// The output value is named after the operator, with an "_out" suffix.
+    fn add_op(
+        &mut self,
+        name: &str,
+        op: impl Operator + Send + Sync + 'static,
+        inputs: &[NodeId],
+    ) -> NodeId {
+        let output = self
+            .graph
+            .add_value(Some(&format!("{}_out", name)), None, None);
+        self.add_op_with_output(name, op, inputs, output);
+        output
+    }
+
+    fn add_op_with_output(
+        &mut self,
+        name: &str,
+        op: impl Operator + Send + Sync + 'static,
+        inputs: &[NodeId],
+        output: NodeId,
+    ) {
+        let inputs: Vec<_> = inputs.iter().copied().map(Some).collect();
+        self.graph
+            .add_op(Some(name), Arc::new(op), &inputs, &[Some(output)]);
+    }
+
+    /// Load the weights for a linear layer from the tensor `name`, which has
+    /// shape `(out_features, in_features)`.
+    ///
+    /// If `row_order` is specified, the rows of the weight are reordered
+    /// such that row `i` of the result is row `row_order[i]` of the tensor.
+    fn load_linear(
+        &mut self,
+        name: &str,
+        row_order: Option<&[usize]>,
+    ) -> Result<LinearWeight, LoadError> {
+        let info = self.tensor_info(name)?;
+        let &[out_features, in_features] = info.shape().as_slice() else {
+            return Err(load_error!(GraphError, Some(name), "expected a 2D weight"));
+        };
+        let data = self.tensor_data(info)?;
+        if out_features == 0 || data.len() % out_features != 0 {
+            return Err(load_error!(
+                GraphError,
+                Some(name),
+                "data size {} is not a multiple of {} rows",
+                data.len(),
+                out_features
+            ));
+        }
+        let row_bytes = data.len() / out_features;
+
+        let reordered;
+        let data = if let Some(row_order) = row_order {
+            if row_order.len() != out_features || row_order.iter().any(|&row| row >= out_features) {
+                return Err(load_error!(
+                    GraphError,
+                    Some(name),
+                    "row order does not match {} output features",
+                    out_features
+                ));
+            }
+            reordered = row_order
+                .iter()
+                .flat_map(|&row| &data[row * row_bytes..(row + 1) * row_bytes])
+                .copied()
+                .collect::<Vec<u8>>();
+            reordered.as_slice()
+        } else {
+            data
+        };
+
+        if let Some(weight) = repack_nbits(info.ggml_type, data, out_features, in_features) {
+            let NBitsWeight {
+                bits,
+                block_size,
+                quant,
+                scales,
+                zero_points,
+            } = weight;
+            let quant = self.add_constant(&format!("{}.quant", name), quant.into_arc());
+            let scales = self.add_constant(&format!("{}.scales", name), scales.into_arc());
+            let zero_points = zero_points.map(|zero_points| {
+                self.add_constant(&format!("{}.zero_points", name), zero_points.into_arc())
+            });
+            return Ok(LinearWeight::Quantized {
+                op: MatMulNBits {
+                    bits,
+                    block_size,
+                    accuracy_level: AccuracyLevel::Int8,
+                },
+                quant,
+                scales,
+                zero_points,
+            });
+        }
+
+        // Converting block-quantized weights to float would increase their
+        // size several times over, so other block-quantized types are
+        // rejected rather than dequantized.
+        if !matches!(
+            info.ggml_type,
+            GgmlType::F32 | GgmlType::F16 | GgmlType::BF16
+        ) {
+            return Err(load_error!(
+                GraphError,
+                Some(name),
+                "unsupported weight type {}",
+                info.ggml_type
+            ));
+        }
+        let mut weight = self.float_tensor(info, data, &[out_features, in_features])?;
+        weight.transpose();
+        Ok(LinearWeight::Float(self.add_float_constant(name, weight)))
+    }
+
+    /// Add a linear layer to the graph, with an optional bias.
+    fn add_linear(
+        &mut self,
+        name: &str,
+        input: NodeId,
+        weight: &LinearWeight,
+        bias: Option<NodeId>,
+    ) -> NodeId {
+        let output = self
+            .graph
+            .add_value(Some(&format!("{}_out", name)), None, None);
+        self.add_matmul_with_output(name, input, weight, output);
+        if let Some(bias) = bias {
+            self.add_op(&format!("{}.add_bias", name), Add {}, &[output, bias])
+        } else {
+            output
+        }
+    }
+
+    /// Add a multiplication of `input` by the weights of a linear layer.
+    fn add_matmul_with_output(
+        &mut self,
+        name: &str,
+        input: NodeId,
+        weight: &LinearWeight,
+        output: NodeId,
+    ) {
+        match weight {
+            LinearWeight::Float(weight) => {
+                self.add_op_with_output(name, MatMul {}, &[input, *weight], output)
+            }
+            LinearWeight::Quantized {
+                op,
+                quant,
+                scales,
+                zero_points,
+            } => {
+                let mut inputs = vec![input, *quant, *scales];
+                inputs.extend(*zero_points);
+                self.add_op_with_output(name, op.clone(), &inputs, output)
+            }
+        }
+    }
+
+    /// Load and add a linear layer whose weight and optional bias are named
+    /// `{prefix}.weight` and `{prefix}.bias`.
+    fn linear(
+        &mut self,
+        prefix: &str,
+        input: NodeId,
+        row_order: Option<&[usize]>,
+    ) -> Result<NodeId, LoadError> {
+        let weight = self.load_linear(&format!("{}.weight", prefix), row_order)?;
+        let bias_name = format!("{}.bias", prefix);
+        let bias = if self.file.tensor(&bias_name).is_some() {
+            let mut bias = self.load_f32(&bias_name)?;
+            if let Some(row_order) = row_order {
+                bias = Tensor::from(row_order.iter().map(|&i| bias[[i]]).collect::<Vec<_>>());
+            }
+            Some(self.add_constant(&bias_name, bias.into_arc()))
+        } else {
+            None
+        };
+        Ok(self.add_linear(prefix, input, &weight, bias))
+    }
+
+    fn rms_norm(&mut self, name: &str, input: NodeId, eps: f32) -> Result<NodeId, LoadError> {
+        let weight_name = format!("{}.weight", name);
+        let weight = self.load_f32(&weight_name)?.into_arc();
+        let weight = self.add_constant(&weight_name, weight);
+        Ok(self.add_op(
+            name,
+            RmsNormalization {
+                axis: -1,
+                epsilon: Some(eps),
+            },
+            &[input, weight],
+        ))
+    }
+
+    /// Apply rotary position embeddings to `x`, which has shape
+    /// `(batch, heads, seq, head_dim)`.
+    ///
+    /// This uses the "rotate half" formulation, where element `i` in the
+    /// first half of each head is paired with element `i + head_dim / 2`.
+    fn rope(&mut self, name: &str, x: NodeId, cos: NodeId, sin: NodeId, head_dim: usize) -> NodeId {
+        let half = head_dim as i32 / 2;
+        let axes = self.add_i32(&format!("{}.axes", name), &[-1]);
+        let zero = self.add_i32(&format!("{}.start", name), &[0]);
+        let mid = self.add_i32(&format!("{}.mid", name), &[half]);
+        let end = self.add_i32(&format!("{}.end", name), &[half * 2]);
+
+        let x1 = self.add_op(&format!("{}.x1", name), Slice {}, &[x, zero, mid, axes]);
+        let x2 = self.add_op(&format!("{}.x2", name), Slice {}, &[x, mid, end, axes]);
+        let neg_x2 = self.add_op(&format!("{}.neg_x2", name), Neg {}, &[x2]);
+        let rotated = self.add_op(
+            &format!("{}.rotate_half", name),
+            Concat { axis: -1 },
+            &[neg_x2, x1],
+        );
+        let x_cos = self.add_op(&format!("{}.mul_cos", name), Mul {}, &[x, cos]);
+        let rot_sin = self.add_op(&format!("{}.mul_sin", name), Mul {}, &[rotated, sin]);
+        self.add_op(name, Add {}, &[x_cos, rot_sin])
+    }
+}
+
+/// Block-quantized weights in the layout used by [`MatMulNBits`].
+struct NBitsWeight {
+    bits: u8,
+    block_size: usize,
+    /// Quantized elements with shape `(rows, k_blocks, block_size * bits / 8)`.
+    quant: Tensor<u8>,
+    /// Scales with shape `(rows, k_blocks)`.
+    scales: Tensor<f32>,
+    /// Zero points with shape `(rows, k_blocks)`. If `None`, the zero point
+    /// is `2^(bits - 1)`.
+    zero_points: Option<Tensor<f32>>,
+}
+
+/// Read a little-endian f16 value from the start of `bytes`.
+fn read_f16(bytes: &[u8]) -> f32 {
+    f16_to_f32(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+/// Pack `values` into a little-endian bitstream with `bits` bits per value,
+/// so that the first value occupies the lowest bits of the first byte.
+fn pack_bits(values: &[u8], bits: u8, out: &mut Vec<u8>) {
+    let mut acc = 0u32;
+    let mut acc_bits = 0;
+    for &value in values {
+        acc |= (value as u32) << acc_bits;
+        acc_bits += bits as u32;
+        while acc_bits >= 8 {
+            out.push(acc as u8);
+            acc >>= 8;
+            acc_bits -= 8;
+        }
+    }
+}
+
+/// Convert block-quantized GGML data into the layout used by [`MatMulNBits`].
+///
+/// `data` contains `rows` rows of `cols` elements, quantized along the
+/// columns. Returns `None` if `ty` is not a type which can be converted.
+///
+/// GGML types store the elements of a block in a type-specific order,
+/// whereas MatMulNBits packs elements into a bitstream in order. The types
+/// map onto MatMulNBits as follows:
+///
+///  - Q4_0 and Q8_0 have a scale for each block of 32 elements and zero
+///    points of 8 and 128 respectively, once Q8_0's signed values are
+///    converted to unsigned.
+///  - Q6_K has a scale for each sub-block of 16 elements and a zero point
+///    of 32.
+///  - Q4_K has a scale and minimum for each sub-block of 32 elements, and
+///    elements are dequantized as `scale * q - min`. This is represented as
+///    `scale * (q - min / scale)` using float zero points.
+fn repack_nbits(ty: GgmlType, data: &[u8], rows: usize, cols: usize) -> Option<NBitsWeight> {
+    let (bits, block_size) = match ty {
+        GgmlType::Q4_0 | GgmlType::Q4_K => (4, 32),
+        GgmlType::Q8_0 => (8, 32),
+        GgmlType::Q6_K => (6, 16),
+        _ => return None,
+    };
+    let (ggml_block_len, ggml_block_bytes) = ty.block_size()?;
+    let k_blocks = cols / block_size;
+    let block_bytes = block_size * bits as usize / 8;
+
+    let mut quant = Vec::with_capacity(rows * k_blocks * block_bytes);
+    let mut scales = Vec::with_capacity(rows * k_blocks);
+    let mut zero_points = Vec::new();
+
+    // Unsigned quantized values of the current GGML block, in element order.
+    let mut values = vec![0u8; ggml_block_len];
+
+    for block in data.chunks_exact(ggml_block_bytes) {
+        match ty {
+            GgmlType::Q4_0 => {
+                scales.push(read_f16(&block[0..2]));
+                for (i, &q) in block[2..18].iter().enumerate() {
+                    values[i] = q & 0x0F;
+                    values[i + 16] = q >> 4;
+                }
+            }
+            GgmlType::Q8_0 => {
+                scales.push(read_f16(&block[0..2]));
+                for (value, &q) in values.iter_mut().zip(&block[2..34]) {
+                    *value = q ^ 0x80;
+                }
+            }
+            GgmlType::Q4_K => {
+                let d = read_f16(&block[0..2]);
+                let dmin = read_f16(&block[2..4]);
+                let packed_scales = &block[4..16];
+                for (group, qs) in block[16..144].chunks_exact(32).enumerate() {
+                    for (l, &q) in qs.iter().enumerate() {
+                        values[group * 64 + l] = q & 0x0F;
+                        values[group * 64 + 32 + l] = q >> 4;
+                    }
+                }
+                for j in 0..ggml_block_len / block_size {
+                    let (sc, m) = q4_k_scale_min(j, packed_scales);
+                    let (scale, min) = (d * sc as f32, dmin * m as f32);
+                    if scale == 0. {
+                        // All elements of the sub-block have the value `-min`.
+                        values[j * block_size..(j + 1) * block_size].fill(0);
+                        scales.push(1.);
+                        zero_points.push(min);
+                    } else {
+                        scales.push(scale);
+                        zero_points.push(min / scale);
+                    }
+                }
+            }
+            GgmlType::Q6_K => {
+                let d = read_f16(&block[208..210]);
+                for half in 0..2 {
+                    let ql = &block[half * 64..];
+                    let qh = &block[128 + half * 32..];
+                    let y = &mut values[half * 128..];
+                    for l in 0..32 {
+                        y[l] = (ql[l] & 0x0F) | ((qh[l] & 3) << 4);
+                        y[l + 32] = (ql[l + 32] & 0x0F) | (((qh[l] >> 2) & 3) << 4);
+                        y[l + 64] = (ql[l] >> 4) | (((qh[l] >> 4) & 3) << 4);
+                        y[l + 96] = (ql[l + 32] >> 4) | (((qh[l] >> 6) & 3) << 4);
+                    }
+                }
+                scales.extend(block[192..208].iter().map(|&sc| d * (sc as i8) as f32));
+            }
+            _ => unreachable!(),
+        }
+        for sub_block in values.chunks_exact(block_size) {
+            pack_bits(sub_block, bits, &mut quant);
+        }
+    }
+
+    Some(NBitsWeight {
+        bits,
+        block_size,
+        quant: Tensor::from_data(&[rows, k_blocks, block_bytes], quant),
+        scales: Tensor::from_data(&[rows, k_blocks], scales),
+        zero_points: (!zero_points.is_empty())
+            .then(|| Tensor::from_data(&[rows, k_blocks], zero_points)),
+    })
+}
+
+/// Return the row order which reverses llama.cpp's permutation of query and
+/// key projection weights for a layer with `n_head` heads of size `head_dim`.
+fn unpermute_qk_rows(n_head: usize, head_dim: usize) -> Vec<usize> {
+    let half = head_dim / 2;
+    (0..n_head)
+        .flat_map(|head| {
+            (0..2).flat_map(move |j| (0..half).map(move |i| head * head_dim + 2 * i + j))
+        })
+        .collect()
+}
+
+fn load_metadata(file: &GgufFile) -> ModelMetadata {
+    let fields = file.metadata().iter().filter_map(|(key, value)| {
+        let value = value.as_str()?;
+        let field = match key.as_str() {
+            "general.description" => MetadataField::Description,
+            "general.license" => MetadataField::License,
+            key if key.starts_with("tokenizer.") => return None,
+            key => MetadataField::Custom(key.to_string()),
+        };
+        Some((field, value.to_string()))
+    });
+    ModelMetadata::from_fields(fields)
+}
+
+fn build_graph(file: &GgufFile, config: &Config) -> Result<Graph, LoadError> {
+    let Config {
+        n_layer,
+        n_embd,
+        n_head,
+        n_head_kv,
+        head_dim,
+        rms_eps,
+        ..
+    } = *config;
+
+    let mut b = GraphBuilder {
+        file,
+        graph: Graph::with_capacity(n_layer * 80),
+    };
+
+    let batch = || Dimension::Symbolic("batch".to_string());
+    let seq = || Dimension::Symbolic("sequence".to_string());
+    let kv_shape = |seq_name: &str| {
+        Some(vec![
+            batch(),
+            Dimension::Fixed(n_head_kv),
+            Dimension::Symbolic(seq_name.to_string()),
+            Dimension::Fixed(head_dim),
+        ])
+    };
+    let float_type = Some(ValueType::Tensor(DataType::Float));
+    let int_type = Some(ValueType::Tensor(DataType::Int32));
+
+    let input_ids = b
+        .graph
+        .add_value(Some("input_ids"), Some(vec![batch(), seq()]), int_type);
+    let position_ids =
+        b.graph
+            .add_value(Some("position_ids"), Some(vec![batch(), seq()]), int_type);
+    let mut input_node_ids = vec![input_ids, position_ids];
+    let mut output_node_ids = Vec::new();
+
+    // Token embeddings. 16-bit float embeddings are kept as-is, but
+    // block-quantized embeddings are converted to f32, as `Gather` does not
+    // support block-quantized inputs.
+    let embedding_info = b.tensor_info("token_embd.weight")?;
+    let embedding_data = b.tensor_data(embedding_info)?;
+    let embedding = b.float_tensor(embedding_info, embedding_data, &embedding_info.shape())?;
+    let &[vocab_size, embd_size] = embedding.shape() else {
+        return Err(graph_error("token embeddings should be 2D"));
+    };
+    if embd_size != n_embd {
+        return Err(graph_error("token embedding size mismatch"));
+    }
+    let embedding_id = b.add_float_constant("token_embd.weight", embedding.clone());
+    let mut hidden = b.add_op("token_embd", Gather { axis: 0 }, &[embedding_id, input_ids]);
+
+    // Rotary embedding tables of shape (batch, 1, seq, head_dim), shared by
+    // all layers.
+    let mut inv_freq: Vec<f32> = (0..head_dim / 2)
+        .map(|i| {
+            1. / (config.rope_freq_base.powf((2 * i) as f32 / head_dim as f32) * config.rope_scale)
+        })
+        .collect();
+    if b.file.tensor("rope_freqs.weight").is_some() {
+        // Per-frequency scaling factors used by Llama 3.1 and later.
+        let factors = b.load_f32("rope_freqs.weight")?;
+        if factors.len() != inv_freq.len() {
+            return Err(graph_error("rope frequency factors have incorrect size"));
+        }
+        for (freq, factor) in inv_freq.iter_mut().zip(factors.iter()) {
+            *freq /= factor;
+        }
+    }
+    let inv_freq = b.add_constant("rope.inv_freq", Tensor::from(inv_freq).into_arc());
+    let position_f32 = b.add_op(
+        "rope.positions",
+        Cast {
+            to: DataType::Float,
+        },
+        &[position_ids],
+    );
+    let last_axis = b.add_i32("rope.last_axis", &[-1]);
+    let position_f32 = b.add_op(
+        "rope.positions_unsqueeze",
+        Unsqueeze {},
+        &[position_f32, last_axis],
+    );
+    let freqs = b.add_op("rope.freqs", Mul {}, &[position_f32, inv_freq]);
+    let freqs = b.add_op("rope.freqs_concat", Concat { axis: -1 }, &[freqs, freqs]);
+    let head_axis = b.add_i32("rope.head_axis", &[1]);
+    let cos = b.add_op("rope.cos", Cos {}, &[freqs]);
+    let cos = b.add_op("rope.cos_unsqueeze", Unsqueeze {}, &[cos, head_axis]);
+    let sin = b.add_op("rope.sin", Sin {}, &[freqs]);
+    let sin = b.add_op("rope.sin_unsqueeze", Unsqueeze {}, &[sin, head_axis]);
+
+    // Causal attention mask of shape (batch, 1, seq, past_seq + seq). Key
+    // positions are compared against `position_ids`, which are the absolute
+    // positions of the query tokens.
+    let past_len_source = if n_layer > 0 {
+        Some(b.graph.add_value(
+            Some("past_key_values.0.key"),
+            kv_shape("past_sequence"),
+            float_type,
+        ))
+    } else {
+        None
+    };
+    let mask = if let Some(past_key) = past_len_source {
+        let seq_len = b.add_op(
+            "mask.seq_len",
+            Shape {
+                start: Some(1),
+                end: Some(2),
+            },
+            &[input_ids],
+        );
+        let past_len = b.add_op(
+            "mask.past_len",
+            Shape {
+                start: Some(2),
+                end: Some(3),
+            },
+            &[past_key],
+        );
+        let total_len = b.add_op("mask.total_len", Add {}, &[past_len, seq_len]);
+        let zero = b.add_constant("mask.zero", Tensor::from(0i32).into_arc());
+        let one = b.add_constant("mask.one", Tensor::from(1i32).into_arc());
+        let key_pos = b.add_op("mask.key_pos", Range {}, &[zero, total_len, one]);
+        let query_axes = b.add_i32("mask.query_axes", &[1, 3]);
+        let query_pos = b.add_op("mask.query_pos", Unsqueeze {}, &[position_ids, query_axes]);
+        let masked = b.add_op("mask.masked", Greater {}, &[key_pos, query_pos]);
+        let neg_inf = b.add_f32_scalar("mask.neg_inf", f32::NEG_INFINITY);
+        let zero_f32 = b.add_f32_scalar("mask.zero_f32", 0.);
+        Some(b.add_op("mask", Where {}, &[masked, neg_inf, zero_f32]))
+    } else {
+        None
+    };
+
+    let head_shape = |n: usize| [0, 0, n as i32, head_dim as i32];
+    let q_shape = b.add_i32("attn.q_shape", &head_shape(n_head));
+    let kv_shape_id = b.add_i32("attn.kv_shape", &head_shape(n_head_kv));
+    let merge_shape = b.add_i32("attn.merge_shape", &[0, 0, (n_head * head_dim) as i32]);
+    let scale = b.add_f32_scalar("attn.scale", 1. / (head_dim as f32).sqrt());
+
+    let (q_order, k_order) = if config.arch.permuted_qk() {
+        (
+            Some(unpermute_qk_rows(n_head, head_dim)),
+            Some(unpermute_qk_rows(n_head_kv, head_dim)),
+        )
+    } else {
+        (None, None)
+    };
+
+    let transpose_heads = || Transpose {
+        perm: Some(vec![0, 2, 1, 3]),
+    };
+
+    for layer in 0..n_layer {
+        let p = format!("blk.{}", layer);
+        let past_key = if layer == 0
+            && let Some(past_key) = past_len_source
+        {
+            past_key
+        } else {
+            b.graph.add_value(
+                Some(&format!("past_key_values.{}.key", layer)),
+                kv_shape("past_sequence"),
+                float_type,
+            )
+        };
+        let past_value = b.graph.add_value(
+            Some(&format!("past_key_values.{}.value", layer)),
+            kv_shape("past_sequence"),
+            float_type,
+        );
+        input_node_ids.extend([past_key, past_value]);
+
+        // Self-attention
+        let x = b.rms_norm(&format!("{}.attn_norm", p), hidden, rms_eps)?;
+
+        let q = b.linear(&format!("{}.attn_q", p), x, q_order.as_deref())?;
+        let k = b.linear(&format!("{}.attn_k", p), x, k_order.as_deref())?;
+        let v = b.linear(&format!("{}.attn_v", p), x, None)?;
+
+        let q = b.add_op(
+            &format!("{}.q_reshape", p),
+            Reshape { allow_zero: false },
+            &[q, q_shape],
+        );
+        let q = b.add_op(&format!("{}.q_transpose", p), transpose_heads(), &[q]);
+        let k = b.add_op(
+            &format!("{}.k_reshape", p),
+            Reshape { allow_zero: false },
+            &[k, kv_shape_id],
+        );
+        let k = b.add_op(&format!("{}.k_transpose", p), transpose_heads(), &[k]);
+        let v = b.add_op(
+            &format!("{}.v_reshape", p),
+            Reshape { allow_zero: false },
+            &[v, kv_shape_id],
+        );
+        let v = b.add_op(&format!("{}.v_transpose", p), transpose_heads(), &[v]);
+
+        let q = b.rope(&format!("{}.q_rope", p), q, cos, sin, head_dim);
+        let k = b.rope(&format!("{}.k_rope", p), k, cos, sin, head_dim);
+
+        let present_key = b.graph.add_value(
+            Some(&format!("present.{}.key", layer)),
+            kv_shape("present_sequence"),
+            float_type,
+        );
+        let present_value = b.graph.add_value(
+            Some(&format!("present.{}.value", layer)),
+            kv_shape("present_sequence"),
+            float_type,
+        );
+        b.add_op_with_output(
+            &format!("{}.key_cache", p),
+            Concat { axis: 2 },
+            &[past_key, k],
+            present_key,
+        );
+        b.add_op_with_output(
+            &format!("{}.value_cache", p),
+            Concat { axis: 2 },
+            &[past_value, v],
+            present_value,
+        );
+        output_node_ids.extend([present_key, present_value]);
+
+        let (keys, values) = if n_head != n_head_kv {
+            let repeats = n_head / n_head_kv;
+            (
+                b.add_op(
+                    &format!("{}.repeat_keys", p),
+                    RepeatInterleave { axis: 1, repeats },
+                    &[present_key],
+                ),
+                b.add_op(
+                    &format!("{}.repeat_values", p),
+                    RepeatInterleave { axis: 1, repeats },
+                    &[present_value],
+                ),
+            )
+        } else {
+            (present_key, present_value)
+        };
+
+        let keys_t = b.add_op(
+            &format!("{}.keys_transpose", p),
+            Transpose {
+                perm: Some(vec![0, 1, 3, 2]),
+            },
+            &[keys],
+        );
+        let scores = b.add_op(&format!("{}.qk", p), MatMul {}, &[q, keys_t]);
+        let scores = b.add_op(&format!("{}.qk_scale", p), Mul {}, &[scores, scale]);
+        let scores = if let Some(mask) = mask {
+            b.add_op(&format!("{}.qk_mask", p), Add {}, &[scores, mask])
+        } else {
+            scores
+        };
+        let probs = b.add_op(
+            &format!("{}.softmax", p),
+            Softmax {
+                axis: -1,
+                flush_nans_to_zero: false,
+            },
+            &[scores],
+        );
+        let attn = b.add_op(&format!("{}.attn", p), MatMul {}, &[probs, values]);
+        let attn = b.add_op(&format!("{}.attn_transpose", p), transpose_heads(), &[attn]);
+        let attn = b.add_op(
+            &format!("{}.attn_reshape", p),
+            Reshape { allow_zero: false },
+            &[attn, merge_shape],
+        );
+        let attn = b.linear(&format!("{}.attn_output", p), attn, None)?;
+        hidden = b.add_op(&format!("{}.attn_residual", p), Add {}, &[hidden, attn]);
+
+        // Feed-forward
+        let x = b.rms_norm(&format!("{}.ffn_norm", p), hidden, rms_eps)?;
+        let gate = b.linear(&format!("{}.ffn_gate", p), x, None)?;
+        let gate = b.add_op(&format!("{}.ffn_silu", p), Silu {}, &[gate]);
+        let up = b.linear(&format!("{}.ffn_up", p), x, None)?;
+        let x = b.add_op(&format!("{}.ffn_mul", p), Mul {}, &[gate, up]);
+        let down = b.linear(&format!("{}.ffn_down", p), x, None)?;
+        hidden = b.add_op(&format!("{}.ffn_residual", p), Add {}, &[hidden, down]);
+    }
+
+    let hidden = b.rms_norm("output_norm", hidden, rms_eps)?;
+
+    // Models with tied embeddings don't have an output weight. In that case
+    // the transposed token embeddings are used.
+    let output_weight = if b.file.tensor("output.weight").is_some() {
+        b.load_linear("output.weight", None)?
+    } else {
+        let mut weight = embedding;
+        weight.transpose();
+        LinearWeight::Float(b.add_float_constant("output.weight", weight))
+    };
+    let logits = b.graph.add_value(
+        Some("logits"),
+        Some(vec![batch(), seq(), Dimension::Fixed(vocab_size)]),
+        float_type,
+    );
+    b.add_matmul_with_output("output", hidden, &output_weight, logits);
+    output_node_ids.insert(0, logits);
+
+    b.graph.set_input_ids(&input_node_ids);
+    b.graph.set_output_ids(&output_node_ids);
+
+    Ok(b.graph)
+}
+
+/// Construct a model from a GGUF file.
+///
+/// The weights are copied out of `data`, since they are generally converted
+/// to a different format.
+pub fn load(data: &[u8], options: &ModelOptions) -> Result<Model, LoadError> {
+    let file = GgufFile::parse(data).map_err(|err| LoadErrorImpl::ParseFailed(Box::new(err)))?;
+    let config = Config::from_metadata(&file)?;
+    let graph = build_graph(&file, &config)?;
+
+    let optimize = options.optimize_mode();
+    let optimized = matches!(optimize, OptimizeMode::On(_));
+    let graph = if let OptimizeMode::On(opts) = optimize {
+        GraphOptimizer::new()
+            .optimize(graph, None, opts)
+            .map_err(|err| LoadErrorImpl::OptimizeError(Box::new(err)))?
+    } else {
+        graph
+    };
+
+    let mut weight_cache = WeightCache::new();
+    if options.prepack_weights {
+        graph.prepack_weights(&mut weight_cache);
+    }
+
+    Ok(Model {
+        metadata: load_metadata(&file),
+        graph,
+        weight_cache,
+        optimized,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rten_gguf::{GgmlType, GgufWriter, TensorInfo, Value};
+    use rten_tensor::prelude::*;
+    use rten_tensor::rng::XorShiftRng;
+    use rten_tensor::test_util::expect_equal_with_tolerance;
+    use rten_tensor::{NdTensor, Tensor};
+
+    use super::{load, repack_nbits, unpermute_qk_rows};
+    use crate::ModelOptions;
+    use crate::graph::Node;
+    use crate::model::{LoadErrorKind, Model};
+    use crate::value::{DataType, ValueType};
+
+    const VOCAB: usize = 16;
+    const N_EMBD: usize = 64;
+    const N_HEAD: usize = 4;
+    const N_HEAD_KV: usize = 2;
+    const HEAD_DIM: usize = N_EMBD / N_HEAD;
+    const N_FFN: usize = 64;
+    const N_LAYER: usize = 2;
+
+    /// Quantize `data` to Q4_0 blocks in the same way as llama.cpp.
+    fn quantize_q4_0(data: &[f32]) -> Vec<u8> {
+        let mut out = Vec::new();
+        for block in data.chunks(32) {
+            let max = block
+                .iter()
+                .copied()
+                .fold(0f32, |max, x| if x.abs() > max.abs() { x } else { max });
+            let scale = max / -8.;
+            let inv_scale = if scale != 0. { 1. / scale } else { 0. };
+            let quant = |x: f32| ((x * inv_scale + 8.5) as u8).min(15);
+            out.extend(rten_base::half::f32_to_f16(scale).to_le_bytes());
+            out.extend((0..16).map(|i| quant(block[i]) | (quant(block[i + 16]) << 4)));
+        }
+        out
+    }
+
+    /// Quantize `data` to Q8_0 blocks in the same way as llama.cpp.
+    fn quantize_q8_0(data: &[f32]) -> Vec<u8> {
+        let mut out = Vec::new();
+        for block in data.chunks(32) {
+            let max = block.iter().fold(0f32, |max, x| max.max(x.abs()));
+            let scale = max / 127.;
+            let inv_scale = if scale != 0. { 1. / scale } else { 0. };
+            out.extend(rten_base::half::f32_to_f16(scale).to_le_bytes());
+            out.extend(block.iter().map(|x| (x * inv_scale).round() as i8 as u8));
+        }
+        out
+    }
+
+    /// Generate `n_blocks` blocks of type `ty` with random quantized values
+    /// and scales.
+    fn random_blocks(ty: GgmlType, n_blocks: usize, rng: &mut XorShiftRng) -> Vec<u8> {
+        let (_, block_bytes) = ty.block_size().unwrap();
+        let mut data: Vec<u8> = (0..n_blocks * block_bytes)
+            .map(|_| rng.next_u64() as u8)
+            .collect();
+
+        // Replace the f16 scales with values in a reasonable range, as random
+        // bits may be NaN or infinite.
+        let scale_offsets: &[usize] = match ty {
+            GgmlType::Q4_0 | GgmlType::Q8_0 => &[0],
+            GgmlType::Q4_K => &[0, 2],
+            GgmlType::Q6_K => &[208],
+            _ => unimplemented!("unsupported type"),
+        };
+        for block in data.chunks_exact_mut(block_bytes) {
+            for &offset in scale_offsets {
+                let scale = rten_base::half::f32_to_f16(rng.next_f32() * 0.01);
+                block[offset..offset + 2].copy_from_slice(&scale.to_le_bytes());
+            }
+        }
+        data
+    }
+
+    /// Permute rows of a query or key weight in the same way as llama.cpp's
+    /// converter does for Llama models.
+    fn permute_qk(weight: &Tensor<f32>, n_head: usize) -> Tensor<f32> {
+        let [rows, cols]: [usize; 2] = weight.shape().try_into().unwrap();
+        let head_dim = rows / n_head;
+        let mut out = Tensor::zeros(&[rows, cols]);
+        for h in 0..n_head {
+            for j in 0..2 {
+                for i in 0..head_dim / 2 {
+                    let src = h * head_dim + j * head_dim / 2 + i;
+                    let dst = h * head_dim + 2 * i + j;
+                    out.slice_mut(dst).copy_from(&weight.slice(src));
+                }
+            }
+        }
+        out
+    }
+
+    /// Storage format for linear layer weights in test models.
+    #[derive(Copy, Clone, PartialEq)]
+    enum WeightFormat {
+        Float,
+        /// Weights quantized to a block-quantized type (Q4_0 or Q8_0).
+        Quantized(GgmlType),
+        /// Float weights with values rounded to those representable in a
+        /// block-quantized type.
+        QuantizedAsFloat(GgmlType),
+        /// Weights and token embeddings stored as a 16-bit float type (F16
+        /// or BF16).
+        Half(GgmlType),
+        /// Float weights and token embeddings with values rounded to those
+        /// representable in a 16-bit float type.
+        HalfAsFloat(GgmlType),
+    }
+
+    /// Convert `data` to GGML type `ty`.
+    fn quantize(ty: GgmlType, data: &[f32]) -> Vec<u8> {
+        match ty {
+            GgmlType::Q4_0 => quantize_q4_0(data),
+            GgmlType::Q8_0 => quantize_q8_0(data),
+            GgmlType::F16 => data
+                .iter()
+                .flat_map(|x| rten_base::half::f32_to_f16(*x).to_le_bytes())
+                .collect(),
+            GgmlType::BF16 => data
+                .iter()
+                .flat_map(|x| ((x.to_bits() >> 16) as u16).to_le_bytes())
+                .collect(),
+            _ => unimplemented!("unsupported type"),
+        }
+    }
+
+    /// Add a tensor to `writer` in the given format.
+    fn add_tensor(writer: &mut GgufWriter, name: &str, weight: &Tensor<f32>, ty: GgmlType) {
+        writer.add_tensor(name, weight.shape(), ty, &quantize(ty, &weight.to_vec()));
+    }
+
+    /// Add a tensor to `writer` as f32, with values rounded to those
+    /// representable in `ty`.
+    fn add_rounded_tensor(writer: &mut GgufWriter, name: &str, weight: &Tensor<f32>, ty: GgmlType) {
+        let mut rounded = vec![0.; weight.len()];
+        rten_gguf::dequantize(ty, &quantize(ty, &weight.to_vec()), &mut rounded).unwrap();
+        writer.add_f32_tensor(name, weight.shape(), &rounded);
+    }
+
+    struct ModelParams {
+        arch: &'static str,
+        weights: WeightFormat,
+        tied_embeddings: bool,
+    }
+
+    /// Generate a GGUF file for a small Llama-like model with random weights.
+    ///
+    /// The same weights are generated for each call, regardless of the
+    /// architecture or quantization.
+    fn generate_model(params: ModelParams) -> Vec<u8> {
+        let mut rng = XorShiftRng::new(1234);
+        let mut rand = |shape: &[usize]| Tensor::<f32>::rand(shape, &mut rng).map(|x| x - 0.5);
+
+        let mut writer = GgufWriter::new();
+        let arch = params.arch;
+        writer.add_metadata("general.architecture", Value::String(arch.into()));
+        writer.add_metadata("general.name", Value::String("test-model".into()));
+        let add_u32 = |writer: &mut GgufWriter, key: &str, val: usize| {
+            writer.add_metadata(&format!("{}.{}", arch, key), Value::U32(val as u32));
+        };
+        add_u32(&mut writer, "block_count", N_LAYER);
+        add_u32(&mut writer, "embedding_length", N_EMBD);
+        add_u32(&mut writer, "attention.head_count", N_HEAD);
+        add_u32(&mut writer, "attention.head_count_kv", N_HEAD_KV);
+        writer.add_metadata(
+            &format!("{}.attention.layer_norm_rms_epsilon", arch),
+            Value::F32(1e-5),
+        );
+
+        let add_linear = |writer: &mut GgufWriter, name: &str, weight: &Tensor<f32>| match params
+            .weights
+        {
+            WeightFormat::Float => writer.add_f32_tensor(name, weight.shape(), &weight.to_vec()),
+            WeightFormat::Quantized(ty) | WeightFormat::Half(ty) => {
+                add_tensor(writer, name, weight, ty)
+            }
+            WeightFormat::QuantizedAsFloat(ty) | WeightFormat::HalfAsFloat(ty) => {
+                add_rounded_tensor(writer, name, weight, ty)
+            }
+        };
+        let add_norm = |writer: &mut GgufWriter, name: &str, weight: &Tensor<f32>| {
+            writer.add_f32_tensor(name, weight.shape(), &weight.map(|x| x + 1.).to_vec());
+        };
+
+        let embedding = rand(&[VOCAB, N_EMBD]);
+        match params.weights {
+            WeightFormat::Half(ty) => add_tensor(&mut writer, "token_embd.weight", &embedding, ty),
+            WeightFormat::HalfAsFloat(ty) => {
+                add_rounded_tensor(&mut writer, "token_embd.weight", &embedding, ty)
+            }
+            _ => writer.add_f32_tensor("token_embd.weight", embedding.shape(), &embedding.to_vec()),
+        }
+
+        for layer in 0..N_LAYER {
+            let p = format!("blk.{}", layer);
+            add_norm(
+                &mut writer,
+                &format!("{}.attn_norm.weight", p),
+                &rand(&[N_EMBD]),
+            );
+
+            let mut q = rand(&[N_HEAD * HEAD_DIM, N_EMBD]);
+            let mut k = rand(&[N_HEAD_KV * HEAD_DIM, N_EMBD]);
+            if arch == "llama" {
+                q = permute_qk(&q, N_HEAD);
+                k = permute_qk(&k, N_HEAD_KV);
+            }
+            add_linear(&mut writer, &format!("{}.attn_q.weight", p), &q);
+            add_linear(&mut writer, &format!("{}.attn_k.weight", p), &k);
+            add_linear(
+                &mut writer,
+                &format!("{}.attn_v.weight", p),
+                &rand(&[N_HEAD_KV * HEAD_DIM, N_EMBD]),
+            );
+            add_linear(
+                &mut writer,
+                &format!("{}.attn_output.weight", p),
+                &rand(&[N_EMBD, N_HEAD * HEAD_DIM]),
+            );
+
+            add_norm(
+                &mut writer,
+                &format!("{}.ffn_norm.weight", p),
+                &rand(&[N_EMBD]),
+            );
+            add_linear(
+                &mut writer,
+                &format!("{}.ffn_gate.weight", p),
+                &rand(&[N_FFN, N_EMBD]),
+            );
+            add_linear(
+                &mut writer,
+                &format!("{}.ffn_up.weight", p),
+                &rand(&[N_FFN, N_EMBD]),
+            );
+            add_linear(
+                &mut writer,
+                &format!("{}.ffn_down.weight", p),
+                &rand(&[N_EMBD, N_FFN]),
+            );
+        }
+
+        add_norm(&mut writer, "output_norm.weight", &rand(&[N_EMBD]));
+        let output = rand(&[VOCAB, N_EMBD]);
+        if !params.tied_embeddings {
+            add_linear(&mut writer, "output.weight", &output);
+        }
+
+        writer.finish()
+    }
+
+    /// Run the model with a sequence of tokens and a KV cache containing
+    /// `past` previous tokens.
+    ///
+    /// Returns `(logits, present_kv)`.
+    fn run_model(
+        model: &Model,
+        input_ids: &[i32],
+        past_kv: &[NdTensor<f32, 4>],
+    ) -> (NdTensor<f32, 3>, Vec<NdTensor<f32, 4>>) {
+        let past_len = past_kv[0].size(2);
+        let seq_len = input_ids.len();
+        let input_ids = NdTensor::from_data([1, seq_len], input_ids.to_vec());
+        let position_ids = NdTensor::from_data(
+            [1, seq_len],
+            (past_len..past_len + seq_len)
+                .map(|x| x as i32)
+                .collect::<Vec<_>>(),
+        );
+
+        let mut inputs = vec![
+            (model.node_id("input_ids").unwrap(), input_ids.into()),
+            (model.node_id("position_ids").unwrap(), position_ids.into()),
+        ];
+        let mut outputs = vec![model.node_id("logits").unwrap()];
+        for layer in 0..N_LAYER {
+            for (i, kind) in ["key", "value"].iter().enumerate() {
+                let past_name = format!("past_key_values.{}.{}", layer, kind);
+                inputs.push((
+                    model.node_id(&past_name).unwrap(),
+                    past_kv[layer * 2 + i].view().into(),
+                ));
+                let present_name = format!("present.{}.{}", layer, kind);
+                outputs.push(model.node_id(&present_name).unwrap());
+            }
+        }
+
+        let mut results = model.run(inputs, &outputs, None).unwrap().into_iter();
+        let logits = results.next().unwrap().try_into().unwrap();
+        let present = results.map(|kv| kv.try_into().unwrap()).collect();
+        (logits, present)
+    }
+
+    fn empty_kv_cache() -> Vec<NdTensor<f32, 4>> {
+        (0..N_LAYER * 2)
+            .map(|_| NdTensor::zeros([1, N_HEAD_KV, 0, HEAD_DIM]))
+            .collect()
+    }
+
+    fn load_model(params: ModelParams) -> Model {
+        let data = generate_model(params);
+        ModelOptions::with_all_ops().load(data).unwrap()
+    }
+
+    #[test]
+    fn test_load_llama() {
+        let model = load_model(ModelParams {
+            arch: "llama",
+            weights: WeightFormat::Float,
+            tied_embeddings: false,
+        });
+        assert_eq!(model.metadata().get("general.name"), Some("test-model"));
+
+        // Run the model on a full sequence, then again incrementally using
+        // the KV cache. The logits for the last token should match.
+        let tokens = [1, 5, 3, 7];
+        let (full_logits, full_kv) = run_model(&model, &tokens, &empty_kv_cache());
+        assert_eq!(full_logits.shape(), [1, tokens.len(), VOCAB]);
+        assert_eq!(full_kv[0].shape(), [1, N_HEAD_KV, tokens.len(), HEAD_DIM]);
+
+        let (_, kv) = run_model(&model, &tokens[..2], &empty_kv_cache());
+        let (_, kv) = run_model(&model, &tokens[2..3], &kv);
+        let (logits, kv) = run_model(&model, &tokens[3..], &kv);
+
+        expect_equal_with_tolerance(
+            &logits.slice((.., 0)),
+            &full_logits.slice((.., -1)),
+            1e-4,
+            1e-4,
+        )
+        .unwrap();
+        assert_eq!(kv[0].shape(), full_kv[0].shape());
+    }
+
+    #[test]
+    fn test_llama_qk_permutation() {
+        // The Llama model stores permuted query and key weights. Once this is
+        // reversed, it should produce the same output as a Qwen2 model using
+        // the unpermuted weights.
+        let llama = load_model(ModelParams {
+            arch: "llama",
+            weights: WeightFormat::Float,
+            tied_embeddings: false,
+        });
+        let qwen2 = load_model(ModelParams {
+            arch: "qwen2",
+            weights: WeightFormat::Float,
+            tied_embeddings: false,
+        });
+        let tokens = [2, 4, 6];
+        let (llama_logits, _) = run_model(&llama, &tokens, &empty_kv_cache());
+        let (qwen2_logits, _) = run_model(&qwen2, &tokens, &empty_kv_cache());
+        expect_equal_with_tolerance(&llama_logits, &qwen2_logits, 1e-4, 1e-4).unwrap();
+
+        // Check the row order reverses the permutation.
+        let weight = Tensor::arange(0., 16., None).into_shape([8, 2].as_slice());
+        let permuted = permute_qk(&weight, 2);
+        let order = unpermute_qk_rows(2, 4);
+        for (row, &src) in order.iter().enumerate() {
+            assert_eq!(permuted.slice(src), weight.slice(row));
+        }
+    }
+
+    /// Return the data types of the constant nodes in `model` whose names
+    /// end with `suffix`.
+    fn constant_types(model: &Model, suffix: &str) -> Vec<DataType> {
+        model
+            .graph
+            .iter()
+            .filter_map(|(_, node)| match node {
+                Node::Constant(constant) if constant.name()?.ends_with(suffix) => {
+                    match node.dtype() {
+                        Some(ValueType::Tensor(dtype)) => Some(dtype),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_load_quantized() {
+        for ty in [GgmlType::Q4_0, GgmlType::Q8_0] {
+            let float_model = load_model(ModelParams {
+                arch: "llama",
+                weights: WeightFormat::QuantizedAsFloat(ty),
+                tied_embeddings: true,
+            });
+            let quant_model = load_model(ModelParams {
+                arch: "llama",
+                weights: WeightFormat::Quantized(ty),
+                tied_embeddings: true,
+            });
+
+            // Weights should be kept in their quantized form.
+            let quant_types = constant_types(&quant_model, ".quant");
+            assert_eq!(quant_types.len(), N_LAYER * 7);
+            assert!(quant_types.iter().all(|ty| *ty == DataType::UInt8));
+
+            let tokens = [3, 1, 4, 1, 5];
+            let (float_logits, _) = run_model(&float_model, &tokens, &empty_kv_cache());
+            let (quant_logits, _) = run_model(&quant_model, &tokens, &empty_kv_cache());
+
+            // The quantized model should match the float model with the same
+            // weights, up to differences caused by quantizing activations.
+            let max_diff = float_logits
+                .iter()
+                .zip(quant_logits.iter())
+                .map(|(x, y)| (x - y).abs())
+                .fold(0., f32::max);
+            let max_abs = float_logits.iter().map(|x| x.abs()).fold(0., f32::max);
+            assert!(
+                max_diff < max_abs * 0.05,
+                "max diff {} exceeds tolerance for {}",
+                max_diff,
+                ty
+            );
+        }
+    }
+
+    #[test]
+    fn test_load_half() {
+        for (ty, dtype) in [
+            (GgmlType::F16, DataType::Float16),
+            (GgmlType::BF16, DataType::BFloat16),
+        ] {
+            let float_model = load_model(ModelParams {
+                arch: "llama",
+                weights: WeightFormat::HalfAsFloat(ty),
+                tied_embeddings: true,
+            });
+            let half_model = load_model(ModelParams {
+                arch: "llama",
+                weights: WeightFormat::Half(ty),
+                tied_embeddings: true,
+            });
+
+            // Linear weights and embeddings should keep their 16-bit type.
+            for name in ["token_embd.weight", "attn_q.weight", "ffn_down.weight"] {
+                let types = constant_types(&half_model, name);
+                assert!(!types.is_empty());
+                assert!(types.iter().all(|t| *t == dtype));
+            }
+
+            let tokens = [3, 1, 4, 1, 5];
+            let (float_logits, _) = run_model(&float_model, &tokens, &empty_kv_cache());
+            let (half_logits, _) = run_model(&half_model, &tokens, &empty_kv_cache());
+            expect_equal_with_tolerance(&half_logits, &float_logits, 1e-4, 1e-4).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_repack_nbits() {
+        let mut rng = XorShiftRng::new(5678);
+        let (rows, cols) = (3, 512);
+
+        for ty in [
+            GgmlType::Q4_0,
+            GgmlType::Q8_0,
+            GgmlType::Q4_K,
+            GgmlType::Q6_K,
+        ] {
+            let (block_len, _) = ty.block_size().unwrap();
+            let mut data = random_blocks(ty, rows * cols / block_len, &mut rng);
+            if ty == GgmlType::Q4_K {
+                // Set the scale of the first sub-block to zero, so that all
+                // of its elements equal the negated minimum.
+                data[4] &= !63;
+            }
+
+            let mut expected = vec![0.; rows * cols];
+            rten_gguf::dequantize(ty, &data, &mut expected).unwrap();
+
+            let weight = repack_nbits(ty, &data, rows, cols).unwrap();
+            let k_blocks = cols / weight.block_size;
+            let bits = weight.bits as usize;
+            assert_eq!(
+                weight.quant.shape(),
+                [rows, k_blocks, weight.block_size * bits / 8]
+            );
+            assert_eq!(weight.scales.shape(), [rows, k_blocks]);
+
+            // Dequantize using the MatMulNBits layout, where elements are
+            // packed into a little-endian bitstream.
+            let zero_point = (1 << (bits - 1)) as f32;
+            let mut actual = Vec::new();
+            for row in 0..rows {
+                for block in 0..k_blocks {
+                    let scale = weight.scales[[row, block]];
+                    let zero = weight
+                        .zero_points
+                        .as_ref()
+                        .map(|zp| zp[[row, block]])
+                        .unwrap_or(zero_point);
+                    let bytes = weight.quant.slice((row, block)).to_vec();
+                    for i in 0..weight.block_size {
+                        let bit = i * bits;
+                        let word = bytes[bit / 8] as u32
+                            | bytes
+                                .get(bit / 8 + 1)
+                                .map(|b| (*b as u32) << 8)
+                                .unwrap_or(0);
+                        let q = (word >> (bit % 8)) & ((1 << bits) - 1);
+                        actual.push(scale * (q as f32 - zero));
+                    }
+                }
+            }
+            expect_equal_with_tolerance(&Tensor::from(actual), &Tensor::from(expected), 1e-5, 1e-5)
+                .unwrap();
+        }
+
+        assert!(repack_nbits(GgmlType::F16, &[], 0, 0).is_none());
+    }
+
+    #[test]
+    fn test_load_invalid() {
+        let mut writer = GgufWriter::new();
+        writer.add_metadata("general.architecture", Value::String("mamba".into()));
+        let err = load(&writer.finish(), &ModelOptions::with_all_ops())
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), LoadErrorKind::GraphError);
+        assert!(err.to_string().contains("unsupported architecture"));
+
+        let err = load(b"GGUF", &ModelOptions::with_all_ops()).err().unwrap();
+        assert_eq!(err.kind(), LoadErrorKind::ParseError);
+
+        // Block-quantized types which can't be used without dequantizing
+        // are rejected.
+        let data = generate_model(ModelParams {
+            arch: "qwen2",
+            weights: WeightFormat::Float,
+            tied_embeddings: true,
+        });
+        let data = replace_tensor(&data, "blk.0.attn_q.weight", |writer, info| {
+            let len = info.len().unwrap();
+            let q4_1 = vec![0; GgmlType::Q4_1.size_in_bytes(len).unwrap()];
+            writer.add_tensor(&info.name, &info.shape(), GgmlType::Q4_1, &q4_1);
+        });
+        let err = load(&data, &ModelOptions::with_all_ops()).err().unwrap();
+        assert_eq!(err.kind(), LoadErrorKind::GraphError);
+        assert!(err.to_string().contains("unsupported weight type"));
+    }
+
+    #[test]
+    fn test_load_invalid_linear_weight() {
+        // Query weight with fewer rows than `n_head * head_dim`, which doesn't
+        // match the row order used to undo the Llama Q/K permutation.
+        let data = generate_model(ModelParams {
+            arch: "llama",
+            weights: WeightFormat::Float,
+            tied_embeddings: false,
+        });
+        let data = replace_tensor(&data, "blk.0.attn_q.weight", |writer, info| {
+            let rows = N_HEAD * HEAD_DIM / 2;
+            writer.add_f32_tensor(&info.name, &[rows, N_EMBD], &vec![0.; rows * N_EMBD]);
+        });
+        let err = load(&data, &ModelOptions::with_all_ops()).err().unwrap();
+        assert_eq!(err.kind(), LoadErrorKind::GraphError);
+        assert!(err.to_string().contains("row order does not match"));
+
+        // Weight whose data can't be split evenly into rows. Here the single
+        // Q8_0 block spans all 4 rows.
+        let data = generate_model(ModelParams {
+            arch: "qwen2",
+            weights: WeightFormat::Float,
+            tied_embeddings: false,
+        });
+        let data = replace_tensor(&data, "output.weight", |writer, info| {
+            let q8_0 = vec![0; GgmlType::Q8_0.size_in_bytes(32).unwrap()];
+            writer.add_tensor(&info.name, &[4, 8], GgmlType::Q8_0, &q8_0);
+        });
+        let err = load(&data, &ModelOptions::with_all_ops()).err().unwrap();
+        assert_eq!(err.kind(), LoadErrorKind::GraphError);
+        assert!(err.to_string().contains("is not a multiple of 4 rows"));
+    }
+
+    /// Copy a GGUF file, using `replace` to write the tensor called `name`.
+    fn replace_tensor(
+        data: &[u8],
+        name: &str,
+        replace: impl FnOnce(&mut GgufWriter, &TensorInfo),
+    ) -> Vec<u8> {
+        let mut writer = GgufWriter::new();
+        let file = rten_gguf::GgufFile::parse(data).unwrap();
+        for (key, value) in file.metadata() {
+            writer.add_metadata(key, value.clone());
+        }
+        let mut replace = Some(replace);
+        for info in file.tensors() {
+            if info.name == name {
+                (replace.take().unwrap())(&mut writer, info);
+            } else {
+                let tensor_data = file.tensor_data(info).unwrap();
+                writer.add_tensor(&info.name, &info.shape(), info.ggml_type, tensor_data);
+            }
+        }
+        writer.finish()
+    }
+}
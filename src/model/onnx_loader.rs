@@ -1,9 +1,9 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs::File;
 use std::path::Path;
 
 use rten_base::byte_cast::{Pod, cast_pod_slice};
-use rten_base::half::f16_to_f32;
+use rten_base::half::{bf16, bf16_to_f32, f16, f16_to_f32};
 use rten_onnx::onnx;
 use rten_tensor::{ArcTensor, Storage, Tensor};
 
@@ -49,14 +49,10 @@ pub fn load(
     }
     .map_err(|err| LoadErrorImpl::ParseFailed(Box::new(err)))?;
 
+    let optimize = options.optimize_mode();
+    let optimized = matches!(optimize, OptimizeMode::On(_));
     let graph = if let Some(onnx_graph) = &model.graph {
-        load_graph(
-            onnx_graph,
-            &options.registry,
-            options.optimize_mode(),
-            None,
-            loader,
-        )?
+        load_graph(onnx_graph, &options.registry, optimize, None, loader)?
     } else {
         Graph::new()
     };
@@ -72,6 +68,7 @@ pub fn load(
         metadata,
         graph,
         weight_cache,
+        optimized,
     })
 }
 
@@ -155,8 +152,13 @@ fn load_graph(
     }
 
     // Add constants from initializers.
+    let half_float_initializers = half_float_initializers(onnx_graph);
     for initializer in &onnx_graph.initializer {
-        let constant = load_constant(initializer, loader, None)?;
+        let keep_half = initializer
+            .name
+            .as_deref()
+            .is_some_and(|name| half_float_initializers.contains(name));
+        let constant = load_constant(initializer, loader, None, keep_half)?;
         graph.add_constant_node(constant);
     }
 
@@ -262,6 +264,71 @@ fn load_graph(
     }
 }
 
+/// Return the names of f16 and bf16 initializers which can be kept in 16-bit
+/// form when loaded.
+///
+/// Other than MatMul and Gather, operators in RTen use f32 for floats, so
+/// 16-bit initializers are converted to f32 at load time unless they are only
+/// used as MatMul weights or Gather data. Keeping these initializers as 16-bit
+/// tensors halves the memory they use.
+fn half_float_initializers(onnx_graph: &onnx::GraphProto) -> HashSet<&str> {
+    let is_half = |init: &onnx::TensorProto| {
+        matches!(
+            init.data_type,
+            Some(onnx::DataType::FLOAT16 | onnx::DataType::BFLOAT16)
+        )
+    };
+    if !onnx_graph.initializer.iter().any(is_half) {
+        return HashSet::new();
+    }
+
+    // Map of value name to whether every use supports 16-bit inputs.
+    let mut half_uses: HashMap<&str, bool> = HashMap::new();
+    fn add_subgraph_uses<'a>(graph: &'a onnx::GraphProto, uses: &mut HashMap<&'a str, bool>) {
+        for node in &graph.node {
+            for input in &node.input {
+                uses.insert(input, false);
+            }
+            for attr in &node.attribute {
+                if let Some(subgraph) = &attr.g {
+                    add_subgraph_uses(subgraph, uses);
+                }
+            }
+        }
+    }
+
+    for node in &onnx_graph.node {
+        for (i, input) in node.input.iter().enumerate() {
+            let supports_half = matches!(
+                (node.op_type.as_deref(), i),
+                (Some("MatMul"), 1) | (Some("Gather"), 0)
+            );
+            half_uses
+                .entry(input)
+                .and_modify(|ok| *ok &= supports_half)
+                .or_insert(supports_half);
+        }
+        for attr in &node.attribute {
+            if let Some(subgraph) = &attr.g {
+                add_subgraph_uses(subgraph, &mut half_uses);
+            }
+        }
+    }
+    for output in &onnx_graph.output {
+        if let Some(name) = &output.name {
+            half_uses.insert(name, false);
+        }
+    }
+
+    onnx_graph
+        .initializer
+        .iter()
+        .filter(|init| is_half(init))
+        .filter_map(|init| init.name.as_deref())
+        .filter(|name| half_uses.get(name).copied().unwrap_or(false))
+        .collect()
+}
+
 /// Convert data type and shape information from an ONNX value to RTen's
 /// types.
 ///
@@ -291,18 +358,15 @@ fn load_value_info(
             onnx::DataType::INT8 => Some(DataType::Int8),
             onnx::DataType::UINT8 => Some(DataType::UInt8),
 
-            // RTen doesn't internally support i64 or bool tensors but converts
-            // them to i32 tensors instead. Adjust the value type here to match.
-            //
-            // This does mean that when querying metadata for an input via
-            // `Model::node_info`, the caller may get a type that doesn't
-            // match the ONNX model. It will however match the type that RTen
-            // expects for that input.
-            onnx::DataType::INT64 | onnx::DataType::BOOL => Some(DataType::Int32),
+            onnx::DataType::INT64 => Some(DataType::Int64),
+            onnx::DataType::BOOL => Some(DataType::Bool),
 
-            // RTen doesn't internally support f16 or f64 but converts to f32
-            // tensors instead. Adjust the value type here to match.
-            onnx::DataType::DOUBLE | onnx::DataType::FLOAT16 => Some(DataType::Float),
+            // RTen computes f16, bf16 and f64 values in f32. Only weights
+            // (see `half_float_initializers`) are kept in 16-bit form. Adjust
+            // the value type here to match.
+            onnx::DataType::DOUBLE | onnx::DataType::FLOAT16 | onnx::DataType::BFLOAT16 => {
+                Some(DataType::Float)
+            }
 
             _ => None,
         };
@@ -334,10 +398,14 @@ fn load_value_info(
 /// Create a constant graph node from an ONNX tensor.
 ///
 /// If `name` is provided, it overrides the name from `initializer.name`.
+///
+/// If `keep_half` is true, f16 and bf16 tensors are loaded as-is. Otherwise
+/// they are converted to f32.
 fn load_constant(
     initializer: &onnx::TensorProto,
     loader: Option<&dyn DataLoader>,
     name: Option<&str>,
+    keep_half: bool,
 ) -> Result<Constant, LoadError> {
     let name = name.or(initializer.name.as_deref());
 
@@ -423,37 +491,25 @@ fn load_constant(
             |x| x as i8,
         )?,
 
-        // RTen internally does not support i64 or bool tensors. Instead both
-        // are converted to i32 at load time.
         Some(onnx::DataType::INT64) => {
-            let i64_to_i32 = |bytes: [u8; 8]| saturating_cast_i64_to_i32(i64::from_le_bytes(bytes));
             let data = if let Some(data) = raw_data {
-                elements_from_le_bytes(&data, i64_to_i32)
+                elements_from_le_bytes(&data, i64::from_le_bytes)
             } else if let Some(external_data) = external_data {
-                elements_from_le_bytes(external_data.data(), i64_to_i32)
+                elements_from_le_bytes(external_data.data(), i64::from_le_bytes)
             } else {
-                initializer
-                    .int64_data
-                    .iter()
-                    .copied()
-                    .map(saturating_cast_i64_to_i32)
-                    .collect()
+                initializer.int64_data.clone()
             };
             let tensor = tensor_from_elements(&shape, data, name)?;
             Constant::new(name, tensor)
         }
         Some(onnx::DataType::BOOL) => {
-            let u8_to_i32 = |bytes: [u8; 1]| if bytes[0] != 0 { 1 } else { 0 };
+            let u8_to_bool = |bytes: [u8; 1]| bytes[0] != 0;
             let data = if let Some(data) = raw_data {
-                elements_from_le_bytes(&data, u8_to_i32)
+                elements_from_le_bytes(&data, u8_to_bool)
             } else if let Some(external_data) = external_data {
-                elements_from_le_bytes(external_data.data(), u8_to_i32)
+                elements_from_le_bytes(external_data.data(), u8_to_bool)
             } else {
-                initializer
-                    .int32_data
-                    .iter()
-                    .map(|x| if *x != 0 { 1 } else { 0 })
-                    .collect()
+                initializer.int32_data.iter().map(|x| *x != 0).collect()
             };
             let tensor = tensor_from_elements(&shape, data, name)?;
             Constant::new(name, tensor)
@@ -477,16 +533,41 @@ fn load_constant(
             Constant::new(name, tensor)
         }
 
-        Some(onnx::DataType::FLOAT16) => {
+        // 16-bit floats are stored in `int32_data` if not stored as raw or
+        // external data.
+        Some(onnx::DataType::FLOAT16) if keep_half => make_constant(
+            name,
+            &shape,
+            raw_data,
+            external_data,
+            &initializer.int32_data,
+            |x| f16::from_bits(x as u16),
+        )?,
+        Some(onnx::DataType::BFLOAT16) if keep_half => make_constant(
+            name,
+            &shape,
+            raw_data,
+            external_data,
+            &initializer.int32_data,
+            |x| bf16::from_bits(x as u16),
+        )?,
+        Some(dtype @ (onnx::DataType::FLOAT16 | onnx::DataType::BFLOAT16)) => {
+            let half_to_f32 = if dtype == onnx::DataType::FLOAT16 {
+                f16_to_f32
+            } else {
+                bf16_to_f32
+            };
+            let half_bytes_to_f32 = |bytes: [u8; 2]| half_to_f32(u16::from_le_bytes(bytes));
             let data = if let Some(data) = raw_data {
-                let f16_to_f32_bytes = |bytes: [u8; 2]| f16_to_f32(u16::from_le_bytes(bytes));
-                elements_from_le_bytes(&data, f16_to_f32_bytes)
+                elements_from_le_bytes(&data, half_bytes_to_f32)
+            } else if let Some(external_data) = external_data {
+                elements_from_le_bytes(external_data.data(), half_bytes_to_f32)
             } else {
                 initializer
                     .int32_data
                     .iter()
                     .copied()
-                    .map(|x| f16_to_f32(x as u16))
+                    .map(|x| half_to_f32(x as u16))
                     .collect()
             };
             let tensor = tensor_from_elements(&shape, data, name)?;
@@ -596,16 +677,6 @@ where
     Ok(Constant::new(name, tensor))
 }
 
-/// Convert `x` to i32 with saturation.
-///
-/// RTen internally does not support i64 values so we convert to i32. We use a
-/// saturating cast because there is a convention in ONNX models to use values
-/// like `i64::{MIN, MAX}` to represent slicing to the end of a dimension in
-/// Slice ops. This is handled by converting to `i32::{MIN, MAX}`.
-fn saturating_cast_i64_to_i32(x: i64) -> i32 {
-    x.clamp(i32::MIN as i64, i32::MAX as i64) as i32
-}
-
 /// Load a tensor from a "Constant" operator.
 fn load_constant_from_constant_op(
     op: &onnx::NodeProto,
@@ -639,22 +710,15 @@ fn load_constant_from_constant_op(
                         "invalid \"value\" attribute"
                     ));
                 };
-                load_constant(value, loader, const_name)?
+                load_constant(value, loader, const_name, false)?
             }
             "value_int" => {
                 let value = attr.i.unwrap_or_default();
-                let data = Vec::from([saturating_cast_i64_to_i32(value)]);
-                let tensor = Tensor::from_data(&[], data);
+                let tensor = Tensor::from_scalar(value);
                 Constant::new(const_name, tensor.into_arc())
             }
             "value_ints" => {
-                let i32s: Vec<_> = attr
-                    .ints
-                    .iter()
-                    .copied()
-                    .map(saturating_cast_i64_to_i32)
-                    .collect();
-                let tensor = Tensor::from_data(&[i32s.len()], i32s);
+                let tensor = Tensor::from_data(&[attr.ints.len()], attr.ints.clone());
                 Constant::new(const_name, tensor.into_arc())
             }
             "value_float" => {
@@ -700,10 +764,7 @@ fn load_constant_from_constant_op(
 
 fn constant_from_attr_value(val: ConstInput) -> Constant {
     match val {
-        ConstInput::Ints(vals) => {
-            let vals: Vec<i32> = vals.into_iter().map(saturating_cast_i64_to_i32).collect();
-            Constant::new(None, Tensor::from(vals).into_arc())
-        }
+        ConstInput::Ints(vals) => Constant::new(None, Tensor::from(vals).into_arc()),
         ConstInput::Float(float) => Constant::new(None, Tensor::from(float).into_arc()),
     }
 }
@@ -943,7 +1004,7 @@ fn add_operator(
 
 #[cfg(test)]
 mod tests {
-    use rten_base::half::{f16_to_f32, f32_to_f16};
+    use rten_base::half::{bf16, f16, f16_to_f32, f32_to_f16};
     use rten_onnx::onnx;
     use rten_tensor::{Tensor, TensorView};
 
@@ -1130,6 +1191,40 @@ mod tests {
         assert_eq!(floats_vec, TensorView::from(&[0.1, 0.2, 0.3]));
     }
 
+    #[test]
+    fn test_load_i64_and_bool_initializers() {
+        let i64_raw = |name, values: &[i64]| {
+            create_tensor(
+                name,
+                &[values.len()],
+                onnx::DataType::INT64,
+                TensorData::Raw(values.iter().flat_map(|x| x.to_le_bytes()).collect()),
+            )
+        };
+
+        let model_proto = onnx::GraphProto::default()
+            .with_initializer(i64_raw("small_ints", &[-1, 2, 3]))
+            .with_initializer(i64_raw("large_ints", &[1, 1 << 40]))
+            .with_initializer(create_tensor(
+                "bools",
+                &[3],
+                onnx::DataType::BOOL,
+                TensorData::Raw([1, 0, 2].into()),
+            ))
+            .into_model();
+
+        let model = load_model(model_proto, None).unwrap();
+
+        // i64 values are not narrowed, regardless of whether they fit in i32.
+        let small_ints = model.get_tensor_by_name::<i64>("small_ints").unwrap();
+        assert_eq!(small_ints, TensorView::from(&[-1, 2, 3]));
+        let large_ints = model.get_tensor_by_name::<i64>("large_ints").unwrap();
+        assert_eq!(large_ints, TensorView::from(&[1, 1 << 40]));
+
+        let bools = model.get_tensor_by_name::<bool>("bools").unwrap();
+        assert_eq!(bools, TensorView::from(&[true, false, true]));
+    }
+
     #[test]
     fn test_load_f16_initializer() {
         // "Round" an f32 by converting to f16 and back
@@ -1173,8 +1268,8 @@ mod tests {
         let tensor = create_tensor(
             "init",
             &[],
-            onnx::DataType::BFLOAT16,
-            TensorData::Raw((0u16).to_le_bytes().into()),
+            onnx::DataType::COMPLEX64,
+            TensorData::Raw((0u64).to_le_bytes().into()),
         );
 
         let model_proto = onnx::GraphProto::default()
@@ -1185,8 +1280,98 @@ mod tests {
 
         assert_eq!(
             err.to_string(),
-            "in node \"init\": graph error: initializer has unsupported data type BFLOAT16"
+            "in node \"init\": graph error: initializer has unsupported data type COMPLEX64"
+        );
+    }
+
+    #[test]
+    fn test_load_half_float_weights() {
+        let f16_bytes = |xs: &[f32]| -> Vec<u8> {
+            xs.iter()
+                .flat_map(|x| f16::from_f32(*x).to_bits().to_le_bytes())
+                .collect()
+        };
+        let bf16_bytes = |xs: &[f32]| -> Vec<u8> {
+            xs.iter()
+                .flat_map(|x| bf16::from_f32(*x).to_bits().to_le_bytes())
+                .collect()
+        };
+
+        // f16 weight used only by MatMul. This should stay as f16.
+        let weight = create_tensor(
+            "weight",
+            &[2, 3],
+            onnx::DataType::FLOAT16,
+            TensorData::Raw(f16_bytes(&[1., 2., 3., 4., 5., 6.])),
+        );
+        // bf16 embedding table used only by Gather. This should stay as bf16.
+        let embed = create_tensor(
+            "embed",
+            &[2, 2],
+            onnx::DataType::BFLOAT16,
+            TensorData::Raw(bf16_bytes(&[0.5, 1.5, 2.5, 3.5])),
         );
+        // bf16 tensor used by another operator. This should be converted to f32.
+        let bias = create_tensor(
+            "bias",
+            &[3],
+            onnx::DataType::BFLOAT16,
+            TensorData::Raw(bf16_bytes(&[0.5, 0.25, 0.125])),
+        );
+
+        let mut matmul = create_node("MatMul").with_input("x").with_input("weight");
+        matmul.output.push("matmul_out".into());
+        let mut add = create_node("Add")
+            .with_input("matmul_out")
+            .with_input("bias");
+        add.output.push("y".into());
+        let mut gather = create_node("Gather").with_input("embed").with_input("ids");
+        gather.output.push("embeddings".into());
+
+        let model_proto = onnx::GraphProto::default()
+            .with_input(create_value_info("x"))
+            .with_input(create_value_info("ids"))
+            .with_output(create_value_info("y"))
+            .with_output(create_value_info("embeddings"))
+            .with_initializer(weight)
+            .with_initializer(embed)
+            .with_initializer(bias)
+            .with_node(matmul)
+            .with_node(add)
+            .with_node(gather)
+            .into_model();
+
+        let model = load_model(model_proto, None).unwrap();
+
+        assert!(model.get_tensor_by_name::<f16>("weight").is_some());
+        assert!(model.get_tensor_by_name::<bf16>("embed").is_some());
+        assert_eq!(
+            model.get_tensor_by_name::<f32>("bias").unwrap(),
+            TensorView::from(&[0.5, 0.25, 0.125])
+        );
+
+        let x = Tensor::from([[1., 2.]]);
+        let ids = Tensor::from([1, 0]);
+        let [y, embeddings] = model
+            .run_n(
+                [
+                    (model.node_id("x").unwrap(), x.into()),
+                    (model.node_id("ids").unwrap(), ids.into()),
+                ]
+                .into(),
+                [
+                    model.node_id("y").unwrap(),
+                    model.node_id("embeddings").unwrap(),
+                ],
+                None,
+            )
+            .unwrap();
+
+        let y: Tensor<f32> = y.try_into().unwrap();
+        assert_eq!(y, Tensor::from([[9.5, 12.25, 15.125]]));
+
+        let embeddings: Tensor<f32> = embeddings.try_into().unwrap();
+        assert_eq!(embeddings, Tensor::from([[2.5, 3.5], [0.5, 1.5]]));
     }
 
     #[test]
@@ -1223,14 +1408,14 @@ mod tests {
 
         let model = load_model(model_proto, Some(&loader)).unwrap();
 
-        let tensor = model.get_tensor_by_name::<i32>("i64_tensor").unwrap();
-        assert_eq!(tensor, Tensor::from(1i32));
+        let tensor = model.get_tensor_by_name::<i64>("i64_tensor").unwrap();
+        assert_eq!(tensor, Tensor::from(1i64));
 
         let tensor = model.get_tensor_by_name::<f32>("f32_tensor").unwrap();
         assert_eq!(tensor, Tensor::from(3.14));
 
-        let tensor = model.get_tensor_by_name::<i32>("bool_tensor").unwrap();
-        assert_eq!(tensor, Tensor::from(1i32));
+        let tensor = model.get_tensor_by_name::<bool>("bool_tensor").unwrap();
+        assert_eq!(tensor, Tensor::from(true));
     }
 
     #[test]
@@ -1,23 +1,33 @@
-use flatbuffers::{FlatBufferBuilder, UnionWIPOffset, Vector, WIPOffset};
+use flatbuffers::{FlatBufferBuilder, ForwardsUOffset, UnionWIPOffset, Vector, WIPOffset};
+use rten_base::half::{bf16, f16};
 use rten_base::num::LeBytes;
 use rten_model_file::header::Header;
 use rten_model_file::schema as sg;
+use rten_shape_inference::SymExpr;
 use rten_tensor::TensorView;
 use rten_tensor::prelude::*;
 
 use crate::graph::{Dimension, NodeId};
 use crate::ops::{
-    ArgMax, ArgMin, AveragePool, BatchNormalization, BoxOrder, Cast, CastLike, Concat,
-    ConstantOfShape, Conv, ConvInteger, ConvTranspose, CoordTransformMode, DepthToSpace,
-    DepthToSpaceMode, DequantizeLinear, Einsum, Elu, EyeLike, Flatten, Gather, GatherElements,
-    GatherND, Gelu, Gemm, HardSigmoid, InstanceNormalization, LayerNormalization, LeakyRelu,
-    LogSoftmax, MaxPool, Mod, NearestMode, NonMaxSuppression, OneHot, Padding, QuantizeLinear,
-    ReduceMax, ReduceMean, ReduceMin, ReduceProd, ReduceSum, ReduceSumSquare, Reshape, Resize,
-    ResizeMode, ScatterElements, ScatterReduction, SequenceEmpty, Shape, Softmax, Split, TopK,
-    Transpose, Trilu,
+    AccuracyLevel, AddSoftmax, ArgMax, ArgMin, Attention, AveragePool, BatchNormalization,
+    BitShift, BitShiftDirection, BoxOrder, Cast, CastLike, Celu, Col2Im, Compress, ComputeShape,
+    Concat, ConcatFromSequence, ConstantOfShape, Conv, ConvInteger, ConvTranspose,
+    CoordTransformMode, DepthToSpace, DepthToSpaceMode, DequantizeLinear, Direction, Einsum, Elu,
+    EyeLike, Flatten, FusedMatMul, GRU, Gather, GatherElements, GatherND, Gelu, Gemm, GridSample,
+    GroupNormalization, GroupQueryAttention, GroupedQueryAttentionMatMul, HardSigmoid, Hardmax,
+    InstanceNormalization, LSTM, LayerNormalization, LeakyRelu, LogSoftmax, LpNormalization,
+    LpPool, MatMulNBits, MaxPool, MaxUnpool, MeanVarianceNormalization, Mod, NearestMode,
+    NonMaxSuppression, OneHot, Pad, PadMode, Padding, QuantizeLinear, ReduceL2, ReduceLogSumExp,
+    ReduceMax, ReduceMean, ReduceMin, ReduceProd, ReduceSum, ReduceSumSquare, RepeatInterleave,
+    Reshape, Resize, ResizeMode, ReverseSequence, RmsNormalization, RoiAlign, RoiAlignMode,
+    RotaryEmbedding, ScatterElements, ScatterND, ScatterReduction, Selu, SequenceEmpty, Shape,
+    Shrink, SkipSimplifiedLayerNormalization, Softmax, SpaceToDepth, Split, SplitToSequence, Swish,
+    ThresholdedRelu, TopK, Transpose, Trilu, Unique,
 };
 use crate::value::{DataType, Scalar};
 
+#[cfg(feature = "fft")]
+use crate::ops::{BlackmanWindow, DFT, HammingWindow, HannWindow, STFT};
 #[cfg(feature = "random")]
 use crate::ops::{Dropout, RandomNormal, RandomNormalLike, RandomUniform, RandomUniformLike};
 
@@ -28,36 +38,69 @@ pub struct IfArgs<'a> {
     pub else_branch: WIPOffset<sg::Graph<'a>>,
 }
 
+/// Struct like `crate::ops::Loop` with the body replaced by a pre-serialized
+/// graph.
+pub struct LoopArgs<'a> {
+    pub body: WIPOffset<sg::Graph<'a>>,
+}
+
+/// Struct like `crate::ops::transform_inputs::TransformInputs` with the
+/// wrapped operator replaced by a pre-serialized operator.
+pub struct TransformInputsArgs<'a> {
+    /// Wrapped operator, created using [`GraphBuilder::create_nested_operator`].
+    pub op: WIPOffset<sg::OperatorNode<'a>>,
+
+    /// `(input_index, perm)` permutations applied to inputs.
+    pub transforms: Vec<(usize, Option<Vec<usize>>)>,
+}
+
 /// Enum of all the built-in operators
+#[allow(clippy::upper_case_acronyms)]
 pub enum OpType<'a> {
     Abs,
     Acos,
     Add,
+    AddSoftmax(AddSoftmax),
     And,
     ArgMax(ArgMax),
     ArgMin(ArgMin),
     Asin,
     Atan,
+    Attention(Attention),
     AveragePool(AveragePool),
     BatchNormalization(BatchNormalization),
+    BitShift(BitShift),
+    BitwiseAnd,
+    BitwiseNot,
+    BitwiseOr,
+    BitwiseXor,
+    #[cfg(feature = "fft")]
+    BlackmanWindow(BlackmanWindow),
     Cast(Cast),
     CastLike(CastLike),
     Ceil,
+    Celu(Celu),
     Clip,
+    Col2Im(Col2Im),
+    Compress(Compress),
+    ComputeShape(ComputeShape),
     Concat(Concat),
+    ConcatFromSequence(ConcatFromSequence),
     ConstantOfShape(ConstantOfShape),
     Conv(Conv),
     ConvInteger(ConvInteger),
     ConvTranspose(ConvTranspose),
     Cos,
+    CumSum,
     DequantizeLinear(DequantizeLinear),
     DepthToSpace(DepthToSpace),
+    Det,
+    #[cfg(feature = "fft")]
+    DFT(DFT),
     Div,
     #[cfg(feature = "random")]
     Dropout(Dropout),
-    #[allow(dead_code)]
     DynamicQuantizeLinear,
-    #[allow(dead_code)]
     Einsum(Einsum),
     Elu(Elu),
     Equal,
@@ -67,9 +110,9 @@ pub enum OpType<'a> {
     EyeLike(EyeLike),
     Flatten(Flatten),
     Floor,
+    FusedMatMul(FusedMatMul),
     Gather(Gather),
     GatherElements(GatherElements),
-    #[allow(dead_code)]
     GatherND(GatherND),
     Gelu(Gelu),
     Gemm(Gemm),
@@ -77,6 +120,16 @@ pub enum OpType<'a> {
     GlobalMaxPool,
     Greater,
     GreaterOrEqual,
+    GridSample(GridSample),
+    GroupNormalization(GroupNormalization),
+    GroupQueryAttention(GroupQueryAttention),
+    GroupedQueryAttentionMatMul(GroupedQueryAttentionMatMul),
+    GRU(GRU),
+    Hardmax(Hardmax),
+    #[cfg(feature = "fft")]
+    HammingWindow(HammingWindow),
+    #[cfg(feature = "fft")]
+    HannWindow(HannWindow),
     HardSigmoid(HardSigmoid),
     HardSwish,
     Identity,
@@ -90,12 +143,23 @@ pub enum OpType<'a> {
     LessOrEqual,
     Log,
     LogSoftmax(LogSoftmax),
+    Loop(LoopArgs<'a>),
+    LSTM(LSTM),
+    LpNormalization(LpNormalization),
+    LpPool(LpPool),
     MatMul,
     MatMulInteger,
+    MatMulIntegerToFloat,
+    MatMulNBits(MatMulNBits),
     Max,
     MaxPool(MaxPool),
+    MaxUnpool(MaxUnpool),
     Mean,
+    MeanVarianceNormalization(MeanVarianceNormalization),
+    #[cfg(feature = "fft")]
+    MelWeightMatrix,
     Min,
+    Mish,
     Mod(Mod),
     Mul,
     Neg,
@@ -104,8 +168,9 @@ pub enum OpType<'a> {
     Not,
     OneHot(OneHot),
     Or,
-    Pad,
+    Pad(Pad),
     Pow,
+    PRelu,
 
     #[cfg(feature = "random")]
     RandomNormal(RandomNormal),
@@ -118,6 +183,8 @@ pub enum OpType<'a> {
 
     Range,
     Reciprocal,
+    ReduceL2(ReduceL2),
+    ReduceLogSumExp(ReduceLogSumExp),
     ReduceMax(ReduceMax),
     ReduceMean(ReduceMean),
     ReduceMin(ReduceMin),
@@ -125,36 +192,54 @@ pub enum OpType<'a> {
     ReduceSum(ReduceSum),
     ReduceSumSquare(ReduceSumSquare),
     Relu,
+    RepeatInterleave(RepeatInterleave),
     Reshape(Reshape),
     Resize(Resize),
+    ReverseSequence(ReverseSequence),
+    RmsNormalization(RmsNormalization),
+    RoiAlign(RoiAlign),
+    RotaryEmbedding(RotaryEmbedding),
     Round,
     QuantizeLinear(QuantizeLinear),
     ScatterElements(ScatterElements),
-    #[allow(dead_code)]
+    ScatterND(ScatterND),
+    Selu(Selu),
     SequenceAt,
-    #[allow(dead_code)]
+    SequenceConstruct,
     SequenceEmpty(SequenceEmpty),
-    #[allow(dead_code)]
+    SequenceErase,
     SequenceInsert,
+    SequenceLength,
     Shape(Shape),
+    Shrink(Shrink),
     Sigmoid,
     Sign,
+    Silu,
     Sin,
     Size,
+    SkipSimplifiedLayerNormalization(SkipSimplifiedLayerNormalization),
     Slice,
     Softmax(Softmax),
     Softplus,
+    SpaceToDepth(SpaceToDepth),
     Split(Split),
+    SplitToSequence(SplitToSequence),
     Sqrt,
     Squeeze,
+    #[cfg(feature = "fft")]
+    STFT(STFT),
     Sub,
     Sum,
+    Swish(Swish),
     Tan,
     Tanh,
+    ThresholdedRelu(ThresholdedRelu),
     Tile,
     TopK(TopK),
+    TransformInputs(TransformInputsArgs<'a>),
     Transpose(Transpose),
     Trilu(Trilu),
+    Unique(Unique),
     Unsqueeze,
     Where,
     Xor,
@@ -164,6 +249,7 @@ pub enum OpType<'a> {
 pub enum ModelFormat {
     /// Generate the V1 format. This consists of just FlatBuffers data
     /// containing both the model structure and tensor data.
+    #[cfg_attr(not(test), allow(dead_code))]
     V1,
 
     /// Generate the V2 format, which consists of a header, the FlatBuffers
@@ -214,6 +300,42 @@ impl_to_constant_data!(i32, Int32, Int32Data, Int32DataArgs);
 impl_to_constant_data!(u8, UInt8, UInt8Data, UInt8DataArgs);
 impl_to_constant_data!(i8, Int8, Int8Data, Int8DataArgs);
 
+/// Implement [`ToConstantData`] for a type which has no inline data table.
+///
+/// Inline data for these types is stored as little-endian bytes in a
+/// `UInt8Data` table and the loader uses the constant's `dtype` to interpret
+/// it.
+macro_rules! impl_to_constant_data_bytes {
+    ($type:ty, $dtype:ident) => {
+        impl ToConstantData for $type {
+            fn dtype() -> sg::ConstantDataType {
+                sg::ConstantDataType::$dtype
+            }
+
+            fn create_inline_data(
+                builder: &mut FlatBufferBuilder<'_>,
+                data: &[Self],
+            ) -> (sg::ConstantData, WIPOffset<UnionWIPOffset>) {
+                let bytes: Vec<u8> = data.iter().flat_map(|x| x.to_le_bytes()).collect();
+                let data_vec = builder.create_vector(&bytes);
+                let data = sg::UInt8Data::create(
+                    builder,
+                    &sg::UInt8DataArgs {
+                        data: Some(data_vec),
+                    },
+                )
+                .as_union_value();
+                (sg::ConstantData::UInt8Data, data)
+            }
+        }
+    };
+}
+
+impl_to_constant_data_bytes!(f16, Float16);
+impl_to_constant_data_bytes!(bf16, BFloat16);
+impl_to_constant_data_bytes!(i64, Int64);
+impl_to_constant_data_bytes!(bool, Bool);
+
 enum NodeData<'a> {
     Constant(WIPOffset<sg::ConstantNode<'a>>),
     Value(WIPOffset<sg::ValueNode<'a>>),
@@ -221,8 +343,16 @@ enum NodeData<'a> {
 }
 
 /// Arguments for [`ModelBuilder::add_metadata`].
+#[derive(Default)]
 pub struct MetadataArgs {
     pub onnx_hash: Option<String>,
+    pub description: Option<String>,
+    pub license: Option<String>,
+    pub commit: Option<String>,
+    pub code_repository: Option<String>,
+    pub model_repository: Option<String>,
+    pub run_id: Option<String>,
+    pub run_url: Option<String>,
 }
 
 struct PadArgs {
@@ -249,6 +379,41 @@ fn convert_dtype(dtype: DataType) -> sg::DataType {
         DataType::Float => sg::DataType::Float,
         DataType::Int8 => sg::DataType::Int8,
         DataType::UInt8 => sg::DataType::UInt8,
+        DataType::Float16 => sg::DataType::Float16,
+        DataType::BFloat16 => sg::DataType::BFloat16,
+        DataType::Int64 => sg::DataType::Int64,
+        DataType::Bool => sg::DataType::Bool,
+    }
+}
+
+fn convert_constant_dtype(dtype: DataType) -> sg::ConstantDataType {
+    match dtype {
+        DataType::Int32 => sg::ConstantDataType::Int32,
+        DataType::Float => sg::ConstantDataType::Float32,
+        DataType::Int8 => sg::ConstantDataType::Int8,
+        DataType::UInt8 => sg::ConstantDataType::UInt8,
+        DataType::Float16 => sg::ConstantDataType::Float16,
+        DataType::BFloat16 => sg::ConstantDataType::BFloat16,
+        DataType::Int64 => sg::ConstantDataType::Int64,
+        DataType::Bool => sg::ConstantDataType::Bool,
+    }
+}
+
+fn convert_direction(direction: Direction) -> sg::RNNDirection {
+    match direction {
+        Direction::Forward => sg::RNNDirection::Forward,
+        Direction::Reverse => sg::RNNDirection::Reverse,
+        Direction::Bidirectional => sg::RNNDirection::Bidirectional,
+    }
+}
+
+fn convert_reduction(reduction: Option<ScatterReduction>) -> sg::ScatterReduction {
+    match reduction {
+        None => sg::ScatterReduction::None,
+        Some(ScatterReduction::Add) => sg::ScatterReduction::Add,
+        Some(ScatterReduction::Mul) => sg::ScatterReduction::Mul,
+        Some(ScatterReduction::Min) => sg::ScatterReduction::Min,
+        Some(ScatterReduction::Max) => sg::ScatterReduction::Max,
     }
 }
 
@@ -260,6 +425,8 @@ pub struct GraphBuilder<'mb, 'a> {
     nodes: Vec<WIPOffset<sg::Node<'a>>>,
     input_ids: Vec<NodeId>,
     output_ids: Vec<NodeId>,
+    captures: Vec<NodeId>,
+    prepacked_weights: Vec<WIPOffset<sg::PrepackedWeight<'a>>>,
 }
 
 impl<'mb, 'a> GraphBuilder<'mb, 'a> {
@@ -274,6 +441,8 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
             nodes: Vec::new(),
             input_ids: Vec::new(),
             output_ids: Vec::new(),
+            captures: Vec::new(),
+            prepacked_weights: Vec::new(),
         }
     }
 
@@ -305,10 +474,20 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
         )
     }
 
-    /// Add a constant node (eg. weights, biases) to the model
+    /// Add an unnamed constant node (eg. weights, biases) to the model
+    #[cfg(test)]
     pub fn add_constant<T: Copy + LeBytes + ToConstantData>(
         &mut self,
         input: TensorView<T>,
+    ) -> NodeId {
+        self.add_constant_node(None, input)
+    }
+
+    /// Add a constant node with an optional name to the model
+    pub fn add_constant_node<T: Copy + LeBytes + ToConstantData>(
+        &mut self,
+        name: Option<&str>,
+        input: TensorView<T>,
     ) -> NodeId {
         let shape: Vec<u32> = input.shape().iter().map(|&x| x as u32).collect();
         let shape_vec = self.builder.create_vector(&shape[..]);
@@ -339,15 +518,26 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
         };
 
         let const_node = sg::ConstantNode::create(self.builder, &args);
-        self.add_node(None, NodeData::Constant(const_node))
+        self.add_node(name, NodeData::Constant(const_node))
     }
 
     /// Add a value node to the model
+    #[cfg(test)]
     pub fn add_value(
         &mut self,
         id: &str,
         shape: Option<&[Dimension]>,
         dtype: Option<DataType>,
+    ) -> NodeId {
+        self.add_value_node(Some(id), shape, dtype)
+    }
+
+    /// Add a value node with an optional name to the model
+    pub fn add_value_node(
+        &mut self,
+        name: Option<&str>,
+        shape: Option<&[Dimension]>,
+        dtype: Option<DataType>,
     ) -> NodeId {
         let shape = shape.map(|shape| {
             let dim_vec: Vec<_> = shape
@@ -376,10 +566,11 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
         });
         let dtype = dtype.map(convert_dtype);
         let value_node = sg::ValueNode::create(self.builder, &sg::ValueNodeArgs { shape, dtype });
-        self.add_node(Some(id), NodeData::Value(value_node))
+        self.add_node(name, NodeData::Value(value_node))
     }
 
     /// Add an operator node to the model
+    #[cfg(test)]
     pub fn add_operator(
         &mut self,
         id: &str,
@@ -387,6 +578,37 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
         inputs: &[Option<NodeId>],
         outputs: &[NodeId],
     ) -> NodeId {
+        let outputs: Vec<_> = outputs.iter().copied().map(Some).collect();
+        self.add_operator_node(Some(id), op_info, inputs, &outputs)
+    }
+
+    /// Add an operator node with an optional name and optional outputs to
+    /// the model.
+    pub fn add_operator_node(
+        &mut self,
+        name: Option<&str>,
+        op_info: OpType,
+        inputs: &[Option<NodeId>],
+        outputs: &[Option<NodeId>],
+    ) -> NodeId {
+        let op_node = self.create_operator(op_info, inputs, outputs);
+        self.add_node(name, NodeData::Operator(op_node))
+    }
+
+    /// Serialize an operator which is referenced by the attributes of another
+    /// operator, rather than being a node in the graph.
+    ///
+    /// This is used for the operator wrapped by `TransformInputs`.
+    pub fn create_nested_operator(&mut self, op_info: OpType) -> WIPOffset<sg::OperatorNode<'a>> {
+        self.create_operator(op_info, &[], &[])
+    }
+
+    fn create_operator(
+        &mut self,
+        op_info: OpType,
+        inputs: &[Option<NodeId>],
+        outputs: &[Option<NodeId>],
+    ) -> WIPOffset<sg::OperatorNode<'a>> {
         // Generate an (op_type, attr_type, attrs) tuple for an operator with
         // no attributes.
         macro_rules! op {
@@ -426,6 +648,14 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
             OpType::Abs => op!(Abs),
             OpType::Acos => op!(Acos),
             OpType::Add => op!(Add),
+            OpType::AddSoftmax(args) => op_with_attrs!(
+                AddSoftmax,
+                SoftmaxAttrs,
+                sg::SoftmaxAttrsArgs {
+                    axis: -1,
+                    flush_nans_to_zero: args.flush_nans_to_zero,
+                }
+            ),
             OpType::And => op!(And),
             OpType::ArgMax(args) => op_with_attrs!(ArgMax, ArgMaxAttrs, {
                 sg::ArgMaxAttrsArgs {
@@ -441,6 +671,18 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
             }),
             OpType::Asin => op!(Asin),
             OpType::Atan => op!(Atan),
+            OpType::Attention(args) => op_with_attrs!(
+                Attention,
+                AttentionAttrs,
+                sg::AttentionAttrsArgs {
+                    is_causal: args.is_causal,
+                    q_num_heads: args.q_num_heads.map(|n| n as u32),
+                    kv_num_heads: args.kv_num_heads.map(|n| n as u32),
+                    scale: args.scale,
+                    softcap: args.softcap,
+                    flush_nans_to_zero: args.flush_nans_to_zero,
+                }
+            ),
             OpType::AveragePool(args) => op_with_attrs!(AveragePool, AveragePoolAttrs, {
                 let pad_args = pad_args_from_padding(args.padding);
                 let pads = self.create_vec(pad_args.pads, |pad| pad as u32);
@@ -462,6 +704,28 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
                     epsilon: args.epsilon
                 }
             ),
+            OpType::BitShift(args) => op_with_attrs!(
+                BitShift,
+                BitShiftAttrs,
+                sg::BitShiftAttrsArgs {
+                    direction: match args.direction {
+                        BitShiftDirection::Left => sg::BitShiftDirection::Left,
+                        BitShiftDirection::Right => sg::BitShiftDirection::Right,
+                    },
+                }
+            ),
+            OpType::BitwiseAnd => op!(BitwiseAnd),
+            OpType::BitwiseNot => op!(BitwiseNot),
+            OpType::BitwiseOr => op!(BitwiseOr),
+            OpType::BitwiseXor => op!(BitwiseXor),
+            #[cfg(feature = "fft")]
+            OpType::BlackmanWindow(args) => op_with_attrs!(
+                BlackmanWindow,
+                WindowAttrs,
+                sg::WindowAttrsArgs {
+                    periodic: args.periodic
+                }
+            ),
             OpType::Cast(args) => op_with_attrs!(
                 Cast,
                 CastAttrs,
@@ -473,7 +737,50 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
                 op_with_attrs!(CastLike, CastLikeAttrs, sg::CastLikeAttrsArgs {})
             }
             OpType::Ceil => op!(Ceil),
+            OpType::Celu(args) => {
+                op_with_attrs!(Celu, CeluAttrs, sg::CeluAttrsArgs { alpha: args.alpha })
+            }
             OpType::Clip => op!(Clip),
+            OpType::Col2Im(args) => op_with_attrs!(Col2Im, Col2ImAttrs, {
+                let dilations = self.create_vec(Some(args.dilations), |d| d as u32);
+                let pads = self.create_vec(Some(args.pads), |pad| pad as u32);
+                let strides = self.create_vec(Some(args.strides), |s| s as u32);
+                sg::Col2ImAttrsArgs {
+                    dilations,
+                    pads,
+                    strides,
+                }
+            }),
+            OpType::Compress(args) => op_with_attrs!(
+                Compress,
+                CompressAttrs,
+                sg::CompressAttrsArgs {
+                    axis: args.axis.map(|axis| axis as i32),
+                }
+            ),
+            OpType::ComputeShape(args) => op_with_attrs!(ComputeShape, ComputeShapeAttrs, {
+                let symbols: Vec<_> = args
+                    .symbols
+                    .iter()
+                    .map(|sym| {
+                        let name = self.builder.create_string(&sym.name);
+                        sg::SymbolInfo::create(
+                            self.builder,
+                            &sg::SymbolInfoArgs {
+                                name: Some(name),
+                                input: sym.input,
+                                axis: sym.axis,
+                            },
+                        )
+                    })
+                    .collect();
+                let symbols = self.builder.create_vector(&symbols);
+                let shape = self.create_sym_exprs(&args.shape);
+                sg::ComputeShapeAttrsArgs {
+                    symbols: Some(symbols),
+                    shape: Some(shape),
+                }
+            }),
             OpType::Concat(args) => op_with_attrs!(
                 Concat,
                 ConcatAttrs,
@@ -481,6 +788,14 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
                     axis: args.axis as i32,
                 }
             ),
+            OpType::ConcatFromSequence(args) => op_with_attrs!(
+                ConcatFromSequence,
+                ConcatFromSequenceAttrs,
+                sg::ConcatFromSequenceAttrsArgs {
+                    axis: args.axis,
+                    new_axis: args.new_axis,
+                }
+            ),
             OpType::ConstantOfShape(args) => {
                 op_with_attrs!(ConstantOfShape, ConstantOfShapeAttrs, {
                     match args.value {
@@ -549,6 +864,7 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
                 }
             }),
             OpType::Cos => op!(Cos),
+            OpType::CumSum => op!(CumSum),
             OpType::DequantizeLinear(args) => op_with_attrs!(
                 DequantizeLinear,
                 DequantizeLinearAttrs,
@@ -567,6 +883,17 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
                     }
                 }
             ),
+            OpType::Det => op!(Det),
+            #[cfg(feature = "fft")]
+            OpType::DFT(args) => op_with_attrs!(
+                DFT,
+                DFTAttrs,
+                sg::DFTAttrsArgs {
+                    axis: args.axis.map(|axis| axis as i32),
+                    inverse: args.inverse,
+                    onesided: args.onesided,
+                }
+            ),
             OpType::Div => op!(Div),
             #[cfg(feature = "random")]
             OpType::Dropout(args) => op_with_attrs!(
@@ -610,6 +937,11 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
                 }
             ),
             OpType::Floor => op!(Floor),
+            OpType::FusedMatMul(args) => op_with_attrs!(
+                FusedMatMul,
+                FusedMatMulAttrs,
+                sg::FusedMatMulAttrsArgs { alpha: args.alpha }
+            ),
             OpType::Gather(args) => op_with_attrs!(
                 Gather,
                 GatherAttrs,
@@ -656,6 +988,75 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
             OpType::GlobalMaxPool => op!(GlobalMaxPool),
             OpType::Greater => op!(Greater),
             OpType::GreaterOrEqual => op!(GreaterOrEqual),
+            OpType::GridSample(args) => op_with_attrs!(
+                GridSample,
+                GridSampleAttrs,
+                sg::GridSampleAttrsArgs {
+                    align_corners: args.align_corners,
+                }
+            ),
+            OpType::GroupNormalization(args) => op_with_attrs!(
+                GroupNormalization,
+                GroupNormalizationAttrs,
+                sg::GroupNormalizationAttrsArgs {
+                    num_groups: args.num_groups as u32,
+                    epsilon: args.epsilon,
+                }
+            ),
+            OpType::GroupQueryAttention(args) => op_with_attrs!(
+                GroupQueryAttention,
+                GroupQueryAttentionAttrs,
+                sg::GroupQueryAttentionAttrsArgs {
+                    num_heads: args.num_heads as u32,
+                    kv_num_heads: args.kv_num_heads as u32,
+                    scale: args.scale,
+                    softcap: args.softcap,
+                    local_window_size: args.local_window_size.map(|size| size as u32),
+                    do_rotary: args.do_rotary,
+                    rotary_interleaved: args.rotary_interleaved,
+                }
+            ),
+            OpType::GroupedQueryAttentionMatMul(args) => op_with_attrs!(
+                GroupedQueryAttentionMatMul,
+                GroupedQueryAttentionMatMulAttrs,
+                sg::GroupedQueryAttentionMatMulAttrsArgs {
+                    repeats: args.repeats as u32,
+                    alpha: args.alpha,
+                    transpose_rhs: args.transpose_rhs,
+                }
+            ),
+            OpType::GRU(args) => op_with_attrs!(
+                GRU,
+                GRUAttrs,
+                sg::GRUAttrsArgs {
+                    direction: convert_direction(args.direction),
+                    hidden_size: args.hidden_size as u32,
+                    linear_before_reset: args.linear_before_reset,
+                }
+            ),
+            OpType::Hardmax(args) => op_with_attrs!(
+                Hardmax,
+                HardmaxAttrs,
+                sg::HardmaxAttrsArgs {
+                    axis: args.axis as i32,
+                }
+            ),
+            #[cfg(feature = "fft")]
+            OpType::HammingWindow(args) => op_with_attrs!(
+                HammingWindow,
+                WindowAttrs,
+                sg::WindowAttrsArgs {
+                    periodic: args.periodic
+                }
+            ),
+            #[cfg(feature = "fft")]
+            OpType::HannWindow(args) => op_with_attrs!(
+                HannWindow,
+                WindowAttrs,
+                sg::WindowAttrsArgs {
+                    periodic: args.periodic
+                }
+            ),
             OpType::HardSigmoid(args) => op_with_attrs!(
                 HardSigmoid,
                 HardSigmoidAttrs,
@@ -704,10 +1105,62 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
                 SoftmaxAttrs,
                 sg::SoftmaxAttrsArgs {
                     axis: args.axis as i32,
+                    flush_nans_to_zero: false,
                 }
             ),
+            OpType::Loop(args) => op_with_attrs!(
+                Loop,
+                LoopAttrs,
+                sg::LoopAttrsArgs {
+                    body: Some(args.body),
+                }
+            ),
+            OpType::LSTM(args) => op_with_attrs!(
+                LSTM,
+                LSTMAttrs,
+                sg::LSTMAttrsArgs {
+                    direction: convert_direction(args.direction),
+                    hidden_size: args.hidden_size as u32,
+                }
+            ),
+            OpType::LpNormalization(args) => op_with_attrs!(
+                LpNormalization,
+                LpNormalizationAttrs,
+                sg::LpNormalizationAttrsArgs {
+                    axis: args.axis as i32,
+                    p: args.p,
+                }
+            ),
+            OpType::LpPool(args) => op_with_attrs!(LpPool, LpPoolAttrs, {
+                let pad_args = pad_args_from_padding(args.padding);
+                let pads = self.create_vec(pad_args.pads, |pad| pad as u32);
+                let kernel_size = self.create_vec(Some(args.kernel_size.to_vec()), |sz| sz as u32);
+                let strides = self.create_vec(Some(args.strides.to_vec()), |s| s as u32);
+                sg::LpPoolAttrsArgs {
+                    kernel_size,
+                    auto_pad: pad_args.auto_pad,
+                    pads,
+                    strides,
+                    ceil_mode: args.ceil_mode,
+                    p: args.p,
+                }
+            }),
             OpType::MatMul => op!(MatMul),
             OpType::MatMulInteger => op!(MatMulInteger),
+            OpType::MatMulIntegerToFloat => op!(MatMulIntegerToFloat),
+            OpType::MatMulNBits(args) => op_with_attrs!(
+                MatMulNBits,
+                MatMulNBitsAttrs,
+                sg::MatMulNBitsAttrsArgs {
+                    bits: args.bits,
+                    block_size: args.block_size as u32,
+                    // Values of ONNX Runtime's `accuracy_level` attribute.
+                    accuracy_level: match args.accuracy_level {
+                        AccuracyLevel::Float => 0,
+                        AccuracyLevel::Int8 => 4,
+                    },
+                }
+            ),
             OpType::Max => op!(Max),
             OpType::MaxPool(args) => op_with_attrs!(MaxPool, MaxPoolAttrs, {
                 let pad_args = pad_args_from_padding(args.padding);
@@ -722,8 +1175,30 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
                     ceil_mode: args.ceil_mode,
                 }
             }),
+            OpType::MaxUnpool(args) => op_with_attrs!(MaxUnpool, MaxPoolAttrs, {
+                let pad_args = pad_args_from_padding(args.padding);
+                let pads = self.create_vec(pad_args.pads, |pad| pad as u32);
+                let kernel_size = self.create_vec(Some(args.kernel_size.to_vec()), |sz| sz as u32);
+                let strides = self.create_vec(Some(args.strides.to_vec()), |s| s as u32);
+                sg::MaxPoolAttrsArgs {
+                    kernel_size,
+                    auto_pad: pad_args.auto_pad,
+                    pads,
+                    strides,
+                    ceil_mode: false,
+                }
+            }),
             OpType::Mean => op!(Mean),
+            OpType::MeanVarianceNormalization(args) => {
+                op_with_attrs!(MeanVarianceNormalization, MeanVarianceNormalizationAttrs, {
+                    let axes = self.create_vec(Some(args.axes), |axis| axis);
+                    sg::MeanVarianceNormalizationAttrsArgs { axes }
+                })
+            }
+            #[cfg(feature = "fft")]
+            OpType::MelWeightMatrix => op!(MelWeightMatrix),
             OpType::Min => op!(Min),
+            OpType::Mish => op!(Mish),
             OpType::Mod(args) => {
                 op_with_attrs!(Mod, ModAttrs, sg::ModAttrsArgs { fmod: args.fmod })
             }
@@ -753,15 +1228,27 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
                     }
                 )
             }
-            OpType::Pad => op!(Pad),
+            OpType::Pad(args) => op_with_attrs!(
+                Pad,
+                PadAttrs,
+                sg::PadAttrsArgs {
+                    mode: match args.mode {
+                        PadMode::Constant => sg::PadMode::Constant,
+                        PadMode::Reflect => sg::PadMode::Reflect,
+                        PadMode::Edge => sg::PadMode::Edge,
+                        PadMode::Wrap => sg::PadMode::Wrap,
+                    }
+                }
+            ),
             OpType::Pow => op!(Pow),
+            OpType::PRelu => op!(PRelu),
 
             OpType::QuantizeLinear(args) => op_with_attrs!(
                 QuantizeLinear,
                 QuantizeLinearAttrs,
                 sg::QuantizeLinearAttrsArgs {
                     axis: args.axis as i32,
-                    output_dtype: None, // Not yet implemented
+                    output_dtype: args.output_dtype.map(convert_dtype),
                 }
             ),
 
@@ -815,6 +1302,12 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
 
             OpType::Range => op!(Range),
             OpType::Reciprocal => op!(Reciprocal),
+            OpType::ReduceL2(args) => {
+                op_with_attrs!(ReduceL2, ReduceMeanAttrs, reduce_attrs!(args))
+            }
+            OpType::ReduceLogSumExp(args) => {
+                op_with_attrs!(ReduceLogSumExp, ReduceMeanAttrs, reduce_attrs!(args))
+            }
             OpType::ReduceMax(args) => {
                 op_with_attrs!(ReduceMax, ReduceMeanAttrs, reduce_attrs!(args))
             }
@@ -834,6 +1327,14 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
                 op_with_attrs!(ReduceSumSquare, ReduceMeanAttrs, reduce_attrs!(args))
             }
             OpType::Relu => op!(Relu),
+            OpType::RepeatInterleave(args) => op_with_attrs!(
+                RepeatInterleave,
+                RepeatInterleaveAttrs,
+                sg::RepeatInterleaveAttrsArgs {
+                    axis: args.axis as u32,
+                    repeats: args.repeats as u32,
+                }
+            ),
             OpType::Reshape(args) => op_with_attrs!(Reshape, ReshapeAttrs, {
                 sg::ReshapeAttrsArgs {
                     allow_zero: args.allow_zero,
@@ -864,8 +1365,49 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
                     nearest_mode,
                 }
             }),
+            OpType::ReverseSequence(args) => op_with_attrs!(
+                ReverseSequence,
+                ReverseSequenceAttrs,
+                sg::ReverseSequenceAttrsArgs {
+                    batch_axis: args.batch_axis as u32,
+                    time_axis: args.time_axis as u32,
+                }
+            ),
+            OpType::RmsNormalization(args) => op_with_attrs!(
+                RmsNormalization,
+                LayerNormalizationAttrs,
+                sg::LayerNormalizationAttrsArgs {
+                    axis: args.axis as i32,
+                    epsilon: args.epsilon.unwrap_or(1e-5)
+                }
+            ),
+            OpType::RoiAlign(args) => op_with_attrs!(
+                RoiAlign,
+                RoiAlignAttrs,
+                sg::RoiAlignAttrsArgs {
+                    mode: match args.opts.mode {
+                        RoiAlignMode::Avg => sg::RoiAlignMode::Avg,
+                        RoiAlignMode::Max => sg::RoiAlignMode::Max,
+                    },
+                    output_height: args.opts.output_height as u32,
+                    output_width: args.opts.output_width as u32,
+                    sampling_ratio: args.opts.sampling_ratio as u32,
+                    spatial_scale: args.opts.spatial_scale,
+                    half_pixel: args.opts.half_pixel,
+                }
+            ),
+            OpType::RotaryEmbedding(args) => op_with_attrs!(
+                RotaryEmbedding,
+                RotaryEmbeddingAttrs,
+                sg::RotaryEmbeddingAttrsArgs {
+                    interleaved: args.interleaved,
+                    num_heads: args.num_heads.map(|n| n as u32),
+                    rotary_embedding_dim: args.rotary_embedding_dim.map(|dim| dim as u32),
+                }
+            ),
             OpType::Round => op!(Round),
             OpType::SequenceAt => op!(SequenceAt),
+            OpType::SequenceConstruct => op!(SequenceConstruct),
             OpType::SequenceEmpty(args) => op_with_attrs!(
                 SequenceEmpty,
                 SequenceEmptyAttrs,
@@ -873,53 +1415,112 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
                     dtype: args.dtype.map(convert_dtype),
                 }
             ),
+            OpType::SequenceErase => op!(SequenceErase),
             OpType::SequenceInsert => op!(SequenceInsert),
+            OpType::SequenceLength => op!(SequenceLength),
             OpType::ScatterElements(args) => {
                 op_with_attrs!(ScatterElements, ScatterElementsAttrs, {
-                    let reduction = match args.reduction {
-                        None => sg::ScatterReduction::None,
-                        Some(ScatterReduction::Add) => sg::ScatterReduction::Add,
-                        Some(ScatterReduction::Mul) => sg::ScatterReduction::Mul,
-                        Some(ScatterReduction::Min) => sg::ScatterReduction::Min,
-                        Some(ScatterReduction::Max) => sg::ScatterReduction::Max,
-                    };
                     sg::ScatterElementsAttrsArgs {
                         axis: args.axis as i32,
-                        reduction,
+                        reduction: convert_reduction(args.reduction),
                     }
                 })
             }
+            OpType::ScatterND(args) => op_with_attrs!(
+                ScatterND,
+                ScatterNDAttrs,
+                sg::ScatterNDAttrsArgs {
+                    reduction: convert_reduction(args.reduction),
+                }
+            ),
+            OpType::Selu(args) => op_with_attrs!(
+                Selu,
+                SeluAttrs,
+                sg::SeluAttrsArgs {
+                    alpha: args.alpha,
+                    gamma: args.gamma,
+                }
+            ),
             OpType::Shape(args) => op_with_attrs!(Shape, ShapeAttrs, {
                 sg::ShapeAttrsArgs {
                     start: args.start,
                     end: args.end,
                 }
             }),
+            OpType::Shrink(args) => op_with_attrs!(
+                Shrink,
+                ShrinkAttrs,
+                sg::ShrinkAttrsArgs {
+                    bias: args.bias,
+                    lambd: args.lambd,
+                }
+            ),
             OpType::Sigmoid => op!(Sigmoid),
             OpType::Slice => op!(Slice),
             OpType::Sin => op!(Sin),
             OpType::Sign => op!(Sign),
+            OpType::Silu => op!(Silu),
             OpType::Size => op!(Size),
+            OpType::SkipSimplifiedLayerNormalization(args) => op_with_attrs!(
+                SkipSimplifiedLayerNormalization,
+                LayerNormalizationAttrs,
+                sg::LayerNormalizationAttrsArgs {
+                    axis: -1,
+                    epsilon: args.epsilon.unwrap_or(1e-5)
+                }
+            ),
             OpType::Softmax(args) => op_with_attrs!(
                 Softmax,
                 SoftmaxAttrs,
                 sg::SoftmaxAttrsArgs {
                     axis: args.axis as i32,
+                    flush_nans_to_zero: args.flush_nans_to_zero,
                 }
             ),
             OpType::Softplus => op!(Softplus),
+            OpType::SpaceToDepth(args) => op_with_attrs!(
+                SpaceToDepth,
+                SpaceToDepthAttrs,
+                sg::SpaceToDepthAttrsArgs {
+                    block_size: args.block_size,
+                }
+            ),
             OpType::Split(args) => op_with_attrs!(Split, SplitAttrs, {
                 sg::SplitAttrsArgs {
                     axis: args.axis as i32,
                     num_outputs: args.num_outputs.map(|n| n as i32),
                 }
             }),
+            OpType::SplitToSequence(args) => op_with_attrs!(
+                SplitToSequence,
+                SplitToSequenceAttrs,
+                sg::SplitToSequenceAttrsArgs {
+                    axis: args.axis,
+                    keep_dims: args.keep_dims,
+                }
+            ),
             OpType::Sqrt => op!(Sqrt),
             OpType::Squeeze => op!(Squeeze),
+            #[cfg(feature = "fft")]
+            OpType::STFT(args) => op_with_attrs!(
+                STFT,
+                STFTAttrs,
+                sg::STFTAttrsArgs {
+                    onesided: args.onesided,
+                }
+            ),
             OpType::Sub => op!(Sub),
             OpType::Sum => op!(Sum),
+            OpType::Swish(args) => {
+                op_with_attrs!(Swish, SwishAttrs, sg::SwishAttrsArgs { beta: args.beta })
+            }
             OpType::Tan => op!(Tan),
             OpType::Tanh => op!(Tanh),
+            OpType::ThresholdedRelu(args) => op_with_attrs!(
+                ThresholdedRelu,
+                ThresholdedReluAttrs,
+                sg::ThresholdedReluAttrsArgs { alpha: args.alpha }
+            ),
             OpType::Tile => op!(Tile),
             OpType::TopK(args) => op_with_attrs!(TopK, TopKAttrs, {
                 sg::TopKAttrsArgs {
@@ -928,6 +1529,29 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
                     sorted: args.sorted,
                 }
             }),
+            OpType::TransformInputs(args) => {
+                op_with_attrs!(TransformInputs, TransformInputsAttrs, {
+                    let transforms: Vec<_> = args
+                        .transforms
+                        .into_iter()
+                        .map(|(input_index, perm)| {
+                            let perm = self.create_vec(perm, |dim| dim as u32);
+                            sg::InputTransform::create(
+                                self.builder,
+                                &sg::InputTransformArgs {
+                                    input_index: input_index as u32,
+                                    perm,
+                                },
+                            )
+                        })
+                        .collect();
+                    let transforms = self.builder.create_vector(&transforms);
+                    sg::TransformInputsAttrsArgs {
+                        op: Some(args.op),
+                        transforms: Some(transforms),
+                    }
+                })
+            }
             OpType::Transpose(args) => op_with_attrs!(Transpose, TransposeAttrs, {
                 let perm = self.create_vec(args.perm, |dim| dim as u32);
                 sg::TransposeAttrsArgs { perm }
@@ -935,23 +1559,29 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
             OpType::Trilu(args) => op_with_attrs!(Trilu, TriluAttrs, {
                 sg::TriluAttrsArgs { upper: args.upper }
             }),
+            OpType::Unique(args) => op_with_attrs!(
+                Unique,
+                UniqueAttrs,
+                sg::UniqueAttrsArgs {
+                    axis: args.axis.map(|axis| axis as i32),
+                    sorted: args.sorted,
+                }
+            ),
             OpType::Unsqueeze => op!(Unsqueeze),
             OpType::Where => op!(Where),
             OpType::Xor => op!(Xor),
         };
 
-        let input_ids: Vec<i32> = inputs
-            .iter()
-            .map(|&id| match id {
-                Some(id) => id.as_u32() as i32,
-                None => -1,
-            })
-            .collect();
-        let output_ids: Vec<i32> = outputs.iter().map(|&id| id.as_u32() as i32).collect();
+        let node_index = |id: &Option<NodeId>| match id {
+            Some(id) => id.as_u32() as i32,
+            None => -1,
+        };
+        let input_ids: Vec<i32> = inputs.iter().map(node_index).collect();
+        let output_ids: Vec<i32> = outputs.iter().map(node_index).collect();
 
         let input_vec = self.builder.create_vector(&input_ids);
         let output_vec = self.builder.create_vector(&output_ids);
-        let op_node = sg::OperatorNode::create(
+        sg::OperatorNode::create(
             self.builder,
             &sg::OperatorNodeArgs {
                 type_: op_type,
@@ -960,8 +1590,7 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
                 inputs: Some(input_vec),
                 outputs: Some(output_vec),
             },
-        );
-        self.add_node(Some(id), NodeData::Operator(op_node))
+        )
     }
 
     /// Mark a node in the graph as an input.
@@ -974,6 +1603,44 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
         self.output_ids.push(node_id);
     }
 
+    /// Mark a node in the graph as a capture.
+    ///
+    /// Captures are value nodes in a subgraph whose values are taken from
+    /// nodes with the same name in an enclosing graph.
+    pub fn add_capture(&mut self, node_id: NodeId) {
+        self.captures.push(node_id);
+    }
+
+    /// Add weights for a constant node which have been prepacked by the
+    /// matrix multiplication kernel named `kernel`.
+    ///
+    /// Prepacked data is stored in the tensor data segment, so this does
+    /// nothing when generating the V1 format.
+    pub fn add_prepacked_weight(
+        &mut self,
+        node_id: NodeId,
+        kernel: &str,
+        dtype: DataType,
+        data: &[u8],
+    ) {
+        let Some(tdb) = self.tensor_data_builder.as_mut() else {
+            return;
+        };
+        let data_offset = tdb.add_bytes(data) as u64;
+        let kernel = self.builder.create_string(kernel);
+        let weight = sg::PrepackedWeight::create(
+            self.builder,
+            &sg::PrepackedWeightArgs {
+                node: node_id.as_u32(),
+                kernel: Some(kernel),
+                dtype: convert_constant_dtype(dtype),
+                data_offset,
+                data_len: data.len() as u64,
+            },
+        );
+        self.prepacked_weights.push(weight);
+    }
+
     /// Convert a `Vec<T>` of elements to a `Vec<U>` and add them to the model buffer
     fn create_vec<T: Copy, U: flatbuffers::Push + Copy, F: Fn(T) -> U>(
         &mut self,
@@ -986,14 +1653,93 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
         })
     }
 
+    /// Serialize symbolic expressions as a sequence of nodes in postfix order.
+    fn create_sym_exprs(
+        &mut self,
+        exprs: &[SymExpr],
+    ) -> WIPOffset<Vector<'a, ForwardsUOffset<sg::SymExprNode<'a>>>> {
+        fn visit<'e>(expr: &'e SymExpr, terms: &mut Vec<&'e SymExpr>) {
+            match expr {
+                SymExpr::Value(_) | SymExpr::Var(_) => {}
+                SymExpr::Neg(x) => visit(x, terms),
+                SymExpr::Add(a, b)
+                | SymExpr::Sub(a, b)
+                | SymExpr::Mul(a, b)
+                | SymExpr::Div(a, b)
+                | SymExpr::DivCeil(a, b)
+                | SymExpr::Max(a, b)
+                | SymExpr::Min(a, b)
+                | SymExpr::Broadcast(a, b) => {
+                    visit(a, terms);
+                    visit(b, terms);
+                }
+            }
+            terms.push(expr);
+        }
+
+        let mut terms = Vec::new();
+        for expr in exprs {
+            visit(expr, &mut terms);
+        }
+
+        let nodes: Vec<_> = terms
+            .into_iter()
+            .map(|term| {
+                let op = match term {
+                    SymExpr::Value(_) => sg::SymExprOp::Value,
+                    SymExpr::Var(_) => sg::SymExprOp::Var,
+                    SymExpr::Add(..) => sg::SymExprOp::Add,
+                    SymExpr::Sub(..) => sg::SymExprOp::Sub,
+                    SymExpr::Mul(..) => sg::SymExprOp::Mul,
+                    SymExpr::Div(..) => sg::SymExprOp::Div,
+                    SymExpr::DivCeil(..) => sg::SymExprOp::DivCeil,
+                    SymExpr::Max(..) => sg::SymExprOp::Max,
+                    SymExpr::Min(..) => sg::SymExprOp::Min,
+                    SymExpr::Broadcast(..) => sg::SymExprOp::Broadcast,
+                    SymExpr::Neg(..) => sg::SymExprOp::Neg,
+                };
+                let args = match term {
+                    SymExpr::Value(value) => sg::SymExprNodeArgs {
+                        op,
+                        value: *value,
+                        ..Default::default()
+                    },
+                    SymExpr::Var(sym) => sg::SymExprNodeArgs {
+                        op,
+                        name: Some(self.builder.create_string(&sym.name)),
+                        positive: sym.positive,
+                        ..Default::default()
+                    },
+                    _ => sg::SymExprNodeArgs {
+                        op,
+                        ..Default::default()
+                    },
+                };
+                sg::SymExprNode::create(self.builder, &args)
+            })
+            .collect();
+        self.builder.create_vector(&nodes)
+    }
+
     /// Finish writing this graph to the FlatBuffers buffer.
     pub fn finish(self) -> WIPOffset<sg::Graph<'a>> {
         let input_ids: Vec<_> = self.input_ids.iter().map(|id| id.as_u32()).collect();
         let output_ids: Vec<_> = self.output_ids.iter().map(|id| id.as_u32()).collect();
+        let capture_ids: Vec<_> = self.captures.iter().map(|id| id.as_u32()).collect();
 
         let inputs_vec = self.builder.create_vector(&input_ids);
         let outputs_vec = self.builder.create_vector(&output_ids);
+        let captures_vec = if capture_ids.is_empty() {
+            None
+        } else {
+            Some(self.builder.create_vector(&capture_ids))
+        };
         let nodes_vec = self.builder.create_vector(&self.nodes[..]);
+        let prepacked_weights_vec = if self.prepacked_weights.is_empty() {
+            None
+        } else {
+            Some(self.builder.create_vector(&self.prepacked_weights[..]))
+        };
 
         sg::Graph::create(
             self.builder,
@@ -1001,7 +1747,8 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
                 nodes: Some(nodes_vec),
                 inputs: Some(inputs_vec),
                 outputs: Some(outputs_vec),
-                captures: None,
+                captures: captures_vec,
+                prepacked_weights: prepacked_weights_vec,
             },
         )
     }
@@ -1009,12 +1756,13 @@ impl<'mb, 'a> GraphBuilder<'mb, 'a> {
 
 /// Serializes models to the RTen model format.
 ///
-/// This exists for use in model-loading tests. Models for deployment are
-/// normally built by converting ONNX models using the Python scripts.
+/// This is used by [`Model::save`](crate::Model::save) and in model-loading
+/// tests.
 pub struct ModelBuilder<'a> {
     builder: FlatBufferBuilder<'a>,
     graph: Option<WIPOffset<sg::Graph<'a>>>,
     metadata: Option<WIPOffset<sg::Metadata<'a>>>,
+    optimization: Option<WIPOffset<sg::OptimizationInfo<'a>>>,
 
     // Builder for the buffer containing tensor data stored outside the model.
     // `None` if building the V1 format which stores all data inline.
@@ -1028,6 +1776,7 @@ impl<'a> ModelBuilder<'a> {
             builder,
             graph: None,
             metadata: None,
+            optimization: None,
             tensor_data_builder: match format {
                 ModelFormat::V1 => None,
                 ModelFormat::V2 => Some(TensorDataBuilder::new()),
@@ -1053,15 +1802,31 @@ impl<'a> ModelBuilder<'a> {
 
     /// Add model metadata
     pub fn add_metadata(&mut self, metadata: MetadataArgs) {
-        let hash = metadata
-            .onnx_hash
-            .as_ref()
-            .map(|hash| self.builder.create_string(hash));
-        let mut meta_builder = sg::MetadataBuilder::new(&mut self.builder);
-        if let Some(hash) = hash {
-            meta_builder.add_onnx_hash(hash);
-        }
-        self.metadata = Some(meta_builder.finish());
+        let mut create_string =
+            |field: Option<String>| field.map(|value| self.builder.create_string(&value));
+        let args = sg::MetadataArgs {
+            onnx_hash: create_string(metadata.onnx_hash),
+            description: create_string(metadata.description),
+            license: create_string(metadata.license),
+            commit: create_string(metadata.commit),
+            code_repository: create_string(metadata.code_repository),
+            model_repository: create_string(metadata.model_repository),
+            run_id: create_string(metadata.run_id),
+            run_url: create_string(metadata.run_url),
+        };
+        self.metadata = Some(sg::Metadata::create(&mut self.builder, &args));
+    }
+
+    /// Mark the model's graph as having been optimized by version
+    /// `rten_version` of RTen.
+    pub fn set_optimization_info(&mut self, rten_version: &str) {
+        let rten_version = self.builder.create_string(rten_version);
+        self.optimization = Some(sg::OptimizationInfo::create(
+            &mut self.builder,
+            &sg::OptimizationInfoArgs {
+                rten_version: Some(rten_version),
+            },
+        ));
     }
 
     /// Finish writing the model data to the buffer and return the buffer's contents.
@@ -1072,6 +1837,7 @@ impl<'a> ModelBuilder<'a> {
                 schema_version: 1,
                 graph: self.graph,
                 metadata: self.metadata,
+                optimization: self.optimization,
             },
         );
 
@@ -1084,14 +1850,17 @@ impl<'a> ModelBuilder<'a> {
         if let Some(tensor_data) = self.tensor_data_builder.take() {
             let mut file_buf = Vec::new();
             let tensor_data = tensor_data.into_vec();
+            let model_end = Header::LEN + model_data.len();
+            let tensor_data_offset = model_end.next_multiple_of(TENSOR_ALIGN);
             let header = Header {
                 version: 2,
                 model_len: model_data.len() as u64,
                 model_offset: Header::LEN as u64,
-                tensor_data_offset: Header::LEN as u64 + model_data.len() as u64,
+                tensor_data_offset: tensor_data_offset as u64,
             };
             file_buf.extend(header.to_buf());
             file_buf.extend(model_data);
+            file_buf.resize(tensor_data_offset, 0);
             file_buf.extend(tensor_data);
             file_buf
         } else {
@@ -1106,6 +1875,12 @@ impl Default for ModelBuilder<'_> {
     }
 }
 
+/// Alignment of tensors in the tensor data segment.
+///
+/// This is larger than the alignment required by any element type, so that
+/// rows of matrices can start on a cache line boundary.
+const TENSOR_ALIGN: usize = 64;
+
 /// Builds the buffer used for storing tensor data outside the FlatBuffers
 /// model.
 struct TensorDataBuilder {
@@ -1122,17 +1897,7 @@ impl TensorDataBuilder {
     /// Returns the offset within the buffer of the start of the data for the
     /// tensor.
     fn add_tensor<T: Copy + LeBytes>(&mut self, data: &[T]) -> usize {
-        let offset = self.data.len();
-
-        // This currently uses the minimum required alignment for the type.
-        //
-        // In the real models we might choose a larger alignment so that rows
-        // of matrices start on a cache line boundary.
-        let align = std::mem::align_of::<T>();
-        let padding = offset.next_multiple_of(align) - offset;
-        self.data.extend(std::iter::repeat(0).take(padding));
-
-        let start_offset = self.data.len();
+        let start_offset = self.align();
 
         for x in data {
             let bytes = x.to_le_bytes();
@@ -1142,6 +1907,24 @@ impl TensorDataBuilder {
         start_offset
     }
 
+    /// Append raw bytes to the end of the buffer.
+    ///
+    /// Returns the offset within the buffer of the start of the data.
+    fn add_bytes(&mut self, data: &[u8]) -> usize {
+        let start_offset = self.align();
+        self.data.extend_from_slice(data);
+        start_offset
+    }
+
+    /// Pad the buffer so that its length is a multiple of [`TENSOR_ALIGN`]
+    /// and return the new length.
+    fn align(&mut self) -> usize {
+        let offset = self.data.len();
+        let padding = offset.next_multiple_of(TENSOR_ALIGN) - offset;
+        self.data.extend(std::iter::repeat(0).take(padding));
+        self.data.len()
+    }
+
     /// Consume the builder and return the finalized tensor data buffer.
     fn into_vec(self) -> Vec<u8> {
         self.data
@@ -4,21 +4,24 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use rten_base::byte_cast::{Pod, cast_pod_slice};
+use rten_base::half::{bf16, f16};
 use rten_base::num::LeBytes;
 use rten_model_file::header::{Header, HeaderError};
 use rten_model_file::schema as sg;
 use rten_model_file::schema::root_as_model;
 use rten_tensor::ArcTensor;
+use rten_tensor::prelude::*;
 
 use super::load_error::{LoadError, LoadErrorImpl, load_error};
 use super::metadata::{MetadataField, ModelMetadata};
 use super::{Model, ModelOptions, OptimizeMode};
 use crate::constant_storage::{ArcSlice, ArcTensorView, ConstantStorage};
-use crate::graph::{CaptureEnv, ConstantNodeData, Dimension, Graph, NodeId};
-use crate::op_registry::rten_registry::{OpLoadContext, convert_dtype};
+use crate::graph::{CaptureEnv, ConstantNodeData, Dimension, Graph, Node, NodeId};
+use crate::op_registry::rten_registry::{OpLoadContext, RtenOpRegistry, convert_dtype};
 use crate::op_registry::{OpRegistry, ReadOpError};
+use crate::operator::{Operator, PrepackedInput};
 use crate::optimize::GraphOptimizer;
-use crate::value::ValueType;
+use crate::value::{DataType, ValueType};
 use crate::weight_cache::WeightCache;
 
 /// Load a model from a .rten model file.
@@ -52,18 +55,44 @@ pub fn load(storage: Arc<ConstantStorage>, options: &ModelOptions) -> Result<Mod
         return Err(LoadErrorImpl::ParseFailed(err.into()).into());
     }
 
+    // Skip optimization if the graph was already optimized by this version
+    // of RTen. Graphs optimized by other versions are optimized again, as
+    // the set of optimizations may differ.
+    let pre_optimized = model
+        .optimization()
+        .is_some_and(|info| info.rten_version() == env!("CARGO_PKG_VERSION"));
+    let optimize = if pre_optimized {
+        OptimizeMode::Off
+    } else {
+        options.optimize_mode()
+    };
+    let optimized = pre_optimized || matches!(optimize, OptimizeMode::On(_));
+
     let tensor_data_offset = header.as_ref().map(|h| h.tensor_data_offset);
     let graph = load_graph(
         model.graph(),
         registry,
         storage.clone(),
         tensor_data_offset,
-        options.optimize_mode(),
+        optimize,
         None, /* capture_env */
     )?;
 
     let mut weight_cache = WeightCache::new();
     if options.prepack_weights {
+        // Prepacked weights refer to nodes by index, so they are only valid
+        // if the graph was not modified by optimization after loading.
+        if pre_optimized && let Some(tensor_data_offset) = tensor_data_offset {
+            load_prepacked_weights(
+                &graph,
+                model.graph(),
+                &storage,
+                tensor_data_offset,
+                &mut weight_cache,
+            );
+        }
+
+        // Pack any weights which were not restored from the file.
         graph.prepack_weights(&mut weight_cache);
     }
 
@@ -73,10 +102,55 @@ pub fn load(storage: Arc<ConstantStorage>, options: &ModelOptions) -> Result<Mod
         graph,
         metadata,
         weight_cache,
+        optimized,
     };
     Ok(model)
 }
 
+/// Restore prepacked weights saved in a model file into `weight_cache`.
+///
+/// Weights that were packed for a different matrix multiplication kernel than
+/// the one that would be used on this system are skipped, so they will be
+/// packed again.
+fn load_prepacked_weights(
+    graph: &Graph,
+    serialized_graph: sg::Graph,
+    storage: &ConstantStorage,
+    tensor_data_offset: u64,
+    weight_cache: &mut WeightCache,
+) {
+    // Packed data uses the native byte order and is only saved by
+    // little-endian systems.
+    if !cfg!(target_endian = "little") {
+        return;
+    }
+
+    for weight in serialized_graph.prepacked_weights().iter().flatten() {
+        let node_id = NodeId::from_u32(weight.node());
+        let Some(Node::Constant(constant)) = graph.get_node(node_id) else {
+            continue;
+        };
+        let &[rows, cols] = constant.shape() else {
+            continue;
+        };
+        let dtype = match weight.dtype() {
+            sg::ConstantDataType::Float32 => DataType::Float,
+            sg::ConstantDataType::Int8 => DataType::Int8,
+            _ => continue,
+        };
+        let start = (tensor_data_offset + weight.data_offset()) as usize;
+        let Some(data) = storage
+            .data()
+            .get(start..start.saturating_add(weight.data_len() as usize))
+        else {
+            continue;
+        };
+        if let Some(packed) = PrepackedInput::from_bytes(dtype, weight.kernel(), rows, cols, data) {
+            weight_cache.insert(node_id, packed);
+        }
+    }
+}
+
 fn load_metadata(meta: sg::Metadata) -> ModelMetadata {
     let mut fields = Vec::new();
 
@@ -219,16 +293,25 @@ fn add_graph_operator(
 
     struct LoadContext<'a> {
         load_graph: &'a dyn Fn(sg::Graph) -> Result<Graph, LoadError>,
+        registry: &'a RtenOpRegistry,
     }
 
     impl OpLoadContext for LoadContext<'_> {
         fn load_graph(&self, graph: sg::Graph) -> Result<Graph, ReadOpError> {
             (self.load_graph)(graph).map_err(|err| ReadOpError::SubgraphError(err.into()))
         }
+
+        fn read_op(
+            &self,
+            op: &sg::OperatorNode,
+        ) -> Result<Arc<dyn Operator + Send + Sync>, ReadOpError> {
+            self.registry.read_op(op, self)
+        }
     }
 
     let ctx = LoadContext {
         load_graph: &load_subgraph,
+        registry: registry.rten_registry(),
     };
     let op = registry
         .rten_registry()
@@ -347,6 +430,26 @@ fn add_graph_constant(
                     constant_data_from_storage_offset::<u8>(storage, &shape, data_offset, name)?;
                 graph.add_constant(name, const_data)
             }
+            Some(sg::ConstantDataType::Float16) => {
+                let const_data =
+                    constant_data_from_storage_offset::<f16>(storage, &shape, data_offset, name)?;
+                graph.add_constant(name, const_data)
+            }
+            Some(sg::ConstantDataType::BFloat16) => {
+                let const_data =
+                    constant_data_from_storage_offset::<bf16>(storage, &shape, data_offset, name)?;
+                graph.add_constant(name, const_data)
+            }
+            Some(sg::ConstantDataType::Int64) => {
+                let const_data =
+                    constant_data_from_storage_offset::<i64>(storage, &shape, data_offset, name)?;
+                graph.add_constant(name, const_data)
+            }
+            Some(sg::ConstantDataType::Bool) => {
+                let const_data =
+                    constant_data_from_storage_offset::<u8>(storage, &shape, data_offset, name)?;
+                graph.add_constant(name, bool_constant_data(const_data))
+            }
             _ => {
                 return Err(load_error!(
                     GraphError,
@@ -368,8 +471,32 @@ fn add_graph_constant(
             let const_data = constant_data_from_flatbuffers_vec(storage, int8_data.data(), &shape);
             graph.add_constant(name, const_data)
         } else if let Some(uint8_data) = constant.data_as_uint_8_data() {
-            let const_data = constant_data_from_flatbuffers_vec(storage, uint8_data.data(), &shape);
-            graph.add_constant(name, const_data)
+            // Types without their own inline data table are stored as bytes.
+            let bytes = uint8_data.data().bytes();
+            match constant.dtype() {
+                Some(sg::ConstantDataType::Float16) => {
+                    let const_data = constant_data_from_bytes::<f16>(storage, &shape, bytes, name)?;
+                    graph.add_constant(name, const_data)
+                }
+                Some(sg::ConstantDataType::BFloat16) => {
+                    let const_data =
+                        constant_data_from_bytes::<bf16>(storage, &shape, bytes, name)?;
+                    graph.add_constant(name, const_data)
+                }
+                Some(sg::ConstantDataType::Int64) => {
+                    let const_data = constant_data_from_bytes::<i64>(storage, &shape, bytes, name)?;
+                    graph.add_constant(name, const_data)
+                }
+                Some(sg::ConstantDataType::Bool) => {
+                    let const_data = constant_data_from_bytes::<u8>(storage, &shape, bytes, name)?;
+                    graph.add_constant(name, bool_constant_data(const_data))
+                }
+                _ => {
+                    let const_data =
+                        constant_data_from_flatbuffers_vec(storage, uint8_data.data(), &shape);
+                    graph.add_constant(name, const_data)
+                }
+            }
         } else {
             return Err(load_error!(
                 GraphError,
@@ -426,6 +553,26 @@ fn constant_data_from_storage_offset<T: LeBytes + Pod>(
         return Err(load_error!(GraphError, name, "invalid tensor data offset"));
     };
 
+    constant_data_from_bytes(storage, shape, bytes, name)
+}
+
+/// Convert a slice of little-endian bytes from `storage` into data for a graph
+/// constant node.
+fn constant_data_from_bytes<T: LeBytes + Pod>(
+    storage: &Arc<ConstantStorage>,
+    shape: &[usize],
+    bytes: &[u8],
+    name: Option<&str>,
+) -> Result<ConstantNodeData<T>, LoadError> {
+    let n_elements: usize = shape.iter().product();
+    if bytes.len() != n_elements * std::mem::size_of::<T>() {
+        return Err(load_error!(
+            GraphError,
+            name,
+            "tensor data length does not match shape"
+        ));
+    }
+
     if let Some(elements) = cast_le_bytes(bytes) {
         let storage =
             ArcSlice::new(storage.clone(), elements).expect("storage does not contain data");
@@ -440,6 +587,18 @@ fn constant_data_from_storage_offset<T: LeBytes + Pod>(
     }
 }
 
+/// Convert `u8` constant data to `bool`, treating non-zero values as true.
+///
+/// Bools are stored as one byte per element. The data is copied rather than
+/// used in-place because not all byte values are valid bools.
+fn bool_constant_data(data: ConstantNodeData<u8>) -> ConstantNodeData<bool> {
+    let view = match &data {
+        ConstantNodeData::ArcSlice(data) => data.view(),
+        ConstantNodeData::Arc(data) => data.view(),
+    };
+    view.map(|x| *x != 0).into_arc().into()
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
@@ -0,0 +1,506 @@
+//! Serialization of model graphs to the `.rten` format.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use flatbuffers::WIPOffset;
+use rten_model_file::schema as sg;
+
+use super::ModelMetadata;
+use super::rten_builder::{
+    GraphBuilder, IfArgs, LoopArgs, MetadataArgs, ModelBuilder, ModelFormat, OpType,
+    TransformInputsArgs,
+};
+use crate::graph::{Constant, Graph, Node, NodeId, OperatorNode};
+use crate::operator::Operator;
+use crate::ops;
+use crate::ops::transform_inputs::TransformInputs;
+use crate::value::ValueType;
+use crate::weight_cache::WeightCache;
+
+/// Errors that occur when saving a model.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SaveError {
+    /// An I/O error occurred writing the model file.
+    IoError(std::io::Error),
+
+    /// The graph contains an operator that cannot be represented in the
+    /// `.rten` format.
+    UnsupportedOperator {
+        /// Name of the operator node, if it has one.
+        node: Option<String>,
+        /// Operator type, as returned by [`Operator::name`].
+        op_type: String,
+    },
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IoError(err) => write!(f, "io error: {}", err),
+            Self::UnsupportedOperator { node, op_type } => {
+                write!(f, "operator {} ", op_type)?;
+                if let Some(node) = node {
+                    write!(f, "in node \"{}\" ", node)?;
+                }
+                write!(f, "is not supported by the .rten format")
+            }
+        }
+    }
+}
+
+impl Error for SaveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::IoError(err) => Some(err),
+            Self::UnsupportedOperator { .. } => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for SaveError {
+    fn from(val: std::io::Error) -> Self {
+        Self::IoError(val)
+    }
+}
+
+/// Options which customize how a model is saved.
+#[derive(Clone, Debug, Default)]
+pub struct SaveOptions {
+    prepacked_weights: bool,
+}
+
+impl SaveOptions {
+    /// Set whether prepacked weights are saved.
+    ///
+    /// If enabled and the model was loaded with
+    /// [`ModelOptions::prepack_weights`](crate::ModelOptions::prepack_weights),
+    /// the prepacked copies of the weights are saved in addition to the
+    /// original weights. Loading the saved model with prepacking enabled will
+    /// then use the saved copies, if they were packed for the same CPU
+    /// instruction set, instead of packing the weights again.
+    ///
+    /// This increases the size of the saved model.
+    pub fn prepacked_weights(&mut self, enable: bool) -> &mut Self {
+        self.prepacked_weights = enable;
+        self
+    }
+}
+
+/// Serialize a model graph and metadata to a buffer in the V2 `.rten` format.
+///
+/// If `optimized` is true the model is marked as optimized by the current
+/// version of RTen, so that loading it with the same version skips graph
+/// optimization.
+pub fn serialize_model(
+    graph: &Graph,
+    metadata: &ModelMetadata,
+    optimized: bool,
+    weight_cache: &WeightCache,
+    opts: &SaveOptions,
+) -> Result<Vec<u8>, SaveError> {
+    let mut builder = ModelBuilder::new(ModelFormat::V2);
+
+    // Packed data uses the native byte order, so is only saved on
+    // little-endian systems.
+    let weight_cache =
+        (opts.prepacked_weights && cfg!(target_endian = "little")).then_some(weight_cache);
+
+    let mut graph_builder = builder.graph_builder();
+    write_graph(&mut graph_builder, graph, weight_cache)?;
+    let graph = graph_builder.finish();
+    builder.set_graph(graph);
+
+    if optimized {
+        builder.set_optimization_info(env!("CARGO_PKG_VERSION"));
+    }
+
+    let field = |value: Option<&str>| value.map(|v| v.to_string());
+    builder.add_metadata(MetadataArgs {
+        onnx_hash: field(metadata.onnx_hash()),
+        description: field(metadata.description()),
+        license: field(metadata.license()),
+        commit: field(metadata.commit()),
+        code_repository: field(metadata.code_repository()),
+        model_repository: field(metadata.model_repository()),
+        run_id: field(metadata.run_id()),
+        run_url: field(metadata.run_url()),
+    });
+
+    Ok(builder.finish())
+}
+
+/// Write the nodes, inputs, outputs and captures of `graph` using `builder`.
+///
+/// The format requires that nodes referenced by an operator appear before it.
+/// Nodes are written in ID order, which preserves IDs for graphs loaded from
+/// `.rten` files, with operators deferred if they reference later nodes.
+///
+/// If `weight_cache` is set, prepacked weights for constants in the graph are
+/// also written.
+fn write_graph(
+    builder: &mut GraphBuilder,
+    graph: &Graph,
+    weight_cache: Option<&WeightCache>,
+) -> Result<(), SaveError> {
+    let mut nodes: Vec<(NodeId, &Node)> = graph.iter().collect();
+    nodes.sort_by_key(|(id, _node)| *id);
+
+    // Map of node ID in `graph` to ID in the serialized graph.
+    let mut id_map: HashMap<NodeId, NodeId> = HashMap::with_capacity(nodes.len());
+    let mut deferred_ops = Vec::new();
+
+    for (id, node) in nodes {
+        let new_id = match node {
+            Node::Constant(constant) => write_constant(builder, constant),
+            Node::Value(value) => {
+                let shape = value.shape();
+                let dtype = match value.dtype() {
+                    Some(ValueType::Tensor(dtype)) => Some(dtype),
+                    // The format does not currently record sequence types.
+                    Some(ValueType::Sequence(_)) | None => None,
+                };
+                builder.add_value_node(node.name(), shape.as_deref(), dtype)
+            }
+            Node::Operator(op_node) => {
+                let is_ready = op_node
+                    .input_ids()
+                    .iter()
+                    .chain(op_node.output_ids())
+                    .flatten()
+                    .all(|id| id_map.contains_key(id));
+                if !is_ready {
+                    deferred_ops.push((id, op_node));
+                    continue;
+                }
+                write_operator(builder, op_node, &id_map)?
+            }
+        };
+        id_map.insert(id, new_id);
+    }
+
+    // Operators only reference constants and values, so these can all be
+    // written once the other nodes have been.
+    for (id, op_node) in deferred_ops {
+        let new_id = write_operator(builder, op_node, &id_map)?;
+        id_map.insert(id, new_id);
+    }
+
+    for id in graph.input_ids() {
+        builder.add_input(id_map[id]);
+    }
+    for id in graph.output_ids() {
+        builder.add_output(id_map[id]);
+    }
+    for id in graph.captures() {
+        builder.add_capture(id_map[id]);
+    }
+
+    if let Some(weight_cache) = weight_cache {
+        let mut packed: Vec<_> = id_map
+            .iter()
+            .filter_map(|(id, new_id)| weight_cache.get(*id).map(|packed| (*new_id, packed)))
+            .collect();
+        packed.sort_by_key(|(id, _packed)| *id);
+
+        for (id, packed) in packed {
+            builder.add_prepacked_weight(
+                id,
+                packed.kernel_name(),
+                packed.dtype(),
+                packed.as_bytes(),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn write_constant(builder: &mut GraphBuilder, constant: &Constant) -> NodeId {
+    let name = constant.name();
+    match constant {
+        Constant::Float(node) => builder.add_constant_node(name, node.view()),
+        Constant::Int32(node) => builder.add_constant_node(name, node.view()),
+        Constant::Int8(node) => builder.add_constant_node(name, node.view()),
+        Constant::UInt8(node) => builder.add_constant_node(name, node.view()),
+        Constant::Float16(node) => builder.add_constant_node(name, node.view()),
+        Constant::BFloat16(node) => builder.add_constant_node(name, node.view()),
+        Constant::Int64(node) => builder.add_constant_node(name, node.view()),
+        Constant::Bool(node) => builder.add_constant_node(name, node.view()),
+    }
+}
+
+fn write_operator(
+    builder: &mut GraphBuilder,
+    op_node: &OperatorNode,
+    id_map: &HashMap<NodeId, NodeId>,
+) -> Result<NodeId, SaveError> {
+    let operator = op_node.operator();
+    let op_type =
+        convert_operator(builder, operator)?.ok_or_else(|| SaveError::UnsupportedOperator {
+            node: op_node.name().map(|name| name.to_string()),
+            op_type: operator.name().to_string(),
+        })?;
+
+    let map_ids = |ids: &[Option<NodeId>]| -> Vec<Option<NodeId>> {
+        ids.iter().map(|id| id.map(|id| id_map[&id])).collect()
+    };
+    let inputs = map_ids(op_node.input_ids());
+    let outputs = map_ids(op_node.output_ids());
+
+    Ok(builder.add_operator_node(op_node.name(), op_type, &inputs, &outputs))
+}
+
+/// Serialize a subgraph of a control flow operator.
+fn write_subgraph<'a>(
+    builder: &mut GraphBuilder<'_, 'a>,
+    graph: &Graph,
+) -> Result<WIPOffset<sg::Graph<'a>>, SaveError> {
+    let mut subgraph_builder = builder.subgraph_builder();
+    write_graph(&mut subgraph_builder, graph, None /* weight_cache */)?;
+    Ok(subgraph_builder.finish())
+}
+
+/// Convert an operator to the corresponding [`OpType`].
+///
+/// Returns `None` if the operator cannot be represented in the `.rten` format.
+fn convert_operator<'a>(
+    builder: &mut GraphBuilder<'_, 'a>,
+    op: &dyn Operator,
+) -> Result<Option<OpType<'a>>, SaveError> {
+    // Convert an operator with attributes by cloning them from the operator.
+    macro_rules! attrs {
+        ($op_type:ident) => {{
+            let Some(op) = op.downcast_ref::<ops::$op_type>() else {
+                return Ok(None);
+            };
+            OpType::$op_type(op.clone())
+        }};
+    }
+
+    let op_type = match op.name() {
+        "Abs" => OpType::Abs,
+        "Acos" => OpType::Acos,
+        "Add" => OpType::Add,
+        "AddSoftmax" => attrs!(AddSoftmax),
+        "And" => OpType::And,
+        "ArgMax" => attrs!(ArgMax),
+        "ArgMin" => attrs!(ArgMin),
+        "Asin" => OpType::Asin,
+        "Atan" => OpType::Atan,
+        "Attention" => attrs!(Attention),
+        "AveragePool" => attrs!(AveragePool),
+        "BatchNormalization" => attrs!(BatchNormalization),
+        "BitShift" => attrs!(BitShift),
+        "BitwiseAnd" => OpType::BitwiseAnd,
+        "BitwiseNot" => OpType::BitwiseNot,
+        "BitwiseOr" => OpType::BitwiseOr,
+        "BitwiseXor" => OpType::BitwiseXor,
+        #[cfg(feature = "fft")]
+        "BlackmanWindow" => attrs!(BlackmanWindow),
+        "Cast" => attrs!(Cast),
+        "CastLike" => attrs!(CastLike),
+        "Ceil" => OpType::Ceil,
+        "Celu" => attrs!(Celu),
+        "Clip" => OpType::Clip,
+        "ComputeShape" => attrs!(ComputeShape),
+        "Col2Im" => attrs!(Col2Im),
+        "Compress" => attrs!(Compress),
+        "Concat" => attrs!(Concat),
+        "ConcatFromSequence" => attrs!(ConcatFromSequence),
+        "ConstantOfShape" => attrs!(ConstantOfShape),
+        "Conv" => attrs!(Conv),
+        "ConvInteger" => attrs!(ConvInteger),
+        "ConvTranspose" => attrs!(ConvTranspose),
+        "Cos" => OpType::Cos,
+        "CumSum" => OpType::CumSum,
+        "DequantizeLinear" => attrs!(DequantizeLinear),
+        "DepthToSpace" => attrs!(DepthToSpace),
+        "Det" => OpType::Det,
+        #[cfg(feature = "fft")]
+        "DFT" => attrs!(DFT),
+        "Div" => OpType::Div,
+        #[cfg(feature = "random")]
+        "Dropout" => attrs!(Dropout),
+        "DynamicQuantizeLinear" => OpType::DynamicQuantizeLinear,
+        "Einsum" => attrs!(Einsum),
+        "Elu" => attrs!(Elu),
+        "Equal" => OpType::Equal,
+        "Erf" => OpType::Erf,
+        "Exp" => OpType::Exp,
+        "Expand" => OpType::Expand,
+        "EyeLike" => attrs!(EyeLike),
+        "Flatten" => attrs!(Flatten),
+        "Floor" => OpType::Floor,
+        "FusedMatMul" => attrs!(FusedMatMul),
+        "Gather" => attrs!(Gather),
+        "GatherElements" => attrs!(GatherElements),
+        "GatherND" => attrs!(GatherND),
+        "Gelu" => attrs!(Gelu),
+        "Gemm" => attrs!(Gemm),
+        "GlobalAveragePool" => OpType::GlobalAveragePool,
+        "GlobalMaxPool" => OpType::GlobalMaxPool,
+        "Greater" => OpType::Greater,
+        "GreaterOrEqual" => OpType::GreaterOrEqual,
+        "GridSample" => attrs!(GridSample),
+        "GroupNormalization" => attrs!(GroupNormalization),
+        "GroupQueryAttention" => attrs!(GroupQueryAttention),
+        "GroupedQueryAttentionMatMul" => attrs!(GroupedQueryAttentionMatMul),
+        "GRU" => attrs!(GRU),
+        "Hardmax" => attrs!(Hardmax),
+        #[cfg(feature = "fft")]
+        "HammingWindow" => attrs!(HammingWindow),
+        #[cfg(feature = "fft")]
+        "HannWindow" => attrs!(HannWindow),
+        "HardSigmoid" => attrs!(HardSigmoid),
+        "HardSwish" => OpType::HardSwish,
+        "Identity" => OpType::Identity,
+        "If" => {
+            let Some(op) = op.downcast_ref::<ops::If>() else {
+                return Ok(None);
+            };
+            let then_branch = write_subgraph(builder, &op.then_branch)?;
+            let else_branch = write_subgraph(builder, &op.else_branch)?;
+            OpType::If(IfArgs {
+                then_branch,
+                else_branch,
+            })
+        }
+        "InstanceNormalization" => attrs!(InstanceNormalization),
+        "IsInf" => OpType::IsInf,
+        "IsNaN" => OpType::IsNaN,
+        "LayerNormalization" => attrs!(LayerNormalization),
+        "LeakyRelu" => attrs!(LeakyRelu),
+        "Less" => OpType::Less,
+        "LessOrEqual" => OpType::LessOrEqual,
+        "Log" => OpType::Log,
+        "LogSoftmax" => attrs!(LogSoftmax),
+        "Loop" => {
+            let Some(op) = op.downcast_ref::<ops::Loop>() else {
+                return Ok(None);
+            };
+            let body = write_subgraph(builder, &op.body)?;
+            OpType::Loop(LoopArgs { body })
+        }
+        "LSTM" => attrs!(LSTM),
+        "LpNormalization" => attrs!(LpNormalization),
+        "LpPool" => attrs!(LpPool),
+        "MatMul" => OpType::MatMul,
+        "MatMulInteger" => OpType::MatMulInteger,
+        "MatMulIntegerToFloat" => OpType::MatMulIntegerToFloat,
+        "MatMulNBits" => attrs!(MatMulNBits),
+        "Max" => OpType::Max,
+        "MaxPool" => attrs!(MaxPool),
+        "MaxUnpool" => attrs!(MaxUnpool),
+        "Mean" => OpType::Mean,
+        "MeanVarianceNormalization" => attrs!(MeanVarianceNormalization),
+        #[cfg(feature = "fft")]
+        "MelWeightMatrix" => OpType::MelWeightMatrix,
+        "Min" => OpType::Min,
+        "Mish" => OpType::Mish,
+        "Mod" => attrs!(Mod),
+        "Mul" => OpType::Mul,
+        "Neg" => OpType::Neg,
+        "NonMaxSuppression" => attrs!(NonMaxSuppression),
+        "NonZero" => OpType::NonZero,
+        "Not" => OpType::Not,
+        "OneHot" => attrs!(OneHot),
+        "Or" => OpType::Or,
+        "Pad" => attrs!(Pad),
+        "Pow" => OpType::Pow,
+        "PRelu" => OpType::PRelu,
+        "QuantizeLinear" => attrs!(QuantizeLinear),
+        #[cfg(feature = "random")]
+        "RandomNormal" => attrs!(RandomNormal),
+        #[cfg(feature = "random")]
+        "RandomNormalLike" => attrs!(RandomNormalLike),
+        #[cfg(feature = "random")]
+        "RandomUniform" => attrs!(RandomUniform),
+        #[cfg(feature = "random")]
+        "RandomUniformLike" => attrs!(RandomUniformLike),
+        "Range" => OpType::Range,
+        "Reciprocal" => OpType::Reciprocal,
+        "ReduceL2" => attrs!(ReduceL2),
+        "ReduceLogSumExp" => attrs!(ReduceLogSumExp),
+        "ReduceMax" => attrs!(ReduceMax),
+        "ReduceMean" => attrs!(ReduceMean),
+        "ReduceMin" => attrs!(ReduceMin),
+        "ReduceProd" => attrs!(ReduceProd),
+        "ReduceSum" => attrs!(ReduceSum),
+        "ReduceSumSquare" => attrs!(ReduceSumSquare),
+        "Relu" => OpType::Relu,
+        "RepeatInterleave" => attrs!(RepeatInterleave),
+        "Reshape" => attrs!(Reshape),
+        "Resize" => attrs!(Resize),
+        "ReverseSequence" => attrs!(ReverseSequence),
+        "RmsNormalization" => attrs!(RmsNormalization),
+        "RoiAlign" => attrs!(RoiAlign),
+        "RotaryEmbedding" => attrs!(RotaryEmbedding),
+        "Round" => OpType::Round,
+        "ScatterElements" => attrs!(ScatterElements),
+        "ScatterND" => attrs!(ScatterND),
+        "Selu" => attrs!(Selu),
+        "SequenceAt" => OpType::SequenceAt,
+        "SequenceConstruct" => OpType::SequenceConstruct,
+        "SequenceEmpty" => attrs!(SequenceEmpty),
+        "SequenceErase" => OpType::SequenceErase,
+        "SequenceInsert" => OpType::SequenceInsert,
+        "SequenceLength" => OpType::SequenceLength,
+        "Shape" => attrs!(Shape),
+        "Shrink" => attrs!(Shrink),
+        "Sigmoid" => OpType::Sigmoid,
+        "Sign" => OpType::Sign,
+        "Silu" => OpType::Silu,
+        "Sin" => OpType::Sin,
+        "Size" => OpType::Size,
+        "SkipSimplifiedLayerNormalization" => attrs!(SkipSimplifiedLayerNormalization),
+        "Slice" => OpType::Slice,
+        "Softmax" => attrs!(Softmax),
+        "Softplus" => OpType::Softplus,
+        "SpaceToDepth" => attrs!(SpaceToDepth),
+        "Split" => attrs!(Split),
+        "SplitToSequence" => attrs!(SplitToSequence),
+        "Sqrt" => OpType::Sqrt,
+        "Squeeze" => OpType::Squeeze,
+        #[cfg(feature = "fft")]
+        "STFT" => attrs!(STFT),
+        "Sub" => OpType::Sub,
+        "Sum" => OpType::Sum,
+        "Swish" => attrs!(Swish),
+        "Tan" => OpType::Tan,
+        "Tanh" => OpType::Tanh,
+        "ThresholdedRelu" => attrs!(ThresholdedRelu),
+        "Tile" => OpType::Tile,
+        "TopK" => attrs!(TopK),
+        "Transpose" => attrs!(Transpose),
+        "Trilu" => attrs!(Trilu),
+        "Unique" => attrs!(Unique),
+        "Unsqueeze" => OpType::Unsqueeze,
+        "Where" => OpType::Where,
+        "Xor" => OpType::Xor,
+
+        // The name of `TransformInputs` includes the name of the wrapped
+        // operator.
+        name if name.starts_with("TransformInputs(") => {
+            let Some(op) = op.downcast_ref::<TransformInputs>() else {
+                return Ok(None);
+            };
+            let Some(inner) = convert_operator(builder, op.inner().as_ref())? else {
+                return Ok(None);
+            };
+            let transforms = op
+                .permutations()
+                .map(|(input_index, perm)| (input_index, perm.map(|p| p.to_vec())))
+                .collect();
+            OpType::TransformInputs(TransformInputsArgs {
+                op: builder.create_nested_operator(inner),
+                transforms,
+            })
+        }
+        _ => return Ok(None),
+    };
+
+    Ok(Some(op_type))
+}
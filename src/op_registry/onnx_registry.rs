@@ -17,8 +17,8 @@ use crate::graph::Graph;
 use crate::operator::Operator;
 use crate::ops;
 use crate::ops::{
-    AccuracyLevel, BoxOrder, CoordTransformMode, DepthToSpaceMode, Direction, NearestMode, PadMode,
-    Padding, ResizeMode, ScatterReduction,
+    AccuracyLevel, BitShiftDirection, BoxOrder, CoordTransformMode, DepthToSpaceMode, Direction,
+    NearestMode, PadMode, Padding, ResizeMode, RoiAlignMode, RoiAlignOptions, ScatterReduction,
 };
 use crate::value::{DataType, Scalar};
 
@@ -105,12 +105,22 @@ impl OnnxOpRegistry {
         register_op!(ArgMin);
         register_op!(Asin);
         register_op!(Atan);
+        register_op!(Attention);
         register_op!(AveragePool);
         register_op!(BatchNormalization);
+        register_op!(BitShift);
+        register_op!(BitwiseAnd);
+        register_op!(BitwiseNot);
+        register_op!(BitwiseOr);
+        register_op!(BitwiseXor);
+        register_op!(BlackmanWindow, feature = "fft");
         register_op!(Cast);
         register_op!(CastLike);
         register_op!(Ceil);
+        register_op!(Celu);
         register_op!(Clip);
+        register_op!(Col2Im);
+        register_op!(Compress);
         register_op!(Concat);
         register_op!(ConcatFromSequence);
         register_op!(Conv);
@@ -121,6 +131,8 @@ impl OnnxOpRegistry {
         register_op!(CumSum);
         register_op!(DequantizeLinear);
         register_op!(DepthToSpace);
+        register_op!(Det);
+        register_op!(DFT, feature = "fft");
         register_op!(Div);
         register_op!(Dropout, feature = "random");
         register_op!(DynamicQuantizeLinear);
@@ -143,7 +155,11 @@ impl OnnxOpRegistry {
         register_op!(Greater);
         register_op!(GreaterOrEqual);
         register_op!(GridSample);
+        register_op!(GroupNormalization);
         register_op!(GRU);
+        register_op!(Hardmax);
+        register_op!(HammingWindow, feature = "fft");
+        register_op!(HannWindow, feature = "fft");
         register_op!(HardSigmoid);
         register_op!(HardSwish);
         register_op!(Identity);
@@ -159,12 +175,18 @@ impl OnnxOpRegistry {
         register_op!(LogSoftmax);
         register_op!(Loop);
         register_op!(LSTM);
+        register_op!(LpNormalization);
+        register_op!(LpPool);
         register_op!(MatMul);
         register_op!(MatMulInteger);
         register_op!(Max);
         register_op!(MaxPool);
+        register_op!(MaxUnpool);
         register_op!(Mean);
+        register_op!(MeanVarianceNormalization);
+        register_op!(MelWeightMatrix, feature = "fft");
         register_op!(Min);
+        register_op!(Mish);
         register_op!(Mod);
         register_op!(Mul);
         register_op!(Neg);
@@ -184,6 +206,7 @@ impl OnnxOpRegistry {
         register_op!(Range);
         register_op!(Reciprocal);
         register_op!(ReduceL2);
+        register_op!(ReduceLogSumExp);
         register_op!(ReduceMax);
         register_op!(ReduceMean);
         register_op!(ReduceMin);
@@ -193,9 +216,14 @@ impl OnnxOpRegistry {
         register_op!(Relu);
         register_op!(Reshape);
         register_op!(Resize);
+        register_op!(ReverseSequence);
+        register_op!(RmsNormalization);
+        register_op!(RoiAlign);
+        register_op!(RotaryEmbedding);
         register_op!(Round);
         register_op!(ScatterElements);
         register_op!(ScatterND);
+        register_op!(Selu);
         register_op!(SequenceAt);
         register_op!(SequenceConstruct);
         register_op!(SequenceEmpty);
@@ -203,6 +231,7 @@ impl OnnxOpRegistry {
         register_op!(SequenceInsert);
         register_op!(SequenceLength);
         register_op!(Shape);
+        register_op!(Shrink);
         register_op!(Sigmoid);
         register_op!(Sign);
         register_op!(Sin);
@@ -210,6 +239,7 @@ impl OnnxOpRegistry {
         register_op!(Slice);
         register_op!(Softmax);
         register_op!(Softplus);
+        register_op!(SpaceToDepth);
         register_op!(Split);
         register_op!(SplitToSequence);
         register_op!(Sqrt);
@@ -219,16 +249,20 @@ impl OnnxOpRegistry {
         register_op!(Sum);
         register_op!(Tan);
         register_op!(Tanh);
+        register_op!(ThresholdedRelu);
         register_op!(Tile);
         register_op!(TopK);
         register_op!(Transpose);
         register_op!(Trilu);
+        register_op!(Unique);
         register_op!(Unsqueeze);
         register_op!(Where);
         register_op!(Xor);
 
         // com.microsoft ops.
+        register_op!(GroupQueryAttention);
         register_op!(MatMulNBits);
+        register_op!(SkipSimplifiedLayerNormalization);
 
         reg
     }
@@ -534,12 +568,13 @@ impl<'a> Attr<'a> {
         // The conversions here should match those used when converting
         // initializers and value types in the ONNX model loader.
         match onnx_dtype {
-            onnx::DataType::FLOAT | onnx::DataType::FLOAT16 | onnx::DataType::DOUBLE => {
-                Ok(DataType::Float)
-            }
-            onnx::DataType::INT32 | onnx::DataType::INT64 | onnx::DataType::BOOL => {
-                Ok(DataType::Int32)
-            }
+            onnx::DataType::FLOAT
+            | onnx::DataType::FLOAT16
+            | onnx::DataType::BFLOAT16
+            | onnx::DataType::DOUBLE => Ok(DataType::Float),
+            onnx::DataType::INT32 => Ok(DataType::Int32),
+            onnx::DataType::INT64 => Ok(DataType::Int64),
+            onnx::DataType::BOOL => Ok(DataType::Bool),
             onnx::DataType::INT8 => Ok(DataType::Int8),
             onnx::DataType::UINT8 => Ok(DataType::UInt8),
             _ => Err(ReadOpError::attr_error(
@@ -673,6 +708,31 @@ impl_read_op!(ArgMin, |attrs: &Attrs| {
 impl_read_op!(Asin);
 impl_read_op!(Atan);
 
+impl_read_op!(Attention, |attrs: &Attrs| {
+    let is_causal = attrs.get_as("is_causal").unwrap_or(false);
+    let q_num_heads = attrs.get_as_int("q_num_heads")?;
+    let kv_num_heads = attrs.get_as_int("kv_num_heads")?;
+    let scale = attrs.get_as("scale");
+    let softcap = attrs
+        .get_as("softcap")
+        .filter(|softcap: &f32| *softcap != 0.);
+
+    // Outputting the intermediate `Q @ K^T` values is not supported.
+    attrs.check_eq("qk_matmul_output_mode", 0)?;
+
+    // Softmax is always computed in f32.
+    attrs.check_unused::<i64>("softmax_precision")?;
+
+    Ok(ops::Attention {
+        is_causal,
+        q_num_heads,
+        kv_num_heads,
+        scale,
+        softcap,
+        flush_nans_to_zero: false,
+    })
+});
+
 impl_read_op!(AveragePool, |attrs: &Attrs| {
     let PoolAttrs {
         ceil_mode,
@@ -702,6 +762,29 @@ impl_read_op!(BatchNormalization, |attrs: &Attrs| {
     Ok(ops::BatchNormalization { epsilon })
 });
 
+#[cfg(feature = "fft")]
+impl_read_op!(BlackmanWindow, |attrs: &Attrs| {
+    attrs.check_eq("output_datatype", 1)?;
+    let periodic = attrs.get_as("periodic").unwrap_or(true);
+    Ok(ops::BlackmanWindow { periodic })
+});
+
+impl_read_op!(BitShift, |attrs: &Attrs| {
+    let direction = attrs
+        .require("direction")?
+        .as_string_enum(|dir| match dir {
+            "LEFT" => Some(BitShiftDirection::Left),
+            "RIGHT" => Some(BitShiftDirection::Right),
+            _ => None,
+        })?;
+    Ok(ops::BitShift { direction })
+});
+
+impl_read_op!(BitwiseAnd);
+impl_read_op!(BitwiseNot);
+impl_read_op!(BitwiseOr);
+impl_read_op!(BitwiseXor);
+
 impl_read_op!(Cast, |attrs: &Attrs| {
     // The "saturate" attribute only applies to FP8, which is unsupported.
     // Conversions to other types do not saturate, even if this attribute is 1.
@@ -714,6 +797,14 @@ impl_read_op!(Cast, |attrs: &Attrs| {
 impl_read_op!(CastLike);
 impl_read_op!(Ceil);
 
+impl_read_op!(Celu, |attrs: &Attrs| {
+    let alpha = attrs.get_as("alpha").unwrap_or(1.0);
+    if alpha == 0. {
+        return Err(ReadOpError::attr_error("alpha", "must be non-zero"));
+    }
+    Ok(ops::Celu { alpha })
+});
+
 impl_read_op!(Clip, |attrs: &Attrs| {
     let mut const_inputs = Vec::new();
 
@@ -727,6 +818,26 @@ impl_read_op!(Clip, |attrs: &Attrs| {
     Ok(ParsedOp::new(ops::Clip {}).with_inputs(const_inputs))
 });
 
+impl_read_op!(Col2Im, |attrs: &Attrs| {
+    let get_ints = |name| {
+        attrs
+            .get(name)
+            .map(|v| v.cast_ints())
+            .transpose()
+            .map(|v| v.unwrap_or_default())
+    };
+    Ok(ops::Col2Im {
+        dilations: get_ints("dilations")?,
+        pads: get_ints("pads")?,
+        strides: get_ints("strides")?,
+    })
+});
+
+impl_read_op!(Compress, |attrs: &Attrs| {
+    let axis = attrs.get_as_int("axis")?;
+    Ok(ops::Compress { axis })
+});
+
 impl_read_op!(Concat, |attrs: &Attrs| {
     let axis = attrs.require("axis")?.cast_int()?;
     Ok(ops::Concat { axis })
@@ -950,6 +1061,21 @@ impl_read_op!(DepthToSpace, |attrs: &Attrs| {
     Ok(ops::DepthToSpace { mode, block_size })
 });
 
+impl_read_op!(Det);
+
+#[cfg(feature = "fft")]
+impl_read_op!(DFT, |attrs: &Attrs| {
+    // In opset 20+ the axis is specified as an input rather than an attribute.
+    let axis = attrs.get_as_int("axis")?;
+    let inverse = attrs.get_as("inverse").unwrap_or(false);
+    let onesided = attrs.get_as("onesided").unwrap_or(false);
+    Ok(ops::DFT {
+        axis,
+        inverse,
+        onesided,
+    })
+});
+
 impl_read_op!(Div);
 
 #[cfg(feature = "random")]
@@ -1044,6 +1170,17 @@ impl_read_op!(GridSample, |attrs: &Attrs| {
     Ok(ops::GridSample { align_corners })
 });
 
+impl_read_op!(GroupNormalization, |attrs: &Attrs| {
+    let num_groups = attrs.require("num_groups")?.cast_int()?;
+    let epsilon = attrs.get_as("epsilon");
+    attrs.check_eq("stash_type", 1)?;
+
+    Ok(ops::GroupNormalization {
+        num_groups,
+        epsilon,
+    })
+});
+
 impl_read_op!(GRU, |attrs: &Attrs| {
     let RnnAttrs {
         direction,
@@ -1058,6 +1195,25 @@ impl_read_op!(GRU, |attrs: &Attrs| {
     })
 });
 
+#[cfg(feature = "fft")]
+impl_read_op!(HammingWindow, |attrs: &Attrs| {
+    attrs.check_eq("output_datatype", 1)?;
+    let periodic = attrs.get_as("periodic").unwrap_or(true);
+    Ok(ops::HammingWindow { periodic })
+});
+
+#[cfg(feature = "fft")]
+impl_read_op!(HannWindow, |attrs: &Attrs| {
+    attrs.check_eq("output_datatype", 1)?;
+    let periodic = attrs.get_as("periodic").unwrap_or(true);
+    Ok(ops::HannWindow { periodic })
+});
+
+impl_read_op!(Hardmax, |attrs: &Attrs| {
+    let axis = attrs.get_as_int("axis")?.unwrap_or(-1);
+    Ok(ops::Hardmax { axis })
+});
+
 impl_read_op!(HardSigmoid, |attrs: &Attrs| {
     let alpha = attrs.get_as("alpha").unwrap_or(0.2);
     let beta = attrs.get_as("beta").unwrap_or(0.5);
@@ -1178,12 +1334,68 @@ impl_read_op!(LSTM, |attrs: &Attrs| {
     })
 });
 
+impl_read_op!(LpNormalization, |attrs: &Attrs| {
+    let axis = attrs.get_as_int("axis")?.unwrap_or(-1);
+    let p = attrs.get_as_int("p")?.unwrap_or(2);
+    Ok(ops::LpNormalization { axis, p })
+});
+
+impl_read_op!(LpPool, |attrs: &Attrs| {
+    let PoolAttrs {
+        ceil_mode,
+        kernel_size,
+        padding,
+        strides,
+    } = get_common_pool_attrs(attrs)?;
+    let p = attrs.get_as_int("p")?.unwrap_or(2);
+
+    Ok(ops::LpPool {
+        ceil_mode,
+        kernel_size,
+        padding,
+        strides,
+        p,
+    })
+});
+
+impl_read_op!("com.microsoft", GroupQueryAttention, |attrs: &Attrs| {
+    let num_heads = attrs.require("num_heads")?.cast_int()?;
+    let kv_num_heads = attrs.require("kv_num_heads")?.cast_int()?;
+
+    // Zero means "unset" for `scale` and `softcap` and -1 means "unset" for
+    // `local_window_size`.
+    let scale = attrs.get_as("scale").filter(|scale: &f32| *scale != 0.);
+    let softcap = attrs
+        .get_as("softcap")
+        .filter(|softcap: &f32| *softcap != 0.);
+    let local_window_size = attrs
+        .get_as::<i64>("local_window_size")
+        .and_then(|size| usize::try_from(size).ok());
+
+    let do_rotary = attrs.get_as("do_rotary").unwrap_or(false);
+    let rotary_interleaved = attrs.get_as("rotary_interleaved").unwrap_or(false);
+
+    attrs.check_eq("smooth_softmax", 0)?;
+    attrs.check_eq("qk_output", 0)?;
+
+    Ok(ops::GroupQueryAttention {
+        num_heads,
+        kv_num_heads,
+        scale,
+        softcap,
+        local_window_size,
+        do_rotary,
+        rotary_interleaved,
+    })
+});
+
 impl_read_op!(MatMul);
 impl_read_op!(MatMulInteger);
 
 impl_read_op!("com.microsoft", MatMulNBits, |attrs: &Attrs| {
     // Spec allows any value between 2 and 8.
-    attrs.check_eq("bits", 4)?;
+    attrs.check("bits", |bits: i64| (2..=8).contains(&bits))?;
+    let bits = attrs.get_as_int("bits")?.unwrap_or(4);
 
     // These are inferred from the inputs.
     attrs.check_unused::<i64>("block_size")?;
@@ -1204,7 +1416,7 @@ impl_read_op!("com.microsoft", MatMulNBits, |attrs: &Attrs| {
 
     Ok(ops::MatMulNBits {
         accuracy_level,
-        bits: 4,
+        bits,
         block_size,
     })
 });
@@ -1236,7 +1448,7 @@ fn get_common_pool_attrs(attrs: &Attrs) -> Result<PoolAttrs, ReadOpError> {
         .get("strides")
         .map(|v| v.cast_ints())
         .transpose()?
-        .unwrap_or_default()
+        .unwrap_or_else(|| vec![1; kernel_size.len()])
         .into();
     Ok(PoolAttrs {
         ceil_mode,
@@ -1261,8 +1473,39 @@ impl_read_op!(MaxPool, |attrs: &Attrs| {
     })
 });
 
+impl_read_op!(MaxUnpool, |attrs: &Attrs| {
+    let PoolAttrs {
+        kernel_size,
+        padding,
+        strides,
+        ..
+    } = get_common_pool_attrs(attrs)?;
+    Ok(ops::MaxUnpool {
+        kernel_size,
+        padding,
+        strides,
+    })
+});
+
 impl_read_op!(Mean);
+
+impl_read_op!(MeanVarianceNormalization, |attrs: &Attrs| {
+    let axes = attrs
+        .get("axes")
+        .map(|v| v.cast_ints())
+        .transpose()?
+        .unwrap_or_else(|| [0, 2, 3].into());
+    Ok(ops::MeanVarianceNormalization { axes })
+});
+
+#[cfg(feature = "fft")]
+impl_read_op!(MelWeightMatrix, |attrs: &Attrs| {
+    attrs.check_eq("output_datatype", 1)?;
+    Ok(ops::MelWeightMatrix {})
+});
+
 impl_read_op!(Min);
+impl_read_op!(Mish);
 
 impl_read_op!(Mod, |attrs: &Attrs| {
     let fmod = attrs.get_as("fmod").unwrap_or(false);
@@ -1391,6 +1634,7 @@ macro_rules! impl_read_op_for_reduce_op {
 }
 
 impl_read_op_for_reduce_op!(ReduceL2);
+impl_read_op_for_reduce_op!(ReduceLogSumExp);
 impl_read_op_for_reduce_op!(ReduceMax);
 impl_read_op_for_reduce_op!(ReduceMean);
 impl_read_op_for_reduce_op!(ReduceMin);
@@ -1463,6 +1707,88 @@ impl_read_op!(Resize, |attrs: &Attrs| {
     })
 });
 
+impl_read_op!(ReverseSequence, |attrs: &Attrs| {
+    let batch_axis = attrs.get_as_int("batch_axis")?.unwrap_or(1);
+    let time_axis = attrs.get_as_int("time_axis")?.unwrap_or(0);
+    Ok(ops::ReverseSequence {
+        batch_axis,
+        time_axis,
+    })
+});
+
+// The ONNX operator name differs in capitalization from the RTen operator.
+impl ReadOp for ops::RmsNormalization {
+    fn id() -> OpId<'static> {
+        OpId::new("RMSNormalization")
+    }
+
+    fn read(op: &onnx::NodeProto, _ctx: &dyn OpLoadContext) -> Result<ParsedOp<Self>, ReadOpError> {
+        let attrs = Attrs::new(&op.attribute);
+        let axis = attrs.get_as_int("axis")?.unwrap_or(-1);
+        let epsilon = attrs.get_as("epsilon");
+        attrs.check_eq("stash_type", 1)?;
+
+        Ok(ParsedOp::new(ops::RmsNormalization { axis, epsilon })
+            .with_unused_attrs(attrs.unused_attrs()))
+    }
+}
+
+impl_read_op!(RoiAlign, |attrs: &Attrs| {
+    let half_pixel = attrs
+        .get("coordinate_transformation_mode")
+        .map(|v| {
+            v.as_string_enum(|mode| match mode {
+                "half_pixel" => Some(true),
+                "output_half_pixel" => Some(false),
+                _ => None,
+            })
+        })
+        .transpose()?
+        .unwrap_or(true);
+    let mode = attrs
+        .get("mode")
+        .map(|v| {
+            v.as_string_enum(|mode| match mode {
+                "avg" => Some(RoiAlignMode::Avg),
+                "max" => Some(RoiAlignMode::Max),
+                _ => None,
+            })
+        })
+        .transpose()?
+        .unwrap_or(RoiAlignMode::Avg);
+    let output_height = attrs.get_as_int("output_height")?.unwrap_or(1);
+    let output_width = attrs.get_as_int("output_width")?.unwrap_or(1);
+    let sampling_ratio = attrs.get_as_int("sampling_ratio")?.unwrap_or(0);
+    let spatial_scale = attrs.get_as("spatial_scale").unwrap_or(1.0);
+
+    Ok(ops::RoiAlign {
+        opts: RoiAlignOptions {
+            mode,
+            output_height,
+            output_width,
+            sampling_ratio,
+            spatial_scale,
+            half_pixel,
+        },
+    })
+});
+
+impl_read_op!(RotaryEmbedding, |attrs: &Attrs| {
+    let interleaved = attrs.get_as("interleaved").unwrap_or(false);
+
+    // Zero means "unset" for these attributes.
+    let num_heads = attrs.get_as_int("num_heads")?.filter(|n| *n != 0);
+    let rotary_embedding_dim = attrs
+        .get_as_int("rotary_embedding_dim")?
+        .filter(|dim| *dim != 0);
+
+    Ok(ops::RotaryEmbedding {
+        interleaved,
+        num_heads,
+        rotary_embedding_dim,
+    })
+});
+
 impl_read_op!(Round);
 
 fn convert_scatter_reduction(
@@ -1492,11 +1818,22 @@ impl_read_op!(ScatterND, |attrs: &Attrs| {
     Ok(ops::ScatterND { reduction })
 });
 
+impl_read_op!(Selu, |attrs: &Attrs| {
+    let alpha = attrs.get_as("alpha").unwrap_or(1.6732632);
+    let gamma = attrs.get_as("gamma").unwrap_or(1.050701);
+    Ok(ops::Selu { alpha, gamma })
+});
+
 impl_read_op!(SequenceAt);
 impl_read_op!(SequenceConstruct);
 
 impl_read_op!(SequenceEmpty, |attrs: &Attrs| {
-    let dtype = attrs.get("dtype").map(|v| v.as_dtype()).transpose()?;
+    // Sequence operators don't support i64 or bool tensors, so these are
+    // converted to i32 when inserted into a sequence.
+    let dtype = attrs
+        .get("dtype")
+        .map(|v| v.as_dtype().map(|dtype| dtype.narrow_int()))
+        .transpose()?;
     Ok(ops::SequenceEmpty { dtype })
 });
 
@@ -1510,10 +1847,25 @@ impl_read_op!(Shape, |attrs: &Attrs| {
     Ok(ops::Shape { start, end })
 });
 
+impl_read_op!(Shrink, |attrs: &Attrs| {
+    let bias = attrs.get_as("bias").unwrap_or(0.);
+    let lambd = attrs.get_as("lambd").unwrap_or(0.5);
+    Ok(ops::Shrink { bias, lambd })
+});
+
 impl_read_op!(Sigmoid);
 impl_read_op!(Sign);
 impl_read_op!(Sin);
 impl_read_op!(Size);
+
+impl_read_op!(
+    "com.microsoft",
+    SkipSimplifiedLayerNormalization,
+    |attrs: &Attrs| {
+        let epsilon = attrs.get_as("epsilon");
+        Ok(ops::SkipSimplifiedLayerNormalization { epsilon })
+    }
+);
 impl_read_op!(Slice);
 
 impl_read_op!(Softmax, |attrs: &Attrs| {
@@ -1526,6 +1878,11 @@ impl_read_op!(Softmax, |attrs: &Attrs| {
 
 impl_read_op!(Softplus);
 
+impl_read_op!(SpaceToDepth, |attrs: &Attrs| {
+    let block_size = attrs.require("blocksize")?.cast_int()?;
+    Ok(ops::SpaceToDepth { block_size })
+});
+
 impl_read_op!(Split, |attrs: &Attrs| {
     let axis = attrs.get_as_int("axis")?.unwrap_or(0);
     let num_outputs = attrs.get_as_int("num_outputs")?;
@@ -1564,6 +1921,12 @@ impl_read_op!(Sub);
 impl_read_op!(Sum);
 impl_read_op!(Tan);
 impl_read_op!(Tanh);
+
+impl_read_op!(ThresholdedRelu, |attrs: &Attrs| {
+    let alpha = attrs.get_as("alpha").unwrap_or(1.0);
+    Ok(ops::ThresholdedRelu { alpha })
+});
+
 impl_read_op!(Tile);
 
 impl_read_op!(TopK, |attrs: &Attrs| {
@@ -1587,6 +1950,12 @@ impl_read_op!(Trilu, |attrs: &Attrs| {
     Ok(ops::Trilu { upper })
 });
 
+impl_read_op!(Unique, |attrs: &Attrs| {
+    let axis = attrs.get_as_int("axis")?;
+    let sorted = attrs.get_as("sorted").unwrap_or(true);
+    Ok(ops::Unique { axis, sorted })
+});
+
 impl_read_op!(Unsqueeze, |attrs: &Attrs| {
     let mut const_inputs = Vec::new();
     if let Some(axes) = attrs.get("axes") {
@@ -1606,7 +1975,12 @@ mod tests {
     use super::{ConstInput, OnnxOpRegistry, OpLoadContext, ReadOpError};
     use crate::graph::Graph;
     use crate::model::onnx_builder::{NodeProtoExt, TensorData, create_node, create_tensor};
-    use crate::ops::{ArgMax, ConstantOfShape, Conv, Padding};
+    use crate::ops::{
+        ArgMax, Attention, Celu, Col2Im, ConstantOfShape, Conv, GroupNormalization,
+        GroupQueryAttention, LpPool, MatMulNBits, MaxUnpool, MeanVarianceNormalization, Padding,
+        RmsNormalization, RoiAlign, RoiAlignMode, RotaryEmbedding,
+        SkipSimplifiedLayerNormalization,
+    };
     use crate::value::Scalar;
 
     struct FakeOpLoadContext;
@@ -1683,6 +2057,197 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_read_celu() {
+        let reg = OnnxOpRegistry::with_all_ops();
+
+        let node = create_node("Celu").with_attr("alpha", 2.);
+        let op = reg.read_op(&node, &FakeOpLoadContext).unwrap().op;
+        assert_eq!(op.downcast_ref::<Celu>().unwrap().alpha, 2.);
+
+        let node = create_node("Celu").with_attr("alpha", 0.);
+        let op = reg.read_op(&node, &FakeOpLoadContext);
+        assert!(matches!(op, Err(ReadOpError::AttrError { attr, .. }) if attr == "alpha"));
+    }
+
+    #[test]
+    fn test_matmul_nbits_bits() {
+        let reg = OnnxOpRegistry::with_all_ops();
+
+        for bits in 2..=8 {
+            let node = create_node("MatMulNBits")
+                .with_domain("com.microsoft")
+                .with_attr("bits", bits)
+                .with_attr("block_size", 32);
+            let op = reg.read_op(&node, &FakeOpLoadContext).unwrap().op;
+            let op = op.downcast_ref::<MatMulNBits>().unwrap();
+            assert_eq!(i64::from(op.bits), bits);
+        }
+
+        let node = create_node("MatMulNBits")
+            .with_domain("com.microsoft")
+            .with_attr("bits", 16)
+            .with_attr("block_size", 32);
+        assert!(reg.read_op(&node, &FakeOpLoadContext).is_err());
+    }
+
+    #[test]
+    fn test_read_attention() {
+        let reg = OnnxOpRegistry::with_all_ops();
+        let node = create_node("Attention")
+            .with_attr("is_causal", 1)
+            .with_attr("q_num_heads", 8)
+            .with_attr("kv_num_heads", 2)
+            .with_attr("softcap", 0.);
+
+        let op = reg.read_op(&node, &FakeOpLoadContext).unwrap().op;
+        let attention = op.downcast_ref::<Attention>().unwrap();
+        assert!(attention.is_causal);
+        assert_eq!(attention.q_num_heads, Some(8));
+        assert_eq!(attention.kv_num_heads, Some(2));
+        assert_eq!(attention.scale, None);
+        assert_eq!(attention.softcap, None);
+
+        // Outputting QK matmul values is not supported.
+        let node = create_node("Attention").with_attr("qk_matmul_output_mode", 1);
+        assert!(reg.read_op(&node, &FakeOpLoadContext).is_err());
+    }
+
+    #[test]
+    fn test_read_llm_ops() {
+        let reg = OnnxOpRegistry::with_all_ops();
+
+        let node = create_node("GroupQueryAttention")
+            .with_domain("com.microsoft")
+            .with_attr("num_heads", 8)
+            .with_attr("kv_num_heads", 2)
+            .with_attr("scale", 0.)
+            .with_attr("local_window_size", -1)
+            .with_attr("do_rotary", 1);
+        let op = reg.read_op(&node, &FakeOpLoadContext).unwrap().op;
+        let gqa = op.downcast_ref::<GroupQueryAttention>().unwrap();
+        assert_eq!(gqa.num_heads, 8);
+        assert_eq!(gqa.kv_num_heads, 2);
+        assert_eq!(gqa.scale, None);
+        assert_eq!(gqa.local_window_size, None);
+        assert!(gqa.do_rotary);
+        assert!(!gqa.rotary_interleaved);
+
+        // Heads are required.
+        let node = create_node("GroupQueryAttention").with_domain("com.microsoft");
+        assert!(reg.read_op(&node, &FakeOpLoadContext).is_err());
+
+        let node = create_node("RotaryEmbedding")
+            .with_attr("interleaved", 1)
+            .with_attr("num_heads", 4)
+            .with_attr("rotary_embedding_dim", 0);
+        let op = reg.read_op(&node, &FakeOpLoadContext).unwrap().op;
+        let rope = op.downcast_ref::<RotaryEmbedding>().unwrap();
+        assert!(rope.interleaved);
+        assert_eq!(rope.num_heads, Some(4));
+        assert_eq!(rope.rotary_embedding_dim, None);
+
+        let node = create_node("RMSNormalization").with_attr("epsilon", 1e-6);
+        let op = reg.read_op(&node, &FakeOpLoadContext).unwrap().op;
+        let rms_norm = op.downcast_ref::<RmsNormalization>().unwrap();
+        assert_eq!(rms_norm.axis, -1);
+        assert_eq!(rms_norm.epsilon, Some(1e-6));
+
+        let node = create_node("SkipSimplifiedLayerNormalization")
+            .with_domain("com.microsoft")
+            .with_attr("epsilon", 1e-6);
+        let op = reg.read_op(&node, &FakeOpLoadContext).unwrap().op;
+        let skip_norm = op
+            .downcast_ref::<SkipSimplifiedLayerNormalization>()
+            .unwrap();
+        assert_eq!(skip_norm.epsilon, Some(1e-6));
+    }
+
+    #[test]
+    fn test_read_vision_ops() {
+        let reg = OnnxOpRegistry::with_all_ops();
+
+        let node = create_node("GroupNormalization")
+            .with_attr("num_groups", 4)
+            .with_attr("epsilon", 1e-6);
+        let op = reg.read_op(&node, &FakeOpLoadContext).unwrap().op;
+        let group_norm = op.downcast_ref::<GroupNormalization>().unwrap();
+        assert_eq!(group_norm.num_groups, 4);
+        assert_eq!(group_norm.epsilon, Some(1e-6));
+
+        // `num_groups` is required.
+        let node = create_node("GroupNormalization");
+        assert!(reg.read_op(&node, &FakeOpLoadContext).is_err());
+
+        let node = create_node("MeanVarianceNormalization");
+        let op = reg.read_op(&node, &FakeOpLoadContext).unwrap().op;
+        let mvn = op.downcast_ref::<MeanVarianceNormalization>().unwrap();
+        assert_eq!(mvn.axes, [0, 2, 3]);
+
+        let node = create_node("LpPool").with_attr("kernel_shape", vec![2, 2]);
+        let op = reg.read_op(&node, &FakeOpLoadContext).unwrap().op;
+        let lp_pool = op.downcast_ref::<LpPool>().unwrap();
+        assert_eq!(lp_pool.p, 2);
+        assert_eq!(lp_pool.strides.as_slice(), [1, 1]);
+
+        let node = create_node("MaxUnpool")
+            .with_attr("kernel_shape", vec![2, 2])
+            .with_attr("strides", vec![2, 2]);
+        let op = reg.read_op(&node, &FakeOpLoadContext).unwrap().op;
+        let max_unpool = op.downcast_ref::<MaxUnpool>().unwrap();
+        assert_eq!(max_unpool.kernel_size.as_slice(), [2, 2]);
+        assert_eq!(max_unpool.padding, Padding::Fixed([0, 0, 0, 0].into()));
+
+        let node = create_node("Col2Im").with_attr("strides", vec![2, 2]);
+        let op = reg.read_op(&node, &FakeOpLoadContext).unwrap().op;
+        let col2im = op.downcast_ref::<Col2Im>().unwrap();
+        assert_eq!(col2im.strides, [2, 2]);
+        assert!(col2im.dilations.is_empty());
+
+        let node = create_node("RoiAlign")
+            .with_attr(
+                "coordinate_transformation_mode",
+                "output_half_pixel".to_string(),
+            )
+            .with_attr("mode", "max".to_string())
+            .with_attr("output_height", 7)
+            .with_attr("spatial_scale", 0.25);
+        let op = reg.read_op(&node, &FakeOpLoadContext).unwrap().op;
+        let roi_align = op.downcast_ref::<RoiAlign>().unwrap();
+        assert_eq!(roi_align.opts.mode, RoiAlignMode::Max);
+        assert!(!roi_align.opts.half_pixel);
+        assert_eq!(roi_align.opts.output_height, 7);
+        assert_eq!(roi_align.opts.output_width, 1);
+        assert_eq!(roi_align.opts.spatial_scale, 0.25);
+
+        let node = create_node("RoiAlign").with_attr("mode", "min".to_string());
+        assert!(reg.read_op(&node, &FakeOpLoadContext).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "fft")]
+    fn test_read_fft_ops() {
+        use crate::ops::{DFT, HannWindow};
+
+        let reg = OnnxOpRegistry::with_all_ops();
+
+        let node = create_node("DFT").with_attr("inverse", 1);
+        let op = reg.read_op(&node, &FakeOpLoadContext).unwrap().op;
+        let dft = op.downcast_ref::<DFT>().unwrap();
+        assert_eq!(dft.axis, None);
+        assert!(dft.inverse);
+        assert!(!dft.onesided);
+
+        let node = create_node("HannWindow").with_attr("periodic", 0);
+        let op = reg.read_op(&node, &FakeOpLoadContext).unwrap().op;
+        let window = op.downcast_ref::<HannWindow>().unwrap();
+        assert!(!window.periodic);
+
+        // Only float outputs are supported.
+        let node = create_node("HannWindow").with_attr("output_datatype", 10);
+        assert!(reg.read_op(&node, &FakeOpLoadContext).is_err());
+    }
+
     #[test]
     fn test_conv_op_defaults() {
         let reg = OnnxOpRegistry::with_all_ops();
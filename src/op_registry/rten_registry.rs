@@ -4,15 +4,17 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use rten_model_file::schema as sg;
+use rten_shape_inference::{SymExpr, Symbol};
 use smallvec::{SmallVec, smallvec};
 
 use super::ReadOpError;
 use crate::graph::Graph;
 use crate::operator::Operator;
 use crate::ops;
+use crate::ops::transform_inputs::{TransformInputs, TransformInputsBuilder};
 use crate::ops::{
-    BoxOrder, CoordTransformMode, DepthToSpaceMode, Direction, NearestMode, PadMode, Padding,
-    ResizeMode, ScatterReduction,
+    BitShiftDirection, BoxOrder, CoordTransformMode, DepthToSpaceMode, Direction, NearestMode,
+    PadMode, Padding, ResizeMode, RoiAlignMode, RoiAlignOptions, ScatterReduction, SymbolInfo,
 };
 use crate::value::{DataType, Scalar};
 
@@ -20,6 +22,10 @@ use crate::value::{DataType, Scalar};
 pub trait OpLoadContext {
     /// Deserialize a graph definition.
     fn load_graph(&self, graph: sg::Graph) -> Result<Graph, ReadOpError>;
+
+    /// Deserialize an operator which is part of another operator's
+    /// attributes.
+    fn read_op(&self, op: &sg::OperatorNode) -> ReadOpResult;
 }
 
 /// Deserialize operators from .rten model files.
@@ -43,9 +49,15 @@ impl RtenOpRegistry {
 
     /// Deserialize an operator from a model file using the operators in the
     /// registry.
+    ///
+    /// Fused operators created by graph optimization are always available,
+    /// as they can appear in optimized models regardless of which operators
+    /// the original model used.
     pub(crate) fn read_op(&self, op: &sg::OperatorNode, ctx: &dyn OpLoadContext) -> ReadOpResult {
         self.ops
             .get(&op.type_())
+            .copied()
+            .or_else(|| fused_op_reader(op.type_()))
             .ok_or_else(|| ReadOpError::OperatorUnavailable {
                 name: op.type_().variant_name().map(|s| s.to_string()),
             })
@@ -100,12 +112,22 @@ impl RtenOpRegistry {
         register_op!(ArgMin);
         register_op!(Asin);
         register_op!(Atan);
+        register_op!(Attention);
         register_op!(AveragePool);
         register_op!(BatchNormalization);
+        register_op!(BitShift);
+        register_op!(BitwiseAnd);
+        register_op!(BitwiseNot);
+        register_op!(BitwiseOr);
+        register_op!(BitwiseXor);
+        register_op!(BlackmanWindow, feature = "fft");
         register_op!(Cast);
         register_op!(CastLike);
         register_op!(Ceil);
+        register_op!(Celu);
         register_op!(Clip);
+        register_op!(Col2Im);
+        register_op!(Compress);
         register_op!(Concat);
         register_op!(ConcatFromSequence);
         register_op!(Conv);
@@ -116,6 +138,8 @@ impl RtenOpRegistry {
         register_op!(CumSum);
         register_op!(DequantizeLinear);
         register_op!(DepthToSpace);
+        register_op!(Det);
+        register_op!(DFT, feature = "fft");
         register_op!(Div);
         register_op!(Dropout, feature = "random");
         register_op!(DynamicQuantizeLinear);
@@ -138,7 +162,12 @@ impl RtenOpRegistry {
         register_op!(Greater);
         register_op!(GreaterOrEqual);
         register_op!(GridSample);
+        register_op!(GroupNormalization);
+        register_op!(GroupQueryAttention);
         register_op!(GRU);
+        register_op!(Hardmax);
+        register_op!(HammingWindow, feature = "fft");
+        register_op!(HannWindow, feature = "fft");
         register_op!(HardSigmoid);
         register_op!(HardSwish);
         register_op!(Identity);
@@ -154,12 +183,19 @@ impl RtenOpRegistry {
         register_op!(LogSoftmax);
         register_op!(Loop);
         register_op!(LSTM);
+        register_op!(LpNormalization);
+        register_op!(LpPool);
         register_op!(MatMul);
         register_op!(MatMulInteger);
+        register_op!(MatMulNBits);
         register_op!(Max);
         register_op!(MaxPool);
+        register_op!(MaxUnpool);
         register_op!(Mean);
+        register_op!(MeanVarianceNormalization);
+        register_op!(MelWeightMatrix, feature = "fft");
         register_op!(Min);
+        register_op!(Mish);
         register_op!(Mod);
         register_op!(Mul);
         register_op!(Neg);
@@ -179,6 +215,7 @@ impl RtenOpRegistry {
         register_op!(Range);
         register_op!(Reciprocal);
         register_op!(ReduceL2);
+        register_op!(ReduceLogSumExp);
         register_op!(ReduceMax);
         register_op!(ReduceMean);
         register_op!(ReduceMin);
@@ -188,9 +225,13 @@ impl RtenOpRegistry {
         register_op!(Relu);
         register_op!(Reshape);
         register_op!(Resize);
+        register_op!(ReverseSequence);
+        register_op!(RoiAlign);
+        register_op!(RotaryEmbedding);
         register_op!(Round);
         register_op!(ScatterElements);
         register_op!(ScatterND);
+        register_op!(Selu);
         register_op!(SequenceAt);
         register_op!(SequenceEmpty);
         register_op!(SequenceErase);
@@ -198,13 +239,16 @@ impl RtenOpRegistry {
         register_op!(SequenceInsert);
         register_op!(SequenceLength);
         register_op!(Shape);
+        register_op!(Shrink);
         register_op!(Sigmoid);
         register_op!(Sign);
         register_op!(Sin);
         register_op!(Size);
+        register_op!(SkipSimplifiedLayerNormalization);
         register_op!(Slice);
         register_op!(Softmax);
         register_op!(Softplus);
+        register_op!(SpaceToDepth);
         register_op!(Split);
         register_op!(SplitToSequence);
         register_op!(Sqrt);
@@ -214,10 +258,12 @@ impl RtenOpRegistry {
         register_op!(Sum);
         register_op!(Tan);
         register_op!(Tanh);
+        register_op!(ThresholdedRelu);
         register_op!(Tile);
         register_op!(TopK);
         register_op!(Transpose);
         register_op!(Trilu);
+        register_op!(Unique);
         register_op!(Unsqueeze);
         register_op!(Where);
         register_op!(Xor);
@@ -226,12 +272,36 @@ impl RtenOpRegistry {
     }
 }
 
+/// Return the deserialization function for a fused operator.
+fn fused_op_reader(op_type: sg::OperatorType) -> Option<&'static ReadOpFunction> {
+    let read_fn: &'static ReadOpFunction = match op_type {
+        sg::OperatorType::AddSoftmax => &ops::AddSoftmax::read_boxed,
+        sg::OperatorType::ComputeShape => &ops::ComputeShape::read_boxed,
+        sg::OperatorType::FusedMatMul => &ops::FusedMatMul::read_boxed,
+        sg::OperatorType::GroupedQueryAttentionMatMul => {
+            &ops::GroupedQueryAttentionMatMul::read_boxed
+        }
+        sg::OperatorType::MatMulIntegerToFloat => &ops::MatMulIntegerToFloat::read_boxed,
+        sg::OperatorType::RepeatInterleave => &ops::RepeatInterleave::read_boxed,
+        sg::OperatorType::RmsNormalization => &ops::RmsNormalization::read_boxed,
+        sg::OperatorType::Silu => &ops::Silu::read_boxed,
+        sg::OperatorType::Swish => &ops::Swish::read_boxed,
+        sg::OperatorType::TransformInputs => &TransformInputs::read_boxed,
+        _ => return None,
+    };
+    Some(read_fn)
+}
+
 pub fn convert_dtype(attr: &'static str, dtype: sg::DataType) -> Result<DataType, ReadOpError> {
     match dtype {
         sg::DataType::Int32 => Ok(DataType::Int32),
         sg::DataType::Float => Ok(DataType::Float),
         sg::DataType::UInt8 => Ok(DataType::UInt8),
         sg::DataType::Int8 => Ok(DataType::Int8),
+        sg::DataType::Float16 => Ok(DataType::Float16),
+        sg::DataType::BFloat16 => Ok(DataType::BFloat16),
+        sg::DataType::Int64 => Ok(DataType::Int64),
+        sg::DataType::Bool => Ok(DataType::Bool),
         _ => Err(ReadOpError::attr_error(attr, "unknown value")),
     }
 }
@@ -399,11 +469,34 @@ macro_rules! impl_read_op {
 impl_read_op!(Abs);
 impl_read_op!(Acos);
 impl_read_op!(Add);
+impl_read_op!(
+    AddSoftmax,
+    attrs_as_softmax_attrs,
+    |attrs: sg::SoftmaxAttrs| {
+        Ok(ops::AddSoftmax {
+            flush_nans_to_zero: attrs.flush_nans_to_zero(),
+        })
+    }
+);
 impl_read_op!(And);
 impl_read_op!(ArgMax, attrs_as_arg_max_attrs, reduce_axis);
 impl_read_op!(ArgMin, attrs_as_arg_max_attrs, reduce_axis);
 impl_read_op!(Asin);
 impl_read_op!(Atan);
+impl_read_op!(
+    Attention,
+    attrs_as_attention_attrs,
+    |attrs: sg::AttentionAttrs| {
+        Ok(ops::Attention {
+            is_causal: attrs.is_causal(),
+            q_num_heads: attrs.q_num_heads().map(|n| n as usize),
+            kv_num_heads: attrs.kv_num_heads().map(|n| n as usize),
+            scale: attrs.scale(),
+            softcap: attrs.softcap(),
+            flush_nans_to_zero: attrs.flush_nans_to_zero(),
+        })
+    }
+);
 impl_read_op!(
     AveragePool,
     attrs_as_average_pool_attrs,
@@ -433,6 +526,35 @@ impl_read_op!(
         })
     }
 );
+impl_read_op!(
+    BitShift,
+    attrs_as_bit_shift_attrs,
+    |attrs: sg::BitShiftAttrs| {
+        let direction = match attrs.direction() {
+            sg::BitShiftDirection::Left => BitShiftDirection::Left,
+            sg::BitShiftDirection::Right => BitShiftDirection::Right,
+            _ => {
+                return Err(ReadOpError::attr_error("direction", "unknown value"));
+            }
+        };
+        Ok(ops::BitShift { direction })
+    }
+);
+impl_read_op!(BitwiseAnd);
+impl_read_op!(BitwiseNot);
+impl_read_op!(BitwiseOr);
+impl_read_op!(BitwiseXor);
+#[cfg(feature = "fft")]
+impl_read_op!(
+    BlackmanWindow,
+    attrs_as_window_attrs,
+    |attrs: sg::WindowAttrs| {
+        Ok(ops::BlackmanWindow {
+            periodic: attrs.periodic(),
+        })
+    }
+);
+
 impl_read_op!(Cast, attrs_as_cast_attrs, |attrs: sg::CastAttrs| {
     let to = convert_dtype("to", attrs.to())?;
     Ok(ops::Cast { to })
@@ -443,7 +565,90 @@ impl_read_op!(
     |_attrs: sg::CastLikeAttrs| { Ok(ops::CastLike {}) }
 );
 impl_read_op!(Ceil);
+impl_read_op!(Celu, attrs_as_celu_attrs, |attrs: sg::CeluAttrs| {
+    let alpha = attrs.alpha();
+    if alpha == 0. {
+        return Err(ReadOpError::attr_error("alpha", "must be non-zero"));
+    }
+    Ok(ops::Celu { alpha })
+});
 impl_read_op!(Clip);
+
+impl ReadOp for ops::ComputeShape {
+    fn op_type() -> sg::OperatorType {
+        sg::OperatorType::ComputeShape
+    }
+
+    fn read(op: &sg::OperatorNode, _ctx: &dyn OpLoadContext) -> Result<Self, ReadOpError> {
+        let attrs = op
+            .attrs_as_compute_shape_attrs()
+            .ok_or(ReadOpError::AttrsMissingError)?;
+
+        let symbols = attrs
+            .symbols()
+            .map(|symbols| {
+                symbols
+                    .iter()
+                    .map(|sym| SymbolInfo {
+                        name: sym.name().to_string(),
+                        input: sym.input(),
+                        axis: sym.axis(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        // Evaluate the postfix expression sequence. This leaves one
+        // expression per output dimension on the stack.
+        let invalid_shape = || ReadOpError::attr_error("shape", "invalid expression");
+        let mut shape: Vec<SymExpr> = Vec::new();
+        for term in attrs.shape().iter().flatten() {
+            let expr = match term.op() {
+                sg::SymExprOp::Value => SymExpr::Value(term.value()),
+                sg::SymExprOp::Var => SymExpr::Var(Arc::new(Symbol {
+                    name: term.name().ok_or_else(invalid_shape)?.to_string(),
+                    positive: term.positive(),
+                })),
+                sg::SymExprOp::Neg => SymExpr::Neg(shape.pop().ok_or_else(invalid_shape)?.into()),
+                binary_op => {
+                    let rhs = Arc::new(shape.pop().ok_or_else(invalid_shape)?);
+                    let lhs = Arc::new(shape.pop().ok_or_else(invalid_shape)?);
+                    match binary_op {
+                        sg::SymExprOp::Add => SymExpr::Add(lhs, rhs),
+                        sg::SymExprOp::Sub => SymExpr::Sub(lhs, rhs),
+                        sg::SymExprOp::Mul => SymExpr::Mul(lhs, rhs),
+                        sg::SymExprOp::Div => SymExpr::Div(lhs, rhs),
+                        sg::SymExprOp::DivCeil => SymExpr::DivCeil(lhs, rhs),
+                        sg::SymExprOp::Max => SymExpr::Max(lhs, rhs),
+                        sg::SymExprOp::Min => SymExpr::Min(lhs, rhs),
+                        sg::SymExprOp::Broadcast => SymExpr::Broadcast(lhs, rhs),
+                        _ => return Err(invalid_shape()),
+                    }
+                }
+            };
+            shape.push(expr);
+        }
+
+        Ok(ops::ComputeShape { symbols, shape })
+    }
+}
+
+impl_read_op!(Col2Im, attrs_as_col2_im_attrs, |attrs: sg::Col2ImAttrs| {
+    Ok(ops::Col2Im {
+        dilations: opt_vec_from_attr(attrs.dilations()).unwrap_or_default(),
+        pads: opt_vec_from_attr(attrs.pads()).unwrap_or_default(),
+        strides: opt_vec_from_attr(attrs.strides()).unwrap_or_default(),
+    })
+});
+impl_read_op!(
+    Compress,
+    attrs_as_compress_attrs,
+    |attrs: sg::CompressAttrs| {
+        Ok(ops::Compress {
+            axis: attrs.axis().map(|axis| axis as isize),
+        })
+    }
+);
 impl_read_op!(Concat, attrs_as_concat_attrs, axis);
 impl_read_op!(
     ConcatFromSequence,
@@ -526,6 +731,16 @@ impl_read_op!(
         Ok(ops::DepthToSpace { mode, block_size })
     }
 );
+impl_read_op!(Det);
+#[cfg(feature = "fft")]
+impl_read_op!(DFT, attrs_as_dftattrs, |attrs: sg::DFTAttrs| {
+    Ok(ops::DFT {
+        axis: attrs.axis().map(|axis| axis as isize),
+        inverse: attrs.inverse(),
+        onesided: attrs.onesided(),
+    })
+});
+
 impl_read_op!(Div);
 
 #[cfg(feature = "random")]
@@ -565,6 +780,15 @@ impl_read_op!(
 );
 impl_read_op!(Flatten, attrs_as_flatten_attrs, axis);
 impl_read_op!(Floor);
+impl_read_op!(
+    FusedMatMul,
+    attrs_as_fused_mat_mul_attrs,
+    |attrs: sg::FusedMatMulAttrs| {
+        Ok(ops::FusedMatMul {
+            alpha: attrs.alpha(),
+        })
+    }
+);
 impl_read_op!(Gather, attrs_as_gather_attrs, axis);
 impl_read_op!(GatherElements, attrs_as_gather_attrs, axis);
 impl_read_op!(
@@ -610,6 +834,42 @@ impl_read_op!(
         })
     }
 );
+impl_read_op!(
+    GroupNormalization,
+    attrs_as_group_normalization_attrs,
+    |attrs: sg::GroupNormalizationAttrs| {
+        Ok(ops::GroupNormalization {
+            num_groups: attrs.num_groups() as usize,
+            epsilon: attrs.epsilon(),
+        })
+    }
+);
+impl_read_op!(
+    GroupQueryAttention,
+    attrs_as_group_query_attention_attrs,
+    |attrs: sg::GroupQueryAttentionAttrs| {
+        Ok(ops::GroupQueryAttention {
+            num_heads: attrs.num_heads() as usize,
+            kv_num_heads: attrs.kv_num_heads() as usize,
+            scale: attrs.scale(),
+            softcap: attrs.softcap(),
+            local_window_size: attrs.local_window_size().map(|size| size as usize),
+            do_rotary: attrs.do_rotary(),
+            rotary_interleaved: attrs.rotary_interleaved(),
+        })
+    }
+);
+impl_read_op!(
+    GroupedQueryAttentionMatMul,
+    attrs_as_grouped_query_attention_mat_mul_attrs,
+    |attrs: sg::GroupedQueryAttentionMatMulAttrs| {
+        Ok(ops::GroupedQueryAttentionMatMul {
+            repeats: attrs.repeats() as usize,
+            alpha: attrs.alpha(),
+            transpose_rhs: attrs.transpose_rhs(),
+        })
+    }
+);
 impl_read_op!(GRU, attrs_as_gruattrs, |attrs: sg::GRUAttrs| {
     let hidden_size = attrs.hidden_size() as usize;
     let direction = match attrs.direction() {
@@ -625,6 +885,29 @@ impl_read_op!(GRU, attrs_as_gruattrs, |attrs: sg::GRUAttrs| {
         linear_before_reset: attrs.linear_before_reset(),
     })
 });
+#[cfg(feature = "fft")]
+impl_read_op!(
+    HammingWindow,
+    attrs_as_window_attrs,
+    |attrs: sg::WindowAttrs| {
+        Ok(ops::HammingWindow {
+            periodic: attrs.periodic(),
+        })
+    }
+);
+
+#[cfg(feature = "fft")]
+impl_read_op!(
+    HannWindow,
+    attrs_as_window_attrs,
+    |attrs: sg::WindowAttrs| {
+        Ok(ops::HannWindow {
+            periodic: attrs.periodic(),
+        })
+    }
+);
+
+impl_read_op!(Hardmax, attrs_as_hardmax_attrs, axis);
 impl_read_op!(
     HardSigmoid,
     attrs_as_hard_sigmoid_attrs,
@@ -734,8 +1017,62 @@ impl_read_op!(LSTM, attrs_as_lstmattrs, |attrs: sg::LSTMAttrs| {
         hidden_size,
     })
 });
+impl_read_op!(
+    LpNormalization,
+    attrs_as_lp_normalization_attrs,
+    |attrs: sg::LpNormalizationAttrs| {
+        Ok(ops::LpNormalization {
+            axis: attrs.axis() as isize,
+            p: attrs.p(),
+        })
+    }
+);
+impl_read_op!(LpPool, attrs_as_lp_pool_attrs, |attrs: sg::LpPoolAttrs| {
+    let kernel_size: SmallVec<_> = attrs.kernel_size().iter().map(|x| x as usize).collect();
+    let padding = padding_from_attrs(attrs.auto_pad(), attrs.pads());
+    let strides = attrs
+        .strides()
+        .map(|stride| stride.iter().map(|x| x as usize).collect())
+        .unwrap_or(std::iter::repeat(1).take(kernel_size.len()).collect());
+
+    Ok(ops::LpPool {
+        kernel_size,
+        padding,
+        strides,
+        ceil_mode: attrs.ceil_mode(),
+        p: attrs.p(),
+    })
+});
 impl_read_op!(MatMul);
 impl_read_op!(MatMulInteger);
+
+impl ReadOp for ops::MatMulIntegerToFloat {
+    fn op_type() -> sg::OperatorType {
+        sg::OperatorType::MatMulIntegerToFloat
+    }
+
+    fn read(_op: &sg::OperatorNode, _ctx: &dyn OpLoadContext) -> Result<Self, ReadOpError> {
+        Ok(ops::MatMulIntegerToFloat::default())
+    }
+}
+
+impl_read_op!(
+    MatMulNBits,
+    attrs_as_mat_mul_nbits_attrs,
+    |attrs: sg::MatMulNBitsAttrs| {
+        // Values of ONNX Runtime's `accuracy_level` attribute.
+        let accuracy_level = if attrs.accuracy_level() <= 3 {
+            ops::AccuracyLevel::Float
+        } else {
+            ops::AccuracyLevel::Int8
+        };
+        Ok(ops::MatMulNBits {
+            bits: attrs.bits(),
+            block_size: attrs.block_size() as usize,
+            accuracy_level,
+        })
+    }
+);
 impl_read_op!(Max);
 impl_read_op!(
     MaxPool,
@@ -756,8 +1093,42 @@ impl_read_op!(
         })
     }
 );
+impl_read_op!(
+    MaxUnpool,
+    attrs_as_max_pool_attrs,
+    |attrs: sg::MaxPoolAttrs| {
+        let kernel_size: SmallVec<_> = attrs.kernel_size().iter().map(|x| x as usize).collect();
+        let padding = padding_from_attrs(attrs.auto_pad(), attrs.pads());
+        let strides = attrs
+            .strides()
+            .map(|stride| stride.iter().map(|x| x as usize).collect())
+            .unwrap_or(std::iter::repeat(1).take(kernel_size.len()).collect());
+
+        Ok(ops::MaxUnpool {
+            kernel_size,
+            padding,
+            strides,
+        })
+    }
+);
 impl_read_op!(Mean);
+impl_read_op!(
+    MeanVarianceNormalization,
+    attrs_as_mean_variance_normalization_attrs,
+    |attrs: sg::MeanVarianceNormalizationAttrs| {
+        let axes = attrs
+            .axes()
+            .map(|axes| axes.iter().collect())
+            .unwrap_or_default();
+        Ok(ops::MeanVarianceNormalization { axes })
+    }
+);
+
+#[cfg(feature = "fft")]
+impl_read_op!(MelWeightMatrix);
+
 impl_read_op!(Min);
+impl_read_op!(Mish);
 impl_read_op!(Mod, attrs_as_mod_attrs, |attrs: sg::ModAttrs| {
     Ok(ops::Mod { fmod: attrs.fmod() })
 });
@@ -883,6 +1254,7 @@ impl_read_op!(
 impl_read_op!(Range);
 impl_read_op!(Reciprocal);
 impl_read_op!(ReduceL2, attrs_as_reduce_mean_attrs, reduce_axes);
+impl_read_op!(ReduceLogSumExp, attrs_as_reduce_mean_attrs, reduce_axes);
 impl_read_op!(ReduceMax, attrs_as_reduce_mean_attrs, reduce_axes);
 impl_read_op!(ReduceMean, attrs_as_reduce_mean_attrs, reduce_axes);
 impl_read_op!(ReduceMin, attrs_as_reduce_mean_attrs, reduce_axes);
@@ -890,6 +1262,16 @@ impl_read_op!(ReduceProd, attrs_as_reduce_mean_attrs, reduce_axes);
 impl_read_op!(ReduceSum, attrs_as_reduce_mean_attrs, reduce_axes);
 impl_read_op!(ReduceSumSquare, attrs_as_reduce_mean_attrs, reduce_axes);
 impl_read_op!(Relu);
+impl_read_op!(
+    RepeatInterleave,
+    attrs_as_repeat_interleave_attrs,
+    |attrs: sg::RepeatInterleaveAttrs| {
+        Ok(ops::RepeatInterleave {
+            axis: attrs.axis() as usize,
+            repeats: attrs.repeats() as usize,
+        })
+    }
+);
 impl_read_op!(
     Reshape,
     attrs_as_reshape_attrs,
@@ -927,6 +1309,58 @@ impl_read_op!(Resize, attrs_as_resize_attrs, |attrs: sg::ResizeAttrs| {
         nearest_mode,
     })
 });
+impl_read_op!(
+    ReverseSequence,
+    attrs_as_reverse_sequence_attrs,
+    |attrs: sg::ReverseSequenceAttrs| {
+        Ok(ops::ReverseSequence {
+            batch_axis: attrs.batch_axis() as usize,
+            time_axis: attrs.time_axis() as usize,
+        })
+    }
+);
+impl_read_op!(
+    RmsNormalization,
+    attrs_as_layer_normalization_attrs,
+    |attrs: sg::LayerNormalizationAttrs| {
+        Ok(ops::RmsNormalization {
+            axis: attrs.axis() as isize,
+            epsilon: Some(attrs.epsilon()),
+        })
+    }
+);
+impl_read_op!(
+    RoiAlign,
+    attrs_as_roi_align_attrs,
+    |attrs: sg::RoiAlignAttrs| {
+        let mode = match attrs.mode() {
+            sg::RoiAlignMode::Avg => RoiAlignMode::Avg,
+            sg::RoiAlignMode::Max => RoiAlignMode::Max,
+            _ => RoiAlignMode::Avg,
+        };
+        Ok(ops::RoiAlign {
+            opts: RoiAlignOptions {
+                mode,
+                output_height: attrs.output_height() as usize,
+                output_width: attrs.output_width() as usize,
+                sampling_ratio: attrs.sampling_ratio() as usize,
+                spatial_scale: attrs.spatial_scale(),
+                half_pixel: attrs.half_pixel(),
+            },
+        })
+    }
+);
+impl_read_op!(
+    RotaryEmbedding,
+    attrs_as_rotary_embedding_attrs,
+    |attrs: sg::RotaryEmbeddingAttrs| {
+        Ok(ops::RotaryEmbedding {
+            interleaved: attrs.interleaved(),
+            num_heads: attrs.num_heads().map(|n| n as usize),
+            rotary_embedding_dim: attrs.rotary_embedding_dim().map(|dim| dim as usize),
+        })
+    }
+);
 impl_read_op!(Round);
 impl_read_op!(
     ScatterElements,
@@ -948,6 +1382,12 @@ impl_read_op!(
     }
 );
 
+impl_read_op!(Selu, attrs_as_selu_attrs, |attrs: sg::SeluAttrs| {
+    Ok(ops::Selu {
+        alpha: attrs.alpha(),
+        gamma: attrs.gamma(),
+    })
+});
 impl_read_op!(SequenceAt);
 impl_read_op!(SequenceConstruct);
 impl_read_op!(
@@ -979,10 +1419,27 @@ impl ReadOp for ops::Shape {
     }
 }
 
+impl_read_op!(Shrink, attrs_as_shrink_attrs, |attrs: sg::ShrinkAttrs| {
+    Ok(ops::Shrink {
+        bias: attrs.bias(),
+        lambd: attrs.lambd(),
+    })
+});
+
 impl_read_op!(Sigmoid);
 impl_read_op!(Sign);
+impl_read_op!(Silu);
 impl_read_op!(Sin);
 impl_read_op!(Size);
+impl_read_op!(
+    SkipSimplifiedLayerNormalization,
+    attrs_as_layer_normalization_attrs,
+    |attrs: sg::LayerNormalizationAttrs| {
+        Ok(ops::SkipSimplifiedLayerNormalization {
+            epsilon: Some(attrs.epsilon()),
+        })
+    }
+);
 impl_read_op!(Slice);
 impl_read_op!(
     Softmax,
@@ -990,11 +1447,20 @@ impl_read_op!(
     |attrs: sg::SoftmaxAttrs| {
         Ok(ops::Softmax {
             axis: attrs.axis() as isize,
-            flush_nans_to_zero: false,
+            flush_nans_to_zero: attrs.flush_nans_to_zero(),
         })
     }
 );
 impl_read_op!(Softplus);
+impl_read_op!(
+    SpaceToDepth,
+    attrs_as_space_to_depth_attrs,
+    |attrs: sg::SpaceToDepthAttrs| {
+        Ok(ops::SpaceToDepth {
+            block_size: attrs.block_size(),
+        })
+    }
+);
 impl_read_op!(Split, attrs_as_split_attrs, |attrs: sg::SplitAttrs| {
     let axis = attrs.axis() as isize;
     let num_outputs = attrs.num_outputs().map(|n| n as u32);
@@ -1022,8 +1488,20 @@ impl_read_op!(STFT, attrs_as_stftattrs, |attrs: sg::STFTAttrs| {
 
 impl_read_op!(Sub);
 impl_read_op!(Sum);
+impl_read_op!(Swish, attrs_as_swish_attrs, |attrs: sg::SwishAttrs| {
+    Ok(ops::Swish { beta: attrs.beta() })
+});
 impl_read_op!(Tan);
 impl_read_op!(Tanh);
+impl_read_op!(
+    ThresholdedRelu,
+    attrs_as_thresholded_relu_attrs,
+    |attrs: sg::ThresholdedReluAttrs| {
+        Ok(ops::ThresholdedRelu {
+            alpha: attrs.alpha(),
+        })
+    }
+);
 impl_read_op!(Tile);
 impl_read_op!(TopK, attrs_as_top_kattrs, |attrs: sg::TopKAttrs| {
     let largest = attrs.largest();
@@ -1035,6 +1513,28 @@ impl_read_op!(TopK, attrs_as_top_kattrs, |attrs: sg::TopKAttrs| {
         sorted,
     })
 });
+impl ReadOp for TransformInputs {
+    fn op_type() -> sg::OperatorType {
+        sg::OperatorType::TransformInputs
+    }
+
+    fn read(op: &sg::OperatorNode, ctx: &dyn OpLoadContext) -> Result<Self, ReadOpError> {
+        let attrs = op
+            .attrs_as_transform_inputs_attrs()
+            .ok_or(ReadOpError::AttrsMissingError)?;
+        let inner = ctx.read_op(&attrs.op())?;
+
+        let mut builder = TransformInputsBuilder::new();
+        for transform in attrs.transforms().iter().flatten() {
+            builder = builder.permute(
+                transform.input_index() as usize,
+                opt_vec_from_attr(transform.perm()),
+            );
+        }
+        Ok(builder.build(inner))
+    }
+}
+
 impl_read_op!(
     Transpose,
     attrs_as_transpose_attrs,
@@ -1050,6 +1550,12 @@ impl_read_op!(Trilu, attrs_as_trilu_attrs, |attrs: sg::TriluAttrs| {
         upper: attrs.upper(),
     })
 });
+impl_read_op!(Unique, attrs_as_unique_attrs, |attrs: sg::UniqueAttrs| {
+    Ok(ops::Unique {
+        axis: attrs.axis().map(|axis| axis as isize),
+        sorted: attrs.sorted(),
+    })
+});
 impl_read_op!(Unsqueeze);
 impl_read_op!(Where);
 impl_read_op!(Xor);
@@ -7,7 +7,7 @@ use std::error::Error;
 use std::fmt;
 use std::fmt::{Debug, Display};
 
-use rten_gemm::PackedBMatrix;
+use rten_gemm::{GemmExecutor, PackedBMatrix};
 use rten_tensor::errors::DimensionError;
 use rten_tensor::{Layout, Storage, TensorBase};
 use smallvec::SmallVec;
@@ -30,12 +30,56 @@ pub enum PrepackedInput {
 }
 
 impl PrepackedInput {
-    fn dtype(&self) -> DataType {
+    pub(crate) fn dtype(&self) -> DataType {
         match self {
             Self::FloatBMatrix(_) => DataType::Float,
             Self::Int8BMatrix(_) => DataType::Int8,
         }
     }
+
+    /// Return the name of the kernel which packed the data.
+    pub(crate) fn kernel_name(&self) -> &str {
+        match self {
+            Self::FloatBMatrix(packed) => packed.kernel_name(),
+            Self::Int8BMatrix(packed) => packed.kernel_name(),
+        }
+    }
+
+    /// Return the packed data as bytes.
+    ///
+    /// This can be converted back using [`from_bytes`](Self::from_bytes).
+    pub(crate) fn as_bytes(&self) -> &[u8] {
+        match self {
+            Self::FloatBMatrix(packed) => packed.as_bytes(),
+            Self::Int8BMatrix(packed) => packed.as_bytes(),
+        }
+    }
+
+    /// Restore a prepacked `[rows, cols]` B matrix from data returned by
+    /// [`as_bytes`](Self::as_bytes).
+    ///
+    /// Returns `None` if the data was packed by a different kernel than the
+    /// one that would be used for matrix multiplication with this data type,
+    /// or is otherwise incompatible.
+    pub(crate) fn from_bytes(
+        dtype: DataType,
+        kernel_name: &str,
+        rows: usize,
+        cols: usize,
+        data: &[u8],
+    ) -> Option<PrepackedInput> {
+        match dtype {
+            DataType::Float => GemmExecutor::<f32>::default()
+                .prepacked_b_from_bytes(kernel_name, rows, cols, data)
+                .ok()
+                .map(PrepackedInput::FloatBMatrix),
+            DataType::Int8 => GemmExecutor::<u8, i8, i32>::default()
+                .prepacked_b_from_bytes(kernel_name, rows, cols, data)
+                .ok()
+                .map(PrepackedInput::Int8BMatrix),
+            _ => None,
+        }
+    }
 }
 
 macro_rules! impl_prepacked_input_conversions {
@@ -319,6 +363,9 @@ pub enum OutputType {
     Fixed(ValueType),
     /// This output has the same type as the input at a given index.
     CopyFromInput(u32),
+    /// This output has the same type as the input at a given index, except
+    /// that 16-bit float types are widened to f32.
+    WidenedFromInput(u32),
     /// The output is the element type of an input sequence.
     ElementTypeOfInputSequence(u32),
     /// The output is a sequence whose element type matches an input.
@@ -388,6 +435,16 @@ pub trait Operator: Any + Debug {
         false
     }
 
+    /// Return true if input `index` of this operator accepts `i64` and `bool`
+    /// tensors.
+    ///
+    /// The graph executor converts `i64` and `bool` tensors passed to other
+    /// inputs to `i32` before running the operator. The conversion fails if an
+    /// `i64` value is outside the range of `i32`.
+    fn supports_int64_bool_input(&self, #[allow(unused)] index: usize) -> bool {
+        false
+    }
+
     /// Return true if this operator's outputs depend only on its inputs.
     ///
     /// The default implementation returns true, since most operators are
@@ -1,21 +1,24 @@
 //! Attention-related operations.
 
 use rayon::prelude::*;
-use rten_gemm::{GemmExecutor, GemmInputA, GemmInputB, GemmUninitOptions};
-use rten_simd::SimdOp;
+use rten_gemm::{GemmExecutor, GemmInputA, GemmInputB, GemmOptions, GemmUninitOptions};
+use rten_shape_inference::ops as shape_ops;
+use rten_simd::{SimdOp, SimdUnaryOp};
 use rten_tensor::prelude::*;
-use rten_tensor::{NdTensorView, Tensor, TensorView};
-use rten_vecmath::Softmax;
+use rten_tensor::{CowNdTensor, NdTensor, NdTensorView, Tensor, TensorView};
+use rten_vecmath::{Exp, Softmax};
 
 use crate::buffer_pool::{AutoReturn, BufferPool};
+use crate::infer_shapes::{InferShapes, UnaryOp, impl_infer_shapes};
 use crate::operator::{
     IntoOpResult, OpError, OpRunContext, Operator, OutputList, OutputType, OutputTypeList,
     OutputTypesContext,
 };
 use crate::ops::{
-    binary_elementwise::broadcast_shapes, layout::expand_to, norm::NanHandling, resolve_axis,
+    binary_elementwise::broadcast_shapes, concat::concat, layout::expand_to, norm::NanHandling,
+    resolve_axis,
 };
-use crate::value::Value;
+use crate::value::{Value, ValueView};
 
 const BROADCAST_ERROR: OpError = OpError::IncompatibleInputShapes("Cannot broadcast inputs");
 
@@ -70,7 +73,7 @@ fn add_softmax_in_place(
 /// The fusion takes advantage of the fact that we can perform Add + Softmax
 /// on each lane separately, and get better cache efficiency by having
 /// the lane already be in a higher cache level when the Softmax step runs.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct AddSoftmax {
     /// See `flush_nans_to_zero` on Softmax operator.
     pub flush_nans_to_zero: bool,
@@ -185,7 +188,7 @@ fn repeat_interleave<T: Copy>(
 /// it acts as a building block for higher-level fusions.
 ///
 /// See https://docs.pytorch.org/docs/stable/generated/torch.repeat_interleave.html.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct RepeatInterleave {
     pub axis: usize,
     pub repeats: usize,
@@ -215,7 +218,7 @@ impl Operator for RepeatInterleave {
 ///
 /// This fusion is used in Grouped-query Attention operators, where K represents
 /// either the key or value tensor.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct GroupedQueryAttentionMatMul {
     /// Number of times to repeat the second dimension of the RHS input.
     pub repeats: usize,
@@ -297,17 +300,1104 @@ impl Operator for GroupedQueryAttentionMatMul {
     }
 }
 
+/// Number of query rows processed by each task in [`attention`].
+const QUERY_TILE_SIZE: usize = 64;
+
+/// Number of keys processed in each step of the online softmax in
+/// [`attention`].
+const KEY_TILE_SIZE: usize = 128;
+
+/// Mask applied to attention scores before the softmax.
+#[derive(Clone, Copy)]
+enum AttentionMask<'a, const N: usize> {
+    /// Mask where `true` indicates that a query can attend to a key.
+    Bool(NdTensorView<'a, bool, N>),
+    /// Mask that is added to the attention scores.
+    Float(NdTensorView<'a, f32, N>),
+}
+
+impl<'a> AttentionMask<'a, 4> {
+    /// Return the mask for a single batch item and query head.
+    fn head(&self, batch: usize, head: usize) -> AttentionMask<'a, 2> {
+        match self {
+            Self::Bool(mask) => AttentionMask::Bool(mask.slice([batch, head])),
+            Self::Float(mask) => AttentionMask::Float(mask.slice([batch, head])),
+        }
+    }
+}
+
+impl AttentionMask<'_, 2> {
+    /// Apply the mask for query `row` to the scores for keys starting at
+    /// `key_start`.
+    ///
+    /// Keys beyond the end of the mask are masked out.
+    fn apply(&self, scores: &mut [f32], row: usize, key_start: usize) {
+        let (mask_len, n_masked) = match self {
+            Self::Bool(mask) => {
+                let mask_len = mask.size(1);
+                let n_masked = mask_len.saturating_sub(key_start).min(scores.len());
+                let mask_row = mask.slice((row, key_start..key_start + n_masked));
+                for (score, attend) in scores.iter_mut().zip(mask_row.iter()) {
+                    if !attend {
+                        *score = f32::NEG_INFINITY;
+                    }
+                }
+                (mask_len, n_masked)
+            }
+            Self::Float(mask) => {
+                let mask_len = mask.size(1);
+                let n_masked = mask_len.saturating_sub(key_start).min(scores.len());
+                let mask_row = mask.slice((row, key_start..key_start + n_masked));
+                for (score, bias) in scores.iter_mut().zip(mask_row.iter()) {
+                    *score += bias;
+                }
+                (mask_len, n_masked)
+            }
+        };
+        if key_start + scores.len() > mask_len {
+            scores[n_masked..].fill(f32::NEG_INFINITY);
+        }
+    }
+}
+
+/// Options for [`attention`].
+#[derive(Clone, Copy, Debug)]
+struct AttentionOptions {
+    /// Scale applied to `Q @ K^T`.
+    scale: f32,
+    /// If set, scores are capped using `softcap * tanh(score / softcap)`.
+    softcap: Option<f32>,
+    /// Prevent queries from attending to keys at later positions.
+    is_causal: bool,
+    /// If set, limits each query to attending to keys at most this many
+    /// positions before it.
+    local_window: Option<usize>,
+    /// Output zeros instead of NaNs for queries which cannot attend to any keys.
+    flush_nans_to_zero: bool,
+}
+
+/// Positions of queries and valid keys for one batch item in [`attention`].
+#[derive(Clone, Copy, Debug)]
+struct SeqPositions {
+    /// Number of valid keys. Keys after this are padding.
+    kv_len: usize,
+    /// Position of the first query in the key sequence.
+    ///
+    /// This is non-zero when keys from earlier steps are cached.
+    query_offset: usize,
+}
+
+/// Compute `Softmax(Q @ K^T * scale + mask) @ V`.
+///
+/// `q` has shape `(batch, q_heads, q_seq, head_size)`, `k` has shape `(batch,
+/// kv_heads, kv_seq, head_size)` and `v` has shape `(batch, kv_heads, kv_seq,
+/// v_head_size)`. `q_heads` must be a multiple of `kv_heads`, in which case
+/// each key and value head is shared by a group of query heads, as in
+/// Grouped-query Attention.
+///
+/// This uses the tiling and online softmax approach from FlashAttention
+/// (<https://arxiv.org/abs/2205.14135>). Queries are split into tiles of rows
+/// which are processed in parallel. For each query tile, keys and values are
+/// processed in tiles and the output is accumulated across key tiles, rescaling
+/// the partial output whenever the running maximum score for a row changes.
+/// This avoids materializing the `(q_seq, kv_seq)` matrix of attention scores.
+///
+/// `positions` specifies the number of valid (non-padding) keys and the
+/// position of the first query in the key sequence for each batch item. If
+/// not specified, all keys are valid and the first query is at position 0.
+fn attention(
+    pool: &BufferPool,
+    q: NdTensorView<f32, 4>,
+    k: NdTensorView<f32, 4>,
+    v: NdTensorView<f32, 4>,
+    mask: Option<AttentionMask<4>>,
+    positions: Option<&[SeqPositions]>,
+    opts: AttentionOptions,
+) -> Result<NdTensor<f32, 4>, OpError> {
+    let [batch, q_heads, q_seq, head_size] = q.shape();
+    let [k_batch, kv_heads, kv_seq, k_head_size] = k.shape();
+    let [v_batch, v_heads, v_seq, v_head_size] = v.shape();
+
+    if k_batch != batch || v_batch != batch {
+        return Err(OpError::IncompatibleInputShapes("Batch size mismatch"));
+    }
+    if k_head_size != head_size {
+        return Err(OpError::IncompatibleInputShapes(
+            "Query and key head sizes do not match",
+        ));
+    }
+    if v_heads != kv_heads || v_seq != kv_seq {
+        return Err(OpError::IncompatibleInputShapes(
+            "Key and value shapes do not match",
+        ));
+    }
+    if kv_heads == 0 || !q_heads.is_multiple_of(kv_heads) {
+        return Err(OpError::IncompatibleInputShapes(
+            "Query heads must be a multiple of key-value heads",
+        ));
+    }
+    if positions.is_some_and(|pos| pos.len() != batch) {
+        return Err(OpError::IncompatibleInputShapes(
+            "Sequence lengths do not match batch size",
+        ));
+    }
+
+    let mut out = NdTensor::zeros_in(pool, [batch, q_heads, q_seq, v_head_size]);
+    if out.is_empty() {
+        return Ok(out);
+    }
+
+    let group_size = q_heads / kv_heads;
+    let gemm = GemmExecutor::default();
+
+    out.data_mut()
+        .expect("should be contiguous")
+        .par_chunks_mut(q_seq * v_head_size)
+        .enumerate()
+        .for_each(|(batch_head, out_head)| {
+            let b = batch_head / q_heads;
+            let h = batch_head % q_heads;
+            let q_head = q.slice([b, h]);
+            let k_head = k.slice([b, h / group_size]);
+            let v_head = v.slice([b, h / group_size]);
+            let mask = mask.map(|m| m.head(b, h));
+            let (kv_len, query_offset) = positions
+                .map(|pos| (pos[b].kv_len.min(kv_seq), pos[b].query_offset))
+                .unwrap_or((kv_seq, 0));
+
+            out_head
+                .par_chunks_mut(QUERY_TILE_SIZE * v_head_size)
+                .enumerate()
+                .for_each(|(tile, out_tile)| {
+                    let row_start = tile * QUERY_TILE_SIZE;
+                    let n_rows = out_tile.len() / v_head_size;
+                    attention_tile(
+                        &gemm,
+                        out_tile,
+                        q_head.slice(row_start..row_start + n_rows),
+                        k_head.slice(..kv_len),
+                        v_head.slice(..kv_len),
+                        mask.as_ref(),
+                        row_start,
+                        query_offset,
+                        &opts,
+                    );
+                });
+        });
+
+    Ok(out)
+}
+
+/// Compute the attention output for a tile of query rows from one head.
+///
+/// `out` is a `(q.rows, v.cols)` matrix which must be zero-initialized.
+/// `row_start` is the index of the first query row in the query sequence and
+/// `query_offset` is the position of the first query in the key sequence.
+#[allow(clippy::too_many_arguments)]
+fn attention_tile(
+    gemm: &GemmExecutor,
+    out: &mut [f32],
+    q: NdTensorView<f32, 2>,
+    k: NdTensorView<f32, 2>,
+    v: NdTensorView<f32, 2>,
+    mask: Option<&AttentionMask<2>>,
+    row_start: usize,
+    query_offset: usize,
+    opts: &AttentionOptions,
+) {
+    let n_rows = q.size(0);
+    let v_head_size = v.size(1);
+    let pos_start = query_offset + row_start;
+
+    // With causal masking, keys after the last query row are never attended
+    // to. With a local window, keys before the window of the first query row
+    // are never attended to.
+    let key_end = if opts.is_causal {
+        k.size(0).min(pos_start + n_rows)
+    } else {
+        k.size(0)
+    };
+    let key_begin = opts
+        .local_window
+        .map(|window| pos_start.saturating_sub(window).min(key_end))
+        .unwrap_or(0);
+
+    // Running maximum and sum of exponentiated scores for each row.
+    let mut row_max = vec![f32::NEG_INFINITY; n_rows];
+    let mut row_sum = vec![0.; n_rows];
+    let mut scores = Vec::with_capacity(n_rows * KEY_TILE_SIZE.min(key_end - key_begin));
+
+    for key_start in (key_begin..key_end).step_by(KEY_TILE_SIZE) {
+        let n_keys = KEY_TILE_SIZE.min(key_end - key_start);
+        let k_tile = k.slice(key_start..key_start + n_keys);
+        let v_tile = v.slice(key_start..key_start + n_keys);
+
+        scores.clear();
+        scores.resize(n_rows * n_keys, 0.);
+        gemm.gemm(
+            &mut scores,
+            GemmInputA::Unpacked(q),
+            GemmInputB::Unpacked(k_tile.transposed()),
+            GemmOptions {
+                alpha: opts.scale,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        for (r, ((row_scores, out_row), (max, sum))) in scores
+            .chunks_mut(n_keys)
+            .zip(out.chunks_mut(v_head_size))
+            .zip(row_max.iter_mut().zip(row_sum.iter_mut()))
+            .enumerate()
+        {
+            let row = row_start + r;
+            let pos = pos_start + r;
+
+            if let Some(softcap) = opts.softcap {
+                for score in row_scores.iter_mut() {
+                    *score = softcap * (*score / softcap).tanh();
+                }
+            }
+            if let Some(mask) = mask {
+                mask.apply(row_scores, row, key_start);
+            }
+            if opts.is_causal {
+                let n_visible = (pos + 1).saturating_sub(key_start).min(n_keys);
+                row_scores[n_visible..].fill(f32::NEG_INFINITY);
+            }
+            if let Some(window) = opts.local_window {
+                let n_hidden = pos.saturating_sub(window).saturating_sub(key_start);
+                row_scores[..n_hidden.min(n_keys)].fill(f32::NEG_INFINITY);
+            }
+
+            let tile_max = row_scores
+                .iter()
+                .fold(f32::NEG_INFINITY, |max, x| max.max(*x));
+            let new_max = max.max(tile_max);
+            if new_max == f32::NEG_INFINITY {
+                // All keys seen so far are masked.
+                row_scores.fill(0.);
+                continue;
+            }
+
+            for score in row_scores.iter_mut() {
+                *score -= new_max;
+            }
+            Exp {}.map_mut(row_scores);
+
+            // Rescale the output accumulated from previous key tiles.
+            let correction = (*max - new_max).exp();
+            if correction != 1.0 {
+                for x in out_row.iter_mut() {
+                    *x *= correction;
+                }
+            }
+            *sum = *sum * correction + row_scores.iter().sum::<f32>();
+            *max = new_max;
+        }
+
+        let probs = NdTensorView::from_data([n_rows, n_keys], scores.as_slice());
+        gemm.gemm(
+            out,
+            GemmInputA::Unpacked(probs),
+            GemmInputB::Unpacked(v_tile),
+            GemmOptions {
+                beta: 1.0,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    }
+
+    for (out_row, sum) in out.chunks_mut(v_head_size).zip(row_sum) {
+        if sum == 0. {
+            // Query cannot attend to any keys. The softmax output is NaN, as
+            // for a Softmax over a row where every element is `-inf`.
+            let fill = if opts.flush_nans_to_zero {
+                0.
+            } else {
+                f32::NAN
+            };
+            out_row.fill(fill);
+        } else {
+            let inv_sum = 1. / sum;
+            for x in out_row {
+                *x *= inv_sum;
+            }
+        }
+    }
+}
+
+/// Split the last dimension of a `(batch, seq, heads * head_size)` tensor into
+/// heads and return a `(batch, heads, seq, head_size)` view.
+fn split_heads<'a>(
+    pool: &BufferPool,
+    x: TensorView<'a, f32>,
+    num_heads: Option<usize>,
+) -> Result<CowNdTensor<'a, f32, 4>, OpError> {
+    let num_heads = num_heads.ok_or(OpError::InvalidValue(
+        "Number of heads must be specified for 3D inputs",
+    ))?;
+    let [batch, seq, hidden] = x.shape() else {
+        return Err(OpError::InvalidValue("Expected 3D input"));
+    };
+    if num_heads == 0 || !hidden.is_multiple_of(num_heads) {
+        return Err(OpError::InvalidValue(
+            "Hidden size must be a multiple of number of heads",
+        ));
+    }
+    let mut x = x.reshaped_in(pool, [*batch, *seq, num_heads, hidden / num_heads]);
+    x.permute([0, 2, 1, 3]);
+    Ok(x)
+}
+
+/// Merge the heads of a `(batch, heads, seq, head_size)` tensor into a
+/// `(batch, seq, heads * head_size)` tensor.
+///
+/// This is the inverse of [`split_heads`].
+fn merge_heads(pool: &BufferPool, x: NdTensor<f32, 4>) -> Tensor {
+    let [batch, heads, seq, head_size] = x.shape();
+    let x = x.auto_return(pool);
+    let mut merged = x.permuted([0, 2, 1, 3]).to_tensor_in(pool).into_dyn();
+    merged.reshape(&[batch, seq, heads * head_size]);
+    merged
+}
+
+/// Scaled dot-product attention.
+///
+/// This computes `Softmax(Q @ K^T * scale + mask) @ V` using a fused kernel
+/// that never materializes the full matrix of attention scores. Key and value
+/// heads can be shared by groups of query heads (Grouped-query Attention).
+///
+/// The operator is produced by fusing attention subgraphs and from the ONNX
+/// `Attention` operator. Causal masking follows the ONNX spec, where the mask
+/// is aligned to the top-left corner of the `(q_seq, kv_seq)` score matrix.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__Attention.html>.
+#[derive(Clone, Debug, Default)]
+pub struct Attention {
+    /// Prevent queries from attending to keys at later positions.
+    pub is_causal: bool,
+    /// Number of query heads. Required if inputs are 3D.
+    pub q_num_heads: Option<usize>,
+    /// Number of key and value heads. Required if inputs are 3D.
+    pub kv_num_heads: Option<usize>,
+    /// Scale applied to `Q @ K^T`. Defaults to `1 / sqrt(head_size)`.
+    pub scale: Option<f32>,
+    /// If set, scores are capped using `softcap * tanh(score / softcap)`
+    /// before the mask is applied.
+    pub softcap: Option<f32>,
+    /// See `flush_nans_to_zero` on Softmax operator.
+    pub flush_nans_to_zero: bool,
+}
+
+impl Operator for Attention {
+    fn name(&self) -> &str {
+        "Attention"
+    }
+
+    fn max_inputs(&self) -> Option<usize> {
+        Some(7)
+    }
+
+    fn supports_int64_bool_input(&self, index: usize) -> bool {
+        // `attn_mask` and `nonpad_kv_seqlen` inputs.
+        matches!(index, 3 | 6)
+    }
+
+    fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
+        let inputs = ctx.inputs();
+        let pool = ctx.pool();
+
+        let q: TensorView = inputs.require_as(0)?;
+        let k: TensorView = inputs.require_as(1)?;
+        let v: TensorView = inputs.require_as(2)?;
+        let mask = inputs.get(3);
+        let past_key: Option<NdTensorView<f32, 4>> = inputs.get_as(4)?;
+        let past_value: Option<NdTensorView<f32, 4>> = inputs.get_as(5)?;
+        let nonpad_kv_seqlen = inputs.get(6);
+
+        if ctx.num_outputs().is_some_and(|n| n > 3) {
+            return Err(OpError::UnsupportedValue(
+                "QK matmul output is not supported",
+            ));
+        }
+        let want_present_kv = ctx.num_outputs().is_none_or(|n| n > 1);
+
+        let is_3d = match (q.ndim(), k.ndim(), v.ndim()) {
+            (4, 4, 4) => false,
+            (3, 3, 3) => true,
+            _ => {
+                return Err(OpError::InvalidValue("Q, K and V must all be 3D or 4D"));
+            }
+        };
+        let (q, k, v) = if is_3d {
+            (
+                split_heads(pool, q, self.q_num_heads)?,
+                split_heads(pool, k, self.kv_num_heads)?,
+                split_heads(pool, v, self.kv_num_heads)?,
+            )
+        } else {
+            (
+                q.nd_view().as_cow(),
+                k.nd_view().as_cow(),
+                v.nd_view().as_cow(),
+            )
+        };
+
+        let present_key = past_key
+            .map(|past| concat(pool, &[past.as_dyn(), k.as_dyn()], 2))
+            .transpose()?;
+        let present_value = past_value
+            .map(|past| concat(pool, &[past.as_dyn(), v.as_dyn()], 2))
+            .transpose()?;
+        let keys = present_key
+            .as_ref()
+            .map(|pk| pk.nd_view())
+            .unwrap_or(k.view());
+        let values = present_value
+            .as_ref()
+            .map(|pv| pv.nd_view())
+            .unwrap_or(v.view());
+
+        let [batch, q_heads, q_seq, head_size] = q.shape();
+        let kv_seq = keys.size(2);
+
+        // Broadcast the mask to `(batch, q_heads, q_seq, mask_len)` where
+        // `mask_len <= kv_seq`.
+        let mask_shape = |mask_len: usize| -> Result<[usize; 4], OpError> {
+            if mask_len > kv_seq {
+                return Err(OpError::IncompatibleInputShapes(
+                    "Mask is longer than key sequence",
+                ));
+            }
+            Ok([batch, q_heads, q_seq, mask_len])
+        };
+        let mask = match mask {
+            None => None,
+            Some(ValueView::BoolTensor(mask)) if mask.ndim() > 0 => {
+                let shape = mask_shape(mask.size(mask.ndim() - 1))?;
+                let mask = mask.try_broadcast(shape).map_err(|_| BROADCAST_ERROR)?;
+                Some(AttentionMask::Bool(mask))
+            }
+            Some(ValueView::FloatTensor(mask)) if mask.ndim() > 0 => {
+                let shape = mask_shape(mask.size(mask.ndim() - 1))?;
+                let mask = mask.try_broadcast(shape).map_err(|_| BROADCAST_ERROR)?;
+                Some(AttentionMask::Float(mask))
+            }
+            Some(ValueView::BoolTensor(_) | ValueView::FloatTensor(_)) => {
+                return Err(OpError::InvalidValue("Mask must have at least one dim"));
+            }
+            Some(_) => {
+                return Err(OpError::UnsupportedType);
+            }
+        };
+
+        let kv_seq_lens: Option<Vec<usize>> = match nonpad_kv_seqlen {
+            None => None,
+            Some(ValueView::Int64Tensor(lens)) => {
+                Some(lens.iter().map(|&len| len.max(0) as usize).collect())
+            }
+            Some(ValueView::Int32Tensor(lens)) => {
+                Some(lens.iter().map(|&len| len.max(0) as usize).collect())
+            }
+            Some(_) => {
+                return Err(OpError::UnsupportedType);
+            }
+        };
+        let positions: Option<Vec<SeqPositions>> = kv_seq_lens.map(|lens| {
+            lens.into_iter()
+                .map(|kv_len| SeqPositions {
+                    kv_len,
+                    query_offset: 0,
+                })
+                .collect()
+        });
+
+        let opts = AttentionOptions {
+            scale: self.scale.unwrap_or_else(|| 1. / (head_size as f32).sqrt()),
+            softcap: self.softcap.filter(|softcap| *softcap > 0.),
+            is_causal: self.is_causal,
+            local_window: None,
+            flush_nans_to_zero: self.flush_nans_to_zero,
+        };
+        let output = attention(
+            pool,
+            q.view(),
+            keys,
+            values,
+            mask,
+            positions.as_deref(),
+            opts,
+        )?;
+
+        let output: Value = if is_3d {
+            merge_heads(pool, output).into()
+        } else {
+            output.into()
+        };
+
+        if !want_present_kv {
+            return Ok([output].into());
+        }
+
+        let present_key = present_key.unwrap_or_else(|| k.to_tensor_in(pool).into_dyn());
+        let present_value = present_value.unwrap_or_else(|| v.to_tensor_in(pool).into_dyn());
+        Ok([output, present_key.into(), present_value.into()]
+            .into_iter()
+            .collect())
+    }
+
+    fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
+        Some(OutputTypeList::from_slice(&[
+            OutputType::CopyFromInput(0),
+            OutputType::CopyFromInput(1),
+            OutputType::CopyFromInput(2),
+        ]))
+    }
+
+    fn as_infer_shapes(&self) -> Option<&dyn InferShapes> {
+        Some(self)
+    }
+}
+
+impl_infer_shapes!(
+    Attention,
+    op,
+    shape_ops::Attention {
+        q_num_heads: op.q_num_heads,
+        kv_num_heads: op.kv_num_heads,
+    }
+);
+
+/// Apply rotary position embeddings to each head in `x` in-place.
+///
+/// `x` is a sequence of heads of size `head_size`. `cos` and `sin` have shape
+/// `(max_position, rotary_dim / 2)` and `position` returns the row of `cos`
+/// and `sin` to use for the head at a given index. The first `rotary_dim`
+/// elements of each head are rotated and the rest are left unchanged.
+///
+/// If `interleaved` is true, the rotated pairs are adjacent elements.
+/// Otherwise the pairs are formed from the first and second halves of the
+/// rotated elements.
+fn apply_rotary_embedding(
+    x: &mut [f32],
+    head_size: usize,
+    cos: NdTensorView<f32, 2>,
+    sin: NdTensorView<f32, 2>,
+    interleaved: bool,
+    position: impl Fn(usize) -> usize + Sync,
+) {
+    let half_dim = cos.size(1);
+    debug_assert!(half_dim * 2 <= head_size);
+    debug_assert_eq!(cos.shape(), sin.shape());
+
+    x.par_chunks_mut(head_size)
+        .enumerate()
+        .for_each(|(head_idx, head)| {
+            let pos = position(head_idx);
+            let cos = cos.slice(pos);
+            let sin = sin.slice(pos);
+            for i in 0..half_dim {
+                let (i1, i2) = if interleaved {
+                    (2 * i, 2 * i + 1)
+                } else {
+                    (i, i + half_dim)
+                };
+                let (x1, x2) = (head[i1], head[i2]);
+                let (c, s) = (cos[i], sin[i]);
+                head[i1] = c * x1 - s * x2;
+                head[i2] = s * x1 + c * x2;
+            }
+        });
+}
+
+/// Select the first `half_dim` columns of a `(positions, dim)` cos or sin
+/// cache.
+fn rotary_cache<'a>(
+    cache: NdTensorView<'a, f32, 2>,
+    half_dim: usize,
+) -> Result<NdTensorView<'a, f32, 2>, OpError> {
+    if cache.size(1) < half_dim {
+        return Err(OpError::IncompatibleInputShapes(
+            "Cos and sin cache are smaller than rotary dim",
+        ));
+    }
+    Ok(cache.slice((.., ..half_dim)))
+}
+
+/// Rotary position embeddings (RoPE).
+///
+/// Rotates pairs of elements in each head by an angle that depends on the
+/// position of the token, using precomputed cos and sin caches.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__RotaryEmbedding.html>.
+#[derive(Clone, Debug, Default)]
+pub struct RotaryEmbedding {
+    /// Rotate adjacent pairs of elements rather than pairs formed from the
+    /// first and second halves of each head.
+    pub interleaved: bool,
+    /// Number of heads. Required if the input is 3D.
+    pub num_heads: Option<usize>,
+    /// Number of elements of each head to rotate. Defaults to the head size.
+    pub rotary_embedding_dim: Option<usize>,
+}
+
+impl RotaryEmbedding {
+    /// Apply rotary embeddings to `x`, which has shape `(batch, heads, seq,
+    /// head_size)` or `(batch, seq, heads * head_size)`.
+    ///
+    /// If `position_ids` is set, `cos` and `sin` have shape `(max_position,
+    /// rotary_dim / 2)` and are indexed by position ID. Otherwise they have
+    /// shape `(batch, seq, rotary_dim / 2)`.
+    fn apply(
+        &self,
+        pool: &BufferPool,
+        x: &mut Tensor,
+        cos: TensorView,
+        sin: TensorView,
+        position_ids: Option<NdTensorView<i32, 2>>,
+    ) -> Result<(), OpError> {
+        // Get input dimensions and whether heads are the innermost dimension
+        // after the sequence.
+        let (batch, seq, num_heads, head_size, heads_inner) = match *x.shape() {
+            [batch, num_heads, seq, head_size] => (batch, seq, num_heads, head_size, false),
+            [batch, seq, hidden] => {
+                let num_heads = self.num_heads.ok_or(OpError::InvalidValue(
+                    "Number of heads must be specified for 3D inputs",
+                ))?;
+                if num_heads == 0 || !hidden.is_multiple_of(num_heads) {
+                    return Err(OpError::InvalidValue(
+                        "Hidden size must be a multiple of number of heads",
+                    ));
+                }
+                (batch, seq, num_heads, hidden / num_heads, true)
+            }
+            _ => {
+                return Err(OpError::InvalidValue("Input must be 3D or 4D"));
+            }
+        };
+
+        let rotary_dim = self.rotary_embedding_dim.unwrap_or(head_size);
+        if rotary_dim > head_size || !rotary_dim.is_multiple_of(2) {
+            return Err(OpError::InvalidValue(
+                "Rotary embedding dim must be even and not exceed head size",
+            ));
+        }
+        if cos.shape() != sin.shape() {
+            return Err(OpError::IncompatibleInputShapes(
+                "Cos and sin cache must have the same shape",
+            ));
+        }
+
+        // Map each `(batch, seq)` position to a row in the cos and sin caches.
+        let (cos, sin, rows) = if let Some(position_ids) = position_ids {
+            let (Ok(cos), Ok(sin)) = (
+                NdTensorView::<f32, 2>::try_from(cos),
+                NdTensorView::<f32, 2>::try_from(sin),
+            ) else {
+                return Err(OpError::InvalidValue(
+                    "Cos and sin cache must be 2D if position IDs are specified",
+                ));
+            };
+            if position_ids.shape() != [batch, seq] {
+                return Err(OpError::IncompatibleInputShapes(
+                    "Position IDs must have shape (batch, seq)",
+                ));
+            }
+            let rows = position_ids
+                .iter()
+                .map(|&pos| usize::try_from(pos).ok().filter(|pos| *pos < cos.size(0)))
+                .collect::<Option<Vec<_>>>()
+                .ok_or(OpError::InvalidValue("Position ID is out of range"))?;
+            (cos.as_cow(), sin.as_cow(), rows)
+        } else {
+            let [cos_batch, cos_seq, cos_dim] = *cos.shape() else {
+                return Err(OpError::InvalidValue(
+                    "Cos and sin cache must be 3D if position IDs are not specified",
+                ));
+            };
+            if cos_batch != batch || cos_seq != seq {
+                return Err(OpError::IncompatibleInputShapes(
+                    "Cos and sin cache must have shape (batch, seq, dim)",
+                ));
+            }
+            let shape = [batch * seq, cos_dim];
+            (
+                cos.reshaped_in(pool, shape),
+                sin.reshaped_in(pool, shape),
+                (0..batch * seq).collect(),
+            )
+        };
+        let half_dim = rotary_dim / 2;
+        let cos = rotary_cache(cos.view(), half_dim)?;
+        let sin = rotary_cache(sin.view(), half_dim)?;
+
+        x.make_contiguous();
+        let data = x.data_mut().unwrap();
+        apply_rotary_embedding(data, head_size, cos, sin, self.interleaved, |head_idx| {
+            let b = head_idx / (num_heads * seq);
+            let s = if heads_inner {
+                (head_idx / num_heads) % seq
+            } else {
+                head_idx % seq
+            };
+            rows[b * seq + s]
+        });
+
+        Ok(())
+    }
+}
+
+impl Operator for RotaryEmbedding {
+    fn name(&self) -> &str {
+        "RotaryEmbedding"
+    }
+
+    fn max_inputs(&self) -> Option<usize> {
+        Some(4)
+    }
+
+    fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
+        let inputs = ctx.inputs();
+        let input: TensorView = inputs.require_as(0)?;
+        let cos = inputs.require_as(1)?;
+        let sin = inputs.require_as(2)?;
+        let position_ids = inputs.get_as(3)?;
+
+        let mut output = input.to_tensor_in(ctx.pool());
+        self.apply(ctx.pool(), &mut output, cos, sin, position_ids)?;
+        output.into_op_result()
+    }
+
+    fn can_run_in_place(&self) -> bool {
+        true
+    }
+
+    fn run_in_place(&self, input: Value, ctx: &OpRunContext) -> Result<Value, OpError> {
+        let mut output: Tensor = input.try_into()?;
+        let inputs = ctx.inputs();
+        let cos = inputs.require_as(0)?;
+        let sin = inputs.require_as(1)?;
+        let position_ids = inputs.get_as(2)?;
+
+        self.apply(ctx.pool(), &mut output, cos, sin, position_ids)?;
+
+        Ok(output.into())
+    }
+
+    fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
+        Some([OutputType::CopyFromInput(0)].into())
+    }
+
+    fn as_infer_shapes(&self) -> Option<&dyn InferShapes> {
+        Some(&UnaryOp)
+    }
+}
+
+/// Grouped-query attention with a key-value cache.
+///
+/// This is the `com.microsoft.GroupQueryAttention` operator produced by ONNX
+/// Runtime exports of LLMs. Queries attend causally to the cached keys and
+/// values from previous steps (`past_key`, `past_value`) followed by the keys
+/// and values for the current step. The updated caches are returned as the
+/// `present_key` and `present_value` outputs.
+///
+/// See <https://github.com/microsoft/onnxruntime/blob/main/docs/ContribOperators.md#com.microsoft.GroupQueryAttention>.
+#[derive(Clone, Debug, Default)]
+pub struct GroupQueryAttention {
+    /// Number of query heads.
+    pub num_heads: usize,
+    /// Number of key and value heads.
+    pub kv_num_heads: usize,
+    /// Scale applied to `Q @ K^T`. Defaults to `1 / sqrt(head_size)`.
+    pub scale: Option<f32>,
+    /// If set, scores are capped using `softcap * tanh(score / softcap)`.
+    pub softcap: Option<f32>,
+    /// If set, limits each query to attending to keys at most this many
+    /// positions before it.
+    pub local_window_size: Option<usize>,
+    /// Apply rotary embeddings to queries and keys using the cos and sin
+    /// cache inputs.
+    pub do_rotary: bool,
+    /// See [`RotaryEmbedding::interleaved`].
+    pub rotary_interleaved: bool,
+}
+
+impl Operator for GroupQueryAttention {
+    fn name(&self) -> &str {
+        "GroupQueryAttention"
+    }
+
+    fn max_inputs(&self) -> Option<usize> {
+        Some(11)
+    }
+
+    fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
+        let inputs = ctx.inputs();
+        let pool = ctx.pool();
+
+        let query: NdTensorView<f32, 3> = inputs.require_as(0)?;
+        let key: Option<NdTensorView<f32, 3>> = inputs.get_as(1)?;
+        let value: Option<NdTensorView<f32, 3>> = inputs.get_as(2)?;
+        let past_key: Option<NdTensorView<f32, 4>> = inputs.get_as(3)?;
+        let past_value: Option<NdTensorView<f32, 4>> = inputs.get_as(4)?;
+        let seqlens_k: NdTensorView<i32, 1> = inputs.require_as(5)?;
+        let total_seq_len: i32 = inputs.require_as(6)?;
+        let cos_cache: Option<NdTensorView<f32, 2>> = inputs.get_as(7)?;
+        let sin_cache: Option<NdTensorView<f32, 2>> = inputs.get_as(8)?;
+        let position_ids: Option<NdTensorView<i32, 2>> = inputs.get_as(9)?;
+        let attention_bias: Option<TensorView> = inputs.get_as(10)?;
+
+        let (num_heads, kv_num_heads) = (self.num_heads, self.kv_num_heads);
+        if num_heads == 0 || kv_num_heads == 0 || !num_heads.is_multiple_of(kv_num_heads) {
+            return Err(OpError::InvalidValue(
+                "Number of query heads must be a multiple of number of key-value heads",
+            ));
+        }
+
+        // Split packed QKV input if separate keys and values are not given.
+        let [batch, seq, q_hidden] = query.shape();
+        let (query, key, value, head_size) = match (key, value) {
+            (Some(key), Some(value)) => {
+                if !q_hidden.is_multiple_of(num_heads) {
+                    return Err(OpError::InvalidValue(
+                        "Query size must be a multiple of number of heads",
+                    ));
+                }
+                let head_size = q_hidden / num_heads;
+                let kv_shape = [batch, seq, kv_num_heads * head_size];
+                if key.shape() != kv_shape || value.shape() != kv_shape {
+                    return Err(OpError::IncompatibleInputShapes(
+                        "Key and value must have shape (batch, seq, kv_num_heads * head_size)",
+                    ));
+                }
+                (query, key, value, head_size)
+            }
+            (None, None) => {
+                let packed_heads = num_heads + 2 * kv_num_heads;
+                if !q_hidden.is_multiple_of(packed_heads) {
+                    return Err(OpError::InvalidValue(
+                        "Packed QKV size must be a multiple of total number of heads",
+                    ));
+                }
+                let head_size = q_hidden / packed_heads;
+                let q_end = num_heads * head_size;
+                let k_end = q_end + kv_num_heads * head_size;
+                (
+                    query.slice((.., .., ..q_end)),
+                    query.slice((.., .., q_end..k_end)),
+                    query.slice((.., .., k_end..)),
+                    head_size,
+                )
+            }
+            _ => {
+                return Err(OpError::MissingInputs);
+            }
+        };
+
+        if seqlens_k.size(0) != batch {
+            return Err(OpError::IncompatibleInputShapes(
+                "seqlens_k length must match batch size",
+            ));
+        }
+        let past_len = match (past_key, past_value) {
+            (Some(past_key), Some(past_value)) => {
+                let [past_batch, past_heads, past_len, past_head_size] = past_key.shape();
+                if past_value.shape() != past_key.shape()
+                    || past_batch != batch
+                    || past_heads != kv_num_heads
+                    || past_head_size != head_size
+                {
+                    return Err(OpError::IncompatibleInputShapes(
+                        "Past key and value must have shape (batch, kv_num_heads, past_seq, head_size)",
+                    ));
+                }
+                past_len
+            }
+            (None, None) => 0,
+            _ => {
+                return Err(OpError::MissingInputs);
+            }
+        };
+        let total_seq_len = usize::try_from(total_seq_len)
+            .map_err(|_| OpError::InvalidValue("total_sequence_length must be positive"))?;
+        let present_len = past_len.max(total_seq_len);
+
+        // `seqlens_k` is the number of valid keys, including those for the
+        // current step, minus one. The current step's keys are appended after
+        // the valid keys in the cache.
+        let positions = seqlens_k
+            .iter()
+            .map(|&len| {
+                let kv_len = usize::try_from(len).ok()? + 1;
+                let query_offset = kv_len.saturating_sub(seq);
+                (query_offset <= past_len && query_offset + seq <= present_len).then_some(
+                    SeqPositions {
+                        kv_len,
+                        query_offset,
+                    },
+                )
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or(OpError::InvalidValue(
+                "seqlens_k is inconsistent with sequence lengths",
+            ))?;
+
+        let mut query = query.as_dyn().to_tensor_in(pool);
+        let mut key = key.as_dyn().to_tensor_in(pool);
+        if self.do_rotary {
+            let (Some(cos), Some(sin)) = (cos_cache, sin_cache) else {
+                return Err(OpError::MissingInputs);
+            };
+
+            // Positions default to the position of each token in the key
+            // sequence.
+            let position_ids = match position_ids {
+                Some(ids) => ids.as_cow(),
+                None => NdTensor::from_fn([batch, seq], |[b, s]| {
+                    (positions[b].query_offset + s)
+                        .try_into()
+                        .unwrap_or(i32::MAX)
+                })
+                .into_cow(),
+            };
+
+            let rope = RotaryEmbedding {
+                interleaved: self.rotary_interleaved,
+                num_heads: Some(num_heads),
+                rotary_embedding_dim: Some(cos.size(1) * 2),
+            };
+            for (x, num_heads) in [(&mut query, num_heads), (&mut key, kv_num_heads)] {
+                let rope = RotaryEmbedding {
+                    num_heads: Some(num_heads),
+                    ..rope
+                };
+                rope.apply(
+                    pool,
+                    x,
+                    cos.as_dyn(),
+                    sin.as_dyn(),
+                    Some(position_ids.view()),
+                )?;
+            }
+        }
+        let query = query.auto_return(pool);
+        let key = key.auto_return(pool);
+
+        let query = split_heads(pool, query.view(), Some(num_heads))?;
+        let key = split_heads(pool, key.view(), Some(kv_num_heads))?;
+        let value = split_heads(pool, value.as_dyn(), Some(kv_num_heads))?;
+
+        // Build the updated KV cache from the valid entries in the past cache,
+        // followed by the current step's keys and values.
+        let present_shape = [batch, kv_num_heads, present_len, head_size];
+        let mut present_key = NdTensor::zeros_in(pool, present_shape);
+        let mut present_value = NdTensor::zeros_in(pool, present_shape);
+        for (b, pos) in positions.iter().enumerate() {
+            let past_end = pos.query_offset;
+            let new_end = past_end + seq;
+            if let (Some(past_key), Some(past_value)) = (past_key, past_value) {
+                present_key
+                    .slice_mut((b, .., ..past_end))
+                    .copy_from(&past_key.slice((b, .., ..past_end)));
+                present_value
+                    .slice_mut((b, .., ..past_end))
+                    .copy_from(&past_value.slice((b, .., ..past_end)));
+            }
+            present_key
+                .slice_mut((b, .., past_end..new_end))
+                .copy_from(&key.slice(b));
+            present_value
+                .slice_mut((b, .., past_end..new_end))
+                .copy_from(&value.slice(b));
+        }
+
+        let mask = match attention_bias {
+            Some(bias) if bias.ndim() > 0 => {
+                let mask_len = bias.size(bias.ndim() - 1);
+                if mask_len > present_len {
+                    return Err(OpError::IncompatibleInputShapes(
+                        "Attention bias is longer than key sequence",
+                    ));
+                }
+                let bias = bias
+                    .try_broadcast([batch, num_heads, seq, mask_len])
+                    .map_err(|_| BROADCAST_ERROR)?;
+                Some(AttentionMask::Float(bias))
+            }
+            Some(_) => {
+                return Err(OpError::InvalidValue(
+                    "Attention bias must have at least one dim",
+                ));
+            }
+            None => None,
+        };
+
+        let opts = AttentionOptions {
+            scale: self.scale.unwrap_or_else(|| 1. / (head_size as f32).sqrt()),
+            softcap: self.softcap.filter(|softcap| *softcap > 0.),
+            is_causal: true,
+            local_window: self.local_window_size,
+            flush_nans_to_zero: false,
+        };
+        let output = attention(
+            pool,
+            query.view(),
+            present_key.view(),
+            present_value.view(),
+            mask,
+            Some(&positions),
+            opts,
+        )?;
+        let output = merge_heads(pool, output);
+
+        Ok([output.into(), present_key.into(), present_value.into()]
+            .into_iter()
+            .collect())
+    }
+
+    fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
+        Some(OutputTypeList::from_slice(&[
+            OutputType::CopyFromInput(0),
+            OutputType::CopyFromInput(0),
+            OutputType::CopyFromInput(0),
+        ]))
+    }
+
+    fn as_infer_shapes(&self) -> Option<&dyn InferShapes> {
+        Some(self)
+    }
+}
+
+impl_infer_shapes!(
+    GroupQueryAttention,
+    op,
+    shape_ops::GroupQueryAttention {
+        num_heads: op.num_heads,
+        kv_num_heads: op.kv_num_heads,
+    }
+);
+
 #[cfg(test)]
 mod tests {
     use rten_tensor::prelude::*;
     use rten_tensor::rng::XorShiftRng;
-    use rten_tensor::test_util::expect_equal;
-    use rten_tensor::{NdTensor, Tensor, TensorView};
+    use rten_tensor::test_util::{expect_equal, expect_equal_with_tolerance};
+    use rten_tensor::{NdTensor, NdTensorView, Tensor, TensorView};
     use rten_testing::TestCases;
 
-    use super::{AddSoftmax, BROADCAST_ERROR, GroupedQueryAttentionMatMul, RepeatInterleave};
-    use crate::operator::{OpError, OperatorExt};
+    use super::{
+        AddSoftmax, Attention, AttentionMask, AttentionOptions, BROADCAST_ERROR,
+        GroupQueryAttention, GroupedQueryAttentionMatMul, KEY_TILE_SIZE, QUERY_TILE_SIZE,
+        RepeatInterleave, RotaryEmbedding, SeqPositions, attention,
+    };
+    use crate::buffer_pool::BufferPool;
+    use crate::operator::{InputList, OpError, OpRunContext, Operator, OperatorExt};
+    use crate::ops::concat::concat;
     use crate::ops::{Add, Softmax};
+    use crate::value::ValueView;
 
     fn reference_add_softmax(x: TensorView, y: TensorView) -> Result<Tensor, OpError> {
         let add = Add {};
@@ -462,4 +1552,990 @@ mod tests {
         let qkv: NdTensor<f32, 4> = op.run_simple((query_key.view(), value.view())).unwrap();
         assert_eq!(qkv.shape(), [batch, query_heads, seq, d_model]);
     }
+
+    /// Mask input for [`reference_attention`].
+    enum RefMask<'a> {
+        Bool(NdTensorView<'a, bool, 4>),
+        Float(NdTensorView<'a, f32, 4>),
+    }
+
+    /// Reference implementation of attention which computes the full matrix
+    /// of attention scores.
+    ///
+    /// `mask` must have shape `(batch, q_heads, q_seq, mask_len)` where
+    /// `mask_len <= kv_seq`.
+    fn reference_attention(
+        q: NdTensorView<f32, 4>,
+        k: NdTensorView<f32, 4>,
+        v: NdTensorView<f32, 4>,
+        mask: Option<RefMask>,
+        positions: Option<&[SeqPositions]>,
+        opts: AttentionOptions,
+    ) -> NdTensor<f32, 4> {
+        let [batch, q_heads, q_seq, head_size] = q.shape();
+        let [_, kv_heads, kv_seq, v_head_size] = v.shape();
+        let group_size = q_heads / kv_heads;
+        let mut out = NdTensor::zeros([batch, q_heads, q_seq, v_head_size]);
+
+        for b in 0..batch {
+            let (kv_len, query_offset) = positions
+                .map(|pos| (pos[b].kv_len, pos[b].query_offset))
+                .unwrap_or((kv_seq, 0));
+            for h in 0..q_heads {
+                let kv_h = h / group_size;
+                for i in 0..q_seq {
+                    let mut scores: Vec<f32> = (0..kv_seq)
+                        .map(|j| {
+                            let mut score = 0.;
+                            for d in 0..head_size {
+                                score += q[[b, h, i, d]] * k[[b, kv_h, j, d]];
+                            }
+                            score *= opts.scale;
+                            if let Some(softcap) = opts.softcap {
+                                score = softcap * (score / softcap).tanh();
+                            }
+                            score
+                        })
+                        .collect();
+
+                    for (j, score) in scores.iter_mut().enumerate() {
+                        match &mask {
+                            Some(RefMask::Bool(mask))
+                                if j < mask.size(3) && !mask[[b, h, i, j]] =>
+                            {
+                                *score = f32::NEG_INFINITY;
+                            }
+                            Some(RefMask::Bool(mask)) if j < mask.size(3) => {}
+                            Some(RefMask::Float(mask)) if j < mask.size(3) => {
+                                *score += mask[[b, h, i, j]];
+                            }
+                            Some(_) => *score = f32::NEG_INFINITY,
+                            None => {}
+                        }
+                        let pos = query_offset + i;
+                        if (opts.is_causal && j > pos)
+                            || opts.local_window.is_some_and(|w| j + w < pos)
+                            || j >= kv_len
+                        {
+                            *score = f32::NEG_INFINITY;
+                        }
+                    }
+
+                    let max = scores.iter().fold(f32::NEG_INFINITY, |m, x| m.max(*x));
+                    let exps: Vec<f32> = scores.iter().map(|s| (s - max).exp()).collect();
+                    let sum: f32 = exps.iter().sum();
+                    for d in 0..v_head_size {
+                        let mut acc = 0.;
+                        for j in 0..kv_seq {
+                            if exps[j] != 0. {
+                                acc += exps[j] * v[[b, kv_h, j, d]];
+                            }
+                        }
+                        out[[b, h, i, d]] = if max == f32::NEG_INFINITY {
+                            if opts.flush_nans_to_zero {
+                                0.
+                            } else {
+                                f32::NAN
+                            }
+                        } else {
+                            acc / sum
+                        };
+                    }
+                }
+            }
+        }
+
+        out
+    }
+
+    #[test]
+    fn test_attention_kernel() {
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        enum MaskType {
+            None,
+            Bool,
+            Float,
+        }
+
+        #[derive(Debug)]
+        struct Case {
+            batch: usize,
+            q_heads: usize,
+            kv_heads: usize,
+            q_seq: usize,
+            kv_seq: usize,
+            head_size: usize,
+            v_head_size: usize,
+            is_causal: bool,
+            mask: MaskType,
+            mask_len: Option<usize>,
+            /// `(kv_len, query_offset)` for each batch item.
+            positions: Option<Vec<(usize, usize)>>,
+            local_window: Option<usize>,
+            softcap: Option<f32>,
+        }
+
+        impl Default for Case {
+            fn default() -> Self {
+                Case {
+                    batch: 1,
+                    q_heads: 2,
+                    kv_heads: 2,
+                    q_seq: 5,
+                    kv_seq: 5,
+                    head_size: 8,
+                    v_head_size: 8,
+                    is_causal: false,
+                    mask: MaskType::None,
+                    mask_len: None,
+                    positions: None,
+                    local_window: None,
+                    softcap: None,
+                }
+            }
+        }
+
+        let cases = [
+            // Multi-head attention
+            Case::default(),
+            // Different head sizes for keys and values
+            Case {
+                v_head_size: 4,
+                ..Default::default()
+            },
+            // Grouped-query attention
+            Case {
+                batch: 2,
+                q_heads: 8,
+                kv_heads: 2,
+                ..Default::default()
+            },
+            // Causal attention with a single query (decoding)
+            Case {
+                q_seq: 1,
+                kv_seq: 7,
+                is_causal: true,
+                ..Default::default()
+            },
+            // Causal attention over multiple query and key tiles
+            Case {
+                q_seq: QUERY_TILE_SIZE + 13,
+                kv_seq: KEY_TILE_SIZE + 21,
+                is_causal: true,
+                ..Default::default()
+            },
+            // Non-causal attention over multiple key tiles
+            Case {
+                q_seq: 3,
+                kv_seq: 2 * KEY_TILE_SIZE + 5,
+                ..Default::default()
+            },
+            // Boolean mask
+            Case {
+                mask: MaskType::Bool,
+                ..Default::default()
+            },
+            // Additive float mask
+            Case {
+                mask: MaskType::Float,
+                kv_seq: KEY_TILE_SIZE + 3,
+                ..Default::default()
+            },
+            // Mask that is shorter than the key sequence
+            Case {
+                mask: MaskType::Float,
+                kv_seq: 6,
+                mask_len: Some(4),
+                ..Default::default()
+            },
+            // Padding in key sequence
+            Case {
+                batch: 2,
+                kv_seq: 6,
+                positions: Some([(6, 0), (3, 0)].into()),
+                ..Default::default()
+            },
+            // Causal attention with cached keys and padding
+            Case {
+                batch: 2,
+                q_seq: 3,
+                kv_seq: 10,
+                is_causal: true,
+                positions: Some([(10, 7), (6, 3)].into()),
+                ..Default::default()
+            },
+            // Causal attention with a local window
+            Case {
+                q_seq: QUERY_TILE_SIZE + 5,
+                kv_seq: QUERY_TILE_SIZE + 5,
+                is_causal: true,
+                local_window: Some(20),
+                ..Default::default()
+            },
+            // Local window with cached keys
+            Case {
+                q_seq: 1,
+                kv_seq: KEY_TILE_SIZE + 10,
+                is_causal: true,
+                positions: Some([(KEY_TILE_SIZE + 10, KEY_TILE_SIZE + 9)].into()),
+                local_window: Some(KEY_TILE_SIZE / 2),
+                ..Default::default()
+            },
+            // Softcap
+            Case {
+                softcap: Some(0.5),
+                ..Default::default()
+            },
+        ];
+
+        cases.test_each(|case| {
+            let mut rng = XorShiftRng::new(1234);
+            let pool = BufferPool::new();
+
+            let q = NdTensor::<f32, 4>::rand(
+                [case.batch, case.q_heads, case.q_seq, case.head_size],
+                &mut rng,
+            );
+            let k = NdTensor::<f32, 4>::rand(
+                [case.batch, case.kv_heads, case.kv_seq, case.head_size],
+                &mut rng,
+            );
+            let v = NdTensor::<f32, 4>::rand(
+                [case.batch, case.kv_heads, case.kv_seq, case.v_head_size],
+                &mut rng,
+            );
+
+            let mask_shape = [
+                case.batch,
+                case.q_heads,
+                case.q_seq,
+                case.mask_len.unwrap_or(case.kv_seq),
+            ];
+            let float_mask = NdTensor::<f32, 4>::rand(mask_shape, &mut rng).map(|x| x - 0.5);
+            let bool_mask = NdTensor::<f32, 4>::rand(mask_shape, &mut rng).map(|x| *x > 0.3);
+
+            let (mask, ref_mask) = match case.mask {
+                MaskType::None => (None, None),
+                MaskType::Bool => (
+                    Some(AttentionMask::Bool(bool_mask.view())),
+                    Some(RefMask::Bool(bool_mask.view())),
+                ),
+                MaskType::Float => (
+                    Some(AttentionMask::Float(float_mask.view())),
+                    Some(RefMask::Float(float_mask.view())),
+                ),
+            };
+
+            let opts = AttentionOptions {
+                scale: 1. / (case.head_size as f32).sqrt(),
+                softcap: case.softcap,
+                is_causal: case.is_causal,
+                local_window: case.local_window,
+                flush_nans_to_zero: true,
+            };
+            let positions: Option<Vec<_>> = case.positions.as_ref().map(|pos| {
+                pos.iter()
+                    .map(|&(kv_len, query_offset)| SeqPositions {
+                        kv_len,
+                        query_offset,
+                    })
+                    .collect()
+            });
+            let positions = positions.as_deref();
+
+            let result =
+                attention(&pool, q.view(), k.view(), v.view(), mask, positions, opts).unwrap();
+            let expected =
+                reference_attention(q.view(), k.view(), v.view(), ref_mask, positions, opts);
+
+            expect_equal_with_tolerance(&result, &expected, 1e-5, 1e-5).unwrap();
+        });
+    }
+
+    #[test]
+    fn test_attention_kernel_fully_masked() {
+        let pool = BufferPool::new();
+        let mut rng = XorShiftRng::new(1234);
+        let q = NdTensor::<f32, 4>::rand([1, 1, 2, 4], &mut rng);
+        let k = NdTensor::<f32, 4>::rand([1, 1, 3, 4], &mut rng);
+        let v = NdTensor::<f32, 4>::rand([1, 1, 3, 4], &mut rng);
+
+        // First query row can attend to all keys, second to none.
+        let mask = NdTensor::from([[true, true, true], [false, false, false]]);
+        let mask = mask.broadcast([1, 1, 2, 3]);
+
+        for flush_nans_to_zero in [false, true] {
+            let opts = AttentionOptions {
+                scale: 0.5,
+                softcap: None,
+                is_causal: false,
+                local_window: None,
+                flush_nans_to_zero,
+            };
+            let result = attention(
+                &pool,
+                q.view(),
+                k.view(),
+                v.view(),
+                Some(AttentionMask::Bool(mask)),
+                None,
+                opts,
+            )
+            .unwrap();
+
+            assert!(result.slice((0, 0, 0)).iter().all(|x| x.is_finite()));
+            if flush_nans_to_zero {
+                assert!(result.slice((0, 0, 1)).iter().all(|x| *x == 0.));
+            } else {
+                assert!(result.slice((0, 0, 1)).iter().all(|x| x.is_nan()));
+            }
+        }
+    }
+
+    /// Convert a `(batch, heads, seq, head_size)` tensor to `(batch, seq, heads
+    /// * head_size)`.
+    fn to_3d(x: &NdTensor<f32, 4>) -> NdTensor<f32, 3> {
+        let [b, h, s, d] = x.shape();
+        x.permuted([0, 2, 1, 3])
+            .to_tensor()
+            .into_shape([b, s, h * d])
+    }
+
+    /// Convert a `(batch, seq, heads * head_size)` tensor to `(batch, heads,
+    /// seq, head_size)`.
+    fn to_4d(x: NdTensorView<f32, 3>, heads: usize) -> NdTensor<f32, 4> {
+        let [b, s, hd] = x.shape();
+        x.to_tensor()
+            .into_shape([b, s, heads, hd / heads])
+            .permuted([0, 2, 1, 3])
+            .to_tensor()
+    }
+
+    /// Run an attention operator and return all outputs.
+    fn run_attention(
+        op: &dyn Operator,
+        inputs: &[Option<ValueView>],
+        num_outputs: u32,
+    ) -> Result<Vec<Tensor>, OpError> {
+        let pool = BufferPool::new();
+        let inputs = InputList::from_optional(inputs);
+        let mut ctx = OpRunContext::new(&pool, &inputs);
+        ctx.set_num_outputs(num_outputs);
+        let outputs = op.run(&ctx)?;
+        Ok(outputs
+            .into_iter()
+            .map(|output| output.try_into().unwrap())
+            .collect())
+    }
+
+    #[test]
+    fn test_attention_op() {
+        let mut rng = XorShiftRng::new(1234);
+        let [batch, q_heads, kv_heads, seq, past_seq, head_size] = [2, 4, 2, 3, 5, 8];
+
+        let q = NdTensor::<f32, 4>::rand([batch, q_heads, seq, head_size], &mut rng);
+        let k = NdTensor::<f32, 4>::rand([batch, kv_heads, seq, head_size], &mut rng);
+        let v = NdTensor::<f32, 4>::rand([batch, kv_heads, seq, head_size], &mut rng);
+        let past_k = NdTensor::<f32, 4>::rand([batch, kv_heads, past_seq, head_size], &mut rng);
+        let past_v = NdTensor::<f32, 4>::rand([batch, kv_heads, past_seq, head_size], &mut rng);
+        let mask = NdTensor::<f32, 2>::rand([seq, past_seq + seq], &mut rng);
+
+        let op = Attention::default();
+        let outputs = run_attention(
+            &op,
+            &[
+                Some(q.view().into()),
+                Some(k.view().into()),
+                Some(v.view().into()),
+                Some(mask.view().into()),
+                Some(past_k.view().into()),
+                Some(past_v.view().into()),
+            ],
+            3,
+        )
+        .unwrap();
+        assert_eq!(outputs.len(), 3);
+
+        let pool = BufferPool::new();
+        let present_k = concat(&pool, &[past_k.as_dyn(), k.as_dyn()], 2).unwrap();
+        let present_v = concat(&pool, &[past_v.as_dyn(), v.as_dyn()], 2).unwrap();
+        let expected = reference_attention(
+            q.view(),
+            present_k.nd_view(),
+            present_v.nd_view(),
+            Some(RefMask::Float(mask.broadcast([
+                batch,
+                q_heads,
+                seq,
+                past_seq + seq,
+            ]))),
+            None,
+            AttentionOptions {
+                scale: 1. / (head_size as f32).sqrt(),
+                softcap: None,
+                is_causal: false,
+                local_window: None,
+                flush_nans_to_zero: false,
+            },
+        );
+
+        expect_equal_with_tolerance(&outputs[0], &expected.into_dyn(), 1e-5, 1e-5).unwrap();
+        assert_eq!(outputs[1], present_k);
+        assert_eq!(outputs[2], present_v);
+
+        // Run with 3D inputs. The result should be the same as for 4D inputs
+        // after merging heads.
+        let op = Attention {
+            q_num_heads: Some(q_heads),
+            kv_num_heads: Some(kv_heads),
+            is_causal: true,
+            ..Default::default()
+        };
+        let outputs_4d = run_attention(
+            &op,
+            &[
+                Some(q.view().into()),
+                Some(k.view().into()),
+                Some(v.view().into()),
+            ],
+            1,
+        )
+        .unwrap();
+        let outputs_3d = run_attention(
+            &op,
+            &[
+                Some(to_3d(&q).view().into()),
+                Some(to_3d(&k).view().into()),
+                Some(to_3d(&v).view().into()),
+            ],
+            1,
+        )
+        .unwrap();
+        assert_eq!(outputs_3d.len(), 1);
+        let expected_3d = to_3d(&outputs_4d[0].nd_view().to_tensor());
+        expect_equal(&outputs_3d[0], &expected_3d.into_dyn()).unwrap();
+
+        // Padding lengths specified as int64 tensor.
+        let nonpad_kv_seqlen = NdTensor::from([3i64, 1]);
+        let outputs = run_attention(
+            &Attention::default(),
+            &[
+                Some(q.view().into()),
+                Some(k.view().into()),
+                Some(v.view().into()),
+                None,
+                None,
+                None,
+                Some(nonpad_kv_seqlen.view().into()),
+            ],
+            1,
+        )
+        .unwrap();
+        let expected = reference_attention(
+            q.view(),
+            k.view(),
+            v.view(),
+            None,
+            Some(&[
+                SeqPositions {
+                    kv_len: 3,
+                    query_offset: 0,
+                },
+                SeqPositions {
+                    kv_len: 1,
+                    query_offset: 0,
+                },
+            ]),
+            AttentionOptions {
+                scale: 1. / (head_size as f32).sqrt(),
+                softcap: None,
+                is_causal: false,
+                local_window: None,
+                flush_nans_to_zero: false,
+            },
+        );
+        expect_equal_with_tolerance(&outputs[0], &expected.into_dyn(), 1e-5, 1e-5).unwrap();
+    }
+
+    #[test]
+    fn test_attention_op_invalid() {
+        let mut rng = XorShiftRng::new(1234);
+        let q = NdTensor::<f32, 4>::rand([1, 3, 4, 8], &mut rng);
+        let k = NdTensor::<f32, 4>::rand([1, 2, 4, 8], &mut rng);
+        let q_3d = NdTensor::<f32, 3>::rand([1, 4, 8], &mut rng);
+        let long_mask = NdTensor::<f32, 2>::rand([4, 5], &mut rng);
+
+        #[derive(Debug)]
+        struct Case<'a> {
+            op: Attention,
+            inputs: Vec<Option<ValueView<'a>>>,
+            expected: OpError,
+        }
+
+        let cases = [
+            Case {
+                op: Attention::default(),
+                inputs: [q.view(), k.view(), k.view()]
+                    .map(|x| Some(x.into()))
+                    .into(),
+                expected: OpError::IncompatibleInputShapes(
+                    "Query heads must be a multiple of key-value heads",
+                ),
+            },
+            Case {
+                op: Attention::default(),
+                inputs: [q_3d.view(), q_3d.view(), q_3d.view()]
+                    .map(|x| Some(x.into()))
+                    .into(),
+                expected: OpError::InvalidValue("Number of heads must be specified for 3D inputs"),
+            },
+            Case {
+                op: Attention::default(),
+                inputs: vec![
+                    Some(k.view().into()),
+                    Some(k.view().into()),
+                    Some(k.view().into()),
+                    Some(long_mask.view().into()),
+                ],
+                expected: OpError::IncompatibleInputShapes("Mask is longer than key sequence"),
+            },
+        ];
+
+        cases.test_each(|case| {
+            let result = run_attention(&case.op, &case.inputs, 1);
+            assert_eq!(result.err().as_ref(), Some(&case.expected));
+        });
+    }
+
+    /// Reference implementation of rotary embeddings for `(batch, heads, seq,
+    /// head_size)` inputs.
+    fn reference_rotary_embedding(
+        x: NdTensorView<f32, 4>,
+        cos: NdTensorView<f32, 2>,
+        sin: NdTensorView<f32, 2>,
+        position_ids: NdTensorView<i32, 2>,
+        rotary_dim: usize,
+        interleaved: bool,
+    ) -> NdTensor<f32, 4> {
+        let [batch, heads, seq, _] = x.shape();
+        let half_dim = rotary_dim / 2;
+        let mut out = x.to_tensor();
+        for b in 0..batch {
+            for h in 0..heads {
+                for s in 0..seq {
+                    let pos = position_ids[[b, s]] as usize;
+                    for i in 0..half_dim {
+                        let (i1, i2) = if interleaved {
+                            (2 * i, 2 * i + 1)
+                        } else {
+                            (i, i + half_dim)
+                        };
+                        let (x1, x2) = (x[[b, h, s, i1]], x[[b, h, s, i2]]);
+                        let (c, sn) = (cos[[pos, i]], sin[[pos, i]]);
+                        out[[b, h, s, i1]] = c * x1 - sn * x2;
+                        out[[b, h, s, i2]] = sn * x1 + c * x2;
+                    }
+                }
+            }
+        }
+        out
+    }
+
+    /// Generate `(max_pos, head_size / 2)` cos and sin caches.
+    fn rotary_caches(max_pos: usize, head_size: usize) -> (NdTensor<f32, 2>, NdTensor<f32, 2>) {
+        let angle =
+            |pos: usize, i: usize| pos as f32 * 10000f32.powf(-2. * i as f32 / head_size as f32);
+        let shape = [max_pos, head_size / 2];
+        (
+            NdTensor::from_fn(shape, |[pos, i]| angle(pos, i).cos()),
+            NdTensor::from_fn(shape, |[pos, i]| angle(pos, i).sin()),
+        )
+    }
+
+    #[test]
+    fn test_rotary_embedding() {
+        #[derive(Debug)]
+        struct Case {
+            interleaved: bool,
+            rotary_dim: Option<usize>,
+            use_position_ids: bool,
+            input_3d: bool,
+        }
+
+        let cases = [
+            Case {
+                interleaved: false,
+                rotary_dim: None,
+                use_position_ids: false,
+                input_3d: false,
+            },
+            Case {
+                interleaved: true,
+                rotary_dim: None,
+                use_position_ids: true,
+                input_3d: false,
+            },
+            Case {
+                interleaved: false,
+                rotary_dim: Some(4),
+                use_position_ids: true,
+                input_3d: true,
+            },
+            Case {
+                interleaved: true,
+                rotary_dim: Some(6),
+                use_position_ids: false,
+                input_3d: true,
+            },
+        ];
+
+        cases.test_each(|case| {
+            let mut rng = XorShiftRng::new(1234);
+            let [batch, heads, seq, head_size, max_pos] = [2, 3, 4, 8, 10];
+            let x = NdTensor::<f32, 4>::rand([batch, heads, seq, head_size], &mut rng);
+            let (cos, sin) = rotary_caches(max_pos, head_size);
+            let position_ids = NdTensor::from_fn([batch, seq], |[b, s]| (b * 3 + s) as i32);
+
+            // Without position IDs, the caches contain the values for each
+            // position in the input.
+            let gather = |cache: &NdTensor<f32, 2>| {
+                NdTensor::from_fn([batch, seq, head_size / 2], |[b, s, i]| {
+                    cache[[position_ids[[b, s]] as usize, i]]
+                })
+                .into_dyn()
+            };
+            let (cos_input, sin_input) = if case.use_position_ids {
+                (cos.clone().into_dyn(), sin.clone().into_dyn())
+            } else {
+                (gather(&cos), gather(&sin))
+            };
+            let x_input = if case.input_3d {
+                to_3d(&x).into_dyn()
+            } else {
+                x.clone().into_dyn()
+            };
+
+            let op = RotaryEmbedding {
+                interleaved: case.interleaved,
+                num_heads: case.input_3d.then_some(heads),
+                rotary_embedding_dim: case.rotary_dim,
+            };
+            let outputs = run_attention(
+                &op,
+                &[
+                    Some(x_input.view().into()),
+                    Some(cos_input.view().into()),
+                    Some(sin_input.view().into()),
+                    case.use_position_ids.then(|| position_ids.view().into()),
+                ],
+                1,
+            )
+            .unwrap();
+
+            let expected = reference_rotary_embedding(
+                x.view(),
+                cos.view(),
+                sin.view(),
+                position_ids.view(),
+                case.rotary_dim.unwrap_or(head_size),
+                case.interleaved,
+            );
+            let expected = if case.input_3d {
+                to_3d(&expected).into_dyn()
+            } else {
+                expected.into_dyn()
+            };
+            expect_equal_with_tolerance(&outputs[0], &expected, 1e-5, 1e-5).unwrap();
+        });
+    }
+
+    #[test]
+    fn test_rotary_embedding_invalid() {
+        let mut rng = XorShiftRng::new(1234);
+        let x = NdTensor::<f32, 4>::rand([1, 2, 3, 8], &mut rng);
+        let (cos, sin) = rotary_caches(4, 8);
+        let position_ids = NdTensor::from([[0, 1, 4]]);
+
+        let result = run_attention(
+            &RotaryEmbedding::default(),
+            &[
+                Some(x.view().into()),
+                Some(cos.view().into()),
+                Some(sin.view().into()),
+                Some(position_ids.view().into()),
+            ],
+            1,
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("Position ID is out of range"))
+        );
+
+        let result = run_attention(
+            &RotaryEmbedding {
+                rotary_embedding_dim: Some(5),
+                ..Default::default()
+            },
+            &[
+                Some(x.view().into()),
+                Some(cos.view().into()),
+                Some(sin.view().into()),
+                Some(position_ids.view().into()),
+            ],
+            1,
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "Rotary embedding dim must be even and not exceed head size"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_group_query_attention() {
+        #[derive(Debug)]
+        struct Case {
+            seq: usize,
+            /// Size of the past key-value cache, or `None` if there is no
+            /// past input.
+            past_seq: Option<usize>,
+            seqlens_k: Vec<i32>,
+            total_seq_len: i32,
+            packed_qkv: bool,
+            do_rotary: bool,
+            local_window_size: Option<usize>,
+        }
+
+        let cases = [
+            // Prefill
+            Case {
+                seq: 3,
+                past_seq: None,
+                seqlens_k: [2, 2].into(),
+                total_seq_len: 3,
+                packed_qkv: false,
+                do_rotary: false,
+                local_window_size: None,
+            },
+            // Prefill with packed QKV
+            Case {
+                seq: 3,
+                past_seq: None,
+                seqlens_k: [2, 2].into(),
+                total_seq_len: 3,
+                packed_qkv: true,
+                do_rotary: false,
+                local_window_size: None,
+            },
+            // Decode with cached keys and values, where batch items have
+            // different lengths.
+            Case {
+                seq: 1,
+                past_seq: Some(6),
+                seqlens_k: [4, 2].into(),
+                total_seq_len: 5,
+                packed_qkv: false,
+                do_rotary: false,
+                local_window_size: None,
+            },
+            // Decode with rotary embeddings and a local window
+            Case {
+                seq: 2,
+                past_seq: Some(4),
+                seqlens_k: [5, 3].into(),
+                total_seq_len: 6,
+                packed_qkv: true,
+                do_rotary: true,
+                local_window_size: Some(2),
+            },
+        ];
+
+        cases.test_each(|case| {
+            let mut rng = XorShiftRng::new(1234);
+            let [batch, num_heads, kv_num_heads, head_size] = [2, 4, 2, 8];
+            let seq = case.seq;
+
+            let q = NdTensor::<f32, 3>::rand([batch, seq, num_heads * head_size], &mut rng);
+            let k = NdTensor::<f32, 3>::rand([batch, seq, kv_num_heads * head_size], &mut rng);
+            let v = NdTensor::<f32, 3>::rand([batch, seq, kv_num_heads * head_size], &mut rng);
+            let past = case.past_seq.map(|past_seq| {
+                let shape = [batch, kv_num_heads, past_seq, head_size];
+                (
+                    NdTensor::<f32, 4>::rand(shape, &mut rng),
+                    NdTensor::<f32, 4>::rand(shape, &mut rng),
+                )
+            });
+            let seqlens_k = NdTensor::from(case.seqlens_k.clone());
+            let total_seq_len = NdTensor::from(case.total_seq_len);
+            let (cos, sin) = rotary_caches(16, head_size);
+
+            let pool = BufferPool::new();
+            let packed = concat(&pool, &[q.as_dyn(), k.as_dyn(), v.as_dyn()], 2).unwrap();
+            let (query, key, value) = if case.packed_qkv {
+                (packed.view(), None, None)
+            } else {
+                (q.as_dyn(), Some(k.view().into()), Some(v.view().into()))
+            };
+            let (past_k, past_v) = match &past {
+                Some((k, v)) => (Some(k.view().into()), Some(v.view().into())),
+                None => (None, None),
+            };
+            let (cos_input, sin_input) = if case.do_rotary {
+                (Some(cos.view().into()), Some(sin.view().into()))
+            } else {
+                (None, None)
+            };
+
+            let op = GroupQueryAttention {
+                num_heads,
+                kv_num_heads,
+                local_window_size: case.local_window_size,
+                do_rotary: case.do_rotary,
+                ..Default::default()
+            };
+            let outputs = run_attention(
+                &op,
+                &[
+                    Some(query.into()),
+                    key,
+                    value,
+                    past_k,
+                    past_v,
+                    Some(seqlens_k.view().into()),
+                    Some(total_seq_len.view().into()),
+                    cos_input,
+                    sin_input,
+                ],
+                3,
+            )
+            .unwrap();
+            assert_eq!(outputs.len(), 3);
+
+            // Compute expected present key and value and positions.
+            let past_len = case.past_seq.unwrap_or(0);
+            let present_len = past_len.max(case.total_seq_len as usize);
+            let positions: Vec<SeqPositions> = case
+                .seqlens_k
+                .iter()
+                .map(|&len| {
+                    let kv_len = len as usize + 1;
+                    SeqPositions {
+                        kv_len,
+                        query_offset: kv_len.saturating_sub(seq),
+                    }
+                })
+                .collect();
+            let position_ids = NdTensor::from_fn([batch, seq], |[b, s]| {
+                (positions[b].query_offset + s) as i32
+            });
+
+            let mut q4 = to_4d(q.view(), num_heads);
+            let mut k4 = to_4d(k.view(), kv_num_heads);
+            let v4 = to_4d(v.view(), kv_num_heads);
+            if case.do_rotary {
+                let rotary = |x: &NdTensor<f32, 4>| {
+                    reference_rotary_embedding(
+                        x.view(),
+                        cos.view(),
+                        sin.view(),
+                        position_ids.view(),
+                        head_size,
+                        false,
+                    )
+                };
+                q4 = rotary(&q4);
+                k4 = rotary(&k4);
+            }
+
+            let present_shape = [batch, kv_num_heads, present_len, head_size];
+            let mut present_k = NdTensor::<f32, 4>::zeros(present_shape);
+            let mut present_v = NdTensor::<f32, 4>::zeros(present_shape);
+            for (b, pos) in positions.iter().enumerate() {
+                let offset = pos.query_offset;
+                if let Some((past_k, past_v)) = &past {
+                    present_k
+                        .slice_mut((b, .., ..offset))
+                        .copy_from(&past_k.slice((b, .., ..offset)));
+                    present_v
+                        .slice_mut((b, .., ..offset))
+                        .copy_from(&past_v.slice((b, .., ..offset)));
+                }
+                present_k
+                    .slice_mut((b, .., offset..offset + seq))
+                    .copy_from(&k4.slice(b));
+                present_v
+                    .slice_mut((b, .., offset..offset + seq))
+                    .copy_from(&v4.slice(b));
+            }
+
+            let expected = reference_attention(
+                q4.view(),
+                present_k.view(),
+                present_v.view(),
+                None,
+                Some(&positions),
+                AttentionOptions {
+                    scale: 1. / (head_size as f32).sqrt(),
+                    softcap: None,
+                    is_causal: true,
+                    local_window: case.local_window_size,
+                    flush_nans_to_zero: false,
+                },
+            );
+
+            expect_equal_with_tolerance(&outputs[0], &to_3d(&expected).into_dyn(), 1e-5, 1e-5)
+                .unwrap();
+            expect_equal(&outputs[1], &present_k.into_dyn()).unwrap();
+            expect_equal(&outputs[2], &present_v.into_dyn()).unwrap();
+        });
+    }
+
+    #[test]
+    fn test_group_query_attention_invalid() {
+        let mut rng = XorShiftRng::new(1234);
+        let q = NdTensor::<f32, 3>::rand([1, 2, 32], &mut rng);
+        let kv = NdTensor::<f32, 3>::rand([1, 2, 16], &mut rng);
+        let total_seq_len = NdTensor::from(2);
+
+        let op = GroupQueryAttention {
+            num_heads: 4,
+            kv_num_heads: 2,
+            ..Default::default()
+        };
+        let run = |seqlens_k: &[i32]| {
+            let seqlens_k = NdTensor::from(seqlens_k.to_vec());
+            run_attention(
+                &op,
+                &[
+                    Some(q.view().into()),
+                    Some(kv.view().into()),
+                    Some(kv.view().into()),
+                    None,
+                    None,
+                    Some(seqlens_k.view().into()),
+                    Some(total_seq_len.view().into()),
+                ],
+                3,
+            )
+        };
+
+        assert!(run(&[1]).is_ok());
+
+        // Cached keys are required if the sequence length exceeds the number
+        // of new keys.
+        assert_eq!(
+            run(&[3]).err(),
+            Some(OpError::InvalidValue(
+                "seqlens_k is inconsistent with sequence lengths"
+            ))
+        );
+
+        assert_eq!(
+            run(&[1, 1]).err(),
+            Some(OpError::IncompatibleInputShapes(
+                "seqlens_k length must match batch size"
+            ))
+        );
+    }
 }
@@ -15,7 +15,7 @@ use crate::operator::{
     IntoOpResult, OpError, OpRunContext, Operator, OutputList, OutputType, OutputTypeList,
     OutputTypesContext,
 };
-use crate::ops::{map_value, map_value_view};
+use crate::ops::{map_value, map_value_view, unify_int_types};
 use crate::value::{DataType, Value, ValueType, ValueView};
 
 /// Given the shapes of two inputs to a binary operation, return the shape
@@ -416,6 +416,26 @@ fn binary_commutative_op<T: Copy + Debug + Default>(
     }
 }
 
+/// Extract two input operands from `$inputs`, converting them to a common type
+/// if they are a mix of `i32` and `i64` or `bool` tensors, then invoke
+/// `$op_func` with the tensor types listed in `$types`. Any additional
+/// arguments are passed to `$op_func` after the operands.
+///
+/// See [`unify_int_types`].
+macro_rules! run_unified_op {
+    ($pool:expr, $inputs:expr, $op_func:ident, [$($types:ident),*] $(, $arg:expr)*) => {{
+        let a = $inputs.require(0)?;
+        let b = $inputs.require(1)?;
+        let (a_unified, b_unified) = unify_int_types($pool, &a, &b)?;
+        let a = a_unified.as_ref().map(|a| a.as_view()).unwrap_or(a);
+        let b = b_unified.as_ref().map(|b| b.as_view()).unwrap_or(b);
+        map_value_view!(a, a, [$($types),*], {
+            let b = b.try_into().map_err(|error| OpError::InputCastFailed { index: 1, error })?;
+            $op_func($pool, a, b $(, $arg)*).into_op_result()
+        })
+    }};
+}
+
 /// Extract two input operands from `$inputs` and invoke the appropriate
 /// instantiation of `$op_func` depending on the tensor type.
 macro_rules! run_typed_op {
@@ -505,14 +525,14 @@ impl Operator for Add {
 
 /// Define a logical boolean operator.
 ///
-/// These accept two i32 tensors and produce an i32 result.
+/// These accept two bool or i32 tensors and produce a result of the same type.
 macro_rules! logical_boolean_op {
     ($op:ident, $op_fn:ident, $expr:expr) => {
-        pub fn $op_fn<T: AsBool + Copy + Debug>(
+        pub fn $op_fn<T: AsBool + Copy + Debug + From<bool>>(
             pool: &BufferPool,
             a: TensorView<T>,
             b: TensorView<T>,
-        ) -> Result<Tensor<i32>, OpError> {
+        ) -> Result<Tensor<T>, OpError> {
             #[allow(clippy::redundant_closure_call)]
             binary_op(pool, a, b, &|x: T, y: T| {
                 $expr(x.as_bool(), y.as_bool()).into()
@@ -539,14 +559,15 @@ macro_rules! logical_boolean_op {
             }
 
             fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
-                let inputs = ctx.inputs();
-                let a: TensorView<i32> = inputs.require_as(0)?;
-                let b: TensorView<i32> = inputs.require_as(1)?;
-                $op_fn(ctx.pool(), a, b).into_op_result()
+                run_unified_op!(ctx.pool(), ctx.inputs(), $op_fn, [BoolTensor, Int32Tensor])
+            }
+
+            fn supports_int64_bool_input(&self, _index: usize) -> bool {
+                true
             }
 
             fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
-                Some([OutputType::Fixed(ValueType::Tensor(DataType::Int32))].into())
+                Some([OutputType::CopyFromInput(0)].into())
             }
 
             fn as_infer_shapes(&self) -> Option<&dyn InferShapes> {
@@ -560,6 +581,166 @@ logical_boolean_op!(And, and, |x, y| x && y);
 logical_boolean_op!(Or, or, |x, y| x || y);
 logical_boolean_op!(Xor, xor, |x, y| x ^ y);
 
+/// Define a bitwise operator.
+///
+/// These accept two integer tensors and produce a result of the same type.
+macro_rules! bitwise_op {
+    ($op:ident, $op_fn:ident, $trait:ident, $expr:expr) => {
+        pub fn $op_fn<T: Copy + Debug + Default + std::ops::$trait<Output = T>>(
+            pool: &BufferPool,
+            a: TensorView<T>,
+            b: TensorView<T>,
+        ) -> Result<Tensor<T>, OpError> {
+            binary_commutative_op(pool, a, b, &$expr)
+        }
+
+        #[derive(Debug)]
+        pub struct $op {}
+
+        impl Operator for $op {
+            fn name(&self) -> &str {
+                stringify!($op)
+            }
+
+            fn max_inputs(&self) -> Option<usize> {
+                Some(2)
+            }
+
+            fn is_commutative(&self) -> bool {
+                true
+            }
+
+            fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
+                run_unified_op!(
+                    ctx.pool(),
+                    ctx.inputs(),
+                    $op_fn,
+                    [Int32Tensor, Int64Tensor, Int8Tensor, UInt8Tensor]
+                )
+            }
+
+            fn supports_int64_bool_input(&self, _index: usize) -> bool {
+                true
+            }
+
+            fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
+                Some([OutputType::CopyFromInput(0)].into())
+            }
+
+            fn as_infer_shapes(&self) -> Option<&dyn InferShapes> {
+                Some(&BinaryOp)
+            }
+        }
+    };
+}
+
+bitwise_op!(BitwiseAnd, bitwise_and, BitAnd, |x, y| x & y);
+bitwise_op!(BitwiseOr, bitwise_or, BitOr, |x, y| x | y);
+bitwise_op!(BitwiseXor, bitwise_xor, BitXor, |x, y| x ^ y);
+
+/// Direction of shift for [`BitShift`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum BitShiftDirection {
+    Left,
+    Right,
+}
+
+/// Logical bit shift for integer types.
+///
+/// Values are treated as unsigned. Shifting by an amount that is negative or
+/// greater than or equal to the number of bits produces zero.
+pub trait ShiftBits: Copy {
+    fn shift_left(self, n: Self) -> Self;
+    fn shift_right(self, n: Self) -> Self;
+}
+
+impl ShiftBits for u8 {
+    fn shift_left(self, n: u8) -> u8 {
+        self.checked_shl(n.into()).unwrap_or(0)
+    }
+
+    fn shift_right(self, n: u8) -> u8 {
+        self.checked_shr(n.into()).unwrap_or(0)
+    }
+}
+
+impl ShiftBits for i32 {
+    fn shift_left(self, n: i32) -> i32 {
+        (self as u32).checked_shl(n as u32).unwrap_or(0) as i32
+    }
+
+    fn shift_right(self, n: i32) -> i32 {
+        (self as u32).checked_shr(n as u32).unwrap_or(0) as i32
+    }
+}
+
+impl ShiftBits for i64 {
+    fn shift_left(self, n: i64) -> i64 {
+        let n = u32::try_from(n).unwrap_or(u32::MAX);
+        (self as u64).checked_shl(n).unwrap_or(0) as i64
+    }
+
+    fn shift_right(self, n: i64) -> i64 {
+        let n = u32::try_from(n).unwrap_or(u32::MAX);
+        (self as u64).checked_shr(n).unwrap_or(0) as i64
+    }
+}
+
+/// Shift the bits of each element in `a` by the corresponding amount in `b`.
+pub fn bit_shift<T: ShiftBits + Debug>(
+    pool: &BufferPool,
+    a: TensorView<T>,
+    b: TensorView<T>,
+    direction: BitShiftDirection,
+) -> Result<Tensor<T>, OpError> {
+    binary_op(
+        pool,
+        a,
+        b,
+        match direction {
+            BitShiftDirection::Left => &|x: T, y: T| x.shift_left(y),
+            BitShiftDirection::Right => &|x: T, y: T| x.shift_right(y),
+        },
+    )
+}
+
+#[derive(Clone, Debug)]
+pub struct BitShift {
+    pub direction: BitShiftDirection,
+}
+
+impl Operator for BitShift {
+    fn name(&self) -> &str {
+        "BitShift"
+    }
+
+    fn max_inputs(&self) -> Option<usize> {
+        Some(2)
+    }
+
+    fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
+        run_unified_op!(
+            ctx.pool(),
+            ctx.inputs(),
+            bit_shift,
+            [Int32Tensor, Int64Tensor, UInt8Tensor],
+            self.direction
+        )
+    }
+
+    fn supports_int64_bool_input(&self, _index: usize) -> bool {
+        true
+    }
+
+    fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
+        Some([OutputType::CopyFromInput(0)].into())
+    }
+
+    fn as_infer_shapes(&self) -> Option<&dyn InferShapes> {
+        Some(&BinaryOp)
+    }
+}
+
 /// Perform elementwise division of two tensors.
 pub fn div<
     T: Copy
@@ -642,27 +823,25 @@ fn boolean_op<T: Copy + Debug + PartialEq + PartialOrd>(
     a: TensorView<T>,
     b: TensorView<T>,
     op: BooleanOp,
-) -> Result<Tensor<i32>, OpError> {
-    binary_op(pool, a, b, &|x, y| {
-        i32::from(match op {
-            BooleanOp::Equal => x == y,
-            BooleanOp::Less => x < y,
-            BooleanOp::LessOrEqual => x <= y,
-            BooleanOp::Greater => x > y,
-            BooleanOp::GreaterOrEqual => x >= y,
-        })
+) -> Result<Tensor<bool>, OpError> {
+    binary_op(pool, a, b, &|x, y| match op {
+        BooleanOp::Equal => x == y,
+        BooleanOp::Less => x < y,
+        BooleanOp::LessOrEqual => x <= y,
+        BooleanOp::Greater => x > y,
+        BooleanOp::GreaterOrEqual => x >= y,
     })
 }
 
 /// Define a boolean comparison operator which supports all numeric tensor
-/// types.
+/// types and produces a bool result.
 macro_rules! boolean_cmp_op {
     ($name:ident, $func:ident, $infer_shapes:expr) => {
         pub fn $func<T: Copy + Debug + PartialEq + PartialOrd>(
             pool: &BufferPool,
             a: TensorView<T>,
             b: TensorView<T>,
-        ) -> Result<Tensor<i32>, OpError> {
+        ) -> Result<Tensor<bool>, OpError> {
             boolean_op(pool, a, b, BooleanOp::$name)
         }
 
@@ -686,11 +865,20 @@ macro_rules! boolean_cmp_op {
             }
 
             fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
-                run_typed_op!(ctx.pool(), ctx.inputs(), $func)
+                run_unified_op!(
+                    ctx.pool(),
+                    ctx.inputs(),
+                    $func,
+                    [FloatTensor, Int32Tensor, Int64Tensor, BoolTensor]
+                )
+            }
+
+            fn supports_int64_bool_input(&self, _index: usize) -> bool {
+                true
             }
 
             fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
-                Some([OutputType::Fixed(ValueType::Tensor(DataType::Int32))].into())
+                Some([OutputType::Fixed(ValueType::Tensor(DataType::Bool))].into())
             }
 
             fn as_infer_shapes(&self) -> Option<&dyn InferShapes> {
@@ -764,7 +952,7 @@ pub fn mod_op<
     )
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Mod {
     /// If true, use truncated division (see [`DivMode::TruncDiv`], otherwise
     /// use flooring division (see [`DivMode::FloorDiv`]).
@@ -1073,9 +1261,9 @@ impl Operator for Sub {
     }
 }
 
-pub fn where_op<T: Copy>(
+pub fn where_op<C: AsBool + Copy, T: Copy>(
     pool: &BufferPool,
-    cond: TensorView<i32>,
+    cond: TensorView<C>,
     x: TensorView<T>,
     y: TensorView<T>,
 ) -> Result<Tensor<T>, OpError> {
@@ -1115,7 +1303,7 @@ pub fn where_op<T: Copy>(
                                 let cond_elt = *cond.get_unchecked([i0, i1, i2, i3]);
                                 let x_elt = *x.get_unchecked([i0, i1, i2, i3]);
                                 let y_elt = *y.get_unchecked([i0, i1, i2, i3]);
-                                let out_elt = if cond_elt != 0 { x_elt } else { y_elt };
+                                let out_elt = if cond_elt.as_bool() { x_elt } else { y_elt };
                                 out_uninit.get_unchecked_mut(out_offset).write(out_elt);
                                 out_offset += 1;
                             }
@@ -1148,15 +1336,32 @@ impl Operator for Where {
 
     fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
         let inputs = ctx.inputs();
-        let condition = inputs.require_as(0)?;
         let x = inputs.require(1)?;
-
-        map_value_view!(x, x, [FloatTensor, Int32Tensor], {
-            let y = inputs.require_as(2)?;
-            where_op(ctx.pool(), condition, x, y).into_op_result()
+        let y = inputs.require(2)?;
+        let (x_unified, y_unified) = unify_int_types(ctx.pool(), &x, &y)?;
+        let x = x_unified.as_ref().map(|x| x.as_view()).unwrap_or(x);
+        let y = y_unified.as_ref().map(|y| y.as_view()).unwrap_or(y);
+
+        map_value_view!(x, x, [FloatTensor, Int32Tensor, Int64Tensor, BoolTensor], {
+            let y = y
+                .try_into()
+                .map_err(|error| OpError::InputCastFailed { index: 2, error })?;
+            match inputs.require(0)? {
+                ValueView::BoolTensor(condition) => {
+                    where_op(ctx.pool(), condition, x, y).into_op_result()
+                }
+                _ => {
+                    let condition: TensorView<i32> = inputs.require_as(0)?;
+                    where_op(ctx.pool(), condition, x, y).into_op_result()
+                }
+            }
         })
     }
 
+    fn supports_int64_bool_input(&self, _index: usize) -> bool {
+        true
+    }
+
     fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
         Some([OutputType::CopyFromInput(1)].into())
     }
@@ -1177,9 +1382,10 @@ mod tests {
 
     use super::fast_broadcast_cycles_repeats;
     use super::{
-        Add, DivMode, add, add_in_place, and, div, div_in_place, equal, greater, greater_or_equal,
-        less, less_or_equal, mod_op, mul, mul_in_place, or, pow, pow_in_place, sub, sub_in_place,
-        where_op, xor,
+        Add, BitShift, BitShiftDirection, BitwiseAnd, DivMode, Equal, Where, add, add_in_place,
+        and, bit_shift, bitwise_and, bitwise_or, bitwise_xor, div, div_in_place, equal, greater,
+        greater_or_equal, less, less_or_equal, mod_op, mul, mul_in_place, or, pow, pow_in_place,
+        sub, sub_in_place, where_op, xor,
     };
     use crate::buffer_pool::BufferPool;
     use crate::operator::{InputList, OpError, OpRunContext, Operator, OperatorExt};
@@ -1379,6 +1585,72 @@ mod tests {
         let expected = Tensor::from([0, 0, 0, 1]);
         let result = and(&pool, a.view(), b.view()).unwrap();
         assert_eq!(&result, &expected);
+
+        // Bool tensors
+        let a = Tensor::from([false, true, false, true]);
+        let b = Tensor::from([false, false, true, true]);
+        let expected = Tensor::from([false, false, false, true]);
+        let result = and(&pool, a.view(), b.view()).unwrap();
+        assert_eq!(&result, &expected);
+    }
+
+    #[test]
+    fn test_bit_shift() {
+        let pool = BufferPool::new();
+
+        // Examples from ONNX backend tests.
+        let x = Tensor::from([16u8, 4, 1]);
+        let y = Tensor::from([1u8, 2, 3]);
+        let result = bit_shift(&pool, x.view(), y.view(), BitShiftDirection::Left).unwrap();
+        assert_eq!(result, Tensor::from([32u8, 16, 8]));
+        let result = bit_shift(&pool, x.view(), y.view(), BitShiftDirection::Right).unwrap();
+        assert_eq!(result, Tensor::from([8u8, 1, 0]));
+
+        // Values are treated as unsigned, and out-of-range shifts produce zero.
+        let x = Tensor::from([-1, 1, 1, 1]);
+        let y = Tensor::from([28, 31, 32, -1]);
+        let result = bit_shift(&pool, x.view(), y.view(), BitShiftDirection::Right).unwrap();
+        assert_eq!(result, Tensor::from([15, 0, 0, 0]));
+        let result = bit_shift(&pool, x.view(), y.view(), BitShiftDirection::Left).unwrap();
+        assert_eq!(result, Tensor::from([-268435456, i32::MIN, 0, 0]));
+
+        let x = Tensor::from([1i64 << 40, 1]);
+        let y = Tensor::from([8i64, 64]);
+        let result = bit_shift(&pool, x.view(), y.view(), BitShiftDirection::Right).unwrap();
+        assert_eq!(result, Tensor::from([1i64 << 32, 0]));
+
+        // Mixed i32 and i64 inputs.
+        let op = BitShift {
+            direction: BitShiftDirection::Left,
+        };
+        let x = Tensor::from([1i64, 2]);
+        let y = Tensor::from([3, 4]);
+        let result: Tensor<i64> = op.run_simple((x.view(), y.view())).unwrap();
+        assert_eq!(result, Tensor::from([8i64, 32]));
+    }
+
+    #[test]
+    fn test_bitwise_ops() {
+        let pool = BufferPool::new();
+
+        let a = Tensor::from([[0b1100, -1], [0, 7]]);
+        let b = Tensor::from([0b1010, 5]);
+        let result = bitwise_and(&pool, a.view(), b.view()).unwrap();
+        assert_eq!(result, Tensor::from([[0b1000, 5], [0, 5]]));
+        let result = bitwise_or(&pool, a.view(), b.view()).unwrap();
+        assert_eq!(result, Tensor::from([[0b1110, -1], [0b1010, 7]]));
+        let result = bitwise_xor(&pool, a.view(), b.view()).unwrap();
+        assert_eq!(result, Tensor::from([[0b0110, -6], [0b1010, 2]]));
+
+        let a = Tensor::from([0xf0u8, 0x0f]);
+        let b = Tensor::from([0xffu8, 0x01]);
+        let result = bitwise_xor(&pool, a.view(), b.view()).unwrap();
+        assert_eq!(result, Tensor::from([0x0fu8, 0x0e]));
+
+        // Float inputs are not supported.
+        let a = Tensor::from([1., 2.]);
+        let result: Result<Tensor, _> = BitwiseAnd {}.run_simple((a.view(), a.view()));
+        assert_eq!(result.err(), Some(OpError::UnsupportedType));
     }
 
     #[test]
@@ -1456,18 +1728,32 @@ mod tests {
         // Int tensor
         let a = Tensor::from([1, 2]);
         let b = Tensor::from([1, 3]);
-        let expected = Tensor::from([1, 0]);
+        let expected = Tensor::from([true, false]);
         let result = equal(&pool, a.view(), b.view()).unwrap();
         assert_eq!(&result, &expected);
 
         // Float tensor
         let a = Tensor::from([1., 2.]);
         let b = Tensor::from([1., 3.]);
-        let expected = Tensor::from([1, 0]);
+        let expected = Tensor::from([true, false]);
         let result = equal(&pool, a.view(), b.view()).unwrap();
         assert_eq!(&result, &expected);
     }
 
+    #[test]
+    fn test_equal_int64() {
+        // i64 values which differ only outside the i32 range.
+        let a = Tensor::from([1i64 << 40, (1i64 << 40) + 1]);
+        let b = Tensor::from([1i64 << 40]);
+        let result: Tensor<bool> = Equal {}.run_simple((a.view(), b.view())).unwrap();
+        assert_eq!(result, Tensor::from([true, false]));
+
+        // i64 value compared with an i32 constant.
+        let b = Tensor::from([1]);
+        let result: Tensor<bool> = Equal {}.run_simple((a.view(), b.view())).unwrap();
+        assert_eq!(result, Tensor::from([false, false]));
+    }
+
     #[test]
     fn test_greater() {
         let pool = BufferPool::new();
@@ -1475,14 +1761,14 @@ mod tests {
         // Int tensor
         let a = Tensor::from([1, 2, 5]);
         let b = Tensor::from([1, 3, 4]);
-        let expected = Tensor::from([0, 0, 1]);
+        let expected = Tensor::from([false, false, true]);
         let result = greater(&pool, a.view(), b.view()).unwrap();
         assert_eq!(&result, &expected);
 
         // Float tensor
         let a = Tensor::from([1., 2., 5.]);
         let b = Tensor::from([1., 3., 4.]);
-        let expected = Tensor::from([0, 0, 1]);
+        let expected = Tensor::from([false, false, true]);
         let result = greater(&pool, a.view(), b.view()).unwrap();
         assert_eq!(&result, &expected);
     }
@@ -1494,14 +1780,14 @@ mod tests {
         // Int tensor
         let a = Tensor::from([1, 2, 5]);
         let b = Tensor::from([1, 3, 4]);
-        let expected = Tensor::from([1, 0, 1]);
+        let expected = Tensor::from([true, false, true]);
         let result = greater_or_equal(&pool, a.view(), b.view()).unwrap();
         assert_eq!(&result, &expected);
 
         // Float tensor
         let a = Tensor::from([1., 2., 5.]);
         let b = Tensor::from([1., 3., 4.]);
-        let expected = Tensor::from([1, 0, 1]);
+        let expected = Tensor::from([true, false, true]);
         let result = greater_or_equal(&pool, a.view(), b.view()).unwrap();
         assert_eq!(&result, &expected);
     }
@@ -1513,14 +1799,14 @@ mod tests {
         // Int tensor
         let a = Tensor::from([1, 2]);
         let b = Tensor::from([1, 3]);
-        let expected = Tensor::from([0, 1]);
+        let expected = Tensor::from([false, true]);
         let result = less(&pool, a.view(), b.view()).unwrap();
         assert_eq!(&result, &expected);
 
         // Float tensor
         let a = Tensor::from([1., 2.]);
         let b = Tensor::from([1., 3.]);
-        let expected = Tensor::from([0, 1]);
+        let expected = Tensor::from([false, true]);
         let result = less(&pool, a.view(), b.view()).unwrap();
         assert_eq!(&result, &expected);
     }
@@ -1532,14 +1818,14 @@ mod tests {
         // Int tensor
         let a = Tensor::from([1, 2, 5]);
         let b = Tensor::from([1, 3, 4]);
-        let expected = Tensor::from([1, 1, 0]);
+        let expected = Tensor::from([true, true, false]);
         let result = less_or_equal(&pool, a.view(), b.view()).unwrap();
         assert_eq!(&result, &expected);
 
         // Float tensor
         let a = Tensor::from([1., 2., 5.]);
         let b = Tensor::from([1., 3., 4.]);
-        let expected = Tensor::from([1, 1, 0]);
+        let expected = Tensor::from([true, true, false]);
         let result = less_or_equal(&pool, a.view(), b.view()).unwrap();
         assert_eq!(&result, &expected);
     }
@@ -1850,6 +2136,17 @@ mod tests {
         assert_eq!(&result, &expected);
     }
 
+    #[test]
+    fn test_where_bool_int64() {
+        let cond = Tensor::from([true, false]);
+        let x = Tensor::from([i64::MAX, i64::MIN]);
+        let y = Tensor::from(0);
+        let result: Tensor<i64> = Where {}
+            .run_simple((cond.view(), x.view(), y.view()))
+            .unwrap();
+        assert_eq!(result, Tensor::from([i64::MAX, 0]));
+    }
+
     #[test]
     fn test_where_invalid_inputs() {
         let pool = BufferPool::new();
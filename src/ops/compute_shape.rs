@@ -25,7 +25,7 @@ pub struct SymbolInfo {
 
 /// Compute a tensor shape from a combination of static values and the dynamic
 /// shapes of inputs.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ComputeShape {
     /// Specifies how to map dimension sizes of inputs to symbols used by the
     /// `shape` field.
@@ -12,8 +12,8 @@ use crate::operator::{
     InputList, IntoOpResult, OpError, OpRunContext, Operator, OutputList, OutputType,
     OutputTypeList, OutputTypesContext,
 };
-use crate::ops::{map_value, map_value_view, resolve_axis};
-use crate::value::{TryFromValueError, Value, ValueView};
+use crate::ops::{map_value, map_value_view, resolve_axis, unify_int_types};
+use crate::value::{DataType, TryFromValueError, Value, ValueType, ValueView};
 
 /// Return the shape formed by concatenating all tensors along a given axis.
 fn concatenated_shape<T: Copy>(
@@ -103,7 +103,7 @@ pub fn concat_in_place<T: Copy>(
     Ok(output)
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Concat {
     pub axis: isize,
 }
@@ -119,11 +119,51 @@ impl Operator for Concat {
 
     fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
         let inputs = ctx.inputs();
+
+        // Inputs may mix `i32` values (eg. from `Shape`) with `i64` or `bool`
+        // values. Convert them to match the widest input type.
+        let wide_input = inputs
+            .iter()
+            .flatten()
+            .find(|x| x.dtype() == ValueType::Tensor(DataType::Int64))
+            .or_else(|| {
+                inputs
+                    .iter()
+                    .flatten()
+                    .find(|x| x.dtype() == ValueType::Tensor(DataType::Bool))
+            });
+        if let Some(wide_input) = wide_input
+            && inputs
+                .iter()
+                .flatten()
+                .any(|x| x.dtype() != wide_input.dtype())
+        {
+            let converted = inputs
+                .iter()
+                .flatten()
+                .map(|x| unify_int_types(ctx.pool(), &wide_input, &x).map(|(_, x)| x))
+                .collect::<Result<Vec<_>, _>>()?;
+            let unified: Vec<_> = inputs
+                .iter()
+                .flatten()
+                .zip(&converted)
+                .map(|(x, converted)| converted.as_ref().map(|c| c.as_view()).unwrap_or(x))
+                .collect();
+            let input_list = InputList::from(&unified);
+            let ctx = OpRunContext::new(ctx.pool(), &input_list);
+            return self.run(&ctx);
+        }
+
         let first = inputs.require(0)?;
-        map_value_view!(first, first, [FloatTensor, Int32Tensor], {
-            let typed_inputs = typed_inputs(inputs, first)?;
-            concat(ctx.pool(), &typed_inputs, self.axis).into_op_result()
-        })
+        map_value_view!(
+            first,
+            first,
+            [FloatTensor, Int32Tensor, Int64Tensor, BoolTensor],
+            {
+                let typed_inputs = typed_inputs(inputs, first)?;
+                concat(ctx.pool(), &typed_inputs, self.axis).into_op_result()
+            }
+        )
     }
 
     fn can_run_in_place(&self) -> bool {
@@ -139,11 +179,37 @@ impl Operator for Concat {
         true
     }
 
+    fn supports_int64_bool_input(&self, _index: usize) -> bool {
+        true
+    }
+
     fn run_in_place(&self, input: Value, ctx: &OpRunContext) -> Result<Value, OpError> {
-        map_value!(input, input, [FloatTensor, Int32Tensor], {
-            let typed_inputs = typed_inputs(ctx.inputs(), input.view())?;
-            concat_in_place(ctx.pool(), input, &typed_inputs, self.axis).map(|t| t.into())
-        })
+        // Fall back to copying if inputs need to be converted to a common type.
+        if ctx
+            .inputs()
+            .iter()
+            .flatten()
+            .any(|x| x.dtype() != input.dtype())
+        {
+            let input = input.auto_return(ctx.pool());
+            let mut inputs: Vec<_> = vec![input.as_view()];
+            for x in ctx.inputs().iter().flatten() {
+                inputs.push(x);
+            }
+            let input_list = InputList::from(&inputs);
+            let ctx = OpRunContext::new(ctx.pool(), &input_list);
+            return self.run(&ctx).map(|mut outputs| outputs.remove(0));
+        }
+
+        map_value!(
+            input,
+            input,
+            [FloatTensor, Int32Tensor, Int64Tensor, BoolTensor],
+            {
+                let typed_inputs = typed_inputs(ctx.inputs(), input.view())?;
+                concat_in_place(ctx.pool(), input, &typed_inputs, self.axis).map(|t| t.into())
+            }
+        )
     }
 
     fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
@@ -281,9 +347,12 @@ impl Operator for Tile {
         let input = inputs.require(0)?;
         let repeats = inputs.require_as(1)?;
 
-        map_value_view!(input, input, [FloatTensor, Int32Tensor], {
-            tile(ctx.pool(), input, repeats).into_op_result()
-        })
+        map_value_view!(
+            input,
+            input,
+            [FloatTensor, Int32Tensor, Int64Tensor, BoolTensor],
+            { tile(ctx.pool(), input, repeats).into_op_result() }
+        )
     }
 
     fn can_run_in_place(&self) -> bool {
@@ -291,6 +360,10 @@ impl Operator for Tile {
         true
     }
 
+    fn supports_int64_bool_input(&self, index: usize) -> bool {
+        index == 0
+    }
+
     fn run_in_place(&self, input: Value, ctx: &OpRunContext) -> Result<Value, OpError> {
         let repeats: NdTensorView<i32, 1> = ctx.inputs().require_as(0)?;
 
@@ -298,9 +371,12 @@ impl Operator for Tile {
             return Ok(input);
         }
 
-        map_value!(input, input, [FloatTensor, Int32Tensor], {
-            tile(ctx.pool(), input.view(), repeats).map(|t| t.into())
-        })
+        map_value!(
+            input,
+            input,
+            [FloatTensor, Int32Tensor, Int64Tensor, BoolTensor],
+            { tile(ctx.pool(), input.view(), repeats).map(|t| t.into()) }
+        )
     }
 
     fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
@@ -136,6 +136,11 @@ impl Operator for Loop {
         Some(self as &dyn SubgraphOperator)
     }
 
+    fn supports_int64_bool_input(&self, index: usize) -> bool {
+        // Loop-carried dependencies are passed to the body unchanged.
+        index >= 2
+    }
+
     fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
         // Type inference is not implemented for ops with subgraphs yet.
         None
@@ -218,10 +223,16 @@ impl SubgraphOperator for Loop {
             // scan_outputs...]`.
 
             // Extract condition.
-            let next_cond: Tensor<i32> = step_outputs.remove(0).try_into().map_err(|_| {
-                make_run_error(OpError::InvalidValue("condition output has incorrect type"))
-            })?;
-            let Some(&next_cond) = next_cond.item() else {
+            let next_cond = match step_outputs.remove(0) {
+                Value::BoolTensor(next_cond) => next_cond.item().map(|c| i32::from(*c)),
+                Value::Int32Tensor(next_cond) => next_cond.item().copied(),
+                _ => {
+                    return Err(make_run_error(OpError::InvalidValue(
+                        "condition output has incorrect type",
+                    )));
+                }
+            };
+            let Some(next_cond) = next_cond else {
                 return Err(make_run_error(OpError::InvalidValue(
                     "condition output should be a scalar",
                 )));
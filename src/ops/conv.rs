@@ -360,7 +360,7 @@ where
     Ok(output.into())
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Conv {
     pub groups: usize,
     pub dilations: Vec<usize>,
@@ -516,7 +516,7 @@ where
     )
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ConvInteger {
     pub groups: usize,
     pub dilations: Vec<usize>,
@@ -571,6 +571,193 @@ impl Operator for ConvInteger {
     }
 }
 
+/// Decompose a flat index into an index for each dimension of `shape`.
+fn unravel_index(mut index: usize, shape: &[usize], out: &mut [usize]) {
+    for (dim_index, &size) in out.iter_mut().zip(shape).rev() {
+        *dim_index = index % size;
+        index /= size;
+    }
+}
+
+/// Combine sliding blocks of an image into a tensor of shape `(N, C,
+/// image_shape...)`. This is the inverse of the im2col operation used to
+/// implement convolution. Values from overlapping blocks are summed.
+///
+/// `input` has shape `(N, C * product(block_shape), L)` where `L` is the
+/// number of blocks. `dilations`, `pads` and `strides` default to 1, 0 and 1
+/// respectively for each spatial dimension if empty.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__Col2Im.html>.
+pub fn col2im(
+    pool: &BufferPool,
+    input: NdTensorView<f32, 3>,
+    image_shape: &[usize],
+    block_shape: &[usize],
+    dilations: &[usize],
+    pads: &[usize],
+    strides: &[usize],
+) -> Result<Tensor, OpError> {
+    let n_spatial = image_shape.len();
+    let attr_or_default =
+        |attr: &[usize], len: usize, default: usize, err: &'static str| match attr.len() {
+            0 => Ok(vec![default; len]),
+            n if n == len => Ok(attr.to_vec()),
+            _ => Err(OpError::InvalidValue(err)),
+        };
+    let dilations = attr_or_default(
+        dilations,
+        n_spatial,
+        1,
+        "dilations len does not match spatial dims",
+    )?;
+    let pads = attr_or_default(
+        pads,
+        n_spatial * 2,
+        0,
+        "pads len does not match spatial dims",
+    )?;
+    let strides = attr_or_default(
+        strides,
+        n_spatial,
+        1,
+        "strides len does not match spatial dims",
+    )?;
+
+    if block_shape.len() != n_spatial {
+        return Err(OpError::InvalidValue(
+            "block_shape len does not match image_shape len",
+        ));
+    }
+    if dilations.contains(&0) || strides.contains(&0) || block_shape.contains(&0) {
+        return Err(OpError::InvalidValue(
+            "dilations, strides and block_shape must be > 0",
+        ));
+    }
+
+    // Compute the number of blocks along each spatial dimension.
+    let mut n_blocks = Vec::with_capacity(n_spatial);
+    for i in 0..n_spatial {
+        let padded_size = image_shape[i] + pads[i] + pads[n_spatial + i];
+        let dilated_block_size = dilations[i] * (block_shape[i] - 1) + 1;
+        let size = padded_size
+            .checked_sub(dilated_block_size)
+            .map(|size| size / strides[i] + 1)
+            .ok_or(OpError::InvalidValue(
+                "block size is larger than padded image",
+            ))?;
+        n_blocks.push(size);
+    }
+
+    let [batch, rows, cols] = input.shape();
+    let block_len: usize = block_shape.iter().product();
+    if rows % block_len != 0 {
+        return Err(OpError::InvalidValue(
+            "input dim 1 must be a multiple of the block size",
+        ));
+    }
+    if cols != n_blocks.iter().product::<usize>() {
+        return Err(OpError::InvalidValue(
+            "input dim 2 does not match number of blocks",
+        ));
+    }
+    let chans = rows / block_len;
+
+    let mut out_shape = vec![batch, chans];
+    out_shape.extend_from_slice(image_shape);
+    let mut output = Tensor::zeros_in(pool, &out_shape);
+    let image_len: usize = image_shape.iter().product();
+    if output.is_empty() {
+        return Ok(output);
+    }
+
+    let input = input.to_contiguous_in(pool);
+    let in_data = input.data();
+
+    output
+        .data_mut()
+        .unwrap()
+        .par_chunks_mut(image_len)
+        .enumerate()
+        .for_each(|(batch_chan, out_image)| {
+            let mut block_offset = vec![0; n_spatial];
+            let mut block_index = vec![0; n_spatial];
+
+            for k in 0..block_len {
+                unravel_index(k, block_shape, &mut block_offset);
+                let row = batch_chan * block_len + k;
+                let in_row = &in_data[row * cols..][..cols];
+
+                'block: for (col, &val) in in_row.iter().enumerate() {
+                    unravel_index(col, &n_blocks, &mut block_index);
+
+                    let mut out_offset = 0;
+                    for i in 0..n_spatial {
+                        let pos = (block_index[i] * strides[i] + block_offset[i] * dilations[i])
+                            as isize
+                            - pads[i] as isize;
+                        if pos < 0 || pos as usize >= image_shape[i] {
+                            continue 'block;
+                        }
+                        out_offset = out_offset * image_shape[i] + pos as usize;
+                    }
+                    out_image[out_offset] += val;
+                }
+            }
+        });
+
+    Ok(output)
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Col2Im {
+    pub dilations: Vec<usize>,
+    pub pads: Vec<usize>,
+    pub strides: Vec<usize>,
+}
+
+impl Operator for Col2Im {
+    fn name(&self) -> &str {
+        "Col2Im"
+    }
+
+    fn max_inputs(&self) -> Option<usize> {
+        Some(3)
+    }
+
+    fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
+        let inputs = ctx.inputs();
+        let input = inputs.require_as(0)?;
+        let image_shape: NdTensorView<i32, 1> = inputs.require_as(1)?;
+        let block_shape: NdTensorView<i32, 1> = inputs.require_as(2)?;
+
+        let to_sizes = |shape: NdTensorView<i32, 1>| {
+            shape
+                .iter()
+                .map(|&size| {
+                    usize::try_from(size).map_err(|_| OpError::InvalidValue("Sizes must be >= 0"))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        };
+        let image_shape = to_sizes(image_shape)?;
+        let block_shape = to_sizes(block_shape)?;
+
+        col2im(
+            ctx.pool(),
+            input,
+            &image_shape,
+            &block_shape,
+            &self.dilations,
+            &self.pads,
+            &self.strides,
+        )
+        .into_op_result()
+    }
+
+    fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
+        Some([OutputType::CopyFromInput(0)].into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::error::Error;
@@ -579,7 +766,7 @@ mod tests {
     use rten_tensor::prelude::*;
     use rten_tensor::rng::XorShiftRng;
     use rten_tensor::test_util::{ExpectEqualError, expect_equal};
-    use rten_tensor::{Tensor, TensorView};
+    use rten_tensor::{NdTensor, Tensor, TensorView};
     use rten_testing::TestCases;
 
     use crate::buffer_pool::AutoReturn;
@@ -587,7 +774,7 @@ mod tests {
     use crate::operator::{OpError, OperatorExt};
     use crate::ops::pooling::{RoundMode, calc_output_size_and_padding};
     use crate::ops::tests::expect_eq_1e4;
-    use crate::ops::{Conv, Padding, conv, conv_integer};
+    use crate::ops::{Conv, Padding, col2im, conv, conv_integer};
 
     trait ReferenceConvKernel<X, W> {
         /// Update a single output element (`self`) with a given input and weight value.
@@ -763,6 +950,70 @@ mod tests {
 
     /// Basic tests for conv. These compare the results against values
     /// computed from PyTorch as well as the reference implementation.
+    #[test]
+    fn test_col2im() {
+        #[derive(Debug)]
+        struct Case {
+            input: NdTensor<f32, 3>,
+            image_shape: Vec<usize>,
+            block_shape: Vec<usize>,
+            pads: Vec<usize>,
+            expected: Result<Tensor, OpError>,
+        }
+
+        let cases = [
+            // Example from ONNX backend tests.
+            Case {
+                input: NdTensor::from_fn([1, 5, 5], |[_, row, col]| (col * 5 + row + 1) as f32),
+                image_shape: [5, 5].into(),
+                block_shape: [1, 5].into(),
+                pads: Vec::new(),
+                expected: Ok(Tensor::arange(1., 26., None).into_shape([1, 1, 5, 5].as_slice())),
+            },
+            // Overlapping blocks
+            Case {
+                input: NdTensor::full([1, 4, 4], 1.),
+                image_shape: [3, 3].into(),
+                block_shape: [2, 2].into(),
+                pads: Vec::new(),
+                expected: Ok(NdTensor::from([[1., 2., 1.], [2., 4., 2.], [1., 2., 1.]])
+                    .into_shape([1, 1, 3, 3].as_slice())),
+            },
+            // Padded image
+            Case {
+                input: NdTensor::full([1, 4, 9], 1.),
+                image_shape: [2, 2].into(),
+                block_shape: [2, 2].into(),
+                pads: [1, 1, 1, 1].into(),
+                expected: Ok(Tensor::full(&[1, 1, 2, 2], 4.)),
+            },
+            // Block count mismatch
+            Case {
+                input: NdTensor::full([1, 4, 5], 1.),
+                image_shape: [3, 3].into(),
+                block_shape: [2, 2].into(),
+                pads: Vec::new(),
+                expected: Err(OpError::InvalidValue(
+                    "input dim 2 does not match number of blocks",
+                )),
+            },
+        ];
+
+        cases.test_each(|case| {
+            let pool = BufferPool::new();
+            let result = col2im(
+                &pool,
+                case.input.view(),
+                &case.image_shape,
+                &case.block_shape,
+                &[],
+                &case.pads,
+                &[],
+            );
+            assert_eq!(result, case.expected);
+        })
+    }
+
     #[test]
     fn test_conv() -> Result<(), Box<dyn Error>> {
         let kernel = Tensor::from_data(
@@ -356,7 +356,7 @@ pub fn conv_transpose(
     Ok(output.into_dyn())
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ConvTranspose {
     pub groups: usize,
     pub padding: Padding,
@@ -1,10 +1,14 @@
+use std::mem::MaybeUninit;
+
 use rten_base::byte_cast::{Pod, cast_pod_vec};
+use rten_base::half::{bf16, f16};
 use rten_base::num;
-
-use rten_tensor::Tensor;
+use rten_simd::SimdOp;
 use rten_tensor::prelude::*;
+use rten_tensor::{Tensor, TensorView};
+use rten_vecmath::{Bf16ToF32, ExtendInit, F16ToF32};
 
-use crate::buffer_pool::BufferPool;
+use crate::buffer_pool::{AutoReturn, BufferPool};
 use crate::infer_shapes::{InferShapes, InferShapesError, SymTensor, SymbolGen, UnaryOp};
 use crate::operator::{
     IntoOpResult, OpError, OpRunContext, Operator, OutputList, OutputType, OutputTypeList,
@@ -12,6 +16,32 @@ use crate::operator::{
 };
 use crate::value::{DataType, Value, ValueType, ValueView};
 
+/// 16-bit float types which can be widened to f32 using a vectorized kernel.
+pub(crate) trait WidenToF32: Copy {
+    /// Convert each element of `src` to f32, writing to `dest`.
+    fn widen<'a>(src: &[Self], dest: &'a mut [MaybeUninit<f32>]) -> &'a mut [f32];
+}
+
+impl WidenToF32 for f16 {
+    fn widen<'a>(src: &[f16], dest: &'a mut [MaybeUninit<f32>]) -> &'a mut [f32] {
+        F16ToF32::new(src, dest).dispatch()
+    }
+}
+
+impl WidenToF32 for bf16 {
+    fn widen<'a>(src: &[bf16], dest: &'a mut [MaybeUninit<f32>]) -> &'a mut [f32] {
+        Bf16ToF32::new(src, dest).dispatch()
+    }
+}
+
+/// Convert a tensor of 16-bit floats to f32.
+pub(crate) fn widen_to_f32<T: WidenToF32>(pool: &BufferPool, x: TensorView<T>) -> Tensor<f32> {
+    let x = x.to_contiguous_in(pool).auto_return(pool);
+    let mut out = pool.alloc(x.len());
+    out.extend_init(|uninit| T::widen(x.data(), uninit));
+    Tensor::from_data(x.shape(), out)
+}
+
 fn cast(pool: &BufferPool, input: ValueView, dtype: DataType) -> Result<Value, OpError> {
     macro_rules! cast_as {
         ($x:ident) => {
@@ -23,12 +53,63 @@ fn cast(pool: &BufferPool, input: ValueView, dtype: DataType) -> Result<Value, O
         };
     }
 
+    // Conversions to and from 16-bit floats go via f32.
+    macro_rules! cast_from_half {
+        ($x:ident, $dest_ty:ty) => {
+            Ok($x.map_in(pool, |x| x.to_f32() as $dest_ty).into())
+        };
+    }
+
+    macro_rules! cast_to_half {
+        ($x:ident, $half_ty:ty) => {
+            Ok($x.map_in(pool, |x| <$half_ty>::from_f32(*x as f32)).into())
+        };
+    }
+
+    macro_rules! cast_half_to_half {
+        ($x:ident, $half_ty:ty) => {
+            Ok($x.map_in(pool, |x| <$half_ty>::from_f32(x.to_f32())).into())
+        };
+    }
+
+    // Bools can only be cast to integer types with `as`, so go via u8.
+    macro_rules! cast_from_bool {
+        ($x:ident, $dest_ty:ty) => {
+            Ok($x.map_in(pool, |x| *x as u8 as $dest_ty).into())
+        };
+    }
+
+    macro_rules! cast_bool_to_half {
+        ($x:ident, $half_ty:ty) => {
+            Ok($x
+                .map_in(pool, |x| <$half_ty>::from_f32(*x as u8 as f32))
+                .into())
+        };
+    }
+
+    // Non-zero values are converted to true.
+    macro_rules! cast_to_bool {
+        ($x:ident, $src_ty:ty) => {
+            Ok($x.map_in(pool, |x| *x != <$src_ty>::default()).into())
+        };
+    }
+
+    macro_rules! cast_half_to_bool {
+        ($x:ident) => {
+            Ok($x.map_in(pool, |x| x.to_f32() != 0.).into())
+        };
+    }
+
     match dtype {
         DataType::Int32 => match input {
             ValueView::Int32Tensor(t) => cast_as!(t),
             ValueView::FloatTensor(t) => cast_as!(t, i32),
             ValueView::Int8Tensor(t) => cast_as!(t, i32),
             ValueView::UInt8Tensor(t) => cast_as!(t, i32),
+            ValueView::Int64Tensor(t) => cast_as!(t, i32),
+            ValueView::Float16Tensor(t) => cast_from_half!(t, i32),
+            ValueView::BFloat16Tensor(t) => cast_from_half!(t, i32),
+            ValueView::BoolTensor(t) => cast_from_bool!(t, i32),
 
             // The ONNX Cast op doesn't support sequences, although logically
             // this could be supported by casting each tensor in the sequence.
@@ -39,6 +120,10 @@ fn cast(pool: &BufferPool, input: ValueView, dtype: DataType) -> Result<Value, O
             ValueView::Int32Tensor(t) => cast_as!(t, f32),
             ValueView::Int8Tensor(t) => cast_as!(t, f32),
             ValueView::UInt8Tensor(t) => cast_as!(t, f32),
+            ValueView::Int64Tensor(t) => cast_as!(t, f32),
+            ValueView::Float16Tensor(t) => Ok(widen_to_f32(pool, t).into()),
+            ValueView::BFloat16Tensor(t) => Ok(widen_to_f32(pool, t).into()),
+            ValueView::BoolTensor(t) => cast_from_bool!(t, f32),
             ValueView::Sequence(_) => Err(OpError::UnsupportedType),
         },
         DataType::Int8 => match input {
@@ -46,6 +131,10 @@ fn cast(pool: &BufferPool, input: ValueView, dtype: DataType) -> Result<Value, O
             ValueView::FloatTensor(t) => cast_as!(t, i8),
             ValueView::Int32Tensor(t) => cast_as!(t, i8),
             ValueView::UInt8Tensor(t) => cast_as!(t, i8),
+            ValueView::Int64Tensor(t) => cast_as!(t, i8),
+            ValueView::Float16Tensor(t) => cast_from_half!(t, i8),
+            ValueView::BFloat16Tensor(t) => cast_from_half!(t, i8),
+            ValueView::BoolTensor(t) => cast_from_bool!(t, i8),
             ValueView::Sequence(_) => Err(OpError::UnsupportedType),
         },
         DataType::UInt8 => match input {
@@ -53,9 +142,126 @@ fn cast(pool: &BufferPool, input: ValueView, dtype: DataType) -> Result<Value, O
             ValueView::FloatTensor(t) => cast_as!(t, u8),
             ValueView::Int32Tensor(t) => cast_as!(t, u8),
             ValueView::Int8Tensor(t) => cast_as!(t, u8),
+            ValueView::Int64Tensor(t) => cast_as!(t, u8),
+            ValueView::Float16Tensor(t) => cast_from_half!(t, u8),
+            ValueView::BFloat16Tensor(t) => cast_from_half!(t, u8),
+            ValueView::BoolTensor(t) => cast_from_bool!(t, u8),
             ValueView::Sequence(_) => Err(OpError::UnsupportedType),
         },
+        DataType::Int64 => match input {
+            ValueView::Int64Tensor(t) => cast_as!(t),
+            ValueView::FloatTensor(t) => cast_as!(t, i64),
+            ValueView::Int32Tensor(t) => cast_as!(t, i64),
+            ValueView::Int8Tensor(t) => cast_as!(t, i64),
+            ValueView::UInt8Tensor(t) => cast_as!(t, i64),
+            ValueView::Float16Tensor(t) => cast_from_half!(t, i64),
+            ValueView::BFloat16Tensor(t) => cast_from_half!(t, i64),
+            ValueView::BoolTensor(t) => cast_from_bool!(t, i64),
+            ValueView::Sequence(_) => Err(OpError::UnsupportedType),
+        },
+        DataType::Bool => match input {
+            ValueView::BoolTensor(t) => cast_as!(t),
+            ValueView::FloatTensor(t) => cast_to_bool!(t, f32),
+            ValueView::Int32Tensor(t) => cast_to_bool!(t, i32),
+            ValueView::Int8Tensor(t) => cast_to_bool!(t, i8),
+            ValueView::UInt8Tensor(t) => cast_to_bool!(t, u8),
+            ValueView::Int64Tensor(t) => cast_to_bool!(t, i64),
+            ValueView::Float16Tensor(t) => cast_half_to_bool!(t),
+            ValueView::BFloat16Tensor(t) => cast_half_to_bool!(t),
+            ValueView::Sequence(_) => Err(OpError::UnsupportedType),
+        },
+        DataType::Float16 => match input {
+            ValueView::Float16Tensor(t) => cast_as!(t),
+            ValueView::FloatTensor(t) => cast_to_half!(t, f16),
+            ValueView::Int32Tensor(t) => cast_to_half!(t, f16),
+            ValueView::Int8Tensor(t) => cast_to_half!(t, f16),
+            ValueView::UInt8Tensor(t) => cast_to_half!(t, f16),
+            ValueView::Int64Tensor(t) => cast_to_half!(t, f16),
+            ValueView::BFloat16Tensor(t) => cast_half_to_half!(t, f16),
+            ValueView::BoolTensor(t) => cast_bool_to_half!(t, f16),
+            ValueView::Sequence(_) => Err(OpError::UnsupportedType),
+        },
+        DataType::BFloat16 => match input {
+            ValueView::BFloat16Tensor(t) => cast_as!(t),
+            ValueView::FloatTensor(t) => cast_to_half!(t, bf16),
+            ValueView::Int32Tensor(t) => cast_to_half!(t, bf16),
+            ValueView::Int8Tensor(t) => cast_to_half!(t, bf16),
+            ValueView::UInt8Tensor(t) => cast_to_half!(t, bf16),
+            ValueView::Int64Tensor(t) => cast_to_half!(t, bf16),
+            ValueView::Float16Tensor(t) => cast_half_to_half!(t, bf16),
+            ValueView::BoolTensor(t) => cast_bool_to_half!(t, bf16),
+            ValueView::Sequence(_) => Err(OpError::UnsupportedType),
+        },
+    }
+}
+
+/// Convert an `i64` or `bool` tensor to `i32`, for use with operator inputs
+/// that don't support these types.
+///
+/// Returns `Ok(None)` if `input` has a different type, or an error if an
+/// `i64` value is outside the range of `i32`.
+pub(crate) fn narrow_to_i32(
+    pool: &BufferPool,
+    input: &ValueView,
+) -> Result<Option<Tensor<i32>>, OpError> {
+    match input {
+        ValueView::Int64Tensor(t) => {
+            if !fits_in_i32(t) {
+                return Err(OpError::UnsupportedValue(
+                    "i64 value is outside the i32 range supported by this operator",
+                ));
+            }
+            Ok(Some(t.map_in(pool, |x| *x as i32)))
+        }
+        ValueView::BoolTensor(t) => Ok(Some(t.map_in(pool, |x| *x as i32))),
+        _ => Ok(None),
+    }
+}
+
+/// Convert a pair of `i32`, `i64` or `bool` inputs to a common type.
+///
+/// This is used by operators which accept `i64` or `bool` inputs that must
+/// match the type of another input. Operators such as `Shape` produce `i32`
+/// outputs, and `i64` or `bool` inputs to operators which don't support them
+/// are converted to `i32`, so an operator may receive an `i32` value alongside
+/// an `i64` or `bool` value. In that case the `i32` input is converted to the
+/// type of the other input.
+///
+/// Returns the converted values, or `None` for inputs which are unchanged.
+pub(crate) fn unify_int_types(
+    pool: &BufferPool,
+    a: &ValueView,
+    b: &ValueView,
+) -> Result<(Option<Value>, Option<Value>), OpError> {
+    let int_type = |dtype| match dtype {
+        ValueType::Tensor(dt @ (DataType::Int32 | DataType::Int64 | DataType::Bool)) => Some(dt),
+        _ => None,
+    };
+    let (Some(a_type), Some(b_type)) = (int_type(a.dtype()), int_type(b.dtype())) else {
+        return Ok((None, None));
+    };
+    if a_type == b_type {
+        return Ok((None, None));
     }
+
+    let dtype = if a_type == DataType::Int64 || b_type == DataType::Int64 {
+        DataType::Int64
+    } else {
+        DataType::Bool
+    };
+    let convert = |x: &ValueView, x_type| {
+        if x_type == dtype {
+            Ok(None)
+        } else {
+            cast(pool, x.clone(), dtype).map(Some)
+        }
+    };
+    Ok((convert(a, a_type)?, convert(b, b_type)?))
+}
+
+/// Return true if all elements of `x` are in the range of `i32`.
+fn fits_in_i32(x: &TensorView<i64>) -> bool {
+    x.iter().all(|x| i32::try_from(*x).is_ok())
 }
 
 /// Cast a tensor from type T to U in-place.
@@ -99,10 +305,26 @@ fn cast_in_place(input: Value, dtype: DataType) -> Result<Value, Value> {
             Value::Int8Tensor(t) => Ok(cast_tensor::<_, u8>(t).into()),
             _ => Err(input),
         },
+        DataType::Int64 => match input {
+            Value::Int64Tensor(t) => Ok(t.into()),
+            _ => Err(input),
+        },
+        DataType::Bool => match input {
+            Value::BoolTensor(t) => Ok(t.into()),
+            _ => Err(input),
+        },
+        DataType::Float16 => match input {
+            Value::Float16Tensor(t) => Ok(t.into()),
+            _ => Err(input),
+        },
+        DataType::BFloat16 => match input {
+            Value::BFloat16Tensor(t) => Ok(t.into()),
+            _ => Err(input),
+        },
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Cast {
     pub to: DataType,
 }
@@ -121,6 +343,10 @@ impl Operator for Cast {
         cast(ctx.pool(), input, self.to).into_op_result()
     }
 
+    fn supports_int64_bool_input(&self, _index: usize) -> bool {
+        true
+    }
+
     fn can_run_in_place(&self) -> bool {
         // Cast can run in place if the input's dtype already matches `self.to`
         // or both dtypes have the same element size.
@@ -171,7 +397,7 @@ impl InferShapes for Cast {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct CastLike {}
 
 impl Operator for CastLike {
@@ -214,6 +440,7 @@ impl Operator for CastLike {
 
 #[cfg(test)]
 mod tests {
+    use rten_base::half::{bf16, f16};
     use rten_tensor::Tensor;
     use rten_testing::TestCases;
 
@@ -284,6 +511,66 @@ mod tests {
                 dtype: DataType::Int32,
                 expected: Tensor::from([i32::MIN, i32::MAX]).into(),
             },
+            // f32 -> f16
+            Case {
+                input: Tensor::from([1., 2.5, -3.]).into(),
+                dtype: DataType::Float16,
+                expected: Tensor::from([1., 2.5, -3.].map(f16::from_f32)).into(),
+            },
+            // f16 -> f32
+            Case {
+                input: Tensor::from([1., 2.5, -3.].map(f16::from_f32)).into(),
+                dtype: DataType::Float,
+                expected: Tensor::from([1., 2.5, -3.]).into(),
+            },
+            // bf16 -> i32
+            Case {
+                input: Tensor::from([1., 2., -3.].map(bf16::from_f32)).into(),
+                dtype: DataType::Int32,
+                expected: Tensor::from([1, 2, -3]).into(),
+            },
+            // f16 -> bf16
+            Case {
+                input: Tensor::from([1., 2.5, -3.].map(f16::from_f32)).into(),
+                dtype: DataType::BFloat16,
+                expected: Tensor::from([1., 2.5, -3.].map(bf16::from_f32)).into(),
+            },
+            // i32 -> i64
+            Case {
+                input: Tensor::from([i32::MIN, 0, i32::MAX]).into(),
+                dtype: DataType::Int64,
+                expected: Tensor::from([i32::MIN as i64, 0, i32::MAX as i64]).into(),
+            },
+            // i64 -> f32
+            Case {
+                input: Tensor::from([-1i64, 1 << 40]).into(),
+                dtype: DataType::Float,
+                expected: Tensor::from([-1., (1i64 << 40) as f32]).into(),
+            },
+            // f32 -> bool
+            Case {
+                input: Tensor::from([0., 0.5, -1.]).into(),
+                dtype: DataType::Bool,
+                expected: Tensor::from([false, true, true]).into(),
+            },
+            // i64 -> bool
+            Case {
+                input: Tensor::from([0i64, 1 << 40]).into(),
+                dtype: DataType::Bool,
+                expected: Tensor::from([false, true]).into(),
+            },
+            // bool -> i32
+            Case {
+                input: Tensor::from([false, true]).into(),
+                dtype: DataType::Int32,
+                expected: Tensor::from([0, 1]).into(),
+            },
+            // bool -> u8
+            Case {
+                input: Tensor::from([false, true]).into(),
+                dtype: DataType::UInt8,
+                expected: Tensor::from([0u8, 1]).into(),
+            },
         ];
 
         cases.test_each(|case| {
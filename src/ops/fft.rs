@@ -1,13 +1,17 @@
+use std::f64::consts::PI;
+
 use rten_tensor::prelude::*;
-use rten_tensor::{NdTensor, NdTensorView, TensorView};
-use rustfft::FftPlanner;
+use rten_tensor::{NdTensor, NdTensorView, Tensor, TensorView};
 use rustfft::num_complex::Complex32;
+use rustfft::{FftDirection, FftPlanner};
 
 use crate::buffer_pool::BufferPool;
 use crate::operator::{
     IntoOpResult, OpError, OpRunContext, Operator, OutputList, OutputType, OutputTypeList,
     OutputTypesContext,
 };
+use crate::ops::resolve_axis;
+use crate::value::{DataType, ValueType};
 
 enum FftType {
     /// FFT with real input signal.
@@ -120,7 +124,7 @@ pub fn stft(
 }
 
 #[allow(clippy::upper_case_acronyms)]
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct STFT {
     pub onesided: bool,
 }
@@ -160,14 +164,351 @@ impl Operator for STFT {
     }
 }
 
+/// Compute the Discrete Fourier Transform of a real or complex signal along
+/// `axis`.
+///
+/// `input` has shape `[batch, ..., 1]` for a real signal or `[batch, ..., 2]`
+/// for a complex signal. The output has the same shape as the input, except
+/// that the last dimension has size 2 and the size of `axis` is the number of
+/// frequency bins. The signal is zero-padded or truncated to `dft_length`.
+///
+/// If `onesided` is true, only the first `dft_length / 2 + 1` bins are
+/// returned, since the remaining bins of the DFT of a real signal are
+/// redundant. If `inverse` is true, the inverse DFT is computed and the result
+/// is scaled by `1 / dft_length`.
+pub fn dft(
+    pool: &BufferPool,
+    input: TensorView,
+    dft_length: Option<usize>,
+    axis: isize,
+    inverse: bool,
+    onesided: bool,
+) -> Result<Tensor, OpError> {
+    let ndim = input.ndim();
+    if ndim < 2 {
+        return Err(OpError::InvalidValue("input must have at least 2 dims"));
+    }
+    let axis = resolve_axis(ndim, axis)?;
+    if axis == ndim - 1 {
+        return Err(OpError::InvalidValue("axis cannot be the last dimension"));
+    }
+
+    let n_components = input.size(ndim - 1);
+    let fft_type = match n_components {
+        1 => FftType::Real,
+        2 => FftType::Complex,
+        _ => {
+            return Err(OpError::InvalidValue(
+                "Last dimension of input must have size 1 or 2",
+            ));
+        }
+    };
+    if onesided && (inverse || matches!(fft_type, FftType::Complex)) {
+        return Err(OpError::UnsupportedValue(
+            "One-sided DFT is only supported for forward transforms of real inputs",
+        ));
+    }
+
+    let signal_len = input.size(axis);
+    let n_fft = dft_length.unwrap_or(signal_len);
+    if n_fft == 0 {
+        return Err(OpError::InvalidValue("dft_length must be > 0"));
+    }
+    let n_bins = if onesided { n_fft / 2 + 1 } else { n_fft };
+
+    // Move the transformed axis next to the component axis, then flatten the
+    // remaining axes into a batch.
+    let mut perm: Vec<usize> = (0..ndim - 1).filter(|&dim| dim != axis).collect();
+    perm.extend([axis, ndim - 1]);
+    let signal = input.permuted(perm.as_slice());
+    let batch_shape = &signal.shape()[..ndim - 2];
+    let batch: usize = batch_shape.iter().product();
+    let signal = signal.reshaped_in(pool, [batch, signal_len, n_components]);
+
+    let mut output = NdTensor::zeros_in(pool, [batch, n_bins, 2]);
+    let direction = if inverse {
+        FftDirection::Inverse
+    } else {
+        FftDirection::Forward
+    };
+    let fft = FftPlanner::new().plan_fft(n_fft, direction);
+    let scale = if inverse { 1. / n_fft as f32 } else { 1. };
+
+    // Temporary buffer for FFT input and output.
+    let mut tmp_buf = Vec::with_capacity(n_fft);
+
+    for (signal_item, mut out_item) in signal.axis_iter(0).zip(output.axis_iter_mut(0)) {
+        tmp_buf.clear();
+        tmp_buf.extend((0..n_fft).map(|k| {
+            if k >= signal_len {
+                return Complex32::default();
+            }
+            let re = signal_item[[k, 0]];
+            let im = match fft_type {
+                FftType::Real => 0.,
+                FftType::Complex => signal_item[[k, 1]],
+            };
+            Complex32 { re, im }
+        }));
+
+        fft.process(&mut tmp_buf);
+
+        for (bin, val) in tmp_buf.iter().take(n_bins).enumerate() {
+            out_item[[bin, 0]] = val.re * scale;
+            out_item[[bin, 1]] = val.im * scale;
+        }
+    }
+
+    // Restore the original order of axes.
+    let mut out_shape = batch_shape.to_vec();
+    out_shape.extend([n_bins, 2]);
+    let mut output = output.into_dyn();
+    output.reshape(&out_shape);
+
+    let mut inv_perm = vec![0; ndim];
+    for (i, &dim) in perm.iter().enumerate() {
+        inv_perm[dim] = i;
+    }
+    output.permute(&inv_perm);
+    output.make_contiguous();
+
+    Ok(output)
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Debug, Default)]
+pub struct DFT {
+    /// Axis to transform. This is used if the `axis` input is not set, as
+    /// in opsets < 20 where the axis was an attribute. Defaults to -2.
+    pub axis: Option<isize>,
+    pub inverse: bool,
+    pub onesided: bool,
+}
+
+impl Operator for DFT {
+    fn name(&self) -> &str {
+        "DFT"
+    }
+
+    fn max_inputs(&self) -> Option<usize> {
+        Some(3)
+    }
+
+    fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
+        let inputs = ctx.inputs();
+        let input = inputs.require_as(0)?;
+        let dft_length = inputs
+            .get_as::<i32>(1)?
+            .map(|len| {
+                usize::try_from(len).map_err(|_| OpError::InvalidValue("dft_length must be > 0"))
+            })
+            .transpose()?;
+        let axis = inputs
+            .get_as::<i32>(2)?
+            .map(|axis| axis as isize)
+            .or(self.axis)
+            .unwrap_or(-2);
+
+        dft(
+            ctx.pool(),
+            input,
+            dft_length,
+            axis,
+            self.inverse,
+            self.onesided,
+        )
+        .into_op_result()
+    }
+
+    fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
+        Some([OutputType::CopyFromInput(0)].into())
+    }
+}
+
+/// Generate a window of the form `a0 - a1 * cos(2πn / N) + a2 * cos(4πn / N)`.
+///
+/// `N` is `size` if `periodic` is true or `size - 1` otherwise.
+fn cosine_window(size: i32, periodic: bool, [a0, a1, a2]: [f64; 3]) -> Result<Tensor, OpError> {
+    let size =
+        usize::try_from(size).map_err(|_| OpError::InvalidValue("Window size must be >= 0"))?;
+    let n = if periodic {
+        size
+    } else {
+        size.saturating_sub(1)
+    } as f64;
+    let window: Vec<f32> = (0..size)
+        .map(|i| {
+            let angle = 2. * PI * i as f64 / n;
+            (a0 - a1 * angle.cos() + a2 * (2. * angle).cos()) as f32
+        })
+        .collect();
+    Ok(Tensor::from_data(&[size], window))
+}
+
+macro_rules! impl_window_op {
+    ($op:ident, $coeffs:expr) => {
+        #[derive(Clone, Debug)]
+        pub struct $op {
+            /// If true, generate a window for use in spectral analysis, where
+            /// the window is treated as one period of a periodic function.
+            /// Otherwise generate a window which is symmetric.
+            pub periodic: bool,
+        }
+
+        impl Operator for $op {
+            fn name(&self) -> &str {
+                stringify!($op)
+            }
+
+            fn max_inputs(&self) -> Option<usize> {
+                Some(1)
+            }
+
+            fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
+                let size = ctx.inputs().require_as(0)?;
+                cosine_window(size, self.periodic, $coeffs).into_op_result()
+            }
+
+            fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
+                Some([OutputType::Fixed(ValueType::Tensor(DataType::Float))].into())
+            }
+        }
+    };
+}
+
+// See https://onnx.ai/onnx/operators/onnx__HannWindow.html.
+impl_window_op!(HannWindow, [0.5, 0.5, 0.]);
+
+// See https://onnx.ai/onnx/operators/onnx__HammingWindow.html.
+impl_window_op!(HammingWindow, [25. / 46., 21. / 46., 0.]);
+
+// See https://onnx.ai/onnx/operators/onnx__BlackmanWindow.html.
+impl_window_op!(BlackmanWindow, [0.42, 0.5, 0.08]);
+
+/// Generate a matrix which maps the bins of a one-sided spectrogram to bins
+/// on the mel scale.
+///
+/// The output has shape `(dft_length / 2 + 1, num_mel_bins)`. Each column is
+/// a triangular filter. The construction of the filters follows the ONNX
+/// reference implementation.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__MelWeightMatrix.html>.
+pub fn mel_weight_matrix(
+    pool: &BufferPool,
+    num_mel_bins: usize,
+    dft_length: usize,
+    sample_rate: usize,
+    lower_edge_hertz: f32,
+    upper_edge_hertz: f32,
+) -> Result<NdTensor<f32, 2>, OpError> {
+    if dft_length == 0 || sample_rate == 0 {
+        return Err(OpError::InvalidValue(
+            "dft_length and sample_rate must be > 0",
+        ));
+    }
+    if !(0. <= lower_edge_hertz
+        && lower_edge_hertz <= upper_edge_hertz
+        && upper_edge_hertz <= sample_rate as f32 / 2.)
+    {
+        return Err(OpError::InvalidValue(
+            "Edge frequencies must satisfy 0 <= lower <= upper <= sample_rate / 2",
+        ));
+    }
+
+    let hz_to_mel = |hz: f64| 2595. * (1. + hz / 700.).log10();
+    let mel_to_hz = |mel: f64| 700. * (10f64.powf(mel / 2595.) - 1.);
+
+    let num_spectrogram_bins = dft_length / 2 + 1;
+    let low_mel = hz_to_mel(lower_edge_hertz as f64);
+    let high_mel = hz_to_mel(upper_edge_hertz as f64);
+
+    // Spectrogram bins of the edges and centers of each mel filter.
+    let n_points = num_mel_bins + 2;
+    let mel_step = (high_mel - low_mel) / n_points as f64;
+    let points: Vec<usize> = (0..n_points)
+        .map(|i| {
+            let hz = mel_to_hz(low_mel + i as f64 * mel_step);
+            ((dft_length + 1) as f64 * hz / sample_rate as f64).floor() as usize
+        })
+        .collect();
+    if points.iter().any(|&bin| bin >= num_spectrogram_bins) {
+        return Err(OpError::InvalidValue("Mel filter exceeds spectrogram bins"));
+    }
+
+    let mut output = NdTensor::zeros_in(pool, [num_spectrogram_bins, num_mel_bins]);
+    for (i, edges) in points.windows(3).enumerate() {
+        let &[low, center, high] = edges else {
+            unreachable!()
+        };
+
+        let low_to_center = center - low;
+        if low_to_center == 0 {
+            output[[center, i]] = 1.;
+        } else {
+            for j in low..=center {
+                output[[j, i]] = (j - low) as f32 / low_to_center as f32;
+            }
+        }
+
+        let center_to_high = high - center;
+        for j in center..high {
+            output[[j, i]] = (high - j) as f32 / center_to_high as f32;
+        }
+    }
+
+    Ok(output)
+}
+
+#[derive(Clone, Debug)]
+pub struct MelWeightMatrix {}
+
+impl Operator for MelWeightMatrix {
+    fn name(&self) -> &str {
+        "MelWeightMatrix"
+    }
+
+    fn max_inputs(&self) -> Option<usize> {
+        Some(5)
+    }
+
+    fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
+        let inputs = ctx.inputs();
+        let to_usize = |val: i32| {
+            usize::try_from(val).map_err(|_| OpError::InvalidValue("Expected value >= 0"))
+        };
+        let num_mel_bins = to_usize(inputs.require_as(0)?)?;
+        let dft_length = to_usize(inputs.require_as(1)?)?;
+        let sample_rate = to_usize(inputs.require_as(2)?)?;
+        let lower_edge_hertz = inputs.require_as(3)?;
+        let upper_edge_hertz = inputs.require_as(4)?;
+
+        mel_weight_matrix(
+            ctx.pool(),
+            num_mel_bins,
+            dft_length,
+            sample_rate,
+            lower_edge_hertz,
+            upper_edge_hertz,
+        )
+        .into_op_result()
+    }
+
+    fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
+        Some([OutputType::Fixed(ValueType::Tensor(DataType::Float))].into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::f64::consts::PI;
+
     use rten_tensor::prelude::*;
-    use rten_tensor::{NdTensor, Tensor};
+    use rten_tensor::test_util::expect_equal_with_tolerance;
+    use rten_tensor::{NdTensor, NdTensorView, Tensor};
     use rten_testing::TestCases;
 
-    use super::STFT;
-    use crate::operator::{InputList, OpError, OperatorExt};
+    use super::{BlackmanWindow, DFT, HammingWindow, HannWindow, MelWeightMatrix, STFT};
+    use crate::operator::{InputList, OpError, Operator, OperatorExt};
     use crate::ops::tests::{IntoNDim, expect_eq_1e4};
 
     #[test]
@@ -379,4 +720,349 @@ mod tests {
             }
         });
     }
+
+    #[test]
+    fn test_dft() {
+        #[derive(Debug)]
+        struct Case {
+            input: Tensor,
+            dft_length: Option<i32>,
+            axis: Option<i32>,
+            inverse: bool,
+            onesided: bool,
+            expected: Result<Tensor, OpError>,
+        }
+
+        impl Default for Case {
+            fn default() -> Case {
+                Case {
+                    input: Tensor::from([[[1.], [2.], [3.], [4.]]]),
+                    dft_length: None,
+                    axis: None,
+                    inverse: false,
+                    onesided: false,
+                    expected: Err(OpError::InvalidValue("Invalid expectation")),
+                }
+            }
+        }
+
+        let cases = [
+            // Real-to-complex
+            Case {
+                expected: Ok(Tensor::from([[
+                    [10., 0.],
+                    [-2., 2.],
+                    [-2., 0.],
+                    [-2., -2.],
+                ]])),
+                ..Default::default()
+            },
+            // One-sided real-to-complex
+            Case {
+                onesided: true,
+                expected: Ok(Tensor::from([[[10., 0.], [-2., 2.], [-2., 0.]]])),
+                ..Default::default()
+            },
+            // Truncated signal
+            Case {
+                dft_length: Some(2),
+                expected: Ok(Tensor::from([[[3., 0.], [-1., 0.]]])),
+                ..Default::default()
+            },
+            // Zero-padded signal
+            Case {
+                input: Tensor::from([[[1.], [2.]]]),
+                dft_length: Some(4),
+                expected: Ok(Tensor::from([[[3., 0.], [1., -2.], [-1., 0.], [1., 2.]]])),
+                ..Default::default()
+            },
+            // Inverse complex-to-complex
+            Case {
+                input: Tensor::from([[[10., 0.], [-2., 2.], [-2., 0.], [-2., -2.]]]),
+                inverse: true,
+                expected: Ok(Tensor::from([[[1., 0.], [2., 0.], [3., 0.], [4., 0.]]])),
+                ..Default::default()
+            },
+            // Non-default axis
+            Case {
+                input: Tensor::from([[[1.], [1.]], [[2.], [0.]], [[3.], [0.]], [[4.], [0.]]]),
+                axis: Some(0),
+                expected: Ok(Tensor::from([
+                    [[10., 0.], [1., 0.]],
+                    [[-2., 2.], [1., 0.]],
+                    [[-2., 0.], [1., 0.]],
+                    [[-2., -2.], [1., 0.]],
+                ])),
+                ..Default::default()
+            },
+            // Invalid axis
+            Case {
+                axis: Some(-1),
+                expected: Err(OpError::InvalidValue("axis cannot be the last dimension")),
+                ..Default::default()
+            },
+            // One-sided complex input
+            Case {
+                input: Tensor::from([[[1., 0.], [2., 0.]]]),
+                onesided: true,
+                expected: Err(OpError::UnsupportedValue(
+                    "One-sided DFT is only supported for forward transforms of real inputs",
+                )),
+                ..Default::default()
+            },
+        ];
+
+        cases.test_each(|case| {
+            let dft_length = case.dft_length.map(Tensor::from);
+            let axis = case.axis.map(Tensor::from);
+            let inputs = InputList::from_iter([
+                Some(case.input.view().into()),
+                dft_length.as_ref().map(|len| len.view().into()),
+                axis.as_ref().map(|axis| axis.view().into()),
+            ]);
+            let result: Result<Tensor, _> = DFT {
+                axis: None,
+                inverse: case.inverse,
+                onesided: case.onesided,
+            }
+            .run_simple(inputs);
+
+            match (&result, &case.expected) {
+                (Ok(result), Ok(expected)) => {
+                    expect_eq_1e4(result, expected).unwrap();
+                }
+                _ => assert_eq!(result, case.expected),
+            }
+        });
+    }
+
+    /// Compute the DFT of complex values along the first axis of `x`, which has
+    /// shape `(N, cols, 2)`, using the definition.
+    fn reference_dft(x: NdTensorView<f32, 3>, inverse: bool) -> NdTensor<f32, 3> {
+        let [n, cols, _] = x.shape();
+        let (sign, scale) = if inverse {
+            (1., 1. / n as f64)
+        } else {
+            (-1., 1.)
+        };
+        NdTensor::from_fn([n, cols, 2], |[k, col, part]| {
+            let (re, im) = (0..n).fold((0., 0.), |(re, im), t| {
+                let angle = sign * 2. * PI * (k * t % n) as f64 / n as f64;
+                let (x_re, x_im) = (x[[t, col, 0]] as f64, x[[t, col, 1]] as f64);
+                (
+                    re + x_re * angle.cos() - x_im * angle.sin(),
+                    im + x_re * angle.sin() + x_im * angle.cos(),
+                )
+            });
+            ((if part == 0 { re } else { im }) * scale) as f32
+        })
+    }
+
+    #[test]
+    fn test_dft_onnx_backend_cases() {
+        // Cases from the ONNX backend tests (`test_dft`, `test_dft_axis` and
+        // `test_dft_inverse`). These transform a 10x10 signal with shape
+        // `(1, 10, 10, 1)` along each axis, or a complex signal with shape
+        // `(1, 10, 10, 2)` for the inverse transform.
+        #[derive(Debug)]
+        struct Case {
+            axis: i32,
+            inverse: bool,
+        }
+
+        let cases = [
+            Case {
+                axis: 1,
+                inverse: false,
+            },
+            Case {
+                axis: 2,
+                inverse: false,
+            },
+            Case {
+                axis: 1,
+                inverse: true,
+            },
+        ];
+
+        cases.test_each(|case| {
+            let signal = NdTensor::from_fn([10, 10, 2], |[row, col, part]| {
+                if part == 0 {
+                    (row * 10 + col) as f32
+                } else {
+                    0.
+                }
+            });
+            let input = if case.inverse {
+                signal.to_tensor()
+            } else {
+                signal.slice((.., .., ..1)).to_tensor()
+            };
+            let channels = input.size(2);
+            let input = input.into_shape([1, 10, 10, channels]);
+            let axis = Tensor::from(case.axis);
+            let inputs =
+                InputList::from_iter([Some(input.view().into()), None, Some(axis.view().into())]);
+
+            let result: Tensor = DFT {
+                axis: None,
+                inverse: case.inverse,
+                onesided: false,
+            }
+            .run_simple(inputs)
+            .unwrap();
+
+            let expected = if case.axis == 1 {
+                reference_dft(signal.view(), case.inverse)
+            } else {
+                let mut expected = reference_dft(signal.permuted([1, 0, 2]), case.inverse);
+                expected.permute([1, 0, 2]);
+                expected
+            };
+            let mut expected = expected.into_dyn();
+            expected.insert_axis(0);
+            expect_equal_with_tolerance(&result, &expected, 1e-4, 1e-3).unwrap();
+        });
+    }
+
+    #[test]
+    fn test_window_ops() {
+        let size = Tensor::from(4);
+        let periodic =
+            |op: &dyn Operator| -> NdTensor<f32, 1> { op.run_simple(size.view()).unwrap() };
+
+        let hann = periodic(&HannWindow { periodic: true });
+        expect_eq_1e4(&hann, &NdTensor::from([0., 0.5, 1., 0.5])).unwrap();
+
+        let hamming = periodic(&HammingWindow { periodic: true });
+        expect_eq_1e4(&hamming, &NdTensor::from([0.0870, 0.5435, 1., 0.5435])).unwrap();
+
+        let blackman = periodic(&BlackmanWindow { periodic: true });
+        expect_eq_1e4(&blackman, &NdTensor::from([0., 0.34, 1., 0.34])).unwrap();
+
+        // Symmetric window
+        let size = Tensor::from(5);
+        let hann: NdTensor<f32, 1> = HannWindow { periodic: false }
+            .run_simple(size.view())
+            .unwrap();
+        expect_eq_1e4(&hann, &NdTensor::from([0., 0.5, 1., 0.5, 0.])).unwrap();
+
+        // Cases from the ONNX backend tests (`test_hannwindow`,
+        // `test_hannwindow_symmetric` etc.), which use a size of 10.
+        let size = Tensor::from(10);
+        let window =
+            |op: &dyn Operator| -> NdTensor<f32, 1> { op.run_simple(size.view()).unwrap() };
+        let cases: [(&dyn Operator, [f32; 10]); 6] = [
+            (
+                &HannWindow { periodic: true },
+                [
+                    0., 0.0955, 0.3455, 0.6545, 0.9045, 1., 0.9045, 0.6545, 0.3455, 0.0955,
+                ],
+            ),
+            (
+                &HannWindow { periodic: false },
+                [
+                    0., 0.1170, 0.4132, 0.7500, 0.9698, 0.9698, 0.7500, 0.4132, 0.1170, 0.,
+                ],
+            ),
+            (
+                &HammingWindow { periodic: true },
+                [
+                    0.0870, 0.1741, 0.4024, 0.6846, 0.9128, 1., 0.9128, 0.6846, 0.4024, 0.1741,
+                ],
+            ),
+            (
+                &HammingWindow { periodic: false },
+                [
+                    0.0870, 0.1938, 0.4642, 0.7717, 0.9725, 0.9725, 0.7717, 0.4642, 0.1938, 0.0870,
+                ],
+            ),
+            (
+                &BlackmanWindow { periodic: true },
+                [
+                    0., 0.0402, 0.2008, 0.5098, 0.8492, 1., 0.8492, 0.5098, 0.2008, 0.0402,
+                ],
+            ),
+            (
+                &BlackmanWindow { periodic: false },
+                [
+                    0., 0.0509, 0.2580, 0.6300, 0.9511, 0.9511, 0.6300, 0.2580, 0.0509, 0.,
+                ],
+            ),
+        ];
+        for (op, expected) in cases {
+            expect_eq_1e4(&window(op), &NdTensor::from(expected)).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_mel_weight_matrix() {
+        let num_mel_bins = Tensor::from(2);
+        let dft_length = Tensor::from(16);
+        let sample_rate = Tensor::from(1000);
+        let lower_edge_hertz = Tensor::from(0.);
+        let upper_edge_hertz = Tensor::from(500.);
+
+        let inputs = InputList::from(&[
+            num_mel_bins.view().into(),
+            dft_length.view().into(),
+            sample_rate.view().into(),
+            lower_edge_hertz.view().into(),
+            upper_edge_hertz.view().into(),
+        ]);
+        let result: NdTensor<f32, 2> = MelWeightMatrix {}.run_simple(inputs).unwrap();
+
+        // Reference values from the ONNX reference implementation.
+        let expected = NdTensor::from([
+            [0., 0.],
+            [1., 0.],
+            [0.5, 0.5],
+            [0., 1.],
+            [0., 0.5],
+            [0., 0.],
+            [0., 0.],
+            [0., 0.],
+            [0., 0.],
+        ]);
+        expect_eq_1e4(&result, &expected).unwrap();
+
+        // Upper edge above the Nyquist frequency.
+        let upper_edge_hertz = Tensor::from(600.);
+        let inputs = InputList::from(&[
+            num_mel_bins.view().into(),
+            dft_length.view().into(),
+            sample_rate.view().into(),
+            lower_edge_hertz.view().into(),
+            upper_edge_hertz.view().into(),
+        ]);
+        let result: Result<NdTensor<f32, 2>, _> = MelWeightMatrix {}.run_simple(inputs);
+        assert!(matches!(result, Err(OpError::InvalidValue(_))));
+
+        // Case from the ONNX backend tests (`test_melweightmatrix`).
+        let num_mel_bins = Tensor::from(8);
+        let dft_length = Tensor::from(16);
+        let sample_rate = Tensor::from(8192);
+        let lower_edge_hertz = Tensor::from(0.);
+        let upper_edge_hertz = Tensor::from(4096.);
+        let inputs = InputList::from(&[
+            num_mel_bins.view().into(),
+            dft_length.view().into(),
+            sample_rate.view().into(),
+            lower_edge_hertz.view().into(),
+            upper_edge_hertz.view().into(),
+        ]);
+        let result: NdTensor<f32, 2> = MelWeightMatrix {}.run_simple(inputs).unwrap();
+        let expected = NdTensor::from([
+            [1., 1., 0., 0., 0., 0., 0., 0.],
+            [0., 0., 1., 1., 0., 0., 0., 0.],
+            [0., 0., 0., 0., 1., 0., 0., 0.],
+            [0., 0., 0., 0., 0., 1., 0., 0.],
+            [0., 0., 0., 0., 0., 0., 1., 0.],
+            [0., 0., 0., 0., 0., 0., 0., 1.],
+            [0., 0., 0., 0., 0., 0., 0., 0.],
+            [0., 0., 0., 0., 0., 0., 0., 0.],
+            [0., 0., 0., 0., 0., 0., 0., 0.],
+        ]);
+        expect_eq_1e4(&result, &expected).unwrap();
+    }
 }
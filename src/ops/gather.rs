@@ -17,9 +17,10 @@ use crate::operator::{
     IntoOpResult, OpError, OpRunContext, Operator, OutputList, OutputType, OutputTypeList,
     OutputTypesContext,
 };
+use crate::ops::convert::widen_to_f32;
 use crate::ops::reduce::{cmp_nan_greater, cmp_nan_less};
 use crate::ops::{map_value_view, resolve_axis, resolve_index};
-use crate::value::ValueView;
+use crate::value::{DataType, ValueType, ValueView};
 
 const INVALID_INDEX_ERR: OpError = OpError::InvalidValue("Entry in `indices` is out of range");
 
@@ -168,7 +169,7 @@ pub fn gather<T: Copy + Default>(
     Ok(output)
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Gather {
     pub axis: isize,
 }
@@ -182,18 +183,35 @@ impl Operator for Gather {
         Some(2)
     }
 
+    fn supports_int64_bool_input(&self, index: usize) -> bool {
+        index == 0
+    }
+
     fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
         let inputs = ctx.inputs();
         let input = inputs.require(0)?;
         let indices = inputs.require_as(1)?;
-
-        map_value_view!(input, x, {
-            gather(ctx.pool(), x, self.axis, indices).into_op_result()
-        })
+        let pool = ctx.pool();
+
+        // 16-bit float inputs are typically embedding tables. The gathered
+        // rows are converted to f32 for use by subsequent operators.
+        match input {
+            ValueView::Float16Tensor(x) => {
+                let output = gather(pool, x, self.axis, indices)?.auto_return(pool);
+                widen_to_f32(pool, output.view()).into_op_result()
+            }
+            ValueView::BFloat16Tensor(x) => {
+                let output = gather(pool, x, self.axis, indices)?.auto_return(pool);
+                widen_to_f32(pool, output.view()).into_op_result()
+            }
+            _ => map_value_view!(input, x, {
+                gather(pool, x, self.axis, indices).into_op_result()
+            }),
+        }
     }
 
     fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
-        Some([OutputType::CopyFromInput(0)].into())
+        Some([OutputType::WidenedFromInput(0)].into())
     }
 
     fn as_infer_shapes(&self) -> Option<&dyn InferShapes> {
@@ -299,7 +317,7 @@ pub fn gather_elements<T: Copy + Default + Send + Sync + std::fmt::Debug>(
     Ok(output)
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct GatherElements {
     pub axis: isize,
 }
@@ -313,6 +331,10 @@ impl Operator for GatherElements {
         Some(2)
     }
 
+    fn supports_int64_bool_input(&self, index: usize) -> bool {
+        index == 0
+    }
+
     fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
         let inputs = ctx.inputs();
         let input = inputs.require(0)?;
@@ -425,7 +447,7 @@ pub fn gather_nd<T: Clone + Default>(
     Ok(unsafe { output.assume_init() })
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct GatherND {
     pub batch_dims: usize,
 }
@@ -439,6 +461,10 @@ impl Operator for GatherND {
         Some(2)
     }
 
+    fn supports_int64_bool_input(&self, index: usize) -> bool {
+        index == 0
+    }
+
     fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
         let inputs = ctx.inputs();
         let input = inputs.require(0)?;
@@ -539,7 +565,7 @@ pub fn scatter_elements<
     Ok(output)
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ScatterElements {
     pub axis: isize,
     pub reduction: Option<ScatterReduction>,
@@ -559,11 +585,16 @@ impl Operator for ScatterElements {
         let data = inputs.require(0)?;
         let indices = inputs.require_as(1)?;
 
-        map_value_view!(data, x, {
-            let updates = inputs.require_as(2)?;
-            scatter_elements(ctx.pool(), x, indices, updates, self.axis, self.reduction)
-                .into_op_result()
-        })
+        map_value_view!(
+            data,
+            x,
+            [FloatTensor, Int32Tensor, Int8Tensor, UInt8Tensor],
+            {
+                let updates = inputs.require_as(2)?;
+                scatter_elements(ctx.pool(), x, indices, updates, self.axis, self.reduction)
+                    .into_op_result()
+            }
+        )
     }
 
     fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
@@ -637,7 +668,7 @@ pub fn scatter_nd<
     Ok(output)
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ScatterND {
     pub reduction: Option<ScatterReduction>,
 }
@@ -656,9 +687,105 @@ impl Operator for ScatterND {
         let data = inputs.require(0)?;
         let indices = inputs.require_as(1)?;
 
-        map_value_view!(data, x, {
-            let updates = inputs.require_as(2)?;
-            scatter_nd(ctx.pool(), x, indices, updates, self.reduction).into_op_result()
+        map_value_view!(
+            data,
+            x,
+            [FloatTensor, Int32Tensor, Int8Tensor, UInt8Tensor],
+            {
+                let updates = inputs.require_as(2)?;
+                scatter_nd(ctx.pool(), x, indices, updates, self.reduction).into_op_result()
+            }
+        )
+    }
+
+    fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
+        Some([OutputType::CopyFromInput(0)].into())
+    }
+
+    fn as_infer_shapes(&self) -> Option<&dyn InferShapes> {
+        Some(&UnaryOp)
+    }
+}
+
+/// Reverse the first `seq_lens[i]` elements along `time_axis` for each batch
+/// item `i` along `batch_axis`.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__ReverseSequence.html>.
+pub fn reverse_sequence<T: Clone>(
+    pool: &BufferPool,
+    input: TensorView<T>,
+    seq_lens: NdTensorView<i32, 1>,
+    batch_axis: usize,
+    time_axis: usize,
+) -> Result<Tensor<T>, OpError> {
+    if input.ndim() < 2 {
+        return Err(OpError::InvalidValue("Input must have at least 2 dims"));
+    }
+    if batch_axis > 1 || time_axis > 1 || batch_axis == time_axis {
+        return Err(OpError::InvalidValue(
+            "`batch_axis` and `time_axis` must be 0 and 1 or 1 and 0",
+        ));
+    }
+    if seq_lens.size(0) != input.size(batch_axis) {
+        return Err(OpError::InvalidValue(
+            "`sequence_lens` length does not match batch size",
+        ));
+    }
+
+    let mut output = input.to_tensor_in(pool);
+    let mut src_range: SmallVec<[SliceItem; 4]> =
+        (0..input.ndim()).map(|_| SliceItem::full_range()).collect();
+    let mut dst_range = src_range.clone();
+
+    for (batch, &seq_len) in seq_lens.iter().enumerate() {
+        if seq_len < 0 || seq_len as usize > input.size(time_axis) {
+            return Err(OpError::InvalidValue(
+                "Entry in `sequence_lens` is out of range",
+            ));
+        }
+        let seq_len = seq_len as usize;
+        src_range[batch_axis] = SliceItem::Index(batch as isize);
+        dst_range[batch_axis] = SliceItem::Index(batch as isize);
+
+        for t in 0..seq_len {
+            src_range[time_axis] = SliceItem::Index(t as isize);
+            dst_range[time_axis] = SliceItem::Index((seq_len - 1 - t) as isize);
+            output
+                .slice_mut(dst_range.as_slice())
+                .copy_from(&input.slice(src_range.as_slice()));
+        }
+    }
+
+    Ok(output)
+}
+
+#[derive(Clone, Debug)]
+pub struct ReverseSequence {
+    pub batch_axis: usize,
+    pub time_axis: usize,
+}
+
+impl Operator for ReverseSequence {
+    fn name(&self) -> &str {
+        "ReverseSequence"
+    }
+
+    fn max_inputs(&self) -> Option<usize> {
+        Some(2)
+    }
+
+    fn supports_int64_bool_input(&self, index: usize) -> bool {
+        index == 0
+    }
+
+    fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
+        let inputs = ctx.inputs();
+        let input = inputs.require(0)?;
+        let seq_lens = inputs.require_as(1)?;
+
+        map_value_view!(input, x, {
+            reverse_sequence(ctx.pool(), x, seq_lens, self.batch_axis, self.time_axis)
+                .into_op_result()
         })
     }
 
@@ -671,10 +798,254 @@ impl Operator for ScatterND {
     }
 }
 
+/// Select slices of `input` along `axis` where `condition` is non-zero.
+///
+/// If `axis` is `None`, the input is flattened first. `condition` may be
+/// shorter than the selected axis, in which case slices beyond its length are
+/// discarded.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__Compress.html>.
+pub fn compress<T: Copy + Default>(
+    pool: &BufferPool,
+    input: TensorView<T>,
+    condition: NdTensorView<i32, 1>,
+    axis: Option<isize>,
+) -> Result<Tensor<T>, OpError> {
+    let flat_input;
+    let (input, axis) = match axis {
+        Some(axis) => (input.view(), resolve_axis(input.ndim(), axis)?),
+        None => {
+            flat_input = input.reshaped([input.len()].as_slice());
+            (flat_input.view(), 0)
+        }
+    };
+
+    if condition.size(0) > input.size(axis) {
+        return Err(OpError::InvalidValue(
+            "`condition` length exceeds size of axis",
+        ));
+    }
+
+    let indices: Vec<i32> = condition
+        .iter()
+        .enumerate()
+        .filter_map(|(i, &c)| (c != 0).then_some(i as i32))
+        .collect();
+    let indices = Tensor::from(indices);
+
+    gather(pool, input.view(), axis as isize, indices.view())
+}
+
+#[derive(Clone, Debug)]
+pub struct Compress {
+    pub axis: Option<isize>,
+}
+
+impl Operator for Compress {
+    fn name(&self) -> &str {
+        "Compress"
+    }
+
+    fn max_inputs(&self) -> Option<usize> {
+        Some(2)
+    }
+
+    fn supports_int64_bool_input(&self, index: usize) -> bool {
+        index == 0
+    }
+
+    fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
+        let inputs = ctx.inputs();
+        let input = inputs.require(0)?;
+        let condition = inputs.require_as(1)?;
+
+        map_value_view!(input, x, {
+            compress(ctx.pool(), x, condition, self.axis).into_op_result()
+        })
+    }
+
+    fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
+        Some([OutputType::CopyFromInput(0)].into())
+    }
+}
+
+/// Output of [`unique`].
+pub struct UniqueOutput<T> {
+    /// Unique elements or slices of the input.
+    pub values: Tensor<T>,
+
+    /// Index of the first occurrence of each entry of `values` in the input.
+    pub indices: Tensor<i32>,
+
+    /// Index into `values` of each element or slice of the input.
+    pub inverse_indices: Tensor<i32>,
+
+    /// Number of occurrences of each entry of `values` in the input.
+    pub counts: Tensor<i32>,
+}
+
+/// Compare two values, treating NaNs as equal to each other and greater than
+/// all other values.
+fn cmp_nan_equal<T: PartialOrd + IsNaN>(a: T, b: T) -> std::cmp::Ordering {
+    match a.partial_cmp(&b) {
+        Some(ordering) => ordering,
+        None => match (a.is_nan(), b.is_nan()) {
+            (true, true) => std::cmp::Ordering::Equal,
+            (true, false) => std::cmp::Ordering::Greater,
+            _ => std::cmp::Ordering::Less,
+        },
+    }
+}
+
+/// Find the unique elements of `input`, or the unique slices along `axis`.
+///
+/// If `sorted` is true the unique values are returned in ascending order,
+/// otherwise they are returned in order of first occurrence.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__Unique.html>.
+pub fn unique<T: Copy + Default + PartialOrd + IsNaN>(
+    pool: &BufferPool,
+    input: TensorView<T>,
+    axis: Option<isize>,
+    sorted: bool,
+) -> Result<UniqueOutput<T>, OpError> {
+    let flat_input;
+    let (input, axis) = match axis {
+        Some(axis) => (input.view(), resolve_axis(input.ndim(), axis)?),
+        None => {
+            flat_input = input.reshaped([input.len()].as_slice());
+            (flat_input.view(), 0)
+        }
+    };
+
+    let slices: Vec<Vec<T>> = input
+        .axis_iter(axis)
+        .map(|slice| slice.iter().copied().collect())
+        .collect();
+    let cmp_slices = |a: &[T], b: &[T]| {
+        a.iter()
+            .zip(b)
+            .map(|(x, y)| cmp_nan_equal(*x, *y))
+            .find(|ord| ord.is_ne())
+            .unwrap_or(std::cmp::Ordering::Equal)
+    };
+
+    // Sort slice indices by value. The sort is stable, so the first index in
+    // each group of equal slices is the first occurrence.
+    let mut order: Vec<usize> = (0..slices.len()).collect();
+    order.sort_by(|&a, &b| cmp_slices(&slices[a], &slices[b]));
+
+    // Group equal slices. Each group is `(first_index, count)`.
+    let mut groups: Vec<(usize, usize)> = Vec::new();
+    let mut group_of_slice = vec![0; slices.len()];
+    for (i, &idx) in order.iter().enumerate() {
+        let is_new_group = i == 0 || cmp_slices(&slices[order[i - 1]], &slices[idx]).is_ne();
+        if is_new_group {
+            groups.push((idx, 0));
+        }
+        groups.last_mut().unwrap().1 += 1;
+        group_of_slice[idx] = groups.len() - 1;
+    }
+
+    // Map from sorted group position to output position.
+    let mut output_pos: Vec<usize> = (0..groups.len()).collect();
+    if !sorted {
+        let mut by_occurrence: Vec<usize> = (0..groups.len()).collect();
+        by_occurrence.sort_by_key(|&g| groups[g].0);
+        for (pos, &g) in by_occurrence.iter().enumerate() {
+            output_pos[g] = pos;
+        }
+    }
+
+    let mut indices = vec![0i32; groups.len()];
+    let mut counts = vec![0i32; groups.len()];
+    for (g, &(first_index, count)) in groups.iter().enumerate() {
+        indices[output_pos[g]] = first_index as i32;
+        counts[output_pos[g]] = count as i32;
+    }
+    let inverse_indices: Vec<i32> = group_of_slice
+        .iter()
+        .map(|&g| output_pos[g] as i32)
+        .collect();
+
+    let indices = Tensor::from(indices);
+    let values = gather(pool, input.view(), axis as isize, indices.view())?;
+
+    Ok(UniqueOutput {
+        values,
+        indices,
+        inverse_indices: Tensor::from(inverse_indices),
+        counts: Tensor::from(counts),
+    })
+}
+
+#[derive(Clone, Debug)]
+pub struct Unique {
+    pub axis: Option<isize>,
+    pub sorted: bool,
+}
+
+impl Operator for Unique {
+    fn name(&self) -> &str {
+        "Unique"
+    }
+
+    fn max_inputs(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    fn supports_int64_bool_input(&self, index: usize) -> bool {
+        index == 0
+    }
+
+    fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
+        let input = ctx.inputs().require(0)?;
+
+        map_value_view!(
+            input,
+            x,
+            [
+                FloatTensor,
+                Int32Tensor,
+                Int64Tensor,
+                Int8Tensor,
+                UInt8Tensor
+            ],
+            {
+                let UniqueOutput {
+                    values,
+                    indices,
+                    inverse_indices,
+                    counts,
+                } = unique(ctx.pool(), x, self.axis, self.sorted)?;
+                Ok([
+                    values.into(),
+                    indices.into(),
+                    inverse_indices.into(),
+                    counts.into(),
+                ]
+                .into_iter()
+                .collect())
+            }
+        )
+    }
+
+    fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
+        Some(OutputTypeList::from_slice(&[
+            OutputType::CopyFromInput(0),
+            OutputType::Fixed(ValueType::Tensor(DataType::Int32)),
+            OutputType::Fixed(ValueType::Tensor(DataType::Int32)),
+            OutputType::Fixed(ValueType::Tensor(DataType::Int32)),
+        ]))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::error::Error;
+    use std::sync::Arc;
 
+    use rten_base::half::{bf16, f16};
     use rten_tensor::Tensor;
     use rten_tensor::prelude::*;
     use rten_tensor::rng::XorShiftRng;
@@ -682,9 +1053,11 @@ mod tests {
     use rten_testing::TestCases;
 
     use crate::buffer_pool::BufferPool;
-    use crate::operator::OpError;
+    use crate::graph::Graph;
+    use crate::operator::{OpError, OperatorExt};
     use crate::ops::{
-        ScatterReduction, gather, gather_elements, gather_nd, scatter_elements, scatter_nd,
+        Gather, ScatterReduction, Unique, compress, gather, gather_elements, gather_nd,
+        reverse_sequence, scatter_elements, scatter_nd, unique,
     };
 
     #[test]
@@ -757,6 +1130,23 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_gather_half_float() {
+        let input = Tensor::from([[1., 2.], [3., 4.], [5., 6.]]);
+        let indices = Tensor::from([2, 0]);
+        let expected = Tensor::from([[5., 6.], [1., 2.]]);
+        let op = Gather { axis: 0 };
+
+        // 16-bit inputs are gathered and then converted to f32.
+        let f16_input = input.map(|x| f16::from_f32(*x));
+        let result: Tensor<f32> = op.run_simple((f16_input.view(), indices.view())).unwrap();
+        assert_eq!(result, expected);
+
+        let bf16_input = input.map(|x| bf16::from_f32(*x));
+        let result: Tensor<f32> = op.run_simple((bf16_input.view(), indices.view())).unwrap();
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn test_gather_invalid_axis() {
         let pool = BufferPool::new();
@@ -1017,6 +1407,38 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_compress() {
+        let pool = BufferPool::new();
+        let input = Tensor::from([[1, 2], [3, 4], [5, 6]]);
+
+        // Examples from ONNX backend tests.
+        let condition = Tensor::from([0, 1, 1]);
+        let result = compress(&pool, input.view(), condition.nd_view(), Some(0)).unwrap();
+        assert_eq!(result, Tensor::from([[3, 4], [5, 6]]));
+
+        let condition = Tensor::from([0, 1]);
+        let result = compress(&pool, input.view(), condition.nd_view(), Some(1)).unwrap();
+        assert_eq!(result, Tensor::from([[2], [4], [6]]));
+
+        let result = compress(&pool, input.view(), condition.nd_view(), Some(-1)).unwrap();
+        assert_eq!(result, Tensor::from([[2], [4], [6]]));
+
+        let condition = Tensor::from([0, 1, 0, 0, 1]);
+        let result = compress(&pool, input.view(), condition.nd_view(), None).unwrap();
+        assert_eq!(result, Tensor::from([2, 5]));
+
+        // Condition longer than axis.
+        let condition = Tensor::from([0, 1, 0, 0]);
+        let result = compress(&pool, input.view(), condition.nd_view(), Some(0));
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "`condition` length exceeds size of axis"
+            ))
+        );
+    }
+
     #[test]
     fn test_scatter_elements() {
         #[derive(Debug)]
@@ -1297,4 +1719,110 @@ mod tests {
             assert_eq!(result.as_ref(), Err(&case.expected));
         })
     }
+
+    #[test]
+    fn test_reverse_sequence() {
+        let pool = BufferPool::new();
+
+        // Examples from ONNX backend tests.
+        let input = Tensor::from([[0, 4, 8, 12], [1, 5, 9, 13], [2, 6, 10, 14], [3, 7, 11, 15]]);
+        let seq_lens = Tensor::from([4, 3, 2, 1]);
+        let result = reverse_sequence(&pool, input.view(), seq_lens.nd_view(), 1, 0).unwrap();
+        let expected = Tensor::from([[3, 6, 9, 12], [2, 5, 8, 13], [1, 4, 10, 14], [0, 7, 11, 15]]);
+        assert_eq!(result, expected);
+
+        let input = Tensor::from([[0, 1, 2, 3], [4, 5, 6, 7], [8, 9, 10, 11], [12, 13, 14, 15]]);
+        let seq_lens = Tensor::from([1, 2, 3, 4]);
+        let result = reverse_sequence(&pool, input.view(), seq_lens.nd_view(), 0, 1).unwrap();
+        let expected = Tensor::from([[0, 1, 2, 3], [5, 4, 6, 7], [10, 9, 8, 11], [15, 14, 13, 12]]);
+        assert_eq!(result, expected);
+
+        // Sequence length out of range.
+        let seq_lens = Tensor::from([1, 2, 3, 5]);
+        let result = reverse_sequence(&pool, input.view(), seq_lens.nd_view(), 0, 1);
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "Entry in `sequence_lens` is out of range"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_unique() {
+        let pool = BufferPool::new();
+
+        // Examples from ONNX backend tests.
+        let input = Tensor::from([2., 1., 1., 3., 4., 3.]);
+        let result = unique(&pool, input.view(), None, false).unwrap();
+        assert_eq!(result.values, Tensor::from([2., 1., 3., 4.]));
+        assert_eq!(result.indices, Tensor::from([0, 1, 3, 4]));
+        assert_eq!(result.inverse_indices, Tensor::from([0, 1, 1, 2, 3, 2]));
+        assert_eq!(result.counts, Tensor::from([1, 2, 2, 1]));
+
+        let result = unique(&pool, input.view(), None, true).unwrap();
+        assert_eq!(result.values, Tensor::from([1., 2., 3., 4.]));
+        assert_eq!(result.indices, Tensor::from([1, 0, 3, 4]));
+        assert_eq!(result.inverse_indices, Tensor::from([1, 0, 0, 2, 3, 2]));
+        assert_eq!(result.counts, Tensor::from([2, 1, 2, 1]));
+
+        let input = Tensor::from([[1, 0, 0], [1, 0, 0], [2, 3, 4]]);
+        let result = unique(&pool, input.view(), Some(0), true).unwrap();
+        assert_eq!(result.values, Tensor::from([[1, 0, 0], [2, 3, 4]]));
+        assert_eq!(result.indices, Tensor::from([0, 2]));
+        assert_eq!(result.inverse_indices, Tensor::from([0, 0, 1]));
+        assert_eq!(result.counts, Tensor::from([2, 1]));
+
+        // NaNs are treated as equal to each other.
+        let input = Tensor::from([f32::NAN, 1., f32::NAN]);
+        let result = unique(&pool, input.view(), None, true).unwrap();
+        assert_eq!(result.values.to_vec()[0], 1.);
+        assert!(result.values.to_vec()[1].is_nan());
+        assert_eq!(result.counts, Tensor::from([1, 2]));
+
+        // The values output has the same type as the input.
+        let input = Tensor::from([3i64, 1, 3, i64::MAX]);
+        let op = Unique {
+            axis: None,
+            sorted: true,
+        };
+        let values: Tensor<i64> = op.run_simple(input.view()).unwrap();
+        assert_eq!(values, Tensor::from([1, 3, i64::MAX]));
+    }
+
+    #[test]
+    fn test_unique_int64_in_graph() {
+        // i64 inputs are passed to the operator without being narrowed to i32.
+        let mut g = Graph::new();
+        let input_id = g.add_value(Some("input"), None, None);
+        let values_id = g.add_value(Some("values"), None, None);
+        let counts_id = g.add_value(Some("counts"), None, None);
+        g.add_op(
+            Some("unique"),
+            Arc::new(Unique {
+                axis: None,
+                sorted: true,
+            }),
+            &[Some(input_id)],
+            &[Some(values_id), None, None, Some(counts_id)],
+        );
+
+        let input = Tensor::from([3i64, 1, 3, i64::MAX]);
+        let result = g
+            .run(
+                vec![(input_id, input.view().into())],
+                &[values_id, counts_id],
+                None,
+                None,
+            )
+            .unwrap();
+        assert_eq!(
+            result[0].as_tensor_view::<i64>().unwrap(),
+            Tensor::from([1, 3, i64::MAX]).view()
+        );
+        assert_eq!(
+            result[1].as_tensor_view::<i32>().unwrap(),
+            Tensor::from([1, 2, 1]).view()
+        );
+    }
 }
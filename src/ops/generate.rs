@@ -28,7 +28,7 @@ pub fn constant_of_shape<T: Copy>(
     Ok(Tensor::full_in(pool, &shape, value))
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct ConstantOfShape {
     pub value: Scalar,
 }
@@ -115,7 +115,7 @@ fn extract_off_on_values<T: Copy>(values: NdTensorView<T, 1>) -> Result<(T, T),
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct OneHot {
     pub axis: isize,
 }
@@ -232,7 +232,7 @@ pub fn eye_like<T: Copy + Default + Identities>(
     output
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct EyeLike {
     pub dtype: Option<DataType>,
     pub k: i32,
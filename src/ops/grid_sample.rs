@@ -122,7 +122,7 @@ fn grid_sample(
     Ok(unsafe { output.assume_init() })
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct GridSample {
     pub align_corners: bool,
 }
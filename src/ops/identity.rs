@@ -26,6 +26,10 @@ impl Operator for Identity {
         Some(1)
     }
 
+    fn supports_int64_bool_input(&self, index: usize) -> bool {
+        index == 0
+    }
+
     fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
         let input = ctx.inputs().require(0)?;
         map_value_view!(input, x, { identity(ctx.pool(), x).into_op_result() })
@@ -63,7 +63,7 @@ pub fn depth_to_space<T: Clone>(
     Ok(tmp)
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct DepthToSpace {
     pub block_size: u32,
     pub mode: DepthToSpaceMode,
@@ -88,6 +88,66 @@ impl Operator for DepthToSpace {
     }
 }
 
+/// Rearrange blocks of spatial data into depth. This is the inverse of
+/// [`depth_to_space`] in [`DepthToSpaceMode::DepthColumnRow`] mode.
+pub fn space_to_depth<T: Clone>(
+    pool: &BufferPool,
+    input: TensorView<T>,
+    block_size: u32,
+) -> Result<Tensor<T>, OpError> {
+    if block_size == 0 {
+        return Err(OpError::InvalidValue("`block_size` must be > 0"));
+    }
+
+    let input = static_dims!(input, 4, "NCHW")?;
+    let [n, c, h, w] = input.shape();
+    let block_size = block_size as usize;
+
+    if h % block_size != 0 || w % block_size != 0 {
+        return Err(OpError::InvalidValue(
+            "input height and width must be multiples of `block_size`",
+        ));
+    }
+
+    let new_h = h / block_size;
+    let new_w = w / block_size;
+    let new_shape = [n, c * block_size * block_size, new_h, new_w];
+
+    // Reshape following steps in `SpaceToDepth` ONNX spec.
+    // See https://onnx.ai/onnx/operators/onnx__SpaceToDepth.html#summary
+    let tmp = input.to_contiguous_in(pool);
+    let tmp = tmp.reshaped([n, c, new_h, block_size, new_w, block_size]);
+    let tmp = tmp.permuted([0, 3, 5, 1, 2, 4]);
+    let mut tmp = tmp.to_tensor_in(pool).into_dyn();
+    tmp.reshape(&new_shape);
+
+    Ok(tmp)
+}
+
+#[derive(Clone, Debug)]
+pub struct SpaceToDepth {
+    pub block_size: u32,
+}
+
+impl Operator for SpaceToDepth {
+    fn name(&self) -> &str {
+        "SpaceToDepth"
+    }
+
+    fn max_inputs(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
+        let input = ctx.inputs().require_as(0)?;
+        space_to_depth::<f32>(ctx.pool(), input, self.block_size).into_op_result()
+    }
+
+    fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
+        Some([OutputType::CopyFromInput(0)].into())
+    }
+}
+
 /// Return the tensor shape resulting from broadcasting `input_shape` with `shape`.
 fn expand_output_shape(
     input_shape: &[usize],
@@ -167,6 +227,10 @@ impl Operator for Expand {
         Some(2)
     }
 
+    fn supports_int64_bool_input(&self, index: usize) -> bool {
+        index == 0
+    }
+
     fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
         let inputs = ctx.inputs();
         let input = inputs.require(0)?;
@@ -237,7 +301,7 @@ pub fn flatten_in_place<T: Copy>(
     Ok(())
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Flatten {
     pub axis: isize,
 }
@@ -251,6 +315,10 @@ impl Operator for Flatten {
         Some(1)
     }
 
+    fn supports_int64_bool_input(&self, index: usize) -> bool {
+        index == 0
+    }
+
     fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
         let input = ctx.inputs().require(0)?;
         map_value_view!(input, x, {
@@ -382,7 +450,7 @@ pub fn reshape_in_place<T: Copy>(
     Ok(())
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Reshape {
     pub allow_zero: bool,
 }
@@ -396,6 +464,10 @@ impl Operator for Reshape {
         Some(2)
     }
 
+    fn supports_int64_bool_input(&self, index: usize) -> bool {
+        index == 0
+    }
+
     fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
         let inputs = ctx.inputs();
         let input = inputs.require(0)?;
@@ -436,7 +508,7 @@ impl_infer_shapes!(
     }
 );
 
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub struct Shape {
     pub start: Option<i32>,
     pub end: Option<i32>,
@@ -451,6 +523,10 @@ impl Operator for Shape {
         Some(1)
     }
 
+    fn supports_int64_bool_input(&self, index: usize) -> bool {
+        index == 0
+    }
+
     fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
         let input = ctx.inputs().require(0)?;
 
@@ -499,6 +575,10 @@ impl Operator for Size {
         Some(1)
     }
 
+    fn supports_int64_bool_input(&self, index: usize) -> bool {
+        index == 0
+    }
+
     fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
         let input = ctx.inputs().require(0)?;
         let len = input.len() as i32;
@@ -574,6 +654,10 @@ impl Operator for Squeeze {
         Some(2)
     }
 
+    fn supports_int64_bool_input(&self, index: usize) -> bool {
+        index == 0
+    }
+
     fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
         let inputs = ctx.inputs();
         let input = inputs.require(0)?;
@@ -625,7 +709,7 @@ pub fn transpose<T: Copy>(
     Ok(output.init_from(&transposed))
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Transpose {
     /// The order of the transposed dimensions. If ommitted, the dimensions
     /// are reversed.
@@ -641,6 +725,10 @@ impl Operator for Transpose {
         Some(1)
     }
 
+    fn supports_int64_bool_input(&self, index: usize) -> bool {
+        index == 0
+    }
+
     fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
         let input = ctx.inputs().require(0)?;
         let perm_slice = self.perm.as_deref();
@@ -716,6 +804,10 @@ impl Operator for Unsqueeze {
         Some(2)
     }
 
+    fn supports_int64_bool_input(&self, index: usize) -> bool {
+        index == 0
+    }
+
     fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
         let inputs = ctx.inputs();
         let input = inputs.require(0)?;
@@ -758,7 +850,7 @@ mod tests {
     use rten_tensor::{NdTensor, Tensor};
     use rten_testing::TestCases;
 
-    use super::{DepthToSpaceMode, depth_to_space};
+    use super::{DepthToSpaceMode, depth_to_space, space_to_depth};
     use crate::buffer_pool::BufferPool;
     use crate::operator::{OpError, OperatorExt};
     use crate::ops::layout::{
@@ -1206,6 +1298,69 @@ mod tests {
         assert_eq!(result.item(), Some(&4));
     }
 
+    #[test]
+    fn test_space_to_depth() {
+        #[derive(Debug)]
+        struct Case {
+            input: Tensor,
+            block_size: u32,
+            expected: Result<Tensor, OpError>,
+        }
+
+        let cases = [
+            // Example from ONNX backend tests.
+            Case {
+                input: NdTensor::from([
+                    [0., 6., 1., 7., 2., 8.],
+                    [12., 18., 13., 19., 14., 20.],
+                    [3., 9., 4., 10., 5., 11.],
+                    [15., 21., 16., 22., 17., 23.],
+                ])
+                .into_shape([1, 1, 4, 6].as_slice()),
+                block_size: 2,
+                expected: Ok(NdTensor::from([
+                    [[0., 1., 2.], [3., 4., 5.]],
+                    [[6., 7., 8.], [9., 10., 11.]],
+                    [[12., 13., 14.], [15., 16., 17.]],
+                    [[18., 19., 20.], [21., 22., 23.]],
+                ])
+                .into_shape([1, 4, 2, 3].as_slice())),
+            },
+            // H or W not a multiple of block_size
+            Case {
+                input: Tensor::full(&[1, 1, 3, 4], 1.0),
+                block_size: 2,
+                expected: Err(OpError::InvalidValue(
+                    "input height and width must be multiples of `block_size`",
+                )),
+            },
+            // block_size == 0
+            Case {
+                input: Tensor::full(&[1, 1, 2, 2], 1.0),
+                block_size: 0,
+                expected: Err(OpError::InvalidValue("`block_size` must be > 0")),
+            },
+        ];
+
+        cases.test_each(|case| {
+            let pool = BufferPool::new();
+            let result = space_to_depth(&pool, case.input.view(), case.block_size);
+            assert_eq!(result, case.expected);
+
+            // SpaceToDepth should be the inverse of DepthToSpace in DCR mode.
+            if let Ok(output) = result {
+                let round_trip = depth_to_space(
+                    &pool,
+                    output.view(),
+                    case.block_size,
+                    DepthToSpaceMode::DepthColumnRow,
+                )
+                .unwrap();
+                assert_eq!(round_trip, case.input);
+            }
+        })
+    }
+
     #[test]
     fn test_squeeze() -> Result<(), Box<dyn Error>> {
         let pool = BufferPool::new();
@@ -1,13 +1,13 @@
 use rayon::prelude::*;
 use rten_base::byte_cast::{Pod, cast_pod_vec};
 use rten_gemm::{
-    BiasVector, BlockQuantizedError, BlockQuantizedGemm, BlockQuantizedMatrix, ComputeMode,
-    GemmExecutor, GemmInT, GemmInputA, GemmInputB, GemmOptions, GemmOutT, GemmUninitOptions,
-    PackedBMatrix, QuantParams,
+    BiasVector, BlockQuantizedError, BlockQuantizedGemm, BlockQuantizedMatrix,
+    BlockQuantizedZeroPoints, ComputeMode, GemmExecutor, GemmInT, GemmInputA, GemmInputB,
+    GemmOptions, GemmOutT, GemmUninitOptions, PackedBMatrix, QuantParams,
 };
 use rten_shape_inference::ops as shape_ops;
 use rten_tensor::prelude::*;
-use rten_tensor::{CowNdTensor, Matrix, NdTensorView, Tensor, TensorView};
+use rten_tensor::{CowNdTensor, Matrix, NdTensor, NdTensorView, Tensor, TensorView};
 use rten_vecmath::ExtendInit;
 use smallvec::SmallVec;
 
@@ -18,6 +18,7 @@ use crate::operator::{
     OutputTypesContext, PrepackedInput, static_dims,
 };
 use crate::ops::binary_elementwise::broadcast_shapes;
+use crate::ops::convert::{WidenToF32, widen_to_f32};
 use crate::ops::layout::expand_to;
 use crate::value::{DataType, ValueType, ValueView};
 
@@ -101,7 +102,7 @@ where
     Ok(output)
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Gemm {
     pub alpha: f32,
     pub beta: f32,
@@ -181,6 +182,26 @@ where
     Some(executor.prepack_b(matrix).into())
 }
 
+/// Prepack a "B" input for an f32 matmul.
+///
+/// 16-bit float inputs are converted to f32 before packing. The packed matrix
+/// is twice the size of the input, but this avoids converting the weights on
+/// each run.
+fn matmul_prepack_b_f32(input: ValueView) -> Option<PrepackedInput> {
+    let pool = BufferPool::new();
+    match input {
+        ValueView::Float16Tensor(b) if b.ndim() == 2 => {
+            let b = widen_to_f32(&pool, b);
+            matmul_prepack_b::<f32, f32, f32>(b.view().into())
+        }
+        ValueView::BFloat16Tensor(b) if b.ndim() == 2 => {
+            let b = widen_to_f32(&pool, b);
+            matmul_prepack_b::<f32, f32, f32>(b.view().into())
+        }
+        _ => matmul_prepack_b::<f32, f32, f32>(input),
+    }
+}
+
 pub fn matmul<LhsT: GemmInT, RhsT: GemmInT, OutT: Default + GemmOutT>(
     pool: &BufferPool,
     a: TensorView<LhsT>,
@@ -397,12 +418,22 @@ impl Operator for MatMul {
     fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
         let inputs = ctx.inputs();
         let a = inputs.require_as(0)?;
-        let b = inputs.require_as(1)?;
         let packed_b = match inputs.get_prepacked(1) {
             Some(PrepackedInput::FloatBMatrix(pb)) => Some(pb),
             _ => None,
         };
-        matmul::<f32, f32, f32>(ctx.pool(), a, b, packed_b).into_op_result()
+        match inputs.require(1)? {
+            ValueView::Float16Tensor(b) => {
+                matmul_half_b(ctx.pool(), a, b, packed_b, None, None).into_op_result()
+            }
+            ValueView::BFloat16Tensor(b) => {
+                matmul_half_b(ctx.pool(), a, b, packed_b, None, None).into_op_result()
+            }
+            _ => {
+                let b = inputs.require_as(1)?;
+                matmul::<f32, f32, f32>(ctx.pool(), a, b, packed_b).into_op_result()
+            }
+        }
     }
 
     fn prepack_inputs(&self) -> SmallVec<[usize; 1]> {
@@ -411,7 +442,7 @@ impl Operator for MatMul {
 
     fn prepack(&self, index: usize, input: ValueView) -> Option<PrepackedInput> {
         if index == 1 {
-            matmul_prepack_b::<f32, f32, f32>(input)
+            matmul_prepack_b_f32(input)
         } else {
             None
         }
@@ -450,6 +481,152 @@ where
     )
 }
 
+/// Maximum number of elements of a 16-bit "B" matrix which are converted to
+/// f32 at once by [`matmul_half_b`].
+const HALF_B_CHUNK_SIZE: usize = 1 << 20;
+
+/// Matrix multiplication where `b` has 16-bit float elements.
+///
+/// If `packed_b` is provided, it is used for matrix-matrix products. This is
+/// an f32 copy of `b` created by [`matmul_prepack_b_f32`].
+///
+/// Otherwise `b` is converted to f32 in blocks of rows, each of which is
+/// multiplied by the corresponding columns of `a` and accumulated into the
+/// output. This bounds the memory needed for converted weights, which would
+/// otherwise be twice the size of `b`.
+fn matmul_half_b<T: WidenToF32>(
+    pool: &BufferPool,
+    a: TensorView<f32>,
+    b: TensorView<T>,
+    packed_b: Option<&PackedBMatrix<f32>>,
+    bias: Option<BiasVector<f32>>,
+    alpha: Option<f32>,
+) -> Result<Tensor<f32>, OpError> {
+    matmul_half_b_impl(pool, a, b, packed_b, bias, alpha, HALF_B_CHUNK_SIZE)
+}
+
+fn matmul_half_b_impl<T: WidenToF32>(
+    pool: &BufferPool,
+    mut a: TensorView<f32>,
+    b: TensorView<T>,
+    packed_b: Option<&PackedBMatrix<f32>>,
+    bias: Option<BiasVector<f32>>,
+    alpha: Option<f32>,
+    max_chunk_len: usize,
+) -> Result<Tensor<f32>, OpError> {
+    let a_is_vec = a.ndim() == 1;
+    if a_is_vec {
+        a.insert_axis(0);
+    }
+
+    // Batched and empty products are uncommon for weights, so just convert
+    // all of `b` in these cases.
+    let m: usize = a.shape().iter().take(a.ndim().saturating_sub(1)).product();
+    if b.ndim() != 2 || a.ndim() < 2 || b.is_empty() || m == 0 {
+        let b = widen_to_f32(pool, b).auto_return(pool);
+        let mut output = matmul_fused(pool, a, b.view(), None, bias, alpha)?;
+        if a_is_vec {
+            output.remove_axis(output.ndim() - 2);
+        }
+        return Ok(output);
+    }
+
+    let [k, n] = [b.size(0), b.size(1)];
+    if a.size(a.ndim() - 1) != k {
+        return Err(OpError::IncompatibleInputShapes(
+            "Columns of first matrix does not match rows of second matrix",
+        ));
+    }
+
+    let a = a.to_contiguous_in(pool).auto_return(pool);
+    let a_mat = a.reshaped([m, k]);
+
+    let gemm = GemmExecutor::<f32, f32, f32>::default();
+    let alpha = alpha.unwrap_or(1.);
+    let mut out_data: Vec<f32> = pool.alloc(m * n);
+
+    // Vector-matrix products don't use packed inputs, so we convert `b` in
+    // chunks for these even if it has been prepacked.
+    if let Some(packed_b) = packed_b.filter(|_| m > 1) {
+        out_data.extend_init(|uninit| {
+            gemm.gemm_uninit(
+                &mut uninit[..m * n],
+                GemmInputA::Unpacked(a_mat.view()),
+                GemmInputB::Packed(packed_b),
+                GemmUninitOptions {
+                    alpha,
+                    bias,
+                    ..Default::default()
+                },
+            )
+            .unwrap()
+        });
+        return Ok(half_b_output(a.shape(), n, out_data, a_is_vec));
+    }
+
+    let b = b.nd_view::<2>();
+    let chunk_rows = (max_chunk_len / n).clamp(1, k);
+    let mut b_chunk_buf: Vec<f32> = pool.alloc(chunk_rows * n);
+
+    for k_start in (0..k).step_by(chunk_rows) {
+        let k_end = (k_start + chunk_rows).min(k);
+        let chunk_len = (k_end - k_start) * n;
+
+        // If `b` is not contiguous (eg. transposed weights), copy one chunk at
+        // a time rather than all of `b`.
+        let b_rows = b
+            .slice(k_start..k_end)
+            .to_contiguous_in(pool)
+            .auto_return(pool);
+        b_chunk_buf.clear();
+        b_chunk_buf.extend_init(|uninit| T::widen(b_rows.data(), &mut uninit[..chunk_len]));
+        let b_chunk = NdTensorView::from_data([k_end - k_start, n], b_chunk_buf.as_slice());
+        let a_chunk = a_mat.slice((.., k_start..k_end));
+
+        if k_start == 0 {
+            out_data.extend_init(|uninit| {
+                gemm.gemm_uninit(
+                    &mut uninit[..m * n],
+                    GemmInputA::Unpacked(a_chunk),
+                    GemmInputB::Unpacked(b_chunk),
+                    GemmUninitOptions {
+                        alpha,
+                        bias,
+                        ..Default::default()
+                    },
+                )
+                .unwrap()
+            });
+        } else {
+            gemm.gemm(
+                &mut out_data,
+                GemmInputA::Unpacked(a_chunk),
+                GemmInputB::Unpacked(b_chunk),
+                GemmOptions {
+                    alpha,
+                    beta: 1.,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        }
+    }
+    pool.add(b_chunk_buf);
+
+    Ok(half_b_output(a.shape(), n, out_data, a_is_vec))
+}
+
+/// Create the output of [`matmul_half_b`] from the `[M, N]` product.
+fn half_b_output(a_shape: &[usize], n: usize, data: Vec<f32>, a_is_vec: bool) -> Tensor<f32> {
+    let mut out_shape: SmallVec<[usize; 4]> = a_shape.into();
+    *out_shape.last_mut().unwrap() = n;
+    let mut output = Tensor::from_data(out_shape.as_slice(), data);
+    if a_is_vec {
+        output.remove_axis(output.ndim() - 2);
+    }
+    output
+}
+
 /// MatMul with fused addition of bias and scaling of result.
 #[derive(Clone, Debug)]
 pub struct FusedMatMul {
@@ -469,18 +646,28 @@ impl Operator for FusedMatMul {
     fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
         let inputs = ctx.inputs();
         let a = inputs.require_as(0)?;
-        let b = inputs.require_as(1)?;
-        let packed_b = match inputs.get_prepacked(1) {
-            Some(PrepackedInput::FloatBMatrix(pb)) => Some(pb),
-            _ => None,
-        };
 
         let bias = inputs
             .get_as::<NdTensorView<f32, 1>>(2)?
             .map(|b| b.to_contiguous_in(ctx.pool()));
         let bias = bias.as_ref().map(|b| BiasVector::Row(b.data()));
+        let packed_b = match inputs.get_prepacked(1) {
+            Some(PrepackedInput::FloatBMatrix(pb)) => Some(pb),
+            _ => None,
+        };
 
-        matmul_fused(ctx.pool(), a, b, packed_b, bias, self.alpha).into_op_result()
+        match inputs.require(1)? {
+            ValueView::Float16Tensor(b) => {
+                matmul_half_b(ctx.pool(), a, b, packed_b, bias, self.alpha).into_op_result()
+            }
+            ValueView::BFloat16Tensor(b) => {
+                matmul_half_b(ctx.pool(), a, b, packed_b, bias, self.alpha).into_op_result()
+            }
+            _ => {
+                let b = inputs.require_as(1)?;
+                matmul_fused(ctx.pool(), a, b, packed_b, bias, self.alpha).into_op_result()
+            }
+        }
     }
 
     fn prepack_inputs(&self) -> SmallVec<[usize; 1]> {
@@ -489,7 +676,7 @@ impl Operator for FusedMatMul {
 
     fn prepack(&self, index: usize, input: ValueView) -> Option<PrepackedInput> {
         if index == 1 {
-            matmul_prepack_b::<f32, f32, f32>(input)
+            matmul_prepack_b_f32(input)
         } else {
             None
         }
@@ -696,6 +883,31 @@ impl Operator for MatMulIntegerToFloat {
     }
 }
 
+/// Zero points for the RHS input of [`matmul_nbits`].
+#[derive(Clone, Debug)]
+enum NBitsZeroPoints<'a> {
+    /// Zero points packed with the same number of bits as the RHS elements,
+    /// with shape (N, ceil(k_blocks * bits / 8)).
+    Packed(NdTensorView<'a, u8, 2>),
+    /// Float zero points with shape (N, k_blocks).
+    Float(NdTensorView<'a, f32, 2>),
+}
+
+/// Optional inputs for [`matmul_nbits`].
+#[derive(Clone, Debug, Default)]
+struct NBitsOptions<'a> {
+    /// Zero points for each block of the RHS. Defaults to `2^(bits - 1)`.
+    zero_points: Option<NBitsZeroPoints<'a>>,
+
+    /// Index of the quantization block used for each row of the RHS.
+    ///
+    /// Defaults to `k / block_size` for row `k`.
+    g_idx: Option<NdTensorView<'a, i32, 1>>,
+
+    /// Bias of shape (N,) added to each output row.
+    bias: Option<NdTensorView<'a, f32, 1>>,
+}
+
 fn matmul_nbits(
     pool: &BufferPool,
     lhs: TensorView<f32>,
@@ -703,6 +915,7 @@ fn matmul_nbits(
     scales: NdTensorView<f32, 2>,
     bits: u8,
     accuracy: AccuracyLevel,
+    opts: NBitsOptions,
 ) -> Result<Tensor<f32>, OpError> {
     if lhs.ndim() < 2 {
         return Err(OpError::InvalidValue("A input must have at least 2 dims"));
@@ -715,12 +928,74 @@ fn matmul_nbits(
     let rhs = rhs.to_contiguous_in(pool);
     let scales = scales.to_contiguous_in(pool);
 
-    let b_mat = BlockQuantizedMatrix::new(rhs.view(), scales.view(), bits).map_err(|err| {
+    let map_err = |err| {
         OpError::UnsupportedValue(match err {
             BlockQuantizedError::UnsupportedBlockSize => "Unsupported K block size",
             BlockQuantizedError::UnsupportedElementSize => "Unsupported bits-per-element",
+            BlockQuantizedError::WrongZeroPointSize => "Zero point shape does not match scales",
         })
-    })?;
+    };
+    let mut b_mat = BlockQuantizedMatrix::new(rhs.view(), scales.view(), bits).map_err(map_err)?;
+
+    let packed_zero_points;
+    let float_zero_points;
+    match opts.zero_points {
+        Some(NBitsZeroPoints::Packed(zp)) => {
+            packed_zero_points = zp.to_contiguous_in(pool);
+            b_mat = b_mat
+                .with_zero_points(BlockQuantizedZeroPoints::Packed(packed_zero_points.view()))
+                .map_err(map_err)?;
+        }
+        Some(NBitsZeroPoints::Float(zp)) => {
+            float_zero_points = zp.to_contiguous_in(pool);
+            b_mat = b_mat
+                .with_zero_points(BlockQuantizedZeroPoints::Float(float_zero_points.view()))
+                .map_err(map_err)?;
+        }
+        None => {}
+    }
+
+    let bias = match opts.bias {
+        Some(bias) if bias.len() != b_mat.cols() => {
+            return Err(OpError::InvalidValue("Bias length does not match N"));
+        }
+        Some(bias) => Some(bias.to_contiguous_in(pool)),
+        None => None,
+    };
+
+    // The quantized K dimension is padded to a multiple of the block size.
+    let k = b_mat.rows();
+    if lhs_cols > k || k - lhs_cols >= b_mat.elements_per_block() {
+        return Err(OpError::IncompatibleInputShapes(
+            "Columns of first matrix does not match rows of second matrix",
+        ));
+    }
+
+    // Convert `g_idx` into a block index for each row, or `None` if it has
+    // the default value.
+    let block_index: Option<Vec<usize>> = match opts.g_idx {
+        Some(g_idx) => {
+            if g_idx.len() != lhs_cols && g_idx.len() != k {
+                return Err(OpError::InvalidValue("g_idx length does not match K"));
+            }
+            let block_size = b_mat.elements_per_block();
+            let block_index: Vec<usize> = (0..k)
+                .map(|row| match g_idx.get([row]) {
+                    Some(&idx) => usize::try_from(idx)
+                        .ok()
+                        .filter(|idx| *idx < k / block_size)
+                        .ok_or(OpError::InvalidValue("g_idx value is out of range")),
+                    None => Ok(row / block_size),
+                })
+                .collect::<Result<_, _>>()?;
+            let is_default = block_index
+                .iter()
+                .enumerate()
+                .all(|(row, block)| *block == row / block_size);
+            (!is_default).then_some(block_index)
+        }
+        None => None,
+    };
 
     let batch_len = batch_dims.iter().product();
     let out_shape: SmallVec<[usize; 4]> = batch_dims
@@ -731,10 +1006,43 @@ fn matmul_nbits(
     let out_len = out_shape.iter().product();
     let mut out_data = pool.alloc(out_len);
 
-    if lhs_cols != b_mat.rows() {
-        return Err(OpError::IncompatibleInputShapes(
-            "Columns of first matrix does not match rows of second matrix",
-        ));
+    let lhs = lhs
+        .reshaped_in(pool, [batch_len * rows, lhs_cols])
+        .auto_return(pool);
+
+    // Pad LHS with zeros if K is not a multiple of the block size.
+    let padded_lhs = (lhs_cols != k).then(|| {
+        let mut padded = NdTensor::zeros_in(pool, [batch_len * rows, k]);
+        padded.slice_mut((.., ..lhs_cols)).copy_from(&lhs.view());
+        padded.auto_return(pool)
+    });
+    let lhs = padded_lhs
+        .as_ref()
+        .map(|padded| padded.view())
+        .unwrap_or(lhs.view());
+
+    let bias_vec = bias.as_ref().map(|bias| BiasVector::Row(bias.data()));
+
+    // Reordered blocks are not supported by the quantized kernels, so in that
+    // case dequantize the weights and use a regular matmul. When the weights
+    // are constant, the graph optimizer avoids this by reordering them ahead
+    // of time with `reorder_nbits_rhs`.
+    if let Some(block_index) = block_index {
+        let rhs = b_mat.dequantize(Some(&block_index));
+        let gemm = GemmExecutor::default();
+        out_data.extend_init(|uninit_out_data| {
+            gemm.gemm_uninit(
+                &mut uninit_out_data[..out_len],
+                GemmInputA::Unpacked(lhs.view()),
+                GemmInputB::Unpacked(rhs.view()),
+                GemmUninitOptions {
+                    bias: bias_vec,
+                    ..Default::default()
+                },
+            )
+            .unwrap()
+        });
+        return Ok(Tensor::from_data(out_shape.as_slice(), out_data));
     }
 
     // For vector-matrix products use an optimized implementation. Otherwise use
@@ -752,25 +1060,30 @@ fn matmul_nbits(
             BlockQuantizedGemm::new()
         };
 
-        let lhs = lhs
-            .reshaped_in(pool, [batch_len, rows, lhs_cols])
-            .auto_return(pool);
+        let lhs = lhs.reshaped([batch_len, rows, k]);
         out_data.extend_init(|uninit_out_data| {
             gemm.batched_gemm_uninit(&mut uninit_out_data[..out_len], lhs.view(), b_mat)
                 .unwrap()
         });
-    } else {
-        let lhs = lhs
-            .reshaped_in(pool, [batch_len * rows, lhs_cols])
-            .auto_return(pool);
 
+        if let Some(bias) = &bias {
+            for out_row in out_data.chunks_mut(b_mat.cols()) {
+                for (y, b) in out_row.iter_mut().zip(bias.iter()) {
+                    *y += b;
+                }
+            }
+        }
+    } else {
         let gemm = GemmExecutor::default();
         out_data.extend_init(|uninit_out_data| {
             gemm.gemm_uninit(
                 &mut uninit_out_data[..out_len],
                 GemmInputA::Unpacked(lhs.view()),
                 GemmInputB::BlockQuantized(b_mat),
-                GemmUninitOptions::default(),
+                GemmUninitOptions {
+                    bias: bias_vec,
+                    ..Default::default()
+                },
             )
             .unwrap()
         });
@@ -779,6 +1092,61 @@ fn matmul_nbits(
     Ok(Tensor::from_data(out_shape.as_slice(), out_data))
 }
 
+/// Reorder the rows of a [`MatMulNBits`] RHS so that the rows which use each
+/// quantization block are contiguous.
+///
+/// `g_idx` specifies the block index for each row of `rhs`. Returns the
+/// reordered RHS and the permutation of rows, which must be applied to the
+/// columns of the LHS. Returns `None` if `g_idx` does not assign exactly
+/// `block_size` rows to each block.
+pub(crate) fn reorder_nbits_rhs(
+    rhs: NdTensorView<u8, 3>,
+    bits: u8,
+    block_size: usize,
+    g_idx: NdTensorView<i32, 1>,
+) -> Option<(NdTensor<u8, 3>, Vec<i32>)> {
+    let [n, k_blocks, block_bytes] = rhs.shape();
+    let k = k_blocks * block_size;
+    if k == 0 || g_idx.len() != k || block_bytes * 8 != block_size * bits as usize {
+        return None;
+    }
+
+    let mut perm: Vec<usize> = (0..k).collect();
+    perm.sort_by_key(|&row| g_idx[[row]]);
+    if perm
+        .iter()
+        .enumerate()
+        .any(|(i, &row)| usize::try_from(g_idx[[row]]) != Ok(i / block_size))
+    {
+        return None;
+    }
+
+    // Elements in each row of the RHS are packed into a little-endian
+    // bitstream, so they are moved one bit at a time.
+    let bits = bits as usize;
+    let row_bytes = k_blocks * block_bytes;
+    let rhs = rhs.to_contiguous();
+    let mut reordered = vec![0u8; n * row_bytes];
+    for (src, dst) in rhs
+        .data()
+        .chunks(row_bytes)
+        .zip(reordered.chunks_mut(row_bytes))
+    {
+        for (dst_idx, &src_idx) in perm.iter().enumerate() {
+            for bit in 0..bits {
+                let src_bit = src_idx * bits + bit;
+                let dst_bit = dst_idx * bits + bit;
+                dst[dst_bit / 8] |= ((src[src_bit / 8] >> (src_bit % 8)) & 1) << (dst_bit % 8);
+            }
+        }
+    }
+
+    Some((
+        NdTensor::from_data([n, k_blocks, block_bytes], reordered),
+        perm.into_iter().map(|row| row as i32).collect(),
+    ))
+}
+
 /// Specifies whether the LHS input may be quantized.
 ///
 /// Using [`Int8`](Self::Int8) quantization can significantly improve
@@ -797,7 +1165,7 @@ pub enum AccuracyLevel {
 /// Matrix multiplication of un-quantized LHS by block-quantized RHS.
 ///
 /// See https://github.com/microsoft/onnxruntime/blob/main/docs/ContribOperators.md#com.microsoft.MatMulNBits.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct MatMulNBits {
     pub bits: u8,
     pub block_size: usize,
@@ -829,7 +1197,11 @@ impl Operator for MatMulNBits {
                 .unwrap(),
             1 => {
                 let k = lhs.ndim().checked_sub(1).map(|d| lhs.size(d)).unwrap_or(1);
-                let k_blocks = k.checked_div(self.block_size).unwrap_or(0);
+                let k_blocks = if self.block_size > 0 {
+                    k.div_ceil(self.block_size)
+                } else {
+                    0
+                };
                 let rhs_cols = rhs.size(0);
 
                 if scales.len() != rhs_cols * k_blocks {
@@ -846,11 +1218,43 @@ impl Operator for MatMulNBits {
             }
         };
 
-        if ctx.inputs().len() > 3 {
-            return Err(OpError::UnsupportedValue(
-                "zero_points, g_idx and bias inputs are unsupported",
-            ));
-        }
+        // Zero points may be 1D or 2D. Convert them to 2D.
+        let n = rhs.size(0);
+        let packed_zero_points: Option<CowNdTensor<u8, 2>>;
+        let float_zero_points: Option<CowNdTensor<f32, 2>>;
+        match ctx.inputs().get(3) {
+            Some(ValueView::UInt8Tensor(zp)) => {
+                let zp_cols = zp.len().checked_div(n).unwrap_or(0);
+                if zp.len() != n * zp_cols {
+                    return Err(OpError::InvalidValue(
+                        "Expected `zero_points` size to be a multiple of N",
+                    ));
+                }
+                packed_zero_points = Some(zp.reshaped_in(ctx.pool(), [n, zp_cols]));
+                float_zero_points = None;
+            }
+            Some(ValueView::FloatTensor(zp)) => {
+                if zp.len() != scales.len() {
+                    return Err(OpError::InvalidValue(
+                        "Expected float `zero_points` size to match `scales`",
+                    ));
+                }
+                packed_zero_points = None;
+                float_zero_points = Some(zp.reshaped_in(ctx.pool(), scales.shape()));
+            }
+            Some(_) => return Err(OpError::UnsupportedType),
+            None => {
+                packed_zero_points = None;
+                float_zero_points = None;
+            }
+        };
+        let zero_points = match (&packed_zero_points, &float_zero_points) {
+            (Some(zp), _) => Some(NBitsZeroPoints::Packed(zp.view())),
+            (_, Some(zp)) => Some(NBitsZeroPoints::Float(zp.view())),
+            (None, None) => None,
+        };
+        let g_idx = ctx.inputs().get_as(4)?;
+        let bias = ctx.inputs().get_as(5)?;
 
         matmul_nbits(
             ctx.pool(),
@@ -859,6 +1263,11 @@ impl Operator for MatMulNBits {
             scales.view(),
             self.bits,
             self.accuracy_level,
+            NBitsOptions {
+                zero_points,
+                g_idx,
+                bias,
+            },
         )
         .into_op_result()
     }
@@ -876,6 +1285,7 @@ impl Operator for MatMulNBits {
 mod tests {
     use std::error::Error;
 
+    use rten_base::half::{bf16, f16};
     use rten_bench::run_bench;
     use rten_gemm::{
         BiasVector, BlockQuantizedMatrix, GemmExecutor, GemmInT, GemmInputA, GemmInputB,
@@ -889,12 +1299,14 @@ mod tests {
 
     use crate::buffer_pool::AutoReturn;
     use crate::buffer_pool::BufferPool;
-    use crate::operator::{InputList, Operator, OperatorExt};
+    use crate::operator::{InputList, Operator, OperatorExt, PrepackedInput};
     use crate::ops::binary_elementwise::broadcast_shapes;
+    use crate::value::ValueView;
 
     use super::{
         AccuracyLevel, FusedMatMul, MatMul, MatMulInteger, MatMulNBits, MatmulStrategy, OpError,
-        OpRunContext, cast_scale, gemm, matmul, matmul_fused, matmul_impl, matmul_integer,
+        OpRunContext, cast_scale, gemm, matmul, matmul_fused, matmul_half_b_impl, matmul_impl,
+        matmul_integer, reorder_nbits_rhs,
     };
 
     fn gemm_tensors(c: &mut Tensor, a: &Tensor, b: &Tensor, alpha: f32, beta: f32) {
@@ -1331,6 +1743,155 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_matmul_half_b() {
+        #[derive(Debug)]
+        struct Case<'a> {
+            a_shape: &'a [usize],
+            b_shape: &'a [usize],
+            bias: bool,
+            alpha: Option<f32>,
+            max_chunk_len: usize,
+            transpose_b: bool,
+            prepack: bool,
+        }
+
+        let cases = [
+            // Single chunk
+            Case {
+                a_shape: &[4, 16],
+                b_shape: &[16, 8],
+                bias: false,
+                alpha: None,
+                max_chunk_len: 1 << 20,
+                transpose_b: false,
+                prepack: false,
+            },
+            // Multiple chunks, with a partial final chunk
+            Case {
+                a_shape: &[2, 3, 16],
+                b_shape: &[16, 8],
+                bias: true,
+                alpha: Some(0.5),
+                max_chunk_len: 40,
+                transpose_b: false,
+                prepack: false,
+            },
+            // Vector-matrix product
+            Case {
+                a_shape: &[16],
+                b_shape: &[16, 8],
+                bias: false,
+                alpha: None,
+                max_chunk_len: 32,
+                transpose_b: false,
+                prepack: false,
+            },
+            // Batched `b`. This converts all of `b` at once.
+            Case {
+                a_shape: &[2, 4, 16],
+                b_shape: &[2, 16, 8],
+                bias: false,
+                alpha: None,
+                max_chunk_len: 32,
+                transpose_b: false,
+                prepack: false,
+            },
+            // Transposed `b`, converted in chunks
+            Case {
+                a_shape: &[3, 16],
+                b_shape: &[8, 16],
+                bias: true,
+                alpha: None,
+                max_chunk_len: 40,
+                transpose_b: true,
+                prepack: false,
+            },
+            // Prepacked `b`
+            Case {
+                a_shape: &[2, 3, 16],
+                b_shape: &[16, 8],
+                bias: true,
+                alpha: Some(0.5),
+                max_chunk_len: 32,
+                transpose_b: false,
+                prepack: true,
+            },
+            // Prepacked `b` with a vector-matrix product. This ignores the
+            // packed matrix.
+            Case {
+                a_shape: &[1, 16],
+                b_shape: &[8, 16],
+                bias: false,
+                alpha: None,
+                max_chunk_len: 32,
+                transpose_b: true,
+                prepack: true,
+            },
+        ];
+
+        cases.test_each(|case| {
+            let pool = BufferPool::new();
+            let mut rng = XorShiftRng::new(1234);
+            let a = Tensor::<f32>::rand(case.a_shape, &mut rng);
+            let mut b = Tensor::<f32>::rand(case.b_shape, &mut rng);
+            let mut b_f16 = b.map(|x| f16::from_f32(*x));
+            let mut b_bf16 = b.map(|x| bf16::from_f32(*x));
+            if case.transpose_b {
+                b.transpose();
+                b_f16.transpose();
+                b_bf16.transpose();
+            }
+            let bias_data: Vec<f32> = (0..b.size(b.ndim() - 1)).map(|_| rng.next_f32()).collect();
+            let bias = case.bias.then_some(BiasVector::Row(&bias_data));
+
+            let op = MatMul {};
+            let prepack = |b: ValueView| {
+                case.prepack.then(|| match op.prepack(1, b) {
+                    Some(PrepackedInput::FloatBMatrix(packed)) => packed,
+                    _ => panic!("expected prepacked f32 matrix"),
+                })
+            };
+            let packed_f16 = prepack(b_f16.view().into());
+            let packed_bf16 = prepack(b_bf16.view().into());
+
+            // Expected results use `b` rounded to 16 bits and converted back,
+            // so they should match up to differences in summation order.
+            for (b_half, b_rounded) in [
+                (
+                    matmul_half_b_impl(
+                        &pool,
+                        a.view(),
+                        b_f16.view(),
+                        packed_f16.as_ref(),
+                        bias,
+                        case.alpha,
+                        case.max_chunk_len,
+                    ),
+                    b_f16.map(|x| x.to_f32()),
+                ),
+                (
+                    matmul_half_b_impl(
+                        &pool,
+                        a.view(),
+                        b_bf16.view(),
+                        packed_bf16.as_ref(),
+                        bias,
+                        case.alpha,
+                        case.max_chunk_len,
+                    ),
+                    b_bf16.map(|x| x.to_f32()),
+                ),
+            ] {
+                let expected =
+                    matmul_fused(&pool, a.view(), b_rounded.view(), None, bias, case.alpha)
+                        .unwrap();
+                let result = b_half.unwrap();
+                expect_equal_with_tolerance(&result, &expected, 1e-4, 1e-4).unwrap();
+            }
+        })
+    }
+
     #[test]
     fn test_matmul_invalid() {
         #[derive(Debug)]
@@ -1765,6 +2326,211 @@ mod tests {
         });
     }
 
+    /// Read the `index`th `bits`-bit element from a little-endian bitstream.
+    fn read_bits(data: &[u8], bits: u8, index: usize) -> u8 {
+        let start = index * bits as usize;
+        (0..bits as usize).fold(0, |acc, i| {
+            let bit = (data[(start + i) / 8] >> ((start + i) % 8)) & 1;
+            acc | (bit << i)
+        })
+    }
+
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    enum ZeroPointType {
+        None,
+        Packed,
+        Float,
+    }
+
+    #[test]
+    fn test_matmul_nbits_optional_inputs() {
+        #[derive(Clone, Debug)]
+        struct Case {
+            bits: u8,
+            m: usize,
+            k: usize,
+            zero_points: ZeroPointType,
+            g_idx: bool,
+            bias: bool,
+        }
+
+        let mut cases = Vec::new();
+        for bits in 2..=8 {
+            for zero_points in [
+                ZeroPointType::None,
+                ZeroPointType::Packed,
+                ZeroPointType::Float,
+            ] {
+                for m in [1, 3] {
+                    cases.push(Case {
+                        bits,
+                        m,
+                        k: 64,
+                        zero_points,
+                        g_idx: false,
+                        bias: false,
+                    });
+                }
+            }
+        }
+        for m in [1, 3] {
+            // Bias
+            cases.push(Case {
+                bits: 4,
+                m,
+                k: 64,
+                zero_points: ZeroPointType::Packed,
+                g_idx: false,
+                bias: true,
+            });
+            // Reordered blocks
+            cases.push(Case {
+                bits: 4,
+                m,
+                k: 64,
+                zero_points: ZeroPointType::Packed,
+                g_idx: true,
+                bias: true,
+            });
+            // K that is not a multiple of the block size
+            cases.push(Case {
+                bits: 8,
+                m,
+                k: 40,
+                zero_points: ZeroPointType::Float,
+                g_idx: false,
+                bias: true,
+            });
+        }
+
+        cases.test_each_clone(|case| {
+            let Case {
+                bits,
+                m,
+                k,
+                zero_points,
+                g_idx,
+                bias,
+            } = case;
+
+            let mut rng = XorShiftRng::new(1234);
+
+            let block_size = 16;
+            let k_blocks = k.div_ceil(block_size);
+            let block_bytes = block_size * bits as usize / 8;
+            let n = 5;
+
+            let lhs = NdTensor::<f32, 2>::rand([m, k], &mut rng);
+            let rhs = NdTensor::<u8, 3>::rand([n, k_blocks, block_bytes], &mut rng);
+            let scales = NdTensor::<f32, 2>::rand([n, k_blocks], &mut rng);
+            let packed_zp =
+                NdTensor::<u8, 1>::rand([n * (k_blocks * bits as usize).div_ceil(8)], &mut rng);
+            let float_zp = NdTensor::<f32, 1>::rand([n * k_blocks], &mut rng);
+            let g_idx_data = NdTensor::<i32, 1>::from_fn([k], |[ki]| ((ki * 7) % k_blocks) as i32);
+            let bias_data = NdTensor::<f32, 1>::rand([n], &mut rng);
+
+            // Dequantize the RHS and compute the expected result.
+            let zp_row_len = packed_zp.len() / n;
+            let mut expected = NdTensor::<f32, 2>::zeros([m, n]);
+            for col in 0..n {
+                for ki in 0..k {
+                    let block = if g_idx {
+                        g_idx_data[[ki]] as usize
+                    } else {
+                        ki / block_size
+                    };
+                    let block_data = rhs.slice((col, ki / block_size)).to_vec();
+                    let elem = read_bits(&block_data, bits, ki % block_size) as f32;
+                    let zero_point = match zero_points {
+                        ZeroPointType::None => (1 << (bits - 1)) as f32,
+                        ZeroPointType::Packed => {
+                            let zp_row = &packed_zp.data().unwrap()[col * zp_row_len..];
+                            read_bits(zp_row, bits, block) as f32
+                        }
+                        ZeroPointType::Float => float_zp[[col * k_blocks + block]],
+                    };
+                    let rhs_val = (elem - zero_point) * scales[[col, block]];
+                    for row in 0..m {
+                        expected[[row, col]] += lhs[[row, ki]] * rhs_val;
+                    }
+                }
+                if bias {
+                    for row in 0..m {
+                        expected[[row, col]] += bias_data[[col]];
+                    }
+                }
+            }
+
+            let op = MatMulNBits {
+                bits,
+                block_size,
+                accuracy_level: AccuracyLevel::Float,
+            };
+            let inputs = [
+                Some(lhs.view().into()),
+                Some(rhs.view().into()),
+                Some(scales.view().into()),
+                match zero_points {
+                    ZeroPointType::None => None,
+                    ZeroPointType::Packed => Some(packed_zp.view().into()),
+                    ZeroPointType::Float => Some(float_zp.view().into()),
+                },
+                g_idx.then(|| g_idx_data.view().into()),
+                bias.then(|| bias_data.view().into()),
+            ];
+            let result: NdTensor<f32, 2> =
+                op.run_simple(InputList::from_optional(&inputs)).unwrap();
+
+            expect_equal_with_tolerance(&result, &expected, 1e-4, 1e-4).unwrap();
+        });
+    }
+
+    #[test]
+    fn test_reorder_nbits_rhs() {
+        let mut rng = XorShiftRng::new(1234);
+        let [m, n, k_blocks, block_size] = [2, 3, 4, 16];
+        let k = k_blocks * block_size;
+
+        for bits in [2, 4, 5, 8] {
+            let block_bytes = block_size * bits as usize / 8;
+            let lhs = NdTensor::<f32, 2>::rand([m, k], &mut rng);
+            let rhs = NdTensor::<u8, 3>::rand([n, k_blocks, block_bytes], &mut rng);
+            let scales = NdTensor::<f32, 2>::rand([n, k_blocks], &mut rng);
+            let g_idx =
+                NdTensor::<i32, 1>::from_fn([k], |[row]| (((row * 5) % k) / block_size) as i32);
+            let op = MatMulNBits {
+                bits,
+                block_size,
+                accuracy_level: AccuracyLevel::Float,
+            };
+
+            let inputs = [
+                Some(lhs.view().into()),
+                Some(rhs.view().into()),
+                Some(scales.view().into()),
+                None,
+                Some(g_idx.view().into()),
+            ];
+            let expected: NdTensor<f32, 2> =
+                op.run_simple(InputList::from_optional(&inputs)).unwrap();
+
+            let (reordered, perm) = reorder_nbits_rhs(rhs.view(), bits, block_size, g_idx.view())
+                .expect("should reorder");
+            let lhs =
+                NdTensor::<f32, 2>::from_fn([m, k], |[row, col]| lhs[[row, perm[col] as usize]]);
+            let result: NdTensor<f32, 2> = op
+                .run_simple((lhs.view(), reordered.view(), scales.view()))
+                .unwrap();
+
+            expect_equal_with_tolerance(&result, &expected, 1e-4, 1e-4).unwrap();
+        }
+
+        // Blocks which are not used by exactly `block_size` rows.
+        let rhs = NdTensor::<u8, 3>::zeros([n, k_blocks, block_size / 2]);
+        let g_idx = NdTensor::<i32, 1>::from_fn([k], |[row]| (row % 3) as i32);
+        assert!(reorder_nbits_rhs(rhs.view(), 4, block_size, g_idx.view()).is_none());
+    }
+
     #[test]
     fn test_matmul_nbits_invalid() {
         #[derive(Debug)]
@@ -1775,12 +2541,23 @@ mod tests {
             expected: OpError,
         }
 
-        let cases = [Case {
-            lhs_shape: [1].into(),
-            rhs_shape: [1, 1, 16],
-            scales_shape: [1, 1].into(),
-            expected: OpError::InvalidValue("A input must have at least 2 dims"),
-        }];
+        let cases = [
+            Case {
+                lhs_shape: [1].into(),
+                rhs_shape: [1, 1, 16],
+                scales_shape: [1, 1].into(),
+                expected: OpError::InvalidValue("A input must have at least 2 dims"),
+            },
+            // K is more than one block smaller than the quantized K.
+            Case {
+                lhs_shape: [1, 16].into(),
+                rhs_shape: [1, 2, 16],
+                scales_shape: [1, 2].into(),
+                expected: OpError::IncompatibleInputShapes(
+                    "Columns of first matrix does not match rows of second matrix",
+                ),
+            },
+        ];
 
         cases.test_each(|case| {
             let op = MatMulNBits {
@@ -48,6 +48,7 @@ mod random;
 mod reduce;
 mod resize;
 mod rnn;
+mod roi_align;
 mod sequence;
 mod slice;
 mod split;
@@ -61,46 +62,58 @@ pub(crate) mod transform_inputs;
 // Operator structs. These are re-exported for internal use by the model loader
 // and tests.
 #[cfg(feature = "fft")]
-pub(crate) use fft::STFT;
+pub(crate) use fft::{BlackmanWindow, DFT, HammingWindow, HannWindow, MelWeightMatrix, STFT};
 #[cfg(feature = "random")]
 pub(crate) use random::{
     Dropout, RandomNormal, RandomNormalLike, RandomUniform, RandomUniformLike,
 };
 pub(crate) use {
-    attention::{AddSoftmax, GroupedQueryAttentionMatMul, RepeatInterleave},
+    attention::{
+        AddSoftmax, Attention, GroupQueryAttention, GroupedQueryAttentionMatMul, RepeatInterleave,
+        RotaryEmbedding,
+    },
     binary_elementwise::{
-        Add, And, Div, Equal, Greater, GreaterOrEqual, Less, LessOrEqual, Mod, Mul, Or, Pow, Sub,
-        Where, Xor,
+        Add, And, BitShift, BitwiseAnd, BitwiseOr, BitwiseXor, Div, Equal, Greater, GreaterOrEqual,
+        Less, LessOrEqual, Mod, Mul, Or, Pow, Sub, Where, Xor,
     },
     compute_shape::{ComputeShape, SymbolInfo},
     concat::{Concat, Tile},
     control_flow::{If, Loop},
-    conv::{Conv, ConvInteger},
+    conv::{Col2Im, Conv, ConvInteger},
     conv_transpose::ConvTranspose,
-    convert::{Cast, CastLike},
+    convert::{Cast, CastLike, narrow_to_i32, unify_int_types},
     einsum::Einsum,
-    gather::{Gather, GatherElements, GatherND, ScatterElements, ScatterND, ScatterReduction},
+    gather::{
+        Compress, Gather, GatherElements, GatherND, ReverseSequence, ScatterElements, ScatterND,
+        ScatterReduction, Unique,
+    },
     generate::{ConstantOfShape, EyeLike, OneHot, Range},
     grid_sample::GridSample,
     identity::Identity,
-    layout::{DepthToSpace, Expand, Flatten, Reshape, Shape, Size, Squeeze, Transpose, Unsqueeze},
+    layout::{
+        DepthToSpace, Expand, Flatten, Reshape, Shape, Size, SpaceToDepth, Squeeze, Transpose,
+        Unsqueeze,
+    },
     matmul::{
         AccuracyLevel, FusedMatMul, Gemm, MatMul, MatMulInteger, MatMulIntegerToFloat, MatMulNBits,
+        reorder_nbits_rhs,
     },
     non_max_suppression::NonMaxSuppression,
     norm::{
-        BatchNormalization, InstanceNormalization, LayerNormalization, LogSoftmax,
-        RmsNormalization, Softmax,
+        BatchNormalization, GroupNormalization, InstanceNormalization, LayerNormalization,
+        LogSoftmax, LpNormalization, MeanVarianceNormalization, RmsNormalization,
+        SkipSimplifiedLayerNormalization, Softmax,
     },
     pad::Pad,
-    pooling::{AveragePool, GlobalAveragePool, GlobalMaxPool, MaxPool},
+    pooling::{AveragePool, GlobalAveragePool, GlobalMaxPool, LpPool, MaxPool, MaxUnpool},
     quantize::{DequantizeLinear, DynamicQuantizeLinear, QuantizeLinear},
     reduce::{
-        ArgMax, ArgMin, CumSum, NonZero, ReduceL2, ReduceMax, ReduceMean, ReduceMin, ReduceProd,
-        ReduceSum, ReduceSumSquare, TopK,
+        ArgMax, ArgMin, CumSum, Det, Hardmax, NonZero, ReduceL2, ReduceLogSumExp, ReduceMax,
+        ReduceMean, ReduceMin, ReduceProd, ReduceSum, ReduceSumSquare, TopK,
     },
     resize::Resize,
     rnn::{GRU, LSTM},
+    roi_align::RoiAlign,
     sequence::{
         ConcatFromSequence, SequenceAt, SequenceConstruct, SequenceEmpty, SequenceErase,
         SequenceInsert, SequenceLength, SplitToSequence,
@@ -109,9 +122,10 @@ pub(crate) use {
     split::Split,
     trilu::Trilu,
     unary_elementwise::{
-        Abs, Acos, Asin, Atan, Ceil, Clip, Cos, Elu, Erf, Exp, Floor, Gelu, HardSigmoid, HardSwish,
-        IsInf, IsNaN, LeakyRelu, Log, Neg, Not, PRelu, Reciprocal, Relu, Round, Sigmoid, Sign,
-        Silu, Sin, Softplus, Sqrt, Swish, Tan, Tanh,
+        Abs, Acos, Asin, Atan, BitwiseNot, Ceil, Celu, Clip, Cos, Elu, Erf, Exp, Floor, Gelu,
+        HardSigmoid, HardSwish, IsInf, IsNaN, LeakyRelu, Log, Mish, Neg, Not, PRelu, Reciprocal,
+        Relu, Round, Selu, Shrink, Sigmoid, Sign, Silu, Sin, Softplus, Sqrt, Swish, Tan, Tanh,
+        ThresholdedRelu,
     },
     variadic_elementwise::{Max, Mean, Min, Sum},
 };
@@ -123,35 +137,42 @@ pub(crate) use {
 // These may be removed from the public API of the crate in future.
 // See https://github.com/robertknight/rten/issues/911.
 pub use binary_elementwise::{
-    DivMode, add, and, div, equal, greater, greater_or_equal, less, less_or_equal, mod_op, mul, or,
-    pow, sub, where_op, xor,
+    BitShiftDirection, DivMode, add, and, bit_shift, bitwise_and, bitwise_or, bitwise_xor, div,
+    equal, greater, greater_or_equal, less, less_or_equal, mod_op, mul, or, pow, sub, where_op,
+    xor,
 };
 pub use concat::{concat, tile};
-pub use conv::{conv, conv_integer};
+pub use conv::{col2im, conv, conv_integer};
 pub use conv_transpose::conv_transpose;
 pub use einsum::einsum;
-pub use gather::{gather, gather_elements, gather_nd, scatter_elements, scatter_nd};
+pub use gather::{
+    UniqueOutput, compress, gather, gather_elements, gather_nd, reverse_sequence, scatter_elements,
+    scatter_nd, unique,
+};
 pub use generate::{constant_of_shape, onehot, range};
-pub use layout::{DepthToSpaceMode, depth_to_space, expand, flatten, reshape, squeeze};
+pub use layout::{
+    DepthToSpaceMode, depth_to_space, expand, flatten, reshape, space_to_depth, squeeze,
+};
 pub use matmul::{gemm, matmul};
 pub use non_max_suppression::{BoxOrder, non_max_suppression};
 pub use norm::{
-    batch_norm, instance_normalization, layer_normalization, log_softmax, rms_normalization,
-    softmax,
+    batch_norm, group_normalization, instance_normalization, layer_normalization, log_softmax,
+    lp_normalization, mean_variance_normalization, rms_normalization, softmax,
 };
 pub use pad::{PadMode, pad};
-pub use pooling::{average_pool, global_average_pool, max_pool};
+pub use pooling::{average_pool, global_average_pool, lp_pool, max_pool, max_unpool};
 pub use quantize::{dequantize_linear, dynamic_quantize_linear, quantize_linear};
 
 #[cfg(feature = "fft")]
-pub use fft::stft;
+pub use fft::{dft, mel_weight_matrix, stft};
 
 pub use reduce::{
-    arg_max, arg_min, cum_sum, nonzero, reduce_l2, reduce_max, reduce_mean, reduce_min,
-    reduce_prod, reduce_sum, reduce_sum_square, topk,
+    arg_max, arg_min, cum_sum, det, hardmax, nonzero, reduce_l2, reduce_log_sum_exp, reduce_max,
+    reduce_mean, reduce_min, reduce_prod, reduce_sum, reduce_sum_square, topk,
 };
 pub use resize::{CoordTransformMode, NearestMode, ResizeMode, ResizeTarget, resize, resize_image};
 pub use rnn::{Direction, gru, lstm};
+pub use roi_align::{RoiAlignMode, RoiAlignOptions, roi_align};
 pub use slice::slice;
 pub use split::split;
 pub use trilu::trilu;
@@ -266,6 +287,10 @@ macro_rules! map_value_view {
             ValueView::Int32Tensor($typed_input) => $block,
             ValueView::UInt8Tensor($typed_input) => $block,
             ValueView::Int8Tensor($typed_input) => $block,
+            ValueView::Float16Tensor($typed_input) => $block,
+            ValueView::BFloat16Tensor($typed_input) => $block,
+            ValueView::Int64Tensor($typed_input) => $block,
+            ValueView::BoolTensor($typed_input) => $block,
             ValueView::Sequence(_) => Err(OpError::UnsupportedType)
         }
     };
@@ -307,6 +332,22 @@ macro_rules! map_dtype {
                 type $type = i8;
                 $block
             }
+            DataType::Float16 => {
+                type $type = rten_base::half::f16;
+                $block
+            }
+            DataType::BFloat16 => {
+                type $type = rten_base::half::bf16;
+                $block
+            }
+            DataType::Int64 => {
+                type $type = i64;
+                $block
+            }
+            DataType::Bool => {
+                type $type = bool;
+                $block
+            }
         }
     }};
 }
@@ -335,6 +376,14 @@ macro_rules! map_value {
             Value::UInt8Tensor(mut $typed_input) => $block,
             #[allow(unused_mut)]
             Value::Int8Tensor(mut $typed_input) => $block,
+            #[allow(unused_mut)]
+            Value::Float16Tensor(mut $typed_input) => $block,
+            #[allow(unused_mut)]
+            Value::BFloat16Tensor(mut $typed_input) => $block,
+            #[allow(unused_mut)]
+            Value::Int64Tensor(mut $typed_input) => $block,
+            #[allow(unused_mut)]
+            Value::BoolTensor(mut $typed_input) => $block,
             Value::Sequence(_) => Err(OpError::UnsupportedType),
         }
     };
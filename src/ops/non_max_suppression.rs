@@ -185,7 +185,7 @@ pub fn non_max_suppression(
     Ok(selected_indices)
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct NonMaxSuppression {
     pub box_order: BoxOrder,
 }
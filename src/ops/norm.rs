@@ -2,18 +2,19 @@ use std::mem::MaybeUninit;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
 use rayon::prelude::*;
+use rten_shape_inference::ops as shape_ops;
 use rten_simd::SimdOp;
 use rten_tensor::prelude::*;
 use rten_tensor::{NdTensorView, Tensor, TensorView};
 use rten_vecmath as vecmath;
 
-use crate::buffer_pool::BufferPool;
+use crate::buffer_pool::{AutoReturn, BufferPool};
 use crate::infer_shapes::{InferShapes, UnaryOp};
 use crate::operator::{
     IntoOpResult, OpError, OpRunContext, Operator, OutputList, OutputType, OutputTypeList,
     OutputTypesContext,
 };
-use crate::ops::resolve_axis;
+use crate::ops::{add, resolve_axes, resolve_axis};
 use crate::slice_reductions::slice_max;
 use crate::value::Value;
 
@@ -235,7 +236,7 @@ pub fn batch_norm(
     Ok(output)
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct BatchNormalization {
     pub epsilon: f32,
 }
@@ -333,7 +334,7 @@ pub fn instance_normalization_in_place(
     Ok(())
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct InstanceNormalization {
     pub epsilon: Option<f32>,
 }
@@ -381,6 +382,310 @@ impl Operator for InstanceNormalization {
     }
 }
 
+pub fn group_normalization(
+    pool: &BufferPool,
+    input: TensorView,
+    scale: NdTensorView<f32, 1>,
+    bias: NdTensorView<f32, 1>,
+    num_groups: usize,
+    epsilon: Option<f32>,
+) -> Result<Tensor, OpError> {
+    let mut output = input.to_tensor_in(pool);
+    group_normalization_in_place(&mut output, scale, bias, num_groups, epsilon)?;
+    Ok(output)
+}
+
+/// Perform in-place group normalization on an `NC*` tensor.
+///
+/// `scale` and `bias` may either have one entry per channel (as in opset 21+)
+/// or one entry per group (as in opset 18).
+///
+/// See <https://onnx.ai/onnx/operators/onnx__GroupNormalization.html>.
+pub fn group_normalization_in_place(
+    input: &mut Tensor,
+    scale: NdTensorView<f32, 1>,
+    bias: NdTensorView<f32, 1>,
+    num_groups: usize,
+    epsilon: Option<f32>,
+) -> Result<(), OpError> {
+    let &[batch, chans, ..] = input.shape() else {
+        return Err(OpError::InvalidValue("expected input with >= 2 dims"));
+    };
+    let epsilon = epsilon.unwrap_or(1e-5);
+
+    if num_groups == 0 || chans % num_groups != 0 {
+        return Err(OpError::InvalidValue(
+            "channel count must be a multiple of `num_groups`",
+        ));
+    }
+    let chans_per_group = chans / num_groups;
+
+    let param_index = |len: usize| -> Result<fn(usize, usize) -> usize, OpError> {
+        if len == chans {
+            Ok(|chan, _| chan)
+        } else if len == num_groups {
+            Ok(|chan, chans_per_group| chan / chans_per_group)
+        } else {
+            Err(OpError::InvalidValue(
+                "scale and bias length should match channel or group count",
+            ))
+        }
+    };
+    let scale_index = param_index(scale.size(0))?;
+    let bias_index = param_index(bias.size(0))?;
+
+    if input.is_empty() {
+        return Ok(());
+    }
+
+    input.make_contiguous();
+    let elts_per_chan = input.len() / (batch * chans);
+    let elts_per_group = elts_per_chan * chans_per_group;
+
+    input
+        .data_mut()
+        .unwrap()
+        .par_chunks_mut(elts_per_group)
+        .enumerate()
+        .for_each(|(batch_group, group_data)| {
+            let group = batch_group % num_groups;
+            let mean = vecmath::Sum::new(group_data).dispatch() / group_data.len() as f32;
+            let variance =
+                vecmath::SumSquareSub::new(group_data, mean).dispatch() / group_data.len() as f32;
+
+            for (i, chan_data) in group_data.chunks_mut(elts_per_chan).enumerate() {
+                let chan = group * chans_per_group + i;
+                normalize_slice(
+                    chan_data.into(),
+                    NormalizeOptions {
+                        mean_normalize: MeanNormalize::Static { mean, variance },
+                        epsilon,
+                        scale: scale[scale_index(chan, chans_per_group)],
+                        bias: bias[bias_index(chan, chans_per_group)],
+                        ..Default::default()
+                    },
+                );
+            }
+        });
+
+    Ok(())
+}
+
+#[derive(Clone, Debug)]
+pub struct GroupNormalization {
+    pub num_groups: usize,
+    pub epsilon: Option<f32>,
+}
+
+impl Operator for GroupNormalization {
+    fn name(&self) -> &str {
+        "GroupNormalization"
+    }
+
+    fn max_inputs(&self) -> Option<usize> {
+        Some(3)
+    }
+
+    fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
+        let inputs = ctx.inputs();
+        let input = inputs.require_as(0)?;
+        let scale = inputs.require_as(1)?;
+        let bias = inputs.require_as(2)?;
+
+        group_normalization(
+            ctx.pool(),
+            input,
+            scale,
+            bias,
+            self.num_groups,
+            self.epsilon,
+        )
+        .into_op_result()
+    }
+
+    fn can_run_in_place(&self) -> bool {
+        true
+    }
+
+    fn run_in_place(&self, input: Value, ctx: &OpRunContext) -> Result<Value, OpError> {
+        let mut output: Tensor = input.try_into()?;
+        let inputs = ctx.inputs();
+        let scale = inputs.require_as(0)?;
+        let bias = inputs.require_as(1)?;
+
+        group_normalization_in_place(&mut output, scale, bias, self.num_groups, self.epsilon)?;
+
+        Ok(output.into())
+    }
+
+    fn as_infer_shapes(&self) -> Option<&dyn InferShapes> {
+        Some(&UnaryOp)
+    }
+
+    fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
+        Some([OutputType::CopyFromInput(0)].into())
+    }
+}
+
+/// Normalize the mean and variance of `input` over `axes`.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__MeanVarianceNormalization.html>.
+pub fn mean_variance_normalization(
+    pool: &BufferPool,
+    input: TensorView,
+    axes: &[i32],
+) -> Result<Tensor, OpError> {
+    let ndim = input.ndim();
+    let axes = resolve_axes(ndim, axes.iter())?;
+
+    // Move the normalized axes to the end, so each set of elements which are
+    // normalized together forms a contiguous chunk.
+    let mut perm: Vec<usize> = (0..ndim).filter(|dim| !axes.contains(dim)).collect();
+    perm.extend((0..ndim).filter(|dim| axes.contains(dim)));
+    let chunk_len: usize = perm[ndim - axes.len()..]
+        .iter()
+        .map(|&dim| input.size(dim))
+        .product();
+
+    let mut output = input.permuted(perm.as_slice()).to_tensor_in(pool);
+    if chunk_len > 0 {
+        output
+            .data_mut()
+            .unwrap()
+            .par_chunks_mut(chunk_len)
+            .for_each(|chunk| {
+                let mean = vecmath::Sum::new(chunk).dispatch() / chunk.len() as f32;
+                let variance =
+                    vecmath::SumSquareSub::new(chunk, mean).dispatch() / chunk.len() as f32;
+
+                // The ONNX reference adds a small value to the standard
+                // deviation rather than the variance.
+                let std_dev = variance.sqrt() + 1e-9;
+                normalize_slice(
+                    chunk.into(),
+                    NormalizeOptions {
+                        mean_normalize: MeanNormalize::Static {
+                            mean,
+                            variance: std_dev * std_dev,
+                        },
+                        epsilon: 0.,
+                        ..Default::default()
+                    },
+                );
+            });
+    }
+
+    let mut inverse_perm = vec![0; ndim];
+    for (i, &dim) in perm.iter().enumerate() {
+        inverse_perm[dim] = i;
+    }
+    output.permute(&inverse_perm);
+    output.make_contiguous();
+
+    Ok(output)
+}
+
+#[derive(Clone, Debug)]
+pub struct MeanVarianceNormalization {
+    pub axes: Vec<i32>,
+}
+
+impl Default for MeanVarianceNormalization {
+    fn default() -> Self {
+        MeanVarianceNormalization {
+            axes: [0, 2, 3].into(),
+        }
+    }
+}
+
+impl Operator for MeanVarianceNormalization {
+    fn name(&self) -> &str {
+        "MeanVarianceNormalization"
+    }
+
+    fn max_inputs(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
+        let input = ctx.inputs().require_as(0)?;
+        mean_variance_normalization(ctx.pool(), input, &self.axes).into_op_result()
+    }
+
+    fn as_infer_shapes(&self) -> Option<&dyn InferShapes> {
+        Some(&UnaryOp)
+    }
+
+    fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
+        Some([OutputType::CopyFromInput(0)].into())
+    }
+}
+
+/// Scale lanes of `input` along `axis` to have unit L1 (`p = 1`) or L2
+/// (`p = 2`) norm.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__LpNormalization.html>.
+pub fn lp_normalization(
+    pool: &BufferPool,
+    input: TensorView,
+    axis: isize,
+    p: i32,
+) -> Result<Tensor, OpError> {
+    let axis = resolve_axis(input.ndim(), axis)?;
+    if !matches!(p, 1 | 2) {
+        return Err(OpError::InvalidValue("`p` must be 1 or 2"));
+    }
+
+    let norms: Vec<f32> = input
+        .lanes(axis)
+        .map(|lane| {
+            if p == 1 {
+                lane.map(|x| x.abs()).sum()
+            } else {
+                lane.map(|x| x * x).sum::<f32>().sqrt()
+            }
+        })
+        .collect();
+    let mut output = input.to_tensor_in(pool);
+    for (lane, norm) in output.lanes_mut(axis).zip(norms) {
+        // Lanes with a norm of zero are left unchanged, matching ONNX Runtime.
+        if norm != 0. {
+            lane.for_each(|x| *x /= norm);
+        }
+    }
+
+    Ok(output)
+}
+
+#[derive(Clone, Debug)]
+pub struct LpNormalization {
+    pub axis: isize,
+    pub p: i32,
+}
+
+impl Operator for LpNormalization {
+    fn name(&self) -> &str {
+        "LpNormalization"
+    }
+
+    fn max_inputs(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
+        let input = ctx.inputs().require_as(0)?;
+        lp_normalization(ctx.pool(), input, self.axis, self.p).into_op_result()
+    }
+
+    fn as_infer_shapes(&self) -> Option<&dyn InferShapes> {
+        Some(&UnaryOp)
+    }
+
+    fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
+        Some([OutputType::CopyFromInput(0)].into())
+    }
+}
+
 pub fn rms_normalization(
     pool: &BufferPool,
     input: TensorView,
@@ -494,7 +799,7 @@ fn layer_normalization_impl(
     Ok(Tensor::from_data(input.shape(), output))
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct LayerNormalization {
     pub axis: isize,
     pub epsilon: Option<f32>,
@@ -535,7 +840,7 @@ impl Operator for LayerNormalization {
 /// normalize the scale.
 ///
 /// See <https://pytorch.org/docs/stable/generated/torch.nn.modules.normalization.RMSNorm.html>.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct RmsNormalization {
     pub axis: isize,
     pub epsilon: Option<f32>,
@@ -567,6 +872,84 @@ impl Operator for RmsNormalization {
     }
 }
 
+/// Add `input`, `skip` and an optional `bias`, then apply RMS normalization
+/// over the last axis.
+///
+/// This is the `com.microsoft.SkipSimplifiedLayerNormalization` operator used
+/// by ONNX Runtime exports of LLMs. In addition to the normalized output, the
+/// operator returns the mean and inverse RMS of each row and the sum of the
+/// inputs, which is used as the residual for the next layer.
+#[derive(Clone, Debug)]
+pub struct SkipSimplifiedLayerNormalization {
+    pub epsilon: Option<f32>,
+}
+
+impl Operator for SkipSimplifiedLayerNormalization {
+    fn name(&self) -> &str {
+        "SkipSimplifiedLayerNormalization"
+    }
+
+    fn max_inputs(&self) -> Option<usize> {
+        Some(4)
+    }
+
+    fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
+        let pool = ctx.pool();
+        let inputs = ctx.inputs();
+        let input = inputs.require_as(0)?;
+        let skip = inputs.require_as(1)?;
+        let scale = inputs.require_as(2)?;
+        let bias = inputs.get_as(3)?;
+
+        let mut sum: Tensor = add(pool, input, skip)?;
+        if let Some(bias) = bias {
+            sum = add(pool, sum.auto_return(pool).view(), bias)?;
+        }
+        let output = rms_normalization(pool, sum.view(), scale, -1, self.epsilon)?;
+
+        if ctx.num_outputs().is_some_and(|n| n <= 1) {
+            return output.into_op_result();
+        }
+
+        // Compute the mean and inverse RMS statistics of each row.
+        let mut stats_shape = sum.shape().to_vec();
+        let row_size = stats_shape.pop().unwrap_or(1);
+        stats_shape.push(1);
+        let epsilon = self.epsilon.unwrap_or(1e-5);
+        sum.make_contiguous();
+        let (mean, inv_rms): (Vec<f32>, Vec<f32>) = sum
+            .data()
+            .unwrap()
+            .chunks(row_size.max(1))
+            .map(|row| {
+                let n = row.len() as f32;
+                let mean = row.iter().sum::<f32>() / n;
+                let mean_square = row.iter().map(|x| x * x).sum::<f32>() / n;
+                (mean, 1. / (mean_square + epsilon).sqrt())
+            })
+            .unzip();
+        let mean = Tensor::from_data(&stats_shape, mean);
+        let inv_rms = Tensor::from_data(&stats_shape, inv_rms);
+
+        Ok([output.into(), mean.into(), inv_rms.into(), sum.into()]
+            .into_iter()
+            .collect())
+    }
+
+    fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
+        Some(OutputTypeList::from_slice(&[
+            OutputType::CopyFromInput(0),
+            OutputType::CopyFromInput(0),
+            OutputType::CopyFromInput(0),
+            OutputType::CopyFromInput(0),
+        ]))
+    }
+
+    fn as_infer_shapes(&self) -> Option<&dyn InferShapes> {
+        Some(&shape_ops::SkipSimplifiedLayerNormalization)
+    }
+}
+
 pub fn log_softmax(pool: &BufferPool, input: TensorView, axis: isize) -> Result<Tensor, OpError> {
     let mut output = input.to_tensor_in(pool);
     log_softmax_in_place(&mut output, axis)?;
@@ -656,7 +1039,7 @@ pub fn log_softmax_in_place(output: &mut Tensor, axis: isize) -> Result<(), OpEr
     })
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct LogSoftmax {
     pub axis: isize,
 }
@@ -731,7 +1114,7 @@ pub fn softmax_in_place(
     Ok(())
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Softmax {
     pub axis: isize,
 
@@ -789,6 +1172,7 @@ impl Operator for Softmax {
 #[cfg(test)]
 mod tests {
     use std::error::Error;
+    use std::f32::consts::FRAC_1_SQRT_2;
 
     use rten_tensor::prelude::*;
     use rten_tensor::rng::XorShiftRng;
@@ -798,10 +1182,12 @@ mod tests {
 
     use super::SOFTMAX_GRAIN_SIZE;
     use super::{
-        NanHandling, batch_norm, batch_norm_in_place, instance_normalization, layer_normalization,
-        log_softmax, rms_normalization, softmax,
+        NanHandling, SkipSimplifiedLayerNormalization, batch_norm, batch_norm_in_place,
+        group_normalization, instance_normalization, layer_normalization, log_softmax,
+        lp_normalization, mean_variance_normalization, rms_normalization, softmax,
     };
     use crate::buffer_pool::BufferPool;
+    use crate::operator::{InputList, OpRunContext, Operator};
     use crate::ops::OpError;
     use crate::ops::tests::expect_eq_1e4;
 
@@ -918,6 +1304,141 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_group_normalization() -> Result<(), Box<dyn Error>> {
+        let input = Tensor::from([[[0.1, 0.9], [0.4, 0.7], [0.3, 0.2], [0.8, 0.5]]]);
+        let pool = BufferPool::new();
+
+        // Per-channel scale and bias.
+        let scale = Tensor::from([1., 2., 1., 0.5]);
+        let bias = Tensor::from([0., 1., 0., -1.]);
+        let result = group_normalization(
+            &pool,
+            input.view(),
+            scale.nd_view(),
+            bias.nd_view(),
+            2,
+            None,
+        )?;
+        let expected = Tensor::from([[
+            [-1.4021, 1.2371],
+            [0.1753, 2.1546],
+            [-0.6546, -1.0910],
+            [-0.2363, -0.8909],
+        ]]);
+        expect_eq_1e4(&result, &expected)?;
+
+        // Per-group scale and bias.
+        let scale = Tensor::from([2., 0.5]);
+        let bias = Tensor::from([1., -1.]);
+        let result = group_normalization(
+            &pool,
+            input.view(),
+            scale.nd_view(),
+            bias.nd_view(),
+            2,
+            None,
+        )?;
+        let expected = Tensor::from([[
+            [-1.8041, 3.4742],
+            [0.1753, 2.1546],
+            [-1.3273, -1.5455],
+            [-0.2363, -0.8909],
+        ]]);
+        expect_eq_1e4(&result, &expected)?;
+
+        // Channel count not divisible by group count.
+        let result = group_normalization(
+            &pool,
+            input.view(),
+            scale.nd_view(),
+            bias.nd_view(),
+            3,
+            None,
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue(
+                "channel count must be a multiple of `num_groups`"
+            ))
+        );
+
+        Ok(())
+    }
+
+    /// Reference implementation of GroupNormalization with per-channel scale
+    /// and bias, for an input of shape `(N, C, H, W)`.
+    fn reference_group_norm(
+        input: NdTensorView<f32, 4>,
+        scale: &[f32],
+        bias: &[f32],
+        num_groups: usize,
+        epsilon: f32,
+    ) -> NdTensor<f32, 4> {
+        let [n, c, h, w] = input.shape();
+        let group_size = c / num_groups;
+        let mut stats = Vec::new();
+        for batch in 0..n {
+            for group in 0..num_groups {
+                let chans = group * group_size..(group + 1) * group_size;
+                let group_input = input.slice((batch, chans));
+                let count = group_input.len() as f32;
+                let mean = group_input.iter().sum::<f32>() / count;
+                let var = group_input.iter().map(|x| (x - mean).powi(2)).sum::<f32>() / count;
+                stats.push((mean, var));
+            }
+        }
+        NdTensor::from_fn([n, c, h, w], |[batch, chan, y, x]| {
+            let (mean, var) = stats[batch * num_groups + chan / group_size];
+            let norm = (input[[batch, chan, y, x]] - mean) / (var + epsilon).sqrt();
+            scale[chan] * norm + bias[chan]
+        })
+    }
+
+    #[test]
+    fn test_group_normalization_onnx_backend_cases() {
+        // Cases based on the ONNX backend tests (`test_group_normalization_example`
+        // and `test_group_normalization_epsilon`), which use random inputs.
+        #[derive(Debug)]
+        struct Case {
+            epsilon: Option<f32>,
+        }
+
+        let cases = [
+            Case { epsilon: None },
+            Case {
+                epsilon: Some(1e-2),
+            },
+        ];
+
+        cases.test_each(|case| {
+            let mut rng = XorShiftRng::new(1234);
+            let pool = BufferPool::new();
+            let input = NdTensor::<f32, 4>::rand([3, 4, 2, 2], &mut rng);
+            let scale = NdTensor::<f32, 1>::rand([4], &mut rng);
+            let bias = NdTensor::<f32, 1>::rand([4], &mut rng);
+
+            let result = group_normalization(
+                &pool,
+                input.as_dyn(),
+                scale.view(),
+                bias.view(),
+                2,
+                case.epsilon,
+            )
+            .unwrap();
+
+            let expected = reference_group_norm(
+                input.view(),
+                scale.data().unwrap(),
+                bias.data().unwrap(),
+                2,
+                case.epsilon.unwrap_or(1e-5),
+            );
+            expect_eq_1e4(&result, &expected.into_dyn()).unwrap();
+        });
+    }
+
     #[test]
     fn test_instance_normalization() -> Result<(), Box<dyn Error>> {
         // Sample values generated using `torch.rand`.
@@ -1066,6 +1587,119 @@ mod tests {
         NdTensor::from_data(input.shape(), out)
     }
 
+    #[test]
+    fn test_lp_normalization() -> Result<(), Box<dyn Error>> {
+        let pool = BufferPool::new();
+        let input = Tensor::from([[3., 4.], [0., 0.], [-1., 1.]]);
+
+        let result = lp_normalization(&pool, input.view(), -1, 2)?;
+        let expected = Tensor::from([[0.6, 0.8], [0., 0.], [-FRAC_1_SQRT_2, FRAC_1_SQRT_2]]);
+        expect_eq_1e4(&result, &expected)?;
+
+        let result = lp_normalization(&pool, input.view(), 1, 1)?;
+        let expected = Tensor::from([[3. / 7., 4. / 7.], [0., 0.], [-0.5, 0.5]]);
+        expect_eq_1e4(&result, &expected)?;
+
+        let result = lp_normalization(&pool, input.view(), 0, 1)?;
+        let expected = Tensor::from([[0.75, 0.8], [0., 0.], [-0.25, 0.2]]);
+        expect_eq_1e4(&result, &expected)?;
+
+        let result = lp_normalization(&pool, input.view(), -1, 3);
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("`p` must be 1 or 2"))
+        );
+
+        // Cases from the ONNX backend tests (`test_l1normalization_*`,
+        // `test_l2normalization_*` and `test_lpnormalization_default`).
+        let result = lp_normalization(&pool, Tensor::from([3., 4.]).view(), 0, 1)?;
+        expect_eq_1e4(&result, &Tensor::from([3. / 7., 4. / 7.]))?;
+
+        let x2 = Tensor::from([[3., 4.], [6., 8.]]);
+        let result = lp_normalization(&pool, x2.view(), 1, 1)?;
+        let expected = Tensor::from([[3. / 7., 4. / 7.], [6. / 14., 8. / 14.]]);
+        expect_eq_1e4(&result, &expected)?;
+
+        let result = lp_normalization(&pool, x2.view(), 1, 2)?;
+        let expected = Tensor::from([[0.6, 0.8], [0.6, 0.8]]);
+        expect_eq_1e4(&result, &expected)?;
+
+        let x3 = Tensor::from([[[1., 2., 2.], [3., 4., 0.]], [[0., 5., 5.], [6., 8., 0.]]]);
+        let result = lp_normalization(&pool, x3.view(), -1, 1)?;
+        let expected = Tensor::from([
+            [[0.2, 0.4, 0.4], [0.4286, 0.5714, 0.]],
+            [[0., 0.5, 0.5], [0.4286, 0.5714, 0.]],
+        ]);
+        expect_eq_1e4(&result, &expected)?;
+
+        let result = lp_normalization(&pool, x3.view(), -1, 2)?;
+        let expected = Tensor::from([
+            [[0.3333, 0.6667, 0.6667], [0.6, 0.8, 0.]],
+            [[0., FRAC_1_SQRT_2, FRAC_1_SQRT_2], [0.6, 0.8, 0.]],
+        ]);
+        expect_eq_1e4(&result, &expected)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_mean_variance_normalization() -> Result<(), Box<dyn Error>> {
+        let pool = BufferPool::new();
+        let input = Tensor::from_data(&[2, 2, 1, 2], vec![1., 2., 3., 5., 4., 0., 6., 9.]);
+
+        // Expected values computed using the ONNX reference implementation.
+        let result = mean_variance_normalization(&pool, input.view(), &[0, 2, 3])?;
+        let expected = Tensor::from_data(
+            &[2, 2, 1, 2],
+            vec![
+                -0.5071, 0.1690, -1.2702, -0.3464, 1.5213, -1.1832, 0.1155, 1.5011,
+            ],
+        );
+        expect_eq_1e4(&result, &expected)?;
+
+        // Case from the ONNX backend tests (`test_mvn`).
+        let input = Tensor::from([
+            [
+                [0.8439683, 0.5665144, 0.05836735],
+                [0.02916367, 0.12964272, 0.5060197],
+                [0.79538304, 0.9411346, 0.9546573],
+            ],
+            [
+                [0.17730942, 0.46192095, 0.26480448],
+                [0.6746842, 0.01665257, 0.62473077],
+                [0.9240844, 0.9722341, 0.11965699],
+            ],
+            [
+                [0.41356155, 0.9129373, 0.59330076],
+                [0.81929934, 0.7862604, 0.11799799],
+                [0.69248444, 0.54119414, 0.07513223],
+            ],
+        ])
+        .into_shape([3, 3, 3, 1].as_slice());
+        let result = mean_variance_normalization(&pool, input.view(), &[0, 2, 3])?;
+        let expected = Tensor::from([
+            [
+                [1.3546, 0.3305, -1.5451],
+                [-1.2107, -0.8926, 0.2989],
+                [0.3808, 0.8181, 0.8587],
+            ],
+            [
+                [-1.1061, -0.0555, -0.7831],
+                [0.8328, -1.2503, 0.6747],
+                [0.7669, 0.9114, -1.6464],
+            ],
+            [
+                [-0.2340, 1.6092, 0.4294],
+                [1.2906, 1.1860, -0.9295],
+                [0.0721, -0.3817, -1.7799],
+            ],
+        ])
+        .into_shape([3, 3, 3, 1].as_slice());
+        expect_eq_1e4(&result, &expected)?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_rms_normalization() -> Result<(), Box<dyn Error>> {
         let mut rng = XorShiftRng::new(5678);
@@ -1083,6 +1717,46 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_skip_simplified_layer_normalization() -> Result<(), Box<dyn Error>> {
+        let mut rng = XorShiftRng::new(5678);
+        let input = NdTensor::<f32, 2>::rand([3, 10], &mut rng);
+        let skip = NdTensor::<f32, 2>::rand([3, 10], &mut rng);
+        let scale = NdTensor::<f32, 1>::rand([10], &mut rng);
+        let bias = NdTensor::<f32, 1>::rand([10], &mut rng);
+
+        let pool = BufferPool::new();
+        let inputs = InputList::from(&[
+            input.view().into(),
+            skip.view().into(),
+            scale.view().into(),
+            bias.view().into(),
+        ]);
+        let mut ctx = OpRunContext::new(&pool, &inputs);
+        ctx.set_num_outputs(4);
+        let op = SkipSimplifiedLayerNormalization { epsilon: None };
+        let outputs: Vec<Tensor> = op
+            .run(&ctx)?
+            .into_iter()
+            .map(|output| output.try_into().unwrap())
+            .collect();
+        assert_eq!(outputs.len(), 4);
+
+        let sum = NdTensor::from_fn([3, 10], |[i, j]| input[[i, j]] + skip[[i, j]] + bias[j]);
+        for (i, row) in sum.axis_iter(0).enumerate() {
+            let expected = reference_rms(row, scale.view()).into_dyn();
+            expect_eq_1e4(&outputs[0].slice(i).to_tensor(), &expected)?;
+
+            let mean = row.iter().sum::<f32>() / 10.;
+            let rms = (row.iter().map(|x| x * x).sum::<f32>() / 10.).sqrt();
+            assert!((outputs[1][[i, 0]] - mean).abs() < 1e-4);
+            assert!((outputs[2][[i, 0]] - 1. / rms).abs() < 1e-3);
+        }
+        expect_eq_1e4(&outputs[3], &sum.into_dyn())?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_log_softmax() -> Result<(), Box<dyn Error>> {
         let pool = BufferPool::new();
@@ -255,7 +255,7 @@ unsafe impl PadSource for WrapPad {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Pad {
     pub mode: PadMode,
 }
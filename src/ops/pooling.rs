@@ -422,7 +422,7 @@ pub fn average_pool(
     )
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct AveragePool {
     pub kernel_size: SmallVec<[usize; 2]>,
     pub padding: Padding,
@@ -582,6 +582,91 @@ impl Operator for GlobalMaxPool {
     }
 }
 
+/// Apply Lp pooling to `input`.
+///
+/// Each output value is the Lp norm of the values in the corresponding
+/// window, with padding values treated as zero.
+pub fn lp_pool(
+    pool: &BufferPool,
+    input: TensorView,
+    kernel_size: &[usize],
+    strides: &[usize],
+    padding: Padding,
+    p: i32,
+    round_mode: RoundMode,
+) -> Result<Tensor, OpError> {
+    check_value!(p > 0, InvalidValue, "p must be > 0");
+    let inv_p = 1. / p as f32;
+
+    pool_impl(
+        pool,
+        input,
+        kernel_size,
+        strides,
+        padding,
+        0.,
+        &|acc, x| acc + x.abs().powi(p),
+        &|acc, _non_pad_count| acc.powf(inv_p),
+        round_mode,
+    )
+}
+
+#[derive(Clone, Debug)]
+pub struct LpPool {
+    pub kernel_size: SmallVec<[usize; 2]>,
+    pub padding: Padding,
+    pub strides: SmallVec<[usize; 2]>,
+    pub ceil_mode: bool,
+    pub p: i32,
+}
+
+impl Operator for LpPool {
+    fn name(&self) -> &str {
+        "LpPool"
+    }
+
+    fn max_inputs(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
+        let input = ctx.inputs().require_as(0)?;
+        lp_pool(
+            ctx.pool(),
+            input,
+            &self.kernel_size,
+            &self.strides,
+            self.padding.clone(),
+            self.p,
+            if self.ceil_mode {
+                RoundMode::Ceil
+            } else {
+                RoundMode::Floor
+            },
+        )
+        .into_op_result()
+    }
+
+    fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
+        Some([OutputType::CopyFromInput(0)].into())
+    }
+
+    fn as_infer_shapes(&self) -> Option<&dyn InferShapes> {
+        Some(self)
+    }
+}
+
+impl_infer_shapes!(
+    LpPool,
+    op,
+    shape_ops::Pool {
+        strides: &op.strides,
+        dilations: &[1, 1],
+        kernel_size: &op.kernel_size,
+        padding: op.padding.as_shape_inference_padding(),
+    }
+);
+
 pub fn max_pool(
     pool: &BufferPool,
     input: TensorView,
@@ -603,7 +688,7 @@ pub fn max_pool(
     )
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct MaxPool {
     pub kernel_size: SmallVec<[usize; 2]>,
     pub padding: Padding,
@@ -657,6 +742,142 @@ impl_infer_shapes!(
     }
 );
 
+/// Compute a partial inverse of [`max_pool`].
+///
+/// Each value in `input` is written to the position in the output given by
+/// the corresponding entry in `indices`, which is an index into the flattened
+/// output. All other output values are zero. `indices` is typically the second
+/// output of an ONNX MaxPool operator.
+///
+/// If `output_shape` is not specified, the output shape is the smallest shape
+/// which would produce an input of the given size when max-pooled.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__MaxUnpool.html>.
+pub fn max_unpool(
+    pool: &BufferPool,
+    input: TensorView,
+    indices: TensorView<i32>,
+    kernel_size: &[usize],
+    strides: &[usize],
+    padding: Padding,
+    output_shape: Option<&[usize]>,
+) -> Result<Tensor, OpError> {
+    let spatial_dims = input.ndim().saturating_sub(2);
+    check_value!(
+        input.ndim() >= 2,
+        InvalidValue,
+        "Input must have at least 2 dims"
+    );
+    check_value!(
+        kernel_size.len() == spatial_dims,
+        InvalidValue,
+        "kernel_size len does not match spatial dims"
+    );
+    check_value!(
+        strides.len() == spatial_dims,
+        InvalidValue,
+        "strides len does not match spatial dims"
+    );
+    check_value!(
+        indices.shape() == input.shape(),
+        IncompatibleInputShapes,
+        "Indices shape must match input shape"
+    );
+
+    let out_shape: SmallVec<[usize; 4]> = if let Some(output_shape) = output_shape {
+        check_value!(
+            output_shape.len() == input.ndim() && output_shape[..2] == input.shape()[..2],
+            InvalidValue,
+            "Output shape must match batch and channel dims of input"
+        );
+        output_shape.into()
+    } else {
+        let pads = match &padding {
+            Padding::Fixed(pads) if pads.len() == spatial_dims * 2 => pads,
+            Padding::Fixed(_) => {
+                return Err(OpError::InvalidValue(
+                    "pads len does not match spatial dims",
+                ));
+            }
+            Padding::Same => {
+                return Err(OpError::UnsupportedValue(
+                    "\"SAME\" padding is not supported",
+                ));
+            }
+        };
+        let mut shape: SmallVec<[usize; 4]> = input.shape()[..2].into();
+        for i in 0..spatial_dims {
+            let size = (input.size(2 + i).saturating_sub(1) * strides[i] + kernel_size[i])
+                .checked_sub(pads[i] + pads[spatial_dims + i])
+                .ok_or(OpError::InvalidValue("Padding is too large"))?;
+            shape.push(size);
+        }
+        shape
+    };
+
+    let mut output = Tensor::zeros_in(pool, &out_shape);
+    let out_data = output.data_mut().unwrap();
+    for (&x, &index) in input.iter().zip(indices.iter()) {
+        let out_el = usize::try_from(index)
+            .ok()
+            .and_then(|index| out_data.get_mut(index))
+            .ok_or(OpError::InvalidValue("Index is out of bounds"))?;
+        *out_el = x;
+    }
+
+    Ok(output)
+}
+
+#[derive(Clone, Debug)]
+pub struct MaxUnpool {
+    pub kernel_size: SmallVec<[usize; 2]>,
+    pub padding: Padding,
+    pub strides: SmallVec<[usize; 2]>,
+}
+
+impl Operator for MaxUnpool {
+    fn name(&self) -> &str {
+        "MaxUnpool"
+    }
+
+    fn max_inputs(&self) -> Option<usize> {
+        Some(3)
+    }
+
+    fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
+        let inputs = ctx.inputs();
+        let input = inputs.require_as(0)?;
+        let indices = inputs.require_as(1)?;
+        let output_shape: Option<NdTensorView<i32, 1>> = inputs.get_as(2)?;
+        let output_shape = output_shape
+            .map(|shape| {
+                shape
+                    .iter()
+                    .map(|&size| {
+                        usize::try_from(size)
+                            .map_err(|_| OpError::InvalidValue("Output size must be >= 0"))
+                    })
+                    .collect::<Result<SmallVec<[usize; 4]>, _>>()
+            })
+            .transpose()?;
+
+        max_unpool(
+            ctx.pool(),
+            input,
+            indices,
+            &self.kernel_size,
+            &self.strides,
+            self.padding.clone(),
+            output_shape.as_deref(),
+        )
+        .into_op_result()
+    }
+
+    fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
+        Some([OutputType::CopyFromInput(0)].into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::error::Error;
@@ -668,7 +889,7 @@ mod tests {
 
     use super::{
         RoundMode, average_pool, calc_output_size_and_padding, global_average_pool,
-        global_max_pool, max_pool,
+        global_max_pool, lp_pool, max_pool, max_unpool,
     };
     use crate::buffer_pool::BufferPool;
     use crate::ops::tests::expect_eq_1e4;
@@ -869,6 +1090,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_lp_pool() -> Result<(), Box<dyn Error>> {
+        let pool = BufferPool::new();
+        let rm = RoundMode::default();
+        let input = Tensor::from_data(&[1, 1, 3, 3], vec![1., 2., 3., 4., -5., 6., 7., 8., 9.]);
+
+        // L2 pooling
+        let result = lp_pool(
+            &pool,
+            input.view(),
+            &[2, 2],
+            &[1, 1],
+            Padding::zero::<2>(),
+            2,
+            rm,
+        )?;
+        let expected = Tensor::from_data(
+            &[1, 1, 2, 2],
+            vec![46f32.sqrt(), 74f32.sqrt(), 154f32.sqrt(), 206f32.sqrt()],
+        );
+        expect_eq_1e4(&result, &expected)?;
+
+        // L1 pooling with padding
+        let result = lp_pool(
+            &pool,
+            input.view(),
+            &[2, 2],
+            &[2, 2],
+            [1, 1, 1, 1].into(),
+            1,
+            rm,
+        )?;
+        let expected = Tensor::from_data(&[1, 1, 2, 2], vec![1., 5., 11., 28.]);
+        expect_eq_1e4(&result, &expected)?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_max_pool() {
         let input = Tensor::from([
@@ -1016,6 +1275,71 @@ mod tests {
         assert_eq!(result.shape(), &[1, 1, 3, 3]);
     }
 
+    #[test]
+    fn test_max_unpool() {
+        let pool = BufferPool::new();
+
+        // Example from ONNX backend tests.
+        let input = Tensor::from_data(&[1, 1, 2, 2], vec![5., 6., 7., 8.]);
+        let indices = Tensor::from_data(&[1, 1, 2, 2], vec![5, 7, 13, 15]);
+
+        let result = max_unpool(
+            &pool,
+            input.view(),
+            indices.view(),
+            &[2, 2],
+            &[2, 2],
+            Padding::zero::<2>(),
+            None,
+        )
+        .unwrap();
+        let expected = Tensor::from([
+            [0., 0., 0., 0.],
+            [0., 5., 0., 6.],
+            [0., 0., 0., 0.],
+            [0., 7., 0., 8.],
+        ])
+        .into_shape([1, 1, 4, 4].as_slice());
+        assert_eq!(result, expected);
+
+        // Explicit output shape
+        let result = max_unpool(
+            &pool,
+            input.view(),
+            indices.view(),
+            &[2, 2],
+            &[2, 2],
+            Padding::zero::<2>(),
+            Some(&[1, 1, 5, 5]),
+        )
+        .unwrap();
+        let expected = Tensor::from([
+            [0., 0., 0., 0., 0.],
+            [5., 0., 6., 0., 0.],
+            [0., 0., 0., 7., 0.],
+            [8., 0., 0., 0., 0.],
+            [0., 0., 0., 0., 0.],
+        ])
+        .into_shape([1, 1, 5, 5].as_slice());
+        assert_eq!(result, expected);
+
+        // Out of bounds index
+        let indices = Tensor::from_data(&[1, 1, 2, 2], vec![5, 7, 13, 16]);
+        let result = max_unpool(
+            &pool,
+            input.view(),
+            indices.view(),
+            &[2, 2],
+            &[2, 2],
+            Padding::zero::<2>(),
+            None,
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("Index is out of bounds"))
+        );
+    }
+
     #[test]
     fn test_calc_output_size_and_padding() {
         #[derive(Debug)]
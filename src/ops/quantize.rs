@@ -92,7 +92,7 @@ pub fn dequantize_linear<T: Copy + Default + Dequantize<f32> + Scalar>(
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct DequantizeLinear {
     pub axis: isize,
 }
@@ -265,7 +265,7 @@ where
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct QuantizeLinear {
     pub axis: isize,
     pub output_dtype: Option<DataType>,
@@ -9,7 +9,7 @@ use crate::operator::{
 };
 use crate::value::{DataType, Value, ValueType};
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct RandomUniform {
     pub low: f32,
     pub high: f32,
@@ -52,7 +52,7 @@ impl Operator for RandomUniform {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct RandomUniformLike {
     pub low: f32,
     pub high: f32,
@@ -90,7 +90,7 @@ impl Operator for RandomUniformLike {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct RandomNormal {
     pub mean: f32,
     pub scale: f32,
@@ -143,7 +143,7 @@ impl Operator for RandomNormal {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct RandomNormalLike {
     pub mean: f32,
     pub scale: f32,
@@ -181,7 +181,7 @@ impl Operator for RandomNormalLike {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Dropout {
     pub seed: Option<i32>,
 }
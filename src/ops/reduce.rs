@@ -9,7 +9,9 @@ use rten_tensor::{NdTensor, NdTensorView, Tensor, TensorView};
 use rten_vecmath as vecmath;
 
 use crate::buffer_pool::BufferPool;
-use crate::infer_shapes::{InferShapes, InferShapesError, ReductionOp, SymTensor, SymbolGen};
+use crate::infer_shapes::{
+    InferShapes, InferShapesError, ReductionOp, SymTensor, SymbolGen, UnaryOp,
+};
 use crate::operator::{
     InputList, IntoOpResult, OpError, OpRunContext, Operator, OutputList, OutputType,
     OutputTypeList, OutputTypesContext,
@@ -122,7 +124,7 @@ pub fn arg_max<T: Copy + PartialOrd + IsNaN>(
     select_max_index(pool, input, axis, keep_dims, |a, b| cmp_nan_greater(*a, *b))
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ArgMax {
     pub axis: isize,
     pub keep_dims: bool,
@@ -172,7 +174,7 @@ pub fn arg_min<T: Copy + PartialOrd + IsNaN>(
     })
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ArgMin {
     pub axis: isize,
     pub keep_dims: bool,
@@ -205,6 +207,134 @@ impl Operator for ArgMin {
 
 impl_infer_shapes_for_arg_op!(ArgMin);
 
+/// Compute the determinant of each square matrix in the last two dimensions
+/// of `input`.
+///
+/// The output has the shape of `input` without the last two dimensions.
+pub fn det(pool: &BufferPool, input: TensorView) -> Result<Tensor, OpError> {
+    if input.ndim() < 2 {
+        return Err(OpError::InvalidValue("Input must have at least 2 dims"));
+    }
+    let [rows, cols] = [input.size(input.ndim() - 2), input.size(input.ndim() - 1)];
+    if rows != cols {
+        return Err(OpError::InvalidValue("Input matrices must be square"));
+    }
+    let batch_shape = &input.shape()[..input.ndim() - 2];
+
+    /// Compute the determinant of an `n x n` row-major matrix using LU
+    /// decomposition with partial pivoting. `mat` is overwritten.
+    fn lu_det(mat: &mut [f64], n: usize) -> f64 {
+        let mut det = 1.0;
+        for col in 0..n {
+            let pivot_row = (col..n)
+                .max_by(|&a, &b| mat[a * n + col].abs().total_cmp(&mat[b * n + col].abs()))
+                .unwrap();
+            let pivot = mat[pivot_row * n + col];
+            if pivot == 0. {
+                return 0.;
+            }
+            if pivot_row != col {
+                for k in 0..n {
+                    mat.swap(col * n + k, pivot_row * n + k);
+                }
+                det = -det;
+            }
+            det *= pivot;
+
+            for row in col + 1..n {
+                let factor = mat[row * n + col] / pivot;
+                for k in col + 1..n {
+                    mat[row * n + k] -= factor * mat[col * n + k];
+                }
+            }
+        }
+        det
+    }
+
+    let mut tmp = Vec::with_capacity(rows * cols);
+    let mut output = pool.alloc(batch_shape.iter().product());
+    output.extend(input.inner_iter::<2>().map(|mat| {
+        tmp.clear();
+        tmp.extend(mat.iter().map(|&x| x as f64));
+        lu_det(&mut tmp, rows) as f32
+    }));
+
+    Ok(Tensor::from_data(batch_shape, output))
+}
+
+#[derive(Debug)]
+pub struct Det {}
+
+impl Operator for Det {
+    fn name(&self) -> &str {
+        "Det"
+    }
+
+    fn max_inputs(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
+        let input = ctx.inputs().require_as(0)?;
+        det(ctx.pool(), input).into_op_result()
+    }
+
+    fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
+        Some([OutputType::CopyFromInput(0)].into())
+    }
+}
+
+/// Set the first maximum value along `axis` to 1 and all other values to 0.
+pub fn hardmax(pool: &BufferPool, input: TensorView, axis: isize) -> Result<Tensor, OpError> {
+    let axis = resolve_axis(input.ndim(), axis)?;
+    let mut output = Tensor::zeros_in(pool, input.shape());
+    if output.is_empty() {
+        return Ok(output);
+    }
+
+    for (in_lane, mut out_lane) in input.lanes(axis).zip(output.lanes_mut(axis)) {
+        let mut max_index = 0;
+        let mut max_val = f32::NEG_INFINITY;
+        for (i, &x) in in_lane.enumerate() {
+            if i == 0 || x > max_val {
+                max_index = i;
+                max_val = x;
+            }
+        }
+        *out_lane.nth(max_index).unwrap() = 1.;
+    }
+
+    Ok(output)
+}
+
+#[derive(Clone, Debug)]
+pub struct Hardmax {
+    pub axis: isize,
+}
+
+impl Operator for Hardmax {
+    fn name(&self) -> &str {
+        "Hardmax"
+    }
+
+    fn max_inputs(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
+        let input = ctx.inputs().require_as(0)?;
+        hardmax(ctx.pool(), input, self.axis).into_op_result()
+    }
+
+    fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
+        Some([OutputType::CopyFromInput(0)].into())
+    }
+
+    fn as_infer_shapes(&self) -> Option<&dyn InferShapes> {
+        Some(&UnaryOp)
+    }
+}
+
 pub fn cum_sum<T: Copy + Default + Identities + std::ops::AddAssign>(
     pool: &BufferPool,
     input: TensorView<T>,
@@ -300,11 +430,18 @@ impl Operator for NonZero {
         Some(1)
     }
 
+    fn supports_int64_bool_input(&self, index: usize) -> bool {
+        index == 0
+    }
+
     fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
         let input = ctx.inputs().require(0)?;
-        map_value_view!(input, input, [FloatTensor, Int32Tensor], {
-            nonzero(ctx.pool(), input).into_op_result()
-        })
+        map_value_view!(
+            input,
+            input,
+            [FloatTensor, Int32Tensor, Int64Tensor, BoolTensor],
+            { nonzero(ctx.pool(), input).into_op_result() }
+        )
     }
 
     fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
@@ -498,7 +635,7 @@ pub fn reduce_mean(
     reduce(pool, input, axes, keep_dims, &MeanKernel {})
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ReduceMean {
     pub axes: Option<Vec<i32>>,
     pub keep_dims: bool,
@@ -561,7 +698,7 @@ pub fn reduce_l2(
     reduce(pool, input, axes, keep_dims, &L2ReduceKernel {})
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ReduceL2 {
     pub axes: Option<Vec<i32>>,
     pub keep_dims: bool,
@@ -608,6 +745,70 @@ impl Operator for ReduceL2 {
 
 impl_infer_shapes_for_reduce_op!(ReduceL2);
 
+pub fn reduce_log_sum_exp(
+    pool: &BufferPool,
+    input: TensorView,
+    axes: Option<&[i32]>,
+    keep_dims: bool,
+) -> Result<Tensor, OpError> {
+    struct LogSumExpKernel {}
+    impl ReduceKernel<f32> for LogSumExpKernel {
+        fn reduce_slice(&self, slice: &[f32]) -> f32 {
+            // Subtract the maximum value before exponentiating to avoid
+            // overflow.
+            let max = vecmath::MaxNum::new(slice).dispatch();
+            if max.is_infinite() {
+                return max;
+            }
+            let sum: f32 = slice.iter().map(|x| (x - max).exp()).sum();
+            max + sum.ln()
+        }
+    }
+
+    reduce(pool, input, axes, keep_dims, &LogSumExpKernel {})
+}
+
+#[derive(Clone, Debug)]
+pub struct ReduceLogSumExp {
+    pub axes: Option<Vec<i32>>,
+    pub keep_dims: bool,
+    pub noop_with_empty_axes: bool,
+}
+
+impl Operator for ReduceLogSumExp {
+    fn name(&self) -> &str {
+        "ReduceLogSumExp"
+    }
+
+    fn max_inputs(&self) -> Option<usize> {
+        Some(2)
+    }
+
+    fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
+        let inputs = ctx.inputs();
+        let input = inputs.require(0)?;
+        let axes = get_axes(inputs, &self.axes)?;
+
+        if is_none_or_empty(axes.as_deref()) && self.noop_with_empty_axes {
+            return input.to_owned_in(ctx.pool()).into_op_result();
+        }
+
+        map_value_view!(input, input, [FloatTensor], {
+            reduce_log_sum_exp(ctx.pool(), input, axes.as_deref(), self.keep_dims).into_op_result()
+        })
+    }
+
+    fn as_infer_shapes(&self) -> Option<&dyn InferShapes> {
+        Some(self)
+    }
+
+    fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
+        Some([OutputType::CopyFromInput(0)].into())
+    }
+}
+
+impl_infer_shapes_for_reduce_op!(ReduceLogSumExp);
+
 /// Compare `a` and `b`, treating all NaN values as greater than non-NaN values.
 pub fn cmp_nan_greater<T: PartialOrd + IsNaN>(a: T, b: T) -> std::cmp::Ordering {
     match a.partial_cmp(&b) {
@@ -701,7 +902,7 @@ fn is_none_or_empty<T>(x: Option<&[T]>) -> bool {
     x.map(|x| x.is_empty()).unwrap_or(true)
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ReduceMin {
     pub axes: Option<Vec<i32>>,
     pub keep_dims: bool,
@@ -766,7 +967,7 @@ pub fn reduce_max<T: Copy + IsNaN + MinMax>(
     reduce(pool, input, axes, keep_dims, kernel)
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ReduceMax {
     pub axes: Option<Vec<i32>>,
     pub keep_dims: bool,
@@ -822,7 +1023,7 @@ pub fn reduce_prod<T: Copy + std::iter::Product>(
     reduce(pool, input, axes, keep_dims, &ProdKernel {})
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ReduceProd {
     pub axes: Option<Vec<i32>>,
     pub keep_dims: bool,
@@ -887,7 +1088,7 @@ pub fn reduce_sum<T: Copy + Default + std::ops::Add<T, Output = T>>(
     reduce(pool, input, axes, keep_dims, kernel)
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ReduceSum {
     pub axes: Option<Vec<i32>>,
     pub keep_dims: bool,
@@ -955,7 +1156,7 @@ pub fn reduce_sum_square<T: Copy + std::ops::Mul<T, Output = T> + std::iter::Sum
     reduce(pool, input, axes, keep_dims, kernel)
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ReduceSumSquare {
     pub axes: Option<Vec<i32>>,
     pub keep_dims: bool,
@@ -1069,7 +1270,7 @@ pub fn topk<T: Copy + Default + PartialOrd + IsNaN>(
     Ok((out_values, indices))
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct TopK {
     pub axis: Option<isize>,
     pub largest: bool,
@@ -1125,8 +1326,9 @@ mod tests {
     use crate::operator::{Operator, OperatorExt};
     use crate::ops::{
         OpError, ReduceL2, ReduceMax, ReduceMean, ReduceMin, ReduceProd, ReduceSum,
-        ReduceSumSquare, arg_max, arg_min, cum_sum, nonzero, reduce_l2, reduce_max, reduce_mean,
-        reduce_min, reduce_prod, reduce_sum, reduce_sum_square, topk,
+        ReduceSumSquare, arg_max, arg_min, cum_sum, det, hardmax, nonzero, reduce_l2,
+        reduce_log_sum_exp, reduce_max, reduce_mean, reduce_min, reduce_prod, reduce_sum,
+        reduce_sum_square, topk,
     };
 
     #[test]
@@ -1302,6 +1504,80 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_det() -> Result<(), Box<dyn Error>> {
+        let pool = BufferPool::new();
+
+        // Examples from ONNX backend tests.
+        let input = Tensor::from([[1., 2.], [3., 4.]]);
+        let result = det(&pool, input.view()).unwrap();
+        expect_equal(&result, &Tensor::from(-2.))?;
+
+        let input = Tensor::from([
+            [[1., 2.], [3., 4.]],
+            [[1., 2.], [2., 1.]],
+            [[1., 3.], [3., 1.]],
+        ]);
+        let result = det(&pool, input.view()).unwrap();
+        expect_equal(&result, &Tensor::from([-2., -3., -8.]))?;
+
+        // Matrix which requires pivoting.
+        let input = Tensor::from([[0., -1., 4.], [2., 1., 3.], [1., 2., 0.5]]);
+        let result = det(&pool, input.view()).unwrap();
+        expect_equal(&result, &Tensor::from(10.))?;
+
+        // Singular matrix.
+        let input = Tensor::from([[1., 2.], [2., 4.]]);
+        let result = det(&pool, input.view()).unwrap();
+        expect_equal(&result, &Tensor::from(0.))?;
+
+        // Invalid inputs.
+        let result = det(&pool, Tensor::from([1., 2.]).view());
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("Input must have at least 2 dims"))
+        );
+        let result = det(&pool, Tensor::<f32>::zeros(&[2, 3]).view());
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("Input matrices must be square"))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hardmax() {
+        let pool = BufferPool::new();
+
+        // Examples from ONNX backend tests.
+        let input = Tensor::from([
+            [3., 0., 1., 2.],
+            [2., 5., 1., 0.],
+            [0., 1., 3., 2.],
+            [0., 1., 2., 3.],
+        ]);
+        let result = hardmax(&pool, input.view(), -1).unwrap();
+        let expected = Tensor::from([
+            [1., 0., 0., 0.],
+            [0., 1., 0., 0.],
+            [0., 0., 1., 0.],
+            [0., 0., 0., 1.],
+        ]);
+        assert_eq!(result, expected);
+
+        // The first of several equal maximum values is selected.
+        let input = Tensor::from([[2., 2., 2., 2.], [3., 3., 3., 3.]]);
+        let result = hardmax(&pool, input.view(), -1).unwrap();
+        let expected = Tensor::from([[1., 0., 0., 0.], [1., 0., 0., 0.]]);
+        assert_eq!(result, expected);
+
+        // Non-last axis.
+        let input = Tensor::from([[1., 4.], [3., 2.]]);
+        let result = hardmax(&pool, input.view(), 0).unwrap();
+        assert_eq!(result, Tensor::from([[0., 1.], [1., 0.]]));
+    }
+
     #[test]
     fn test_nonzero() {
         let pool = BufferPool::new();
@@ -1406,6 +1682,37 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_reduce_log_sum_exp() -> Result<(), Box<dyn Error>> {
+        let pool = BufferPool::new();
+
+        // Example from ONNX backend tests.
+        let input = Tensor::from([
+            [[5., 1.], [20., 2.]],
+            [[30., 1.], [40., 2.]],
+            [[55., 1.], [60., 2.]],
+        ]);
+        let expected = Tensor::from([
+            [[20.0, 2.3132617]],
+            [[40.000046, 2.3132617]],
+            [[60.006714, 2.3132617]],
+        ]);
+        let result = reduce_log_sum_exp(&pool, input.view(), Some(&[1]), true).unwrap();
+        expect_equal(&result, &expected)?;
+
+        // Large values which would overflow if exponentiated directly.
+        let input = Tensor::from([1000., 1000.]);
+        let result = reduce_log_sum_exp(&pool, input.view(), None, false).unwrap();
+        expect_equal(&result, &Tensor::from(1000. + 2f32.ln()))?;
+
+        // All values are -inf.
+        let input = Tensor::from([f32::NEG_INFINITY, f32::NEG_INFINITY]);
+        let result = reduce_log_sum_exp(&pool, input.view(), None, false).unwrap();
+        assert_eq!(result, Tensor::from(f32::NEG_INFINITY));
+
+        Ok(())
+    }
+
     // Tests for ReduceMean specifically that also cover common functionality
     // across the different reductions.
     #[test]
@@ -94,7 +94,7 @@ fn zip4<T1, T2, T3, T4>(
 const PREPACK_MIN_SEQ_LEN: usize = 5;
 
 /// Gated Recurrent Unit operator.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 #[allow(clippy::upper_case_acronyms)]
 pub struct GRU {
     pub direction: Direction,
@@ -356,7 +356,7 @@ impl Operator for GRU {
 }
 
 /// Long Short-Term Memory operator.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 #[allow(clippy::upper_case_acronyms)]
 pub struct LSTM {
     pub direction: Direction,
@@ -0,0 +1,529 @@
+use rayon::prelude::*;
+use rten_tensor::prelude::*;
+use rten_tensor::{NdTensor, NdTensorView, TensorView};
+
+use crate::buffer_pool::BufferPool;
+use crate::operator::{
+    IntoOpResult, OpError, OpRunContext, Operator, OutputList, OutputType, OutputTypeList,
+    OutputTypesContext, static_dims,
+};
+
+/// Method used to pool the sampled values in each output bin.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum RoiAlignMode {
+    #[default]
+    Avg,
+    Max,
+}
+
+/// Bilinear interpolation weights and offsets for a sample point in a feature
+/// map.
+#[derive(Clone, Copy, Default)]
+struct Sample {
+    /// Offsets of the top-left, top-right, bottom-left and bottom-right
+    /// neighbors of the sample point, in a single channel of the feature map.
+    offsets: [usize; 4],
+
+    /// Weights of each neighbor.
+    weights: [f32; 4],
+}
+
+impl Sample {
+    /// Compute the sample for point `(y, x)` in a feature map of size
+    /// `(height, width)`.
+    ///
+    /// This follows the boundary handling of ONNX Runtime's implementation.
+    /// Points which are more than one pixel outside the feature map have zero
+    /// weight.
+    fn new(y: f32, x: f32, height: usize, width: usize) -> Sample {
+        if y < -1.0 || y > height as f32 || x < -1.0 || x > width as f32 {
+            return Sample::default();
+        }
+
+        let (y_low, y_high, ly) = Self::neighbors(y.max(0.), height);
+        let (x_low, x_high, lx) = Self::neighbors(x.max(0.), width);
+        let hy = 1. - ly;
+        let hx = 1. - lx;
+
+        Sample {
+            offsets: [
+                y_low * width + x_low,
+                y_low * width + x_high,
+                y_high * width + x_low,
+                y_high * width + x_high,
+            ],
+            weights: [hy * hx, hy * lx, ly * hx, ly * lx],
+        }
+    }
+
+    /// Return the low and high neighbor coordinates of `coord` and the
+    /// distance from `coord` to the low neighbor.
+    fn neighbors(coord: f32, size: usize) -> (usize, usize, f32) {
+        let low = coord as usize;
+        if low >= size - 1 {
+            (size - 1, size - 1, 0.)
+        } else {
+            (low, low + 1, coord - low as f32)
+        }
+    }
+}
+
+/// Options for [`roi_align`].
+#[derive(Clone, Debug)]
+pub struct RoiAlignOptions {
+    pub mode: RoiAlignMode,
+    pub output_height: usize,
+    pub output_width: usize,
+
+    /// Number of sampling points along each axis of each output bin. If zero,
+    /// this is computed adaptively as `ceil(roi_size / output_size)`.
+    pub sampling_ratio: usize,
+
+    /// Scale factor which maps ROI coordinates to input coordinates.
+    pub spatial_scale: f32,
+
+    /// If true, ROI coordinates are shifted by -0.5 pixels after scaling,
+    /// which aligns them with pixel centers. This corresponds to the
+    /// `coordinate_transformation_mode="half_pixel"` ONNX attribute value.
+    pub half_pixel: bool,
+}
+
+/// Extract features for regions of interest in a feature map.
+///
+/// `input` is an `(N, C, H, W)` feature map. `rois` is a `(num_rois, 4)`
+/// tensor of `[x1, y1, x2, y2]` boxes and `batch_indices` specifies the image
+/// in the batch that each ROI refers to. Returns a tensor of shape
+/// `(num_rois, C, output_height, output_width)`.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__RoiAlign.html>.
+pub fn roi_align(
+    pool: &BufferPool,
+    input: NdTensorView<f32, 4>,
+    rois: NdTensorView<f32, 2>,
+    batch_indices: NdTensorView<i32, 1>,
+    opts: &RoiAlignOptions,
+) -> Result<NdTensor<f32, 4>, OpError> {
+    let [batch, chans, height, width] = input.shape();
+    let [num_rois, roi_len] = rois.shape();
+    let RoiAlignOptions {
+        mode,
+        output_height,
+        output_width,
+        sampling_ratio,
+        spatial_scale,
+        half_pixel,
+    } = *opts;
+
+    if roi_len != 4 {
+        return Err(OpError::InvalidValue("rois must have shape (num_rois, 4)"));
+    }
+    if batch_indices.size(0) != num_rois {
+        return Err(OpError::IncompatibleInputShapes(
+            "batch_indices length must match number of rois",
+        ));
+    }
+    if output_height == 0 || output_width == 0 {
+        return Err(OpError::InvalidValue(
+            "output_height and output_width must be > 0",
+        ));
+    }
+
+    let batch_indices = batch_indices
+        .iter()
+        .map(|&idx| {
+            usize::try_from(idx)
+                .ok()
+                .filter(|&idx| idx < batch)
+                .ok_or(OpError::InvalidValue("batch index is out of range"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut output = NdTensor::zeros_in(pool, [num_rois, chans, output_height, output_width]);
+    if output.is_empty() || height == 0 || width == 0 {
+        return Ok(output);
+    }
+
+    let input = input.to_contiguous_in(pool);
+    let offset = if half_pixel { 0.5 } else { 0. };
+
+    output
+        .axis_iter_mut(0)
+        .into_par_iter()
+        .zip(batch_indices.into_par_iter())
+        .enumerate()
+        .for_each(|(roi_idx, (mut roi_output, batch_idx))| {
+            let [x1, y1, x2, y2] =
+                std::array::from_fn(|i| rois[[roi_idx, i]] * spatial_scale - offset);
+
+            let mut roi_width = x2 - x1;
+            let mut roi_height = y2 - y1;
+            if !half_pixel {
+                // Force malformed ROIs to be 1x1.
+                roi_width = roi_width.max(1.);
+                roi_height = roi_height.max(1.);
+            }
+
+            let bin_height = roi_height / output_height as f32;
+            let bin_width = roi_width / output_width as f32;
+            let grid_height = if sampling_ratio > 0 {
+                sampling_ratio
+            } else {
+                bin_height.ceil() as usize
+            };
+            let grid_width = if sampling_ratio > 0 {
+                sampling_ratio
+            } else {
+                bin_width.ceil() as usize
+            };
+            let count = (grid_height * grid_width).max(1) as f32;
+
+            // Sample positions and weights are the same for each channel, so
+            // compute them once per ROI.
+            let samples: Vec<Sample> = (0..output_height)
+                .flat_map(|out_y| (0..output_width).map(move |out_x| (out_y, out_x)))
+                .flat_map(|(out_y, out_x)| {
+                    (0..grid_height).flat_map(move |grid_y| {
+                        (0..grid_width).map(move |grid_x| {
+                            let y = y1
+                                + out_y as f32 * bin_height
+                                + (grid_y as f32 + 0.5) * bin_height / grid_height as f32;
+                            let x = x1
+                                + out_x as f32 * bin_width
+                                + (grid_x as f32 + 0.5) * bin_width / grid_width as f32;
+                            Sample::new(y, x, height, width)
+                        })
+                    })
+                })
+                .collect();
+            let samples_per_bin = grid_height * grid_width;
+
+            for (chan, mut chan_output) in roi_output.axis_iter_mut(0).enumerate() {
+                let chan_data = input.slice([batch_idx, chan]);
+                let chan_data = chan_data.data().unwrap();
+
+                for (out_el, bin_samples) in chan_output
+                    .iter_mut()
+                    .zip(samples.chunks(samples_per_bin.max(1)))
+                {
+                    let weighted_values = bin_samples.iter().map(|sample| {
+                        let Sample { offsets, weights } = sample;
+                        std::array::from_fn::<f32, 4, _>(|i| weights[i] * chan_data[offsets[i]])
+                    });
+                    *out_el = match mode {
+                        RoiAlignMode::Avg => {
+                            weighted_values
+                                .map(|vals| vals.iter().sum::<f32>())
+                                .sum::<f32>()
+                                / count
+                        }
+                        RoiAlignMode::Max => weighted_values
+                            .map(|vals| vals.into_iter().fold(f32::NEG_INFINITY, f32::max))
+                            .reduce(f32::max)
+                            .unwrap_or(0.),
+                    };
+                }
+            }
+        });
+
+    Ok(output)
+}
+
+#[derive(Clone, Debug)]
+pub struct RoiAlign {
+    pub opts: RoiAlignOptions,
+}
+
+impl Operator for RoiAlign {
+    fn name(&self) -> &str {
+        "RoiAlign"
+    }
+
+    fn max_inputs(&self) -> Option<usize> {
+        Some(3)
+    }
+
+    fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
+        let inputs = ctx.inputs();
+        let input: TensorView = inputs.require_as(0)?;
+        let input = static_dims!(input, 4, "NCHW")?;
+        let rois: TensorView = inputs.require_as(1)?;
+        let rois = static_dims!(rois, 2)?;
+        let batch_indices: TensorView<i32> = inputs.require_as(2)?;
+        let batch_indices = static_dims!(batch_indices, 1)?;
+
+        roi_align(ctx.pool(), input, rois, batch_indices, &self.opts).into_op_result()
+    }
+
+    fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
+        Some([OutputType::CopyFromInput(0)].into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rten_tensor::NdTensor;
+    use rten_tensor::prelude::*;
+    use rten_testing::TestCases;
+
+    use super::{RoiAlignMode, RoiAlignOptions, roi_align};
+    use crate::buffer_pool::BufferPool;
+    use crate::operator::OpError;
+    use crate::ops::tests::expect_eq_1e4;
+
+    #[test]
+    fn test_roi_align() {
+        #[derive(Debug)]
+        struct Case {
+            roi: [f32; 4],
+            opts: RoiAlignOptions,
+            expected: NdTensor<f32, 2>,
+        }
+
+        let default_opts = RoiAlignOptions {
+            mode: RoiAlignMode::Avg,
+            output_height: 2,
+            output_width: 2,
+            sampling_ratio: 0,
+            spatial_scale: 1.0,
+            half_pixel: true,
+        };
+
+        // Expected values were computed using a Python port of ONNX Runtime's
+        // implementation.
+        let cases = [
+            Case {
+                roi: [0., 0., 3., 3.],
+                opts: RoiAlignOptions {
+                    output_height: 1,
+                    output_width: 1,
+                    sampling_ratio: 2,
+                    half_pixel: false,
+                    ..default_opts.clone()
+                },
+                expected: NdTensor::from([[7.5]]),
+            },
+            Case {
+                roi: [0., 0., 3., 3.],
+                opts: default_opts.clone(),
+                expected: NdTensor::from([[1.5625, 3.0], [7.3125, 8.75]]),
+            },
+            // ROI which extends outside the feature map.
+            Case {
+                roi: [1., 0.5, 5., 4.],
+                opts: RoiAlignOptions {
+                    sampling_ratio: 2,
+                    half_pixel: false,
+                    ..default_opts.clone()
+                },
+                expected: NdTensor::from([[7.5, 4.25], [13.375, 7.1875]]),
+            },
+            Case {
+                roi: [0., 0., 3., 3.],
+                opts: RoiAlignOptions {
+                    mode: RoiAlignMode::Max,
+                    sampling_ratio: 2,
+                    ..default_opts.clone()
+                },
+                expected: NdTensor::from([[2.5, 3.28125], [7.0, 7.65625]]),
+            },
+            Case {
+                roi: [0., 0., 6., 6.],
+                opts: RoiAlignOptions {
+                    spatial_scale: 0.5,
+                    ..default_opts.clone()
+                },
+                expected: NdTensor::from([[1.5625, 3.0], [7.3125, 8.75]]),
+            },
+        ];
+
+        cases.test_each(|case| {
+            let pool = BufferPool::new();
+
+            // Use an input where each channel is `4y + x`, multiplied by a
+            // different scale for each channel.
+            let chan_scale = |n: usize, c: usize| (1 + n * 2 + c) as f32;
+            let input = NdTensor::from_fn([2, 2, 4, 4], |[n, c, y, x]| {
+                (4 * y + x) as f32 * chan_scale(n, c)
+            });
+            let rois = NdTensor::from([case.roi, case.roi]);
+            let batch_indices = NdTensor::from([0, 1]);
+
+            let result = roi_align(
+                &pool,
+                input.view(),
+                rois.view(),
+                batch_indices.view(),
+                &case.opts,
+            )
+            .unwrap();
+            let [out_h, out_w] = case.expected.shape();
+            assert_eq!(result.shape(), [2, 2, out_h, out_w]);
+
+            for n in 0..2 {
+                for c in 0..2 {
+                    let expected = case.expected.map(|x| x * chan_scale(n, c));
+                    expect_eq_1e4(&result.slice([n, c]), &expected.view()).unwrap();
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_roi_align_onnx_backend_cases() {
+        // The ROIs and attributes are from the ONNX backend tests
+        // (`test_roialign_aligned_false`, `test_roialign_aligned_true` and
+        // `test_roialign_mode_max`). Expected values were computed using a
+        // Python port of ONNX Runtime's implementation.
+        #[derive(Debug)]
+        struct Case {
+            mode: RoiAlignMode,
+            half_pixel: bool,
+            expected: NdTensor<f32, 3>,
+        }
+
+        let cases = [
+            Case {
+                mode: RoiAlignMode::Avg,
+                half_pixel: false,
+                expected: NdTensor::from([
+                    [
+                        [0.3586, 0.5159, 0.4825, 0.6004, 0.5307],
+                        [0.4780, 0.4646, 0.6675, 0.5679, 0.4033],
+                        [0.5330, 0.6115, 0.5650, 0.3310, 0.5095],
+                        [0.5330, 0.4559, 0.4625, 0.4410, 0.4751],
+                        [0.6317, 0.4184, 0.3725, 0.5329, 0.5670],
+                    ],
+                    [
+                        [0.5280, 0.7040, 0.5400, 0.4960, 0.7120],
+                        [0.4080, 0.5840, 0.7800, 0.3760, 0.4720],
+                        [0.6280, 0.4240, 0.5600, 0.2160, 0.2720],
+                        [0.7280, 0.7440, 0.4600, 0.5360, 0.3120],
+                        [0.5680, 0.6640, 0.2600, 0.4560, 0.5120],
+                    ],
+                    [
+                        [0.3780, 0.5540, 0.3900, 0.3460, 0.5620],
+                        [0.2580, 0.4340, 0.6500, 0.3860, 0.4020],
+                        [0.4780, 0.2740, 0.4900, 0.7060, 0.5020],
+                        [0.5780, 0.5940, 0.3300, 0.5460, 0.7220],
+                        [0.4180, 0.6340, 0.5900, 0.4260, 0.6020],
+                    ],
+                ]),
+            },
+            Case {
+                mode: RoiAlignMode::Avg,
+                half_pixel: true,
+                expected: NdTensor::from([
+                    [
+                        [0.2741, 0.4996, 0.4481, 0.5773, 0.6595],
+                        [0.4441, 0.4421, 0.5994, 0.5760, 0.5207],
+                        [0.4985, 0.5690, 0.5781, 0.3947, 0.4689],
+                        [0.4891, 0.5703, 0.5431, 0.4060, 0.4276],
+                        [0.6966, 0.4677, 0.3431, 0.5104, 0.5320],
+                    ],
+                    [
+                        [0.5410, 0.6840, 0.5350, 0.4160, 0.6570],
+                        [0.3085, 0.5490, 0.6950, 0.6310, 0.4720],
+                        [0.5435, 0.4590, 0.6050, 0.6010, 0.2470],
+                        [0.7935, 0.5790, 0.5150, 0.4510, 0.2470],
+                        [0.5860, 0.7940, 0.6000, 0.4060, 0.5495],
+                    ],
+                    [
+                        [0.4630, 0.5340, 0.3850, 0.2660, 0.5070],
+                        [0.2530, 0.3990, 0.5450, 0.4810, 0.3220],
+                        [0.4130, 0.3090, 0.4550, 0.6010, 0.4970],
+                        [0.5880, 0.4290, 0.3650, 0.5110, 0.7020],
+                        [0.4030, 0.6440, 0.5250, 0.3760, 0.5670],
+                    ],
+                ]),
+            },
+            Case {
+                mode: RoiAlignMode::Max,
+                half_pixel: true,
+                expected: NdTensor::from([
+                    [
+                        [0.5950, 0.5550, 0.3825, 0.5865, 0.9120],
+                        [0.4909, 0.4095, 0.3630, 0.4014, 0.6311],
+                        [0.4400, 0.3253, 0.2426, 0.2779, 0.3971],
+                        [0.4807, 0.5287, 0.3503, 0.3510, 0.5036],
+                        [0.7833, 0.4384, 0.3035, 0.5842, 0.6944],
+                    ],
+                    [
+                        [0.4500, 0.7047, 0.5481, 0.2745, 0.4941],
+                        [0.2500, 0.4617, 0.5922, 0.7614, 0.2745],
+                        [0.6300, 0.3591, 0.4606, 0.5922, 0.1953],
+                        [0.8100, 0.4365, 0.4032, 0.5184, 0.3195],
+                        [0.5400, 0.7857, 0.6111, 0.3195, 0.5751],
+                    ],
+                    [
+                        [0.2835, 0.5832, 0.4536, 0.2070, 0.3726],
+                        [0.1836, 0.3402, 0.4977, 0.6399, 0.2070],
+                        [0.4725, 0.2646, 0.3871, 0.4977, 0.5418],
+                        [0.6075, 0.3690, 0.3087, 0.3969, 0.6966],
+                        [0.3645, 0.6642, 0.5166, 0.2520, 0.4536],
+                    ],
+                ]),
+            },
+        ];
+
+        cases.test_each(|case| {
+            let pool = BufferPool::new();
+
+            // The backend tests use a random 10x10 input. Use a deterministic
+            // pattern with a similar range instead.
+            let input = NdTensor::from_fn([1, 1, 10, 10], |[_, _, y, x]| {
+                ((y * 10 + x) * 37 % 100) as f32 / 100.
+            });
+            let rois = NdTensor::from([[0., 0., 9., 9.], [0., 5., 4., 9.], [5., 5., 9., 9.]]);
+            let batch_indices = NdTensor::from([0, 0, 0]);
+            let opts = RoiAlignOptions {
+                mode: case.mode,
+                output_height: 5,
+                output_width: 5,
+                sampling_ratio: 2,
+                spatial_scale: 1.0,
+                half_pixel: case.half_pixel,
+            };
+
+            let result = roi_align(
+                &pool,
+                input.view(),
+                rois.view(),
+                batch_indices.view(),
+                &opts,
+            )
+            .unwrap();
+            assert_eq!(result.shape(), [3, 1, 5, 5]);
+            for (i, expected) in case.expected.axis_iter(0).enumerate() {
+                expect_eq_1e4(&result.slice([i, 0]), &expected).unwrap();
+            }
+        })
+    }
+
+    #[test]
+    fn test_roi_align_invalid() {
+        let pool = BufferPool::new();
+        let input = NdTensor::zeros([1, 1, 4, 4]);
+        let rois = NdTensor::from([[0., 0., 2., 2.]]);
+        let batch_indices = NdTensor::from([1]);
+        let opts = RoiAlignOptions {
+            mode: RoiAlignMode::Avg,
+            output_height: 1,
+            output_width: 1,
+            sampling_ratio: 0,
+            spatial_scale: 1.0,
+            half_pixel: true,
+        };
+
+        let result = roi_align(
+            &pool,
+            input.view(),
+            rois.view(),
+            batch_indices.view(),
+            &opts,
+        );
+        assert_eq!(
+            result.err(),
+            Some(OpError::InvalidValue("batch index is out of range"))
+        );
+    }
+}
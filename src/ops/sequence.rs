@@ -11,7 +11,7 @@ use crate::ops::split::split;
 use crate::ops::{Concat, map_value_view, resolve_axis, resolve_index};
 use crate::value::{DataType, Sequence, Value, ValueType, ValueView};
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct SequenceEmpty {
     pub dtype: Option<DataType>,
 }
@@ -243,7 +243,7 @@ impl Operator for SequenceLength {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ConcatFromSequence {
     pub axis: i32,
     pub new_axis: bool,
@@ -299,7 +299,7 @@ impl Operator for ConcatFromSequence {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct SplitToSequence {
     pub axis: i32,
     pub keep_dims: bool,
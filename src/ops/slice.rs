@@ -1,6 +1,6 @@
 use rten_shape_inference::ops as shape_ops;
 use rten_tensor::prelude::*;
-use rten_tensor::{NdTensorView, SliceItem, SliceRange, Tensor, TensorView};
+use rten_tensor::{NdTensor, NdTensorView, SliceItem, SliceRange, Tensor, TensorView};
 
 use smallvec::SmallVec;
 
@@ -113,6 +113,22 @@ pub fn slice_in_place<T: Copy>(
     Ok(())
 }
 
+/// Get a `starts`, `ends`, `axes` or `steps` input of a Slice operator as
+/// `i32` values.
+///
+/// `i64` values are saturated to the `i32` range. ONNX models commonly use
+/// `i64::MIN` and `i64::MAX` to slice to the start or end of a dimension.
+fn get_slice_input(inputs: &InputList, index: usize) -> Result<Option<NdTensor<i32, 1>>, OpError> {
+    match inputs.get(index) {
+        Some(ValueView::Int64Tensor(_)) => Ok(inputs
+            .get_as::<NdTensorView<i64, 1>>(index)?
+            .map(|x| x.map(|x| (*x).clamp(i32::MIN as i64, i32::MAX as i64) as i32))),
+        _ => Ok(inputs
+            .get_as::<NdTensorView<i32, 1>>(index)?
+            .map(|x| x.to_tensor())),
+    }
+}
+
 #[derive(Debug)]
 pub struct Slice {}
 
@@ -129,14 +145,22 @@ impl Operator for Slice {
         let inputs = ctx.inputs();
         let input = inputs.require(0)?;
 
-        let starts = inputs.require_as(1)?;
-        let ends = inputs.require_as(2)?;
+        let starts = get_slice_input(inputs, 1)?.ok_or(OpError::MissingInputs)?;
+        let ends = get_slice_input(inputs, 2)?.ok_or(OpError::MissingInputs)?;
 
-        let axes = inputs.get_as(3)?;
-        let steps = inputs.get_as(4)?;
+        let axes = get_slice_input(inputs, 3)?;
+        let steps = get_slice_input(inputs, 4)?;
 
         let result: Result<Value, OpError> = map_value_view!(input, x, {
-            slice(ctx.pool(), x, &starts, &ends, axes.as_ref(), steps.as_ref()).map(|t| t.into())
+            slice(
+                ctx.pool(),
+                x,
+                &starts.view(),
+                &ends.view(),
+                axes.as_ref().map(|a| a.view()).as_ref(),
+                steps.as_ref().map(|s| s.view()).as_ref(),
+            )
+            .map(|t| t.into())
         });
         result.into_op_result()
     }
@@ -145,13 +169,17 @@ impl Operator for Slice {
         true
     }
 
+    fn supports_int64_bool_input(&self, _index: usize) -> bool {
+        true
+    }
+
     fn run_in_place(&self, input: Value, ctx: &OpRunContext) -> Result<Value, OpError> {
         let other = ctx.inputs();
-        let starts = other.require_as(0)?;
-        let ends = other.require_as(1)?;
+        let starts = get_slice_input(other, 0)?.ok_or(OpError::MissingInputs)?;
+        let ends = get_slice_input(other, 1)?.ok_or(OpError::MissingInputs)?;
 
-        let axes = other.get_as(2)?;
-        let steps = other.get_as::<NdTensorView<i32, 1>>(3)?;
+        let axes = get_slice_input(other, 2)?;
+        let steps = get_slice_input(other, 3)?;
 
         // Fall back to copying if non-default steps are given.
         if let Some(steps) = steps
@@ -172,7 +200,12 @@ impl Operator for Slice {
         }
 
         map_value!(input, output, {
-            slice_in_place(&mut output, &starts, &ends, axes.as_ref())?;
+            slice_in_place(
+                &mut output,
+                &starts.view(),
+                &ends.view(),
+                axes.as_ref().map(|a| a.view()).as_ref(),
+            )?;
             Ok(output.into())
         })
     }
@@ -196,9 +229,11 @@ mod tests {
     use rten_tensor::test_util::expect_equal;
     use rten_testing::TestCases;
 
-    use super::{slice, slice_in_place};
+    use super::{Slice, slice, slice_in_place};
     use crate::buffer_pool::BufferPool;
+    use crate::operator::{InputList, OperatorExt};
     use crate::ops::OpError;
+    use crate::value::ValueView;
 
     fn from_slice<T: Copy>(data: &[T]) -> Tensor<T> {
         Tensor::from_data(&[data.len()], data.to_vec())
@@ -473,6 +508,34 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_slice_int64_indices() {
+        let input = Tensor::from([1, 2, 3, 4, 5]);
+
+        // ONNX models use `i64::MIN` and `i64::MAX` as starts / ends to slice
+        // to the start or end of a dimension.
+        let starts = Tensor::from([1i64]);
+        let ends = Tensor::from([i64::MAX]);
+        let result: Tensor<i32> = Slice {}
+            .run_simple((input.view(), starts.view(), ends.view()))
+            .unwrap();
+        assert_eq!(result, Tensor::from([2, 3, 4, 5]));
+
+        let starts = Tensor::from([i64::MAX]);
+        let ends = Tensor::from([i64::MIN]);
+        let steps = Tensor::from([-1i64]);
+        let axes = Tensor::from([0i64]);
+        let inputs: [ValueView; 5] = [
+            input.view().into(),
+            starts.view().into(),
+            ends.view().into(),
+            axes.view().into(),
+            steps.view().into(),
+        ];
+        let result: Tensor<i32> = Slice {}.run_simple(InputList::from(&inputs)).unwrap();
+        assert_eq!(result, Tensor::from([5, 4, 3, 2, 1]));
+    }
+
     #[test]
     fn test_slice_with_step() {
         let input = from_slice(&[1, 2, 3, 4, 5]);
@@ -89,7 +89,7 @@ pub fn split<T: Copy>(
     Ok(outputs)
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Split {
     pub axis: isize,
     pub num_outputs: Option<u32>,
@@ -104,6 +104,10 @@ impl Operator for Split {
         Some(2)
     }
 
+    fn supports_int64_bool_input(&self, index: usize) -> bool {
+        index == 0
+    }
+
     fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
         let input = ctx.inputs().require(0)?;
 
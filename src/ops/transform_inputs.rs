@@ -93,6 +93,21 @@ pub struct TransformInputs {
     transforms: Vec<TransformIndex>,
 }
 
+impl TransformInputs {
+    /// Return the wrapped operator.
+    pub fn inner(&self) -> &Arc<dyn Operator + Send + Sync> {
+        &self.inner
+    }
+
+    /// Return the `(input_index, perm)` permutations applied to inputs, in
+    /// the order they were added by [`TransformInputsBuilder::permute`].
+    pub fn permutations(&self) -> impl Iterator<Item = (usize, Option<&[usize]>)> {
+        self.transforms.iter().map(|t| match &t.transform {
+            Transform::Permute(PermuteInput { perm }) => (t.input_index, perm.as_deref()),
+        })
+    }
+}
+
 impl Operator for TransformInputs {
     fn name(&self) -> &str {
         &self.name
@@ -63,7 +63,7 @@ pub fn trilu<T: Copy + Default>(
     Ok(output)
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Trilu {
     pub upper: bool,
 }
@@ -223,11 +223,89 @@ declare_operator!(Atan);
 impl_operator!(Atan, [FloatTensor]);
 impl_get_kernel!(Atan, f32, |val: f32| val.atan());
 
+/// Bitwise NOT of integer values.
+#[derive(Debug)]
+pub struct BitwiseNot {}
+
+impl<T: Copy + std::ops::Not<Output = T>> GetKernel<T> for BitwiseNot {
+    fn get_kernel(&self) -> impl UnaryKernel<T> + Send + Sync {
+        |val: T| !val
+    }
+}
+
+impl Operator for BitwiseNot {
+    fn name(&self) -> &str {
+        "BitwiseNot"
+    }
+
+    fn max_inputs(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    fn can_run_in_place(&self) -> bool {
+        true
+    }
+
+    fn supports_int64_bool_input(&self, _index: usize) -> bool {
+        true
+    }
+
+    fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
+        let input = ctx.inputs().require(0)?;
+        map_value_view!(
+            input,
+            input,
+            [Int32Tensor, Int64Tensor, Int8Tensor, UInt8Tensor],
+            {
+                let kernel = self.get_kernel();
+                unary_op(ctx.pool(), input, &kernel).into_op_result()
+            }
+        )
+    }
+
+    fn run_in_place(&self, input: Value, ctx: &OpRunContext) -> Result<Value, OpError> {
+        map_value!(
+            input,
+            input,
+            [Int32Tensor, Int64Tensor, Int8Tensor, UInt8Tensor],
+            {
+                let kernel = self.get_kernel();
+                let result = unary_op_in_place(ctx.pool(), input, &kernel);
+                Ok(result.into())
+            }
+        )
+    }
+
+    fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
+        Some([OutputType::CopyFromInput(0)].into())
+    }
+
+    fn as_infer_shapes(&self) -> Option<&dyn InferShapes> {
+        Some(&UnaryOp)
+    }
+}
+
 declare_operator!(Ceil);
 impl_operator!(Ceil, [FloatTensor]);
 impl_operator_fn!(Ceil, ceil, cfg_test);
 impl_get_kernel!(Ceil, f32, |val: f32| val.ceil());
 
+/// Continuously Differentiable Exponential Linear Unit.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__Celu.html>.
+#[derive(Clone, Debug)]
+pub struct Celu {
+    pub alpha: f32,
+}
+
+impl_operator!(Celu, [FloatTensor]);
+
+impl GetKernel<f32> for Celu {
+    fn get_kernel(&self) -> impl UnaryKernel<f32> + Send + Sync {
+        SimdKernel(vecmath::Celu { alpha: self.alpha })
+    }
+}
+
 /// Numeric value with a finite minimum and maximum and operations to clamp
 /// values.
 pub trait Clamp: Copy + PartialOrd {
@@ -341,7 +419,7 @@ declare_operator!(Cos);
 impl_operator!(Cos, [FloatTensor]);
 impl_get_kernel!(Cos, f32, SimdKernel(vecmath::Cos::new()));
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Elu {
     pub alpha: f32,
 }
@@ -368,7 +446,7 @@ impl_operator!(Floor, [FloatTensor]);
 impl_operator_fn!(Floor, floor, cfg_test);
 impl_get_kernel!(Floor, f32, |val: f32| val.floor());
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Gelu {
     pub approximate: bool,
 }
@@ -406,7 +484,7 @@ impl UnaryKernel<f32> for GeluKernel {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct HardSigmoid {
     pub alpha: f32,
     pub beta: f32,
@@ -497,7 +575,7 @@ impl Operator for IsNaN {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct LeakyRelu {
     pub alpha: f32,
 }
@@ -520,6 +598,12 @@ declare_operator!(Log);
 impl_operator!(Log, [FloatTensor]);
 impl_get_kernel!(Log, f32, |val: f32| val.ln());
 
+// Mish activation function, `x * tanh(softplus(x))`.
+// See https://arxiv.org/abs/1908.08681.
+declare_operator!(Mish);
+impl_operator!(Mish, [FloatTensor]);
+impl_get_kernel!(Mish, f32, SimdKernel(vecmath::Mish {}));
+
 declare_operator!(Neg);
 impl_operator!(Neg, [FloatTensor, Int32Tensor], Some(&shape_ops::Neg));
 
@@ -529,12 +613,12 @@ impl<T: Copy + std::ops::Neg<Output = T>> GetKernel<T> for Neg {
     }
 }
 
-pub fn not<T: AsBool + PartialEq>(pool: &BufferPool, input: TensorView<T>) -> Tensor<i32> {
-    input.map_in(pool, |x| i32::from(!x.as_bool()))
+pub fn not<T: AsBool + From<bool>>(pool: &BufferPool, input: TensorView<T>) -> Tensor<T> {
+    input.map_in(pool, |x| T::from(!x.as_bool()))
 }
 
-pub fn not_in_place(mut input: TensorViewMut<i32>) {
-    input.apply(|x| i32::from(!x.as_bool()));
+pub fn not_in_place<T: AsBool + From<bool>>(mut input: TensorViewMut<T>) {
+    input.apply(|x| T::from(!x.as_bool()));
 }
 
 #[derive(Debug)]
@@ -550,22 +634,29 @@ impl Operator for Not {
     }
 
     fn run(&self, ctx: &OpRunContext) -> Result<OutputList, OpError> {
-        let input: TensorView<i32> = ctx.inputs().require_as(0)?;
-        not(ctx.pool(), input).into_op_result()
+        let input = ctx.inputs().require(0)?;
+        map_value_view!(input, input, [BoolTensor, Int32Tensor], {
+            not(ctx.pool(), input).into_op_result()
+        })
     }
 
     fn can_run_in_place(&self) -> bool {
         true
     }
 
+    fn supports_int64_bool_input(&self, _index: usize) -> bool {
+        true
+    }
+
     fn run_in_place(&self, input: Value, _ctx: &OpRunContext) -> Result<Value, OpError> {
-        let mut output: Tensor<i32> = input.try_into()?;
-        not_in_place(output.view_mut());
-        Ok(output.into())
+        map_value!(input, output, [BoolTensor, Int32Tensor], {
+            not_in_place(output.view_mut());
+            Ok(output.into())
+        })
     }
 
     fn output_types(&self, _ctx: &OutputTypesContext) -> Option<OutputTypeList> {
-        Some([OutputType::Fixed(ValueType::Tensor(DataType::Int32))].into())
+        Some([OutputType::CopyFromInput(0)].into())
     }
 
     fn as_infer_shapes(&self) -> Option<&dyn InferShapes> {
@@ -639,6 +730,47 @@ impl Operator for PRelu {
     }
 }
 
+/// Scaled Exponential Linear Unit.
+///
+/// See <https://onnx.ai/onnx/operators/onnx__Selu.html>.
+#[derive(Clone, Debug)]
+pub struct Selu {
+    pub alpha: f32,
+    pub gamma: f32,
+}
+
+impl_operator!(Selu, [FloatTensor]);
+
+impl GetKernel<f32> for Selu {
+    fn get_kernel(&self) -> impl UnaryKernel<f32> + Send + Sync {
+        SimdKernel(vecmath::Selu {
+            alpha: self.alpha,
+            gamma: self.gamma,
+        })
+    }
+}
+
+/// Shrink values towards zero.
+///
+/// Values in `[-lambd, lambd]` are set to zero, others are moved towards zero
+/// by `bias`. See <https://onnx.ai/onnx/operators/onnx__Shrink.html>.
+#[derive(Clone, Debug)]
+pub struct Shrink {
+    pub bias: f32,
+    pub lambd: f32,
+}
+
+impl_operator!(Shrink, [FloatTensor]);
+
+impl GetKernel<f32> for Shrink {
+    fn get_kernel(&self) -> impl UnaryKernel<f32> + Send + Sync {
+        SimdKernel(vecmath::Shrink {
+            bias: self.bias,
+            lambd: self.lambd,
+        })
+    }
+}
+
 declare_operator!(Sigmoid);
 impl_operator!(Sigmoid, [FloatTensor]);
 impl_operator_fn!(Sigmoid, sigmoid);
@@ -659,7 +791,7 @@ impl_get_kernel!(Silu, f32, SimdKernel(vecmath::Silu {}));
 ///
 /// This computes `x * sigmoid(beta * x)`. The special case where beta = 1 is
 /// known as [`Silu`].
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Swish {
     pub beta: f32,
 }
@@ -722,6 +854,20 @@ impl_operator!(Tanh, [FloatTensor]);
 impl_operator_fn!(Tanh, tanh);
 impl_get_kernel!(Tanh, f32, SimdKernel(vecmath::Tanh {}));
 
+/// ReLU variant which sets values less than or equal to `alpha` to zero.
+#[derive(Clone, Debug)]
+pub struct ThresholdedRelu {
+    pub alpha: f32,
+}
+
+impl_operator!(ThresholdedRelu, [FloatTensor]);
+
+impl GetKernel<f32> for ThresholdedRelu {
+    fn get_kernel(&self) -> impl UnaryKernel<f32> + Send + Sync {
+        SimdKernel(vecmath::ThresholdedRelu { alpha: self.alpha })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::error::Error;
@@ -733,9 +879,10 @@ mod tests {
     use rten_testing::TestCases;
 
     use super::{
-        Abs, Acos, Asin, Atan, Cos, Elu, Exp, Gelu, IsInf, IsNaN, Log, Neg, Not, PRelu, Reciprocal,
-        Relu, Sigmoid, Sign, Silu, Sin, Softplus, Sqrt, Swish, Tan, Tanh, ceil, clip,
-        clip_in_place, erf, floor, hard_sigmoid, hard_swish, leaky_relu, round,
+        Abs, Acos, Asin, Atan, BitwiseNot, Celu, Cos, Elu, Exp, Gelu, IsInf, IsNaN, Log, Mish, Neg,
+        Not, PRelu, Reciprocal, Relu, Selu, Shrink, Sigmoid, Sign, Silu, Sin, Softplus, Sqrt,
+        Swish, Tan, Tanh, ThresholdedRelu, ceil, clip, clip_in_place, erf, floor, hard_sigmoid,
+        hard_swish, leaky_relu, round,
     };
     use crate::buffer_pool::BufferPool;
     use crate::operator::{OpError, Operator, OperatorExt};
@@ -845,6 +992,13 @@ mod tests {
     test_unary_op!(test_asin, Asin {}, |x: &f32| x.asin());
     test_unary_op!(test_atan, Atan {}, |x: &f32| x.atan());
 
+    #[test]
+    fn test_bitwise_not() {
+        test_unary_op_both(BitwiseNot {}, |x| !x, Tensor::from([0, 1, -1, 5])).unwrap();
+        test_unary_op_both(BitwiseNot {}, |x| !x, Tensor::from([0i64, 1, -1, 5])).unwrap();
+        test_unary_op_both(BitwiseNot {}, |x| !x, Tensor::from([0u8, 1, 255, 5])).unwrap();
+    }
+
     #[test]
     fn test_ceil() {
         let pool = BufferPool::new();
@@ -872,6 +1026,24 @@ mod tests {
         assert!(eq_with_nans(result.view(), expected.view()));
     }
 
+    #[test]
+    fn test_celu() -> Result<(), Box<dyn Error>> {
+        // Example from ONNX backend tests, with alpha = 2.
+        let input = Tensor::from([-1., 0., 1.]);
+        let expected = Tensor::from([-0.7869387, 0., 1.]);
+        let result: Tensor = Celu { alpha: 2. }.run_simple(input.view()).unwrap();
+        expect_equal(&result, &expected)?;
+
+        let reference_op = |&x: &f32| x.max(0.) + (2. * ((x / 2.).exp() - 1.)).min(0.);
+        test_unary_op_both(
+            Celu { alpha: 2. },
+            reference_op,
+            [-3., -0.5, 0.5, 3.].into(),
+        )?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_clip() {
         #[derive(Debug)]
@@ -1075,6 +1247,13 @@ mod tests {
         Tensor::from([0.1, 0.5, 1., 10.])
     );
 
+    test_unary_op!(
+        test_mish,
+        Mish {},
+        |x: &f32| x * x.exp().ln_1p().tanh(),
+        Tensor::from([-10., -1., -0.5, 0., 0.5, 1., 10., 50.])
+    );
+
     test_unary_op!(test_neg, Neg {}, |x| -x, Tensor::from([0, 1, -1, 2]));
 
     test_unary_op!(
@@ -1135,6 +1314,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_selu() -> Result<(), Box<dyn Error>> {
+        // Example from ONNX backend tests, with alpha = 2 and gamma = 3.
+        let input = Tensor::from([-1., 0., 1.]);
+        let expected = Tensor::from([-3.7927232, 0., 3.]);
+        let op = Selu {
+            alpha: 2.,
+            gamma: 3.,
+        };
+        let result: Tensor = op.run_simple(input.view()).unwrap();
+        expect_equal(&result, &expected)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_shrink() -> Result<(), Box<dyn Error>> {
+        // Examples from ONNX backend tests.
+        let input = Tensor::from([-2., -1., 0., 1., 2.]);
+        let op = Shrink {
+            bias: 0.,
+            lambd: 1.5,
+        };
+        let result: Tensor = op.run_simple(input.view()).unwrap();
+        expect_equal(&result, &Tensor::from([-2., 0., 0., 0., 2.]))?;
+
+        let op = Shrink {
+            bias: 1.5,
+            lambd: 1.5,
+        };
+        let result: Tensor = op.run_simple(input.view()).unwrap();
+        expect_equal(&result, &Tensor::from([-0.5, 0., 0., 0., 0.5]))?;
+
+        Ok(())
+    }
+
     test_unary_op!(test_sign, Sign {}, |x: &f32| x.signum());
 
     fn reference_sigmoid(x: f32) -> f32 {
@@ -1160,4 +1374,10 @@ mod tests {
         * reference_sigmoid(0.5 * *x));
     test_unary_op!(test_tan, Tan {}, |x: &f32| x.tan());
     test_unary_op!(test_tanh, Tanh {}, |x: &f32| x.tanh());
+    test_unary_op!(
+        test_thresholded_relu,
+        ThresholdedRelu { alpha: 2. },
+        |&x: &f32| if x > 2. { x } else { 0. },
+        Tensor::from([-1.5, 0., 1.2, 2., 2.2])
+    );
 }
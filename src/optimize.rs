@@ -3,7 +3,7 @@ use std::error::Error;
 use std::fmt::{Display, Formatter};
 use std::sync::Arc;
 
-use rten_tensor::Tensor;
+use rten_tensor::{NdTensorView, Tensor};
 use rustc_hash::FxHashSet;
 use smallvec::SmallVec;
 
@@ -15,7 +15,7 @@ use crate::graph::{
 };
 use crate::infer_shapes::{InferError, InferShapeOptions, Shape, infer_shapes};
 use crate::operator::Operator;
-use crate::ops::Identity;
+use crate::ops::{Gather, Identity, MatMulNBits, reorder_nbits_rhs};
 
 mod diagnostics;
 mod fusions;
@@ -24,12 +24,12 @@ mod pattern_matcher;
 use diagnostics::{DiagnosticLevel, Diagnostics};
 
 use fusions::{
-    AddSoftmaxFusion, ApproxGeluFusion, CastElimination, ComputeShapeFusion, Fusion, FusionError,
-    FusionVisitor, GeluFusion, GroupedQueryAttentionMatMulFusion, IdentityFusion,
-    LayerNormalizationFusion, MatMulAddFusion, MatMulIntegerToFloatFusion, MatMulScaleFusion,
-    PatternFusion, ReciprocalFusion, ReduceMeanAxesFusion, RepeatInterleaveFusion,
-    RmsNormalizationFusion, SafeSoftmaxFusion, ShapeSliceToConstant, SiluFusion, SwishFusion,
-    TransposeFusion,
+    AddSoftmaxFusion, ApproxGeluFusion, AttentionFusion, CastElimination, ComputeShapeFusion,
+    Fusion, FusionError, FusionVisitor, GeluFusion, GroupedQueryAttentionMatMulFusion,
+    IdentityFusion, LayerNormalizationFusion, MatMulAddFusion, MatMulIntegerToFloatFusion,
+    MatMulScaleFusion, PatternFusion, ReciprocalFusion, ReduceMeanAxesFusion,
+    RepeatInterleaveFusion, RmsNormalizationFusion, SafeSoftmaxFusion, ShapeSliceToConstant,
+    SiluFusion, SwishFusion, TransposeFusion,
 };
 
 /// Errors that occur while applying graph optimizations.
@@ -483,6 +483,7 @@ impl GraphOptimizer {
             self.convert_captured_values_to_constants(&mut graph_mut, capture_env)?;
         }
         self.propagate_constants(&mut graph_mut)?;
+        self.reorder_nbits_weights(&mut graph_mut);
 
         // Fuse operators.
         //
@@ -534,6 +535,20 @@ impl GraphOptimizer {
             }
         }
 
+        // Fusions which match the outputs of earlier fusions.
+        let mut late_fusions = FusionList::new();
+
+        // Attention fusion expects the matmul, softmax and transpose fusions
+        // above to have been applied.
+        late_fusions.push(AttentionFusion {}.into_visitor());
+
+        for _ in 0..max_iters {
+            let n_fused_ops = self.apply_fusions(&mut graph_mut, late_fusions.visitors(), &diag)?;
+            if n_fused_ops == 0 {
+                break;
+            }
+        }
+
         Ok(graph_mut.finalize_graph())
     }
 
@@ -611,6 +626,18 @@ impl GraphOptimizer {
                 Value::UInt8Tensor(tensor) => {
                     Some(graph.add_constant(const_name, tensor.into_arc()))
                 }
+                Value::Float16Tensor(tensor) => {
+                    Some(graph.add_constant(const_name, tensor.into_arc()))
+                }
+                Value::BFloat16Tensor(tensor) => {
+                    Some(graph.add_constant(const_name, tensor.into_arc()))
+                }
+                Value::Int64Tensor(tensor) => {
+                    Some(graph.add_constant(const_name, tensor.into_arc()))
+                }
+                Value::BoolTensor(tensor) => {
+                    Some(graph.add_constant(const_name, tensor.into_arc()))
+                }
 
                 // Sequence constants are not yet supported, but we could add
                 // them in future. For now, the sequence generated by constant
@@ -625,6 +652,108 @@ impl GraphOptimizer {
         Ok(())
     }
 
+    /// Reorder the weights of `MatMulNBits` operators which have a constant,
+    /// non-default `g_idx` input.
+    ///
+    /// The quantized matmul kernels require the rows which use each
+    /// quantization block to be contiguous, so otherwise the operator
+    /// dequantizes the weights on every run. This replaces
+    /// `MatMulNBits(A, B, ..., g_idx)` with
+    /// `MatMulNBits(Gather(A, perm, axis=-1), B[perm], ...)`, where `perm` sorts
+    /// the rows of `B` by block.
+    fn reorder_nbits_weights(&self, graph: &mut GraphMutator) {
+        struct Reorder {
+            op_id: NodeId,
+            name: Option<String>,
+            op: MatMulNBits,
+            input_ids: Vec<Option<NodeId>>,
+            output_ids: Vec<Option<NodeId>>,
+            rhs: Tensor<u8>,
+            perm: Vec<i32>,
+        }
+
+        let get_constant = |id: Option<NodeId>| match graph.graph().get_node(id?) {
+            Some(Node::Constant(const_node)) => Some(const_node.as_view()),
+            _ => None,
+        };
+
+        let reorders: Vec<Reorder> = graph
+            .graph()
+            .iter()
+            .filter_map(|(op_id, node)| {
+                let op_node = node.as_operator()?;
+                let op = op_node.operator().downcast_ref::<MatMulNBits>()?;
+                let input_ids = op_node.input_ids();
+                let rhs: NdTensorView<u8, 3> = get_constant(input_ids.get(1).copied().flatten())?
+                    .try_into()
+                    .ok()?;
+                let g_idx: NdTensorView<i32, 1> =
+                    get_constant(input_ids.get(4).copied().flatten())?
+                        .try_into()
+                        .ok()?;
+                let (rhs, perm) = reorder_nbits_rhs(rhs, op.bits, op.block_size, g_idx)?;
+
+                // The operator ignores `g_idx` if rows are already in order.
+                if perm.iter().enumerate().all(|(i, &row)| row as usize == i) {
+                    return None;
+                }
+
+                Some(Reorder {
+                    op_id,
+                    name: op_node.name().map(|name| name.to_string()),
+                    op: op.clone(),
+                    input_ids: input_ids.to_vec(),
+                    output_ids: op_node.output_ids().to_vec(),
+                    rhs: rhs.into_dyn(),
+                    perm,
+                })
+            })
+            .collect();
+
+        for Reorder {
+            op_id,
+            name,
+            op,
+            mut input_ids,
+            output_ids,
+            rhs,
+            perm,
+        } in reorders
+        {
+            graph.graph.remove_nodes(&[op_id]);
+
+            let perm_id = graph.add_constant(None, Tensor::from(perm).into_arc());
+            let lhs_id = graph.graph.add_value(None, None, None);
+            graph.add_operator(
+                None,
+                Arc::new(Gather { axis: -1 }),
+                &[input_ids[0], Some(perm_id)],
+                &[Some(lhs_id)],
+            );
+
+            let old_rhs_id = input_ids[1];
+            let old_g_idx_id = input_ids[4];
+            input_ids[0] = Some(lhs_id);
+            input_ids[1] = Some(graph.add_constant(None, rhs.into_arc()));
+            input_ids[4] = None;
+            graph.add_operator(name.as_deref(), Arc::new(op), &input_ids, &output_ids);
+
+            // Remove the original weights if nothing else uses them.
+            let unused: Vec<NodeId> = [old_rhs_id, old_g_idx_id]
+                .into_iter()
+                .flatten()
+                .filter(|id| {
+                    graph
+                        .graph()
+                        .get_consumers(*id)
+                        .is_none_or(|consumers| consumers.is_empty())
+                        && !graph.output_ids().contains(id)
+                })
+                .collect();
+            graph.graph.remove_nodes(&unused);
+        }
+    }
+
     /// Traverse the graph and test each operator node against a sequence of
     /// fusion visitors, applying the first returned fusion if any.
     ///
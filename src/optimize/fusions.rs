@@ -12,9 +12,9 @@ use crate::graph::{
     TypedConstant,
 };
 use crate::operator::Operator;
-use crate::ops::transform_inputs::TransformInputsBuilder;
+use crate::ops::transform_inputs::{TransformInputs, TransformInputsBuilder};
 use crate::ops::{
-    AddSoftmax, Cast, ComputeShape, DynamicQuantizeLinear, FusedMatMul, Gelu,
+    AddSoftmax, Attention, Cast, ComputeShape, DynamicQuantizeLinear, FusedMatMul, Gelu,
     GroupedQueryAttentionMatMul, LayerNormalization, MatMulIntegerToFloat, Mul, Reciprocal,
     ReduceMean, RepeatInterleave, RmsNormalization, Shape, Silu, Softmax, Swish, SymbolInfo,
     Transpose,
@@ -383,12 +383,20 @@ impl PatternFusion for ReduceMeanAxesFusion {
         let mean_op = g
             .get_operator::<ReduceMean>(pat_match.node_id("mean").unwrap())
             .ok_or(FusionError::CheckFailed("wrong op"))?;
-        let axes = g
-            .get_vector::<i32>(pat_match.node_id("axes").unwrap())
-            .ok_or(FusionError::CheckFailed("axes not a vector"))?;
+        let axes_id = pat_match.node_id("axes").unwrap();
+        let axes = match g.get_vector::<i32>(axes_id) {
+            Some(axes) => axes.to_vec(),
+            None => g
+                .get_vector::<i64>(axes_id)
+                .ok_or(FusionError::CheckFailed("axes not a vector"))?
+                .iter()
+                .map(|&axis| i32::try_from(axis))
+                .collect::<Result<_, _>>()
+                .map_err(|_| FusionError::CheckFailed("axis out of range"))?,
+        };
 
         Ok(ReduceMean {
-            axes: Some(axes.to_vec()),
+            axes: Some(axes),
             keep_dims: mean_op.keep_dims,
             noop_with_empty_axes: false,
         })
@@ -1205,16 +1213,24 @@ impl FusionVisitor for ShapeSliceToConstant {
         let ndim = x_shape.len();
         let x_shape = NdTensorView::from_data([ndim], &*x_shape);
 
-        // Check for constant starts/ends.
-        let Some(&[start]) = graph.get_vector::<i32>(starts_id) else {
+        // Check for constant starts/ends. These may be `i64` if they are
+        // outside the `i32` range (eg. `i64::MAX` to slice to the end).
+        let get_index = |id| match graph.get_vector::<i32>(id) {
+            Some(&[index]) => Some(index as isize),
+            _ => match graph.get_vector::<i64>(id) {
+                Some(&[index]) => Some(index.clamp(isize::MIN as i64, isize::MAX as i64) as isize),
+                _ => None,
+            },
+        };
+        let Some(start) = get_index(starts_id) else {
             return Err(FusionError::CheckFailed("start is not constant"));
         };
-        let Some(&[end]) = graph.get_vector::<i32>(ends_id) else {
+        let Some(end) = get_index(ends_id) else {
             return Err(FusionError::CheckFailed("end is not constant"));
         };
 
         // Clamp dimensions here the same as the `Slice` op does.
-        let dim_range = SliceRange::new(start as isize, Some(end as isize), 1).clamp(ndim);
+        let dim_range = SliceRange::new(start, Some(end), 1).clamp(ndim);
 
         // Extract the selected dimensions and check they all have a fixed size.
         let dims: Option<Vec<_>> = x_shape
@@ -1666,6 +1682,192 @@ impl PatternFusion for GroupedQueryAttentionMatMulFusion {
         })
     }
 }
+
+/// Fuses `Softmax(Q @ K^T * scale + mask) @ V` into an [`Attention`] operation.
+///
+/// This matches attention subgraphs after other attention fusions have been
+/// applied, so `Q @ K^T * scale` is expected to be a `FusedMatMul` or
+/// `GroupedQueryAttentionMatMul`, and `Softmax(QK + mask)` an `AddSoftmax`.
+/// The transpose of K may have been fused into the matmul by
+/// [`TransposeFusion`]. The unfused forms are also accepted.
+///
+/// If `Q @ K^T` is not scaled, the fused operator uses a scale of 1 rather
+/// than the `Attention` operator's default of `1 / sqrt(head_size)`.
+pub struct AttentionFusion {}
+
+impl PatternFusion for AttentionFusion {
+    type Operator = Attention;
+
+    fn name(&self) -> &str {
+        "AttentionFusion"
+    }
+
+    fn pattern(&self) -> Pattern {
+        let q = Pattern::symbol("q");
+        let k = Pattern::symbol("k");
+        let v = Pattern::symbol("v");
+        let mask = Pattern::symbol("mask");
+
+        let k_transposed = Pattern::unary_op("Transpose", k.clone()).with_name("k_transpose");
+        let scores = Pattern::any_of(vec![
+            Pattern::binary_op("FusedMatMul", q.clone(), k_transposed.clone())
+                .with_name("qk_matmul"),
+            Pattern::binary_op("MatMul", q.clone(), k_transposed),
+            Pattern::binary_op("TransformInputs(FusedMatMul)", q.clone(), k.clone())
+                .with_name("qk_transformed"),
+            Pattern::binary_op("TransformInputs(MatMul)", q.clone(), k.clone())
+                .with_name("qk_transformed"),
+            Pattern::binary_op("GroupedQueryAttentionMatMul", q, k).with_name("qk_gqa"),
+        ]);
+        let probs = Pattern::any_of(vec![
+            Pattern::binary_op("AddSoftmax", scores.clone(), mask.clone()).with_name("softmax"),
+            Pattern::unary_op("Softmax", Pattern::binary_op("Add", scores.clone(), mask))
+                .with_name("softmax"),
+            Pattern::unary_op("Softmax", scores).with_name("softmax"),
+        ]);
+
+        Pattern::any_of(vec![
+            Pattern::binary_op("MatMul", probs.clone(), v.clone()),
+            Pattern::binary_op("GroupedQueryAttentionMatMul", probs, v).with_name("v_gqa"),
+        ])
+    }
+
+    fn inputs(&self) -> &[&str] {
+        &["q", "k", "v", "mask"]
+    }
+
+    fn maybe_fuse(&self, pat_match: &Match, graph: &Graph) -> Result<Attention, FusionError> {
+        if let Some(transpose_id) = pat_match.node_id("k_transpose") {
+            let transpose = graph
+                .get_operator::<Transpose>(transpose_id)
+                .ok_or(FusionError::NoMatch)?;
+            if transpose.perm.as_deref() != Some(&[0, 1, 3, 2]) {
+                return Err(FusionError::CheckFailed("K transpose is not [0, 1, 3, 2]"));
+            }
+        }
+
+        let mut scale = None;
+        if let Some(qk_id) = pat_match.node_id("qk_transformed") {
+            let transform = graph
+                .get_operator::<TransformInputs>(qk_id)
+                .ok_or(FusionError::NoMatch)?;
+            let permutations: Vec<_> = transform.permutations().collect();
+            if permutations != [(1, Some([0, 1, 3, 2].as_slice()))] {
+                return Err(FusionError::CheckFailed("K transpose is not [0, 1, 3, 2]"));
+            }
+            if let Some(matmul) = transform.inner().downcast_ref::<FusedMatMul>() {
+                scale = matmul.alpha;
+            }
+        }
+        if let Some(qk_id) = pat_match.node_id("qk_matmul") {
+            let matmul = graph
+                .get_operator::<FusedMatMul>(qk_id)
+                .ok_or(FusionError::NoMatch)?;
+            scale = matmul.alpha;
+        }
+        if let Some(qk_id) = pat_match.node_id("qk_gqa") {
+            let matmul = graph
+                .get_operator::<GroupedQueryAttentionMatMul>(qk_id)
+                .ok_or(FusionError::NoMatch)?;
+            if !matmul.transpose_rhs {
+                return Err(FusionError::CheckFailed(
+                    "Q @ K matmul does not transpose K",
+                ));
+            }
+            scale = matmul.alpha;
+        }
+        if let Some(v_id) = pat_match.node_id("v_gqa") {
+            let matmul = graph
+                .get_operator::<GroupedQueryAttentionMatMul>(v_id)
+                .ok_or(FusionError::NoMatch)?;
+            if matmul.transpose_rhs || matmul.alpha.is_some_and(|alpha| alpha != 1.) {
+                return Err(FusionError::CheckFailed("unsupported P @ V matmul"));
+            }
+        }
+
+        let softmax_id = pat_match.node_id("softmax").unwrap();
+        let flush_nans_to_zero = if let Some(softmax) = graph.get_operator::<Softmax>(softmax_id) {
+            if !op_applied_to_last_axis::<Softmax>(graph, softmax_id) {
+                return Err(FusionError::CheckFailed("softmax not applied to last axis"));
+            }
+            softmax.flush_nans_to_zero
+        } else {
+            graph
+                .get_operator::<AddSoftmax>(softmax_id)
+                .ok_or(FusionError::NoMatch)?
+                .flush_nans_to_zero
+        };
+
+        // Q, K and V must have shapes `(batch, heads, seq, head_size)` where
+        // the number of query heads is a multiple of the number of K/V heads.
+        let get_shape = |name| {
+            let id = pat_match.node_id(name).unwrap();
+            graph
+                .get_node(id)
+                .and_then(|n| n.shape())
+                .filter(|shape| shape.len() == 4)
+                .ok_or(FusionError::CheckFailed(
+                    "Q, K or V shape is unknown or not 4D",
+                ))
+        };
+        let q_shape = get_shape("q")?;
+        let k_shape = get_shape("k")?;
+        let v_shape = get_shape("v")?;
+
+        if q_shape[0] != k_shape[0] || k_shape[0] != v_shape[0] {
+            return Err(FusionError::CheckFailed("batch sizes differ"));
+        }
+        if k_shape[1] != v_shape[1] || k_shape[2] != v_shape[2] {
+            return Err(FusionError::CheckFailed("K and V shapes do not match"));
+        }
+        let (Dimension::Fixed(q_heads), Dimension::Fixed(kv_heads)) = (&q_shape[1], &k_shape[1])
+        else {
+            return Err(FusionError::CheckFailed("unknown number of heads"));
+        };
+        if *kv_heads == 0 || q_heads % kv_heads != 0 {
+            return Err(FusionError::CheckFailed(
+                "query heads not a multiple of KV heads",
+            ));
+        }
+
+        // The mask must broadcast to the shape of the attention scores without
+        // expanding them.
+        if let Some(mask_id) = pat_match.node_id("mask") {
+            let mask_shape = graph
+                .get_node(mask_id)
+                .and_then(|n| n.shape())
+                .ok_or(FusionError::CheckFailed("unknown mask shape"))?;
+            let scores_shape = [
+                q_shape[0].clone(),
+                q_shape[1].clone(),
+                q_shape[2].clone(),
+                k_shape[2].clone(),
+            ];
+            if mask_shape.len() > scores_shape.len()
+                || mask_shape.last() != scores_shape.last()
+                || !mask_shape
+                    .iter()
+                    .rev()
+                    .zip(scores_shape.iter().rev())
+                    .all(|(m, s)| m == s || *m == Dimension::Fixed(1))
+            {
+                return Err(FusionError::CheckFailed(
+                    "mask does not broadcast to attention scores",
+                ));
+            }
+        }
+
+        Ok(Attention {
+            is_causal: false,
+            q_num_heads: None,
+            kv_num_heads: None,
+            scale: Some(scale.unwrap_or(1.)),
+            softcap: None,
+            flush_nans_to_zero,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     // Tests for fusions are currently defined in the main `optimize.rs` module.
@@ -3,6 +3,9 @@ use std::sync::Arc;
 
 use rten_base::byte_cast::cast_pod_slice;
 use rten_shape_inference::{SymExpr, Symbol};
+use rten_tensor::prelude::*;
+use rten_tensor::rng::XorShiftRng;
+use rten_tensor::test_util::expect_equal_with_tolerance;
 use rten_tensor::{NdTensor, Tensor};
 use rten_testing::TestCases;
 
@@ -15,10 +18,11 @@ use crate::graph::{
 };
 use crate::infer_shapes::InferShapeOptions;
 use crate::ops::{
-    Add, Cast, ComputeShape, DynamicQuantizeLinear, Erf, Expand, FusedMatMul, Gather, Gelu,
-    GroupedQueryAttentionMatMul, Identity, IsNaN, LayerNormalization, MatMul, MatMulInteger, Neg,
-    Pow, ReduceMean, RepeatInterleave, Reshape, RmsNormalization, Shape, Sigmoid, Slice, Softmax,
-    Sqrt, Swish, Tanh, Transpose, Unsqueeze, Where,
+    AccuracyLevel, Add, Attention, Cast, ComputeShape, DynamicQuantizeLinear, Erf, Expand,
+    FusedMatMul, Gather, Gelu, GroupedQueryAttentionMatMul, Identity, IsNaN, LayerNormalization,
+    MatMul, MatMulInteger, MatMulNBits, Neg, Pow, ReduceMean, RepeatInterleave, Reshape,
+    RmsNormalization, Shape, Sigmoid, Slice, Softmax, Sqrt, Swish, Tanh, Transpose, Unsqueeze,
+    Where,
 };
 use crate::value::{DataType, Value, ValueType};
 
@@ -631,15 +635,18 @@ fn test_fuse_reciprocal() {
 
 #[test]
 fn test_fuse_reduce_mean_axes() {
-    let graph = {
-        let x = Expr::value("x");
-        let axes = Expr::constant(Tensor::from([-1i32]));
-        x.mean_axes(axes).build_graph(["x"])
-    };
-    let graph = optimize_graph(graph).unwrap();
-    let (_, op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
-    let mean_op = op.operator().downcast_ref::<ReduceMean>().unwrap();
-    assert_eq!(mean_op.axes.as_deref(), Some([-1].as_slice()));
+    let axes: [Value; 2] = [Tensor::from([-1i32]).into(), Tensor::from([-1i64]).into()];
+    for axes in axes {
+        let graph = {
+            let x = Expr::value("x");
+            let axes = Expr::constant(axes);
+            x.mean_axes(axes).build_graph(["x"])
+        };
+        let graph = optimize_graph(graph).unwrap();
+        let (_, op) = graph.get_source_node(graph.output_ids()[0]).unwrap();
+        let mean_op = op.operator().downcast_ref::<ReduceMean>().unwrap();
+        assert_eq!(mean_op.axes.as_deref(), Some([-1].as_slice()));
+    }
 }
 
 #[test]
@@ -784,6 +791,69 @@ fn test_fuse_matmulinteger_cast_scale() {
     assert_eq!(op.operator().name(), "MatMulIntegerToFloat");
 }
 
+#[test]
+fn test_reorder_matmul_nbits_weights() {
+    let mut rng = XorShiftRng::new(1234);
+    let [n, k_blocks, block_size] = [3, 4, 16];
+    let k = k_blocks * block_size;
+
+    // Assign rows to blocks in a shuffled order, as in GPTQ models which were
+    // quantized using activation order.
+    let g_idx = Tensor::from_fn(&[k], |idx| (((idx[0] * 5) % k) / block_size) as i32);
+
+    let mut graph = Graph::new();
+    let x = graph.add_value(Some("x"), None, None);
+    let rhs = graph.add_constant(
+        Some("rhs"),
+        Tensor::<u8>::rand(&[n, k_blocks, block_size / 2], &mut rng).into_arc(),
+    );
+    let scales = graph.add_constant(
+        Some("scales"),
+        Tensor::<f32>::rand(&[n, k_blocks], &mut rng).into_arc(),
+    );
+    let g_idx = graph.add_constant(Some("g_idx"), g_idx.into_arc());
+    let out = graph.add_value(Some("out"), None, None);
+    graph.add_op(
+        Some("matmul"),
+        Arc::new(MatMulNBits {
+            bits: 4,
+            block_size,
+            accuracy_level: AccuracyLevel::Float,
+        }),
+        &[Some(x), Some(rhs), Some(scales), None, Some(g_idx)],
+        &[Some(out)],
+    );
+    graph.set_input_ids(&[x]);
+    graph.set_output_ids(&[out]);
+
+    let input = Tensor::<f32>::rand(&[2, k], &mut rng);
+    let run = |graph: &Graph| -> Tensor<f32> {
+        graph
+            .run(vec![(x, input.view().into())], &[out], None, None)
+            .unwrap()
+            .remove(0)
+            .try_into()
+            .unwrap()
+    };
+    let expected = run(&graph);
+
+    let graph = optimize_graph(graph).unwrap();
+
+    // The reordered weights should be used with the LHS columns permuted to
+    // match, and the original weights removed.
+    let (_, op) = graph.get_source_node(out).unwrap();
+    assert_eq!(op.operator().name(), "MatMulNBits");
+    assert_eq!(op.input_ids().get(4).copied().flatten(), None);
+    let (_, gather_op) = graph.get_source_node(op.input_ids()[0].unwrap()).unwrap();
+    assert_eq!(gather_op.operator().name(), "Gather");
+    assert_eq!(gather_op.input_ids()[0], Some(x));
+    assert!(graph.get_node_id("rhs").is_none());
+    assert!(graph.get_node_id("g_idx").is_none());
+
+    let result = run(&graph);
+    expect_equal_with_tolerance(&result, &expected, 1e-4, 1e-4).unwrap();
+}
+
 #[test]
 fn test_slice_shape_to_constant() {
     // `Slice(Shape(X), starts, ends)` which can be simplified to a constant.
@@ -1239,6 +1309,212 @@ fn test_fuse_grouped_query_attention_matmul_with_transpose_and_scale() {
     assert_eq!(qkv_matmul.transpose_rhs, true);
 }
 
+#[test]
+fn test_fuse_attention() {
+    let batch_dim = Dimension::Symbolic("batch".to_string());
+    let seq_dim = Dimension::Symbolic("seq".to_string());
+    let n_heads = 4;
+    let d_model = 16;
+    let scale = (d_model as f32).sqrt();
+
+    let qkv_shape = [
+        batch_dim.clone(),
+        Dimension::Fixed(n_heads),
+        seq_dim.clone(),
+        Dimension::Fixed(d_model),
+    ];
+    let q = Expr::value_with_info("q", ValueType::Tensor(DataType::Float), &qkv_shape);
+    let k = Expr::value_with_info("k", ValueType::Tensor(DataType::Float), &qkv_shape);
+    let v = Expr::value_with_info("v", ValueType::Tensor(DataType::Float), &qkv_shape);
+    let mask = Expr::value_with_info(
+        "mask",
+        ValueType::Tensor(DataType::Float),
+        &[
+            batch_dim.clone(),
+            Dimension::Fixed(1),
+            seq_dim.clone(),
+            seq_dim.clone(),
+        ],
+    );
+
+    // Softmax(Q @ K^T / scale + mask) @ V
+    let scores = q.matmul(k.permute(&[0, 1, 3, 2])) / scale;
+    let expr = (scores + mask).softmax(-1).matmul(v);
+    let graph = expr.build_graph(["q", "k", "v", "mask"]);
+    let input_ids = graph.input_ids().to_vec();
+
+    let optimized = optimize_graph(graph).unwrap();
+
+    let (_, op) = optimized
+        .get_source_node(optimized.output_ids()[0])
+        .unwrap();
+    let attention = op.operator().downcast_ref::<Attention>().unwrap();
+    assert_eq!(attention.scale, Some(1. / scale));
+    assert!(!attention.is_causal);
+    assert!(!attention.flush_nans_to_zero);
+    let expected_inputs: Vec<_> = input_ids.into_iter().map(Some).collect();
+    assert_eq!(op.input_ids(), expected_inputs);
+
+    // Without a scale, the fused operator must use a scale of 1 rather than
+    // the default of `1 / sqrt(head_size)`.
+    let q = Expr::value_with_info("q", ValueType::Tensor(DataType::Float), &qkv_shape);
+    let k = Expr::value_with_info("k", ValueType::Tensor(DataType::Float), &qkv_shape);
+    let v = Expr::value_with_info("v", ValueType::Tensor(DataType::Float), &qkv_shape);
+    let expr = q.matmul(k.permute(&[0, 1, 3, 2])).softmax(-1).matmul(v);
+    let graph = expr.build_graph(["q", "k", "v"]);
+    let input_ids = graph.input_ids().to_vec();
+    let output_id = graph.output_ids()[0];
+
+    let mut rng = XorShiftRng::new(1234);
+    let inputs: Vec<Tensor<f32>> = (0..3)
+        .map(|_| Tensor::rand(&[1, n_heads, 5, d_model], &mut rng))
+        .collect();
+    let run = |graph: &Graph| -> Tensor<f32> {
+        let inputs = input_ids
+            .iter()
+            .zip(&inputs)
+            .map(|(id, x)| (*id, x.view().into()))
+            .collect();
+        graph
+            .run(inputs, &[output_id], None, None)
+            .unwrap()
+            .remove(0)
+            .try_into()
+            .unwrap()
+    };
+    let expected = run(&graph);
+
+    let optimized = optimize_graph(graph).unwrap();
+
+    let (_, op) = optimized.get_source_node(output_id).unwrap();
+    let attention = op.operator().downcast_ref::<Attention>().unwrap();
+    assert_eq!(attention.scale, Some(1.));
+    let result = run(&optimized);
+    expect_equal_with_tolerance(&result, &expected, 1e-4, 1e-4).unwrap();
+
+    // Mask which broadcasts along the key dimension is not supported by the
+    // fused operator.
+    let q = Expr::value_with_info("q", ValueType::Tensor(DataType::Float), &qkv_shape);
+    let k = Expr::value_with_info("k", ValueType::Tensor(DataType::Float), &qkv_shape);
+    let v = Expr::value_with_info("v", ValueType::Tensor(DataType::Float), &qkv_shape);
+    let mask = Expr::value_with_info(
+        "mask",
+        ValueType::Tensor(DataType::Float),
+        &[seq_dim.clone(), Dimension::Fixed(1)],
+    );
+    let scores = q.matmul(k.permute(&[0, 1, 3, 2])) / scale;
+    let expr = (scores + mask).softmax(-1).matmul(v);
+    let graph = expr.build_graph(["q", "k", "v", "mask"]);
+
+    let optimized = optimize_graph(graph).unwrap();
+
+    let (_, op) = optimized
+        .get_source_node(optimized.output_ids()[0])
+        .unwrap();
+    assert_ne!(op.operator().name(), "Attention");
+}
+
+#[test]
+fn test_fuse_grouped_query_attention() {
+    let batch_dim = Dimension::Symbolic("batch".to_string());
+    let seq_dim = Dimension::Symbolic("seq".to_string());
+    let kv_heads = 2;
+    let n_repeats = 4;
+    let query_heads = kv_heads * n_repeats;
+    let d_model = 16;
+    let scale = (d_model as f32).sqrt();
+
+    let head_shape = |n_heads| {
+        [
+            batch_dim.clone(),
+            Dimension::Fixed(n_heads),
+            seq_dim.clone(),
+            Dimension::Fixed(d_model),
+        ]
+    };
+    let q = Expr::value_with_info(
+        "q",
+        ValueType::Tensor(DataType::Float),
+        &head_shape(query_heads),
+    );
+    let k = Expr::value_with_info(
+        "k",
+        ValueType::Tensor(DataType::Float),
+        &head_shape(kv_heads),
+    );
+    let v = Expr::value_with_info(
+        "v",
+        ValueType::Tensor(DataType::Float),
+        &head_shape(kv_heads),
+    );
+    let expand_shape = Expr::value_with_info(
+        "expand_shape",
+        ValueType::Tensor(DataType::Float),
+        &[
+            batch_dim.clone(),
+            Dimension::Fixed(kv_heads),
+            Dimension::Fixed(n_repeats),
+            seq_dim.clone(),
+            Dimension::Fixed(d_model),
+        ],
+    );
+    let reshape_shape = Expr::value_with_info(
+        "reshape_shape",
+        ValueType::Tensor(DataType::Float),
+        &head_shape(query_heads),
+    );
+
+    // RepeatInterleave(x, axis=1) expressed as Unsqueeze + Expand + Reshape.
+    let repeat_kv = |x: Expr| {
+        let unsqueeze_axes = Expr::constant(Value::from(NdTensor::from([2i32])));
+        let output_meta = OutputMeta::Meta((DataType::Float, head_shape(query_heads).to_vec()));
+        x.binary(Unsqueeze {}, unsqueeze_axes)
+            .binary(Expand {}, expand_shape.clone())
+            .apply(
+                Reshape { allow_zero: false },
+                std::slice::from_ref(&reshape_shape),
+                &[output_meta],
+            )
+    };
+
+    // Softmax(Q @ RepeatInterleave(K)^T / scale) @ RepeatInterleave(V)
+    let scores = q.matmul(repeat_kv(k).permute(&[0, 1, 3, 2])) / scale;
+    let probs_meta = OutputMeta::Meta((
+        DataType::Float,
+        vec![
+            batch_dim.clone(),
+            Dimension::Fixed(query_heads),
+            seq_dim.clone(),
+            seq_dim.clone(),
+        ],
+    ));
+    let probs = scores.apply(
+        Softmax {
+            axis: -1,
+            flush_nans_to_zero: false,
+        },
+        &[],
+        &[probs_meta],
+    );
+    let expr = probs.matmul(repeat_kv(v));
+    let graph = expr.build_graph(["q", "k", "v", "expand_shape", "reshape_shape"]);
+    let qkv_ids = graph.input_ids()[..3].to_vec();
+
+    let optimized = optimize_graph(graph).unwrap();
+
+    let (_, op) = optimized
+        .get_source_node(optimized.output_ids()[0])
+        .unwrap();
+    let attention = op.operator().downcast_ref::<Attention>().unwrap();
+    assert_eq!(attention.scale, Some(1. / scale));
+
+    // The fused operator should use the K and V inputs before heads were
+    // repeated, and have no mask.
+    let mut expected_inputs: Vec<_> = qkv_ids.into_iter().map(Some).collect();
+    expected_inputs.push(None);
+    assert_eq!(op.input_ids(), expected_inputs);
+}
+
 #[test]
 fn test_infer_shapes() {
     // Build a graph that has input shape and type metadata, but no output
@@ -4,6 +4,7 @@ use std::error::Error;
 use std::fmt;
 use std::fmt::Display;
 
+use rten_base::half::{bf16, f16};
 use rten_tensor::errors::DimensionError;
 use rten_tensor::storage::{Alloc, GlobalAlloc, ViewData};
 use rten_tensor::{
@@ -22,14 +23,44 @@ pub enum DataType {
     Float,
     Int8,
     UInt8,
+    Float16,
+    BFloat16,
+    Int64,
+    Bool,
 }
 
 impl DataType {
     /** Return the size of elements of this type in bytes. */
     pub fn size(self) -> u8 {
         match self {
+            DataType::Int64 => 8,
             DataType::Int32 | DataType::Float => 4,
-            DataType::Int8 | DataType::UInt8 => 1,
+            DataType::Float16 | DataType::BFloat16 => 2,
+            DataType::Int8 | DataType::UInt8 | DataType::Bool => 1,
+        }
+    }
+
+    /// Return true if this is a 16-bit floating point type.
+    pub fn is_half_float(self) -> bool {
+        matches!(self, DataType::Float16 | DataType::BFloat16)
+    }
+
+    /// Return the type that values of this type are converted to when used
+    /// in f32 computations.
+    pub(crate) fn widen_float(self) -> Self {
+        if self.is_half_float() {
+            DataType::Float
+        } else {
+            self
+        }
+    }
+
+    /// Return the type that values of this type are converted to when passed
+    /// to operator inputs which only support `i32` integers.
+    pub(crate) fn narrow_int(self) -> Self {
+        match self {
+            DataType::Int64 | DataType::Bool => DataType::Int32,
+            other => other,
         }
     }
 }
@@ -60,6 +91,22 @@ impl ValueType {
             stype @ Self::Sequence(_) => stype,
         }
     }
+
+    /// Widen 16-bit float element types to f32.
+    pub(crate) fn widen_float(self) -> Self {
+        match self {
+            Self::Tensor(dtype) => Self::Tensor(dtype.widen_float()),
+            Self::Sequence(dtype) => Self::Sequence(dtype.widen_float()),
+        }
+    }
+
+    /// Narrow `i64` and `bool` element types to `i32`.
+    pub(crate) fn narrow_int(self) -> Self {
+        match self {
+            Self::Tensor(dtype) => Self::Tensor(dtype.narrow_int()),
+            Self::Sequence(dtype) => Self::Sequence(dtype.narrow_int()),
+        }
+    }
 }
 
 impl std::fmt::Display for ValueType {
@@ -91,6 +138,10 @@ impl_data_type_of!(f32, Float);
 impl_data_type_of!(i32, Int32);
 impl_data_type_of!(i8, Int8);
 impl_data_type_of!(u8, UInt8);
+impl_data_type_of!(f16, Float16);
+impl_data_type_of!(bf16, BFloat16);
+impl_data_type_of!(i64, Int64);
+impl_data_type_of!(bool, Bool);
 
 impl std::fmt::Display for DataType {
     /// Format this enum value in the style of the corresponding Rust type (eg.
@@ -104,6 +155,10 @@ impl std::fmt::Display for DataType {
                 DataType::Int32 => "i32",
                 DataType::Int8 => "i8",
                 DataType::UInt8 => "u8",
+                DataType::Float16 => "f16",
+                DataType::BFloat16 => "bf16",
+                DataType::Int64 => "i64",
+                DataType::Bool => "bool",
             }
         )
     }
@@ -293,6 +348,10 @@ pub enum ValueView<'a> {
     Int32Tensor(TensorView<'a, i32>),
     Int8Tensor(TensorView<'a, i8>),
     UInt8Tensor(TensorView<'a, u8>),
+    Float16Tensor(TensorView<'a, f16>),
+    BFloat16Tensor(TensorView<'a, bf16>),
+    Int64Tensor(TensorView<'a, i64>),
+    BoolTensor(TensorView<'a, bool>),
     Sequence(&'a Sequence),
 }
 
@@ -319,6 +378,10 @@ impl<'a> ValueView<'a> {
             Self::Int32Tensor(_) => t(DataType::Int32),
             Self::Int8Tensor(_) => t(DataType::Int8),
             Self::UInt8Tensor(_) => t(DataType::UInt8),
+            Self::Float16Tensor(_) => t(DataType::Float16),
+            Self::BFloat16Tensor(_) => t(DataType::BFloat16),
+            Self::Int64Tensor(_) => t(DataType::Int64),
+            Self::BoolTensor(_) => t(DataType::Bool),
             Self::Sequence(seq) => ValueType::Sequence(seq.dtype()),
         }
     }
@@ -335,6 +398,10 @@ impl<'a> ValueView<'a> {
             ValueView::Int32Tensor(t) => t.to_tensor_in(alloc).into(),
             ValueView::Int8Tensor(t) => t.to_tensor_in(alloc).into(),
             ValueView::UInt8Tensor(t) => t.to_tensor_in(alloc).into(),
+            ValueView::Float16Tensor(t) => t.to_tensor_in(alloc).into(),
+            ValueView::BFloat16Tensor(t) => t.to_tensor_in(alloc).into(),
+            ValueView::Int64Tensor(t) => t.to_tensor_in(alloc).into(),
+            ValueView::BoolTensor(t) => t.to_tensor_in(alloc).into(),
             ValueView::Sequence(seq) => (*seq).clone().into(),
         }
     }
@@ -353,6 +420,10 @@ impl<'a> ValueView<'a> {
             ValueView::Int32Tensor(t) => t.layout().into(),
             ValueView::Int8Tensor(t) => t.layout().into(),
             ValueView::UInt8Tensor(t) => t.layout().into(),
+            ValueView::Float16Tensor(t) => t.layout().into(),
+            ValueView::BFloat16Tensor(t) => t.layout().into(),
+            ValueView::Int64Tensor(t) => t.layout().into(),
+            ValueView::BoolTensor(t) => t.layout().into(),
             ValueView::Sequence(seq) => ValueLayout::Vector(seq.len()),
         }
     }
@@ -438,6 +509,10 @@ impl_value_view_conversions!(FloatTensor, f32);
 impl_value_view_conversions!(Int32Tensor, i32);
 impl_value_view_conversions!(Int8Tensor, i8);
 impl_value_view_conversions!(UInt8Tensor, u8);
+impl_value_view_conversions!(Float16Tensor, f16);
+impl_value_view_conversions!(BFloat16Tensor, bf16);
+impl_value_view_conversions!(Int64Tensor, i64);
+impl_value_view_conversions!(BoolTensor, bool);
 
 impl<'a> From<&'a Value> for ValueView<'a> {
     fn from(output: &'a Value) -> ValueView<'a> {
@@ -446,6 +521,10 @@ impl<'a> From<&'a Value> for ValueView<'a> {
             Value::Int32Tensor(t) => ValueView::Int32Tensor(t.view()),
             Value::Int8Tensor(t) => ValueView::Int8Tensor(t.view()),
             Value::UInt8Tensor(t) => ValueView::UInt8Tensor(t.view()),
+            Value::Float16Tensor(t) => ValueView::Float16Tensor(t.view()),
+            Value::BFloat16Tensor(t) => ValueView::BFloat16Tensor(t.view()),
+            Value::Int64Tensor(t) => ValueView::Int64Tensor(t.view()),
+            Value::BoolTensor(t) => ValueView::BoolTensor(t.view()),
             Value::Sequence(seq) => ValueView::Sequence(seq),
         }
     }
@@ -481,6 +560,10 @@ pub enum Value {
     Int32Tensor(Tensor<i32>),
     Int8Tensor(Tensor<i8>),
     UInt8Tensor(Tensor<u8>),
+    Float16Tensor(Tensor<f16>),
+    BFloat16Tensor(Tensor<bf16>),
+    Int64Tensor(Tensor<i64>),
+    BoolTensor(Tensor<bool>),
     Sequence(Sequence),
 }
 
@@ -521,6 +604,10 @@ impl Value {
             Self::Int32Tensor(_) => t(DataType::Int32),
             Self::Int8Tensor(_) => t(DataType::Int8),
             Self::UInt8Tensor(_) => t(DataType::UInt8),
+            Self::Float16Tensor(_) => t(DataType::Float16),
+            Self::BFloat16Tensor(_) => t(DataType::BFloat16),
+            Self::Int64Tensor(_) => t(DataType::Int64),
+            Self::BoolTensor(_) => t(DataType::Bool),
             Self::Sequence(seq) => ValueType::Sequence(seq.dtype()),
         }
     }
@@ -532,6 +619,10 @@ impl Value {
             Self::Int32Tensor(it) => ValueView::Int32Tensor(it.view()),
             Self::Int8Tensor(it) => ValueView::Int8Tensor(it.view()),
             Self::UInt8Tensor(it) => ValueView::UInt8Tensor(it.view()),
+            Self::Float16Tensor(it) => ValueView::Float16Tensor(it.view()),
+            Self::BFloat16Tensor(it) => ValueView::BFloat16Tensor(it.view()),
+            Self::Int64Tensor(it) => ValueView::Int64Tensor(it.view()),
+            Self::BoolTensor(it) => ValueView::BoolTensor(it.view()),
             Self::Sequence(seq) => ValueView::Sequence(seq),
         }
     }
@@ -551,6 +642,10 @@ impl Value {
             Self::Int32Tensor(t) => t.extract_buffer().map(|buf| pool.add(buf)),
             Self::Int8Tensor(t) => t.extract_buffer().map(|buf| pool.add(buf)),
             Self::UInt8Tensor(t) => t.extract_buffer().map(|buf| pool.add(buf)),
+            Self::Float16Tensor(t) => t.extract_buffer().map(|buf| pool.add(buf)),
+            Self::BFloat16Tensor(t) => t.extract_buffer().map(|buf| pool.add(buf)),
+            Self::Int64Tensor(t) => t.extract_buffer().map(|buf| pool.add(buf)),
+            Self::BoolTensor(t) => t.extract_buffer().map(|buf| pool.add(buf)),
             Self::Sequence(seq) => {
                 seq.add_to_pool(pool);
                 Some(())
@@ -585,6 +680,10 @@ impl Value {
             Value::Int32Tensor(t) => tensor_bytes(t),
             Value::Int8Tensor(t) => tensor_bytes(t),
             Value::UInt8Tensor(t) => tensor_bytes(t),
+            Value::Float16Tensor(t) => tensor_bytes(t),
+            Value::BFloat16Tensor(t) => tensor_bytes(t),
+            Value::Int64Tensor(t) => tensor_bytes(t),
+            Value::BoolTensor(t) => tensor_bytes(t),
             Value::FloatTensor(t) => tensor_bytes(t),
             Value::Sequence(seq) => seq.bytes(),
         }
@@ -595,6 +694,10 @@ impl Value {
             Value::Int32Tensor(t) => t.layout().into(),
             Value::Int8Tensor(t) => t.layout().into(),
             Value::UInt8Tensor(t) => t.layout().into(),
+            Value::Float16Tensor(t) => t.layout().into(),
+            Value::BFloat16Tensor(t) => t.layout().into(),
+            Value::Int64Tensor(t) => t.layout().into(),
+            Value::BoolTensor(t) => t.layout().into(),
             Value::FloatTensor(t) => t.layout().into(),
             Value::Sequence(seq) => ValueLayout::Vector(seq.len()),
         }
@@ -615,6 +718,10 @@ impl ExtractBuffer for Value {
             Value::Int32Tensor(t) => t.extract_buffer(),
             Value::Int8Tensor(t) => t.extract_buffer(),
             Value::UInt8Tensor(t) => t.extract_buffer(),
+            Value::Float16Tensor(t) => t.extract_buffer(),
+            Value::BFloat16Tensor(t) => t.extract_buffer(),
+            Value::Int64Tensor(t) => t.extract_buffer(),
+            Value::BoolTensor(t) => t.extract_buffer(),
             Value::FloatTensor(t) => t.extract_buffer(),
             Value::Sequence(_) => {
                 // We can't implement `ExtractBuffer` for sequences because
@@ -716,6 +823,10 @@ impl_value_conversions!(FloatTensor, f32);
 impl_value_conversions!(Int32Tensor, i32);
 impl_value_conversions!(Int8Tensor, i8);
 impl_value_conversions!(UInt8Tensor, u8);
+impl_value_conversions!(Float16Tensor, f16);
+impl_value_conversions!(BFloat16Tensor, bf16);
+impl_value_conversions!(Int64Tensor, i64);
+impl_value_conversions!(BoolTensor, bool);
 
 impl From<Sequence> for Value {
     fn from(seq: Sequence) -> Value {
@@ -837,7 +948,7 @@ impl ExtractBuffer for ValueOrView<'_> {
 }
 
 /// A scalar value with runtime-determined type.
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Scalar {
     Int(i32),
     Float(f32),
@@ -870,6 +981,10 @@ pub enum Sequence {
     Int32(Vec<Tensor<i32>>),
     Int8(Vec<Tensor<i8>>),
     UInt8(Vec<Tensor<u8>>),
+    Float16(Vec<Tensor<f16>>),
+    BFloat16(Vec<Tensor<bf16>>),
+    Int64(Vec<Tensor<i64>>),
+    Bool(Vec<Tensor<bool>>),
 }
 
 impl Sequence {
@@ -879,6 +994,10 @@ impl Sequence {
             DataType::Int32 => Vec::<Tensor<i32>>::new().into(),
             DataType::Int8 => Vec::<Tensor<i8>>::new().into(),
             DataType::UInt8 => Vec::<Tensor<u8>>::new().into(),
+            DataType::Float16 => Vec::<Tensor<f16>>::new().into(),
+            DataType::BFloat16 => Vec::<Tensor<bf16>>::new().into(),
+            DataType::Int64 => Vec::<Tensor<i64>>::new().into(),
+            DataType::Bool => Vec::<Tensor<bool>>::new().into(),
             DataType::Float => Vec::<Tensor<f32>>::new().into(),
         }
     }
@@ -890,6 +1009,10 @@ impl Sequence {
             Self::Int32(_) => DataType::Int32,
             Self::Int8(_) => DataType::Int8,
             Self::UInt8(_) => DataType::UInt8,
+            Self::Float16(_) => DataType::Float16,
+            Self::BFloat16(_) => DataType::BFloat16,
+            Self::Int64(_) => DataType::Int64,
+            Self::Bool(_) => DataType::Bool,
         }
     }
 
@@ -900,6 +1023,10 @@ impl Sequence {
             Self::Int32(ints) => ints.len(),
             Self::Int8(ints) => ints.len(),
             Self::UInt8(ints) => ints.len(),
+            Self::Float16(ints) => ints.len(),
+            Self::BFloat16(ints) => ints.len(),
+            Self::Int64(ints) => ints.len(),
+            Self::Bool(ints) => ints.len(),
         }
     }
 
@@ -915,6 +1042,10 @@ impl Sequence {
             Self::Int32(ints) => Self::at_impl(ints, index),
             Self::Int8(ints) => Self::at_impl(ints, index),
             Self::UInt8(ints) => Self::at_impl(ints, index),
+            Self::Float16(ints) => Self::at_impl(ints, index),
+            Self::BFloat16(ints) => Self::at_impl(ints, index),
+            Self::Int64(ints) => Self::at_impl(ints, index),
+            Self::Bool(ints) => Self::at_impl(ints, index),
         }
     }
 
@@ -938,6 +1069,10 @@ impl Sequence {
             (Self::Int32(ints), Value::Int32Tensor(val)) => ints.insert(index, val),
             (Self::Int8(ints), Value::Int8Tensor(val)) => ints.insert(index, val),
             (Self::UInt8(ints), Value::UInt8Tensor(val)) => ints.insert(index, val),
+            (Self::Float16(ints), Value::Float16Tensor(val)) => ints.insert(index, val),
+            (Self::BFloat16(ints), Value::BFloat16Tensor(val)) => ints.insert(index, val),
+            (Self::Int64(ints), Value::Int64Tensor(val)) => ints.insert(index, val),
+            (Self::Bool(ints), Value::BoolTensor(val)) => ints.insert(index, val),
             _ => {
                 return Err(SequenceError::InvalidType);
             }
@@ -955,6 +1090,10 @@ impl Sequence {
             Self::Int32(ints) => ints.remove(index).into(),
             Self::Int8(ints) => ints.remove(index).into(),
             Self::UInt8(ints) => ints.remove(index).into(),
+            Self::Float16(ints) => ints.remove(index).into(),
+            Self::BFloat16(ints) => ints.remove(index).into(),
+            Self::Int64(ints) => ints.remove(index).into(),
+            Self::Bool(ints) => ints.remove(index).into(),
         };
         Ok(value)
     }
@@ -971,6 +1110,10 @@ impl Sequence {
             Self::Int32(tensors) => tensors.iter().map(tensor_bytes).sum(),
             Self::Int8(tensors) => tensors.iter().map(tensor_bytes).sum(),
             Self::UInt8(tensors) => tensors.iter().map(tensor_bytes).sum(),
+            Self::Float16(tensors) => tensors.iter().map(tensor_bytes).sum(),
+            Self::BFloat16(tensors) => tensors.iter().map(tensor_bytes).sum(),
+            Self::Int64(tensors) => tensors.iter().map(tensor_bytes).sum(),
+            Self::Bool(tensors) => tensors.iter().map(tensor_bytes).sum(),
         }
     }
 
@@ -982,6 +1125,10 @@ impl Sequence {
             Self::Int32(ints) => Self::add_items_to_pool(ints, pool),
             Self::Int8(ints) => Self::add_items_to_pool(ints, pool),
             Self::UInt8(ints) => Self::add_items_to_pool(ints, pool),
+            Self::Float16(ints) => Self::add_items_to_pool(ints, pool),
+            Self::BFloat16(ints) => Self::add_items_to_pool(ints, pool),
+            Self::Int64(ints) => Self::add_items_to_pool(ints, pool),
+            Self::Bool(ints) => Self::add_items_to_pool(ints, pool),
         }
     }
 
@@ -1013,6 +1160,10 @@ impl_sequence_conversions!(Float, Tensor<f32>);
 impl_sequence_conversions!(Int32, Tensor<i32>);
 impl_sequence_conversions!(Int8, Tensor<i8>);
 impl_sequence_conversions!(UInt8, Tensor<u8>);
+impl_sequence_conversions!(Float16, Tensor<f16>);
+impl_sequence_conversions!(BFloat16, Tensor<bf16>);
+impl_sequence_conversions!(Int64, Tensor<i64>);
+impl_sequence_conversions!(Bool, Tensor<bool>);
 
 impl<'a> TryFrom<ValueView<'a>> for &'a Sequence {
     type Error = TryFromValueError;